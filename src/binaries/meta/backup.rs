@@ -0,0 +1,144 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::sync::Arc;
+
+use databend_common_meta_client::ClientHandle;
+use databend_common_meta_client::MetaGrpcClient;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_types::protobuf as pb;
+use databend_meta::backup::Namespace;
+use databend_meta::backup::Restore;
+use databend_meta::configs::Config;
+use tokio_stream::StreamExt;
+
+fn new_client(conf: &Config) -> anyhow::Result<Arc<ClientHandle>> {
+    let client = MetaGrpcClient::try_create(
+        vec![conf.grpc_api_address.clone()],
+        &conf.username,
+        &conf.password,
+        None,
+        None,
+        None,
+    )?;
+    Ok(client)
+}
+
+/// Export a logical backup from the meta-service to the backup file or stdout.
+pub async fn export_backup(conf: &Config) -> anyhow::Result<()> {
+    if conf.backup_file.len() > 1 {
+        return Err(anyhow::anyhow!(
+            "--export accepts at most one --backup-file"
+        ));
+    }
+
+    let client = new_client(conf)?;
+    let mut grpc_client = client.make_established_client().await?;
+
+    let mut stream = grpc_client
+        .backup(pb::BackupRequest {
+            since_seq: conf.since_seq,
+            chunk_size: None,
+        })
+        .await?
+        .into_inner();
+
+    let mut file = match conf.backup_file.first() {
+        Some(path) => {
+            eprintln!("Export to: {}", path);
+            Some(File::create(path)?)
+        }
+        None => None,
+    };
+
+    while let Some(chunk) = stream.next().await {
+        for line in chunk?.data {
+            match file.as_mut() {
+                Some(f) => f.write_all(format!("{}\n", line).as_bytes())?,
+                None => println!("{}", line),
+            }
+        }
+    }
+
+    if let Some(f) = file {
+        f.sync_all()?;
+    }
+
+    Ok(())
+}
+
+/// Restore the key-values into the meta-service from a full backup and the following incremental backups.
+pub async fn import_backup(conf: &Config) -> anyhow::Result<()> {
+    if conf.backup_file.is_empty() {
+        return Err(anyhow::anyhow!("--import requires --backup-file"));
+    }
+
+    let namespace = match (conf.restore_tenant.as_str(), conf.restore_database.as_str()) {
+        ("", "") => None,
+        ("", _) => {
+            return Err(anyhow::anyhow!(
+                "--restore-database requires --restore-tenant"
+            ));
+        }
+        (tenant, "") => Some(Namespace::new(tenant, None)),
+        (tenant, database) => Some(Namespace::new(tenant, Some(database.to_string()))),
+    };
+
+    let mut restore = Restore::new(conf.until_seq);
+    for path in &conf.backup_file {
+        let lines = BufReader::new(File::open(path)?)
+            .lines()
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if restore.apply(lines)? {
+            eprintln!("Applied backup: {}, seq: {:?}", path, restore.seq());
+        } else {
+            eprintln!("Skipped backup taken after --until-seq: {}", path);
+            break;
+        }
+    }
+
+    if restore.seq().is_none() {
+        return Err(anyhow::anyhow!("no backup is taken before --until-seq"));
+    }
+
+    let client = new_client(conf)?;
+    let current = client
+        .prefix_list_kv("")
+        .await?
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+
+    let txn = restore.build_txn(&current, namespace.as_ref())?;
+    let n = txn.if_then.len();
+    let reply = client.transaction(txn).await?;
+    if !reply.success {
+        return Err(anyhow::anyhow!(
+            "the keyspace is changed during the restore, nothing is restored"
+        ));
+    }
+
+    eprintln!(
+        "Restored to seq: {:?}, {} records are written",
+        restore.seq(),
+        n
+    );
+
+    Ok(())
+}
@@ -48,6 +48,7 @@ use log::warn;
 use tokio::time::sleep;
 use tokio::time::Instant;
 
+use crate::backup;
 use crate::kvapi::KvApiCommand;
 
 const CMD_KVAPI_PREFIX: &str = "kvapi::";
@@ -57,6 +58,14 @@ pub async fn entry(conf: Config) -> anyhow::Result<()> {
         return Ok(());
     }
 
+    if conf.export {
+        return backup::export_backup(&conf).await;
+    }
+
+    if conf.import {
+        return backup::import_backup(&conf).await;
+    }
+
     let mut _sentry_guard = None;
     let bend_sentry_env = env::var("DATABEND_SENTRY_DSN").unwrap_or_else(|_| "".to_string());
     if !bend_sentry_env.is_empty() {
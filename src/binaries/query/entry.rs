@@ -17,7 +17,9 @@ use std::time::Duration;
 
 use databend_common_base::mem_allocator::GlobalAllocator;
 use databend_common_base::runtime::set_alloc_error_hook;
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::GLOBAL_MEM_STAT;
+use databend_common_base::runtime::GLOBAL_TASK;
 use databend_common_config::Commands;
 use databend_common_config::InnerConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
@@ -29,7 +31,9 @@ use databend_common_storage::DataOperator;
 use databend_common_tracing::set_panic_hook;
 use databend_enterprise_background_service::get_background_service_handler;
 use databend_query::clusters::ClusterDiscovery;
+use databend_query::config_reload::ConfigReloader;
 use databend_query::local;
+use databend_query::pipes::PipeManager;
 use databend_query::servers::admin::AdminService;
 use databend_query::servers::flight::FlightService;
 use databend_query::servers::metrics::MetricService;
@@ -40,8 +44,11 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::spillers::remove_orphan_spill_files;
+use databend_query::storage_usage::StorageUsageCollector;
 use databend_query::GlobalServices;
 use log::info;
+use log::warn;
 
 pub async fn run_cmd(conf: &InnerConfig) -> Result<bool> {
     match &conf.subcommand {
@@ -136,7 +143,8 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         let tls_config = MySQLTlsConfig::new(
             conf.query.mysql_tls_server_cert.clone(),
             conf.query.mysql_tls_server_key.clone(),
-        );
+        )
+        .with_root_ca(conf.query.mysql_tls_server_root_ca_cert.clone());
 
         let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, tls_config)?;
         let listening = handler.start(listening.parse()?).await?;
@@ -232,6 +240,28 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // Spill files left by crashed nodes.
+    {
+        let conf = conf.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            if let Err(cause) = remove_orphan_spill_files(&conf).await {
+                warn!(
+                    "Failed to remove the orphan spill files, cause: {:?}",
+                    cause
+                );
+            }
+        });
+    }
+
+    // Pipes.
+    PipeManager::instance().start();
+
+    // Storage usage.
+    StorageUsageCollector::instance().start();
+
+    // Config reload.
+    ConfigReloader::start()?;
+
     // Print information to users.
     println!("Databend Query");
     println!();
@@ -17,6 +17,7 @@ use std::marker::PhantomData;
 
 use parquet2::metadata::ColumnDescriptor;
 
+use crate::arrow::array::new_empty_array;
 use crate::arrow::array::Array;
 use crate::arrow::array::BinaryArray;
 use crate::arrow::array::Utf8Array;
@@ -32,6 +33,10 @@ use crate::arrow::offset::OffsetsBuffer;
 use crate::arrow::types::Offset;
 use crate::native::compression::binary::decompress_binary;
 use crate::native::read::read_basic::*;
+use crate::native::read::selection::check_selection;
+use crate::native::read::selection::filter_binary;
+use crate::native::read::selection::filter_bitmap;
+use crate::native::read::ArrayIterator;
 use crate::native::read::BufReader;
 use crate::native::read::NativeReadBuf;
 use crate::native::read::PageIterator;
@@ -100,6 +105,66 @@ where
             validity,
         )
     }
+
+    fn deserialize_with_selection(
+        &mut self,
+        num_values: u64,
+        mut buffer: Vec<u8>,
+        selection: &Bitmap,
+    ) -> Result<Box<dyn Array>> {
+        let length = num_values as usize;
+        check_selection(selection, length)?;
+        if selection.unset_bits() == length {
+            // No rows are selected, skip the decompression of the page.
+            self.iter.swap_buffer(&mut buffer);
+            return Ok(new_empty_array(self.data_type.clone()));
+        }
+
+        let mut reader = BufReader::with_capacity(buffer.len(), Cursor::new(buffer));
+        let validity = if self.is_nullable {
+            let mut validity_builder = MutableBitmap::with_capacity(length);
+            read_validity(&mut reader, length, &mut validity_builder)?;
+            Some(filter_bitmap(&validity_builder, selection))
+        } else {
+            None
+        };
+
+        let mut offsets: Vec<O> = Vec::with_capacity(length + 1);
+        let mut values = Vec::with_capacity(0);
+
+        decompress_binary(
+            &mut reader,
+            length,
+            &mut offsets,
+            &mut values,
+            &mut self.scratch,
+        )?;
+
+        // Only the selected values are copied to the result.
+        let (offsets, values) = filter_binary(&offsets, &values, selection);
+        try_new_binary_array(
+            self.data_type.clone(),
+            unsafe { OffsetsBuffer::new_unchecked(offsets.into()) },
+            values.into(),
+            validity,
+        )
+    }
+}
+
+impl<I, O> ArrayIterator for BinaryIter<I, O>
+where
+    I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync,
+    O: Offset,
+{
+    fn nth_with_selection(&mut self, n: usize, selection: &Bitmap) -> Option<Self::Item> {
+        match self.iter.nth(n) {
+            Some(Ok((num_values, buffer))) => {
+                Some(self.deserialize_with_selection(num_values, buffer, selection))
+            }
+            Some(Err(err)) => Some(Result::Err(err)),
+            None => None,
+        }
+    }
 }
 
 impl<I, O> Iterator for BinaryIter<I, O>
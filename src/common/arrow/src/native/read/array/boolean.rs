@@ -16,8 +16,10 @@ use std::io::Cursor;
 
 use parquet2::metadata::ColumnDescriptor;
 
+use crate::arrow::array::new_empty_array;
 use crate::arrow::array::Array;
 use crate::arrow::array::BooleanArray;
+use crate::arrow::bitmap::Bitmap;
 use crate::arrow::bitmap::MutableBitmap;
 use crate::arrow::datatypes::DataType;
 use crate::arrow::error::Result;
@@ -25,6 +27,9 @@ use crate::arrow::io::parquet::read::InitNested;
 use crate::arrow::io::parquet::read::NestedState;
 use crate::native::compression::boolean::decompress_boolean;
 use crate::native::read::read_basic::*;
+use crate::native::read::selection::check_selection;
+use crate::native::read::selection::filter_bitmap;
+use crate::native::read::ArrayIterator;
 use crate::native::read::BufReader;
 use crate::native::read::NativeReadBuf;
 use crate::native::read::PageIterator;
@@ -77,6 +82,54 @@ where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
         let array = BooleanArray::try_new(self.data_type.clone(), values, validity)?;
         Ok(Box::new(array) as Box<dyn Array>)
     }
+
+    fn deserialize_with_selection(
+        &mut self,
+        num_values: u64,
+        mut buffer: Vec<u8>,
+        selection: &Bitmap,
+    ) -> Result<Box<dyn Array>> {
+        let length = num_values as usize;
+        check_selection(selection, length)?;
+        if selection.unset_bits() == length {
+            // No rows are selected, skip the decompression of the page.
+            self.iter.swap_buffer(&mut buffer);
+            return Ok(new_empty_array(self.data_type.clone()));
+        }
+
+        let mut reader = BufReader::with_capacity(buffer.len(), Cursor::new(buffer));
+        let validity = if self.is_nullable {
+            let mut validity_builder = MutableBitmap::with_capacity(length);
+            read_validity(&mut reader, length, &mut validity_builder)?;
+            Some(filter_bitmap(&validity_builder, selection))
+        } else {
+            None
+        };
+        let mut bitmap_builder = MutableBitmap::with_capacity(length);
+
+        decompress_boolean(&mut reader, length, &mut bitmap_builder, &mut self.scratch)?;
+
+        let values = filter_bitmap(&bitmap_builder, selection);
+        let mut buffer = reader.into_inner().into_inner();
+        self.iter.swap_buffer(&mut buffer);
+
+        let array = BooleanArray::try_new(self.data_type.clone(), values, validity)?;
+        Ok(Box::new(array) as Box<dyn Array>)
+    }
+}
+
+impl<I> ArrayIterator for BooleanIter<I>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
+{
+    fn nth_with_selection(&mut self, n: usize, selection: &Bitmap) -> Option<Self::Item> {
+        match self.iter.nth(n) {
+            Some(Ok((num_values, buffer))) => {
+                Some(self.deserialize_with_selection(num_values, buffer, selection))
+            }
+            Some(Err(err)) => Some(Result::Err(err)),
+            None => None,
+        }
+    }
 }
 
 impl<I> Iterator for BooleanIter<I>
@@ -14,8 +14,11 @@
 
 use crate::arrow::array::Array;
 use crate::arrow::array::NullArray;
+use crate::arrow::bitmap::Bitmap;
 use crate::arrow::datatypes::DataType;
 use crate::arrow::error::Result;
+use crate::native::read::selection::check_selection;
+use crate::native::read::ArrayIterator;
 use crate::native::read::PageIterator;
 use crate::native::PageMeta;
 
@@ -73,6 +76,25 @@ where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
     }
 }
 
+impl<I> ArrayIterator for NullIter<I>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync
+{
+    fn nth_with_selection(&mut self, n: usize, selection: &Bitmap) -> Option<Self::Item> {
+        match self.iter.nth(n) {
+            Some(Ok((num_values, mut buffer))) => {
+                self.iter.swap_buffer(&mut buffer);
+                if let Err(err) = check_selection(selection, num_values as usize) {
+                    return Some(Err(err));
+                }
+                let num_selected = selection.len() - selection.unset_bits();
+                Some(self.deserialize(num_selected as u64))
+            }
+            Some(Err(err)) => Some(Result::Err(err)),
+            None => None,
+        }
+    }
+}
+
 pub fn read_null(data_type: DataType, page_metas: Vec<PageMeta>) -> Result<Box<dyn Array>> {
     let length = page_metas.iter().map(|p| p.num_values as usize).sum();
 
@@ -15,8 +15,10 @@
 use parquet2::metadata::ColumnDescriptor;
 
 use super::array::*;
+use super::selection::filter_array;
 use super::PageIterator;
 use crate::arrow::array::*;
+use crate::arrow::bitmap::Bitmap;
 use crate::arrow::datatypes::DataType;
 use crate::arrow::datatypes::Field;
 use crate::arrow::datatypes::PhysicalType;
@@ -51,7 +53,49 @@ impl<'a, V> DynIter<'a, V> {
     }
 }
 
-pub type ArrayIter<'a> = DynIter<'a, Result<Box<dyn Array>>>;
+/// [`ArrayIterator`] is an iterator of the arrays of pages, it can decode only the selected
+/// rows of a page, which is used to late materialize the columns after filtering.
+pub trait ArrayIterator: Iterator<Item = Result<Box<dyn Array>>> + Send + Sync {
+    /// Reads the next nth page like `nth`, and returns only the rows set in `selection`.
+    fn nth_with_selection(&mut self, n: usize, selection: &Bitmap) -> Option<Self::Item> {
+        match self.nth(n) {
+            Some(Ok(array)) => Some(filter_array(array.as_ref(), selection)),
+            other => other,
+        }
+    }
+}
+
+/// [`ArrayIter`] is a boxed [`ArrayIterator`] of a column.
+pub struct ArrayIter<'a> {
+    iter: Box<dyn ArrayIterator + 'a>,
+}
+
+impl<'a> Iterator for ArrayIter<'a> {
+    type Item = Result<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n)
+    }
+}
+
+impl<'a> ArrayIterator for ArrayIter<'a> {
+    fn nth_with_selection(&mut self, n: usize, selection: &Bitmap) -> Option<Self::Item> {
+        self.iter.nth_with_selection(n, selection)
+    }
+}
+
+impl<'a> ArrayIter<'a> {
+    pub fn new<I>(iter: I) -> Self
+    where I: ArrayIterator + 'a {
+        Self {
+            iter: Box::new(iter),
+        }
+    }
+}
 
 /// [`NestedIter`] is a wrapper iterator used to remove the `NestedState` from inner iterator
 /// and return only the `Box<dyn Array>`
@@ -92,42 +136,40 @@ where I: Iterator<Item = Result<(NestedState, Box<dyn Array>)>> + Send + Sync
     }
 }
 
+impl<I> ArrayIterator for NestedIter<I> where I: Iterator<Item = Result<(NestedState, Box<dyn Array>)>> + Send + Sync
+{}
+
 pub type NestedIters<'a> = DynIter<'a, Result<(NestedState, Box<dyn Array>)>>;
 
-fn deserialize_simple<'a, I: 'a>(
-    reader: I,
-    field: Field,
-) -> Result<DynIter<'a, Result<Box<dyn Array>>>>
-where
-    I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync,
-{
+fn deserialize_simple<'a, I: 'a>(reader: I, field: Field) -> Result<ArrayIter<'a>>
+where I: Iterator<Item = Result<(u64, Vec<u8>)>> + PageIterator + Send + Sync {
     use PhysicalType::*;
 
     let is_nullable = field.is_nullable;
     let data_type = field.data_type().clone();
 
     Ok(match data_type.to_physical_type() {
-        Null => DynIter::new(NullIter::new(reader, data_type)),
-        Boolean => DynIter::new(BooleanIter::new(reader, is_nullable, data_type)),
+        Null => ArrayIter::new(NullIter::new(reader, data_type)),
+        Boolean => ArrayIter::new(BooleanIter::new(reader, is_nullable, data_type)),
         Primitive(primitive) => with_match_integer_double_type!(primitive,
         |$I| {
-            DynIter::new(IntegerIter::<_, $I>::new(
+            ArrayIter::new(IntegerIter::<_, $I>::new(
                 reader,
                 is_nullable,
                 data_type,
             ))
         },
         |$T| {
-             DynIter::new(DoubleIter::<_, $T>::new(
+             ArrayIter::new(DoubleIter::<_, $T>::new(
                 reader,
                 is_nullable,
                 data_type,
             ))
         }
         ),
-        Binary | Utf8 => DynIter::new(BinaryIter::<_, i32>::new(reader, is_nullable, data_type)),
+        Binary | Utf8 => ArrayIter::new(BinaryIter::<_, i32>::new(reader, is_nullable, data_type)),
         LargeBinary | LargeUtf8 => {
-            DynIter::new(BinaryIter::<_, i64>::new(reader, is_nullable, data_type))
+            ArrayIter::new(BinaryIter::<_, i64>::new(reader, is_nullable, data_type))
         }
         FixedSizeBinary => unimplemented!(),
         _ => unreachable!(),
@@ -243,7 +285,7 @@ where
     if is_nested {
         let iter = deserialize_nested(readers, leaves, field, vec![])?;
         let nested_iter = NestedIter::new(iter);
-        Ok(DynIter::new(nested_iter))
+        Ok(ArrayIter::new(nested_iter))
     } else {
         deserialize_simple(readers.pop().unwrap(), field)
     }
@@ -17,8 +17,12 @@ pub mod batch_read;
 pub mod deserialize;
 pub use deserialize::column_iter_to_arrays;
 pub use deserialize::ArrayIter;
+pub use deserialize::ArrayIterator;
 pub(crate) mod read_basic;
+pub mod selection;
 use std::io::BufReader;
+
+pub use selection::filter_array;
 pub mod reader;
 
 pub trait NativeReadBuf: std::io::BufRead {
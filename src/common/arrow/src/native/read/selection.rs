@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::arrow::array::Array;
+use crate::arrow::array::PrimitiveArray;
+use crate::arrow::bitmap::Bitmap;
+use crate::arrow::bitmap::MutableBitmap;
+use crate::arrow::compute::take::take;
+use crate::arrow::error::Error;
+use crate::arrow::error::Result;
+use crate::arrow::types::Offset;
+
+/// Returns an error if the selection does not cover all the rows of the page.
+pub(crate) fn check_selection(selection: &Bitmap, num_values: usize) -> Result<()> {
+    if selection.len() != num_values {
+        return Err(Error::oos(format!(
+            "The length of selection {} does not match the number of values {} in the page",
+            selection.len(),
+            num_values
+        )));
+    }
+    Ok(())
+}
+
+/// Keeps the rows of the array that are set in `selection`.
+pub fn filter_array(array: &dyn Array, selection: &Bitmap) -> Result<Box<dyn Array>> {
+    check_selection(selection, array.len())?;
+    if selection.unset_bits() == 0 {
+        return Ok(array.to_boxed());
+    }
+
+    let indices = selection
+        .iter()
+        .enumerate()
+        .filter_map(|(i, selected)| selected.then_some(i as u32))
+        .collect::<Vec<_>>();
+    take(array, &PrimitiveArray::<u32>::from_vec(indices))
+}
+
+/// Keeps the values that are set in `selection`.
+pub(crate) fn filter_values<T: Copy>(values: &[T], selection: &Bitmap) -> Vec<T> {
+    let mut result = Vec::with_capacity(selection.len() - selection.unset_bits());
+    for (value, selected) in values.iter().zip(selection.iter()) {
+        if selected {
+            result.push(*value);
+        }
+    }
+    result
+}
+
+/// Keeps the bits that are set in `selection`.
+pub(crate) fn filter_bitmap(bitmap: &MutableBitmap, selection: &Bitmap) -> Bitmap {
+    let mut result = MutableBitmap::with_capacity(selection.len() - selection.unset_bits());
+    for (value, selected) in bitmap.iter().zip(selection.iter()) {
+        if selected {
+            result.push(value);
+        }
+    }
+    result.into()
+}
+
+/// Keeps the binary values that are set in `selection`.
+pub(crate) fn filter_binary<O: Offset>(
+    offsets: &[O],
+    values: &[u8],
+    selection: &Bitmap,
+) -> (Vec<O>, Vec<u8>) {
+    let mut result_offsets = Vec::with_capacity(selection.len() - selection.unset_bits() + 1);
+    let mut result_values = Vec::new();
+    result_offsets.push(O::from_as_usize(0));
+    for (i, selected) in selection.iter().enumerate() {
+        if selected {
+            let start = offsets[i].to_usize();
+            let end = offsets[i + 1].to_usize();
+            result_values.extend_from_slice(&values[start..end]);
+            result_offsets.push(O::from_as_usize(result_values.len()));
+        }
+    }
+    (result_offsets, result_values)
+}
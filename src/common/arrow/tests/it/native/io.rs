@@ -46,8 +46,10 @@ use databend_common_arrow::arrow::io::parquet::write::to_parquet_schema;
 use databend_common_arrow::arrow::offset::OffsetsBuffer;
 use databend_common_arrow::native::read::batch_read::batch_read_array;
 use databend_common_arrow::native::read::deserialize::column_iter_to_arrays;
+use databend_common_arrow::native::read::filter_array;
 use databend_common_arrow::native::read::reader::is_primitive;
 use databend_common_arrow::native::read::reader::NativeReader;
+use databend_common_arrow::native::read::ArrayIterator;
 use databend_common_arrow::native::write::NativeWriter;
 use databend_common_arrow::native::write::WriteOptions;
 use databend_common_arrow::native::ColumnMeta;
@@ -471,6 +473,7 @@ fn test_write_read_with_options(chunk: Chunk<Box<dyn Array>>, options: WriteOpti
     log::info!("write finished, start to read");
 
     let mut batch_metas = writer.metas.clone();
+    let mut selection_metas = writer.metas.clone();
     let mut metas = writer.metas.clone();
     let schema_descriptor = to_parquet_schema(&schema).unwrap();
     let mut leaves = schema_descriptor.columns().to_vec();
@@ -536,4 +539,49 @@ fn test_write_read_with_options(chunk: Chunk<Box<dyn Array>>, options: WriteOpti
     let batch_result_chunk = Chunk::new(batch_results);
 
     assert_eq!(chunk, batch_result_chunk);
+
+    // test read with selection
+    let schema_descriptor = to_parquet_schema(&schema).unwrap();
+    let mut leaves = schema_descriptor.columns().to_vec();
+    for (field, array) in schema.fields.iter().zip(chunk.iter()) {
+        let n = n_columns(&field.data_type);
+
+        let curr_metas: Vec<ColumnMeta> = selection_metas.drain(..n).collect();
+        let curr_leaves: Vec<ColumnDescriptor> = leaves.drain(..n).collect();
+        let is_nested = !is_primitive(field.data_type());
+        if is_nested {
+            // the number of values in the pages of nested columns is not the number of rows.
+            continue;
+        }
+
+        let mut native_readers = Vec::with_capacity(n);
+        for curr_meta in curr_metas.iter() {
+            let mut range_bytes = std::io::Cursor::new(bytes.clone());
+            range_bytes.consume(curr_meta.offset as usize);
+
+            let native_reader = NativeReader::new(range_bytes, curr_meta.pages.clone(), vec![]);
+            native_readers.push(native_reader);
+        }
+
+        let mut array_iter =
+            column_iter_to_arrays(native_readers, curr_leaves, field.clone(), is_nested).unwrap();
+
+        let mut offset = 0;
+        for (i, page) in curr_metas[0].pages.iter().enumerate() {
+            let num_values = page.num_values as usize;
+            // select every third row, and none of the rows in the last page
+            let selection = Bitmap::from_iter(
+                (0..num_values).map(|row| i + 1 != curr_metas[0].pages.len() && row % 3 == 0),
+            );
+            let selected = array_iter
+                .nth_with_selection(0, &selection)
+                .unwrap()
+                .unwrap();
+
+            let expected = array.sliced(offset, num_values);
+            let expected = filter_array(expected.as_ref(), &selection).unwrap();
+            assert_eq!(expected, selected);
+            offset += num_values;
+        }
+    }
 }
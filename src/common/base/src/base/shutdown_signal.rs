@@ -61,10 +61,11 @@ impl Stream for DummySignalStream {
     }
 }
 
+/// The stream of the shutdown signals. SIGHUP is not a shutdown signal, it is used to
+/// reload the config.
 #[cfg(not(target_os = "windows"))]
 pub fn signal_stream() -> Result<SignalStream> {
     Ok(Box::pin(UnixShutdownSignalStream {
-        hangup_signal: signal(SignalKind::hangup())?,
         sigint_signal: signal(SignalKind::interrupt())?,
         sigterm_signal: signal(SignalKind::terminate())?,
     }))
@@ -77,7 +78,6 @@ pub fn signal_stream() -> Result<SignalStream> {
 
 #[cfg(not(target_os = "windows"))]
 struct UnixShutdownSignalStream {
-    hangup_signal: Signal,
     sigint_signal: Signal,
     sigterm_signal: Signal,
 }
@@ -93,10 +93,6 @@ impl Stream for UnixShutdownSignalStream {
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let mut_self = self.get_mut();
-        if let Poll::Ready(res) = mut_self.hangup_signal.poll_recv(cx) {
-            return Poll::Ready(res.map(|_| SignalType::Hangup));
-        }
-
         if let Poll::Ready(res) = mut_self.sigint_signal.poll_recv(cx) {
             return Poll::Ready(res.map(|_| SignalType::Sigint));
         }
@@ -18,4 +18,5 @@ mod range_merger;
 
 pub use range_map::RangeMap;
 pub use range_map_key::RangeMapKey;
+pub use range_merger::split_range;
 pub use range_merger::RangeMerger;
@@ -90,3 +90,20 @@ impl RangeMerger {
         self.ranges.push(range.clone());
     }
 }
+
+/// Split a range into ranges no larger than `max_size`, so that they can be read concurrently.
+/// The range is not split if `max_size` is 0.
+pub fn split_range(range: Range<u64>, max_size: u64) -> Vec<Range<u64>> {
+    if max_size == 0 || range.end - range.start <= max_size {
+        return vec![range];
+    }
+
+    let mut ranges = Vec::with_capacity(((range.end - range.start) / max_size + 1) as usize);
+    let mut start = range.start;
+    while start < range.end {
+        let end = cmp::min(start + max_size, range.end);
+        ranges.push(start..end);
+        start = end;
+    }
+    ranges
+}
@@ -46,7 +46,9 @@ pub use runtime::Dropper;
 pub use runtime::Runtime;
 pub use runtime::TrySpawn;
 pub use runtime::GLOBAL_TASK;
+pub use runtime_tracker::IoPriority;
 pub use runtime_tracker::LimitMemGuard;
+pub use runtime_tracker::QueryPriority;
 pub use runtime_tracker::ThreadTracker;
 pub use runtime_tracker::TrackingPayload;
 pub use runtime_tracker::UnlimitedFuture;
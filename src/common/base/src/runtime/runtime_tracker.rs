@@ -103,6 +103,63 @@ pub struct TrackingPayload {
     pub profile: Option<Arc<Profile>>,
     pub mem_stat: Option<Arc<MemStat>>,
     pub metrics: Option<Arc<ScopedRegistry>>,
+    pub io_priority: IoPriority,
+    /// The id of the query the tracked thread or future works for, it's attached to
+    /// the logs emitted inside, such as the storage access logs.
+    pub query_id: Option<Arc<String>>,
+}
+
+/// The priority of the storage requests sent by the tracked threads and futures.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IoPriority {
+    /// Queries of high priority, they go before the interactive queries.
+    High,
+    /// Queries that users are waiting for.
+    #[default]
+    Interactive,
+    /// Jobs that can be delayed, such as compaction after write.
+    Background,
+}
+
+impl IoPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IoPriority::High => "high",
+            IoPriority::Interactive => "interactive",
+            IoPriority::Background => "background",
+        }
+    }
+}
+
+/// The priority of a query, set by `PRIORITY LOW | NORMAL | HIGH <statement>` or the
+/// `query_priority` setting. It decides the share of the CPU time of the query when
+/// the node is busy, and the priority of its storage requests.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum QueryPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl QueryPriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryPriority::Low => "low",
+            QueryPriority::Normal => "normal",
+            QueryPriority::High => "high",
+        }
+    }
+
+    /// The priority of the storage requests of the query, `io_priority` is the one set
+    /// by the `io_priority` setting, which applies to the queries of normal priority.
+    pub fn io_priority(&self, io_priority: IoPriority) -> IoPriority {
+        match self {
+            QueryPriority::Low => IoPriority::Background,
+            QueryPriority::Normal => io_priority,
+            QueryPriority::High => IoPriority::High,
+        }
+    }
 }
 
 pub struct TrackingGuard {
@@ -162,6 +219,8 @@ impl ThreadTracker {
                 profile: None,
                 metrics: None,
                 mem_stat: None,
+                io_priority: IoPriority::Interactive,
+                query_id: None,
             },
         }
     }
@@ -197,6 +256,13 @@ impl ThreadTracker {
         TRACKER.with(move |x| TrackingFuture::create(future, x.borrow().payload.clone()))
     }
 
+    /// Track the future with the current payload, and tag it with the query id.
+    pub fn tracking_query_future<T: Future>(query_id: &str, future: T) -> TrackingFuture<T> {
+        let mut payload = Self::new_tracking_payload();
+        payload.query_id = Some(Arc::new(query_id.to_string()));
+        TrackingFuture::create(future, payload)
+    }
+
     pub fn tracking_function<F, T>(f: F) -> impl FnOnce() -> T
     where F: FnOnce() -> T {
         TRACKER.with(move |x| {
@@ -212,6 +278,19 @@ impl ThreadTracker {
         TRACKER.with(|x| x.borrow().payload.clone())
     }
 
+    /// The priority of the storage requests sent by the current thread.
+    pub fn io_priority() -> IoPriority {
+        TRACKER.with(|x| x.borrow().payload.io_priority)
+    }
+
+    /// The id of the query the current thread works for.
+    pub fn query_id() -> Option<Arc<String>> {
+        TRACKER
+            .try_with(|x| x.try_borrow().ok().and_then(|x| x.payload.query_id.clone()))
+            .ok()
+            .flatten()
+    }
+
     /// Replace the `out_of_limit_desc` with the current thread's.
     pub fn replace_error_message(desc: Option<String>) -> Option<String> {
         TRACKER.with(|v: &RefCell<ThreadTracker>| {
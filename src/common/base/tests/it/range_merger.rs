@@ -15,6 +15,7 @@
 use std::fmt;
 use std::fmt::Formatter;
 
+use databend_common_base::rangemap::split_range;
 use databend_common_base::rangemap::RangeMerger;
 use databend_common_exception::Result;
 
@@ -119,3 +120,21 @@ fn test_range_merger_with_gap() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_split_range() -> Result<()> {
+    // no split
+    assert_eq!(format!("{}", Array(split_range(1..20, 0))), "[1,20] ");
+    assert_eq!(format!("{}", Array(split_range(1..20, 19))), "[1,20] ");
+
+    assert_eq!(
+        format!("{}", Array(split_range(1..20, 5))),
+        "[1,6] [6,11] [11,16] [16,20] "
+    );
+    assert_eq!(
+        format!("{}", Array(split_range(0..20, 10))),
+        "[0,10] [10,20] "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,357 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::ErrorCode;
+
+/// The class of an error, so that the clients can branch on it instead of matching the message.
+///
+/// The numeric code of an error is stable, the category and the SQLSTATE are derived from it.
+/// They are returned to the HTTP and the MySQL clients, there is no Postgres wire handler.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    Ok,
+    Syntax,
+    Semantic,
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    Authentication,
+    InvalidArgument,
+    DataException,
+    ConstraintViolation,
+    /// Conflicts with a concurrent operation, the statement can be retried.
+    Conflict,
+    Transaction,
+    Timeout,
+    Cancelled,
+    ResourceExhausted,
+    /// A node or a service the query depends on is unavailable, the statement can be retried.
+    Unavailable,
+    Unsupported,
+    Internal,
+}
+
+impl ErrorCategory {
+    pub fn from_code(code: u16) -> ErrorCategory {
+        match code {
+            ErrorCode::OK => ErrorCategory::Ok,
+
+            ErrorCode::SYNTAX_EXCEPTION | ErrorCode::TANTIVY_QUERY_PARSER_ERROR => {
+                ErrorCategory::Syntax
+            }
+
+            ErrorCode::SEMANTIC_ERROR
+            | ErrorCode::SCRIPT_SEMANTIC_ERROR
+            | ErrorCode::ILLEGAL_DATA_TYPE
+            | ErrorCode::NUMBER_ARGUMENTS_NOT_MATCH
+            | ErrorCode::UNMATCH_COLUMN_DATA_TYPE
+            | ErrorCode::UNMATCH_MASK_POLICY_RETURN_TYPE
+            | ErrorCode::TABLE_SCHEMA_MISMATCH
+            | ErrorCode::TABLE_WITH_INTERNAL_COLUMN_NAME
+            | ErrorCode::COLUMN_REFERENCED_BY_COMPUTED_COLUMN
+            | ErrorCode::DROP_COLUMN_EMPTY_ERROR
+            | ErrorCode::INVALID_CLUSTER_KEYS
+            | ErrorCode::UNCLUSTERED_TABLE => ErrorCategory::Semantic,
+
+            ErrorCode::UNKNOWN_DATABASE
+            | ErrorCode::UNKNOWN_DATABASE_ID
+            | ErrorCode::UNKNOWN_TABLE
+            | ErrorCode::UNKNOWN_TABLE_ID
+            | ErrorCode::UNKNOWN_VIEW
+            | ErrorCode::UNKNOWN_COLUMN
+            | ErrorCode::VIRTUAL_COLUMN_NOT_FOUND
+            | ErrorCode::UNKNOWN_FUNCTION
+            | ErrorCode::UNKNOWN_AGGREGATE_FUNCTION
+            | ErrorCode::UNKNOWN_U_D_F
+            | ErrorCode::UNKNOWN_CATALOG
+            | ErrorCode::CATALOG_NOT_FOUND
+            | ErrorCode::UNKNOWN_DATAMASK
+            | ErrorCode::UNKNOWN_USER
+            | ErrorCode::UNKNOWN_ROLE
+            | ErrorCode::UNKNOWN_NETWORK_POLICY
+            | ErrorCode::UNKNOWN_PASSWORD_POLICY
+            | ErrorCode::UNKNOWN_STAGE
+            | ErrorCode::UNKNOWN_FILE_FORMAT
+            | ErrorCode::UNKNOWN_CONNECTION
+            | ErrorCode::UNKNOWN_PIPE
+            | ErrorCode::UNKNOWN_SHARE
+            | ErrorCode::UNKNOWN_SHARE_ID
+            | ErrorCode::UNKNOWN_SHARE_ACCOUNTS
+            | ErrorCode::UNKNOWN_SHARE_ENDPOINT
+            | ErrorCode::UNKNOWN_SHARE_ENDPOINT_ID
+            | ErrorCode::UNKNOWN_SHARE_TABLE
+            | ErrorCode::UNKNOWN_INDEX
+            | ErrorCode::INDEX_COLUMN_ID_NOT_FOUND
+            | ErrorCode::UNKNOWN_STREAM
+            | ErrorCode::UNKNOWN_STREAM_ID
+            | ErrorCode::UNKNOWN_VARIABLE
+            | ErrorCode::UNKNOWN_BACKGROUND_JOB
+            | ErrorCode::UNKNOWN_SESSION
+            | ErrorCode::UNKNOWN_DATABASE_ENGINE
+            | ErrorCode::UNKNOWN_TABLE_ENGINE
+            | ErrorCode::UNKNOWN_CATALOG_TYPE
+            | ErrorCode::UNKNOWN_FORMAT
+            | ErrorCode::UNKNOWN_COMPRESSION_TYPE
+            | ErrorCode::TENANT_QUOTA_UNKNOWN
+            | ErrorCode::TABLE_HISTORICAL_DATA_NOT_FOUND
+            | ErrorCode::UNDROP_TABLE_HAS_NO_HISTORY
+            | ErrorCode::UNDROP_DB_HAS_NO_HISTORY
+            | ErrorCode::NOT_FOUND_CLUSTER_NODE
+            | ErrorCode::CLUSTER_UNKNOWN_NODE
+            | ErrorCode::HTTP_NOT_FOUND
+            | ErrorCode::STORAGE_NOT_FOUND => ErrorCategory::NotFound,
+
+            ErrorCode::DATABASE_ALREADY_EXISTS
+            | ErrorCode::TABLE_ALREADY_EXISTS
+            | ErrorCode::VIEW_ALREADY_EXISTS
+            | ErrorCode::UNDROP_TABLE_ALREADY_EXISTS
+            | ErrorCode::ADD_COLUMN_EXIST_ERROR
+            | ErrorCode::VIRTUAL_COLUMN_ALREADY_EXISTS
+            | ErrorCode::CATALOG_ALREADY_EXISTS
+            | ErrorCode::DATAMASK_ALREADY_EXISTS
+            | ErrorCode::USER_ALREADY_EXISTS
+            | ErrorCode::ROLE_ALREADY_EXISTS
+            | ErrorCode::NETWORK_POLICY_ALREADY_EXISTS
+            | ErrorCode::PASSWORD_POLICY_ALREADY_EXISTS
+            | ErrorCode::CLUSTER_NODE_ALREADY_EXISTS
+            | ErrorCode::STAGE_ALREADY_EXISTS
+            | ErrorCode::STAGE_FILE_ALREADY_EXISTS
+            | ErrorCode::FILE_FORMAT_ALREADY_EXISTS
+            | ErrorCode::CONNECTION_ALREADY_EXISTS
+            | ErrorCode::PIPE_ALREADY_EXISTS
+            | ErrorCode::UDF_ALREADY_EXISTS
+            | ErrorCode::SHARE_ALREADY_EXISTS
+            | ErrorCode::SHARE_ACCOUNTS_ALREADY_EXISTS
+            | ErrorCode::SHARE_ENDPOINT_ALREADY_EXISTS
+            | ErrorCode::INDEX_ALREADY_EXISTS
+            | ErrorCode::DUPLICATED_INDEX_COLUMN_ID
+            | ErrorCode::STREAM_ALREADY_EXISTS
+            | ErrorCode::BACKGROUND_JOB_ALREADY_EXISTS => ErrorCategory::AlreadyExists,
+
+            ErrorCode::PERMISSION_DENIED
+            | ErrorCode::MANAGEMENT_MODE_PERMISSION_DENIED
+            | ErrorCode::STAGE_PERMISSION_DENIED
+            | ErrorCode::STORAGE_PERMISSION_DENIED
+            | ErrorCode::SHARE_HAS_NO_GRANTED_PRIVILEGE
+            | ErrorCode::LICENCE_DENIED
+            | ErrorCode::LICENSE_KEY_INVALID
+            | ErrorCode::LICENSE_KEY_PARSE_ERROR
+            | ErrorCode::TABLE_NOT_WRITABLE => ErrorCategory::PermissionDenied,
+
+            ErrorCode::AUTHENTICATE_FAILURE | ErrorCode::INVALID_AUTH_INFO => {
+                ErrorCategory::Authentication
+            }
+
+            ErrorCode::BAD_ARGUMENTS
+            | ErrorCode::INVALID_ARGUMENT
+            | ErrorCode::INVALID_TIMEZONE
+            | ErrorCode::TENANT_IS_EMPTY
+            | ErrorCode::BAD_ADDRESS_FORMAT
+            | ErrorCode::ILLEGAL_GRANT
+            | ErrorCode::ILLEGAL_USER_INFO_FORMAT
+            | ErrorCode::INVALID_ROLE
+            | ErrorCode::ILLEGAL_ROLE
+            | ErrorCode::ILLEGAL_USER
+            | ErrorCode::INVALID_PASSWORD
+            | ErrorCode::ILLEGAL_NETWORK_POLICY
+            | ErrorCode::NETWORK_POLICY_IS_USED_BY_USER
+            | ErrorCode::ILLEGAL_PASSWORD_POLICY
+            | ErrorCode::PASSWORD_POLICY_IS_USED_BY_USER
+            | ErrorCode::ILLEGAL_USER_STAGE_FORMAT
+            | ErrorCode::ILLEGAL_STAGE_FILE_FORMAT
+            | ErrorCode::ILLEGAL_FILE_FORMAT
+            | ErrorCode::ILLEGAL_CONNECTION
+            | ErrorCode::ILLEGAL_U_D_F_FORMAT
+            | ErrorCode::ILLEGAL_STREAM
+            | ErrorCode::ILLEGAL_TENANT_QUOTA_FORMAT
+            | ErrorCode::UNSUPPORTED_ENGINE_PARAMS
+            | ErrorCode::WRONG_SHARE_OBJECT
+            | ErrorCode::WRONG_SHARE
+            | ErrorCode::SHARE_HAS_NO_GRANTED_DATABASE
+            | ErrorCode::WRONG_VALUE_FOR_VARIABLE
+            | ErrorCode::ONLY_SUPPORT_ASCII_CHARS
+            | ErrorCode::TABLE_OPTION_INVALID
+            | ErrorCode::INDEX_OPTION_INVALID
+            | ErrorCode::UNDROP_TABLE_WITH_NO_DROP_TIME
+            | ErrorCode::UNDROP_DB_WITH_NO_DROP_TIME => ErrorCategory::InvalidArgument,
+
+            ErrorCode::BAD_DATA_VALUE_TYPE
+            | ErrorCode::DATA_STRUCT_MISS_MATCH
+            | ErrorCode::BAD_DATA_ARRAY_LENGTH
+            | ErrorCode::BAD_BYTES
+            | ErrorCode::OVERFLOW
+            | ErrorCode::INDEX_OUT_OF_BOUNDS
+            | ErrorCode::STR_PARSE_ERROR
+            | ErrorCode::INVALID_DATE
+            | ErrorCode::INVALID_TIMESTAMP
+            | ErrorCode::INVALID_UTF8_STRING
+            | ErrorCode::INVALID_COMPRESSION_DATA
+            | ErrorCode::PARQUET_FILE_INVALID
+            | ErrorCode::GEOMETRY_ERROR
+            | ErrorCode::U_D_F_DATA_ERROR
+            | ErrorCode::U_D_F_SCHEMA_MISMATCH => ErrorCategory::DataException,
+
+            ErrorCode::PRIMARY_KEY_VIOLATION => ErrorCategory::ConstraintViolation,
+
+            ErrorCode::TABLE_VERSION_MISMATCHED
+            | ErrorCode::O_C_C_RETRY_FAILURE
+            | ErrorCode::TXN_RETRY_MAX_TIMES
+            | ErrorCode::TABLE_ALREADY_LOCKED
+            | ErrorCode::TABLE_LOCK_EXPIRED
+            | ErrorCode::STREAM_VERSION_MISMATCHED
+            | ErrorCode::UNRESOLVABLE_CONFLICT
+            | ErrorCode::OUTDATED => ErrorCategory::Conflict,
+
+            ErrorCode::CURRENT_TRANSACTION_IS_ABORTED | ErrorCode::INVALID_SESSION_STATE => {
+                ErrorCategory::Transaction
+            }
+
+            ErrorCode::TIMEOUT | ErrorCode::TRANSACTION_TIMEOUT => ErrorCategory::Timeout,
+
+            ErrorCode::ABORTED_QUERY
+            | ErrorCode::ABORTED_SESSION
+            | ErrorCode::CLOSED_QUERY
+            | ErrorCode::ALREADY_STOPPED => ErrorCategory::Cancelled,
+
+            ErrorCode::TOO_MANY_USER_CONNECTIONS | ErrorCode::TENANT_QUOTA_EXCEEDED => {
+                ErrorCategory::ResourceExhausted
+            }
+
+            ErrorCode::CANNOT_CONNECT_NODE
+            | ErrorCode::SERVER_SHUTTING_DOWN
+            | ErrorCode::DNS_PARSE_ERROR
+            | ErrorCode::EMPTY_DATA_FROM_SERVER
+            | ErrorCode::META_SERVICE_ERROR
+            | ErrorCode::STORAGE_UNAVAILABLE
+            | ErrorCode::CLOUD_CONTROL_CONNECT_ERROR
+            | ErrorCode::U_D_F_SERVER_CONNECT_ERROR => ErrorCategory::Unavailable,
+
+            ErrorCode::UNIMPLEMENTED
+            | ErrorCode::TABLE_ENGINE_NOT_SUPPORTED
+            | ErrorCode::CATALOG_NOT_SUPPORTED
+            | ErrorCode::UNSUPPORTED_INDEX
+            | ErrorCode::UNSUPPORTED_DATA_TYPE
+            | ErrorCode::STORAGE_UNSUPPORTED
+            | ErrorCode::STORAGE_INSECURE
+            | ErrorCode::DEPRECATED_INDEX_FORMAT
+            | ErrorCode::CLOUD_CONTROL_NOT_ENABLED
+            | ErrorCode::CANNOT_SHARE_DATABASE_CREATED_FROM_SHARE => ErrorCategory::Unsupported,
+
+            _ => ErrorCategory::Internal,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Ok => "ok",
+            ErrorCategory::Syntax => "syntax",
+            ErrorCategory::Semantic => "semantic",
+            ErrorCategory::NotFound => "not_found",
+            ErrorCategory::AlreadyExists => "already_exists",
+            ErrorCategory::PermissionDenied => "permission_denied",
+            ErrorCategory::Authentication => "authentication",
+            ErrorCategory::InvalidArgument => "invalid_argument",
+            ErrorCategory::DataException => "data_exception",
+            ErrorCategory::ConstraintViolation => "constraint_violation",
+            ErrorCategory::Conflict => "conflict",
+            ErrorCategory::Transaction => "transaction",
+            ErrorCategory::Timeout => "timeout",
+            ErrorCategory::Cancelled => "cancelled",
+            ErrorCategory::ResourceExhausted => "resource_exhausted",
+            ErrorCategory::Unavailable => "unavailable",
+            ErrorCategory::Unsupported => "unsupported",
+            ErrorCategory::Internal => "internal",
+        }
+    }
+
+    /// The SQLSTATE of the category, an error may use a more specific SQLSTATE in the same class.
+    pub fn sqlstate(&self) -> &'static str {
+        match self {
+            ErrorCategory::Ok => "00000",
+            ErrorCategory::Syntax => "42601",
+            ErrorCategory::Semantic => "42000",
+            ErrorCategory::NotFound => "42704",
+            ErrorCategory::AlreadyExists => "42710",
+            ErrorCategory::PermissionDenied => "42501",
+            ErrorCategory::Authentication => "28000",
+            ErrorCategory::InvalidArgument => "22023",
+            ErrorCategory::DataException => "22000",
+            ErrorCategory::ConstraintViolation => "23000",
+            ErrorCategory::Conflict => "40001",
+            ErrorCategory::Transaction => "25000",
+            ErrorCategory::Timeout => "57014",
+            ErrorCategory::Cancelled => "57014",
+            ErrorCategory::ResourceExhausted => "53000",
+            ErrorCategory::Unavailable => "08006",
+            ErrorCategory::Unsupported => "0A000",
+            ErrorCategory::Internal => "XX000",
+        }
+    }
+
+    /// Whether the statement failed with this category may succeed if it's retried as is.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ErrorCategory::Conflict | ErrorCategory::Unavailable)
+    }
+}
+
+impl Display for ErrorCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl ErrorCode {
+    pub fn category(&self) -> ErrorCategory {
+        ErrorCategory::from_code(self.code())
+    }
+
+    /// The SQLSTATE of the error, see <https://www.postgresql.org/docs/current/errcodes-appendix.html>.
+    pub fn sqlstate(&self) -> &'static str {
+        match self.code() {
+            ErrorCode::UNKNOWN_DATABASE | ErrorCode::UNKNOWN_DATABASE_ID => "3D000",
+            ErrorCode::UNKNOWN_TABLE | ErrorCode::UNKNOWN_TABLE_ID | ErrorCode::UNKNOWN_VIEW => {
+                "42P01"
+            }
+            ErrorCode::UNKNOWN_COLUMN | ErrorCode::VIRTUAL_COLUMN_NOT_FOUND => "42703",
+            ErrorCode::UNKNOWN_FUNCTION
+            | ErrorCode::UNKNOWN_AGGREGATE_FUNCTION
+            | ErrorCode::UNKNOWN_U_D_F
+            | ErrorCode::NUMBER_ARGUMENTS_NOT_MATCH => "42883",
+            ErrorCode::ILLEGAL_DATA_TYPE
+            | ErrorCode::UNMATCH_COLUMN_DATA_TYPE
+            | ErrorCode::UNMATCH_MASK_POLICY_RETURN_TYPE => "42804",
+            ErrorCode::STORAGE_NOT_FOUND => "58P01",
+            ErrorCode::DATABASE_ALREADY_EXISTS => "42P04",
+            ErrorCode::TABLE_ALREADY_EXISTS
+            | ErrorCode::VIEW_ALREADY_EXISTS
+            | ErrorCode::UNDROP_TABLE_ALREADY_EXISTS
+            | ErrorCode::INDEX_ALREADY_EXISTS => "42P07",
+            ErrorCode::ADD_COLUMN_EXIST_ERROR | ErrorCode::VIRTUAL_COLUMN_ALREADY_EXISTS => "42701",
+            ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+            ErrorCode::OVERFLOW => "22003",
+            ErrorCode::INDEX_OUT_OF_BOUNDS => "2202E",
+            ErrorCode::STR_PARSE_ERROR => "22P02",
+            ErrorCode::INVALID_DATE | ErrorCode::INVALID_TIMESTAMP => "22007",
+            ErrorCode::INVALID_UTF8_STRING => "22021",
+            ErrorCode::PRIMARY_KEY_VIOLATION => "23505",
+            ErrorCode::TABLE_ALREADY_LOCKED => "55P03",
+            ErrorCode::CURRENT_TRANSACTION_IS_ABORTED => "25P02",
+            ErrorCode::TOO_MANY_USER_CONNECTIONS => "53300",
+            _ => self.category().sqlstate(),
+        }
+    }
+}
@@ -97,6 +97,7 @@ build_exceptions! {
     BadAddressFormat(1036),
     DnsParseError(1037),
     CannotConnectNode(1038),
+    ServerShuttingDown(1039),
     TooManyUserConnections(1041),
     AbortedSession(1042),
     AbortedQuery(1043),
@@ -152,6 +153,9 @@ build_exceptions! {
     UnmatchMaskPolicyReturnType(1121),
     Timeout(1122),
     Outdated(1123),
+    // The build side of a broadcast join exceeds `broadcast_join_max_build_bytes`,
+    // the query will be re-planned with shuffle join.
+    BroadcastJoinBuildTooLarge(1124),
 
     // Data Related Errors
 
@@ -175,6 +179,11 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// PrimaryKeyViolation is used when the inserted rows conflict with the
+    /// enforced primary key of the table.
+    ///
+    /// For example: insert a row whose primary key already exists.
+    PrimaryKeyViolation(1304),
 
     // License related errors starts here
 
@@ -300,6 +309,14 @@ build_exceptions! {
     IllegalConnection(2511),
     ConnectionAlreadyExists(2512),
 
+    // Pipe error codes.
+    UnknownPipe(2513),
+    PipeAlreadyExists(2514),
+
+    // Stored procedure error codes.
+    UnknownProcedure(2515),
+    ProcedureAlreadyExists(2516),
+
     // User defined function error codes.
     IllegalUDFFormat(2601),
     UnknownUDF(2602),
@@ -356,10 +373,6 @@ build_exceptions! {
     IllegalTenantQuotaFormat(2901),
     TenantQuotaUnknown(2902),
     TenantQuotaExceeded(2903),
-
-    // Script error codes.
-    ScriptSemanticError(3001),
-    ScriptExecutionError(3002),
 }
 
 // Storage errors [3001, 4000].
@@ -384,4 +397,8 @@ build_exceptions! {
 build_exceptions! {
     // A task that already stopped and can not stopped twice.
     AlreadyStopped(5002),
+
+    // Script error codes.
+    ScriptSemanticError(5101),
+    ScriptExecutionError(5102),
 }
@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 pub mod exception;
 mod exception_backtrace;
+mod exception_category;
 mod exception_code;
 mod exception_flight;
 mod exception_into;
@@ -24,6 +25,7 @@ mod with_context;
 pub use exception::ErrorCode;
 pub use exception::Result;
 pub use exception::ToErrorCode;
+pub use exception_category::ErrorCategory;
 pub use exception_into::SerializedError;
 pub use span::merge_span;
 pub use span::offset_span;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::ErrorCategory;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::SerializedError;
 use tonic::Code;
@@ -49,6 +50,50 @@ fn test_error_code() {
     assert_eq!(err.code(), ErrorCode::UNKNOWN_EXCEPTION);
 }
 
+#[test]
+fn test_error_category_and_sqlstate() {
+    let err = ErrorCode::SyntaxException("test message");
+    assert_eq!(err.category(), ErrorCategory::Syntax);
+    assert_eq!(err.sqlstate(), "42601");
+
+    let err = ErrorCode::UnknownTable("test message");
+    assert_eq!(err.category(), ErrorCategory::NotFound);
+    assert_eq!(err.sqlstate(), "42P01");
+
+    let err = ErrorCode::UnknownStage("test message");
+    assert_eq!(err.category(), ErrorCategory::NotFound);
+    assert_eq!(err.sqlstate(), "42704");
+
+    let err = ErrorCode::PermissionDenied("test message");
+    assert_eq!(err.category(), ErrorCategory::PermissionDenied);
+    assert_eq!(err.sqlstate(), "42501");
+
+    let err = ErrorCode::TableVersionMismatched("test message");
+    assert_eq!(err.category(), ErrorCategory::Conflict);
+    assert_eq!(err.sqlstate(), "40001");
+    assert!(err.category().is_retryable());
+
+    let err = ErrorCode::AbortedQuery("test message");
+    assert_eq!(err.category(), ErrorCategory::Cancelled);
+    assert_eq!(err.sqlstate(), "57014");
+    assert!(!err.category().is_retryable());
+
+    let err = ErrorCode::Internal("test message");
+    assert_eq!(err.category(), ErrorCategory::Internal);
+    assert_eq!(err.sqlstate(), "XX000");
+    assert_eq!(err.category().to_string(), "internal");
+
+    // The script errors used to share the codes with the storage errors.
+    assert_ne!(
+        ErrorCode::SCRIPT_SEMANTIC_ERROR,
+        ErrorCode::STORAGE_NOT_FOUND
+    );
+    assert_ne!(
+        ErrorCode::SCRIPT_EXECUTION_ERROR,
+        ErrorCode::STORAGE_PERMISSION_DENIED
+    );
+}
+
 #[test]
 fn test_derive_from_std_error() {
     use databend_common_exception::exception::ErrorCode;
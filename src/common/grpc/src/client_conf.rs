@@ -18,12 +18,20 @@ use std::time::Duration;
 pub struct RpcClientTlsConfig {
     pub rpc_tls_server_root_ca_cert: String,
     pub domain_name: String,
+    /// The certificate and the key presented to the servers requiring mutual TLS,
+    /// no client certificate is presented if any of them is empty.
+    pub client_cert: String,
+    pub client_key: String,
 }
 
 impl RpcClientTlsConfig {
     pub fn enabled(&self) -> bool {
         !self.rpc_tls_server_root_ca_cert.is_empty() && !self.domain_name.is_empty()
     }
+
+    pub fn client_auth_enabled(&self) -> bool {
+        !self.client_cert.is_empty() && !self.client_key.is_empty()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -39,6 +39,7 @@ use tonic::transport::Certificate;
 use tonic::transport::Channel;
 use tonic::transport::ClientTlsConfig;
 use tonic::transport::Endpoint;
+use tonic::transport::Identity;
 
 use crate::RpcClientTlsConfig;
 
@@ -213,9 +214,14 @@ impl ConnectionFactory {
         let server_root_ca_cert = std::fs::read(conf.rpc_tls_server_root_ca_cert.as_str())?;
         let server_root_ca_cert = Certificate::from_pem(server_root_ca_cert);
 
-        let tls = ClientTlsConfig::new()
+        let mut tls = ClientTlsConfig::new()
             .domain_name(conf.domain_name.to_string())
             .ca_certificate(server_root_ca_cert);
+        if conf.client_auth_enabled() {
+            let cert = std::fs::read(conf.client_cert.as_str())?;
+            let key = std::fs::read(conf.client_key.as_str())?;
+            tls = tls.identity(Identity::from_pem(cert, key));
+        }
         Ok(tls)
     }
 }
@@ -272,6 +272,46 @@ where
             Err(e) => Err(&mut *e.get_mut_ptr()),
         }
     }
+
+    /// Remove the entry of `key` and return its value, the slot is reusable at once.
+    ///
+    /// The key bytes of long keys stay in the arena until the hashtable is cleared.
+    #[inline(always)]
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let key = key.as_bytes();
+        let removed = match key.len() {
+            _ if key.last().copied() == Some(0) => unsafe {
+                self.table4.remove(&FallbackKey::new(key))
+            },
+            0 => return self.table0.remove(),
+            1..=8 => unsafe {
+                let mut t = [0u64; 1];
+                t[0] = read_le(key.as_ptr(), key.len());
+                let t = std::mem::transmute::<_, InlineKey<0>>(t);
+                self.table1.remove(&t)
+            },
+            9..=16 => unsafe {
+                let mut t = [0u64; 2];
+                t[0] = (key.as_ptr() as *const u64).read_unaligned();
+                t[1] = read_le(key.as_ptr().offset(8), key.len() - 8);
+                let t = std::mem::transmute::<_, InlineKey<1>>(t);
+                self.table2.remove(&t)
+            },
+            17..=24 => unsafe {
+                let mut t = [0u64; 3];
+                t[0] = (key.as_ptr() as *const u64).read_unaligned();
+                t[1] = (key.as_ptr() as *const u64).offset(1).read_unaligned();
+                t[2] = read_le(key.as_ptr().offset(16), key.len() - 16);
+                let t = std::mem::transmute::<_, InlineKey<2>>(t);
+                self.table3.remove(&t)
+            },
+            _ => unsafe { self.table4.remove(&FallbackKey::new(key)) },
+        };
+        if removed.is_some() {
+            self.key_size -= key.len();
+        }
+        removed
+    }
 }
 
 pub struct ShortStringHashtableIter<'a, K, V>
@@ -222,6 +222,43 @@ where
         }
         panic!("the hash table overflows")
     }
+    /// # Safety
+    ///
+    /// `key` doesn't equal to zero.
+    ///
+    /// The freed slot is filled by shifting back the following entries of the probe
+    /// sequence, so no tombstone is left behind and the slot can be reused at once.
+    pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+        if self.len == 0 {
+            return None;
+        }
+        let mut hole = self.get_slot_index(key)?;
+        let val = self.entries[hole].val.assume_init_read();
+        let mask = self.entries.len() - 1;
+        let mut i = hole;
+        loop {
+            i = (i + 1) & mask;
+            assume(i < self.entries.len());
+            if self.entries[i].is_zero() {
+                break;
+            }
+            let index = (self.entries[i].key.assume_init_ref().hash() as usize) & mask;
+            // the entry stays if its home slot is cyclically in (hole, i].
+            let stays = if hole < i {
+                hole < index && index <= i
+            } else {
+                hole < index || index <= i
+            };
+            if !stays {
+                self.entries[hole] = std::ptr::read(&self.entries[i]);
+                hole = i;
+            }
+        }
+        self.entries[hole].key = MaybeUninit::zeroed();
+        self.len -= 1;
+        Some(val)
+    }
+
     pub fn iter(&self) -> Table0Iter<'_, K, V> {
         Table0Iter {
             slice: self.entries.as_ref(),
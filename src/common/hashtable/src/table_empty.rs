@@ -79,6 +79,15 @@ impl<V, A: Allocator + Clone> TableEmpty<V, A> {
         }
     }
 
+    pub fn remove(&mut self) -> Option<V> {
+        if self.has_zero {
+            self.has_zero = false;
+            Some(unsafe { self.slice[0].val.assume_init_read() })
+        } else {
+            None
+        }
+    }
+
     pub fn iter(&self) -> TableEmptyIter<'_, V> {
         TableEmptyIter {
             slice: self.slice.as_ref(),
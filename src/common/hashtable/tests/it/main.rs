@@ -142,6 +142,73 @@ fn test_unsized_hash_map() {
     assert_eq!(COUNT.load(Ordering::Relaxed), 0);
 }
 
+#[test]
+fn test_unsized_hash_map_remove() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    #[derive(Debug)]
+    struct U64(u64);
+    impl U64 {
+        fn new(x: u64) -> Self {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            Self(x)
+        }
+    }
+    impl Drop for U64 {
+        fn drop(&mut self) {
+            COUNT.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+    let mut sequence = vec![vec![], vec![1u8, 0], vec![7u8; 30]];
+    for _ in 0..1000 {
+        let length = rand::thread_rng().gen_range(0..64);
+        let mut array = vec![0u8; length];
+        rand::thread_rng().fill(&mut array[..]);
+        sequence.push(array);
+    }
+    let mut standard = std::collections::HashMap::<&[u8], u64>::new();
+    let mut hashtable = ShortStringHashMap::<[u8], U64>::new(Arc::new(Bump::new()));
+    for (i, s) in sequence.iter().enumerate() {
+        standard.insert(s, i as u64);
+        match unsafe { hashtable.insert_and_entry(s) } {
+            Ok(mut e) => {
+                e.write(U64::new(i as u64));
+            }
+            Err(mut e) => {
+                e.get_mut().0 = i as u64;
+            }
+        }
+    }
+
+    // remove every other key, then insert them back.
+    for (i, s) in sequence.iter().enumerate().step_by(2) {
+        let expected = standard.remove(&s[..]);
+        assert_eq!(expected, hashtable.remove(s).map(|v| v.0), "key {i}");
+        assert!(hashtable.entry(s).is_none());
+        assert!(hashtable.remove(s).is_none());
+    }
+    assert_eq!(standard.len(), hashtable.len());
+    assert_eq!(standard.len(), COUNT.load(Ordering::Relaxed));
+    for e in hashtable.iter() {
+        assert_eq!(standard.get(e.key()).copied().unwrap(), e.get().0);
+    }
+    for (s, v) in standard.iter() {
+        assert_eq!(hashtable.entry(s).unwrap().get().0, *v);
+    }
+
+    for (i, s) in sequence.iter().enumerate().step_by(2) {
+        if let Ok(mut e) = unsafe { hashtable.insert_and_entry(s) } {
+            standard.insert(s, i as u64);
+            e.write(U64::new(i as u64));
+        }
+    }
+    assert_eq!(standard.len(), hashtable.len());
+    for (s, v) in standard.iter() {
+        assert_eq!(hashtable.entry(s).unwrap().get().0, *v);
+    }
+    drop(hashtable);
+    assert_eq!(COUNT.load(Ordering::Relaxed), 0);
+}
+
 #[test]
 fn test_dictionary_hash_map() {
     let mut hashtable = DictionaryStringHashMap::<usize>::new(Arc::new(Bump::new()), 2);
@@ -21,7 +21,9 @@ use databend_common_base::base::tokio::sync::broadcast;
 use databend_common_base::base::tokio::sync::oneshot;
 use databend_common_base::base::tokio::task::JoinHandle;
 use futures::future::Either;
+use futures::stream::BoxStream;
 use futures::FutureExt;
+use futures::StreamExt;
 use log::error;
 use log::info;
 use poem::listener::Acceptor;
@@ -55,6 +57,28 @@ impl HttpShutdownHandler {
         tls_config: Option<RustlsConfig>,
         ep: impl Endpoint + 'static,
         graceful_shutdown_timeout: Option<Duration>,
+    ) -> Result<SocketAddr, HttpError> {
+        let tls_config = match tls_config {
+            None => None,
+            Some(tls_config) => Some(
+                tls_config
+                    .into_stream()
+                    .map_err(|err| HttpError::TlsConfigError(AnyError::new(&err)))?
+                    .boxed(),
+            ),
+        };
+        self.start_service_with_tls_stream(listening, tls_config, ep, graceful_shutdown_timeout)
+            .await
+    }
+
+    /// Starts the service with the TLS configs yielded by the stream, each new config replaces
+    /// the previous one for the connections accepted afterwards.
+    pub async fn start_service_with_tls_stream(
+        &mut self,
+        listening: SocketAddr,
+        tls_config: Option<BoxStream<'static, RustlsConfig>>,
+        ep: impl Endpoint + 'static,
+        graceful_shutdown_timeout: Option<Duration>,
     ) -> Result<SocketAddr, HttpError> {
         assert!(self.join_handle.is_none());
         assert!(self.abort_handle.is_none());
@@ -71,11 +95,7 @@ impl HttpShutdownHandler {
             .and_then(|addr| addr.0.as_socket_addr().cloned())
             .expect("socket addr");
 
-        if let Some(tls_config) = tls_config {
-            let conf_stream = tls_config
-                .into_stream()
-                .map_err(|err| HttpError::TlsConfigError(AnyError::new(&err)))?;
-
+        if let Some(conf_stream) = tls_config {
             acceptor = acceptor.rustls(conf_stream).boxed();
         }
 
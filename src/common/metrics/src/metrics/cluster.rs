@@ -15,9 +15,13 @@
 use std::sync::LazyLock;
 
 use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::register_gauge;
 use databend_common_base::runtime::metrics::register_gauge_family;
+use databend_common_base::runtime::metrics::register_histogram_in_milliseconds;
 use databend_common_base::runtime::metrics::FamilyCounter;
 use databend_common_base::runtime::metrics::FamilyGauge;
+use databend_common_base::runtime::metrics::Gauge;
+use databend_common_base::runtime::metrics::Histogram;
 
 static CLUSTER_CLUSTER_HEARTBEAT_COUNT: LazyLock<FamilyCounter<Vec<(&'static str, String)>>> =
     LazyLock::new(|| register_counter_family("cluster_heartbeat_count"));
@@ -25,6 +29,10 @@ static CLUSTER_CLUSTER_ERROR_COUNT: LazyLock<FamilyCounter<Vec<(&'static str, St
     LazyLock::new(|| register_counter_family("cluster_error_count"));
 static CLUSTER_DISCOVERED_NODE_GAUGE: LazyLock<FamilyGauge<Vec<(&'static str, String)>>> =
     LazyLock::new(|| register_gauge_family("cluster_discovered_node"));
+static CLUSTER_EXCHANGE_BUFFERED_BYTES: LazyLock<Gauge> =
+    LazyLock::new(|| register_gauge("cluster_exchange_buffered_bytes"));
+static CLUSTER_EXCHANGE_BACKPRESSURE_WAIT_MS: LazyLock<Histogram> =
+    LazyLock::new(|| register_histogram_in_milliseconds("cluster_exchange_backpressure_wait_ms"));
 
 pub fn metric_incr_cluster_heartbeat_count(
     local_id: &str,
@@ -80,3 +88,15 @@ pub fn metrics_gauge_discovered_nodes(
         .get_or_create(labels)
         .set(val as i64);
 }
+
+pub fn metrics_inc_exchange_buffered_bytes(bytes: usize) {
+    CLUSTER_EXCHANGE_BUFFERED_BYTES.inc_by(bytes as i64);
+}
+
+pub fn metrics_dec_exchange_buffered_bytes(bytes: usize) {
+    CLUSTER_EXCHANGE_BUFFERED_BYTES.dec_by(bytes as i64);
+}
+
+pub fn metrics_observe_exchange_backpressure_wait_ms(milliseconds: f64) {
+    CLUSTER_EXCHANGE_BACKPRESSURE_WAIT_MS.observe(milliseconds);
+}
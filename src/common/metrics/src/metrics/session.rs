@@ -42,6 +42,17 @@ pub static SESSION_QUEUE_ACQUIRE_DURATION_MS: LazyLock<Histogram> =
 pub static SESSION_RUNNING_ACQUIRED_QUERIES: LazyLock<Gauge> =
     LazyLock::new(|| register_gauge("session_running_acquired_queries"));
 
+pub static SESSION_REJECTED_BY_USER_LIMIT_COUNT: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_rejected_by_user_limit_count"));
+pub static SESSION_REJECTED_BY_TENANT_LIMIT_COUNT: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_rejected_by_tenant_limit_count"));
+pub static SESSION_AUTH_FAILURE_COUNT: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_auth_failure_count"));
+pub static SESSION_AUTH_LOCKOUT_COUNT: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_auth_lockout_count"));
+pub static SESSION_AUTH_REJECTED_BY_LOCKOUT_COUNT: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("session_auth_rejected_by_lockout_count"));
+
 pub fn incr_session_connect_numbers() {
     SESSION_CONNECT_NUMBERS.inc();
 }
@@ -81,3 +92,23 @@ pub fn inc_session_running_acquired_queries() {
 pub fn dec_session_running_acquired_queries() {
     SESSION_RUNNING_ACQUIRED_QUERIES.dec();
 }
+
+pub fn incr_session_rejected_by_user_limit_count() {
+    SESSION_REJECTED_BY_USER_LIMIT_COUNT.inc();
+}
+
+pub fn incr_session_rejected_by_tenant_limit_count() {
+    SESSION_REJECTED_BY_TENANT_LIMIT_COUNT.inc();
+}
+
+pub fn incr_session_auth_failure_count() {
+    SESSION_AUTH_FAILURE_COUNT.inc();
+}
+
+pub fn incr_session_auth_lockout_count() {
+    SESSION_AUTH_LOCKOUT_COUNT.inc();
+}
+
+pub fn incr_session_auth_rejected_by_lockout_count() {
+    SESSION_AUTH_REJECTED_BY_LOCKOUT_COUNT.inc();
+}
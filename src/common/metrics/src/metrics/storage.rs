@@ -202,6 +202,8 @@ static BYTES_BLOCK_INVERTED_INDEX_PRUNING_AFTER: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_bytes_block_inverted_index_pruning_after"));
 static PRUNING_PREWHERE_NUMS: LazyLock<Counter> =
     LazyLock::new(|| register_counter("fuse_pruning_prewhere_nums"));
+static PRUNING_TOPK_BLOCKS: LazyLock<Counter> =
+    LazyLock::new(|| register_counter("fuse_pruning_topk_blocks"));
 static PRUNING_MILLISECONDS: LazyLock<Histogram> =
     LazyLock::new(|| register_histogram_in_milliseconds("fuse_pruning_milliseconds"));
 static DELETION_BLOCK_RANGE_PRUNED_NUMS: LazyLock<Counter> =
@@ -590,6 +592,10 @@ pub fn metrics_inc_pruning_prewhere_nums(c: u64) {
     PRUNING_PREWHERE_NUMS.inc_by(c);
 }
 
+pub fn metrics_inc_pruning_topk_blocks(c: u64) {
+    PRUNING_TOPK_BLOCKS.inc_by(c);
+}
+
 pub fn metrics_inc_pruning_milliseconds(c: u64) {
     PRUNING_MILLISECONDS.observe(c as f64);
 }
@@ -44,6 +44,65 @@ pub struct StorageConfig {
     pub num_cpus: u64,
     pub allow_insecure: bool,
     pub params: StorageParams,
+    pub io: StorageIoConfig,
+}
+
+/// Retry and timeout policy of the requests to the storage backend.
+///
+/// Reads, writes and lists are configured separately, because the writes and lists
+/// on some object storages are much slower than the reads. A timeout of `0` means
+/// no timeout.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageIoConfig {
+    pub read: StorageIoClassConfig,
+    pub write: StorageIoClassConfig,
+    pub list: StorageIoClassConfig,
+    /// Timeout of each IO call of the readers and writers, such as reading a chunk.
+    pub io_timeout_secs: u64,
+    /// The delay before the first retry.
+    pub retry_min_delay_ms: u64,
+    /// The max delay between retries.
+    pub retry_max_delay_ms: u64,
+    /// The delay is multiplied by the factor after each retry.
+    pub retry_factor: u32,
+    /// The max concurrent requests of all the operators on this node, 0 means no limit.
+    pub max_concurrent_requests: u64,
+    /// The percent of `max_concurrent_requests` that background jobs can use, the rest
+    /// is reserved for the interactive queries.
+    pub background_requests_percent: u64,
+}
+
+impl Default for StorageIoConfig {
+    fn default() -> Self {
+        StorageIoConfig {
+            read: StorageIoClassConfig::default(),
+            write: StorageIoClassConfig::default(),
+            list: StorageIoClassConfig::default(),
+            io_timeout_secs: 10,
+            retry_min_delay_ms: 1000,
+            retry_max_delay_ms: 60000,
+            retry_factor: 2,
+            max_concurrent_requests: 0,
+            background_requests_percent: 50,
+        }
+    }
+}
+
+/// Retry and timeout of one class of requests.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageIoClassConfig {
+    pub max_retries: usize,
+    /// Timeout of each attempt of the request.
+    pub timeout_secs: u64,
+}
+
+impl Default for StorageIoClassConfig {
+    fn default() -> Self {
+        StorageIoClassConfig {
+            max_retries: 3,
+            timeout_secs: 10,
+        }
+    }
 }
 
 // TODO: This config should be moved out of common-storage crate.
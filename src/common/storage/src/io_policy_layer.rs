@@ -0,0 +1,263 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::RwLock;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::FamilyCounter;
+use log::warn;
+use opendal::layers::RetryInterceptor;
+use opendal::layers::RetryLayer;
+use opendal::layers::TimeoutLayer;
+use opendal::raw::oio;
+use opendal::raw::Accessor;
+use opendal::raw::FusedAccessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpBatch;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpWrite;
+use opendal::raw::RpBatch;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpWrite;
+use opendal::Error;
+use opendal::Operator;
+use opendal::Result;
+
+use crate::io_scheduler::set_io_scheduler;
+use crate::StorageIoClassConfig;
+use crate::StorageIoConfig;
+
+type RetryLabels = [(&'static str, &'static str); 2];
+
+/// The IO policy used by the operators built after it's set.
+static STORAGE_IO_CONFIG: LazyLock<RwLock<StorageIoConfig>> =
+    LazyLock::new(|| RwLock::new(StorageIoConfig::default()));
+
+static IO_RETRY_METRICS: LazyLock<IoRetryMetrics> = LazyLock::new(|| IoRetryMetrics {
+    retries_total: register_counter_family("opendal_retries"),
+    timeouts_total: register_counter_family("opendal_timeouts"),
+});
+
+/// Sets the IO policy of the storage operators, the operators built before are not affected.
+pub fn set_storage_io_config(config: &StorageIoConfig) {
+    *STORAGE_IO_CONFIG.write().unwrap() = config.clone();
+    set_io_scheduler(config);
+}
+
+pub(crate) fn storage_io_config() -> StorageIoConfig {
+    STORAGE_IO_CONFIG.read().unwrap().clone()
+}
+
+struct IoRetryMetrics {
+    /// Total counter of the retried requests.
+    retries_total: FamilyCounter<RetryLabels>,
+    /// Total counter of the requests that timed out.
+    timeouts_total: FamilyCounter<RetryLabels>,
+}
+
+/// Records the retries and timeouts of one class of requests.
+struct IoRetryInterceptor {
+    scheme: &'static str,
+    class: &'static str,
+}
+
+impl RetryInterceptor for IoRetryInterceptor {
+    fn intercept(&self, err: &Error, dur: Duration) {
+        let labels = [("scheme", self.scheme), ("op", self.class)];
+        IO_RETRY_METRICS.retries_total.get_or_create(&labels).inc();
+        // TimeoutLayer returns an unexpected error, the message is the only way to tell.
+        if err.to_string().contains("timeout") {
+            IO_RETRY_METRICS.timeouts_total.get_or_create(&labels).inc();
+        }
+
+        warn!(
+            "will retry {} request to {} after {}ms: {}",
+            self.class,
+            self.scheme,
+            dur.as_millis(),
+            err
+        );
+    }
+}
+
+/// Builds the operators for reads, writes and lists upon `op` with the timeout and retry
+/// of their class, and returns the operator that dispatches the requests to them.
+pub(crate) fn apply_io_policy(op: Operator, config: &StorageIoConfig) -> Operator {
+    let scheme = op.info().scheme().into_static();
+    let build = |class: &'static str, class_config: &StorageIoClassConfig| {
+        op.clone()
+            .layer(timeout_layer(
+                class_config.timeout_secs,
+                config.io_timeout_secs,
+            ))
+            .layer(
+                RetryLayer::new()
+                    .with_max_times(class_config.max_retries)
+                    .with_min_delay(Duration::from_millis(config.retry_min_delay_ms))
+                    .with_max_delay(Duration::from_millis(config.retry_max_delay_ms))
+                    .with_factor(config.retry_factor as f32)
+                    .with_jitter()
+                    .with_notify(IoRetryInterceptor { scheme, class }),
+            )
+    };
+
+    let read = build("read", &config.read);
+    let write = build("write", &config.write);
+    let list = build("list", &config.list);
+    read.layer(IoPolicyLayer::new(write, list))
+}
+
+fn timeout_layer(timeout_secs: u64, io_timeout_secs: u64) -> TimeoutLayer {
+    let mut timeout_layer = TimeoutLayer::new();
+
+    if timeout_secs != 0 {
+        // Return timeout error if the operation timeout
+        timeout_layer = timeout_layer.with_timeout(Duration::from_secs(timeout_secs));
+    }
+
+    if io_timeout_secs != 0 {
+        // Return timeout error if the io operation timeout
+        timeout_layer = timeout_layer.with_io_timeout(Duration::from_secs(io_timeout_secs));
+    }
+
+    timeout_layer
+}
+
+/// IoPolicyLayer sends the writes and lists to the operators with their own retry and
+/// timeout policy, the other requests are sent to the inner operator for reads.
+#[derive(Clone)]
+pub struct IoPolicyLayer {
+    write: FusedAccessor,
+    list: FusedAccessor,
+}
+
+impl IoPolicyLayer {
+    pub fn new(write: Operator, list: Operator) -> Self {
+        IoPolicyLayer {
+            write: write.into_inner(),
+            list: list.into_inner(),
+        }
+    }
+}
+
+impl Debug for IoPolicyLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IoPolicyLayer")
+    }
+}
+
+impl<A: Accessor> Layer<A> for IoPolicyLayer {
+    type LayeredAccessor = IoPolicyAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        IoPolicyAccessor {
+            inner: Arc::new(inner),
+            write: self.write.clone(),
+            list: self.list.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct IoPolicyAccessor<A> {
+    inner: Arc<A>,
+    write: FusedAccessor,
+    list: FusedAccessor,
+}
+
+impl<A: Accessor> Debug for IoPolicyAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IoPolicyAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for IoPolicyAccessor<A> {
+    type Inner = A;
+    type Reader = A::Reader;
+    type BlockingReader = A::BlockingReader;
+    type Writer = oio::Writer;
+    type BlockingWriter = oio::BlockingWriter;
+    type Lister = oio::Lister;
+    type BlockingLister = oio::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        self.inner.read(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.write.create_dir(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.write.write(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.write.delete(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.list.list(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        self.write.batch(args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        self.write.blocking_create_dir(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.write.blocking_write(path, args)
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        self.write.blocking_delete(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.list.blocking_list(path, args)
+    }
+}
@@ -0,0 +1,432 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::io;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::task::Context;
+use std::task::Poll;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use databend_common_base::base::tokio::sync::oneshot;
+use databend_common_base::runtime::metrics::register_counter_family;
+use databend_common_base::runtime::metrics::register_gauge_family;
+use databend_common_base::runtime::metrics::register_histogram_family_in_milliseconds;
+use databend_common_base::runtime::metrics::FamilyCounter;
+use databend_common_base::runtime::metrics::FamilyGauge;
+use databend_common_base::runtime::metrics::FamilyHistogram;
+use databend_common_base::runtime::IoPriority;
+use databend_common_base::runtime::ThreadTracker;
+use opendal::raw::oio;
+use opendal::raw::Accessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpBatch;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpBatch;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Result;
+
+use crate::StorageIoConfig;
+
+type PriorityLabels = [(&'static str, &'static str); 1];
+
+static IO_SCHEDULER_METRICS: LazyLock<IoSchedulerMetrics> = LazyLock::new(|| IoSchedulerMetrics {
+    running_requests: register_gauge_family("opendal_scheduler_running_requests"),
+    waiting_requests: register_gauge_family("opendal_scheduler_waiting_requests"),
+    scheduled_total: register_counter_family("opendal_scheduler_requests"),
+    wait_milliseconds: register_histogram_family_in_milliseconds(
+        "opendal_scheduler_wait_milliseconds",
+    ),
+});
+
+/// The scheduler shared by the operators built after the io config is set.
+static IO_SCHEDULER: LazyLock<Mutex<Option<Arc<IoScheduler>>>> = LazyLock::new(|| Mutex::new(None));
+
+struct IoSchedulerMetrics {
+    running_requests: FamilyGauge<PriorityLabels>,
+    waiting_requests: FamilyGauge<PriorityLabels>,
+    scheduled_total: FamilyCounter<PriorityLabels>,
+    wait_milliseconds: FamilyHistogram<PriorityLabels>,
+}
+
+fn priority_labels(priority: IoPriority) -> PriorityLabels {
+    [("priority", priority.as_str())]
+}
+
+pub(crate) fn set_io_scheduler(config: &StorageIoConfig) {
+    let scheduler = IoScheduler::try_create(config).map(Arc::new);
+    *IO_SCHEDULER.lock().unwrap() = scheduler;
+}
+
+pub(crate) fn io_scheduler() -> Option<Arc<IoScheduler>> {
+    IO_SCHEDULER.lock().unwrap().clone()
+}
+
+/// IoScheduler bounds the concurrent storage requests of the node.
+///
+/// The requests of background jobs can use at most `background_requests_percent` of the
+/// permits, and a released permit is handed to the waiting requests of high priority first,
+/// then the interactive ones, so that the background jobs can't starve the interactive queries.
+pub struct IoScheduler {
+    max_requests: usize,
+    max_background_requests: usize,
+    state: Mutex<SchedulerState>,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    running: usize,
+    running_background: usize,
+    waiting_high: VecDeque<oneshot::Sender<()>>,
+    waiting_interactive: VecDeque<oneshot::Sender<()>>,
+    waiting_background: VecDeque<oneshot::Sender<()>>,
+}
+
+impl IoScheduler {
+    /// Returns `None` if the concurrent requests are not limited.
+    pub fn try_create(config: &StorageIoConfig) -> Option<IoScheduler> {
+        if config.max_concurrent_requests == 0 {
+            return None;
+        }
+
+        let max_requests = config.max_concurrent_requests as usize;
+        let percent = config.background_requests_percent.min(100) as usize;
+        Some(IoScheduler {
+            max_requests,
+            max_background_requests: (max_requests * percent / 100).max(1),
+            state: Mutex::new(SchedulerState::default()),
+        })
+    }
+
+    /// Waits until the request of `priority` can be sent.
+    #[async_backtrace::framed]
+    pub async fn acquire(self: &Arc<Self>, priority: IoPriority) -> IoPermit {
+        let metrics = &IO_SCHEDULER_METRICS;
+        let labels = priority_labels(priority);
+        metrics.scheduled_total.get_or_create(&labels).inc();
+
+        let rx = {
+            let mut state = self.state.lock().unwrap();
+            if self.can_run(&state, priority) {
+                Self::start(&mut state, priority);
+                None
+            } else {
+                let (tx, rx) = oneshot::channel();
+                match priority {
+                    IoPriority::High => state.waiting_high.push_back(tx),
+                    IoPriority::Interactive => state.waiting_interactive.push_back(tx),
+                    IoPriority::Background => state.waiting_background.push_back(tx),
+                }
+                Some(rx)
+            }
+        };
+
+        if let Some(rx) = rx {
+            let start = Instant::now();
+            metrics.waiting_requests.get_or_create(&labels).inc();
+            let mut waiting = Waiting {
+                scheduler: self.clone(),
+                priority,
+                rx: Some(rx),
+            };
+            // The sender is only dropped after sending, so the permit is always granted.
+            let _ = waiting.rx.as_mut().unwrap().await;
+            waiting.rx = None;
+            metrics.waiting_requests.get_or_create(&labels).dec();
+            metrics
+                .wait_milliseconds
+                .get_or_create(&labels)
+                .observe(start.elapsed().as_millis() as f64);
+        }
+
+        metrics.running_requests.get_or_create(&labels).inc();
+        IoPermit {
+            scheduler: self.clone(),
+            priority,
+        }
+    }
+
+    fn can_run(&self, state: &SchedulerState, priority: IoPriority) -> bool {
+        // The waiting requests go first.
+        match priority {
+            IoPriority::High => state.running < self.max_requests && state.waiting_high.is_empty(),
+            IoPriority::Interactive => {
+                state.running < self.max_requests
+                    && state.waiting_high.is_empty()
+                    && state.waiting_interactive.is_empty()
+            }
+            IoPriority::Background => {
+                state.running < self.max_requests
+                    && state.running_background < self.max_background_requests
+                    && state.waiting_high.is_empty()
+                    && state.waiting_interactive.is_empty()
+                    && state.waiting_background.is_empty()
+            }
+        }
+    }
+
+    fn start(state: &mut SchedulerState, priority: IoPriority) {
+        state.running += 1;
+        if priority == IoPriority::Background {
+            state.running_background += 1;
+        }
+    }
+
+    fn release(&self, priority: IoPriority) {
+        let mut state = self.state.lock().unwrap();
+        state.running -= 1;
+        if priority == IoPriority::Background {
+            state.running_background -= 1;
+        }
+
+        // Hand the permits to the requests of high priority first, then the interactive ones.
+        while state.running < self.max_requests {
+            let (tx, priority) = if let Some(tx) = state.waiting_high.pop_front() {
+                (tx, IoPriority::High)
+            } else if let Some(tx) = state.waiting_interactive.pop_front() {
+                (tx, IoPriority::Interactive)
+            } else if state.running_background < self.max_background_requests {
+                match state.waiting_background.pop_front() {
+                    Some(tx) => (tx, IoPriority::Background),
+                    None => break,
+                }
+            } else {
+                break;
+            };
+
+            // The waiter may be cancelled, try the next one.
+            if tx.send(()).is_ok() {
+                Self::start(&mut state, priority);
+            }
+        }
+    }
+}
+
+/// Gives the permit back if the waiting request is cancelled after the permit is granted.
+struct Waiting {
+    scheduler: Arc<IoScheduler>,
+    priority: IoPriority,
+    rx: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Waiting {
+    fn drop(&mut self) {
+        if let Some(mut rx) = self.rx.take() {
+            IO_SCHEDULER_METRICS
+                .waiting_requests
+                .get_or_create(&priority_labels(self.priority))
+                .dec();
+            rx.close();
+            if rx.try_recv().is_ok() {
+                self.scheduler.release(self.priority);
+            }
+        }
+    }
+}
+
+pub struct IoPermit {
+    scheduler: Arc<IoScheduler>,
+    priority: IoPriority,
+}
+
+impl Drop for IoPermit {
+    fn drop(&mut self) {
+        IO_SCHEDULER_METRICS
+            .running_requests
+            .get_or_create(&priority_labels(self.priority))
+            .dec();
+        self.scheduler.release(self.priority);
+    }
+}
+
+/// IoSchedulerLayer sends the async requests through the [`IoScheduler`], with the priority
+/// of the current thread. The permit of a read or write is held until the reader or writer
+/// is dropped.
+///
+/// The blocking requests are not scheduled.
+#[derive(Clone)]
+pub struct IoSchedulerLayer {
+    scheduler: Arc<IoScheduler>,
+}
+
+impl IoSchedulerLayer {
+    pub fn new(scheduler: Arc<IoScheduler>) -> Self {
+        IoSchedulerLayer { scheduler }
+    }
+}
+
+impl Debug for IoSchedulerLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IoSchedulerLayer({})", self.scheduler.max_requests)
+    }
+}
+
+impl<A: Accessor> Layer<A> for IoSchedulerLayer {
+    type LayeredAccessor = IoSchedulerAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        IoSchedulerAccessor {
+            inner: Arc::new(inner),
+            scheduler: self.scheduler.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct IoSchedulerAccessor<A> {
+    inner: Arc<A>,
+    scheduler: Arc<IoScheduler>,
+}
+
+impl<A: Accessor> Debug for IoSchedulerAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IoSchedulerAccessor")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A> IoSchedulerAccessor<A> {
+    async fn acquire(&self) -> IoPermit {
+        self.scheduler.acquire(ThreadTracker::io_priority()).await
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for IoSchedulerAccessor<A> {
+    type Inner = A;
+    type Reader = IoPermitWrapper<A::Reader>;
+    type BlockingReader = A::BlockingReader;
+    type Writer = IoPermitWrapper<A::Writer>;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn create_dir(&self, path: &str, args: OpCreateDir) -> Result<RpCreateDir> {
+        let _permit = self.acquire().await;
+        self.inner.create_dir(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let permit = self.acquire().await;
+        let (rp, r) = self.inner.read(path, args).await?;
+        Ok((rp, IoPermitWrapper {
+            inner: r,
+            _permit: permit,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let permit = self.acquire().await;
+        let (rp, w) = self.inner.write(path, args).await?;
+        Ok((rp, IoPermitWrapper {
+            inner: w,
+            _permit: permit,
+        }))
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        let _permit = self.acquire().await;
+        self.inner.stat(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        let _permit = self.acquire().await;
+        self.inner.delete(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        let _permit = self.acquire().await;
+        self.inner.list(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let _permit = self.acquire().await;
+        self.inner.batch(args).await
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        self.inner.blocking_read(path, args)
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
+
+/// Holds the permit until the reader or writer is dropped.
+pub struct IoPermitWrapper<R> {
+    inner: R,
+    _permit: IoPermit,
+}
+
+impl<R: oio::Read> oio::Read for IoPermitWrapper<R> {
+    fn poll_read(&mut self, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<Result<usize>> {
+        self.inner.poll_read(cx, buf)
+    }
+
+    fn poll_seek(&mut self, cx: &mut Context<'_>, pos: io::SeekFrom) -> Poll<Result<u64>> {
+        self.inner.poll_seek(cx, pos)
+    }
+
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<Bytes>>> {
+        self.inner.poll_next(cx)
+    }
+}
+
+impl<R: oio::Write> oio::Write for IoPermitWrapper<R> {
+    fn poll_write(&mut self, cx: &mut Context<'_>, bs: &dyn oio::WriteBuf) -> Poll<Result<usize>> {
+        self.inner.poll_write(cx, bs)
+    }
+
+    fn poll_abort(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner.poll_abort(cx)
+    }
+
+    fn poll_close(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        self.inner.poll_close(cx)
+    }
+}
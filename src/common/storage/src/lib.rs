@@ -34,17 +34,34 @@
 mod config;
 pub use config::ShareTableConfig;
 pub use config::StorageConfig;
+pub use config::StorageIoClassConfig;
+pub use config::StorageIoConfig;
 
 mod operator;
 pub use operator::init_operator;
 pub use operator::DataOperator;
+pub use operator::StoragePolicies;
 
 pub mod metrics;
 pub use crate::metrics::StorageMetrics;
 pub use crate::metrics::StorageMetricsLayer;
 
+mod io_policy_layer;
+pub use io_policy_layer::set_storage_io_config;
+pub use io_policy_layer::IoPolicyLayer;
+
+mod io_scheduler;
+pub use io_scheduler::IoPermit;
+pub use io_scheduler::IoScheduler;
+pub use io_scheduler::IoSchedulerLayer;
+
 mod runtime_layer;
 
+mod tiering_layer;
+pub use tiering_layer::cold_path;
+pub use tiering_layer::TieringLayer;
+pub use tiering_layer::COLD_PATH_PREFIX;
+
 mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
@@ -60,6 +77,7 @@ pub use parquet_rs::read_parquet_schema_async_rs;
 
 mod stage;
 pub use stage::init_stage_operator;
+pub use stage::stage_storage_params;
 pub use stage::StageFileInfo;
 pub use stage::StageFileStatus;
 pub use stage::StageFilesInfo;
@@ -69,6 +87,7 @@ mod copy;
 mod merge;
 mod metrics_layer;
 mod multi_table_insert;
+mod multipart;
 mod statistics;
 
 pub use copy::CopyStatus;
@@ -76,5 +95,6 @@ pub use copy::FileParseError;
 pub use copy::FileStatus;
 pub use merge::MergeStatus;
 pub use multi_table_insert::MultiTableInsertStatus;
+pub use multipart::MultipartUpload;
 pub use statistics::Datum;
 pub use statistics::F64;
@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use opendal::Operator;
+use opendal::Result;
+
+/// MultipartUpload writes the data larger than a part by multipart upload, and uploads
+/// the parts concurrently.
+///
+/// The data is written in a single request if the backend does not support multipart
+/// upload, or it fits in one part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MultipartUpload {
+    part_size: usize,
+    concurrency: usize,
+}
+
+impl MultipartUpload {
+    pub fn new(part_size: usize, concurrency: usize) -> Self {
+        MultipartUpload {
+            part_size: part_size.max(1),
+            concurrency: concurrency.max(1),
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(&self, op: &Operator, path: &str, data: Vec<u8>) -> Result<()> {
+        let capability = op.info().full_capability();
+        if data.len() <= self.part_size || !capability.write_can_multi {
+            return op.write(path, data).await;
+        }
+
+        let mut writer = op
+            .writer_with(path)
+            .buffer(self.part_size)
+            .concurrent(self.concurrency)
+            .await?;
+        writer.write(data).await?;
+        writer.close().await
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::env;
 use std::io::Error;
 use std::io::ErrorKind;
@@ -48,14 +49,17 @@ use opendal::layers::ConcurrentLimitLayer;
 use opendal::layers::ImmutableIndexLayer;
 use opendal::layers::LoggingLayer;
 use opendal::layers::MinitraceLayer;
-use opendal::layers::RetryLayer;
-use opendal::layers::TimeoutLayer;
 use opendal::raw::HttpClient;
 use opendal::services;
 use opendal::Builder;
 use opendal::Operator;
 use reqwest_hickory_resolver::HickoryResolver;
 
+use crate::io_policy_layer::apply_io_policy;
+use crate::io_policy_layer::set_storage_io_config;
+use crate::io_policy_layer::storage_io_config;
+use crate::io_scheduler::io_scheduler;
+use crate::io_scheduler::IoSchedulerLayer;
 use crate::metrics_layer::METRICS_LAYER;
 use crate::runtime_layer::RuntimeLayer;
 use crate::StorageConfig;
@@ -99,6 +103,22 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
     let ob = Operator::new(builder)?;
 
+    let mut io_config = storage_io_config();
+    if let Some(retry_timeout) = env::var("_DATABEND_INTERNAL_RETRY_TIMEOUT")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        io_config.read.timeout_secs = retry_timeout;
+        io_config.write.timeout_secs = retry_timeout;
+        io_config.list.timeout_secs = retry_timeout;
+    }
+    if let Some(retry_io_timeout) = env::var("_DATABEND_INTERNAL_RETRY_IO_TIMEOUT")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+    {
+        io_config.io_timeout_secs = retry_io_timeout;
+    }
+
     let op = ob
         // NOTE
         //
@@ -106,33 +126,17 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
         // storage operator so that all underlying storage operations
         // will send to storage runtime.
         .layer(RuntimeLayer::new(GlobalIORuntime::instance()))
-        .layer({
-            let retry_timeout = env::var("_DATABEND_INTERNAL_RETRY_TIMEOUT")
-                .ok()
-                .and_then(|v| v.parse::<u64>().ok())
-                .unwrap_or(10);
-            let retry_io_timeout = env::var("_DATABEND_INTERNAL_RETRY_IO_TIMEOUT")
-                .ok()
-                .and_then(|v| v.parse::<u64>().ok())
-                .unwrap_or(10);
-
-            let mut timeout_layer = TimeoutLayer::new();
-
-            if retry_timeout != 0 {
-                // Return timeout error if the operation timeout
-                timeout_layer = timeout_layer.with_timeout(Duration::from_secs(retry_timeout));
-            }
+        .finish();
 
-            if retry_io_timeout != 0 {
-                // Return timeout error if the io operation timeout
-                timeout_layer =
-                    timeout_layer.with_io_timeout(Duration::from_secs(retry_io_timeout));
-            }
+    // Add timeout and retry for reads, writes and lists
+    let mut op = apply_io_policy(op, &io_config);
 
-            timeout_layer
-        })
-        // Add retry
-        .layer(RetryLayer::new().with_jitter())
+    // Bound the concurrent requests of the node, the retries share the permit
+    if let Some(scheduler) = io_scheduler() {
+        op = op.layer(IoSchedulerLayer::new(scheduler));
+    }
+
+    let op = op
         // Add async backtrace
         .layer(AsyncBacktraceLayer)
         // Add logging
@@ -144,11 +148,11 @@ pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
 
     if let Ok(permits) = env::var("_DATABEND_INTERNAL_MAX_CONCURRENT_IO_REQUEST") {
         if let Ok(permits) = permits.parse::<usize>() {
-            return Ok(op.layer(ConcurrentLimitLayer::new(permits)).finish());
+            return Ok(op.layer(ConcurrentLimitLayer::new(permits)));
         }
     }
 
-    Ok(op.finish())
+    Ok(op)
 }
 
 /// init_azblob_operator will init an opendal azblob operator.
@@ -439,6 +443,8 @@ impl DataOperator {
 
     #[async_backtrace::framed]
     pub async fn init(conf: &StorageConfig) -> databend_common_exception::Result<()> {
+        // The io policy must be set before any operator is built.
+        set_storage_io_config(&conf.io);
         GlobalInstance::set(Self::try_create(&conf.params).await?);
 
         Ok(())
@@ -502,3 +508,39 @@ impl DataOperator {
         GlobalInstance::get()
     }
 }
+
+/// The operators of the named storage policies in config, tables can be created on them
+/// with the `storage_policy` option, and move the cold data to them with `cold_storage_policy`.
+#[derive(Clone, Debug, Default)]
+pub struct StoragePolicies {
+    operators: HashMap<String, DataOperator>,
+}
+
+impl StoragePolicies {
+    #[async_backtrace::framed]
+    pub async fn init(
+        policies: &HashMap<String, StorageConfig>,
+    ) -> databend_common_exception::Result<()> {
+        let mut operators = HashMap::with_capacity(policies.len());
+        for (name, conf) in policies {
+            operators.insert(name.clone(), DataOperator::try_create(&conf.params).await?);
+        }
+        GlobalInstance::set(StoragePolicies { operators });
+
+        Ok(())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.operators.contains_key(name)
+    }
+
+    pub fn get(&self, name: &str) -> databend_common_exception::Result<DataOperator> {
+        self.operators.get(name).cloned().ok_or_else(|| {
+            ErrorCode::StorageUnavailable(format!("storage policy {name} is not configured"))
+        })
+    }
+
+    pub fn instance() -> StoragePolicies {
+        GlobalInstance::get()
+    }
+}
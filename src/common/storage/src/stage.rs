@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::storage::StorageParams;
 use futures::TryStreamExt;
 use opendal::EntryMode;
 use opendal::Metadata;
@@ -69,15 +70,19 @@ impl StageFileInfo {
 }
 
 pub fn init_stage_operator(stage_info: &StageInfo) -> Result<Operator> {
+    Ok(init_operator(&stage_storage_params(stage_info))?)
+}
+
+/// The storage params to access the stage, the root of an internal stage is
+/// the stage prefix in the data storage.
+pub fn stage_storage_params(stage_info: &StageInfo) -> StorageParams {
     if stage_info.stage_type == StageType::External {
-        Ok(init_operator(&stage_info.stage_params.storage)?)
+        stage_info.stage_params.storage.clone()
     } else {
         let stage_prefix = stage_info.stage_prefix();
-        let param = DataOperator::instance()
+        DataOperator::instance()
             .params()
-            .map_root(|path| format!("{path}/{stage_prefix}"));
-
-        Ok(init_operator(&param)?)
+            .map_root(|path| format!("{path}/{stage_prefix}"))
     }
 }
 /// select * from @s1/<path> (FILES => <files> PATTERN => <pattern>)
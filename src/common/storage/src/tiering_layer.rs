@@ -0,0 +1,208 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use opendal::raw::oio;
+use opendal::raw::Accessor;
+use opendal::raw::BatchOperation;
+use opendal::raw::BatchedReply;
+use opendal::raw::FusedAccessor;
+use opendal::raw::Layer;
+use opendal::raw::LayeredAccessor;
+use opendal::raw::OpBatch;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpBatch;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Operator;
+use opendal::Result;
+
+/// The paths with this prefix are stored on the cold storage, without the prefix.
+pub const COLD_PATH_PREFIX: &str = "_cold/";
+
+/// Returns the path on the cold storage of a path with [`COLD_PATH_PREFIX`].
+pub fn cold_path(path: &str) -> Option<&str> {
+    path.strip_prefix(COLD_PATH_PREFIX)
+}
+
+/// TieringLayer routes the reads and deletes of the paths with [`COLD_PATH_PREFIX`]
+/// to the cold storage, so that a table can keep the moved files in its meta
+/// with the prefixed locations.
+///
+/// Writes and lists always go to the hot storage, the files are copied to the cold
+/// storage with the cold operator directly.
+#[derive(Clone)]
+pub struct TieringLayer {
+    cold: FusedAccessor,
+}
+
+impl TieringLayer {
+    pub fn new(cold: Operator) -> Self {
+        TieringLayer {
+            cold: cold.into_inner(),
+        }
+    }
+}
+
+impl Debug for TieringLayer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TieringLayer({:?})", self.cold.info())
+    }
+}
+
+impl<A: Accessor> Layer<A> for TieringLayer {
+    type LayeredAccessor = TieringAccessor<A>;
+
+    fn layer(&self, inner: A) -> Self::LayeredAccessor {
+        TieringAccessor {
+            inner: Arc::new(inner),
+            cold: self.cold.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TieringAccessor<A> {
+    inner: Arc<A>,
+    cold: FusedAccessor,
+}
+
+impl<A: Accessor> Debug for TieringAccessor<A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TieringAccessor({:?})", self.cold.info())
+    }
+}
+
+#[async_trait]
+impl<A: Accessor> LayeredAccessor for TieringAccessor<A> {
+    type Inner = A;
+    type Reader = oio::Reader;
+    type BlockingReader = oio::BlockingReader;
+    type Writer = A::Writer;
+    type BlockingWriter = A::BlockingWriter;
+    type Lister = A::Lister;
+    type BlockingLister = A::BlockingLister;
+
+    fn inner(&self) -> &Self::Inner {
+        &self.inner
+    }
+
+    #[async_backtrace::framed]
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        match cold_path(path) {
+            Some(path) => self.cold.read(path, args).await,
+            None => self
+                .inner
+                .read(path, args)
+                .await
+                .map(|(rp, r)| (rp, Box::new(r) as oio::Reader)),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        self.inner.write(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match cold_path(path) {
+            Some(path) => self.cold.stat(path, args).await,
+            None => self.inner.stat(path, args).await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        match cold_path(path) {
+            Some(path) => self.cold.delete(path, args).await,
+            None => self.inner.delete(path, args).await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn list(&self, path: &str, args: OpList) -> Result<(RpList, Self::Lister)> {
+        self.inner.list(path, args).await
+    }
+
+    #[async_backtrace::framed]
+    async fn batch(&self, args: OpBatch) -> Result<RpBatch> {
+        let (cold, hot): (Vec<_>, Vec<_>) = args
+            .into_operation()
+            .into_iter()
+            .partition(|(path, _)| cold_path(path).is_some());
+
+        let mut results = if hot.is_empty() {
+            vec![]
+        } else {
+            self.inner.batch(OpBatch::new(hot)).await?.into_results()
+        };
+
+        // The cold storage may not support batch, delete the cold files one by one.
+        for (path, op) in cold {
+            let BatchOperation::Delete(args) = op;
+            let res = self
+                .cold
+                .delete(&path[COLD_PATH_PREFIX.len()..], args)
+                .await
+                .map(BatchedReply::Delete);
+            results.push((path, res));
+        }
+
+        Ok(RpBatch::new(results))
+    }
+
+    fn blocking_read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::BlockingReader)> {
+        match cold_path(path) {
+            Some(path) => self.cold.blocking_read(path, args),
+            None => self
+                .inner
+                .blocking_read(path, args)
+                .map(|(rp, r)| (rp, Box::new(r) as oio::BlockingReader)),
+        }
+    }
+
+    fn blocking_write(&self, path: &str, args: OpWrite) -> Result<(RpWrite, Self::BlockingWriter)> {
+        self.inner.blocking_write(path, args)
+    }
+
+    fn blocking_stat(&self, path: &str, args: OpStat) -> Result<RpStat> {
+        match cold_path(path) {
+            Some(path) => self.cold.blocking_stat(path, args),
+            None => self.inner.blocking_stat(path, args),
+        }
+    }
+
+    fn blocking_delete(&self, path: &str, args: OpDelete) -> Result<RpDelete> {
+        match cold_path(path) {
+            Some(path) => self.cold.blocking_delete(path, args),
+            None => self.inner.blocking_delete(path, args),
+        }
+    }
+
+    fn blocking_list(&self, path: &str, args: OpList) -> Result<(RpList, Self::BlockingLister)> {
+        self.inner.blocking_list(path, args)
+    }
+}
@@ -15,6 +15,8 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
 use std::io::Write;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 
 use databend_common_base::base::tokio;
@@ -34,6 +36,35 @@ use crate::Config;
 
 const HEADER_TRACE_PARENT: &str = "traceparent";
 
+// The levels of the file and stderr loggers, which can be changed at runtime.
+static FILE_LOG_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Off as usize);
+static STDERR_LOG_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Off as usize);
+// The max level of the other loggers.
+static OTHER_LOG_LEVEL: AtomicUsize = AtomicUsize::new(LevelFilter::Info as usize);
+
+fn load_level(level: &AtomicUsize) -> LevelFilter {
+    LevelFilter::iter()
+        .nth(level.load(Ordering::Relaxed))
+        .unwrap_or(LevelFilter::Trace)
+}
+
+/// Changes the levels of the file and the stderr loggers without restarting.
+pub fn set_log_levels(file_level: LevelFilter, stderr_level: LevelFilter) {
+    FILE_LOG_LEVEL.store(file_level as usize, Ordering::Relaxed);
+    STDERR_LOG_LEVEL.store(stderr_level as usize, Ordering::Relaxed);
+    update_max_log_level();
+}
+
+// The log macros skip the records above the max level before dispatching them.
+fn update_max_log_level() {
+    let level = [&FILE_LOG_LEVEL, &STDERR_LOG_LEVEL, &OTHER_LOG_LEVEL]
+        .into_iter()
+        .map(load_level)
+        .max()
+        .unwrap_or(LevelFilter::Info);
+    log::set_max_level(level);
+}
+
 #[allow(dyn_drop)]
 pub struct GlobalLogger {
     _guards: Vec<Box<dyn Drop + Send + Sync + 'static>>,
@@ -163,8 +194,10 @@ pub fn init_logging(
         let (normal_log_file, flush_guard) =
             new_file_log_writer(&cfg.file.dir, log_name, cfg.file.limit);
         guards.push(Box::new(flush_guard));
+        let level = cfg.file.level.parse().unwrap_or(LevelFilter::Info);
+        FILE_LOG_LEVEL.store(level as usize, Ordering::Relaxed);
         let dispatch = fern::Dispatch::new()
-            .level(cfg.file.level.parse().unwrap_or(LevelFilter::Info))
+            .filter(|meta| meta.level() <= load_level(&FILE_LOG_LEVEL))
             .format(formatter(&cfg.file.format))
             .chain(Box::new(normal_log_file) as Box<dyn Write + Send>);
         normal_logger = normal_logger.chain(dispatch);
@@ -172,8 +205,10 @@ pub fn init_logging(
 
     // Console logger
     if cfg.stderr.on {
+        let level = cfg.stderr.level.parse().unwrap_or(LevelFilter::Info);
+        STDERR_LOG_LEVEL.store(level as usize, Ordering::Relaxed);
         let dispatch = fern::Dispatch::new()
-            .level(cfg.stderr.level.parse().unwrap_or(LevelFilter::Info))
+            .filter(|meta| meta.level() <= load_level(&STDERR_LOG_LEVEL))
             .format(formatter(&cfg.stderr.format))
             .chain(std::io::stderr());
         normal_logger = normal_logger.chain(dispatch)
@@ -185,8 +220,10 @@ pub fn init_logging(
         labels.insert("category".to_string(), "system".to_string());
         labels.extend(cfg.otlp.labels.clone());
         let logger = OpenTelemetryLogger::new(log_name, &cfg.otlp.endpoint, labels);
+        let level = cfg.otlp.level.parse().unwrap_or(LevelFilter::Info);
+        OTHER_LOG_LEVEL.fetch_max(level as usize, Ordering::Relaxed);
         let dispatch = fern::Dispatch::new()
-            .level(cfg.otlp.level.parse().unwrap_or(LevelFilter::Info))
+            .level(level)
             .format(formatter("json"))
             .chain(Box::new(logger) as Box<dyn Log>);
         normal_logger = normal_logger.chain(dispatch);
@@ -200,6 +237,7 @@ pub fn init_logging(
             .parse()
             .ok()
             .unwrap_or(LevelFilter::Info);
+        OTHER_LOG_LEVEL.fetch_max(level as usize, Ordering::Relaxed);
         normal_logger = normal_logger.chain(
             fern::Dispatch::new()
                 .level(level)
@@ -293,6 +331,7 @@ pub fn init_logging(
         eprintln!("logger has already been set");
         return Vec::new();
     }
+    update_max_log_level();
 
     #[cfg(feature = "console")]
     init_tokio_console();
@@ -31,6 +31,7 @@ pub use crate::config::StructLogConfig;
 pub use crate::config::TracingConfig;
 pub use crate::init::init_logging;
 pub use crate::init::inject_span_to_tonic_request;
+pub use crate::init::set_log_levels;
 pub use crate::init::start_trace_for_remote_request;
 pub use crate::init::GlobalLogger;
 pub use crate::panic_hook::log_panic;
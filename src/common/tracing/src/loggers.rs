@@ -19,6 +19,7 @@ use std::sync::Arc;
 use std::time::Duration;
 use std::time::SystemTime;
 
+use databend_common_base::runtime::ThreadTracker;
 use fern::FormatCallback;
 use opentelemetry::logs::AnyValue;
 use opentelemetry::logs::Severity;
@@ -180,6 +181,9 @@ pub fn formatter(
 fn format_json_log(out: FormatCallback, message: &fmt::Arguments, record: &log::Record) {
     let mut fields = Map::new();
     fields.insert("message".to_string(), format!("{}", message).into());
+    if let Some(query_id) = ThreadTracker::query_id() {
+        fields.insert("query_id".to_string(), query_id.as_str().into());
+    }
     let mut visitor = KvCollector {
         fields: &mut fields,
     };
@@ -210,8 +214,11 @@ fn format_json_log(out: FormatCallback, message: &fmt::Arguments, record: &log::
 }
 
 fn format_text_log(out: FormatCallback, message: &fmt::Arguments, record: &log::Record) {
+    let query_id = ThreadTracker::query_id()
+        .map(|query_id| format!(" query_id={query_id}"))
+        .unwrap_or_default();
     out.finish(format_args!(
-        "{} {:>5} {}: {}:{} {}{}",
+        "{} {:>5} {}: {}:{} {}{}{}",
         humantime::format_rfc3339_micros(SystemTime::now()),
         record.level(),
         record.module_path().unwrap_or(""),
@@ -219,6 +226,7 @@ fn format_text_log(out: FormatCallback, message: &fmt::Arguments, record: &log::
         record.line().unwrap_or(0),
         message,
         KvDisplay::new(record.key_values()),
+        query_id,
     ));
 }
 
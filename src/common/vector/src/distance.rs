@@ -14,72 +14,115 @@
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
-use ndarray::ArrayView;
 
-pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
-    if from.len() != to.len() {
+/// Number of independent accumulators used by the kernels.
+///
+/// The inputs are processed in chunks of `LANES` elements and each lane has its
+/// own accumulator, so there is no dependency between the additions of a chunk
+/// and the compiler can turn the loop body into SIMD instructions.
+const LANES: usize = 8;
+
+macro_rules! impl_distance_kernels {
+    ($t:ty, $dot_and_norms:ident, $squared_l2:ident) => {
+        /// Returns the dot product of `a` and `b` and the squared norms of `a` and `b`.
+        #[inline]
+        fn $dot_and_norms(a: &[$t], b: &[$t]) -> ($t, $t, $t) {
+            let mut dot = [0.0 as $t; LANES];
+            let mut aa = [0.0 as $t; LANES];
+            let mut bb = [0.0 as $t; LANES];
+
+            let a_chunks = a.chunks_exact(LANES);
+            let b_chunks = b.chunks_exact(LANES);
+            let (a_rem, b_rem) = (a_chunks.remainder(), b_chunks.remainder());
+            for (x, y) in a_chunks.zip(b_chunks) {
+                for i in 0..LANES {
+                    dot[i] += x[i] * y[i];
+                    aa[i] += x[i] * x[i];
+                    bb[i] += y[i] * y[i];
+                }
+            }
+
+            let mut sums = (reduce_lanes(&dot), reduce_lanes(&aa), reduce_lanes(&bb));
+            for (x, y) in a_rem.iter().zip(b_rem.iter()) {
+                sums.0 += x * y;
+                sums.1 += x * x;
+                sums.2 += y * y;
+            }
+            sums
+        }
+
+        /// Returns the squared euclidean distance between `a` and `b`.
+        #[inline]
+        fn $squared_l2(a: &[$t], b: &[$t]) -> $t {
+            let mut acc = [0.0 as $t; LANES];
+
+            let a_chunks = a.chunks_exact(LANES);
+            let b_chunks = b.chunks_exact(LANES);
+            let (a_rem, b_rem) = (a_chunks.remainder(), b_chunks.remainder());
+            for (x, y) in a_chunks.zip(b_chunks) {
+                for i in 0..LANES {
+                    let diff = x[i] - y[i];
+                    acc[i] += diff * diff;
+                }
+            }
+
+            let mut sum = reduce_lanes(&acc);
+            for (x, y) in a_rem.iter().zip(b_rem.iter()) {
+                let diff = x - y;
+                sum += diff * diff;
+            }
+            sum
+        }
+    };
+}
+
+impl_distance_kernels!(f32, dot_and_norms, squared_l2);
+impl_distance_kernels!(f64, dot_and_norms_64, squared_l2_64);
+
+#[inline]
+fn reduce_lanes<T: Copy + std::ops::Add<Output = T>>(lanes: &[T; LANES]) -> T {
+    (lanes[0] + lanes[4]) + (lanes[1] + lanes[5]) + (lanes[2] + lanes[6]) + (lanes[3] + lanes[7])
+}
+
+fn check_length(from: usize, to: usize) -> Result<()> {
+    if from != to {
         return Err(ErrorCode::InvalidArgument(format!(
             "Vector length not equal: {:} != {:}",
-            from.len(),
-            to.len(),
+            from, to,
         )));
     }
+    Ok(())
+}
 
-    let a = ArrayView::from(from);
-    let b = ArrayView::from(to);
-    let aa_sum = (&a * &a).sum();
-    let bb_sum = (&b * &b).sum();
+pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
+    check_length(from.len(), to.len())?;
 
-    Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
+    let (dot, aa_sum, bb_sum) = dot_and_norms(from, to);
+    Ok(1.0 - dot / (aa_sum.sqrt() * bb_sum.sqrt()))
 }
 
 pub fn l2_distance(from: &[f32], to: &[f32]) -> Result<f32> {
-    if from.len() != to.len() {
-        return Err(ErrorCode::InvalidArgument(format!(
-            "Vector length not equal: {:} != {:}",
-            from.len(),
-            to.len(),
-        )));
-    }
+    check_length(from.len(), to.len())?;
 
-    Ok(from
-        .iter()
-        .zip(to.iter())
-        .map(|(a, b)| (a - b).powi(2))
-        .sum::<f32>()
-        .sqrt())
+    Ok(squared_l2(from, to).sqrt())
 }
 
 pub fn cosine_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
-    if from.len() != to.len() {
-        return Err(ErrorCode::InvalidArgument(format!(
-            "Vector length not equal: {:} != {:}",
-            from.len(),
-            to.len(),
-        )));
-    }
+    check_length(from.len(), to.len())?;
 
-    let a = ArrayView::from(from);
-    let b = ArrayView::from(to);
-    let aa_sum = (&a * &a).sum();
-    let bb_sum = (&b * &b).sum();
-
-    Ok(1.0 - (&a * &b).sum() / ((aa_sum).sqrt() * (bb_sum).sqrt()))
+    let (dot, aa_sum, bb_sum) = dot_and_norms_64(from, to);
+    Ok(1.0 - dot / (aa_sum.sqrt() * bb_sum.sqrt()))
 }
 
 pub fn l2_distance_64(from: &[f64], to: &[f64]) -> Result<f64> {
-    if from.len() != to.len() {
-        return Err(ErrorCode::InvalidArgument(format!(
-            "Vector length not equal: {:} != {:}",
-            from.len(),
-            to.len(),
-        )));
-    }
+    check_length(from.len(), to.len())?;
+
+    Ok(squared_l2_64(from, to).sqrt())
+}
 
-    Ok(from
-        .iter()
-        .zip(to.iter())
-        .map(|(a, b)| (a - b).powi(2))
-        .sum::<f64>()
-        .sqrt())
+/// Returns the squared euclidean distance of two vectors of the same length,
+/// it is cheaper than [`l2_distance`] when only the order of the distances matters.
+pub fn squared_l2_distance(from: &[f32], to: &[f32]) -> f32 {
+    debug_assert_eq!(from.len(), to.len());
+    squared_l2(from, to)
 }
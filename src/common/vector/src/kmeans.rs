@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::distance::squared_l2_distance;
+
+/// Partitions the vectors into at most `k` clusters with Lloyd's algorithm.
+///
+/// The initial centroids are the vectors evenly spaced in the input, so the result
+/// is deterministic. Returns the centroids and the index of the cluster of each vector,
+/// clusters without any vector are removed.
+pub fn kmeans(
+    vectors: &[&[f32]],
+    dimension: usize,
+    k: usize,
+    max_iterations: usize,
+) -> (Vec<Vec<f32>>, Vec<usize>) {
+    let k = k.min(vectors.len());
+    if k == 0 {
+        return (vec![], vec![]);
+    }
+
+    let mut centroids = (0..k)
+        .map(|i| vectors[i * vectors.len() / k].to_vec())
+        .collect::<Vec<_>>();
+    let mut assignments = vec![usize::MAX; vectors.len()];
+
+    for _ in 0..max_iterations {
+        let mut changed = false;
+        for (vector, assignment) in vectors.iter().zip(assignments.iter_mut()) {
+            let nearest = nearest_centroid(&centroids, vector);
+            if nearest != *assignment {
+                *assignment = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![vec![0.0f64; dimension]; k];
+        let mut counts = vec![0usize; k];
+        for (vector, assignment) in vectors.iter().zip(assignments.iter()) {
+            counts[*assignment] += 1;
+            for (sum, value) in sums[*assignment].iter_mut().zip(vector.iter()) {
+                *sum += *value as f64;
+            }
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            // Keep the previous centroid of an empty cluster.
+            if count > 0 {
+                for (c, s) in centroid.iter_mut().zip(sum) {
+                    *c = (s / count as f64) as f32;
+                }
+            }
+        }
+    }
+
+    // Assign the vectors to the final centroids and drop the empty clusters.
+    let mut counts = vec![0usize; k];
+    for (vector, assignment) in vectors.iter().zip(assignments.iter_mut()) {
+        *assignment = nearest_centroid(&centroids, vector);
+        counts[*assignment] += 1;
+    }
+    let mut remap = vec![usize::MAX; k];
+    let mut result = Vec::with_capacity(k);
+    for (i, centroid) in centroids.into_iter().enumerate() {
+        if counts[i] > 0 {
+            remap[i] = result.len();
+            result.push(centroid);
+        }
+    }
+    for assignment in assignments.iter_mut() {
+        *assignment = remap[*assignment];
+    }
+    (result, assignments)
+}
+
+fn nearest_centroid(centroids: &[Vec<f32>], vector: &[f32]) -> usize {
+    let mut nearest = 0;
+    let mut min_distance = f32::INFINITY;
+    for (i, centroid) in centroids.iter().enumerate() {
+        let distance = squared_l2_distance(centroid, vector);
+        if distance < min_distance {
+            min_distance = distance;
+            nearest = i;
+        }
+    }
+    nearest
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 use databend_common_vector::cosine_distance;
+use databend_common_vector::cosine_distance_64;
+use databend_common_vector::l2_distance;
+use databend_common_vector::l2_distance_64;
 
 #[test]
 fn test_cosine() {
@@ -39,3 +42,48 @@ fn test_cosine() {
         assert!(d.is_err());
     }
 }
+
+#[test]
+fn test_l2() {
+    {
+        let x = vec![1.0, 2.0, 3.0];
+        let y = vec![4.0, 6.0, 3.0];
+        let d = l2_distance(&x, &y).unwrap();
+        approx::assert_relative_eq!(d, 5.0);
+    }
+
+    {
+        // the length is not a multiple of the lanes of the kernel
+        let x: Vec<f32> = (0..19).map(|v| v as f32 * 0.5).collect();
+        let y: Vec<f32> = (0..19).map(|v| (v * v) as f32 * 0.1).collect();
+        let want = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<f32>()
+            .sqrt();
+        let d = l2_distance(&x, &y).unwrap();
+        approx::assert_relative_eq!(d, want, max_relative = 1e-6);
+    }
+
+    {
+        let x: Vec<f64> = (0..19).map(|v| v as f64).collect();
+        let y: Vec<f64> = (0..19).map(|v| v as f64 + 2.0).collect();
+        let d = l2_distance_64(&x, &y).unwrap();
+        approx::assert_relative_eq!(d, (19.0f64 * 4.0).sqrt());
+    }
+
+    {
+        let x = vec![1.0, 2.0, 3.0];
+        let y = vec![4.0, 6.0];
+        assert!(l2_distance(&x, &y).is_err());
+    }
+}
+
+#[test]
+fn test_cosine_64() {
+    let x: Vec<f64> = (1..20).map(|v| v as f64).collect();
+    let y: Vec<f64> = (1..20).map(|v| v as f64 * 3.0).collect();
+    let d = cosine_distance_64(&x, &y).unwrap();
+    approx::assert_abs_diff_eq!(d, 0.0, epsilon = 1e-12);
+}
@@ -0,0 +1,44 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_vector::kmeans;
+
+#[test]
+fn test_kmeans() {
+    let data = [
+        [0.0, 0.0],
+        [10.0, 10.0],
+        [0.1, 0.2],
+        [10.2, 9.9],
+        [0.2, 0.1],
+        [9.8, 10.1],
+    ];
+    let vectors = data.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+
+    let (centroids, assignments) = kmeans(&vectors, 2, 2, 10);
+    assert_eq!(centroids.len(), 2);
+    assert_eq!(assignments, vec![0, 1, 0, 1, 0, 1]);
+    approx::assert_relative_eq!(centroids[0][0], 0.1, max_relative = 1e-6);
+    approx::assert_relative_eq!(centroids[1][1], 10.0, max_relative = 1e-6);
+
+    // duplicated vectors leave the other clusters empty
+    let data = [[1.0, 1.0]; 4];
+    let vectors = data.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+    let (centroids, assignments) = kmeans(&vectors, 2, 3, 10);
+    assert_eq!(centroids, vec![vec![1.0, 1.0]]);
+    assert_eq!(assignments, vec![0; 4]);
+
+    let (centroids, assignments) = kmeans(&[], 2, 3, 10);
+    assert!(centroids.is_empty() && assignments.is_empty());
+}
@@ -42,6 +42,8 @@ const OPT_ERROR_ON_COLUMN_COUNT_MISMATCH: &str = "error_on_column_count_mismatch
 const MISSING_FIELD_AS: &str = "missing_field_as";
 const NULL_FIELD_AS: &str = "null_field_as";
 const NULL_IF: &str = "null_if";
+const OPT_EXTRA_FIELD: &str = "extra_field";
+const OPT_SHEET_NAME: &str = "sheet_name";
 const OPT_EMPTY_FIELD_AS: &str = "empty_field_as";
 const OPT_BINARY_FORMAT: &str = "binary_format";
 
@@ -112,6 +114,8 @@ pub enum FileFormatParams {
     Json(JsonFileFormatParams),
     Xml(XmlFileFormatParams),
     Parquet(ParquetFileFormatParams),
+    Avro(AvroFileFormatParams),
+    Xlsx(XlsxFileFormatParams),
 }
 
 impl FileFormatParams {
@@ -123,6 +127,8 @@ impl FileFormatParams {
             FileFormatParams::Json(_) => StageFileFormatType::Json,
             FileFormatParams::Xml(_) => StageFileFormatType::Xml,
             FileFormatParams::Parquet(_) => StageFileFormatType::Parquet,
+            FileFormatParams::Avro(_) => StageFileFormatType::Avro,
+            FileFormatParams::Xlsx(_) => StageFileFormatType::Xlsx,
         }
     }
 
@@ -140,6 +146,12 @@ impl FileFormatParams {
                 Ok(FileFormatParams::Json(JsonFileFormatParams::default()))
             }
             StageFileFormatType::Xml => Ok(FileFormatParams::Xml(XmlFileFormatParams::default())),
+            StageFileFormatType::Avro => {
+                Ok(FileFormatParams::Avro(AvroFileFormatParams::default()))
+            }
+            StageFileFormatType::Xlsx => {
+                Ok(FileFormatParams::Xlsx(XlsxFileFormatParams::default()))
+            }
             _ => Err(ErrorCode::IllegalFileFormat(format!(
                 "Unsupported file format type: {:?}",
                 format_type
@@ -155,6 +167,9 @@ impl FileFormatParams {
             FileFormatParams::Json(v) => v.compression,
             FileFormatParams::Xml(v) => v.compression,
             FileFormatParams::Parquet(_) => StageFileCompression::None,
+            FileFormatParams::Avro(v) => v.compression,
+            // an xlsx file is a zip archive itself
+            FileFormatParams::Xlsx(_) => StageFileCompression::None,
         }
     }
 
@@ -180,6 +195,12 @@ impl FileFormatParams {
                 let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
                 let null_field_as = ast.options.remove(NULL_FIELD_AS);
                 let null_if = ast.options.remove(NULL_IF);
+                let extra_field = ast
+                    .options
+                    .remove(OPT_EXTRA_FIELD)
+                    .map(|v| ExtraField::from_str(&v))
+                    .transpose()?
+                    .unwrap_or_default();
                 let null_if = match null_if {
                     None => {
                         vec![]
@@ -197,8 +218,33 @@ impl FileFormatParams {
                     missing_field_as.as_deref(),
                     null_field_as.as_deref(),
                     null_if,
+                    extra_field,
+                )?)
+            }
+            StageFileFormatType::Avro => {
+                let compression = ast.take_compression()?;
+                let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
+                let extra_field = ast
+                    .options
+                    .remove(OPT_EXTRA_FIELD)
+                    .map(|v| ExtraField::from_str(&v))
+                    .transpose()?
+                    .unwrap_or_default();
+                FileFormatParams::Avro(AvroFileFormatParams::try_create(
+                    compression,
+                    missing_field_as.as_deref(),
+                    extra_field,
                 )?)
             }
+            StageFileFormatType::Xlsx => {
+                let default = XlsxFileFormatParams::default();
+                let sheet_name = ast.take_string(OPT_SHEET_NAME, default.sheet_name);
+                let headers = ast.take_u64(OPT_SKIP_HEADER, default.headers)?;
+                FileFormatParams::Xlsx(XlsxFileFormatParams {
+                    sheet_name,
+                    headers,
+                })
+            }
             StageFileFormatType::Parquet => {
                 let missing_field_as = ast.options.remove(MISSING_FIELD_AS);
                 FileFormatParams::Parquet(ParquetFileFormatParams::try_create(
@@ -568,6 +614,42 @@ impl Display for BinaryFormat {
     }
 }
 
+/// The name of the VARIANT column which keeps the fields not in the table schema,
+/// when loading NDJSON or AVRO with `EXTRA_FIELD = VARIANT`.
+pub const EXTRA_FIELDS_COLUMN: &str = "extra_fields";
+
+/// How to handle the fields of a NDJSON row or AVRO record which are not in the table schema.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ExtraField {
+    #[default]
+    Ignore,
+    /// collected into the VARIANT column [`EXTRA_FIELDS_COLUMN`] as an object
+    Variant,
+}
+
+impl FromStr for ExtraField {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ignore" => Ok(Self::Ignore),
+            "variant" => Ok(Self::Variant),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Invalid option value: EXTRA_FIELD is set to {s}. The valid values are IGNORE | VARIANT."
+            ))),
+        }
+    }
+}
+
+impl Display for ExtraField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Ignore => write!(f, "IGNORE"),
+            Self::Variant => write!(f, "VARIANT"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JsonFileFormatParams {
     pub compression: StageFileCompression,
@@ -596,6 +678,7 @@ pub struct NdJsonFileFormatParams {
     pub missing_field_as: NullAs,
     pub null_field_as: NullAs,
     pub null_if: Vec<String>,
+    pub extra_field: ExtraField,
 }
 
 impl NdJsonFileFormatParams {
@@ -604,6 +687,7 @@ impl NdJsonFileFormatParams {
         missing_field_as: Option<&str>,
         null_field_as: Option<&str>,
         null_if: Vec<String>,
+        extra_field: ExtraField,
     ) -> Result<Self> {
         let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
         let null_field_as = NullAs::parse(null_field_as, MISSING_FIELD_AS, NullAs::Null)?;
@@ -617,6 +701,7 @@ impl NdJsonFileFormatParams {
             missing_field_as,
             null_field_as,
             null_if,
+            extra_field,
         })
     }
 }
@@ -628,6 +713,7 @@ impl Default for NdJsonFileFormatParams {
             missing_field_as: NullAs::Error,
             null_field_as: NullAs::FieldDefault,
             null_if: vec![],
+            extra_field: ExtraField::Ignore,
         }
     }
 }
@@ -641,6 +727,38 @@ impl NdJsonFileFormatParams {
     }
 }
 
+/// AVRO container files, the writer schema is read from the file header.
+/// Schema Registry lookups and the single-object encoding are not supported.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvroFileFormatParams {
+    pub compression: StageFileCompression,
+    pub missing_field_as: NullAs,
+    pub extra_field: ExtraField,
+}
+
+impl AvroFileFormatParams {
+    pub fn try_create(
+        compression: StageFileCompression,
+        missing_field_as: Option<&str>,
+        extra_field: ExtraField,
+    ) -> Result<Self> {
+        let missing_field_as = NullAs::parse(missing_field_as, MISSING_FIELD_AS, NullAs::Error)?;
+        Ok(Self {
+            compression,
+            missing_field_as,
+            extra_field,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XlsxFileFormatParams {
+    /// the first sheet is read if empty
+    pub sheet_name: String,
+    /// the number of rows to skip, the last one is used as column names by `infer_schema`
+    pub headers: u64,
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ParquetFileFormatParams {
     pub missing_field_as: NullAs,
@@ -707,8 +825,11 @@ impl Display for FileFormatParams {
             FileFormatParams::NdJson(params) => {
                 write!(
                     f,
-                    "TYPE = NDJSON, COMPRESSION = {:?} MISSING_FIELD_AS = {} NULL_FIELDS_AA = {}",
-                    params.compression, params.missing_field_as, params.null_field_as
+                    "TYPE = NDJSON, COMPRESSION = {:?} MISSING_FIELD_AS = {} NULL_FIELDS_AA = {} EXTRA_FIELD = {}",
+                    params.compression,
+                    params.missing_field_as,
+                    params.null_field_as,
+                    params.extra_field
                 )
             }
             FileFormatParams::Parquet(params) => {
@@ -718,6 +839,21 @@ impl Display for FileFormatParams {
                     params.missing_field_as
                 )
             }
+            FileFormatParams::Avro(params) => {
+                write!(
+                    f,
+                    "TYPE = AVRO COMPRESSION = {:?} MISSING_FIELD_AS = {} EXTRA_FIELD = {}",
+                    params.compression, params.missing_field_as, params.extra_field
+                )
+            }
+            FileFormatParams::Xlsx(params) => {
+                write!(
+                    f,
+                    "TYPE = XLSX SHEET_NAME = '{}' SKIP_HEADER = {}",
+                    escape_string(&params.sheet_name),
+                    params.headers
+                )
+            }
         }
     }
 }
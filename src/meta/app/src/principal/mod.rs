@@ -19,7 +19,9 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe;
 mod principal_identity;
+mod procedure;
 pub mod role_ident;
 mod role_info;
 pub mod udf_ident;
@@ -38,6 +40,8 @@ mod user_stage_file_ident;
 pub mod connection_ident;
 pub mod network_policy_ident;
 pub mod password_policy_ident;
+pub mod pipe_ident;
+pub mod procedure_ident;
 pub mod user_defined_file_format_ident;
 pub mod user_setting_ident;
 pub mod user_stage_ident;
@@ -49,7 +53,12 @@ pub use network_policy_ident::NetworkPolicyIdent;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
 pub use password_policy_ident::PasswordPolicyIdent;
+pub use pipe::PipeInfo;
+pub use pipe_ident::PipeIdent;
 pub use principal_identity::PrincipalIdentity;
+pub use procedure::ProcedureExecuteAs;
+pub use procedure::ProcedureInfo;
+pub use procedure_ident::ProcedureIdent;
 pub use role_ident::RoleIdent;
 pub use role_ident::RoleIdentRaw;
 pub use role_info::RoleInfo;
@@ -62,6 +71,7 @@ pub use user_auth::PasswordHashMethod;
 pub use user_defined_file_format::UserDefinedFileFormat;
 pub use user_defined_function::LambdaUDF;
 pub use user_defined_function::UDFDefinition;
+pub use user_defined_function::UDFModel;
 pub use user_defined_function::UDFScript;
 pub use user_defined_function::UDFServer;
 pub use user_defined_function::UserDefinedFunction;
@@ -0,0 +1,34 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::principal::UserIdentity;
+
+/// A pipe loads the new files of a stage into a table continuously.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct PipeInfo {
+    pub name: String,
+    /// The `COPY INTO <table> FROM <stage>` statement run by the pipe.
+    pub copy_stmt: String,
+    /// The stage is polled for new files if true, otherwise the files are loaded by `ALTER PIPE ... REFRESH`.
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    /// The copy statement runs as the creator of the pipe.
+    pub creator: UserIdentity,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: Option<DateTime<Utc>>,
+}
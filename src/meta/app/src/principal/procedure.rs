@@ -0,0 +1,70 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+use crate::principal::UserIdentity;
+
+/// The privileges a stored procedure body runs with.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ProcedureExecuteAs {
+    #[default]
+    Owner,
+    Caller,
+}
+
+impl std::fmt::Display for ProcedureExecuteAs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcedureExecuteAs::Owner => write!(f, "OWNER"),
+            ProcedureExecuteAs::Caller => write!(f, "CALLER"),
+        }
+    }
+}
+
+/// A stored procedure is a named script block with typed arguments.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct ProcedureInfo {
+    pub name: String,
+    pub arg_names: Vec<String>,
+    /// The SQL types of the arguments, in the same order as `arg_names`.
+    pub arg_types: Vec<String>,
+    pub return_type: String,
+    /// The script block run by `CALL PROCEDURE`.
+    pub script: String,
+    pub execute_as: ProcedureExecuteAs,
+    pub comment: String,
+    /// The user whose privileges the script runs with when it executes as owner.
+    pub owner: UserIdentity,
+    /// The role of the owner the script runs with when it executes as owner, which is the current
+    /// role of the owner when the procedure is created.
+    pub owner_role: Option<String>,
+    pub created_on: DateTime<Utc>,
+    pub updated_on: Option<DateTime<Utc>>,
+}
+
+impl ProcedureInfo {
+    /// Renders the signature as `name(arg type, ...) RETURNS type`.
+    pub fn signature(&self) -> String {
+        let args = self
+            .arg_names
+            .iter()
+            .zip(self.arg_types.iter())
+            .map(|(name, ty)| format!("{name} {ty}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}({}) RETURNS {}", self.name, args, self.return_type)
+    }
+}
@@ -44,11 +44,21 @@ pub struct UDFScript {
     pub runtime_version: String,
 }
 
+/// A machine-learning model in a stage, evaluated by `ml_predict`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UDFModel {
+    /// The stage location of the model file without the leading `@`, like `models/iris.onnx`.
+    pub location: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UDFDefinition {
     LambdaUDF(LambdaUDF),
     UDFServer(UDFServer),
     UDFScript(UDFScript),
+    UDFModel(UDFModel),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -124,6 +134,25 @@ impl UserDefinedFunction {
             created_on: Utc::now(),
         }
     }
+
+    pub fn create_udf_model(
+        name: &str,
+        location: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            definition: UDFDefinition::UDFModel(UDFModel {
+                location: location.to_string(),
+                arg_types,
+                return_type,
+            }),
+            created_on: Utc::now(),
+        }
+    }
 }
 
 impl Display for UDFDefinition {
@@ -180,6 +209,19 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} RUNTIME_VERSION = {runtime_version} HANDLER = {handler} AS $${code}$$"
                 )?;
             }
+            UDFDefinition::UDFModel(UDFModel {
+                location,
+                arg_types,
+                return_type,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ") RETURNS {return_type} FROM @{location}")?;
+            }
         }
         Ok(())
     }
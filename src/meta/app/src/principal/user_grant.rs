@@ -25,7 +25,7 @@ use crate::tenant::Tenant;
 
 /// [`OwnershipObject`] is used to maintain the grant object that support rename by id. Using ID over name
 /// have many benefits, it can avoid lost privileges after the object get renamed.
-/// But Stage and UDF do not support the concept of renaming and do not have ids, so names can be used.
+/// But Stage, UDF and Procedure do not support the concept of renaming and do not have ids, so names can be used.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Hash)]
 pub enum OwnershipObject {
     /// used on the fuse databases
@@ -48,6 +48,10 @@ pub enum OwnershipObject {
     UDF {
         name: String,
     },
+
+    Procedure {
+        name: String,
+    },
 }
 
 impl OwnershipObject {
@@ -94,6 +98,7 @@ impl OwnershipObject {
             }
             OwnershipObject::Stage { name } => b.push_raw("stage-by-name").push_str(name),
             OwnershipObject::UDF { name } => b.push_raw("udf-by-name").push_str(name),
+            OwnershipObject::Procedure { name } => b.push_raw("procedure-by-name").push_str(name),
         }
     }
 
@@ -141,9 +146,13 @@ impl OwnershipObject {
                 let name = p.next_str()?;
                 Ok(OwnershipObject::UDF { name })
             }
+            "procedure-by-name" => {
+                let name = p.next_str()?;
+                Ok(OwnershipObject::Procedure { name })
+            }
             _ => Err(kvapi::KeyError::InvalidSegment {
                 i: p.index(),
-                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name"
+                expect: "database-by-id|database-by-catalog-id|table-by-id|table-by-catalog-id|stage-by-name|udf-by-name|procedure-by-name"
                     .to_string(),
                 got: q.to_string(),
             }),
@@ -163,6 +172,7 @@ impl OwnershipObject {
 /// - `table-by-catalog-id/<catalog>/<table_id>`
 /// - `stage-by-name/<stage_name>`
 /// - `udf-by-name/<udf_name>`
+/// - `procedure-by-name/<procedure_name>`
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct TenantOwnershipObject {
     tenant: Tenant,
@@ -183,6 +193,7 @@ impl TenantOwnershipObject {
         //     }
         //     OwnershipObject::Stage { .. } => {}
         //     OwnershipObject::UDF { .. } => {}
+        //     OwnershipObject::Procedure { .. } => {}
         // }
 
         Self::new_unchecked(tenant, subject)
@@ -209,6 +220,7 @@ pub enum GrantObject {
     TableById(String, u64, u64),
     UDF(String),
     Stage(String),
+    Procedure(String),
 }
 
 impl GrantObject {
@@ -241,6 +253,7 @@ impl GrantObject {
             (GrantObject::Table(_, _, _), _) => false,
             (GrantObject::Stage(lstage), GrantObject::Stage(rstage)) => lstage == rstage,
             (GrantObject::UDF(udf), GrantObject::UDF(rudf)) => udf == rudf,
+            (GrantObject::Procedure(lproc), GrantObject::Procedure(rproc)) => lproc == rproc,
             _ => false,
         }
     }
@@ -261,12 +274,18 @@ impl GrantObject {
             GrantObject::Stage(_) => {
                 UserPrivilegeSet::available_privileges_on_stage(available_ownership)
             }
+            GrantObject::Procedure(_) => {
+                UserPrivilegeSet::available_privileges_on_procedure(available_ownership)
+            }
         }
     }
 
     pub fn catalog(&self) -> Option<String> {
         match self {
-            GrantObject::Global | GrantObject::Stage(_) | GrantObject::UDF(_) => None,
+            GrantObject::Global
+            | GrantObject::Stage(_)
+            | GrantObject::UDF(_)
+            | GrantObject::Procedure(_) => None,
             GrantObject::Database(cat, _) | GrantObject::DatabaseById(cat, _) => Some(cat.clone()),
             GrantObject::Table(cat, _, _) | GrantObject::TableById(cat, _, _) => Some(cat.clone()),
         }
@@ -287,6 +306,7 @@ impl fmt::Display for GrantObject {
             }
             GrantObject::UDF(udf) => write!(f, "UDF {udf}"),
             GrantObject::Stage(stage) => write!(f, "STAGE {stage}"),
+            GrantObject::Procedure(procedure) => write!(f, "PROCEDURE {procedure}"),
         }
     }
 }
@@ -654,5 +674,21 @@ mod tests {
             let parsed = TenantOwnershipObject::from_str_key(&key).unwrap();
             assert_eq!(role_grantee, parsed);
         }
+
+        // procedure
+        {
+            let role_grantee = TenantOwnershipObject::new_unchecked(
+                Tenant::new_literal("test"),
+                OwnershipObject::Procedure {
+                    name: "foo".to_string(),
+                },
+            );
+
+            let key = role_grantee.to_string_key();
+            assert_eq!("__fd_object_owners/test/procedure-by-name/foo", key);
+
+            let parsed = TenantOwnershipObject::from_str_key(&key).unwrap();
+            assert_eq!(role_grantee, parsed);
+        }
     }
 }
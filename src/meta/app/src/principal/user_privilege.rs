@@ -35,7 +35,8 @@ use enumflags2::BitFlags;
     num_derive::FromPrimitive,
 )]
 pub enum UserPrivilegeType {
-    // UsagePrivilege is a synonym for “no privileges”, if object is udf, means can use this udf
+    // UsagePrivilege is a synonym for “no privileges”, if object is udf, means can use this udf,
+    // if object is procedure, means can call this procedure
     Usage = 1 << 0,
     // Privilege to select rows from tables in a database.
     Select = 1 << 2,
@@ -201,6 +202,14 @@ impl UserPrivilegeSet {
         }
     }
 
+    pub fn available_privileges_on_procedure(available_ownership: bool) -> Self {
+        if available_ownership {
+            make_bitflags!(UserPrivilegeType::{ Usage | Ownership }).into()
+        } else {
+            make_bitflags!(UserPrivilegeType::{ Usage }).into()
+        }
+    }
+
     // TODO: remove this, as ALL has different meanings on different objects
     pub fn all_privileges() -> Self {
         ALL_PRIVILEGES.into()
@@ -174,6 +174,7 @@ pub enum StageFileFormatType {
     Orc,
     Parquet,
     Xml,
+    Xlsx,
     None,
 }
 
@@ -193,11 +194,13 @@ impl FromStr for StageFileFormatType {
             "PARQUET" => Ok(StageFileFormatType::Parquet),
             "XML" => Ok(StageFileFormatType::Xml),
             "JSON" => Ok(StageFileFormatType::Json),
-            "ORC" | "AVRO" => Err(format!(
-                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+            "AVRO" => Ok(StageFileFormatType::Avro),
+            "XLSX" => Ok(StageFileFormatType::Xlsx),
+            "ORC" | "PROTOBUF" => Err(format!(
+                "File format type '{s}' not implemented yet', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | AVRO | XLSX)"
             )),
             _ => Err(format!(
-                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML)"
+                "Unknown file format type '{s}', must be one of ( CSV | TSV | NDJSON | PARQUET | XML | AVRO | XLSX)"
             )),
         }
     }
@@ -573,6 +576,8 @@ pub struct StageInfo {
     pub number_of_files: u64,
     pub creator: Option<UserIdentity>,
     pub created_on: DateTime<Utc>,
+    /// `SELECT * FROM @stage` returns the metadata of the files in the stage.
+    pub directory_enabled: bool,
 }
 
 impl StageInfo {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt::Display;
 use std::ops::Deref;
 
@@ -23,11 +24,13 @@ use crate::storage::StorageParams;
 use crate::tenant::Tenant;
 use crate::KeyWithTenant;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+#[derive(Clone, PartialEq, Eq, Debug, Hash)]
 pub enum CatalogType {
-    Default = 1,
-    Hive = 2,
-    Iceberg = 3,
+    Default,
+    Hive,
+    Iceberg,
+    /// A catalog provided by a plugin, identified by the name it is registered with.
+    Custom(String),
 }
 
 impl Display for CatalogType {
@@ -36,6 +39,7 @@ impl Display for CatalogType {
             CatalogType::Default => write!(f, "DEFAULT"),
             CatalogType::Hive => write!(f, "HIVE"),
             CatalogType::Iceberg => write!(f, "ICEBERG"),
+            CatalogType::Custom(name) => write!(f, "{}", name),
         }
     }
 }
@@ -51,6 +55,8 @@ pub enum CatalogOption {
     Hive(HiveCatalogOption),
     // Catalog option for Iceberg.
     Iceberg(IcebergCatalogOption),
+    // Catalog option for a catalog provided by a plugin.
+    Custom(CustomCatalogOption),
 }
 
 impl CatalogOption {
@@ -59,6 +65,7 @@ impl CatalogOption {
             CatalogOption::Default => CatalogType::Default,
             CatalogOption::Hive(_) => CatalogType::Hive,
             CatalogOption::Iceberg(_) => CatalogType::Iceberg,
+            CatalogOption::Custom(opt) => CatalogType::Custom(opt.catalog_type.clone()),
         }
     }
 }
@@ -76,6 +83,16 @@ pub struct IcebergCatalogOption {
     pub storage_params: Box<StorageParams>,
 }
 
+/// Option for creating a catalog provided by a plugin
+///
+/// The options are passed to the plugin as is, it is up to the plugin to interpret them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CustomCatalogOption {
+    /// The name the plugin is registered with.
+    pub catalog_type: String,
+    pub options: BTreeMap<String, String>,
+}
+
 /// Same as `CatalogNameIdent`, but with `serde` support,
 /// and can be used a s part of a value.
 // #[derive(Clone, Debug, PartialEq, Eq)]
@@ -120,6 +120,7 @@ pub use table::UpsertTableCopiedFileReply;
 pub use table::UpsertTableCopiedFileReq;
 pub use table::UpsertTableOptionReply;
 pub use table::UpsertTableOptionReq;
+pub use table::ViewDependency;
 pub use virtual_column::CreateVirtualColumnReply;
 pub use virtual_column::CreateVirtualColumnReq;
 pub use virtual_column::DropVirtualColumnReply;
@@ -254,6 +254,8 @@ pub struct TableMeta {
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
     pub indexes: BTreeMap<String, TableIndex>,
+    // The tables referenced by the query of a view, empty for the other engines.
+    pub view_dependencies: Vec<ViewDependency>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -271,6 +273,14 @@ pub struct TableIndex {
     pub options: BTreeMap<String, String>,
 }
 
+/// A table that the query of a view refers to.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct ViewDependency {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+}
+
 impl TableMeta {
     pub fn add_column(
         &mut self,
@@ -384,6 +394,7 @@ impl Default for TableMeta {
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
             indexes: BTreeMap::new(),
+            view_dependencies: vec![],
         }
     }
 }
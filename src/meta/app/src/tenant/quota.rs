@@ -31,6 +31,9 @@ pub struct TenantQuota {
 
     // The max number of users can be created in the tenant.
     pub max_users: u32,
+
+    // The max size in bytes of a file that can be copied into a table.
+    pub max_copy_file_size: u64,
 }
 
 impl TryFrom<Vec<u8>> for TenantQuota {
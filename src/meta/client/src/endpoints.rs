@@ -14,11 +14,33 @@
 
 use std::collections::BTreeMap;
 use std::fmt;
+use std::time::Duration;
+use std::time::Instant;
 
 use itertools::Itertools;
 
+/// The max time a failed node is avoided.
+const MAX_UNHEALTHY_DURATION: Duration = Duration::from_secs(10);
+
+/// The health state of a node.
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Status {}
+pub struct Status {
+    /// The number of consecutive failures of the requests sent to this node.
+    failures: u64,
+
+    /// The node is avoided until this time, unless no other node is healthy.
+    unhealthy_until: Option<Instant>,
+}
+
+impl Status {
+    pub fn failures(&self) -> u64 {
+        self.failures
+    }
+
+    pub fn is_healthy(&self, now: Instant) -> bool {
+        self.unhealthy_until.map_or(true, |t| t <= now)
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Endpoints {
@@ -47,22 +69,59 @@ impl Endpoints {
     /// Update `current` to choose the next node to send a request to.
     ///
     /// This is used when the `current` node fails.
+    /// The unhealthy nodes are skipped, unless no node is healthy.
     pub fn choose_next(&mut self) -> &str {
-        if let Some(ref c) = self.current {
-            let index = self.nodes.keys().position(|x| x == c).unwrap();
-
-            self.current = self
-                .nodes
-                .keys()
-                .nth((index + 1) % self.nodes.len())
-                .map(|x| x.to_string());
-        } else {
-            self.current = self.nodes.keys().next().map(|x| x.to_string());
-        }
+        let now = Instant::now();
+        let n = self.nodes.len();
+
+        let start = match self.current {
+            Some(ref c) => self.nodes.keys().position(|x| x == c).unwrap() + 1,
+            None => 0,
+        };
+
+        let healthy = (0..n)
+            .map(|i| (start + i) % n)
+            .find(|i| self.nodes.values().nth(*i).unwrap().is_healthy(now));
+
+        let index = healthy.unwrap_or(start % n);
+        self.current = self.nodes.keys().nth(index).map(|x| x.to_string());
 
         self.current.as_deref().unwrap()
     }
 
+    /// Return a healthy node other than `exclude`, to send a duplicated request to.
+    pub fn choose_other(&self, exclude: &str) -> Option<&str> {
+        let now = Instant::now();
+
+        self.nodes
+            .iter()
+            .find(|(k, st)| k.as_str() != exclude && st.is_healthy(now))
+            .map(|(k, _)| k.as_str())
+    }
+
+    /// Record a failed request to a node, the node is avoided for a while.
+    ///
+    /// The more consecutive failures, the longer it is avoided.
+    pub fn report_failure(&mut self, node: &str, now: Instant) {
+        if let Some(st) = self.nodes.get_mut(node) {
+            st.failures += 1;
+
+            let d = Duration::from_secs(st.failures).min(MAX_UNHEALTHY_DURATION);
+            st.unhealthy_until = Some(now + d);
+        }
+    }
+
+    /// Record a successful request to a node, the node becomes healthy.
+    pub fn report_success(&mut self, node: &str) {
+        if let Some(st) = self.nodes.get_mut(node) {
+            *st = Status::default();
+        }
+    }
+
+    pub fn status(&self, node: &str) -> Option<&Status> {
+        self.nodes.get(node)
+    }
+
     /// Return the endpoint of the node to connect.
     ///
     /// It always return the currently known leader address.
@@ -100,11 +159,18 @@ impl Endpoints {
 
     /// Replace the nodes of the meta-service cluster.
     ///
+    /// The health state of the nodes that are still in the cluster is kept.
     /// If the `current` node is not in the new nodes, it will be set to `None`.
     pub fn replace_nodes(&mut self, nodes: impl IntoIterator<Item = impl ToString>) {
+        let mut prev = std::mem::take(&mut self.nodes);
+
         self.nodes = nodes
             .into_iter()
-            .map(|x| (x.to_string(), Status::default()))
+            .map(|x| {
+                let x = x.to_string();
+                let st = prev.remove(&x).unwrap_or_default();
+                (x, st)
+            })
             .collect();
 
         if let Some(c) = self.current.as_deref() {
@@ -128,6 +194,8 @@ impl fmt::Display for Endpoints {
 
 #[cfg(test)]
 mod tests {
+    use std::time::Instant;
+
     use crate::endpoints::Endpoints;
 
     #[test]
@@ -209,4 +277,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_endpoints_skip_unhealthy() -> anyhow::Result<()> {
+        let now = Instant::now();
+
+        let mut es = Endpoints::new(["a", "b", "c"]);
+        es.report_failure("b", now);
+        assert_eq!(1, es.status("b").unwrap().failures());
+        assert!(!es.status("b").unwrap().is_healthy(now));
+
+        assert_eq!("a", es.choose_next());
+        assert_eq!("c", es.choose_next(), "b is skipped");
+        assert_eq!(Some("a"), es.choose_other("c"));
+        assert_eq!(Some("c"), es.choose_other("a"));
+
+        // Health state is kept when nodes are replaced.
+        es.replace_nodes(["b", "c"]);
+        assert!(!es.status("b").unwrap().is_healthy(now));
+
+        // When no node is healthy, choose the next one.
+        es.report_failure("c", now);
+        assert_eq!(None, es.choose_other("b"));
+        assert_eq!("b", es.choose_next());
+
+        es.report_success("b");
+        assert_eq!(0, es.status("b").unwrap().failures());
+        assert_eq!(Some("b"), es.choose_other("c"));
+
+        Ok(())
+    }
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Instant;
 
 use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::protobuf::meta_service_client::MetaServiceClient;
@@ -40,6 +41,7 @@ use tonic::Response;
 use tonic::Status;
 
 use crate::endpoints::Endpoints;
+use crate::grpc_client::status_is_retryable;
 use crate::grpc_client::AuthInterceptor;
 use crate::grpc_client::RealClient;
 
@@ -52,8 +54,14 @@ impl<T> HandleRPCResult<T> for Result<Response<T>, Status> {
     fn update_client(self, client: &mut EstablishedClient) -> Result<Response<T>, Status> {
         // - Set the `current` node in endpoints to the leader if the request is forwarded by a follower to a leader.
         // - Store the error if received an error.
+        // - Update the health state of the target node.
 
         self.map(|response| {
+            client
+                .endpoints
+                .lock()
+                .report_success(&client.target_endpoint);
+
             let forwarded_leader = GrpcHelper::get_response_meta_leader(&response);
 
             // `leader` is set iff the request is forwarded by a follower to a leader
@@ -84,6 +92,12 @@ impl<T> HandleRPCResult<T> for Result<Response<T>, Status> {
             response
         })
         .map_err(|status| {
+            if status_is_retryable(&status) {
+                client
+                    .endpoints
+                    .lock()
+                    .report_failure(&client.target_endpoint, Instant::now());
+            }
             client.set_error(status.clone());
             status
         })
@@ -177,6 +191,13 @@ impl EstablishedClient {
         self.client.export_v1(request).await.update_client(self)
     }
 
+    pub async fn backup(
+        &mut self,
+        request: impl tonic::IntoRequest<pb::BackupRequest>,
+    ) -> Result<Response<Streaming<ExportedChunk>>, Status> {
+        self.client.backup(request).await.update_client(self)
+    }
+
     pub async fn watch(
         &mut self,
         request: impl tonic::IntoRequest<WatchRequest>,
@@ -30,6 +30,7 @@ use databend_common_base::base::tokio::sync::oneshot;
 use databend_common_base::base::tokio::sync::oneshot::Receiver as OneRecv;
 use databend_common_base::base::tokio::sync::oneshot::Sender as OneSend;
 use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::tokio::time::timeout;
 use databend_common_base::containers::ItemManager;
 use databend_common_base::containers::Pool;
 use databend_common_base::future::TimingFutureExt;
@@ -63,6 +64,8 @@ use databend_common_meta_types::MetaHandshakeError;
 use databend_common_meta_types::MetaNetworkError;
 use databend_common_meta_types::TxnReply;
 use databend_common_meta_types::TxnRequest;
+use futures::future::select_ok;
+use futures::future::BoxFuture;
 use futures::stream::StreamExt;
 use log::debug;
 use log::error;
@@ -74,9 +77,11 @@ use minitrace::Span;
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
 use prost::Message;
+use rand::Rng;
 use semver::Version;
 use serde::de::DeserializeOwned;
 use tonic::async_trait;
+use tonic::codec::Streaming;
 use tonic::codegen::BoxStream;
 use tonic::codegen::InterceptedService;
 use tonic::metadata::MetadataValue;
@@ -84,6 +89,7 @@ use tonic::service::Interceptor;
 use tonic::transport::Channel;
 use tonic::Code;
 use tonic::Request;
+use tonic::Response;
 use tonic::Status;
 
 use crate::endpoints::Endpoints;
@@ -99,7 +105,15 @@ use crate::MetaGrpcReq;
 use crate::METACLI_COMMIT_SEMVER;
 use crate::MIN_METASRV_SEMVER;
 
-const RPC_RETRIES: usize = 2;
+const RPC_RETRIES: usize = 6;
+
+/// The base and the max time to wait before retrying a failed request.
+const RETRY_BACKOFF_BASE: Duration = Duration::from_millis(100);
+const RETRY_BACKOFF_MAX: Duration = Duration::from_secs(2);
+
+/// A read request that does not respond in this time is sent to another node too.
+const HEDGE_READ_AFTER: Duration = Duration::from_millis(500);
+
 const AUTH_TOKEN_KEY: &str = "auth-token-bin";
 
 pub(crate) type RealClient = MetaServiceClient<InterceptedService<Channel, AuthInterceptor>>;
@@ -658,6 +672,7 @@ impl MetaGrpcClient {
                         addr, client_err
                     );
                     grpc_metrics::incr_meta_grpc_make_client_fail(&addr);
+                    self.endpoints.lock().report_failure(&addr, Instant::now());
                     self.choose_next_endpoint();
                     last_err = Some(client_err);
                     continue;
@@ -956,6 +971,8 @@ impl MetaGrpcClient {
         let mut failures = vec![];
 
         for i in 0..RPC_RETRIES {
+            Self::backoff(i).await;
+
             let mut client = self
                 .make_established_client()
                 .timed_ge(threshold(), info_spent("MetaGrpcClient::make_client"))
@@ -1021,16 +1038,17 @@ impl MetaGrpcClient {
         let mut failures = vec![];
 
         for i in 0..RPC_RETRIES {
-            let mut client = self
+            Self::backoff(i).await;
+
+            let client = self
                 .make_established_client()
                 .timed_ge(threshold(), info_spent("MetaGrpcClient::make_client"))
                 .await?;
 
             let raft_req: RaftRequest = grpc_req.clone().into();
-            let req = traced_req(raft_req.clone());
 
-            let result = client
-                .kv_read_v1(req)
+            let result = self
+                .hedged_kv_read_v1(client, &raft_req)
                 .timed_ge(threshold(), info_spent("client::kv_read_v1"))
                 .await;
 
@@ -1083,36 +1101,126 @@ impl MetaGrpcClient {
             "MetaGrpcClient::transaction request"
         );
 
-        let req: Request<TxnRequest> = Request::new(txn.clone());
-        let req = databend_common_tracing::inject_span_to_tonic_request(req);
+        let mut failures = vec![];
 
-        let mut client = self.make_established_client().await?;
-        let result = client.transaction(req).await;
+        for i in 0..RPC_RETRIES {
+            Self::backoff(i).await;
 
-        let result: Result<TxnReply, Status> = match result {
-            Ok(r) => return Ok(r.into_inner()),
-            Err(s) => {
-                if status_is_retryable(&s) {
-                    self.choose_next_endpoint();
-                    let mut client = self.make_established_client().await?;
-                    let req: Request<TxnRequest> = Request::new(txn);
-                    let req = databend_common_tracing::inject_span_to_tonic_request(req);
-                    let ret = client.transaction(req).await?.into_inner();
-                    return Ok(ret);
-                } else {
-                    Err(s)
+            let mut client = self.make_established_client().await?;
+
+            let req = traced_req(txn.clone());
+            let result = client.transaction(req).await;
+
+            let status = match result {
+                Ok(r) => {
+                    let reply = r.into_inner();
+
+                    debug!(
+                        reply :% =(&reply);
+                        "MetaGrpcClient::transaction reply"
+                    );
+
+                    return Ok(reply);
                 }
+                Err(status) => status,
+            };
+
+            if !status_is_retryable(&status) {
+                return Err(status.into());
             }
+
+            warn!(
+                req :% =(&txn),
+                error :? =(&status);
+                "MetaGrpcClient::transaction error is retryable, {}-th try", i
+            );
+
+            self.choose_next_endpoint();
+            failures.push(status);
+        }
+
+        let net_err = MetaNetworkError::ConnectionError(ConnectionError::new(
+            AnyError::error(format_args!(
+                "failed after {} retries: {:?}",
+                RPC_RETRIES, failures
+            )),
+            "failed to connect to meta-service",
+        ));
+
+        Err(net_err.into())
+    }
+
+    /// Send a read request, and if it does not respond in [`HEDGE_READ_AFTER`],
+    /// send the same request to another healthy node.
+    ///
+    /// The first successful response is returned. Only an idempotent request can be hedged.
+    async fn hedged_kv_read_v1(
+        &self,
+        client: EstablishedClient,
+        raft_req: &RaftRequest,
+    ) -> Result<Response<Streaming<pb::StreamItem>>, Status> {
+        let target = client.target_endpoint().to_string();
+        let mut primary = Self::boxed_kv_read_v1(client, raft_req);
+
+        if let Ok(res) = timeout(HEDGE_READ_AFTER, &mut primary).await {
+            return res;
+        }
+
+        let other = {
+            let es = self.endpoints.lock();
+            es.choose_other(&target).map(|x| x.to_string())
         };
 
-        let reply = result?;
+        let Some(addr) = other else {
+            return primary.await;
+        };
 
-        debug!(
-            reply :% =(&reply);
-            "MetaGrpcClient::transaction reply"
+        let hedge_client = match self.conn_pool.get(&addr).await {
+            Ok(c) => c,
+            Err(e) => {
+                warn!(
+                    "failed to build client to {} for hedged read: {:?}",
+                    addr, e
+                );
+                return primary.await;
+            }
+        };
+
+        info!(
+            "kv_read_v1 to {} does not respond in {:?}, send hedged request to {}",
+            target, HEDGE_READ_AFTER, addr
         );
+        grpc_metrics::incr_meta_grpc_client_hedged_request(&addr);
+
+        let hedge = Self::boxed_kv_read_v1(hedge_client, raft_req);
+        let (res, _rest) = select_ok([primary, hedge]).await?;
+        Ok(res)
+    }
+
+    fn boxed_kv_read_v1(
+        mut client: EstablishedClient,
+        raft_req: &RaftRequest,
+    ) -> BoxFuture<'static, Result<Response<Streaming<pb::StreamItem>>, Status>> {
+        let req = traced_req(raft_req.clone());
+        Box::pin(async move { client.kv_read_v1(req).await })
+    }
+
+    /// Wait before the `i`-th try of a request.
+    ///
+    /// The first retry is sent at once to the next node, which is likely to be the leader.
+    /// The following retries wait for an exponentially increasing time with jitter,
+    /// so that clients do not retry at the same time during a leader election.
+    async fn backoff(i: usize) {
+        if i <= 1 {
+            return;
+        }
+
+        let d = RETRY_BACKOFF_BASE * 2u32.pow((i - 2).min(8) as u32);
+        let ms = d.min(RETRY_BACKOFF_MAX).as_millis() as u64;
+        let d = Duration::from_millis(rand::thread_rng().gen_range(ms / 2..=ms));
 
-        Ok(reply)
+        debug!("MetaGrpcClient backoff {:?} before {}-th try", d, i);
+        sleep(d).await;
     }
 
     fn get_current_endpoint(&self) -> Option<String> {
@@ -1132,7 +1240,7 @@ fn traced_req<T>(t: T) -> Request<T> {
     databend_common_tracing::inject_span_to_tonic_request(req)
 }
 
-fn status_is_retryable(status: &Status) -> bool {
+pub(crate) fn status_is_retryable(status: &Status) -> bool {
     matches!(
         status.code(),
         Code::Unauthenticated | Code::Unavailable | Code::Internal
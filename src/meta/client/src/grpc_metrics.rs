@@ -41,6 +41,9 @@ pub static META_GRPC_CLIENT_REQUEST_FAILED: LazyLock<FamilyCounter<VecLabels>> =
 pub static META_GRPC_MAKE_CLIENT_FAIL: LazyLock<FamilyCounter<VecLabels>> =
     LazyLock::new(|| register_counter_family("meta_grpc_make_client_fail"));
 
+pub static META_GRPC_CLIENT_HEDGED_REQUEST: LazyLock<FamilyCounter<VecLabels>> =
+    LazyLock::new(|| register_counter_family("meta_grpc_client_hedged_request"));
+
 const LABEL_ENDPOINT: &str = "endpoint";
 const LABEL_REQUEST: &str = "request";
 const LABEL_ERROR: &str = "error";
@@ -90,3 +93,8 @@ pub fn incr_meta_grpc_make_client_fail(endpoint: &str) {
     let labels = vec![(LABEL_ENDPOINT, endpoint.to_string())];
     META_GRPC_MAKE_CLIENT_FAIL.get_or_create(&labels).inc();
 }
+
+pub fn incr_meta_grpc_client_hedged_request(endpoint: &str) {
+    let labels = vec![(LABEL_ENDPOINT, endpoint.to_string())];
+    META_GRPC_CLIENT_HEDGED_REQUEST.get_or_create(&labels).inc();
+}
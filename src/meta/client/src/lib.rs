@@ -108,6 +108,13 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 /// - 2024-03-01: since: TODO(update me when merged):
 ///   client: `MetaSpec` use `ttl`, remove `expire_at`, require 1.2.258
 ///
+/// - 2024-03-20: since: TODO(update me when merged):
+///   server: add backup() to build a full or incremental logical backup of the key-values;
+///
+/// - 2024-03-22: since: TODO(update me when merged):
+///   server: add `WatchRequest.prefixes` and `WatchRequest.start_revision`,
+///   `WatchResponse.revision` and `WatchResponse.compacted`, to watch multiple prefixes and resume a watch;
+///
 /// Server feature set:
 /// ```yaml
 /// server_features:
@@ -105,6 +105,16 @@ impl MetaService for GrpcServiceForTestImpl {
         unimplemented!()
     }
 
+    type BackupStream =
+        Pin<Box<dyn Stream<Item = Result<ExportedChunk, tonic::Status>> + Send + 'static>>;
+
+    async fn backup(
+        &self,
+        _request: Request<databend_common_meta_types::protobuf::BackupRequest>,
+    ) -> Result<Response<Self::BackupStream>, Status> {
+        unimplemented!()
+    }
+
     type WatchStream =
         Pin<Box<dyn Stream<Item = Result<WatchResponse, tonic::Status>> + Send + 'static>>;
 
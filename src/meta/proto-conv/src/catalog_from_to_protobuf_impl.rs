@@ -19,6 +19,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use databend_common_meta_app::schema as mt;
 use databend_common_meta_app::schema::CatalogOption;
+use databend_common_meta_app::schema::CustomCatalogOption;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
@@ -67,6 +68,14 @@ impl FromToProto for mt::CatalogMeta {
                         )?),
                     })
                 }
+                pb::catalog_option::CatalogOption::Custom(v) => {
+                    reader_check_msg(v.ver, v.min_reader_ver)?;
+
+                    CatalogOption::Custom(CustomCatalogOption {
+                        catalog_type: v.catalog_type,
+                        options: v.options,
+                    })
+                }
             },
             created_on: DateTime::<Utc>::from_pb(p.created_on)?,
         };
@@ -103,6 +112,16 @@ impl FromToProto for mt::CatalogMeta {
                         },
                     )),
                 }),
+                CatalogOption::Custom(v) => Some(pb::CatalogOption {
+                    catalog_option: Some(pb::catalog_option::CatalogOption::Custom(
+                        pb::CustomCatalogOption {
+                            ver: VER,
+                            min_reader_ver: MIN_READER_VER,
+                            catalog_type: v.catalog_type,
+                            options: v.options,
+                        },
+                    )),
+                }),
             },
             created_on: self.created_on.to_pb()?,
         };
@@ -20,6 +20,7 @@ use std::str::FromStr;
 use databend_common_meta_app as mt;
 use databend_common_meta_app::principal::BinaryFormat;
 use databend_common_meta_app::principal::EmptyFieldAs;
+use databend_common_meta_app::principal::ExtraField;
 use databend_common_meta_app::principal::NullAs;
 use databend_common_protos::pb;
 use num::FromPrimitive;
@@ -44,6 +45,7 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             pb::StageFileFormatType::Orc => Ok(mt::principal::StageFileFormatType::Orc),
             pb::StageFileFormatType::Parquet => Ok(mt::principal::StageFileFormatType::Parquet),
             pb::StageFileFormatType::Xml => Ok(mt::principal::StageFileFormatType::Xml),
+            pb::StageFileFormatType::Xlsx => Ok(mt::principal::StageFileFormatType::Xlsx),
         }
     }
 
@@ -57,6 +59,7 @@ impl FromToProtoEnum for mt::principal::StageFileFormatType {
             mt::principal::StageFileFormatType::Orc => Ok(pb::StageFileFormatType::Orc),
             mt::principal::StageFileFormatType::Parquet => Ok(pb::StageFileFormatType::Parquet),
             mt::principal::StageFileFormatType::Xml => Ok(pb::StageFileFormatType::Xml),
+            mt::principal::StageFileFormatType::Xlsx => Ok(pb::StageFileFormatType::Xlsx),
             mt::principal::StageFileFormatType::None => Err(Incompatible {
                 reason: "StageFileFormatType::None cannot be converted to protobuf".to_string(),
             }),
@@ -244,6 +247,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::from_pb(p)?,
                 ))
             }
+            Some(pb::file_format_params::Format::Avro(p)) => {
+                Ok(mt::principal::FileFormatParams::Avro(
+                    mt::principal::AvroFileFormatParams::from_pb(p)?,
+                ))
+            }
+            Some(pb::file_format_params::Format::Xlsx(p)) => {
+                Ok(mt::principal::FileFormatParams::Xlsx(
+                    mt::principal::XlsxFileFormatParams::from_pb(p)?,
+                ))
+            }
             None => Err(Incompatible {
                 reason: "FileFormatParams.format cannot be None".to_string(),
             }),
@@ -282,6 +295,16 @@ impl FromToProto for mt::principal::FileFormatParams {
                     mt::principal::XmlFileFormatParams::to_pb(p)?,
                 )),
             }),
+            Self::Avro(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Avro(
+                    mt::principal::AvroFileFormatParams::to_pb(p)?,
+                )),
+            }),
+            Self::Xlsx(p) => Ok(Self::PB {
+                format: Some(pb::file_format_params::Format::Xlsx(
+                    mt::principal::XlsxFileFormatParams::to_pb(p)?,
+                )),
+            }),
         }
     }
 }
@@ -323,11 +346,21 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
             })?,
         )?;
 
+        let extra_field = p
+            .extra_field
+            .map(|s| ExtraField::from_str(&s))
+            .transpose()
+            .map_err(|e| Incompatible {
+                reason: format!("{:?}", e),
+            })?
+            .unwrap_or_default();
+
         mt::principal::NdJsonFileFormatParams::try_create(
             compression,
             p.missing_field_as.as_deref(),
             p.null_field_as.as_deref(),
             p.null_if,
+            extra_field,
         )
         .map_err(|e| Incompatible {
             reason: format!("{e}"),
@@ -344,6 +377,79 @@ impl FromToProto for mt::principal::NdJsonFileFormatParams {
             missing_field_as: Some(self.missing_field_as.to_string()),
             null_field_as: Some(self.null_field_as.to_string()),
             null_if: self.null_if.clone(),
+            extra_field: Some(self.extra_field.to_string()),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::AvroFileFormatParams {
+    type PB = pb::AvroFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::AvroFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        let compression = mt::principal::StageFileCompression::from_pb_enum(
+            FromPrimitive::from_i32(p.compression).ok_or_else(|| Incompatible {
+                reason: format!("invalid StageFileCompression: {}", p.compression),
+            })?,
+        )?;
+
+        let extra_field = p
+            .extra_field
+            .map(|s| ExtraField::from_str(&s))
+            .transpose()
+            .map_err(|e| Incompatible {
+                reason: format!("{:?}", e),
+            })?
+            .unwrap_or_default();
+
+        mt::principal::AvroFileFormatParams::try_create(
+            compression,
+            p.missing_field_as.as_deref(),
+            extra_field,
+        )
+        .map_err(|e| Incompatible {
+            reason: format!("{e}"),
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::AvroFileFormatParams, Incompatible> {
+        let compression =
+            mt::principal::StageFileCompression::to_pb_enum(&self.compression)? as i32;
+        Ok(pb::AvroFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            compression,
+            missing_field_as: Some(self.missing_field_as.to_string()),
+            extra_field: Some(self.extra_field.to_string()),
+        })
+    }
+}
+
+impl FromToProto for mt::principal::XlsxFileFormatParams {
+    type PB = pb::XlsxFileFormatParams;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+
+    fn from_pb(p: pb::XlsxFileFormatParams) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(Self {
+            sheet_name: p.sheet_name,
+            headers: p.headers,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::XlsxFileFormatParams, Incompatible> {
+        Ok(pb::XlsxFileFormatParams {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            sheet_name: self.sheet_name.clone(),
+            headers: self.headers,
         })
     }
 }
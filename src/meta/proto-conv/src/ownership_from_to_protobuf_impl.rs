@@ -82,6 +82,9 @@ impl FromToProto for mt::principal::OwnershipObject {
             Some(pb::ownership_object::Object::Stage(
                 pb::ownership_object::OwnershipStageObject { stage },
             )) => Ok(mt::principal::OwnershipObject::Stage { name: stage }),
+            Some(pb::ownership_object::Object::Procedure(
+                pb::ownership_object::OwnershipProcedureObject { procedure },
+            )) => Ok(mt::principal::OwnershipObject::Procedure { name: procedure }),
             _ => Err(Incompatible {
                 reason: "OwnershipObject cannot be None".to_string(),
             }),
@@ -120,6 +123,13 @@ impl FromToProto for mt::principal::OwnershipObject {
                     stage: name.clone(),
                 }),
             ),
+            mt::principal::OwnershipObject::Procedure { name } => {
+                Some(pb::ownership_object::Object::Procedure(
+                    pb::ownership_object::OwnershipProcedureObject {
+                        procedure: name.clone(),
+                    },
+                ))
+            }
         };
         Ok(pb::OwnershipObject {
             ver: VER,
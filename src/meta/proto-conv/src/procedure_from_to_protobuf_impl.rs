@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+
+use crate::missing;
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::ProcedureInfo {
+    type PB = pb::ProcedureInfo;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: Self::PB) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        if p.arg_names.len() != p.arg_types.len() {
+            return Err(Incompatible {
+                reason: format!(
+                    "ProcedureInfo has {} argument names but {} argument types",
+                    p.arg_names.len(),
+                    p.arg_types.len()
+                ),
+            });
+        }
+
+        Ok(Self {
+            name: p.name,
+            arg_names: p.arg_names,
+            arg_types: p.arg_types,
+            return_type: p.return_type,
+            script: p.script,
+            execute_as: if p.execute_as_caller {
+                mt::ProcedureExecuteAs::Caller
+            } else {
+                mt::ProcedureExecuteAs::Owner
+            },
+            comment: p.comment,
+            owner: mt::UserIdentity::from_pb(p.owner.ok_or_else(missing("ProcedureInfo.owner"))?)?,
+            owner_role: p.owner_role,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+            updated_on: match p.updated_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<Self::PB, Incompatible> {
+        Ok(Self::PB {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            arg_names: self.arg_names.clone(),
+            arg_types: self.arg_types.clone(),
+            return_type: self.return_type.clone(),
+            script: self.script.clone(),
+            execute_as_caller: self.execute_as == mt::ProcedureExecuteAs::Caller,
+            comment: self.comment.clone(),
+            owner: Some(self.owner.to_pb()?),
+            created_on: self.created_on.to_pb()?,
+            updated_on: match &self.updated_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+            owner_role: self.owner_role.clone(),
+        })
+    }
+}
@@ -262,6 +262,11 @@ impl FromToProto for ex::TableDataType {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
                     Dt24::EmptyMapT(_) => ex::TableDataType::EmptyMap,
+                    Dt24::VectorT(v) => {
+                        reader_check_msg(v.ver, v.min_reader_ver)?;
+                        ex::TableDataType::Vector(v.dimension)
+                    }
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
                 };
                 Ok(x)
             }
@@ -324,6 +329,12 @@ impl FromToProto for ex::TableDataType {
             }
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
+            TableDataType::Vector(dimension) => new_pb_dt24(Dt24::VectorT(pb::Vector {
+                ver: VER,
+                min_reader_ver: MIN_READER_VER,
+                dimension: *dimension,
+            })),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
         };
         Ok(x)
     }
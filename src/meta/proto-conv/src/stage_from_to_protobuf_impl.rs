@@ -226,6 +226,7 @@ impl FromToProto for mt::principal::StageInfo {
                 Some(c) => DateTime::<Utc>::from_pb(c)?,
                 None => DateTime::<Utc>::default(),
             },
+            directory_enabled: p.directory_enabled,
         })
     }
 
@@ -248,6 +249,7 @@ impl FromToProto for mt::principal::StageInfo {
                 None => None,
             },
             created_on: Some(self.created_on.to_pb()?),
+            directory_enabled: self.directory_enabled,
         })
     }
 }
@@ -231,6 +231,11 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            view_dependencies: p
+                .view_dependencies
+                .into_iter()
+                .map(mt::ViewDependency::from_pb)
+                .collect::<Result<Vec<_>, _>>()?,
         };
         Ok(v)
     }
@@ -272,6 +277,11 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            view_dependencies: self
+                .view_dependencies
+                .iter()
+                .map(|dep| dep.to_pb())
+                .collect::<Result<Vec<_>, _>>()?,
         };
         Ok(p)
     }
@@ -365,3 +375,31 @@ impl FromToProto for mt::TableIndex {
         Ok(p)
     }
 }
+
+impl FromToProto for mt::ViewDependency {
+    type PB = pb::ViewDependency;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::ViewDependency) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            catalog: p.catalog,
+            database: p.database,
+            table: p.table,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::ViewDependency, Incompatible> {
+        let p = pb::ViewDependency {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            catalog: self.catalog.clone(),
+            database: self.database.clone(),
+            table: self.table.clone(),
+        };
+        Ok(p)
+    }
+}
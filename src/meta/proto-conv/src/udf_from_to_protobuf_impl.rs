@@ -164,6 +164,58 @@ impl FromToProto for mt::UDFScript {
     }
 }
 
+impl FromToProto for mt::UDFModel {
+    type PB = pb::UdfModel;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::UdfModel) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let mut arg_types = Vec::with_capacity(p.arg_types.len());
+        for arg_type in p.arg_types {
+            let arg_type = DataType::from(&TableDataType::from_pb(arg_type)?);
+            arg_types.push(arg_type);
+        }
+        let return_type = DataType::from(&TableDataType::from_pb(p.return_type.ok_or_else(
+            || Incompatible {
+                reason: "UDFModel.return_type can not be None".to_string(),
+            },
+        )?)?);
+
+        Ok(mt::UDFModel {
+            location: p.location,
+            arg_types,
+            return_type,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::UdfModel, Incompatible> {
+        let mut arg_types = Vec::with_capacity(self.arg_types.len());
+        for arg_type in self.arg_types.iter() {
+            let arg_type = infer_schema_type(arg_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            arg_types.push(arg_type);
+        }
+        let return_type = infer_schema_type(&self.return_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::UdfModel {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            location: self.location.clone(),
+            arg_types,
+            return_type: Some(return_type),
+        })
+    }
+}
+
 impl FromToProto for mt::UserDefinedFunction {
     type PB = pb::UserDefinedFunction;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -181,6 +233,9 @@ impl FromToProto for mt::UserDefinedFunction {
             Some(pb::user_defined_function::Definition::UdfScript(udf_script)) => {
                 mt::UDFDefinition::UDFScript(mt::UDFScript::from_pb(udf_script)?)
             }
+            Some(pb::user_defined_function::Definition::UdfModel(udf_model)) => {
+                mt::UDFDefinition::UDFModel(mt::UDFModel::from_pb(udf_model)?)
+            }
             None => {
                 return Err(Incompatible {
                     reason: "UserDefinedFunction.definition cannot be None".to_string(),
@@ -210,6 +265,9 @@ impl FromToProto for mt::UserDefinedFunction {
             mt::UDFDefinition::UDFScript(udf_script) => {
                 pb::user_defined_function::Definition::UdfScript(udf_script.to_pb()?)
             }
+            mt::UDFDefinition::UDFModel(udf_model) => {
+                pb::user_defined_function::Definition::UdfModel(udf_model.to_pb()?)
+            }
         };
 
         Ok(pb::UserDefinedFunction {
@@ -117,6 +117,18 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (85, "2024-03-26: Add: table.inverted_index sync_creation"),
     (86, "2024-04-01: Add: table.inverted_index version, options"),
     (87, "2024-04-17: Add: UserOption::disabled"),
+    (88, "2024-04-22: Add: udf.proto/UDFModel"),
+    (89, "2024-04-24: Add: datatype.proto/Vector"),
+    (90, "2024-04-26: Add: datatype.proto/IntervalT"),
+    (91, "2024-04-29: Add: catalog.proto/CustomCatalogOption"),
+    (92, "2024-05-06: Add: stage.proto/StageInfo::directory_enabled"),
+    (93, "2024-05-07: Add: pipe.proto/PipeInfo"),
+    (94, "2024-05-08: Add: file_format.proto/NdJsonFileFormatParams::extra_field"),
+    (95, "2024-05-09: Add: file_format.proto/AvroFileFormatParams"),
+    (96, "2024-05-10: Add: file_format.proto/XlsxFileFormatParams"),
+    (97, "2024-05-11: Add: procedure.proto/ProcedureInfo"),
+    (98, "2024-05-12: Add: table.proto/TableMeta::view_dependencies"),
+    (99, "2024-05-13: Add: user.proto/GrantProcedureObject, ownership.proto/OwnershipProcedureObject, procedure.proto/ProcedureInfo::owner_role"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
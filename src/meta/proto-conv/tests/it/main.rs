@@ -90,3 +90,15 @@ mod v084_background_task_creator;
 mod v085_table_index;
 mod v086_table_index;
 mod v087_user_option_disabled;
+mod v088_udf_model;
+mod v089_vector_datatype;
+mod v090_interval_datatype;
+mod v091_custom_catalog_option;
+mod v092_stage_directory;
+mod v093_pipe;
+mod v094_ndjson_extra_field;
+mod v095_avro_file_format_params;
+mod v096_xlsx_file_format_params;
+mod v097_procedure;
+mod v098_view_dependencies;
+mod v099_procedure_privilege;
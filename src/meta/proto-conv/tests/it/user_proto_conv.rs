@@ -110,6 +110,7 @@ pub(crate) fn test_fs_stage_info() -> mt::principal::StageInfo {
             hostname: "databend.rs".to_string(),
         }),
         created_on: Utc::now(),
+        directory_enabled: false,
     }
 }
 
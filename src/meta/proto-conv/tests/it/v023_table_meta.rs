@@ -148,6 +148,7 @@ fn test_decode_v23_table_meta() -> anyhow::Result<()> {
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
         indexes: btreemap! {},
+        view_dependencies: vec![],
     };
 
     common::test_pb_from_to(func_name!(), want())?;
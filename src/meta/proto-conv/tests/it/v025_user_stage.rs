@@ -77,6 +77,7 @@ fn test_decode_v25_user_stage() -> anyhow::Result<()> {
             hostname: "databend.rs".to_string(),
         }),
         created_on: DateTime::<Utc>::default(),
+        directory_enabled: false,
     };
     common::test_load_old(func_name!(), stage_info_v25.as_slice(), 25, want())?;
     common::test_pb_from_to(func_name!(), want())?;
@@ -14,6 +14,7 @@
 
 use databend_common_meta_app as mt;
 use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::ExtraField;
 use databend_common_meta_app::principal::NullAs;
 use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::TsvFileFormatParams;
@@ -94,6 +95,7 @@ fn test_decode_v32_ndjson_file_format_params() -> anyhow::Result<()> {
             missing_field_as: NullAs::Error,
             null_field_as: NullAs::Null,
             null_if: vec![],
+            extra_field: ExtraField::Ignore,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
@@ -150,6 +150,7 @@ fn test_decode_v33_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: None,
         indexes: btreemap! {},
+        view_dependencies: vec![],
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -74,6 +74,7 @@ fn test_decode_v35_user_stage() -> anyhow::Result<()> {
             hostname: "databend.rs".to_string(),
         }),
         created_on: DateTime::<Utc>::default(),
+        directory_enabled: false,
     };
     common::test_load_old(func_name!(), stage_info_v35.as_slice(), 35, want())?;
     common::test_pb_from_to(func_name!(), want())?;
@@ -77,6 +77,7 @@ fn test_decode_v42_s3_stage_new_field() -> anyhow::Result<()> {
             hostname: "databend.rs".to_string(),
         }),
         created_on: DateTime::<Utc>::default(),
+        directory_enabled: false,
     };
 
     common::test_load_old(func_name!(), stage_info_v42.as_slice(), 42, want())?;
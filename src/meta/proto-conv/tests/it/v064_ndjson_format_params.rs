@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::ExtraField;
 use databend_common_meta_app::principal::NdJsonFileFormatParams;
 use databend_common_meta_app::principal::NullAs;
 use databend_common_meta_app::principal::StageFileCompression;
@@ -42,6 +43,7 @@ fn test_decode_v64_ndjson_file_format_params() -> anyhow::Result<()> {
             missing_field_as: NullAs::FieldDefault,
             null_field_as: NullAs::Null,
             null_if: vec![],
+            extra_field: ExtraField::Ignore,
         })
     };
     common::test_pb_from_to(func_name!(), want())?;
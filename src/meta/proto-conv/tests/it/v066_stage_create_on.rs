@@ -77,6 +77,7 @@ fn test_decode_v66_stage() -> anyhow::Result<()> {
             hostname: "%".to_string(),
         }),
         created_on: DateTime::<Utc>::from_timestamp(1702603569, 0).unwrap(),
+        directory_enabled: false,
     };
     common::test_pb_from_to(func_name!(), want())?;
     common::test_load_old(func_name!(), stage_info_v66.as_slice(), 66, want())?;
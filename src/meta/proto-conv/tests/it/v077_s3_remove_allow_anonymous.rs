@@ -79,6 +79,7 @@ fn test_v077_s3_remove_allow_anonymous() -> anyhow::Result<()> {
             hostname: "databend.rs".to_string(),
         }),
         created_on: DateTime::<Utc>::default(),
+        directory_enabled: false,
     };
 
     common::test_load_old(func_name!(), stage_info_v77.as_slice(), 77, want())?;
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_meta_app::principal::ExtraField;
 use databend_common_meta_app::principal::NdJsonFileFormatParams;
 use databend_common_meta_app::principal::NullAs;
 use databend_common_meta_app::principal::StageFileCompression;
@@ -40,6 +41,7 @@ fn test_decode_v83_ndjson_file_format_params() -> anyhow::Result<()> {
         missing_field_as: NullAs::FieldDefault,
         null_field_as: NullAs::FieldDefault,
         null_if: vec!["".to_string()],
+        extra_field: ExtraField::Ignore,
     };
     common::test_load_old(
         func_name!(),
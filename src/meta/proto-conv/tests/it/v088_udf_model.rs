@@ -0,0 +1,70 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_meta_app::principal::UDFDefinition;
+use databend_common_meta_app::principal::UDFModel;
+use databend_common_meta_app::principal::UserDefinedFunction;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v88_udf_model() -> anyhow::Result<()> {
+    let bytes = vec![
+        10, 12, 105, 114, 105, 115, 95, 112, 114, 101, 100, 105, 99, 116, 18, 21, 84, 104, 105,
+        115, 32, 105, 115, 32, 97, 32, 100, 101, 115, 99, 114, 105, 112, 116, 105, 111, 110, 58,
+        164, 1, 10, 16, 109, 111, 100, 101, 108, 115, 47, 105, 114, 105, 115, 46, 111, 110, 110,
+        120, 18, 26, 178, 2, 17, 154, 2, 8, 74, 0, 160, 6, 88, 168, 6, 24, 160, 6, 88, 168, 6, 24,
+        160, 6, 88, 168, 6, 24, 18, 26, 178, 2, 17, 154, 2, 8, 74, 0, 160, 6, 88, 168, 6, 24, 160,
+        6, 88, 168, 6, 24, 160, 6, 88, 168, 6, 24, 18, 26, 178, 2, 17, 154, 2, 8, 74, 0, 160, 6,
+        88, 168, 6, 24, 160, 6, 88, 168, 6, 24, 160, 6, 88, 168, 6, 24, 18, 26, 178, 2, 17, 154, 2,
+        8, 74, 0, 160, 6, 88, 168, 6, 24, 160, 6, 88, 168, 6, 24, 160, 6, 88, 168, 6, 24, 26, 26,
+        178, 2, 17, 154, 2, 8, 82, 0, 160, 6, 88, 168, 6, 24, 160, 6, 88, 168, 6, 24, 160, 6, 88,
+        168, 6, 24, 160, 6, 88, 168, 6, 24, 42, 23, 50, 48, 50, 52, 45, 48, 52, 45, 50, 50, 32, 48,
+        56, 58, 49, 49, 58, 51, 53, 32, 85, 84, 67, 160, 6, 88, 168, 6, 24,
+    ];
+
+    let want = || UserDefinedFunction {
+        name: "iris_predict".to_string(),
+        description: "This is a description".to_string(),
+        definition: UDFDefinition::UDFModel(UDFModel {
+            location: "models/iris.onnx".to_string(),
+            arg_types: vec![
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float32))),
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float32))),
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float32))),
+                DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float32))),
+            ],
+            return_type: DataType::Nullable(Box::new(DataType::Number(NumberDataType::Float64))),
+        }),
+        created_on: DateTime::<Utc>::from_timestamp(1713773495, 0).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 88, want())
+}
@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::UserIdentity;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_build_pb_buf()`
+#[test]
+fn test_decode_v93_pipe() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 5, 112, 105, 112, 101, 49, 18, 21, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 116, 49, 32,
+        70, 82, 79, 77, 32, 64, 115, 49, 24, 1, 32, 1, 42, 12, 112, 105, 112, 101, 32, 99, 111,
+        109, 109, 101, 110, 116, 50, 16, 10, 5, 117, 115, 101, 114, 49, 18, 1, 37, 160, 6, 93, 168,
+        6, 24, 58, 23, 50, 48, 50, 52, 45, 48, 53, 45, 48, 55, 32, 49, 48, 58, 48, 48, 58, 48, 48,
+        32, 85, 84, 67, 66, 23, 50, 48, 50, 52, 45, 48, 53, 45, 48, 55, 32, 49, 49, 58, 48, 48, 58,
+        48, 48, 32, 85, 84, 67, 160, 6, 93, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::principal::PipeInfo {
+        name: "pipe1".to_string(),
+        copy_stmt: "COPY INTO t1 FROM @s1".to_string(),
+        auto_ingest: true,
+        execution_paused: true,
+        comment: "pipe comment".to_string(),
+        creator: UserIdentity {
+            username: "user1".to_string(),
+            hostname: "%".to_string(),
+        },
+        created_on: Utc.with_ymd_and_hms(2024, 5, 7, 10, 0, 0).unwrap(),
+        updated_on: Some(Utc.with_ymd_and_hms(2024, 5, 7, 11, 0, 0).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 93, want())
+}
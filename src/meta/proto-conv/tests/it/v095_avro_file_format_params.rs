@@ -0,0 +1,51 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::AvroFileFormatParams;
+use databend_common_meta_app::principal::ExtraField;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageFileCompression;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v95_avro_file_format_params() -> anyhow::Result<()> {
+    let avro_file_format_params_v95 = vec![
+        8, 1, 18, 4, 78, 85, 76, 76, 26, 7, 86, 65, 82, 73, 65, 78, 84, 160, 6, 95, 168, 6, 24,
+    ];
+    let want = || AvroFileFormatParams {
+        compression: StageFileCompression::Gzip,
+        missing_field_as: NullAs::Null,
+        extra_field: ExtraField::Variant,
+    };
+    common::test_load_old(
+        func_name!(),
+        avro_file_format_params_v95.as_slice(),
+        95,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
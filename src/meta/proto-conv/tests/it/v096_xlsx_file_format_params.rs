@@ -0,0 +1,47 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::principal::XlsxFileFormatParams;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+#[test]
+fn test_decode_v96_xlsx_file_format_params() -> anyhow::Result<()> {
+    let xlsx_file_format_params_v96 = vec![
+        10, 6, 83, 104, 101, 101, 116, 50, 16, 1, 160, 6, 96, 168, 6, 24,
+    ];
+    let want = || XlsxFileFormatParams {
+        sheet_name: "Sheet2".to_string(),
+        headers: 1,
+    };
+    common::test_load_old(
+        func_name!(),
+        xlsx_file_format_params_v96.as_slice(),
+        96,
+        want(),
+    )?;
+    common::test_pb_from_to(func_name!(), want())?;
+    Ok(())
+}
@@ -0,0 +1,108 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app as mt;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::ProcedureExecuteAs;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use enumflags2::make_bitflags;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+
+#[test]
+fn test_decode_v99_procedure_grant_entry() -> anyhow::Result<()> {
+    let grant_entry_v99 = vec![
+        10, 12, 66, 4, 10, 2, 112, 49, 160, 6, 99, 168, 6, 24, 16, 1, 160, 6, 99, 168, 6, 24,
+    ];
+
+    let want = || {
+        mt::principal::GrantEntry::new(
+            mt::principal::GrantObject::Procedure("p1".to_string()),
+            make_bitflags!(UserPrivilegeType::{Usage}),
+        )
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), grant_entry_v99.as_slice(), 99, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v99_procedure_ownership() -> anyhow::Result<()> {
+    let ownership_info_v99 = vec![
+        10, 2, 114, 49, 18, 12, 42, 4, 10, 2, 112, 49, 160, 6, 99, 168, 6, 24, 160, 6, 99, 168, 6,
+        24,
+    ];
+
+    let want = || mt::principal::OwnershipInfo {
+        role: "r1".to_string(),
+        object: OwnershipObject::Procedure {
+            name: "p1".to_string(),
+        },
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), ownership_info_v99.as_slice(), 99, want())?;
+
+    Ok(())
+}
+
+#[test]
+fn test_decode_v99_procedure_owner_role() -> anyhow::Result<()> {
+    let procedure_v99 = vec![
+        10, 5, 112, 114, 111, 99, 49, 18, 1, 97, 18, 1, 98, 26, 5, 73, 110, 116, 51, 50, 26, 6, 83,
+        84, 82, 73, 78, 71, 34, 6, 83, 84, 82, 73, 78, 71, 42, 20, 66, 69, 71, 73, 78, 32, 82, 69,
+        84, 85, 82, 78, 32, 98, 59, 32, 69, 78, 68, 59, 58, 12, 112, 114, 111, 99, 32, 99, 111,
+        109, 109, 101, 110, 116, 66, 16, 10, 5, 117, 115, 101, 114, 49, 18, 1, 37, 160, 6, 99, 168,
+        6, 24, 74, 23, 50, 48, 50, 52, 45, 48, 53, 45, 49, 51, 32, 49, 48, 58, 48, 48, 58, 48, 48,
+        32, 85, 84, 67, 90, 5, 114, 111, 108, 101, 49, 160, 6, 99, 168, 6, 24,
+    ];
+
+    let want = || mt::principal::ProcedureInfo {
+        name: "proc1".to_string(),
+        arg_names: vec!["a".to_string(), "b".to_string()],
+        arg_types: vec!["Int32".to_string(), "STRING".to_string()],
+        return_type: "STRING".to_string(),
+        script: "BEGIN RETURN b; END;".to_string(),
+        execute_as: ProcedureExecuteAs::Owner,
+        comment: "proc comment".to_string(),
+        owner: UserIdentity {
+            username: "user1".to_string(),
+            hostname: "%".to_string(),
+        },
+        owner_role: Some("role1".to_string()),
+        created_on: Utc.with_ymd_and_hms(2024, 5, 13, 10, 0, 0).unwrap(),
+        updated_on: None,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), procedure_v99.as_slice(), 99, want())?;
+
+    Ok(())
+}
@@ -116,7 +116,7 @@ impl<'a> Applier<'a> {
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.sm.subscriber {
             for event in self.changes.drain(..) {
-                subscriber.kv_changed(event);
+                subscriber.kv_changed(log_id.index, event);
             }
         }
 
@@ -94,7 +94,10 @@ const TREE_STATE_MACHINE: &str = "state_machine";
 
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>);
+    /// Called when a key is changed by the raft log at index `revision`.
+    ///
+    /// One log may change several keys thus the changes may have the same revision.
+    fn kv_changed(&self, revision: u64, change: Change<Vec<u8>, String>);
 }
 
 /// The state machine of the `MemStore`.
@@ -314,7 +317,7 @@ impl StateMachine {
         // Send queued change events to subscriber
         if let Some(subscriber) = &self.subscriber {
             for event in changes {
-                subscriber.kv_changed(event);
+                subscriber.kv_changed(entry.log_id.index, event);
             }
         }
 
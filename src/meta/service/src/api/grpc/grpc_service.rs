@@ -386,6 +386,38 @@ impl MetaService for MetaServiceImpl {
         Ok(Response::new(Box::pin(s)))
     }
 
+    type BackupStream = Pin<Box<dyn Stream<Item = Result<ExportedChunk, Status>> + Send + 'static>>;
+
+    /// Build a logical backup of the key-values on this node.
+    ///
+    /// The backup is a series of JSON encoded strings of `BackupEntry`.
+    async fn backup(
+        &self,
+        request: Request<pb::BackupRequest>,
+    ) -> Result<Response<Self::BackupStream>, Status> {
+        self.check_token(request.metadata())?;
+        let _guard = RequestInFlight::guard();
+
+        let req = request.into_inner();
+        let lines = self
+            .meta_node
+            .backup(req.since_seq)
+            .await
+            .map_err(|e| Status::internal(format!("backup failed: {}", e)))?;
+
+        let chunk_size = req.chunk_size.unwrap_or(32).max(1) as usize;
+        let chunks = lines
+            .chunks(chunk_size)
+            .map(|chunk| {
+                Ok(ExportedChunk {
+                    data: chunk.to_vec(),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Response::new(Box::pin(futures::stream::iter(chunks))))
+    }
+
     type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send + 'static>>;
 
     #[minitrace::trace]
@@ -400,8 +432,12 @@ impl MetaService for MetaServiceImpl {
         let add_res = mn.add_watcher(request.into_inner(), tx).await;
 
         match add_res {
-            Ok(watcher) => {
+            Ok((watcher, initial)) => {
                 let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+
+                // The replayed changes are sent before the changes dispatched after the watcher is added.
+                let initial = futures::stream::iter(initial.into_iter().map(Ok));
+                let stream = initial.chain(stream);
                 Ok(Response::new(Box::pin(stream) as Self::WatchStream))
             }
             Err(e) => {
@@ -28,6 +28,7 @@ use databend_common_meta_types::MetaNetworkError;
 use futures::future::Either;
 use log::info;
 use minitrace::prelude::*;
+use tonic::transport::Certificate;
 use tonic::transport::Identity;
 use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
@@ -175,7 +176,12 @@ impl GrpcServer {
             let key = tokio::fs::read(conf.grpc_tls_server_key.as_str()).await?;
             let server_identity = Identity::from_pem(cert, key);
 
-            let tls = ServerTlsConfig::new().identity(server_identity);
+            let mut tls = ServerTlsConfig::new().identity(server_identity);
+            if !conf.grpc_tls_server_root_ca_cert.is_empty() {
+                let root_ca_cert =
+                    tokio::fs::read(conf.grpc_tls_server_root_ca_cert.as_str()).await?;
+                tls = tls.client_ca_root(Certificate::from_pem(root_ca_cert));
+            }
             Ok(Some(tls))
         } else {
             Ok(None)
@@ -0,0 +1,389 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Logical backup of the key-values in meta-service.
+//!
+//! A backup is a series of json lines of [`BackupEntry`], the first line is always a
+//! [`BackupHeader`]. Unlike the raft level export, a logical backup contains only the
+//! application data, and can be restored into a running meta-service of any data version.
+//!
+//! A full backup contains all of the non-expired records.
+//! An incremental backup is taken since the `seq` of a previous backup, it contains the values of
+//! the records updated after that `seq`, and only the keys of the other records,
+//! so that the records deleted since the previous backup are also restored.
+//!
+//! ```text
+//! {"Header":{"version":1,"seq":5,"since_seq":null,"time_ms":1711111111111}}
+//! {"KV":{"key":"foo","seq":1,"meta":null,"data":[102,111,111]}}
+//! {"Unchanged":{"key":"bar"}}
+//! ```
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io;
+
+use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
+use databend_common_meta_app::schema::DBIdTableName;
+use databend_common_meta_app::schema::DatabaseId;
+use databend_common_meta_app::schema::DatabaseIdToName;
+use databend_common_meta_app::schema::DbIdListKey;
+use databend_common_meta_app::schema::LeastVisibleTimeKey;
+use databend_common_meta_app::schema::TableCopiedFileNameIdent;
+use databend_common_meta_app::schema::TableId;
+use databend_common_meta_app::schema::TableIdListKey;
+use databend_common_meta_app::schema::TableIdToName;
+use databend_common_meta_kvapi::kvapi::Key;
+use databend_common_meta_kvapi::kvapi::KeyParser;
+use databend_common_meta_raft_store::sm_v002::leveled_store::sys_data_api::SysDataApiRO;
+use databend_common_meta_raft_store::sm_v002::SMV002;
+use databend_common_meta_types::KVMeta;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::SeqValue;
+use databend_common_meta_types::TxnCondition;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
+use futures::TryStreamExt;
+
+/// The version of the logical backup format.
+///
+/// It is increased when a backward incompatible change is made to [`BackupEntry`].
+/// A backup of a greater version can not be restored.
+pub const BACKUP_FORMAT_VERSION: u64 = 1;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BackupHeader {
+    /// The format version of this backup, see [`BACKUP_FORMAT_VERSION`].
+    pub version: u64,
+
+    /// The last seq of the state machine when this backup is taken.
+    pub seq: u64,
+
+    /// The seq of the previous backup this incremental backup is taken since.
+    /// It is `None` for a full backup.
+    pub since_seq: Option<u64>,
+
+    /// The time when this backup is taken, in milliseconds since 1970.
+    pub time_ms: u64,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum BackupEntry {
+    Header(BackupHeader),
+
+    /// A record with its value.
+    KV {
+        key: String,
+        seq: u64,
+        meta: Option<KVMeta>,
+        data: Vec<u8>,
+    },
+
+    /// A record that is not updated since the `since_seq` of an incremental backup.
+    Unchanged {
+        key: String,
+    },
+}
+
+impl BackupEntry {
+    pub fn to_line(&self) -> Result<String, io::Error> {
+        serde_json::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn from_line(line: &str) -> Result<Self, io::Error> {
+        serde_json::from_str(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Build a logical backup of the non-expired records in the state machine.
+///
+/// If `since_seq` is specified, it builds an incremental backup.
+pub async fn backup_state_machine(
+    sm: &SMV002,
+    since_seq: Option<u64>,
+    now_ms: u64,
+) -> Result<Vec<String>, io::Error> {
+    let header = BackupHeader {
+        version: BACKUP_FORMAT_VERSION,
+        seq: sm.sys_data_ref().curr_seq(),
+        since_seq,
+        time_ms: now_ms,
+    };
+
+    let mut lines = vec![BackupEntry::Header(header).to_line()?];
+
+    let mut strm = sm.list_kv("").await?;
+    while let Some((key, seq_v)) = strm.try_next().await? {
+        if seq_v.is_expired(now_ms) {
+            continue;
+        }
+
+        let ent = match since_seq {
+            Some(since) if seq_v.seq <= since => BackupEntry::Unchanged { key },
+            _ => BackupEntry::KV {
+                key,
+                seq: seq_v.seq,
+                meta: seq_v.meta,
+                data: seq_v.data,
+            },
+        };
+        lines.push(ent.to_line()?);
+    }
+
+    Ok(lines)
+}
+
+/// Rebuilds the records from a full backup and the following incremental backups.
+#[derive(Debug, Default)]
+pub struct Restore {
+    /// The seq of the last applied backup.
+    seq: Option<u64>,
+
+    /// Restore only the backups taken no later than this seq.
+    until_seq: Option<u64>,
+
+    records: BTreeMap<String, SeqV>,
+}
+
+impl Restore {
+    pub fn new(until_seq: Option<u64>) -> Self {
+        Self {
+            until_seq,
+            ..Default::default()
+        }
+    }
+
+    /// The seq of the last applied backup.
+    pub fn seq(&self) -> Option<u64> {
+        self.seq
+    }
+
+    pub fn records(&self) -> &BTreeMap<String, SeqV> {
+        &self.records
+    }
+
+    /// Applies a backup on the restored records.
+    ///
+    /// Returns `false` if the backup is taken after `until_seq` and is skipped.
+    pub fn apply(&mut self, lines: impl IntoIterator<Item = String>) -> Result<bool, io::Error> {
+        let mut lines = lines.into_iter();
+
+        let header = match lines
+            .next()
+            .map(|l| BackupEntry::from_line(&l))
+            .transpose()?
+        {
+            Some(BackupEntry::Header(header)) => header,
+            _ => return Err(invalid_data("backup does not start with a header")),
+        };
+
+        if header.version > BACKUP_FORMAT_VERSION {
+            return Err(invalid_data(format!(
+                "backup format version {} is not supported, the max supported version is {}",
+                header.version, BACKUP_FORMAT_VERSION
+            )));
+        }
+
+        if let Some(until) = self.until_seq {
+            if header.seq > until {
+                return Ok(false);
+            }
+        }
+
+        if header.since_seq != self.seq {
+            return Err(invalid_data(format!(
+                "backup since seq {:?} does not follow the restored seq {:?}",
+                header.since_seq, self.seq
+            )));
+        }
+
+        let mut records = BTreeMap::new();
+        for line in lines {
+            match BackupEntry::from_line(&line)? {
+                BackupEntry::Header(_) => {
+                    return Err(invalid_data("unexpected header in the middle of backup"));
+                }
+                BackupEntry::KV {
+                    key,
+                    seq,
+                    meta,
+                    data,
+                } => {
+                    records.insert(key, SeqV::with_meta(seq, meta, data));
+                }
+                BackupEntry::Unchanged { key } => {
+                    let seq_v = self.records.remove(&key).ok_or_else(|| {
+                        invalid_data(format!(
+                            "unchanged key {} is not in the previous backup",
+                            key
+                        ))
+                    })?;
+                    records.insert(key, seq_v);
+                }
+            }
+        }
+
+        self.records = records;
+        self.seq = Some(header.seq);
+        Ok(true)
+    }
+
+    /// Builds a transaction that restores the records in the namespace.
+    ///
+    /// `current` is the records currently stored in meta-service.
+    /// Restoring into a namespace that already has records is refused,
+    /// and the transaction does nothing if any restored key is created before it is committed,
+    /// so that a restore either writes all the records or none of them.
+    pub fn build_txn(
+        &self,
+        current: &BTreeMap<String, SeqV>,
+        namespace: Option<&Namespace>,
+    ) -> Result<TxnRequest, io::Error> {
+        let (restored, existing) = match namespace {
+            Some(ns) => (ns.select(&self.records), ns.select(current)),
+            None => (
+                self.records.keys().cloned().collect(),
+                current.keys().cloned().collect::<BTreeSet<_>>(),
+            ),
+        };
+
+        if let Some(key) = existing.first() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "can not restore into a non-empty keyspace: {} records exist, e.g., {}",
+                    existing.len(),
+                    key
+                ),
+            ));
+        }
+
+        let mut condition = vec![];
+        let mut if_then = vec![];
+        for key in restored {
+            let seq_v = &self.records[&key];
+            let expire_at = seq_v
+                .meta
+                .as_ref()
+                .and_then(|m| m.get_expire_at_ms())
+                .map(|ms| ms / 1000);
+            condition.push(TxnCondition::eq_seq(&key, 0));
+            if_then.push(TxnOp::put_with_expire(&key, seq_v.data.clone(), expire_at));
+        }
+
+        Ok(TxnRequest {
+            condition,
+            if_then,
+            else_then: vec![],
+        })
+    }
+}
+
+/// Selects the records that belong to a tenant, or to a database of a tenant.
+///
+/// The records of a tenant are those whose key has the tenant as the second segment,
+/// e.g., `__fd_database/<tenant>/<db_name>`, and the records of the databases and tables
+/// that are keyed by id, e.g., `__fd_database_by_id/<db_id>` and `__fd_table_by_id/<table_id>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Namespace {
+    pub tenant: String,
+    pub database: Option<String>,
+}
+
+impl Namespace {
+    pub fn new(tenant: impl ToString, database: Option<String>) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+            database,
+        }
+    }
+
+    /// Returns the keys of the records in this namespace.
+    pub fn select(&self, records: &BTreeMap<String, SeqV>) -> BTreeSet<String> {
+        // The ids of the databases in this namespace.
+        let mut db_ids = BTreeSet::new();
+        for (key, seq_v) in records {
+            if let Some((prefix, tenant, rest)) = split_key(key) {
+                if prefix == DatabaseNameIdent::PREFIX
+                    && tenant == self.tenant
+                    && self.database.as_ref().map_or(true, |db| db == &rest)
+                {
+                    if let Ok(id) = serde_json::from_slice::<u64>(&seq_v.data) {
+                        db_ids.insert(id.to_string());
+                    }
+                }
+            }
+        }
+
+        // The ids of the tables in the databases.
+        let mut table_ids = BTreeSet::new();
+        for (key, seq_v) in records {
+            if let Some((prefix, db_id, _)) = split_key(key) {
+                if prefix == DBIdTableName::PREFIX && db_ids.contains(&db_id) {
+                    if let Ok(id) = serde_json::from_slice::<u64>(&seq_v.data) {
+                        table_ids.insert(id.to_string());
+                    }
+                }
+            }
+        }
+
+        let db_id_prefixes = [
+            DatabaseId::PREFIX,
+            DatabaseIdToName::PREFIX,
+            DBIdTableName::PREFIX,
+            TableIdListKey::PREFIX,
+        ];
+        let table_id_prefixes = [
+            TableId::PREFIX,
+            TableIdToName::PREFIX,
+            TableCopiedFileNameIdent::PREFIX,
+            LeastVisibleTimeKey::PREFIX,
+        ];
+
+        records
+            .keys()
+            .filter(|key| {
+                let Some((prefix, second, rest)) = split_key(key) else {
+                    return false;
+                };
+                if db_id_prefixes.contains(&prefix.as_str()) {
+                    db_ids.contains(&second)
+                } else if table_id_prefixes.contains(&prefix.as_str()) {
+                    table_ids.contains(&second)
+                } else if second != self.tenant {
+                    false
+                } else if let Some(db) = &self.database {
+                    // Only the records named by the database.
+                    (prefix == DatabaseNameIdent::PREFIX || prefix == DbIdListKey::PREFIX)
+                        && db == &rest
+                } else {
+                    true
+                }
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Split a key into the prefix, the unescaped second segment and the unescaped third segment.
+fn split_key(key: &str) -> Option<(String, String, String)> {
+    let mut parser = KeyParser::new(key);
+    let prefix = parser.next_raw().ok()?.to_string();
+    let second = parser.next_str().ok()?;
+    let rest = parser.next_str().unwrap_or_default();
+    Some((prefix, second, rest))
+}
+
+fn invalid_data(e: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
@@ -28,6 +28,13 @@ pub struct Config {
     pub value: String,
     pub expire_after: Option<u64>,
     pub prefix: String,
+    pub export: bool,
+    pub import: bool,
+    pub backup_file: Vec<String>,
+    pub since_seq: Option<u64>,
+    pub until_seq: Option<u64>,
+    pub restore_tenant: String,
+    pub restore_database: String,
     pub username: String,
     pub password: String,
     pub config_file: String,
@@ -40,6 +47,8 @@ pub struct Config {
     /// Certificate for server to identify itself
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
+    /// CA certificate verifying the client certificates
+    pub grpc_tls_server_root_ca_cert: String,
     pub raft_config: RaftConfig,
 }
 
@@ -51,6 +60,13 @@ impl Default for Config {
             value: "".to_string(),
             expire_after: None,
             prefix: "".to_string(),
+            export: false,
+            import: false,
+            backup_file: vec![],
+            since_seq: None,
+            until_seq: None,
+            restore_tenant: "".to_string(),
+            restore_database: "".to_string(),
             username: "".to_string(),
             password: "".to_string(),
             config_file: "".to_string(),
@@ -62,6 +78,7 @@ impl Default for Config {
             grpc_api_advertise_host: None,
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
+            grpc_tls_server_root_ca_cert: "".to_string(),
             raft_config: Default::default(),
         }
     }
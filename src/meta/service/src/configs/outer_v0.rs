@@ -80,6 +80,43 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub prefix: String,
 
+    /// Export a logical backup of the key-values from the databend-meta server at `--grpc-api-address`,
+    /// to the `--backup-file`, or to stdout if it is not specified.
+    ///
+    /// With `--since-seq`, an incremental backup is exported since a previous backup.
+    #[clap(long)]
+    pub export: bool,
+
+    /// Restore the key-values into the databend-meta server at `--grpc-api-address`,
+    /// from a full backup and the following incremental backups specified by `--backup-file` in order.
+    ///
+    /// Use `--until-seq` to restore to a point in time,
+    /// and `--restore-tenant` and `--restore-database` to restore only a tenant or a database.
+    /// The restored tenant or database, or the whole keyspace, must be empty,
+    /// and the records are written in one transaction.
+    #[clap(long)]
+    pub import: bool,
+
+    /// The backup files to export to or to import from. Only used when running with `--export` or `--import`
+    #[clap(long)]
+    pub backup_file: Vec<String>,
+
+    /// Export an incremental backup since the `seq` of a previous backup. Only used when running with `--export`
+    #[clap(long)]
+    pub since_seq: Option<u64>,
+
+    /// Restore only the backups taken no later than this `seq`. Only used when running with `--import`
+    #[clap(long)]
+    pub until_seq: Option<u64>,
+
+    /// Restore only the key-values of this tenant. Only used when running with `--import`
+    #[clap(long, default_value = "")]
+    pub restore_tenant: String,
+
+    /// Restore only the key-values of this database of `--restore-tenant`. Only used when running with `--import`
+    #[clap(long, default_value = "")]
+    pub restore_database: String,
+
     #[clap(long, default_value = "root")]
     pub username: String,
 
@@ -128,6 +165,11 @@ pub struct Config {
     #[clap(long, default_value = "")]
     pub grpc_tls_server_key: String,
 
+    /// CA certificate verifying the client certificates, the clients must present
+    /// a certificate signed by it if set
+    #[clap(long, default_value = "")]
+    pub grpc_tls_server_root_ca_cert: String,
+
     #[clap(flatten)]
     pub raft_config: RaftConfig,
 }
@@ -154,6 +196,13 @@ impl From<Config> for InnerConfig {
             value: outer.value,
             expire_after: outer.expire_after,
             prefix: outer.prefix,
+            export: outer.export,
+            import: outer.import,
+            backup_file: outer.backup_file,
+            since_seq: outer.since_seq,
+            until_seq: outer.until_seq,
+            restore_tenant: outer.restore_tenant,
+            restore_database: outer.restore_database,
             username: outer.username,
             password: outer.password,
             config_file: outer.config_file,
@@ -165,6 +214,7 @@ impl From<Config> for InnerConfig {
             grpc_api_advertise_host: outer.grpc_api_advertise_host,
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
             grpc_tls_server_key: outer.grpc_tls_server_key,
+            grpc_tls_server_root_ca_cert: outer.grpc_tls_server_root_ca_cert,
             raft_config: outer.raft_config.into(),
         }
     }
@@ -178,6 +228,13 @@ impl From<InnerConfig> for Config {
             value: inner.value,
             expire_after: inner.expire_after,
             prefix: inner.prefix,
+            export: inner.export,
+            import: inner.import,
+            backup_file: inner.backup_file,
+            since_seq: inner.since_seq,
+            until_seq: inner.until_seq,
+            restore_tenant: inner.restore_tenant,
+            restore_database: inner.restore_database,
             username: inner.username,
             password: inner.password,
             config_file: inner.config_file,
@@ -191,6 +248,7 @@ impl From<InnerConfig> for Config {
             grpc_api_advertise_host: inner.grpc_api_advertise_host,
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
+            grpc_tls_server_root_ca_cert: inner.grpc_tls_server_root_ca_cert,
             raft_config: inner.raft_config.into(),
         }
     }
@@ -278,6 +336,7 @@ pub struct ConfigViaEnv {
     pub metasrv_grpc_api_advertise_host: Option<String>,
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
+    pub grpc_tls_server_root_ca_cert: String,
 
     pub config_id: String,
     pub kvsrv_listen_host: String,
@@ -325,6 +384,7 @@ impl From<Config> for ConfigViaEnv {
             metasrv_grpc_api_advertise_host: cfg.grpc_api_advertise_host,
             grpc_tls_server_cert: cfg.grpc_tls_server_cert,
             grpc_tls_server_key: cfg.grpc_tls_server_key,
+            grpc_tls_server_root_ca_cert: cfg.grpc_tls_server_root_ca_cert,
             config_id: cfg.raft_config.config_id,
             kvsrv_listen_host: cfg.raft_config.raft_listen_host,
             kvsrv_advertise_host: cfg.raft_config.raft_advertise_host,
@@ -390,12 +450,19 @@ impl Into<Config> for ConfigViaEnv {
         };
 
         Config {
-            // cmd, key, value and prefix should only be passed in from CLI
+            // cmd, key, value, prefix and the backup options should only be passed in from CLI
             cmd: "".to_string(),
             key: vec![],
             value: "".to_string(),
             expire_after: None,
             prefix: "".to_string(),
+            export: false,
+            import: false,
+            backup_file: vec![],
+            since_seq: None,
+            until_seq: None,
+            restore_tenant: "".to_string(),
+            restore_database: "".to_string(),
             username: "".to_string(),
             password: "".to_string(),
             config_file: self.metasrv_config_file,
@@ -409,6 +476,7 @@ impl Into<Config> for ConfigViaEnv {
             grpc_api_advertise_host: self.metasrv_grpc_api_advertise_host,
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
+            grpc_tls_server_root_ca_cert: self.grpc_tls_server_root_ca_cert,
             raft_config,
         }
     }
@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::io;
 use std::net::Ipv4Addr;
 use std::sync::atomic::AtomicI32;
 use std::sync::Arc;
@@ -41,6 +42,7 @@ use databend_common_meta_stoerr::MetaStorageError;
 use databend_common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use databend_common_meta_types::protobuf::raft_service_server::RaftServiceServer;
 use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::AppliedState;
 use databend_common_meta_types::Cmd;
 use databend_common_meta_types::CommittedLeaderId;
@@ -60,6 +62,7 @@ use databend_common_meta_types::MetaStartupError;
 use databend_common_meta_types::Node;
 use databend_common_meta_types::NodeId;
 use databend_common_meta_types::RaftMetrics;
+use databend_common_meta_types::SeqV;
 use databend_common_meta_types::TypeConfig;
 use futures::channel::oneshot;
 use itertools::Itertools;
@@ -75,6 +78,7 @@ use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
 
+use crate::backup::backup_state_machine;
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
@@ -214,11 +218,19 @@ impl MetaNodeBuilder {
 
         let (tx, rx) = watch::channel::<()>(());
 
-        let dispatcher_tx = EventDispatcher::spawn();
-
-        sto.get_state_machine()
-            .await
-            .set_subscriber(Box::new(DispatcherSender(dispatcher_tx.clone())));
+        // Hold the state machine so that no change is applied before the subscriber is set.
+        let dispatcher_tx = {
+            let mut sm = sto.get_state_machine().await;
+            let applied = sm
+                .sys_data_ref()
+                .last_applied_ref()
+                .as_ref()
+                .map_or(0, |l| l.index);
+
+            let dispatcher_tx = EventDispatcher::spawn(applied);
+            sm.set_subscriber(Box::new(DispatcherSender(dispatcher_tx.clone())));
+            dispatcher_tx
+        };
 
         let mn = Arc::new(MetaNode {
             sto: sto.clone(),
@@ -948,6 +960,14 @@ impl MetaNode {
         sm.sys_data_ref().curr_seq()
     }
 
+    /// Build a logical backup of the key-values in the local state machine.
+    ///
+    /// See [`crate::backup`] for the format.
+    pub async fn backup(&self, since_seq: Option<u64>) -> Result<Vec<String>, io::Error> {
+        let sm = self.sto.state_machine.read().await;
+        backup_state_machine(&sm, since_seq, SeqV::<()>::now_ms()).await
+    }
+
     #[minitrace::trace]
     pub async fn get_grpc_advertise_addrs(&self) -> Vec<String> {
         // inconsistent get: from local state machine
@@ -1176,7 +1196,7 @@ impl MetaNode {
         &self,
         request: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), &'static str> {
         let (resp_tx, resp_rx) = oneshot::channel();
 
         self.dispatcher_handle.request(|d: &mut EventDispatcher| {
@@ -13,11 +13,14 @@
 // limitations under the License.
 
 use core::ops::Range;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
 
 use databend_common_base::base::tokio::sync::mpsc;
 use databend_common_base::base::tokio::sync::oneshot;
 use databend_common_base::rangemap::RangeMap;
 use databend_common_base::rangemap::RangeMapKey;
+use databend_common_meta_kvapi::kvapi::prefix_to_range;
 use databend_common_meta_raft_store::state_machine::StateMachineSubscriber;
 use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::protobuf::watch_request::FilterType;
@@ -37,6 +40,9 @@ use crate::watcher::Watcher;
 
 pub type WatcherId = i64;
 
+/// The max number of recent changes retained for a watcher to resume from.
+pub const MAX_HISTORY_CHANGES: usize = 4096;
+
 /// A sender for dispatcher to send event to interested watchers.
 pub type WatcherSender = mpsc::Sender<Result<WatchResponse, Status>>;
 
@@ -46,8 +52,8 @@ pub(crate) struct DispatcherSender(pub(crate) mpsc::UnboundedSender<WatchEvent>)
 
 /// An event sent to EventDispatcher.
 pub(crate) enum WatchEvent {
-    /// Submit a kv change event to dispatcher
-    KVChange(Change<Vec<u8>, String>),
+    /// Submit a kv change event and its revision to dispatcher
+    KVChange(u64, Change<Vec<u8>, String>),
 
     /// Send a request to EventDispatcher.
     ///
@@ -96,17 +102,32 @@ pub struct EventDispatcher {
     watcher_range_map: RangeMap<String, WatcherId, WatchStreamHandle>,
 
     current_watcher_id: WatcherId,
+
+    /// The recent changes and their revisions, in revision order.
+    history: VecDeque<(u64, Change<Vec<u8>, String>)>,
+
+    /// The changes with revision no greater than this are not retained in `history`.
+    compacted_revision: u64,
+
+    /// The revision of the last dispatched change.
+    revision: u64,
 }
 
 impl EventDispatcher {
     /// Spawn a dispatcher loop task.
-    pub(crate) fn spawn() -> mpsc::UnboundedSender<WatchEvent> {
+    ///
+    /// `applied_revision` is the last applied log index when the dispatcher starts,
+    /// the changes before it are not retained.
+    pub(crate) fn spawn(applied_revision: u64) -> mpsc::UnboundedSender<WatchEvent> {
         let (event_tx, event_rx) = mpsc::unbounded_channel();
 
         let dispatcher = EventDispatcher {
             event_rx,
             watcher_range_map: RangeMap::new(),
             current_watcher_id: 1,
+            history: VecDeque::new(),
+            compacted_revision: applied_revision,
+            revision: applied_revision,
         };
 
         let _h = databend_common_base::runtime::spawn(dispatcher.main());
@@ -119,8 +140,8 @@ impl EventDispatcher {
         loop {
             if let Some(event) = self.event_rx.recv().await {
                 match event {
-                    WatchEvent::KVChange(kv_change) => {
-                        self.dispatch_event(kv_change).await;
+                    WatchEvent::KVChange(revision, kv_change) => {
+                        self.dispatch_event(revision, kv_change).await;
                     }
                     WatchEvent::Request { req } => req(&mut self),
                 }
@@ -132,93 +153,155 @@ impl EventDispatcher {
     }
 
     /// Dispatch a kv change event to interested watchers.
-    async fn dispatch_event(&mut self, change: Change<Vec<u8>, String>) {
+    async fn dispatch_event(&mut self, revision: u64, change: Change<Vec<u8>, String>) {
+        self.retain_change(revision, change.clone());
+
         let k = change.ident.as_ref().unwrap();
         let set = self.watcher_range_map.get_by_point(k);
         if set.is_empty() {
             return;
         }
 
-        let current = change.result;
-        let prev = change.prev;
+        let is_delete_event = change.result.is_none();
+        let resp = Self::build_response(revision, &change);
 
-        let is_delete_event = current.is_none();
-        let mut remove_range_keys: Vec<RangeMapKey<String, WatcherId>> = vec![];
+        // A watcher may be found more than once if its ranges overlap.
+        let mut sent = BTreeSet::new();
+        let mut remove_watchers = vec![];
 
         for range_key_stream in set.iter() {
-            let filter = range_key_stream.1.watcher.filter_type;
+            let watcher_id = range_key_stream.0.key;
+            let stream = range_key_stream.1;
+            assert_eq!(stream.watcher.id, watcher_id);
 
             // filter out event
-            if (filter == FilterType::Delete && !is_delete_event)
-                || (filter == FilterType::Update && is_delete_event)
-            {
+            if !stream.watcher.accepts(is_delete_event) || !sent.insert(watcher_id) {
                 continue;
             }
 
-            let watcher_id = range_key_stream.0.key;
-            let stream = range_key_stream.1;
-            assert_eq!(stream.watcher.id, watcher_id);
-            let resp = WatchResponse {
-                event: Some(Event {
-                    key: k.to_string(),
-                    current: current.clone().map(pb::SeqV::from),
-                    prev: prev.clone().map(pb::SeqV::from),
-                }),
-            };
-
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
-            if let Err(err) = stream.send(resp).await {
+            if let Err(err) = stream.send(resp.clone()).await {
                 warn!(
                     "close watcher stream {:?} cause send err: {:?}",
                     watcher_id, err
                 );
-                remove_range_keys.push(RangeMapKey::new(
-                    stream.watcher.key_range.clone(),
-                    watcher_id,
-                ));
+                remove_watchers.push(stream.watcher.clone());
             };
         }
 
         // TODO: when a watcher stream is dropped, send a event to remove the watcher explicitly
-        for range_key in remove_range_keys {
-            self.remove_watcher(&range_key);
+        for watcher in remove_watchers {
+            self.remove_watcher(&watcher);
+        }
+    }
+
+    /// Retain a change for the watchers to resume from, and discard the oldest ones.
+    fn retain_change(&mut self, revision: u64, change: Change<Vec<u8>, String>) {
+        self.revision = revision;
+        self.history.push_back((revision, change));
+
+        while self.history.len() > MAX_HISTORY_CHANGES {
+            if let Some((rev, _)) = self.history.pop_front() {
+                // Other changes of the same log may still be retained,
+                // but a watcher can not resume from the middle of a log.
+                self.compacted_revision = rev;
+            }
+        }
+    }
+
+    fn build_response(revision: u64, change: &Change<Vec<u8>, String>) -> WatchResponse {
+        WatchResponse {
+            event: Some(Event {
+                key: change.ident.clone().unwrap_or_default(),
+                current: change.result.clone().map(pb::SeqV::from),
+                prev: change.prev.clone().map(pb::SeqV::from),
+            }),
+            revision,
+            compacted: false,
         }
     }
 
+    /// Add a watcher and returns the responses to send before the new changes.
+    ///
+    /// If `start_revision` is specified, the responses are the retained changes after it,
+    /// or a single `compacted` response if some of these changes are not retained.
     #[minitrace::trace]
     pub fn add_watcher(
         &mut self,
         create: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), &'static str> {
         info!("add_watcher: {:?}", create);
 
-        let range = match EventDispatcher::build_key_range(create.key.clone(), &create.key_end) {
-            Ok(range) => range,
-            Err(e) => return Err(e),
+        let ranges = if create.prefixes.is_empty() {
+            vec![EventDispatcher::build_key_range(
+                create.key.clone(),
+                &create.key_end,
+            )?]
+        } else {
+            let mut ranges = Vec::with_capacity(create.prefixes.len());
+            for prefix in create.prefixes.iter() {
+                let (start, end) = prefix_to_range(prefix).map_err(|_e| "prefix must be ascii")?;
+                ranges.push(start..end);
+            }
+            ranges
         };
 
         self.current_watcher_id += 1;
         let watcher_id = self.current_watcher_id;
         let filter: FilterType = create.filter_type();
 
-        let watcher = Watcher::new(watcher_id, filter, range.clone());
-        let stream_handle = WatchStreamHandle::new(watcher.clone(), tx);
+        let watcher = Watcher::new(watcher_id, filter, ranges.clone());
+        let initial = match create.start_revision {
+            None => vec![],
+            Some(start) => self.replay(&watcher, start),
+        };
 
-        self.watcher_range_map
-            .insert(range, watcher_id, stream_handle);
+        for range in ranges {
+            let stream_handle = WatchStreamHandle::new(watcher.clone(), tx.clone());
+            self.watcher_range_map
+                .insert(range, watcher_id, stream_handle);
+        }
 
         server_metrics::incr_watchers(1);
 
-        Ok(watcher)
+        Ok((watcher, initial))
+    }
+
+    /// Build the responses of the retained changes after `start_revision` for a watcher.
+    fn replay(&self, watcher: &Watcher, start_revision: u64) -> Vec<WatchResponse> {
+        if start_revision < self.compacted_revision {
+            info!(
+                "watcher {} start revision {} is compacted, compacted revision: {}",
+                watcher.id, start_revision, self.compacted_revision
+            );
+            return vec![WatchResponse {
+                event: None,
+                revision: self.revision,
+                compacted: true,
+            }];
+        }
+
+        self.history
+            .iter()
+            .filter(|(rev, change)| {
+                *rev > start_revision
+                    && watcher.contains_key(change.ident.as_deref().unwrap_or_default())
+                    && watcher.accepts(change.result.is_none())
+            })
+            .map(|(rev, change)| Self::build_response(*rev, change))
+            .collect()
     }
 
     #[minitrace::trace]
-    pub fn remove_watcher(&mut self, key: &RangeMapKey<String, WatcherId>) {
-        info!("remove_watcher: {:?}", key);
+    pub fn remove_watcher(&mut self, watcher: &Watcher) {
+        info!("remove_watcher: {:?}", watcher);
 
-        self.watcher_range_map.remove_by_key(key);
+        for range in watcher.key_ranges.iter() {
+            let key = RangeMapKey::new(range.clone(), watcher.id);
+            self.watcher_range_map.remove_by_key(&key);
+        }
 
         // TODO: decrease it only when the key is actually removed
         server_metrics::incr_watchers(-1);
@@ -245,7 +328,7 @@ impl EventDispatcher {
 }
 
 impl StateMachineSubscriber for DispatcherSender {
-    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
-        let _ = self.0.send(WatchEvent::KVChange(change));
+    fn kv_changed(&self, revision: u64, change: Change<Vec<u8>, String>) {
+        let _ = self.0.send(WatchEvent::KVChange(revision, change));
     }
 }
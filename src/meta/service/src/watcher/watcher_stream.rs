@@ -19,7 +19,6 @@ use std::task::Poll;
 
 use databend_common_base::base::tokio::sync::mpsc::error::SendError;
 use databend_common_base::base::tokio::sync::mpsc::Receiver;
-use databend_common_base::rangemap::RangeMapKey;
 use databend_common_meta_types::protobuf::watch_request::FilterType;
 use databend_common_meta_types::protobuf::WatchResponse;
 use futures::Stream;
@@ -34,19 +33,41 @@ use crate::watcher::EventDispatcherHandle;
 pub struct Watcher {
     pub id: WatcherId,
 
-    /// Defines how to filter keys with `key_range`.
+    /// Defines how to filter keys with `key_ranges`.
     pub filter_type: FilterType,
 
-    /// The range of key this watcher is interested in.
-    pub key_range: Range<String>,
+    /// The ranges of key this watcher is interested in.
+    pub key_ranges: Vec<Range<String>>,
 }
 
 impl Watcher {
-    pub fn new(id: WatcherId, filter_type: FilterType, key_range: Range<String>) -> Self {
+    pub fn new(id: WatcherId, filter_type: FilterType, key_ranges: Vec<Range<String>>) -> Self {
         Self {
             id,
             filter_type,
-            key_range,
+            key_ranges,
+        }
+    }
+
+    /// Returns if the key is in one of the ranges.
+    ///
+    /// A range with the same start and end contains only the start key.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.key_ranges.iter().any(|r| {
+            if r.start == r.end {
+                r.start == key
+            } else {
+                r.start.as_str() <= key && key < r.end.as_str()
+            }
+        })
+    }
+
+    /// Returns if the event passes the filter of this watcher.
+    pub fn accepts(&self, is_delete_event: bool) -> bool {
+        match self.filter_type {
+            FilterType::All => true,
+            FilterType::Update => !is_delete_event,
+            FilterType::Delete => is_delete_event,
         }
     }
 }
@@ -80,13 +101,9 @@ pub struct WatchStream<T> {
 
 impl<T> Drop for WatchStream<T> {
     fn drop(&mut self) {
-        let rng = self.watcher.key_range.clone();
-        let id = self.watcher.id;
+        let watcher = self.watcher.clone();
 
-        self.dispatcher.request(move |d| {
-            let key = RangeMapKey::new(rng, id);
-            d.remove_watcher(&key)
-        })
+        self.dispatcher.request(move |d| d.remove_watcher(&watcher))
     }
 }
 
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::TxnCondition;
+use databend_common_meta_types::TxnOp;
+use databend_meta::backup::BackupEntry;
+use databend_meta::backup::BackupHeader;
+use databend_meta::backup::Namespace;
+use databend_meta::backup::Restore;
+use databend_meta::backup::BACKUP_FORMAT_VERSION;
+
+fn header(seq: u64, since_seq: Option<u64>) -> String {
+    BackupEntry::Header(BackupHeader {
+        version: BACKUP_FORMAT_VERSION,
+        seq,
+        since_seq,
+        time_ms: 0,
+    })
+    .to_line()
+    .unwrap()
+}
+
+fn kv(key: &str, seq: u64, data: &[u8]) -> String {
+    BackupEntry::KV {
+        key: key.to_string(),
+        seq,
+        meta: None,
+        data: data.to_vec(),
+    }
+    .to_line()
+    .unwrap()
+}
+
+fn unchanged(key: &str) -> String {
+    BackupEntry::Unchanged {
+        key: key.to_string(),
+    }
+    .to_line()
+    .unwrap()
+}
+
+#[test]
+fn test_restore_incremental() -> anyhow::Result<()> {
+    let full = vec![header(2, None), kv("a", 1, b"a"), kv("b", 2, b"b")];
+    // `a` is deleted, `b` is unchanged and `c` is added.
+    let incr = vec![header(3, Some(2)), unchanged("b"), kv("c", 3, b"c")];
+
+    let mut restore = Restore::new(None);
+    assert!(restore.apply(full.clone())?);
+    assert!(restore.apply(incr.clone())?);
+    assert_eq!(Some(3), restore.seq());
+    assert_eq!(
+        vec!["b", "c"],
+        restore
+            .records()
+            .keys()
+            .map(|k| k.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    // Point-in-time restore skips the backups taken later.
+    let mut restore = Restore::new(Some(2));
+    assert!(restore.apply(full.clone())?);
+    assert!(!restore.apply(incr.clone())?);
+    assert_eq!(Some(2), restore.seq());
+
+    // An incremental backup must follow the previous one.
+    let mut restore = Restore::new(None);
+    assert!(restore.apply(incr).is_err());
+
+    // A backup of a newer format can not be restored.
+    let newer = BackupEntry::Header(BackupHeader {
+        version: BACKUP_FORMAT_VERSION + 1,
+        seq: 1,
+        since_seq: None,
+        time_ms: 0,
+    })
+    .to_line()?;
+    let mut restore = Restore::new(None);
+    assert!(restore.apply(vec![newer]).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_namespace_select() {
+    let id = |i: u64| SeqV::new(1, serde_json::to_vec(&i).unwrap());
+    let records: BTreeMap<String, SeqV> = [
+        ("__fd_database/t1/db1", id(10)),
+        ("__fd_database/t1/db2", id(20)),
+        ("__fd_database/t2/db1", id(30)),
+        ("__fd_database_by_id/10", id(0)),
+        ("__fd_database_by_id/20", id(0)),
+        ("__fd_database_by_id/30", id(0)),
+        ("__fd_table/10/tb1", id(100)),
+        ("__fd_table/30/tb1", id(300)),
+        ("__fd_table_by_id/100", id(0)),
+        ("__fd_table_by_id/300", id(0)),
+        ("__fd_users/t1/u1", id(0)),
+        ("__fd_users/t2/u1", id(0)),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v))
+    .collect();
+
+    let select = |ns: Namespace| ns.select(&records).into_iter().collect::<Vec<_>>();
+
+    assert_eq!(
+        vec![
+            "__fd_database/t1/db1",
+            "__fd_database/t1/db2",
+            "__fd_database_by_id/10",
+            "__fd_database_by_id/20",
+            "__fd_table/10/tb1",
+            "__fd_table_by_id/100",
+            "__fd_users/t1/u1",
+        ],
+        select(Namespace::new("t1", None))
+    );
+
+    assert_eq!(
+        vec![
+            "__fd_database/t1/db1",
+            "__fd_database_by_id/10",
+            "__fd_table/10/tb1",
+            "__fd_table_by_id/100",
+        ],
+        select(Namespace::new("t1", Some("db1".to_string())))
+    );
+}
+
+#[test]
+fn test_restore_build_txn() -> anyhow::Result<()> {
+    let mut restore = Restore::new(None);
+    restore.apply(vec![header(2, None), kv("a", 1, b"a"), kv("b", 2, b"b")])?;
+
+    // Every restored key must not exist when the txn is committed.
+    let txn = restore.build_txn(&BTreeMap::new(), None)?;
+    assert_eq!(
+        vec![TxnCondition::eq_seq("a", 0), TxnCondition::eq_seq("b", 0)],
+        txn.condition
+    );
+    assert_eq!(
+        vec![
+            TxnOp::put_with_expire("a", b"a".to_vec(), None),
+            TxnOp::put_with_expire("b", b"b".to_vec(), None),
+        ],
+        txn.if_then
+    );
+    assert!(txn.else_then.is_empty());
+
+    // Restoring into a non-empty keyspace is refused.
+    let current: BTreeMap<String, SeqV> = [("x".to_string(), SeqV::new(1, b"x".to_vec()))]
+        .into_iter()
+        .collect();
+    assert!(restore.build_txn(&current, None).is_err());
+
+    // Records outside the restored namespace do not block the restore.
+    let ns = Namespace::new("t1", None);
+    assert!(restore.build_txn(&current, Some(&ns))?.if_then.is_empty());
+
+    Ok(())
+}
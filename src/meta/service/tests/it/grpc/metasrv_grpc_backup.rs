@@ -0,0 +1,134 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_meta_client::ClientHandle;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::protobuf as pb;
+use databend_meta::backup::BackupEntry;
+use databend_meta::backup::Restore;
+use log::info;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+use tokio_stream::StreamExt;
+
+use crate::testing::meta_service_test_harness;
+
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_backup_and_restore() -> anyhow::Result<()> {
+    // - Start a metasrv server.
+    // - Take a full backup and an incremental backup.
+    // - Restore to each of the backups and check the data.
+
+    let (tc, _addr) = crate::tests::start_metasrv().await?;
+
+    let client = tc.grpc_client().await?;
+
+    info!("--- full backup");
+    for k in ["foo", "bar"] {
+        client.upsert_kv(UpsertKVReq::update(k, &b(k))).await?;
+    }
+    let full = backup(&client, None).await?;
+
+    let seq = match BackupEntry::from_line(&full[0])? {
+        BackupEntry::Header(header) => header.seq,
+        _ => unreachable!("the first line is the header"),
+    };
+    assert_eq!(3, full.len());
+
+    info!("--- incremental backup");
+    client
+        .upsert_kv(UpsertKVReq::update("wow", &b("wow")))
+        .await?;
+    client.upsert_kv(UpsertKVReq::delete("foo")).await?;
+    let incr = backup(&client, Some(seq)).await?;
+
+    assert_eq!(
+        vec![r#"{"Unchanged":{"key":"bar"}}"#],
+        incr[1..2].to_vec(),
+        "bar is not changed"
+    );
+
+    info!("--- restore to the full backup");
+    {
+        let mut restore = Restore::new(Some(seq));
+        restore.apply(full.clone())?;
+        restore.apply(incr.clone())?;
+        apply_restore(&client, &restore).await?;
+
+        assert_eq!(vec!["bar", "foo"], list_keys(&client).await?);
+    }
+
+    info!("--- restore to the incremental backup");
+    {
+        let mut restore = Restore::new(None);
+        restore.apply(full)?;
+        restore.apply(incr)?;
+        apply_restore(&client, &restore).await?;
+
+        assert_eq!(vec!["bar", "wow"], list_keys(&client).await?);
+        let got = client.get_kv("wow").await?;
+        assert_eq!(b("wow"), got.unwrap().data);
+    }
+
+    Ok(())
+}
+
+async fn backup(client: &ClientHandle, since_seq: Option<u64>) -> anyhow::Result<Vec<String>> {
+    let mut grpc_client = client.make_established_client().await?;
+    let mut stream = grpc_client
+        .backup(tonic::Request::new(pb::BackupRequest {
+            since_seq,
+            chunk_size: Some(1),
+        }))
+        .await?
+        .into_inner();
+
+    let mut lines = vec![];
+    while let Some(chunk) = stream.next().await {
+        lines.extend(chunk?.data);
+    }
+    Ok(lines)
+}
+
+async fn apply_restore(client: &ClientHandle, restore: &Restore) -> anyhow::Result<()> {
+    let current = client
+        .prefix_list_kv("")
+        .await?
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+
+    // A restore is only allowed into an empty keyspace.
+    for key in current.keys() {
+        client.upsert_kv(UpsertKVReq::delete(key)).await?;
+    }
+
+    let reply = client
+        .transaction(restore.build_txn(&BTreeMap::new(), None)?)
+        .await?;
+    assert!(reply.success);
+    Ok(())
+}
+
+async fn list_keys(client: &ClientHandle) -> anyhow::Result<Vec<String>> {
+    let kvs = client.prefix_list_kv("").await?;
+    Ok(kvs.into_iter().map(|(k, _)| k).collect())
+}
+
+fn b(s: impl ToString) -> Vec<u8> {
+    s.to_string().into_bytes()
+}
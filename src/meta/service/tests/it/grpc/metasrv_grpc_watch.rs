@@ -131,6 +131,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key: "a".to_string(),
             key_end: Some("z".to_string()),
             filter_type: FilterType::All.into(),
+            prefixes: vec![],
+            start_revision: None,
         };
 
         let key_a = s("a");
@@ -189,6 +191,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key_end: None,
             // filter only delete events
             filter_type: FilterType::Delete.into(),
+            prefixes: vec![],
+            start_revision: None,
         };
 
         let key = s(key_str);
@@ -252,6 +256,8 @@ async fn test_watch() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            prefixes: vec![],
+            start_revision: None,
         };
 
         let conditions = vec![TxnCondition {
@@ -363,6 +369,8 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
             key: start,
             key_end: Some(end),
             filter_type: FilterType::All.into(),
+            prefixes: vec![],
+            start_revision: None,
         };
         watch_client.request(watch).await?
     };
@@ -448,6 +456,8 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
         key: "a".to_string(),
         key_end: Some("z".to_string()),
         filter_type: FilterType::All.into(),
+        prefixes: vec![],
+        start_revision: None,
     };
 
     let client1 = make_client(&addr)?;
@@ -505,6 +515,99 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[minitrace::trace]
+async fn test_watch_prefixes_and_resume() -> anyhow::Result<()> {
+    // - Watch two prefixes and receive the changes with their revisions.
+    // - Resume watching from a revision, the changes missed are replayed.
+    // - Restart the server, resuming from a revision before restart is compacted.
+
+    let (mut tc, addr) = crate::tests::start_metasrv().await?;
+    let client = make_client(&addr)?;
+
+    let watch_req = |start_revision: Option<u64>| WatchRequest {
+        key: "".to_string(),
+        key_end: None,
+        filter_type: FilterType::All.into(),
+        prefixes: vec![s("a/"), s("c/")],
+        start_revision,
+    };
+
+    info!("--- watch prefixes");
+    let last_revision = {
+        let mut watch_stream = client.request(watch_req(None)).await?;
+
+        client
+            .upsert_kv(UpsertKVReq::update("a/1", &b("a1")))
+            .await?;
+        client
+            .upsert_kv(UpsertKVReq::update("b/1", &b("b1")))
+            .await?;
+        client
+            .upsert_kv(UpsertKVReq::update("c/1", &b("c1")))
+            .await?;
+
+        let r1 = watch_stream.message().await?.unwrap();
+        let r2 = watch_stream.message().await?.unwrap();
+
+        assert_eq!(s("a/1"), r1.event.unwrap().key);
+        assert_eq!(s("c/1"), r2.event.unwrap().key);
+        assert!(r1.revision < r2.revision);
+        r2.revision
+    };
+
+    info!("--- resume from the last revision");
+    {
+        client
+            .upsert_kv(UpsertKVReq::update("a/2", &b("a2")))
+            .await?;
+        client
+            .upsert_kv(UpsertKVReq::update("b/2", &b("b2")))
+            .await?;
+        client.upsert_kv(UpsertKVReq::delete("c/1")).await?;
+
+        let mut watch_stream = client.request(watch_req(Some(last_revision))).await?;
+
+        client
+            .upsert_kv(UpsertKVReq::update("a/3", &b("a3")))
+            .await?;
+
+        let mut got = vec![];
+        let mut prev_revision = last_revision;
+        for _ in 0..3 {
+            let resp = watch_stream.message().await?.unwrap();
+            assert!(!resp.compacted);
+            assert!(resp.revision > prev_revision);
+            prev_revision = resp.revision;
+
+            let event = resp.event.unwrap();
+            got.push((event.key, event.current.is_some()));
+        }
+
+        assert_eq!(
+            vec![(s("a/2"), true), (s("c/1"), false), (s("a/3"), true)],
+            got
+        );
+    }
+
+    info!("--- restart, the changes before restart are compacted");
+    {
+        let mut srv = tc.grpc_srv.take().unwrap();
+        srv.stop(None).await?;
+        crate::tests::start_metasrv_with_context(&mut tc).await?;
+
+        let client = make_client(&addr)?;
+        let mut watch_stream = client.request(watch_req(Some(last_revision))).await?;
+
+        let resp = watch_stream.message().await?.unwrap();
+        assert!(resp.compacted);
+        assert!(resp.event.is_none());
+        assert!(resp.revision >= last_revision);
+    }
+
+    Ok(())
+}
+
 fn s(x: &str) -> String {
     x.to_string()
 }
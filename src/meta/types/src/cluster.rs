@@ -79,6 +79,9 @@ pub struct NodeInfo {
     pub version: u32,
     pub flight_address: String,
     pub binary_version: String,
+    /// A draining node is not assigned the fragments of new queries,
+    /// it leaves the cluster once the running queries finish.
+    pub draining: bool,
 }
 
 impl NodeInfo {
@@ -94,6 +97,7 @@ impl NodeInfo {
             version: 0,
             flight_address,
             binary_version,
+            draining: false,
         }
     }
 
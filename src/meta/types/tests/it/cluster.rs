@@ -22,6 +22,7 @@ fn test_node_info_ip_port() -> anyhow::Result<()> {
         version: 1,
         flight_address: "1.2.3.4:123".to_string(),
         binary_version: "v0.8-binary-version".to_string(),
+        draining: false,
     };
 
     let (ip, port) = n.ip_port()?;
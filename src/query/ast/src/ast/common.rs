@@ -16,6 +16,7 @@ use std::fmt::Display;
 use std::fmt::Formatter;
 
 use databend_common_exception::Span;
+use databend_common_io::escape_string_with_quote;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
@@ -28,17 +29,26 @@ pub struct Identifier {
     pub span: Span,
     #[drive(skip)]
     pub name: String,
+    /// The quote of the identifier, `'` for `IDENTIFIER('db.t')` whose object name is
+    /// resolved from the string at bind time.
     #[drive(skip)]
     pub quote: Option<char>,
     #[drive(skip)]
     pub is_hole: bool,
 }
 
+/// The quote of the dynamic identifiers, which is never an identifier quote of the dialects.
+const DYNAMIC_IDENT_QUOTE: char = '\'';
+
 impl Identifier {
     pub fn is_quoted(&self) -> bool {
         self.quote.is_some()
     }
 
+    pub fn is_dynamic(&self) -> bool {
+        self.quote == Some(DYNAMIC_IDENT_QUOTE)
+    }
+
     pub fn from_name(span: Span, name: impl Into<String>) -> Self {
         Self {
             span,
@@ -48,6 +58,16 @@ impl Identifier {
         }
     }
 
+    /// Creates the identifier of `IDENTIFIER('<name>')`.
+    pub fn from_dynamic_name(span: Span, name: impl Into<String>) -> Self {
+        Self {
+            span,
+            name: name.into(),
+            quote: Some(DYNAMIC_IDENT_QUOTE),
+            is_hole: false,
+        }
+    }
+
     pub fn from_name_with_quoted(span: Span, name: impl Into<String>, quote: Option<char>) -> Self {
         Self {
             span,
@@ -62,6 +82,12 @@ impl Display for Identifier {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         if self.is_hole {
             write!(f, "IDENTIFIER(:{})", self.name)
+        } else if self.is_dynamic() {
+            write!(
+                f,
+                "IDENTIFIER('{}')",
+                escape_string_with_quote(&self.name, Some('\''))
+            )
         } else if let Some(c) = self.quote {
             let quoted = quote_ident(&self.name, c, true);
             write!(f, "{}", quoted)
@@ -843,6 +843,8 @@ pub struct FunctionCall {
     pub name: Identifier,
     pub args: Vec<Expr>,
     pub params: Vec<Expr>,
+    /// The order of the values aggregated by the aggregate function, like `ARRAY_AGG(a ORDER BY b)`
+    pub order_by: Vec<OrderByExpr>,
     pub window: Option<Window>,
     pub lambda: Option<Lambda>,
 }
@@ -854,6 +856,7 @@ impl Display for FunctionCall {
             name,
             args,
             params,
+            order_by,
             window,
             lambda,
         } = self;
@@ -871,6 +874,10 @@ impl Display for FunctionCall {
         if let Some(lambda) = lambda {
             write!(f, ", {lambda}")?;
         }
+        if !order_by.is_empty() {
+            write!(f, " ORDER BY ")?;
+            write_comma_separated_list(f, order_by)?;
+        }
         write!(f, ")")?;
 
         if let Some(window) = window {
@@ -930,6 +937,9 @@ pub enum TypeName {
     },
     Variant,
     Geometry,
+    Interval,
+    /// A fixed-dimension vector of Float32, `VECTOR(FLOAT32, <dimension>)`.
+    Vector(#[drive(skip)] u64),
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -1049,6 +1059,12 @@ impl Display for TypeName {
             TypeName::Geometry => {
                 write!(f, "GEOMETRY")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
+            TypeName::Vector(dimension) => {
+                write!(f, "VECTOR(FLOAT32, {})", dimension)?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -450,10 +450,11 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
+        order_by: &'ast [OrderByExpr],
         _over: &'ast Option<Window>,
         _lambda: &'ast Option<Lambda>,
     ) {
-        let mut children = Vec::with_capacity(args.len());
+        let mut children = Vec::with_capacity(args.len() + order_by.len());
         for arg in args.iter() {
             self.visit_expr(arg);
             children.push(self.children.pop().unwrap());
@@ -461,6 +462,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         for param in params.iter() {
             self.visit_expr(param);
         }
+        for order_by in order_by.iter() {
+            self.visit_order_by(order_by);
+            children.push(self.children.pop().unwrap());
+        }
         let node_name = if distinct {
             format!("Function {name}Distinct")
         } else {
@@ -735,9 +740,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         .iter()
                         .map(|opt| {
                             match opt {
-                                ExplainOption::Verbose => "Verbose",
-                                ExplainOption::Logical => "Logical",
-                                ExplainOption::Optimized => "Optimized",
+                                ExplainOption::Verbose => "Verbose".to_string(),
+                                ExplainOption::Logical => "Logical".to_string(),
+                                ExplainOption::Optimized => "Optimized".to_string(),
+                                ExplainOption::Format(format) => format!("Format {format}"),
                             }
                         })
                         .join(", ")
@@ -948,6 +954,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowProcessList".to_string());
     }
 
+    fn visit_show_warnings(&mut self) {
+        let name = "ShowWarnings".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_show_metrics(&mut self, show_options: &'ast Option<ShowOptions>) {
         self.visit_show_options(show_options, "ShowMetrics".to_string());
     }
@@ -956,6 +969,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowEngines".to_string());
     }
 
+    fn visit_show_quotas(&mut self, show_options: &'ast Option<ShowOptions>) {
+        self.visit_show_options(show_options, "ShowQuotas".to_string());
+    }
+
     fn visit_show_functions(&mut self, show_options: &'ast Option<ShowOptions>) {
         self.visit_show_options(show_options, "ShowFunctions".to_string());
     }
@@ -986,6 +1003,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_show_options(&mut self, show_options: &'ast Option<ShowOptions>, name: String) {
         let mut children = Vec::new();
+        self.show_options_to_children(show_options, &mut children);
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn show_options_to_children(
+        &mut self,
+        show_options: &'ast Option<ShowOptions>,
+        children: &mut Vec<FormatTreeNode<AstFormatContext>>,
+    ) {
         if let Some(show_options) = show_options {
             if let Some(show_limit) = &show_options.show_limit {
                 self.visit_show_limit(show_limit);
@@ -998,9 +1026,6 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 children.push(node);
             }
         }
-        let format_ctx = AstFormatContext::with_children(name, children.len());
-        let node = FormatTreeNode::with_children(format_ctx, children);
-        self.children.push(node);
     }
 
     fn visit_show_limit(&mut self, limit: &'ast ShowLimit) {
@@ -1170,10 +1195,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_show_databases(&mut self, stmt: &'ast ShowDatabasesStmt) {
         let mut children = Vec::new();
-        if let Some(limit) = &stmt.limit {
-            self.visit_show_limit(limit);
-            children.push(self.children.pop().unwrap());
-        }
+        self.show_options_to_children(&stmt.show_options, &mut children);
         let name = "ShowDatabases".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1255,6 +1277,36 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_backup_database(&mut self, stmt: &'ast BackupDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let database_child = self.children.pop().unwrap();
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        let location_child = FormatTreeNode::new(location_format_ctx);
+        let name = if stmt.incremental {
+            "BackupDatabase Incremental".to_string()
+        } else {
+            "BackupDatabase".to_string()
+        };
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![database_child, location_child]);
+        self.children.push(node);
+    }
+
+    fn visit_restore_database(&mut self, stmt: &'ast RestoreDatabaseStmt) {
+        self.visit_database_ref(&stmt.catalog, &stmt.database);
+        let mut children = vec![self.children.pop().unwrap()];
+        let location_format_ctx = AstFormatContext::new(format!("Location @{}", stmt.location));
+        children.push(FormatTreeNode::new(location_format_ctx));
+        if let Some(backup_id) = &stmt.backup_id {
+            let backup_id_format_ctx = AstFormatContext::new(format!("BackupId {backup_id}"));
+            children.push(FormatTreeNode::new(backup_id_format_ctx));
+        }
+        let name = "RestoreDatabase".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_use_database(&mut self, database: &'ast Identifier) {
         self.visit_identifier(database);
         let child = self.children.pop().unwrap();
@@ -1272,10 +1324,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
             let database_node = FormatTreeNode::new(database_format_ctx);
             children.push(database_node);
         }
-        if let Some(limit) = &stmt.limit {
-            self.visit_show_limit(limit);
-            children.push(self.children.pop().unwrap());
-        }
+        self.show_options_to_children(&stmt.show_options, &mut children);
         let name = "ShowTables".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1296,10 +1345,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         let table_node = FormatTreeNode::new(table_format_ctx);
         children.push(table_node);
 
-        if let Some(limit) = &stmt.limit {
-            self.visit_show_limit(limit);
-            children.push(self.children.pop().unwrap());
-        }
+        self.show_options_to_children(&stmt.show_options, &mut children);
         let name = "ShowColumns".to_string();
         let format_ctx = AstFormatContext::with_children(name, children.len());
         let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1416,12 +1462,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, primary_key) => {
+                let mut children = Vec::with_capacity(columns.len() + 1);
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                if let Some(primary_key) = primary_key {
+                    let name = format!("PrimaryKey {}", primary_key);
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1431,6 +1482,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 catalog,
                 database,
                 table,
+                ..
             } => {
                 self.visit_table_ref(catalog, database, table);
                 let child = self.children.pop().unwrap();
@@ -2474,6 +2526,59 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_model(&mut self, stmt: &'ast CreateModelStmt) {
+        let mut children = Vec::new();
+        let model_name_format_ctx =
+            AstFormatContext::new(format!("ModelNameIdentifier {}", stmt.model_name));
+        children.push(FormatTreeNode::new(model_name_format_ctx));
+
+        if !stmt.arg_types.is_empty() {
+            let mut arg_types_children = Vec::with_capacity(stmt.arg_types.len());
+            for arg_type in stmt.arg_types.iter() {
+                let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+            }
+            let arg_format_ctx = AstFormatContext::with_children(
+                "ModelArgTypes".to_string(),
+                arg_types_children.len(),
+            );
+            children.push(FormatTreeNode::with_children(
+                arg_format_ctx,
+                arg_types_children,
+            ));
+        }
+
+        let return_type_format_ctx =
+            AstFormatContext::new(format!("ModelReturnType {}", stmt.return_type));
+        children.push(FormatTreeNode::new(return_type_format_ctx));
+
+        let location_format_ctx =
+            AstFormatContext::new(format!("ModelLocation @{}", stmt.location));
+        children.push(FormatTreeNode::new(location_format_ctx));
+
+        if let Some(description) = &stmt.description {
+            let description_name = format!("ModelDescription {}", description);
+            let description_format_ctx = AstFormatContext::new(description_name);
+            children.push(FormatTreeNode::new(description_format_ctx));
+        }
+
+        let name = "CreateModel".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_drop_model(&mut self, _if_exists: bool, model_name: &'ast Identifier) {
+        let model_name_format_ctx =
+            AstFormatContext::new(format!("ModelIdentifier {}", model_name));
+        let child = FormatTreeNode::new(model_name_format_ctx);
+
+        let name = "DropModel".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_stage(&mut self, stmt: &'ast CreateStageStmt) {
         let mut children = Vec::new();
         let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stmt.stage_name));
@@ -2525,11 +2630,8 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
-    fn visit_show_stages(&mut self) {
-        let name = "ShowStages".to_string();
-        let format_ctx = AstFormatContext::new(name);
-        let node = FormatTreeNode::new(format_ctx);
-        self.children.push(node);
+    fn visit_show_stages(&mut self, show_options: &'ast Option<ShowOptions>) {
+        self.visit_show_options(show_options, "ShowStages".to_string());
     }
 
     fn visit_drop_stage(&mut self, _if_exists: bool, stage_name: &'ast str) {
@@ -2552,6 +2654,16 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_refresh_stage(&mut self, stage_name: &'ast str) {
+        let stage_name_format_ctx = AstFormatContext::new(format!("StageName {}", stage_name));
+        let child = FormatTreeNode::new(stage_name_format_ctx);
+
+        let name = "RefreshStage".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_remove_stage(&mut self, location: &'ast str, pattern: &'ast str) {
         let location_format_ctx = AstFormatContext::new(format!("Location {}", location));
         let location_child = FormatTreeNode::new(location_format_ctx);
@@ -3041,6 +3153,35 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                         FormatTreeNode::with_children(cube_list_format_ctx, cube_list_children);
                     children.push(cube_list_node);
                 }
+                GroupBy::Fill(fill) => {
+                    let mut fill_children = Vec::with_capacity(3 + fill.interpolate.len());
+                    for expr in [&fill.expr, &fill.from, &fill.to]
+                        .into_iter()
+                        .chain(fill.step.iter())
+                    {
+                        self.visit_expr(expr);
+                        fill_children.push(self.children.pop().unwrap());
+                    }
+                    for item in fill.interpolate.iter() {
+                        let item_name = format!("Interpolate {}", item.column);
+                        let item_node = if let Some(expr) = &item.expr {
+                            self.visit_expr(expr);
+                            let item_format_ctx = AstFormatContext::with_children(item_name, 1);
+                            FormatTreeNode::with_children(item_format_ctx, vec![self
+                                .children
+                                .pop()
+                                .unwrap()])
+                        } else {
+                            FormatTreeNode::new(AstFormatContext::new(item_name))
+                        };
+                        fill_children.push(item_node);
+                    }
+                    let fill_name = "GroupByFill".to_string();
+                    let fill_format_ctx =
+                        AstFormatContext::with_children(fill_name, fill_children.len());
+                    let fill_node = FormatTreeNode::with_children(fill_format_ctx, fill_children);
+                    children.push(fill_node);
+                }
             }
         }
 
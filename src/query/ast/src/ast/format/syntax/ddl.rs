@@ -113,11 +113,12 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, primary_key) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(primary_key.map(|primary_key| RcDoc::text(primary_key.to_string()))),
             )
             .group(),
         )),
@@ -125,6 +126,7 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
             catalog,
             database,
             table,
+            including,
         } => RcDoc::space()
             .append(RcDoc::text("LIKE"))
             .append(RcDoc::space())
@@ -138,7 +140,10 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
             } else {
                 RcDoc::nil()
             })
-            .append(RcDoc::text(table.to_string())),
+            .append(RcDoc::text(table.to_string()))
+            .append(RcDoc::concat(including.into_iter().map(|option| {
+                RcDoc::space().append(RcDoc::text(option.to_string()))
+            }))),
     }
 }
 
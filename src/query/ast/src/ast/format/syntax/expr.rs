@@ -252,6 +252,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 distinct,
                 args,
                 params,
+                order_by,
                 window,
                 lambda,
             } = func;
@@ -293,6 +294,15 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
                 } else {
                     RcDoc::nil()
                 })
+                .append(if !order_by.is_empty() {
+                    RcDoc::text(" ORDER BY ").append(inline_comma(
+                        order_by
+                            .into_iter()
+                            .map(|order_by| RcDoc::text(order_by.to_string())),
+                    ))
+                } else {
+                    RcDoc::nil()
+                })
                 .append(RcDoc::text(")"))
                 .append(if let Some(window) = window {
                     RcDoc::text(" OVER (")
@@ -272,6 +272,33 @@ fn pretty_group_by(group_by: Option<GroupBy>) -> RcDoc<'static> {
                 )
                 .append(RcDoc::line())
                 .append(RcDoc::text(")")),
+            GroupBy::Fill(fill) => RcDoc::line()
+                .append(RcDoc::text("GROUP BY").append(RcDoc::space().nest(NEST_FACTOR)))
+                .append(pretty_expr(fill.expr))
+                .append(RcDoc::text(" WITH FILL FROM "))
+                .append(pretty_expr(fill.from))
+                .append(RcDoc::text(" TO "))
+                .append(pretty_expr(fill.to))
+                .append(if let Some(step) = fill.step {
+                    RcDoc::text(" STEP ").append(pretty_expr(step))
+                } else {
+                    RcDoc::nil()
+                })
+                .append(if !fill.interpolate.is_empty() {
+                    RcDoc::text(" INTERPOLATE (")
+                        .append(inline_comma(fill.interpolate.into_iter().map(|item| {
+                            RcDoc::text(item.column.to_string()).append(
+                                if let Some(expr) = item.expr {
+                                    RcDoc::text(" AS ").append(pretty_expr(expr))
+                                } else {
+                                    RcDoc::nil()
+                                },
+                            )
+                        })))
+                        .append(RcDoc::text(")"))
+                } else {
+                    RcDoc::nil()
+                }),
         }
     } else {
         RcDoc::nil()
@@ -216,6 +216,9 @@ impl Display for SelectStmt {
                     write_comma_separated_list(f, exprs)?;
                     write!(f, ")")?;
                 }
+                GroupBy::Fill(fill) => {
+                    write!(f, "{fill}")?;
+                }
             }
         }
 
@@ -249,6 +252,57 @@ pub enum GroupBy {
     Cube(Vec<Expr>),
     /// GROUP BY ROLLUP ( expr [, expr]* )
     Rollup(Vec<Expr>),
+    /// GROUP BY expr WITH FILL FROM expr TO expr [STEP expr] [INTERPOLATE ( InterpolateItem [, InterpolateItem]* )]
+    Fill(Box<GroupByFill>),
+}
+
+/// Group by a single expression, and fill the missing groups in `[from, to]` with `step`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct GroupByFill {
+    pub expr: Expr,
+    pub from: Expr,
+    pub to: Expr,
+    pub step: Option<Expr>,
+    pub interpolate: Vec<InterpolateItem>,
+}
+
+impl Display for GroupByFill {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} WITH FILL FROM {} TO {}",
+            self.expr, self.from, self.to
+        )?;
+        if let Some(step) = &self.step {
+            write!(f, " STEP {step}")?;
+        }
+        if !self.interpolate.is_empty() {
+            write!(f, " INTERPOLATE (")?;
+            write_comma_separated_list(f, &self.interpolate)?;
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// InterpolateItem := column [AS expr]
+///
+/// The value of the column in the filled groups is `expr` if specified,
+/// otherwise it is the value of the previous group.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct InterpolateItem {
+    pub column: Identifier,
+    pub expr: Option<Expr>,
+}
+
+impl Display for InterpolateItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.column)?;
+        if let Some(expr) = &self.expr {
+            write!(f, " AS {expr}")?;
+        }
+        Ok(())
+    }
 }
 
 /// A relational set expression, like `SELECT ... FROM ... {UNION|EXCEPT|INTERSECT} SELECT ... FROM ...`
@@ -19,7 +19,7 @@ use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use crate::ast::Identifier;
-use crate::ast::ShowLimit;
+use crate::ast::ShowOptions;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct ShowColumnsStmt {
@@ -28,7 +28,7 @@ pub struct ShowColumnsStmt {
     pub table: Identifier,
     #[drive(skip)]
     pub full: bool,
-    pub limit: Option<ShowLimit>,
+    pub show_options: Option<ShowOptions>,
 }
 
 impl Display for ShowColumnsStmt {
@@ -47,8 +47,8 @@ impl Display for ShowColumnsStmt {
             write!(f, "{database}")?;
         }
 
-        if let Some(limit) = &self.limit {
-            write!(f, " {limit}")?;
+        if let Some(show_options) = &self.show_options {
+            write!(f, " {show_options}")?;
         }
 
         Ok(())
@@ -21,17 +21,18 @@ use databend_common_meta_app::KeyWithTenant;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
-use crate::ast::statements::show::ShowLimit;
+use crate::ast::statements::show::ShowOptions;
 use crate::ast::write_dot_separated_list;
 use crate::ast::DatabaseRef;
 use crate::ast::Identifier;
+use crate::parser::unescape::escape_at_string;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct ShowDatabasesStmt {
     pub catalog: Option<Identifier>,
     #[drive(skip)]
     pub full: bool,
-    pub limit: Option<ShowLimit>,
+    pub show_options: Option<ShowOptions>,
 }
 
 impl Display for ShowDatabasesStmt {
@@ -44,8 +45,8 @@ impl Display for ShowDatabasesStmt {
         if let Some(catalog) = &self.catalog {
             write!(f, " FROM {catalog}")?;
         }
-        if let Some(limit) = &self.limit {
-            write!(f, " {limit}")?;
+        if let Some(show_options) = &self.show_options {
+            write!(f, " {show_options}")?;
         }
 
         Ok(())
@@ -168,6 +169,52 @@ impl Display for AlterDatabaseStmt {
     }
 }
 
+/// `BACKUP DATABASE <database> TO @<stage>/<path> [INCREMENTAL]`
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct BackupDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    #[drive(skip)]
+    pub location: String,
+    #[drive(skip)]
+    pub incremental: bool,
+}
+
+impl Display for BackupDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "BACKUP DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " TO @{}", escape_at_string(&self.location))?;
+        if self.incremental {
+            write!(f, " INCREMENTAL")?;
+        }
+        Ok(())
+    }
+}
+
+/// `RESTORE DATABASE <database> FROM @<stage>/<path> [BACKUP_ID = '<backup_id>']`
+#[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
+pub struct RestoreDatabaseStmt {
+    pub catalog: Option<Identifier>,
+    pub database: Identifier,
+    #[drive(skip)]
+    pub location: String,
+    #[drive(skip)]
+    pub backup_id: Option<String>,
+}
+
+impl Display for RestoreDatabaseStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RESTORE DATABASE ")?;
+        write_dot_separated_list(f, self.catalog.iter().chain(Some(&self.database)))?;
+        write!(f, " FROM @{}", escape_at_string(&self.location))?;
+        if let Some(backup_id) = &self.backup_id {
+            write!(f, " BACKUP_ID = '{backup_id}'")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub enum AlterDatabaseAction {
     RenameDatabase { new_db: Identifier },
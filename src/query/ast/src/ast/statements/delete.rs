@@ -18,8 +18,10 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::Expr;
 use crate::ast::Hint;
+use crate::ast::SelectTarget;
 use crate::ast::TableReference;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -27,6 +29,8 @@ pub struct DeleteStmt {
     pub hints: Option<Hint>,
     pub table: TableReference,
     pub selection: Option<Expr>,
+    /// `RETURNING <expr>, ...`, the expressions evaluated on the deleted rows.
+    pub returning: Vec<SelectTarget>,
 }
 
 impl Display for DeleteStmt {
@@ -39,6 +43,10 @@ impl Display for DeleteStmt {
         if let Some(conditions) = &self.selection {
             write!(f, " WHERE {conditions}")?;
         }
+        if !self.returning.is_empty() {
+            write!(f, " RETURNING ")?;
+            write_comma_separated_list(f, &self.returning)?;
+        }
         Ok(())
     }
 }
@@ -12,6 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
@@ -44,4 +47,24 @@ pub enum ExplainOption {
     Verbose,
     Logical,
     Optimized,
+    Format(#[drive(skip)] ExplainFormat),
+}
+
+/// Output format of `EXPLAIN`, set by `EXPLAIN(FORMAT JSON | GRAPHVIZ | TEXT)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExplainFormat {
+    #[default]
+    Text,
+    Json,
+    Graphviz,
+}
+
+impl Display for ExplainFormat {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ExplainFormat::Text => write!(f, "TEXT"),
+            ExplainFormat::Json => write!(f, "JSON"),
+            ExplainFormat::Graphviz => write!(f, "GRAPHVIZ"),
+        }
+    }
 }
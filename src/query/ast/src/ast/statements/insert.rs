@@ -25,6 +25,7 @@ use crate::ast::FileFormatOptions;
 use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Query;
+use crate::ast::SelectTarget;
 use crate::ast::With;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -39,6 +40,11 @@ pub struct InsertStmt {
     pub source: InsertSource,
     #[drive(skip)]
     pub overwrite: bool,
+    /// `ON CONFLICT DO UPDATE`, replace the existing rows which have the same primary key.
+    #[drive(skip)]
+    pub on_conflict_update: bool,
+    /// `RETURNING <expr>, ...`, the expressions evaluated on the inserted rows.
+    pub returning: Vec<SelectTarget>,
 }
 
 impl Display for InsertStmt {
@@ -67,7 +73,15 @@ impl Display for InsertStmt {
             write_comma_separated_list(f, &self.columns)?;
             write!(f, ")")?;
         }
-        write!(f, " {}", self.source)
+        if self.on_conflict_update {
+            write!(f, " ON CONFLICT DO UPDATE")?;
+        }
+        write!(f, " {}", self.source)?;
+        if !self.returning.is_empty() {
+            write!(f, " RETURNING ")?;
+            write_comma_separated_list(f, &self.returning)?;
+        }
+        Ok(())
     }
 }
 
@@ -28,11 +28,13 @@ mod insert_multi_table;
 mod kill;
 mod lock;
 mod merge_into;
+mod model;
 mod network_policy;
 mod notification;
 mod password_policy;
 mod pipe;
 mod presign;
+mod priority;
 mod procedure;
 mod replace;
 mod script;
@@ -66,11 +68,13 @@ pub use insert_multi_table::*;
 pub use kill::*;
 pub use lock::*;
 pub use merge_into::*;
+pub use model::*;
 pub use network_policy::*;
 pub use notification::*;
 pub use password_policy::*;
 pub use pipe::*;
 pub use presign::*;
+pub use priority::*;
 pub use procedure::*;
 pub use replace::*;
 pub use script::*;
@@ -0,0 +1,60 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use databend_common_meta_app::schema::CreateOption;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+
+use crate::ast::write_comma_separated_list;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
+/// `CREATE MODEL` registers an ONNX model file in a stage, so it can be
+/// evaluated with `ml_predict(<model_name>, <feature>, ...)`.
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateModelStmt {
+    #[drive(skip)]
+    pub create_option: CreateOption,
+    pub model_name: Identifier,
+    pub arg_types: Vec<TypeName>,
+    pub return_type: TypeName,
+    /// The stage location of the model file, without the leading `@`.
+    #[drive(skip)]
+    pub location: String,
+    #[drive(skip)]
+    pub description: Option<String>,
+}
+
+impl Display for CreateModelStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, " OR REPLACE")?;
+        }
+        write!(f, " MODEL")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {} (", self.model_name)?;
+        write_comma_separated_list(f, &self.arg_types)?;
+        write!(f, ") RETURNS {} FROM @{}", self.return_type, self.location)?;
+        if let Some(description) = &self.description {
+            write!(f, " DESC = '{description}'")?;
+        }
+        Ok(())
+    }
+}
@@ -15,9 +15,15 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use databend_common_meta_app::schema::CreateOption;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::write_comma_separated_list;
+use crate::ast::Expr;
+use crate::ast::Identifier;
+use crate::ast::TypeName;
+
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct ExecuteImmediateStmt {
     #[drive(skip)]
@@ -30,3 +36,104 @@ impl Display for ExecuteImmediateStmt {
         Ok(())
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Drive, DriveMut)]
+pub enum ProcedureExecuteAs {
+    /// Run the procedure body with the privileges of its owner.
+    #[default]
+    Owner,
+    /// Run the procedure body with the privileges of the calling user.
+    Caller,
+}
+
+impl Display for ProcedureExecuteAs {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcedureExecuteAs::Owner => write!(f, "OWNER"),
+            ProcedureExecuteAs::Caller => write!(f, "CALLER"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct ProcedureArg {
+    pub name: Identifier,
+    pub data_type: TypeName,
+}
+
+impl Display for ProcedureArg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CreateProcedureStmt {
+    #[drive(skip)]
+    pub create_option: CreateOption,
+    pub name: Identifier,
+    pub args: Vec<ProcedureArg>,
+    pub return_type: TypeName,
+    #[drive(skip)]
+    pub execute_as: ProcedureExecuteAs,
+    #[drive(skip)]
+    pub comment: Option<String>,
+    #[drive(skip)]
+    pub script: String,
+}
+
+impl Display for CreateProcedureStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE")?;
+        if let CreateOption::CreateOrReplace = self.create_option {
+            write!(f, " OR REPLACE")?;
+        }
+        write!(f, " PROCEDURE")?;
+        if let CreateOption::CreateIfNotExists = self.create_option {
+            write!(f, " IF NOT EXISTS")?;
+        }
+        write!(f, " {}(", self.name)?;
+        write_comma_separated_list(f, &self.args)?;
+        write!(f, ") RETURNS {} LANGUAGE SQL", self.return_type)?;
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{comment}'")?;
+        }
+        write!(
+            f,
+            " EXECUTE AS {} AS $$\n{}\n$$",
+            self.execute_as, self.script
+        )?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct DropProcedureStmt {
+    #[drive(skip)]
+    pub if_exists: bool,
+    pub name: Identifier,
+}
+
+impl Display for DropProcedureStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DROP PROCEDURE")?;
+        if self.if_exists {
+            write!(f, " IF EXISTS")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct CallProcedureStmt {
+    pub name: Identifier,
+    pub args: Vec<Expr>,
+}
+
+impl Display for CallProcedureStmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CALL PROCEDURE {}(", self.name)?;
+        write_comma_separated_list(f, &self.args)?;
+        write!(f, ")")
+    }
+}
@@ -17,6 +17,7 @@ use std::fmt::Formatter;
 
 use databend_common_exception::Span;
 
+use crate::ast::write_comma_separated_list;
 use crate::ast::Expr;
 use crate::ast::Identifier;
 use crate::ast::Statement;
@@ -28,6 +29,7 @@ pub struct ScriptBlock {
     pub span: Span,
     pub declares: Vec<DeclareItem>,
     pub body: Vec<ScriptStatement>,
+    pub exception_handlers: Vec<ExceptionHandler>,
 }
 
 impl Display for ScriptBlock {
@@ -48,11 +50,61 @@ impl Display for ScriptBlock {
                 indent::indent_all_by(INDENT_DEPTH, format!("{};", stmt))
             )?;
         }
+        if !self.exception_handlers.is_empty() {
+            writeln!(f, "EXCEPTION")?;
+            for handler in &self.exception_handlers {
+                writeln!(
+                    f,
+                    "{}",
+                    indent::indent_all_by(INDENT_DEPTH, handler.to_string())
+                )?;
+            }
+        }
         writeln!(f, "END;")?;
         Ok(())
     }
 }
 
+/// `WHEN { OTHER | <error_code> [, ...] } THEN <statements>` in the `EXCEPTION` section
+/// of a script block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExceptionHandler {
+    pub span: Span,
+    pub condition: ExceptionCondition,
+    pub body: Vec<ScriptStatement>,
+}
+
+impl Display for ExceptionHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "WHEN {} THEN", self.condition)?;
+        for stmt in &self.body {
+            write!(
+                f,
+                "\n{}",
+                indent::indent_all_by(INDENT_DEPTH, format!("{};", stmt))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExceptionCondition {
+    /// Catches errors with any of the given error codes.
+    ErrorCodes(Vec<u64>),
+    /// Catches all errors.
+    Other,
+}
+
+impl Display for ExceptionCondition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExceptionCondition::ErrorCodes(codes) => write_comma_separated_list(f, codes),
+            ExceptionCondition::Other => write!(f, "OTHER"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DeclareItem {
     Var(DeclareVar),
@@ -49,11 +49,14 @@ pub struct ShowOptions {
 
 impl Display for ShowOptions {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if let Some(limit_option) = self.show_limit.clone() {
+        if let Some(limit_option) = &self.show_limit {
             write!(f, "{}", limit_option)?;
+            if self.limit.is_some() {
+                write!(f, " ")?;
+            }
         }
         if let Some(limit) = self.limit {
-            write!(f, " LIMIT {limit}")?;
+            write!(f, "LIMIT {limit}")?;
         }
         Ok(())
     }
@@ -44,6 +44,8 @@ pub struct CreateStageStmt {
     pub validation_mode: String,
     #[drive(skip)]
     pub comments: String,
+    #[drive(skip)]
+    pub directory_enabled: bool,
 }
 
 impl Display for CreateStageStmt {
@@ -82,6 +84,10 @@ impl Display for CreateStageStmt {
             write!(f, " COMMENTS = '{}'", self.comments)?;
         }
 
+        if self.directory_enabled {
+            write!(f, " DIRECTORY = (ENABLE = TRUE)")?;
+        }
+
         Ok(())
     }
 }
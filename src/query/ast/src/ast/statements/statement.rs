@@ -45,6 +45,14 @@ pub enum Statement {
     ExplainAnalyze {
         query: Box<Statement>,
     },
+    // Bind the query and describe its result schema without executing it.
+    DescribeQuery {
+        query: Box<Query>,
+    },
+    WithPriority {
+        priority: QueryPriority,
+        stmt: Box<Statement>,
+    },
 
     CopyIntoTable(CopyIntoTableStmt),
     CopyIntoLocation(CopyIntoLocationStmt),
@@ -57,12 +65,16 @@ pub enum Statement {
     ShowProcessList {
         show_options: Option<ShowOptions>,
     },
+    ShowWarnings,
     ShowMetrics {
         show_options: Option<ShowOptions>,
     },
     ShowEngines {
         show_options: Option<ShowOptions>,
     },
+    ShowQuotas {
+        show_options: Option<ShowOptions>,
+    },
     ShowFunctions {
         show_options: Option<ShowOptions>,
     },
@@ -124,6 +136,8 @@ pub enum Statement {
     DropDatabase(DropDatabaseStmt),
     UndropDatabase(UndropDatabaseStmt),
     AlterDatabase(AlterDatabaseStmt),
+    BackupDatabase(BackupDatabaseStmt),
+    RestoreDatabase(RestoreDatabaseStmt),
     UseDatabase {
         database: Identifier,
     },
@@ -218,9 +232,19 @@ pub enum Statement {
     },
     AlterUDF(AlterUDFStmt),
 
+    // Models
+    CreateModel(CreateModelStmt),
+    DropModel {
+        #[drive(skip)]
+        if_exists: bool,
+        model_name: Identifier,
+    },
+
     // Stages
     CreateStage(CreateStageStmt),
-    ShowStages,
+    ShowStages {
+        show_options: Option<ShowOptions>,
+    },
     DropStage {
         #[drive(skip)]
         if_exists: bool,
@@ -231,6 +255,10 @@ pub enum Statement {
         #[drive(skip)]
         stage_name: String,
     },
+    RefreshStage {
+        #[drive(skip)]
+        stage_name: String,
+    },
     RemoveStage {
         #[drive(skip)]
         location: String,
@@ -328,6 +356,9 @@ pub enum Statement {
 
     // Stored procedures
     ExecuteImmediate(ExecuteImmediateStmt),
+    CreateProcedure(CreateProcedureStmt),
+    DropProcedure(DropProcedureStmt),
+    CallProcedure(CallProcedureStmt),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -391,9 +422,10 @@ impl Display for Statement {
                             .iter()
                             .map(|opt| {
                                 match opt {
-                                    ExplainOption::Verbose => "VERBOSE",
-                                    ExplainOption::Logical => "LOGICAL",
-                                    ExplainOption::Optimized => "OPTIMIZED",
+                                    ExplainOption::Verbose => "VERBOSE".to_string(),
+                                    ExplainOption::Logical => "LOGICAL".to_string(),
+                                    ExplainOption::Optimized => "OPTIMIZED".to_string(),
+                                    ExplainOption::Format(format) => format!("FORMAT {format}"),
                                 }
                             })
                             .join(", ")
@@ -417,6 +449,12 @@ impl Display for Statement {
             Statement::ExplainAnalyze { query } => {
                 write!(f, "EXPLAIN ANALYZE {query}")?;
             }
+            Statement::DescribeQuery { query } => {
+                write!(f, "DESCRIBE {query}")?;
+            }
+            Statement::WithPriority { priority, stmt } => {
+                write!(f, "PRIORITY {priority} {stmt}")?;
+            }
             Statement::Query(stmt) => write!(f, "{stmt}")?,
             Statement::Insert(stmt) => write!(f, "{stmt}")?,
             Statement::InsertMultiTable(insert_multi_table) => write!(f, "{insert_multi_table}")?,
@@ -438,6 +476,7 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::ShowWarnings => write!(f, "SHOW WARNINGS")?,
             Statement::ShowMetrics { show_options } => {
                 write!(f, "SHOW METRICS")?;
                 if let Some(show_options) = show_options {
@@ -450,6 +489,12 @@ impl Display for Statement {
                     write!(f, " {show_options}")?;
                 }
             }
+            Statement::ShowQuotas { show_options } => {
+                write!(f, "SHOW QUOTAS")?;
+                if let Some(show_options) = show_options {
+                    write!(f, " {show_options}")?;
+                }
+            }
             Statement::ShowIndexes { show_options } => {
                 write!(f, "SHOW INDEXES")?;
                 if let Some(show_options) = show_options {
@@ -525,6 +570,8 @@ impl Display for Statement {
             Statement::DropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UndropDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::AlterDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::BackupDatabase(stmt) => write!(f, "{stmt}")?,
+            Statement::RestoreDatabase(stmt) => write!(f, "{stmt}")?,
             Statement::UseDatabase { database } => write!(f, "USE {database}")?,
             Statement::ShowTables(stmt) => write!(f, "{stmt}")?,
             Statement::ShowColumns(stmt) => write!(f, "{stmt}")?,
@@ -617,13 +664,29 @@ impl Display for Statement {
                 write!(f, " {udf_name}")?;
             }
             Statement::AlterUDF(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateModel(stmt) => write!(f, "{stmt}")?,
+            Statement::DropModel {
+                if_exists,
+                model_name,
+            } => {
+                write!(f, "DROP MODEL")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {model_name}")?;
+            }
             Statement::ListStage { location, pattern } => {
                 write!(f, "LIST @{location}")?;
                 if let Some(pattern) = pattern {
                     write!(f, " PATTERN = '{pattern}'")?;
                 }
             }
-            Statement::ShowStages => write!(f, "SHOW STAGES")?,
+            Statement::ShowStages { show_options } => {
+                write!(f, "SHOW STAGES")?;
+                if let Some(show_options) = show_options {
+                    write!(f, " {show_options}")?;
+                }
+            }
             Statement::DropStage {
                 if_exists,
                 stage_name,
@@ -642,6 +705,9 @@ impl Display for Statement {
                 }
             }
             Statement::DescribeStage { stage_name } => write!(f, "DESC STAGE {stage_name}")?,
+            Statement::RefreshStage { stage_name } => {
+                write!(f, "ALTER STAGE {stage_name} REFRESH")?
+            }
             Statement::CreateFileFormat {
                 create_option,
                 name,
@@ -721,6 +787,9 @@ impl Display for Statement {
             Statement::DescribeNotification(stmt) => write!(f, "{stmt}")?,
 
             Statement::ExecuteImmediate(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateProcedure(stmt) => write!(f, "{stmt}")?,
+            Statement::DropProcedure(stmt) => write!(f, "{stmt}")?,
+            Statement::CallProcedure(stmt) => write!(f, "{stmt}")?,
         }
         Ok(())
     }
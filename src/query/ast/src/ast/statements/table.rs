@@ -22,6 +22,7 @@ use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
 use crate::ast::statements::show::ShowLimit;
+use crate::ast::statements::show::ShowOptions;
 use crate::ast::write_comma_separated_list;
 use crate::ast::write_comma_separated_string_map;
 use crate::ast::write_dot_separated_list;
@@ -40,7 +41,7 @@ pub struct ShowTablesStmt {
     pub database: Option<Identifier>,
     #[drive(skip)]
     pub full: bool,
-    pub limit: Option<ShowLimit>,
+    pub show_options: Option<ShowOptions>,
     #[drive(skip)]
     pub with_history: bool,
 }
@@ -62,8 +63,8 @@ impl Display for ShowTablesStmt {
             }
             write!(f, "{database}")?;
         }
-        if let Some(limit) = &self.limit {
-            write!(f, " {limit}")?;
+        if let Some(show_options) = &self.show_options {
+            write!(f, " {show_options}")?;
         }
 
         Ok(())
@@ -234,29 +235,54 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Option<PrimaryKeyDefinition>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
         table: Identifier,
+        including: Vec<CreateTableLikeOption>,
     },
 }
 
+/// What `CREATE TABLE ... LIKE` copies from the source table besides the columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum CreateTableLikeOption {
+    Options,
+    ClusterKeys,
+}
+
+impl Display for CreateTableLikeOption {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            CreateTableLikeOption::Options => write!(f, "INCLUDING OPTIONS"),
+            CreateTableLikeOption::ClusterKeys => write!(f, "INCLUDING CLUSTER KEYS"),
+        }
+    }
+}
+
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, primary_key) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if let Some(primary_key) = primary_key {
+                    write!(f, ", {primary_key}")?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
                 catalog,
                 database,
                 table,
+                including,
             } => {
                 write!(f, "LIKE ")?;
-                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                for option in including {
+                    write!(f, " {option}")?;
+                }
+                Ok(())
             }
         }
     }
@@ -290,6 +316,8 @@ pub struct DropTableStmt {
     pub table: Identifier,
     #[drive(skip)]
     pub all: bool,
+    #[drive(skip)]
+    pub cascade: bool,
 }
 
 impl Display for DropTableStmt {
@@ -308,6 +336,9 @@ impl Display for DropTableStmt {
         if self.all {
             write!(f, " ALL")?;
         }
+        if self.cascade {
+            write!(f, " CASCADE")?;
+        }
 
         Ok(())
     }
@@ -755,6 +786,7 @@ pub enum OptimizeTableAction {
     All,
     Purge { before: Option<TimeTravelPoint> },
     Compact { target: CompactTarget },
+    Tier,
 }
 
 impl Display for OptimizeTableAction {
@@ -779,6 +811,7 @@ impl Display for OptimizeTableAction {
                 }
                 Ok(())
             }
+            OptimizeTableAction::Tier => write!(f, "TIER"),
         }
     }
 }
@@ -835,6 +868,25 @@ impl Display for ColumnDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
+pub struct PrimaryKeyDefinition {
+    pub columns: Vec<Identifier>,
+    #[drive(skip)]
+    pub enforced: bool,
+}
+
+impl Display for PrimaryKeyDefinition {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "PRIMARY KEY (")?;
+        write_comma_separated_list(f, &self.columns)?;
+        write!(f, ")")?;
+        if self.enforced {
+            write!(f, " ENFORCED")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
@@ -23,12 +23,18 @@ use crate::ast::Identifier;
 
 #[derive(Debug, Clone, PartialEq, Eq, Drive, DriveMut)]
 pub struct UnSetStmt {
+    /// Only unset the session value, the global value takes effect again.
+    #[drive(skip)]
+    pub session: bool,
     pub source: UnSetSource,
 }
 
 impl Display for UnSetStmt {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         write!(f, "UNSET ")?;
+        if self.session {
+            write!(f, "SESSION ")?;
+        }
         write!(f, "{}", self.source)
     }
 }
@@ -22,6 +22,7 @@ use crate::ast::write_comma_separated_list;
 use crate::ast::Expr;
 use crate::ast::Hint;
 use crate::ast::Identifier;
+use crate::ast::SelectTarget;
 use crate::ast::TableReference;
 
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
@@ -30,6 +31,8 @@ pub struct UpdateStmt {
     pub table: TableReference,
     pub update_list: Vec<UpdateExpr>,
     pub selection: Option<Expr>,
+    /// `RETURNING <expr>, ...`, the expressions evaluated on the updated rows.
+    pub returning: Vec<SelectTarget>,
 }
 
 impl Display for UpdateStmt {
@@ -43,6 +46,10 @@ impl Display for UpdateStmt {
         if let Some(conditions) = &self.selection {
             write!(f, " WHERE {conditions}")?;
         }
+        if !self.returning.is_empty() {
+            write!(f, " RETURNING ")?;
+            write_comma_separated_list(f, &self.returning)?;
+        }
         Ok(())
     }
 }
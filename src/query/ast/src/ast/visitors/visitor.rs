@@ -232,6 +232,7 @@ pub trait Visitor<'ast>: Sized {
         _name: &'ast Identifier,
         args: &'ast [Expr],
         params: &'ast [Expr],
+        order_by: &'ast [OrderByExpr],
         over: &'ast Option<Window>,
         lambda: &'ast Option<Lambda>,
     ) {
@@ -241,6 +242,9 @@ pub trait Visitor<'ast>: Sized {
         for param in params {
             walk_expr(self, param);
         }
+        for order_by in order_by {
+            walk_expr(self, &order_by.expr);
+        }
 
         if let Some(over) = over {
             self.visit_window(over);
@@ -396,10 +400,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_show_process_list(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_show_warnings(&mut self) {}
+
     fn visit_show_metrics(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
     fn visit_show_engines(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
+    fn visit_show_quotas(&mut self, _show_options: &'ast Option<ShowOptions>) {}
+
     fn visit_show_functions(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
     fn visit_show_user_functions(&mut self, _show_options: &'ast Option<ShowOptions>) {}
@@ -508,6 +516,10 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_database(&mut self, _stmt: &'ast AlterDatabaseStmt) {}
 
+    fn visit_backup_database(&mut self, _stmt: &'ast BackupDatabaseStmt) {}
+
+    fn visit_restore_database(&mut self, _stmt: &'ast RestoreDatabaseStmt) {}
+
     fn visit_use_database(&mut self, _database: &'ast Identifier) {}
 
     fn visit_show_tables(&mut self, _stmt: &'ast ShowTablesStmt) {}
@@ -620,14 +632,20 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_alter_udf(&mut self, _stmt: &'ast AlterUDFStmt) {}
 
+    fn visit_create_model(&mut self, _stmt: &'ast CreateModelStmt) {}
+
+    fn visit_drop_model(&mut self, _if_exists: bool, _model_name: &'ast Identifier) {}
+
     fn visit_create_stage(&mut self, _stmt: &'ast CreateStageStmt) {}
 
-    fn visit_show_stages(&mut self) {}
+    fn visit_show_stages(&mut self, _show_options: &'ast Option<ShowOptions>) {}
 
     fn visit_drop_stage(&mut self, _if_exists: bool, _stage_name: &'ast str) {}
 
     fn visit_describe_stage(&mut self, _stage_name: &'ast str) {}
 
+    fn visit_refresh_stage(&mut self, _stage_name: &'ast str) {}
+
     fn visit_remove_stage(&mut self, _location: &'ast str, _pattern: &'ast str) {}
 
     fn visit_list_stage(&mut self, _location: &'ast str, _pattern: &'ast Option<String>) {}
@@ -772,6 +790,19 @@ pub trait Visitor<'ast>: Sized {
                     }
                 }
             }
+            Some(GroupBy::Fill(fill)) => {
+                walk_expr(self, &fill.expr);
+                walk_expr(self, &fill.from);
+                walk_expr(self, &fill.to);
+                if let Some(step) = &fill.step {
+                    walk_expr(self, step);
+                }
+                for item in &fill.interpolate {
+                    if let Some(expr) = &item.expr {
+                        walk_expr(self, expr);
+                    }
+                }
+            }
             _ => {}
         }
 
@@ -246,6 +246,7 @@ pub trait VisitorMut: Sized {
         _name: &mut Identifier,
         args: &mut Vec<Expr>,
         params: &mut Vec<Expr>,
+        order_by: &mut Vec<OrderByExpr>,
         over: &mut Option<Window>,
         lambda: &mut Option<Lambda>,
     ) {
@@ -255,6 +256,9 @@ pub trait VisitorMut: Sized {
         for param in params.iter_mut() {
             Self::visit_expr(self, param);
         }
+        for order_by in order_by.iter_mut() {
+            Self::visit_expr(self, &mut order_by.expr);
+        }
 
         if let Some(over) = over {
             match over {
@@ -408,10 +412,14 @@ pub trait VisitorMut: Sized {
 
     fn visit_show_process_list(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_show_warnings(&mut self) {}
+
     fn visit_show_metrics(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_show_engines(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
+    fn visit_show_quotas(&mut self, _show_options: &mut Option<ShowOptions>) {}
+
     fn visit_show_functions(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_show_user_functions(&mut self, _show_options: &mut Option<ShowOptions>) {}
@@ -520,6 +528,10 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_database(&mut self, _stmt: &mut AlterDatabaseStmt) {}
 
+    fn visit_backup_database(&mut self, _stmt: &mut BackupDatabaseStmt) {}
+
+    fn visit_restore_database(&mut self, _stmt: &mut RestoreDatabaseStmt) {}
+
     fn visit_use_database(&mut self, _database: &mut Identifier) {}
 
     fn visit_show_tables(&mut self, _stmt: &mut ShowTablesStmt) {}
@@ -632,14 +644,20 @@ pub trait VisitorMut: Sized {
 
     fn visit_alter_udf(&mut self, _stmt: &mut AlterUDFStmt) {}
 
+    fn visit_create_model(&mut self, _stmt: &mut CreateModelStmt) {}
+
+    fn visit_drop_model(&mut self, _if_exists: bool, _model_name: &mut Identifier) {}
+
     fn visit_create_stage(&mut self, _stmt: &mut CreateStageStmt) {}
 
-    fn visit_show_stages(&mut self) {}
+    fn visit_show_stages(&mut self, _show_options: &mut Option<ShowOptions>) {}
 
     fn visit_drop_stage(&mut self, _if_exists: bool, _stage_name: &mut String) {}
 
     fn visit_describe_stage(&mut self, _stage_name: &mut String) {}
 
+    fn visit_refresh_stage(&mut self, _stage_name: &mut String) {}
+
     fn visit_remove_stage(&mut self, _location: &mut String, _pattern: &mut String) {}
 
     fn visit_list_stage(&mut self, _location: &mut String, _pattern: &mut Option<String>) {}
@@ -786,6 +804,19 @@ pub trait VisitorMut: Sized {
                     }
                 }
             }
+            Some(GroupBy::Fill(fill)) => {
+                Self::visit_expr(self, &mut fill.expr);
+                Self::visit_expr(self, &mut fill.from);
+                Self::visit_expr(self, &mut fill.to);
+                if let Some(step) = &mut fill.step {
+                    Self::visit_expr(self, step);
+                }
+                for item in &mut fill.interpolate {
+                    if let Some(expr) = &mut item.expr {
+                        Self::visit_expr(self, expr);
+                    }
+                }
+            }
             _ => {}
         }
 
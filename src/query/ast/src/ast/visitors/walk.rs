@@ -107,10 +107,13 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expr: &'a Expr) {
                     name,
                     args,
                     params,
+                    order_by,
                     window,
                     lambda,
                 },
-        } => visitor.visit_function_call(*span, *distinct, name, args, params, window, lambda),
+        } => visitor.visit_function_call(
+            *span, *distinct, name, args, params, order_by, window, lambda,
+        ),
         Expr::Case {
             span,
             operand,
@@ -402,6 +405,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             query,
         } => visitor.visit_explain(kind, options, query),
         Statement::ExplainAnalyze { query } => visitor.visit_statement(query),
+        Statement::DescribeQuery { query } => visitor.visit_query(query),
+        Statement::WithPriority { stmt, .. } => visitor.visit_statement(stmt),
         Statement::Query(query) => visitor.visit_query(query),
         Statement::Insert(insert) => visitor.visit_insert(insert),
         Statement::Replace(replace) => visitor.visit_replace(replace),
@@ -414,8 +419,10 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::ShowProcessList { show_options } => {
             visitor.visit_show_process_list(show_options)
         }
+        Statement::ShowWarnings => visitor.visit_show_warnings(),
         Statement::ShowMetrics { show_options } => visitor.visit_show_metrics(show_options),
         Statement::ShowEngines { show_options } => visitor.visit_show_engines(show_options),
+        Statement::ShowQuotas { show_options } => visitor.visit_show_quotas(show_options),
         Statement::ShowFunctions { show_options } => visitor.visit_show_functions(show_options),
         Statement::ShowUserFunctions { show_options } => {
             visitor.visit_show_user_functions(show_options)
@@ -450,6 +457,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::DropDatabase(stmt) => visitor.visit_drop_database(stmt),
         Statement::UndropDatabase(stmt) => visitor.visit_undrop_database(stmt),
         Statement::AlterDatabase(stmt) => visitor.visit_alter_database(stmt),
+        Statement::BackupDatabase(stmt) => visitor.visit_backup_database(stmt),
+        Statement::RestoreDatabase(stmt) => visitor.visit_restore_database(stmt),
         Statement::UseDatabase { database } => visitor.visit_use_database(database),
         Statement::ShowTables(stmt) => visitor.visit_show_tables(stmt),
         Statement::ShowColumns(stmt) => visitor.visit_show_columns(stmt),
@@ -511,8 +520,13 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
             udf_name,
         } => visitor.visit_drop_udf(*if_exists, udf_name),
         Statement::AlterUDF(stmt) => visitor.visit_alter_udf(stmt),
+        Statement::CreateModel(stmt) => visitor.visit_create_model(stmt),
+        Statement::DropModel {
+            if_exists,
+            model_name,
+        } => visitor.visit_drop_model(*if_exists, model_name),
         Statement::ListStage { location, pattern } => visitor.visit_list_stage(location, pattern),
-        Statement::ShowStages => visitor.visit_show_stages(),
+        Statement::ShowStages { show_options } => visitor.visit_show_stages(show_options),
         Statement::DropStage {
             if_exists,
             stage_name,
@@ -531,6 +545,7 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         }
         Statement::ShowFileFormats => visitor.visit_show_file_formats(),
         Statement::DescribeStage { stage_name } => visitor.visit_describe_stage(stage_name),
+        Statement::RefreshStage { stage_name } => visitor.visit_refresh_stage(stage_name),
         Statement::Call(stmt) => visitor.visit_call(stmt),
         Statement::Presign(stmt) => visitor.visit_presign(stmt),
         Statement::CreateShareEndpoint(stmt) => visitor.visit_create_share_endpoint(stmt),
@@ -586,5 +601,8 @@ pub fn walk_statement<'a, V: Visitor<'a>>(visitor: &mut V, statement: &'a Statem
         Statement::Abort => {}
         Statement::InsertMultiTable(_) => {}
         Statement::ExecuteImmediate(_) => {}
+        Statement::CreateProcedure(_) => {}
+        Statement::DropProcedure(_) => {}
+        Statement::CallProcedure(_) => {}
     }
 }
@@ -29,6 +29,7 @@ use crate::ast::ColumnID;
 use crate::ast::DatabaseRef;
 use crate::ast::Identifier;
 use crate::ast::TableRef;
+use crate::parser::expr::literal_string;
 use crate::parser::input::Input;
 use crate::parser::input::WithSpan;
 use crate::parser::quote::unquote_ident;
@@ -157,8 +158,16 @@ fn quoted_identifier(i: Input) -> IResult<Identifier> {
     })
 }
 
+/// Parse `IDENTIFIER('db.t')` whose object name is resolved at bind time, or
+/// `IDENTIFIER(:var)` in SQL template.
 fn identifier_hole(i: Input) -> IResult<Identifier> {
-    check_template_mode(map(
+    let dynamic = map(
+        consumed(rule! {
+            IDENTIFIER ~ ^"(" ~ #literal_string ~ ^")"
+        }),
+        |(span, (_, _, name, _))| Identifier::from_dynamic_name(transform_span(span.tokens), name),
+    );
+    let hole = check_template_mode(map(
         consumed(rule! {
             IDENTIFIER ~ ^"(" ~ ^#template_hole ~ ^")"
         }),
@@ -168,7 +177,12 @@ fn identifier_hole(i: Input) -> IResult<Identifier> {
             quote: None,
             is_hole: true,
         },
-    ))(i)
+    ));
+
+    rule!(
+        #dynamic
+        | #hole
+    )(i)
 }
 
 fn non_reserved_identifier(
@@ -618,6 +618,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                             args: vec![source],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: Some(Lambda {
                                 params: vec![param.clone()],
                                 expr: Box::new(filter),
@@ -634,6 +635,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                         args: vec![source],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: Some(Lambda {
                             params: vec![param.clone()],
                             expr: Box::new(result),
@@ -767,6 +769,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                     name,
                     args: [vec![lhs], args].concat(),
                     params: vec![],
+                    order_by: vec![],
                     window: None,
                     lambda,
                 },
@@ -1005,14 +1008,15 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     let function_call = map(
         rule! {
             #function_name
-            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ #aggregate_order_by? ~ ")"
         },
-        |(name, _, opt_distinct, opt_args, _)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, opt_order_by, _)| ExprElement::FunctionCall {
             func: FunctionCall {
                 distinct: opt_distinct.is_some(),
                 name,
                 args: opt_args.unwrap_or_default(),
                 params: vec![],
+                order_by: opt_order_by.unwrap_or_default(),
                 window: None,
                 lambda: None,
             },
@@ -1030,6 +1034,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 args: vec![arg],
                 params: vec![],
                 window: None,
+                order_by: vec![],
                 lambda: Some(Lambda {
                     params,
                     expr: Box::new(expr),
@@ -1040,15 +1045,16 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
     let function_call_with_window = map(
         rule! {
             #function_name
-            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ ")"
+            ~ "(" ~ DISTINCT? ~ #comma_separated_list0(subexpr(0))? ~ #aggregate_order_by? ~ ")"
             ~ (OVER ~ #window_spec_ident)
         },
-        |(name, _, opt_distinct, opt_args, _, window)| ExprElement::FunctionCall {
+        |(name, _, opt_distinct, opt_args, opt_order_by, _, window)| ExprElement::FunctionCall {
             func: FunctionCall {
                 distinct: opt_distinct.is_some(),
                 name,
                 args: opt_args.unwrap_or_default(),
                 params: vec![],
+                order_by: opt_order_by.unwrap_or_default(),
                 window: Some(window.1),
                 lambda: None,
             },
@@ -1067,6 +1073,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 args: opt_args.unwrap_or_default(),
                 params: params.map(|(_, x, _)| x).unwrap_or_default(),
                 window: None,
+                order_by: vec![],
                 lambda: None,
             },
         },
@@ -1249,6 +1256,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.tokens),
+                value: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1264,6 +1284,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 args: vec![],
                 params: vec![],
                 window: None,
+                order_by: vec![],
                 lambda: None,
             },
         }
@@ -1287,6 +1308,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
             | #date_expr: "`DATE <str_literal>`"
             | #timestamp_expr: "`TIMESTAMP <str_literal>`"
             | #interval: "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+            | #interval_expr: "`INTERVAL <str_literal>`"
             | #pg_cast : "`::<type_name>`"
             | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
             | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
@@ -1646,6 +1668,18 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
+    let ty_vector = map_res(
+        rule! { VECTOR ~ "(" ~ ( FLOAT32 | FLOAT ) ~ "," ~ ^#literal_u64 ~ ^")" },
+        |(_, _, _, _, dimension, _)| {
+            if dimension == 0 {
+                return Err(nom::Err::Failure(ErrorKind::Other(
+                    "vector dimension must be greater than 0",
+                )));
+            }
+            Ok(TypeName::Vector(dimension))
+        },
+    );
     map_res(
         alt((
             rule! {
@@ -1675,6 +1709,8 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_string
             | #ty_variant
             | #ty_geometry
+            | #ty_interval
+            | #ty_vector : "VECTOR(FLOAT32, <dimension>)"
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -16,14 +16,18 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 use super::input::ParseMode;
+use super::statement::hint;
 use super::statement::insert_stmt;
 use super::statement::replace_stmt;
 use crate::ast::Expr;
+use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Statement;
 use crate::parser::common::comma_separated_list0;
 use crate::parser::common::comma_separated_list1;
 use crate::parser::common::ident;
+use crate::parser::common::match_text;
+use crate::parser::common::separated_list1;
 use crate::parser::common::transform_span;
 use crate::parser::common::IResult;
 use crate::parser::error::display_parser_error;
@@ -91,6 +95,13 @@ pub fn parse_comma_separated_idents(tokens: &[Token], dialect: Dialect) -> Resul
     })
 }
 
+/// Parse the dot separated object name referenced by `IDENTIFIER('<name>')`, e.g. `db.t`.
+pub fn parse_dot_separated_idents(tokens: &[Token], dialect: Dialect) -> Result<Vec<Identifier>> {
+    run_parser(tokens, dialect, ParseMode::Default, false, |i| {
+        separated_list1(match_text("."), ident)(i)
+    })
+}
+
 pub fn parse_values_with_placeholder(
     tokens: &[Token],
     dialect: Dialect,
@@ -124,6 +135,30 @@ pub fn parse_raw_replace_stmt(tokens: &[Token], dialect: Dialect) -> Result<Stat
     )
 }
 
+/// Parse the hint at the beginning of the SQL, e.g. `/*+ SET_VAR(max_threads=4) */ SELECT ...`,
+/// which applies to the whole statement.
+pub fn parse_leading_hint(sql: &str, dialect: Dialect) -> Result<Option<Hint>> {
+    let mut tokenizer = Tokenizer::new(sql).with_leading_hint();
+    let mut tokens = match tokenizer.next() {
+        Some(Ok(token)) if token.kind == TokenKind::HintPrefix => vec![token],
+        _ => return Ok(None),
+    };
+    for token in tokenizer {
+        let token = token?;
+        match token.kind {
+            // not a complete hint, let the statement parser report the error (if any)
+            TokenKind::EOI => return Ok(None),
+            TokenKind::HintSuffix => {
+                tokens.push(token);
+                break;
+            }
+            _ => tokens.push(token),
+        }
+    }
+    tokens.push(Token::new_eoi(sql));
+    run_parser(&tokens, dialect, ParseMode::Default, false, hint).map(Some)
+}
+
 pub fn run_parser<O>(
     tokens: &[Token],
     dialect: Dialect,
@@ -640,6 +640,16 @@ pub fn order_by_expr(i: Input) -> IResult<OrderByExpr> {
     )(i)
 }
 
+/// The `ORDER BY` in the arguments of an aggregate function call, like `ARRAY_AGG(a ORDER BY b)`.
+pub fn aggregate_order_by(i: Input) -> IResult<Vec<OrderByExpr>> {
+    map(
+        rule! {
+            ORDER ~ ^BY ~ ^#comma_separated_list1(order_by_expr)
+        },
+        |(_, _, order_by)| order_by,
+    )(i)
+}
+
 pub fn table_reference(i: Input) -> IResult<TableReference> {
     let (rest, table_reference_elements) = rule! { #table_reference_element+ }(i)?;
     let iter = &mut table_reference_elements.into_iter();
@@ -1007,7 +1017,31 @@ pub fn group_by_items(i: Input) -> IResult<GroupBy> {
         rule! { GROUPING ~ SETS ~ "(" ~ ^#comma_separated_list1(group_set) ~ ")"  },
         |(_, _, _, sets, _)| GroupBy::GroupingSets(sets),
     );
-    rule!(#all | #group_sets | #cube | #rollup | #normal)(i)
+    let interpolate_item = map(rule! { #ident ~ ( AS ~ ^#expr )? }, |(column, opt_expr)| {
+        InterpolateItem {
+            column,
+            expr: opt_expr.map(|(_, expr)| expr),
+        }
+    });
+    let fill = map(
+        rule! {
+            #expr ~ WITH ~ ^FILL ~ ^FROM ~ ^#expr ~ ^TO ~ ^#expr
+            ~ ( STEP ~ ^#expr )?
+            ~ ( INTERPOLATE ~ ^"(" ~ ^#comma_separated_list1(interpolate_item) ~ ^")" )?
+        },
+        |(expr, _, _, _, from, _, to, opt_step, opt_interpolate)| {
+            GroupBy::Fill(Box::new(GroupByFill {
+                expr,
+                from,
+                to,
+                step: opt_step.map(|(_, step)| step),
+                interpolate: opt_interpolate
+                    .map(|(_, _, items, _)| items)
+                    .unwrap_or_default(),
+            }))
+        },
+    );
+    rule!(#all | #group_sets | #cube | #rollup | #fill | #normal)(i)
 }
 
 pub fn window_frame_bound(i: Input) -> IResult<WindowFrameBound> {
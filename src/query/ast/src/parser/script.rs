@@ -29,20 +29,45 @@ pub fn script_block(i: Input) -> IResult<ScriptBlock> {
             ( DECLARE ~ #semicolon_terminated_list1(declare_item) )?
             ~ BEGIN
             ~ #semicolon_terminated_list1(script_stmt)
+            ~ ( EXCEPTION ~ #exception_handler+ )?
             ~ END
             ~ ";"
         }),
-        |(span, (declares, _, body, _, _))| {
+        |(span, (declares, _, body, exception_handlers, _, _))| {
             let declares = declares.map(|(_, declare)| declare).unwrap_or_default();
+            let exception_handlers = exception_handlers
+                .map(|(_, handlers)| handlers)
+                .unwrap_or_default();
             ScriptBlock {
                 span: transform_span(span.tokens),
                 declares,
                 body,
+                exception_handlers,
             }
         },
     )(i)
 }
 
+pub fn exception_handler(i: Input) -> IResult<ExceptionHandler> {
+    let other = map(rule! { OTHER }, |_| ExceptionCondition::Other);
+    let error_codes = map(
+        rule! { #comma_separated_list1(literal_u64) },
+        ExceptionCondition::ErrorCodes,
+    );
+
+    map(
+        consumed(rule! {
+            WHEN ~ ^( #other | #error_codes ) ~ ^THEN
+            ~ ^#semicolon_terminated_list1(script_stmt)
+        }),
+        |(span, (_, condition, _, body))| ExceptionHandler {
+            span: transform_span(span.tokens),
+            condition,
+            body,
+        },
+    )(i)
+}
+
 pub fn declare_item(i: Input) -> IResult<DeclareItem> {
     let declare_var = map(declare_var, DeclareItem::Var);
     let declare_set = map(declare_set, DeclareItem::Set);
@@ -77,7 +77,7 @@ pub fn connection_options(i: Input) -> IResult<BTreeMap<String, String>> {
 pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
     let option_type = map(
         rule! {
-            TYPE ~ "=" ~ ( TSV | CSV | NDJSON | PARQUET | JSON | XML )
+            TYPE ~ "=" ~ ( TSV | CSV | NDJSON | PARQUET | JSON | XML | AVRO | XLSX )
         },
         |(_, _, v)| {
             (
@@ -100,7 +100,7 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
     );
 
     let ident_options = map(
-        rule! { (BINARY_FORMAT | MISSING_FIELD_AS | EMPTY_FIELD_AS | NULL_FIELD_AS)  ~ "=" ~ (NULL | STRING | Ident)},
+        rule! { (BINARY_FORMAT | MISSING_FIELD_AS | EMPTY_FIELD_AS | NULL_FIELD_AS | EXTRA_FIELD)  ~ "=" ~ (NULL | STRING | VARIANT | Ident)},
         |(k, _, v)| {
             (
                 k.text().to_string(),
@@ -122,6 +122,8 @@ pub fn format_options(i: Input) -> IResult<FileFormatOptions> {
                 | ESCAPE
                 | NULL_FIELD_AS
                 | MISSING_FIELD_AS
+                | EXTRA_FIELD
+                | SHEET_NAME
                 | ROW_TAG) ~ ^"=" ~ ^#literal_string
         },
         |(k, _, v)| (k.text().to_string(), FileFormatValue::String(v)),
@@ -30,6 +30,7 @@ use nom::branch::alt;
 use nom::combinator::consumed;
 use nom::combinator::map;
 use nom::combinator::value;
+use nom::combinator::verify;
 use nom::Slice;
 
 use crate::ast::*;
@@ -106,6 +107,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             query: Box::new(statement.stmt),
         },
     );
+    let describe_query = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ #query
+        },
+        |(_, query)| Statement::DescribeQuery {
+            query: Box::new(query),
+        },
+    );
 
     let create_task = map(
         rule! {
@@ -248,12 +257,14 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         rule! {
             DELETE ~ #hint? ~ FROM ~ #table_reference_with_alias
             ~ ( WHERE ~ ^#expr )?
+            ~ #returning?
         },
-        |(_, hints, _, table, opt_selection)| {
+        |(_, hints, _, table, opt_selection, opt_returning)| {
             Statement::Delete(DeleteStmt {
                 hints,
                 table,
                 selection: opt_selection.map(|(_, selection)| selection),
+                returning: opt_returning.unwrap_or_default(),
             })
         },
     );
@@ -263,13 +274,15 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             UPDATE ~ #hint? ~ #table_reference_only
             ~ SET ~ ^#comma_separated_list1(update_expr)
             ~ ( WHERE ~ ^#expr )?
+            ~ #returning?
         },
-        |(_, hints, table, _, update_list, opt_selection)| {
+        |(_, hints, table, _, update_list, opt_selection, opt_returning)| {
             Statement::Update(UpdateStmt {
                 hints,
                 table,
                 update_list,
                 selection: opt_selection.map(|(_, selection)| selection),
+                returning: opt_returning.unwrap_or_default(),
             })
         },
     );
@@ -280,13 +293,19 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
         |(_, _, show_options)| Statement::ShowSettings { show_options },
     );
-    let show_stages = value(Statement::ShowStages, rule! { SHOW ~ STAGES });
+    let show_stages = map(
+        rule! {
+            SHOW ~ STAGES ~ #non_empty_show_options?
+        },
+        |(_, _, show_options)| Statement::ShowStages { show_options },
+    );
     let show_process_list = map(
         rule! {
             SHOW ~ PROCESSLIST ~ #show_options?
         },
         |(_, _, show_options)| Statement::ShowProcessList { show_options },
     );
+    let show_warnings = value(Statement::ShowWarnings, rule! { SHOW ~ WARNINGS });
     let show_metrics = map(
         rule! {
             SHOW ~ METRICS ~ #show_options?
@@ -299,6 +318,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
         |(_, _, show_options)| Statement::ShowEngines { show_options },
     );
+    let show_quotas = map(
+        rule! {
+            SHOW ~ QUOTAS ~ #show_options?
+        },
+        |(_, _, show_options)| Statement::ShowQuotas { show_options },
+    );
     let show_functions = map(
         rule! {
             SHOW ~ FUNCTIONS ~ #show_options?
@@ -359,10 +384,11 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let unset_variable = map(
         rule! {
-            UNSET ~ #unset_source
+            UNSET ~ SESSION? ~ #unset_source
         },
-        |(_, unset_source)| {
+        |(_, opt_session, unset_source)| {
             Statement::UnSetVariable(UnSetStmt {
+                session: opt_session.is_some(),
                 source: unset_source,
             })
         },
@@ -437,13 +463,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let show_databases = map(
         rule! {
-            SHOW ~ FULL? ~ ( DATABASES | SCHEMAS ) ~ ( ( FROM | IN ) ~ ^#ident )? ~ #show_limit?
+            SHOW ~ FULL? ~ ( DATABASES | SCHEMAS ) ~ ( ( FROM | IN ) ~ ^#ident )? ~ #non_empty_show_options?
         },
-        |(_, opt_full, _, opt_catalog, limit)| {
+        |(_, opt_full, _, opt_catalog, show_options)| {
             Statement::ShowDatabases(ShowDatabasesStmt {
                 catalog: opt_catalog.map(|(_, catalog)| catalog),
                 full: opt_full.is_some(),
-                limit,
+                show_options,
             })
         },
     );
@@ -536,6 +562,33 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let backup_database = map(
+        rule! {
+            BACKUP ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ TO ~ #stage_location ~ INCREMENTAL?
+        },
+        |(_, _, (catalog, database), _, location, opt_incremental)| {
+            Statement::BackupDatabase(BackupDatabaseStmt {
+                catalog,
+                database,
+                location,
+                incremental: opt_incremental.is_some(),
+            })
+        },
+    );
+    let restore_database = map(
+        rule! {
+            RESTORE ~ DATABASE ~ #dot_separated_idents_1_to_2 ~ FROM ~ #stage_location
+            ~ ( BACKUP_ID ~ ^"=" ~ ^#literal_string )?
+        },
+        |(_, _, (catalog, database), _, location, opt_backup_id)| {
+            Statement::RestoreDatabase(RestoreDatabaseStmt {
+                catalog,
+                database,
+                location,
+                backup_id: opt_backup_id.map(|(_, _, backup_id)| backup_id),
+            })
+        },
+    );
     let use_database = map(
         rule! {
             USE ~ #ident
@@ -544,9 +597,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let show_tables = map(
         rule! {
-            SHOW ~ FULL? ~ TABLES ~ HISTORY? ~ ( ( FROM | IN ) ~ #dot_separated_idents_1_to_2 )? ~ #show_limit?
+            SHOW ~ FULL? ~ TABLES ~ HISTORY? ~ ( ( FROM | IN ) ~ #dot_separated_idents_1_to_2 )? ~ #non_empty_show_options?
         },
-        |(_, opt_full, _, opt_history, ctl_db, limit)| {
+        |(_, opt_full, _, opt_history, ctl_db, show_options)| {
             let (catalog, database) = match ctl_db {
                 Some((_, (Some(c), d))) => (Some(c), Some(d)),
                 Some((_, (None, d))) => (None, Some(d)),
@@ -556,7 +609,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 catalog,
                 database,
                 full: opt_full.is_some(),
-                limit,
+                show_options,
                 with_history: opt_history.is_some(),
             })
         },
@@ -567,9 +620,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ FULL? ~ COLUMNS
             ~ ( FROM | IN ) ~ #ident
             ~ (( FROM | IN ) ~ ^#dot_separated_idents_1_to_2)?
-            ~ #show_limit?
+            ~ #non_empty_show_options?
         },
-        |(_, opt_full, _, _, table, ctl_db, limit)| {
+        |(_, opt_full, _, _, table, ctl_db, show_options)| {
             let (catalog, database) = match ctl_db {
                 Some((_, (Some(c), d))) => (Some(c), Some(d)),
                 Some((_, (None, d))) => (None, Some(d)),
@@ -580,7 +633,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                 database,
                 table,
                 full: opt_full.is_some(),
-                limit,
+                show_options,
             })
         },
     );
@@ -706,15 +759,16 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
     );
     let drop_table = map(
         rule! {
-            DROP ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ ALL?
+            DROP ~ TABLE ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3 ~ ALL? ~ CASCADE?
         },
-        |(_, _, opt_if_exists, (catalog, database, table), opt_all)| {
+        |(_, _, opt_if_exists, (catalog, database, table), opt_all, opt_cascade)| {
             Statement::DropTable(DropTableStmt {
                 if_exists: opt_if_exists.is_some(),
                 catalog,
                 database,
                 table,
                 all: opt_all.is_some(),
+                cascade: opt_cascade.is_some(),
             })
         },
     );
@@ -1341,6 +1395,52 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    // models
+    let create_model = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ MODEL ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ RETURNS ~ #udf_arg_type
+            ~ FROM ~ #at_string
+            ~ ( DESC ~ ^"=" ~ ^#literal_string )?
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            model_name,
+            _,
+            arg_types,
+            _,
+            _,
+            return_type,
+            _,
+            location,
+            opt_description,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            Ok(Statement::CreateModel(CreateModelStmt {
+                create_option,
+                model_name,
+                arg_types,
+                return_type,
+                location,
+                description: opt_description.map(|(_, _, description)| description),
+            }))
+        },
+    );
+    let drop_model = map(
+        rule! {
+            DROP ~ MODEL ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, model_name)| Statement::DropModel {
+            if_exists: opt_if_exists.is_some(),
+            model_name,
+        },
+    );
+
     // stages
     let create_stage = map_res(
         rule! {
@@ -1352,6 +1452,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             ~ ( SIZE_LIMIT ~ ^"=" ~ ^#literal_u64 )?
             ~ ( VALIDATION_MODE ~ ^"=" ~ ^#ident )?
             ~ ( (COMMENT | COMMENTS) ~ ^"=" ~ ^#literal_string )?
+            ~ ( DIRECTORY ~ ^"=" ~ ^"(" ~ ^ENABLE ~ ^"=" ~ ^#literal_bool ~ ^")" )?
         },
         |(
             _,
@@ -1365,6 +1466,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             size_limit_opt,
             validation_mode_opt,
             comment_opt,
+            directory_opt,
         )| {
             let create_option =
                 parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
@@ -1379,10 +1481,20 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
                     .map(|v| v.2.to_string())
                     .unwrap_or_default(),
                 comments: comment_opt.map(|v| v.2).unwrap_or_default(),
+                directory_enabled: directory_opt.map(|v| v.5).unwrap_or_default(),
             }))
         },
     );
 
+    let refresh_stage = map(
+        rule! {
+            ALTER ~ STAGE ~ #stage_name ~ REFRESH
+        },
+        |(_, _, stage_name, _)| Statement::RefreshStage {
+            stage_name: stage_name.to_string(),
+        },
+    );
+
     let list_stage = map(
         rule! {
             LIST ~ #at_string ~ (PATTERN ~ "=" ~ #literal_string)?
@@ -2031,6 +2143,71 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
         |(_, _, script)| Statement::ExecuteImmediate(ExecuteImmediateStmt { script }),
     );
+    let create_procedure = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ PROCEDURE ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ^"(" ~ ^#comma_separated_list0(procedure_arg) ~ ^")"
+            ~ ^RETURNS ~ ^#type_name
+            ~ ^LANGUAGE ~ ^#ident
+            ~ ( COMMENT ~ ^"=" ~ ^#literal_string )?
+            ~ ( EXECUTE ~ ^AS ~ ^#procedure_execute_as )?
+            ~ ^AS ~ ^#code_string
+        },
+        |(
+            _,
+            opt_or_replace,
+            _,
+            opt_if_not_exists,
+            name,
+            _,
+            args,
+            _,
+            _,
+            return_type,
+            _,
+            language,
+            opt_comment,
+            opt_execute_as,
+            _,
+            script,
+        )| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            if !language.name.eq_ignore_ascii_case("sql") {
+                return Err(nom::Err::Failure(ErrorKind::Other(
+                    "only LANGUAGE SQL is supported for procedures",
+                )));
+            }
+            Ok(Statement::CreateProcedure(CreateProcedureStmt {
+                create_option,
+                name,
+                args,
+                return_type,
+                execute_as: opt_execute_as
+                    .map(|(_, _, execute_as)| execute_as)
+                    .unwrap_or_default(),
+                comment: opt_comment.map(|(_, _, comment)| comment),
+                script,
+            }))
+        },
+    );
+    let drop_procedure = map(
+        rule! {
+            DROP ~ PROCEDURE ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, opt_if_exists, name)| {
+            Statement::DropProcedure(DropProcedureStmt {
+                if_exists: opt_if_exists.is_some(),
+                name,
+            })
+        },
+    );
+    let call_procedure = map(
+        rule! {
+            CALL ~ PROCEDURE ~ ^#ident ~ ^"(" ~ ^#comma_separated_list0(expr) ~ ^")"
+        },
+        |(_, _, name, _, args, _)| Statement::CallProcedure(CallProcedureStmt { name, args }),
+    );
 
     alt((
         // query, explain,show
@@ -2038,10 +2215,13 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #map(query, |query| Statement::Query(Box::new(query)))
             | #explain : "`EXPLAIN [PIPELINE | GRAPH] <statement>`"
             | #explain_analyze : "`EXPLAIN ANALYZE <statement>`"
+            | #describe_query : "`DESCRIBE <query>`"
             | #show_settings : "`SHOW SETTINGS [<show_limit>]`"
             | #show_stages : "`SHOW STAGES`"
             | #show_engines : "`SHOW ENGINES`"
+            | #show_quotas : "`SHOW QUOTAS [<show_limit>]`"
             | #show_process_list : "`SHOW PROCESSLIST`"
+            | #show_warnings : "`SHOW WARNINGS`"
             | #show_metrics : "`SHOW METRICS`"
             | #show_functions : "`SHOW FUNCTIONS [<show_limit>]`"
             | #show_indexes : "`SHOW INDEXES`"
@@ -2057,6 +2237,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_database : "`CREATE [OR REPLACE] DATABASE [IF NOT EXISTS] <database> [ENGINE = <engine>]`"
             | #drop_database : "`DROP DATABASE [IF EXISTS] <database>`"
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
+            | #backup_database : "`BACKUP DATABASE <database> TO @<stage>/<path> [INCREMENTAL]`"
+            | #restore_database : "`RESTORE DATABASE <database> FROM @<stage>/<path> [BACKUP_ID = '<backup_id>']`"
             | #use_database : "`USE <database>`"
         ),
         // network policy / password policy
@@ -2083,7 +2265,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
-            | #unset_variable : "`UNSET <variable>`"
+            | #unset_variable : "`UNSET [SESSION] <variable>`"
             | #begin
             | #commit
             | #abort
@@ -2143,6 +2325,8 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
+            | #create_model : "`CREATE [OR REPLACE] MODEL [IF NOT EXISTS] <name> (<arg_type>, ...) RETURNS <return_type> FROM @<stage_path> [DESC = <description>]`"
+            | #drop_model : "`DROP MODEL [IF EXISTS] <model_name>`"
             | #set_role: "`SET [DEFAULT] ROLE <role>`"
             | #set_secondary_roles: "`SET SECONDARY ROLES (ALL | NONE)`"
             | #show_user_functions : "`SHOW USER FUNCTIONS [<show_limit>]`"
@@ -2151,7 +2335,9 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             #create_stage: "`CREATE [OR REPLACE] STAGE [ IF NOT EXISTS ] <stage_name>
                 [ FILE_FORMAT = ( { TYPE = { CSV | PARQUET } [ formatTypeOptions ] ) } ]
                 [ COPY_OPTIONS = ( copyOptions ) ]
-                [ COMMENT = '<string_literal>' ]`"
+                [ COMMENT = '<string_literal>' ]
+                [ DIRECTORY = ( ENABLE = { TRUE | FALSE } ) ]`"
+            | #refresh_stage: "`ALTER STAGE <stage_name> REFRESH`"
             | #desc_stage: "`DESC STAGE <stage_name>`"
             | #list_stage: "`LIST @<stage_name> [pattern = '<pattern>']`"
             | #remove_stage: "`REMOVE @<stage_name> [pattern = '<pattern>']`"
@@ -2234,6 +2420,9 @@ AS
             | #desc_connection: "`DESC | DESCRIBE CONNECTION  <connection_name>`"
             | #show_connections: "`SHOW CONNECTIONS`"
             | #execute_immediate : "`EXECUTE IMMEDIATE $$ <script> $$`"
+            | #create_procedure : "`CREATE [OR REPLACE] PROCEDURE [IF NOT EXISTS] <name>(<arg> <type>, ...) RETURNS <type> LANGUAGE SQL [COMMENT = '<comment>'] [EXECUTE AS { OWNER | CALLER }] AS $$ <script> $$`"
+            | #drop_procedure : "`DROP PROCEDURE [IF EXISTS] <name>`"
+            | #call_procedure : "`CALL PROCEDURE <name>(<expr>, ...)`"
         ),
     ))(i)
 }
@@ -2241,10 +2430,16 @@ AS
 pub fn statement(i: Input) -> IResult<StatementWithFormat> {
     map(
         rule! {
-            #statement_body ~ ( FORMAT ~ ^#ident )? ~ ";"? ~ &EOI
+            ( PRIORITY ~ ^#query_priority )? ~ #statement_body ~ ( FORMAT ~ ^#ident )? ~ ";"? ~ &EOI
         },
-        |(stmt, opt_format, _, _)| StatementWithFormat {
-            stmt,
+        |(opt_priority, stmt, opt_format, _, _)| StatementWithFormat {
+            stmt: match opt_priority {
+                Some((_, priority)) => Statement::WithPriority {
+                    priority,
+                    stmt: Box::new(stmt),
+                },
+                None => stmt,
+            },
             format: opt_format.map(|(_, format)| format.name),
         },
     )(i)
@@ -2276,7 +2471,9 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 #with? ~ INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
                 ~ #dot_separated_idents_1_to_3
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
+                ~ ( ON ~ ^CONFLICT ~ ^DO ~ ^UPDATE )?
                 ~ #insert_source_parser
+                ~ #returning?
             },
             |(
                 with,
@@ -2286,7 +2483,9 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 _,
                 (catalog, database, table),
                 opt_columns,
+                opt_on_conflict,
                 source,
+                opt_returning,
             )| {
                 Statement::Insert(InsertStmt {
                     hints: opt_hints,
@@ -2299,6 +2498,8 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                         .unwrap_or_default(),
                     source,
                     overwrite: overwrite.kind == OVERWRITE,
+                    on_conflict_update: opt_on_conflict.is_some(),
+                    returning: opt_returning.unwrap_or_default(),
                 })
             },
         )(i)
@@ -2440,6 +2641,16 @@ pub fn replace_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement>
 }
 
 // `VALUES (expr, expr), (expr, expr)`
+/// `RETURNING <expr> [AS <alias>], ...` of INSERT, UPDATE and DELETE.
+pub fn returning(i: Input) -> IResult<Vec<SelectTarget>> {
+    map(
+        rule! {
+            RETURNING ~ ^#comma_separated_list1(select_target)
+        },
+        |(_, targets)| targets,
+    )(i)
+}
+
 pub fn insert_source(i: Input) -> IResult<InsertSource> {
     let row = map(
         rule! {
@@ -2699,6 +2910,32 @@ pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     Ok((i, def))
 }
 
+enum TableElement {
+    Column(ColumnDefinition),
+    PrimaryKey(PrimaryKeyDefinition),
+}
+
+fn table_element(i: Input) -> IResult<TableElement> {
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")" ~ ( NOT? ~ ENFORCED )?
+        },
+        |(_, _, _, columns, _, opt_enforced)| {
+            TableElement::PrimaryKey(PrimaryKeyDefinition {
+                columns,
+                enforced: matches!(opt_enforced, Some((None, _))),
+            })
+        },
+    );
+
+    let column = map(column_def, TableElement::Column);
+
+    rule!(
+        #primary_key : "`PRIMARY KEY (<column>, ...) [[NOT] ENFORCED]`"
+        | #column
+    )(i)
+}
+
 pub fn role_name(i: Input) -> IResult<String> {
     let role_ident = map(
         rule! {
@@ -2760,6 +2997,26 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
         },
     );
 
+    let procedure_privs = map(
+        rule! {
+            USAGE ~ ON ~ PROCEDURE ~ #ident
+        },
+        |(_, _, _, procedure)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level: AccountMgrLevel::Procedure(procedure.to_string()),
+        },
+    );
+
+    let procedure_all_privs = map(
+        rule! {
+            ALL ~ PRIVILEGES? ~ ON ~ PROCEDURE ~ #ident
+        },
+        |(_, _, _, _, procedure)| AccountMgrSource::Privs {
+            privileges: vec![UserPrivilegeType::Usage],
+            level: AccountMgrLevel::Procedure(procedure.to_string()),
+        },
+    );
+
     let stage_privs = map(
         rule! {
             #comma_separated_list1(stage_priv_type) ~ ON ~ STAGE ~ #ident
@@ -2773,9 +3030,11 @@ pub fn grant_source(i: Input) -> IResult<AccountMgrSource> {
     rule!(
         #role : "ROLE <role_name>"
         | #udf_privs: "USAGE ON UDF <udf_name>"
+        | #procedure_privs: "USAGE ON PROCEDURE <procedure_name>"
         | #privs : "<privileges> ON <privileges_level>"
         | #stage_privs : "<stage_privileges> ON STAGE <stage_name>"
         | #udf_all_privs: "ALL [ PRIVILEGES ] ON UDF <udf_name>"
+        | #procedure_all_privs: "ALL [ PRIVILEGES ] ON PROCEDURE <procedure_name>"
         | #all : "ALL [ PRIVILEGES ] ON <privileges_level>"
     )(i)
 }
@@ -2937,10 +3196,12 @@ pub fn grant_ownership_level(i: Input) -> IResult<AccountMgrLevel> {
     enum Object {
         Stage,
         Udf,
+        Procedure,
     }
     let object = alt((
         value(Object::Udf, rule! { UDF }),
         value(Object::Stage, rule! { STAGE }),
+        value(Object::Procedure, rule! { PROCEDURE }),
     ));
 
     // Object object_name
@@ -2949,13 +3210,14 @@ pub fn grant_ownership_level(i: Input) -> IResult<AccountMgrLevel> {
         |(object, object_name)| match object {
             Object::Stage => AccountMgrLevel::Stage(object_name.to_string()),
             Object::Udf => AccountMgrLevel::UDF(object_name.to_string()),
+            Object::Procedure => AccountMgrLevel::Procedure(object_name.to_string()),
         },
     );
 
     rule!(
         #db : "<database>.*"
         | #table : "<database>.<table>"
-        | #object : "STAGE | UDF <object_name>"
+        | #object : "STAGE | UDF | PROCEDURE <object_name>"
     )(i)
 }
 
@@ -3010,20 +3272,42 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
 }
 
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
-    let columns = map(
-        rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+    let columns = map_res(
+        rule! {
+            "(" ~ ^#comma_separated_list1(table_element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut primary_key = None;
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::PrimaryKey(def) => {
+                        if primary_key.replace(def).is_some() {
+                            return Err(nom::Err::Failure(ErrorKind::Other(
+                                "multiple primary keys are not allowed",
+                            )));
+                        }
+                    }
+                }
+            }
+            if columns.is_empty() {
+                return Err(nom::Err::Failure(ErrorKind::Other(
+                    "at least one column must be defined",
+                )));
+            }
+            Ok(CreateTableSource::Columns(columns, primary_key))
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
-            LIKE ~ #dot_separated_idents_1_to_3
+            LIKE ~ #dot_separated_idents_1_to_3 ~ #create_table_like_option*
         },
-        |(_, (catalog, database, table))| CreateTableSource::Like {
+        |(_, (catalog, database, table), including)| CreateTableSource::Like {
             catalog,
             database,
             table,
+            including,
         },
     );
 
@@ -3033,6 +3317,19 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
     )(i)
 }
 
+pub fn create_table_like_option(i: Input) -> IResult<CreateTableLikeOption> {
+    alt((
+        value(
+            CreateTableLikeOption::Options,
+            rule! { INCLUDING ~ OPTIONS },
+        ),
+        value(
+            CreateTableLikeOption::ClusterKeys,
+            rule! { INCLUDING ~ CLUSTER ~ KEYS },
+        ),
+    ))(i)
+}
+
 pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
     let mut rename_database = map(
         rule! {
@@ -3375,6 +3672,7 @@ pub fn optimize_table_action(i: Input) -> IResult<OptimizeTableAction> {
                 target: opt_segment.map_or(CompactTarget::Block, |_| CompactTarget::Segment),
             }
         }),
+        value(OptimizeTableAction::Tier, rule! { TIER }),
     ))(i)
 }
 
@@ -3611,6 +3909,14 @@ pub fn kill_target(i: Input) -> IResult<KillTarget> {
     ))(i)
 }
 
+pub fn query_priority(i: Input) -> IResult<QueryPriority> {
+    alt((
+        value(QueryPriority::Low, rule! { LOW }),
+        value(QueryPriority::Normal, rule! { NORMAL }),
+        value(QueryPriority::High, rule! { HIGH }),
+    ))(i)
+}
+
 pub fn limit_where(i: Input) -> IResult<ShowLimit> {
     map(
         rule! {
@@ -3650,6 +3956,13 @@ pub fn show_options(i: Input) -> IResult<ShowOptions> {
     )(i)
 }
 
+/// `show_options` with a filter or a limit, so the statement keeps `None` without them.
+pub fn non_empty_show_options(i: Input) -> IResult<ShowOptions> {
+    verify(show_options, |show_options: &ShowOptions| {
+        show_options.show_limit.is_some() || show_options.limit.is_some()
+    })(i)
+}
+
 pub fn table_option(i: Input) -> IResult<BTreeMap<String, String>> {
     map(
         rule! {
@@ -3742,6 +4055,9 @@ pub fn catalog_type(i: Input) -> IResult<CatalogType> {
         value(CatalogType::Default, rule! { DEFAULT }),
         value(CatalogType::Hive, rule! { HIVE }),
         value(CatalogType::Iceberg, rule! { ICEBERG }),
+        map(rule! { #ident }, |typ| {
+            CatalogType::Custom(typ.name.to_lowercase())
+        }),
     ))(i)
 }
 
@@ -3966,6 +4282,19 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
     )(i)
 }
 
+pub fn procedure_arg(i: Input) -> IResult<ProcedureArg> {
+    map(rule! { #ident ~ #type_name }, |(name, data_type)| {
+        ProcedureArg { name, data_type }
+    })(i)
+}
+
+pub fn procedure_execute_as(i: Input) -> IResult<ProcedureExecuteAs> {
+    alt((
+        value(ProcedureExecuteAs::Owner, rule! { OWNER }),
+        value(ProcedureExecuteAs::Caller, rule! { CALLER }),
+    ))(i)
+}
+
 pub fn merge_update_expr(i: Input) -> IResult<MergeUpdateExpr> {
     map(
         rule! { #dot_separated_idents_1_to_2 ~ "=" ~ ^#expr },
@@ -4090,7 +4419,18 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
 }
 
 pub fn explain_option(i: Input) -> IResult<ExplainOption> {
-    map(
+    let format = map(
+        rule! {
+            FORMAT ~ ( TEXT | JSON | GRAPHVIZ )
+        },
+        |(_, format)| match &format.kind {
+            TEXT => ExplainOption::Format(ExplainFormat::Text),
+            JSON => ExplainOption::Format(ExplainFormat::Json),
+            GRAPHVIZ => ExplainOption::Format(ExplainFormat::Graphviz),
+            _ => unreachable!(),
+        },
+    );
+    let flag = map(
         rule! {
             VERBOSE | LOGICAL | OPTIMIZED
         },
@@ -4100,6 +4440,11 @@ pub fn explain_option(i: Input) -> IResult<ExplainOption> {
             OPTIMIZED => ExplainOption::Optimized,
             _ => unreachable!(),
         },
+    );
+
+    rule!(
+        #format
+        | #flag
     )(i)
 }
 
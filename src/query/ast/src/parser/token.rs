@@ -54,6 +54,7 @@ pub struct Tokenizer<'a> {
     lexer: Lexer<'a, TokenKind>,
     prev_token: Option<TokenKind>,
     eoi: bool,
+    allow_leading_hint: bool,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -63,8 +64,16 @@ impl<'a> Tokenizer<'a> {
             lexer: TokenKind::lexer(source),
             eoi: false,
             prev_token: None,
+            allow_leading_hint: false,
         }
     }
+
+    /// Keep the hint at the beginning of the source, e.g. `/*+ SET_VAR(max_threads=4) */ SELECT ...`,
+    /// which is skipped as a comment by default.
+    pub fn with_leading_hint(mut self) -> Self {
+        self.allow_leading_hint = true;
+        self
+    }
 }
 
 impl<'a> Iterator for Tokenizer<'a> {
@@ -88,7 +97,8 @@ impl<'a> Iterator for Tokenizer<'a> {
                             | TokenKind::DELETE
                             | TokenKind::COPY
                     )
-                ) && kind == TokenKind::HintPrefix
+                ) && !(self.allow_leading_hint && self.prev_token.is_none())
+                    && kind == TokenKind::HintPrefix
                 {
                     loop {
                         match self.next() {
@@ -340,6 +350,8 @@ pub enum TokenKind {
     ARGS,
     #[token("AUTO", ignore(ascii_case))]
     AUTO,
+    #[token("AVRO", ignore(ascii_case))]
+    AVRO,
     #[token("SOME", ignore(ascii_case))]
     SOME,
     #[token("ALTER", ignore(ascii_case))]
@@ -366,6 +378,10 @@ pub enum TokenKind {
     ASYNC,
     #[token("ATTACH", ignore(ascii_case))]
     ATTACH,
+    #[token("BACKUP", ignore(ascii_case))]
+    BACKUP,
+    #[token("BACKUP_ID", ignore(ascii_case))]
+    BACKUP_ID,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
     #[token("BETWEEN", ignore(ascii_case))]
@@ -404,6 +420,10 @@ pub enum TokenKind {
     BZ2,
     #[token("CALL", ignore(ascii_case))]
     CALL,
+    #[token("CALLER", ignore(ascii_case))]
+    CALLER,
+    #[token("CASCADE", ignore(ascii_case))]
+    CASCADE,
     #[token("CASE", ignore(ascii_case))]
     CASE,
     #[token("CAST", ignore(ascii_case))]
@@ -500,6 +520,8 @@ pub enum TokenKind {
     DETAILED_OUTPUT,
     #[token("DESCRIBE", ignore(ascii_case))]
     DESCRIBE,
+    #[token("DIRECTORY", ignore(ascii_case))]
+    DIRECTORY,
     #[token("DISABLE_VARIANT_CHECK", ignore(ascii_case))]
     DISABLE_VARIANT_CHECK,
     #[token("DISTINCT", ignore(ascii_case))]
@@ -528,18 +550,24 @@ pub enum TokenKind {
     DRY,
     #[token("EXCEPT", ignore(ascii_case))]
     EXCEPT,
+    #[token("EXCEPTION", ignore(ascii_case))]
+    EXCEPTION,
     #[token("EXCLUDE", ignore(ascii_case))]
     EXCLUDE,
     #[token("ELSE", ignore(ascii_case))]
     ELSE,
     #[token("EMPTY_FIELD_AS", ignore(ascii_case))]
     EMPTY_FIELD_AS,
+    #[token("ENABLE", ignore(ascii_case))]
+    ENABLE,
     #[token("ENABLE_VIRTUAL_HOST_STYLE", ignore(ascii_case))]
     ENABLE_VIRTUAL_HOST_STYLE,
     #[token("END", ignore(ascii_case))]
     END,
     #[token("ENDPOINT", ignore(ascii_case))]
     ENDPOINT,
+    #[token("ENFORCED", ignore(ascii_case))]
+    ENFORCED,
     #[token("ENGINE", ignore(ascii_case))]
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
@@ -558,6 +586,8 @@ pub enum TokenKind {
     EXPIRE,
     #[token("EXTRACT", ignore(ascii_case))]
     EXTRACT,
+    #[token("EXTRA_FIELD", ignore(ascii_case))]
+    EXTRA_FIELD,
     #[token("ELSEIF", ignore(ascii_case))]
     ELSEIF,
     #[token("FALSE", ignore(ascii_case))]
@@ -578,6 +608,8 @@ pub enum TokenKind {
     FILE,
     #[token("FILES", ignore(ascii_case))]
     FILES,
+    #[token("FILL", ignore(ascii_case))]
+    FILL,
     #[token("FINAL", ignore(ascii_case))]
     FINAL,
     #[token("FLASHBACK", ignore(ascii_case))]
@@ -624,12 +656,16 @@ pub enum TokenKind {
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
     GRAPH,
+    #[token("GRAPHVIZ", ignore(ascii_case))]
+    GRAPHVIZ,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HIGH", ignore(ascii_case))]
+    HIGH,
     #[token("HISTORY", ignore(ascii_case))]
     HISTORY,
     #[token("HIVE", ignore(ascii_case))]
@@ -642,6 +678,8 @@ pub enum TokenKind {
     ICEBERG,
     #[token("INTERSECT", ignore(ascii_case))]
     INTERSECT,
+    #[token("INTERPOLATE", ignore(ascii_case))]
+    INTERPOLATE,
     #[token("IDENTIFIED", ignore(ascii_case))]
     IDENTIFIED,
     #[token("IDENTIFIER", ignore(ascii_case))]
@@ -650,6 +688,10 @@ pub enum TokenKind {
     IF,
     #[token("IN", ignore(ascii_case))]
     IN,
+    #[token("INCLUDING", ignore(ascii_case))]
+    INCLUDING,
+    #[token("INCREMENTAL", ignore(ascii_case))]
+    INCREMENTAL,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
     #[token("INFORMATION", ignore(ascii_case))]
@@ -694,6 +736,8 @@ pub enum TokenKind {
     JWT,
     #[token("KEY", ignore(ascii_case))]
     KEY,
+    #[token("KEYS", ignore(ascii_case))]
+    KEYS,
     #[token("KILL", ignore(ascii_case))]
     KILL,
     #[token("LATERAL", ignore(ascii_case))]
@@ -706,6 +750,8 @@ pub enum TokenKind {
     LOGICAL,
     #[token("LOOP", ignore(ascii_case))]
     LOOP,
+    #[token("LOW", ignore(ascii_case))]
+    LOW,
     #[token("SECONDARY", ignore(ascii_case))]
     SECONDARY,
     #[token("ROLES", ignore(ascii_case))]
@@ -751,6 +797,8 @@ pub enum TokenKind {
     MINUTE,
     #[token("MONTH", ignore(ascii_case))]
     MONTH,
+    #[token("MODEL", ignore(ascii_case))]
+    MODEL,
     #[token("MODIFY", ignore(ascii_case))]
     MODIFY,
     #[token("MATERIALIZED", ignore(ascii_case))]
@@ -769,6 +817,8 @@ pub enum TokenKind {
     NO_PASSWORD,
     #[token("NONE", ignore(ascii_case))]
     NONE,
+    #[token("NORMAL", ignore(ascii_case))]
+    NORMAL,
     #[token("NOT", ignore(ascii_case))]
     NOT,
     #[token("NOTENANTSETTING", ignore(ascii_case))]
@@ -795,6 +845,8 @@ pub enum TokenKind {
     OR,
     #[token("ORDER", ignore(ascii_case))]
     ORDER,
+    #[token("OTHER", ignore(ascii_case))]
+    OTHER,
     #[token("OUTPUT_HEADER", ignore(ascii_case))]
     OUTPUT_HEADER,
     #[token("OUTER", ignore(ascii_case))]
@@ -805,6 +857,8 @@ pub enum TokenKind {
     OVER,
     #[token("OVERWRITE", ignore(ascii_case))]
     OVERWRITE,
+    #[token("OWNER", ignore(ascii_case))]
+    OWNER,
     #[token("PARTITION", ignore(ascii_case))]
     PARTITION,
     #[token("PARQUET", ignore(ascii_case))]
@@ -845,6 +899,8 @@ pub enum TokenKind {
     POLICY,
     #[token("POSITION", ignore(ascii_case))]
     POSITION,
+    #[token("PROCEDURE", ignore(ascii_case))]
+    PROCEDURE,
     #[token("PROCESSLIST", ignore(ascii_case))]
     PROCESSLIST,
     #[token("PURGE", ignore(ascii_case))]
@@ -853,6 +909,8 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUOTAS", ignore(ascii_case))]
+    QUOTAS,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
@@ -875,6 +933,8 @@ pub enum TokenKind {
     RENAME,
     #[token("REPLACE", ignore(ascii_case))]
     REPLACE,
+    #[token("RESTORE", ignore(ascii_case))]
+    RESTORE,
     #[token("RETURN_FAILED_ONLY", ignore(ascii_case))]
     RETURN_FAILED_ONLY,
     #[token("REVERSE", ignore(ascii_case))]
@@ -907,6 +967,10 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
+    #[token("PRIORITY", ignore(ascii_case))]
+    PRIORITY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
@@ -923,6 +987,8 @@ pub enum TokenKind {
     RETURN,
     #[token("RETURNS", ignore(ascii_case))]
     RETURNS,
+    #[token("RETURNING", ignore(ascii_case))]
+    RETURNING,
     #[token("RESULTSET", ignore(ascii_case))]
     RESULTSET,
     #[token("RUN", ignore(ascii_case))]
@@ -955,6 +1021,8 @@ pub enum TokenKind {
     SET,
     #[token("UNSET", ignore(ascii_case))]
     UNSET,
+    #[token("SESSION", ignore(ascii_case))]
+    SESSION,
     #[token("SETTINGS", ignore(ascii_case))]
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
@@ -999,12 +1067,16 @@ pub enum TokenKind {
     UPLOAD,
     #[token("SHARE", ignore(ascii_case))]
     SHARE,
+    #[token("SHEET_NAME", ignore(ascii_case))]
+    SHEET_NAME,
     #[token("SHARES", ignore(ascii_case))]
     SHARES,
     #[token("SUPER", ignore(ascii_case))]
     SUPER,
     #[token("STATUS", ignore(ascii_case))]
     STATUS,
+    #[token("STEP", ignore(ascii_case))]
+    STEP,
     #[token("STORED", ignore(ascii_case))]
     STORED,
     #[token("STREAM", ignore(ascii_case))]
@@ -1045,6 +1117,8 @@ pub enum TokenKind {
     TENANT,
     #[token("THEN", ignore(ascii_case))]
     THEN,
+    #[token("TIER", ignore(ascii_case))]
+    TIER,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
@@ -1119,6 +1193,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VERBOSE", ignore(ascii_case))]
     VERBOSE,
     #[token("VIEW", ignore(ascii_case))]
@@ -1127,6 +1203,8 @@ pub enum TokenKind {
     VIEWS,
     #[token("VIRTUAL", ignore(ascii_case))]
     VIRTUAL,
+    #[token("WARNINGS", ignore(ascii_case))]
+    WARNINGS,
     #[token("WHEN", ignore(ascii_case))]
     WHEN,
     #[token("WHERE", ignore(ascii_case))]
@@ -1137,6 +1215,8 @@ pub enum TokenKind {
     WINDOW,
     #[token("WITH", ignore(ascii_case))]
     WITH,
+    #[token("XLSX", ignore(ascii_case))]
+    XLSX,
     #[token("XML", ignore(ascii_case))]
     XML,
     #[token("XOR", ignore(ascii_case))]
@@ -1594,7 +1674,7 @@ impl TokenKind {
             | TokenKind::ROWS
             | TokenKind::RANGE
             // | TokenKind::OVERLAPS
-            // | TokenKind::RETURNING
+            | TokenKind::RETURNING
             | TokenKind::STAGE
             | TokenKind::UDF
             | TokenKind::SHARE
@@ -95,6 +95,8 @@ fn test_statement() {
     let cases = &[
         r#"show databases"#,
         r#"show databases format TabSeparatedWithNamesAndTypes;"#,
+        r#"show databases like 'db%' limit 2"#,
+        r#"show stages limit 1"#,
         r#"show tables"#,
         r#"show drop tables"#,
         r#"show drop tables like 't%'"#,
@@ -110,11 +112,13 @@ fn test_statement() {
         r#"show full columns from t from db like 'id%'"#,
         r#"show processlist like 't%' limit 2;"#,
         r#"show processlist where database='default' limit 2;"#,
+        r#"show warnings;"#,
         r#"show create table a.b;"#,
         r#"show create table a.b format TabSeparatedWithNamesAndTypes;"#,
         r#"explain pipeline select a from b;"#,
         r#"explain pipeline select a from t1 ignore_result;"#,
         r#"explain(verbose, logical, optimized) select * from t where a = 1"#,
+        r#"explain(format json) select a from b"#,
         r#"describe a;"#,
         r#"describe a format TabSeparatedWithNamesAndTypes;"#,
         r#"CREATE AGGREGATING INDEX idx1 AS SELECT SUM(a), b FROM t1 WHERE b > 3 GROUP BY b;"#,
@@ -131,6 +135,7 @@ fn test_statement() {
         r#"create table if not exists a.b (a int, b int, c int generated always as (a + b) virtual );"#,
         r#"create table a.b like c.d;"#,
         r#"create table t like t2 engine = memory;"#,
+        r#"create table t like t2 including options including cluster keys;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"
             create table if not exists a.b (a int) 's3://testbucket/admin/data/'
@@ -141,8 +146,11 @@ fn test_statement() {
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
+        r#"drop table a cascade;"#,
+        r#"drop table IDENTIFIER('db.t');"#,
         r#"use "a";"#,
         r#"create catalog ctl type=hive connection=(url='<hive-meta-store>' thrift_protocol='binary');"#,
+        r#"create catalog ctl type=My_Metastore connection=(address='127.0.0.1:9000');"#,
         r#"create database if not exists a;"#,
         r#"create database ctl.t engine = Default;"#,
         r#"create database t engine = Default;"#,
@@ -234,6 +242,8 @@ fn test_statement() {
         r#"CREATE STAGE IF NOT EXISTS test_stage url='s3://load/files/' credentials=(aws_key_id='1a2b3c', aws_secret_key='4x5y6z') file_format=(type = CSV, compression = GZIP record_delimiter=',')"#,
         r#"CREATE STAGE IF NOT EXISTS test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
         r#"CREATE OR REPLACE STAGE test_stage url='azblob://load/files/' connection=(account_name='1a2b3c' account_key='4x5y6z') file_format=(type = CSV compression = GZIP record_delimiter=',')"#,
+        r#"CREATE STAGE IF NOT EXISTS test_stage DIRECTORY = (ENABLE = TRUE)"#,
+        r#"ALTER STAGE test_stage REFRESH"#,
         r#"DROP STAGE abc"#,
         r#"DROP STAGE ~"#,
         r#"list @stage_a;"#,
@@ -252,6 +262,7 @@ fn test_statement() {
         r#"OPTIMIZE TABLE t COMPACT LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
+        r#"OPTIMIZE TABLE t TIER LIMIT 10;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
         r#"ALTER TABLE t COMMENT='t1-commnet';"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
@@ -274,7 +285,10 @@ fn test_statement() {
         r#"ALTER DATABASE IF EXISTS ctl.c RENAME TO a;"#,
         r#"ALTER DATABASE c RENAME TO a;"#,
         r#"ALTER DATABASE ctl.c RENAME TO a;"#,
+        r#"BACKUP DATABASE db1 TO @backup_stage/daily INCREMENTAL;"#,
+        r#"RESTORE DATABASE ctl.db2 FROM @backup_stage/daily BACKUP_ID = '20240601120000_0a1b2c3d';"#,
         r#"VACUUM TABLE t;"#,
+        r#"PRIORITY LOW VACUUM TABLE t;"#,
         r#"VACUUM TABLE t DRY RUN;"#,
         r#"VACUUM TABLE t DRY RUN SUMMARY;"#,
         r#"VACUUM DROP TABLE;"#,
@@ -520,6 +534,7 @@ fn test_statement() {
         r#"show functions where name='to_day_of_year' limit 1"#,
         r#"show engines like 'FU%' limit 1"#,
         r#"show engines where engine='MEMORY' limit 1"#,
+        r#"show quotas like 'max_%'"#,
         r#"show metrics like '%parse%' limit 1"#,
         r#"show metrics where metric='session_connect_numbers' limit 1"#,
         r#"show table_functions like 'fuse%' limit 1"#,
@@ -553,6 +568,8 @@ fn test_statement() {
         r#"GRANT usage ON UDF a TO 'test-grant';"#,
         r#"REVOKE usage ON UDF a FROM 'test-grant';"#,
         r#"REVOKE all ON UDF a FROM 'test-grant';"#,
+        r#"GRANT usage ON PROCEDURE p1 TO 'test-grant';"#,
+        r#"REVOKE all ON PROCEDURE p1 FROM 'test-grant';"#,
         r#"REVOKE USAGE ON DATABASE db1 FROM SHARE a;"#,
         r#"REVOKE SELECT ON TABLE db1.tb1 FROM SHARE a;"#,
         r#"ALTER SHARE a ADD TENANTS = b,c;"#,
@@ -565,10 +582,14 @@ fn test_statement() {
         r#"SHOW GRANTS ON DATABASE db;"#,
         r#"SHOW GRANTS OF SHARE t;"#,
         r#"UPDATE db1.tb1 set a = a + 1, b = 2 WHERE c > 3;"#,
+        r#"UPDATE t SET a = 1 RETURNING a AS b;"#,
+        r#"DELETE FROM t RETURNING *;"#,
+        r#"INSERT INTO t VALUES (1) RETURNING a;"#,
         r#"SET max_threads = 10;"#,
         r#"SET max_threads = 10*2;"#,
         r#"UNSET max_threads;"#,
         r#"UNSET (max_threads, sql_dialect);"#,
+        r#"UNSET SESSION max_threads;"#,
         r#"select $1 FROM '@my_stage/my data/'"#,
         r#"
             SELECT t.c1 FROM @stage1/dir/file
@@ -709,6 +730,7 @@ fn test_statement() {
         r#"GRANT OWNERSHIP ON d20_0014.t TO ROLE 'd20_0015_owner';"#,
         r#"GRANT OWNERSHIP ON STAGE s1 TO ROLE 'd20_0015_owner';"#,
         r#"GRANT OWNERSHIP ON UDF f1 TO ROLE 'd20_0015_owner';"#,
+        r#"GRANT OWNERSHIP ON PROCEDURE p1 TO ROLE 'd20_0015_owner';"#,
         r#"attach table t 's3://a' connection=(access_key_id ='x' secret_access_key ='y' endpoint_url='http://127.0.0.1:9900')"#,
         r#"CREATE FUNCTION IF NOT EXISTS isnotempty AS(p) -> not(is_null(p));"#,
         r#"CREATE OR REPLACE FUNCTION isnotempty_test_replace AS(p) -> not(is_null(p))  DESC = 'This is a description';"#,
@@ -738,6 +760,9 @@ fn test_statement() {
             END;
             $$
         "#,
+        r#"CREATE PROCEDURE p1(a INT, b STRING) RETURNS STRING LANGUAGE SQL EXECUTE AS CALLER AS $$BEGIN RETURN b; END;$$;"#,
+        r#"DROP PROCEDURE IF EXISTS p1;"#,
+        r#"CALL PROCEDURE p1(1, 'a');"#,
     ];
 
     for case in cases {
@@ -101,7 +101,15 @@ pub struct StorageDescription {
     pub support_cluster_key: bool,
 }
 
+/// Creates the catalogs of one catalog type.
+///
+/// This is the extension point for the external catalogs: implement it for a catalog type
+/// and register it to [`CatalogCreatorRegistry`](super::CatalogCreatorRegistry), or for a
+/// catalog provided by another crate, with [`register_catalog_plugin`](super::register_catalog_plugin).
 pub trait CatalogCreator: Send + Sync + Debug {
+    /// Create a catalog from the info stored in meta or built from the config.
+    ///
+    /// It is called every time the catalog is accessed, so it should be cheap.
     fn try_create(&self, info: &CatalogInfo) -> Result<Arc<dyn Catalog>>;
 }
 
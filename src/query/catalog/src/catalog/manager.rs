@@ -40,7 +40,7 @@ use databend_common_meta_types::anyerror::func_name;
 use databend_storages_common_txn::TxnManagerRef;
 
 use super::Catalog;
-use super::CatalogCreator;
+use super::CatalogCreatorRegistry;
 use crate::catalog::session_catalog::SessionCatalog;
 
 pub const CATALOG_DEFAULT: &str = "default";
@@ -53,8 +53,8 @@ pub struct CatalogManager {
     /// external catalogs is the external catalogs that configured in config.
     pub external_catalogs: HashMap<String, Arc<dyn Catalog>>,
 
-    /// catalog_creators is the catalog creators that registered.
-    pub catalog_creators: HashMap<CatalogType, Arc<dyn CatalogCreator>>,
+    /// catalog_creators is the catalog creators that registered, including the ones of plugins.
+    pub catalog_creators: CatalogCreatorRegistry,
 }
 
 impl CatalogManager {
@@ -69,7 +69,7 @@ impl CatalogManager {
     pub async fn init(
         conf: &InnerConfig,
         default_catalog: Arc<dyn Catalog>,
-        catalog_creators: Vec<(CatalogType, Arc<dyn CatalogCreator>)>,
+        catalog_creators: CatalogCreatorRegistry,
     ) -> Result<()> {
         GlobalInstance::set(Self::try_create(conf, default_catalog, catalog_creators).await?);
 
@@ -81,7 +81,7 @@ impl CatalogManager {
     async fn try_create(
         conf: &InnerConfig,
         default_catalog: Arc<dyn Catalog>,
-        catalog_creators: Vec<(CatalogType, Arc<dyn CatalogCreator>)>,
+        catalog_creators: CatalogCreatorRegistry,
     ) -> Result<Arc<CatalogManager>> {
         let meta = {
             let provider = Arc::new(MetaStoreProvider::new(conf.meta.to_meta_grpc_client_conf()));
@@ -90,15 +90,12 @@ impl CatalogManager {
         };
 
         let tenant = conf.query.tenant_id.clone();
-        let catalog_creators = HashMap::from_iter(catalog_creators.into_iter());
 
         // init external catalogs.
         let mut external_catalogs = HashMap::default();
         for (name, ctl_cfg) in conf.catalogs.iter() {
             let CatalogConfig::Hive(hive_ctl_cfg) = ctl_cfg;
-            let creator = catalog_creators.get(&CatalogType::Hive).ok_or_else(|| {
-                ErrorCode::BadArguments(format!("unknown catalog type: {:?}", CatalogType::Hive))
-            })?;
+            let creator = catalog_creators.get(&CatalogType::Hive)?;
             let ctl = creator.try_create(&CatalogInfo {
                 id: CatalogId { catalog_id: 0 }.into(),
                 name_ident: CatalogNameIdent::new(tenant.clone(), name).into(),
@@ -146,12 +143,7 @@ impl CatalogManager {
             return self.get_default_catalog(txn_mgr);
         }
 
-        let creator = self
-            .catalog_creators
-            .get(&typ)
-            .ok_or_else(|| ErrorCode::BadArguments(format!("unknown catalog type: {:?}", typ)))?;
-
-        creator.try_create(info)
+        self.catalog_creators.get(&typ)?.try_create(info)
     }
 
     /// Get a catalog from manager.
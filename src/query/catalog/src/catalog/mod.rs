@@ -16,6 +16,8 @@
 mod interface;
 /// the catalog manager implementation
 mod manager;
+/// the registry of the catalog creators, including the ones provided by plugins
+mod registry;
 mod session_catalog;
 
 pub use interface::Catalog;
@@ -23,3 +25,5 @@ pub use interface::CatalogCreator;
 pub use interface::StorageDescription;
 pub use manager::CatalogManager;
 pub use manager::CATALOG_DEFAULT;
+pub use registry::register_catalog_plugin;
+pub use registry::CatalogCreatorRegistry;
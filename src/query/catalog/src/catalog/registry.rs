@@ -0,0 +1,106 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CatalogType;
+use parking_lot::RwLock;
+
+use super::CatalogCreator;
+
+/// The catalog creators registered by plugins before the query service starts.
+static CATALOG_PLUGINS: LazyLock<RwLock<Vec<(CatalogType, Arc<dyn CatalogCreator>)>>> =
+    LazyLock::new(|| RwLock::new(vec![]));
+
+/// Register a catalog provided by a plugin crate.
+///
+/// The catalog can then be created with `CREATE CATALOG <name> TYPE = <catalog_type>
+/// CONNECTION = (...)`, the connection options are passed to the creator as
+/// [`CustomCatalogOption`](databend_common_meta_app::schema::CustomCatalogOption).
+///
+/// It must be called before the global services are initialized, e.g.:
+///
+/// ```ignore
+/// register_catalog_plugin("my_metastore", Arc::new(MyMetastoreCreator))?;
+/// GlobalServices::init(&conf).await?;
+/// ```
+pub fn register_catalog_plugin(catalog_type: &str, creator: Arc<dyn CatalogCreator>) -> Result<()> {
+    let typ = CatalogType::Custom(catalog_type.to_lowercase());
+
+    let mut plugins = CATALOG_PLUGINS.write();
+    if plugins.iter().any(|(t, _)| t == &typ) {
+        return Err(ErrorCode::BadArguments(format!(
+            "catalog plugin {} is already registered",
+            typ
+        )));
+    }
+    plugins.push((typ, creator));
+    Ok(())
+}
+
+/// CatalogCreatorRegistry maps a catalog type to the creator of the catalogs of that type.
+#[derive(Clone, Default)]
+pub struct CatalogCreatorRegistry {
+    creators: HashMap<CatalogType, Arc<dyn CatalogCreator>>,
+}
+
+impl CatalogCreatorRegistry {
+    /// Create a registry with the given creators and the ones registered by plugins.
+    pub fn create(creators: Vec<(CatalogType, Arc<dyn CatalogCreator>)>) -> Result<Self> {
+        let mut registry = Self::default();
+        for (typ, creator) in creators {
+            registry.register(typ, creator)?;
+        }
+        for (typ, creator) in CATALOG_PLUGINS.read().iter() {
+            registry.register(typ.clone(), creator.clone())?;
+        }
+        Ok(registry)
+    }
+
+    /// Register a creator for a catalog type.
+    ///
+    /// DEFAULT catalog is built by the catalog manager and can not be registered.
+    pub fn register(&mut self, typ: CatalogType, creator: Arc<dyn CatalogCreator>) -> Result<()> {
+        if typ == CatalogType::Default {
+            return Err(ErrorCode::BadArguments(
+                "default catalog creator cannot be registered",
+            ));
+        }
+
+        if self.creators.contains_key(&typ) {
+            return Err(ErrorCode::BadArguments(format!(
+                "catalog creator of type {} is already registered",
+                typ
+            )));
+        }
+
+        self.creators.insert(typ, creator);
+        Ok(())
+    }
+
+    pub fn get(&self, typ: &CatalogType) -> Result<Arc<dyn CatalogCreator>> {
+        self.creators
+            .get(typ)
+            .cloned()
+            .ok_or_else(|| ErrorCode::UnknownCatalogType(format!("unknown catalog type: {}", typ)))
+    }
+
+    pub fn contains(&self, typ: &CatalogType) -> bool {
+        self.creators.contains_key(typ)
+    }
+}
@@ -23,6 +23,10 @@ pub struct PartStatistics {
     pub read_rows: usize,
     /// Total bytes of the query read.
     pub read_bytes: usize,
+    /// Estimated bytes fetched from storage, as stored (compressed).
+    pub read_compressed_bytes: usize,
+    /// Estimated bytes of the fetched data once decoded into memory.
+    pub read_uncompressed_bytes: usize,
     /// Number of partitions scanned, (after pruning)
     pub partitions_scanned: usize,
     /// Number of partitions, (before pruning)
@@ -49,6 +53,8 @@ impl PartStatistics {
             partitions_total,
             is_exact: false,
             pruning_stats: Default::default(),
+            read_compressed_bytes: 0,
+            read_uncompressed_bytes: 0,
         }
     }
 
@@ -66,9 +72,18 @@ impl PartStatistics {
             is_exact: true,
             snapshot: None,
             pruning_stats: Default::default(),
+            read_compressed_bytes: 0,
+            read_uncompressed_bytes: 0,
         }
     }
 
+    /// Set the compressed and decoded byte estimates of the scan.
+    pub fn with_decode_estimates(mut self, compressed: usize, uncompressed: usize) -> Self {
+        self.read_compressed_bytes = compressed;
+        self.read_uncompressed_bytes = uncompressed;
+        self
+    }
+
     pub fn default_exact() -> Self {
         Self {
             is_exact: true,
@@ -103,6 +118,8 @@ impl PartStatistics {
     pub fn merge(&mut self, other: &Self) {
         self.read_rows += other.read_rows;
         self.read_bytes += other.read_bytes;
+        self.read_compressed_bytes += other.read_compressed_bytes;
+        self.read_uncompressed_bytes += other.read_uncompressed_bytes;
         self.partitions_scanned += other.partitions_scanned;
         self.partitions_total += other.partitions_total;
         self.pruning_stats.merge(&other.pruning_stats);
@@ -92,6 +92,58 @@ pub struct InvertedIndexInfo {
     pub query_text: String,
 }
 
+/// Information about the vector index, used by `ORDER BY <distance> LIMIT N`,
+/// the distance is `cosine_distance` or `l2_distance` between a vector column and a constant.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    /// The name of the vector column.
+    pub column_name: String,
+    /// The name of the distance function.
+    pub func_name: String,
+    /// The constant query vector.
+    pub query_values: Vec<F32>,
+    /// The number of the nearest rows to return, including the offset.
+    pub limit: usize,
+}
+
+/// The push downs a table engine can evaluate while reading,
+/// the optimizer only pushes these down into its scans.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PushDownCapabilities {
+    /// Filters, used to prune partitions or rows.
+    pub filter: bool,
+    /// Only the projected columns are read.
+    pub projection: bool,
+    /// Reading stops after the limit.
+    pub limit: bool,
+    /// Aggregations can be answered by aggregating indexes.
+    pub aggregate: bool,
+}
+
+impl PushDownCapabilities {
+    /// Nothing is pushed down, the table is read as a whole.
+    pub const NONE: PushDownCapabilities = PushDownCapabilities {
+        filter: false,
+        projection: false,
+        limit: false,
+        aggregate: false,
+    };
+
+    /// The names of the push downs which are not supported, for EXPLAIN.
+    pub fn unsupported(&self) -> Vec<&'static str> {
+        [
+            (self.filter, "filter"),
+            (self.projection, "projection"),
+            (self.limit, "limit"),
+            (self.aggregate, "aggregate"),
+        ]
+        .into_iter()
+        .filter(|(supported, _)| !supported)
+        .map(|(_, name)| name)
+        .collect()
+    }
+}
+
 /// Extras is a wrapper for push down items.
 #[derive(serde::Serialize, serde::Deserialize, Clone, Default, Debug, PartialEq, Eq)]
 pub struct PushDownInfo {
@@ -121,6 +173,8 @@ pub struct PushDownInfo {
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
     pub inverted_index: Option<InvertedIndexInfo>,
+    /// Optional vector index information.
+    pub vector_index: Option<VectorIndexInfo>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
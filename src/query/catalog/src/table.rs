@@ -43,6 +43,7 @@ use crate::plan::DataSourceInfo;
 use crate::plan::DataSourcePlan;
 use crate::plan::PartStatistics;
 use crate::plan::Partitions;
+use crate::plan::PushDownCapabilities;
 use crate::plan::PushDownInfo;
 use crate::plan::StreamColumn;
 use crate::statistics::BasicColumnStatistics;
@@ -102,6 +103,19 @@ pub trait Table: Sync + Send {
         false
     }
 
+    /// What the table engine can evaluate while reading, see [`PushDownCapabilities`].
+    ///
+    /// Filters and limits are pushed down by default, since they are only hints:
+    /// the filter and limit operators are kept above the scan.
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        PushDownCapabilities {
+            filter: true,
+            projection: self.support_column_projection(),
+            limit: true,
+            aggregate: false,
+        }
+    }
+
     /// whether table has the exact number of total rows
     fn has_exact_total_row_count(&self) -> bool {
         false
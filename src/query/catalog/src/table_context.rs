@@ -148,6 +148,11 @@ pub trait TableContext: Send + Sync {
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics;
     fn get_partition(&self) -> Option<PartInfoPtr>;
     fn get_partitions(&self, num: usize) -> Vec<PartInfoPtr>;
+    /// Gets the next partition, the partitions of a distributed source may be pulled from
+    /// the coordinator once the local ones are drained.
+    async fn fetch_partition(&self) -> Result<Option<PartInfoPtr>> {
+        Ok(self.get_partition())
+    }
     fn partition_num(&self) -> usize {
         unimplemented!()
     }
@@ -255,6 +260,11 @@ pub trait TableContext: Send + Sync {
 
     fn get_multi_table_insert_status(&self) -> Arc<Mutex<MultiTableInsertStatus>>;
 
+    /// Buffer rows produced by a `RETURNING` clause of INSERT/UPDATE/DELETE.
+    fn add_returning_block(&self, block: DataBlock);
+
+    fn get_returning_blocks(&self) -> Vec<DataBlock>;
+
     /// Get license key from context, return empty if license is not found or error happened.
     fn get_license_key(&self) -> String;
 
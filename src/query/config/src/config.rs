@@ -45,6 +45,8 @@ use databend_common_meta_app::storage::StorageWebhdfsConfig as InnerStorageWebhd
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_meta_app::tenant::TenantQuota;
 use databend_common_storage::StorageConfig as InnerStorageConfig;
+use databend_common_storage::StorageIoClassConfig as InnerStorageIoClassConfig;
+use databend_common_storage::StorageIoConfig as InnerStorageIoConfig;
 use databend_common_tracing::Config as InnerLogConfig;
 use databend_common_tracing::FileConfig as InnerFileLogConfig;
 use databend_common_tracing::OTLPConfig as InnerOTLPLogConfig;
@@ -146,6 +148,17 @@ pub struct Config {
     /// when converted from inner config, all catalog configurations will store in `catalogs`
     #[clap(skip)]
     pub catalogs: HashMap<String, CatalogConfig>,
+
+    /// Named storage backends that tables can be created on with `STORAGE_POLICY`.
+    ///
+    /// ```toml
+    /// [storage_policies.cold]
+    /// type = "s3"
+    /// [storage_policies.cold.s3]
+    /// bucket = "cold-bucket"
+    /// ```
+    #[clap(skip)]
+    pub storage_policies: HashMap<String, StorageConfig>,
 }
 
 #[derive(Subcommand, Default, Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
@@ -304,6 +317,10 @@ pub struct StorageConfig {
     // COS storage backend config
     #[clap(flatten)]
     pub cos: CosStorageConfig,
+
+    // Retry and timeout policy of the storage requests
+    #[clap(flatten)]
+    pub io: StorageIoConfig,
 }
 
 impl Default for StorageConfig {
@@ -331,6 +348,7 @@ impl From<InnerStorageConfig> for StorageConfig {
             obs: Default::default(),
             webhdfs: Default::default(),
             cos: Default::default(),
+            io: inner.io.into(),
 
             // Deprecated fields
             storage_type: None,
@@ -403,6 +421,7 @@ impl TryInto<InnerStorageConfig> for StorageConfig {
         Ok(InnerStorageConfig {
             num_cpus: self.storage_num_cpus,
             allow_insecure: self.allow_insecure,
+            io: self.io.try_into()?,
             params: {
                 match self.typ.as_str() {
                     "azblob" => StorageParams::Azblob(self.azblob.try_into()?),
@@ -1306,6 +1325,186 @@ impl TryFrom<CosStorageConfig> for InnerStorageCosConfig {
     }
 }
 
+/// Retry and timeout policy of the storage requests.
+///
+/// ```toml
+/// [storage.io]
+/// read_max_retries = 5
+/// read_timeout_secs = 30
+/// list_timeout_secs = 120
+/// retry_min_delay_ms = 500
+/// max_concurrent_requests = 512
+/// background_requests_percent = 30
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct StorageIoConfig {
+    /// Max retries of the reads and stats
+    #[clap(
+        long = "storage-io-read-max-retries",
+        value_name = "VALUE",
+        default_value = "3"
+    )]
+    pub read_max_retries: usize,
+
+    /// Timeout in seconds of each attempt of the reads and stats, 0 means no timeout
+    #[clap(
+        long = "storage-io-read-timeout-secs",
+        value_name = "VALUE",
+        default_value = "10"
+    )]
+    pub read_timeout_secs: u64,
+
+    /// Max retries of the writes and deletes
+    #[clap(
+        long = "storage-io-write-max-retries",
+        value_name = "VALUE",
+        default_value = "3"
+    )]
+    pub write_max_retries: usize,
+
+    /// Timeout in seconds of each attempt of the writes and deletes, 0 means no timeout
+    #[clap(
+        long = "storage-io-write-timeout-secs",
+        value_name = "VALUE",
+        default_value = "10"
+    )]
+    pub write_timeout_secs: u64,
+
+    /// Max retries of the lists
+    #[clap(
+        long = "storage-io-list-max-retries",
+        value_name = "VALUE",
+        default_value = "3"
+    )]
+    pub list_max_retries: usize,
+
+    /// Timeout in seconds of each attempt of the lists, 0 means no timeout
+    #[clap(
+        long = "storage-io-list-timeout-secs",
+        value_name = "VALUE",
+        default_value = "10"
+    )]
+    pub list_timeout_secs: u64,
+
+    /// Timeout in seconds of each IO call of the readers and writers, 0 means no timeout
+    #[clap(
+        long = "storage-io-timeout-secs",
+        value_name = "VALUE",
+        default_value = "10"
+    )]
+    pub io_timeout_secs: u64,
+
+    /// Delay in milliseconds before the first retry
+    #[clap(
+        long = "storage-io-retry-min-delay-ms",
+        value_name = "VALUE",
+        default_value = "1000"
+    )]
+    pub retry_min_delay_ms: u64,
+
+    /// Max delay in milliseconds between retries
+    #[clap(
+        long = "storage-io-retry-max-delay-ms",
+        value_name = "VALUE",
+        default_value = "60000"
+    )]
+    pub retry_max_delay_ms: u64,
+
+    /// The delay between retries is multiplied by the factor after each retry
+    #[clap(
+        long = "storage-io-retry-factor",
+        value_name = "VALUE",
+        default_value = "2"
+    )]
+    pub retry_factor: u32,
+
+    /// Max concurrent storage requests of this node, 0 means no limit
+    #[clap(
+        long = "storage-io-max-concurrent-requests",
+        value_name = "VALUE",
+        default_value = "0"
+    )]
+    pub max_concurrent_requests: u64,
+
+    /// Percent of max_concurrent_requests that background jobs like compaction can use
+    #[clap(
+        long = "storage-io-background-requests-percent",
+        value_name = "VALUE",
+        default_value = "50"
+    )]
+    pub background_requests_percent: u64,
+}
+
+impl Default for StorageIoConfig {
+    fn default() -> Self {
+        InnerStorageIoConfig::default().into()
+    }
+}
+
+impl From<InnerStorageIoConfig> for StorageIoConfig {
+    fn from(inner: InnerStorageIoConfig) -> Self {
+        Self {
+            read_max_retries: inner.read.max_retries,
+            read_timeout_secs: inner.read.timeout_secs,
+            write_max_retries: inner.write.max_retries,
+            write_timeout_secs: inner.write.timeout_secs,
+            list_max_retries: inner.list.max_retries,
+            list_timeout_secs: inner.list.timeout_secs,
+            io_timeout_secs: inner.io_timeout_secs,
+            retry_min_delay_ms: inner.retry_min_delay_ms,
+            retry_max_delay_ms: inner.retry_max_delay_ms,
+            retry_factor: inner.retry_factor,
+            max_concurrent_requests: inner.max_concurrent_requests,
+            background_requests_percent: inner.background_requests_percent,
+        }
+    }
+}
+
+impl TryInto<InnerStorageIoConfig> for StorageIoConfig {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<InnerStorageIoConfig> {
+        if self.retry_factor == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "`storage.io.retry_factor` must be greater than 0",
+            ));
+        }
+        if self.retry_min_delay_ms > self.retry_max_delay_ms {
+            return Err(ErrorCode::InvalidConfig(
+                "`storage.io.retry_min_delay_ms` must not be greater than `retry_max_delay_ms`",
+            ));
+        }
+
+        if self.background_requests_percent > 100 {
+            return Err(ErrorCode::InvalidConfig(
+                "`storage.io.background_requests_percent` must not be greater than 100",
+            ));
+        }
+
+        Ok(InnerStorageIoConfig {
+            read: InnerStorageIoClassConfig {
+                max_retries: self.read_max_retries,
+                timeout_secs: self.read_timeout_secs,
+            },
+            write: InnerStorageIoClassConfig {
+                max_retries: self.write_max_retries,
+                timeout_secs: self.write_timeout_secs,
+            },
+            list: InnerStorageIoClassConfig {
+                max_retries: self.list_max_retries,
+                timeout_secs: self.list_timeout_secs,
+            },
+            io_timeout_secs: self.io_timeout_secs,
+            retry_min_delay_ms: self.retry_min_delay_ms,
+            retry_max_delay_ms: self.retry_max_delay_ms,
+            retry_factor: self.retry_factor,
+            max_concurrent_requests: self.max_concurrent_requests,
+            background_requests_percent: self.background_requests_percent,
+        })
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SettingValue {
     UInt64(u64),
@@ -1396,12 +1595,36 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_key: String,
 
+    /// CA certificate verifying the client certificates, the MySQL handler requires
+    /// the clients to present a certificate if set
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub mysql_tls_server_root_ca_cert: String,
+
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
     #[clap(long, value_name = "VALUE", default_value = "8")]
     pub max_running_queries: u64,
 
+    /// The max concurrent sessions of a user on this node, 0 means unlimited
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_connections_per_user: u64,
+
+    /// The max concurrent sessions of a tenant on this node, 0 means unlimited
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub max_connections_per_tenant: u64,
+
+    /// The failed authentications of a user from a client within `auth_failure_window_secs`
+    /// that lock the client out for `auth_lockout_secs`, 0 means disabled
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub auth_failure_max_attempts: u64,
+
+    #[clap(long, value_name = "VALUE", default_value = "60")]
+    pub auth_failure_window_secs: u64,
+
+    #[clap(long, value_name = "VALUE", default_value = "300")]
+    pub auth_lockout_secs: u64,
+
     /// The max total memory in bytes that can be used by this process.
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub max_server_memory_usage: u64,
@@ -1479,6 +1702,11 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub rpc_tls_server_key: String,
 
+    /// CA certificate verifying the certificates of the other query nodes, the rpc server
+    /// requires mutual TLS if set
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub rpc_tls_server_root_ca_cert: String,
+
     /// Certificate for client to identify query rpc server
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub rpc_tls_query_server_root_ca_cert: String,
@@ -1486,6 +1714,14 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "localhost")]
     pub rpc_tls_query_service_domain_name: String,
 
+    /// Certificate presented to the rpc servers of the other query nodes requiring mutual TLS
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub rpc_tls_query_client_cert: String,
+
+    /// key for rpc client cert
+    #[clap(long, value_name = "VALUE", default_value_t)]
+    pub rpc_tls_query_client_key: String,
+
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub rpc_client_timeout_secs: u64,
 
@@ -1499,6 +1735,11 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "10000")]
     pub max_query_log_size: usize,
 
+    /// Interval of collecting the storage usage of the tables into
+    /// `system.storage_usage_history`, 0 means disabled
+    #[clap(long, value_name = "VALUE", default_value = "0")]
+    pub storage_usage_collect_interval_secs: u64,
+
     #[clap(long, value_name = "VALUE")]
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
@@ -1681,8 +1922,14 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            mysql_tls_server_root_ca_cert: self.mysql_tls_server_root_ca_cert,
             max_active_sessions: self.max_active_sessions,
             max_running_queries: self.max_running_queries,
+            max_connections_per_user: self.max_connections_per_user,
+            max_connections_per_tenant: self.max_connections_per_tenant,
+            auth_failure_max_attempts: self.auth_failure_max_attempts,
+            auth_failure_window_secs: self.auth_failure_window_secs,
+            auth_lockout_secs: self.auth_lockout_secs,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
             clickhouse_http_handler_host: self.clickhouse_http_handler_host,
@@ -1705,12 +1952,16 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             flight_sql_tls_server_key: self.flight_sql_tls_server_key,
             rpc_tls_server_cert: self.rpc_tls_server_cert,
             rpc_tls_server_key: self.rpc_tls_server_key,
+            rpc_tls_server_root_ca_cert: self.rpc_tls_server_root_ca_cert,
             rpc_tls_query_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert,
             rpc_tls_query_service_domain_name: self.rpc_tls_query_service_domain_name,
+            rpc_tls_query_client_cert: self.rpc_tls_query_client_cert,
+            rpc_tls_query_client_key: self.rpc_tls_query_client_key,
             rpc_client_timeout_secs: self.rpc_client_timeout_secs,
             table_engine_memory_enabled: self.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: self.shutdown_wait_timeout_ms,
             max_query_log_size: self.max_query_log_size,
+            storage_usage_collect_interval_secs: self.storage_usage_collect_interval_secs,
             databend_enterprise_license: self.databend_enterprise_license,
             management_mode: self.management_mode,
             parquet_fast_read_bytes: self.parquet_fast_read_bytes,
@@ -1761,8 +2012,14 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            mysql_tls_server_root_ca_cert: inner.mysql_tls_server_root_ca_cert,
             max_active_sessions: inner.max_active_sessions,
             max_running_queries: inner.max_running_queries,
+            max_connections_per_user: inner.max_connections_per_user,
+            max_connections_per_tenant: inner.max_connections_per_tenant,
+            auth_failure_max_attempts: inner.auth_failure_max_attempts,
+            auth_failure_window_secs: inner.auth_failure_window_secs,
+            auth_lockout_secs: inner.auth_lockout_secs,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
 
@@ -1790,12 +2047,16 @@ impl From<InnerQueryConfig> for QueryConfig {
             flight_sql_tls_server_key: inner.flight_sql_tls_server_key,
             rpc_tls_server_cert: inner.rpc_tls_server_cert,
             rpc_tls_server_key: inner.rpc_tls_server_key,
+            rpc_tls_server_root_ca_cert: inner.rpc_tls_server_root_ca_cert,
             rpc_tls_query_server_root_ca_cert: inner.rpc_tls_query_server_root_ca_cert,
             rpc_tls_query_service_domain_name: inner.rpc_tls_query_service_domain_name,
+            rpc_tls_query_client_cert: inner.rpc_tls_query_client_cert,
+            rpc_tls_query_client_key: inner.rpc_tls_query_client_key,
             rpc_client_timeout_secs: inner.rpc_client_timeout_secs,
             table_engine_memory_enabled: inner.table_engine_memory_enabled,
             shutdown_wait_timeout_ms: inner.shutdown_wait_timeout_ms,
             max_query_log_size: inner.max_query_log_size,
+            storage_usage_collect_interval_secs: inner.storage_usage_collect_interval_secs,
             databend_enterprise_license: inner.databend_enterprise_license,
             management_mode: inner.management_mode,
             parquet_fast_read_bytes: inner.parquet_fast_read_bytes,
@@ -2477,6 +2738,22 @@ pub struct MetaConfig {
         default_value = "localhost"
     )]
     pub rpc_tls_meta_service_domain_name: String,
+
+    /// Certificate presented to the meta service requiring mutual TLS
+    #[clap(
+        long = "meta-rpc-tls-meta-client-cert",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub rpc_tls_meta_client_cert: String,
+
+    /// key for meta rpc client cert
+    #[clap(
+        long = "meta-rpc-tls-meta-client-key",
+        value_name = "VALUE",
+        default_value_t
+    )]
+    pub rpc_tls_meta_client_key: String,
 }
 
 impl Default for MetaConfig {
@@ -2521,6 +2798,8 @@ impl TryInto<InnerMetaConfig> for MetaConfig {
             unhealth_endpoint_evict_time: self.unhealth_endpoint_evict_time,
             rpc_tls_meta_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: self.rpc_tls_meta_service_domain_name,
+            rpc_tls_meta_client_cert: self.rpc_tls_meta_client_cert,
+            rpc_tls_meta_client_key: self.rpc_tls_meta_client_key,
         })
     }
 }
@@ -2537,6 +2816,8 @@ impl From<InnerMetaConfig> for MetaConfig {
             unhealth_endpoint_evict_time: inner.unhealth_endpoint_evict_time,
             rpc_tls_meta_server_root_ca_cert: inner.rpc_tls_meta_server_root_ca_cert,
             rpc_tls_meta_service_domain_name: inner.rpc_tls_meta_service_domain_name,
+            rpc_tls_meta_client_cert: inner.rpc_tls_meta_client_cert,
+            rpc_tls_meta_client_key: inner.rpc_tls_meta_client_key,
 
             // Deprecated fields
             meta_embedded_dir: None,
@@ -2568,6 +2849,8 @@ impl Debug for MetaConfig {
                 "rpc_tls_meta_service_domain_name",
                 &self.rpc_tls_meta_service_domain_name,
             )
+            .field("rpc_tls_meta_client_cert", &self.rpc_tls_meta_client_cert)
+            .field("rpc_tls_meta_client_key", &self.rpc_tls_meta_client_key)
             .finish()
     }
 }
@@ -2932,6 +3215,11 @@ mod cache_config_converters {
                     .into_iter()
                     .map(|(k, v)| (k, v.into()))
                     .collect(),
+                storage_policies: inner
+                    .storage_policies
+                    .into_iter()
+                    .map(|(k, v)| (k, v.into()))
+                    .collect(),
                 cache: inner.cache.into(),
                 background: inner.background.into(),
             }
@@ -2956,6 +3244,11 @@ mod cache_config_converters {
                 catalogs.insert(CATALOG_HIVE.to_string(), catalog);
             }
 
+            let mut storage_policies = HashMap::new();
+            for (k, v) in self.storage_policies.into_iter() {
+                storage_policies.insert(k, v.try_into()?);
+            }
+
             Ok(InnerConfig {
                 subcommand: self.subcommand,
                 config_file: self.config_file,
@@ -2964,6 +3257,7 @@ mod cache_config_converters {
                 meta: self.meta.try_into()?,
                 storage: self.storage.try_into()?,
                 catalogs,
+                storage_policies,
                 cache: self.cache.try_into()?,
                 background: self.background.try_into()?,
             })
@@ -13,25 +13,42 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::sync::RwLock;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_exception::Result;
 
 use crate::InnerConfig;
 
-pub struct GlobalConfig;
+pub struct GlobalConfig {
+    config: RwLock<Arc<InnerConfig>>,
+}
 
 impl GlobalConfig {
     pub fn init(config: &InnerConfig) -> Result<()> {
-        GlobalInstance::set(Arc::new(config.clone()));
+        GlobalInstance::set(Arc::new(GlobalConfig {
+            config: RwLock::new(Arc::new(config.clone())),
+        }));
         Ok(())
     }
 
     pub fn instance() -> Arc<InnerConfig> {
-        GlobalInstance::get()
+        let global = GlobalInstance::get::<Arc<GlobalConfig>>();
+        let config = global.config.read().unwrap_or_else(|e| e.into_inner());
+        config.clone()
     }
 
     pub fn try_get_instance() -> Option<Arc<InnerConfig>> {
-        GlobalInstance::try_get()
+        let global = GlobalInstance::try_get::<Arc<GlobalConfig>>()?;
+        let config = global.config.read().unwrap_or_else(|e| e.into_inner());
+        Some(config.clone())
+    }
+
+    /// Replaces the global config after some of the settings are reloaded at runtime,
+    /// the components that read the config once at startup are not affected.
+    pub fn reload(config: &InnerConfig) {
+        let global = GlobalInstance::get::<Arc<GlobalConfig>>();
+        let mut current = global.config.write().unwrap_or_else(|e| e.into_inner());
+        *current = Arc::new(config.clone());
     }
 }
@@ -61,6 +61,9 @@ pub struct InnerConfig {
     // - currently only supports HIVE (via hive meta store)
     pub catalogs: HashMap<String, CatalogConfig>,
 
+    // Named storage backends for the tables created with `STORAGE_POLICY`.
+    pub storage_policies: HashMap<String, StorageConfig>,
+
     // Cache Config
     pub cache: CacheConfig,
 
@@ -80,6 +83,9 @@ impl InnerConfig {
 
         // Handle auto detect for storage params.
         cfg.storage.params = cfg.storage.params.auto_detect().await?;
+        for policy in cfg.storage_policies.values_mut() {
+            policy.params = policy.params.clone().auto_detect().await?;
+        }
 
         // Only check meta config when cmd is empty.
         if cfg.subcommand.is_none() {
@@ -139,6 +145,7 @@ impl Debug for InnerConfig {
             .field("meta", &self.meta)
             .field("storage", &self.storage)
             .field("catalogs", &self.catalogs)
+            .field("storage_policies", &self.storage_policies)
             .field("cache", &self.cache)
             .field("background", &self.background)
             .finish()
@@ -160,8 +167,18 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub mysql_tls_server_root_ca_cert: String,
     pub max_active_sessions: u64,
     pub max_running_queries: u64,
+    /// The max concurrent sessions of a user on this node, 0 means unlimited
+    pub max_connections_per_user: u64,
+    /// The max concurrent sessions of a tenant on this node, 0 means unlimited
+    pub max_connections_per_tenant: u64,
+    /// The failed authentications of a user from a client within `auth_failure_window_secs`
+    /// that lock the client out for `auth_lockout_secs`, 0 means disabled
+    pub auth_failure_max_attempts: u64,
+    pub auth_failure_window_secs: u64,
+    pub auth_lockout_secs: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
     pub clickhouse_http_handler_host: String,
@@ -186,15 +203,22 @@ pub struct QueryConfig {
     pub rpc_tls_server_cert: String,
     /// key for rpc server cert
     pub rpc_tls_server_key: String,
+    /// CA certificate verifying the client certificates of the rpc server
+    pub rpc_tls_server_root_ca_cert: String,
     /// Certificate for client to identify query rpc server
     pub rpc_tls_query_server_root_ca_cert: String,
     pub rpc_tls_query_service_domain_name: String,
+    /// Certificate presented to the other query nodes requiring mutual TLS
+    pub rpc_tls_query_client_cert: String,
+    pub rpc_tls_query_client_key: String,
     pub rpc_client_timeout_secs: u64,
     /// Table engine memory enabled
     pub table_engine_memory_enabled: bool,
     /// Graceful shutdown timeout
     pub shutdown_wait_timeout_ms: u64,
     pub max_query_log_size: usize,
+    /// Interval of collecting the storage usage of the tables, 0 means disabled
+    pub storage_usage_collect_interval_secs: u64,
     pub databend_enterprise_license: Option<String>,
     /// If in management mode, only can do some meta level operations(database/table/user/stage etc.) with metasrv.
     pub management_mode: bool,
@@ -246,8 +270,14 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            mysql_tls_server_root_ca_cert: "".to_string(),
             max_active_sessions: 256,
             max_running_queries: 8,
+            max_connections_per_user: 0,
+            max_connections_per_tenant: 0,
+            auth_failure_max_attempts: 0,
+            auth_failure_window_secs: 60,
+            auth_lockout_secs: 300,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
             clickhouse_http_handler_host: "127.0.0.1".to_string(),
@@ -269,12 +299,16 @@ impl Default for QueryConfig {
             http_handler_tls_server_root_ca_cert: "".to_string(),
             rpc_tls_server_cert: "".to_string(),
             rpc_tls_server_key: "".to_string(),
+            rpc_tls_server_root_ca_cert: "".to_string(),
             rpc_tls_query_server_root_ca_cert: "".to_string(),
             rpc_tls_query_service_domain_name: "localhost".to_string(),
+            rpc_tls_query_client_cert: "".to_string(),
+            rpc_tls_query_client_key: "".to_string(),
             rpc_client_timeout_secs: 0,
             table_engine_memory_enabled: true,
             shutdown_wait_timeout_ms: 5000,
             max_query_log_size: 10_000,
+            storage_usage_collect_interval_secs: 0,
             databend_enterprise_license: None,
             management_mode: false,
             parquet_fast_read_bytes: None,
@@ -312,6 +346,8 @@ impl QueryConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_query_server_root_ca_cert.clone(),
             domain_name: self.rpc_tls_query_service_domain_name.clone(),
+            client_cert: self.rpc_tls_query_client_cert.clone(),
+            client_key: self.rpc_tls_query_client_key.clone(),
         }
     }
 
@@ -345,6 +381,9 @@ pub struct MetaConfig {
     /// Certificate for client to identify meta rpc serve
     pub rpc_tls_meta_server_root_ca_cert: String,
     pub rpc_tls_meta_service_domain_name: String,
+    /// Certificate presented to the meta service requiring mutual TLS
+    pub rpc_tls_meta_client_cert: String,
+    pub rpc_tls_meta_client_key: String,
 }
 
 impl Default for MetaConfig {
@@ -359,6 +398,8 @@ impl Default for MetaConfig {
             unhealth_endpoint_evict_time: 120,
             rpc_tls_meta_server_root_ca_cert: "".to_string(),
             rpc_tls_meta_service_domain_name: "localhost".to_string(),
+            rpc_tls_meta_client_cert: "".to_string(),
+            rpc_tls_meta_client_key: "".to_string(),
         }
     }
 }
@@ -395,6 +436,8 @@ impl MetaConfig {
         RpcClientTlsConfig {
             rpc_tls_server_root_ca_cert: self.rpc_tls_meta_server_root_ca_cert.to_string(),
             domain_name: self.rpc_tls_meta_service_domain_name.to_string(),
+            client_cert: self.rpc_tls_meta_client_cert.to_string(),
+            client_key: self.rpc_tls_meta_client_key.to_string(),
         }
     }
 
@@ -441,6 +484,8 @@ impl Debug for MetaConfig {
                 "rpc_tls_meta_service_domain_name",
                 &self.rpc_tls_meta_service_domain_name,
             )
+            .field("rpc_tls_meta_client_cert", &self.rpc_tls_meta_client_cert)
+            .field("rpc_tls_meta_client_key", &self.rpc_tls_meta_client_key)
             .finish()
     }
 }
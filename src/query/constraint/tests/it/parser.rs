@@ -199,6 +199,7 @@ fn mir_to_sql_ast(expr: &MirExpr) -> ASTExpr {
                             args: vec![mir_to_sql_ast(arg)],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     };
@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use bumpalo::Bump;
 use databend_common_exception::Result;
+use log::info;
 
 use super::partitioned_payload::PartitionedPayload;
 use super::payload_flush::PayloadFlushState;
@@ -52,6 +53,9 @@ pub struct AggregateHashTable {
     entries: Vec<Entry>,
     count: usize,
     capacity: usize,
+    // The rows and new groups seen before deciding whether to bypass partial aggregation
+    checked_rows: usize,
+    checked_groups: usize,
 }
 
 unsafe impl Send for AggregateHashTable {}
@@ -88,6 +92,8 @@ impl AggregateHashTable {
             ),
             capacity,
             config,
+            checked_rows: 0,
+            checked_groups: 0,
         }
     }
 
@@ -117,6 +123,8 @@ impl AggregateHashTable {
             ),
             capacity,
             config,
+            checked_rows: 0,
+            checked_groups: 0,
         }
     }
 
@@ -183,7 +191,9 @@ impl AggregateHashTable {
             self.payload.append_rows(state, row_count, group_columns);
             row_count
         } else {
-            self.probe_and_create(state, group_columns, row_count)
+            let new_group_count = self.probe_and_create(state, group_columns, row_count);
+            self.maybe_bypass(row_count, new_group_count);
+            new_group_count
         };
 
         if !self.payload.aggrs.is_empty() {
@@ -448,6 +458,31 @@ impl AggregateHashTable {
         Ok(false)
     }
 
+    // If most of the first rows are new groups, the partial aggregation hardly reduces the rows,
+    // the later rows are appended as new groups directly and left to the final aggregation.
+    fn maybe_bypass(&mut self, row_count: usize, new_group_count: usize) {
+        let bypass_rows = self.config.partial_bypass_rows;
+        if !self.config.partial_agg || bypass_rows == 0 || self.checked_rows >= bypass_rows {
+            return;
+        }
+
+        self.checked_rows += row_count;
+        self.checked_groups += new_group_count;
+
+        if self.checked_rows >= bypass_rows
+            && self.checked_groups as f64
+                > self.checked_rows as f64 * self.config.partial_bypass_ratio
+        {
+            info!(
+                "Bypass partial aggregation, {} new groups in the first {} rows.",
+                self.checked_groups, self.checked_rows
+            );
+            self.direct_append = true;
+            self.entries = vec![];
+            self.reset_count();
+        }
+    }
+
     fn maybe_repartition(&mut self) -> bool {
         // already final stage or the max radix bits
         if !self.config.partial_agg || (self.current_radix_bits == self.config.max_radix_bits) {
@@ -17,6 +17,8 @@ use ordered_float::OrderedFloat;
 
 use crate::types::decimal::DecimalType;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::Interval;
+use crate::types::string::StringDictionaryColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::BinaryType;
@@ -25,6 +27,7 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::IntervalType;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
@@ -48,6 +51,10 @@ pub fn group_hash_columns(cols: &[Column], values: &mut [u64]) {
 }
 
 pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut [u64]) {
+    if c.is_compact() {
+        return combine_group_hash_column::<IS_FIRST>(&c.expand(), values);
+    }
+
     match c.data_type() {
         DataType::Null => {}
         DataType::EmptyArray => {}
@@ -68,8 +75,14 @@ pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut
         DataType::Boolean => combine_group_hash_type_column::<IS_FIRST, BooleanType>(c, values),
         DataType::Timestamp => combine_group_hash_type_column::<IS_FIRST, TimestampType>(c, values),
         DataType::Date => combine_group_hash_type_column::<IS_FIRST, DateType>(c, values),
+        DataType::Interval => combine_group_hash_type_column::<IS_FIRST, IntervalType>(c, values),
         DataType::Binary => combine_group_hash_string_column::<IS_FIRST, BinaryType>(c, values),
-        DataType::String => combine_group_hash_string_column::<IS_FIRST, StringType>(c, values),
+        DataType::String => match c {
+            Column::StringDictionary(col) => {
+                combine_group_hash_dictionary_column::<IS_FIRST>(col, values)
+            }
+            _ => combine_group_hash_string_column::<IS_FIRST, StringType>(c, values),
+        },
         DataType::Bitmap => combine_group_hash_string_column::<IS_FIRST, BitmapType>(c, values),
         DataType::Variant => combine_group_hash_string_column::<IS_FIRST, VariantType>(c, values),
         DataType::Geometry => combine_group_hash_string_column::<IS_FIRST, GeometryType>(c, values),
@@ -140,6 +153,27 @@ fn combine_group_hash_string_column<const IS_FIRST: bool, T: ArgType>(
     }
 }
 
+fn combine_group_hash_dictionary_column<const IS_FIRST: bool>(
+    col: &StringDictionaryColumn,
+    values: &mut [u64],
+) {
+    // Each distinct value is hashed only once.
+    let hashes = col
+        .values()
+        .iter()
+        .map(|x| x.as_bytes().agg_hash())
+        .collect::<Vec<_>>();
+    if IS_FIRST {
+        for (key, val) in col.keys().iter().zip(values.iter_mut()) {
+            *val = hashes[*key as usize];
+        }
+    } else {
+        for (key, val) in col.keys().iter().zip(values.iter_mut()) {
+            *val = (*val).wrapping_mul(NULL_HASH_VAL) ^ hashes[*key as usize];
+        }
+    }
+}
+
 pub trait AggHash {
     fn agg_hash(&self) -> u64;
 }
@@ -220,6 +254,12 @@ impl AggHash for i128 {
     }
 }
 
+impl AggHash for Interval {
+    fn agg_hash(&self) -> u64 {
+        self.to_i128().agg_hash()
+    }
+}
+
 impl AggHash for i256 {
     fn agg_hash(&self) -> u64 {
         self.to_le_bytes().agg_hash()
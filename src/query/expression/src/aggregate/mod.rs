@@ -66,6 +66,10 @@ pub struct HashTableConfig {
     pub block_fill_factor: f64,
     pub partial_agg: bool,
     pub max_partial_capacity: usize,
+    // The rows to check the reduction of partial aggregation before bypassing it, 0 means never bypass
+    pub partial_bypass_rows: usize,
+    // Bypass partial aggregation if the ratio of new groups to the checked rows exceeds it
+    pub partial_bypass_ratio: f64,
 }
 
 impl Default for HashTableConfig {
@@ -78,6 +82,8 @@ impl Default for HashTableConfig {
             block_fill_factor: 1.8,
             partial_agg: false,
             max_partial_capacity: 131072,
+            partial_bypass_rows: 0,
+            partial_bypass_ratio: 0.9,
         }
     }
 }
@@ -111,6 +117,12 @@ impl HashTableConfig {
         self
     }
 
+    pub fn with_partial_bypass(mut self, bypass_rows: usize, bypass_ratio: f64) -> Self {
+        self.partial_bypass_rows = bypass_rows;
+        self.partial_bypass_ratio = bypass_ratio;
+        self
+    }
+
     pub fn update_current_max_radix_bits(&self) {
         loop {
             let current_max_radix_bits = self.current_max_radix_bits.load(Ordering::SeqCst);
@@ -29,6 +29,7 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberType;
 use crate::types::TimestampType;
@@ -50,6 +51,7 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Interval => 16,
         // use address instead
         DataType::Binary
         | DataType::String
@@ -72,6 +74,19 @@ pub unsafe fn serialize_column_to_rowformat(
     offset: usize,
     scratch: &mut Vec<u8>,
 ) {
+    if column.is_compact() {
+        let column = column.expand();
+        return serialize_column_to_rowformat(
+            arena,
+            &column,
+            select_vector,
+            rows,
+            address,
+            offset,
+            scratch,
+        );
+    }
+
     match column {
         Column::Null { .. } | Column::EmptyArray { .. } | Column::EmptyMap { .. } => {}
         Column::Number(v) => with_number_mapped_type!(|NUM_TYPE| match v {
@@ -126,6 +141,16 @@ pub unsafe fn serialize_column_to_rowformat(
                 );
             }
         }
+        Column::StringDictionary(v) => {
+            for index in select_vector.iter().take(rows).copied() {
+                let data = arena.alloc_str(v.index_unchecked(index));
+                store(&(data.len() as u32), address[index].add(offset) as *mut u8);
+                store(
+                    &(data.as_ptr() as u64),
+                    address[index].add(offset + 4) as *mut u8,
+                );
+            }
+        }
         Column::Timestamp(buffer) => {
             for index in select_vector.iter().take(rows).copied() {
                 store(&buffer[index], address[index].add(offset) as *mut u8);
@@ -136,6 +161,11 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Interval(buffer) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&buffer[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -211,6 +241,21 @@ pub unsafe fn row_match_column(
     no_match: &mut SelectVector,
     no_match_count: &mut usize,
 ) {
+    if col.is_compact() {
+        let col = col.expand();
+        return row_match_column(
+            &col,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        );
+    }
+
     let (validity, col) = if let Column::Nullable(c) = col {
         (Some(&c.validity), &c.column)
     } else {
@@ -300,6 +345,18 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Interval(_) => row_match_column_type::<IntervalType>(
+            col,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
         Column::Bitmap(v) | Column::Binary(v) | Column::Variant(v) | Column::Geometry(v) => {
             row_match_binary_column(
                 v,
@@ -329,6 +386,21 @@ pub unsafe fn row_match_column(
                 no_match_count,
             )
         }
+        Column::StringDictionary(v) => {
+            let v = &BinaryColumn::from(v.to_string_column());
+            row_match_binary_column(
+                v,
+                validity,
+                address,
+                select_vector,
+                temp_vector,
+                count,
+                validity_offset,
+                col_offset,
+                no_match,
+                no_match_count,
+            )
+        }
         Column::Nullable(_) => unreachable!("nullable is unwrapped"),
         other => row_match_generic_column(
             other,
@@ -314,6 +314,25 @@ impl DataBlock {
         res
     }
 
+    /// Split the block by `max_rows_per_block`, and also by `max_bytes_per_block` if it's not 0.
+    /// The rows of each block under the bytes limit are estimated by the average row size.
+    pub fn split_by_rows_and_bytes_no_tail(
+        &self,
+        max_rows_per_block: usize,
+        max_bytes_per_block: usize,
+    ) -> Vec<Self> {
+        let mut max_rows = max_rows_per_block;
+        if max_bytes_per_block != 0 {
+            let memory_size = self.memory_size();
+            if memory_size > max_bytes_per_block {
+                let rows = (self.num_rows as u128 * max_bytes_per_block as u128
+                    / memory_size as u128) as usize;
+                max_rows = max_rows.min(rows.max(1));
+            }
+        }
+        self.split_by_rows_no_tail(max_rows)
+    }
+
     pub fn split_by_rows_if_needed_no_tail(&self, min_rows_per_block: usize) -> Vec<Self> {
         let max_rows_per_block = min_rows_per_block * 2;
         let mut res = vec![];
@@ -15,16 +15,30 @@
 use std::sync::Arc;
 
 use arrow_array::RecordBatch;
+use arrow_buffer::BooleanBuffer;
+use arrow_buffer::NullBuffer;
+use arrow_data::ArrayData;
 use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Field as ArrowField;
 use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::TimeUnit;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::datatypes::DataType as Arrow2DataType;
 use databend_common_arrow::arrow::datatypes::Field as Arrow2Field;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
 use super::EXTENSION_KEY;
+use crate::types::binary::BinaryColumn;
+use crate::types::decimal::DecimalColumn;
+use crate::types::nullable::NullableColumn;
 use crate::types::DataType;
+use crate::types::DecimalDataType;
+use crate::types::NumberColumn;
+use crate::types::NumberDataType;
+use crate::types::F32;
+use crate::types::F64;
 use crate::Column;
 use crate::DataBlock;
 use crate::DataField;
@@ -89,10 +103,126 @@ impl DataBlock {
 }
 
 impl Column {
+    /// Convert an arrow-rs array into a column of `data_type`.
+    ///
+    /// The buffers of numbers, decimal128, booleans, large binaries, timestamps, dates and
+    /// their nullable arrays are shared with the column without copying, other arrays are
+    /// converted through arrow2.
     pub fn from_arrow_rs(array: Arc<dyn arrow_array::Array>, data_type: &DataType) -> Result<Self> {
+        if let Some(column) = Column::from_arrow_rs_data(&array.to_data(), data_type) {
+            return Ok(column);
+        }
+
         let arrow2_array: Box<dyn databend_common_arrow::arrow::array::Array> = array.into();
         Column::from_arrow(arrow2_array.as_ref(), data_type)
     }
+
+    fn from_arrow_rs_data(data: &ArrayData, data_type: &DataType) -> Option<Column> {
+        match data_type {
+            DataType::Nullable(inner_ty) => {
+                let column = Column::from_arrow_rs_values(data, inner_ty)?;
+                let validity = match data.nulls() {
+                    Some(nulls) => Bitmap::from_null_buffer(nulls.clone()),
+                    None => Bitmap::new_constant(true, data.len()),
+                };
+                Some(Column::Nullable(Box::new(NullableColumn {
+                    column,
+                    validity,
+                })))
+            }
+            _ if data.null_count() > 0 => None,
+            _ => Column::from_arrow_rs_values(data, data_type),
+        }
+    }
+
+    /// Share the values of the array without looking at its nulls.
+    fn from_arrow_rs_values(data: &ArrayData, data_type: &DataType) -> Option<Column> {
+        let column = match (data_type, data.data_type()) {
+            (DataType::Number(NumberDataType::UInt8), ArrowDataType::UInt8) => {
+                Column::Number(NumberColumn::UInt8(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::UInt16), ArrowDataType::UInt16) => {
+                Column::Number(NumberColumn::UInt16(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::UInt32), ArrowDataType::UInt32) => {
+                Column::Number(NumberColumn::UInt32(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::UInt64), ArrowDataType::UInt64) => {
+                Column::Number(NumberColumn::UInt64(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::Int8), ArrowDataType::Int8) => {
+                Column::Number(NumberColumn::Int8(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::Int16), ArrowDataType::Int16) => {
+                Column::Number(NumberColumn::Int16(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::Int32), ArrowDataType::Int32) => {
+                Column::Number(NumberColumn::Int32(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::Int64), ArrowDataType::Int64) => {
+                Column::Number(NumberColumn::Int64(primitive_values(data)?))
+            }
+            (DataType::Number(NumberDataType::Float32), ArrowDataType::Float32) => {
+                let values = primitive_values::<f32>(data)?;
+                Column::Number(NumberColumn::Float32(unsafe {
+                    std::mem::transmute::<Buffer<f32>, Buffer<F32>>(values)
+                }))
+            }
+            (DataType::Number(NumberDataType::Float64), ArrowDataType::Float64) => {
+                let values = primitive_values::<f64>(data)?;
+                Column::Number(NumberColumn::Float64(unsafe {
+                    std::mem::transmute::<Buffer<f64>, Buffer<F64>>(values)
+                }))
+            }
+            (
+                DataType::Decimal(DecimalDataType::Decimal128(size)),
+                ArrowDataType::Decimal128(precision, scale),
+            ) if size.precision == *precision && size.scale as i8 == *scale => {
+                Column::Decimal(DecimalColumn::Decimal128(primitive_values(data)?, *size))
+            }
+            (DataType::Timestamp, ArrowDataType::Timestamp(TimeUnit::Microsecond, None)) => {
+                Column::Timestamp(primitive_values(data)?)
+            }
+            (DataType::Date, ArrowDataType::Date32) => Column::Date(primitive_values(data)?),
+            (DataType::Boolean, ArrowDataType::Boolean) => {
+                let values =
+                    BooleanBuffer::new(data.buffers()[0].clone(), data.offset(), data.len());
+                Column::Boolean(Bitmap::from_null_buffer(NullBuffer::new(values)))
+            }
+            (DataType::Binary, ArrowDataType::LargeBinary) => {
+                // The offsets of a valid array are never negative, so they can be reinterpreted as `u64`.
+                let offsets =
+                    buffer_from_arrow_rs::<u64>(&data.buffers()[0], data.offset(), data.len() + 1)?;
+                let values =
+                    buffer_from_arrow_rs::<u8>(&data.buffers()[1], 0, data.buffers()[1].len())?;
+                Column::Binary(BinaryColumn::new(values, offsets))
+            }
+            _ => return None,
+        };
+        Some(column)
+    }
+}
+
+fn primitive_values<T: databend_common_arrow::arrow::types::NativeType>(
+    data: &ArrayData,
+) -> Option<Buffer<T>> {
+    buffer_from_arrow_rs(&data.buffers()[0], data.offset(), data.len())
+}
+
+/// Share the arrow-rs buffer as `Buffer<T>`, returns `None` if it's not aligned
+/// or shorter than `offset + len` items, the caller should fallback to copying.
+fn buffer_from_arrow_rs<T: databend_common_arrow::arrow::types::NativeType>(
+    buffer: &arrow_buffer::Buffer,
+    offset: usize,
+    len: usize,
+) -> Option<Buffer<T>> {
+    if buffer.as_ptr().align_offset(std::mem::align_of::<T>()) != 0
+        || buffer.len() < (offset + len) * std::mem::size_of::<T>()
+    {
+        return None;
+    }
+    let buffer: Buffer<T> = buffer.clone().into();
+    Some(buffer.sliced(offset, len))
 }
 
 fn arrow2_field_from_arrow_field(field: &ArrowField) -> Arrow2Field {
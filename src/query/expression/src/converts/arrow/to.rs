@@ -21,10 +21,15 @@ use arrow_array::LargeListArray;
 use arrow_array::MapArray;
 use arrow_array::RecordBatch;
 use arrow_array::StructArray;
+use arrow_buffer::NullBuffer;
+use arrow_data::ArrayData;
+use arrow_data::ArrayDataBuilder;
 use arrow_schema::DataType as ArrowDataType;
 use arrow_schema::Field as ArrowField;
 use arrow_schema::Fields;
 use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::TimeUnit;
+use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::datatypes::DataType as Arrow2DataType;
 use databend_common_arrow::arrow::datatypes::Field as Arrow2Field;
 use databend_common_exception::Result;
@@ -32,6 +37,10 @@ use databend_common_exception::Result;
 use super::EXTENSION_KEY;
 use crate::converts::arrow2::table_field_to_arrow2_field_ignore_inside_nullable;
 use crate::infer_table_schema;
+use crate::types::decimal::DecimalColumn;
+use crate::types::NumberColumn;
+use crate::types::F32;
+use crate::types::F64;
 use crate::Column;
 use crate::DataBlock;
 use crate::DataField;
@@ -168,11 +177,83 @@ impl DataBlock {
 }
 
 impl Column {
+    /// Convert the column into an arrow-rs array.
+    ///
+    /// The buffers of numbers, decimal128, booleans, binaries, timestamps, dates and
+    /// their nullable columns are shared with the arrow-rs array without copying,
+    /// other columns are converted through arrow2.
     pub fn into_arrow_rs(self) -> Arc<dyn arrow_array::Array> {
+        if let Some(data) = self.to_arrow_rs_data() {
+            return arrow_array::make_array(data);
+        }
+
         let arrow2_array: Box<dyn databend_common_arrow::arrow::array::Array> = self.as_arrow();
         let arrow_array: Arc<dyn arrow_array::Array> = arrow2_array.into();
         arrow_array
     }
+
+    fn to_arrow_rs_data(&self) -> Option<ArrayData> {
+        let (column, nulls) = match self {
+            Column::Nullable(col) => (&col.column, Some(NullBuffer::from(col.validity.clone()))),
+            col => (col, None),
+        };
+
+        let builder = match column {
+            Column::Number(NumberColumn::UInt8(col)) => primitive_data(ArrowDataType::UInt8, col),
+            Column::Number(NumberColumn::UInt16(col)) => primitive_data(ArrowDataType::UInt16, col),
+            Column::Number(NumberColumn::UInt32(col)) => primitive_data(ArrowDataType::UInt32, col),
+            Column::Number(NumberColumn::UInt64(col)) => primitive_data(ArrowDataType::UInt64, col),
+            Column::Number(NumberColumn::Int8(col)) => primitive_data(ArrowDataType::Int8, col),
+            Column::Number(NumberColumn::Int16(col)) => primitive_data(ArrowDataType::Int16, col),
+            Column::Number(NumberColumn::Int32(col)) => primitive_data(ArrowDataType::Int32, col),
+            Column::Number(NumberColumn::Int64(col)) => primitive_data(ArrowDataType::Int64, col),
+            Column::Number(NumberColumn::Float32(col)) => {
+                let values =
+                    unsafe { std::mem::transmute::<Buffer<F32>, Buffer<f32>>(col.clone()) };
+                primitive_data(ArrowDataType::Float32, &values)
+            }
+            Column::Number(NumberColumn::Float64(col)) => {
+                let values =
+                    unsafe { std::mem::transmute::<Buffer<F64>, Buffer<f64>>(col.clone()) };
+                primitive_data(ArrowDataType::Float64, &values)
+            }
+            Column::Decimal(DecimalColumn::Decimal128(col, size)) => primitive_data(
+                ArrowDataType::Decimal128(size.precision, size.scale as i8),
+                col,
+            ),
+            Column::Timestamp(col) => {
+                primitive_data(ArrowDataType::Timestamp(TimeUnit::Microsecond, None), col)
+            }
+            Column::Date(col) => primitive_data(ArrowDataType::Date32, col),
+            Column::Boolean(bitmap) => {
+                let values = NullBuffer::from(bitmap.clone()).into_inner();
+                ArrayDataBuilder::new(ArrowDataType::Boolean)
+                    .len(values.len())
+                    .offset(values.offset())
+                    .add_buffer(values.into_inner())
+            }
+            Column::Binary(col) => {
+                // The offsets never exceed `i64::MAX`, so they can be reinterpreted as `i64`.
+                ArrayDataBuilder::new(ArrowDataType::LargeBinary)
+                    .len(col.len())
+                    .add_buffer(col.offsets().clone().into())
+                    .add_buffer(col.data().clone().into())
+            }
+            _ => return None,
+        };
+
+        // Safety: the buffers are taken from a valid column with the same layout.
+        Some(unsafe { builder.nulls(nulls).build_unchecked() })
+    }
+}
+
+fn primitive_data<T: databend_common_arrow::arrow::types::NativeType>(
+    data_type: ArrowDataType,
+    values: &Buffer<T>,
+) -> ArrayDataBuilder {
+    ArrayDataBuilder::new(data_type)
+        .len(values.len())
+        .add_buffer(values.clone().into())
 }
 
 fn arrow_field_from_arrow2_field(field: Arrow2Field) -> ArrowField {
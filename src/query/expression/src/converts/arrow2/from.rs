@@ -25,10 +25,12 @@ use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::array::ArrayColumn;
 use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::Interval;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::DataType;
@@ -144,6 +146,7 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
             ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
             ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
             ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+            ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
             _ => arrow_type_to_table_type(data_type, is_nullable)?,
         },
         _ => {
@@ -520,6 +523,16 @@ impl Column {
                         .values()
                         .clone(),
                 ),
+                (DataType::Interval, ArrowDataType::Decimal(_, _)) => Column::Interval(
+                    arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect("fail to read `Interval` from arrow: array should be `PrimitiveArray<i128>`")
+                        .values()
+                        .iter()
+                        .map(|v| Interval::from_i128(*v))
+                        .collect(),
+                ),
                 (
                     DataType::Variant,
                     ArrowDataType::Extension(name, box ArrowDataType::Binary, None),
@@ -20,6 +20,7 @@ pub const ARROW_EXT_TYPE_EMPTY_MAP: &str = "EmptyMap";
 pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
 
 pub use to::set_validities;
 pub use to::table_field_to_arrow2_field_ignore_inside_nullable;
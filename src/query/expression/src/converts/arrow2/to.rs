@@ -24,7 +24,9 @@ use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
 use super::ARROW_EXT_TYPE_VARIANT;
+use crate::types::binary::BinaryColumn;
 use crate::types::decimal::DecimalColumn;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
@@ -183,6 +185,17 @@ fn table_type_to_arrow_type(
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        TableDataType::Vector(_) => ArrowDataType::LargeList(Box::new(ArrowField::new(
+            "_array",
+            ArrowDataType::Float32,
+            false,
+        ))),
+        // The interval is packed into an `i128`, see `Interval::to_i128`.
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
     }
 }
 
@@ -325,7 +338,12 @@ impl Column {
                     .unwrap(),
                 )
             }
+            Column::StringDictionary(col) => Column::String(col.to_string_column()).as_arrow(),
+            Column::Constant(col) => col.to_column().as_arrow(),
+            Column::RunLength(col) => col.to_column().as_arrow(),
             Column::String(col) => {
+                // The views are materialized to the offsets and data of `LargeUtf8`.
+                let col = BinaryColumn::from(col.clone());
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
@@ -354,6 +372,14 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Interval(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                    arrow_type,
+                    col.iter().map(|i| i.to_i128()).collect(),
+                    None,
+                )
+                .unwrap(),
+            ),
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
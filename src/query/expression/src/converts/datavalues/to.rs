@@ -38,6 +38,7 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         Scalar::Decimal(_) => unimplemented!("decimal type is not supported"),
         Scalar::Timestamp(x) => DataValue::Int64(*x),
         Scalar::Date(x) => DataValue::Int64(*x as i64),
+        Scalar::Interval(x) => DataValue::String(x.to_string().into_bytes()),
         Scalar::Boolean(x) => DataValue::Boolean(*x),
         Scalar::Variant(x) => DataValue::String(x.clone()),
         Scalar::Geometry(x) => DataValue::String(x.clone()),
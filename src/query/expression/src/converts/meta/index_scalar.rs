@@ -79,6 +79,7 @@ impl TryFrom<Scalar> for IndexScalar {
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
+            | Scalar::Interval(_)
             | Scalar::EmptyArray
             | Scalar::EmptyMap => return Err(()),
         })
@@ -51,6 +51,10 @@ use crate::FunctionEval;
 use crate::FunctionRegistry;
 use crate::RemoteExpr;
 
+/// The branches of `if` and the right side of `and`/`or` are evaluated only on the selected
+/// rows if they contain function calls and the selected rows are not more than this ratio.
+const SHORT_CIRCUIT_MAX_SELECTIVITY: f64 = 0.25;
+
 #[derive(Default)]
 pub struct EvaluateOptions<'a> {
     pub selection: Option<&'a [u32]>,
@@ -141,9 +145,15 @@ impl<'a> Evaluator<'a> {
         #[cfg(debug_assertions)]
         self.check_expr(expr);
 
+        if let Some(result) = self.try_run_jit(expr) {
+            return Ok(result);
+        }
+
         let result = match expr {
             Expr::Constant { scalar, .. } => Ok(Value::Scalar(scalar.clone())),
-            Expr::ColumnRef { id, .. } => Ok(self.data_block.get_by_offset(*id).value.clone()),
+            Expr::ColumnRef { id, .. } => {
+                Ok(self.data_block.get_by_offset(*id).value.expand_compact())
+            }
             Expr::Cast {
                 span,
                 is_try,
@@ -181,10 +191,24 @@ impl<'a> Evaluator<'a> {
                 let child_suppress_error = function.signature.name == "is_not_error";
                 let mut child_option = options.with_suppress_error(child_suppress_error);
 
-                let args = args
-                    .iter()
-                    .map(|expr| self.partial_run(expr, validity.clone(), &mut child_option))
-                    .collect::<Result<Vec<_>>>()?;
+                let args = match function.signature.name.as_str() {
+                    "and" | "or" if args.len() == 2 => {
+                        let lhs =
+                            self.partial_run(&args[0], validity.clone(), &mut child_option)?;
+                        // The rows decided by the left side don't need the right side.
+                        let mask = self.short_circuit_mask(
+                            &function.signature.name,
+                            &lhs,
+                            validity.clone(),
+                        );
+                        let rhs = self.run_with_mask(&args[1], mask, &mut child_option)?;
+                        vec![lhs, rhs]
+                    }
+                    _ => args
+                        .iter()
+                        .map(|expr| self.partial_run(expr, validity.clone(), &mut child_option))
+                        .collect::<Result<Vec<_>>>()?,
+                };
 
                 assert!(
                     args.iter()
@@ -293,6 +317,20 @@ impl<'a> Evaluator<'a> {
         result
     }
 
+    // The compiled expressions never fail, so the validity is not required.
+    #[cfg(feature = "jit")]
+    fn try_run_jit(&self, expr: &Expr) -> Option<Value<AnyType>> {
+        if !self.func_ctx.enable_expression_jit || !matches!(expr, Expr::FunctionCall { .. }) {
+            return None;
+        }
+        crate::jit::try_eval(expr, self.data_block)
+    }
+
+    #[cfg(not(feature = "jit"))]
+    fn try_run_jit(&self, _expr: &Expr) -> Option<Value<AnyType>> {
+        None
+    }
+
     pub fn run_cast(
         &self,
         span: Span,
@@ -636,6 +674,41 @@ impl<'a> Evaluator<'a> {
             }
         }
 
+        // If there is no `try_to_xxx` function for the types, run the `to_xxx` function with
+        // errors suppressed, the rows that failed to convert are collected in the error bitmap
+        // and become NULL, so the whole column is still converted in one call.
+        if !src_type.is_nullable_or_null() {
+            if let Some(cast_fn) = get_simple_cast_function(false, src_type, inner_dest_type) {
+                let mut options = EvaluateOptions {
+                    suppress_error: true,
+                    ..Default::default()
+                };
+                if let Ok(Some(new_value)) = self.run_simple_cast(
+                    span,
+                    src_type,
+                    inner_dest_type,
+                    value.clone(),
+                    &cast_fn,
+                    None,
+                    &mut options,
+                ) {
+                    let validity: Option<Bitmap> =
+                        options.errors.take().map(|(valids, _)| valids.into());
+                    return Ok(match new_value {
+                        Value::Scalar(_) if validity.is_some() => Value::Scalar(Scalar::Null),
+                        Value::Scalar(scalar) => Value::Scalar(scalar),
+                        Value::Column(column) => {
+                            Value::Column(Column::Nullable(Box::new(NullableColumn {
+                                validity: validity
+                                    .unwrap_or_else(|| Bitmap::new_constant(true, column.len())),
+                                column,
+                            })))
+                        }
+                    });
+                }
+            }
+        }
+
         match (src_type, inner_dest_type) {
             (DataType::Null, _) => match value {
                 Value::Scalar(Scalar::Null) => Ok(Value::Scalar(Scalar::Null)),
@@ -876,7 +949,7 @@ impl<'a> Evaluator<'a> {
         let mut flags = Vec::new();
         let mut results = Vec::new();
         for cond_idx in (0..args.len() - 1).step_by(2) {
-            let cond = self.partial_run(&args[cond_idx], Some(validity.clone()), options)?;
+            let cond = self.run_with_mask(&args[cond_idx], validity.clone(), options)?;
             match cond.try_downcast::<NullableType<BooleanType>>().unwrap() {
                 Value::Scalar(None | Some(false)) => {
                     results.push(Value::Scalar(Scalar::default_value(&generics[0])));
@@ -894,9 +967,9 @@ impl<'a> Evaluator<'a> {
                 }
                 Value::Column(cond) => {
                     let flag = (&cond.column) & (&cond.validity);
-                    results.push(self.partial_run(
+                    results.push(self.run_with_mask(
                         &args[cond_idx + 1],
-                        Some((&validity) & (&flag)),
+                        (&validity) & (&flag),
                         options,
                     )?);
                     validity = (&validity) & (&flag.not());
@@ -905,7 +978,7 @@ impl<'a> Evaluator<'a> {
             };
             conds.push(cond);
         }
-        let else_result = self.partial_run(&args[args.len() - 1], Some(validity), options)?;
+        let else_result = self.run_with_mask(&args[args.len() - 1], validity, options)?;
 
         // Assert that all the arguments have the same length.
         assert!(
@@ -938,6 +1011,121 @@ impl<'a> Evaluator<'a> {
         }
     }
 
+    /// Evaluates the expression for the rows set in `mask`, the results of the other rows
+    /// are default values.
+    ///
+    /// If the expression has function calls and only a few rows are selected, the selected rows
+    /// are taken out to evaluate the expression on them only. Otherwise, all the rows are
+    /// evaluated and the unselected rows are just not allowed to throw errors.
+    fn run_with_mask(
+        &self,
+        expr: &Expr,
+        mask: Bitmap,
+        options: &mut EvaluateOptions,
+    ) -> Result<Value<AnyType>> {
+        let num_rows = self.data_block.num_rows();
+        let selected = mask.len() - mask.unset_bits();
+        if selected == 0 {
+            return Ok(Value::Scalar(Scalar::default_value(expr.data_type())));
+        }
+        if options.suppress_error
+            || selected as f64 > num_rows as f64 * SHORT_CIRCUIT_MAX_SELECTIVITY
+            || !has_function_call(expr)
+        {
+            return self.partial_run(expr, Some(mask), options);
+        }
+
+        // The results of the rows out of the selection are not used.
+        let mask = match options.selection {
+            Some(selection) => {
+                let mut selected_mask = MutableBitmap::from_len_zeroed(num_rows);
+                for idx in selection {
+                    if mask.get_bit(*idx as usize) {
+                        selected_mask.set(*idx as usize, true);
+                    }
+                }
+                Bitmap::from(selected_mask)
+            }
+            None => mask,
+        };
+        let selected = mask.len() - mask.unset_bits();
+        if selected == 0 {
+            return Ok(Value::Scalar(Scalar::default_value(expr.data_type())));
+        }
+
+        // Only the columns referred by the expression are filtered.
+        let column_refs = expr.column_refs();
+        let columns = self
+            .data_block
+            .columns()
+            .iter()
+            .enumerate()
+            .map(|(offset, entry)| {
+                let value = if column_refs.contains_key(&offset) {
+                    match entry.value.expand_compact() {
+                        Value::Column(column) => Value::Column(column.filter(&mask)),
+                        scalar => scalar,
+                    }
+                } else {
+                    Value::Scalar(Scalar::default_value(&entry.data_type))
+                };
+                BlockEntry::new(entry.data_type.clone(), value)
+            })
+            .collect();
+        let block = DataBlock::new(columns, selected);
+        let evaluator = Evaluator::new(&block, self.func_ctx, self.fn_registry);
+        let value = evaluator.partial_run(expr, None, &mut EvaluateOptions::default())?;
+
+        match value {
+            Value::Scalar(scalar) => Ok(Value::Scalar(scalar)),
+            Value::Column(column) => {
+                let mut builder = ColumnBuilder::with_capacity(expr.data_type(), num_rows);
+                let mut selected_row = 0;
+                for is_selected in mask.iter() {
+                    if is_selected {
+                        builder.push(unsafe { column.index_unchecked(selected_row) });
+                        selected_row += 1;
+                    } else {
+                        builder.push_default();
+                    }
+                }
+                Ok(Value::Column(builder.build()))
+            }
+        }
+    }
+
+    // Returns the rows that need the right side of `and`/`or`, i.e. the rows in which
+    // the left side is not false for `and`, or not true for `or`.
+    fn short_circuit_mask(
+        &self,
+        func_name: &str,
+        lhs: &Value<AnyType>,
+        validity: Option<Bitmap>,
+    ) -> Bitmap {
+        let num_rows = self.data_block.num_rows();
+        let validity = validity.unwrap_or_else(|| Bitmap::new_constant(true, num_rows));
+        let short_circuit_value = func_name == "or";
+        let decided = match lhs {
+            Value::Scalar(Scalar::Boolean(value)) if *value == short_circuit_value => {
+                return Bitmap::new_constant(false, num_rows);
+            }
+            Value::Column(Column::Boolean(column)) => match short_circuit_value {
+                true => column.clone(),
+                false => column.not(),
+            },
+            Value::Column(Column::Nullable(box nullable_column)) => {
+                let column = nullable_column.column.as_boolean().unwrap();
+                let decided = match short_circuit_value {
+                    true => column.clone(),
+                    false => column.not(),
+                };
+                (&decided) & (&nullable_column.validity)
+            }
+            _ => return validity,
+        };
+        (&validity) & (&decided.not())
+    }
+
     // `and_filters` is a special builtin function similar to `if` that conditionally evaluate its arguments.
     fn eval_and_filters(
         &self,
@@ -1227,6 +1415,10 @@ impl<'a> Evaluator<'a> {
         #[cfg(debug_assertions)]
         self.check_expr(expr);
 
+        if let Some(result) = self.try_run_jit(expr) {
+            return Ok((result, expr.data_type().clone()));
+        }
+
         let result = match expr {
             Expr::Constant { scalar, .. } => Ok((
                 Value::Scalar(scalar.clone()),
@@ -1234,7 +1426,7 @@ impl<'a> Evaluator<'a> {
             )),
             Expr::ColumnRef { id, .. } => {
                 let entry = self.data_block.get_by_offset(*id);
-                Ok((entry.value.clone(), entry.data_type.clone()))
+                Ok((entry.value.expand_compact(), entry.data_type.clone()))
             }
             Expr::Cast {
                 span,
@@ -1994,3 +2186,11 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
         Some(output_domain)
     }
 }
+
+fn has_function_call(expr: &Expr) -> bool {
+    match expr {
+        Expr::Constant { .. } | Expr::ColumnRef { .. } => false,
+        Expr::Cast { expr, .. } => has_function_call(expr),
+        Expr::FunctionCall { .. } | Expr::LambdaFunctionCall { .. } => true,
+    }
+}
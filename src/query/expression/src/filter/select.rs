@@ -97,7 +97,7 @@ impl<'a> Selector<'a> {
         match left_data_type.remove_nullable() {
             DataType::Number(ty) => {
                 with_number_mapped_type!(|T| match ty {
-                    NumberDataType::T => self.select_type_values_cmp::<NumberType<T>>(
+                    NumberDataType::T => self.select_number_values_cmp::<T>(
                         &op,
                         left,
                         right,
@@ -323,7 +323,7 @@ impl<'a> Selector<'a> {
             validity = Some(nullable_column.validity);
         }
         // It's safe to unwrap because the column's data type is `DataType::String`.
-        let column = column.into_string().unwrap();
+        let column = StringType::try_downcast_column(&column).unwrap();
 
         // To unite the function signature, we define a dummy function for `LikePattern::SimplePattern`.
         let dummy_function = |_: &[u8], _: &[u8]| -> bool { false };
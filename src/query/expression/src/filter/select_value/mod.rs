@@ -16,12 +16,15 @@ use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_exception::Result;
 
 use crate::types::AnyType;
+use crate::types::NumberType;
 use crate::types::ValueType;
 use crate::with_mapped_cmp_method;
 use crate::SelectOp;
 use crate::SelectStrategy;
 use crate::Selector;
+use crate::SimdNumber;
 use crate::Value;
+use crate::ValueRef;
 
 mod select_column;
 mod select_column_scalar;
@@ -58,6 +61,71 @@ impl<'a> Selector<'a> {
         })
     }
 
+    // Select indices by comparing two number `Value`, if all the rows are selected, they are
+    // compared by the SIMD kernel first and then selected by the result bitmap.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn select_number_values_cmp<T: SimdNumber>(
+        &self,
+        op: &SelectOp,
+        left: Value<AnyType>,
+        right: Value<AnyType>,
+        validity: Option<Bitmap>,
+        true_selection: &mut [u32],
+        false_selection: (&mut [u32], bool),
+        mutable_true_idx: &mut usize,
+        mutable_false_idx: &mut usize,
+        select_strategy: SelectStrategy,
+        count: usize,
+    ) -> Result<usize> {
+        let (column, other) = match (left, right) {
+            (Value::Column(column), other) | (other, Value::Column(column))
+                if select_strategy == SelectStrategy::All =>
+            {
+                (column, other)
+            }
+            (left, right) => {
+                return self.select_type_values_cmp::<NumberType<T>>(
+                    op,
+                    left,
+                    right,
+                    validity,
+                    true_selection,
+                    false_selection,
+                    mutable_true_idx,
+                    mutable_false_idx,
+                    select_strategy,
+                    count,
+                );
+            }
+        };
+
+        // The `op` has been reversed if the scalar is on the left.
+        let lhs = ValueRef::Column(NumberType::<T>::try_downcast_column(&column).unwrap());
+        let rhs = match &other {
+            Value::Column(column) => {
+                ValueRef::Column(NumberType::<T>::try_downcast_column(column).unwrap())
+            }
+            Value::Scalar(scalar) => {
+                ValueRef::Scalar(NumberType::<T>::try_downcast_scalar(&scalar.as_ref()).unwrap())
+            }
+        };
+        let bitmap = T::simd_compare(op, lhs, rhs).into_column().unwrap();
+        let bitmap = match validity {
+            Some(validity) => &bitmap & &validity,
+            None => bitmap,
+        };
+
+        Ok(self.select_boolean_column_adapt(
+            bitmap,
+            true_selection,
+            false_selection,
+            mutable_true_idx,
+            mutable_false_idx,
+            select_strategy,
+            count,
+        ))
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn select_type_values<
         T: ValueType,
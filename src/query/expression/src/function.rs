@@ -63,6 +63,15 @@ pub struct FunctionSignature {
     pub return_type: DataType,
 }
 
+/// The document of a function, shown in `system.functions`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FunctionDoc {
+    // Introduce the function in brief.
+    pub description: &'static str,
+    // Example SQL of the function that can be run directly in query.
+    pub example: &'static str,
+}
+
 #[derive(EnumAsInner)]
 #[allow(clippy::type_complexity)]
 pub enum FunctionEval {
@@ -91,11 +100,34 @@ pub enum FunctionEval {
     },
 }
 
+/// How the overflowed results of the decimal arithmetic are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DecimalOverflowMode {
+    #[default]
+    Error,
+    /// The overflowed results are NULL, the arithmetic is resolved to its `try_` variant.
+    Null,
+    /// The overflowed results are clamped to the min or max value of the result precision.
+    Saturate,
+}
+
+impl DecimalOverflowMode {
+    pub fn from_setting(mode: &str) -> Self {
+        match mode {
+            "null" => DecimalOverflowMode::Null,
+            "saturate" => DecimalOverflowMode::Saturate,
+            _ => DecimalOverflowMode::Error,
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct FunctionContext {
     pub tz: TzLUT,
     pub rounding_mode: bool,
     pub disable_variant_check: bool,
+    pub enable_expression_jit: bool,
+    pub decimal_overflow_mode: DecimalOverflowMode,
 
     pub openai_api_chat_base_url: String,
     pub openai_api_embedding_base_url: String,
@@ -155,6 +187,8 @@ pub struct FunctionRegistry {
     pub auto_try_cast_rules: Vec<(DataType, DataType)>,
 
     pub properties: HashMap<String, FunctionProperty>,
+
+    pub docs: HashMap<String, FunctionDoc>,
 }
 
 impl Function {
@@ -422,6 +456,53 @@ impl FunctionRegistry {
         }
     }
 
+    pub fn register_doc(
+        &mut self,
+        fn_name: &str,
+        description: &'static str,
+        example: &'static str,
+    ) {
+        self.docs.insert(fn_name.to_string(), FunctionDoc {
+            description,
+            example,
+        });
+    }
+
+    pub fn get_doc(&self, func_name: &str) -> Option<FunctionDoc> {
+        let name = self
+            .aliases
+            .get(func_name)
+            .map_or(func_name, |n| n.as_str());
+        self.docs.get(name).copied()
+    }
+
+    /// Returns the signatures of the overloads of the function. The nullable overloads are
+    /// omitted if there are non-nullable ones, and the factory functions have no signatures.
+    pub fn get_signatures(&self, func_name: &str) -> Vec<String> {
+        let name = self
+            .aliases
+            .get(func_name)
+            .map_or(func_name, |n| n.as_str());
+        let Some(funcs) = self.funcs.get(name) else {
+            return vec![];
+        };
+
+        let signatures = funcs
+            .iter()
+            .sorted_by_key(|(_, id)| *id)
+            .map(|(func, _)| &func.signature)
+            .collect::<Vec<_>>();
+        let non_nullable = signatures
+            .iter()
+            .filter(|sig| !sig.args_type.iter().any(|ty| ty.is_nullable_or_null()))
+            .collect::<Vec<_>>();
+        if non_nullable.is_empty() {
+            signatures.iter().map(|sig| sig.to_string()).collect()
+        } else {
+            non_nullable.iter().map(|sig| sig.to_string()).collect()
+        }
+    }
+
     pub fn register_default_cast_rules(
         &mut self,
         default_cast_rules: impl IntoIterator<Item = (DataType, DataType)>,
@@ -712,3 +793,35 @@ pub fn error_to_null<I1: ArgType, O: ArgType>(
         }
     }
 }
+
+pub fn error_to_null_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
+    func: impl for<'a> Fn(ValueRef<'a, I1>, ValueRef<'a, I2>, &mut EvalContext) -> Value<O>
+        + Copy
+        + Send
+        + Sync,
+) -> impl for<'a> Fn(ValueRef<'a, I1>, ValueRef<'a, I2>, &mut EvalContext) -> Value<NullableType<O>>
+       + Copy
+       + Send
+       + Sync {
+    debug_assert!(!O::data_type().is_nullable_or_null());
+    move |lhs, rhs, ctx| {
+        let output = func(lhs, rhs, ctx);
+        if let Some((validity, _)) = ctx.errors.take() {
+            match output {
+                Value::Scalar(_) => Value::Scalar(None),
+                Value::Column(column) => Value::Column(NullableColumn {
+                    column,
+                    validity: validity.into(),
+                }),
+            }
+        } else {
+            match output {
+                Value::Scalar(scalar) => Value::Scalar(Some(scalar)),
+                Value::Column(column) => Value::Column(NullableColumn {
+                    column,
+                    validity: Bitmap::new_constant(true, ctx.num_rows),
+                }),
+            }
+        }
+    }
+}
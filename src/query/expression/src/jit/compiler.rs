@@ -0,0 +1,441 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+
+use cranelift_codegen::ir::condcodes::IntCC;
+use cranelift_codegen::ir::types;
+use cranelift_codegen::ir::AbiParam;
+use cranelift_codegen::ir::InstBuilder;
+use cranelift_codegen::ir::MemFlags;
+use cranelift_codegen::ir::Type;
+use cranelift_codegen::ir::Value as IrValue;
+use cranelift_codegen::settings;
+use cranelift_codegen::settings::Configurable;
+use cranelift_frontend::FunctionBuilder;
+use cranelift_frontend::FunctionBuilderContext;
+use cranelift_frontend::Variable;
+use cranelift_jit::JITBuilder;
+use cranelift_jit::JITModule;
+use cranelift_module::default_libcall_names;
+use cranelift_module::Linkage;
+use cranelift_module::Module;
+
+use crate::types::DataType;
+use crate::types::NumberDataType;
+use crate::types::NumberScalar;
+use crate::Expr;
+use crate::Scalar;
+
+/// `fn(inputs: *const *const u8, output: *mut u8, num_rows: u64)`
+///
+/// `inputs` are the values of the columns referred by the expression, in the order of
+/// `CompiledExpr::columns`. The results are written to `output`, a boolean takes one byte.
+pub type CompiledFn = unsafe extern "C" fn(*const *const u8, *mut u8, u64);
+
+/// The type of the values in the compiled code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JitType {
+    Number(NumberDataType),
+    Boolean,
+}
+
+impl JitType {
+    fn from_data_type(data_type: &DataType) -> Option<Self> {
+        match data_type {
+            DataType::Number(ty) => Some(JitType::Number(*ty)),
+            DataType::Boolean => Some(JitType::Boolean),
+            _ => None,
+        }
+    }
+
+    fn ir_type(&self) -> Type {
+        match self {
+            JitType::Boolean => types::I8,
+            JitType::Number(NumberDataType::Float32) => types::F32,
+            JitType::Number(NumberDataType::Float64) => types::F64,
+            JitType::Number(ty) => Type::int(ty.bit_width() as u16).unwrap(),
+        }
+    }
+
+    fn size(&self) -> i64 {
+        self.ir_type().bytes() as i64
+    }
+}
+
+/// Returns the signature of the expression if it can be compiled, which is used as the key of
+/// the compiled code, and the number of operations in it.
+///
+/// The supported expressions are the arithmetic, comparison and logical operations on the
+/// non-nullable numbers and booleans, which never fail.
+pub fn signature(expr: &Expr) -> Option<(String, usize)> {
+    let mut buf = String::new();
+    let num_ops = write_signature(expr, &mut buf)?;
+    JitType::from_data_type(expr.data_type())?;
+    Some((buf, num_ops))
+}
+
+fn write_signature(expr: &Expr, buf: &mut String) -> Option<usize> {
+    match expr {
+        Expr::Constant { scalar, .. } => {
+            match scalar {
+                Scalar::Number(_) | Scalar::Boolean(_) => {}
+                _ => return None,
+            }
+            write!(buf, "{scalar:?}").ok()?;
+            Some(0)
+        }
+        Expr::ColumnRef { id, data_type, .. } => {
+            if !matches!(data_type, DataType::Number(_)) {
+                return None;
+            }
+            write!(buf, "#{id}:{data_type}").ok()?;
+            Some(0)
+        }
+        Expr::Cast {
+            is_try: false,
+            expr,
+            dest_type: DataType::Number(dest),
+            ..
+        } => {
+            let DataType::Number(src) = expr.data_type() else {
+                return None;
+            };
+            if !src.can_lossless_cast_to(*dest) {
+                return None;
+            }
+            write!(buf, "CAST(").ok()?;
+            let num_ops = write_signature(expr, buf)?;
+            write!(buf, " AS {dest})").ok()?;
+            Some(num_ops + 1)
+        }
+        Expr::FunctionCall { function, args, .. } => {
+            let signature = &function.signature;
+            let arg_types = signature
+                .args_type
+                .iter()
+                .map(JitType::from_data_type)
+                .collect::<Option<Vec<_>>>()?;
+            let return_type = JitType::from_data_type(&signature.return_type)?;
+            let supported = match (signature.name.as_str(), arg_types.as_slice()) {
+                ("plus" | "minus" | "multiply", [JitType::Number(_), JitType::Number(_)]) => {
+                    matches!(return_type, JitType::Number(_))
+                }
+                (
+                    "eq" | "noteq" | "lt" | "lte" | "gt" | "gte",
+                    [JitType::Number(lhs), JitType::Number(rhs)],
+                ) => {
+                    // The floats are compared by total order, which is not supported.
+                    lhs == rhs && lhs.is_integer()
+                }
+                ("and" | "or", [JitType::Boolean, JitType::Boolean]) => true,
+                ("not", [JitType::Boolean]) => true,
+                _ => false,
+            };
+            if !supported {
+                return None;
+            }
+
+            write!(buf, "{}(", signature.name).ok()?;
+            let mut num_ops = 1;
+            for (i, arg) in args.iter().enumerate() {
+                if i > 0 {
+                    buf.push_str(", ");
+                }
+                num_ops += write_signature(arg, buf)?;
+            }
+            buf.push(')');
+            Some(num_ops)
+        }
+        _ => None,
+    }
+}
+
+/// The compiled code of an expression, the memory of the code is released on drop.
+pub struct CompiledExpr {
+    module: Option<JITModule>,
+    func: CompiledFn,
+    /// The offsets of the columns in the block, which are passed as the inputs.
+    pub columns: Vec<usize>,
+    pub return_type: JitType,
+}
+
+// Safety: the module is only used to release the memory, the compiled code has no state.
+unsafe impl Send for CompiledExpr {}
+unsafe impl Sync for CompiledExpr {}
+
+impl CompiledExpr {
+    /// # Safety
+    ///
+    /// `inputs` must point to the values of `columns`, each one has `num_rows` values of its type,
+    /// `output` must have space for `num_rows` values of `return_type`.
+    pub unsafe fn call(&self, inputs: &[*const u8], output: *mut u8, num_rows: usize) {
+        (self.func)(inputs.as_ptr(), output, num_rows as u64)
+    }
+}
+
+impl Drop for CompiledExpr {
+    fn drop(&mut self) {
+        if let Some(module) = self.module.take() {
+            unsafe { module.free_memory() }
+        }
+    }
+}
+
+/// Compiles the expression into a loop that evaluates it row by row.
+pub fn compile(expr: &Expr) -> Option<CompiledExpr> {
+    let mut flag_builder = settings::builder();
+    flag_builder.set("use_colocated_libcalls", "false").ok()?;
+    flag_builder.set("is_pic", "false").ok()?;
+    flag_builder.set("opt_level", "speed").ok()?;
+    let isa = cranelift_native::builder()
+        .ok()?
+        .finish(settings::Flags::new(flag_builder))
+        .ok()?;
+    let mut module = JITModule::new(JITBuilder::with_isa(isa, default_libcall_names()));
+
+    let ptr_type = module.target_config().pointer_type();
+    let mut ctx = module.make_context();
+    ctx.func.signature.params.push(AbiParam::new(ptr_type));
+    ctx.func.signature.params.push(AbiParam::new(ptr_type));
+    ctx.func.signature.params.push(AbiParam::new(types::I64));
+
+    let mut builder_ctx = FunctionBuilderContext::new();
+    let mut builder = FunctionBuilder::new(&mut ctx.func, &mut builder_ctx);
+    let entry_block = builder.create_block();
+    let header_block = builder.create_block();
+    let body_block = builder.create_block();
+    let exit_block = builder.create_block();
+
+    builder.append_block_params_for_function_params(entry_block);
+    builder.switch_to_block(entry_block);
+    builder.seal_block(entry_block);
+    let inputs = builder.block_params(entry_block)[0];
+    let output = builder.block_params(entry_block)[1];
+    let num_rows = builder.block_params(entry_block)[2];
+
+    let mut columns = Vec::new();
+    collect_columns(expr, &mut columns);
+    let column_ptrs = (0..columns.len())
+        .map(|i| {
+            builder.ins().load(
+                ptr_type,
+                MemFlags::trusted(),
+                inputs,
+                (i * ptr_type.bytes() as usize) as i32,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let row = Variable::new(0);
+    builder.declare_var(row, types::I64);
+    let zero = builder.ins().iconst(types::I64, 0);
+    builder.def_var(row, zero);
+    builder.ins().jump(header_block, &[]);
+
+    // while row < num_rows
+    builder.switch_to_block(header_block);
+    let row_idx = builder.use_var(row);
+    let finished = builder
+        .ins()
+        .icmp(IntCC::UnsignedGreaterThanOrEqual, row_idx, num_rows);
+    builder
+        .ins()
+        .brif(finished, exit_block, &[], body_block, &[]);
+
+    builder.switch_to_block(body_block);
+    builder.seal_block(body_block);
+    let mut codegen = CodeGen {
+        builder: &mut builder,
+        columns: &columns,
+        column_ptrs: &column_ptrs,
+        row_idx,
+    };
+    let (value, return_type) = codegen.translate(expr)?;
+    let offset = builder.ins().imul_imm(row_idx, return_type.size());
+    let address = builder.ins().iadd(output, offset);
+    builder.ins().store(MemFlags::trusted(), value, address, 0);
+    let next_row = builder.ins().iadd_imm(row_idx, 1);
+    builder.def_var(row, next_row);
+    builder.ins().jump(header_block, &[]);
+    builder.seal_block(header_block);
+
+    builder.switch_to_block(exit_block);
+    builder.seal_block(exit_block);
+    builder.ins().return_(&[]);
+    builder.finalize();
+
+    let func_id = module
+        .declare_function("eval", Linkage::Export, &ctx.func.signature)
+        .ok()?;
+    module.define_function(func_id, &mut ctx).ok()?;
+    module.clear_context(&mut ctx);
+    module.finalize_definitions().ok()?;
+    let code = module.get_finalized_function(func_id);
+
+    Some(CompiledExpr {
+        module: Some(module),
+        func: unsafe { std::mem::transmute::<*const u8, CompiledFn>(code) },
+        columns,
+        return_type,
+    })
+}
+
+fn collect_columns(expr: &Expr, columns: &mut Vec<usize>) {
+    match expr {
+        Expr::ColumnRef { id, .. } => {
+            if !columns.contains(id) {
+                columns.push(*id);
+            }
+        }
+        Expr::Cast { expr, .. } => collect_columns(expr, columns),
+        Expr::FunctionCall { args, .. } => {
+            for arg in args {
+                collect_columns(arg, columns);
+            }
+        }
+        _ => {}
+    }
+}
+
+struct CodeGen<'a, 'b> {
+    builder: &'a mut FunctionBuilder<'b>,
+    columns: &'a [usize],
+    column_ptrs: &'a [IrValue],
+    row_idx: IrValue,
+}
+
+impl<'a, 'b> CodeGen<'a, 'b> {
+    fn translate(&mut self, expr: &Expr) -> Option<(IrValue, JitType)> {
+        match expr {
+            Expr::Constant {
+                scalar, data_type, ..
+            } => {
+                let ty = JitType::from_data_type(data_type)?;
+                let ins = self.builder.ins();
+                let value = match scalar {
+                    Scalar::Boolean(value) => ins.iconst(types::I8, *value as i64),
+                    Scalar::Number(NumberScalar::Float32(value)) => ins.f32const(value.0),
+                    Scalar::Number(NumberScalar::Float64(value)) => ins.f64const(value.0),
+                    Scalar::Number(number) => {
+                        // The immediate is the zero-extended bits of the integer.
+                        let bit_width = ty.ir_type().bits();
+                        let mut value = number.integer_to_i128()? as i64;
+                        if bit_width < 64 {
+                            value &= (1i64 << bit_width) - 1;
+                        }
+                        ins.iconst(ty.ir_type(), value)
+                    }
+                    _ => return None,
+                };
+                Some((value, ty))
+            }
+            Expr::ColumnRef { id, data_type, .. } => {
+                let ty = JitType::from_data_type(data_type)?;
+                let idx = self.columns.iter().position(|column| column == id)?;
+                let offset = self.builder.ins().imul_imm(self.row_idx, ty.size());
+                let address = self.builder.ins().iadd(self.column_ptrs[idx], offset);
+                let value = self
+                    .builder
+                    .ins()
+                    .load(ty.ir_type(), MemFlags::trusted(), address, 0);
+                Some((value, ty))
+            }
+            Expr::Cast {
+                expr, dest_type, ..
+            } => {
+                let dest = JitType::from_data_type(dest_type)?;
+                let (value, src) = self.translate(expr)?;
+                Some((self.convert(value, src, dest)?, dest))
+            }
+            Expr::FunctionCall { function, args, .. } => {
+                let signature = &function.signature;
+                let return_type = JitType::from_data_type(&signature.return_type)?;
+                let mut values = Vec::with_capacity(args.len());
+                for arg in args {
+                    values.push(self.translate(arg)?);
+                }
+
+                let value = match (signature.name.as_str(), values.as_slice()) {
+                    ("plus" | "minus" | "multiply", [(lhs, lhs_ty), (rhs, rhs_ty)]) => {
+                        let lhs = self.convert(*lhs, *lhs_ty, return_type)?;
+                        let rhs = self.convert(*rhs, *rhs_ty, return_type)?;
+                        let is_float = matches!(return_type, JitType::Number(ty) if ty.is_float());
+                        let ins = self.builder.ins();
+                        match (signature.name.as_str(), is_float) {
+                            ("plus", false) => ins.iadd(lhs, rhs),
+                            ("minus", false) => ins.isub(lhs, rhs),
+                            ("multiply", false) => ins.imul(lhs, rhs),
+                            ("plus", true) => ins.fadd(lhs, rhs),
+                            ("minus", true) => ins.fsub(lhs, rhs),
+                            ("multiply", true) => ins.fmul(lhs, rhs),
+                            _ => unreachable!(),
+                        }
+                    }
+                    (name, [(lhs, JitType::Number(ty)), (rhs, _)])
+                        if matches!(name, "eq" | "noteq" | "lt" | "lte" | "gt" | "gte") =>
+                    {
+                        let cond = match (name, ty.is_signed()) {
+                            ("eq", _) => IntCC::Equal,
+                            ("noteq", _) => IntCC::NotEqual,
+                            ("lt", true) => IntCC::SignedLessThan,
+                            ("lte", true) => IntCC::SignedLessThanOrEqual,
+                            ("gt", true) => IntCC::SignedGreaterThan,
+                            ("gte", true) => IntCC::SignedGreaterThanOrEqual,
+                            ("lt", false) => IntCC::UnsignedLessThan,
+                            ("lte", false) => IntCC::UnsignedLessThanOrEqual,
+                            ("gt", false) => IntCC::UnsignedGreaterThan,
+                            ("gte", false) => IntCC::UnsignedGreaterThanOrEqual,
+                            _ => unreachable!(),
+                        };
+                        self.builder.ins().icmp(cond, *lhs, *rhs)
+                    }
+                    ("and", [(lhs, _), (rhs, _)]) => self.builder.ins().band(*lhs, *rhs),
+                    ("or", [(lhs, _), (rhs, _)]) => self.builder.ins().bor(*lhs, *rhs),
+                    ("not", [(arg, _)]) => self.builder.ins().bxor_imm(*arg, 1),
+                    _ => return None,
+                };
+                Some((value, return_type))
+            }
+            _ => None,
+        }
+    }
+
+    // Converts the value as the `as` operator of rust.
+    fn convert(&mut self, value: IrValue, src: JitType, dest: JitType) -> Option<IrValue> {
+        let (JitType::Number(src), JitType::Number(dest)) = (src, dest) else {
+            return (src == dest).then_some(value);
+        };
+        if src == dest {
+            return Some(value);
+        }
+
+        let dest_type = JitType::Number(dest).ir_type();
+        let ins = self.builder.ins();
+        let value = match (src.is_float(), dest.is_float()) {
+            (false, false) => match src.bit_width().cmp(&dest.bit_width()) {
+                std::cmp::Ordering::Less if src.is_signed() => ins.sextend(dest_type, value),
+                std::cmp::Ordering::Less => ins.uextend(dest_type, value),
+                std::cmp::Ordering::Equal => value,
+                std::cmp::Ordering::Greater => ins.ireduce(dest_type, value),
+            },
+            (false, true) if src.is_signed() => ins.fcvt_from_sint(dest_type, value),
+            (false, true) => ins.fcvt_from_uint(dest_type, value),
+            (true, true) if src.bit_width() < dest.bit_width() => ins.fpromote(dest_type, value),
+            (true, true) => ins.fdemote(dest_type, value),
+            (true, false) => return None,
+        };
+        Some(value)
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compiles the expressions on the primitive types into native code with cranelift.
+
+mod compiler;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+
+use self::compiler::compile;
+use self::compiler::signature;
+use self::compiler::CompiledExpr;
+use self::compiler::JitType;
+use crate::types::number::NumberColumn;
+use crate::types::AnyType;
+use crate::types::NumberDataType;
+use crate::with_number_mapped_type;
+use crate::Column;
+use crate::DataBlock;
+use crate::Expr;
+use crate::Value;
+
+/// The expressions with fewer operations are not compiled, they are fast enough with the
+/// vectorized functions.
+const MIN_JIT_OPERATIONS: usize = 2;
+
+const MAX_COMPILED_EXPRS: usize = 1024;
+
+/// The compiled expressions by their signatures, None if failed to compile.
+static COMPILED_EXPRS: LazyLock<Mutex<HashMap<String, Option<Arc<CompiledExpr>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Evaluates the expression with the compiled code, returns None if the expression or the
+/// block is not supported.
+pub fn try_eval(expr: &Expr, data_block: &DataBlock) -> Option<Value<AnyType>> {
+    let num_rows = data_block.num_rows();
+    if num_rows == 0 {
+        return None;
+    }
+    let (signature, num_ops) = signature(expr)?;
+    if num_ops < MIN_JIT_OPERATIONS {
+        return None;
+    }
+
+    let compiled = get_or_compile(signature, expr)?;
+    let inputs = compiled
+        .columns
+        .iter()
+        .map(|offset| match &data_block.get_by_offset(*offset).value {
+            Value::Column(Column::Number(column)) => Some(number_column_ptr(column)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let column = match compiled.return_type {
+        JitType::Boolean => {
+            let mut values = Vec::<u8>::with_capacity(num_rows);
+            unsafe {
+                compiled.call(&inputs, values.as_mut_ptr(), num_rows);
+                values.set_len(num_rows);
+            }
+            Column::Boolean(values.iter().map(|value| *value != 0).collect::<Bitmap>())
+        }
+        JitType::Number(ty) => with_number_mapped_type!(|NUM_TYPE| match ty {
+            NumberDataType::NUM_TYPE => {
+                let mut values = Vec::<NUM_TYPE>::with_capacity(num_rows);
+                unsafe {
+                    compiled.call(&inputs, values.as_mut_ptr() as *mut u8, num_rows);
+                    values.set_len(num_rows);
+                }
+                Column::Number(NumberColumn::NUM_TYPE(values.into()))
+            }
+        }),
+    };
+    Some(Value::Column(column))
+}
+
+fn get_or_compile(signature: String, expr: &Expr) -> Option<Arc<CompiledExpr>> {
+    if let Some(compiled) = COMPILED_EXPRS.lock().unwrap().get(&signature) {
+        return compiled.clone();
+    }
+
+    // Compile without the lock, an expression may be compiled more than once at the beginning.
+    let compiled = compile(expr).map(Arc::new);
+    let mut compiled_exprs = COMPILED_EXPRS.lock().unwrap();
+    if compiled_exprs.len() >= MAX_COMPILED_EXPRS {
+        compiled_exprs.clear();
+    }
+    compiled_exprs.insert(signature, compiled.clone());
+    compiled
+}
+
+fn number_column_ptr(column: &NumberColumn) -> *const u8 {
+    with_number_mapped_type!(|NUM_TYPE| match column {
+        NumberColumn::NUM_TYPE(values) => values.as_ptr() as *const u8,
+    })
+}
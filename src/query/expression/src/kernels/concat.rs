@@ -32,6 +32,8 @@ use crate::types::geometry::GeometryType;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
+use crate::types::run_length::RunLengthColumn;
+use crate::types::string::rebase_view;
 use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
@@ -118,6 +120,12 @@ impl Column {
             Some(col) => col,
         };
         let capacity = columns_iter_clone.fold(first_column.len(), |acc, x| acc + x.len());
+        if columns
+            .clone()
+            .any(|col| matches!(col, Column::Constant(_) | Column::RunLength(_)))
+        {
+            return Self::concat_compact_columns(columns.collect(), capacity);
+        }
         let column = match first_column {
             Column::Null { .. } => Column::Null { len: capacity },
             Column::EmptyArray { .. } => Column::EmptyArray { len: capacity },
@@ -249,9 +257,29 @@ impl Column {
                 capacity,
             )),
             Column::String(_) => StringType::upcast_column(Self::concat_string_types(
-                columns.map(|col| col.into_string().unwrap()),
+                columns.map(|col| StringType::try_downcast_column(&col).unwrap()),
                 capacity,
             )),
+            Column::StringDictionary(first) => {
+                let same_values = columns.clone().all(|col| match col {
+                    Column::StringDictionary(col) => col.has_same_values(&first),
+                    _ => false,
+                });
+                if same_values {
+                    // The blocks read from the same page share the values, only the keys
+                    // need to be concatenated.
+                    let keys = Self::concat_primitive_types(
+                        columns.map(|col| col.into_string_dictionary().unwrap().keys().clone()),
+                        capacity,
+                    );
+                    Column::StringDictionary(first.with_keys(keys.into()))
+                } else {
+                    StringType::upcast_column(Self::concat_string_types(
+                        columns.map(|col| StringType::try_downcast_column(&col).unwrap()),
+                        capacity,
+                    ))
+                }
+            }
             Column::Timestamp(_) => {
                 let builder = Self::concat_primitive_types(
                     columns.map(|col| col.into_timestamp().unwrap()),
@@ -282,6 +310,13 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(_) => Column::Interval(
+                Self::concat_primitive_types(
+                    columns.map(|col| col.into_interval().unwrap()),
+                    capacity,
+                )
+                .into(),
+            ),
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -343,10 +378,45 @@ impl Column {
                 columns.map(|col| col.into_geometry().unwrap()),
                 capacity,
             )),
+            Column::Constant(_) | Column::RunLength(_) => unreachable!(),
         };
         Ok(column)
     }
 
+    /// Concatenates the columns that contain constant or run-length columns, the result
+    /// stays compact if all the columns are compact, otherwise they are expanded.
+    fn concat_compact_columns(columns: Vec<Column>, capacity: usize) -> Result<Column> {
+        if let Column::Constant(first) = &columns[0] {
+            let same_value = columns.iter().all(|col| match col {
+                Column::Constant(col) => col.value() == first.value(),
+                _ => false,
+            });
+            if same_value {
+                return Ok(Column::Constant(Box::new(first.with_len(capacity))));
+            }
+        }
+
+        let all_compact = columns
+            .iter()
+            .all(|col| matches!(col, Column::Constant(_) | Column::RunLength(_)));
+        if all_compact {
+            let columns = columns
+                .iter()
+                .map(|col| match col {
+                    Column::Constant(col) => RunLengthColumn::from_constant(col),
+                    Column::RunLength(col) => col.as_ref().clone(),
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>();
+            return Ok(Column::RunLength(Box::new(RunLengthColumn::concat(
+                &columns,
+            )?)));
+        }
+
+        let columns = columns.iter().map(|col| col.expand()).collect::<Vec<_>>();
+        Self::concat_columns_impl(columns.into_iter())
+    }
+
     pub fn concat_primitive_types<T>(
         cols: impl Iterator<Item = Buffer<T>>,
         num_rows: usize,
@@ -408,12 +478,13 @@ impl Column {
         cols: impl Iterator<Item = StringColumn> + Clone,
         num_rows: usize,
     ) -> StringColumn {
-        unsafe {
-            StringColumn::from_binary_unchecked(Self::concat_binary_types(
-                cols.map(Into::into),
-                num_rows,
-            ))
+        let cols = cols.collect::<Vec<_>>();
+        let (buffers, bases) = StringColumn::merge_buffers(cols.iter());
+        let mut views = Vec::with_capacity(num_rows);
+        for (col, base) in cols.iter().zip(bases) {
+            views.extend(col.views().iter().map(|view| rebase_view(*view, base)));
         }
+        unsafe { StringColumn::from_views_unchecked(views.into(), buffers) }
     }
 
     pub fn concat_boolean_types(bitmaps: impl Iterator<Item = Bitmap>, num_rows: usize) -> Bitmap {
@@ -131,6 +131,12 @@ impl Column {
                 let column = Self::filter_string_scalars(column, filter);
                 Column::String(column)
             }
+            Column::StringDictionary(column) => {
+                let keys = Self::filter_primitive_types(column.keys(), filter);
+                Column::StringDictionary(column.with_keys(keys))
+            }
+            Column::Constant(column) => Column::Constant(Box::new(column.with_len(length))),
+            Column::RunLength(column) => Column::RunLength(Box::new(column.filter(filter))),
             Column::Timestamp(column) => {
                 let ts = Self::filter_primitive_types(column, filter);
                 Column::Timestamp(ts)
@@ -139,6 +145,10 @@ impl Column {
                 let d = Self::filter_primitive_types(column, filter);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::filter_primitive_types(column, filter);
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(length + 1);
                 offsets.push(0);
@@ -451,12 +461,8 @@ impl Column {
     }
 
     fn filter_string_scalars(values: &StringColumn, filter: &Bitmap) -> StringColumn {
-        unsafe {
-            StringColumn::from_binary_unchecked(Self::filter_binary_scalars(
-                &values.clone().into(),
-                filter,
-            ))
-        }
+        // Only the views are copied, the strings are shared with the original column.
+        values.with_views(Self::filter_primitive_types(values.views(), filter))
     }
 
     /// # Safety
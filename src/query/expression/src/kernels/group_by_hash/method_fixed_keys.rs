@@ -240,7 +240,7 @@ macro_rules! impl_hash_method_fixed_keys {
                 // faster path for single fixed keys
                 if group_columns.len() == 1 {
                     if group_columns[0].1.is_unsigned_numeric() {
-                        return Ok(KeysState::Column(group_columns[0].0.clone()));
+                        return Ok(KeysState::Column(group_columns[0].0.expand()));
                     }
 
                     if group_columns[0].1.is_signed_numeric() {
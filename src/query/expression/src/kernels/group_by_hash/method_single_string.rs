@@ -17,6 +17,9 @@ use databend_common_exception::Result;
 use databend_common_hashtable::hash_join_fast_string_hash;
 
 use crate::types::binary::BinaryIterator;
+use crate::types::string::StringBinaryIterator;
+use crate::types::string::StringColumn;
+use crate::types::string::StringDictionaryIterator;
 use crate::types::DataType;
 use crate::Column;
 use crate::HashMethod;
@@ -29,7 +32,7 @@ pub struct HashMethodSingleBinary {}
 impl HashMethod for HashMethodSingleBinary {
     type HashKey = [u8];
 
-    type HashKeyIter<'a> = BinaryIterator<'a>;
+    type HashKeyIter<'a> = SingleBinaryKeyIter<'a>;
 
     fn name(&self) -> String {
         "SingleBinary".to_string()
@@ -40,15 +43,20 @@ impl HashMethod for HashMethodSingleBinary {
         group_columns: &[(Column, DataType)],
         _rows: usize,
     ) -> Result<KeysState> {
-        Ok(KeysState::Column(group_columns[0].0.clone()))
+        Ok(KeysState::Column(group_columns[0].0.expand()))
     }
 
     fn build_keys_iter<'a>(&self, keys_state: &'a KeysState) -> Result<Self::HashKeyIter<'a>> {
         match keys_state {
             KeysState::Column(Column::Binary(col))
             | KeysState::Column(Column::Variant(col))
-            | KeysState::Column(Column::Bitmap(col)) => Ok(col.iter()),
-            KeysState::Column(Column::String(col)) => Ok(col.iter_binary()),
+            | KeysState::Column(Column::Bitmap(col)) => Ok(SingleBinaryKeyIter::Binary(col.iter())),
+            KeysState::Column(Column::String(col)) => {
+                Ok(SingleBinaryKeyIter::String(col.iter_binary()))
+            }
+            KeysState::Column(Column::StringDictionary(col)) => {
+                Ok(SingleBinaryKeyIter::Dictionary(col.iter()))
+            }
             _ => unreachable!(),
         }
     }
@@ -68,14 +76,59 @@ impl HashMethod for HashMethodSingleBinary {
             }
             KeysState::Column(Column::String(col)) => {
                 hashes.extend(col.iter_binary().map(hash_join_fast_string_hash));
-                let (data, offsets) = col.into_buffer();
-                Ok(Box::new(BinaryKeyAccessor::new(data, offsets)))
+                Ok(Box::new(StringKeyAccessor::new(col)))
+            }
+            KeysState::Column(Column::StringDictionary(col)) => {
+                // Each distinct value is hashed only once.
+                let value_hashes = col
+                    .values()
+                    .iter_binary()
+                    .map(hash_join_fast_string_hash)
+                    .collect::<Vec<_>>();
+                hashes.extend(
+                    col.keys()
+                        .iter()
+                        .map(|key| unsafe { *value_hashes.get_unchecked(*key as usize) }),
+                );
+                let keys = col.keys().clone();
+                Ok(Box::new(DictionaryKeyAccessor::new(
+                    col.values().clone(),
+                    keys,
+                )))
             }
             _ => unreachable!(),
         }
     }
 }
 
+pub enum SingleBinaryKeyIter<'a> {
+    Binary(BinaryIterator<'a>),
+    String(StringBinaryIterator<'a>),
+    Dictionary(StringDictionaryIterator<'a>),
+}
+
+impl<'a> Iterator for SingleBinaryKeyIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            SingleBinaryKeyIter::Binary(iter) => iter.next(),
+            SingleBinaryKeyIter::String(iter) => iter.next(),
+            SingleBinaryKeyIter::Dictionary(iter) => iter.next().map(|value| value.as_bytes()),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            SingleBinaryKeyIter::Binary(iter) => iter.size_hint(),
+            SingleBinaryKeyIter::String(iter) => iter.size_hint(),
+            SingleBinaryKeyIter::Dictionary(iter) => iter.size_hint(),
+        }
+    }
+}
+
+unsafe impl<'a> std::iter::TrustedLen for SingleBinaryKeyIter<'a> {}
+
 pub struct BinaryKeyAccessor {
     data: Buffer<u8>,
     offsets: Buffer<u64>,
@@ -99,3 +152,47 @@ impl KeyAccessor for BinaryKeyAccessor {
             ..*self.offsets.get_unchecked(index + 1) as usize]
     }
 }
+
+pub struct StringKeyAccessor {
+    col: StringColumn,
+}
+
+impl StringKeyAccessor {
+    pub fn new(col: StringColumn) -> Self {
+        Self { col }
+    }
+}
+
+impl KeyAccessor for StringKeyAccessor {
+    type Key = [u8];
+
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*.
+    unsafe fn key_unchecked(&self, index: usize) -> &Self::Key {
+        self.col.index_unchecked_bytes(index)
+    }
+}
+
+pub struct DictionaryKeyAccessor {
+    values: StringColumn,
+    keys: Buffer<u32>,
+}
+
+impl DictionaryKeyAccessor {
+    pub fn new(values: StringColumn, keys: Buffer<u32>) -> Self {
+        Self { values, keys }
+    }
+}
+
+impl KeyAccessor for DictionaryKeyAccessor {
+    type Key = [u8];
+
+    /// # Safety
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*.
+    unsafe fn key_unchecked(&self, index: usize) -> &Self::Key {
+        debug_assert!(index < self.keys.len());
+
+        let key = *self.keys.get_unchecked(index) as usize;
+        self.values.index_unchecked_bytes(key)
+    }
+}
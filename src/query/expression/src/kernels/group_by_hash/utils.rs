@@ -88,8 +88,17 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
             store_advance::<u64>(&(len as u64), row_space);
             copy_advance_aligned::<u8>(value.as_ptr(), row_space, len);
         }
+        Column::StringDictionary(v) => {
+            let value = unsafe { v.index_unchecked(row) };
+            let len = value.len();
+            store_advance::<u64>(&(len as u64), row_space);
+            copy_advance_aligned::<u8>(value.as_ptr(), row_space, len);
+        }
+        Column::Constant(v) => serialize_column_binary(v.value(), 0, row_space),
+        Column::RunLength(v) => serialize_column_binary(v.values(), v.run_index(row), row_space),
         Column::Timestamp(v) => store_advance::<i64>(&v[row], row_space),
         Column::Date(v) => store_advance::<i32>(&v[row], row_space),
+        Column::Interval(v) => store_advance::<i128>(&v[row].to_i128(), row_space),
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             store_advance::<u64>(&(data.len() as u64), row_space);
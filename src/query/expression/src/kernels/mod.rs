@@ -17,6 +17,7 @@ mod filter;
 mod group_by;
 mod group_by_hash;
 mod scatter;
+mod simd;
 mod sort;
 mod take;
 mod take_chunks;
@@ -26,6 +27,7 @@ mod topk;
 mod utils;
 
 pub use group_by_hash::*;
+pub use simd::*;
 pub use sort::*;
 pub use take_chunks::*;
 pub use topk::*;
@@ -0,0 +1,383 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Explicit SIMD kernels of the comparisons and arithmetics on the primitive columns.
+//!
+//! The kernels are compiled twice on x86_64, once for the baseline target and once with
+//! AVX2 enabled, the AVX2 version is picked at runtime if the CPU supports it.
+
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Sub;
+use std::simd::prelude::SimdPartialEq;
+use std::simd::prelude::SimdPartialOrd;
+use std::simd::Mask;
+use std::simd::Simd;
+use std::simd::SimdElement;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::buffer::Buffer;
+use num_traits::AsPrimitive;
+
+use crate::filter::SelectOp;
+use crate::types::number::Number;
+use crate::types::number::F32;
+use crate::types::number::F64;
+use crate::types::BooleanType;
+use crate::types::NumberType;
+use crate::Value;
+use crate::ValueRef;
+
+const LANES: usize = 16;
+
+type Lanes<T> = Simd<<T as SimdNumber>::Lane, LANES>;
+type LanesMask<T> = Mask<<<T as SimdNumber>::Lane as SimdElement>::Mask, LANES>;
+
+/// Number types that can be processed as the SIMD lanes.
+pub trait SimdNumber: Number {
+    /// The lane type that has the same memory layout as `Self`.
+    type Lane: SimdElement + Default + AsPrimitive<Self::Lane>;
+
+    /// Compares two number values.
+    fn simd_compare(
+        op: &SelectOp,
+        lhs: ValueRef<NumberType<Self>>,
+        rhs: ValueRef<NumberType<Self>>,
+    ) -> Value<BooleanType>;
+
+    /// Computes `lhs op rhs` after casting both sides to `Self`, the integers wrap on overflow.
+    fn simd_arithmetic<L: SimdNumber, R: SimdNumber>(
+        op: ArithmeticOp,
+        lhs: ValueRef<NumberType<L>>,
+        rhs: ValueRef<NumberType<R>>,
+    ) -> Value<NumberType<Self>>
+    where
+        L::Lane: AsPrimitive<Self::Lane>,
+        R::Lane: AsPrimitive<Self::Lane>;
+}
+
+macro_rules! impl_simd_number {
+    ($($ty:ty => $lane:ty, $compare:ident);* $(;)?) => {
+        $(
+            impl SimdNumber for $ty {
+                type Lane = $lane;
+
+                fn simd_compare(
+                    op: &SelectOp,
+                    lhs: ValueRef<NumberType<Self>>,
+                    rhs: ValueRef<NumberType<Self>>,
+                ) -> Value<BooleanType> {
+                    $compare::<Self>(op, lhs, rhs)
+                }
+
+                fn simd_arithmetic<L: SimdNumber, R: SimdNumber>(
+                    op: ArithmeticOp,
+                    lhs: ValueRef<NumberType<L>>,
+                    rhs: ValueRef<NumberType<R>>,
+                ) -> Value<NumberType<Self>>
+                where
+                    L::Lane: AsPrimitive<Self::Lane>,
+                    R::Lane: AsPrimitive<Self::Lane>,
+                {
+                    arithmetic_values::<L, R, Self>(op, lhs, rhs)
+                }
+            }
+        )*
+    };
+}
+
+// The floats are compared row by row, as `F32` and `F64` compare `NaN` as the greatest
+// value, which is different from the lanes comparisons.
+impl_simd_number! {
+    u8 => u8, compare_values;
+    u16 => u16, compare_values;
+    u32 => u32, compare_values;
+    u64 => u64, compare_values;
+    i8 => i8, compare_values;
+    i16 => i16, compare_values;
+    i32 => i32, compare_values;
+    i64 => i64, compare_values;
+    F32 => f32, compare_rows;
+    F64 => f64, compare_rows;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticOp {
+    Plus,
+    Minus,
+    Multiply,
+}
+
+fn compare_values<T: SimdNumber>(
+    op: &SelectOp,
+    lhs: ValueRef<NumberType<T>>,
+    rhs: ValueRef<NumberType<T>>,
+) -> Value<BooleanType>
+where
+    Lanes<T>: SimdPartialOrd<Mask = LanesMask<T>>,
+{
+    if let (ValueRef::Scalar(lhs), ValueRef::Scalar(rhs)) = (&lhs, &rhs) {
+        return Value::Scalar(compare_scalar(op, *lhs, *rhs));
+    }
+    let len = value_len(&lhs).max(value_len(&rhs));
+    Value::Column(compare(op, len, SimdArg::new(&lhs), SimdArg::new(&rhs)))
+}
+
+fn compare_rows<T: SimdNumber>(
+    op: &SelectOp,
+    lhs: ValueRef<NumberType<T>>,
+    rhs: ValueRef<NumberType<T>>,
+) -> Value<BooleanType> {
+    match (lhs, rhs) {
+        (ValueRef::Scalar(lhs), ValueRef::Scalar(rhs)) => {
+            Value::Scalar(compare_scalar(op, lhs, rhs))
+        }
+        (ValueRef::Column(lhs), ValueRef::Scalar(rhs)) => Value::Column(
+            Bitmap::from_trusted_len_iter(lhs.iter().map(|lhs| compare_scalar(op, *lhs, rhs))),
+        ),
+        (ValueRef::Scalar(lhs), ValueRef::Column(rhs)) => Value::Column(
+            Bitmap::from_trusted_len_iter(rhs.iter().map(|rhs| compare_scalar(op, lhs, *rhs))),
+        ),
+        (ValueRef::Column(lhs), ValueRef::Column(rhs)) => {
+            Value::Column(Bitmap::from_trusted_len_iter(
+                lhs.iter()
+                    .zip(rhs.iter())
+                    .map(|(lhs, rhs)| compare_scalar(op, *lhs, *rhs)),
+            ))
+        }
+    }
+}
+
+fn arithmetic_values<L: SimdNumber, R: SimdNumber, T: SimdNumber>(
+    op: ArithmeticOp,
+    lhs: ValueRef<NumberType<L>>,
+    rhs: ValueRef<NumberType<R>>,
+) -> Value<NumberType<T>>
+where
+    Lanes<T>: Add<Output = Lanes<T>> + Sub<Output = Lanes<T>> + Mul<Output = Lanes<T>>,
+    L::Lane: AsPrimitive<T::Lane>,
+    R::Lane: AsPrimitive<T::Lane>,
+{
+    let is_scalar = matches!((&lhs, &rhs), (ValueRef::Scalar(_), ValueRef::Scalar(_)));
+    // The scalars are computed as a column of one row.
+    let len = value_len(&lhs).max(value_len(&rhs));
+    let values = arithmetic(op, len, SimdArg::new(&lhs), SimdArg::new(&rhs));
+    if is_scalar {
+        Value::Scalar(values[0])
+    } else {
+        Value::Column(values)
+    }
+}
+
+fn compare<T: SimdNumber>(op: &SelectOp, len: usize, lhs: SimdArg<T>, rhs: SimdArg<T>) -> Bitmap
+where Lanes<T>: SimdPartialOrd<Mask = LanesMask<T>> {
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // Safety: the CPU supports AVX2.
+        return unsafe { compare_avx2::<T>(op, len, lhs, rhs) };
+    }
+    compare_lanes::<T>(op, len, lhs, rhs)
+}
+
+fn arithmetic<L: SimdNumber, R: SimdNumber, T: SimdNumber>(
+    op: ArithmeticOp,
+    len: usize,
+    lhs: SimdArg<L>,
+    rhs: SimdArg<R>,
+) -> Buffer<T>
+where
+    Lanes<T>: Add<Output = Lanes<T>> + Sub<Output = Lanes<T>> + Mul<Output = Lanes<T>>,
+    L::Lane: AsPrimitive<T::Lane>,
+    R::Lane: AsPrimitive<T::Lane>,
+{
+    #[cfg(target_arch = "x86_64")]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        // Safety: the CPU supports AVX2.
+        return unsafe { arithmetic_avx2::<L, R, T>(op, len, lhs, rhs) };
+    }
+    arithmetic_lanes::<L, R, T>(op, len, lhs, rhs)
+}
+
+fn compare_scalar<T: Number>(op: &SelectOp, lhs: T, rhs: T) -> bool {
+    match op {
+        SelectOp::Equal => lhs == rhs,
+        SelectOp::NotEqual => lhs != rhs,
+        SelectOp::Gt => lhs > rhs,
+        SelectOp::Lt => lhs < rhs,
+        SelectOp::Gte => lhs >= rhs,
+        SelectOp::Lte => lhs <= rhs,
+    }
+}
+
+fn value_len<T: SimdNumber>(value: &ValueRef<NumberType<T>>) -> usize {
+    match value {
+        ValueRef::Scalar(_) => 1,
+        ValueRef::Column(col) => col.len(),
+    }
+}
+
+/// A column or a scalar viewed as the lanes.
+#[derive(Clone, Copy)]
+enum SimdArg<'a, T: SimdNumber> {
+    Column(&'a [T::Lane]),
+    Scalar(T::Lane),
+}
+
+impl<'a, T: SimdNumber> SimdArg<'a, T> {
+    fn new(value: &'a ValueRef<NumberType<T>>) -> Self {
+        match value {
+            ValueRef::Column(col) => SimdArg::Column(as_lanes::<T>(col)),
+            ValueRef::Scalar(scalar) => SimdArg::Scalar(as_lanes::<T>(&[*scalar])[0]),
+        }
+    }
+
+    /// Loads the lanes starting at `offset` as `U`, the lanes out of the column are filled
+    /// with default.
+    #[inline(always)]
+    fn load<U: SimdNumber>(&self, offset: usize) -> Lanes<U>
+    where T::Lane: AsPrimitive<U::Lane> {
+        let mut lanes = [U::Lane::default(); LANES];
+        match self {
+            SimdArg::Column(values) => {
+                let chunk = &values[offset..values.len().min(offset + LANES)];
+                for (lane, value) in lanes.iter_mut().zip(chunk) {
+                    *lane = value.as_();
+                }
+            }
+            SimdArg::Scalar(scalar) => lanes = [scalar.as_(); LANES],
+        }
+        Simd::from_array(lanes)
+    }
+}
+
+fn as_lanes<T: SimdNumber>(values: &[T]) -> &[T::Lane] {
+    debug_assert_eq!(std::mem::size_of::<T>(), std::mem::size_of::<T::Lane>());
+    // Safety: `T` and `T::Lane` have the same memory layout.
+    unsafe { std::slice::from_raw_parts(values.as_ptr() as *const T::Lane, values.len()) }
+}
+
+fn from_lanes<T: SimdNumber>(values: Vec<T::Lane>) -> Buffer<T> {
+    let (ptr, len, cap) = values.into_raw_parts();
+    // Safety: `T` and `T::Lane` have the same memory layout.
+    let values = unsafe { Vec::from_raw_parts(ptr as *mut T, len, cap) };
+    values.into()
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn compare_avx2<T: SimdNumber>(
+    op: &SelectOp,
+    len: usize,
+    lhs: SimdArg<T>,
+    rhs: SimdArg<T>,
+) -> Bitmap
+where
+    Lanes<T>: SimdPartialOrd<Mask = LanesMask<T>>,
+{
+    compare_lanes::<T>(op, len, lhs, rhs)
+}
+
+#[inline(always)]
+fn compare_lanes<T: SimdNumber>(
+    op: &SelectOp,
+    len: usize,
+    lhs: SimdArg<T>,
+    rhs: SimdArg<T>,
+) -> Bitmap
+where
+    Lanes<T>: SimdPartialOrd<Mask = LanesMask<T>>,
+{
+    match op {
+        SelectOp::Equal => compare_lanes_by::<T, _>(len, lhs, rhs, |l, r| l.simd_eq(r)),
+        SelectOp::NotEqual => compare_lanes_by::<T, _>(len, lhs, rhs, |l, r| l.simd_ne(r)),
+        SelectOp::Gt => compare_lanes_by::<T, _>(len, lhs, rhs, |l, r| l.simd_gt(r)),
+        SelectOp::Lt => compare_lanes_by::<T, _>(len, lhs, rhs, |l, r| l.simd_lt(r)),
+        SelectOp::Gte => compare_lanes_by::<T, _>(len, lhs, rhs, |l, r| l.simd_ge(r)),
+        SelectOp::Lte => compare_lanes_by::<T, _>(len, lhs, rhs, |l, r| l.simd_le(r)),
+    }
+}
+
+#[inline(always)]
+fn compare_lanes_by<T: SimdNumber, F>(
+    len: usize,
+    lhs: SimdArg<T>,
+    rhs: SimdArg<T>,
+    cmp: F,
+) -> Bitmap
+where
+    F: Fn(Lanes<T>, Lanes<T>) -> LanesMask<T>,
+{
+    let mut bytes = Vec::with_capacity(len.div_ceil(LANES) * LANES / 8);
+    for offset in (0..len).step_by(LANES) {
+        let bits = cmp(lhs.load::<T>(offset), rhs.load::<T>(offset)).to_bitmask() as u16;
+        bytes.extend_from_slice(&bits.to_le_bytes());
+    }
+    Bitmap::from_u8_vec(bytes, len)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn arithmetic_avx2<L: SimdNumber, R: SimdNumber, T: SimdNumber>(
+    op: ArithmeticOp,
+    len: usize,
+    lhs: SimdArg<L>,
+    rhs: SimdArg<R>,
+) -> Buffer<T>
+where
+    Lanes<T>: Add<Output = Lanes<T>> + Sub<Output = Lanes<T>> + Mul<Output = Lanes<T>>,
+    L::Lane: AsPrimitive<T::Lane>,
+    R::Lane: AsPrimitive<T::Lane>,
+{
+    arithmetic_lanes::<L, R, T>(op, len, lhs, rhs)
+}
+
+#[inline(always)]
+fn arithmetic_lanes<L: SimdNumber, R: SimdNumber, T: SimdNumber>(
+    op: ArithmeticOp,
+    len: usize,
+    lhs: SimdArg<L>,
+    rhs: SimdArg<R>,
+) -> Buffer<T>
+where
+    Lanes<T>: Add<Output = Lanes<T>> + Sub<Output = Lanes<T>> + Mul<Output = Lanes<T>>,
+    L::Lane: AsPrimitive<T::Lane>,
+    R::Lane: AsPrimitive<T::Lane>,
+{
+    match op {
+        ArithmeticOp::Plus => arithmetic_lanes_by::<L, R, T, _>(len, lhs, rhs, |l, r| l + r),
+        ArithmeticOp::Minus => arithmetic_lanes_by::<L, R, T, _>(len, lhs, rhs, |l, r| l - r),
+        ArithmeticOp::Multiply => arithmetic_lanes_by::<L, R, T, _>(len, lhs, rhs, |l, r| l * r),
+    }
+}
+
+#[inline(always)]
+fn arithmetic_lanes_by<L: SimdNumber, R: SimdNumber, T: SimdNumber, F>(
+    len: usize,
+    lhs: SimdArg<L>,
+    rhs: SimdArg<R>,
+    func: F,
+) -> Buffer<T>
+where
+    F: Fn(Lanes<T>, Lanes<T>) -> Lanes<T>,
+    L::Lane: AsPrimitive<T::Lane>,
+    R::Lane: AsPrimitive<T::Lane>,
+{
+    let mut values = Vec::with_capacity(len.div_ceil(LANES) * LANES);
+    for offset in (0..len).step_by(LANES) {
+        let lanes = func(lhs.load::<T>(offset), rhs.load::<T>(offset));
+        values.extend_from_slice(lanes.as_array());
+    }
+    values.truncate(len);
+    from_lanes::<T>(values)
+}
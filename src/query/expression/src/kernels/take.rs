@@ -118,6 +118,12 @@ impl Column {
                 indices,
                 string_items_buf.as_mut(),
             )),
+            Column::StringDictionary(column) => {
+                let keys = Self::take_primitive_types(column.keys(), indices);
+                Column::StringDictionary(column.with_keys(keys.into()))
+            }
+            Column::Constant(column) => Column::Constant(Box::new(column.with_len(indices.len()))),
+            Column::RunLength(column) => Column::RunLength(Box::new(column.take(indices))),
             Column::Timestamp(column) => {
                 let builder = Self::take_primitive_types(column, indices);
                 let ts = <NumberType<i64>>::upcast_column(<NumberType<i64>>::column_from_vec(
@@ -142,6 +148,9 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                Column::Interval(Self::take_primitive_types(column, indices).into())
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(indices.len() + 1);
                 offsets.push(0);
@@ -275,18 +284,13 @@ impl Column {
     pub fn take_string_types<I>(
         col: &StringColumn,
         indices: &[I],
-        string_items_buf: Option<&mut Vec<(u64, usize)>>,
+        _string_items_buf: Option<&mut Vec<(u64, usize)>>,
     ) -> StringColumn
     where
         I: databend_common_arrow::arrow::types::Index,
     {
-        unsafe {
-            StringColumn::from_binary_unchecked(Self::take_binary_types(
-                &col.clone().into(),
-                indices,
-                string_items_buf,
-            ))
-        }
+        // Only the views are copied, the strings are shared with the original column.
+        col.with_views(Self::take_primitive_types(col.views(), indices).into())
     }
 
     pub fn take_boolean_types<I>(col: &Bitmap, indices: &[I]) -> Bitmap
@@ -33,6 +33,7 @@ use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnVec;
 use crate::types::number::NumberColumn;
+use crate::types::string::rebase_view;
 use crate::types::string::StringColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
@@ -41,6 +42,7 @@ use crate::types::BinaryType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberColumnVec;
 use crate::types::NumberType;
@@ -234,6 +236,14 @@ impl Column {
         indices: &[BlockRowIndex],
         result_size: usize,
     ) -> Column {
+        if columns
+            .iter()
+            .any(|col| matches!(col, Column::Constant(_) | Column::RunLength(_)))
+        {
+            let columns = columns.iter().map(|col| col.expand()).collect::<Vec<_>>();
+            return Self::take_column_indices(&columns, datatype, indices, result_size);
+        }
+
         match &columns[0] {
             Column::Null { .. } => Column::Null { len: result_size },
             Column::EmptyArray { .. } => Column::EmptyArray { len: result_size },
@@ -272,7 +282,7 @@ impl Column {
                 let builder = BinaryType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<BinaryType>(columns, builder, indices)
             }
-            Column::String(_) => {
+            Column::String(_) | Column::StringDictionary(_) => {
                 let builder = StringType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<StringType>(columns, builder, indices)
             }
@@ -284,6 +294,10 @@ impl Column {
                 let builder = DateType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<DateType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(result_size + 1);
                 offsets.push(0);
@@ -381,10 +395,19 @@ impl Column {
                 let builder = GeometryType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<GeometryType>(columns, builder, indices)
             }
+            Column::Constant(_) | Column::RunLength(_) => unreachable!(),
         }
     }
 
     pub fn take_downcast_column_vec(columns: &[Column], datatype: DataType) -> ColumnVec {
+        if columns
+            .iter()
+            .any(|col| matches!(col, Column::Constant(_) | Column::RunLength(_)))
+        {
+            let columns = columns.iter().map(|col| col.expand()).collect::<Vec<_>>();
+            return Self::take_downcast_column_vec(&columns, datatype);
+        }
+
         match &columns[0] {
             Column::Null { .. } => ColumnVec::Null,
             Column::EmptyArray { .. } => ColumnVec::EmptyArray,
@@ -487,7 +510,7 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Binary(columns)
             }
-            Column::String(_) => {
+            Column::String(_) | Column::StringDictionary(_) => {
                 let columns = columns
                     .iter()
                     .map(|col| StringType::try_downcast_column(col).unwrap())
@@ -508,6 +531,13 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Date(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
             Column::Array(_) => {
                 let columns = columns
                     .iter()
@@ -596,6 +626,7 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Geometry(columns)
             }
+            Column::Constant(_) | Column::RunLength(_) => unreachable!(),
         }
     }
 
@@ -658,6 +689,9 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            ColumnVec::Interval(columns) => {
+                Column::Interval(Self::take_block_vec_primitive_types(columns, indices).into())
+            }
             ColumnVec::Array(columns) => {
                 let data_type = data_type.as_array().unwrap();
                 let mut offsets = Vec::with_capacity(result_size + 1);
@@ -803,19 +837,21 @@ impl Column {
     pub fn take_block_vec_string_types(
         cols: &[StringColumn],
         indices: &[RowPtr],
-        binary_items_buf: Option<&mut Vec<(u64, usize)>>,
+        _binary_items_buf: Option<&mut Vec<(u64, usize)>>,
     ) -> StringColumn {
-        let binary_cols = cols
+        let (buffers, bases) = StringColumn::merge_buffers(cols.iter());
+        let views = indices
             .iter()
-            .map(|col| col.clone().into())
-            .collect::<Vec<BinaryColumn>>();
-        unsafe {
-            StringColumn::from_binary_unchecked(Self::take_block_vec_binary_types(
-                &binary_cols,
-                indices,
-                binary_items_buf,
-            ))
-        }
+            .map(|row_ptr| unsafe {
+                let chunk_index = row_ptr.chunk_index as usize;
+                let view = *cols
+                    .get_unchecked(chunk_index)
+                    .views()
+                    .get_unchecked(row_ptr.row_index as usize);
+                rebase_view(view, *bases.get_unchecked(chunk_index))
+            })
+            .collect::<Vec<_>>();
+        unsafe { StringColumn::from_views_unchecked(views.into(), buffers) }
     }
 
     pub fn take_block_vec_boolean_types(col: &[Bitmap], indices: &[RowPtr]) -> Bitmap {
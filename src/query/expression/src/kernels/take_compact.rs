@@ -112,6 +112,14 @@ impl Column {
             Column::String(column) => StringType::upcast_column(Self::take_compact_string_types(
                 column, indices, num_rows,
             )),
+            Column::StringDictionary(column) => {
+                let keys = Self::take_compacted_primitive_types(column.keys(), indices, num_rows);
+                Column::StringDictionary(column.with_keys(keys.into()))
+            }
+            Column::Constant(column) => Column::Constant(Box::new(column.with_len(num_rows))),
+            Column::RunLength(column) => {
+                Column::RunLength(Box::new(column.take_compacted_indices(indices)))
+            }
             Column::Timestamp(column) => {
                 let builder = Self::take_compacted_primitive_types(column, indices, num_rows);
                 let ts = <NumberType<i64>>::upcast_column(<NumberType<i64>>::column_from_vec(
@@ -136,6 +144,9 @@ impl Column {
                 .unwrap();
                 Column::Date(d)
             }
+            Column::Interval(column) => Column::Interval(
+                Self::take_compacted_primitive_types(column, indices, num_rows).into(),
+            ),
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -312,13 +323,8 @@ impl Column {
         indices: &[(u32, u32)],
         num_rows: usize,
     ) -> StringColumn {
-        unsafe {
-            StringColumn::from_binary_unchecked(Self::take_compact_binary_types(
-                &col.clone().into(),
-                indices,
-                num_rows,
-            ))
-        }
+        let views = Self::take_compacted_primitive_types(col.views(), indices, num_rows);
+        col.with_views(views.into())
     }
 
     fn take_compacted_arg_types<T: ArgType>(
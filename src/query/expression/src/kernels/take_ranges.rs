@@ -103,6 +103,12 @@ impl Column {
                 let column = Self::take_ranges_string_types(column, ranges, num_rows);
                 Column::String(column)
             }
+            Column::StringDictionary(column) => {
+                let keys = Self::take_ranges_primitive_types(column.keys(), ranges, num_rows);
+                Column::StringDictionary(column.with_keys(keys))
+            }
+            Column::Constant(column) => Column::Constant(Box::new(column.with_len(num_rows))),
+            Column::RunLength(column) => Column::RunLength(Box::new(column.take_ranges(ranges))),
             Column::Timestamp(column) => {
                 let ts = Self::take_ranges_primitive_types(column, ranges, num_rows);
                 Column::Timestamp(ts)
@@ -111,6 +117,10 @@ impl Column {
                 let d = Self::take_ranges_primitive_types(column, ranges, num_rows);
                 Column::Date(d)
             }
+            Column::Interval(column) => {
+                let i = Self::take_ranges_primitive_types(column, ranges, num_rows);
+                Column::Interval(i)
+            }
             Column::Array(column) => {
                 let mut offsets = Vec::with_capacity(num_rows + 1);
                 offsets.push(0);
@@ -246,13 +256,11 @@ impl Column {
         ranges: &[Range<u32>],
         num_rows: usize,
     ) -> StringColumn {
-        unsafe {
-            StringColumn::from_binary_unchecked(Self::take_ranges_binary_types(
-                &values.clone().into(),
-                ranges,
-                num_rows,
-            ))
-        }
+        values.with_views(Self::take_ranges_primitive_types(
+            values.views(),
+            ranges,
+            num_rows,
+        ))
     }
 
     fn take_ranges_boolean_types(
@@ -40,6 +40,7 @@
 #![feature(lazy_cell)]
 #![feature(try_blocks)]
 #![feature(let_chains)]
+#![feature(portable_simd)]
 
 #[allow(dead_code)]
 mod block;
@@ -50,6 +51,8 @@ mod evaluator;
 mod expression;
 pub mod filter;
 mod function;
+#[cfg(feature = "jit")]
+mod jit;
 mod kernels;
 mod property;
 mod register;
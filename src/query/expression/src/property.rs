@@ -192,9 +192,11 @@ impl Domain {
             DataType::Array(ty) => Domain::Array(Some(Box::new(Domain::full(ty)))),
             DataType::EmptyMap => Domain::Map(None),
             DataType::Map(ty) => Domain::Map(Some(Box::new(Domain::full(ty)))),
-            DataType::Binary | DataType::Bitmap | DataType::Variant | DataType::Geometry => {
-                Domain::Undefined
-            }
+            DataType::Binary
+            | DataType::Interval
+            | DataType::Bitmap
+            | DataType::Variant
+            | DataType::Geometry => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
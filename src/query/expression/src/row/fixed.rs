@@ -17,6 +17,7 @@ use ethnum::i256;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
+use crate::types::interval::Interval;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -98,6 +99,20 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthEncoding for Interval {
+    type Encoded = [u8; 32];
+
+    /// Encodes the length first and then the fields, in the same order as `Interval::cmp`.
+    fn encode(self) -> [u8; 32] {
+        let mut b = [0; 32];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..20].copy_from_slice(&self.months.encode());
+        b[20..24].copy_from_slice(&self.days.encode());
+        b[24..].copy_from_slice(&self.micros.encode());
+        b
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut BinaryColumnBuilder,
     iter: I,
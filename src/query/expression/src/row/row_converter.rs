@@ -24,6 +24,7 @@ use super::variable;
 use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::DecimalColumn;
+use crate::types::interval::Interval;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
@@ -79,7 +80,11 @@ impl RowConverter {
                 .all(|(col, f)| col.len() == num_rows && col.data_type() == f.data_type)
         );
 
-        let mut builder = self.new_empty_rows(columns, num_rows);
+        let columns = columns
+            .iter()
+            .map(|column| column.expand().decode_dictionary())
+            .collect::<Vec<_>>();
+        let mut builder = self.new_empty_rows(&columns, num_rows);
         for (column, field) in columns.iter().zip(self.fields.iter()) {
             encode_column(&mut builder, column, field.asc, field.nulls_first);
         }
@@ -123,6 +128,9 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += Interval::ENCODED_LEN as u64),
                 DataType::Binary => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -254,6 +262,7 @@ fn encode_column(out: &mut BinaryColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::String(col) => variable::encode(
             out,
@@ -199,6 +199,10 @@ pub enum TableDataType {
     },
     Variant,
     Geometry,
+    /// A fixed-dimension vector of Float32, stored as `Array(Float32)`.
+    /// The dimension is checked when the data is written to the table.
+    Vector(u64),
+    Interval,
 }
 
 impl DataSchema {
@@ -876,6 +880,18 @@ impl TableSchema {
                         next_column_id,
                     );
                 }
+                TableDataType::Vector(_) => {
+                    collect_in_field(
+                        &TableField::new_from_column_id(
+                            &format!("{}:0", field.name()),
+                            TableDataType::Number(NumberDataType::Float32),
+                            *next_column_id,
+                        ),
+                        fields,
+                        is_nullable,
+                        next_column_id,
+                    );
+                }
                 TableDataType::Map(ty) => {
                     collect_in_field(
                         &TableField::new_from_column_id(
@@ -1060,6 +1076,13 @@ impl TableField {
             TableDataType::Map(a) => {
                 Self::build_column_ids_from_data_type(a.as_ref(), column_ids, next_column_id);
             }
+            TableDataType::Vector(_) => {
+                Self::build_column_ids_from_data_type(
+                    &TableDataType::Number(NumberDataType::Float32),
+                    column_ids,
+                    next_column_id,
+                );
+            }
             _ => {
                 *next_column_id += 1;
             }
@@ -1156,7 +1179,10 @@ impl TableField {
     pub fn is_nested(&self) -> bool {
         matches!(
             self.data_type,
-            TableDataType::Tuple { .. } | TableDataType::Array(_) | TableDataType::Map(_)
+            TableDataType::Tuple { .. }
+                | TableDataType::Array(_)
+                | TableDataType::Map(_)
+                | TableDataType::Vector(_)
         )
     }
 }
@@ -1174,6 +1200,7 @@ impl From<&TableDataType> for DataType {
             TableDataType::Decimal(ty) => DataType::Decimal(*ty),
             TableDataType::Timestamp => DataType::Timestamp,
             TableDataType::Date => DataType::Date,
+            TableDataType::Interval => DataType::Interval,
             TableDataType::Nullable(ty) => DataType::Nullable(Box::new((&**ty).into())),
             TableDataType::Array(ty) => DataType::Array(Box::new((&**ty).into())),
             TableDataType::Map(ty) => DataType::Map(Box::new((&**ty).into())),
@@ -1183,6 +1210,9 @@ impl From<&TableDataType> for DataType {
             }
             TableDataType::Variant => DataType::Variant,
             TableDataType::Geometry => DataType::Geometry,
+            TableDataType::Vector(_) => {
+                DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)))
+            }
         }
     }
 }
@@ -1410,6 +1440,7 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Timestamp => Ok(TableDataType::Timestamp),
         DataType::Decimal(x) => Ok(TableDataType::Decimal(*x)),
         DataType::Date => Ok(TableDataType::Date),
+        DataType::Interval => Ok(TableDataType::Interval),
         DataType::Nullable(inner_type) => Ok(TableDataType::Nullable(Box::new(infer_schema_type(
             inner_type,
         )?))),
@@ -17,17 +17,20 @@ pub mod array;
 pub mod binary;
 pub mod bitmap;
 pub mod boolean;
+pub mod constant;
 pub mod date;
 pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
 pub mod generic;
 pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
 pub mod number;
 pub mod number_class;
+pub mod run_length;
 pub mod string;
 pub mod timestamp;
 pub mod variant;
@@ -52,6 +55,7 @@ pub use self::decimal::DecimalSize;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
 pub use self::generic::GenericType;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableType;
@@ -87,6 +91,7 @@ pub enum DataType {
     Tuple(Vec<DataType>),
     Variant,
     Geometry,
+    Interval,
 
     // Used internally for generic types
     Generic(usize),
@@ -138,6 +143,7 @@ impl DataType {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry => false,
@@ -161,6 +167,7 @@ impl DataType {
             | DataType::Decimal(_)
             | DataType::Timestamp
             | DataType::Date
+            | DataType::Interval
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
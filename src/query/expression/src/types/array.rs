@@ -65,6 +65,9 @@ impl<T: ValueType> ValueType for ArrayType<T> {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         ArrayColumn::try_downcast(col.as_array()?)
     }
 
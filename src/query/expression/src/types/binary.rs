@@ -63,6 +63,9 @@ impl ValueType for BinaryType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         col.as_binary().cloned()
     }
 
@@ -57,6 +57,9 @@ impl ValueType for BitmapType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         col.as_bitmap().cloned()
     }
 
@@ -61,6 +61,9 @@ impl ValueType for BooleanType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         match col {
             Column::Boolean(column) => Some(column.clone()),
             _ => None,
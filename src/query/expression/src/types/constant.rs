@@ -0,0 +1,105 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::types::DataType;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// ConstantColumn repeats one value for all the rows, the value is not expanded
+/// to a full column until it's accessed by the typed kernels.
+#[derive(Clone)]
+pub struct ConstantColumn {
+    /// The column with exactly one row.
+    value: Column,
+    len: usize,
+}
+
+impl ConstantColumn {
+    pub fn try_new(value: Column, len: usize) -> Result<Self> {
+        let col = ConstantColumn { value, len };
+        col.check_valid()?;
+        Ok(col)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn value(&self) -> &Column {
+        &self.value
+    }
+
+    pub fn scalar(&self) -> Scalar {
+        self.value.index(0).unwrap().to_owned()
+    }
+
+    pub fn data_type(&self) -> DataType {
+        self.value.data_type()
+    }
+
+    pub fn with_len(&self, len: usize) -> Self {
+        ConstantColumn {
+            value: self.value.clone(),
+            len,
+        }
+    }
+
+    pub fn index(&self, index: usize) -> Option<ScalarRef> {
+        if index < self.len {
+            self.value.index(0)
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> ScalarRef {
+        debug_assert!(index < self.len);
+        self.value.index_unchecked(0)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        assert!(range.end <= self.len);
+        self.with_len(range.end - range.start)
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.value.memory_size()
+    }
+
+    /// Expands the value to a full column.
+    pub fn to_column(&self) -> Column {
+        let scalar = self.value.index(0).unwrap();
+        ColumnBuilder::repeat(&scalar, self.len, &self.data_type()).build()
+    }
+
+    pub fn check_valid(&self) -> Result<()> {
+        if self.value.len() != 1 {
+            return Err(ErrorCode::Internal(format!(
+                "ConstantColumn value must have exactly one row, but got {}",
+                self.value.len()
+            )));
+        }
+        self.value.check_valid()
+    }
+}
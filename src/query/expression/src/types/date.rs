@@ -85,6 +85,9 @@ impl ValueType for DateType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         match col {
             Column::Date(column) => Some(column.clone()),
             _ => None,
@@ -74,6 +74,9 @@ impl<Num: Decimal> ValueType for DecimalType<Num> {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         let down_col = Num::try_downcast_column(col);
         if let Some(col) = down_col {
             Some(col.0)
@@ -55,6 +55,9 @@ impl ValueType for EmptyArrayType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         match col {
             Column::EmptyArray { len } => Some(*len),
             _ => None,
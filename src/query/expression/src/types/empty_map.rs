@@ -55,6 +55,9 @@ impl ValueType for EmptyMapType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         match col {
             Column::EmptyMap { len } => Some(*len),
             _ => None,
@@ -62,6 +62,9 @@ impl ValueType for GeometryType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         col.as_geometry().cloned()
     }
 
@@ -0,0 +1,574 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_IN_A_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_IN_A_MINUTE: i64 = 60 * MICROS_IN_A_SEC;
+pub const MICROS_IN_AN_HOUR: i64 = 60 * MICROS_IN_A_MINUTE;
+pub const MICROS_IN_A_DAY: i64 = 24 * MICROS_IN_AN_HOUR;
+/// The number of days in a month when an interval is compared or divided, as in PostgreSQL.
+pub const DAYS_IN_A_MONTH: i64 = 30;
+
+/// A span of time. The months and days are kept apart from the microseconds,
+/// because their length depends on the date and the timezone the interval is applied to.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+#[repr(C)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub micros: i64,
+}
+
+impl Interval {
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        Interval {
+            months,
+            days,
+            micros,
+        }
+    }
+
+    /// The length of the interval in microseconds, a month counts as 30 days.
+    pub fn total_micros(&self) -> i128 {
+        (self.months as i128 * DAYS_IN_A_MONTH as i128 + self.days as i128)
+            * MICROS_IN_A_DAY as i128
+            + self.micros as i128
+    }
+
+    /// Packs the interval into an `i128`, which is how it is kept in arrow and parquet.
+    pub fn to_i128(&self) -> i128 {
+        ((self.months as i128) << 96)
+            | (((self.days as u32) as i128) << 64)
+            | ((self.micros as u64) as i128)
+    }
+
+    pub fn from_i128(value: i128) -> Self {
+        Interval {
+            months: (value >> 96) as i32,
+            days: (value >> 64) as i32,
+            micros: value as i64,
+        }
+    }
+
+    pub fn checked_add(&self, other: &Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            micros: self.micros.checked_add(other.micros)?,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Interval) -> Option<Interval> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            micros: self.micros.checked_neg()?,
+        })
+    }
+
+    /// Multiplies the interval by a factor, the fractional months and days
+    /// are cascaded down to the smaller units, e.g. `1 month * 1.5 = 1 month 15 days`.
+    pub fn checked_mul(&self, factor: f64) -> Option<Interval> {
+        let months = self.months as f64 * factor;
+        let days = self.days as f64 * factor + months.fract() * DAYS_IN_A_MONTH as f64;
+        let micros = self.micros as f64 * factor + days.fract() * MICROS_IN_A_DAY as f64;
+        Interval::from_f64(months.trunc(), days.trunc(), micros.round())
+    }
+
+    pub fn checked_div(&self, divisor: f64) -> Option<Interval> {
+        if divisor == 0.0 {
+            return None;
+        }
+        self.checked_mul(1.0 / divisor)
+    }
+
+    fn from_f64(months: f64, days: f64, micros: f64) -> Option<Interval> {
+        let in_range = |v: f64, min: f64, max: f64| v.is_finite() && v >= min && v <= max;
+        if !in_range(months, i32::MIN as f64, i32::MAX as f64)
+            || !in_range(days, i32::MIN as f64, i32::MAX as f64)
+            || !in_range(micros, i64::MIN as f64, i64::MAX as f64)
+        {
+            return None;
+        }
+        Some(Interval::new(months as i32, days as i32, micros as i64))
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    /// Intervals are ordered by their length, the intervals with the same length,
+    /// like `1 month` and `30 days`, are ordered by their fields to keep the order total.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| {
+                (self.months, self.days, self.micros).cmp(&(other.months, other.days, other.micros))
+            })
+    }
+}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_i128().hash(state);
+    }
+}
+
+impl Display for Interval {
+    /// Formats the interval like `1 year 2 mons 3 days 04:05:06.000007`, as in PostgreSQL.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn unit(value: i64, singular: &str, plural: &str) -> String {
+            if value == 1 {
+                format!("{value} {singular}")
+            } else {
+                format!("{value} {plural}")
+            }
+        }
+
+        let mut parts = vec![];
+        let (years, months) = (self.months / 12, self.months % 12);
+        if years != 0 {
+            parts.push(unit(years as i64, "year", "years"));
+        }
+        if months != 0 {
+            parts.push(unit(months as i64, "mon", "mons"));
+        }
+        if self.days != 0 {
+            parts.push(unit(self.days as i64, "day", "days"));
+        }
+        if self.micros != 0 || parts.is_empty() {
+            let sign = if self.micros < 0 { "-" } else { "" };
+            let micros = self.micros.unsigned_abs();
+            let secs = micros / MICROS_IN_A_SEC as u64;
+            let mut time = format!(
+                "{sign}{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            let fraction = micros % MICROS_IN_A_SEC as u64;
+            if fraction != 0 {
+                time.push_str(&format!(".{fraction:06}"));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Parses an interval in the PostgreSQL format, like `1 year 2 months -3 days 04:05:06.7 ago`,
+/// or in the ISO 8601 format, like `P1Y2M3DT4H5M6.7S`.
+pub fn string_to_interval(interval_str: impl AsRef<[u8]>) -> Result<Interval, String> {
+    let text = std::str::from_utf8(interval_str.as_ref())
+        .map_err(|_| "invalid utf8 interval".to_string())?
+        .trim();
+    let result = if text.len() > 1 && (text.starts_with('P') || text.starts_with('p')) {
+        parse_iso_interval(&text[1..])
+    } else {
+        parse_postgres_interval(text.strip_prefix('@').unwrap_or(text))
+    };
+    result.ok_or_else(|| format!("invalid interval: '{}'", text))
+}
+
+#[derive(Default)]
+struct IntervalAccumulator {
+    months: f64,
+    days: f64,
+    micros: f64,
+}
+
+impl IntervalAccumulator {
+    fn add(&mut self, value: f64, unit: &str) -> Option<()> {
+        match unit.to_ascii_lowercase().as_str() {
+            "millennium" | "millennia" | "millenniums" => self.add_months(value * 12000.0),
+            "century" | "centuries" => self.add_months(value * 1200.0),
+            "decade" | "decades" => self.add_months(value * 120.0),
+            "y" | "yr" | "yrs" | "year" | "years" => self.add_months(value * 12.0),
+            "quarter" | "quarters" => self.add_months(value * 3.0),
+            "mon" | "mons" | "month" | "months" => self.add_months(value),
+            "w" | "week" | "weeks" => self.add_days(value * 7.0),
+            "d" | "day" | "days" => self.add_days(value),
+            "h" | "hr" | "hrs" | "hour" | "hours" => {
+                self.micros += value * MICROS_IN_AN_HOUR as f64
+            }
+            "m" | "min" | "mins" | "minute" | "minutes" => {
+                self.micros += value * MICROS_IN_A_MINUTE as f64
+            }
+            "s" | "sec" | "secs" | "second" | "seconds" => {
+                self.micros += value * MICROS_IN_A_SEC as f64
+            }
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => {
+                self.micros += value * 1000.0
+            }
+            "us" | "usec" | "usecs" | "microsecond" | "microseconds" => self.micros += value,
+            _ => return None,
+        }
+        Some(())
+    }
+
+    fn add_months(&mut self, months: f64) {
+        self.months += months.trunc();
+        self.add_days(months.fract() * DAYS_IN_A_MONTH as f64);
+    }
+
+    fn add_days(&mut self, days: f64) {
+        self.days += days.trunc();
+        self.micros += days.fract() * MICROS_IN_A_DAY as f64;
+    }
+
+    fn finish(self, negate: bool) -> Option<Interval> {
+        let interval = Interval::from_f64(self.months, self.days, self.micros.round())?;
+        if negate {
+            interval.checked_neg()
+        } else {
+            Some(interval)
+        }
+    }
+}
+
+fn parse_postgres_interval(text: &str) -> Option<Interval> {
+    let mut acc = IntervalAccumulator::default();
+    let mut tokens = text.split_whitespace().peekable();
+    let mut negate = false;
+    let mut empty = true;
+    while let Some(token) = tokens.next() {
+        if token.eq_ignore_ascii_case("ago") && tokens.peek().is_none() && !empty {
+            negate = true;
+            break;
+        }
+        empty = false;
+        if token.contains(':') {
+            acc.micros += parse_time(token)? as f64;
+            continue;
+        }
+        // The unit may be written next to the number, like `10d`.
+        let split = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let value = token[..split].parse::<f64>().ok()?;
+        let unit = if split < token.len() {
+            &token[split..]
+        } else {
+            match tokens.peek() {
+                Some(unit) if is_unit(unit) => tokens.next().unwrap(),
+                // A number without unit is a number of seconds.
+                _ => "second",
+            }
+        };
+        acc.add(value, unit)?;
+    }
+    if empty {
+        return None;
+    }
+    acc.finish(negate)
+}
+
+fn is_unit(token: &str) -> bool {
+    token.starts_with(|c: char| c.is_ascii_alphabetic()) && !token.eq_ignore_ascii_case("ago")
+}
+
+/// Parses a time like `[-]hh:mm[:ss[.ffffff]]` into microseconds.
+fn parse_time(token: &str) -> Option<i64> {
+    let (negative, token) = match token.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let mut fields = token.split(':');
+    let hours = fields.next()?.parse::<i64>().ok()?;
+    let minutes = fields.next()?.parse::<i64>().ok()?;
+    let seconds = match fields.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if fields.next().is_some() || !(0..60).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return None;
+    }
+    let micros = hours
+        .checked_mul(MICROS_IN_AN_HOUR)?
+        .checked_add(minutes * MICROS_IN_A_MINUTE)?
+        .checked_add((seconds * MICROS_IN_A_SEC as f64).round() as i64)?;
+    Some(if negative { -micros } else { micros })
+}
+
+fn parse_iso_interval(text: &str) -> Option<Interval> {
+    let mut acc = IntervalAccumulator::default();
+    let mut in_time = false;
+    let mut rest = text;
+    while !rest.is_empty() {
+        if let Some(time) = rest.strip_prefix(['T', 't']) {
+            if in_time {
+                return None;
+            }
+            in_time = true;
+            rest = time;
+            continue;
+        }
+        let split = rest.find(|c: char| c.is_ascii_alphabetic())?;
+        let value = rest[..split].parse::<f64>().ok()?;
+        let designator = rest[split..].chars().next()?.to_ascii_uppercase();
+        let unit = match (in_time, designator) {
+            (false, 'Y') => "year",
+            (false, 'M') => "month",
+            (false, 'W') => "week",
+            (false, 'D') => "day",
+            (true, 'H') => "hour",
+            (true, 'M') => "minute",
+            (true, 'S') => "second",
+            _ => return None,
+        };
+        acc.add(value, unit)?;
+        rest = &rest[split + 1..];
+    }
+    acc.finish(false)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = Interval;
+    type ScalarRef<'a> = Interval;
+    type Column = Buffer<Interval>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, Interval>>;
+    type ColumnBuilder = Vec<Interval>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Interval) -> Interval {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(lhs: Self::ScalarRef<'_>, rhs: Self::ScalarRef<'_>) -> Option<Ordering> {
+        Some(lhs.cmp(&rhs))
+    }
+
+    #[inline(always)]
+    fn equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left == right
+    }
+
+    #[inline(always)]
+    fn not_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left != right
+    }
+
+    #[inline(always)]
+    fn greater_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left > right
+    }
+
+    #[inline(always)]
+    fn greater_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left >= right
+    }
+
+    #[inline(always)]
+    fn less_than(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left < right
+    }
+
+    #[inline(always)]
+    fn less_than_equal(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> bool {
+        left <= right
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
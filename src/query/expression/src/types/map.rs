@@ -343,6 +343,9 @@ impl<K: ValueType, V: ValueType> ValueType for MapType<K, V> {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         ArrayColumn::try_downcast(col.as_map()?)
     }
 
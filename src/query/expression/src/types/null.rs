@@ -56,6 +56,9 @@ impl ValueType for NullType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         match col {
             Column::Null { len } => Some(*len),
             _ => None,
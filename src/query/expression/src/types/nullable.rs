@@ -67,6 +67,9 @@ impl<T: ValueType> ValueType for NullableType<T> {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         NullableColumn::try_downcast(col.as_nullable()?)
     }
 
@@ -121,6 +121,9 @@ impl<Num: Number> ValueType for NumberType<Num> {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         Num::try_downcast_column(col.as_number()?)
     }
 
@@ -0,0 +1,263 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Range;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_arrow::arrow::types::Index;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::types::constant::ConstantColumn;
+use crate::types::DataType;
+use crate::values::Column;
+use crate::ScalarRef;
+
+/// The max ratio of the runs to the rows to encode a column as run-length.
+const RUN_LENGTH_MAX_RUNS_RATIO: usize = 8;
+
+/// RunLengthColumn stores each run of the equal values once, the filters and takes
+/// work on the runs and keep the column encoded.
+#[derive(Clone)]
+pub struct RunLengthColumn {
+    /// The value of each run.
+    values: Column,
+    /// The exclusive end row of each run, it's strictly increasing.
+    run_ends: Buffer<u32>,
+}
+
+impl RunLengthColumn {
+    pub fn try_new(values: Column, run_ends: Buffer<u32>) -> Result<Self> {
+        let col = RunLengthColumn { values, run_ends };
+        col.check_valid()?;
+        Ok(col)
+    }
+
+    /// Encodes the column as run-length if the number of runs is small enough,
+    /// returns `None` otherwise.
+    pub fn try_encode(col: &Column) -> Option<Self> {
+        let max_runs = col.len() / RUN_LENGTH_MAX_RUNS_RATIO;
+        if max_runs == 0 {
+            return None;
+        }
+
+        let mut run_starts = vec![0u32];
+        let mut run_ends = Vec::new();
+        let mut prev = col.index(0)?;
+        for row in 1..col.len() {
+            let value = unsafe { col.index_unchecked(row) };
+            if value != prev {
+                if run_starts.len() == max_runs {
+                    return None;
+                }
+                run_ends.push(row as u32);
+                run_starts.push(row as u32);
+            }
+            prev = value;
+        }
+        run_ends.push(col.len() as u32);
+
+        Some(RunLengthColumn {
+            values: col.take(&run_starts, &mut None),
+            run_ends: run_ends.into(),
+        })
+    }
+
+    pub fn from_constant(col: &ConstantColumn) -> Self {
+        if col.len() == 0 {
+            return RunLengthColumn {
+                values: col.value().slice(0..0),
+                run_ends: Buffer::new(),
+            };
+        }
+        RunLengthColumn {
+            values: col.value().clone(),
+            run_ends: vec![col.len() as u32].into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.run_ends.last().map(|end| *end as usize).unwrap_or(0)
+    }
+
+    pub fn num_runs(&self) -> usize {
+        self.run_ends.len()
+    }
+
+    pub fn values(&self) -> &Column {
+        &self.values
+    }
+
+    pub fn run_ends(&self) -> &Buffer<u32> {
+        &self.run_ends
+    }
+
+    pub fn data_type(&self) -> DataType {
+        self.values.data_type()
+    }
+
+    /// Returns the index of the run that contains the row.
+    pub fn run_index(&self, row: usize) -> usize {
+        self.run_ends.partition_point(|end| *end as usize <= row)
+    }
+
+    pub fn index(&self, index: usize) -> Option<ScalarRef> {
+        if index < self.len() {
+            self.values.index(self.run_index(index))
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> ScalarRef {
+        debug_assert!(index < self.len());
+        self.values.index_unchecked(self.run_index(index))
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        assert!(range.end <= self.len());
+        if range.is_empty() {
+            return RunLengthColumn {
+                values: self.values.slice(0..0),
+                run_ends: Buffer::new(),
+            };
+        }
+
+        let first = self.run_index(range.start);
+        let last = self.run_index(range.end - 1);
+        let run_ends = self.run_ends[first..=last]
+            .iter()
+            .map(|end| ((*end as usize).min(range.end) - range.start) as u32)
+            .collect::<Vec<_>>();
+        RunLengthColumn {
+            values: self.values.slice(first..last + 1),
+            run_ends: run_ends.into(),
+        }
+    }
+
+    pub fn filter(&self, filter: &Bitmap) -> Self {
+        debug_assert_eq!(filter.len(), self.len());
+        let mut kept_runs = Vec::new();
+        let mut run_ends = Vec::new();
+        let mut num_rows = 0;
+        let mut start = 0;
+        for (run, end) in self.run_ends.iter().enumerate() {
+            let end = *end as usize;
+            let selected = end - start - filter.clone().sliced(start, end - start).unset_bits();
+            if selected > 0 {
+                num_rows += selected;
+                kept_runs.push(run as u32);
+                run_ends.push(num_rows as u32);
+            }
+            start = end;
+        }
+        RunLengthColumn {
+            values: self.values.take(&kept_runs, &mut None),
+            run_ends: run_ends.into(),
+        }
+    }
+
+    pub fn take<I: Index>(&self, indices: &[I]) -> Self {
+        self.take_rows(indices.iter().map(|index| (index.to_usize(), 1)))
+    }
+
+    pub fn take_compacted_indices(&self, indices: &[(u32, u32)]) -> Self {
+        self.take_rows(
+            indices
+                .iter()
+                .map(|(index, times)| (*index as usize, *times as usize)),
+        )
+    }
+
+    pub fn take_ranges(&self, ranges: &[Range<u32>]) -> Self {
+        self.take_rows(
+            ranges
+                .iter()
+                .flat_map(|range| range.clone().map(|index| (index as usize, 1))),
+        )
+    }
+
+    /// Takes the rows repeated by the times, the adjacent rows of the same run are merged.
+    fn take_rows(&self, rows: impl Iterator<Item = (usize, usize)>) -> Self {
+        let mut kept_runs: Vec<u32> = Vec::new();
+        let mut run_ends: Vec<u32> = Vec::new();
+        let mut num_rows = 0;
+        for (row, times) in rows {
+            if times == 0 {
+                continue;
+            }
+            num_rows += times;
+            let run = self.run_index(row) as u32;
+            if kept_runs.last() == Some(&run) {
+                *run_ends.last_mut().unwrap() = num_rows as u32;
+            } else {
+                kept_runs.push(run);
+                run_ends.push(num_rows as u32);
+            }
+        }
+        RunLengthColumn {
+            values: self.values.take(&kept_runs, &mut None),
+            run_ends: run_ends.into(),
+        }
+    }
+
+    pub fn concat(columns: &[RunLengthColumn]) -> Result<Self> {
+        let values = Column::concat_columns(columns.iter().map(|col| col.values.clone()))?;
+        let mut run_ends = Vec::with_capacity(values.len());
+        let mut offset = 0;
+        for col in columns {
+            run_ends.extend(col.run_ends.iter().map(|end| end + offset));
+            offset += col.len() as u32;
+        }
+        Ok(RunLengthColumn {
+            values,
+            run_ends: run_ends.into(),
+        })
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.values.memory_size() + self.run_ends.len() * 4
+    }
+
+    /// Expands the runs to a full column.
+    pub fn to_column(&self) -> Column {
+        let mut indices = Vec::with_capacity(self.len());
+        let mut start = 0;
+        for (run, end) in self.run_ends.iter().enumerate() {
+            indices.extend(std::iter::repeat(run as u32).take(*end as usize - start));
+            start = *end as usize;
+        }
+        self.values.take(&indices, &mut None)
+    }
+
+    pub fn check_valid(&self) -> Result<()> {
+        if self.values.len() != self.run_ends.len() {
+            return Err(ErrorCode::Internal(format!(
+                "RunLengthColumn has {} values but {} runs",
+                self.values.len(),
+                self.run_ends.len()
+            )));
+        }
+        if self.run_ends.first() == Some(&0) || self.run_ends.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ErrorCode::Internal(
+                "RunLengthColumn run ends must be strictly increasing".to_string(),
+            ));
+        }
+        self.values.check_valid()
+    }
+}
@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::iter::once;
 use std::ops::Range;
+use std::sync::Arc;
 
+use databend_common_arrow::arrow::array::View;
 use databend_common_arrow::arrow::buffer::Buffer;
 use databend_common_arrow::arrow::trusted_len::TrustedLen;
 use databend_common_exception::ErrorCode;
@@ -24,7 +28,6 @@ use serde::Serialize;
 
 use super::binary::BinaryColumn;
 use super::binary::BinaryColumnBuilder;
-use super::binary::BinaryIterator;
 use crate::property::Domain;
 use crate::types::ArgType;
 use crate::types::DataType;
@@ -66,7 +69,14 @@ impl ValueType for StringType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
-        col.as_string().cloned()
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
+        match col {
+            Column::String(col) => Some(col.clone()),
+            Column::StringDictionary(col) => Some(col.to_string_column()),
+            _ => None,
+        }
     }
 
     fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
@@ -161,7 +171,7 @@ impl ValueType for StringType {
     }
 
     fn column_memory_size(col: &Self::Column) -> usize {
-        col.data().len() + col.offsets().len() * 8
+        col.memory_size()
     }
 
     #[inline(always)]
@@ -212,10 +222,20 @@ impl ArgType for StringType {
     }
 }
 
-#[derive(Clone, PartialEq)]
+/// The max length of the strings inlined in the views.
+const MAX_INLINE_LEN: usize = 12;
+
+/// StringColumn stores each row as a 16 bytes view, the short strings are inlined in
+/// the view, and the long ones refer to a range of the data buffers with their 4 bytes
+/// prefix. The filters, takes and concats only move the views and share the buffers.
+///
+/// The column built from contiguous data keeps the data and the offsets, until the rows are
+/// moved, so it converts to [`BinaryColumn`] and [`StringColumnBuilder`] without copying.
+#[derive(Clone)]
 pub struct StringColumn {
-    data: Buffer<u8>,
-    offsets: Buffer<u64>,
+    views: Buffer<View>,
+    buffers: Arc<[Buffer<u8>]>,
+    contiguous: Option<BinaryColumn>,
 }
 
 impl StringColumn {
@@ -248,41 +268,85 @@ impl StringColumn {
         #[cfg(debug_assertions)]
         col.check_utf8().unwrap();
 
+        let mut views = Vec::with_capacity(col.len());
+        let mut buffers = Vec::new();
+        // The offset of a view is u32, the data larger than 4GB is split into several buffers.
+        let mut base = 0;
+        for range in col.offsets.windows(2) {
+            let (start, end) = (range[0] as usize, range[1] as usize);
+            if end - base > u32::MAX as usize {
+                buffers.push(col.data.clone().sliced(base, start - base));
+                base = start;
+            }
+            views.push(make_view(
+                &col.data[start..end],
+                buffers.len() as u32,
+                (start - base) as u32,
+            ));
+        }
+        let data_len = col.data.len();
+        buffers.push(col.data.clone().sliced(base, data_len - base));
+
         StringColumn {
-            data: col.data,
-            offsets: col.offsets,
+            views: views.into(),
+            buffers: buffers.into(),
+            contiguous: Some(col),
         }
     }
 
+    /// # Safety
+    /// This function is unsound iff:
+    /// * the views are not inlined and out of the range of the buffers
+    /// * The bytes of the views are not valid utf8
+    pub unsafe fn from_views_unchecked(views: Buffer<View>, buffers: Arc<[Buffer<u8>]>) -> Self {
+        let col = StringColumn {
+            views,
+            buffers,
+            contiguous: None,
+        };
+
+        #[cfg(debug_assertions)]
+        col.check_valid().unwrap();
+
+        col
+    }
+
     pub fn len(&self) -> usize {
-        self.offsets.len() - 1
+        self.views.len()
+    }
+
+    pub fn views(&self) -> &Buffer<View> {
+        &self.views
     }
 
-    pub fn data(&self) -> &Buffer<u8> {
-        &self.data
+    pub fn buffers(&self) -> &Arc<[Buffer<u8>]> {
+        &self.buffers
     }
 
-    pub fn offsets(&self) -> &Buffer<u64> {
-        &self.offsets
+    /// The total length of the strings.
+    pub fn total_bytes_len(&self) -> usize {
+        self.views.iter().map(|view| view.length as usize).sum()
     }
 
     pub fn memory_size(&self) -> usize {
-        let offsets = self.offsets.as_slice();
-        let len = offsets.len();
-        len * 8 + (offsets[len - 1] - offsets[0]) as usize
+        self.views.len() * std::mem::size_of::<View>()
+            + self
+                .buffers
+                .iter()
+                .map(|buffer| buffer.len())
+                .sum::<usize>()
+            + self
+                .contiguous
+                .as_ref()
+                .map_or(0, |col| col.offsets().len() * 8)
     }
 
     pub fn index(&self, index: usize) -> Option<&str> {
-        if index + 1 >= self.offsets.len() {
+        if index >= self.views.len() {
             return None;
         }
 
-        let bytes = &self.data[(self.offsets[index] as usize)..(self.offsets[index + 1] as usize)];
-
-        #[cfg(debug_assertions)]
-        bytes.check_utf8().unwrap();
-
-        unsafe { Some(std::str::from_utf8_unchecked(bytes)) }
+        unsafe { Some(self.index_unchecked(index)) }
     }
 
     /// # Safety
@@ -290,11 +354,7 @@ impl StringColumn {
     /// Calling this method with an out-of-bounds index is *[undefined behavior]*
     #[inline]
     pub unsafe fn index_unchecked(&self, index: usize) -> &str {
-        debug_assert!(index + 1 < self.offsets.len());
-
-        let start = *self.offsets.get_unchecked(index) as usize;
-        let end = *self.offsets.get_unchecked(index + 1) as usize;
-        let bytes = &self.data.get_unchecked(start..end);
+        let bytes = self.index_unchecked_bytes(index);
 
         #[cfg(debug_assertions)]
         bytes.check_utf8().unwrap();
@@ -307,57 +367,132 @@ impl StringColumn {
     /// Calling this method with an out-of-bounds index is *[undefined behavior]*
     #[inline]
     pub unsafe fn index_unchecked_bytes(&self, index: usize) -> &[u8] {
-        debug_assert!(index + 1 < self.offsets.len());
+        debug_assert!(index < self.views.len());
 
-        let start = *self.offsets.get_unchecked(index) as usize;
-        let end = *self.offsets.get_unchecked(index + 1) as usize;
-        self.data.get_unchecked(start..end)
+        view_bytes(self.views.get_unchecked(index), &self.buffers)
     }
 
     pub fn slice(&self, range: Range<usize>) -> Self {
-        let offsets = self
-            .offsets
+        let views = self
+            .views
             .clone()
-            .sliced(range.start, range.end - range.start + 1);
+            .sliced(range.start, range.end - range.start);
         StringColumn {
-            data: self.data.clone(),
-            offsets,
+            views,
+            buffers: self.buffers.clone(),
+            contiguous: self.contiguous.as_ref().map(|col| col.slice(range)),
+        }
+    }
+
+    /// Returns the column with the new views upon the same buffers,
+    /// it's used by the kernels that only move the rows.
+    pub fn with_views(&self, views: Buffer<View>) -> Self {
+        StringColumn {
+            views,
+            buffers: self.buffers.clone(),
+            contiguous: None,
+        }
+    }
+
+    /// Returns true if the two columns share the same data buffers.
+    pub fn has_same_buffers(&self, other: &StringColumn) -> bool {
+        Arc::ptr_eq(&self.buffers, &other.buffers)
+    }
+
+    /// Compares the row `i` of the column with the row `j` of the other column,
+    /// the prefixes of the views are compared first to avoid touching the buffers.
+    pub fn compare(&self, i: usize, other: &StringColumn, j: usize) -> Ordering {
+        let (left, right) = (self.views[i], other.views[j]);
+        if left.prefix != right.prefix {
+            // The prefix is zero padded, the padding sorts before any byte except zero,
+            // which is consistent with a shorter string.
+            let left = u32::from_be_bytes(left.prefix.to_ne_bytes());
+            let right = u32::from_be_bytes(right.prefix.to_ne_bytes());
+            return left.cmp(&right);
+        }
+        unsafe {
+            self.index_unchecked_bytes(i)
+                .cmp(other.index_unchecked_bytes(j))
         }
     }
 
     pub fn iter(&self) -> StringIterator {
         StringIterator {
-            data: &self.data,
-            offsets: self.offsets.windows(2),
+            views: self.views.iter(),
+            buffers: &self.buffers,
         }
     }
 
-    pub fn iter_binary(&self) -> BinaryIterator {
-        BinaryIterator {
-            data: &self.data,
-            offsets: self.offsets.windows(2),
+    pub fn iter_binary(&self) -> StringBinaryIterator {
+        StringBinaryIterator {
+            views: self.views.iter(),
+            buffers: &self.buffers,
         }
     }
 
-    pub fn into_buffer(self) -> (Buffer<u8>, Buffer<u64>) {
-        (self.data, self.offsets)
+    /// Collects the buffers of the columns, the buffers shared by several columns are added
+    /// only once. Returns the buffers and the index of the first buffer of each column.
+    pub fn merge_buffers<'a>(
+        cols: impl Iterator<Item = &'a StringColumn>,
+    ) -> (Arc<[Buffer<u8>]>, Vec<u32>) {
+        let mut buffers: Vec<Buffer<u8>> = Vec::new();
+        let mut merged: Vec<(&'a Arc<[Buffer<u8>]>, u32)> = Vec::new();
+        let mut bases = Vec::new();
+        for col in cols {
+            let base = match merged
+                .iter()
+                .find(|(col_buffers, _)| Arc::ptr_eq(col_buffers, &col.buffers))
+            {
+                Some((_, base)) => *base,
+                None => {
+                    let base = buffers.len() as u32;
+                    buffers.extend(col.buffers.iter().cloned());
+                    merged.push((&col.buffers, base));
+                    base
+                }
+            };
+            bases.push(base);
+        }
+        (buffers.into(), bases)
     }
 
-    pub fn check_valid(&self) -> Result<()> {
-        let offsets = self.offsets.as_slice();
-        let len = offsets.len();
-        if len < 1 {
-            return Err(ErrorCode::Internal(format!(
-                "StringColumn offsets length must be equal or greater than 1, but got {}",
-                len
-            )));
+    /// Compacts the buffers into a single buffer that only holds the strings of the views,
+    /// it's used when the column is a small slice of large buffers.
+    pub fn gc(&self) -> Self {
+        unsafe { Self::from_binary_unchecked(self.copy_to_binary()) }
+    }
+
+    /// Copies the strings of the views into a new contiguous [`BinaryColumn`].
+    fn copy_to_binary(&self) -> BinaryColumn {
+        let mut builder = BinaryColumnBuilder::with_capacity(self.len(), self.total_bytes_len());
+        for value in self.iter_binary() {
+            builder.put_slice(value);
+            builder.commit_row();
         }
+        builder.build()
+    }
 
-        for i in 1..len {
-            if offsets[i] < offsets[i - 1] {
+    pub fn check_valid(&self) -> Result<()> {
+        for (i, view) in self.views.iter().enumerate() {
+            let len = view.length as usize;
+            if len <= MAX_INLINE_LEN {
+                continue;
+            }
+            let buffer = self.buffers.get(view.buffer_idx as usize).ok_or_else(|| {
+                ErrorCode::Internal(format!(
+                    "StringColumn view {} refers to the buffer {}, but there are only {} buffers",
+                    i,
+                    view.buffer_idx,
+                    self.buffers.len()
+                ))
+            })?;
+            if view.offset as usize + len > buffer.len() {
                 return Err(ErrorCode::Internal(format!(
-                    "StringColumn offsets value must be equal or greater than previous value, but got {}",
-                    offsets[i]
+                    "StringColumn view {} is out of the range of the buffer, offset {} length {} buffer length {}",
+                    i,
+                    view.offset,
+                    len,
+                    buffer.len()
                 )));
             }
         }
@@ -365,11 +500,79 @@ impl StringColumn {
     }
 }
 
+impl PartialEq for StringColumn {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .views
+                .iter()
+                .zip(other.views.iter())
+                .all(|(left, right)| {
+                    left.length == right.length
+                        && left.prefix == right.prefix
+                        && unsafe {
+                            view_bytes(left, &self.buffers) == view_bytes(right, &other.buffers)
+                        }
+                })
+    }
+}
+
+/// Builds the view of the bytes, the bytes are inlined if they are short enough,
+/// otherwise the view refers to the offset of the buffer.
+#[inline]
+fn make_view(bytes: &[u8], buffer_idx: u32, offset: u32) -> View {
+    let len = bytes.len();
+    if len <= MAX_INLINE_LEN {
+        let mut raw = [0u8; 16];
+        raw[0..4].copy_from_slice(&(len as u32).to_ne_bytes());
+        raw[4..4 + len].copy_from_slice(bytes);
+        unsafe { std::mem::transmute::<[u8; 16], View>(raw) }
+    } else {
+        View {
+            length: len as u32,
+            prefix: u32::from_ne_bytes(bytes[0..4].try_into().unwrap()),
+            buffer_idx,
+            offset,
+        }
+    }
+}
+
+/// Moves the view to the buffers collected by `StringColumn::merge_buffers`,
+/// `base` is the index of the first buffer of the column the view belongs to.
+#[inline]
+pub fn rebase_view(view: View, base: u32) -> View {
+    if view.length as usize <= MAX_INLINE_LEN {
+        view
+    } else {
+        View {
+            buffer_idx: view.buffer_idx + base,
+            ..view
+        }
+    }
+}
+
+/// # Safety
+///
+/// The view must be inlined or in the range of the buffers.
+#[inline]
+unsafe fn view_bytes<'a>(view: &'a View, buffers: &'a [Buffer<u8>]) -> &'a [u8] {
+    let len = view.length as usize;
+    if len <= MAX_INLINE_LEN {
+        let ptr = (view as *const View as *const u8).add(4);
+        std::slice::from_raw_parts(ptr, len)
+    } else {
+        let offset = view.offset as usize;
+        buffers
+            .get_unchecked(view.buffer_idx as usize)
+            .get_unchecked(offset..offset + len)
+    }
+}
+
 impl From<StringColumn> for BinaryColumn {
     fn from(col: StringColumn) -> BinaryColumn {
-        BinaryColumn {
-            data: col.data,
-            offsets: col.offsets,
+        match col.contiguous {
+            Some(contiguous) => contiguous,
+            None => col.copy_to_binary(),
         }
     }
 }
@@ -379,26 +582,20 @@ impl TryFrom<BinaryColumn> for StringColumn {
 
     fn try_from(col: BinaryColumn) -> Result<StringColumn> {
         col.check_utf8()?;
-        Ok(StringColumn {
-            data: col.data,
-            offsets: col.offsets,
-        })
+        Ok(unsafe { StringColumn::from_binary_unchecked(col) })
     }
 }
 
 pub struct StringIterator<'a> {
-    data: &'a [u8],
-    offsets: std::slice::Windows<'a, u64>,
+    views: std::slice::Iter<'a, View>,
+    buffers: &'a [Buffer<u8>],
 }
 
 impl<'a> Iterator for StringIterator<'a> {
     type Item = &'a str;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let bytes = self
-            .offsets
-            .next()
-            .map(|range| &self.data[(range[0] as usize)..(range[1] as usize)])?;
+        let bytes = unsafe { view_bytes(self.views.next()?, self.buffers) };
 
         #[cfg(debug_assertions)]
         bytes.check_utf8().unwrap();
@@ -407,7 +604,7 @@ impl<'a> Iterator for StringIterator<'a> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.offsets.size_hint()
+        self.views.size_hint()
     }
 }
 
@@ -415,6 +612,188 @@ unsafe impl<'a> TrustedLen for StringIterator<'a> {}
 
 unsafe impl<'a> std::iter::TrustedLen for StringIterator<'a> {}
 
+pub struct StringBinaryIterator<'a> {
+    views: std::slice::Iter<'a, View>,
+    buffers: &'a [Buffer<u8>],
+}
+
+impl<'a> Iterator for StringBinaryIterator<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe { Some(view_bytes(self.views.next()?, self.buffers)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.views.size_hint()
+    }
+}
+
+unsafe impl<'a> TrustedLen for StringBinaryIterator<'a> {}
+
+unsafe impl<'a> std::iter::TrustedLen for StringBinaryIterator<'a> {}
+
+/// The max ratio of the distinct values to the rows to encode a string column as dictionary.
+const DICTIONARY_MAX_CARDINALITY_RATIO: usize = 4;
+
+/// StringDictionaryColumn is a dictionary encoded string column, each row is a key of the
+/// distinct values. It's used for the low cardinality columns to avoid copying and hashing
+/// the same strings repeatedly, the filters and takes only move the keys.
+#[derive(Clone)]
+pub struct StringDictionaryColumn {
+    values: StringColumn,
+    keys: Buffer<u32>,
+}
+
+impl StringDictionaryColumn {
+    pub fn try_new(values: StringColumn, keys: Buffer<u32>) -> Result<Self> {
+        let col = StringDictionaryColumn { values, keys };
+        col.check_valid()?;
+        Ok(col)
+    }
+
+    /// Encodes the string column as dictionary if the number of distinct values is small
+    /// enough, returns `None` otherwise.
+    pub fn try_encode(col: &StringColumn) -> Option<Self> {
+        let max_values = col.len() / DICTIONARY_MAX_CARDINALITY_RATIO;
+        if max_values == 0 {
+            return None;
+        }
+
+        let mut dict: HashMap<&str, u32> = HashMap::with_capacity(max_values);
+        let mut values = StringColumnBuilder::with_capacity(max_values, 0);
+        let mut keys = Vec::with_capacity(col.len());
+        for value in col.iter() {
+            let key = match dict.get(value) {
+                Some(key) => *key,
+                None => {
+                    if dict.len() == max_values {
+                        return None;
+                    }
+                    let key = dict.len() as u32;
+                    dict.insert(value, key);
+                    values.put_str(value);
+                    values.commit_row();
+                    key
+                }
+            };
+            keys.push(key);
+        }
+
+        Some(StringDictionaryColumn {
+            values: values.build(),
+            keys: keys.into(),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// The distinct values of the column, some of them may be not referenced by any key.
+    pub fn values(&self) -> &StringColumn {
+        &self.values
+    }
+
+    pub fn keys(&self) -> &Buffer<u32> {
+        &self.keys
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.values.memory_size() + self.keys.len() * 4
+    }
+
+    pub fn index(&self, index: usize) -> Option<&str> {
+        let key = self.keys.get(index)?;
+        self.values.index(*key as usize)
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    #[inline]
+    pub unsafe fn index_unchecked(&self, index: usize) -> &str {
+        let key = *self.keys.get_unchecked(index);
+        self.values.index_unchecked(key as usize)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        self.with_keys(
+            self.keys
+                .clone()
+                .sliced(range.start, range.end - range.start),
+        )
+    }
+
+    /// Returns the column with the same values and the new keys,
+    /// it's used by the kernels that only move the rows.
+    pub fn with_keys(&self, keys: Buffer<u32>) -> Self {
+        debug_assert!(keys.iter().all(|key| (*key as usize) < self.values.len()));
+        StringDictionaryColumn {
+            values: self.values.clone(),
+            keys,
+        }
+    }
+
+    /// Returns true if the two columns share the same values.
+    pub fn has_same_values(&self, other: &StringDictionaryColumn) -> bool {
+        self.values.views().as_ptr() == other.values.views().as_ptr()
+            && self.values.has_same_buffers(&other.values)
+            && self.values.len() == other.values.len()
+    }
+
+    pub fn iter(&self) -> StringDictionaryIterator {
+        StringDictionaryIterator {
+            values: &self.values,
+            keys: self.keys.iter(),
+        }
+    }
+
+    /// Decodes the dictionary into a plain string column.
+    pub fn to_string_column(&self) -> StringColumn {
+        let mut builder = StringColumnBuilder::with_capacity(self.len(), 0);
+        for value in self.iter() {
+            builder.put_str(value);
+            builder.commit_row();
+        }
+        builder.build()
+    }
+
+    pub fn check_valid(&self) -> Result<()> {
+        self.values.check_valid()?;
+        let num_values = self.values.len();
+        if let Some(key) = self.keys.iter().find(|key| **key as usize >= num_values) {
+            return Err(ErrorCode::Internal(format!(
+                "StringDictionaryColumn key {} is out of the {} values",
+                key, num_values
+            )));
+        }
+        Ok(())
+    }
+}
+
+pub struct StringDictionaryIterator<'a> {
+    values: &'a StringColumn,
+    keys: std::slice::Iter<'a, u32>,
+}
+
+impl<'a> Iterator for StringDictionaryIterator<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.keys.next()?;
+        unsafe { Some(self.values.index_unchecked(*key as usize)) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+unsafe impl<'a> TrustedLen for StringDictionaryIterator<'a> {}
+
+unsafe impl<'a> std::iter::TrustedLen for StringDictionaryIterator<'a> {}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StringColumnBuilder {
     // if the StringColumnBuilder is created with `data_capacity`, need_estimated is false
@@ -435,10 +814,11 @@ impl StringColumnBuilder {
     }
 
     pub fn from_column(col: StringColumn) -> Self {
+        let (data, offsets) = BinaryColumn::from(col).into_buffer();
         StringColumnBuilder {
-            need_estimated: col.data.is_empty(),
-            data: buffer_into_mut(col.data),
-            offsets: col.offsets.to_vec(),
+            need_estimated: data.is_empty(),
+            data: buffer_into_mut(data),
+            offsets: offsets.to_vec(),
         }
     }
 
@@ -537,19 +917,12 @@ impl StringColumnBuilder {
     }
 
     pub fn append_column(&mut self, other: &StringColumn) {
-        // the first offset of other column may not be zero
-        let other_start = *other.offsets.first().unwrap();
-        let other_last = *other.offsets.last().unwrap();
-        let start = self.offsets.last().cloned().unwrap();
-        self.data
-            .extend_from_slice(&other.data[(other_start as usize)..(other_last as usize)]);
-        self.offsets.extend(
-            other
-                .offsets
-                .iter()
-                .skip(1)
-                .map(|offset| start + offset - other_start),
-        );
+        self.data.reserve(other.total_bytes_len());
+        self.offsets.reserve(other.len());
+        for value in other.iter_binary() {
+            self.data.extend_from_slice(value);
+            self.offsets.push(self.data.len() as u64);
+        }
     }
 
     pub fn build(self) -> StringColumn {
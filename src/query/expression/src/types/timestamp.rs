@@ -92,6 +92,9 @@ impl ValueType for TimestampType {
     }
 
     fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        if col.is_compact() {
+            return Self::try_downcast_column(&col.expand());
+        }
         match col {
             Column::Timestamp(column) => Some(column.clone()),
             _ => None,
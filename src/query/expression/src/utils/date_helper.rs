@@ -31,6 +31,7 @@ use databend_common_exception::Result;
 use num_traits::AsPrimitive;
 
 use crate::types::date::check_date;
+use crate::types::interval::Interval;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::MICROS_IN_A_SEC;
 
@@ -381,6 +382,54 @@ impl AddTimesImpl {
     }
 }
 
+pub struct AddIntervalImpl;
+
+impl AddIntervalImpl {
+    /// The months and days of the interval are added to the local date in the timezone,
+    /// so `1 day` keeps the wall clock time across a daylight saving time change,
+    /// then the microseconds are added to the instant.
+    pub fn eval_timestamp(us: i64, interval: Interval, tz: TzLUT) -> Result<i64, String> {
+        let mut us = us;
+        if interval.months != 0 || interval.days != 0 {
+            let ts = us.to_timestamp(tz.tz);
+            let date = add_months_base(ts.year(), ts.month(), ts.day(), interval.months as i64)?;
+            let date = date
+                .checked_add_signed(Duration::days(interval.days as i64))
+                .ok_or_else(|| format!("Overflow on date {} + {} days.", date, interval.days))?;
+            us = local_to_timestamp(NaiveDateTime::new(date, ts.time()), tz.tz);
+        }
+        let us = us
+            .checked_add(interval.micros)
+            .ok_or_else(|| "Overflow on timestamp add interval.".to_string())?;
+        check_timestamp(us)
+    }
+
+    /// The date is taken as the midnight in the timezone.
+    pub fn eval_date(date: i32, interval: Interval, tz: TzLUT) -> Result<i64, String> {
+        let date = NaiveDate::from_ymd_opt(1970, 1, 1)
+            .unwrap()
+            .checked_add_signed(Duration::days(date as i64))
+            .ok_or_else(|| format!("Overflow on date {}.", date))?;
+        let us = local_to_timestamp(
+            date.and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+            tz.tz,
+        );
+        Self::eval_timestamp(us, interval, tz)
+    }
+}
+
+fn local_to_timestamp(local: NaiveDateTime, tz: Tz) -> i64 {
+    match tz.from_local_datetime(&local) {
+        LocalResult::Single(t) | LocalResult::Ambiguous(t, _) => t.timestamp_micros(),
+        // The local time is skipped by a daylight saving time change,
+        // so it is resolved with the offset before the change.
+        LocalResult::None => {
+            let offset = tz.offset_from_utc_datetime(&local).fix().local_minus_utc() as i64;
+            (local - Duration::seconds(offset)).timestamp_micros()
+        }
+    }
+}
+
 #[inline]
 pub fn today_date(tz: TzLUT) -> i32 {
     let now = Utc::now().with_timezone(&tz.tz);
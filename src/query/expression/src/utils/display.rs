@@ -44,6 +44,7 @@ use crate::property::Domain;
 use crate::property::FunctionProperty;
 use crate::types::binary::BinaryColumn;
 use crate::types::boolean::BooleanDomain;
+use crate::types::constant::ConstantColumn;
 use crate::types::date::date_to_string;
 use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
@@ -56,7 +57,9 @@ use crate::types::number::NumberDataType;
 use crate::types::number::NumberDomain;
 use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
+use crate::types::run_length::RunLengthColumn;
 use crate::types::string::StringColumn;
+use crate::types::string::StringDictionaryColumn;
 use crate::types::string::StringDomain;
 use crate::types::timestamp::timestamp_to_string;
 use crate::types::AnyType;
@@ -132,6 +135,7 @@ impl<'a> Debug for ScalarRef<'a> {
             ScalarRef::String(s) => write!(f, "{s:?}"),
             ScalarRef::Timestamp(t) => write!(f, "{t:?}"),
             ScalarRef::Date(d) => write!(f, "{d:?}"),
+            ScalarRef::Interval(i) => write!(f, "{i:?}"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -201,8 +205,12 @@ impl Debug for Column {
             Column::Boolean(col) => f.debug_tuple("Boolean").field(col).finish(),
             Column::Binary(col) => write!(f, "{col:?}"),
             Column::String(col) => write!(f, "{col:?}"),
+            Column::StringDictionary(col) => write!(f, "{col:?}"),
+            Column::Constant(col) => write!(f, "{col:?}"),
+            Column::RunLength(col) => write!(f, "{col:?}"),
             Column::Timestamp(col) => write!(f, "{col:?}"),
             Column::Date(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
             Column::Array(col) => write!(f, "{col:?}"),
             Column::Map(col) => write!(f, "{col:?}"),
             Column::Bitmap(col) => write!(f, "{col:?}"),
@@ -232,6 +240,7 @@ impl<'a> Display for ScalarRef<'a> {
             ScalarRef::String(s) => write!(f, "'{s}'"),
             ScalarRef::Timestamp(t) => write!(f, "'{}'", timestamp_to_string(*t, Tz::UTC)),
             ScalarRef::Date(d) => write!(f, "'{}'", date_to_string(*d as i64, Tz::UTC)),
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
             ScalarRef::Array(col) => write!(f, "[{}]", col.iter().join(", ")),
             ScalarRef::Map(col) => {
                 write!(f, "{{")?;
@@ -437,12 +446,40 @@ impl Debug for BinaryColumn {
 
 impl Debug for StringColumn {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let col = BinaryColumn::from(self.clone());
         f.debug_struct("StringColumn")
             .field(
                 "data",
-                &format_args!("0x{}", &hex::encode(self.data().as_slice())),
+                &format_args!("0x{}", &hex::encode(col.data().as_slice())),
             )
-            .field("offsets", &self.offsets())
+            .field("offsets", &col.offsets())
+            .finish()
+    }
+}
+
+impl Debug for StringDictionaryColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StringDictionaryColumn")
+            .field("values", &self.values())
+            .field("keys", &self.keys())
+            .finish()
+    }
+}
+
+impl Debug for ConstantColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConstantColumn")
+            .field("value", &self.value())
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl Debug for RunLengthColumn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RunLengthColumn")
+            .field("values", &self.values())
+            .field("run_ends", &self.run_ends())
             .finish()
     }
 }
@@ -525,6 +562,7 @@ impl Display for DataType {
             DataType::Decimal(decimal) => write!(f, "{decimal}"),
             DataType::Timestamp => write!(f, "Timestamp"),
             DataType::Date => write!(f, "Date"),
+            DataType::Interval => write!(f, "Interval"),
             DataType::Null => write!(f, "NULL"),
             DataType::Nullable(inner) => write!(f, "{inner} NULL"),
             DataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -567,6 +605,7 @@ impl Display for TableDataType {
             TableDataType::Decimal(decimal) => write!(f, "{decimal}"),
             TableDataType::Timestamp => write!(f, "Timestamp"),
             TableDataType::Date => write!(f, "Date"),
+            TableDataType::Interval => write!(f, "Interval"),
             TableDataType::Null => write!(f, "NULL"),
             TableDataType::Nullable(inner) => write!(f, "{inner} NULL"),
             TableDataType::EmptyArray => write!(f, "Array(Nothing)"),
@@ -600,6 +639,7 @@ impl Display for TableDataType {
             }
             TableDataType::Variant => write!(f, "Variant"),
             TableDataType::Geometry => write!(f, "Geometry"),
+            TableDataType::Vector(dimension) => write!(f, "Vector(Float32, {dimension})"),
         }
     }
 }
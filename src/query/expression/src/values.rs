@@ -51,6 +51,7 @@ use crate::types::binary::BinaryColumn;
 use crate::types::binary::BinaryColumnBuilder;
 use crate::types::bitmap::BitmapType;
 use crate::types::boolean::BooleanDomain;
+use crate::types::constant::ConstantColumn;
 use crate::types::date::DATE_MAX;
 use crate::types::date::DATE_MIN;
 use crate::types::decimal::Decimal;
@@ -62,6 +63,8 @@ use crate::types::decimal::DecimalSize;
 use crate::types::decimal::DecimalType;
 use crate::types::geometry::compare_geometry;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::Interval;
+use crate::types::interval::MICROS_IN_A_DAY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -72,8 +75,10 @@ use crate::types::number::NumberScalar;
 use crate::types::number::SimpleDomain;
 use crate::types::number::F32;
 use crate::types::number::F64;
+use crate::types::run_length::RunLengthColumn;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
+use crate::types::string::StringDictionaryColumn;
 use crate::types::string::StringDomain;
 use crate::types::timestamp::check_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
@@ -125,6 +130,7 @@ pub enum Scalar {
     Tuple(Vec<Scalar>),
     Variant(Vec<u8>),
     Geometry(Vec<u8>),
+    Interval(Interval),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -146,18 +152,27 @@ pub enum ScalarRef<'a> {
     Tuple(Vec<ScalarRef<'a>>),
     Variant(&'a [u8]),
     Geometry(&'a [u8]),
+    Interval(Interval),
 }
 
 #[derive(Clone, EnumAsInner)]
 pub enum Column {
-    Null { len: usize },
-    EmptyArray { len: usize },
-    EmptyMap { len: usize },
+    Null {
+        len: usize,
+    },
+    EmptyArray {
+        len: usize,
+    },
+    EmptyMap {
+        len: usize,
+    },
     Number(NumberColumn),
     Decimal(DecimalColumn),
     Boolean(Bitmap),
     Binary(BinaryColumn),
     String(StringColumn),
+    /// Dictionary encoded string column, its data type is `DataType::String`.
+    StringDictionary(StringDictionaryColumn),
     Timestamp(Buffer<i64>),
     Date(Buffer<i32>),
     Array(Box<ArrayColumn<AnyType>>),
@@ -167,6 +182,11 @@ pub enum Column {
     Tuple(Vec<Column>),
     Variant(BinaryColumn),
     Geometry(BinaryColumn),
+    Interval(Buffer<Interval>),
+    /// One value repeated for all the rows, its data type is the data type of the value.
+    Constant(Box<ConstantColumn>),
+    /// Run-length encoded column, its data type is the data type of the values.
+    RunLength(Box<RunLengthColumn>),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -188,6 +208,7 @@ pub enum ColumnVec {
     Tuple(Vec<ColumnVec>),
     Variant(Vec<BinaryColumn>),
     Geometry(Vec<BinaryColumn>),
+    Interval(Vec<Buffer<Interval>>),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -209,6 +230,7 @@ pub enum ColumnBuilder {
     Tuple(Vec<ColumnBuilder>),
     Variant(BinaryColumnBuilder),
     Geometry(BinaryColumnBuilder),
+    Interval(Vec<Interval>),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -321,6 +343,16 @@ impl Value<AnyType> {
         Some(self.as_ref().try_downcast::<T>()?.to_owned())
     }
 
+    /// Converts the constant column to scalar and expands the run-length column,
+    /// it's used by the evaluator so the functions only see the scalars and full columns.
+    pub fn expand_compact(&self) -> Self {
+        match self {
+            Value::Column(Column::Constant(col)) => Value::Scalar(col.scalar()),
+            Value::Column(col) if col.is_compact() => Value::Column(col.expand()),
+            _ => self.clone(),
+        }
+    }
+
     pub fn wrap_nullable(self, validity: Option<Bitmap>) -> Self {
         match self {
             Value::Column(c) => Value::Column(c.wrap_nullable(validity)),
@@ -358,6 +390,7 @@ impl Scalar {
             Scalar::String(s) => ScalarRef::String(s.as_str()),
             Scalar::Timestamp(t) => ScalarRef::Timestamp(*t),
             Scalar::Date(d) => ScalarRef::Date(*d),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
             Scalar::Array(col) => ScalarRef::Array(col.clone()),
             Scalar::Map(col) => ScalarRef::Map(col.clone()),
             Scalar::Bitmap(b) => ScalarRef::Bitmap(b.as_slice()),
@@ -390,6 +423,7 @@ impl Scalar {
             DataType::Decimal(ty) => Scalar::Decimal(ty.default_scalar()),
             DataType::Timestamp => Scalar::Timestamp(0),
             DataType::Date => Scalar::Date(0),
+            DataType::Interval => Scalar::Interval(Interval::default()),
             DataType::Nullable(_) => Scalar::Null,
             DataType::Array(ty) => {
                 let builder = ColumnBuilder::with_capacity(ty, 0);
@@ -424,6 +458,7 @@ impl Scalar {
             | Scalar::Decimal(_)
             | Scalar::Timestamp(_)
             | Scalar::Date(_)
+            | Scalar::Interval(_)
             | Scalar::Boolean(_)
             | Scalar::Binary(_)
             | Scalar::String(_)
@@ -475,6 +510,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => Scalar::String(s.to_string()),
             ScalarRef::Timestamp(t) => Scalar::Timestamp(*t),
             ScalarRef::Date(d) => Scalar::Date(*d),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
             ScalarRef::Array(col) => Scalar::Array(col.clone()),
             ScalarRef::Map(col) => Scalar::Map(col.clone()),
             ScalarRef::Bitmap(b) => Scalar::Bitmap(b.to_vec()),
@@ -547,7 +583,8 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Binary(_)
             | ScalarRef::Bitmap(_)
             | ScalarRef::Variant(_)
-            | ScalarRef::Geometry(_) => Domain::Undefined,
+            | ScalarRef::Geometry(_)
+            | ScalarRef::Interval(_) => Domain::Undefined,
         }
     }
 
@@ -571,6 +608,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(s) => s.len(),
             ScalarRef::Timestamp(_) => 8,
             ScalarRef::Date(_) => 4,
+            ScalarRef::Interval(_) => 16,
             ScalarRef::Array(col) => col.memory_size(),
             ScalarRef::Map(col) => col.memory_size(),
             ScalarRef::Bitmap(b) => b.len(),
@@ -598,6 +636,7 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::String(_) => DataType::String,
             ScalarRef::Timestamp(_) => DataType::Timestamp,
             ScalarRef::Date(_) => DataType::Date,
+            ScalarRef::Interval(_) => DataType::Interval,
             ScalarRef::Array(array) => DataType::Array(Box::new(array.data_type())),
             ScalarRef::Map(col) => DataType::Map(Box::new(col.data_type())),
             ScalarRef::Bitmap(_) => DataType::Bitmap,
@@ -658,6 +697,7 @@ impl<'a> ScalarRef<'a> {
             (ScalarRef::String(_), ScalarRef::String(_)) => Some(DataType::String),
             (ScalarRef::Timestamp(_), ScalarRef::Timestamp(_)) => Some(DataType::Timestamp),
             (ScalarRef::Date(_), ScalarRef::Date(_)) => Some(DataType::Date),
+            (ScalarRef::Interval(_), ScalarRef::Interval(_)) => Some(DataType::Interval),
             (ScalarRef::Array(s1), ScalarRef::Array(s2)) if s1.data_type() == s2.data_type() => {
                 Some(DataType::Array(Box::new(s1.data_type())))
             }
@@ -694,6 +734,7 @@ impl<'a> ScalarRef<'a> {
                 (ScalarRef::String(_), DataType::String) => true,
                 (ScalarRef::Timestamp(_), DataType::Timestamp) => true,
                 (ScalarRef::Date(_), DataType::Date) => true,
+                (ScalarRef::Interval(_), DataType::Interval) => true,
                 (ScalarRef::Bitmap(_), DataType::Bitmap) => true,
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Geometry(_), DataType::Geometry) => true,
@@ -726,6 +767,7 @@ impl PartialOrd for Scalar {
             (Scalar::String(s1), Scalar::String(s2)) => s1.partial_cmp(s2),
             (Scalar::Timestamp(t1), Scalar::Timestamp(t2)) => t1.partial_cmp(t2),
             (Scalar::Date(d1), Scalar::Date(d2)) => d1.partial_cmp(d2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
             (Scalar::Array(a1), Scalar::Array(a2)) => a1.partial_cmp(a2),
             (Scalar::Map(m1), Scalar::Map(m2)) => m1.partial_cmp(m2),
             (Scalar::Bitmap(b1), Scalar::Bitmap(b2)) => b1.partial_cmp(b2),
@@ -764,6 +806,7 @@ impl PartialOrd for ScalarRef<'_> {
             (ScalarRef::String(s1), ScalarRef::String(s2)) => s1.partial_cmp(s2),
             (ScalarRef::Timestamp(t1), ScalarRef::Timestamp(t2)) => t1.partial_cmp(t2),
             (ScalarRef::Date(d1), ScalarRef::Date(d2)) => d1.partial_cmp(d2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
             (ScalarRef::Array(a1), ScalarRef::Array(a2)) => a1.partial_cmp(a2),
             (ScalarRef::Map(m1), ScalarRef::Map(m2)) => m1.partial_cmp(m2),
             (ScalarRef::Bitmap(b1), ScalarRef::Bitmap(b2)) => b1.partial_cmp(b2),
@@ -810,6 +853,7 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::String(v) => v.hash(state),
             ScalarRef::Timestamp(v) => v.hash(state),
             ScalarRef::Date(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
             ScalarRef::Array(v) => {
                 let str = serialize_column(v);
                 str.hash(state);
@@ -836,6 +880,9 @@ impl Hash for Scalar {
 
 impl PartialOrd for Column {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.is_compact() || other.is_compact() {
+            return self.expand().partial_cmp(&other.expand());
+        }
         match (self, other) {
             (Column::Null { len: col1 }, Column::Null { len: col2 }) => col1.partial_cmp(col2),
             (Column::EmptyArray { len: col1 }, Column::EmptyArray { len: col2 }) => {
@@ -849,10 +896,16 @@ impl PartialOrd for Column {
             (Column::Boolean(col1), Column::Boolean(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Binary(col1), Column::Binary(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::String(col1), Column::String(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::StringDictionary(col1), Column::StringDictionary(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Timestamp(col1), Column::Timestamp(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
@@ -888,8 +941,12 @@ impl Column {
             Column::Boolean(col) => col.len(),
             Column::Binary(col) => col.len(),
             Column::String(col) => col.len(),
+            Column::StringDictionary(col) => col.len(),
+            Column::Constant(col) => col.len(),
+            Column::RunLength(col) => col.len(),
             Column::Timestamp(col) => col.len(),
             Column::Date(col) => col.len(),
+            Column::Interval(col) => col.len(),
             Column::Array(col) => col.len(),
             Column::Map(col) => col.len(),
             Column::Bitmap(col) => col.len(),
@@ -910,8 +967,12 @@ impl Column {
             Column::Boolean(col) => Some(ScalarRef::Boolean(col.get(index)?)),
             Column::Binary(col) => Some(ScalarRef::Binary(col.index(index)?)),
             Column::String(col) => Some(ScalarRef::String(col.index(index)?)),
+            Column::StringDictionary(col) => Some(ScalarRef::String(col.index(index)?)),
+            Column::Constant(col) => col.index(index),
+            Column::RunLength(col) => col.index(index),
             Column::Timestamp(col) => Some(ScalarRef::Timestamp(col.get(index).cloned()?)),
             Column::Date(col) => Some(ScalarRef::Date(col.get(index).cloned()?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
             Column::Array(col) => Some(ScalarRef::Array(col.index(index)?)),
             Column::Map(col) => Some(ScalarRef::Map(col.index(index)?)),
             Column::Bitmap(col) => Some(ScalarRef::Bitmap(col.index(index)?)),
@@ -940,8 +1001,12 @@ impl Column {
             Column::Boolean(col) => ScalarRef::Boolean(col.get_bit_unchecked(index)),
             Column::Binary(col) => ScalarRef::Binary(col.index_unchecked(index)),
             Column::String(col) => ScalarRef::String(col.index_unchecked(index)),
+            Column::StringDictionary(col) => ScalarRef::String(col.index_unchecked(index)),
+            Column::Constant(col) => col.index_unchecked(index),
+            Column::RunLength(col) => col.index_unchecked(index),
             Column::Timestamp(col) => ScalarRef::Timestamp(*col.get_unchecked(index)),
             Column::Date(col) => ScalarRef::Date(*col.get_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
             Column::Array(col) => ScalarRef::Array(col.index_unchecked(index)),
             Column::Map(col) => ScalarRef::Map(col.index_unchecked(index)),
             Column::Bitmap(col) => ScalarRef::Bitmap(col.index_unchecked(index)),
@@ -987,12 +1052,18 @@ impl Column {
             }
             Column::Binary(col) => Column::Binary(col.slice(range)),
             Column::String(col) => Column::String(col.slice(range)),
+            Column::StringDictionary(col) => Column::StringDictionary(col.slice(range)),
+            Column::Constant(col) => Column::Constant(Box::new(col.slice(range))),
+            Column::RunLength(col) => Column::RunLength(Box::new(col.slice(range))),
             Column::Timestamp(col) => {
                 Column::Timestamp(col.clone().sliced(range.start, range.end - range.start))
             }
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
@@ -1040,6 +1111,15 @@ impl Column {
                     max: Some(max.to_string()),
                 })
             }
+            Column::StringDictionary(col) => {
+                // The values may contain the strings not referenced by the keys,
+                // so the domain is wider but still valid.
+                let (min, max) = col.values().iter().minmax().into_option().unwrap();
+                Domain::String(StringDomain {
+                    min: min.to_string(),
+                    max: Some(max.to_string()),
+                })
+            }
             Column::Timestamp(col) => {
                 let (min, max) = col.iter().minmax().into_option().unwrap();
                 Domain::Timestamp(SimpleDomain {
@@ -1054,6 +1134,9 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Interval(_) => Domain::Undefined,
+            Column::Constant(col) => col.value().domain(),
+            Column::RunLength(col) => col.values().domain(),
             Column::Array(col) => {
                 if col.len() == 0 || col.values.len() == 0 {
                     Domain::Array(None)
@@ -1101,9 +1184,12 @@ impl Column {
             }),
             Column::Boolean(_) => DataType::Boolean,
             Column::Binary(_) => DataType::Binary,
-            Column::String(_) => DataType::String,
+            Column::String(_) | Column::StringDictionary(_) => DataType::String,
+            Column::Constant(col) => col.data_type(),
+            Column::RunLength(col) => col.data_type(),
             Column::Timestamp(_) => DataType::Timestamp,
             Column::Date(_) => DataType::Date,
+            Column::Interval(_) => DataType::Interval,
             Column::Array(array) => {
                 let inner = array.values.data_type();
                 DataType::Array(Box::new(inner))
@@ -1130,6 +1216,9 @@ impl Column {
         match self {
             Column::Binary(x) => x.check_valid(),
             Column::String(x) => x.check_valid(),
+            Column::StringDictionary(x) => x.check_valid(),
+            Column::Constant(x) => x.check_valid(),
+            Column::RunLength(x) => x.check_valid(),
             Column::Variant(x) => x.check_valid(),
             Column::Geometry(x) => x.check_valid(),
             Column::Bitmap(x) => x.check_valid(),
@@ -1237,6 +1326,17 @@ impl Column {
                     .map(|_| rng.gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        Interval::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-365..=365),
+                            rng.gen_range(-MICROS_IN_A_DAY..=MICROS_IN_A_DAY),
+                        )
+                    })
+                    .collect::<Vec<Interval>>(),
+            ),
             DataType::Nullable(ty) => Column::Nullable(Box::new(NullableColumn {
                 column: Column::random(ty, len, seed),
                 validity: Bitmap::from((0..len).map(|_| rng.gen_bool(0.5)).collect::<Vec<bool>>()),
@@ -1319,6 +1419,88 @@ impl Column {
         }
     }
 
+    /// Encodes the low cardinality string column as dictionary, returns `None` if the column
+    /// is not a string column or there are too many distinct values.
+    pub fn encode_dictionary(&self) -> Option<Self> {
+        match self {
+            Column::String(col) => {
+                StringDictionaryColumn::try_encode(col).map(Column::StringDictionary)
+            }
+            Column::Nullable(inner) => {
+                let column = inner.column.encode_dictionary()?;
+                Some(Column::Nullable(Box::new(NullableColumn {
+                    column,
+                    validity: inner.validity.clone(),
+                })))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns true if the column is a constant or run-length column.
+    pub fn is_compact(&self) -> bool {
+        match self {
+            Column::Constant(_) | Column::RunLength(_) => true,
+            Column::Nullable(inner) => inner.column.is_compact(),
+            _ => false,
+        }
+    }
+
+    /// Expands the constant and run-length columns into full columns,
+    /// other columns are returned as is.
+    pub fn expand(&self) -> Self {
+        match self {
+            Column::Constant(col) => col.to_column(),
+            Column::RunLength(col) => col.to_column(),
+            Column::Nullable(inner) if inner.column.is_compact() => {
+                Column::Nullable(Box::new(NullableColumn {
+                    column: inner.column.expand(),
+                    validity: inner.validity.clone(),
+                }))
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Encodes the column as constant if all the values are equal, or as run-length if
+    /// there are only a few runs, returns `None` otherwise.
+    pub fn encode_run_length(&self) -> Option<Self> {
+        match self {
+            Column::Nullable(inner) => {
+                let column = inner.column.encode_run_length()?;
+                Some(Column::Nullable(Box::new(NullableColumn {
+                    column,
+                    validity: inner.validity.clone(),
+                })))
+            }
+            Column::Constant(_) | Column::RunLength(_) => None,
+            _ => {
+                let col = RunLengthColumn::try_encode(self)?;
+                if col.num_runs() == 1 {
+                    let col = ConstantColumn::try_new(col.values().clone(), col.len()).ok()?;
+                    Some(Column::Constant(Box::new(col)))
+                } else {
+                    Some(Column::RunLength(Box::new(col)))
+                }
+            }
+        }
+    }
+
+    /// Decodes the dictionary encoded string column into a plain string column,
+    /// other columns are returned as is.
+    pub fn decode_dictionary(&self) -> Self {
+        match self {
+            Column::StringDictionary(col) => Column::String(col.to_string_column()),
+            Column::Nullable(inner) if inner.column.is_string_dictionary() => {
+                Column::Nullable(Box::new(NullableColumn {
+                    column: inner.column.decode_dictionary(),
+                    validity: inner.validity.clone(),
+                }))
+            }
+            _ => self.clone(),
+        }
+    }
+
     pub fn wrap_nullable(self, validity: Option<Bitmap>) -> Self {
         match self {
             c @ Column::Null { .. } => c,
@@ -1362,8 +1544,12 @@ impl Column {
             Column::Boolean(c) => c.as_slice().0.len(),
             Column::Binary(col) => col.memory_size(),
             Column::String(col) => col.memory_size(),
+            Column::StringDictionary(col) => col.memory_size(),
+            Column::Constant(col) => col.memory_size(),
+            Column::RunLength(col) => col.memory_size(),
             Column::Timestamp(col) => col.len() * 8,
             Column::Date(col) => col.len() * 4,
+            Column::Interval(col) => col.len() * 16,
             Column::Array(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Map(col) => col.values.memory_size() + col.offsets.len() * 8,
             Column::Bitmap(col) => col.memory_size(),
@@ -1387,6 +1573,7 @@ impl Column {
             Column::Number(NumberColumn::Int16(col)) => col.len() * 2,
             Column::Number(NumberColumn::Int32(col)) | Column::Date(col) => col.len() * 4,
             Column::Number(NumberColumn::Int64(col)) | Column::Timestamp(col) => col.len() * 8,
+            Column::Interval(col) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Boolean(c) => c.len(),
@@ -1395,6 +1582,10 @@ impl Column {
             | Column::Variant(col)
             | Column::Geometry(col) => col.memory_size(),
             Column::String(col) => col.memory_size(),
+            Column::StringDictionary(col) => {
+                col.iter().map(|value| value.len()).sum::<usize>() + (col.len() + 1) * 8
+            }
+            Column::Constant(_) | Column::RunLength(_) => self.expand().serialize_size(),
             Column::Array(col) | Column::Map(col) => col.values.serialize_size() + col.len() * 8,
             Column::Nullable(c) => c.column.serialize_size() + c.len(),
             Column::Tuple(fields) => fields.iter().map(|f| f.serialize_size()).sum(),
@@ -1482,8 +1673,14 @@ impl ColumnBuilder {
             Column::Boolean(col) => ColumnBuilder::Boolean(bitmap_into_mut(col)),
             Column::Binary(col) => ColumnBuilder::Binary(BinaryColumnBuilder::from_column(col)),
             Column::String(col) => ColumnBuilder::String(StringColumnBuilder::from_column(col)),
+            Column::StringDictionary(col) => {
+                ColumnBuilder::String(StringColumnBuilder::from_column(col.to_string_column()))
+            }
+            Column::Constant(col) => ColumnBuilder::from_column(col.to_column()),
+            Column::RunLength(col) => ColumnBuilder::from_column(col.to_column()),
             Column::Timestamp(col) => ColumnBuilder::Timestamp(buffer_into_mut(col)),
             Column::Date(col) => ColumnBuilder::Date(buffer_into_mut(col)),
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
             Column::Array(box col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::from_column(col)))
             }
@@ -1545,6 +1742,7 @@ impl ColumnBuilder {
             ScalarRef::String(s) => ColumnBuilder::String(StringColumnBuilder::repeat(s, n)),
             ScalarRef::Timestamp(d) => ColumnBuilder::Timestamp(vec![*d; n]),
             ScalarRef::Date(d) => ColumnBuilder::Date(vec![*d; n]),
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
             ScalarRef::Array(col) => {
                 ColumnBuilder::Array(Box::new(ArrayColumnBuilder::repeat(col, n)))
             }
@@ -1580,6 +1778,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.len(),
             ColumnBuilder::Timestamp(builder) => builder.len(),
             ColumnBuilder::Date(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
             ColumnBuilder::Array(builder) => builder.len(),
             ColumnBuilder::Map(builder) => builder.len(),
             ColumnBuilder::Bitmap(builder) => builder.len(),
@@ -1616,6 +1815,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Timestamp(col) => col.len() * 8,
             ColumnBuilder::Date(col) => col.len() * 4,
+            ColumnBuilder::Interval(col) => col.len() * 16,
             ColumnBuilder::Array(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Map(col) => col.builder.memory_size() + col.offsets.len() * 8,
             ColumnBuilder::Bitmap(col) => col.data.len() + col.offsets.len() * 8,
@@ -1643,6 +1843,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(_) => DataType::String,
             ColumnBuilder::Timestamp(_) => DataType::Timestamp,
             ColumnBuilder::Date(_) => DataType::Date,
+            ColumnBuilder::Interval(_) => DataType::Interval,
             ColumnBuilder::Array(col) => {
                 let inner = col.builder.data_type();
                 DataType::Array(Box::new(inner))
@@ -1693,6 +1894,7 @@ impl ColumnBuilder {
             }
             DataType::Timestamp => ColumnBuilder::Timestamp(Vec::with_capacity(capacity)),
             DataType::Date => ColumnBuilder::Date(Vec::with_capacity(capacity)),
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
             DataType::Nullable(ty) => ColumnBuilder::Nullable(Box::new(NullableColumnBuilder {
                 builder: Self::with_capacity_hint(ty, capacity, enable_datasize_hint),
                 validity: MutableBitmap::with_capacity(capacity),
@@ -1762,6 +1964,7 @@ impl ColumnBuilder {
                 builder.push(value);
             }
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => builder.push(value),
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => builder.push(value),
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 builder.push(value);
             }
@@ -1808,6 +2011,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.commit_row(),
             ColumnBuilder::Timestamp(builder) => builder.push(0),
             ColumnBuilder::Date(builder) => builder.push(0),
+            ColumnBuilder::Interval(builder) => builder.push(Interval::default()),
             ColumnBuilder::Array(builder) => builder.push_default(),
             ColumnBuilder::Map(builder) => builder.push_default(),
             ColumnBuilder::Bitmap(builder) => builder.commit_row(),
@@ -1875,6 +2079,10 @@ impl ColumnBuilder {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let value: i128 = reader.read_scalar()?;
+                builder.push(Interval::from_i128(value));
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -1980,6 +2188,13 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: i128 = reader.read_scalar()?;
+                    builder.push(Interval::from_i128(value));
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2053,6 +2268,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => builder.pop().map(Scalar::String),
             ColumnBuilder::Timestamp(builder) => builder.pop().map(Scalar::Timestamp),
             ColumnBuilder::Date(builder) => builder.pop().map(Scalar::Date),
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
             ColumnBuilder::Array(builder) => builder.pop().map(Scalar::Array),
             ColumnBuilder::Map(builder) => builder.pop().map(Scalar::Map),
             ColumnBuilder::Bitmap(builder) => builder.pop().map(Scalar::Bitmap),
@@ -2100,6 +2316,18 @@ impl ColumnBuilder {
             (ColumnBuilder::String(builder), Column::String(other)) => {
                 builder.append_column(other);
             }
+            (builder, Column::Constant(other)) => {
+                builder.append_column(&other.to_column());
+            }
+            (builder, Column::RunLength(other)) => {
+                builder.append_column(&other.to_column());
+            }
+            (ColumnBuilder::String(builder), Column::StringDictionary(other)) => {
+                for value in other.iter() {
+                    builder.put_str(value);
+                    builder.commit_row();
+                }
+            }
             (ColumnBuilder::Variant(builder), Column::Variant(other)) => {
                 builder.append_column(other);
             }
@@ -2112,6 +2340,9 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), Column::Date(other)) => {
                 builder.extend_from_slice(other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
             (ColumnBuilder::Array(builder), Column::Array(other)) => {
                 builder.append_column(other.as_ref());
             }
@@ -2150,6 +2381,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Column::String(builder.build()),
             ColumnBuilder::Timestamp(builder) => Column::Timestamp(builder.into()),
             ColumnBuilder::Date(builder) => Column::Date(builder.into()),
+            ColumnBuilder::Interval(builder) => Column::Interval(builder.into()),
             ColumnBuilder::Array(builder) => Column::Array(Box::new(builder.build())),
             ColumnBuilder::Map(builder) => Column::Map(Box::new(builder.build())),
             ColumnBuilder::Bitmap(builder) => Column::Bitmap(builder.build()),
@@ -2176,6 +2408,7 @@ impl ColumnBuilder {
             ColumnBuilder::String(builder) => Scalar::String(builder.build_scalar()),
             ColumnBuilder::Timestamp(builder) => Scalar::Timestamp(builder[0]),
             ColumnBuilder::Date(builder) => Scalar::Date(builder[0]),
+            ColumnBuilder::Interval(builder) => Scalar::Interval(builder[0]),
             ColumnBuilder::Array(builder) => Scalar::Array(builder.build_scalar()),
             ColumnBuilder::Map(builder) => Scalar::Map(builder.build_scalar()),
             ColumnBuilder::Bitmap(builder) => Scalar::Bitmap(builder.build_scalar()),
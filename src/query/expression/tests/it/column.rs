@@ -12,12 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use arrow_array::Array;
 use databend_common_arrow::arrow::array::new_empty_array;
 use databend_common_arrow::arrow::datatypes::DataType as ArrowDataType;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::Column;
+use databend_common_expression::FromData;
 
 #[test]
 fn test_from_arrow_extension_to_column() -> Result<()> {
@@ -30,3 +35,75 @@ fn test_from_arrow_extension_to_column() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_arrow_rs_conversion() -> Result<()> {
+    let data_types = vec![
+        DataType::Boolean,
+        DataType::Binary,
+        DataType::String,
+        DataType::Date,
+        DataType::Timestamp,
+        DataType::Number(NumberDataType::UInt8),
+        DataType::Number(NumberDataType::Int64),
+        DataType::Number(NumberDataType::Float32),
+        DataType::Number(NumberDataType::Float64),
+        DataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+            precision: 20,
+            scale: 3,
+        })),
+        DataType::Decimal(DecimalDataType::Decimal256(DecimalSize {
+            precision: 50,
+            scale: 3,
+        })),
+    ];
+
+    for data_type in data_types {
+        for ty in [data_type.clone(), data_type.wrap_nullable()] {
+            let column = Column::random(&ty, 100, None).slice(10..90);
+            let array = column.clone().into_arrow_rs();
+            assert_eq!(array.len(), 80);
+            let result = Column::from_arrow_rs(array, &ty)?;
+            assert_eq!(column, result, "data type: {ty}");
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_arrow_rs_conversion_zero_copy() -> Result<()> {
+    let column =
+        Int32Type::from_data_with_validity(vec![1, 2, 3, 4], vec![true, false, true, true]);
+    let values_ptr = column
+        .as_nullable()
+        .unwrap()
+        .column
+        .as_number()
+        .unwrap()
+        .as_int32()
+        .unwrap()
+        .as_ptr();
+
+    let array = column.clone().into_arrow_rs();
+    assert_eq!(array.null_count(), 1);
+    assert_eq!(
+        array.to_data().buffers()[0].as_ptr(),
+        values_ptr as *const u8
+    );
+
+    let result = Column::from_arrow_rs(array, &column.data_type())?;
+    let result_ptr = result
+        .as_nullable()
+        .unwrap()
+        .column
+        .as_number()
+        .unwrap()
+        .as_int32()
+        .unwrap()
+        .as_ptr();
+    assert_eq!(result_ptr, values_ptr);
+    assert_eq!(result, column);
+
+    Ok(())
+}
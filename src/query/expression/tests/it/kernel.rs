@@ -531,3 +531,312 @@ pub fn test_scatter() -> databend_common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+pub fn test_string_dictionary() -> databend_common_exception::Result<()> {
+    use databend_common_arrow::arrow::bitmap::Bitmap;
+    use databend_common_expression::group_hash_columns;
+
+    let values = (0..100)
+        .map(|i| ["apple", "banana", "cherry"][i % 3])
+        .collect::<Vec<_>>();
+    let column = StringType::from_data(values);
+    let dict = column.encode_dictionary().unwrap();
+    assert!(dict.is_string_dictionary());
+    assert_eq!(dict.data_type(), DataType::String);
+    assert_eq!(dict.decode_dictionary(), column);
+
+    // Too many distinct values.
+    let distinct = StringType::from_data((0..100).map(|i| i.to_string()).collect::<Vec<_>>());
+    assert!(distinct.encode_dictionary().is_none());
+
+    let filter = Bitmap::from_iter((0..100).map(|i| i % 7 != 0));
+    assert_eq!(
+        dict.filter(&filter).decode_dictionary(),
+        column.filter(&filter)
+    );
+
+    let indices = (0..50).map(|i| (i * 3 % 100) as u32).collect::<Vec<_>>();
+    assert_eq!(
+        dict.take(&indices, &mut None).decode_dictionary(),
+        column.take(&indices, &mut None)
+    );
+
+    let compact_indices = vec![(1, 3), (50, 2), (99, 1)];
+    assert_eq!(
+        dict.take_compacted_indices(&compact_indices, 6)
+            .decode_dictionary(),
+        column.take_compacted_indices(&compact_indices, 6)
+    );
+
+    let concat = Column::concat_columns(vec![dict.slice(0..10), dict.slice(10..100)].into_iter())?;
+    assert!(concat.is_string_dictionary());
+    assert_eq!(concat.decode_dictionary(), column);
+
+    let mut dict_hashes = vec![0; 100];
+    let mut hashes = vec![0; 100];
+    group_hash_columns(&[dict], &mut dict_hashes);
+    group_hash_columns(&[column], &mut hashes);
+    assert_eq!(dict_hashes, hashes);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_run_length_column() -> databend_common_exception::Result<()> {
+    use databend_common_arrow::arrow::bitmap::Bitmap;
+    use databend_common_expression::group_hash_columns;
+    use databend_common_expression::Scalar;
+
+    let column = Int32Type::from_data((0..100).map(|i| i / 10).collect::<Vec<i32>>());
+    let rle = column.encode_run_length().unwrap();
+    assert!(rle.is_run_length());
+    assert_eq!(rle.data_type(), column.data_type());
+    assert_eq!(rle.expand(), column);
+
+    let constant = Int32Type::from_data(vec![7; 100])
+        .encode_run_length()
+        .unwrap();
+    assert!(constant.is_constant());
+    assert_eq!(constant.expand(), Int32Type::from_data(vec![7; 100]));
+
+    // Too many runs.
+    let distinct = Int32Type::from_data((0..100).collect::<Vec<i32>>());
+    assert!(distinct.encode_run_length().is_none());
+
+    let filter = Bitmap::from_iter((0..100).map(|i| i % 7 != 0));
+    let filtered = rle.filter(&filter);
+    assert!(filtered.is_run_length());
+    assert_eq!(filtered.expand(), column.filter(&filter));
+    assert_eq!(
+        constant.filter(&filter).expand(),
+        Int32Type::from_data(vec![7; 85])
+    );
+
+    let indices = (0..50).map(|i| (i * 3 % 100) as u32).collect::<Vec<_>>();
+    assert_eq!(
+        rle.take(&indices, &mut None).expand(),
+        column.take(&indices, &mut None)
+    );
+
+    let compact_indices = vec![(1, 3), (50, 2), (99, 1)];
+    assert_eq!(
+        rle.take_compacted_indices(&compact_indices, 6).expand(),
+        column.take_compacted_indices(&compact_indices, 6)
+    );
+
+    let concat = Column::concat_columns(vec![rle.slice(0..15), rle.slice(15..100)].into_iter())?;
+    assert!(concat.is_run_length());
+    assert_eq!(concat.expand(), column);
+
+    let concat = Column::concat_columns(vec![constant.clone(), constant.clone()].into_iter())?;
+    assert!(concat.is_constant());
+    assert_eq!(concat.len(), 200);
+
+    let concat = Column::concat_columns(vec![rle.clone(), column.clone()].into_iter())?;
+    assert_eq!(
+        concat,
+        Column::concat_columns(vec![column.clone(), column.clone()].into_iter())?
+    );
+
+    assert_eq!(
+        Value::Column(constant.clone()).expand_compact(),
+        Value::Scalar(Scalar::Number(NumberScalar::Int32(7)))
+    );
+    assert_eq!(
+        Value::Column(rle.clone()).expand_compact(),
+        Value::Column(column.clone())
+    );
+
+    let mut rle_hashes = vec![0; 100];
+    let mut hashes = vec![0; 100];
+    group_hash_columns(&[rle], &mut rle_hashes);
+    group_hash_columns(&[column], &mut hashes);
+    assert_eq!(rle_hashes, hashes);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_string_view_column() -> databend_common_exception::Result<()> {
+    use databend_common_arrow::arrow::bitmap::Bitmap;
+    use databend_common_expression::types::binary::BinaryColumn;
+    use databend_common_expression::types::string::StringColumn;
+
+    let strings = (0..100)
+        .map(|i| {
+            if i % 2 == 0 {
+                format!("short{i}")
+            } else {
+                format!("a long string that is not inlined {i}")
+            }
+        })
+        .collect::<Vec<_>>();
+    let column = StringType::from_data(strings.clone());
+    let col = column.as_string().unwrap();
+    assert_eq!(col.len(), 100);
+    assert_eq!(
+        col.total_bytes_len(),
+        strings.iter().map(|s| s.len()).sum::<usize>()
+    );
+    for (i, s) in strings.iter().enumerate() {
+        assert_eq!(col.index(i).unwrap(), s.as_str());
+    }
+
+    // Filter, take and slice share the buffers with the origin column.
+    let filter = Bitmap::from_iter((0..100).map(|i| i % 3 != 0));
+    let filtered = column.filter(&filter);
+    assert!(filtered.as_string().unwrap().has_same_buffers(col));
+    assert_eq!(
+        filtered,
+        StringType::from_data(
+            strings
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| i % 3 != 0)
+                .map(|(_, s)| s.clone())
+                .collect::<Vec<_>>()
+        )
+    );
+
+    let indices = (0..50).map(|i| (i * 7 % 100) as u32).collect::<Vec<_>>();
+    let taken = column.take(&indices, &mut None);
+    assert!(taken.as_string().unwrap().has_same_buffers(col));
+    assert_eq!(
+        taken,
+        StringType::from_data(
+            indices
+                .iter()
+                .map(|i| strings[*i as usize].clone())
+                .collect::<Vec<_>>()
+        )
+    );
+
+    let concat =
+        Column::concat_columns(vec![column.slice(0..30), column.slice(30..100)].into_iter())?;
+    assert_eq!(concat, column);
+
+    let gc = taken.as_string().unwrap().gc();
+    assert!(!gc.has_same_buffers(col));
+    assert_eq!(Column::String(gc), taken);
+
+    assert_eq!(col.compare(0, col, 2), "short0".cmp("short2"));
+    assert_eq!(col.compare(1, col, 0), strings[1].as_str().cmp("short0"));
+    assert_eq!(col.compare(3, col, 3), std::cmp::Ordering::Equal);
+
+    // The column built from contiguous data converts to binary without copying,
+    // the column with moved rows is copied.
+    let binary = BinaryColumn::from(col.clone());
+    assert_eq!(binary.len(), 100);
+    assert_eq!(binary.data().as_ptr(), col.buffers()[0].as_ptr());
+    let col2: StringColumn = binary.try_into()?;
+    assert_eq!(&col2, col);
+
+    let taken_binary = BinaryColumn::from(taken.as_string().unwrap().clone());
+    assert_eq!(taken_binary.len(), 50);
+    let taken2: StringColumn = taken_binary.try_into()?;
+    assert_eq!(Column::String(taken2), taken);
+
+    Ok(())
+}
+
+#[test]
+pub fn test_simd_kernels() {
+    use databend_common_expression::types::BooleanType;
+    use databend_common_expression::ArithmeticOp;
+    use databend_common_expression::SelectOp;
+    use databend_common_expression::SimdNumber;
+    use databend_common_expression::ValueRef;
+
+    // Not a multiple of the lanes.
+    let lhs = (0..100).map(|i| i * 7 % 23 - 11).collect::<Vec<i32>>();
+    let rhs = (0..100).map(|i| i % 13 - 6).collect::<Vec<i32>>();
+
+    let ops = [
+        SelectOp::Equal,
+        SelectOp::NotEqual,
+        SelectOp::Gt,
+        SelectOp::Lt,
+        SelectOp::Gte,
+        SelectOp::Lte,
+    ];
+    for op in ops {
+        let expected = |l: i32, r: i32| match op {
+            SelectOp::Equal => l == r,
+            SelectOp::NotEqual => l != r,
+            SelectOp::Gt => l > r,
+            SelectOp::Lt => l < r,
+            SelectOp::Gte => l >= r,
+            SelectOp::Lte => l <= r,
+        };
+        let result = i32::simd_compare(
+            &op,
+            ValueRef::Column(lhs.clone().into()),
+            ValueRef::Column(rhs.clone().into()),
+        );
+        assert_eq!(
+            result,
+            Value::<BooleanType>::Column(
+                lhs.iter()
+                    .zip(rhs.iter())
+                    .map(|(l, r)| expected(*l, *r))
+                    .collect()
+            )
+        );
+
+        let result = i32::simd_compare(
+            &op,
+            ValueRef::Column(lhs.clone().into()),
+            ValueRef::Scalar(3),
+        );
+        assert_eq!(
+            result,
+            Value::<BooleanType>::Column(lhs.iter().map(|l| expected(*l, 3)).collect())
+        );
+    }
+
+    // NaN is the greatest float.
+    let floats = vec![F64::from(1.0), F64::from(f64::NAN), F64::from(-1.0)];
+    let result = F64::simd_compare(
+        &SelectOp::Gt,
+        ValueRef::Column(floats.into()),
+        ValueRef::Scalar(F64::from(0.0)),
+    );
+    assert_eq!(
+        result,
+        Value::<BooleanType>::Column(vec![true, true, false].into_iter().collect())
+    );
+
+    let result = i64::simd_arithmetic::<i32, i32>(
+        ArithmeticOp::Minus,
+        ValueRef::Column(lhs.clone().into()),
+        ValueRef::Column(rhs.clone().into()),
+    );
+    assert_eq!(
+        result,
+        Value::Column(
+            lhs.iter()
+                .zip(rhs.iter())
+                .map(|(l, r)| *l as i64 - *r as i64)
+                .collect::<Vec<_>>()
+                .into()
+        )
+    );
+
+    let result = F64::simd_arithmetic::<i32, F32>(
+        ArithmeticOp::Multiply,
+        ValueRef::Scalar(3),
+        ValueRef::Column(vec![F32::from(0.5), F32::from(-2.0)].into()),
+    );
+    assert_eq!(
+        result,
+        Value::Column(vec![F64::from(1.5), F64::from(-6.0)].into())
+    );
+
+    let result = u64::simd_arithmetic::<u8, u8>(
+        ArithmeticOp::Plus,
+        ValueRef::Scalar(200),
+        ValueRef::Scalar(100),
+    );
+    assert_eq!(result, Value::Scalar(300));
+}
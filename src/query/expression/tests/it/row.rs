@@ -20,6 +20,7 @@ use arrow_schema::SortOptions;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_arrow::arrow::offset::OffsetsBuffer;
 use databend_common_expression::converts::arrow2::set_validities;
+use databend_common_expression::types::binary::BinaryColumn;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
 use databend_common_expression::types::decimal::*;
 use databend_common_expression::types::nullable::NullableColumn;
@@ -552,6 +553,7 @@ fn fuzz_test() {
                     // arrow_ord does not support LargeBinary converted from Databend String
                     Column::Nullable(c) => match &c.column {
                         Column::String(sc) => {
+                            let sc = BinaryColumn::from(sc.clone());
                             let offsets =
                                 sc.offsets().iter().map(|offset| *offset as i64).collect();
                             let array = Box::new(
@@ -27,6 +27,8 @@ use databend_common_expression::types::date::check_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::interval::Interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::timestamp::check_timestamp;
 use databend_common_expression::types::AnyType;
@@ -166,6 +168,7 @@ impl SeparatedTextDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
@@ -280,6 +283,12 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<Interval>, data: &[u8]) -> Result<()> {
+        let interval = string_to_interval(data).map_err(ErrorCode::BadBytes)?;
+        column.push(interval);
+        Ok(())
+    }
+
     fn read_timestamp(&self, column: &mut Vec<i64>, data: &[u8]) -> Result<()> {
         let ts = if !data.contains(&b'-') {
             read_num_text_exact(data)?
@@ -132,8 +132,18 @@ impl FieldEncoderCSV {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.string_formatter.write_string(buf.as_bytes(), out_buf);
             }
-
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) | Column::Variant(..) => {
+            Column::StringDictionary(c) => {
+                let buf = unsafe { c.index_unchecked(row_index) };
+                self.string_formatter.write_string(buf.as_bytes(), out_buf);
+            }
+            Column::Constant(c) => self.write_field(c.value(), 0, out_buf),
+            Column::RunLength(c) => self.write_field(c.values(), c.run_index(row_index), out_buf),
+
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Bitmap(..)
+            | Column::Variant(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
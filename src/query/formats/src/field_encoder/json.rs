@@ -67,8 +67,17 @@ impl FieldEncoderJSON {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.write_string(buf.as_bytes(), out_buf);
             }
+            Column::StringDictionary(c) => {
+                let buf = unsafe { c.index_unchecked(row_index) };
+                self.write_string(buf.as_bytes(), out_buf);
+            }
+            Column::Constant(c) => self.write_field(c.value(), 0, out_buf),
+            Column::RunLength(c) => self.write_field(c.values(), c.run_index(row_index), out_buf),
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::Bitmap(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.write_string(&buf, out_buf);
@@ -19,6 +19,7 @@ use databend_common_expression::types::array::ArrayColumn;
 use databend_common_expression::types::binary::BinaryColumn;
 use databend_common_expression::types::date::date_to_string;
 use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::interval::Interval;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::timestamp::timestamp_to_string;
@@ -132,8 +133,18 @@ impl FieldEncoderValues {
 
             Column::Binary(c) => self.write_binary(c, row_index, out_buf),
             Column::String(c) => self.write_string(c, row_index, out_buf, in_nested),
+            Column::StringDictionary(c) => self.write_string_inner(
+                unsafe { c.index_unchecked(row_index).as_bytes() },
+                out_buf,
+                in_nested,
+            ),
+            Column::Constant(c) => self.write_field(c.value(), 0, out_buf, in_nested),
+            Column::RunLength(c) => {
+                self.write_field(c.values(), c.run_index(row_index), out_buf, in_nested)
+            }
             Column::Date(c) => self.write_date(c, row_index, out_buf, in_nested),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
             Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
@@ -256,6 +267,18 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<Interval>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = v.to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
     fn write_timestamp(
         &self,
         column: &Buffer<i64>,
@@ -301,6 +301,10 @@ pub fn try_create_aggregate_avg_function(
 pub fn aggregate_avg_function_desc() -> AggregateFunctionDescription {
     let features = super::aggregate_function_factory::AggregateFunctionFeatures {
         is_decomposable: true,
+        category: "aggregate",
+        description: "Returns the average of the expression.",
+        definition: "AVG(<expr>)",
+        example: "SELECT AVG(number) FROM numbers(10)",
         ..Default::default()
     };
     AggregateFunctionDescription::creator_with_features(
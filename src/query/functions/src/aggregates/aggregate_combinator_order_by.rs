@@ -0,0 +1,323 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionFactory;
+use crate::aggregates::AggregateFunctionRef;
+
+pub const ORDER_BY_SUFFIX: &str = "_order_by";
+
+/// The sort description of a key in `AggregateOrderByCombinator`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AggregateSortDesc {
+    pub asc: bool,
+    pub nulls_first: bool,
+}
+
+/// The rows of the arguments and the sort keys, the state is serialized with the sort keys,
+/// so the partial states of different nodes can be merged and sorted as a whole.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+struct AggregateOrderByState {
+    rows: Vec<Vec<Scalar>>,
+}
+
+/// Feeds the values to the nested function in the order of the sort keys, like
+/// `array_agg(a ORDER BY b DESC)` and `string_agg(a, ',' ORDER BY b)`.
+///
+/// The arguments are the arguments of the nested function followed by the sort keys, and the
+/// params are the params of the nested function followed by the encoded sort descriptions,
+/// see `AggregateOrderByCombinator::encode_params`.
+#[derive(Clone)]
+pub struct AggregateOrderByCombinator {
+    name: String,
+    nested_name: String,
+    arguments: Vec<DataType>,
+    sort_descs: Vec<AggregateSortDesc>,
+    distinct: bool,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateOrderByCombinator {
+    pub fn try_create(
+        nested_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        _nested_creator: &AggregateFunctionCreator,
+    ) -> Result<AggregateFunctionRef> {
+        let name = format!("OrderByCombinator({})", nested_name);
+        let (nested_params, distinct, sort_descs) = Self::decode_params(&name, params)?;
+        if sort_descs.is_empty() || sort_descs.len() >= arguments.len() {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{} expect to have {} sort keys after at least one argument, but got {} arguments",
+                name,
+                sort_descs.len(),
+                arguments.len()
+            )));
+        }
+
+        let nested_arguments = arguments[..arguments.len() - sort_descs.len()].to_vec();
+        let nested = AggregateFunctionFactory::instance().get(
+            nested_name,
+            nested_params,
+            nested_arguments.clone(),
+        )?;
+
+        Ok(Arc::new(AggregateOrderByCombinator {
+            name,
+            nested_name: nested_name.to_string(),
+            arguments: nested_arguments,
+            sort_descs,
+            distinct,
+            nested,
+        }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(Self::try_create))
+    }
+
+    /// Appends the `DISTINCT` flag and the sort descriptions to the params of the nested
+    /// function, so the function can be built from its name, params and arguments on any node.
+    pub fn encode_params(
+        mut params: Vec<Scalar>,
+        distinct: bool,
+        sort_descs: &[AggregateSortDesc],
+    ) -> Vec<Scalar> {
+        for desc in sort_descs {
+            params.push(Scalar::Boolean(desc.asc));
+            params.push(Scalar::Boolean(desc.nulls_first));
+        }
+        params.push(Scalar::Boolean(distinct));
+        params.push(Scalar::Number(
+            NumberScalar::UInt64(sort_descs.len() as u64),
+        ));
+        params
+    }
+
+    fn decode_params(
+        name: &str,
+        mut params: Vec<Scalar>,
+    ) -> Result<(Vec<Scalar>, bool, Vec<AggregateSortDesc>)> {
+        let invalid_params =
+            || ErrorCode::BadArguments(format!("{} has invalid sort descriptions", name));
+
+        let num_keys = match params.pop() {
+            Some(Scalar::Number(NumberScalar::UInt64(n))) => n as usize,
+            _ => return Err(invalid_params()),
+        };
+        let distinct = match params.pop() {
+            Some(Scalar::Boolean(distinct)) => distinct,
+            _ => return Err(invalid_params()),
+        };
+        if params.len() < num_keys * 2 {
+            return Err(invalid_params());
+        }
+
+        let descs = params.split_off(params.len() - num_keys * 2);
+        let sort_descs = descs
+            .chunks(2)
+            .map(|desc| match desc {
+                [Scalar::Boolean(asc), Scalar::Boolean(nulls_first)] => Ok(AggregateSortDesc {
+                    asc: *asc,
+                    nulls_first: *nulls_first,
+                }),
+                _ => Err(invalid_params()),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok((params, distinct, sort_descs))
+    }
+
+    fn compare_rows(&self, lhs: &[Scalar], rhs: &[Scalar]) -> Ordering {
+        let offset = self.arguments.len();
+        for (i, desc) in self.sort_descs.iter().enumerate() {
+            let (l, r) = (&lhs[offset + i], &rhs[offset + i]);
+            let ordering = match (l.is_null(), r.is_null()) {
+                (true, true) => Ordering::Equal,
+                (true, false) if desc.nulls_first => Ordering::Less,
+                (true, false) => Ordering::Greater,
+                (false, true) if desc.nulls_first => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) if desc.asc => l.cmp(r),
+                (false, false) => r.cmp(l),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        if self.distinct {
+            // Make the duplicated arguments adjacent, they have the same sort keys
+            // since the sort keys of `DISTINCT` must be in the arguments.
+            return lhs[..offset].cmp(&rhs[..offset]);
+        }
+        Ordering::Equal
+    }
+
+    fn build_columns(&self, rows: &[Vec<Scalar>]) -> Vec<Column> {
+        let mut builders = self
+            .arguments
+            .iter()
+            .map(|ty| ColumnBuilder::with_capacity(ty, rows.len()))
+            .collect::<Vec<_>>();
+        for row in rows {
+            for (builder, value) in builders.iter_mut().zip(row.iter()) {
+                builder.push(value.as_ref());
+            }
+        }
+        builders.into_iter().map(|b| b.build()).collect()
+    }
+
+    fn nested_place(&self, place: StateAddr) -> StateAddr {
+        let layout = Layout::new::<AggregateOrderByState>();
+        place.next(layout.size())
+    }
+}
+
+impl AggregateFunction for AggregateOrderByCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateOrderByState::default);
+        self.nested.init_state(self.nested_place(place));
+    }
+
+    fn state_layout(&self) -> Layout {
+        let layout = Layout::new::<AggregateOrderByState>();
+        let nested = self.nested.state_layout();
+        Layout::from_size_align(layout.size() + nested.size(), layout.align()).unwrap()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateOrderByState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                state.rows.push(
+                    columns
+                        .iter()
+                        .map(|col| unsafe { AnyType::index_column_unchecked(col, row).to_owned() })
+                        .collect(),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<AggregateOrderByState>();
+        state.rows.push(
+            columns
+                .iter()
+                .map(|col| unsafe { AnyType::index_column_unchecked(col, row).to_owned() })
+                .collect(),
+        );
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateOrderByState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateOrderByState>();
+        let rhs: AggregateOrderByState = borsh_deserialize_state(reader)?;
+        state.rows.extend(rhs.rows);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateOrderByState>();
+        let other = rhs.get::<AggregateOrderByState>();
+        state.rows.extend(other.rows.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateOrderByState>();
+        let nested_place = self.nested_place(place);
+        // Rebuild the nested state from the rows, the result may be merged
+        // more than once when the function is used as a window function.
+        if self.nested.need_manual_drop_state() {
+            unsafe { self.nested.drop_state(nested_place) };
+        }
+        self.nested.init_state(nested_place);
+        if !state.rows.is_empty() {
+            // The sort is stable, the rows with the same sort keys are kept in the input order.
+            state.rows.sort_by(|lhs, rhs| self.compare_rows(lhs, rhs));
+            if self.distinct {
+                let offset = self.arguments.len();
+                state
+                    .rows
+                    .dedup_by(|lhs, rhs| lhs[..offset] == rhs[..offset]);
+            }
+            let columns = self.build_columns(&state.rows);
+            self.nested
+                .accumulate(nested_place, &columns, None, state.rows.len())?;
+        }
+        self.nested.merge_result(nested_place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateOrderByState>();
+        std::ptr::drop_in_place(state);
+
+        if self.nested.need_manual_drop_state() {
+            self.nested.drop_state(self.nested_place(place));
+        }
+    }
+}
+
+impl fmt::Display for AggregateOrderByCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.nested_name, ORDER_BY_SUFFIX)
+    }
+}
@@ -58,6 +58,11 @@ impl AggregateCountFunction {
         let features = super::aggregate_function_factory::AggregateFunctionFeatures {
             returns_default_when_only_null: true,
             is_decomposable: true,
+            category: "aggregate",
+            description:
+                "Returns the number of rows, or the number of non-NULL values of the expression.",
+            definition: "COUNT(*) | COUNT(<expr>)",
+            example: "SELECT COUNT(*) FROM numbers(10)",
             ..Default::default()
         };
         AggregateFunctionDescription::creator_with_features(Box::new(Self::try_create), features)
@@ -48,6 +48,9 @@ pub struct AggregateCovarianceState {
     pub co_moments: f64,
     pub left_mean: f64,
     pub right_mean: f64,
+    /// The sums of squared deviations of both sides, used by `corr`.
+    pub left_m2: f64,
+    pub right_m2: f64,
 }
 
 // Source: "Numerically Stable, Single-Pass, Parallel Statistics Algorithms"
@@ -71,6 +74,8 @@ impl AggregateCovarianceState {
         let new_right_mean = self.right_mean + right_delta / self.count as f64;
 
         self.co_moments += (s - new_left_mean) * (t - self.right_mean);
+        self.left_m2 += (s - new_left_mean) * left_delta;
+        self.right_m2 += (t - new_right_mean) * right_delta;
         self.left_mean = new_left_mean;
         self.right_mean = new_right_mean;
     }
@@ -91,6 +96,8 @@ impl AggregateCovarianceState {
         let right_delta = self.right_mean - other.right_mean;
 
         self.co_moments += other.co_moments + left_delta * right_delta * factor;
+        self.left_m2 += other.left_m2 + left_delta * left_delta * factor;
+        self.right_m2 += other.right_m2 + right_delta * right_delta * factor;
 
         if large_and_comparable(self.count, other.count) {
             self.left_mean = (self.left_sum() + other.left_sum()) / total as f64;
@@ -155,6 +162,8 @@ where
             left_mean: 0.0,
             right_mean: 0.0,
             co_moments: 0.0,
+            left_m2: 0.0,
+            right_m2: 0.0,
         });
     }
 
@@ -359,3 +368,26 @@ pub fn aggregate_covariance_population_desc() -> AggregateFunctionDescription {
         try_create_aggregate_covariance::<AggregateCovariancePopulationImpl>,
     ))
 }
+
+// Pearson correlation coefficient function implementation
+struct AggregateCorrelationImpl;
+
+impl AggregateCovariance for AggregateCorrelationImpl {
+    fn name() -> &'static str {
+        "AggregateCorrelationFunction"
+    }
+
+    fn apply(state: &AggregateCovarianceState) -> f64 {
+        if state.count < 2 {
+            f64::NAN
+        } else {
+            state.co_moments / (state.left_m2 * state.right_m2).sqrt()
+        }
+    }
+}
+
+pub fn aggregate_correlation_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_covariance::<AggregateCorrelationImpl>,
+    ))
+}
@@ -357,8 +357,8 @@ impl DistinctStateFunc for AggregateUniqStringState {
     }
 
     fn add(&mut self, columns: &[Column], row: usize) -> Result<()> {
-        let column = columns[0].as_string().unwrap();
-        let data = unsafe { column.index_unchecked(row) };
+        let data = unsafe { columns[0].index_unchecked(row) };
+        let data = data.as_string().unwrap();
         let mut hasher = SipHasher24::new();
         hasher.write(data.as_bytes());
         let hash128 = hasher.finish128();
@@ -372,7 +372,7 @@ impl DistinctStateFunc for AggregateUniqStringState {
         validity: Option<&Bitmap>,
         input_rows: usize,
     ) -> Result<()> {
-        let column = columns[0].as_string().unwrap();
+        let column = StringType::try_downcast_column(&columns[0]).unwrap();
         match validity {
             Some(v) => {
                 for (t, v) in column.iter().zip(v.iter()) {
@@ -23,6 +23,7 @@ use databend_common_expression::Scalar;
 
 use super::AggregateFunctionCombinatorNull;
 use super::AggregateFunctionOrNullAdaptor;
+use super::ORDER_BY_SUFFIX;
 use crate::aggregates::AggregateFunctionRef;
 use crate::aggregates::Aggregators;
 
@@ -183,6 +184,12 @@ impl AggregateFunctionFactory {
             return Ok(agg);
         }
 
+        // The NULL sort keys of the order by combinator are sorted instead of skipped,
+        // and its nested function handles the NULL arguments itself.
+        if name.to_lowercase().ends_with(ORDER_BY_SUFFIX) {
+            return self.get_impl(name, params, arguments, &mut features);
+        }
+
         if !arguments.is_empty() && arguments.iter().any(|f| f.is_nullable_or_null()) {
             let (new_params, new_arguments) = match name.to_lowercase().strip_suffix(STATE_SUFFIX) {
                 Some(_) => (params.clone(), arguments.clone()),
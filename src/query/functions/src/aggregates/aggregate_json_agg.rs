@@ -0,0 +1,346 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::date_helper::TzLUT;
+use databend_common_expression::types::variant::cast_scalar_to_variant;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::types::VariantType;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use jsonb::build_array;
+use jsonb::build_object;
+
+use super::aggregate_function_factory::AggregateFunctionDescription;
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::StateAddr;
+use crate::aggregates::assert_arguments;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// Encodes the value of a row into the binary JSON format,
+/// the values are kept encoded in the state, so they are never converted to text.
+fn encode_value(column: &Column, row: usize) -> Vec<u8> {
+    let mut buf = Vec::new();
+    let value = unsafe { AnyType::index_column_unchecked(column, row) };
+    cast_scalar_to_variant(value, TzLUT::default(), &mut buf);
+    buf
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default)]
+pub struct JsonArrayAggState {
+    values: Vec<Vec<u8>>,
+}
+
+/// Aggregates the values into a JSON array, like `json_array_agg(v)`.
+#[derive(Clone)]
+pub struct AggregateJsonArrayAggFunction {
+    display_name: String,
+}
+
+impl AggregateFunction for AggregateJsonArrayAggFunction {
+    fn name(&self) -> &str {
+        "AggregateJsonArrayAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Variant)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(JsonArrayAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<JsonArrayAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                state.values.push(encode_value(&columns[0], row));
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let state = place.next(offset).get::<JsonArrayAggState>();
+            state.values.push(encode_value(&columns[0], row));
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        state.values.push(encode_value(&columns[0], row));
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        let rhs: JsonArrayAggState = borsh_deserialize_state(reader)?;
+        state.values.extend(rhs.values);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        let other = rhs.get::<JsonArrayAggState>();
+        state.values.extend(other.values.iter().cloned());
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<JsonArrayAggState>();
+        let builder = VariantType::try_downcast_builder(builder).unwrap();
+        build_array(state.values.iter().map(|v| &v[..]), &mut builder.data)
+            .map_err(|e| ErrorCode::Internal(format!("{}: {}", self.display_name, e)))?;
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<JsonArrayAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateJsonArrayAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default)]
+pub struct JsonObjectAggState {
+    values: BTreeMap<String, Vec<u8>>,
+}
+
+impl JsonObjectAggState {
+    fn insert(&mut self, display_name: &str, key: String, value: Vec<u8>) -> Result<()> {
+        match self.values.entry(key) {
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                Ok(())
+            }
+            Entry::Occupied(entry) => Err(ErrorCode::BadArguments(format!(
+                "{} got a duplicate key '{}'",
+                display_name,
+                entry.key()
+            ))),
+        }
+    }
+}
+
+/// Aggregates the key-value pairs into a JSON object, like `json_object_agg(k, v)`.
+/// The rows with a NULL key or value are ignored, and a duplicate key is an error.
+#[derive(Clone)]
+pub struct AggregateJsonObjectAggFunction {
+    display_name: String,
+}
+
+impl AggregateJsonObjectAggFunction {
+    fn add_row(
+        &self,
+        state: &mut JsonObjectAggState,
+        columns: &[Column],
+        row: usize,
+    ) -> Result<()> {
+        let keys = StringType::try_downcast_column(&columns[0]).unwrap();
+        let key = unsafe { StringType::index_column_unchecked(&keys, row) };
+        state.insert(
+            &self.display_name,
+            key.to_string(),
+            encode_value(&columns[1], row),
+        )
+    }
+}
+
+impl AggregateFunction for AggregateJsonObjectAggFunction {
+    fn name(&self) -> &str {
+        "AggregateJsonObjectAggFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Variant)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(JsonObjectAggState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<JsonObjectAggState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        for row in 0..input_rows {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                self.add_row(state, columns, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        for (row, place) in places.iter().enumerate() {
+            let state = place.next(offset).get::<JsonObjectAggState>();
+            self.add_row(state, columns, row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        self.add_row(state, columns, row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        let rhs: JsonObjectAggState = borsh_deserialize_state(reader)?;
+        for (key, value) in rhs.values {
+            state.insert(&self.display_name, key, value)?;
+        }
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        let other = rhs.get::<JsonObjectAggState>();
+        for (key, value) in other.values.iter() {
+            state.insert(&self.display_name, key.clone(), value.clone())?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<JsonObjectAggState>();
+        let builder = VariantType::try_downcast_builder(builder).unwrap();
+        build_object(
+            state.values.iter().map(|(k, v)| (k, &v[..])),
+            &mut builder.data,
+        )
+        .map_err(|e| ErrorCode::Internal(format!("{}: {}", self.display_name, e)))?;
+        builder.commit_row();
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<JsonObjectAggState>();
+        std::ptr::drop_in_place(state);
+    }
+}
+
+impl fmt::Display for AggregateJsonObjectAggFunction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+pub fn try_create_aggregate_json_array_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_unary_arguments(display_name, argument_types.len())?;
+    Ok(Arc::new(AggregateJsonArrayAggFunction {
+        display_name: display_name.to_string(),
+    }))
+}
+
+pub fn try_create_aggregate_json_object_agg_function(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    argument_types: Vec<DataType>,
+) -> Result<Arc<dyn AggregateFunction>> {
+    assert_arguments(display_name, argument_types.len(), 2)?;
+    if argument_types[0].remove_nullable() != DataType::String {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "The key of aggregate function {} must be string, but got {}",
+            display_name, argument_types[0]
+        )));
+    }
+    Ok(Arc::new(AggregateJsonObjectAggFunction {
+        display_name: display_name.to_string(),
+    }))
+}
+
+pub fn aggregate_json_array_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_json_array_agg_function))
+}
+
+pub fn aggregate_json_object_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_json_object_agg_function))
+}
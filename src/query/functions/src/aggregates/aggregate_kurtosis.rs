@@ -23,6 +23,7 @@ use databend_common_expression::Scalar;
 use num_traits::AsPrimitive;
 
 use super::AggregateUnaryFunction;
+use super::CentralMomentsState;
 use super::FunctionData;
 use super::UnaryState;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
@@ -31,11 +32,7 @@ use crate::aggregates::AggregateFunctionRef;
 
 #[derive(Default, BorshSerialize, BorshDeserialize)]
 struct KurtosisState {
-    pub n: u64,
-    pub sum: f64,
-    pub sum_sqr: f64,
-    pub sum_cub: f64,
-    pub sum_four: f64,
+    pub moments: CentralMomentsState,
 }
 
 impl<T> UnaryState<T, Float64Type> for KurtosisState
@@ -49,23 +46,12 @@ where
         _function_data: Option<&dyn FunctionData>,
     ) -> Result<()> {
         let other = T::to_owned_scalar(other).as_();
-        self.n += 1;
-        self.sum += other;
-        self.sum_sqr += other.powi(2);
-        self.sum_cub += other.powi(3);
-        self.sum_four += other.powi(4);
+        self.moments.add(other);
         Ok(())
     }
 
     fn merge(&mut self, rhs: &Self) -> Result<()> {
-        if rhs.n == 0 {
-            return Ok(());
-        }
-        self.n += rhs.n;
-        self.sum += rhs.sum;
-        self.sum_sqr += rhs.sum_sqr;
-        self.sum_cub += rhs.sum_cub;
-        self.sum_four += rhs.sum_four;
+        self.moments.merge(&rhs.moments);
         Ok(())
     }
 
@@ -74,27 +60,16 @@ where
         builder: &mut Vec<F64>,
         _function_data: Option<&dyn FunctionData>,
     ) -> Result<()> {
-        if self.n <= 3 {
-            builder.push(F64::from(0_f64));
-            return Ok(());
-        }
-        let n = self.n as f64;
-        let temp = 1.0 / n;
-        if self.sum_sqr - self.sum * self.sum * temp == 0.0 {
-            builder.push(F64::from(0_f64));
-            return Ok(());
-        }
-        let m4 = temp
-            * (self.sum_four - 4.0 * self.sum_cub * self.sum * temp
-                + 6.0 * self.sum_sqr * self.sum * self.sum * temp * temp
-                - 3.0 * self.sum.powi(4) * temp.powi(3));
-        let m2 = temp * (self.sum_sqr - self.sum * self.sum * temp);
-        if m2 <= 0.0 || (n - 2.0) * (n - 3.0) == 0.0 {
+        let CentralMomentsState { n, m2, m4, .. } = self.moments;
+        if n <= 3 || m2 <= 0.0 {
             builder.push(F64::from(0_f64));
             return Ok(());
         }
-        let value =
-            (n - 1.0) * ((n + 1.0) * m4 / (m2 * m2) - 3.0 * (n - 1.0)) / ((n - 2.0) * (n - 3.0));
+        let n = n as f64;
+        // The sample excess kurtosis.
+        let m2_sqr = m2 * m2;
+        let value = (n - 1.0) * ((n + 1.0) * n * m4 - 3.0 * (n - 1.0) * m2_sqr)
+            / ((n - 2.0) * (n - 3.0) * m2_sqr);
         if value.is_infinite() || value.is_nan() {
             return Err(ErrorCode::SemanticError("Kurtosis is out of range!"));
         } else {
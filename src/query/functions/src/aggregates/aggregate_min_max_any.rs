@@ -210,6 +210,10 @@ pub fn try_create_aggregate_min_max_any_function<const CMP_TYPE: u8>(
 pub fn aggregate_min_function_desc() -> AggregateFunctionDescription {
     let features = super::aggregate_function_factory::AggregateFunctionFeatures {
         is_decomposable: true,
+        category: "aggregate",
+        description: "Returns the minimum value of the expression.",
+        definition: "MIN(<expr>)",
+        example: "SELECT MIN(number) FROM numbers(10)",
         ..Default::default()
     };
     AggregateFunctionDescription::creator_with_features(
@@ -221,6 +225,10 @@ pub fn aggregate_min_function_desc() -> AggregateFunctionDescription {
 pub fn aggregate_max_function_desc() -> AggregateFunctionDescription {
     let features = super::aggregate_function_factory::AggregateFunctionFeatures {
         is_decomposable: true,
+        category: "aggregate",
+        description: "Returns the maximum value of the expression.",
+        definition: "MAX(<expr>)",
+        example: "SELECT MAX(number) FROM numbers(10)",
         ..Default::default()
     };
     AggregateFunctionDescription::creator_with_features(
@@ -0,0 +1,491 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use num_traits::AsPrimitive;
+
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::AggregateFunctionRef;
+use super::AggregateNullVariadicAdaptor;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_params;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateFunction;
+
+/// The pattern matching gives up if it takes more steps than this.
+const MAX_MATCH_ITERATIONS: usize = 1_000_000;
+
+#[derive(BorshSerialize, BorshDeserialize)]
+struct AggregateSequenceMatchState {
+    /// The timestamp and the bitmask of the matched conditions of the events,
+    /// the events which match none of the conditions are not kept.
+    pub events_list: Vec<(i64, u32)>,
+    pub sorted: bool,
+}
+
+impl AggregateSequenceMatchState {
+    pub fn new() -> Self {
+        Self {
+            events_list: Vec::new(),
+            sorted: true,
+        }
+    }
+
+    #[inline(always)]
+    fn add(&mut self, timestamp: i64, events: u32) {
+        if self.sorted && !self.events_list.is_empty() {
+            let last = self.events_list.last().unwrap();
+            self.sorted = *last <= (timestamp, events);
+        }
+        self.events_list.push((timestamp, events));
+    }
+
+    fn merge(&mut self, other: &Self) {
+        if other.events_list.is_empty() {
+            return;
+        }
+        if let (Some(last), Some(first)) = (self.events_list.last(), other.events_list.first()) {
+            self.sorted = self.sorted && other.sorted && last <= first;
+        } else {
+            self.sorted = other.sorted;
+        }
+        self.events_list.extend_from_slice(&other.events_list);
+    }
+
+    fn sort(&mut self) {
+        if !self.sorted {
+            self.events_list.sort_unstable();
+            self.sorted = true;
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TimeOp {
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+}
+
+impl TimeOp {
+    fn check(&self, duration: i64, value: i64) -> bool {
+        match self {
+            TimeOp::Lt => duration < value,
+            TimeOp::Le => duration <= value,
+            TimeOp::Gt => duration > value,
+            TimeOp::Ge => duration >= value,
+            TimeOp::Eq => duration == value,
+            TimeOp::Ne => duration != value,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PatternAction {
+    /// `(?N)` matches an event which satisfies the N-th condition.
+    Condition(usize),
+    /// `.` matches any event.
+    AnyEvent,
+    /// `.*` matches any number of events.
+    KleeneStar,
+    /// `(?t<op>N)` limits the time between the events matched before and after it.
+    TimeConstraint(TimeOp, i64),
+}
+
+/// Parses the pattern like `(?1).*(?2)(?t<=3600)(?3)`, a leading `.*` is added
+/// so the pattern can match from any event.
+fn parse_pattern(pattern: &str, event_size: usize) -> Result<Vec<PatternAction>> {
+    let invalid_pattern = |reason: &str| {
+        ErrorCode::BadArguments(format!(
+            "Invalid pattern '{}' of sequence_match: {}",
+            pattern, reason
+        ))
+    };
+
+    let mut actions = vec![PatternAction::KleeneStar];
+    let mut rest = pattern.trim_start();
+    while !rest.is_empty() {
+        if let Some(inner) = rest.strip_prefix("(?") {
+            let end = inner
+                .find(')')
+                .ok_or_else(|| invalid_pattern("missing closing parenthesis"))?;
+            let (body, tail) = (&inner[..end], &inner[end + 1..]);
+            rest = tail;
+
+            if let Some(constraint) = body.strip_prefix('t') {
+                if !matches!(
+                    actions.last(),
+                    Some(PatternAction::Condition(_) | PatternAction::AnyEvent)
+                ) {
+                    return Err(invalid_pattern(
+                        "time constraint must follow a condition or '.'",
+                    ));
+                }
+                let (op, value) = if let Some(v) = constraint.strip_prefix("<=") {
+                    (TimeOp::Le, v)
+                } else if let Some(v) = constraint.strip_prefix(">=") {
+                    (TimeOp::Ge, v)
+                } else if let Some(v) = constraint.strip_prefix("==") {
+                    (TimeOp::Eq, v)
+                } else if let Some(v) = constraint.strip_prefix("!=") {
+                    (TimeOp::Ne, v)
+                } else if let Some(v) = constraint.strip_prefix('<') {
+                    (TimeOp::Lt, v)
+                } else if let Some(v) = constraint.strip_prefix('>') {
+                    (TimeOp::Gt, v)
+                } else {
+                    return Err(invalid_pattern("unknown time constraint operator"));
+                };
+                let value = value
+                    .trim()
+                    .parse::<i64>()
+                    .map_err(|_| invalid_pattern("time constraint must be an integer"))?;
+                actions.push(PatternAction::TimeConstraint(op, value));
+            } else {
+                let n = body
+                    .trim()
+                    .parse::<usize>()
+                    .map_err(|_| invalid_pattern("condition must be a number"))?;
+                if n == 0 || n > event_size {
+                    return Err(invalid_pattern(&format!(
+                        "condition number must be between 1 and {}",
+                        event_size
+                    )));
+                }
+                actions.push(PatternAction::Condition(n - 1));
+            }
+        } else if let Some(tail) = rest.strip_prefix(".*") {
+            rest = tail;
+            if actions.last() != Some(&PatternAction::KleeneStar) {
+                actions.push(PatternAction::KleeneStar);
+            }
+        } else if let Some(tail) = rest.strip_prefix('.') {
+            rest = tail;
+            actions.push(PatternAction::AnyEvent);
+        } else {
+            return Err(invalid_pattern("unexpected character"));
+        }
+        rest = rest.trim_start();
+    }
+    Ok(actions)
+}
+
+#[derive(Clone)]
+pub struct AggregateSequenceMatchFunction<T> {
+    display_name: String,
+    event_size: usize,
+    actions: Vec<PatternAction>,
+    t: PhantomData<T>,
+}
+
+impl<T> AggregateFunction for AggregateSequenceMatchFunction<T>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: AsPrimitive<i64>,
+{
+    fn name(&self) -> &str {
+        "AggregateSequenceMatchFunction"
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateSequenceMatchState::new);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateSequenceMatchState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: &[Column],
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let tcolumn = T::try_downcast_column(&columns[0]).unwrap();
+        let state = place.get::<AggregateSequenceMatchState>();
+        for (row, timestamp) in T::iter_column(&tcolumn).enumerate() {
+            if validity.map(|v| v.get_bit(row)).unwrap_or(true) {
+                let events = self.get_events(columns, row);
+                if events != 0 {
+                    state.add(T::to_owned_scalar(timestamp).as_(), events);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: &[Column],
+        _input_rows: usize,
+    ) -> Result<()> {
+        let tcolumn = T::try_downcast_column(&columns[0]).unwrap();
+        for ((row, timestamp), place) in T::iter_column(&tcolumn).enumerate().zip(places.iter()) {
+            let events = self.get_events(columns, row);
+            if events != 0 {
+                let state = place.next(offset).get::<AggregateSequenceMatchState>();
+                state.add(T::to_owned_scalar(timestamp).as_(), events);
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: &[Column], row: usize) -> Result<()> {
+        let events = self.get_events(columns, row);
+        if events != 0 {
+            let tcolumn = T::try_downcast_column(&columns[0]).unwrap();
+            let timestamp = unsafe { T::index_column_unchecked(&tcolumn, row) };
+            let state = place.get::<AggregateSequenceMatchState>();
+            state.add(T::to_owned_scalar(timestamp).as_(), events);
+        }
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateSequenceMatchState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateSequenceMatchState>();
+        let rhs: AggregateSequenceMatchState = borsh_deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateSequenceMatchState>();
+        let other = rhs.get::<AggregateSequenceMatchState>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateSequenceMatchState>();
+        state.sort();
+
+        let mut iterations = 0;
+        let matched = self.backtrack(&state.events_list, 0, 0, None, None, &mut iterations)?;
+        let builder = BooleanType::try_downcast_builder(builder).unwrap();
+        builder.push(matched);
+        Ok(())
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        true
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        let state = place.get::<AggregateSequenceMatchState>();
+        std::ptr::drop_in_place(state);
+    }
+
+    fn get_own_null_adaptor(
+        &self,
+        _nested_function: AggregateFunctionRef,
+        _params: Vec<Scalar>,
+        _arguments: Vec<DataType>,
+    ) -> Result<Option<AggregateFunctionRef>> {
+        Ok(Some(AggregateNullVariadicAdaptor::<false>::create(
+            Arc::new(self.clone()),
+        )))
+    }
+}
+
+impl<T> fmt::Display for AggregateSequenceMatchFunction<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T> AggregateSequenceMatchFunction<T>
+where
+    T: ArgType + Send + Sync,
+    T::Scalar: AsPrimitive<i64>,
+{
+    pub fn try_create(
+        display_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        let event_size = arguments.len() - 1;
+        let pattern = params[0].as_string().ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "The pattern of {} must be a string, but got {}",
+                display_name, params[0]
+            ))
+        })?;
+        let actions = parse_pattern(pattern, event_size)?;
+
+        Ok(Arc::new(Self {
+            display_name: display_name.to_owned(),
+            event_size,
+            actions,
+            t: PhantomData,
+        }))
+    }
+
+    /// Returns the bitmask of the conditions satisfied by the row.
+    fn get_events(&self, columns: &[Column], row: usize) -> u32 {
+        let mut events = 0;
+        for i in 0..self.event_size {
+            let column = BooleanType::try_downcast_column(&columns[i + 1]).unwrap();
+            if column.get_bit(row) {
+                events |= 1 << i;
+            }
+        }
+        events
+    }
+
+    /// Matches the pattern actions from `action` against the sorted events from `event`,
+    /// `base` is the timestamp of the last matched event, and `constraint` is the pending
+    /// time constraint that the next matched event must satisfy.
+    fn backtrack(
+        &self,
+        events: &[(i64, u32)],
+        action: usize,
+        event: usize,
+        base: Option<i64>,
+        constraint: Option<(TimeOp, i64)>,
+        iterations: &mut usize,
+    ) -> Result<bool> {
+        *iterations += 1;
+        if *iterations > MAX_MATCH_ITERATIONS {
+            return Err(ErrorCode::BadArguments(format!(
+                "The pattern of {} is too complex to match, exceeded {} iterations",
+                self.display_name, MAX_MATCH_ITERATIONS
+            )));
+        }
+
+        let Some(current) = self.actions.get(action) else {
+            return Ok(true);
+        };
+        match current {
+            PatternAction::KleeneStar => {
+                for next in event..=events.len() {
+                    if self.backtrack(events, action + 1, next, base, constraint, iterations)? {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            PatternAction::TimeConstraint(op, value) => self.backtrack(
+                events,
+                action + 1,
+                event,
+                base,
+                Some((*op, *value)),
+                iterations,
+            ),
+            PatternAction::Condition(_) | PatternAction::AnyEvent => {
+                let Some((timestamp, mask)) = events.get(event) else {
+                    return Ok(false);
+                };
+                if let PatternAction::Condition(n) = current {
+                    if mask & (1 << *n) == 0 {
+                        return Ok(false);
+                    }
+                }
+                if let (Some((op, value)), Some(base)) = (constraint, base) {
+                    if !op.check(timestamp - base, value) {
+                        return Ok(false);
+                    }
+                }
+                self.backtrack(
+                    events,
+                    action + 1,
+                    event + 1,
+                    Some(*timestamp),
+                    None,
+                    iterations,
+                )
+            }
+        }
+    }
+}
+
+pub fn try_create_aggregate_sequence_match_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_params(display_name, params.len())?;
+    assert_variadic_arguments(display_name, arguments.len(), (2, 33))?;
+
+    for (idx, arg) in arguments[1..].iter().enumerate() {
+        if !arg.is_boolean() {
+            return Err(ErrorCode::BadDataValueType(format!(
+                "Illegal type of the argument {:?} in AggregateSequenceMatchFunction, must be boolean, got: {:?}",
+                idx + 1,
+                arg
+            )));
+        }
+    }
+
+    with_integer_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => AggregateSequenceMatchFunction::<
+            NumberType<NUM_TYPE>,
+        >::try_create(
+            display_name, params, arguments
+        ),
+        DataType::Date =>
+            AggregateSequenceMatchFunction::<DateType>::try_create(display_name, params, arguments),
+        DataType::Timestamp => AggregateSequenceMatchFunction::<TimestampType>::try_create(
+            display_name,
+            params,
+            arguments
+        ),
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "AggregateSequenceMatchFunction does not support type '{:?}'",
+            arguments[0]
+        ))),
+    })
+}
+
+pub fn aggregate_sequence_match_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_sequence_match_function))
+}
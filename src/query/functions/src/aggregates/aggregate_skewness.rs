@@ -24,6 +24,7 @@ use databend_common_expression::Scalar;
 use num_traits::AsPrimitive;
 
 use super::assert_unary_arguments;
+use super::CentralMomentsState;
 use super::FunctionData;
 use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
 use crate::aggregates::aggregate_unary::AggregateUnaryFunction;
@@ -31,10 +32,7 @@ use crate::aggregates::aggregate_unary::UnaryState;
 
 #[derive(Default, BorshSerialize, BorshDeserialize)]
 pub struct SkewnessStateV2 {
-    pub n: u64,
-    pub sum: f64,
-    pub sum_sqr: f64,
-    pub sum_cub: f64,
+    pub moments: CentralMomentsState,
 }
 
 impl<T> UnaryState<T, Float64Type> for SkewnessStateV2
@@ -48,21 +46,12 @@ where
         _function_data: Option<&dyn FunctionData>,
     ) -> Result<()> {
         let other = T::to_owned_scalar(other).as_();
-        self.n += 1;
-        self.sum += other;
-        self.sum_sqr += other.powi(2);
-        self.sum_cub += other.powi(3);
+        self.moments.add(other);
         Ok(())
     }
 
     fn merge(&mut self, rhs: &Self) -> Result<()> {
-        if rhs.n == 0 {
-            return Ok(());
-        }
-        self.n += rhs.n;
-        self.sum += rhs.sum;
-        self.sum_sqr += rhs.sum_sqr;
-        self.sum_cub += rhs.sum_cub;
+        self.moments.merge(&rhs.moments);
         Ok(())
     }
 
@@ -71,25 +60,14 @@ where
         builder: &mut Vec<F64>,
         _function_data: Option<&dyn FunctionData>,
     ) -> Result<()> {
-        if self.n <= 2 {
-            builder.push(F64::from(0_f64));
-            return Ok(());
-        }
-        let n = self.n as f64;
-        let temp = 1.0 / n;
-        let div = (temp * (self.sum_sqr - self.sum * self.sum * temp))
-            .powi(3)
-            .sqrt();
-        if div == 0.0 {
+        let CentralMomentsState { n, m2, m3, .. } = self.moments;
+        if n <= 2 || m2 <= 0.0 {
             builder.push(F64::from(0_f64));
             return Ok(());
         }
-        let temp1 = (n * (n - 1.0)).sqrt() / (n - 2.0);
-        let value = temp1
-            * temp
-            * (self.sum_cub - 3.0 * self.sum_sqr * self.sum * temp
-                + 2.0 * self.sum.powi(3) * temp * temp)
-            / div;
+        let n = n as f64;
+        // The adjusted Fisher-Pearson coefficient of skewness.
+        let value = (n - 1.0).sqrt() * n / (n - 2.0) * m3 / (m2 * m2.sqrt());
         if value.is_infinite() || value.is_nan() {
             return Err(ErrorCode::SemanticError("Skew is out of range!"));
         } else {
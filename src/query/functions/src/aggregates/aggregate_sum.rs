@@ -259,6 +259,10 @@ pub fn try_create_aggregate_sum_function(
 pub fn aggregate_sum_function_desc() -> AggregateFunctionDescription {
     let features = super::aggregate_function_factory::AggregateFunctionFeatures {
         is_decomposable: true,
+        category: "aggregate",
+        description: "Returns the sum of the expression.",
+        definition: "SUM(<expr>)",
+        example: "SELECT SUM(number) FROM numbers(10)",
         ..Default::default()
     };
     AggregateFunctionDescription::creator_with_features(
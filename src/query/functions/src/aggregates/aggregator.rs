@@ -26,6 +26,7 @@ use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
 use super::aggregate_combinator_state::AggregateStateCombinator;
+use super::aggregate_covariance::aggregate_correlation_desc;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
 use super::aggregate_min_max_any::aggregate_any_function_desc;
@@ -33,14 +34,20 @@ use super::aggregate_min_max_any::aggregate_max_function_desc;
 use super::aggregate_min_max_any::aggregate_min_function_desc;
 use super::aggregate_stddev::aggregate_stddev_pop_function_desc;
 use super::aggregate_stddev::aggregate_stddev_samp_function_desc;
+use super::aggregate_stddev::aggregate_variance_pop_function_desc;
+use super::aggregate_stddev::aggregate_variance_samp_function_desc;
 use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use super::AggregateOrderByCombinator;
+use super::ORDER_BY_SUFFIX;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
 use crate::aggregates::aggregate_histogram_function_desc;
+use crate::aggregates::aggregate_json_array_agg_function_desc;
+use crate::aggregates::aggregate_json_object_agg_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
@@ -50,6 +57,7 @@ use crate::aggregates::aggregate_quantile_disc_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_weighted_function_desc;
 use crate::aggregates::aggregate_retention_function_desc;
+use crate::aggregates::aggregate_sequence_match_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum_function_desc;
@@ -76,6 +84,10 @@ impl Aggregators {
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("stddev", aggregate_stddev_samp_function_desc());
         factory.register("std", aggregate_stddev_pop_function_desc());
+        factory.register("var_samp", aggregate_variance_samp_function_desc());
+        factory.register("var_pop", aggregate_variance_pop_function_desc());
+        factory.register("variance", aggregate_variance_samp_function_desc());
+        factory.register("corr", aggregate_correlation_desc());
         factory.register("quantile", aggregate_quantile_disc_function_desc());
         factory.register("quantile_disc", aggregate_quantile_disc_function_desc());
         factory.register("quantile_cont", aggregate_quantile_cont_function_desc());
@@ -99,6 +111,7 @@ impl Aggregators {
             aggregate_approx_count_distinct_function_desc(),
         );
         factory.register("retention", aggregate_retention_function_desc());
+        factory.register("sequence_match", aggregate_sequence_match_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
         factory.register(
@@ -112,6 +125,10 @@ impl Aggregators {
         factory.register("kurtosis", aggregate_kurtosis_function_desc());
         factory.register("skewness", aggregate_skewness_function_desc());
         factory.register("string_agg", aggregate_string_agg_function_desc());
+        factory.register("listagg", aggregate_string_agg_function_desc());
+        factory.register("json_array_agg", aggregate_json_array_agg_function_desc());
+        factory.register("json_agg", aggregate_json_array_agg_function_desc());
+        factory.register("json_object_agg", aggregate_json_object_agg_function_desc());
 
         factory.register(
             "bitmap_and_count",
@@ -143,5 +160,9 @@ impl Aggregators {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
+        factory.register_combinator(
+            ORDER_BY_SUFFIX,
+            AggregateOrderByCombinator::combinator_desc(),
+        );
     }
 }
@@ -25,12 +25,15 @@ mod aggregate_avg;
 mod aggregate_bitmap;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
+mod aggregate_combinator_order_by;
 mod aggregate_combinator_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
 mod aggregate_histogram;
+mod aggregate_json_agg;
 mod aggregate_kurtosis;
 mod aggregate_min_max_any;
+mod aggregate_moments_state;
 mod aggregate_null_result;
 mod aggregate_quantile_cont;
 mod aggregate_quantile_disc;
@@ -38,6 +41,7 @@ mod aggregate_quantile_tdigest;
 mod aggregate_quantile_tdigest_weighted;
 mod aggregate_retention;
 mod aggregate_scalar_state;
+mod aggregate_sequence_match;
 mod aggregate_skewness;
 mod aggregate_stddev;
 mod aggregate_string_agg;
@@ -53,19 +57,25 @@ pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
+pub use aggregate_combinator_order_by::AggregateOrderByCombinator;
+pub use aggregate_combinator_order_by::AggregateSortDesc;
+pub use aggregate_combinator_order_by::ORDER_BY_SUFFIX;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::*;
 pub use aggregate_function_factory::AggregateFunctionFactory;
 pub use aggregate_histogram::*;
+pub use aggregate_json_agg::*;
 pub use aggregate_kurtosis::*;
 pub use aggregate_min_max_any::*;
+pub use aggregate_moments_state::CentralMomentsState;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_quantile_cont::*;
 pub use aggregate_quantile_disc::*;
 pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
 pub use aggregate_retention::*;
+pub use aggregate_sequence_match::*;
 pub use aggregate_skewness::*;
 pub use aggregate_string_agg::*;
 pub use aggregate_sum::*;
@@ -56,6 +56,7 @@ use databend_common_expression::with_integer_mapped_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::with_number_mapped_type_without_64;
 use databend_common_expression::with_unsigned_integer_mapped_type;
+use databend_common_expression::ArithmeticOp;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
 use databend_common_expression::Function;
@@ -63,6 +64,7 @@ use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
 use databend_common_expression::FunctionSignature;
+use databend_common_expression::SimdNumber;
 use ethnum::i256;
 use lexical_core::FormattedSize;
 use num_traits::AsPrimitive;
@@ -93,7 +95,7 @@ macro_rules! register_plus {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::AddMul;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "plus",
             |_, lhs, rhs| {
                 (|| {
@@ -109,7 +111,7 @@ macro_rules! register_plus {
                 })()
                 .unwrap_or(FunctionDomain::Full)
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) + (AsPrimitive::<T>::as_(b)),
+            |lhs, rhs, _| T::simd_arithmetic::<L, R>(ArithmeticOp::Plus, lhs, rhs),
         );
     };
 }
@@ -119,7 +121,7 @@ macro_rules! register_minus {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::Minus;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "minus",
             |_, lhs, rhs| {
                 (|| {
@@ -135,7 +137,7 @@ macro_rules! register_minus {
                 })()
                 .unwrap_or(FunctionDomain::Full)
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) - (AsPrimitive::<T>::as_(b)),
+            |lhs, rhs, _| T::simd_arithmetic::<L, R>(ArithmeticOp::Minus, lhs, rhs),
         );
     };
 }
@@ -145,7 +147,7 @@ macro_rules! register_multiply {
         type L = $lt;
         type R = $rt;
         type T = <(L, R) as ResultTypeOfBinary>::AddMul;
-        $registry.register_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
+        $registry.register_passthrough_nullable_2_arg::<NumberType<L>, NumberType<R>, NumberType<T>, _, _>(
             "multiply",
             |_, lhs, rhs| {
                 (|| {
@@ -166,7 +168,7 @@ macro_rules! register_multiply {
                 })()
                 .unwrap_or(FunctionDomain::Full)
             },
-            |a, b, _| (AsPrimitive::<T>::as_(a)) * (AsPrimitive::<T>::as_(b)),
+            |lhs, rhs, _| T::simd_arithmetic::<L, R>(ArithmeticOp::Multiply, lhs, rhs),
         );
     };
 }
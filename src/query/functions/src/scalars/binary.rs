@@ -217,7 +217,7 @@ fn eval_binary_to_string(val: ValueRef<BinaryType>, ctx: &mut EvalContext) -> Va
 
 fn eval_unhex(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
     vectorize_string_to_binary(
-        |col| col.data().len() / 2,
+        |col| col.total_bytes_len() / 2,
         |val, output, ctx| {
             let old_len = output.data.len();
             let extra_len = val.len() / 2;
@@ -232,7 +232,7 @@ fn eval_unhex(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryT
 
 fn eval_from_base64(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<BinaryType> {
     vectorize_string_to_binary(
-        |col| col.data().len() * 4 / 3 + col.len() * 4,
+        |col| col.total_bytes_len() * 4 / 3 + col.len() * 4,
         |val, output, ctx| {
             if let Err(err) = base64::Engine::decode_vec(
                 &base64::engine::general_purpose::STANDARD,
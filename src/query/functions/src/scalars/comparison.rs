@@ -46,6 +46,8 @@ use databend_common_expression::FunctionRegistry;
 use databend_common_expression::FunctionSignature;
 use databend_common_expression::LikePattern;
 use databend_common_expression::ScalarRef;
+use databend_common_expression::SelectOp;
+use databend_common_expression::SimdNumber;
 use databend_common_expression::SimpleDomainCmp;
 use databend_common_expression::ValueRef;
 use memchr::memmem;
@@ -158,6 +160,47 @@ macro_rules! register_simple_domain_type_cmp {
     };
 }
 
+macro_rules! register_number_type_cmp {
+    ($registry:ident, $T:ty) => {
+        $registry
+            .register_passthrough_nullable_2_arg::<NumberType<$T>, NumberType<$T>, BooleanType, _, _>(
+                "eq",
+                |_, d1, d2| d1.domain_eq(d2),
+                |lhs, rhs, _| <$T>::simd_compare(&SelectOp::Equal, lhs, rhs),
+            );
+        $registry
+            .register_passthrough_nullable_2_arg::<NumberType<$T>, NumberType<$T>, BooleanType, _, _>(
+                "noteq",
+                |_, d1, d2| d1.domain_noteq(d2),
+                |lhs, rhs, _| <$T>::simd_compare(&SelectOp::NotEqual, lhs, rhs),
+            );
+        $registry
+            .register_passthrough_nullable_2_arg::<NumberType<$T>, NumberType<$T>, BooleanType, _, _>(
+                "gt",
+                |_, d1, d2| d1.domain_gt(d2),
+                |lhs, rhs, _| <$T>::simd_compare(&SelectOp::Gt, lhs, rhs),
+            );
+        $registry
+            .register_passthrough_nullable_2_arg::<NumberType<$T>, NumberType<$T>, BooleanType, _, _>(
+                "gte",
+                |_, d1, d2| d1.domain_gte(d2),
+                |lhs, rhs, _| <$T>::simd_compare(&SelectOp::Gte, lhs, rhs),
+            );
+        $registry
+            .register_passthrough_nullable_2_arg::<NumberType<$T>, NumberType<$T>, BooleanType, _, _>(
+                "lt",
+                |_, d1, d2| d1.domain_lt(d2),
+                |lhs, rhs, _| <$T>::simd_compare(&SelectOp::Lt, lhs, rhs),
+            );
+        $registry
+            .register_passthrough_nullable_2_arg::<NumberType<$T>, NumberType<$T>, BooleanType, _, _>(
+                "lte",
+                |_, d1, d2| d1.domain_lte(d2),
+                |lhs, rhs, _| <$T>::simd_compare(&SelectOp::Lte, lhs, rhs),
+            );
+    };
+}
+
 fn register_string_cmp(registry: &mut FunctionRegistry) {
     register_simple_domain_type_cmp!(registry, StringType);
 }
@@ -237,7 +280,7 @@ fn register_number_cmp(registry: &mut FunctionRegistry) {
     for ty in ALL_NUMBER_CLASSES {
         with_number_mapped_type!(|NUM_TYPE| match ty {
             NumberClass::NUM_TYPE => {
-                register_simple_domain_type_cmp!(registry, NumberType<NUM_TYPE>);
+                register_number_type_cmp!(registry, NUM_TYPE);
             }
             NumberClass::Decimal128 => {
                 register_decimal_compare_op(registry)
@@ -17,6 +17,7 @@ use std::io::Write;
 use chrono::prelude::*;
 use chrono::Datelike;
 use chrono::Days;
+use chrono::LocalResult;
 use chrono::Utc;
 use chrono_tz::Tz;
 use databend_common_arrow::arrow::bitmap::Bitmap;
@@ -54,6 +55,8 @@ use databend_common_expression::vectorize_1_arg;
 use databend_common_expression::vectorize_2_arg;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
+use databend_common_expression::vectorize_with_builder_4_arg;
 use databend_common_expression::EvalContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionProperty;
@@ -62,6 +65,9 @@ use databend_common_expression::Value;
 use databend_common_expression::ValueRef;
 use num_traits::AsPrimitive;
 
+use crate::scalars::template::eval_with_template;
+use crate::scalars::template::DateTemplate;
+
 pub fn register(registry: &mut FunctionRegistry) {
     // cast(xx AS timestamp)
     // to_timestamp(xx)
@@ -100,6 +106,10 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     // [date | timestamp] +/- number
     register_timestamp_add_sub(registry);
+
+    // date_bin(stride, timestamp[, origin]) -> timestamp
+    // time_slice([date | timestamp], number, unit[, 'START' | 'END']) -> [date | timestamp]
+    register_time_bucket_functions(registry);
 }
 
 /// Check if timestamp is within range, and return the timestamp in micros.
@@ -164,64 +174,113 @@ fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
     registry.register_combine_nullable_2_arg::<StringType, StringType, TimestampType, _, _>(
         "to_timestamp",
         |_, _, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_2_arg::<StringType, StringType, NullableType<TimestampType>>(
-            |timestamp, format, output, ctx| {
-                if format.is_empty() {
-                    output.push_null();
-                } else {
-                    //%Z	ACST	Local time zone name. Skips all non-whitespace characters during parsing. Identical to %:z when formatting. 6
-                    // %z	+0930	Offset from the local time to UTC (with UTC being +0000).
-                    // %:z	+09:30	Same as %z but with a colon.
-                    // %::z	+09:30:00	Offset from the local time to UTC with seconds.
-                    // %:::z	+09	Offset from the local time to UTC without minutes.
-                    // %#z	+09	Parsing only: Same as %z but allows minutes to be missing or present.
-                    let timezone_strftime = ["%Z", "%z", "%:z", "%::z", "%:::z", "%#z"];
-                    if timezone_strftime
-                        .iter()
-                        .any(|&pattern| format.contains(pattern))
-                    {
-                        if let Ok(res) = DateTime::parse_from_str(timestamp, format) {
-                            // date need has timezone info.
-                            output.push(res.with_timezone(&ctx.func_ctx.tz.tz).timestamp_micros());
-                        } else {
-                            output.push_null();
-                        }
-                    } else {
-                        // Parse as unix timestamp
-                        if let Ok(res) = NaiveDateTime::parse_from_str(timestamp, format) {
-                            output.push(res.timestamp_micros());
-                        } else {
-                            output.push_null();
+        |timestamp, format, ctx| {
+            eval_with_template::<StringType, NullableType<TimestampType>, _>(
+                timestamp,
+                format,
+                ctx,
+                ParseFormat::compile,
+                |timestamp, format, output, ctx| {
+                    let tz = ctx.func_ctx.tz.tz;
+                    let res = match format {
+                        ParseFormat::Strftime(format) => {
+                            parse_timestamp_with_strftime(timestamp, format, tz)
                         }
+                        ParseFormat::Template(template) => template
+                            .parse(timestamp)
+                            .and_then(|parsed| parsed.timestamp_micros(tz))
+                            .ok(),
+                    };
+                    match res {
+                        Some(ts) => output.push(ts),
+                        None => output.push_null(),
                     }
-                }
-            },
-        ),
+                },
+            )
+        },
     );
 
     registry.register_combine_nullable_2_arg::<StringType, StringType, DateType, _, _>(
         "to_date",
         |_, _, _| FunctionDomain::MayThrow,
-        vectorize_with_builder_2_arg::<StringType, StringType, NullableType<DateType>>(
-            |date, format, output, ctx| {
-                if format.is_empty() {
-                    output.push_null();
-                } else {
-                    match NaiveDate::parse_from_str(date, format) {
+        |date, format, ctx| {
+            eval_with_template::<StringType, NullableType<DateType>, _>(
+                date,
+                format,
+                ctx,
+                ParseFormat::compile,
+                |date, format, output, ctx| {
+                    let res = match format {
+                        ParseFormat::Strftime(format) if format.is_empty() => {
+                            output.push_null();
+                            return;
+                        }
+                        ParseFormat::Strftime(format) => {
+                            NaiveDate::parse_from_str(date, format).map_err(|e| e.to_string())
+                        }
+                        ParseFormat::Template(template) => {
+                            template.parse(date).map(|parsed| parsed.datetime.date())
+                        }
+                    };
+                    match res {
                         Ok(res) => {
                             output.push(res.num_days_from_ce() - EPOCH_DAYS_FROM_CE);
                         }
                         Err(e) => {
-                            ctx.set_error(output.len(), e.to_string());
+                            ctx.set_error(output.len(), e);
                             output.push_null();
                         }
                     }
-                }
-            },
-        ),
+                },
+            )
+        },
     );
 }
 
+/// The format of `to_timestamp` and `to_date`, a format with `%` is a strftime format,
+/// like `%Y-%m-%d`, otherwise it's a template, like `YYYY-MM-DD`.
+enum ParseFormat {
+    Strftime(String),
+    Template(DateTemplate),
+}
+
+impl ParseFormat {
+    fn compile(format: &str) -> Result<Self, String> {
+        if format.is_empty() || format.contains('%') {
+            Ok(ParseFormat::Strftime(format.to_string()))
+        } else {
+            DateTemplate::compile(format).map(ParseFormat::Template)
+        }
+    }
+}
+
+fn parse_timestamp_with_strftime(timestamp: &str, format: &str, tz: Tz) -> Option<i64> {
+    if format.is_empty() {
+        return None;
+    }
+    //%Z	ACST	Local time zone name. Skips all non-whitespace characters during parsing. Identical to %:z when formatting. 6
+    // %z	+0930	Offset from the local time to UTC (with UTC being +0000).
+    // %:z	+09:30	Same as %z but with a colon.
+    // %::z	+09:30:00	Offset from the local time to UTC with seconds.
+    // %:::z	+09	Offset from the local time to UTC without minutes.
+    // %#z	+09	Parsing only: Same as %z but allows minutes to be missing or present.
+    let timezone_strftime = ["%Z", "%z", "%:z", "%::z", "%:::z", "%#z"];
+    if timezone_strftime
+        .iter()
+        .any(|&pattern| format.contains(pattern))
+    {
+        // date need has timezone info.
+        DateTime::parse_from_str(timestamp, format)
+            .ok()
+            .map(|res| res.with_timezone(&tz).timestamp_micros())
+    } else {
+        // Parse as unix timestamp
+        NaiveDateTime::parse_from_str(timestamp, format)
+            .ok()
+            .map(|res| res.timestamp_micros())
+    }
+}
+
 fn register_date_to_timestamp(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_1_arg::<DateType, TimestampType, _, _>(
         "to_timestamp",
@@ -837,6 +896,19 @@ impl_register_arith_functions!(register_sub_functions, "subtract", signed_ident)
 fn register_real_time_functions(registry: &mut FunctionRegistry) {
     registry.register_aliases("now", &["current_timestamp"]);
 
+    registry.register_doc("now", "Returns the current date and time.", "SELECT NOW()");
+    registry.register_doc("today", "Returns the current date.", "SELECT TODAY()");
+    registry.register_doc(
+        "yesterday",
+        "Returns the date of yesterday.",
+        "SELECT YESTERDAY()",
+    );
+    registry.register_doc(
+        "tomorrow",
+        "Returns the date of tomorrow.",
+        "SELECT TOMORROW()",
+    );
+
     registry.properties.insert(
         "now".to_string(),
         FunctionProperty::default().non_deterministic(),
@@ -1455,6 +1527,264 @@ fn register_rounder_functions(registry: &mut FunctionRegistry) {
     );
 }
 
+fn register_time_bucket_functions(registry: &mut FunctionRegistry) {
+    // `date_bin(INTERVAL '15' MINUTE, ts)` is rewritten to `date_bin(900000000, ts)` by the type checker.
+    registry.register_passthrough_nullable_2_arg::<Int64Type, TimestampType, TimestampType, _, _>(
+        "date_bin",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, TimestampType, TimestampType>(
+            |stride, val, output, ctx| match date_bin(stride, val, 0) {
+                Ok(res) => output.push(res),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_3_arg::<
+        Int64Type,
+        TimestampType,
+        TimestampType,
+        TimestampType,
+        _,
+        _,
+    >(
+        "date_bin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<Int64Type, TimestampType, TimestampType, TimestampType>(
+            |stride, val, origin, output, ctx| match date_bin(stride, val, origin) {
+                Ok(res) => output.push(res),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<
+        TimestampType,
+        Int64Type,
+        StringType,
+        TimestampType,
+        _,
+        _,
+    >(
+        "time_slice",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<TimestampType, Int64Type, StringType, TimestampType>(
+            |val, n, unit, output, ctx| {
+                match time_slice_timestamp(val, n, unit, "START", ctx.func_ctx.tz) {
+                    Ok(res) => output.push(res),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                }
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_4_arg::<
+        TimestampType,
+        Int64Type,
+        StringType,
+        StringType,
+        TimestampType,
+        _,
+        _,
+    >(
+        "time_slice",
+        |_, _, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_4_arg::<
+            TimestampType,
+            Int64Type,
+            StringType,
+            StringType,
+            TimestampType,
+        >(|val, n, unit, part, output, ctx| {
+            match time_slice_timestamp(val, n, unit, part, ctx.func_ctx.tz) {
+                Ok(res) => output.push(res),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            }
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_3_arg::<DateType, Int64Type, StringType, DateType, _, _>(
+            "time_slice",
+            |_, _, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_3_arg::<DateType, Int64Type, StringType, DateType>(
+                |val, n, unit, output, ctx| match time_slice_date(val, n, unit, "START") {
+                    Ok(res) => output.push(res),
+                    Err(e) => {
+                        ctx.set_error(output.len(), e);
+                        output.push(0);
+                    }
+                },
+            ),
+        );
+    registry.register_passthrough_nullable_4_arg::<
+        DateType,
+        Int64Type,
+        StringType,
+        StringType,
+        DateType,
+        _,
+        _,
+    >(
+        "time_slice",
+        |_, _, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_4_arg::<DateType, Int64Type, StringType, StringType, DateType>(
+            |val, n, unit, part, output, ctx| match time_slice_date(val, n, unit, part) {
+                Ok(res) => output.push(res),
+                Err(e) => {
+                    ctx.set_error(output.len(), e);
+                    output.push(0);
+                }
+            },
+        ),
+    );
+}
+
+const MICROS_IN_A_DAY: i64 = 86_400 * MICROS_IN_A_SEC;
+
+/// Rounds `us` down to the start of the `stride` wide bin it falls in, the bins are aligned to `origin`.
+#[inline]
+fn date_bin(stride: i64, us: i64, origin: i64) -> Result<i64, String> {
+    if stride <= 0 {
+        return Err(format!(
+            "stride of date_bin must be positive, but got {stride}"
+        ));
+    }
+    let offset = us
+        .checked_sub(origin)
+        .ok_or_else(|| "timestamp is out of range".to_string())?;
+    check_timestamp(origin + offset.div_euclid(stride) * stride)
+}
+
+enum SliceUnit {
+    Micros(i64),
+    Months(i64),
+}
+
+impl SliceUnit {
+    fn parse(n: i64, unit: &str) -> Result<Self, String> {
+        if n <= 0 {
+            return Err(format!(
+                "slice length of time_slice must be positive, but got {n}"
+            ));
+        }
+        let unit = match unit.to_ascii_lowercase().as_str() {
+            "second" => SliceUnit::Micros(MICROS_IN_A_SEC),
+            "minute" => SliceUnit::Micros(60 * MICROS_IN_A_SEC),
+            "hour" => SliceUnit::Micros(3600 * MICROS_IN_A_SEC),
+            "day" => SliceUnit::Micros(MICROS_IN_A_DAY),
+            "week" => SliceUnit::Micros(7 * MICROS_IN_A_DAY),
+            "month" => SliceUnit::Months(1),
+            "quarter" => SliceUnit::Months(3),
+            "year" => SliceUnit::Months(12),
+            _ => {
+                return Err(format!(
+                    "unsupported date part `{unit}` of time_slice, expected one of SECOND, MINUTE, HOUR, DAY, WEEK, MONTH, QUARTER, YEAR"
+                ));
+            }
+        };
+        Ok(match unit {
+            SliceUnit::Micros(us) => SliceUnit::Micros(
+                us.checked_mul(n)
+                    .ok_or_else(|| format!("slice length {n} of time_slice is too large"))?,
+            ),
+            SliceUnit::Months(months) => SliceUnit::Months(
+                months
+                    .checked_mul(n)
+                    .ok_or_else(|| format!("slice length {n} of time_slice is too large"))?,
+            ),
+        })
+    }
+}
+
+fn parse_slice_end(part: &str) -> Result<bool, String> {
+    if part.eq_ignore_ascii_case("START") {
+        Ok(false)
+    } else if part.eq_ignore_ascii_case("END") {
+        Ok(true)
+    } else {
+        Err(format!(
+            "the last argument of time_slice must be 'START' or 'END', but got '{part}'"
+        ))
+    }
+}
+
+/// Returns the start (or the end) of the slice that `local` falls in, the slices are aligned to
+/// 1970-01-01 00:00:00, except that the weeks are aligned to Monday 1970-01-05.
+fn time_slice_local(local: NaiveDateTime, unit: &SliceUnit, end: bool) -> Option<NaiveDateTime> {
+    match unit {
+        SliceUnit::Micros(stride) => {
+            let origin = if *stride % (7 * MICROS_IN_A_DAY) == 0 {
+                4 * MICROS_IN_A_DAY
+            } else {
+                0
+            };
+            let us = local.and_utc().timestamp_micros();
+            let mut start = origin + (us - origin).div_euclid(*stride) * stride;
+            if end {
+                start = start.checked_add(*stride)?;
+            }
+            NaiveDateTime::from_timestamp_micros(start)
+        }
+        SliceUnit::Months(stride) => {
+            let months = (local.year() as i64 - 1970) * 12 + local.month0() as i64;
+            let mut start = months.div_euclid(*stride) * stride;
+            if end {
+                start = start.checked_add(*stride)?;
+            }
+            let year = i32::try_from(1970 + start.div_euclid(12)).ok()?;
+            NaiveDate::from_ymd_opt(year, start.rem_euclid(12) as u32 + 1, 1)?.and_hms_opt(0, 0, 0)
+        }
+    }
+}
+
+fn time_slice_timestamp(us: i64, n: i64, unit: &str, part: &str, tz: TzLUT) -> Result<i64, String> {
+    let unit = SliceUnit::parse(n, unit)?;
+    let end = parse_slice_end(part)?;
+
+    // slice the local wall-clock time, so that days and months start at the local midnight.
+    let local = us.to_timestamp(tz.tz).naive_local();
+    let res = time_slice_local(local, &unit, end)
+        .ok_or_else(|| "timestamp is out of range".to_string())?;
+    let res = match tz.tz.from_local_datetime(&res) {
+        LocalResult::Single(dt) | LocalResult::Ambiguous(dt, _) => dt.timestamp_micros(),
+        // the local time is skipped by a DST transition, use the offset before the transition.
+        LocalResult::None => {
+            let offset = tz.tz.offset_from_utc_datetime(&res).fix().local_minus_utc();
+            res.and_utc().timestamp_micros() - offset as i64 * MICROS_IN_A_SEC
+        }
+    };
+    check_timestamp(res)
+}
+
+fn time_slice_date(days: i32, n: i64, unit: &str, part: &str) -> Result<i32, String> {
+    let unit = SliceUnit::parse(n, unit)?;
+    let end = parse_slice_end(part)?;
+    if let SliceUnit::Micros(stride) = unit {
+        if stride % MICROS_IN_A_DAY != 0 {
+            return Err(
+                "time_slice of DATE only supports DAY, WEEK, MONTH, QUARTER and YEAR".to_string(),
+            );
+        }
+    }
+
+    let local = NaiveDateTime::from_timestamp_opt(days as i64 * 86_400, 0)
+        .ok_or_else(|| "date is out of range".to_string())?;
+    let res =
+        time_slice_local(local, &unit, end).ok_or_else(|| "date is out of range".to_string())?;
+    check_date(res.and_utc().timestamp().div_euclid(86_400))
+}
+
 // current we don't consider tz here
 #[inline]
 fn months_between_ts(ts_a: i64, ts_b: i64) -> f64 {
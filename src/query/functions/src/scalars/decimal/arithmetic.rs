@@ -16,9 +16,12 @@ use std::ops::*;
 use std::sync::Arc;
 
 use databend_common_expression::types::decimal::*;
+use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::*;
 use databend_common_expression::vectorize_2_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::Column;
+use databend_common_expression::DecimalOverflowMode;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
 use databend_common_expression::Function;
@@ -26,6 +29,9 @@ use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionEval;
 use databend_common_expression::FunctionRegistry;
 use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
 use ethnum::i256;
 
 #[derive(Copy, Clone, Debug)]
@@ -36,121 +42,230 @@ enum ArithmeticOp {
     Divide,
 }
 
-macro_rules! op_decimal {
-    ($a: expr, $b: expr, $ctx: expr, $left: expr, $right: expr, $result_type: expr, $op: ident, $arithmetic_op: expr) => {
-        match $left {
-            DecimalDataType::Decimal128(_) => {
-                binary_decimal!(
-                    $a,
-                    $b,
-                    $ctx,
-                    $left,
-                    $right,
-                    $op,
-                    $result_type.size(),
-                    i128,
-                    $arithmetic_op
-                )
-            }
-            DecimalDataType::Decimal256(_) => {
-                binary_decimal!(
-                    $a,
-                    $b,
-                    $ctx,
-                    $left,
-                    $right,
-                    $op,
-                    $result_type.size(),
-                    i256,
-                    $arithmetic_op
-                )
-            }
-        }
-    };
+/// The native integers of the decimals.
+trait DecimalNative:
+    Decimal + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
 }
 
-macro_rules! binary_decimal {
-    ($a: expr, $b: expr, $ctx: expr, $left: expr, $right: expr, $op: ident, $size: expr, $type_name: ty, $arithmetic_op: expr) => {{
-        type T = $type_name;
-
-        let overflow = $size.precision == T::default_decimal_size().precision;
-
-        let a = $a.try_downcast().unwrap();
-        let b = $b.try_downcast().unwrap();
-
-        let zero = T::zero();
-        let one = T::one();
-
-        let result = if matches!($arithmetic_op, ArithmeticOp::Divide) {
-            let scale_a = $left.scale();
-            let scale_b = $right.scale();
-
-
-            // Note: the result scale is always larger than the left scale
-            let scale_mul = scale_b + $size.scale - scale_a;
-            let multiplier = T::e(scale_mul as u32);
-            let func = |a: T, b: T, result: &mut Vec<T>, ctx: &mut EvalContext| {
-                // We are using round div here which follow snowflake's behavior: https://docs.snowflake.com/sql-reference/operators-arithmetic
-                // For example:
-                // round_div(5, 2) --> 3
-                // round_div(-5, 2) --> -3
-                // round_div(5, -2) --> -3
-                // round_div(-5, -2) --> 3
-                if std::intrinsics::unlikely(b == zero) {
-                    ctx.set_error(result.len(), "divided by zero");
-                    result.push(one);
-                } else if a.is_negative() == b.is_negative() {
-                    result.push((a * multiplier + b / 2).div(b));
-                } else {
-                    result.push((a * multiplier - b / 2).div(b));
-                }
-            };
+impl DecimalNative for i128 {}
+impl DecimalNative for i256 {}
+
+/// The result of the arithmetic on a row.
+#[derive(Copy, Clone)]
+enum Outcome<T> {
+    Value(T),
+    /// The result is out of the range of the result precision.
+    Overflow {
+        negative: bool,
+    },
+    DivideByZero,
+}
 
-            vectorize_with_builder_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(func)(
-                a, b, $ctx,
-            )
-        } else {
-            if overflow {
-                let min_for_precision = T::min_for_precision($size.precision);
-                let max_for_precision = T::max_for_precision($size.precision);
-
-                let func = |a: T, b: T, result: &mut Vec<T>, ctx: &mut EvalContext| {
-                    let t = a.$op(b);
-                    if t < min_for_precision || t > max_for_precision {
-                        ctx.set_error(
-                            result.len(),
-                            concat!("Decimal overflow at line : ", line!()),
-                        );
-                        result.push(one);
-                    } else {
-                        result.push(t);
-                    }
-                };
-
-                vectorize_with_builder_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(func)(
-                    a, b, $ctx
-                )
-            } else {
-                let func = |l: T, r: T, _ctx: &mut EvalContext| l.$op(r);
+#[inline(always)]
+fn check_precision<T: Decimal>(value: Option<T>, negative: bool, min: T, max: T) -> Outcome<T> {
+    match value {
+        Some(value) if value >= min && value <= max => Outcome::Value(value),
+        Some(value) => Outcome::Overflow {
+            negative: value < T::zero(),
+        },
+        // The native integer overflows, `negative` is the sign of the exact result.
+        None => Outcome::Overflow { negative },
+    }
+}
 
-                vectorize_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(func)(
-                    a, b, $ctx
+/// Evaluates the arithmetic on the decimals of the same width.
+///
+/// Only the results at the max precision of the width may overflow, the others are
+/// computed without checking unless the overflowed rows are NULL.
+#[allow(clippy::too_many_arguments)]
+fn eval_decimal<T: DecimalNative>(
+    a: &ValueRef<AnyType>,
+    b: &ValueRef<AnyType>,
+    ctx: &mut EvalContext,
+    op: ArithmeticOp,
+    left: DecimalSize,
+    right: DecimalSize,
+    size: DecimalSize,
+    null_on_overflow: bool,
+) -> Value<AnyType> {
+    let a = a.try_downcast::<DecimalType<T>>().unwrap();
+    let b = b.try_downcast::<DecimalType<T>>().unwrap();
+    let checked = null_on_overflow || size.precision == T::default_decimal_size().precision;
+    match op {
+        ArithmeticOp::Plus | ArithmeticOp::Minus => {
+            eval_plus_minus(a, b, ctx, op, size, checked, null_on_overflow)
+        }
+        ArithmeticOp::Multiply => eval_multiply(a, b, ctx, size, checked, null_on_overflow),
+        ArithmeticOp::Divide => eval_divide(a, b, ctx, left, right, size, null_on_overflow),
+    }
+}
+
+/// The operands of plus and minus are cast to the result scale before, so they're added
+/// or subtracted as they are.
+fn eval_plus_minus<T: DecimalNative>(
+    a: ValueRef<DecimalType<T>>,
+    b: ValueRef<DecimalType<T>>,
+    ctx: &mut EvalContext,
+    op: ArithmeticOp,
+    size: DecimalSize,
+    checked: bool,
+    null_on_overflow: bool,
+) -> Value<AnyType> {
+    let is_minus = matches!(op, ArithmeticOp::Minus);
+
+    if !checked {
+        let result = match is_minus {
+            false => vectorize_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(
+                |a, b, _| a + b,
+            )(a, b, ctx),
+            true => {
+                vectorize_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(|a, b, _| a - b)(
+                    a, b, ctx,
                 )
             }
         };
-        result.upcast_decimal($size)
-    }};
+        return result.upcast_decimal(size);
+    }
+
+    let zero = T::zero();
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
+    apply_overflow_mode(a, b, ctx, size, null_on_overflow, move |a: T, b: T| {
+        // The native integer overflows only if the exact result has the sign of `a`.
+        let value = match is_minus {
+            true => a.checked_sub(b),
+            false => a.checked_add(b),
+        };
+        check_precision(value, a < zero, min, max)
+    })
+}
+
+fn eval_multiply<T: DecimalNative>(
+    a: ValueRef<DecimalType<T>>,
+    b: ValueRef<DecimalType<T>>,
+    ctx: &mut EvalContext,
+    size: DecimalSize,
+    checked: bool,
+    null_on_overflow: bool,
+) -> Value<AnyType> {
+    if !checked {
+        return vectorize_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(|a, b, _| a * b)(
+            a, b, ctx,
+        )
+        .upcast_decimal(size);
+    }
+
+    let zero = T::zero();
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
+    apply_overflow_mode(a, b, ctx, size, null_on_overflow, move |a: T, b: T| {
+        check_precision(a.checked_mul(b), (a < zero) != (b < zero), min, max)
+    })
+}
+
+fn eval_divide<T: DecimalNative>(
+    a: ValueRef<DecimalType<T>>,
+    b: ValueRef<DecimalType<T>>,
+    ctx: &mut EvalContext,
+    left: DecimalSize,
+    right: DecimalSize,
+    size: DecimalSize,
+    null_on_overflow: bool,
+) -> Value<AnyType> {
+    let zero = T::zero();
+    let two = T::from_i128(2);
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
+
+    // Note: the result scale is always larger than the left scale
+    let multiplier = T::e((right.scale + size.scale - left.scale) as u32);
+    apply_overflow_mode(a, b, ctx, size, null_on_overflow, move |a: T, b: T| {
+        // We are using round div here which follow snowflake's behavior: https://docs.snowflake.com/sql-reference/operators-arithmetic
+        // For example:
+        // round_div(5, 2) --> 3
+        // round_div(-5, 2) --> -3
+        // round_div(5, -2) --> -3
+        // round_div(-5, -2) --> 3
+        if std::intrinsics::unlikely(b == zero) {
+            return Outcome::DivideByZero;
+        }
+        let negative = (a < zero) != (b < zero);
+        let value = a.checked_mul(multiplier).and_then(|a| match negative {
+            true => a.checked_sub(b / two),
+            false => a.checked_add(b / two),
+        });
+        check_precision(value.map(|a| a / b), negative, min, max)
+    })
+}
+
+/// Handles the overflowed rows by `decimal_overflow_mode`, the `try_` variants always
+/// return NULL for them.
+fn apply_overflow_mode<T: DecimalNative>(
+    a: ValueRef<DecimalType<T>>,
+    b: ValueRef<DecimalType<T>>,
+    ctx: &mut EvalContext,
+    size: DecimalSize,
+    null_on_overflow: bool,
+    func: impl Fn(T, T) -> Outcome<T> + Copy + Send + Sync,
+) -> Value<AnyType> {
+    if null_on_overflow {
+        let result = vectorize_with_builder_2_arg::<
+            DecimalType<T>,
+            DecimalType<T>,
+            NullableType<DecimalType<T>>,
+        >(move |a, b, builder, _ctx| match func(a, b) {
+            Outcome::Value(value) => builder.push(value),
+            Outcome::Overflow { .. } | Outcome::DivideByZero => builder.push_null(),
+        })(a, b, ctx);
+
+        return match result {
+            Value::Scalar(None) => Value::Scalar(Scalar::Null),
+            Value::Scalar(Some(value)) => Value::Scalar(T::upcast_scalar(value, size)),
+            Value::Column(column) => Value::Column(Column::Nullable(Box::new(NullableColumn {
+                column: T::upcast_column(column.column, size),
+                validity: column.validity,
+            }))),
+        };
+    }
+
+    let saturate = ctx.func_ctx.decimal_overflow_mode == DecimalOverflowMode::Saturate;
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
+    let one = T::one();
+    vectorize_with_builder_2_arg::<DecimalType<T>, DecimalType<T>, DecimalType<T>>(
+        move |a, b, builder, ctx| match func(a, b) {
+            Outcome::Value(value) => builder.push(value),
+            Outcome::Overflow { negative } if saturate => {
+                builder.push(if negative { min } else { max })
+            }
+            Outcome::Overflow { .. } => {
+                ctx.set_error(
+                    builder.len(),
+                    concat!("Decimal overflow at line : ", line!()),
+                );
+                builder.push(one);
+            }
+            Outcome::DivideByZero => {
+                ctx.set_error(builder.len(), "divided by zero");
+                builder.push(one);
+            }
+        },
+    )(a, b, ctx)
+    .upcast_decimal(size)
 }
 
 #[inline(always)]
 fn domain_plus<T: Decimal>(
     lhs: &SimpleDomain<T>,
     rhs: &SimpleDomain<T>,
-    precision: u8,
+    _left: DecimalSize,
+    _right: DecimalSize,
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
     // For plus, the scale of the two operands must be the same.
-    let min = T::min_for_precision(precision);
-    let max = T::max_for_precision(precision);
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
     Some(SimpleDomain {
         min: lhs
             .min
@@ -167,11 +282,13 @@ fn domain_plus<T: Decimal>(
 fn domain_minus<T: Decimal>(
     lhs: &SimpleDomain<T>,
     rhs: &SimpleDomain<T>,
-    precision: u8,
+    _left: DecimalSize,
+    _right: DecimalSize,
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
     // For minus, the scale of the two operands must be the same.
-    let min = T::min_for_precision(precision);
-    let max = T::max_for_precision(precision);
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
     Some(SimpleDomain {
         min: lhs
             .min
@@ -188,10 +305,12 @@ fn domain_minus<T: Decimal>(
 fn domain_mul<T: Decimal>(
     lhs: &SimpleDomain<T>,
     rhs: &SimpleDomain<T>,
-    precision: u8,
+    _left: DecimalSize,
+    _right: DecimalSize,
+    size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
-    let min = T::min_for_precision(precision);
-    let max = T::max_for_precision(precision);
+    let min = T::min_for_precision(size.precision);
+    let max = T::max_for_precision(size.precision);
 
     let a = lhs
         .min
@@ -220,17 +339,19 @@ fn domain_mul<T: Decimal>(
 fn domain_div<T: Decimal>(
     _lhs: &SimpleDomain<T>,
     _rhs: &SimpleDomain<T>,
-    _precision: u8,
+    _left: DecimalSize,
+    _right: DecimalSize,
+    _size: DecimalSize,
 ) -> Option<SimpleDomain<T>> {
     // For div, we cannot determine the domain.
     None
 }
 
+/// Registers the decimal arithmetic, the `try_` variant returns NULL for the overflowed
+/// rows and the division by zero.
 macro_rules! register_decimal_binary_op {
-    ($registry: expr, $arithmetic_op: expr, $op: ident, $domain_op: ident, $default_domain: expr) => {
-        let name = format!("{:?}", $arithmetic_op).to_lowercase();
-
-        $registry.register_function_factory(&name, |_, args_type| {
+    ($registry: expr, $name: expr, $arithmetic_op: expr, $domain_op: ident, $default_domain: expr, $null_on_overflow: expr) => {
+        $registry.register_function_factory($name, move |_, args_type| {
             if args_type.len() != 2 {
                 return None;
             }
@@ -264,21 +385,26 @@ macro_rules! register_decimal_binary_op {
             )
             .ok()?;
 
+            let return_type = match $null_on_overflow {
+                true => DataType::Nullable(Box::new(DataType::Decimal(return_decimal_type))),
+                false => DataType::Decimal(return_decimal_type),
+            };
+
             let function = Function {
                 signature: FunctionSignature {
-                    name: format!("{:?}", $arithmetic_op).to_lowercase(),
+                    name: $name.to_string(),
                     args_type: vec![
                         DataType::Decimal(left.clone()),
                         DataType::Decimal(right.clone()),
                     ],
-                    return_type: DataType::Decimal(return_decimal_type),
+                    return_type,
                 },
                 eval: FunctionEval::Scalar {
                     calc_domain: Box::new(move |_ctx, d| {
                         let lhs = d[0].as_decimal();
                         let rhs = d[1].as_decimal();
 
-                        if lhs.is_none() || rhs.is_none() {
+                        if $null_on_overflow || lhs.is_none() || rhs.is_none() {
                             return FunctionDomain::Full;
                         }
 
@@ -292,12 +418,12 @@ macro_rules! register_decimal_binary_op {
                                 (
                                     DecimalDomain::Decimal128(d1, _),
                                     DecimalDomain::Decimal128(d2, _),
-                                ) => $domain_op(&d1, &d2, size.precision)
+                                ) => $domain_op(&d1, &d2, left.size(), right.size(), size)
                                     .map(|d| DecimalDomain::Decimal128(d, size)),
                                 (
                                     DecimalDomain::Decimal256(d1, _),
                                     DecimalDomain::Decimal256(d2, _),
-                                ) => $domain_op(&d1, &d2, size.precision)
+                                ) => $domain_op(&d1, &d2, left.size(), right.size(), size)
                                     .map(|d| DecimalDomain::Decimal256(d, size)),
                                 _ => {
                                     unreachable!("unreachable decimal domain {:?} /{:?}", lhs, rhs)
@@ -307,19 +433,27 @@ macro_rules! register_decimal_binary_op {
                         .map(|d| FunctionDomain::Domain(Domain::Decimal(d)))
                         .unwrap_or($default_domain)
                     }),
-                    eval: Box::new(move |args, ctx| {
-                        let res = op_decimal!(
+                    eval: Box::new(move |args, ctx| match left {
+                        DecimalDataType::Decimal128(_) => eval_decimal::<i128>(
+                            &args[0],
+                            &args[1],
+                            ctx,
+                            $arithmetic_op,
+                            left.size(),
+                            right.size(),
+                            return_decimal_type.size(),
+                            $null_on_overflow,
+                        ),
+                        DecimalDataType::Decimal256(_) => eval_decimal::<i256>(
                             &args[0],
                             &args[1],
                             ctx,
-                            left,
-                            right,
-                            return_decimal_type,
-                            $op,
-                            $arithmetic_op
-                        );
-
-                        res
+                            $arithmetic_op,
+                            left.size(),
+                            right.size(),
+                            return_decimal_type.size(),
+                            $null_on_overflow,
+                        ),
                     }),
                 },
             };
@@ -333,34 +467,44 @@ macro_rules! register_decimal_binary_op {
 }
 
 pub(crate) fn register_decimal_arithmetic(registry: &mut FunctionRegistry) {
-    // TODO checked overflow by default
-    register_decimal_binary_op!(
-        registry,
-        ArithmeticOp::Plus,
-        add,
-        domain_plus,
-        FunctionDomain::Full
-    );
-
-    register_decimal_binary_op!(
-        registry,
-        ArithmeticOp::Minus,
-        sub,
-        domain_minus,
-        FunctionDomain::Full
-    );
-    register_decimal_binary_op!(
-        registry,
-        ArithmeticOp::Divide,
-        div,
-        domain_div,
-        FunctionDomain::MayThrow
-    );
-    register_decimal_binary_op!(
-        registry,
-        ArithmeticOp::Multiply,
-        mul,
-        domain_mul,
-        FunctionDomain::Full
-    );
+    for (name, null_on_overflow) in [("plus", false), ("try_plus", true)] {
+        register_decimal_binary_op!(
+            registry,
+            name,
+            ArithmeticOp::Plus,
+            domain_plus,
+            FunctionDomain::Full,
+            null_on_overflow
+        );
+    }
+    for (name, null_on_overflow) in [("minus", false), ("try_minus", true)] {
+        register_decimal_binary_op!(
+            registry,
+            name,
+            ArithmeticOp::Minus,
+            domain_minus,
+            FunctionDomain::Full,
+            null_on_overflow
+        );
+    }
+    for (name, null_on_overflow) in [("divide", false), ("try_divide", true)] {
+        register_decimal_binary_op!(
+            registry,
+            name,
+            ArithmeticOp::Divide,
+            domain_div,
+            FunctionDomain::MayThrow,
+            null_on_overflow
+        );
+    }
+    for (name, null_on_overflow) in [("multiply", false), ("try_multiply", true)] {
+        register_decimal_binary_op!(
+            registry,
+            name,
+            ArithmeticOp::Multiply,
+            domain_mul,
+            FunctionDomain::Full,
+            null_on_overflow
+        );
+    }
 }
@@ -15,6 +15,7 @@
 use databend_common_exception::ErrorCode;
 use databend_common_expression::types::geometry::GeometryType;
 use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
@@ -342,6 +343,31 @@ pub fn register(registry: &mut FunctionRegistry) {
         ),
     );
 
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+            |left_exp, right_exp, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+                let params = &vec![left_exp, right_exp];
+                let result = binary_to_geos(params)
+                    .and_then(|geos| geos[0].contains(&geos[1]).map_err(|e| e.to_string()));
+                match result {
+                    Ok(contains) => builder.push(contains),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), ErrorCode::GeometryError(e).to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
     registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
         "st_geometryfromwkb",
         |_, _| FunctionDomain::MayThrow,
@@ -0,0 +1,158 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::SimpleDomainCmp;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use databend_common_hashtable::HashSet;
+use databend_common_hashtable::HashtableKeyable;
+use databend_common_hashtable::HashtableLike;
+use databend_common_hashtable::ShortStringHashSet;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // `in_list(v1, v2, ...)(arg)` returns true if `arg` equals to any of the params.
+    // The params are the values of an IN list, which have been cast to the type of `arg`,
+    // so the hash set of them is built once when the function is created instead of
+    // once per block.
+    registry.register_function_factory("in_list", |params, args_type| {
+        if args_type.len() != 1 || params.is_empty() {
+            return None;
+        }
+        let data_type = args_type[0].remove_nullable();
+        let list = build_list(&data_type, params)?;
+
+        let function = with_number_mapped_type!(|NUM_TYPE| match &data_type {
+            DataType::Number(NumberDataType::NUM_TYPE) => {
+                in_list_function::<NumberType<NUM_TYPE>>(data_type.clone(), list)
+            }
+            DataType::Date => in_list_function::<DateType>(data_type.clone(), list),
+            DataType::Timestamp => in_list_function::<TimestampType>(data_type.clone(), list),
+            DataType::String => string_in_list_function(list),
+            _ => return None,
+        });
+
+        if args_type[0].is_nullable() {
+            Some(Arc::new(function.passthrough_nullable()))
+        } else {
+            Some(Arc::new(function))
+        }
+    });
+}
+
+/// Returns true if the values of the data type can be evaluated by `in_list`.
+pub fn in_list_supported_type(data_type: &DataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        DataType::Number(_) | DataType::Date | DataType::Timestamp | DataType::String
+    )
+}
+
+fn build_list(data_type: &DataType, params: &[Scalar]) -> Option<Column> {
+    let mut builder = ColumnBuilder::with_capacity(data_type, params.len());
+    for param in params {
+        if param.as_ref().infer_data_type() != *data_type {
+            return None;
+        }
+        builder.push(param.as_ref());
+    }
+    Some(builder.build())
+}
+
+fn in_list_function<T: ArgType>(data_type: DataType, list: Column) -> Function
+where
+    T::Scalar: HashtableKeyable,
+    T::Domain: SimpleDomainCmp,
+{
+    let list_domain = T::try_downcast_domain(&list.domain()).unwrap();
+    let list = T::try_downcast_column(&list).unwrap();
+    let mut set = HashSet::with_capacity(T::column_len(&list));
+    for value in T::iter_column(&list) {
+        let _ = set.set_insert(T::to_owned_scalar(value));
+    }
+
+    Function {
+        signature: FunctionSignature {
+            name: "in_list".to_string(),
+            args_type: vec![data_type],
+            return_type: DataType::Boolean,
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(move |_, args_domain| {
+                let domain = T::try_downcast_domain(&args_domain[0]).unwrap();
+                list_domain
+                    .domain_contains(&domain)
+                    .map(BooleanType::upcast_domain)
+            }),
+            eval: Box::new(move |args, _| {
+                let arg = args[0].try_downcast::<T>().unwrap();
+                eval_in_list::<T>(arg, |value| set.contains(&T::to_owned_scalar(value)))
+            }),
+        },
+    }
+}
+
+fn string_in_list_function(list: Column) -> Function {
+    let list_domain = StringType::try_downcast_domain(&list.domain()).unwrap();
+    let list = StringType::try_downcast_column(&list).unwrap();
+    let mut set = ShortStringHashSet::<[u8]>::with_capacity(list.len(), Arc::new(Bump::new()));
+    for value in StringType::iter_column(&list) {
+        // The key is copied into the arena of the set.
+        let _ = unsafe { set.insert_and_entry(value.as_bytes()) };
+    }
+
+    Function {
+        signature: FunctionSignature {
+            name: "in_list".to_string(),
+            args_type: vec![DataType::String],
+            return_type: DataType::Boolean,
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(move |_, args_domain| {
+                let domain = StringType::try_downcast_domain(&args_domain[0]).unwrap();
+                list_domain
+                    .domain_contains(&domain)
+                    .map(BooleanType::upcast_domain)
+            }),
+            eval: Box::new(move |args, _| {
+                let arg = args[0].try_downcast::<StringType>().unwrap();
+                eval_in_list::<StringType>(arg, |value| set.get(value.as_bytes()).is_some())
+            }),
+        },
+    }
+}
+
+fn eval_in_list<T: ValueType>(
+    arg: ValueRef<T>,
+    contains: impl for<'a> Fn(T::ScalarRef<'a>) -> bool,
+) -> Value<AnyType> {
+    match arg {
+        ValueRef::Scalar(value) => Value::Scalar(Scalar::Boolean(contains(value))),
+        ValueRef::Column(column) => {
+            let result = BooleanType::column_from_iter(T::iter_column(&column).map(contains), &[]);
+            Value::Column(BooleanType::upcast_column(result))
+        }
+    }
+}
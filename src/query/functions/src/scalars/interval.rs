@@ -0,0 +1,359 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::interval::Interval;
+use databend_common_expression::types::interval::MICROS_IN_AN_HOUR;
+use databend_common_expression::types::interval::MICROS_IN_A_MINUTE;
+use databend_common_expression::types::number::Int64Type;
+use databend_common_expression::types::timestamp::MICROS_IN_A_SEC;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::date_helper::AddIntervalImpl;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+    register_interval_to_string(registry);
+
+    // INTERVAL 1 DAY
+    // to_days(1)
+    register_interval_constructors(registry);
+
+    // timestamp + INTERVAL '1 day'
+    register_interval_add_sub(registry);
+    register_interval_mul_div(registry);
+    register_interval_cmp(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`, {}", e),
+                    );
+                    output.push(Interval::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.data, "{}", val).unwrap();
+            output.commit_row();
+        }),
+    );
+}
+
+fn register_interval_constructors(registry: &mut FunctionRegistry) {
+    register_interval_constructor(registry, "to_years", |n| {
+        Some(Interval::new(i32::try_from(n.checked_mul(12)?).ok()?, 0, 0))
+    });
+    register_interval_constructor(registry, "to_quarters", |n| {
+        Some(Interval::new(i32::try_from(n.checked_mul(3)?).ok()?, 0, 0))
+    });
+    register_interval_constructor(registry, "to_months", |n| {
+        Some(Interval::new(i32::try_from(n).ok()?, 0, 0))
+    });
+    register_interval_constructor(registry, "to_weeks", |n| {
+        Some(Interval::new(0, i32::try_from(n.checked_mul(7)?).ok()?, 0))
+    });
+    register_interval_constructor(registry, "to_days", |n| {
+        Some(Interval::new(0, i32::try_from(n).ok()?, 0))
+    });
+    register_interval_constructor(registry, "to_hours", |n| {
+        Some(Interval::new(0, 0, n.checked_mul(MICROS_IN_AN_HOUR)?))
+    });
+    register_interval_constructor(registry, "to_minutes", |n| {
+        Some(Interval::new(0, 0, n.checked_mul(MICROS_IN_A_MINUTE)?))
+    });
+
+    registry.register_passthrough_nullable_1_arg::<Float64Type, IntervalType, _, _>(
+        "to_seconds",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<Float64Type, IntervalType>(|val, output, ctx| {
+            let micros = (val.0 * MICROS_IN_A_SEC as f64).round();
+            if micros.is_finite() && micros >= i64::MIN as f64 && micros <= i64::MAX as f64 {
+                output.push(Interval::new(0, 0, micros as i64));
+            } else {
+                ctx.set_error(
+                    output.len(),
+                    format!("interval to_seconds({val}) is out of range"),
+                );
+                output.push(Interval::default());
+            }
+        }),
+    );
+}
+
+fn register_interval_constructor(
+    registry: &mut FunctionRegistry,
+    name: &'static str,
+    func: fn(i64) -> Option<Interval>,
+) {
+    registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+        name,
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<Int64Type, IntervalType>(
+            move |val, output, ctx| match func(val) {
+                Some(interval) => output.push(interval),
+                None => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("interval {name}({val}) is out of range"),
+                    );
+                    output.push(Interval::default());
+                }
+            },
+        ),
+    );
+}
+
+fn register_interval_add_sub(registry: &mut FunctionRegistry) {
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(
+                        AddIntervalImpl::eval_timestamp(ts, interval, ctx.func_ctx.tz),
+                        output,
+                        ctx,
+                    )
+                },
+            ),
+        );
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| {
+                    push_timestamp(
+                        AddIntervalImpl::eval_timestamp(ts, interval, ctx.func_ctx.tz),
+                        output,
+                        ctx,
+                    )
+                },
+            ),
+        );
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| {
+                push_timestamp(
+                    AddIntervalImpl::eval_date(date, interval, ctx.func_ctx.tz),
+                    output,
+                    ctx,
+                )
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, DateType, TimestampType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, DateType, TimestampType>(
+            |interval, date, output, ctx| {
+                push_timestamp(
+                    AddIntervalImpl::eval_date(date, interval, ctx.func_ctx.tz),
+                    output,
+                    ctx,
+                )
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| push_interval(lhs.checked_add(&rhs), output, ctx),
+        ),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| match interval.checked_neg() {
+                    Some(interval) => push_timestamp(
+                        AddIntervalImpl::eval_timestamp(ts, interval, ctx.func_ctx.tz),
+                        output,
+                        ctx,
+                    ),
+                    None => push_timestamp(Err(interval_overflow()), output, ctx),
+                },
+            ),
+        );
+    registry.register_passthrough_nullable_2_arg::<DateType, IntervalType, TimestampType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<DateType, IntervalType, TimestampType>(
+            |date, interval, output, ctx| match interval.checked_neg() {
+                Some(interval) => push_timestamp(
+                    AddIntervalImpl::eval_date(date, interval, ctx.func_ctx.tz),
+                    output,
+                    ctx,
+                ),
+                None => push_timestamp(Err(interval_overflow()), output, ctx),
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |lhs, rhs, output, ctx| push_interval(lhs.checked_sub(&rhs), output, ctx),
+        ),
+    );
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|val, output, ctx| {
+            push_interval(val.checked_neg(), output, ctx)
+        }),
+    );
+}
+
+fn register_interval_mul_div(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |interval, factor, output, ctx| {
+                push_interval(interval.checked_mul(factor.0), output, ctx)
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<Float64Type, IntervalType, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Float64Type, IntervalType, IntervalType>(
+            |factor, interval, output, ctx| {
+                push_interval(interval.checked_mul(factor.0), output, ctx)
+            },
+        ),
+    );
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Float64Type, IntervalType, _, _>(
+        "divide",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Float64Type, IntervalType>(
+            |interval, divisor, output, ctx| {
+                if divisor.0 == 0.0 {
+                    ctx.set_error(output.len(), "divided by zero");
+                    output.push(Interval::default());
+                } else {
+                    push_interval(interval.checked_div(divisor.0), output, ctx)
+                }
+            },
+        ),
+    );
+}
+
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
+
+fn interval_overflow() -> String {
+    "Overflow on interval arithmetic.".to_string()
+}
+
+fn push_interval(res: Option<Interval>, output: &mut Vec<Interval>, ctx: &mut EvalContext) {
+    match res {
+        Some(interval) => output.push(interval),
+        None => {
+            ctx.set_error(output.len(), interval_overflow());
+            output.push(Interval::default());
+        }
+    }
+}
+
+fn push_timestamp(res: Result<i64, String>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+    match res {
+        Ok(ts) => output.push(ts),
+        Err(err) => {
+            ctx.set_error(output.len(), err);
+            output.push(0);
+        }
+    }
+}
@@ -40,6 +40,38 @@ use crate::scalars::decimal::register_decimal_math;
 pub fn register(registry: &mut FunctionRegistry) {
     register_decimal_math(registry);
 
+    registry.register_doc("abs", "Returns the absolute value of x.", "SELECT ABS(-5)");
+    registry.register_doc(
+        "ceil",
+        "Rounds the number up to the nearest integer.",
+        "SELECT CEIL(-1.23)",
+    );
+    registry.register_doc(
+        "floor",
+        "Rounds the number down to the nearest integer.",
+        "SELECT FLOOR(1.23)",
+    );
+    registry.register_doc(
+        "round",
+        "Rounds the number to d decimal places, d defaults to 0.",
+        "SELECT ROUND(1.298, 1)",
+    );
+    registry.register_doc(
+        "sqrt",
+        "Returns the square root of a nonnegative number x.",
+        "SELECT SQRT(4)",
+    );
+    registry.register_doc(
+        "pow",
+        "Returns the value of x to the power of y.",
+        "SELECT POW(2, 3)",
+    );
+    registry.register_doc(
+        "exp",
+        "Returns the value of e raised to the power of x.",
+        "SELECT EXP(2)",
+    );
+
     registry.register_1_arg::<NumberType<F64>, NumberType<F64>, _, _>(
         "sin",
         |_, _| {
@@ -28,16 +28,24 @@ mod geo;
 mod geo_h3;
 mod geometry;
 mod hash;
+mod in_list;
+mod interval;
 mod map;
 mod math;
 mod other;
 mod string;
 mod string_multi_args;
+mod template;
 mod tuple;
+mod url;
 mod variant;
 mod vector;
 
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use in_list::in_list_supported_type;
+pub use other::set_ipv4_geoid_resolver;
+pub use other::Ipv4GeoIdResolver;
+pub use template::number_format_decimal_size;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -61,4 +69,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     vector::register(registry);
     bitmap::register(registry);
     geometry::register(registry);
+    url::register(registry);
+    template::register(registry);
+    interval::register(registry);
+    in_list::register(registry);
 }
@@ -13,7 +13,9 @@
 // limitations under the License.
 
 use std::io::Write;
+use std::net::IpAddr;
 use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -26,10 +28,13 @@ use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::number::Float64Type;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt32Type;
+use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::number::F64;
 use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::GenericType;
@@ -44,6 +49,7 @@ use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
 use databend_common_expression::Column;
 use databend_common_expression::Domain;
 use databend_common_expression::EvalContext;
@@ -57,6 +63,7 @@ use databend_common_expression::Scalar;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::Value;
 use databend_common_expression::ValueRef;
+use once_cell::sync::OnceCell;
 use ordered_float::OrderedFloat;
 use rand::Rng;
 use rand::SeedableRng;
@@ -71,6 +78,9 @@ pub fn register(registry: &mut FunctionRegistry) {
 
     register_inet_aton(registry);
     register_inet_ntoa(registry);
+    register_ipv6(registry);
+    register_ipv4_to_geoid(registry);
+    register_ip_in_range(registry);
     register_run_diff(registry);
     register_grouping(registry);
 
@@ -297,6 +307,218 @@ fn register_inet_ntoa(registry: &mut FunctionRegistry) {
     }
 }
 
+/// IPv6 addresses are represented by their 16 bytes in network order as `Binary`,
+/// and IPv4 addresses are mapped to IPv6 addresses as `::ffff:a.b.c.d`.
+fn register_ipv6(registry: &mut FunctionRegistry) {
+    registry.register_aliases("ipv6_string_to_num", &["inet6_aton"]);
+    registry.register_aliases("try_ipv6_string_to_num", &["try_inet6_aton"]);
+    registry.register_aliases("ipv6_num_to_string", &["inet6_ntoa"]);
+    registry.register_aliases("try_ipv6_num_to_string", &["try_inet6_ntoa"]);
+
+    registry.register_passthrough_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "ipv6_string_to_num",
+        |_, _| FunctionDomain::MayThrow,
+        eval_ipv6_string_to_num,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, BinaryType, _, _>(
+        "try_ipv6_string_to_num",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_ipv6_string_to_num),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "ipv6_num_to_string",
+        |_, _| FunctionDomain::MayThrow,
+        eval_ipv6_num_to_string,
+    );
+    registry.register_combine_nullable_1_arg::<BinaryType, StringType, _, _>(
+        "try_ipv6_num_to_string",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_ipv6_num_to_string),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<Int64Type, BinaryType, _, _>(
+        "ipv4_to_ipv6",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<Int64Type, BinaryType>(|val, output, ctx| {
+            match num_traits::cast::cast::<i64, u32>(val) {
+                Some(val) => output.put_slice(&Ipv4Addr::from(val).to_ipv6_mapped().octets()),
+                None => ctx.set_error(
+                    output.len(),
+                    format!("Failed to parse '{}' into a IPV4 address", val),
+                ),
+            }
+            output.commit_row();
+        }),
+    );
+
+    fn eval_ipv6_string_to_num(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<BinaryType> {
+        vectorize_with_builder_1_arg::<StringType, BinaryType>(|addr_str, output, ctx| {
+            match addr_str.parse::<IpAddr>() {
+                Ok(addr) => output.put_slice(&ip_to_ipv6(addr).octets()),
+                Err(err) => ctx.set_error(output.len(), err.to_string()),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+
+    fn eval_ipv6_num_to_string(
+        val: ValueRef<BinaryType>,
+        ctx: &mut EvalContext,
+    ) -> Value<StringType> {
+        vectorize_with_builder_1_arg::<BinaryType, StringType>(|val, output, ctx| {
+            match <[u8; 16]>::try_from(val) {
+                Ok(octets) => output.put_str(&Ipv6Addr::from(octets).to_string()),
+                Err(_) => ctx.set_error(
+                    output.len(),
+                    format!(
+                        "Failed to parse a binary of {} bytes into a IPV6 address, expected 16 bytes",
+                        val.len()
+                    ),
+                ),
+            }
+            output.commit_row();
+        })(val, ctx)
+    }
+}
+
+/// Maps an IPv4 address to the id of its geo region.
+///
+/// No geo database is shipped, the resolver is installed by the embedding service,
+/// e.g. from a GeoIP database, with `set_ipv4_geoid_resolver`.
+pub trait Ipv4GeoIdResolver: Send + Sync {
+    /// Returns `None` if the address is not in any region.
+    fn geoid(&self, addr: Ipv4Addr) -> Option<u64>;
+}
+
+static IPV4_GEOID_RESOLVER: OnceCell<Arc<dyn Ipv4GeoIdResolver>> = OnceCell::new();
+
+/// Installs the resolver of `ipv4_to_geoid`, returns `false` if one is already installed.
+pub fn set_ipv4_geoid_resolver(resolver: Arc<dyn Ipv4GeoIdResolver>) -> bool {
+    IPV4_GEOID_RESOLVER.set(resolver).is_ok()
+}
+
+fn register_ipv4_to_geoid(registry: &mut FunctionRegistry) {
+    registry.register_combine_nullable_1_arg::<Int64Type, UInt64Type, _, _>(
+        "ipv4_to_geoid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<Int64Type, NullableType<UInt64Type>>(|val, output, ctx| {
+            let Some(resolver) = IPV4_GEOID_RESOLVER.get() else {
+                ctx.set_error(
+                    output.len(),
+                    "no geo database is configured for ipv4_to_geoid",
+                );
+                output.push_null();
+                return;
+            };
+            match num_traits::cast::cast::<i64, u32>(val) {
+                Some(val) => match resolver.geoid(Ipv4Addr::from(val)) {
+                    Some(geoid) => output.push(geoid),
+                    None => output.push_null(),
+                },
+                None => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("Failed to parse '{}' into a IPV4 address", val),
+                    );
+                    output.push_null();
+                }
+            }
+        }),
+    );
+}
+
+fn register_ip_in_range(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
+        "ip_in_range",
+        |_, _, _| FunctionDomain::MayThrow,
+        |addr, cidr, ctx| match cidr {
+            // the range is usually a constant, parse it only once.
+            ValueRef::Scalar(cidr) => {
+                match IpRange::parse(cidr) {
+                    Ok(range) => vectorize_with_builder_1_arg::<StringType, BooleanType>(
+                        |addr, output, ctx| match addr.parse::<IpAddr>() {
+                            Ok(addr) => output.push(range.contains(addr)),
+                            Err(err) => {
+                                ctx.set_error(output.len(), err.to_string());
+                                output.push(false);
+                            }
+                        },
+                    )(addr, ctx),
+                    Err(err) => {
+                        ctx.set_error(0, err);
+                        Value::Scalar(false)
+                    }
+                }
+            }
+            cidr => vectorize_with_builder_2_arg::<StringType, StringType, BooleanType>(
+                |addr, cidr, output, ctx| match (IpRange::parse(cidr), addr.parse::<IpAddr>()) {
+                    (Ok(range), Ok(addr)) => output.push(range.contains(addr)),
+                    (Err(err), _) => {
+                        ctx.set_error(output.len(), err);
+                        output.push(false);
+                    }
+                    (_, Err(err)) => {
+                        ctx.set_error(output.len(), err.to_string());
+                        output.push(false);
+                    }
+                },
+            )(addr, cidr, ctx),
+        },
+    );
+}
+
+#[inline]
+fn ip_to_ipv6(addr: IpAddr) -> Ipv6Addr {
+    match addr {
+        IpAddr::V4(addr) => addr.to_ipv6_mapped(),
+        IpAddr::V6(addr) => addr,
+    }
+}
+
+/// An IPv4 or IPv6 network in CIDR notation, e.g. `192.168.0.0/16` or `2001:db8::/32`.
+struct IpRange {
+    network: u128,
+    mask: u128,
+    is_ipv4: bool,
+}
+
+impl IpRange {
+    fn parse(cidr: &str) -> Result<IpRange, String> {
+        let invalid = || format!("Failed to parse '{}' into a CIDR range", cidr);
+        let (addr, prefix) = cidr.split_once('/').ok_or_else(invalid)?;
+        let addr = addr.parse::<IpAddr>().map_err(|_| invalid())?;
+        let prefix = prefix.parse::<u32>().map_err(|_| invalid())?;
+
+        let (is_ipv4, max_prefix) = match addr {
+            IpAddr::V4(_) => (true, 32),
+            IpAddr::V6(_) => (false, 128),
+        };
+        if prefix > max_prefix {
+            return Err(invalid());
+        }
+
+        // IPv4 ranges are matched in the IPv4-mapped IPv6 space.
+        let prefix = prefix + (128 - max_prefix);
+        let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+        Ok(IpRange {
+            network: u128::from(ip_to_ipv6(addr)) & mask,
+            mask,
+            is_ipv4,
+        })
+    }
+
+    fn contains(&self, addr: IpAddr) -> bool {
+        if self.is_ipv4 != addr.is_ipv4() {
+            return false;
+        }
+        u128::from(ip_to_ipv6(addr)) & self.mask == self.network
+    }
+}
+
 macro_rules! register_simple_domain_type_run_diff {
     ($registry:ident, $T:ty, $O:ty, $source_primitive_type:ty, $zero:expr) => {
         $registry.register_passthrough_nullable_1_arg::<$T, $O, _, _>(
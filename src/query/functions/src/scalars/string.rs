@@ -52,11 +52,37 @@ pub fn register(registry: &mut FunctionRegistry) {
         "substring_utf8",
     ]);
 
+    registry.register_doc(
+        "upper",
+        "Returns the string with all characters changed to uppercase.",
+        "SELECT UPPER('databend')",
+    );
+    registry.register_doc(
+        "lower",
+        "Returns the string with all characters changed to lowercase.",
+        "SELECT LOWER('DATABEND')",
+    );
+    registry.register_doc(
+        "length",
+        "Returns the length of the string in characters.",
+        "SELECT LENGTH('databend')",
+    );
+    registry.register_doc(
+        "substr",
+        "Returns the substring starting at position pos, with at most len characters.",
+        "SELECT SUBSTR('databend', 5, 4)",
+    );
+    registry.register_doc(
+        "replace",
+        "Returns the string with all occurrences of from_str replaced by to_str.",
+        "SELECT REPLACE('databend', 'bend', 'base')",
+    );
+
     registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
         "upper",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len(),
+            |col| col.total_bytes_len(),
             |val, output, _| {
                 for ch in val.chars() {
                     if ch.is_ascii() {
@@ -76,7 +102,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "lower",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len(),
+            |col| col.total_bytes_len(),
             |val, output, _| {
                 for ch in val.chars() {
                     if ch.is_ascii() {
@@ -104,14 +130,13 @@ pub fn register(registry: &mut FunctionRegistry) {
         |val, _| match val {
             ValueRef::Scalar(s) => Value::Scalar(s.len() as u64),
             ValueRef::Column(c) => {
-                let diffs = c
-                    .offsets()
+                let lengths = c
+                    .views()
                     .iter()
-                    .zip(c.offsets().iter().skip(1))
-                    .map(|(a, b)| b - a)
+                    .map(|view| view.length as u64)
                     .collect::<Vec<_>>();
 
-                Value::Column(diffs.into())
+                Value::Column(lengths.into())
             }
         },
     );
@@ -336,7 +361,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "quote",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len() * 2,
+            |col| col.total_bytes_len() * 2,
             |val, output, _| {
                 for ch in val.chars() {
                     match ch {
@@ -360,7 +385,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "reverse",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len(),
+            |col| col.total_bytes_len(),
             |val, output, _| {
                 for char in val.chars().rev() {
                     output.put_char(char);
@@ -390,7 +415,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "ltrim",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len(),
+            |col| col.total_bytes_len(),
             |val, output, _| {
                 output.put_str(val.trim_start());
                 output.commit_row();
@@ -402,7 +427,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "rtrim",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len(),
+            |col| col.total_bytes_len(),
             |val, output, _| {
                 output.put_str(val.trim_end());
                 output.commit_row();
@@ -414,7 +439,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "trim",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len(),
+            |col| col.total_bytes_len(),
             |val, output, _| {
                 output.put_str(val.trim());
                 output.commit_row();
@@ -426,7 +451,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "trim_leading",
         |_, _, _| FunctionDomain::Full,
         vectorize_string_to_string_2_arg(
-            |col, _| col.data().len(),
+            |col, _| col.total_bytes_len(),
             |val, trim_str, _, output| {
                 if trim_str.is_empty() {
                     output.put_str(val);
@@ -444,7 +469,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "trim_trailing",
         |_, _, _| FunctionDomain::Full,
         vectorize_string_to_string_2_arg(
-            |col, _| col.data().len(),
+            |col, _| col.total_bytes_len(),
             |val, trim_str, _, output| {
                 if trim_str.is_empty() {
                     output.put_str(val);
@@ -462,7 +487,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "trim_both",
         |_, _, _| FunctionDomain::Full,
         vectorize_string_to_string_2_arg(
-            |col, _| col.data().len(),
+            |col, _| col.total_bytes_len(),
             |val, trim_str, _, output| {
                 if trim_str.is_empty() {
                     output.put_str(val);
@@ -489,7 +514,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "to_hex",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| col.data().len() * 2,
+            |col| col.total_bytes_len() * 2,
             |val, output, _| {
                 let old_len = output.data.len();
                 let extra_len = val.len() * 2;
@@ -578,7 +603,7 @@ pub fn register(registry: &mut FunctionRegistry) {
         "soundex",
         |_, _| FunctionDomain::Full,
         vectorize_string_to_string(
-            |col| usize::max(col.data().len(), 4 * col.len()),
+            |col| usize::max(col.total_bytes_len(), 4 * col.len()),
             soundex::soundex,
         ),
     );
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_expression::passthrough_nullable;
+use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::NumberScalar;
@@ -417,6 +418,64 @@ pub fn register(registry: &mut FunctionRegistry) {
             Some(Arc::new(f))
         }
     });
+
+    registry.register_function_factory("regexp_extract_all", |_, args_type| {
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let args_type = match args_type.len() {
+            2 => vec![DataType::String; 2],
+            3 => vec![
+                DataType::String,
+                DataType::String,
+                DataType::Number(NumberDataType::Int64),
+            ],
+            _ => return None,
+        };
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: "regexp_extract_all".to_string(),
+                args_type,
+                return_type: DataType::Array(Box::new(DataType::String)),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(regexp_extract_all_fn),
+            },
+        };
+
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
+
+    registry.register_function_factory("regexp_split_to_array", |_, args_type| {
+        let has_null = args_type.iter().any(|t| t.is_nullable_or_null());
+        let args_type = match args_type.len() {
+            2 => vec![DataType::String; 2],
+            3 => vec![DataType::String; 3],
+            _ => return None,
+        };
+
+        let f = Function {
+            signature: FunctionSignature {
+                name: "regexp_split_to_array".to_string(),
+                args_type,
+                return_type: DataType::Array(Box::new(DataType::String)),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(regexp_split_to_array_fn),
+            },
+        };
+
+        if has_null {
+            Some(Arc::new(f.passthrough_nullable()))
+        } else {
+            Some(Arc::new(f))
+        }
+    });
 }
 
 fn concat_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
@@ -491,6 +550,7 @@ fn regexp_instr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<A
         }
         _ => None,
     };
+    let mut cache = regexp::RegexpCache::default();
 
     for idx in 0..size {
         let mut source = unsafe { source_arg.index_unchecked(idx) };
@@ -519,22 +579,17 @@ fn regexp_instr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<A
             continue;
         }
 
-        let mut local_re = None;
-        if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_instr", pat, mt) {
-                Ok(re) => {
-                    local_re = Some(re);
-                }
+        let re = match &cached_reg {
+            Some(re) => re,
+            None => match cache.get_or_build("regexp_instr", pat, mt) {
+                Ok(re) => re,
                 Err(err) => {
                     ctx.set_error(builder.len(), err);
                     builder.push(0);
                     continue;
                 }
-            }
+            },
         };
-        let re = cached_reg
-            .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
 
         let pos = pos.unwrap_or(1);
         let occur = occur.unwrap_or(1);
@@ -581,6 +636,7 @@ fn regexp_like_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<An
         }
         _ => None,
     };
+    let mut cache = regexp::RegexpCache::default();
 
     let size = len.unwrap_or(1);
     let mut builder = MutableBitmap::with_capacity(size);
@@ -591,22 +647,17 @@ fn regexp_like_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<An
             .as_ref()
             .map(|mt_arg| unsafe { mt_arg.index_unchecked(idx) });
 
-        let mut local_re = None;
-        if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_like", pat, mt) {
-                Ok(re) => {
-                    local_re = Some(re);
-                }
+        let re = match &cached_reg {
+            Some(re) => re,
+            None => match cache.get_or_build("regexp_like", pat, mt) {
+                Ok(re) => re,
                 Err(err) => {
                     ctx.set_error(builder.len(), err);
                     builder.push(false);
                     continue;
                 }
-            }
+            },
         };
-        let re = cached_reg
-            .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
         builder.push(re.is_match(source));
     }
     match len {
@@ -658,6 +709,7 @@ fn regexp_replace_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value
         }
         _ => None,
     };
+    let mut cache = regexp::RegexpCache::default();
 
     for idx in 0..size {
         let source = unsafe { source_arg.index_unchecked(idx) };
@@ -696,22 +748,17 @@ fn regexp_replace_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value
             continue;
         }
 
-        let mut local_re = None;
-        if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_replace", pat, mt) {
-                Ok(re) => {
-                    local_re = Some(re);
-                }
+        let re = match &cached_reg {
+            Some(re) => re,
+            None => match cache.get_or_build("regexp_replace", pat, mt) {
+                Ok(re) => re,
                 Err(err) => {
                     ctx.set_error(builder.len(), err);
                     StringType::push_default(&mut builder);
                     continue;
                 }
-            }
+            },
         };
-        let re = cached_reg
-            .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
 
         let pos = pos.unwrap_or(1);
         let occur = occur.unwrap_or(0);
@@ -764,6 +811,7 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
         }
         _ => None,
     };
+    let mut cache = regexp::RegexpCache::default();
 
     let size = len.unwrap_or(1);
     let mut builder = StringColumnBuilder::with_capacity(size, 0);
@@ -797,23 +845,18 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
         let pos = pos.unwrap_or(1);
         let occur = occur.unwrap_or(1);
 
-        let mut local_re = None;
-        if cached_reg.is_none() {
-            match regexp::build_regexp_from_pattern("regexp_substr", pat, mt) {
-                Ok(re) => {
-                    local_re = Some(re);
-                }
+        let re = match &cached_reg {
+            Some(re) => re,
+            None => match cache.get_or_build("regexp_substr", pat, mt) {
+                Ok(re) => re,
                 Err(err) => {
                     ctx.set_error(builder.len(), err);
                     StringType::push_default(&mut builder);
                     validity.push(false);
                     continue;
                 }
-            }
+            },
         };
-        let re = cached_reg
-            .as_ref()
-            .unwrap_or_else(|| local_re.as_ref().unwrap());
 
         let substr = regexp::regexp_substr(source, re, pos, occur);
         match substr {
@@ -848,11 +891,176 @@ fn regexp_substr_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<
     }
 }
 
+fn regexp_extract_all_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let source_arg = args[0].try_downcast::<StringType>().unwrap();
+    let pat_arg = args[1].try_downcast::<StringType>().unwrap();
+    let group_arg = if args.len() >= 3 {
+        Some(args[2].try_downcast::<Int64Type>().unwrap())
+    } else {
+        None
+    };
+
+    let cached_reg = match &pat_arg {
+        ValueRef::Scalar(pat) => {
+            regexp::build_regexp_from_pattern("regexp_extract_all", pat, None).ok()
+        }
+        _ => None,
+    };
+    let mut cache = regexp::RegexpCache::default();
+
+    let size = len.unwrap_or(1);
+    let mut builder = ArrayColumnBuilder::<StringType>::with_capacity(size, 0, &[]);
+    for idx in 0..size {
+        let source = unsafe { source_arg.index_unchecked(idx) };
+        let pat = unsafe { pat_arg.index_unchecked(idx) };
+        let group = group_arg
+            .as_ref()
+            .map(|group_arg| unsafe { group_arg.index_unchecked(idx) })
+            .unwrap_or(0);
+
+        let re = match &cached_reg {
+            Some(re) => re,
+            None => match cache.get_or_build("regexp_extract_all", pat, None) {
+                Ok(re) => re,
+                Err(err) => {
+                    ctx.set_error(builder.len(), err);
+                    builder.commit_row();
+                    continue;
+                }
+            },
+        };
+
+        // the group 0 is the whole match.
+        if group < 0 || group as usize >= re.captures_len() {
+            ctx.set_error(
+                builder.len(),
+                format!(
+                    "Incorrect arguments to regexp_extract_all: group must be between 0 and {}, but got {}",
+                    re.captures_len() - 1,
+                    group
+                ),
+            );
+            builder.commit_row();
+            continue;
+        }
+
+        regexp::regexp_extract_all(source, re, group as usize, &mut builder);
+        builder.commit_row();
+    }
+    match len {
+        Some(_) => Value::Column(Column::Array(Box::new(builder.build().upcast()))),
+        _ => Value::Scalar(Scalar::Array(Column::String(builder.build_scalar()))),
+    }
+}
+
+fn regexp_split_to_array_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let source_arg = args[0].try_downcast::<StringType>().unwrap();
+    let pat_arg = args[1].try_downcast::<StringType>().unwrap();
+    let mt_arg = if args.len() >= 3 {
+        Some(args[2].try_downcast::<StringType>().unwrap())
+    } else {
+        None
+    };
+
+    let cached_reg = match (&pat_arg, &mt_arg) {
+        (ValueRef::Scalar(pat), Some(ValueRef::Scalar(mt))) => {
+            regexp::build_regexp_from_pattern("regexp_split_to_array", pat, Some(mt)).ok()
+        }
+        (ValueRef::Scalar(pat), None) => {
+            regexp::build_regexp_from_pattern("regexp_split_to_array", pat, None).ok()
+        }
+        _ => None,
+    };
+    let mut cache = regexp::RegexpCache::default();
+
+    let size = len.unwrap_or(1);
+    let mut builder = ArrayColumnBuilder::<StringType>::with_capacity(size, 0, &[]);
+    for idx in 0..size {
+        let source = unsafe { source_arg.index_unchecked(idx) };
+        let pat = unsafe { pat_arg.index_unchecked(idx) };
+        let mt = mt_arg
+            .as_ref()
+            .map(|mt_arg| unsafe { mt_arg.index_unchecked(idx) });
+
+        let re = match &cached_reg {
+            Some(re) => re,
+            None => match cache.get_or_build("regexp_split_to_array", pat, mt) {
+                Ok(re) => re,
+                Err(err) => {
+                    ctx.set_error(builder.len(), err);
+                    builder.commit_row();
+                    continue;
+                }
+            },
+        };
+
+        regexp::regexp_split(source, re, &mut builder);
+        builder.commit_row();
+    }
+    match len {
+        Some(_) => Value::Column(Column::Array(Box::new(builder.build().upcast()))),
+        _ => Value::Scalar(Scalar::Array(Column::String(builder.build_scalar()))),
+    }
+}
+
 pub mod regexp {
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    use databend_common_expression::types::array::ArrayColumnBuilder;
     use databend_common_expression::types::string::StringColumnBuilder;
+    use databend_common_expression::types::StringType;
     use regex::Regex;
     use regex::RegexBuilder;
 
+    /// The max number of regexes kept in a `RegexpCache`, the cache is cleared when it is full.
+    const REGEXP_CACHE_CAPACITY: usize = 1024;
+
+    /// Caches the regexes built while evaluating a column of patterns. The patterns of the
+    /// rows usually repeat a lot, and building a regex is much more expensive than matching it.
+    #[derive(Default)]
+    pub struct RegexpCache {
+        // match type -> pattern -> regex
+        regexes: HashMap<String, HashMap<String, Regex>>,
+        len: usize,
+    }
+
+    impl RegexpCache {
+        pub fn get_or_build(
+            &mut self,
+            fn_name: &str,
+            pat: &str,
+            mt: Option<&str>,
+        ) -> Result<&Regex, String> {
+            let mt_key = mt.unwrap_or_default();
+            let cached = self
+                .regexes
+                .get(mt_key)
+                .is_some_and(|regexes| regexes.contains_key(pat));
+            if !cached {
+                let re = build_regexp_from_pattern(fn_name, pat, mt)?;
+                if self.len >= REGEXP_CACHE_CAPACITY {
+                    self.regexes.clear();
+                    self.len = 0;
+                }
+                self.regexes
+                    .entry(mt_key.to_string())
+                    .or_default()
+                    .insert(pat.to_string(), re);
+                self.len += 1;
+            }
+            Ok(&self.regexes[mt_key][pat])
+        }
+    }
+
     #[inline]
     pub fn build_regexp_from_pattern(
         fn_name: &str,
@@ -988,14 +1196,17 @@ pub mod regexp {
         let (before_trimmed, trimmed) = (&s[..char_pos], &s[char_pos..]);
         builder.put_str(before_trimmed);
 
+        let repl = convert_backreferences(repl);
         // means we should replace all matched strings
         if occur == 0 {
-            builder.put_str(&re.replace_all(trimmed, repl));
+            builder.put_str(&re.replace_all(trimmed, repl.as_ref()));
         } else if let Some(capature) = re.captures_iter(trimmed).nth((occur - 1) as _) {
             // unwrap on 0 is OK because captures only reports matches.
             let m = capature.get(0).unwrap();
             builder.put_str(&trimmed[0..m.start()]);
-            builder.put_str(repl);
+            let mut expanded = String::new();
+            capature.expand(&repl, &mut expanded);
+            builder.put_str(&expanded);
             builder.put_str(&trimmed[m.end()..]);
         } else {
             builder.put_str(trimmed);
@@ -1017,4 +1228,51 @@ pub mod regexp {
         let m = re.find_iter(&s[char_pos..]).nth((occur - 1) as _);
         m.map(|m| m.as_str())
     }
+
+    /// Converts the backreferences `\N` (N is 0-9) in the replacement to `${N}` of the regex crate,
+    /// `\\` is an escaped backslash. The `$N` and `${name}` references are kept as they are.
+    fn convert_backreferences(repl: &str) -> Cow<str> {
+        if !repl.contains('\\') {
+            return Cow::Borrowed(repl);
+        }
+        let mut converted = String::with_capacity(repl.len() + 8);
+        let mut chars = repl.chars().peekable();
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('\\', Some(n)) if n.is_ascii_digit() => {
+                    converted.push_str("${");
+                    converted.push(*n);
+                    converted.push('}');
+                    chars.next();
+                }
+                ('\\', Some(&'\\')) => {
+                    converted.push('\\');
+                    chars.next();
+                }
+                _ => converted.push(c),
+            }
+        }
+        Cow::Owned(converted)
+    }
+
+    /// Pushes the `group` of each match of `re` in `s`, the group is empty
+    /// if it doesn't participate in the match.
+    #[inline]
+    pub fn regexp_extract_all(
+        s: &str,
+        re: &Regex,
+        group: usize,
+        builder: &mut ArrayColumnBuilder<StringType>,
+    ) {
+        for capture in re.captures_iter(s) {
+            builder.put_item(capture.get(group).map_or("", |m| m.as_str()));
+        }
+    }
+
+    #[inline]
+    pub fn regexp_split(s: &str, re: &Regex, builder: &mut ArrayColumnBuilder<StringType>) {
+        for part in re.split(s) {
+            builder.put_item(part);
+        }
+    }
 }
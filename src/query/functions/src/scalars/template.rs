@@ -0,0 +1,1160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Oracle/PostgreSQL style format templates, like `YYYY-MM-DD HH24:MI:SS` and `FM9,999.99`,
+//! used by `to_char`, `to_number`, `to_timestamp` and `to_date`.
+
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Datelike;
+use chrono::FixedOffset;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::Offset;
+use chrono::TimeZone;
+use chrono::Timelike;
+use chrono_tz::Tz;
+use databend_common_expression::serialize::read_decimal_with_size;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::decimal::DecimalType;
+use databend_common_expression::types::decimal::MAX_DECIMAL256_PRECISION;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::ArgType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::utils::date_helper::DateConverter;
+use databend_common_expression::with_decimal_mapped_type;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+const TEMPLATE_CACHE_CAPACITY: usize = 64;
+
+/// The decimal size of `to_number` whose format is not a constant.
+const DEFAULT_TO_NUMBER_SIZE: DecimalSize = DecimalSize {
+    precision: 38,
+    scale: 10,
+};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<TimestampType, StringType, StringType, _, _>(
+        "to_char",
+        |_, _, _| FunctionDomain::MayThrow,
+        |value, format, ctx| {
+            eval_with_template::<TimestampType, StringType, _>(
+                value,
+                format,
+                ctx,
+                DateTemplate::compile,
+                |value, template, output, ctx| {
+                    let dt = value.to_timestamp(ctx.func_ctx.tz.tz);
+                    template.format(&dt, &mut output.data);
+                    output.commit_row();
+                },
+            )
+        },
+    );
+
+    registry.register_passthrough_nullable_2_arg::<DateType, StringType, StringType, _, _>(
+        "to_char",
+        |_, _, _| FunctionDomain::MayThrow,
+        |value, format, ctx| {
+            eval_with_template::<DateType, StringType, _>(
+                value,
+                format,
+                ctx,
+                DateTemplate::compile,
+                |value, template, output, _| {
+                    let date = value.to_date(Tz::UTC);
+                    let dt = Tz::UTC.from_utc_datetime(&date.and_hms_opt(0, 0, 0).unwrap());
+                    template.format(&dt, &mut output.data);
+                    output.commit_row();
+                },
+            )
+        },
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Int64Type, StringType, StringType, _, _>(
+        "to_char",
+        |_, _, _| FunctionDomain::MayThrow,
+        |value, format, ctx| {
+            eval_with_template::<Int64Type, StringType, _>(
+                value,
+                format,
+                ctx,
+                NumberTemplate::compile,
+                |value, template, output, _| {
+                    output.put_str(&template.format(value as f64));
+                    output.commit_row();
+                },
+            )
+        },
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Float64Type, StringType, StringType, _, _>(
+        "to_char",
+        |_, _, _| FunctionDomain::MayThrow,
+        |value, format, ctx| {
+            eval_with_template::<Float64Type, StringType, _>(
+                value,
+                format,
+                ctx,
+                NumberTemplate::compile,
+                |value, template, output, _| {
+                    output.put_str(&template.format(value.0));
+                    output.commit_row();
+                },
+            )
+        },
+    );
+
+    // `to_number` returns the decimal of the precision and the scale in the params, which are
+    // injected from the constant format by the type checker, see `number_format_decimal_size`.
+    let factory = |params: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 2
+            || args_type
+                .iter()
+                .any(|ty| ty.remove_nullable() != DataType::String)
+        {
+            return None;
+        }
+        let size = match params {
+            [] => DEFAULT_TO_NUMBER_SIZE,
+            [precision, scale] => DecimalSize {
+                precision: precision.get_i64()? as _,
+                scale: scale.get_i64()? as _,
+            },
+            _ => return None,
+        };
+        let decimal_type = DecimalDataType::from_size(size).ok()?;
+
+        Some(Function {
+            signature: FunctionSignature {
+                name: "to_number".to_string(),
+                args_type: vec![DataType::String, DataType::String],
+                return_type: DataType::Decimal(decimal_type),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| eval_string_to_number(args, ctx, decimal_type)),
+            },
+        })
+    };
+
+    registry.register_function_factory("to_number", move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory("to_number", move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+    registry.register_function_factory("try_to_number", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_number".to_string();
+        Some(Arc::new(f.error_to_null()))
+    });
+    registry.register_function_factory("try_to_number", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_number".to_string();
+        Some(Arc::new(f.error_to_null().passthrough_nullable()))
+    });
+
+    fn eval_string_to_number(
+        args: &[ValueRef<AnyType>],
+        ctx: &mut EvalContext,
+        decimal_type: DecimalDataType,
+    ) -> Value<AnyType> {
+        let value = args[0].try_downcast::<StringType>().unwrap();
+        let format = args[1].try_downcast::<StringType>().unwrap();
+        with_decimal_mapped_type!(|DECIMAL_TYPE| match decimal_type {
+            DecimalDataType::DECIMAL_TYPE(size) => {
+                eval_with_template::<StringType, DecimalType<DECIMAL_TYPE>, _>(
+                    value,
+                    format,
+                    ctx,
+                    NumberTemplate::compile,
+                    |value, template, output, ctx| {
+                        let number = template.parse(value).and_then(|number| {
+                            read_decimal_with_size::<DECIMAL_TYPE>(
+                                number.as_bytes(),
+                                size,
+                                true,
+                                ctx.func_ctx.rounding_mode,
+                            )
+                            .map(|(d, _)| d)
+                            .map_err(|e| e.message())
+                        });
+                        match number {
+                            Ok(d) => output.push(d),
+                            Err(err) => {
+                                ctx.set_error(output.len(), err);
+                                output.push(DECIMAL_TYPE::zero());
+                            }
+                        }
+                    },
+                )
+                .upcast_decimal(size)
+            }
+        })
+    }
+}
+
+/// Returns the decimal size of `to_number` with the format, the precision is the number of the
+/// digits in the format and the scale is the number of the digits after the decimal point.
+pub fn number_format_decimal_size(format: &str) -> Result<DecimalSize, String> {
+    let template = NumberTemplate::compile(format)?;
+    let precision = template.int_digits + template.frac_digits;
+    if precision > MAX_DECIMAL256_PRECISION as usize {
+        return Err(format!(
+            "number format '{format}' has more than {MAX_DECIMAL256_PRECISION} digits"
+        ));
+    }
+    Ok(DecimalSize {
+        precision: precision as u8,
+        scale: template.frac_digits as u8,
+    })
+}
+
+/// Caches the compiled templates of a format column, the templates are compiled once
+/// for each distinct format string.
+pub struct TemplateCache<T> {
+    templates: HashMap<String, T>,
+}
+
+impl<T> Default for TemplateCache<T> {
+    fn default() -> Self {
+        Self {
+            templates: HashMap::new(),
+        }
+    }
+}
+
+impl<T> TemplateCache<T> {
+    pub fn get_or_compile(
+        &mut self,
+        format: &str,
+        compile: impl FnOnce(&str) -> Result<T, String>,
+    ) -> Result<&T, String> {
+        if !self.templates.contains_key(format) {
+            let template = compile(format)?;
+            if self.templates.len() >= TEMPLATE_CACHE_CAPACITY {
+                self.templates.clear();
+            }
+            self.templates.insert(format.to_string(), template);
+        }
+        Ok(&self.templates[format])
+    }
+}
+
+/// Evaluates a function whose second argument is a format template, the template is compiled
+/// once when the format is a constant, otherwise the compiled templates are cached.
+pub fn eval_with_template<I: ArgType, O: ArgType, T>(
+    value: ValueRef<I>,
+    format: ValueRef<StringType>,
+    ctx: &mut EvalContext,
+    compile: impl Fn(&str) -> Result<T, String>,
+    func: impl Fn(I::ScalarRef<'_>, &T, &mut O::ColumnBuilder, &mut EvalContext),
+) -> Value<O> {
+    let len = match (&value, &format) {
+        (ValueRef::Column(col), _) => Some(I::column_len(col)),
+        (_, ValueRef::Column(col)) => Some(StringType::column_len(col)),
+        _ => None,
+    };
+    let generics = ctx.generics.to_owned();
+    let mut builder = O::create_builder(len.unwrap_or(1), &generics);
+    let mut cache = TemplateCache::default();
+    for idx in 0..len.unwrap_or(1) {
+        if let Some(validity) = &ctx.validity {
+            if !validity.get_bit(idx) {
+                O::push_default(&mut builder);
+                continue;
+            }
+        }
+        let arg = unsafe { value.index_unchecked(idx) };
+        let format = unsafe { format.index_unchecked(idx) };
+        match cache.get_or_compile(format, &compile) {
+            Ok(template) => func(arg, template, &mut builder, ctx),
+            Err(err) => {
+                ctx.set_error(idx, err);
+                O::push_default(&mut builder);
+            }
+        }
+    }
+    match len {
+        Some(_) => Value::Column(O::build_column(builder)),
+        None => Value::Scalar(O::build_scalar(builder)),
+    }
+}
+
+/// The letter case of the names in the output, like `MONTH`, `Month` and `month`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LetterCase {
+    Upper,
+    Capital,
+    Lower,
+}
+
+impl LetterCase {
+    fn of(keyword: &str) -> Self {
+        let mut chars = keyword.chars();
+        match (chars.next(), chars.next()) {
+            (Some(a), Some(b)) if a.is_ascii_uppercase() && b.is_ascii_uppercase() => {
+                LetterCase::Upper
+            }
+            (Some(a), _) if a.is_ascii_uppercase() => LetterCase::Capital,
+            _ => LetterCase::Lower,
+        }
+    }
+
+    fn apply(&self, name: &str) -> String {
+        match self {
+            LetterCase::Upper => name.to_ascii_uppercase(),
+            LetterCase::Capital => name.to_string(),
+            LetterCase::Lower => name.to_ascii_lowercase(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum DateField {
+    Literal(String),
+    Year,
+    ShortYear,
+    Month,
+    MonthName(LetterCase),
+    MonthAbbr(LetterCase),
+    Day,
+    DayOfYear,
+    DayOfWeek,
+    DayName(LetterCase),
+    DayAbbr(LetterCase),
+    Hour24,
+    Hour12,
+    Minute,
+    Second,
+    Fraction(u32),
+    Meridiem(LetterCase),
+    Quarter,
+    WeekOfYear,
+    IsoWeek,
+    TzHour,
+    TzMinute,
+    TzName,
+}
+
+/// The keywords of the date template, a longer keyword must be placed before its prefixes.
+const DATE_KEYWORDS: [&str; 33] = [
+    "HH24", "HH12", "HH", "YYYY", "RRRR", "YY", "MONTH", "MON", "MM", "MI", "MS", "US", "FF1",
+    "FF2", "FF3", "FF4", "FF5", "FF6", "FF7", "FF8", "FF9", "FF", "SS", "DDD", "DD", "DAY", "DY",
+    "D", "AM", "PM", "Q", "WW", "IW",
+];
+
+const TZ_KEYWORDS: [&str; 3] = ["TZH", "TZM", "TZ"];
+
+fn date_field(keyword: &str, text: &str) -> DateField {
+    let case = LetterCase::of(text);
+    match keyword {
+        "HH24" => DateField::Hour24,
+        "HH12" | "HH" => DateField::Hour12,
+        "YYYY" | "RRRR" => DateField::Year,
+        "YY" => DateField::ShortYear,
+        "MONTH" => DateField::MonthName(case),
+        "MON" => DateField::MonthAbbr(case),
+        "MM" => DateField::Month,
+        "MI" => DateField::Minute,
+        "MS" => DateField::Fraction(3),
+        "US" | "FF" => DateField::Fraction(6),
+        "SS" => DateField::Second,
+        "DDD" => DateField::DayOfYear,
+        "DD" => DateField::Day,
+        "DAY" => DateField::DayName(case),
+        "DY" => DateField::DayAbbr(case),
+        "D" => DateField::DayOfWeek,
+        "AM" | "PM" => DateField::Meridiem(case),
+        "Q" => DateField::Quarter,
+        "WW" => DateField::WeekOfYear,
+        "IW" => DateField::IsoWeek,
+        "TZH" => DateField::TzHour,
+        "TZM" => DateField::TzMinute,
+        "TZ" => DateField::TzName,
+        _ => DateField::Fraction(keyword[2..].parse().unwrap()),
+    }
+}
+
+fn match_keyword<'a>(rest: &str, keywords: &[&'a str]) -> Option<&'a str> {
+    keywords.iter().copied().find(|keyword| {
+        rest.get(..keyword.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(keyword))
+    })
+}
+
+/// A compiled date template, like `YYYY-MM-DD HH24:MI:SS.US`.
+///
+/// The keywords are case-insensitive, and the case of the names in the output follows the
+/// case of the keyword, like `Mon` for `Jan` and `MON` for `JAN`. The `FM` prefix suppresses
+/// the padding of the next field, and the text in double quotes is output literally.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateTemplate {
+    // The field and whether it's padded.
+    fields: Vec<(DateField, bool)>,
+}
+
+impl DateTemplate {
+    pub fn compile(format: &str) -> Result<Self, String> {
+        let mut fields = Vec::new();
+        let mut literal = String::new();
+        let mut fill = true;
+        let mut rest = format;
+        while let Some(c) = rest.chars().next() {
+            if c == '"' {
+                let end = rest[1..]
+                    .find('"')
+                    .ok_or_else(|| format!("unterminated quoted text in format '{format}'"))?;
+                literal.push_str(&rest[1..end + 1]);
+                rest = &rest[end + 2..];
+                continue;
+            }
+            if rest.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("FM")) {
+                fill = false;
+                rest = &rest[2..];
+                continue;
+            }
+            let keyword =
+                match_keyword(rest, &TZ_KEYWORDS).or_else(|| match_keyword(rest, &DATE_KEYWORDS));
+            match keyword {
+                Some(keyword) => {
+                    if !literal.is_empty() {
+                        fields.push((DateField::Literal(std::mem::take(&mut literal)), true));
+                    }
+                    fields.push((date_field(keyword, &rest[..keyword.len()]), fill));
+                    fill = true;
+                    rest = &rest[keyword.len()..];
+                }
+                None => {
+                    literal.push(c);
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        if !literal.is_empty() {
+            fields.push((DateField::Literal(literal), true));
+        }
+        Ok(DateTemplate { fields })
+    }
+
+    pub fn format(&self, dt: &DateTime<Tz>, out: &mut Vec<u8>) {
+        for (field, fill) in &self.fields {
+            let (fill, number, width) = match field {
+                DateField::Literal(text) => {
+                    out.extend_from_slice(text.as_bytes());
+                    continue;
+                }
+                DateField::Year => (*fill, dt.year() as i64, 4),
+                DateField::ShortYear => (*fill, dt.year().rem_euclid(100) as i64, 2),
+                DateField::Month => (*fill, dt.month() as i64, 2),
+                DateField::Day => (*fill, dt.day() as i64, 2),
+                DateField::DayOfYear => (*fill, dt.ordinal() as i64, 3),
+                DateField::DayOfWeek => (*fill, dt.weekday().number_from_sunday() as i64, 1),
+                DateField::Hour24 => (*fill, dt.hour() as i64, 2),
+                DateField::Hour12 => (*fill, (dt.hour() + 11) as i64 % 12 + 1, 2),
+                DateField::Minute => (*fill, dt.minute() as i64, 2),
+                DateField::Second => (*fill, dt.second() as i64, 2),
+                DateField::Fraction(digits) => {
+                    let nanos = dt.nanosecond() % 1_000_000_000;
+                    let value = nanos / 10u32.pow(9 - digits);
+                    (true, value as i64, *digits as usize)
+                }
+                DateField::Quarter => (*fill, (dt.month0() / 3 + 1) as i64, 1),
+                DateField::WeekOfYear => (*fill, (dt.ordinal0() / 7 + 1) as i64, 2),
+                DateField::IsoWeek => (*fill, dt.iso_week().week() as i64, 2),
+                DateField::TzHour => {
+                    let offset = dt.offset().fix().local_minus_utc();
+                    let sign = if offset < 0 { '-' } else { '+' };
+                    write!(out, "{}{:02}", sign, offset.abs() / 3600).unwrap();
+                    continue;
+                }
+                DateField::TzMinute => {
+                    let offset = dt.offset().fix().local_minus_utc();
+                    (true, (offset.abs() % 3600 / 60) as i64, 2)
+                }
+                DateField::TzName => {
+                    write!(out, "{}", dt.format("%Z")).unwrap();
+                    continue;
+                }
+                DateField::MonthName(case) | DateField::DayName(case) => {
+                    let name = match field {
+                        DateField::MonthName(_) => MONTH_NAMES[dt.month0() as usize],
+                        _ => DAY_NAMES[dt.weekday().num_days_from_sunday() as usize],
+                    };
+                    let name = case.apply(name);
+                    if *fill {
+                        write!(out, "{:<9}", name).unwrap();
+                    } else {
+                        out.extend_from_slice(name.as_bytes());
+                    }
+                    continue;
+                }
+                DateField::MonthAbbr(case) => {
+                    let name = case.apply(&MONTH_NAMES[dt.month0() as usize][..3]);
+                    out.extend_from_slice(name.as_bytes());
+                    continue;
+                }
+                DateField::DayAbbr(case) => {
+                    let name = DAY_NAMES[dt.weekday().num_days_from_sunday() as usize];
+                    out.extend_from_slice(case.apply(&name[..3]).as_bytes());
+                    continue;
+                }
+                DateField::Meridiem(case) => {
+                    let name = if dt.hour() < 12 { "AM" } else { "PM" };
+                    let name = match case {
+                        LetterCase::Lower => name.to_ascii_lowercase(),
+                        _ => name.to_string(),
+                    };
+                    out.extend_from_slice(name.as_bytes());
+                    continue;
+                }
+            };
+            if fill {
+                write!(out, "{:0width$}", number, width = width).unwrap();
+            } else {
+                write!(out, "{}", number).unwrap();
+            }
+        }
+    }
+
+    /// Parses the text with the template, the missing fields default to `1970-01-01 00:00:00`.
+    pub fn parse(&self, text: &str) -> Result<ParsedDateTime, String> {
+        let mut scanner = Scanner::new(text);
+        let mut year = 1970;
+        let mut month = None;
+        let mut day = None;
+        let mut day_of_year = None;
+        let mut hour = 0;
+        let mut hour12 = None;
+        let mut pm = None;
+        let mut minute = 0;
+        let mut second = 0;
+        let mut nanos = 0;
+        let mut tz_hour: Option<i32> = None;
+        let mut tz_minute = 0;
+        let mut tz = None;
+
+        for (field, _) in &self.fields {
+            match field {
+                DateField::Literal(literal) => scanner.literal(literal)?,
+                DateField::Year => year = scanner.number(4)? as i32,
+                DateField::ShortYear => {
+                    let short_year = scanner.number(2)? as i32;
+                    year = if short_year < 70 {
+                        2000 + short_year
+                    } else {
+                        1900 + short_year
+                    };
+                }
+                DateField::Month => month = Some(scanner.number(2)?),
+                DateField::MonthName(_) | DateField::MonthAbbr(_) => {
+                    month = Some(scanner.name(&MONTH_NAMES)? + 1);
+                }
+                DateField::Day => day = Some(scanner.number(2)?),
+                DateField::DayOfYear => day_of_year = Some(scanner.number(3)?),
+                DateField::DayOfWeek => {
+                    scanner.number(1)?;
+                }
+                DateField::DayName(_) | DateField::DayAbbr(_) => {
+                    scanner.name(&DAY_NAMES)?;
+                }
+                DateField::Hour24 => hour = scanner.number(2)?,
+                DateField::Hour12 => hour12 = Some(scanner.number(2)?),
+                DateField::Minute => minute = scanner.number(2)?,
+                DateField::Second => second = scanner.number(2)?,
+                DateField::Fraction(digits) => {
+                    let start = scanner.pos;
+                    let value = scanner.number(*digits as usize)?;
+                    let len = (scanner.pos - start) as u32;
+                    nanos = value * 10u32.pow(9 - len);
+                }
+                DateField::Meridiem(_) => pm = Some(scanner.name(&["AM", "PM"])? == 1),
+                DateField::Quarter => {
+                    scanner.number(1)?;
+                }
+                DateField::WeekOfYear | DateField::IsoWeek => {
+                    scanner.number(2)?;
+                }
+                DateField::TzHour => {
+                    scanner.skip_whitespace();
+                    let negative = scanner.sign();
+                    let value = scanner.number(2)? as i32;
+                    tz_hour = Some(if negative { -value } else { value });
+                }
+                DateField::TzMinute => tz_minute = scanner.number(2)? as i32,
+                DateField::TzName => {
+                    let name = scanner.word();
+                    tz = Some(
+                        name.parse::<Tz>()
+                            .map_err(|_| format!("unknown time zone '{name}'"))?,
+                    );
+                }
+            }
+        }
+        scanner.skip_whitespace();
+        if !scanner.rest().is_empty() {
+            return Err(format!(
+                "unexpected trailing characters '{}'",
+                scanner.rest()
+            ));
+        }
+
+        if let Some(hour12) = hour12 {
+            if !(1..=12).contains(&hour12) {
+                return Err(format!("hour {hour12} is out of range 1..12"));
+            }
+            hour = hour12 % 12 + if pm == Some(true) { 12 } else { 0 };
+        }
+        let date = match (day_of_year, month, day) {
+            (Some(day_of_year), None, None) => NaiveDate::from_yo_opt(year, day_of_year),
+            _ => NaiveDate::from_ymd_opt(year, month.unwrap_or(1), day.unwrap_or(1)),
+        }
+        .ok_or_else(|| "the date is out of range".to_string())?;
+        let datetime = date
+            .and_hms_nano_opt(hour, minute, second, nanos)
+            .ok_or_else(|| "the time is out of range".to_string())?;
+
+        let offset = match tz_hour {
+            Some(tz_hour) => {
+                let tz_minute = if tz_hour < 0 { -tz_minute } else { tz_minute };
+                Some(
+                    FixedOffset::east_opt(tz_hour * 3600 + tz_minute * 60)
+                        .ok_or_else(|| "the time zone offset is out of range".to_string())?,
+                )
+            }
+            None => None,
+        };
+        Ok(ParsedDateTime {
+            datetime,
+            offset,
+            tz,
+        })
+    }
+}
+
+/// The result of parsing a text with a `DateTemplate`.
+pub struct ParsedDateTime {
+    pub datetime: NaiveDateTime,
+    pub offset: Option<FixedOffset>,
+    pub tz: Option<Tz>,
+}
+
+impl ParsedDateTime {
+    /// Returns the micros of the timestamp, the local date time is in the time zone of the text,
+    /// or the given time zone if the text has no time zone.
+    pub fn timestamp_micros(&self, tz: Tz) -> Result<i64, String> {
+        let micros = match self.offset {
+            Some(offset) => offset
+                .from_local_datetime(&self.datetime)
+                .earliest()
+                .map(|dt| dt.timestamp_micros()),
+            None => self
+                .tz
+                .unwrap_or(tz)
+                .from_local_datetime(&self.datetime)
+                .earliest()
+                .map(|dt| dt.timestamp_micros()),
+        };
+        micros.ok_or_else(|| "the local time does not exist in the time zone".to_string())
+    }
+}
+
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(text: &'a str) -> Self {
+        Scanner { text, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn bump(&mut self) {
+        if let Some(c) = self.peek() {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.bump();
+        }
+    }
+
+    fn sign(&mut self) -> bool {
+        match self.peek() {
+            Some('-') => {
+                self.bump();
+                true
+            }
+            Some('+') => {
+                self.bump();
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Reads at most `max_digits` digits, the leading whitespaces are skipped.
+    fn number(&mut self, max_digits: usize) -> Result<u32, String> {
+        self.skip_whitespace();
+        let digits = self
+            .rest()
+            .bytes()
+            .take(max_digits)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if digits == 0 {
+            return Err(format!("expect a number at '{}'", self.rest()));
+        }
+        let value = self.rest()[..digits].parse().unwrap();
+        self.pos += digits;
+        Ok(value)
+    }
+
+    /// Matches one of the names or their three-letter abbreviations case-insensitively,
+    /// returns the index of the name.
+    fn name(&mut self, names: &[&str]) -> Result<u32, String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+        let matched = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (i, *name))
+            .chain(
+                names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, name)| name.len() > 3)
+                    .map(|(i, name)| (i, &name[..3])),
+            )
+            .find(|(_, name)| {
+                rest.get(..name.len())
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+            });
+        match matched {
+            Some((i, name)) => {
+                self.pos += name.len();
+                Ok(i as u32)
+            }
+            None => Err(format!("expect a name in {:?} at '{}'", names, rest)),
+        }
+    }
+
+    fn word(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !c.is_whitespace()) {
+            self.bump();
+        }
+        &self.text[start..self.pos]
+    }
+
+    /// Matches the literal text of the template, the whitespaces match any number of
+    /// whitespaces, and a separator matches any separator.
+    fn literal(&mut self, literal: &str) -> Result<(), String> {
+        for c in literal.chars() {
+            if c.is_whitespace() {
+                self.skip_whitespace();
+                continue;
+            }
+            match self.peek() {
+                Some(next) if next.eq_ignore_ascii_case(&c) => self.bump(),
+                Some(next)
+                    if !c.is_alphanumeric() && !next.is_alphanumeric() && !next.is_whitespace() =>
+                {
+                    self.bump()
+                }
+                _ => return Err(format!("expect '{}' at '{}'", c, self.rest())),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum NumberField {
+    // A digit, `0` keeps the leading zeros and `9` doesn't.
+    Digit(bool),
+    Decimal,
+    Group,
+    Sign,
+    Minus,
+    Brackets,
+    Literal(String),
+}
+
+/// A compiled number template, like `FM9,999.99`, `0000` and `S999.9`.
+///
+/// `9` is a digit, `0` is a digit with leading zeros, `.` or `D` is the decimal point, `,` or `G`
+/// is the group separator, `S` is the sign, `MI` is the minus sign of a negative number, `PR`
+/// encloses a negative number in angle brackets, `FM` suppresses the padding blanks and the
+/// trailing zeros. A number without an explicit sign has a leading sign position.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberTemplate {
+    fields: Vec<NumberField>,
+    fill: bool,
+    int_digits: usize,
+    frac_digits: usize,
+}
+
+impl NumberTemplate {
+    pub fn compile(format: &str) -> Result<Self, String> {
+        let mut fields = Vec::new();
+        let mut fill = true;
+        let mut has_decimal = false;
+        let mut int_digits = 0;
+        let mut frac_digits = 0;
+        let mut rest = format;
+        while let Some(c) = rest.chars().next() {
+            let mut len = c.len_utf8();
+            let field = match c.to_ascii_uppercase() {
+                '9' | '0' => {
+                    if has_decimal {
+                        frac_digits += 1;
+                    } else {
+                        int_digits += 1;
+                    }
+                    NumberField::Digit(c == '0')
+                }
+                '.' | 'D' => {
+                    if has_decimal {
+                        return Err(format!("multiple decimal points in format '{format}'"));
+                    }
+                    has_decimal = true;
+                    NumberField::Decimal
+                }
+                ',' | 'G' => NumberField::Group,
+                'S' => NumberField::Sign,
+                '$' | 'L' => NumberField::Literal("$".to_string()),
+                'F' | 'M' | 'P' => {
+                    len = 2;
+                    match rest.get(..2).map(|s| s.to_ascii_uppercase()).as_deref() {
+                        Some("FM") => {
+                            fill = false;
+                            rest = &rest[2..];
+                            continue;
+                        }
+                        Some("MI") => NumberField::Minus,
+                        Some("PR") => NumberField::Brackets,
+                        _ => return Err(format!("invalid number format '{format}'")),
+                    }
+                }
+                '"' => {
+                    let end = rest[1..]
+                        .find('"')
+                        .ok_or_else(|| format!("unterminated quoted text in format '{format}'"))?;
+                    len = end + 2;
+                    NumberField::Literal(rest[1..end + 1].to_string())
+                }
+                c if c.is_alphanumeric() => {
+                    return Err(format!("invalid number format '{format}'"));
+                }
+                _ => NumberField::Literal(c.to_string()),
+            };
+            fields.push(field);
+            rest = &rest[len..];
+        }
+        if int_digits + frac_digits == 0 {
+            return Err(format!("number format '{format}' has no digits"));
+        }
+        Ok(NumberTemplate {
+            fields,
+            fill,
+            int_digits,
+            frac_digits,
+        })
+    }
+
+    fn explicit_sign(&self) -> bool {
+        self.fields.iter().any(|field| {
+            matches!(
+                field,
+                NumberField::Sign | NumberField::Minus | NumberField::Brackets
+            )
+        })
+    }
+
+    pub fn format(&self, value: f64) -> String {
+        let negative = value < 0.0;
+        let scaled = (value.abs() * 10f64.powi(self.frac_digits as i32)).round();
+        let digits = format!("{:0width$.0}", scaled, width = self.frac_digits + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - self.frac_digits);
+        let int_part = match int_part {
+            "0" if self.frac_digits > 0 => "",
+            int_part => int_part,
+        };
+        let overflow = !value.is_finite() || int_part.len() > self.int_digits;
+
+        // Trailing zeros of `9` in the fraction are removed in the fill mode.
+        let mut frac_keep = self.frac_digits;
+        if !self.fill {
+            let frac_fields = self
+                .fields
+                .iter()
+                .skip_while(|field| **field != NumberField::Decimal)
+                .filter_map(|field| match field {
+                    NumberField::Digit(zero) => Some(*zero),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            while frac_keep > 0
+                && !frac_fields[frac_keep - 1]
+                && frac_part.as_bytes()[frac_keep - 1] == b'0'
+            {
+                frac_keep -= 1;
+            }
+        }
+
+        let sign = if negative { "-" } else { "+" };
+        let mut pending_sign = match self.explicit_sign() {
+            false if negative => Some("-"),
+            false if self.fill => Some(" "),
+            _ => None,
+        };
+        let mut out = String::new();
+        let mut started = false;
+        let mut int_index = 0;
+        let mut frac_index = 0;
+        let mut in_fraction = false;
+        let leading = self.int_digits.saturating_sub(int_part.len());
+        let pad = |out: &mut String| {
+            if self.fill {
+                out.push(' ');
+            }
+        };
+        for field in &self.fields {
+            match field {
+                NumberField::Digit(zero) if !in_fraction => {
+                    let position = int_index;
+                    int_index += 1;
+                    if overflow {
+                        if let Some(sign) = pending_sign.take() {
+                            out.push_str(sign);
+                        }
+                        out.push('#');
+                    } else if position < leading && !*zero && !started {
+                        pad(&mut out);
+                    } else {
+                        if !started {
+                            started = true;
+                            if let Some(sign) = pending_sign.take() {
+                                out.push_str(sign);
+                            }
+                        }
+                        if position < leading {
+                            out.push('0');
+                        } else {
+                            out.push(int_part.as_bytes()[position - leading] as char);
+                        }
+                    }
+                }
+                NumberField::Digit(_) => {
+                    let position = frac_index;
+                    frac_index += 1;
+                    if overflow {
+                        out.push('#');
+                    } else if position < frac_keep {
+                        out.push(frac_part.as_bytes()[position] as char);
+                    }
+                }
+                NumberField::Decimal => {
+                    in_fraction = true;
+                    if let Some(sign) = pending_sign.take() {
+                        out.push_str(sign);
+                    }
+                    started = true;
+                    out.push('.');
+                }
+                NumberField::Group => {
+                    if started || overflow {
+                        out.push(',');
+                    } else {
+                        pad(&mut out);
+                    }
+                }
+                NumberField::Sign => {
+                    if started {
+                        out.push_str(sign);
+                    } else {
+                        pending_sign = Some(sign);
+                    }
+                }
+                NumberField::Minus => {
+                    if negative {
+                        out.push('-');
+                    } else {
+                        pad(&mut out);
+                    }
+                }
+                NumberField::Brackets => {
+                    if negative {
+                        out.push('>');
+                    } else {
+                        pad(&mut out);
+                    }
+                }
+                NumberField::Literal(text) => out.push_str(text),
+            }
+        }
+        if self.fields.contains(&NumberField::Brackets) {
+            let opening = if negative {
+                Some('<')
+            } else if self.fill {
+                Some(' ')
+            } else {
+                None
+            };
+            if let Some(opening) = opening {
+                let index = out
+                    .find(|c: char| c.is_ascii_digit() || c == '.' || c == '#')
+                    .unwrap_or(0);
+                out.insert(index, opening);
+            }
+        }
+        out
+    }
+
+    /// Parses the text with the template into a plain number like `-1234.5`, the group
+    /// separators and the currency symbols are skipped.
+    pub fn parse(&self, text: &str) -> Result<String, String> {
+        let mut scanner = Scanner::new(text.trim());
+        let mut number = String::new();
+        let mut negative = false;
+        let mut digits = 0;
+        let mut brackets = false;
+        let explicit_sign = self.explicit_sign();
+
+        if scanner.peek() == Some('<') && self.fields.contains(&NumberField::Brackets) {
+            scanner.bump();
+            brackets = true;
+        }
+        for field in &self.fields {
+            match field {
+                NumberField::Digit(_) => {
+                    if digits == 0 && !number.ends_with('.') {
+                        scanner.skip_whitespace();
+                        if !explicit_sign && matches!(scanner.peek(), Some('-') | Some('+')) {
+                            negative = scanner.sign();
+                            scanner.skip_whitespace();
+                        }
+                    }
+                    if let Some(c) = scanner.peek().filter(|c| c.is_ascii_digit()) {
+                        number.push(c);
+                        digits += 1;
+                        scanner.bump();
+                    }
+                }
+                NumberField::Decimal => {
+                    if scanner.peek() == Some('.') {
+                        number.push('.');
+                        scanner.bump();
+                    }
+                }
+                NumberField::Group => {
+                    if scanner.peek() == Some(',') {
+                        scanner.bump();
+                    }
+                }
+                NumberField::Sign | NumberField::Minus => {
+                    scanner.skip_whitespace();
+                    if matches!(scanner.peek(), Some('-') | Some('+')) {
+                        negative = scanner.sign();
+                    }
+                }
+                NumberField::Brackets => {
+                    if brackets {
+                        if scanner.peek() != Some('>') {
+                            return Err(format!("expect '>' at '{}'", scanner.rest()));
+                        }
+                        scanner.bump();
+                        negative = true;
+                    }
+                }
+                NumberField::Literal(literal) => {
+                    if scanner.rest().starts_with(literal.as_str()) {
+                        scanner.pos += literal.len();
+                    }
+                }
+            }
+        }
+        if digits == 0 || !scanner.rest().is_empty() {
+            return Err(format!("'{}' does not match the number format", text));
+        }
+        if number.starts_with('.') {
+            number.insert(0, '0');
+        }
+        if number.ends_with('.') {
+            number.pop();
+        }
+        if negative {
+            number.insert(0, '-');
+        }
+        Ok(number)
+    }
+}
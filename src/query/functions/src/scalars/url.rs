@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::IpAddr;
+
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use percent_encoding::percent_decode_str;
+use percent_encoding::utf8_percent_encode;
+use percent_encoding::AsciiSet;
+use percent_encoding::NON_ALPHANUMERIC;
+
+/// The characters except the unreserved ones of RFC 3986 are encoded.
+const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_domain",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|url, output, _| {
+            output.put_str(url_domain(url));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "top_level_domain",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|url, output, _| {
+            output.put_str(top_level_domain(url_domain(url)));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_path",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|url, output, _| {
+            output.put_str(url_path(url));
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_2_arg::<StringType, StringType, StringType, _, _>(
+        "url_query_parameter",
+        |_, _, _| FunctionDomain::Full,
+        vectorize_with_builder_2_arg::<StringType, StringType, NullableType<StringType>>(
+            |url, name, output, _| match url_query_parameter(url, name) {
+                Some(value) => output.push(value),
+                None => output.push_null(),
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_encode",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|val, output, _| {
+            for part in utf8_percent_encode(val, URL_ENCODE_SET) {
+                output.put_str(part);
+            }
+            output.commit_row();
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, StringType, _, _>(
+        "url_decode",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<StringType, StringType>(|val, output, ctx| {
+            match percent_decode_str(val).decode_utf8() {
+                Ok(decoded) => output.put_str(&decoded),
+                Err(_) => ctx.set_error(
+                    output.len(),
+                    format!("Failed to decode '{}' into a valid UTF-8 string", val),
+                ),
+            }
+            output.commit_row();
+        }),
+    );
+}
+
+/// Returns the `user@host:port` part of the url, the url may be without a scheme.
+fn url_authority(url: &str) -> &str {
+    let rest = match url.find("://") {
+        Some(pos)
+            if url[..pos]
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"+-.".contains(&b)) =>
+        {
+            &url[pos + 3..]
+        }
+        _ => url.strip_prefix("//").unwrap_or(url),
+    };
+    let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+    &rest[..end]
+}
+
+fn url_domain(url: &str) -> &str {
+    let authority = url_authority(url);
+    let host = match authority.rfind('@') {
+        Some(pos) => &authority[pos + 1..],
+        None => authority,
+    };
+    if host.starts_with('[') {
+        // IPv6 address, e.g. `[::1]:8080`
+        match host.find(']') {
+            Some(pos) => &host[..=pos],
+            None => host,
+        }
+    } else {
+        match host.find(':') {
+            Some(pos) => &host[..pos],
+            None => host,
+        }
+    }
+}
+
+fn top_level_domain(domain: &str) -> &str {
+    let domain = domain.strip_suffix('.').unwrap_or(domain);
+    if domain.starts_with('[') || domain.parse::<IpAddr>().is_ok() {
+        return "";
+    }
+    match domain.rfind('.') {
+        Some(pos) => &domain[pos + 1..],
+        None => "",
+    }
+}
+
+fn url_path(url: &str) -> &str {
+    let authority = url_authority(url);
+    // `authority` is a sub slice of `url`, so the path starts right after it.
+    let start = authority.as_ptr() as usize - url.as_ptr() as usize + authority.len();
+    let rest = &url[start..];
+    let end = rest.find(['?', '#']).unwrap_or(rest.len());
+    &rest[..end]
+}
+
+fn url_query_parameter<'a>(url: &'a str, name: &str) -> Option<&'a str> {
+    let query = &url[url.find('?')? + 1..];
+    let query = match query.find('#') {
+        Some(pos) => &query[..pos],
+        None => query,
+    };
+    query
+        .split('&')
+        .find_map(|pair| match pair.split_once('=') {
+            Some((key, value)) if key == name => Some(value),
+            None if pair == name => Some(""),
+            _ => None,
+        })
+}
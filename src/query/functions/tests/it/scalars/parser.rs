@@ -558,6 +558,10 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
         }
         databend_common_ast::ast::TypeName::Variant => DataType::Variant,
         databend_common_ast::ast::TypeName::Geometry => DataType::Geometry,
+        databend_common_ast::ast::TypeName::Interval => DataType::Interval,
+        databend_common_ast::ast::TypeName::Vector(_) => {
+            DataType::Array(Box::new(DataType::Number(NumberDataType::Float32)))
+        }
         databend_common_ast::ast::TypeName::NotNull(inner_type) => transform_data_type(*inner_type),
     }
 }
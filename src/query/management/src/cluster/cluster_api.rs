@@ -27,6 +27,9 @@ pub trait ClusterApi: Sync + Send {
     // Drop the tenant's cluster one node by node.id.
     async fn drop_node(&self, node_id: String, seq: MatchSeq) -> Result<()>;
 
+    // Update the node info of the tenant's cluster node.
+    async fn update_node(&self, node: NodeInfo, seq: MatchSeq) -> Result<u64>;
+
     // Keep the tenant's cluster node alive.
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64>;
 
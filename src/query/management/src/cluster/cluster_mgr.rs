@@ -129,6 +129,29 @@ impl ClusterApi for ClusterMgr {
         }
     }
 
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_node(&self, node: NodeInfo, seq: MatchSeq) -> Result<u64> {
+        let meta = Some(self.new_lift_time());
+        let value = Operation::Update(serde_json::to_vec(&node)?);
+        let node_key = format!("{}/{}", self.cluster_prefix, escape_for_key(&node.id)?);
+        let upsert_node = self
+            .metastore
+            .upsert_kv(UpsertKVReq::new(&node_key, seq, value, meta));
+
+        match upsert_node.await? {
+            UpsertKVReply {
+                ident: None,
+                prev: Some(_),
+                result: Some(SeqV { seq: s, .. }),
+            } => Ok(s),
+            UpsertKVReply { .. } => Err(ErrorCode::ClusterUnknownNode(format!(
+                "Node with ID '{}' does not exist in the cluster.",
+                node.id
+            ))),
+        }
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn heartbeat(&self, node: &NodeInfo, seq: MatchSeq) -> Result<u64> {
@@ -19,6 +19,8 @@ mod connection;
 mod file_format;
 mod network_policy;
 mod password_policy;
+mod pipe;
+mod procedure;
 mod quota;
 mod role;
 mod serde;
@@ -35,6 +37,8 @@ pub use connection::ConnectionMgr;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
 pub use password_policy::PasswordPolicyMgr;
+pub use pipe::PipeMgr;
+pub use procedure::ProcedureMgr;
 pub use quota::QuotaApi;
 pub use quota::QuotaMgr;
 pub use role::RoleApi;
@@ -104,7 +104,7 @@ impl RoleMgr {
             .await
     }
 
-    /// Build meta-service for a role grantee, which is a tenant's database, table, stage, udf, etc.
+    /// Build meta-service for a role grantee, which is a tenant's database, table, stage, udf, procedure, etc.
     fn ownership_object_ident(&self, object: &OwnershipObject) -> TenantOwnershipObject {
         TenantOwnershipObject::new(self.tenant.clone(), object.clone())
     }
@@ -505,5 +505,6 @@ fn convert_to_grant_obj(owner_obj: &OwnershipObject) -> GrantObject {
         } => GrantObject::TableById(catalog_name.to_string(), *db_id, *table_id),
         OwnershipObject::Stage { name } => GrantObject::Stage(name.to_string()),
         OwnershipObject::UDF { name } => GrantObject::UDF(name.to_string()),
+        OwnershipObject::Procedure { name } => GrantObject::Procedure(name.to_string()),
     }
 }
@@ -113,6 +113,38 @@ async fn test_unknown_node_drop_node() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_successfully_update_node() -> Result<()> {
+    let (_, cluster_api) = new_cluster_api().await?;
+
+    let mut node_info = create_test_node_info();
+    cluster_api.add_node(node_info.clone()).await?;
+
+    node_info.draining = true;
+    cluster_api
+        .update_node(node_info.clone(), MatchSeq::GE(1))
+        .await?;
+
+    let nodes = cluster_api.get_nodes().await?;
+    assert_eq!(nodes, vec![node_info]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_unknown_node_update_node() -> Result<()> {
+    let (_, cluster_api) = new_cluster_api().await?;
+
+    match cluster_api
+        .update_node(create_test_node_info(), MatchSeq::GE(1))
+        .await
+    {
+        Ok(_) => panic!("Unknown node update node must be return Err."),
+        Err(cause) => assert_eq!(cause.code(), 2401),
+    }
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_successfully_heartbeat_node() -> Result<()> {
     let now_ms = SeqV::<()>::now_ms();
@@ -147,6 +179,7 @@ fn create_test_node_info() -> NodeInfo {
         version: 0,
         flight_address: String::from("ip:port"),
         binary_version: "binary_version".to_string(),
+        draining: false,
     }
 }
 
@@ -34,6 +34,8 @@ pub use pipe::PipeItem;
 pub use pipe::SinkPipeBuilder;
 pub use pipe::SourcePipeBuilder;
 pub use pipe::TransformPipeBuilder;
+pub use pipeline::cluster_spill_dir;
+pub use pipeline::query_spill_dir;
 pub use pipeline::query_spill_prefix;
 pub use pipeline::DynTransformBuilder;
 pub use pipeline::Pipeline;
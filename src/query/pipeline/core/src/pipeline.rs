@@ -27,6 +27,8 @@ use crate::pipe::Pipe;
 use crate::pipe::PipeItem;
 use crate::processors::DuplicateProcessor;
 use crate::processors::InputPort;
+use crate::processors::OrderedMergeProcessor;
+use crate::processors::OrderedSplitProcessor;
 use crate::processors::OutputPort;
 use crate::processors::PlanScope;
 use crate::processors::PlanScopeGuard;
@@ -380,6 +382,51 @@ impl Pipeline {
         }
     }
 
+    /// Split the single output of the pipeline into `n` outputs, dispatching the data in round-robin order.
+    ///
+    /// The order of the data can be restored by [`Pipeline::try_ordered_merge`], as long as
+    /// each processor added in between outputs exactly one data per input.
+    pub fn try_ordered_split(&mut self, n: usize) -> Result<()> {
+        match self.pipes.last() {
+            Some(pipe) if pipe.output_length == 1 => {
+                if n <= 1 {
+                    return Ok(());
+                }
+                let processor = OrderedSplitProcessor::create(n);
+                let input = processor.get_input();
+                let outputs = processor.get_outputs();
+                self.add_pipe(Pipe::create(1, n, vec![PipeItem::create(
+                    ProcessorPtr::create(Box::new(processor)),
+                    vec![input],
+                    outputs,
+                )]));
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal(
+                "Ordered split is only allowed for pipe with single output.",
+            )),
+        }
+    }
+
+    /// Merge the outputs of the pipeline in round-robin order, see [`Pipeline::try_ordered_split`].
+    pub fn try_ordered_merge(&mut self) -> Result<()> {
+        match self.pipes.last() {
+            Some(pipe) if pipe.output_length == 1 => Ok(()),
+            Some(pipe) if pipe.output_length > 1 => {
+                let processor = OrderedMergeProcessor::create(pipe.output_length);
+                let inputs = processor.get_inputs();
+                let output = processor.get_output();
+                self.add_pipe(Pipe::create(inputs.len(), 1, vec![PipeItem::create(
+                    ProcessorPtr::create(Box::new(processor)),
+                    inputs,
+                    vec![output],
+                )]));
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("Cannot merge empty pipe.")),
+        }
+    }
+
     /// Duplicate a pipe input to `n` outputs.
     ///
     /// If `force_finish_together` enabled, once one output is finished, the other output will be finished too.
@@ -536,3 +583,20 @@ impl Drop for Pipeline {
 pub fn query_spill_prefix(tenant: &str) -> String {
     format!("_query_spill/{}", tenant)
 }
+
+/// The directory of the spill files of the queries in a cluster.
+pub fn cluster_spill_dir(tenant: &str, cluster_id: &str) -> String {
+    let cluster_id = if cluster_id.is_empty() {
+        "default"
+    } else {
+        cluster_id
+    };
+    format!("{}/{}", query_spill_prefix(tenant), cluster_id)
+}
+
+/// The directory of the spill files of a query, which is removed when the query finishes.
+/// The files written by each node are in the sub directory `<node_id>/`, so the files left
+/// by a crashed node can be told apart: `<prefix>/<cluster_id>/<query_id>/<node_id>/<file>`.
+pub fn query_spill_dir(tenant: &str, cluster_id: &str, query_id: &str) -> String {
+    format!("{}/{}", cluster_spill_dir(tenant, cluster_id), query_id)
+}
@@ -16,12 +16,15 @@ mod port;
 mod processor;
 
 mod duplicate_processor;
+mod ordered_processor;
 mod port_trigger;
 mod profile;
 mod resize_processor;
 mod shuffle_processor;
 
 pub use duplicate_processor::DuplicateProcessor;
+pub use ordered_processor::OrderedMergeProcessor;
+pub use ordered_processor::OrderedSplitProcessor;
 pub use port::connect;
 pub use port::InputPort;
 pub use port::OutputPort;
@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::processors::Event;
+use crate::processors::InputPort;
+use crate::processors::OutputPort;
+use crate::processors::Processor;
+
+/// Dispatches the data of a single input to the outputs in round-robin order.
+///
+/// The n-th data is always pushed to the `n % outputs.len()` output, so that
+/// [`OrderedMergeProcessor`] is able to restore the original order.
+pub struct OrderedSplitProcessor {
+    input: Arc<InputPort>,
+    outputs: Vec<Arc<OutputPort>>,
+    next_output: usize,
+}
+
+impl OrderedSplitProcessor {
+    pub fn create(output_size: usize) -> Self {
+        let outputs = (0..output_size)
+            .map(|_| OutputPort::create())
+            .collect::<Vec<_>>();
+        OrderedSplitProcessor {
+            input: InputPort::create(),
+            outputs,
+            next_output: 0,
+        }
+    }
+
+    pub fn get_input(&self) -> Arc<InputPort> {
+        self.input.clone()
+    }
+
+    pub fn get_outputs(&self) -> Vec<Arc<OutputPort>> {
+        self.outputs.clone()
+    }
+
+    fn finish(&self) -> Event {
+        self.input.finish();
+        for output in &self.outputs {
+            output.finish();
+        }
+        Event::Finished
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for OrderedSplitProcessor {
+    fn name(&self) -> String {
+        "OrderedSplit".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        // the order can not be kept anymore if any of the outputs is finished
+        if self.outputs.iter().any(|output| output.is_finished()) {
+            return Ok(self.finish());
+        }
+
+        if !self.outputs[self.next_output].can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if self.input.has_data() {
+            let data = self.input.pull_data().unwrap();
+            self.outputs[self.next_output].push_data(data);
+            self.next_output = (self.next_output + 1) % self.outputs.len();
+
+            if !self.outputs[self.next_output].can_push() {
+                return Ok(Event::NeedConsume);
+            }
+        }
+
+        if self.input.is_finished() {
+            return Ok(self.finish());
+        }
+
+        self.input.set_need_data();
+        Ok(Event::NeedData)
+    }
+}
+
+/// Merges the data of the inputs in round-robin order.
+///
+/// It restores the order of the data dispatched by [`OrderedSplitProcessor`],
+/// as long as each processor in between outputs exactly one data per input.
+pub struct OrderedMergeProcessor {
+    inputs: Vec<Arc<InputPort>>,
+    output: Arc<OutputPort>,
+    next_input: usize,
+}
+
+impl OrderedMergeProcessor {
+    pub fn create(input_size: usize) -> Self {
+        let inputs = (0..input_size)
+            .map(|_| InputPort::create())
+            .collect::<Vec<_>>();
+        OrderedMergeProcessor {
+            inputs,
+            output: OutputPort::create(),
+            next_input: 0,
+        }
+    }
+
+    pub fn get_inputs(&self) -> Vec<Arc<InputPort>> {
+        self.inputs.clone()
+    }
+
+    pub fn get_output(&self) -> Arc<OutputPort> {
+        self.output.clone()
+    }
+
+    fn finish(&self) -> Event {
+        for input in &self.inputs {
+            input.finish();
+        }
+        self.output.finish();
+        Event::Finished
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for OrderedMergeProcessor {
+    fn name(&self) -> String {
+        "OrderedMerge".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            return Ok(self.finish());
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        let input = &self.inputs[self.next_input];
+        if input.has_data() {
+            self.output.push_data(input.pull_data().unwrap());
+            self.next_input = (self.next_input + 1) % self.inputs.len();
+            return Ok(Event::NeedConsume);
+        }
+
+        // the data of the other inputs (if any) can only come after the data of this input,
+        // so all the data has been merged once the expected input is finished.
+        if input.is_finished() {
+            return Ok(self.finish());
+        }
+
+        input.set_need_data();
+        Ok(Event::NeedData)
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod duplicate;
+mod ordered;
 mod port_test;
 mod resize;
 mod shuffle;
@@ -0,0 +1,116 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_pipeline_core::processors::connect;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OrderedMergeProcessor;
+use databend_common_pipeline_core::processors::OrderedSplitProcessor;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+
+fn block(v: i32) -> DataBlock {
+    DataBlock::new_from_columns(vec![Int32Type::from_data(vec![v])])
+}
+
+fn assert_block_value(block: DataBlock, v: i32) {
+    let col = block.columns()[0].value.as_column().unwrap();
+    assert!(col.eq(&Int32Type::from_data(vec![v])));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ordered_split_processor() -> Result<()> {
+    let mut processor = OrderedSplitProcessor::create(2);
+    let input = processor.get_input();
+    let outputs = processor.get_outputs();
+
+    let upstream_output = OutputPort::create();
+    let downstream_input1 = InputPort::create();
+    let downstream_input2 = InputPort::create();
+
+    unsafe {
+        connect(&input, &upstream_output);
+        connect(&downstream_input1, &outputs[0]);
+        connect(&downstream_input2, &outputs[1]);
+    }
+
+    downstream_input1.set_need_data();
+    downstream_input2.set_need_data();
+
+    upstream_output.push_data(Ok(block(1)));
+    assert!(matches!(processor.event()?, Event::NeedData));
+    upstream_output.push_data(Ok(block(2)));
+    // the first output is not consumed yet
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+    upstream_output.push_data(Ok(block(3)));
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+
+    assert_block_value(downstream_input1.pull_data().unwrap()?, 1);
+    downstream_input1.set_need_data();
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+
+    assert_block_value(downstream_input1.pull_data().unwrap()?, 3);
+    assert_block_value(downstream_input2.pull_data().unwrap()?, 2);
+
+    upstream_output.finish();
+    downstream_input1.set_need_data();
+    downstream_input2.set_need_data();
+    assert!(matches!(processor.event()?, Event::Finished));
+    assert!(downstream_input1.is_finished() && downstream_input2.is_finished());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_ordered_merge_processor() -> Result<()> {
+    let mut processor = OrderedMergeProcessor::create(2);
+    let inputs = processor.get_inputs();
+    let output = processor.get_output();
+
+    let upstream_output1 = OutputPort::create();
+    let upstream_output2 = OutputPort::create();
+    let downstream_input = InputPort::create();
+
+    unsafe {
+        connect(&inputs[0], &upstream_output1);
+        connect(&inputs[1], &upstream_output2);
+        connect(&downstream_input, &output);
+    }
+
+    downstream_input.set_need_data();
+
+    // the data of the second input comes first, but it is merged after the first input
+    upstream_output2.push_data(Ok(block(2)));
+    assert!(matches!(processor.event()?, Event::NeedData));
+
+    upstream_output1.push_data(Ok(block(1)));
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+    assert_block_value(downstream_input.pull_data().unwrap()?, 1);
+
+    downstream_input.set_need_data();
+    assert!(matches!(processor.event()?, Event::NeedConsume));
+    assert_block_value(downstream_input.pull_data().unwrap()?, 2);
+
+    upstream_output1.finish();
+    upstream_output2.finish();
+    downstream_input.set_need_data();
+    assert!(matches!(processor.event()?, Event::Finished));
+    assert!(downstream_input.is_finished());
+
+    Ok(())
+}
@@ -20,6 +20,9 @@ use std::vec;
 use databend_common_ast::ast::BinaryOperator;
 use databend_common_ast::ast::ColumnID;
 use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::DeclareItem;
+use databend_common_ast::ast::ExceptionCondition;
+use databend_common_ast::ast::ExceptionHandler;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FunctionCall;
 use databend_common_ast::ast::Identifier;
@@ -27,6 +30,7 @@ use databend_common_ast::ast::Indirection;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::ReturnItem;
+use databend_common_ast::ast::ScriptBlock;
 use databend_common_ast::ast::ScriptStatement;
 use databend_common_ast::ast::SelectStmt;
 use databend_common_ast::ast::SelectTarget;
@@ -63,6 +67,32 @@ pub fn compile(code: &[ScriptStatement]) -> Result<Vec<ScriptIR>> {
     result
 }
 
+/// Compiles a script block. Errors raised by the declarations or the body are caught
+/// by the `EXCEPTION` handlers of the block, if any.
+#[minitrace::trace]
+pub fn compile_block(block: &ScriptBlock) -> Result<Vec<ScriptIR>> {
+    let mut code = block
+        .declares
+        .iter()
+        .map(|declare| match declare {
+            DeclareItem::Var(declare) => ScriptStatement::LetVar {
+                declare: declare.clone(),
+            },
+            DeclareItem::Set(declare) => ScriptStatement::LetStatement {
+                declare: declare.clone(),
+            },
+        })
+        .collect::<Vec<_>>();
+    code.extend(block.body.iter().cloned());
+
+    let mut compiler = Compiler::new();
+    let result = compiler.compile_block(block.span, &code, &block.exception_handlers);
+
+    assert!(compiler.scopes.len() == 1 || result.is_err());
+
+    result
+}
+
 struct Compiler {
     ref_allocator: RefAllocator,
     scopes: Vec<Scope>,
@@ -269,6 +299,150 @@ impl Compiler {
         Ok(output)
     }
 
+    fn compile_block(
+        &mut self,
+        span: Span,
+        code: &[ScriptStatement],
+        exception_handlers: &[ExceptionHandler],
+    ) -> Result<Vec<ScriptIR>> {
+        if exception_handlers.is_empty() {
+            return self.compile(code);
+        }
+
+        let mut output = vec![];
+
+        let exception_label = LabelRef::new_internal(span, "EXCEPTION", &mut self.ref_allocator);
+        let end_label = LabelRef::new_internal(span, "BLOCK_END", &mut self.ref_allocator);
+
+        // CATCH EXCEPTION
+        output.push(ScriptIR::Catch {
+            to_label: exception_label.clone(),
+        });
+
+        // <body>
+        output.append(&mut self.compile(code)?);
+
+        // GOTO BLOCK_END
+        output.push(ScriptIR::Goto {
+            to_label: end_label.clone(),
+        });
+
+        // Label EXCEPTION
+        output.push(ScriptIR::Label {
+            label: exception_label,
+        });
+
+        // <exception handlers>
+        output.append(&mut self.compile_exception_handlers(
+            span,
+            exception_handlers,
+            end_label.clone(),
+        )?);
+
+        // Label BLOCK_END
+        output.push(ScriptIR::Label { label: end_label });
+
+        Ok(output)
+    }
+
+    fn compile_exception_handlers(
+        &mut self,
+        span: Span,
+        exception_handlers: &[ExceptionHandler],
+        end_label: LabelRef,
+    ) -> Result<Vec<ScriptIR>> {
+        let mut output = vec![];
+
+        self.push_scope();
+
+        // READ_ERROR SQLCODE, SQLERRM
+        let sqlcode = Identifier::from_name(span, "SQLCODE");
+        let sqlerrm = Identifier::from_name(span, "SQLERRM");
+        let to_code = VarRef::new(span, &sqlcode.name, &mut self.ref_allocator);
+        let to_message = VarRef::new(span, &sqlerrm.name, &mut self.ref_allocator);
+        self.declare_ref(&sqlcode, RefItem::Var(to_code.clone()))?;
+        self.declare_ref(&sqlerrm, RefItem::Var(to_message.clone()))?;
+        output.push(ScriptIR::ReadError {
+            to_code,
+            to_message,
+        });
+
+        let then_labels = exception_handlers
+            .iter()
+            .map(|handler| {
+                LabelRef::new_internal(handler.span, "EXCEPTION_THEN", &mut self.ref_allocator)
+            })
+            .collect::<Vec<_>>();
+
+        for (handler, then_label) in exception_handlers.iter().zip(&then_labels) {
+            match &handler.condition {
+                ExceptionCondition::ErrorCodes(codes) => {
+                    // <let condition := is_true(SQLCODE IN (<codes>))>
+                    // JUMP_IF_TRUE condition, EXCEPTION_THEN
+                    let condition = wrap_is_true(Expr::InList {
+                        span: handler.span,
+                        expr: Box::new(Expr::ColumnRef {
+                            span: handler.span,
+                            column: ColumnRef {
+                                database: None,
+                                table: None,
+                                column: ColumnID::Name(sqlcode.clone()),
+                            },
+                        }),
+                        list: codes
+                            .iter()
+                            .map(|code| Expr::Literal {
+                                span: handler.span,
+                                value: Literal::UInt64(*code),
+                            })
+                            .collect(),
+                        not: false,
+                    });
+                    let condition_var = VarRef::new_internal(
+                        condition.span(),
+                        "condition",
+                        &mut self.ref_allocator,
+                    );
+                    output.append(&mut self.compile_expr(&condition, condition_var.clone())?);
+                    output.push(ScriptIR::JumpIfTrue {
+                        condition: condition_var,
+                        to_label: then_label.clone(),
+                    });
+                }
+                ExceptionCondition::Other => {
+                    // GOTO EXCEPTION_THEN
+                    output.push(ScriptIR::Goto {
+                        to_label: then_label.clone(),
+                    });
+                }
+            }
+        }
+
+        // RAISE
+        output.push(ScriptIR::Raise);
+
+        for (handler, then_label) in exception_handlers.iter().zip(&then_labels) {
+            // Label EXCEPTION_THEN
+            output.push(ScriptIR::Label {
+                label: then_label.clone(),
+            });
+
+            // <handler body>
+            self.push_scope();
+            output.append(&mut self.compile(&handler.body)?);
+            self.pop_scope();
+
+            // GOTO BLOCK_END
+            output.push(ScriptIR::Goto {
+                to_label: end_label.clone(),
+            });
+        }
+
+        self.pop_scope();
+
+        Ok(output)
+    }
+
     fn compile_expr(&mut self, expr: &Expr, to_var: VarRef) -> Result<Vec<ScriptIR>> {
         let mut output = vec![];
 
@@ -1146,6 +1320,7 @@ fn wrap_is_true(expr: Expr) -> Expr {
             args: vec![expr],
             params: vec![],
             window: None,
+            order_by: vec![],
             lambda: None,
         },
     }
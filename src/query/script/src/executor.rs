@@ -37,6 +37,8 @@ pub trait Client {
     -> Result<Self::Var>;
     fn set_len(&self, block: &Self::Set) -> usize;
     fn is_true(&self, scalar: &Self::Var) -> Result<bool>;
+    /// Converts a caught error to the error code and the error message variables.
+    fn error_to_vars(&self, error: &ErrorCode) -> Result<(Self::Var, Self::Var)>;
 }
 
 #[derive(Debug, Clone)]
@@ -61,6 +63,8 @@ pub struct Executor<C: Client> {
     sets: HashMap<SetRef, C::Set>,
     iters: HashMap<IterRef, Cursor>,
     label_to_pc: HashMap<LabelRef, usize>,
+    exception_handler: Option<LabelRef>,
+    error: Option<ErrorCode>,
     return_value: Option<ReturnValue<C>>,
     pc: usize,
 }
@@ -84,6 +88,8 @@ impl<C: Client> Executor<C> {
             sets: HashMap::new(),
             iters: HashMap::new(),
             label_to_pc,
+            exception_handler: None,
+            error: None,
             return_value: None,
             pc: 0,
         }
@@ -94,7 +100,17 @@ impl<C: Client> Executor<C> {
             if self.pc >= self.code.len() {
                 return Ok(self.return_value.take());
             }
-            self.step().await?;
+            if let Err(err) = self.step().await {
+                // The exception handler catches at most one error, errors raised
+                // by the handler itself are returned to the caller.
+                match self.exception_handler.take() {
+                    Some(label) => {
+                        self.error = Some(err);
+                        self.goto(&label)?;
+                    }
+                    None => return Err(err),
+                }
+            }
         }
 
         Err(ErrorCode::ScriptExecutionError(format!(
@@ -181,6 +197,25 @@ impl<C: Client> Executor<C> {
                 self.return_value = Some(ReturnValue::Set(self.get_set(set)?.clone()));
                 self.goto_end();
             }
+            ScriptIR::Catch { to_label } => {
+                self.exception_handler = Some(to_label.clone());
+            }
+            ScriptIR::ReadError {
+                to_code,
+                to_message,
+            } => {
+                let error = self.error.as_ref().ok_or_else(|| {
+                    ErrorCode::ScriptExecutionError("no error to handle".to_string())
+                })?;
+                let (code, message) = self.client.error_to_vars(error)?;
+                self.vars.insert(to_code.clone(), code);
+                self.vars.insert(to_message.clone(), message);
+            }
+            ScriptIR::Raise => {
+                return Err(self.error.take().unwrap_or_else(|| {
+                    ErrorCode::ScriptExecutionError("no error to raise".to_string())
+                }));
+            }
         }
 
         self.pc += 1;
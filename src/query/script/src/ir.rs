@@ -92,6 +92,12 @@ pub enum ScriptIR {
     ReturnVar { var: VarRef },
     /// Returns a result set from the script.
     ReturnSet { set: SetRef },
+    /// Jumps to a specified label if any of the following lines raises an error.
+    Catch { to_label: LabelRef },
+    /// Retrieves the code and the message of the caught error to variables.
+    ReadError { to_code: VarRef, to_message: VarRef },
+    /// Raises the caught error again.
+    Raise,
 }
 
 impl Display for ScriptIR {
@@ -122,6 +128,12 @@ impl Display for ScriptIR {
             ScriptIR::Return => write!(f, "RETURN")?,
             ScriptIR::ReturnVar { var } => write!(f, "RETURN {var}")?,
             ScriptIR::ReturnSet { set } => write!(f, "RETURN {set}")?,
+            ScriptIR::Catch { to_label } => write!(f, "CATCH {to_label}")?,
+            ScriptIR::ReadError {
+                to_code,
+                to_message,
+            } => write!(f, "READ_ERROR {to_code}, {to_message}")?,
+            ScriptIR::Raise => write!(f, "RAISE")?,
         };
         Ok(())
     }
@@ -192,7 +204,8 @@ impl StatementTemplate {
                             value: Literal::String(name),
                             ..
                         }) => {
-                            *ident = Identifier::from_name(ident.span, name);
+                            // The object name is resolved from the string at bind time.
+                            *ident = Identifier::from_dynamic_name(ident.span, name);
                         }
                         Ok(value) => {
                             self.error = Some(
@@ -696,6 +696,13 @@ impl Client for MockClient {
     fn is_true(&self, scalar: &Self::Var) -> Result<bool> {
         Ok(*scalar == Literal::Boolean(true))
     }
+
+    fn error_to_vars(&self, error: &ErrorCode) -> Result<(Self::Var, Self::Var)> {
+        Ok((
+            Literal::UInt64(error.code() as u64),
+            Literal::String(error.message()),
+        ))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -27,6 +27,7 @@ use databend_common_users::JwtAuthenticator;
 use databend_common_users::UserApiProvider;
 use minitrace::func_name;
 
+use crate::sessions::ConnectionLimiter;
 use crate::sessions::Session;
 
 pub struct AuthMgr {
@@ -67,6 +68,7 @@ impl AuthMgr {
     #[async_backtrace::framed]
     pub async fn auth(&self, session: Arc<Session>, credential: &Credential) -> Result<()> {
         let user_api = UserApiProvider::instance();
+        let limiter = ConnectionLimiter::instance();
         match credential {
             Credential::Jwt {
                 token: t,
@@ -135,6 +137,8 @@ impl AuthMgr {
                 client_ip,
             } => {
                 let tenant = session.get_current_tenant();
+                limiter.check_lockout(&tenant, n, client_ip.as_deref())?;
+
                 let identity = UserIdentity::new(n, "%");
                 let user = user_api
                     .get_user_with_client_ip(&tenant, identity.clone(), client_ip.as_deref())
@@ -161,6 +165,7 @@ impl AuthMgr {
                     },
                     _ => Err(ErrorCode::AuthenticateFailure("wrong auth type")),
                 };
+                limiter.record_auth_result(&tenant, n, client_ip.as_deref(), authed.is_ok());
                 UserApiProvider::instance()
                     .update_user_login_result(tenant, identity, authed.is_ok())
                     .await?;
@@ -170,6 +175,10 @@ impl AuthMgr {
                 session.set_authed_user(user, None).await?;
             }
         };
+
+        let user = session.get_current_user()?;
+        let guard = limiter.acquire(&session.get_current_tenant(), &user.name)?;
+        session.set_connection_guard(guard);
         Ok(())
     }
 }
@@ -31,6 +31,7 @@ use databend_common_base::base::GlobalInstance;
 use databend_common_base::base::SignalStream;
 use databend_common_base::base::SignalType;
 pub use databend_common_catalog::cluster_info::Cluster;
+use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -48,11 +49,16 @@ use futures::future::Either;
 use futures::Future;
 use futures::StreamExt;
 use log::error;
+use log::info;
 use log::warn;
 use rand::thread_rng;
 use rand::Rng;
+use serde::Deserialize;
+use serde::Serialize;
 
+use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::servers::flight::FlightClient;
+use crate::sessions::SessionManager;
 
 pub struct ClusterDiscovery {
     local_id: String,
@@ -63,6 +69,14 @@ pub struct ClusterDiscovery {
     flight_address: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClusterNodeStatus {
+    pub node_id: String,
+    pub registered: bool,
+    pub draining: bool,
+    pub running_queries_count: usize,
+}
+
 // avoid leak FlightClient to common-xxx
 #[async_trait::async_trait]
 pub trait ClusterHelper {
@@ -213,6 +227,11 @@ impl ClusterDiscovery {
                 let mut res = Vec::with_capacity(cluster_nodes.len());
                 for node in &cluster_nodes {
                     if node.id != self.local_id {
+                        // The draining node is not assigned the fragments of new queries.
+                        if node.draining {
+                            continue;
+                        }
+
                         let start_at = Instant::now();
                         if let Err(cause) = create_client(config, &node.flight_address).await {
                             warn!(
@@ -350,6 +369,123 @@ impl ClusterDiscovery {
         heartbeat.start(node_info);
         Ok(())
     }
+
+    /// The count of the queries running on this node, including the fragments of the
+    /// distributed queries coordinated by the other nodes.
+    pub fn running_queries_count(&self) -> usize {
+        let session_status = SessionManager::instance().get_current_session_status();
+        session_status.running_queries_count as usize
+            + DataExchangeManager::instance().get_queries_count()
+    }
+
+    #[async_backtrace::framed]
+    pub async fn status(self: &Arc<Self>) -> ClusterNodeStatus {
+        let heartbeat = self.heartbeat.lock().await;
+        ClusterNodeStatus {
+            node_id: self.local_id.clone(),
+            registered: heartbeat.node_info.is_some(),
+            draining: heartbeat.node_info.as_ref().is_some_and(|x| x.draining),
+            running_queries_count: self.running_queries_count(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn set_draining(self: &Arc<Self>, draining: bool) -> Result<()> {
+        let mut heartbeat = self.heartbeat.lock().await;
+        let Some(mut node_info) = heartbeat.node_info.clone() else {
+            return Err(ErrorCode::ClusterUnknownNode(format!(
+                "Node with ID '{}' is not registered in the cluster.",
+                self.local_id
+            )));
+        };
+
+        if node_info.draining == draining {
+            return Ok(());
+        }
+
+        heartbeat.shutdown().await?;
+        node_info.draining = draining;
+
+        // Restart the heartbeat with the old node info if the update fails.
+        let update_node = self
+            .api_provider
+            .update_node(node_info.clone(), MatchSeq::GE(1))
+            .await;
+
+        match update_node {
+            Ok(_) => {
+                heartbeat.start(node_info);
+                Ok(())
+            }
+            Err(cause) => {
+                node_info.draining = !draining;
+                heartbeat.start(node_info);
+                Err(cause.add_message_back("(while cluster api update_node)."))
+            }
+        }
+    }
+
+    /// Stops assigning the fragments of new queries to this node, and waits until the
+    /// running queries finish or the timeout.
+    #[async_backtrace::framed]
+    pub async fn drain(self: &Arc<Self>, timeout: Duration) -> Result<ClusterNodeStatus> {
+        self.set_draining(true).await?;
+        info!("Cluster node {} is draining", self.local_id);
+
+        let start_at = Instant::now();
+        while self.running_queries_count() != 0 && start_at.elapsed() < timeout {
+            tokio_async_sleep(Duration::from_millis(200)).await;
+        }
+
+        Ok(self.status().await)
+    }
+
+    /// Drains this node and removes it from the cluster. The node keeps serving the
+    /// remaining queries, it can join the cluster again.
+    #[async_backtrace::framed]
+    pub async fn leave(self: &Arc<Self>, timeout: Duration) -> Result<ClusterNodeStatus> {
+        let status = self.drain(timeout).await?;
+        if status.running_queries_count != 0 {
+            return Err(ErrorCode::Timeout(format!(
+                "Cannot leave the cluster, there are still {} running queries on node {} after {:?}",
+                status.running_queries_count, self.local_id, timeout
+            )));
+        }
+
+        let mut heartbeat = self.heartbeat.lock().await;
+        heartbeat.shutdown().await?;
+        heartbeat.node_info = None;
+
+        let drop_node = self
+            .api_provider
+            .drop_node(self.local_id.clone(), MatchSeq::GE(1))
+            .await;
+
+        if let Err(cause) = drop_node {
+            warn!("Cannot drop cluster node(while leave), cause {:?}", cause);
+        }
+
+        info!("Cluster node {} left the cluster", self.local_id);
+        drop(heartbeat);
+        Ok(self.status().await)
+    }
+
+    /// Registers this node to the cluster again, or stops draining it.
+    #[async_backtrace::framed]
+    pub async fn join(self: &Arc<Self>) -> Result<ClusterNodeStatus> {
+        let registered = self.heartbeat.lock().await.node_info.is_some();
+
+        match registered {
+            true => self.set_draining(false).await?,
+            false => {
+                self.register_to_metastore(&GlobalConfig::instance())
+                    .await?
+            }
+        };
+
+        info!("Cluster node {} joined the cluster", self.local_id);
+        Ok(self.status().await)
+    }
 }
 
 struct ClusterHeartbeat {
@@ -358,6 +494,7 @@ struct ClusterHeartbeat {
     shutdown_notify: Arc<Notify>,
     cluster_api: Arc<dyn ClusterApi>,
     shutdown_handler: Option<JoinHandle<()>>,
+    node_info: Option<NodeInfo>,
     cluster_id: String,
     tenant_id: String,
 }
@@ -375,6 +512,7 @@ impl ClusterHeartbeat {
             shutdown: Arc::new(AtomicBool::new(false)),
             shutdown_notify: Arc::new(Notify::new()),
             shutdown_handler: None,
+            node_info: None,
             cluster_id,
             tenant_id,
         }
@@ -427,6 +565,8 @@ impl ClusterHeartbeat {
     }
 
     pub fn start(&mut self, node_info: NodeInfo) {
+        self.shutdown.store(false, Ordering::Relaxed);
+        self.node_info = Some(node_info.clone());
         self.shutdown_handler = Some(databend_common_base::runtime::spawn(
             self.heartbeat_loop(node_info),
         ));
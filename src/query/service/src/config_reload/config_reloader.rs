@@ -0,0 +1,171 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::GLOBAL_TASK;
+use databend_common_config::Config;
+use databend_common_config::GlobalConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_tracing::set_log_levels;
+use databend_storages_common_cache_manager::CacheManager;
+use log::error;
+use log::info;
+use log::LevelFilter;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::servers::TlsReloader;
+use crate::sessions::QueriesQueueManager;
+use crate::sessions::SessionManager;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    pub name: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// Reloads the config without restarting, on SIGHUP or `POST /v1/config/reload`.
+///
+/// Only the following settings take effect, the changes of the others are ignored and
+/// need a restart:
+/// - `log.file.level` and `log.stderr.level`
+/// - `query.max_active_sessions` and `query.max_running_queries`
+/// - the connection limits and the authentication lockout of `ConnectionLimiter`
+/// - `query.max_storage_io_requests` and `query.parquet_fast_read_bytes`, for new sessions
+/// - the capacities of the in-memory caches, which are emptied by the reload
+///
+/// The TLS certificates of the handlers are also read again from their files on each reload.
+pub struct ConfigReloader;
+
+impl ConfigReloader {
+    /// Reloads the config on SIGHUP.
+    #[cfg(not(target_os = "windows"))]
+    pub fn start() -> Result<()> {
+        use databend_common_base::base::tokio::signal::unix::signal;
+        use databend_common_base::base::tokio::signal::unix::SignalKind;
+
+        let mut hangup = signal(SignalKind::hangup())?;
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            while hangup.recv().await.is_some() {
+                if let Err(cause) = Self::reload_from_file("SIGHUP") {
+                    error!("Failed to reload config on SIGHUP, cause: {:?}", cause);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    pub fn start() -> Result<()> {
+        Ok(())
+    }
+
+    /// Loads the config again from the config file, the environment variables and the
+    /// command line arguments, and applies it.
+    pub fn reload_from_file(source: &str) -> Result<Vec<ConfigChange>> {
+        let config: InnerConfig = Config::load(true)?.try_into()?;
+        Self::reload(&config, source)
+    }
+
+    /// Applies the reloadable settings of the config. Nothing is changed if any of them
+    /// is invalid.
+    pub fn reload(config: &InnerConfig, source: &str) -> Result<Vec<ConfigChange>> {
+        let file_level = parse_log_level("log.file.level", &config.log.file.level)?;
+        let stderr_level = parse_log_level("log.stderr.level", &config.log.stderr.level)?;
+        if config.query.max_active_sessions == 0 {
+            return Err(ErrorCode::InvalidConfig(
+                "query.max_active_sessions must be greater than 0",
+            ));
+        }
+
+        let old = GlobalConfig::instance();
+        let mut new = old.as_ref().clone();
+        let mut changes = vec![];
+        macro_rules! reload {
+            ($($field:ident).+) => {{
+                let changed = old.$($field).+ != config.$($field).+;
+                if changed {
+                    changes.push(ConfigChange {
+                        name: stringify!($($field).+).to_string(),
+                        old_value: format!("{:?}", old.$($field).+),
+                        new_value: format!("{:?}", config.$($field).+),
+                    });
+                    new.$($field).+ = Clone::clone(&config.$($field).+);
+                }
+                changed
+            }};
+        }
+
+        let log_changed = reload!(log.file.level) | reload!(log.stderr.level);
+        if log_changed {
+            // The loggers which are off stay off.
+            let off = |on: bool, level| if on { level } else { LevelFilter::Off };
+            set_log_levels(
+                off(old.log.file.on, file_level),
+                off(old.log.stderr.on, stderr_level),
+            );
+        }
+
+        if reload!(query.max_active_sessions) {
+            SessionManager::instance().set_max_sessions(config.query.max_active_sessions as usize);
+        }
+        if reload!(query.max_running_queries) {
+            QueriesQueueManager::instance().set_permits(config.query.max_running_queries as usize);
+        }
+        // Read by the new sessions from the global config.
+        reload!(query.max_storage_io_requests);
+        reload!(query.parquet_fast_read_bytes);
+        // Read by the connection limiter from the global config on each check.
+        reload!(query.max_connections_per_user);
+        reload!(query.max_connections_per_tenant);
+        reload!(query.auth_failure_max_attempts);
+        reload!(query.auth_failure_window_secs);
+        reload!(query.auth_lockout_secs);
+
+        let cache_changed = reload!(cache.table_meta_snapshot_count)
+            | reload!(cache.table_meta_segment_bytes)
+            | reload!(cache.table_meta_statistic_count)
+            | reload!(cache.table_bloom_index_meta_count)
+            | reload!(cache.table_bloom_index_filter_size)
+            | reload!(cache.table_prune_partitions_count)
+            | reload!(cache.inverted_index_info_count)
+            | reload!(cache.inverted_index_filter_size)
+            | reload!(cache.table_data_deserialized_data_bytes);
+        if cache_changed {
+            CacheManager::reload(&new.cache, &new.query.max_server_memory_usage);
+        }
+
+        GlobalConfig::reload(&new);
+        TlsReloader::instance().reload();
+        for change in &changes {
+            info!(
+                "Config {} changed from {} to {}, reloaded by {}",
+                change.name, change.old_value, change.new_value, source
+            );
+        }
+        if changes.is_empty() {
+            info!("Config reloaded by {}, nothing changed", source);
+        }
+        Ok(changes)
+    }
+}
+
+fn parse_log_level(name: &str, level: &str) -> Result<LevelFilter> {
+    level
+        .parse()
+        .map_err(|_| ErrorCode::InvalidConfig(format!("Invalid {name}: {level}")))
+}
@@ -45,14 +45,19 @@ use databend_common_storages_system::NotificationHistoryTable;
 use databend_common_storages_system::NotificationsTable;
 use databend_common_storages_system::OneTable;
 use databend_common_storages_system::PasswordPoliciesTable;
+use databend_common_storages_system::PlanCacheTable;
+use databend_common_storages_system::ProceduresTable;
 use databend_common_storages_system::ProcessesTable;
 use databend_common_storages_system::ProcessorProfileTable;
 use databend_common_storages_system::QueriesQueueTable;
 use databend_common_storages_system::QueryCacheTable;
 use databend_common_storages_system::QueryLogTable;
+use databend_common_storages_system::QuotasTable;
 use databend_common_storages_system::RolesTable;
 use databend_common_storages_system::SettingsTable;
+use databend_common_storages_system::SlowQueriesTable;
 use databend_common_storages_system::StagesTable;
+use databend_common_storages_system::StorageUsageHistoryTable;
 use databend_common_storages_system::StreamsTable;
 use databend_common_storages_system::TableFunctionsTable;
 use databend_common_storages_system::TablesTableWithHistory;
@@ -63,6 +68,7 @@ use databend_common_storages_system::TempFilesTable;
 use databend_common_storages_system::TracingTable;
 use databend_common_storages_system::UserFunctionsTable;
 use databend_common_storages_system::UsersTable;
+use databend_common_storages_system::ViewDependenciesTable;
 use databend_common_storages_system::ViewsTableWithHistory;
 use databend_common_storages_system::ViewsTableWithoutHistory;
 use databend_common_storages_system::VirtualColumnsTable;
@@ -117,6 +123,11 @@ impl SystemDatabase {
                 sys_db_meta.next_table_id(),
                 config.query.max_query_log_size,
             )),
+            StorageUsageHistoryTable::create(sys_db_meta.next_table_id()),
+            Arc::new(SlowQueriesTable::create(
+                sys_db_meta.next_table_id(),
+                config.query.max_query_log_size,
+            )),
             EnginesTable::create(sys_db_meta.next_table_id()),
             RolesTable::create(sys_db_meta.next_table_id()),
             StagesTable::create(sys_db_meta.next_table_id()),
@@ -136,11 +147,15 @@ impl SystemDatabase {
             LocksTable::create(sys_db_meta.next_table_id()),
             VirtualColumnsTable::create(sys_db_meta.next_table_id()),
             PasswordPoliciesTable::create(sys_db_meta.next_table_id()),
+            QuotasTable::create(sys_db_meta.next_table_id()),
             UserFunctionsTable::create(sys_db_meta.next_table_id()),
             NotificationsTable::create(sys_db_meta.next_table_id()),
             NotificationHistoryTable::create(sys_db_meta.next_table_id()),
             ViewsTableWithHistory::create(sys_db_meta.next_table_id()),
             ViewsTableWithoutHistory::create(sys_db_meta.next_table_id()),
+            ViewDependenciesTable::create(sys_db_meta.next_table_id()),
+            PlanCacheTable::create(sys_db_meta.next_table_id()),
+            ProceduresTable::create(sys_db_meta.next_table_id()),
         ];
 
         let disable_tables = Self::disable_system_tables();
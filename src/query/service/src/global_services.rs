@@ -18,7 +18,7 @@ use std::sync::Arc;
 use databend_common_base::base::GlobalInstance;
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::GlobalQueryRuntime;
-use databend_common_catalog::catalog::CatalogCreator;
+use databend_common_catalog::catalog::CatalogCreatorRegistry;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_config::GlobalConfig;
@@ -26,10 +26,13 @@ use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::CatalogType;
 use databend_common_sharing::ShareEndpointManager;
+use databend_common_sql::PlanCacheManager;
 use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
+use databend_common_storage::StoragePolicies;
 use databend_common_storages_hive::HiveCreator;
 use databend_common_storages_iceberg::IcebergCreator;
+use databend_common_storages_stage::StageDirectory;
 use databend_common_tracing::GlobalLogger;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -41,10 +44,14 @@ use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
 #[cfg(feature = "enable_queries_executor")]
 use crate::pipelines::executor::GlobalQueriesExecutor;
+use crate::pipes::PipeManager;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
 use crate::servers::http::v1::HttpQueryManager;
+use crate::servers::TlsReloader;
+use crate::sessions::ConnectionLimiter;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::storage_usage::StorageUsageCollector;
 
 pub struct GlobalServices;
 
@@ -82,21 +89,17 @@ impl GlobalServices {
         // 4. cluster discovery init.
         ClusterDiscovery::init(config).await?;
 
-        // TODO(xuanwo):
-        //
-        // This part is a bit complex because catalog are used widely in different
-        // crates that we don't have a good place for different kinds of creators.
-        //
-        // Maybe we can do some refactor to simplify the logic here.
+        // The creators of the builtin external catalogs are registered here, the catalogs
+        // of plugins are registered via `register_catalog_plugin` before the services init.
         {
             // Init default catalog.
 
             let default_catalog = DatabaseCatalog::try_create_with_config(config.clone()).await?;
 
-            let catalog_creator: Vec<(CatalogType, Arc<dyn CatalogCreator>)> = vec![
+            let catalog_creator = CatalogCreatorRegistry::create(vec![
                 (CatalogType::Iceberg, Arc::new(IcebergCreator)),
                 (CatalogType::Hive, Arc::new(HiveCreator)),
-            ];
+            ])?;
 
             CatalogManager::init(config, Arc::new(default_catalog), catalog_creator).await?;
         }
@@ -105,7 +108,10 @@ impl GlobalServices {
         HttpQueryManager::init(config).await?;
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
+        ConnectionLimiter::init()?;
+        TlsReloader::init()?;
         LockManager::init()?;
+        PlanCacheManager::init()?;
         AuthMgr::init(config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
@@ -118,6 +124,10 @@ impl GlobalServices {
         ShareEndpointManager::init()?;
 
         DataOperator::init(&config.storage).await?;
+        StoragePolicies::init(&config.storage_policies).await?;
+        StageDirectory::init()?;
+        PipeManager::init(config)?;
+        StorageUsageCollector::init(config)?;
         ShareTableConfig::init(
             &config.query.share_endpoint_address,
             &config.query.share_endpoint_auth_token_file,
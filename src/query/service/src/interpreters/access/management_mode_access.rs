@@ -48,6 +48,7 @@ impl AccessChecker for ManagementModeAccess {
                             | RewriteKind::ShowTables(_, _)
                             | RewriteKind::ShowColumns(_, _, _)
                             | RewriteKind::ShowEngines
+                            | RewriteKind::ShowQuotas
                             | RewriteKind::ShowSettings
                             | RewriteKind::ShowFunctions
                             | RewriteKind::ShowUserFunctions
@@ -70,6 +71,7 @@ impl AccessChecker for ManagementModeAccess {
 
                 // Set
                 | Plan::SetVariable(_)
+                | Plan::ShowWarnings(_)
 
                 // Database.
                 | Plan::CreateDatabase(_)
@@ -101,6 +103,7 @@ impl AccessChecker for ManagementModeAccess {
                 // Stage.
                 | Plan::CreateStage(_)
                 | Plan::DropStage(_)
+                | Plan::RefreshStage(_)
                 // Network policy.
                 | Plan::CreateNetworkPolicy(_)
                 | Plan::AlterNetworkPolicy(_)
@@ -45,6 +45,16 @@ pub struct PrivilegeAccess {
     ctx: Arc<QueryContext>,
 }
 
+/// The denial in the detail of the permission denied error, `CALL system$check_privilege()` tells
+/// which grants each of the roles has on the object.
+#[derive(serde::Serialize)]
+struct PermissionDeniedDetail {
+    required_privilege: String,
+    object: String,
+    user: String,
+    roles: Vec<String>,
+}
+
 enum ObjectId {
     Database(u64),
     Table(u64, u64),
@@ -140,6 +150,9 @@ impl PrivilegeAccess {
             GrantObject::UDF(name) => OwnershipObject::UDF {
                 name: name.to_string(),
             },
+            GrantObject::Procedure(name) => OwnershipObject::Procedure {
+                name: name.to_string(),
+            },
             GrantObject::Global => return Ok(None),
         };
 
@@ -182,23 +195,12 @@ impl PrivilegeAccess {
                             if err.code() != ErrorCode::PERMISSION_DENIED {
                                 return Err(err);
                             }
-                            let current_user = self.ctx.get_current_user()?;
-                            let session = self.ctx.get_current_session();
-                            let roles_name = session
-                                .get_all_effective_roles()
-                                .await?
-                                .iter()
-                                .map(|r| r.name.clone())
-                                .collect::<Vec<_>>()
-                                .join(",");
-                            return Err(ErrorCode::PermissionDenied(format!(
-                                "Permission denied: privilege [{:?}] is required on '{}'.'{}'.* for user {} with roles [{}]",
-                                privileges,
-                                catalog_name,
-                                db_name,
-                                &current_user.identity(),
-                                roles_name,
-                            )));
+                            return Err(self
+                                .permission_denied(
+                                    privileges,
+                                    format!("'{}'.'{}'.*", catalog_name, db_name),
+                                )
+                                .await?);
                         }
                     }
                     Err(e) => match e.code() {
@@ -279,24 +281,15 @@ impl PrivilegeAccess {
                                     if err.code() != ErrorCode::PERMISSION_DENIED {
                                         return Err(err);
                                     }
-                                    let current_user = self.ctx.get_current_user()?;
-                                    let session = self.ctx.get_current_session();
-                                    let roles_name = session
-                                        .get_all_effective_roles()
-                                        .await?
-                                        .iter()
-                                        .map(|r| r.name.clone())
-                                        .collect::<Vec<_>>()
-                                        .join(",");
-                                    return Err(ErrorCode::PermissionDenied(format!(
-                                        "Permission denied: privilege [{:?}] is required on '{}'.'{}'.'{}' for user {} with roles [{}]",
-                                        privilege,
-                                        catalog_name,
-                                        db_name,
-                                        table_name,
-                                        &current_user.identity(),
-                                        roles_name,
-                                    )));
+                                    return Err(self
+                                        .permission_denied(
+                                            privilege,
+                                            format!(
+                                                "'{}'.'{}'.'{}'",
+                                                catalog_name, db_name, table_name
+                                            ),
+                                        )
+                                        .await?);
                                 }
                             }
                             Err(e) => match e.code() {
@@ -363,6 +356,7 @@ impl PrivilegeAccess {
             | GrantObject::DatabaseById(_, _)
             | GrantObject::UDF(_)
             | GrantObject::Stage(_)
+            | GrantObject::Procedure(_)
             | GrantObject::TableById(_, _, _) => true,
             GrantObject::Global => false,
         };
@@ -378,34 +372,55 @@ impl PrivilegeAccess {
                 if err.code() != ErrorCode::PERMISSION_DENIED {
                     return Err(err);
                 }
-                let current_user = self.ctx.get_current_user()?;
-
-                let roles_name = session
-                    .get_all_effective_roles()
-                    .await?
-                    .iter()
-                    .map(|r| r.name.clone())
-                    .collect::<Vec<_>>()
-                    .join(",");
                 match grant_object {
                     GrantObject::TableById(_, _, _) => Err(ErrorCode::PermissionDenied("")),
                     GrantObject::DatabaseById(_, _) => Err(ErrorCode::PermissionDenied("")),
                     GrantObject::Global
                     | GrantObject::UDF(_)
                     | GrantObject::Stage(_)
+                    | GrantObject::Procedure(_)
                     | GrantObject::Database(_, _)
-                    | GrantObject::Table(_, _, _) => Err(ErrorCode::PermissionDenied(format!(
-                        "Permission denied: privilege [{:?}] is required on {} for user {} with roles [{}]",
-                        privilege,
-                        grant_object,
-                        &current_user.identity(),
-                        roles_name,
-                    ))),
+                    | GrantObject::Table(_, _, _) => Err(self
+                        .permission_denied(privilege, grant_object.to_string())
+                        .await?),
                 }
             }
         }
     }
 
+    // Builds the error on the privilege is not granted, the message names the roles evaluated, and
+    // the detail carries the same in JSON for the clients, see `PermissionDeniedDetail`.
+    async fn permission_denied(
+        &self,
+        privilege: UserPrivilegeType,
+        object: String,
+    ) -> Result<ErrorCode> {
+        let current_user = self.ctx.get_current_user()?;
+        let roles = self
+            .ctx
+            .get_current_session()
+            .get_all_effective_roles()
+            .await?
+            .iter()
+            .map(|r| r.name.clone())
+            .collect::<Vec<_>>();
+
+        let message = format!(
+            "Permission denied: privilege [{:?}] is required on {} for user {} with roles [{}]",
+            privilege,
+            object,
+            &current_user.identity(),
+            roles.join(","),
+        );
+        let detail = PermissionDeniedDetail {
+            required_privilege: privilege.to_string(),
+            object,
+            user: current_user.identity().to_string(),
+            roles,
+        };
+        Ok(ErrorCode::PermissionDenied(message).add_detail(serde_json::to_string(&detail)?))
+    }
+
     async fn validate_stage_access(
         &self,
         stage_info: &StageInfo,
@@ -495,6 +510,7 @@ impl AccessChecker for PrivilegeAccess {
                 match rewrite_kind {
                     Some(RewriteKind::ShowDatabases)
                     | Some(RewriteKind::ShowEngines)
+                    | Some(RewriteKind::ShowQuotas)
                     | Some(RewriteKind::ShowFunctions)
                     | Some(RewriteKind::ShowUserFunctions) => {
                         return Ok(());
@@ -602,7 +618,9 @@ impl AccessChecker for PrivilegeAccess {
                     }
                 }
             }
-            Plan::ExplainAnalyze { plan } | Plan::Explain { plan, .. } => {
+            Plan::ExplainAnalyze { plan }
+            | Plan::Explain { plan, .. }
+            | Plan::DescribeQuery { plan } => {
                 self.check(ctx, plan).await?
             }
 
@@ -625,6 +643,15 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Drop)
                     .await?;
             }
+            Plan::BackupDatabase(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, UserPrivilegeType::Super, false).await?;
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Write).await?;
+            }
+            Plan::RestoreDatabase(plan) => {
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::Super)
+                    .await?;
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Read).await?;
+            }
             Plan::UseDatabase(plan) => {
                 let session = self.ctx.get_current_session();
                 if self.has_ownership(&session, &GrantObject::Database(catalog_name.clone(), plan.database.clone())).await? {
@@ -781,7 +808,12 @@ impl AccessChecker for PrivilegeAccess {
             }
             Plan::Replace(plan) => {
                 //plan.delete_when is Expr no need to check privileges.
-                let privileges = vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete];
+                // INSERT into a table with an enforced primary key never deletes rows.
+                let privileges = if plan.error_on_conflict {
+                    vec![UserPrivilegeType::Insert]
+                } else {
+                    vec![UserPrivilegeType::Insert, UserPrivilegeType::Delete]
+                };
                 for privilege in privileges {
                     self.validate_table_access(&plan.catalog, &plan.database, &plan.table, privilege, false).await?;
                 }
@@ -942,12 +974,32 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowObjectGrantPrivileges(_)
             | Plan::ShowGrantTenantsOfShare(_)
             | Plan::GrantRole(_)
-            | Plan::GrantPriv(_)
-            | Plan::RevokePriv(_)
             | Plan::RevokeRole(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Grant)
                     .await?;
             }
+            // The owner of a procedure grants and revokes the usage of it.
+            Plan::GrantPriv(plan) => {
+                let object = match &plan.on {
+                    GrantObject::Procedure(_) => plan.on.clone(),
+                    _ => GrantObject::Global,
+                };
+                self.validate_access(&object, UserPrivilegeType::Grant)
+                    .await?;
+            }
+            Plan::RevokePriv(plan) => {
+                let on_procedures = !plan.on.is_empty()
+                    && plan.on.iter().all(|on| matches!(on, GrantObject::Procedure(_)));
+                if on_procedures {
+                    for on in &plan.on {
+                        self.validate_access(on, UserPrivilegeType::Grant)
+                            .await?;
+                    }
+                } else {
+                    self.validate_access(&GrantObject::Global, UserPrivilegeType::Grant)
+                        .await?;
+                }
+            }
             Plan::SetVariable(_) | Plan::UnSetVariable(_) | Plan::Kill(_) => {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super)
                     .await?;
@@ -1003,6 +1055,9 @@ impl AccessChecker for PrivilegeAccess {
             Plan::RemoveStage(plan) => {
                 self.validate_stage_access(&plan.stage, UserPrivilegeType::Write).await?;
             }
+            Plan::RefreshStage(plan) => {
+                self.validate_stage_access(&plan.stage, UserPrivilegeType::Read).await?;
+            }
             Plan::CreateShareEndpoint(_)
             | Plan::ShowShareEndpoint(_)
             | Plan::DropShareEndpoint(_)
@@ -1031,6 +1086,12 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
             | Plan::DropConnection(_)
+            | Plan::CreatePipe(_)
+            | Plan::AlterPipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescPipe(_)
+            | Plan::CreateProcedure(_)
+            | Plan::DropProcedure(_)
             | Plan::CreateUDF(_)
             | Plan::CreateIndex(_)
             | Plan::CreateTableIndex(_)
@@ -1059,6 +1120,7 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            Plan::ShowWarnings(_) => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
                     PresignAction::Upload => UserPrivilegeType::Write,
@@ -1075,6 +1137,16 @@ impl AccessChecker for PrivilegeAccess {
             Plan::Commit => {}
             Plan::Abort => {}
             Plan::ExecuteImmediate(_) => {}
+            // Calling a procedure requires USAGE on it, the statements of the procedure are checked
+            // when they run, with the privileges of the owner or the caller depending on how the
+            // procedure is defined.
+            Plan::CallProcedure(plan) => {
+                self.validate_access(
+                    &GrantObject::Procedure(plan.procedure.name.clone()),
+                    UserPrivilegeType::Usage,
+                )
+                    .await?;
+            }
         }
 
         Ok(())
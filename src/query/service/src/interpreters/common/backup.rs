@@ -0,0 +1,162 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use futures::TryStreamExt;
+use opendal::EntryMode;
+use opendal::Metakey;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The files of the backed up tables are copied into this directory, keeping their
+/// paths in the table storage, e.g. `<path>/data/<db_id>/<table_id>/_b/<block>.parquet`.
+/// As the files of fuse tables are never modified, the backups in the same path share
+/// the files of the unchanged segments and blocks.
+pub const BACKUP_DATA_DIR: &str = "data";
+/// One manifest per backup: `<path>/manifests/<backup_id>.json`.
+pub const BACKUP_MANIFEST_DIR: &str = "manifests";
+
+/// The manifest of a database backup, it contains all the definitions and the files
+/// needed to restore the database, no matter whether the backup is incremental or not.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupManifest {
+    /// Backup ids are ordered by the creation time.
+    pub backup_id: String,
+    /// The backup whose files are reused by an incremental backup.
+    pub base_backup_id: Option<String>,
+    pub created_on: DateTime<Utc>,
+    pub catalog: String,
+    pub database: String,
+    pub db_id: u64,
+    pub owner: Option<String>,
+    pub tables: Vec<BackupTable>,
+    pub views: Vec<BackupView>,
+    pub grants: Vec<BackupGrant>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupTable {
+    pub name: String,
+    pub table_id: u64,
+    pub schema: TableSchema,
+    /// The options to create the table with, including the comment.
+    pub options: BTreeMap<String, String>,
+    pub field_comments: Vec<String>,
+    pub cluster_key: Option<String>,
+    pub owner: Option<String>,
+    /// None if the table has no data.
+    pub snapshot: Option<BackupSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupSnapshot {
+    pub snapshot_id: String,
+    /// The location of the snapshot, relative to the data directory.
+    pub location: String,
+    /// The prefix of the table files, relative to the data directory.
+    pub storage_prefix: String,
+    /// All the files referenced by the snapshot, including the snapshot itself.
+    pub files: Vec<String>,
+    pub row_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupView {
+    pub name: String,
+    pub query: String,
+    pub owner: Option<String>,
+}
+
+/// The privileges granted to a role on the database, or on a table if `table` is set.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BackupGrant {
+    pub role: String,
+    pub table: Option<String>,
+    pub privileges: Vec<UserPrivilegeType>,
+}
+
+/// The directory of the backups in the stage, empty or ends with '/'.
+pub fn backup_root(path: &str) -> String {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!("{path}/")
+    }
+}
+
+pub fn backup_manifest_location(root: &str, backup_id: &str) -> String {
+    format!("{root}{BACKUP_MANIFEST_DIR}/{backup_id}.json")
+}
+
+pub async fn write_backup_manifest(
+    op: &Operator,
+    root: &str,
+    manifest: &BackupManifest,
+) -> Result<()> {
+    let location = backup_manifest_location(root, &manifest.backup_id);
+    let content = serde_json::to_vec_pretty(manifest)?;
+    op.write(&location, content).await?;
+    Ok(())
+}
+
+/// Read the manifest of the given backup, or of the latest backup if `backup_id` is None.
+pub async fn read_backup_manifest(
+    op: &Operator,
+    root: &str,
+    backup_id: Option<&str>,
+) -> Result<Option<BackupManifest>> {
+    let backup_id = match backup_id {
+        Some(backup_id) => backup_id.to_string(),
+        None => match list_backup_ids(op, root).await?.pop() {
+            Some(backup_id) => backup_id,
+            None => return Ok(None),
+        },
+    };
+
+    let location = backup_manifest_location(root, &backup_id);
+    let content = match op.read(&location).await {
+        Ok(content) => content,
+        Err(e) if e.kind() == opendal::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let manifest = serde_json::from_slice(&content)
+        .map_err(|e| ErrorCode::StorageOther(format!("invalid backup manifest {location}: {e}")))?;
+    Ok(Some(manifest))
+}
+
+/// The ids of the backups in the directory, in ascending order.
+async fn list_backup_ids(op: &Operator, root: &str) -> Result<Vec<String>> {
+    let prefix = format!("{root}{BACKUP_MANIFEST_DIR}/");
+    let mut lister = op.lister_with(&prefix).metakey(Metakey::Mode).await?;
+    let mut backup_ids = vec![];
+    while let Some(entry) = lister.try_next().await? {
+        if entry.metadata().mode() != EntryMode::FILE {
+            continue;
+        }
+        if let Some(backup_id) = entry.name().strip_suffix(".json") {
+            backup_ids.push(backup_id.to_string());
+        }
+    }
+    backup_ids.sort();
+    Ok(backup_ids)
+}
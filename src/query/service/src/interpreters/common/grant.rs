@@ -93,6 +93,11 @@ pub async fn validate_grant_object_exists(
                 )));
             }
         }
+        GrantObject::Procedure(procedure) => {
+            UserApiProvider::instance()
+                .get_procedure(&tenant, procedure)
+                .await?;
+        }
         GrantObject::Global => (),
     }
 
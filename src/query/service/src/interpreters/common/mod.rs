@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod backup;
 mod grant;
 mod metrics;
 mod notification;
 mod query_log;
+mod slow_query_log;
 mod stream;
 mod table;
 mod task;
 mod util;
+mod view;
 
 pub use grant::validate_grant_object_exists;
 pub use notification::get_notification_client_config;
 pub use query_log::InterpreterQueryLog;
+pub use slow_query_log::InterpreterSlowQueryLog;
 pub use stream::build_update_stream_meta_seq;
 pub use table::check_referenced_computed_columns;
 pub use task::get_task_client_config;
@@ -31,5 +35,10 @@ pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
+pub use view::check_shared_table_revocable;
+pub use view::check_shared_view_dependencies;
+pub use view::collect_view_dependencies;
+pub use view::find_dependent_views;
 
+pub use self::backup::*;
 pub use self::metrics::*;
@@ -0,0 +1,89 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::SystemTime;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storages_system::SlowQueryLogElement;
+use databend_common_storages_system::SlowQueryLogQueue;
+
+use crate::sessions::convert_query_log_timestamp;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct InterpreterSlowQueryLog;
+
+impl InterpreterSlowQueryLog {
+    /// Records the query into `system.slow_queries` if it ran longer than `long_query_time`.
+    pub fn log_finish(ctx: &QueryContext, now: SystemTime, err: Option<ErrorCode>) -> Result<()> {
+        let long_query_time = ctx.get_settings().get_long_query_time()?;
+        let query_duration_ms = ctx.get_query_duration_ms();
+        if long_query_time == 0 || (query_duration_ms as u64) < long_query_time * 1000 {
+            return Ok(());
+        }
+
+        let mut changes = ctx
+            .get_settings()
+            .changes()
+            .iter()
+            .map(|item| (item.key().clone(), item.value().value.to_string()))
+            .collect::<Vec<_>>();
+        changes.sort();
+        let mut settings = String::new();
+        for (name, value) in changes {
+            if !settings.is_empty() {
+                settings.push_str(", ");
+            }
+            write!(settings, "{}={}", name, value).expect("write to string must succeed");
+        }
+
+        let query_profiles = ctx.get_query_profiles();
+        let profiles = serde_json::to_string(&query_profiles)?;
+        let explain_analyze = match ctx.get_sampled_physical_plan() {
+            Some((plan, metadata)) => {
+                let profs = query_profiles
+                    .into_iter()
+                    .filter(|x| x.id.is_some())
+                    .map(|x| (x.id.unwrap(), x))
+                    .collect::<HashMap<_, _>>();
+                plan.format(metadata, profs)?.format_pretty()?
+            }
+            None => "".to_string(),
+        };
+
+        let (exception_code, exception_text) = match err {
+            None => (0, "".to_string()),
+            Some(e) => (e.code().into(), e.to_string()),
+        };
+
+        SlowQueryLogQueue::instance()?.append_data(SlowQueryLogElement {
+            query_id: ctx.get_id(),
+            event_time: convert_query_log_timestamp(now),
+            query_start_time: convert_query_log_timestamp(ctx.get_created_time()),
+            query_duration_ms,
+            sql_user: ctx.get_current_user()?.name,
+            current_database: ctx.get_current_database(),
+            query_text: ctx.get_query_str(),
+            settings,
+            query_plan: ctx.get_query_plan().unwrap_or_default(),
+            profiles,
+            explain_analyze,
+            exception_code,
+            exception_text,
+        })
+    }
+}
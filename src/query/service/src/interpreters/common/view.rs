@@ -0,0 +1,184 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::ShareApi;
+use databend_common_meta_app::schema::ViewDependency;
+use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
+use databend_common_meta_app::share::GetShareGrantObjectReq;
+use databend_common_meta_app::share::ShareGrantObjectName;
+use databend_common_sql::MetadataRef;
+use databend_common_storages_factory::Table;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use log::warn;
+
+use crate::sessions::QueryContext;
+
+/// Collects the tables referenced by the query of a view. The tables under the views
+/// in the query are recorded as the dependencies of those views instead.
+pub fn collect_view_dependencies(
+    metadata: &MetadataRef,
+    table_functions: &[String],
+) -> Vec<ViewDependency> {
+    let metadata = metadata.read();
+    metadata
+        .tables()
+        .iter()
+        .filter(|table| {
+            !table.is_source_of_view()
+                && !table.table().is_stage_table()
+                && !table_functions.contains(&table.name().to_string())
+        })
+        .map(|table| ViewDependency {
+            catalog: table.catalog().to_string(),
+            database: table.database().to_string(),
+            table: table.name().to_string(),
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Finds the views in the catalog whose query refers to the given table,
+/// returns the database name and the view.
+pub async fn find_dependent_views(
+    ctx: &QueryContext,
+    catalog_name: &str,
+    database: &str,
+    table: &str,
+) -> Result<Vec<(String, Arc<dyn Table>)>> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog_name).await?;
+    let dependency = ViewDependency {
+        catalog: catalog_name.to_string(),
+        database: database.to_string(),
+        table: table.to_string(),
+    };
+
+    let mut views = vec![];
+    for db in catalog.list_databases(&tenant).await? {
+        let tables = match catalog.list_tables(&tenant, db.name()).await {
+            Ok(tables) => tables,
+            Err(err) if db.get_db_info().meta.from_share.is_some() => {
+                warn!("list tables failed on sharing db {}: {}", db.name(), err);
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        for view in tables {
+            if view.engine() == VIEW_ENGINE
+                && view
+                    .get_table_info()
+                    .meta
+                    .view_dependencies
+                    .contains(&dependency)
+            {
+                views.push((db.name().to_string(), view));
+            }
+        }
+    }
+    Ok(views)
+}
+
+/// The tables and views granted to the share.
+async fn list_shared_tables(ctx: &QueryContext, share: &str) -> Result<Vec<(String, String)>> {
+    let meta_api = UserApiProvider::instance().get_meta_store_client();
+    let reply = meta_api
+        .get_share_grant_objects(GetShareGrantObjectReq {
+            share_name: ShareNameIdent::new(ctx.get_tenant(), share),
+        })
+        .await?;
+    Ok(reply
+        .objects
+        .into_iter()
+        .filter_map(|object| match object.object {
+            ShareGrantObjectName::Table(db, table) => Some((db, table)),
+            ShareGrantObjectName::Database(_) => None,
+        })
+        .collect())
+}
+
+/// Checks that a view granted to the share refers only to the tables granted to the
+/// same share. The consumers resolve the tables of a shared view in the database
+/// mounted from the share, so the other tables are never visible to them.
+pub async fn check_shared_view_dependencies(
+    ctx: &QueryContext,
+    share: &str,
+    database: &str,
+    view: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let table = ctx
+        .get_default_catalog()?
+        .get_table(&tenant, database, view)
+        .await?;
+    if table.engine() != VIEW_ENGINE {
+        return Ok(());
+    }
+
+    let shared_tables = list_shared_tables(ctx, share).await?;
+    for dep in &table.get_table_info().meta.view_dependencies {
+        if dep.catalog != CATALOG_DEFAULT
+            || dep.database != database
+            || !shared_tables.contains(&(dep.database.clone(), dep.table.clone()))
+        {
+            return Err(ErrorCode::WrongShareObject(format!(
+                "View {database}.{view} refers to {}.{}.{} which is not granted to share {share}",
+                dep.catalog, dep.database, dep.table
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that no view granted to the share refers to the table to be revoked.
+pub async fn check_shared_table_revocable(
+    ctx: &QueryContext,
+    share: &str,
+    database: &str,
+    table: &str,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_default_catalog()?;
+    let dependency = ViewDependency {
+        catalog: CATALOG_DEFAULT.to_string(),
+        database: database.to_string(),
+        table: table.to_string(),
+    };
+    for (db, name) in list_shared_tables(ctx, share).await? {
+        if db == database && name == table {
+            continue;
+        }
+        let view = catalog.get_table(&tenant, &db, &name).await?;
+        if view.engine() == VIEW_ENGINE
+            && view
+                .get_table_info()
+                .meta
+                .view_dependencies
+                .contains(&dependency)
+        {
+            return Err(ErrorCode::WrongShareObject(format!(
+                "Table {database}.{table} is referred by view {db}.{name} of share {share}, revoke the view first"
+            )));
+        }
+    }
+    Ok(())
+}
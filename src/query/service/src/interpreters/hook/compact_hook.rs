@@ -16,6 +16,8 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::IoPriority;
+use databend_common_base::runtime::QueryPriority;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::Pipeline;
@@ -135,7 +137,10 @@ async fn compact_table(
     // execute the compact pipeline (for table with cluster keys, re-cluster will also be executed)
     let settings = ctx.get_settings();
     build_res.set_max_threads(settings.get_max_threads()? as usize);
-    let settings = ExecutorSettings::try_create(ctx.clone())?;
+    let mut settings = ExecutorSettings::try_create(ctx.clone())?;
+    // compaction after the write should not slow down the interactive queries.
+    settings.priority = QueryPriority::Low;
+    settings.io_priority = IoPriority::Background;
 
     if build_res.main_pipeline.is_complete_pipeline()? {
         let mut pipelines = build_res.sources_pipelines;
@@ -27,14 +27,19 @@ use databend_common_expression::SendableDataBlockStream;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::SourcePipeBuilder;
 use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanCacheManager;
 use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
+use databend_common_sql::QueryTextRedactor;
+use futures::StreamExt;
 use log::error;
 use log::info;
 
 use crate::interpreters::interpreter_txn_commit::CommitInterpreter;
+use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterMetrics;
 use crate::interpreters::InterpreterQueryLog;
+use crate::interpreters::InterpreterSlowQueryLog;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
 use crate::pipelines::executor::PipelinePullingExecutor;
@@ -42,6 +47,7 @@ use crate::pipelines::PipelineBuildResult;
 use crate::sessions::short_sql;
 use crate::sessions::QueryContext;
 use crate::sessions::SessionManager;
+use crate::spillers::remove_query_spill_files;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
@@ -92,6 +98,11 @@ pub trait Interpreter: Sync + Send {
                 return Err(build_error);
             }
         };
+        if self.is_ddl() {
+            // The cached plans may refer to the objects changed by the DDL, the other nodes
+            // find the changed tables and views by their versions on lookup.
+            PlanCacheManager::instance().invalidate();
+        }
 
         if build_res.main_pipeline.is_empty() {
             InterpreterMetrics::record_query_finished(&ctx, None);
@@ -136,6 +147,7 @@ pub trait Interpreter: Sync + Send {
 
             InterpreterMetrics::record_query_finished(&query_ctx, err_opt.clone());
             log_query_finished(&query_ctx, err_opt, has_profiles);
+            remove_query_spill_files(&query_ctx);
 
             match may_error {
                 Ok(_) => Ok(()),
@@ -156,14 +168,31 @@ pub trait Interpreter: Sync + Send {
             let complete_executor = PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
 
             ctx.set_executor(complete_executor.get_inner())?;
-            complete_executor.execute()?;
+            if let Err(cause) = complete_executor.execute() {
+                if let Some(sql) = shuffle_join_fallback_query(&ctx, &cause)? {
+                    return replan_with_shuffle_join(ctx, &sql).await;
+                }
+                return Err(cause);
+            }
             self.inject_result()
         } else {
             let pulling_executor = PipelinePullingExecutor::from_pipelines(build_res, settings)?;
 
             ctx.set_executor(pulling_executor.get_inner())?;
+            let mut stream: SendableDataBlockStream =
+                Box::pin(PullingExecutorStream::create(pulling_executor)?);
+            if can_replan_with_shuffle_join(&ctx)? {
+                // The query can be re-planned only if nothing has been returned.
+                let first = stream.next().await;
+                if let Some(Err(cause)) = &first
+                    && let Some(sql) = shuffle_join_fallback_query(&ctx, cause)?
+                {
+                    return replan_with_shuffle_join(ctx, &sql).await;
+                }
+                stream = Box::pin(futures::stream::iter(first).chain(stream));
+            }
             Ok(Box::pin(ProgressStream::try_create(
-                Box::pin(PullingExecutorStream::create(pulling_executor)?),
+                stream,
                 ctx.get_result_progress(),
             )?))
         }
@@ -204,27 +233,70 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
     let session = ctx.get_current_session();
 
     session.get_status().write().query_finish();
+    ctx.save_last_warnings();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
     }
 
+    if let Err(error) = InterpreterSlowQueryLog::log_finish(ctx, now, error.clone()) {
+        error!("interpreter.slow_query_log.error: {:?}", error)
+    }
+
     if let Err(error) = InterpreterQueryLog::log_finish(ctx, now, error, has_profiles) {
         error!("interpreter.finish.error: {:?}", error)
     }
 }
 
+/// Returns the SQL to re-plan if the query failed because the build side of broadcast join
+/// is too large and it isn't re-planned yet.
+fn shuffle_join_fallback_query(ctx: &QueryContext, cause: &ErrorCode) -> Result<Option<String>> {
+    if cause.code() != ErrorCode::BROADCAST_JOIN_BUILD_TOO_LARGE
+        || !can_replan_with_shuffle_join(ctx)?
+    {
+        return Ok(None);
+    }
+    Ok(ctx.get_original_query())
+}
+
+fn can_replan_with_shuffle_join(ctx: &QueryContext) -> Result<bool> {
+    let settings = ctx.get_settings();
+    Ok(!ctx.get_cluster().is_empty()
+        && settings.get_broadcast_join_max_build_bytes()? != 0
+        && !settings.get_enforce_broadcast_join()?
+        && !settings.get_enforce_shuffle_join()?
+        && ctx.get_original_query().is_some())
+}
+
+/// Re-plans and executes the query with shuffle join, as the statistics are too stale
+/// to choose broadcast join.
+async fn replan_with_shuffle_join(
+    ctx: Arc<QueryContext>,
+    sql: &str,
+) -> Result<SendableDataBlockStream> {
+    info!(
+        "{}: the build side of broadcast join is too large, re-plan the query with shuffle join",
+        ctx.get_id()
+    );
+    ctx.get_settings().set_enforce_shuffle_join(true)?;
+    let (plan, _) = interpreter_plan_sql(ctx.clone(), sql).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter.execute(ctx).await
+}
+
 /// There are two steps to execute a query:
 /// 1. Plan the SQL
 /// 2. Execute the plan -- interpreter
 ///
 /// This function is used to plan the SQL. If an error occurs, we will log the query start and finished.
 pub async fn interpreter_plan_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<(Plan, PlanExtras)> {
+    ctx.attach_original_query(sql);
     let mut planner = Planner::new(ctx.clone());
     let result = planner.plan_sql(sql).await;
 
     if result.is_err() {
         // Only log if there's an error
-        ctx.attach_query_str(QueryKind::Unknown, short_sql(sql.to_string()));
+        let redactor = QueryTextRedactor::create(&ctx.get_settings());
+        ctx.attach_query_str(QueryKind::Unknown, short_sql(redactor.redact_sql(sql)));
         log_query_start(&ctx);
         log_query_finished(&ctx, result.as_ref().err().cloned(), false);
     }
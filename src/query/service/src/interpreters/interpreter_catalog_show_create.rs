@@ -72,6 +72,14 @@ impl Interpreter for ShowCreateCatalogInterpreter {
                 String::from("iceberg"),
                 format!("STORAGE PARAMS\n{}", op.storage_params),
             ),
+            CatalogOption::Custom(op) => (
+                op.catalog_type,
+                op.options
+                    .iter()
+                    .map(|(k, v)| format!("{} = '{}'", k, v))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
         };
 
         let block = DataBlock::new(
@@ -161,6 +161,7 @@ impl CopyIntoTableInterpreter {
             validation_mode: plan.validation_mode.clone(),
 
             source,
+            pull_files: None,
         }));
 
         if plan.enable_distributed {
@@ -0,0 +1,368 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_sql::plans::BackupDatabasePlan;
+use databend_common_storage::init_stage_operator;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
+use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_LOCATION_URI;
+use log::info;
+use opendal::Operator;
+use uuid::Uuid;
+
+use super::interpreter_table_create::is_valid_create_opt;
+use crate::interpreters::common::backup_root;
+use crate::interpreters::common::read_backup_manifest;
+use crate::interpreters::common::write_backup_manifest;
+use crate::interpreters::common::BackupGrant;
+use crate::interpreters::common::BackupManifest;
+use crate::interpreters::common::BackupSnapshot;
+use crate::interpreters::common::BackupTable;
+use crate::interpreters::common::BackupView;
+use crate::interpreters::common::BACKUP_DATA_DIR;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Backs up the fuse tables, views and grants of a database to a stage.
+///
+/// The files referenced by the current snapshot of each table are copied, an incremental
+/// backup skips the files already copied by the latest backup of the same table.
+/// The manifest is written last, a backup without manifest is never used by restore.
+pub struct BackupDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: BackupDatabasePlan,
+}
+
+#[derive(Default)]
+struct BackupStat {
+    files: u64,
+    copied_files: u64,
+    copied_bytes: u64,
+}
+
+impl BackupDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: BackupDatabasePlan) -> Result<Self> {
+        Ok(BackupDatabaseInterpreter { ctx, plan })
+    }
+
+    async fn get_owner(&self, object: OwnershipObject) -> Result<Option<String>> {
+        let ownership = UserApiProvider::instance()
+            .get_ownership(&self.plan.tenant, &object)
+            .await?;
+        Ok(ownership.map(|v| v.role))
+    }
+
+    async fn backup_table(
+        &self,
+        op: &Operator,
+        root: &str,
+        fuse_table: &FuseTable,
+        base: Option<&BackupTable>,
+        stat: &mut BackupStat,
+    ) -> Result<Option<BackupSnapshot>> {
+        let (Some(snapshot), Some(location)) = (
+            fuse_table.read_table_snapshot().await?,
+            fuse_table.snapshot_loc().await?,
+        ) else {
+            return Ok(None);
+        };
+
+        let base_snapshot = base.and_then(|v| v.snapshot.as_ref());
+        let snapshot_id = snapshot.snapshot_id.simple().to_string();
+        if let Some(base_snapshot) = base_snapshot {
+            if base_snapshot.snapshot_id == snapshot_id {
+                // The table is unchanged since the base backup.
+                stat.files += base_snapshot.files.len() as u64;
+                return Ok(Some(base_snapshot.clone()));
+            }
+        }
+
+        let locations = fuse_table
+            .get_block_locations(self.ctx.clone(), &snapshot.segments, false, false)
+            .await?;
+        let mut files = vec![location.clone()];
+        files.extend(snapshot.segments.iter().map(|(v, _)| v.clone()));
+        files.extend(locations.block_location);
+        files.extend(locations.bloom_location);
+        files.extend(locations.deletion_vector_location);
+        files.extend(locations.vector_index_location);
+        files.extend(snapshot.table_statistics_location.clone());
+
+        // The files of fuse tables are immutable, the files referenced by both the base
+        // snapshot and its descendant are the same, no need to copy them again.
+        let copied: HashSet<&String> = base_snapshot
+            .map(|v| v.files.iter().collect())
+            .unwrap_or_default();
+        let files_to_copy = files
+            .iter()
+            .filter(|v| !copied.contains(v))
+            .collect::<Vec<_>>();
+
+        let table_op = fuse_table.get_operator();
+        let data_root = format!("{root}{BACKUP_DATA_DIR}/");
+        let chunk_size = self.ctx.get_settings().get_max_threads()? as usize;
+        for chunk in files_to_copy.chunks(chunk_size.max(1)) {
+            let sizes = futures::future::try_join_all(chunk.iter().map(|file| async {
+                let data = table_op.read(file).await?;
+                let size = data.len() as u64;
+                op.write(&format!("{data_root}{file}"), data).await?;
+                Ok::<_, opendal::Error>(size)
+            }))
+            .await?;
+            stat.copied_bytes += sizes.iter().sum::<u64>();
+        }
+        stat.files += files.len() as u64;
+        stat.copied_files += files_to_copy.len() as u64;
+
+        Ok(Some(BackupSnapshot {
+            snapshot_id,
+            location,
+            storage_prefix: fuse_table.meta_location_generator().prefix().to_string(),
+            files,
+            row_count: snapshot.summary.row_count,
+        }))
+    }
+
+    async fn backup_grants(
+        &self,
+        db_id: u64,
+        table_names: &HashMap<u64, String>,
+    ) -> Result<Vec<BackupGrant>> {
+        let catalog = &self.plan.catalog;
+        let database = &self.plan.database;
+        let roles = UserApiProvider::instance()
+            .get_roles(&self.plan.tenant)
+            .await?;
+
+        let mut grants = vec![];
+        for role in roles {
+            for entry in role.grants.entries() {
+                let table = match entry.object() {
+                    GrantObject::Database(c, d) if c == catalog && d == database => None,
+                    GrantObject::DatabaseById(c, d) if c == catalog && *d == db_id => None,
+                    GrantObject::Table(c, d, t) if c == catalog && d == database => {
+                        if !table_names.values().any(|v| v == t) {
+                            continue;
+                        }
+                        Some(t.clone())
+                    }
+                    GrantObject::TableById(c, d, t) if c == catalog && *d == db_id => {
+                        match table_names.get(t) {
+                            Some(name) => Some(name.clone()),
+                            None => continue,
+                        }
+                    }
+                    _ => continue,
+                };
+                // The ownership is kept as the owner of the objects.
+                let privileges = UserPrivilegeSet::from(*entry.privileges())
+                    .iter()
+                    .filter(|v| *v != UserPrivilegeType::Ownership)
+                    .collect::<Vec<_>>();
+                if !privileges.is_empty() {
+                    grants.push(BackupGrant {
+                        role: role.name.clone(),
+                        table,
+                        privileges,
+                    });
+                }
+            }
+        }
+        Ok(grants)
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BackupDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "BackupDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let database = catalog
+            .get_database(&self.plan.tenant, &self.plan.database)
+            .await?;
+        let db_id = database.get_db_info().ident.db_id;
+
+        let op = init_stage_operator(&self.plan.stage)?;
+        let root = backup_root(&self.plan.path);
+        let base = if self.plan.incremental {
+            read_backup_manifest(&op, &root, None)
+                .await?
+                .filter(|v| v.catalog == self.plan.catalog && v.db_id == db_id)
+        } else {
+            None
+        };
+        let base_tables = base
+            .as_ref()
+            .map(|v| v.tables.iter().map(|t| (t.table_id, t)).collect())
+            .unwrap_or_else(HashMap::new);
+
+        let now = Utc::now();
+        let backup_id = format!(
+            "{}_{}",
+            now.format("%Y%m%d%H%M%S"),
+            &Uuid::new_v4().simple().to_string()[..8]
+        );
+        info!(
+            "{}: backup database {} to {}, id {}, base {:?}",
+            self.ctx.get_id(),
+            self.plan.database,
+            self.plan.path,
+            backup_id,
+            base.as_ref().map(|v| &v.backup_id)
+        );
+
+        let mut stat = BackupStat::default();
+        let mut tables = vec![];
+        let mut views = vec![];
+        let mut table_names = HashMap::new();
+        for table in database.list_tables().await? {
+            let table_info = table.get_table_info();
+            let owner = self
+                .get_owner(OwnershipObject::Table {
+                    catalog_name: self.plan.catalog.clone(),
+                    db_id,
+                    table_id: table.get_id(),
+                })
+                .await?;
+
+            if table.engine() == VIEW_ENGINE {
+                table_names.insert(table.get_id(), table.name().to_string());
+                views.push(BackupView {
+                    name: table.name().to_string(),
+                    query: table.options().get(QUERY).cloned().unwrap_or_default(),
+                    owner,
+                });
+                continue;
+            }
+
+            let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+                self.ctx.push_warning(format!(
+                    "table {} of engine {} is not backed up",
+                    table.name(),
+                    table.engine()
+                ));
+                continue;
+            };
+
+            let base_table = base_tables.get(&table.get_id()).copied();
+            let snapshot = self
+                .backup_table(&op, &root, fuse_table, base_table, &mut stat)
+                .await?;
+
+            // Keep the options that can be used to create a new table, the data is restored
+            // into a new table in the data storage.
+            let mut options = table_info
+                .meta
+                .options
+                .iter()
+                .filter(|(k, _)| {
+                    is_valid_create_opt(k)
+                        && ![
+                            OPT_KEY_DATABASE_ID,
+                            OPT_KEY_ENGINE,
+                            OPT_KEY_LOCATION,
+                            OPT_KEY_TABLE_LOCATION_URI,
+                        ]
+                        .contains(&k.as_str())
+                })
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<BTreeMap<_, _>>();
+            if !table_info.meta.comment.is_empty() {
+                options.insert(OPT_KEY_COMMENT.to_string(), table_info.meta.comment.clone());
+            }
+
+            table_names.insert(table.get_id(), table.name().to_string());
+            tables.push(BackupTable {
+                name: table.name().to_string(),
+                table_id: table.get_id(),
+                schema: table.schema().as_ref().clone(),
+                options,
+                field_comments: table.field_comments().clone(),
+                cluster_key: table_info.meta.default_cluster_key.clone(),
+                owner,
+                snapshot,
+            });
+        }
+
+        let owner = self
+            .get_owner(OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+            })
+            .await?;
+        let grants = self.backup_grants(db_id, &table_names).await?;
+
+        let manifest = BackupManifest {
+            backup_id: backup_id.clone(),
+            base_backup_id: base.map(|v| v.backup_id),
+            created_on: now,
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            db_id,
+            owner,
+            tables,
+            views,
+            grants,
+        };
+        write_backup_manifest(&op, &root, &manifest).await?;
+        info!(
+            "{}: backup {} finished, {} files, {} files copied, {} bytes copied",
+            self.ctx.get_id(),
+            backup_id,
+            stat.files,
+            stat.copied_files,
+            stat.copied_bytes
+        );
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![backup_id]),
+            UInt64Type::from_data(vec![manifest.tables.len() as u64]),
+            UInt64Type::from_data(vec![manifest.views.len() as u64]),
+            UInt64Type::from_data(vec![stat.files]),
+            UInt64Type::from_data(vec![stat.copied_files]),
+            UInt64Type::from_data(vec![stat.copied_bytes]),
+        ])])
+    }
+}
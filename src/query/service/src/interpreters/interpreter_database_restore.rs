@@ -0,0 +1,363 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Engine;
+use databend_common_ast::parser::quote::quote_ident;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_sql::plans::CreateDatabasePlan;
+use databend_common_sql::plans::CreateTablePlan;
+use databend_common_sql::plans::RestoreDatabasePlan;
+use databend_common_sql::Planner;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::stage_storage_params;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use databend_common_storages_view::view_table::QUERY;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use futures::TryStreamExt;
+use log::info;
+use uuid::Uuid;
+
+use crate::interpreters::common::backup_root;
+use crate::interpreters::common::read_backup_manifest;
+use crate::interpreters::common::BackupSnapshot;
+use crate::interpreters::common::BackupTable;
+use crate::interpreters::common::BACKUP_DATA_DIR;
+use crate::interpreters::CreateDatabaseInterpreter;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Restores a database from a backup in a stage into a new database.
+///
+/// The data of a table is read from the backup through a temporary read-only attached
+/// table, and inserted into the new table, so the restored tables own their data.
+/// The grants and owners are restored if the roles still exist, otherwise a warning
+/// is reported.
+pub struct RestoreDatabaseInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RestoreDatabasePlan,
+}
+
+impl RestoreDatabaseInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RestoreDatabasePlan) -> Result<Self> {
+        Ok(RestoreDatabaseInterpreter { ctx, plan })
+    }
+
+    fn create_table_plan(&self, table: &str) -> CreateTablePlan {
+        CreateTablePlan {
+            create_option: CreateOption::Create,
+            tenant: self.plan.tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            table: table.to_string(),
+            schema: Arc::new(TableSchema::default()),
+            engine: Engine::Fuse,
+            engine_options: BTreeMap::new(),
+            storage_params: None,
+            read_only_attach: false,
+            part_prefix: "".to_string(),
+            options: BTreeMap::new(),
+            field_comments: vec![],
+            cluster_key: None,
+            as_select: None,
+        }
+    }
+
+    async fn restore_table_data(
+        &self,
+        catalog: &Arc<dyn Catalog>,
+        db_id: u64,
+        table: &BackupTable,
+        snapshot: &BackupSnapshot,
+    ) -> Result<()> {
+        let root = backup_root(&self.plan.path);
+        let sp = stage_storage_params(&self.plan.stage)
+            .map_root(|v| format!("{v}/{root}{BACKUP_DATA_DIR}/"));
+
+        // Attaching a table reads the snapshot location from the hint, whose content
+        // starts with the root of the storage where the backup is restored from.
+        let operator = DataOperator::try_create(&sp).await?;
+        let operator = operator.operator();
+        let hint = format!(
+            "{}/{}",
+            snapshot.storage_prefix, FUSE_TBL_LAST_SNAPSHOT_HINT
+        );
+        let snapshot_loc = format!("{}{}", operator.info().root(), snapshot.location);
+        operator.write(&hint, snapshot_loc).await?;
+
+        let attached = format!("_restore_{}_{}", table.name, Uuid::new_v4().simple());
+        let mut plan = self.create_table_plan(&attached);
+        plan.storage_params = Some(sp);
+        plan.read_only_attach = true;
+        plan.options.insert(
+            OPT_KEY_STORAGE_PREFIX.to_string(),
+            snapshot.storage_prefix.clone(),
+        );
+        CreateTableInterpreter::try_create(self.ctx.clone(), plan)?
+            .execute2()
+            .await?;
+
+        let quote = self
+            .ctx
+            .get_settings()
+            .get_sql_dialect()?
+            .default_ident_quote();
+        let columns = table
+            .schema
+            .fields()
+            .iter()
+            .filter(|f| f.computed_expr().is_none())
+            .map(|f| quote_ident(f.name(), quote, true))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let database = quote_ident(&self.plan.database, quote, true);
+        let sql = format!(
+            "INSERT INTO {database}.{} ({columns}) SELECT {columns} FROM {database}.{}",
+            quote_ident(&table.name, quote, true),
+            quote_ident(&attached, quote, true),
+        );
+        let res = self.execute_sql(&sql).await;
+
+        // The attached table is read-only, dropping it never removes the backup files.
+        let attached_id = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &attached)
+            .await?
+            .get_id();
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: true,
+                tenant: self.plan.tenant.clone(),
+                tb_id: attached_id,
+                table_name: attached,
+                db_id,
+            })
+            .await?;
+        res
+    }
+
+    async fn execute_sql(&self, sql: &str) -> Result<()> {
+        let ctx = self
+            .ctx
+            .get_current_session()
+            .create_query_context()
+            .await?;
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(sql).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx.clone()).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+
+    async fn restore_owner(&self, object: OwnershipObject, owner: &Option<String>) {
+        let Some(owner) = owner else {
+            return;
+        };
+        if let Err(e) = UserApiProvider::instance()
+            .grant_ownership_to_role(&self.plan.tenant, &object, owner)
+            .await
+        {
+            self.ctx.push_warning(format!(
+                "Failed to restore the ownership of role {owner}: {}",
+                e.message()
+            ));
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RestoreDatabaseInterpreter {
+    fn name(&self) -> &str {
+        "RestoreDatabaseInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = &self.plan.tenant;
+        let op = init_stage_operator(&self.plan.stage)?;
+        let root = backup_root(&self.plan.path);
+        let manifest = read_backup_manifest(&op, &root, self.plan.backup_id.as_deref())
+            .await?
+            .ok_or_else(|| {
+                ErrorCode::StorageNotFound(format!(
+                    "Backup {} not found in stage {}",
+                    self.plan.backup_id.as_deref().unwrap_or("(latest)"),
+                    self.plan.stage.stage_name
+                ))
+            })?;
+        info!(
+            "{}: restore database {} from backup {} of database {}",
+            self.ctx.get_id(),
+            self.plan.database,
+            manifest.backup_id,
+            manifest.database
+        );
+
+        let plan = CreateDatabasePlan {
+            create_option: CreateOption::Create,
+            tenant: tenant.clone(),
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            meta: DatabaseMeta {
+                engine: "default".to_string(),
+                ..Default::default()
+            },
+        };
+        CreateDatabaseInterpreter::try_create(self.ctx.clone(), plan)?
+            .execute2()
+            .await?;
+
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        let db_id = catalog
+            .get_database(tenant, &self.plan.database)
+            .await?
+            .get_db_info()
+            .ident
+            .db_id;
+        self.restore_owner(
+            OwnershipObject::Database {
+                catalog_name: self.plan.catalog.clone(),
+                db_id,
+            },
+            &manifest.owner,
+        )
+        .await;
+
+        let mut table_ids = HashMap::new();
+        for table in &manifest.tables {
+            let mut plan = self.create_table_plan(&table.name);
+            plan.schema = Arc::new(table.schema.clone());
+            plan.options = table.options.clone();
+            plan.field_comments = table.field_comments.clone();
+            plan.cluster_key = table.cluster_key.clone();
+            CreateTableInterpreter::try_create(self.ctx.clone(), plan)?
+                .execute2()
+                .await?;
+
+            if let Some(snapshot) = &table.snapshot {
+                self.restore_table_data(&catalog, db_id, table, snapshot)
+                    .await?;
+            }
+
+            let table_id = catalog
+                .get_table(tenant, &self.plan.database, &table.name)
+                .await?
+                .get_id();
+            self.restore_owner(
+                OwnershipObject::Table {
+                    catalog_name: self.plan.catalog.clone(),
+                    db_id,
+                    table_id,
+                },
+                &table.owner,
+            )
+            .await;
+            table_ids.insert(table.name.clone(), table_id);
+        }
+
+        // The view queries are restored as they are, without binding, so the views that
+        // refer to each other can be restored in any order.
+        for view in &manifest.views {
+            let req = CreateTableReq {
+                create_option: CreateOption::Create,
+                name_ident: TableNameIdent {
+                    tenant: tenant.clone(),
+                    db_name: self.plan.database.clone(),
+                    table_name: view.name.clone(),
+                },
+                table_meta: TableMeta {
+                    engine: VIEW_ENGINE.to_string(),
+                    options: BTreeMap::from([(QUERY.to_string(), view.query.clone())]),
+                    ..Default::default()
+                },
+                as_dropped: false,
+            };
+            let reply = catalog.create_table(req).await?;
+            self.restore_owner(
+                OwnershipObject::Table {
+                    catalog_name: self.plan.catalog.clone(),
+                    db_id,
+                    table_id: reply.table_id,
+                },
+                &view.owner,
+            )
+            .await;
+            table_ids.insert(view.name.clone(), reply.table_id);
+        }
+
+        let mut grants = 0;
+        for grant in &manifest.grants {
+            let object = match &grant.table {
+                None => GrantObject::DatabaseById(self.plan.catalog.clone(), db_id),
+                Some(table) => match table_ids.get(table) {
+                    Some(table_id) => {
+                        GrantObject::TableById(self.plan.catalog.clone(), db_id, *table_id)
+                    }
+                    None => continue,
+                },
+            };
+            let privileges = UserPrivilegeSet::from(grant.privileges.clone());
+            match UserApiProvider::instance()
+                .grant_privileges_to_role(tenant, &grant.role, object, privileges.clone())
+                .await
+            {
+                Ok(_) => grants += 1,
+                Err(e) => self.ctx.push_warning(format!(
+                    "Failed to restore the grant {privileges} of role {}: {}",
+                    grant.role,
+                    e.message()
+                )),
+            }
+        }
+        RoleCacheManager::instance().invalidate_cache(tenant);
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![manifest.backup_id.clone()]),
+            UInt64Type::from_data(vec![manifest.tables.len() as u64]),
+            UInt64Type::from_data(vec![manifest.views.len() as u64]),
+            UInt64Type::from_data(vec![grants]),
+        ])])
+    }
+}
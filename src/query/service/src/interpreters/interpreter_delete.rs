@@ -26,6 +26,9 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataBlock;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -73,6 +76,7 @@ use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::DeletePlan;
+use crate::stream::DataBlockStream;
 use crate::stream::PullingExecutorStream;
 
 /// interprets DeletePlan
@@ -104,7 +108,12 @@ impl Interpreter for DeleteInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         debug!("ctx.id" = self.ctx.get_id().as_str(); "delete_interpreter_execute");
 
-        let is_distributed = !self.ctx.get_cluster().is_empty();
+        let returning = match &self.plan.returning {
+            Some(returning) => returning.remote_exprs()?,
+            None => vec![],
+        };
+        // the deleted rows are collected on the local node for `RETURNING`.
+        let is_distributed = !self.ctx.get_cluster().is_empty() && returning.is_empty();
 
         let catalog_name = self.plan.catalog_name.as_str();
         let catalog = self.ctx.get_catalog(catalog_name).await?;
@@ -213,6 +222,19 @@ impl Interpreter for DeleteInterpreter {
         }
 
         build_res.main_pipeline.add_lock_guard(lock_guard);
+        // the deleted rows have to be read for `RETURNING`, so it can't be done by a truncate.
+        let (filters, col_indices) = if returning.is_empty() {
+            (filters, col_indices)
+        } else {
+            let filters = match filters {
+                Some(filters) => filters,
+                None => create_push_down_filters(&ScalarExpr::ConstantExpr(ConstantExpr {
+                    span: None,
+                    value: Scalar::Boolean(true),
+                }))?,
+            };
+            (Some(filters), fuse_table.all_column_indices())
+        };
         // check if unconditional deletion
         let Some(filters) = filters else {
             let progress_values = ProgressValues {
@@ -232,7 +254,7 @@ impl Interpreter for DeleteInterpreter {
         };
 
         let query_row_id_col = !self.plan.subquery_desc.is_empty();
-        if col_indices.is_empty() && !query_row_id_col {
+        if col_indices.is_empty() && !query_row_id_col && returning.is_empty() {
             // here the situation: filter_expr is not null, but col_indices in empty, which
             // indicates the expr being evaluated is unrelated to the value of rows:
             //   e.g.
@@ -261,7 +283,8 @@ impl Interpreter for DeleteInterpreter {
         }
 
         let cluster = self.ctx.get_cluster();
-        let is_lazy = !cluster.is_empty() && snapshot.segments.len() >= cluster.nodes.len();
+        let is_lazy = is_distributed && snapshot.segments.len() >= cluster.nodes.len();
+        // blocks and segments can't be removed without reading them for `RETURNING`.
         let partitions = fuse_table
             .mutation_read_partitions(
                 self.ctx.clone(),
@@ -269,7 +292,7 @@ impl Interpreter for DeleteInterpreter {
                 col_indices.clone(),
                 Some(filters.clone()),
                 is_lazy,
-                true,
+                returning.is_empty(),
             )
             .await?;
 
@@ -282,6 +305,7 @@ impl Interpreter for DeleteInterpreter {
             catalog_info,
             is_distributed,
             query_row_id_col,
+            returning,
         )?;
 
         build_res =
@@ -303,6 +327,11 @@ impl Interpreter for DeleteInterpreter {
 
         Ok(build_res)
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        let blocks = self.ctx.get_returning_blocks();
+        Ok(Box::pin(DataBlockStream::create(None, blocks)))
+    }
 }
 
 impl DeleteInterpreter {
@@ -316,6 +345,7 @@ impl DeleteInterpreter {
         catalog_info: CatalogInfo,
         is_distributed: bool,
         query_row_id_col: bool,
+        returning: Vec<RemoteExpr<String>>,
     ) -> Result<PhysicalPlan> {
         let merge_meta = partitions.partitions_type() == PartInfoType::LazyLevel;
         let mut root = PhysicalPlan::DeleteSource(Box::new(DeleteSource {
@@ -326,6 +356,7 @@ impl DeleteInterpreter {
             col_indices,
             query_row_id_col,
             snapshot: snapshot.clone(),
+            returning,
             plan_id: u32::MAX,
         }));
 
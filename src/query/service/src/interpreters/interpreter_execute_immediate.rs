@@ -14,12 +14,11 @@
 
 use std::sync::Arc;
 
-use databend_common_ast::ast::DeclareItem;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FunctionCall;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Literal;
-use databend_common_ast::ast::ScriptStatement;
+use databend_common_ast::ast::ScriptBlock;
 use databend_common_ast::ast::TypeName;
 use databend_common_ast::parser::run_parser;
 use databend_common_ast::parser::script::script_block;
@@ -37,7 +36,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
-use databend_common_script::compile;
+use databend_common_script::compile_block;
 use databend_common_script::ir::ColumnAccess;
 use databend_common_script::Client;
 use databend_common_script::Executor;
@@ -53,6 +52,7 @@ use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::sessions::Session;
 
 #[derive(Debug)]
 pub struct ExecuteImmediateInterpreter {
@@ -80,10 +80,9 @@ impl Interpreter for ExecuteImmediateInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let res: Result<_> = try {
-            let settings = self.ctx.get_settings();
-            let sql_dialect = settings.get_sql_dialect()?;
+            let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
             let tokens = tokenize_sql(&self.plan.script)?;
-            let mut ast = run_parser(
+            let ast = run_parser(
                 &tokens,
                 sql_dialect,
                 ParseMode::Template,
@@ -91,55 +90,51 @@ impl Interpreter for ExecuteImmediateInterpreter {
                 script_block,
             )?;
 
-            let mut src = vec![];
-            for declare in ast.declares {
-                match declare {
-                    DeclareItem::Var(declare) => src.push(ScriptStatement::LetVar { declare }),
-                    DeclareItem::Set(declare) => {
-                        src.push(ScriptStatement::LetStatement { declare })
-                    }
-                }
-            }
-            src.append(&mut ast.body);
-            let compiled = compile(&src)?;
-
-            let client = ScriptClient {
-                ctx: self.ctx.clone(),
-            };
-            let mut executor = Executor::load(ast.span, client, compiled);
-            let script_max_steps = settings.get_script_max_steps()?;
-            let result = executor.run(script_max_steps as usize).await?;
-
-            match result {
-                Some(ReturnValue::Var(scalar)) => {
-                    PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
-                        StringType::from_data(vec![scalar.to_string()]),
-                    ])])?
-                }
-                Some(ReturnValue::Set(set)) => {
-                    let rendered_table = box_render(
-                        &set.schema,
-                        &[set.block.clone()],
-                        usize::MAX,
-                        usize::MAX,
-                        usize::MAX,
-                        true,
-                    )?;
-                    let lines = rendered_table.lines().map(|x| x.to_string()).collect();
-                    PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
-                        StringType::from_data(lines),
-                    ])])?
-                }
-                None => PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
-                    StringType::from_data(Vec::<String>::new()),
-                ])])?,
-            }
+            execute_script(&self.ctx, self.ctx.get_current_session(), &ast).await?
         };
 
         res.map_err(|err| err.display_with_sql(&self.plan.script))
     }
 }
 
+/// Runs a script block, issuing its queries from `session`, and renders the
+/// returned value as a single string column.
+pub(crate) async fn execute_script(
+    ctx: &Arc<QueryContext>,
+    session: Arc<Session>,
+    block: &ScriptBlock,
+) -> Result<PipelineBuildResult> {
+    let compiled = compile_block(block)?;
+
+    let client = ScriptClient { session };
+    let mut executor = Executor::load(block.span, client, compiled);
+    let script_max_steps = ctx.get_settings().get_script_max_steps()?;
+    let result = executor.run(script_max_steps as usize).await?;
+
+    match result {
+        Some(ReturnValue::Var(scalar)) => PipelineBuildResult::from_blocks(vec![
+            DataBlock::new_from_columns(vec![StringType::from_data(vec![scalar.to_string()])]),
+        ]),
+        Some(ReturnValue::Set(set)) => {
+            let rendered_table = box_render(
+                &set.schema,
+                &[set.block.clone()],
+                usize::MAX,
+                usize::MAX,
+                usize::MAX,
+                true,
+            )?;
+            let lines = rendered_table.lines().map(|x| x.to_string()).collect();
+            PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+                StringType::from_data(lines),
+            ])])
+        }
+        None => PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(Vec::<String>::new()),
+        ])]),
+    }
+}
+
 #[derive(Debug, Clone)]
 struct QueryResult {
     schema: DataSchemaRef,
@@ -147,7 +142,7 @@ struct QueryResult {
 }
 
 struct ScriptClient {
-    ctx: Arc<QueryContext>,
+    session: Arc<Session>,
 }
 
 impl Client for ScriptClient {
@@ -155,11 +150,7 @@ impl Client for ScriptClient {
     type Set = QueryResult;
 
     async fn query(&self, query: &str) -> Result<Self::Set> {
-        let ctx = self
-            .ctx
-            .get_current_session()
-            .create_query_context()
-            .await?;
+        let ctx = self.session.create_query_context().await?;
 
         let mut planner = Planner::new(ctx.clone());
         let (plan, _) = planner.plan_sql(query).await?;
@@ -216,6 +207,7 @@ impl Client for ScriptClient {
                         .collect::<Result<Vec<_>>>()?,
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             },
@@ -230,6 +222,7 @@ impl Client for ScriptClient {
                         .collect::<Result<Vec<_>>>()?,
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             },
@@ -273,6 +266,7 @@ impl Client for ScriptClient {
                                     args: keys,
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             },
@@ -284,12 +278,14 @@ impl Client for ScriptClient {
                                     args: vals,
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             },
                         ],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -308,6 +304,7 @@ impl Client for ScriptClient {
                         }],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -320,6 +317,7 @@ impl Client for ScriptClient {
                     args: vec![],
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             },
@@ -331,6 +329,7 @@ impl Client for ScriptClient {
                     args: vec![],
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             },
@@ -360,6 +359,15 @@ impl Client for ScriptClient {
                 target_type: TypeName::Timestamp,
                 pg_style: false,
             },
+            Scalar::Interval(v) => Expr::Cast {
+                span: None,
+                expr: Box::new(Expr::Literal {
+                    span: None,
+                    value: Literal::String(v.to_string()),
+                }),
+                target_type: TypeName::Interval,
+                pg_style: false,
+            },
             Scalar::Null => Expr::Literal {
                 span: None,
                 value: Literal::Null,
@@ -430,4 +438,11 @@ impl Client for ScriptClient {
             ))),
         }
     }
+
+    fn error_to_vars(&self, error: &ErrorCode) -> Result<(Self::Var, Self::Var)> {
+        Ok((
+            Scalar::Number(NumberScalar::UInt16(error.code())),
+            Scalar::String(error.message()),
+        ))
+    }
 }
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ExplainFormat;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::FormatTreeNode;
 use databend_common_catalog::table_context::TableContext;
@@ -69,6 +70,21 @@ impl Interpreter for ExplainInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if self.config.format != ExplainFormat::Text
+            && !matches!(
+                (&self.kind, &self.plan),
+                (
+                    ExplainKind::Plan | ExplainKind::AnalyzePlan,
+                    Plan::Query { .. }
+                )
+            )
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "EXPLAIN(FORMAT {}) is only supported for SELECT statement",
+                self.config.format
+            )));
+        }
+
         let blocks = match &self.kind {
             ExplainKind::Raw | ExplainKind::Optimized => self.explain_plan(&self.plan)?,
             ExplainKind::Plan if self.config.logical => self.explain_plan(&self.plan)?,
@@ -284,16 +300,32 @@ impl ExplainInterpreter {
                 let format_tree =
                     FormatTreeNode::with_children("ReadQueryResultCache".to_string(), children);
 
-                let result = format_tree.format_pretty()?;
-                let line_split_result: Vec<&str> = result.lines().collect();
-                let formatted_plan = StringType::from_data(line_split_result);
-                return Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])]);
+                return self.format_tree_to_blocks(&format_tree);
             }
         }
 
-        let result = plan
-            .format(metadata.clone(), Default::default())?
-            .format_pretty()?;
+        let format_tree = if self.config.verbose {
+            plan.format_verbose(metadata.clone())?
+        } else {
+            plan.format(metadata.clone(), Default::default())?
+        };
+        self.format_tree_to_blocks(&format_tree)
+    }
+
+    /// Render the physical plan tree in the format chosen by `EXPLAIN(FORMAT ...)`,
+    /// one output row per line.
+    fn format_tree_to_blocks(&self, format_tree: &FormatTreeNode) -> Result<Vec<DataBlock>> {
+        let result = match self.config.format {
+            ExplainFormat::Text => format_tree.format_pretty()?,
+            ExplainFormat::Json => {
+                let mut next_id = 0;
+                let value = format_tree_to_json(format_tree, &mut next_id);
+                serde_json::to_string_pretty(&value).map_err(|e| {
+                    ErrorCode::Internal(format!("Failed to format plan as JSON: {e}"))
+                })?
+            }
+            ExplainFormat::Graphviz => format_tree_to_graphviz(format_tree),
+        };
         let line_split_result: Vec<&str> = result.lines().collect();
         let formatted_plan = StringType::from_data(line_split_result);
         Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
@@ -388,12 +420,8 @@ impl ExplainInterpreter {
         // Drain the data
         let query_profiles = self.execute_and_get_profiles(build_res)?;
 
-        let result = plan
-            .format(metadata.clone(), query_profiles)?
-            .format_pretty()?;
-        let line_split_result: Vec<&str> = result.lines().collect();
-        let formatted_plan = StringType::from_data(line_split_result);
-        Ok(vec![DataBlock::new_from_columns(vec![formatted_plan])])
+        let format_tree = plan.format(metadata.clone(), query_profiles)?;
+        self.format_tree_to_blocks(&format_tree)
     }
 
     fn execute_and_get_profiles(
@@ -450,3 +478,93 @@ impl ExplainInterpreter {
             .await
     }
 }
+
+/// Split the children of a physical plan format node into operator attributes
+/// (leaf lines like `estimated rows: 1.00`) and child operators.
+fn split_format_tree_children(node: &FormatTreeNode) -> (Vec<(&str, &str)>, Vec<&FormatTreeNode>) {
+    let mut attributes = vec![];
+    let mut operators = vec![];
+    for child in node.children.iter() {
+        match child.payload.split_once(": ") {
+            Some((key, value)) if child.children.is_empty() => attributes.push((key, value)),
+            _ => operators.push(child),
+        }
+    }
+    (attributes, operators)
+}
+
+/// Convert a physical plan format tree into a JSON object, operators are numbered
+/// in pre-order starting from 0.
+fn format_tree_to_json(node: &FormatTreeNode, next_id: &mut u32) -> serde_json::Value {
+    let id = *next_id;
+    *next_id += 1;
+
+    let (attributes, operators) = split_format_tree_children(node);
+    let mut estimated_rows = None;
+    let mut attribute_map = serde_json::Map::new();
+    for (key, value) in attributes {
+        if key == "estimated rows" {
+            estimated_rows = value.parse::<f64>().ok();
+            continue;
+        }
+        attribute_map.insert(
+            key.replace(' ', "_"),
+            serde_json::Value::String(value.to_string()),
+        );
+    }
+    let children = operators
+        .into_iter()
+        .map(|child| format_tree_to_json(child, next_id))
+        .collect::<Vec<_>>();
+
+    let mut object = serde_json::Map::new();
+    object.insert("id".to_string(), id.into());
+    object.insert("name".to_string(), node.payload.clone().into());
+    object.insert("estimated_rows".to_string(), estimated_rows.into());
+    object.insert("attributes".to_string(), attribute_map.into());
+    object.insert("children".to_string(), children.into());
+    serde_json::Value::Object(object)
+}
+
+/// Convert a physical plan format tree into a Graphviz DOT digraph, edges point
+/// from an operator to its inputs.
+fn format_tree_to_graphviz(node: &FormatTreeNode) -> String {
+    fn escape(s: &str) -> String {
+        s.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    fn visit(
+        node: &FormatTreeNode,
+        next_id: &mut u32,
+        nodes: &mut Vec<String>,
+        edges: &mut Vec<String>,
+    ) -> u32 {
+        let id = *next_id;
+        *next_id += 1;
+
+        let (attributes, operators) = split_format_tree_children(node);
+        let mut label = format!("#{} {}", id, escape(&node.payload));
+        for (key, value) in attributes {
+            label.push_str(&format!("\\n{}: {}", escape(key), escape(value)));
+        }
+        nodes.push(format!("    n{id} [label=\"{label}\"];"));
+
+        for child in operators {
+            let child_id = visit(child, next_id, nodes, edges);
+            edges.push(format!("    n{id} -> n{child_id};"));
+        }
+        id
+    }
+
+    let mut nodes = vec![];
+    let mut edges = vec![];
+    visit(node, &mut 0, &mut nodes, &mut edges);
+
+    let mut result = String::from("digraph plan {\n    node [shape=box];\n");
+    for line in nodes.into_iter().chain(edges) {
+        result.push_str(&line);
+        result.push('\n');
+    }
+    result.push('}');
+    result
+}
@@ -47,7 +47,14 @@ use crate::interpreters::interpreter_notification_alter::AlterNotificationInterp
 use crate::interpreters::interpreter_notification_create::CreateNotificationInterpreter;
 use crate::interpreters::interpreter_notification_desc::DescNotificationInterpreter;
 use crate::interpreters::interpreter_notification_drop::DropNotificationInterpreter;
+use crate::interpreters::interpreter_pipe_alter::AlterPipeInterpreter;
+use crate::interpreters::interpreter_pipe_create::CreatePipeInterpreter;
+use crate::interpreters::interpreter_pipe_desc::DescPipeInterpreter;
+use crate::interpreters::interpreter_pipe_drop::DropPipeInterpreter;
 use crate::interpreters::interpreter_presign::PresignInterpreter;
+use crate::interpreters::interpreter_procedure_call::CallProcedureInterpreter;
+use crate::interpreters::interpreter_procedure_create::CreateProcedureInterpreter;
+use crate::interpreters::interpreter_procedure_drop::DropProcedureInterpreter;
 use crate::interpreters::interpreter_role_show::ShowRolesInterpreter;
 use crate::interpreters::interpreter_table_create::CreateTableInterpreter;
 use crate::interpreters::interpreter_table_revert::RevertTableInterpreter;
@@ -137,6 +144,10 @@ impl InterpreterFactory {
                 ExplainKind::AnalyzePlan,
                 ExplainConfig::default(),
             )?)),
+            Plan::DescribeQuery { plan } => Ok(Arc::new(DescribeQueryInterpreter::try_create(
+                ctx,
+                *plan.clone(),
+            )?)),
 
             Plan::CopyIntoTable(copy_plan) => Ok(Arc::new(CopyIntoTableInterpreter::try_create(
                 ctx,
@@ -177,6 +188,14 @@ impl InterpreterFactory {
                 RenameDatabaseInterpreter::try_create(ctx, *rename_database.clone())?,
             )),
 
+            Plan::BackupDatabase(backup_database) => Ok(Arc::new(
+                BackupDatabaseInterpreter::try_create(ctx, *backup_database.clone())?,
+            )),
+
+            Plan::RestoreDatabase(restore_database) => Ok(Arc::new(
+                RestoreDatabaseInterpreter::try_create(ctx, *restore_database.clone())?,
+            )),
+
             // Tables
             Plan::ShowCreateTable(show_create_table) => Ok(Arc::new(
                 ShowCreateTableInterpreter::try_create(ctx, *show_create_table.clone())?,
@@ -379,6 +398,10 @@ impl InterpreterFactory {
                 ctx,
                 *s.clone(),
             )?)),
+            Plan::RefreshStage(s) => Ok(Arc::new(RefreshStageInterpreter::try_create(
+                ctx,
+                *s.clone(),
+            )?)),
 
             // FileFormats
             Plan::CreateFileFormat(create_file_format) => Ok(Arc::new(
@@ -441,6 +464,7 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::ShowWarnings(_) => Ok(Arc::new(ShowWarningsInterpreter::try_create(ctx)?)),
 
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
@@ -558,6 +582,13 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowConnections(_) => Ok(Arc::new(ShowConnectionsInterpreter::try_create(ctx)?)),
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescPipe(p) => Ok(Arc::new(DescPipeInterpreter::try_create(ctx, *p.clone())?)),
             Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
             Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
             Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
@@ -584,6 +615,18 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateProcedure(p) => Ok(Arc::new(CreateProcedureInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropProcedure(p) => Ok(Arc::new(DropProcedureInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::CallProcedure(p) => Ok(Arc::new(CallProcedureInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
         }
     }
 }
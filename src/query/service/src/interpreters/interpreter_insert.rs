@@ -20,6 +20,7 @@ use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
+use databend_common_expression::SendableDataBlockStream;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
@@ -44,6 +45,7 @@ use crate::pipelines::ValueSource;
 use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
 
 pub struct InsertInterpreter {
     ctx: Arc<QueryContext>,
@@ -104,6 +106,11 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        let returning = match &self.plan.returning {
+            Some(returning) => returning.remote_exprs()?,
+            None => vec![],
+        };
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -216,7 +223,8 @@ impl Interpreter for InsertInterpreter {
                 let catalog_info = catalog.info();
 
                 let insert_select_plan = match select_plan {
-                    PhysicalPlan::Exchange(ref mut exchange) => {
+                    // the inserted rows are collected on the local node for `RETURNING`.
+                    PhysicalPlan::Exchange(ref mut exchange) if returning.is_empty() => {
                         // insert can be dispatched to different nodes
                         let input = exchange.input.clone();
                         exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(Box::new(
@@ -231,6 +239,7 @@ impl Interpreter for InsertInterpreter {
                                 select_column_bindings,
                                 insert_schema: self.plan.schema(),
                                 cast_needed: self.check_schema_cast(plan)?,
+                                returning: vec![],
                             },
                         )));
                         select_plan
@@ -248,6 +257,7 @@ impl Interpreter for InsertInterpreter {
                             select_column_bindings,
                             insert_schema: self.plan.schema(),
                             cast_needed: self.check_schema_cast(plan)?,
+                            returning: returning.clone(),
                         }))
                     }
                 };
@@ -294,6 +304,7 @@ impl Interpreter for InsertInterpreter {
             &mut build_res.main_pipeline,
             table.clone(),
             self.plan.schema(),
+            returning,
             None,
             vec![],
             self.plan.overwrite,
@@ -316,4 +327,9 @@ impl Interpreter for InsertInterpreter {
 
         Ok(build_res)
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        let blocks = self.ctx.get_returning_blocks();
+        Ok(Box::pin(DataBlockStream::create(None, blocks)))
+    }
 }
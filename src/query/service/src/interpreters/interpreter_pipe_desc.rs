@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::DescPipePlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct DescPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DescPipePlan,
+}
+
+impl DescPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DescPipePlan) -> Result<Self> {
+        Ok(Self { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescPipeInterpreter {
+    fn name(&self) -> &str {
+        "DescPipeInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let pipe = UserApiProvider::instance()
+            .get_pipe(&self.ctx.get_tenant(), &self.plan.name)
+            .await?;
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![pipe.name.clone()]),
+            StringType::from_data(vec![pipe.copy_stmt.clone()]),
+            BooleanType::from_data(vec![pipe.auto_ingest]),
+            BooleanType::from_data(vec![pipe.execution_paused]),
+            StringType::from_data(vec![pipe.comment.clone()]),
+            StringType::from_data(vec![pipe.creator.to_string()]),
+            TimestampType::from_data(vec![pipe.created_on.timestamp_micros()]),
+        ])])
+    }
+}
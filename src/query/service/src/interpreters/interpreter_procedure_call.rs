@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::DeclareItem;
+use databend_common_ast::ast::DeclareVar;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::parser::expr::type_name;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::script::script_block;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::ParseMode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::ProcedureExecuteAs;
+use databend_common_sql::plans::CallProcedurePlan;
+use databend_common_users::UserApiProvider;
+
+use crate::interpreters::interpreter_execute_immediate::execute_script;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CallProcedureInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CallProcedurePlan,
+}
+
+impl CallProcedureInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CallProcedurePlan) -> Result<Self> {
+        Ok(Self { ctx, plan })
+    }
+
+    /// Creates the session the procedure body runs in: the caller's own session,
+    /// or a session authenticated as the owner of the procedure, restricted to the
+    /// role the owner created the procedure with.
+    #[async_backtrace::framed]
+    async fn create_session(&self) -> Result<Arc<Session>> {
+        let procedure = &self.plan.procedure;
+        match procedure.execute_as {
+            ProcedureExecuteAs::Caller => Ok(self.ctx.get_current_session()),
+            ProcedureExecuteAs::Owner => {
+                let owner = UserApiProvider::instance()
+                    .get_user(&self.ctx.get_tenant(), procedure.owner.clone())
+                    .await?;
+                let session = SessionManager::instance()
+                    .create_session(SessionType::Dummy)
+                    .await?;
+                session
+                    .set_authed_user(owner, procedure.owner_role.clone())
+                    .await?;
+                session.set_current_database(self.ctx.get_current_database());
+                Ok(session)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CallProcedureInterpreter {
+    fn name(&self) -> &str {
+        "CallProcedureInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let procedure = &self.plan.procedure;
+        let res: Result<_> = try {
+            let sql_dialect = self.ctx.get_settings().get_sql_dialect()?;
+            let tokens = tokenize_sql(&procedure.script)?;
+            let mut ast = run_parser(
+                &tokens,
+                sql_dialect,
+                ParseMode::Template,
+                false,
+                script_block,
+            )?;
+
+            // The arguments are bound to variables declared ahead of the declarations of the body.
+            let mut declares = Vec::with_capacity(procedure.arg_names.len() + ast.declares.len());
+            for ((name, data_type), arg) in procedure
+                .arg_names
+                .iter()
+                .zip(procedure.arg_types.iter())
+                .zip(self.plan.args.iter())
+            {
+                let tokens = tokenize_sql(data_type)?;
+                let target_type =
+                    run_parser(&tokens, sql_dialect, ParseMode::Default, false, type_name)?;
+                declares.push(DeclareItem::Var(DeclareVar {
+                    span: None,
+                    name: Identifier::from_name(None, name),
+                    default: Expr::Cast {
+                        span: None,
+                        expr: Box::new(arg.clone()),
+                        target_type,
+                        pg_style: false,
+                    },
+                }));
+            }
+            declares.append(&mut ast.declares);
+            ast.declares = declares;
+
+            let session = self.create_session().await?;
+            execute_script(&self.ctx, session, &ast).await?
+        };
+
+        res.map_err(|err| err.display_with_sql(&procedure.script))
+    }
+}
@@ -0,0 +1,95 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::ProcedureInfo;
+use databend_common_sql::plans::CreateProcedurePlan;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct CreateProcedureInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateProcedurePlan,
+}
+
+impl CreateProcedureInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateProcedurePlan) -> Result<Self> {
+        Ok(Self { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateProcedureInterpreter {
+    fn name(&self) -> &str {
+        "CreateProcedureInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "create_procedure_execute");
+
+        let plan = self.plan.clone();
+        let current_role = self.ctx.get_current_role();
+        let procedure = ProcedureInfo {
+            name: plan.name,
+            arg_names: plan.arg_names,
+            arg_types: plan.arg_types,
+            return_type: plan.return_type,
+            script: plan.script,
+            execute_as: plan.execute_as,
+            comment: plan.comment,
+            owner: self.ctx.get_current_user()?.identity(),
+            owner_role: current_role.as_ref().map(|role| role.name.clone()),
+            created_on: Utc::now(),
+            updated_on: None,
+        };
+
+        UserApiProvider::instance()
+            .add_procedure(&plan.tenant, procedure, &plan.create_option)
+            .await?;
+
+        // Grant ownership as the current role, the owner grants the usage of the procedure to others.
+        if let Some(current_role) = current_role {
+            let role_api = UserApiProvider::instance().role_api(&plan.tenant);
+            role_api
+                .grant_ownership(
+                    &OwnershipObject::Procedure {
+                        name: self.plan.name.clone(),
+                    },
+                    &current_role.name,
+                )
+                .await?;
+            RoleCacheManager::instance().invalidate_cache(&plan.tenant);
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_sql::plans::Plan;
+
+use crate::interpreters::util::generate_desc_schema;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+/// Describe the result schema of a bound query, the query itself is not executed.
+pub struct DescribeQueryInterpreter {
+    plan: Plan,
+}
+
+impl DescribeQueryInterpreter {
+    pub fn try_create(_ctx: Arc<QueryContext>, plan: Plan) -> Result<Self> {
+        Ok(DescribeQueryInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DescribeQueryInterpreter {
+    fn name(&self) -> &str {
+        "DescribeQueryInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let schema = infer_table_schema(&self.plan.schema())?;
+        let (names, types, nulls, _, _) = generate_desc_schema(schema);
+
+        PipelineBuildResult::from_blocks(vec![DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(types),
+            StringType::from_data(nulls),
+        ])])
+    }
+}
@@ -45,6 +45,7 @@ use databend_common_storage::StageFileInfo;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
 use parking_lot::RwLock;
 
 use crate::interpreters::common::build_update_stream_meta_seq;
@@ -271,17 +272,20 @@ impl ReplaceInterpreter {
             .ctx
             .get_settings()
             .get_replace_into_bloom_pruning_max_column_number()?;
-        let bloom_filter_column_indexes = if !table.cluster_keys(self.ctx.clone()).is_empty() {
-            fuse_table
-                .choose_bloom_filter_columns(
-                    self.ctx.clone(),
-                    &on_conflicts,
-                    max_num_pruning_columns,
-                )
-                .await?
-        } else {
-            vec![]
-        };
+        // the rows of tables with primary key are looked up by the bloom index
+        let has_primary_key = table_info.options().contains_key(OPT_KEY_PRIMARY_KEY);
+        let bloom_filter_column_indexes =
+            if has_primary_key || !table.cluster_keys(self.ctx.clone()).is_empty() {
+                fuse_table
+                    .choose_bloom_filter_columns(
+                        self.ctx.clone(),
+                        &on_conflicts,
+                        max_num_pruning_columns,
+                    )
+                    .await?
+            } else {
+                vec![]
+            };
 
         root = Box::new(PhysicalPlan::ReplaceDeduplicate(Box::new(
             ReplaceDeduplicate {
@@ -314,6 +318,7 @@ impl ReplaceInterpreter {
                 .collect(),
             block_slots: None,
             need_insert: true,
+            error_on_conflict: plan.error_on_conflict,
             plan_id: u32::MAX,
         })));
         if is_distributed {
@@ -40,6 +40,7 @@ use databend_common_storages_result_cache::WriteResultCacheSink;
 use databend_common_users::UserApiProvider;
 use log::error;
 use log::info;
+use rand::Rng;
 
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -263,6 +264,17 @@ impl Interpreter for SelectInterpreter {
             self.ctx.get_id(),
             query_plan
         );
+        self.ctx.set_query_plan(query_plan);
+
+        let settings = self.ctx.get_settings();
+        let sample_rate = settings.get_slow_query_explain_analyze_sample_rate()?;
+        if settings.get_long_query_time()? > 0
+            && sample_rate > 0
+            && rand::thread_rng().gen_range(0..100) < sample_rate
+        {
+            self.ctx
+                .set_sampled_physical_plan(physical_plan.clone(), self.metadata.clone());
+        }
 
         if self.ctx.get_settings().get_enable_query_result_cache()? && self.ctx.get_cacheable() {
             let key = gen_result_cache_key(self.formatted_ast.as_ref().unwrap());
@@ -19,9 +19,11 @@ use databend_common_exception::Result;
 use databend_common_meta_api::ShareApi;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
 use databend_common_meta_app::share::GrantShareObjectReq;
+use databend_common_meta_app::share::ShareGrantObjectName;
 use databend_common_storages_share::save_share_spec;
 use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::check_shared_view_dependencies;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -51,6 +53,10 @@ impl Interpreter for GrantShareObjectInterpreter {
 
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
+        if let ShareGrantObjectName::Table(database, table) = &self.plan.object {
+            check_shared_view_dependencies(&self.ctx, &self.plan.share, database, table).await?;
+        }
+
         let tenant = self.ctx.get_tenant();
         let meta_api = UserApiProvider::instance().get_meta_store_client();
         let req = GrantShareObjectReq {
@@ -43,6 +43,7 @@ use databend_common_sql::field_default_value;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_sql::BloomIndexColumns;
 use databend_common_storage::DataOperator;
+use databend_common_storage::StoragePolicies;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
@@ -55,21 +56,29 @@ use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_AFTER_DAYS;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_STORAGE_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_COMMENT;
 use databend_storages_common_table_meta::table::OPT_KEY_CONNECTION_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY_ENFORCED;
 use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_LOCATION_URI;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 use log::error;
 use log::info;
 
@@ -236,6 +245,7 @@ impl CreateTableInterpreter {
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
             table_info: Some(table_info),
+            returning: None,
         };
 
         // update share spec if needed
@@ -404,10 +414,24 @@ impl CreateTableInterpreter {
         is_valid_block_per_segment(&table_meta.options)?;
         is_valid_row_per_block(&table_meta.options)?;
         // check bloom_index_columns.
-        is_valid_bloom_index_columns(&table_meta.options, schema)?;
+        is_valid_bloom_index_columns(&table_meta.options, schema.clone())?;
+        // check primary key.
+        is_valid_primary_key(&table_meta.options, schema.clone())?;
+        // check vector_index_columns.
+        is_valid_vector_index_columns(&table_meta.options, schema)?;
         is_valid_change_tracking(&table_meta.options)?;
         // check random seed
         is_valid_random_seed(&table_meta.options)?;
+        // check storage policies.
+        is_valid_storage_policy(&table_meta.options)?;
+        if table_meta.storage_params.is_some()
+            && (table_meta.options.contains_key(OPT_KEY_STORAGE_POLICY)
+                || table_meta.options.contains_key(OPT_KEY_COLD_STORAGE_POLICY))
+        {
+            return Err(ErrorCode::TableOptionInvalid(
+                "storage policy can not be used with an external location",
+            ));
+        }
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -518,8 +542,15 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(OPT_KEY_TABLE_COMPRESSION);
     r.insert(OPT_KEY_STORAGE_FORMAT);
     r.insert(OPT_KEY_DATABASE_ID);
+    r.insert(OPT_KEY_TABLE_LOCATION_URI);
     r.insert(OPT_KEY_COMMENT);
     r.insert(OPT_KEY_CHANGE_TRACKING);
+    r.insert(OPT_KEY_PRIMARY_KEY);
+    r.insert(OPT_KEY_PRIMARY_KEY_ENFORCED);
+    r.insert(OPT_KEY_VECTOR_INDEX_COLUMNS);
+    r.insert(OPT_KEY_STORAGE_POLICY);
+    r.insert(OPT_KEY_COLD_STORAGE_POLICY);
+    r.insert(OPT_KEY_COLD_AFTER_DAYS);
 
     r.insert(OPT_KEY_ENGINE);
 
@@ -586,6 +617,52 @@ pub fn is_valid_bloom_index_columns(
     Ok(())
 }
 
+pub fn is_valid_primary_key(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_PRIMARY_KEY) {
+        let mut columns = HashSet::new();
+        for name in value.split(',').map(str::trim) {
+            if schema.field_with_name(name).is_err() {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid primary key, column {name} does not exist"
+                )));
+            }
+            if !columns.insert(name) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid primary key, column {name} duplicated"
+                )));
+            }
+        }
+    }
+    if let Some(value) = options.get(OPT_KEY_PRIMARY_KEY_ENFORCED) {
+        value.to_lowercase().parse::<bool>()?;
+    }
+    Ok(())
+}
+
+pub fn is_valid_vector_index_columns(
+    options: &BTreeMap<String, String>,
+    schema: TableSchemaRef,
+) -> Result<()> {
+    if let Some(value) = options.get(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+        for name in value.split(',').map(str::trim) {
+            let field = schema.field_with_name(name).map_err(|_| {
+                ErrorCode::TableOptionInvalid(format!(
+                    "invalid vector index column, column {name} does not exist"
+                ))
+            })?;
+            if !VectorIndex::supported_type(field.data_type()) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid vector index column, column {name} is not a VECTOR column"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn is_valid_change_tracking(options: &BTreeMap<String, String>) -> Result<()> {
     if let Some(value) = options.get(OPT_KEY_CHANGE_TRACKING) {
         value.to_lowercase().parse::<bool>()?;
@@ -599,3 +676,31 @@ pub fn is_valid_random_seed(options: &BTreeMap<String, String>) -> Result<()> {
     }
     Ok(())
 }
+
+pub fn is_valid_storage_policy(options: &BTreeMap<String, String>) -> Result<()> {
+    let policies = StoragePolicies::instance();
+    for key in [OPT_KEY_STORAGE_POLICY, OPT_KEY_COLD_STORAGE_POLICY] {
+        if let Some(name) = options.get(key) {
+            if !policies.contains(name) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "invalid {key} option, storage policy {name} is not configured"
+                )));
+            }
+        }
+    }
+    if options.get(OPT_KEY_STORAGE_POLICY) == options.get(OPT_KEY_COLD_STORAGE_POLICY)
+        && options.contains_key(OPT_KEY_COLD_STORAGE_POLICY)
+    {
+        return Err(ErrorCode::TableOptionInvalid(
+            "invalid cold_storage_policy option, can't be the same as storage_policy",
+        ));
+    }
+    if let Some(value) = options.get(OPT_KEY_COLD_AFTER_DAYS) {
+        value.parse::<u64>().map_err(|_| {
+            ErrorCode::TableOptionInvalid(format!(
+                "invalid cold_after_days option {value}, must be a number of days"
+            ))
+        })?;
+    }
+    Ok(())
+}
@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -29,6 +31,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 
+use crate::interpreters::common::find_dependent_views;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -43,6 +46,36 @@ impl DropTableInterpreter {
     pub fn try_create(ctx: Arc<QueryContext>, plan: DropTablePlan) -> Result<Self> {
         Ok(DropTableInterpreter { ctx, plan })
     }
+
+    /// Drops the views that refer to the dropped table, and the views that refer to them.
+    #[async_backtrace::framed]
+    async fn drop_dependent_views(&self, views: Vec<(String, Arc<dyn Table>)>) -> Result<()> {
+        let catalog_name = self.plan.catalog.as_str();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let tenant = self.ctx.get_tenant();
+
+        let mut dropped = HashSet::new();
+        let mut views = views;
+        while let Some((db_name, view)) = views.pop() {
+            if !dropped.insert(view.get_id()) {
+                continue;
+            }
+            let db = catalog.get_database(&tenant, &db_name).await?;
+            catalog
+                .drop_table_by_id(DropTableByIdReq {
+                    if_exists: true,
+                    tenant: tenant.clone(),
+                    table_name: view.name().to_string(),
+                    tb_id: view.get_id(),
+                    db_id: db.get_db_info().ident.db_id,
+                })
+                .await?;
+            views.extend(
+                find_dependent_views(&self.ctx, catalog_name, &db_name, view.name()).await?,
+            );
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -99,6 +132,10 @@ impl Interpreter for DropTableInterpreter {
                 })?
         }
 
+        // The views that refer to the table can not be queried once it is dropped.
+        let dependent_views =
+            find_dependent_views(&self.ctx, catalog_name, db_name, tbl_name).await?;
+
         let tenant = self.ctx.get_tenant();
         let db = catalog.get_database(&tenant, &self.plan.database).await?;
         // actually drop table
@@ -125,6 +162,20 @@ impl Interpreter for DropTableInterpreter {
         role_api.revoke_ownership(&owner_object).await?;
         RoleCacheManager::instance().invalidate_cache(&tenant);
 
+        if self.plan.cascade {
+            self.drop_dependent_views(dependent_views).await?;
+        } else if !dependent_views.is_empty() {
+            let views = dependent_views
+                .iter()
+                .map(|(db, view)| format!("`{}`.`{}`", db, view.name()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.ctx.push_warning(format!(
+                "table `{}`.`{}` is referenced by view {}, use `DROP TABLE ... CASCADE` to drop the views as well",
+                db_name, tbl_name, views
+            ));
+        }
+
         let mut build_res = PipelineBuildResult::create();
         // if `plan.all`, truncate, then purge the historical data
         if self.plan.all {
@@ -27,6 +27,8 @@ use databend_common_storages_share::save_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
@@ -98,6 +100,16 @@ impl Interpreter for DropTableColumnInterpreter {
             )?;
         }
 
+        // check if the column is part of the primary key
+        if let Some(value) = table_info.options().get(OPT_KEY_PRIMARY_KEY) {
+            if value.split(',').any(|col| col.trim() == self.plan.column) {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "column {} is part of the primary key, can't be dropped",
+                    self.plan.column
+                )));
+            }
+        }
+
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
@@ -114,6 +126,19 @@ impl Interpreter for DropTableColumnInterpreter {
                 }
             }
         }
+        if let Some(value) = opts.get(OPT_KEY_VECTOR_INDEX_COLUMNS) {
+            // remove from the vector index columns.
+            let cols = value
+                .split(',')
+                .map(str::trim)
+                .filter(|col| *col != self.plan.column)
+                .collect::<Vec<_>>();
+            if cols.is_empty() {
+                opts.remove(OPT_KEY_VECTOR_INDEX_COLUMNS);
+            } else {
+                opts.insert(OPT_KEY_VECTOR_INDEX_COLUMNS.to_string(), cols.join(","));
+            }
+        }
 
         let table_id = table_info.ident.table_id;
         let table_version = table_info.ident.seq;
@@ -37,6 +37,7 @@ use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::executor::PhysicalPlanBuilder;
 use databend_common_sql::field_default_value;
+use databend_common_sql::is_dependent_default_expr;
 use databend_common_sql::plans::ModifyColumnAction;
 use databend_common_sql::plans::ModifyTableColumnPlan;
 use databend_common_sql::plans::Plan;
@@ -160,6 +161,12 @@ impl ModifyTableColumnInterpreter {
             if let Some((i, _)) = schema.column_with_name(column) {
                 if let Some(default_expr) = &field.default_expr {
                     let default_expr = default_expr.to_string();
+                    if is_dependent_default_expr(&default_expr)? {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "default expression of column {} can't refer to other columns when the column is modified",
+                            column
+                        )));
+                    }
                     new_schema.fields[i].data_type = data_type.clone();
                     new_schema.fields[i].default_expr = Some(default_expr);
                     let _ = field_default_value(self.ctx.clone(), &new_schema.fields[i])?;
@@ -405,6 +412,7 @@ impl ModifyTableColumnInterpreter {
                 select_column_bindings,
                 insert_schema: Arc::new(Arc::new(new_schema).into()),
                 cast_needed: true,
+                returning: vec![],
             }));
         let mut build_res =
             build_query_pipeline_without_render_result_set(&self.ctx, &insert_plan).await?;
@@ -38,6 +38,7 @@ use databend_common_sql::plans::OptimizeTablePlan;
 use databend_common_storages_factory::NavigationPoint;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_STORAGE_POLICY;
 
 use crate::interpreters::interpreter_table_recluster::build_recluster_physical_plan;
 use crate::interpreters::Interpreter;
@@ -102,6 +103,10 @@ impl Interpreter for OptimizeTableInterpreter {
                 self.build_pipeline(catalog, table, CompactTarget::Blocks, true)
                     .await
             }
+            OptimizeTableAction::Tier => {
+                tier(ctx, catalog, plan, true).await?;
+                Ok(PipelineBuildResult::create())
+            }
         }
     }
 }
@@ -271,13 +276,13 @@ impl OptimizeTableInterpreter {
         let plan = self.plan.clone();
         if need_purge {
             if build_res.main_pipeline.is_empty() {
-                purge(ctx, catalog, plan, None).await?;
+                tier_and_purge(ctx, catalog, plan).await?;
             } else {
                 build_res
                     .main_pipeline
                     .set_on_finished(move |may_error| match may_error {
                         Ok(_) => GlobalIORuntime::instance()
-                            .block_on(async move { purge(ctx, catalog, plan, None).await }),
+                            .block_on(async move { tier_and_purge(ctx, catalog, plan).await }),
                         Err(error_code) => Err(error_code.clone()),
                     });
             }
@@ -306,3 +311,34 @@ async fn purge(
     assert!(res.is_none());
     Ok(())
 }
+
+/// Moves the cold segments to the cold storage policy of the table.
+///
+/// If `strict` is false, the tables without cold storage policy are skipped.
+async fn tier(
+    ctx: Arc<QueryContext>,
+    catalog: Arc<dyn Catalog>,
+    plan: OptimizeTablePlan,
+    strict: bool,
+) -> Result<()> {
+    // refresh the table, same as purge.
+    let table = catalog
+        .get_table(&ctx.get_tenant(), &plan.database, &plan.table)
+        .await?;
+    if !strict && !table.options().contains_key(OPT_KEY_COLD_STORAGE_POLICY) {
+        return Ok(());
+    }
+
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    fuse_table.do_tiering(ctx, plan.limit).await?;
+    Ok(())
+}
+
+async fn tier_and_purge(
+    ctx: Arc<QueryContext>,
+    catalog: Arc<dyn Catalog>,
+    plan: OptimizeTablePlan,
+) -> Result<()> {
+    tier(ctx.clone(), catalog.clone(), plan.clone(), false).await?;
+    purge(ctx, catalog, plan, None).await
+}
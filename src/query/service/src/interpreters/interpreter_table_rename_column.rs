@@ -27,6 +27,8 @@ use databend_common_storages_share::save_share_table_info;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
 use crate::interpreters::interpreter_table_create::is_valid_column;
@@ -120,6 +122,23 @@ impl Interpreter for RenameTableColumnInterpreter {
                     }
                 }
             }
+            for key in [OPT_KEY_PRIMARY_KEY, OPT_KEY_VECTOR_INDEX_COLUMNS] {
+                let Some(value) = opts.get_mut(key) else {
+                    continue;
+                };
+                // replace the primary key or vector index columns with new column name.
+                *value = value
+                    .split(',')
+                    .map(|col| {
+                        if col.trim() == self.plan.old_column {
+                            self.plan.new_column.as_str()
+                        } else {
+                            col
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(",");
+            }
 
             let table_id = table_info.ident.table_id;
             let table_version = table_info.ident.seq;
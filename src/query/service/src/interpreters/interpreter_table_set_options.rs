@@ -24,14 +24,19 @@ use databend_common_sql::plans::SetOptionsPlan;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING_BEGIN_VER;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_STORAGE_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_POLICY;
 use log::error;
 
 use super::interpreter_table_create::is_valid_block_per_segment;
 use super::interpreter_table_create::is_valid_bloom_index_columns;
 use super::interpreter_table_create::is_valid_create_opt;
+use super::interpreter_table_create::is_valid_primary_key;
 use super::interpreter_table_create::is_valid_row_per_block;
+use super::interpreter_table_create::is_valid_storage_policy;
+use super::interpreter_table_create::is_valid_vector_index_columns;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -81,6 +86,14 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_DATABASE_ID
             )));
         }
+        // the data of the table is already stored on the storage policy
+        if self.plan.set_options.get(OPT_KEY_STORAGE_POLICY).is_some() {
+            error!("{}", &error_str);
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "can't change {} for alter table statement",
+                OPT_KEY_STORAGE_POLICY
+            )));
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -116,6 +129,30 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check primary key.
+        is_valid_primary_key(&self.plan.set_options, table.schema())?;
+        // check vector_index_columns.
+        is_valid_vector_index_columns(&self.plan.set_options, table.schema())?;
+        // check storage policies, against the options after altered.
+        if let Some(cold) = self.plan.set_options.get(OPT_KEY_COLD_STORAGE_POLICY) {
+            if table.get_table_info().meta.storage_params.is_some() {
+                return Err(ErrorCode::TableOptionInvalid(
+                    "storage policy can not be used with an external location",
+                ));
+            }
+            // the cold segments may be already moved to the cold storage policy
+            if let Some(old) = table.options().get(OPT_KEY_COLD_STORAGE_POLICY) {
+                if old != cold {
+                    return Err(ErrorCode::TableOptionInvalid(format!(
+                        "can't change {} once it is set",
+                        OPT_KEY_COLD_STORAGE_POLICY
+                    )));
+                }
+            }
+        }
+        let mut options = table.options().clone();
+        options.extend(self.plan.set_options.clone());
+        is_valid_storage_policy(&options)?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
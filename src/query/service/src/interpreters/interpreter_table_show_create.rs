@@ -22,7 +22,9 @@ use databend_common_expression::BlockEntry;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
 use databend_common_expression::Value;
+use databend_common_io::escape_string_with_quote;
 use databend_common_sql::plans::ShowCreateTablePlan;
 use databend_common_storages_stream::stream_table::StreamTable;
 use databend_common_storages_stream::stream_table::STREAM_ENGINE;
@@ -32,8 +34,10 @@ use databend_storages_common_table_meta::table::is_internal_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_LOCATION_URI;
 use log::debug;
 
+use super::interpreter_table_create::is_valid_create_opt;
 use crate::interpreters::util::format_name;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -110,11 +114,6 @@ impl ShowCreateTableInterpreter {
         {
             let mut create_defs = vec![];
             for (idx, field) in schema.fields().iter().enumerate() {
-                let nullable = if field.is_nullable() {
-                    " NULL".to_string()
-                } else {
-                    " NOT NULL".to_string()
-                };
                 let default_expr = match field.default_expr() {
                     Some(expr) => {
                         format!(" DEFAULT {expr}")
@@ -137,16 +136,15 @@ impl ShowCreateTableInterpreter {
                     // can not use debug print, will add double quote
                     format!(
                         " COMMENT '{}'",
-                        &field_comments[idx].as_str().replace('\'', "\\'")
+                        escape_string_with_quote(&field_comments[idx], Some('\''))
                     )
                 } else {
                     "".to_string()
                 };
                 let column_str = format!(
-                    "  {} {}{}{}{}{}",
+                    "  {} {}{}{}{}",
                     format_name(field.name(), quoted_ident_case_sensitive, sql_dialect),
-                    field.data_type().remove_recursive_nullable().sql_name(),
-                    nullable,
+                    format_column_type(field.data_type()),
                     default_expr,
                     computed_expr,
                     comment
@@ -169,7 +167,11 @@ impl ShowCreateTableInterpreter {
                 let column_names_str = column_names.join(", ").to_string();
                 let mut options = Vec::with_capacity(index_field.options.len());
                 for (key, value) in index_field.options.iter() {
-                    let option = format!("{} = '{}'", key, value);
+                    let option = format!(
+                        "{} = '{}'",
+                        key,
+                        escape_string_with_quote(value, Some('\''))
+                    );
                     options.push(option);
                 }
                 let mut index_str = format!(
@@ -197,6 +199,12 @@ impl ShowCreateTableInterpreter {
         let table_engine = format!(") ENGINE={}", engine);
         table_create_sql.push_str(table_engine.as_str());
 
+        // The external location must be placed before `CLUSTER BY` and the table options.
+        let location_uri = table_info.options().get(OPT_KEY_TABLE_LOCATION_URI);
+        if let Some(uri) = location_uri {
+            table_create_sql.push_str(format!(" {}", uri).as_str());
+        }
+
         if let Some((_, cluster_keys_str)) = table_info.meta.cluster_key() {
             table_create_sql.push_str(format!(" CLUSTER BY {}", cluster_keys_str).as_str());
         }
@@ -210,23 +218,43 @@ impl ShowCreateTableInterpreter {
             table_create_sql.push_str({
                 let mut opts = table_info.options().iter().collect::<Vec<_>>();
                 opts.sort_by_key(|(k, _)| *k);
+                // Only the options that can be specified in `CREATE TABLE`, the others like
+                // `snapshot_location` are maintained by the storage. `TRANSIENT` is already
+                // shown as `CREATE TRANSIENT TABLE`.
                 opts.iter()
-                    .filter(|(k, _)| !is_internal_opt_key(k))
-                    .map(|(k, v)| format!(" {}='{}'", k.to_uppercase(), v))
+                    .filter(|(k, _)| {
+                        !is_internal_opt_key(k)
+                            && is_valid_create_opt(k)
+                            && k.as_str() != "TRANSIENT"
+                    })
+                    .map(|(k, v)| {
+                        format!(
+                            " {}='{}'",
+                            k.to_uppercase(),
+                            escape_string_with_quote(v, Some('\''))
+                        )
+                    })
                     .collect::<Vec<_>>()
                     .join("")
                     .as_str()
             });
         }
 
-        if engine != "ICEBERG" && engine != "DELTA" {
+        if engine != "ICEBERG" && engine != "DELTA" && location_uri.is_none() {
+            // The tables created with an external location before the location uri is kept.
             if let Some(sp) = &table_info.meta.storage_params {
                 table_create_sql.push_str(format!(" LOCATION = '{}'", sp).as_str());
             }
         }
 
         if !table_info.meta.comment.is_empty() {
-            table_create_sql.push_str(format!(" COMMENT = '{}'", table_info.meta.comment).as_str());
+            table_create_sql.push_str(
+                format!(
+                    " COMMENT = '{}'",
+                    escape_string_with_quote(&table_info.meta.comment, Some('\''))
+                )
+                .as_str(),
+            );
         }
 
         let block = DataBlock::new(
@@ -337,3 +365,49 @@ impl ShowCreateTableInterpreter {
         PipelineBuildResult::from_blocks(vec![block])
     }
 }
+
+/// Formats the type of a column as it is written in `CREATE TABLE`. The nullability of the
+/// inner types is always written out, because it defaults to the nullability of the column.
+fn format_column_type(data_type: &TableDataType) -> String {
+    match data_type {
+        TableDataType::Nullable(inner) => format!("{} NULL", format_inner_type(inner)),
+        _ => format!("{} NOT NULL", format_inner_type(data_type)),
+    }
+}
+
+fn format_inner_type(data_type: &TableDataType) -> String {
+    match data_type {
+        TableDataType::Array(inner) => format!("ARRAY({})", format_column_type(inner)),
+        TableDataType::Map(inner) => match inner.as_ref() {
+            TableDataType::Tuple { fields_type, .. } => format!(
+                "MAP({}, {})",
+                format_column_type(&fields_type[0]),
+                format_column_type(&fields_type[1])
+            ),
+            _ => unreachable!(),
+        },
+        TableDataType::Tuple {
+            fields_name,
+            fields_type,
+        } => {
+            // The names of the unnamed tuple fields are their positions.
+            let unnamed = fields_name
+                .iter()
+                .enumerate()
+                .all(|(i, name)| *name == (i + 1).to_string());
+            let fields = fields_name
+                .iter()
+                .zip(fields_type)
+                .map(|(name, ty)| {
+                    if unnamed {
+                        format_column_type(ty)
+                    } else {
+                        format!("{} {}", name, format_column_type(ty))
+                    }
+                })
+                .collect::<Vec<_>>();
+            format!("TUPLE({})", fields.join(", "))
+        }
+        _ => data_type.sql_name(),
+    }
+}
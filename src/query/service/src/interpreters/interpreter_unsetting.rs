@@ -53,6 +53,36 @@ impl Interpreter for UnSettingInterpreter {
         let mut is_globals: Vec<bool> = vec![];
 
         let settings = self.ctx.get_shared_settings();
+        if plan.is_session {
+            for var in plan.vars {
+                let setting_key = var.to_lowercase();
+                if !settings.has_setting(&setting_key)? {
+                    self.ctx
+                        .push_warning(format!("Unknown setting: '{}'", setting_key));
+                    continue;
+                }
+
+                // Fall back to the global value of the tenant, or the default value.
+                settings.try_unset_session_setting(&setting_key).await?;
+                if let Some(item) = settings
+                    .as_ref()
+                    .into_iter()
+                    .find(|x| x.name == setting_key)
+                {
+                    values.push(item.user_value.as_string());
+                    keys.push(setting_key);
+                    is_globals.push(false);
+                }
+            }
+
+            self.ctx.set_affect(QueryAffect::ChangeSettings {
+                keys,
+                values,
+                is_globals,
+            });
+            return Ok(PipelineBuildResult::create());
+        }
+
         for var in plan.vars {
             let (ok, value) = match var.to_lowercase().as_str() {
                 // To be compatible with some drivers
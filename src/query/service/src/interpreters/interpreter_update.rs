@@ -27,6 +27,7 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::SendableDataBlockStream;
 use databend_common_expression::ROW_ID_COLUMN_ID;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -59,6 +60,7 @@ use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 use crate::sql::plans::UpdatePlan;
+use crate::stream::DataBlockStream;
 
 /// interprets UpdatePlan
 pub struct UpdateInterpreter {
@@ -133,6 +135,11 @@ impl Interpreter for UpdateInterpreter {
         build_res.main_pipeline.add_lock_guard(lock_guard);
         Ok(build_res)
     }
+
+    fn inject_result(&self) -> Result<SendableDataBlockStream> {
+        let blocks = self.ctx.get_returning_blocks();
+        Ok(Box::pin(DataBlockStream::create(None, blocks)))
+    }
 }
 
 impl UpdateInterpreter {
@@ -264,7 +271,12 @@ impl UpdateInterpreter {
                 )
                 .await?;
 
-            let is_distributed = !self.ctx.get_cluster().is_empty();
+            let returning = match &self.plan.returning {
+                Some(returning) => returning.remote_exprs()?,
+                None => vec![],
+            };
+            // the updated rows are collected on the local node for `RETURNING`.
+            let is_distributed = !self.ctx.get_cluster().is_empty() && returning.is_empty();
             let physical_plan = Self::build_physical_plan(
                 filters,
                 update_list,
@@ -276,6 +288,7 @@ impl UpdateInterpreter {
                 catalog_info,
                 query_row_id_col,
                 is_distributed,
+                returning,
                 self.ctx.clone(),
             )?;
             return Ok(Some(physical_plan));
@@ -294,6 +307,7 @@ impl UpdateInterpreter {
         catalog_info: CatalogInfo,
         query_row_id_col: bool,
         is_distributed: bool,
+        returning: Vec<RemoteExpr<String>>,
         ctx: Arc<QueryContext>,
     ) -> Result<PhysicalPlan> {
         let merge_meta = partitions.partitions_type() == PartInfoType::LazyLevel;
@@ -306,6 +320,7 @@ impl UpdateInterpreter {
             query_row_id_col,
             update_list,
             computed_list,
+            returning,
             plan_id: u32::MAX,
         }));
 
@@ -23,6 +23,7 @@ use databend_common_meta_app::principal::StageType;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::CreateStagePlan;
+use databend_common_storages_stage::StageDirectory;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -100,6 +101,7 @@ impl Interpreter for CreateUserStageInterpreter {
         let _ = user_mgr
             .add_stage(tenant, user_stage.clone(), &plan.create_option)
             .await?;
+        StageDirectory::instance().remove(tenant, &user_stage.stage_name);
 
         // when create or replace stage success, if old stage is not External stage, remove stage files
         if let Some(stage) = old_stage {
@@ -20,6 +20,7 @@ use databend_common_management::RoleApi;
 use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::principal::StageType;
 use databend_common_sql::plans::DropStagePlan;
+use databend_common_storages_stage::StageDirectory;
 use databend_common_storages_stage::StageTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
@@ -73,6 +74,7 @@ impl Interpreter for DropUserStageInterpreter {
         user_mgr
             .drop_stage(&tenant, &plan.name, plan.if_exists)
             .await?;
+        StageDirectory::instance().remove(&tenant, &plan.name);
 
         if let Ok(stage) = stage {
             // we should do `drop ownership` after actually drop stage,
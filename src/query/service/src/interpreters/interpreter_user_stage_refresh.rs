@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_sql::plans::RefreshStagePlan;
+use databend_common_storages_stage::StageDirectory;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+
+#[derive(Debug)]
+pub struct RefreshStageInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshStagePlan,
+}
+
+impl RefreshStageInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshStagePlan) -> Result<Self> {
+        Ok(RefreshStageInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshStageInterpreter {
+    fn name(&self) -> &str {
+        "RefreshStageInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "refresh_stage_execute");
+
+        let thread_num = self.ctx.get_settings().get_max_threads()? as usize;
+        StageDirectory::instance()
+            .refresh(&self.plan.tenant, &self.plan.stage, thread_num)
+            .await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
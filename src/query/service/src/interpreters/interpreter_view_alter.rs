@@ -12,16 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
-use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterViewPlan;
+use databend_common_sql::plans::Plan;
 use databend_common_sql::Planner;
+use databend_common_storages_view::view_table::QUERY;
 
+use crate::interpreters::common::collect_view_dependencies;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -55,12 +57,17 @@ impl Interpreter for AlterViewInterpreter {
             .get_table(&self.plan.tenant, &self.plan.database, &self.plan.view_name)
             .await
         {
-            let mut options = HashMap::new();
+            let mut planner = Planner::new(self.ctx.clone());
+            let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
+            let dependencies = match &plan {
+                Plan::Query { metadata, .. } => {
+                    collect_view_dependencies(metadata, &catalog.list_table_functions())
+                }
+                _ => vec![],
+            };
             let subquery = if self.plan.column_names.is_empty() {
                 self.plan.subquery.clone()
             } else {
-                let mut planner = Planner::new(self.ctx.clone());
-                let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
                 if plan.schema().fields().len() != self.plan.column_names.len() {
                     return Err(ErrorCode::BadDataArrayLength(format!(
                         "column name length mismatch, expect {}, got {}",
@@ -75,17 +82,22 @@ impl Interpreter for AlterViewInterpreter {
                     self.plan.column_names.join(", ")
                 )
             };
-            options.insert("query".to_string(), Some(subquery));
+            let table_info = tbl.get_table_info();
+            let mut new_table_meta = table_info.meta.clone();
+            new_table_meta.options.insert(QUERY.to_string(), subquery);
+            // The dependencies of the old query are replaced as well.
+            new_table_meta.view_dependencies = dependencies;
 
-            let req = UpsertTableOptionReq {
+            let req = UpdateTableMetaReq {
                 table_id: tbl.get_id(),
-                seq: MatchSeq::Exact(tbl.get_table_info().ident.seq),
-                options,
+                seq: MatchSeq::Exact(table_info.ident.seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
             };
 
-            catalog
-                .upsert_table_option(&self.plan.tenant, &self.plan.database, req)
-                .await?;
+            catalog.update_table_meta(table_info, req).await?;
 
             Ok(PipelineBuildResult::create())
         } else {
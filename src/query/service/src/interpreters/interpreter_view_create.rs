@@ -26,6 +26,7 @@ use databend_common_sql::Planner;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 
+use crate::interpreters::common::collect_view_dependencies;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -60,8 +61,9 @@ impl Interpreter for CreateViewInterpreter {
         let mut options = BTreeMap::new();
         let mut planner = Planner::new(self.ctx.clone());
         let (plan, _) = planner.plan_sql(&self.plan.subquery.clone()).await?;
-        match plan.clone() {
+        let dependencies = match plan.clone() {
             Plan::Query { metadata, .. } => {
+                let dependencies = collect_view_dependencies(&metadata, &table_function);
                 let metadata = metadata.read().clone();
                 for table in metadata.tables() {
                     let database_name = table.database();
@@ -80,12 +82,13 @@ impl Interpreter for CreateViewInterpreter {
                         )));
                     }
                 }
+                dependencies
             }
             _ => {
                 // This logic will never be used, because of QUERY parse as query
                 return Err(ErrorCode::Unimplemented("create view only support Query"));
             }
-        }
+        };
 
         let subquery = if self.plan.column_names.is_empty() {
             self.plan.subquery.clone()
@@ -116,6 +119,7 @@ impl Interpreter for CreateViewInterpreter {
             table_meta: TableMeta {
                 engine: VIEW_ENGINE.to_string(),
                 options,
+                view_dependencies: dependencies,
                 ..Default::default()
             },
             as_dropped: false,
@@ -31,9 +31,11 @@ mod interpreter_copy_into_table;
 mod interpreter_data_mask_create;
 mod interpreter_data_mask_desc;
 mod interpreter_data_mask_drop;
+mod interpreter_database_backup;
 mod interpreter_database_create;
 mod interpreter_database_drop;
 mod interpreter_database_rename;
+mod interpreter_database_restore;
 mod interpreter_database_show_create;
 mod interpreter_database_undrop;
 mod interpreter_delete;
@@ -64,9 +66,17 @@ mod interpreter_password_policy_alter;
 mod interpreter_password_policy_create;
 mod interpreter_password_policy_desc;
 mod interpreter_password_policy_drop;
+mod interpreter_pipe_alter;
+mod interpreter_pipe_create;
+mod interpreter_pipe_desc;
+mod interpreter_pipe_drop;
 mod interpreter_presign;
 mod interpreter_privilege_grant;
 mod interpreter_privilege_revoke;
+mod interpreter_procedure_call;
+mod interpreter_procedure_create;
+mod interpreter_procedure_drop;
+mod interpreter_query_describe;
 mod interpreter_replace;
 mod interpreter_role_create;
 mod interpreter_role_drop;
@@ -90,6 +100,7 @@ mod interpreter_share_show;
 mod interpreter_share_show_grant_tenants;
 mod interpreter_show_grants;
 mod interpreter_show_object_grant_privileges;
+mod interpreter_show_warnings;
 mod interpreter_stream_create;
 mod interpreter_stream_drop;
 mod interpreter_table_add_column;
@@ -131,6 +142,7 @@ mod interpreter_user_create;
 mod interpreter_user_drop;
 mod interpreter_user_stage_create;
 mod interpreter_user_stage_drop;
+mod interpreter_user_stage_refresh;
 mod interpreter_user_stage_remove;
 mod interpreter_user_udf_alter;
 mod interpreter_user_udf_create;
@@ -159,9 +171,11 @@ pub use interpreter_clustering_history::InterpreterClusteringHistory;
 pub use interpreter_data_mask_create::CreateDataMaskInterpreter;
 pub use interpreter_data_mask_desc::DescDataMaskInterpreter;
 pub use interpreter_data_mask_drop::DropDataMaskInterpreter;
+pub use interpreter_database_backup::BackupDatabaseInterpreter;
 pub use interpreter_database_create::CreateDatabaseInterpreter;
 pub use interpreter_database_drop::DropDatabaseInterpreter;
 pub use interpreter_database_rename::RenameDatabaseInterpreter;
+pub use interpreter_database_restore::RestoreDatabaseInterpreter;
 pub use interpreter_database_show_create::ShowCreateDatabaseInterpreter;
 pub use interpreter_database_undrop::UndropDatabaseInterpreter;
 pub use interpreter_delete::DeleteInterpreter;
@@ -184,6 +198,7 @@ pub use interpreter_password_policy_desc::DescPasswordPolicyInterpreter;
 pub use interpreter_password_policy_drop::DropPasswordPolicyInterpreter;
 pub use interpreter_privilege_grant::GrantPrivilegeInterpreter;
 pub use interpreter_privilege_revoke::RevokePrivilegeInterpreter;
+pub use interpreter_query_describe::DescribeQueryInterpreter;
 pub use interpreter_replace::ReplaceInterpreter;
 pub use interpreter_role_create::CreateRoleInterpreter;
 pub use interpreter_role_drop::DropRoleInterpreter;
@@ -205,6 +220,7 @@ pub use interpreter_share_show::ShowSharesInterpreter;
 pub use interpreter_share_show_grant_tenants::ShowGrantTenantsOfShareInterpreter;
 pub use interpreter_show_grants::ShowGrantsInterpreter;
 pub use interpreter_show_object_grant_privileges::ShowObjectGrantPrivilegesInterpreter;
+pub use interpreter_show_warnings::ShowWarningsInterpreter;
 pub use interpreter_stream_create::CreateStreamInterpreter;
 pub use interpreter_stream_drop::DropStreamInterpreter;
 pub use interpreter_table_add_column::AddTableColumnInterpreter;
@@ -235,6 +251,7 @@ pub use interpreter_user_create::CreateUserInterpreter;
 pub use interpreter_user_drop::DropUserInterpreter;
 pub use interpreter_user_stage_create::CreateUserStageInterpreter;
 pub use interpreter_user_stage_drop::DropUserStageInterpreter;
+pub use interpreter_user_stage_refresh::RefreshStageInterpreter;
 pub use interpreter_user_stage_remove::RemoveUserStageInterpreter;
 pub use interpreter_user_udf_alter::AlterUserUDFScript;
 pub use interpreter_user_udf_create::CreateUserUDFScript;
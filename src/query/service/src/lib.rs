@@ -45,15 +45,18 @@ extern crate core;
 pub mod auth;
 pub mod catalogs;
 pub mod clusters;
+pub mod config_reload;
 pub mod databases;
 pub mod interpreters;
 pub mod local;
 pub mod locks;
 pub mod pipelines;
+pub mod pipes;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
 pub mod spillers;
+pub mod storage_usage;
 pub mod stream;
 pub mod table_functions;
 pub mod test_kits;
@@ -141,6 +141,8 @@ impl LockManager {
                 key: lock.watch_delete_key(reply[position - 1].0),
                 key_end: None,
                 filter_type: FilterType::Delete.into(),
+                prefixes: vec![],
+                start_revision: None,
             };
             let mut watch_stream = meta_api.watch(req).await?;
             // Add a timeout period for watch.
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::with_hash_method;
 use databend_common_expression::with_mappedhash_method;
@@ -25,7 +26,6 @@ use databend_common_expression::HashMethodKind;
 use databend_common_expression::HashTableConfig;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
 use databend_common_sql::executor::physical_plans::AggregateFunctionDesc;
@@ -44,6 +44,7 @@ use crate::pipelines::processors::transforms::aggregator::TransformExpandGroupin
 use crate::pipelines::processors::transforms::aggregator::TransformGroupBySpillWriter;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialAggregate;
 use crate::pipelines::processors::transforms::aggregator::TransformPartialGroupBy;
+use crate::pipelines::processors::transforms::aggregator::TransformStreamingAggregate;
 use crate::pipelines::PipelineBuilder;
 
 impl PipelineBuilder {
@@ -139,7 +140,11 @@ impl PipelineBuilder {
         } else {
             HashTableConfig::default()
                 .cluster_with_partial(true, self.ctx.get_cluster().nodes.len())
-        };
+        }
+        .with_partial_bypass(
+            self.settings.get_partial_aggregation_bypass_rows()? as usize,
+            self.settings.get_partial_aggregation_bypass_ratio()? as f64 / 100_f64,
+        );
 
         self.main_pipeline.add_transform(|input, output| {
             Ok(ProcessorPtr::create(
@@ -161,7 +166,8 @@ impl PipelineBuilder {
                             input,
                             output,
                             params.clone(),
-                            partial_agg_config.clone()
+                            partial_agg_config.clone(),
+                            aggregate.group_key_range.clone()
                         ),
                     }),
                 }?,
@@ -171,7 +177,7 @@ impl PipelineBuilder {
         // If cluster mode, spill write will be completed in exchange serialize, because we need scatter the block data first
         if self.ctx.get_cluster().is_empty() {
             let operator = DataOperator::instance().operator();
-            let location_prefix = query_spill_prefix(self.ctx.get_tenant().tenant_name());
+            let location_prefix = self.ctx.node_spill_prefix();
             self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(
                     match params.aggregate_functions.is_empty() {
@@ -243,6 +249,28 @@ impl PipelineBuilder {
             return Ok(());
         }
 
+        if let PhysicalPlan::AggregatePartial(AggregatePartial {
+            input: partial_input,
+            streaming: true,
+            ..
+        }) = aggregate.input.as_ref()
+        {
+            // The input is sorted on the group keys, aggregate it in one stream.
+            self.build_pipeline(partial_input)?;
+            if self.main_pipeline.output_len() != 1 {
+                return Err(ErrorCode::Internal(format!(
+                    "The sorted input of streaming aggregation has {} outputs",
+                    self.main_pipeline.output_len()
+                )));
+            }
+
+            return self.main_pipeline.add_transform(|input, output| {
+                Ok(ProcessorPtr::create(
+                    TransformStreamingAggregate::try_create(input, output, params.clone())?,
+                ))
+            });
+        }
+
         let efficiently_memory = self.settings.get_efficiently_memory_group_by()?;
 
         let group_cols = &params.group_columns;
@@ -266,7 +294,12 @@ impl PipelineBuilder {
 
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
-                    build_partition_bucket::<_, ()>(v, &mut self.main_pipeline, params.clone())
+                    build_partition_bucket::<_, ()>(
+                        v,
+                        &mut self.main_pipeline,
+                        params.clone(),
+                        self.ctx.get_spill_cipher(),
+                    )
                 }
             }),
             false => with_hash_method!(|T| match method {
@@ -281,7 +314,12 @@ impl PipelineBuilder {
                     }
                     self.build_pipeline(&aggregate.input)?;
                     self.exchange_injector = old_inject;
-                    build_partition_bucket::<_, usize>(v, &mut self.main_pipeline, params.clone())
+                    build_partition_bucket::<_, usize>(
+                        v,
+                        &mut self.main_pipeline,
+                        params.clone(),
+                        self.ctx.get_spill_cipher(),
+                    )
                 }
             }),
         }
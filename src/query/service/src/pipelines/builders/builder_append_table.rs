@@ -18,10 +18,12 @@ use databend_common_catalog::table::AppendMode;
 use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::Pipeline;
 
+use crate::pipelines::processors::TransformReturning;
 use crate::pipelines::PipelineBuilder;
 use crate::sessions::QueryContext;
 
@@ -33,6 +35,7 @@ impl PipelineBuilder {
         main_pipeline: &mut Pipeline,
         table: Arc<dyn Table>,
         source_schema: DataSchemaRef,
+        returning: Vec<RemoteExpr<String>>,
         copied_files: Option<UpsertTableCopiedFileReq>,
         update_stream_meta: Vec<UpdateStreamMetaReq>,
         overwrite: bool,
@@ -40,6 +43,7 @@ impl PipelineBuilder {
         deduplicated_label: Option<String>,
     ) -> Result<()> {
         Self::fill_and_reorder_columns(ctx.clone(), main_pipeline, table.clone(), source_schema)?;
+        Self::build_returning(ctx.clone(), main_pipeline, table.as_ref(), returning)?;
 
         table.append_data(ctx.clone(), main_pipeline, append_mode)?;
 
@@ -69,4 +73,28 @@ impl PipelineBuilder {
 
         Ok(())
     }
+
+    /// Collect the results of `RETURNING` from the blocks to be appended, which
+    /// are in the order of the table columns.
+    pub fn build_returning(
+        ctx: Arc<QueryContext>,
+        main_pipeline: &mut Pipeline,
+        table: &dyn Table,
+        returning: Vec<RemoteExpr<String>>,
+    ) -> Result<()> {
+        if returning.is_empty() {
+            return Ok(());
+        }
+
+        let schema = table.schema().remove_virtual_computed_fields();
+        main_pipeline.add_transform(|transform_input_port, transform_output_port| {
+            TransformReturning::try_create(
+                ctx.clone(),
+                transform_input_port,
+                transform_output_port,
+                &schema,
+                returning.clone(),
+            )
+        })
+    }
 }
@@ -30,6 +30,7 @@ impl PipelineBuilder {
             &mut self.main_pipeline,
             to_table,
             copy.input_schema.clone(),
+            vec![],
             None,
             vec![],
             false,
@@ -29,6 +29,7 @@ use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::plans::CopyIntoTableMode;
 use databend_common_storage::StageFileInfo;
 use log::debug;
@@ -37,6 +38,7 @@ use log::info;
 use crate::pipelines::processors::transforms::TransformAddConstColumns;
 use crate::pipelines::processors::TransformCastSchema;
 use crate::pipelines::PipelineBuilder;
+use crate::servers::flight::v1::exchange::RemotePartitions;
 use crate::sessions::QueryContext;
 
 /// This file implements copy into table pipeline builder.
@@ -54,6 +56,17 @@ impl PipelineBuilder {
                 self.ctx
                     .set_read_block_thresholds(to_table.get_block_thresholds());
 
+                if let (Some(pull_files), PhysicalPlan::TableScan(scan)) =
+                    (&copy.pull_files, input.as_ref())
+                {
+                    self.ctx.set_remote_partitions(RemotePartitions::try_create(
+                        &self.ctx,
+                        scan.plan_id,
+                        &pull_files.coordinator,
+                        pull_files.batch_size,
+                    )?);
+                }
+
                 self.build_pipeline(input)?;
                 copy.required_source_schema.clone()
             }
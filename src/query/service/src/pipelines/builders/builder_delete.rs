@@ -104,6 +104,7 @@ impl PipelineBuilder {
             &delete.filters.filter,
             delete.col_indices.clone(),
             delete.query_row_id_col,
+            delete.returning.clone(),
             &mut self.main_pipeline,
         )?;
 
@@ -64,6 +64,12 @@ impl PipelineBuilder {
             table.clone(),
             source_schema.clone(),
         )?;
+        Self::build_returning(
+            self.ctx.clone(),
+            &mut self.main_pipeline,
+            table.as_ref(),
+            insert_select.returning.clone(),
+        )?;
 
         table.append_data(
             self.ctx.clone(),
@@ -109,6 +109,7 @@ impl PipelineBuilder {
             segments,
             block_slots,
             need_insert,
+            error_on_conflict,
             ..
         } = replace;
         let max_threads = self.settings.get_max_threads()?;
@@ -161,6 +162,7 @@ impl PipelineBuilder {
                 segments,
                 block_slots.clone(),
                 io_request_semaphore,
+                *error_on_conflict,
             )?;
             self.main_pipeline.add_pipe(Pipe::create(
                 segment_partition_num,
@@ -244,6 +246,7 @@ impl PipelineBuilder {
                 segments,
                 block_slots.clone(),
                 io_request_semaphore,
+                *error_on_conflict,
             )?;
             assert_eq!(
                 segment_partition_num,
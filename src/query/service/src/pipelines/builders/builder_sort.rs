@@ -18,7 +18,6 @@ use databend_common_exception::Result;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::sort::utils::add_order_field;
 use databend_common_pipeline_transforms::processors::try_add_multi_sort_merge;
@@ -283,8 +282,7 @@ impl SortPipelineBuilder {
 
         if may_spill {
             let schema = add_order_field(sort_merge_output_schema.clone(), &self.sort_desc);
-            let config =
-                SpillerConfig::create(query_spill_prefix(self.ctx.get_tenant().tenant_name()));
+            let config = SpillerConfig::create(self.ctx.node_spill_prefix());
             pipeline.add_transform(|input, output| {
                 let op = DataOperator::instance().operator();
                 let spiller =
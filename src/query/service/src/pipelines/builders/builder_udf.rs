@@ -12,10 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[cfg(not(feature = "ml-inference"))]
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::Udf;
+use databend_common_sql::plans::UDFKind;
 
+#[cfg(feature = "ml-inference")]
+use crate::pipelines::processors::transforms::ModelSessionCache;
+#[cfg(feature = "ml-inference")]
+use crate::pipelines::processors::transforms::TransformModelInference;
 use crate::pipelines::processors::transforms::TransformUdfScript;
 use crate::pipelines::processors::transforms::TransformUdfServer;
 use crate::pipelines::PipelineBuilder;
@@ -24,24 +31,40 @@ impl PipelineBuilder {
     pub(crate) fn build_udf(&mut self, udf: &Udf) -> Result<()> {
         self.build_pipeline(&udf.input)?;
 
-        if udf.script_udf {
-            self.main_pipeline.add_transform(|input, output| {
+        match udf.kind {
+            UDFKind::Script => self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformUdfScript::try_create(
                     self.func_ctx.clone(),
                     udf.udf_funcs.clone(),
                     input,
                     output,
                 )?))
-            })
-        } else {
-            self.main_pipeline.add_transform(|input, output| {
+            }),
+            UDFKind::Server => self.main_pipeline.add_transform(|input, output| {
                 Ok(ProcessorPtr::create(TransformUdfServer::try_create(
                     self.func_ctx.clone(),
                     udf.udf_funcs.clone(),
                     input,
                     output,
                 )?))
-            })
+            }),
+            #[cfg(feature = "ml-inference")]
+            UDFKind::Model => {
+                let cache = ModelSessionCache::create();
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(TransformModelInference::try_create(
+                        self.ctx.clone(),
+                        udf.udf_funcs.clone(),
+                        cache.clone(),
+                        input,
+                        output,
+                    )?))
+                })
+            }
+            #[cfg(not(feature = "ml-inference"))]
+            UDFKind::Model => Err(ErrorCode::Unimplemented(
+                "ml_predict is not supported, databend-query is built without the `ml-inference` feature",
+            )),
         }
     }
 }
@@ -44,6 +44,7 @@ impl PipelineBuilder {
             update.update_list.clone(),
             update.computed_list.clone(),
             update.query_row_id_col,
+            update.returning.clone(),
             &mut self.main_pipeline,
         )?;
 
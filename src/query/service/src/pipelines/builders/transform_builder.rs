@@ -67,6 +67,7 @@ impl PipelineBuilder {
         assert_eq!(predicate.data_type(), &DataType::Boolean);
 
         let max_block_size = self.settings.get_max_block_size()? as usize;
+        let max_block_size_bytes = self.settings.get_max_block_size_bytes()?;
         let (select_expr, has_or) = SelectExprBuilder::new().build(&predicate).into();
         let fun_ctx = self.func_ctx.clone();
         Ok(move |input, output| {
@@ -78,6 +79,7 @@ impl PipelineBuilder {
                 projections.clone(),
                 fun_ctx.clone(),
                 max_block_size,
+                max_block_size_bytes,
             )))
         })
     }
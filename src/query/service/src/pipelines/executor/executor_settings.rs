@@ -15,6 +15,8 @@
 use std::sync::Arc;
 use std::time::Duration;
 
+use databend_common_base::runtime::IoPriority;
+use databend_common_base::runtime::QueryPriority;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 
@@ -25,6 +27,8 @@ pub struct ExecutorSettings {
     pub enable_queries_executor: bool,
     pub max_execute_time_in_seconds: Duration,
     pub executor_node_id: String,
+    pub io_priority: IoPriority,
+    pub priority: QueryPriority,
 }
 
 impl ExecutorSettings {
@@ -33,6 +37,7 @@ impl ExecutorSettings {
         let settings = ctx.get_settings();
         let max_threads = settings.get_max_threads()?;
         let max_execute_time_in_seconds = settings.get_max_execute_time_in_seconds()?;
+        let priority = settings.get_query_priority()?;
 
         Ok(ExecutorSettings {
             enable_queries_executor: settings.get_enable_experimental_queries_executor()?,
@@ -40,6 +45,8 @@ impl ExecutorSettings {
             max_execute_time_in_seconds: Duration::from_secs(max_execute_time_in_seconds),
             max_threads,
             executor_node_id: ctx.get_cluster().local_id.clone(),
+            io_priority: priority.io_priority(settings.get_io_priority()?),
+            priority,
         })
     }
 }
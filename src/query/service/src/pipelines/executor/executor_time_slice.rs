@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::runtime::QueryPriority;
+
+/// The time a worker thread runs the tasks of a query before it checks whether to yield.
+const TIME_SLICE: Duration = Duration::from_millis(10);
+
+/// The number of the running executors of each priority on the node.
+static RUNNING_EXECUTORS: [AtomicUsize; 3] = [
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+    AtomicUsize::new(0),
+];
+
+fn running_executors(priority: QueryPriority) -> usize {
+    RUNNING_EXECUTORS[priority as usize].load(Ordering::Relaxed)
+}
+
+/// Registers a running executor of the priority, the workers of the executors of lower
+/// priority yield their time slices until it's dropped.
+pub struct PriorityGuard {
+    priority: QueryPriority,
+}
+
+impl PriorityGuard {
+    pub fn create(priority: QueryPriority) -> PriorityGuard {
+        RUNNING_EXECUTORS[priority as usize].fetch_add(1, Ordering::Relaxed);
+        PriorityGuard { priority }
+    }
+}
+
+impl Drop for PriorityGuard {
+    fn drop(&mut self) {
+        RUNNING_EXECUTORS[self.priority as usize].fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// The time slice of a worker thread of the executor.
+///
+/// Once the time slice is used up, the worker sleeps if there are queries of higher
+/// priority running on the node, so that they get more CPU time on the shared cores.
+/// The queries of normal priority yield one time slice to the queries of high priority,
+/// and the queries of low priority yield three time slices to them, or one time slice
+/// to the queries of normal priority.
+pub struct TimeSlice {
+    priority: QueryPriority,
+    start: Instant,
+}
+
+impl TimeSlice {
+    pub fn create(priority: QueryPriority) -> TimeSlice {
+        TimeSlice {
+            priority,
+            start: Instant::now(),
+        }
+    }
+
+    /// Called by the worker after a task is executed.
+    pub fn yield_if_needed(&mut self) {
+        if self.priority == QueryPriority::High || self.start.elapsed() < TIME_SLICE {
+            return;
+        }
+
+        let yield_slices = match self.priority {
+            QueryPriority::Low if running_executors(QueryPriority::High) != 0 => 3,
+            QueryPriority::Low if running_executors(QueryPriority::Normal) != 0 => 1,
+            QueryPriority::Normal if running_executors(QueryPriority::High) != 0 => 1,
+            _ => 0,
+        };
+
+        if yield_slices != 0 {
+            std::thread::sleep(TIME_SLICE * yield_slices);
+        }
+        self.start = Instant::now();
+    }
+}
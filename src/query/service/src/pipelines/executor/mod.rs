@@ -17,6 +17,7 @@ mod query_pipeline_executor;
 mod executor_condvar;
 mod executor_graph;
 mod executor_settings;
+mod executor_time_slice;
 mod executor_worker_context;
 mod global_queries_executor;
 mod pipeline_complete_executor;
@@ -33,6 +34,8 @@ pub use executor_condvar::WorkersCondvar;
 pub use executor_condvar::WorkersWaitingStatus;
 pub use executor_graph::RunningGraph;
 pub use executor_settings::ExecutorSettings;
+pub use executor_time_slice::PriorityGuard;
+pub use executor_time_slice::TimeSlice;
 pub use executor_worker_context::CompletedAsyncTask;
 pub use executor_worker_context::ExecutorTask;
 pub use executor_worker_context::ExecutorWorkerContext;
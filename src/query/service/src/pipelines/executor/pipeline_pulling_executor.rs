@@ -101,12 +101,14 @@ pub struct PipelinePullingExecutor {
 }
 
 impl PipelinePullingExecutor {
-    fn execution_tracking_payload(query_id: &str) -> TrackingPayload {
+    fn execution_tracking_payload(settings: &ExecutorSettings) -> TrackingPayload {
         let mut tracking_payload = ThreadTracker::new_tracking_payload();
         tracking_payload.mem_stat = Some(MemStat::create(format!(
             "QueryExecutionMemStat-{}",
-            query_id
+            settings.query_id
         )));
+        tracking_payload.io_priority = settings.io_priority;
+        tracking_payload.query_id = Some(settings.query_id.clone());
         tracking_payload
     }
 
@@ -141,7 +143,7 @@ impl PipelinePullingExecutor {
         mut pipeline: Pipeline,
         settings: ExecutorSettings,
     ) -> Result<PipelinePullingExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         let (sender, receiver) = std::sync::mpsc::sync_channel(pipeline.output_len());
@@ -165,7 +167,7 @@ impl PipelinePullingExecutor {
         build_res: PipelineBuildResult,
         settings: ExecutorSettings,
     ) -> Result<PipelinePullingExecutor> {
-        let tracking_payload = Self::execution_tracking_payload(settings.query_id.as_ref());
+        let tracking_payload = Self::execution_tracking_payload(&settings);
         let _guard = ThreadTracker::tracking(tracking_payload.clone());
 
         let mut main_pipeline = build_res.main_pipeline;
@@ -47,8 +47,10 @@ use petgraph::matrix_graph::Zero;
 use crate::pipelines::executor::executor_graph::ScheduleQueue;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::ExecutorWorkerContext;
+use crate::pipelines::executor::PriorityGuard;
 use crate::pipelines::executor::QueryExecutorTasksQueue;
 use crate::pipelines::executor::RunningGraph;
+use crate::pipelines::executor::TimeSlice;
 use crate::pipelines::executor::WatchNotify;
 use crate::pipelines::executor::WorkersCondvar;
 
@@ -70,6 +72,8 @@ pub struct QueryPipelineExecutor {
     finished_error: Mutex<Option<ErrorCode>>,
     #[allow(unused)]
     lock_guards: Vec<LockGuard>,
+    #[allow(unused)]
+    priority_guard: PriorityGuard,
 }
 
 impl QueryPipelineExecutor {
@@ -188,6 +192,7 @@ impl QueryPipelineExecutor {
     ) -> Result<Arc<QueryPipelineExecutor>> {
         let workers_condvar = WorkersCondvar::create(threads_num);
         let global_tasks_queue = QueryExecutorTasksQueue::create(threads_num);
+        let priority_guard = PriorityGuard::create(settings.priority);
 
         Ok(Arc::new(QueryPipelineExecutor {
             graph,
@@ -201,6 +206,7 @@ impl QueryPipelineExecutor {
             finished_error: Mutex::new(None),
             finished_notify: Arc::new(WatchNotify::new()),
             lock_guards,
+            priority_guard,
         }))
     }
 
@@ -422,6 +428,7 @@ impl QueryPipelineExecutor {
     pub unsafe fn execute_single_thread(self: &Arc<Self>, thread_num: usize) -> Result<()> {
         let workers_condvar = self.workers_condvar.clone();
         let mut context = ExecutorWorkerContext::create(thread_num, workers_condvar);
+        let mut time_slice = TimeSlice::create(self.settings.priority);
 
         while !self.global_tasks_queue.is_finished() {
             // When there are not enough tasks, the thread will be blocked, so we need loop check.
@@ -438,6 +445,8 @@ impl QueryPipelineExecutor {
                         schedule_queue.schedule(&self.global_tasks_queue, &mut context, self);
                     }
                 }
+
+                time_slice.yield_if_needed();
             }
         }
 
@@ -16,7 +16,6 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 use bumpalo::Bump;
-use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoDowncast;
@@ -29,7 +28,6 @@ use databend_common_hashtable::HashtableEntryMutRefLike;
 use databend_common_hashtable::HashtableEntryRefLike;
 use databend_common_hashtable::HashtableLike;
 use databend_common_pipeline_core::processors::ProcessorPtr;
-use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_settings::FlightCompression;
 use databend_common_storage::DataOperator;
@@ -310,7 +308,6 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> FlightScatter
 pub struct AggregateInjector<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> {
     ctx: Arc<QueryContext>,
     method: Method,
-    tenant: String,
     aggregator_params: Arc<AggregatorParams>,
     _phantom: PhantomData<V>,
 }
@@ -321,11 +318,9 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> AggregateInjecto
         method: Method,
         params: Arc<AggregatorParams>,
     ) -> Arc<dyn ExchangeInjector> {
-        let tenant = ctx.get_tenant();
         Arc::new(AggregateInjector::<Method, V> {
             ctx,
             method,
-            tenant: tenant.tenant_name().to_string(),
             aggregator_params: params,
             _phantom: Default::default(),
         })
@@ -369,7 +364,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let params = self.aggregator_params.clone();
 
         let operator = DataOperator::instance().operator();
-        let location_prefix = query_spill_prefix(&self.tenant);
+        let location_prefix = self.ctx.node_spill_prefix();
 
         pipeline.add_transform(|input, output| {
             Ok(ProcessorPtr::create(
@@ -417,7 +412,7 @@ impl<Method: HashMethodBounds, V: Copy + Send + Sync + 'static> ExchangeInjector
         let method = &self.method;
         let params = self.aggregator_params.clone();
         let operator = DataOperator::instance().operator();
-        let location_prefix = query_spill_prefix(&self.tenant);
+        let location_prefix = self.ctx.node_spill_prefix();
 
         let schema = shuffle_params.schema.clone();
         let local_id = &shuffle_params.executor_id;
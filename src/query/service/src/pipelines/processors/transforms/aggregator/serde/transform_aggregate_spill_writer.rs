@@ -209,6 +209,7 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
     partitioned_payload: PartitionedPayload,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
+    let cipher = ctx.get_spill_cipher();
     let location = format!("{}/{}", location_prefix, unique_name);
 
     let mut write_size = 0;
@@ -232,12 +233,13 @@ pub fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
-        write_data.push(columns_data);
+        let data = cipher.encrypt(&columns_data.concat())?;
+        write_size += data.len() as u64;
+        write_data.push(vec![data]);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
             location: location.clone(),
@@ -306,6 +308,7 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
     mut payload: HashTablePayload<PartitionedHashMethod<Method>, usize>,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
+    let cipher = ctx.get_spill_cipher();
     let location = format!("{}/{}", location_prefix, unique_name);
 
     let mut write_size = 0;
@@ -329,12 +332,13 @@ pub fn spilling_aggregate_payload<Method: HashMethodBounds>(
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
-        write_data.push(columns_data);
+        let data = cipher.encrypt(&columns_data.concat())?;
+        write_size += data.len() as u64;
+        write_data.push(vec![data]);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
             location: location.clone(),
@@ -224,6 +224,7 @@ where
             DataPacket::CopyStatus { .. } => unreachable!(),
             DataPacket::MergeStatus { .. } => unreachable!(),
             DataPacket::DataCacheMetrics(_) => unreachable!(),
+            DataPacket::BlockData(_) => unreachable!(),
             DataPacket::FragmentData(v) => self.recv_data(meta.packet, v),
         }
     }
@@ -251,6 +251,7 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
     partitioned_payload: PartitionedPayload,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
+    let cipher = ctx.get_spill_cipher();
     let location = format!("{}/{}", location_prefix, unique_name);
 
     let partition_count = partitioned_payload.partition_count();
@@ -279,12 +280,13 @@ fn agg_spilling_aggregate_payload<Method: HashMethodBounds>(
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
-        write_data.push(columns_data);
+        let data = cipher.encrypt(&columns_data.concat())?;
+        write_size += data.len() as u64;
+        write_data.push(vec![data]);
         buckets_column_data.push(bucket as i64);
         data_range_end_column_data.push(write_size);
         columns_layout_column_data.push(columns_layout);
@@ -373,6 +375,7 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
     mut payload: HashTablePayload<PartitionedHashMethod<Method>, usize>,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
+    let cipher = ctx.get_spill_cipher();
     let location = format!("{}/{}", location_prefix, unique_name);
 
     let mut write_size = 0;
@@ -400,12 +403,13 @@ fn spilling_aggregate_payload<Method: HashMethodBounds>(
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
-        write_data.push(columns_data);
+        let data = cipher.encrypt(&columns_data.concat())?;
+        write_size += data.len() as u64;
+        write_data.push(vec![data]);
         buckets_column_data.push(bucket as i64);
         data_range_end_column_data.push(write_size);
         columns_layout_column_data.push(columns_layout);
@@ -206,6 +206,7 @@ pub fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
     partitioned_payload: PartitionedPayload,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
+    let cipher = ctx.get_spill_cipher();
     let location = format!("{}/{}", location_prefix, unique_name);
 
     let mut write_size = 0;
@@ -229,12 +230,13 @@ pub fn agg_spilling_group_by_payload<Method: HashMethodBounds>(
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
-        write_data.push(columns_data);
+        let data = cipher.encrypt(&columns_data.concat())?;
+        write_size += data.len() as u64;
+        write_data.push(vec![data]);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
             location: location.clone(),
@@ -301,6 +303,7 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
     mut payload: HashTablePayload<PartitionedHashMethod<Method>, ()>,
 ) -> Result<BoxFuture<'static, Result<DataBlock>>> {
     let unique_name = GlobalUniqName::unique();
+    let cipher = ctx.get_spill_cipher();
     let location = format!("{}/{}", location_prefix, unique_name);
 
     let mut write_size = 0;
@@ -322,12 +325,13 @@ pub fn spilling_group_by_payload<Method: HashMethodBounds>(
         for column in columns.into_iter() {
             let column = column.value.as_column().unwrap();
             let column_data = serialize_column(column);
-            write_size += column_data.len() as u64;
             columns_layout.push(column_data.len() as u64);
             columns_data.push(column_data);
         }
 
-        write_data.push(columns_data);
+        let data = cipher.encrypt(&columns_data.concat())?;
+        write_size += data.len() as u64;
+        write_data.push(vec![data]);
         spilled_buckets_payloads.push(BucketSpilledPayload {
             bucket: bucket as isize,
             location: location.clone(),
@@ -38,6 +38,7 @@ use crate::pipelines::processors::transforms::aggregator::AggregateMeta;
 use crate::pipelines::processors::transforms::aggregator::BucketSpilledPayload;
 use crate::pipelines::processors::transforms::aggregator::SerializedPayload;
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
+use crate::spillers::SpillCipher;
 
 type DeserializingMeta<Method, V> = (AggregateMeta<Method, V>, VecDeque<Vec<u8>>);
 
@@ -46,6 +47,7 @@ pub struct TransformSpillReader<Method: HashMethodBounds, V: Send + Sync + 'stat
     output: Arc<OutputPort>,
 
     operator: Operator,
+    cipher: SpillCipher,
     deserialized_meta: Option<BlockMetaInfoPtr>,
     reading_meta: Option<AggregateMeta<Method, V>>,
     deserializing_meta: Option<DeserializingMeta<Method, V>>,
@@ -188,6 +190,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                         .read_with(&payload.location)
                         .range(payload.data_range.clone())
                         .await?;
+                    let data = self.cipher.decrypt(&data)?;
 
                     info!(
                         "Read aggregate spill {} successfully, elapsed: {:?}",
@@ -203,10 +206,12 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                         if let AggregateMeta::BucketSpilled(payload) = meta {
                             let location = payload.location.clone();
                             let operator = self.operator.clone();
+                            let cipher = self.cipher.clone();
                             let data_range = payload.data_range.clone();
                             read_data.push(databend_common_base::runtime::spawn(async move {
                                 let instant = Instant::now();
                                 let data = operator.read_with(&location).range(data_range).await?;
+                                let data = cipher.decrypt(&data)?;
 
                                 // perf
                                 {
@@ -230,7 +235,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                                     instant.elapsed()
                                 );
 
-                                Ok(data)
+                                Ok::<_, ErrorCode>(data)
                             }));
                         }
                     }
@@ -240,7 +245,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> Processor
                             return Err(ErrorCode::TokioError("Cannot join tokio job"));
                         }
                         Ok(read_data) => {
-                            let read_data: Result<VecDeque<Vec<u8>>, opendal::Error> =
+                            let read_data: Result<VecDeque<Vec<u8>>> =
                                 read_data.into_iter().try_collect();
 
                             self.deserializing_meta = Some((block_meta, read_data?));
@@ -259,6 +264,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         operator: Operator,
+        cipher: SpillCipher,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(TransformSpillReader::<
             Method,
@@ -267,6 +273,7 @@ impl<Method: HashMethodBounds, V: Send + Sync + 'static> TransformSpillReader<Me
             input,
             output,
             operator,
+            cipher,
             deserialized_meta: None,
             reading_meta: None,
             deserializing_meta: None,
@@ -26,8 +26,10 @@ use databend_common_exception::Result;
 use databend_common_expression::AggregateHashTable;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
 use databend_common_expression::HashTableConfig;
+use databend_common_expression::KeysState;
 use databend_common_expression::PayloadFlushState;
 use databend_common_expression::ProbeState;
 use databend_common_functions::aggregates::StateAddr;
@@ -39,6 +41,7 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_sql::executor::physical_plans::GroupKeyRange;
 use log::info;
 
 use crate::pipelines::processors::transforms::aggregator::aggregate_cell::AggregateHashTableDropper;
@@ -104,11 +107,60 @@ impl TryFrom<Arc<QueryContext>> for AggregateSettings {
     }
 }
 
+// The states of the group keys within the range estimated from statistics, addressed by
+// the distance of the key from the min key. They are also inserted into the hash table,
+// so the output is the same as without them.
+struct DirectStates {
+    base: KeysState,
+    places: Vec<Option<StateAddr>>,
+}
+
+impl DirectStates {
+    fn try_create<Method: HashMethodBounds>(
+        method: &Method,
+        params: &AggregatorParams,
+        range: &GroupKeyRange,
+    ) -> Result<Option<Self>> {
+        let data_type = &params.group_data_types[0];
+        let column = ColumnBuilder::repeat(&range.min.as_ref(), 1, data_type).build();
+        let base = method.build_keys_state(&[(column, data_type.clone())], 1)?;
+
+        // The keys of the method can't address the states if they have no offset.
+        let key = method.build_keys_iter(&base)?.next().unwrap();
+        if method.key_offset(key, key).is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(DirectStates {
+            base,
+            places: vec![None; range.len],
+        }))
+    }
+
+    // Returns the offsets of the keys, None if any of them is out of the range.
+    fn offsets<Method: HashMethodBounds>(
+        &self,
+        method: &Method,
+        state: &KeysState,
+    ) -> Result<Option<Vec<usize>>> {
+        let base = method.build_keys_iter(&self.base)?.next().unwrap();
+        Ok(method
+            .build_keys_iter(state)?
+            .map(|key| {
+                method
+                    .key_offset(key, base)
+                    .filter(|offset| *offset < self.places.len())
+            })
+            .collect())
+    }
+}
+
 // SELECT column_name, agg(xxx) FROM table_name GROUP BY column_name
 pub struct TransformPartialAggregate<Method: HashMethodBounds> {
     method: Method,
     settings: AggregateSettings,
     hash_table: HashTable<Method>,
+    direct_states: Option<DirectStates>,
     probe_state: ProbeState,
     params: Arc<AggregatorParams>,
 }
@@ -121,6 +173,7 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
         output: Arc<OutputPort>,
         params: Arc<AggregatorParams>,
         config: HashTableConfig,
+        group_key_range: Option<GroupKeyRange>,
     ) -> Result<Box<dyn Processor>> {
         let hash_table = if !params.enable_experimental_aggregate_hashtable {
             let arena = Arc::new(Bump::new());
@@ -155,6 +208,13 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
             }
         };
 
+        let direct_states = match (&hash_table, &group_key_range) {
+            (HashTable::HashTable(_), Some(range)) => {
+                DirectStates::try_create(&method, &params, range)?
+            }
+            _ => None,
+        };
+
         Ok(AccumulatingTransformer::create(
             input,
             output,
@@ -162,6 +222,7 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                 method,
                 params,
                 hash_table,
+                direct_states,
                 probe_state: ProbeState::default(),
                 settings: AggregateSettings::try_from(ctx)?,
             },
@@ -267,15 +328,48 @@ impl<Method: HashMethodBounds> TransformPartialAggregate<Method> {
                     let state = self.method.build_keys_state(&group_columns, rows_num)?;
                     let mut places = Vec::with_capacity(rows_num);
 
-                    for key in self.method.build_keys_iter(&state)? {
-                        places.push(match hashtable.hashtable.insert_and_entry(key) {
-                            Err(entry) => Into::<StateAddr>::into(*entry.get()),
-                            Ok(mut entry) => {
-                                let place = self.params.alloc_layout(&mut hashtable.arena);
-                                *entry.get_mut() = place.addr();
-                                place
+                    let offsets = match &self.direct_states {
+                        None => None,
+                        Some(direct_states) => direct_states.offsets(&self.method, &state)?,
+                    };
+
+                    match (&mut self.direct_states, offsets) {
+                        (Some(direct_states), Some(offsets)) => {
+                            let keys_iter = self.method.build_keys_iter(&state)?;
+                            for (key, offset) in keys_iter.zip(offsets) {
+                                if let Some(place) = direct_states.places[offset] {
+                                    places.push(place);
+                                    continue;
+                                }
+
+                                let place = match hashtable.hashtable.insert_and_entry(key) {
+                                    Err(entry) => Into::<StateAddr>::into(*entry.get()),
+                                    Ok(mut entry) => {
+                                        let place = self.params.alloc_layout(&mut hashtable.arena);
+                                        *entry.get_mut() = place.addr();
+                                        place
+                                    }
+                                };
+                                direct_states.places[offset] = Some(place);
+                                places.push(place);
                             }
-                        })
+                        }
+                        (direct_states, _) => {
+                            if direct_states.take().is_some() {
+                                info!("Group key is out of the estimated range, fall back to the hash table.");
+                            }
+
+                            for key in self.method.build_keys_iter(&state)? {
+                                places.push(match hashtable.hashtable.insert_and_entry(key) {
+                                    Err(entry) => Into::<StateAddr>::into(*entry.get()),
+                                    Ok(mut entry) => {
+                                        let place = self.params.alloc_layout(&mut hashtable.arena);
+                                        *entry.get_mut() = place.addr();
+                                        place
+                                    }
+                                })
+                            }
+                        }
                     }
 
                     if is_agg_index_block {
@@ -361,6 +455,7 @@ impl<Method: HashMethodBounds> AccumulatingTransform for TransformPartialAggrega
                         GLOBAL_MEM_STAT.get_memory_usage() as usize >= self.settings.max_memory_usage))
             {
                 if let HashTable::HashTable(cell) = std::mem::take(&mut self.hash_table) {
+                    self.direct_states = None;
                     self.hash_table = HashTable::PartitionedHashTable(
                         PartitionedHashMethod::convert_hashtable(&self.method, cell)?,
                     );
@@ -48,6 +48,7 @@ use crate::pipelines::processors::transforms::aggregator::TransformGroupBySpillR
 use crate::pipelines::processors::transforms::group_by::HashMethodBounds;
 use crate::pipelines::processors::transforms::group_by::KeysColumnIter;
 use crate::pipelines::processors::transforms::group_by::PartitionedHashMethod;
+use crate::spillers::SpillCipher;
 
 static SINGLE_LEVEL_BUCKET_NUM: isize = -1;
 
@@ -442,6 +443,7 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
     method: Method,
     pipeline: &mut Pipeline,
     params: Arc<AggregatorParams>,
+    cipher: SpillCipher,
 ) -> Result<()> {
     if params.enable_experimental_aggregate_hashtable {
         let input_nums = pipeline.output_len();
@@ -462,9 +464,14 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
         let operator = DataOperator::instance().operator();
         pipeline.add_transform(|input, output| {
             let operator = operator.clone();
+            let cipher = cipher.clone();
             match params.aggregate_functions.is_empty() {
-                true => TransformGroupBySpillReader::<Method>::create(input, output, operator),
-                false => TransformAggregateSpillReader::<Method>::create(input, output, operator),
+                true => {
+                    TransformGroupBySpillReader::<Method>::create(input, output, operator, cipher)
+                }
+                false => {
+                    TransformAggregateSpillReader::<Method>::create(input, output, operator, cipher)
+                }
             }
         })?;
 
@@ -504,9 +511,14 @@ pub fn build_partition_bucket<Method: HashMethodBounds, V: Copy + Send + Sync +
         let operator = DataOperator::instance().operator();
         pipeline.add_transform(|input, output| {
             let operator = operator.clone();
+            let cipher = cipher.clone();
             match params.aggregate_functions.is_empty() {
-                true => TransformGroupBySpillReader::<Method>::create(input, output, operator),
-                false => TransformAggregateSpillReader::<Method>::create(input, output, operator),
+                true => {
+                    TransformGroupBySpillReader::<Method>::create(input, output, operator, cipher)
+                }
+                false => {
+                    TransformAggregateSpillReader::<Method>::create(input, output, operator, cipher)
+                }
             }
         })?;
 
@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bumpalo::Bump;
+use databend_common_catalog::plan::AggIndexMeta;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_functions::aggregates::StateAddr;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_transforms::processors::AccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+
+use crate::pipelines::processors::transforms::aggregator::AggregatorParams;
+
+/// SELECT column_name, agg(xxx) FROM (SELECT ... ORDER BY column_name) GROUP BY column_name
+///
+/// The input is sorted on the group keys, so a group is finished once the keys change,
+/// only the states of the current group are kept.
+pub struct TransformStreamingAggregate {
+    #[allow(dead_code)]
+    arena: Bump,
+    params: Arc<AggregatorParams>,
+    places: Vec<StateAddr>,
+    // The keys of the current group, None before the first row.
+    current_keys: Option<Vec<Scalar>>,
+    group_builders: Vec<ColumnBuilder>,
+    aggr_builders: Vec<ColumnBuilder>,
+    finished_groups: usize,
+}
+
+impl TransformStreamingAggregate {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        params: Arc<AggregatorParams>,
+    ) -> Result<Box<dyn Processor>> {
+        let arena = Bump::new();
+        let mut places = Vec::with_capacity(params.aggregate_functions.len());
+        if let Some(layout) = params.layout {
+            let place: StateAddr = arena.alloc_layout(layout).into();
+            for (idx, func) in params.aggregate_functions.iter().enumerate() {
+                let arg_place = place.next(params.offsets_aggregate_states[idx]);
+                func.init_state(arg_place);
+                places.push(arg_place);
+            }
+        }
+
+        let group_builders = Self::group_builders(&params);
+        let aggr_builders = Self::aggr_builders(&params)?;
+
+        Ok(AccumulatingTransformer::create(
+            input,
+            output,
+            TransformStreamingAggregate {
+                arena,
+                params,
+                places,
+                current_keys: None,
+                group_builders,
+                aggr_builders,
+                finished_groups: 0,
+            },
+        ))
+    }
+
+    fn group_builders(params: &AggregatorParams) -> Vec<ColumnBuilder> {
+        params
+            .group_data_types
+            .iter()
+            .map(|data_type| ColumnBuilder::with_capacity(data_type, params.max_block_size))
+            .collect()
+    }
+
+    fn aggr_builders(params: &AggregatorParams) -> Result<Vec<ColumnBuilder>> {
+        params
+            .aggregate_functions
+            .iter()
+            .map(|func| {
+                Ok(ColumnBuilder::with_capacity(
+                    &func.return_type()?,
+                    params.max_block_size,
+                ))
+            })
+            .collect()
+    }
+
+    // Accumulates the rows in `start..end` of the block into the states of the current group.
+    fn accumulate(
+        &mut self,
+        block: &DataBlock,
+        is_agg_index_block: bool,
+        start: usize,
+        end: usize,
+    ) -> Result<()> {
+        let funcs = &self.params.aggregate_functions;
+        for (idx, func) in funcs.iter().enumerate() {
+            let place = self.places[idx];
+            if is_agg_index_block {
+                // Aggregation states are in the back of the block.
+                let agg_index = block.num_columns() - funcs.len() + idx;
+                let agg_state = block.get_by_offset(agg_index).value.as_column().unwrap();
+                func.batch_merge_single(place, &agg_state.slice(start..end))?;
+            } else {
+                let arg_columns = self.params.aggregate_functions_arguments[idx]
+                    .iter()
+                    .map(|index| {
+                        let column = block.get_by_offset(*index).value.as_column().unwrap();
+                        column.slice(start..end)
+                    })
+                    .collect::<Vec<_>>();
+                func.accumulate(place, &arg_columns, None, end - start)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Outputs the result of the current group and resets the states for the next one.
+    fn finish_group(&mut self) -> Result<()> {
+        let Some(keys) = self.current_keys.take() else {
+            return Ok(());
+        };
+
+        for (builder, key) in self.group_builders.iter_mut().zip(keys.iter()) {
+            builder.push(key.as_ref());
+        }
+
+        for (idx, func) in self.params.aggregate_functions.iter().enumerate() {
+            let place = self.places[idx];
+            func.merge_result(place, &mut self.aggr_builders[idx])?;
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(place) }
+            }
+            func.init_state(place);
+        }
+
+        self.finished_groups += 1;
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<Vec<DataBlock>> {
+        if self.finished_groups == 0 {
+            return Ok(vec![]);
+        }
+
+        let aggr_builders =
+            std::mem::replace(&mut self.aggr_builders, Self::aggr_builders(&self.params)?);
+        let group_builders =
+            std::mem::replace(&mut self.group_builders, Self::group_builders(&self.params));
+        self.finished_groups = 0;
+
+        let columns = aggr_builders
+            .into_iter()
+            .chain(group_builders)
+            .map(|builder| builder.build())
+            .collect::<Vec<Column>>();
+        Ok(vec![DataBlock::new_from_columns(columns)])
+    }
+}
+
+impl AccumulatingTransform for TransformStreamingAggregate {
+    const NAME: &'static str = "TransformStreamingAggregate";
+
+    fn transform(&mut self, block: DataBlock) -> Result<Vec<DataBlock>> {
+        let is_agg_index_block = block
+            .get_meta()
+            .and_then(AggIndexMeta::downcast_ref_from)
+            .map(|index| index.is_agg)
+            .unwrap_or_default();
+
+        let block = block.convert_to_full();
+        let group_columns = self
+            .params
+            .group_columns
+            .iter()
+            .map(|index| block.get_by_offset(*index).value.as_column().unwrap())
+            .collect::<Vec<_>>();
+
+        let mut start = 0;
+        for row in 0..block.num_rows() {
+            let same_group = match &self.current_keys {
+                None => false,
+                Some(keys) => group_columns
+                    .iter()
+                    .zip(keys.iter())
+                    .all(|(column, key)| unsafe { column.index_unchecked(row) } == key.as_ref()),
+            };
+
+            if !same_group {
+                if row > start {
+                    self.accumulate(&block, is_agg_index_block, start, row)?;
+                }
+                self.finish_group()?;
+                self.current_keys = Some(
+                    group_columns
+                        .iter()
+                        .map(|column| unsafe { column.index_unchecked(row) }.to_owned())
+                        .collect(),
+                );
+                start = row;
+            }
+        }
+
+        if block.num_rows() > start {
+            self.accumulate(&block, is_agg_index_block, start, block.num_rows())?;
+        }
+
+        match self.finished_groups >= self.params.max_block_size {
+            true => self.flush(),
+            false => Ok(vec![]),
+        }
+    }
+
+    fn on_finish(&mut self, output: bool) -> Result<Vec<DataBlock>> {
+        let blocks = match output {
+            true => {
+                self.finish_group()?;
+                self.flush()?
+            }
+            false => vec![],
+        };
+
+        // destroy states
+        for (place, func) in self
+            .places
+            .iter()
+            .zip(self.params.aggregate_functions.iter())
+        {
+            if func.need_manual_drop_state() {
+                unsafe { func.drop_state(*place) }
+            }
+        }
+
+        Ok(blocks)
+    }
+}
@@ -142,6 +142,11 @@ pub trait PolymorphicKeysHelper<Method: HashMethod>: Send + Sync + 'static {
     ) -> Self::GroupColumnsBuilder<'_>;
 
     fn get_hash(&self, v: &Method::HashKey) -> u64;
+
+    // The distance of the key from `base`, None if the keys can't address the states directly.
+    fn key_offset(&self, _key: &Method::HashKey, _base: &Method::HashKey) -> Option<usize> {
+        None
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodFixedKeys<u8>> for HashMethodFixedKeys<u8> {
@@ -262,6 +267,10 @@ impl PolymorphicKeysHelper<HashMethodFixedKeys<u32>> for HashMethodFixedKeys<u32
     fn get_hash(&self, v: &u32) -> u64 {
         v.fast_hash()
     }
+
+    fn key_offset(&self, key: &u32, base: &u32) -> Option<usize> {
+        usize::try_from(key.wrapping_sub(*base)).ok()
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodFixedKeys<u64>> for HashMethodFixedKeys<u64> {
@@ -302,6 +311,10 @@ impl PolymorphicKeysHelper<HashMethodFixedKeys<u64>> for HashMethodFixedKeys<u64
     fn get_hash(&self, v: &u64) -> u64 {
         v.fast_hash()
     }
+
+    fn key_offset(&self, key: &u64, base: &u64) -> Option<usize> {
+        usize::try_from(key.wrapping_sub(*base)).ok()
+    }
 }
 
 impl PolymorphicKeysHelper<HashMethodKeysU128> for HashMethodKeysU128 {
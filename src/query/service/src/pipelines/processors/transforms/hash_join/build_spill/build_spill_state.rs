@@ -20,7 +20,6 @@ use databend_common_base::runtime::GLOBAL_MEM_STAT;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
-use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_sql::plans::JoinType;
 use databend_common_storage::DataOperator;
 use log::info;
@@ -43,8 +42,7 @@ pub struct BuildSpillState {
 
 impl BuildSpillState {
     pub fn create(ctx: Arc<QueryContext>, build_state: Arc<HashJoinBuildState>) -> Result<Self> {
-        let tenant = ctx.get_tenant();
-        let spill_config = SpillerConfig::create(query_spill_prefix(tenant.tenant_name()));
+        let spill_config = SpillerConfig::create(ctx.node_spill_prefix());
         let operator = DataOperator::instance().operator();
         let spiller = Spiller::create(ctx, operator, spill_config, SpillerType::HashJoinBuild)?;
         Ok(Self {
@@ -125,6 +125,12 @@ pub struct HashJoinBuildState {
     pub(crate) enable_min_max_runtime_filter: bool,
     /// Need to open runtime filter setting.
     pub(crate) enable_bloom_runtime_filter: bool,
+
+    /// Broadcast join related states
+    /// The max bytes of the build side of broadcast join, 0 means no limit.
+    pub(crate) broadcast_max_build_bytes: usize,
+    /// The bytes of the build side received by the current node.
+    pub(crate) build_bytes: AtomicUsize,
 }
 
 impl HashJoinBuildState {
@@ -162,6 +168,7 @@ impl HashJoinBuildState {
             }
         }
         let chunk_size_limit = ctx.get_settings().get_max_block_size()? as usize * 16;
+        let broadcast_max_build_bytes = Self::broadcast_max_build_bytes(&ctx, &hash_join_state)?;
         let (max_memory_usage, spilling_threshold_per_proc) =
             Self::max_memory_usage(ctx.clone(), num_threads)?;
         Ok(Arc::new(Self {
@@ -186,9 +193,31 @@ impl HashJoinBuildState {
             enable_min_max_runtime_filter,
             spilling_threshold_per_proc,
             spilled_partition_set: Default::default(),
+            broadcast_max_build_bytes,
+            build_bytes: Default::default(),
         }))
     }
 
+    // Get the max bytes of the build side for broadcast join which can fall back to shuffle join.
+    fn broadcast_max_build_bytes(
+        ctx: &Arc<QueryContext>,
+        hash_join_state: &HashJoinState,
+    ) -> Result<usize> {
+        let settings = ctx.get_settings();
+        let hash_join_desc = &hash_join_state.hash_join_desc;
+        if !hash_join_desc.broadcast
+            || matches!(
+                hash_join_desc.join_type,
+                JoinType::Cross | JoinType::NullAwareLeftAnti
+            )
+            || settings.get_enforce_broadcast_join()?
+            || settings.get_enforce_shuffle_join()?
+        {
+            return Ok(0);
+        }
+        settings.get_broadcast_join_max_build_bytes()
+    }
+
     // Get max memory usage for settings
     fn max_memory_usage(ctx: Arc<QueryContext>, num_threads: usize) -> Result<(usize, usize)> {
         debug_assert!(num_threads != 0);
@@ -216,6 +245,19 @@ impl HashJoinBuildState {
 
     /// Add input `DataBlock` to `hash_join_state.row_space`.
     pub fn build(&self, input: DataBlock) -> Result<()> {
+        // The statistics may be stale, stop building a broadcast join which is too large,
+        // then the query will be re-planned with shuffle join.
+        if self.broadcast_max_build_bytes != 0 {
+            let input_bytes = input.memory_size();
+            let build_bytes = self.build_bytes.fetch_add(input_bytes, Ordering::Relaxed) + input_bytes;
+            if build_bytes > self.broadcast_max_build_bytes {
+                return Err(ErrorCode::BroadcastJoinBuildTooLarge(format!(
+                    "The build side of broadcast join exceeds {} bytes",
+                    self.broadcast_max_build_bytes
+                )));
+            }
+        }
+
         let mut buffer = self.hash_join_state.row_space.buffer.write();
 
         let input_rows = input.num_rows();
@@ -521,10 +563,12 @@ impl HashJoinBuildState {
 
                 let space_size = match &keys_state {
                     // safe to unwrap(): offset.len() >= 1.
-                    KeysState::Column(Column::Binary(col) | Column::Variant(col) | Column::Bitmap(col)) => col.offsets().last().unwrap(),
-                    KeysState::Column(Column::String(col) ) => col.offsets().last().unwrap(),
+                    KeysState::Column(Column::Binary(col) | Column::Variant(col) | Column::Bitmap(col)) => *col.offsets().last().unwrap(),
+                    KeysState::Column(Column::String(col) ) => col.total_bytes_len() as u64,
+                    // The keys of dictionary column are copied row by row.
+                    KeysState::Column(Column::StringDictionary(col)) => col.iter().map(|key| key.len() as u64).sum(),
                     // The function `build_keys_state` of both HashMethodSerializer and HashMethodSingleString
-                    // must return `Column::Binary` | `Column::String` | `Column::Variant` | `Column::Bitmap`.
+                    // must return `Column::Binary` | `Column::String` | `Column::StringDictionary` | `Column::Variant` | `Column::Bitmap`.
                     _ => unreachable!(),
                 };
                 let valid_num = match &$valids {
@@ -534,7 +578,7 @@ impl HashJoinBuildState {
                 let mut entry_local_space: Vec<u8> =
                     Vec::with_capacity(valid_num * entry_size);
                 let mut string_local_space: Vec<u8> =
-                    Vec::with_capacity(*space_size as usize);
+                    Vec::with_capacity(space_size as usize);
                 let mut raw_entry_ptr = unsafe { std::mem::transmute::<*mut u8, *mut StringRawEntry>(entry_local_space.as_mut_ptr()) };
                 let mut string_local_space_ptr = string_local_space.as_mut_ptr();
 
@@ -682,6 +726,19 @@ impl HashJoinBuildState {
             }
         }
 
+        if self.hash_join_state.hash_join_desc.join_type == JoinType::NullAwareLeftAnti
+            && let Some(valids) = &valids
+            && valids.unset_bits() > 0
+        {
+            // A NULL build key makes `NOT IN` filter out every probe row.
+            *self
+                .hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .has_null
+                .write() = true;
+        }
+
         valids = match valids {
             Some(valids) => {
                 if valids.unset_bits() == valids.len() {
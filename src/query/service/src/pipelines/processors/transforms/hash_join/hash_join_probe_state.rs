@@ -161,6 +161,7 @@ impl HashJoinProbeState {
             JoinType::Inner
             | JoinType::LeftSemi
             | JoinType::LeftAnti
+            | JoinType::NullAwareLeftAnti
             | JoinType::RightSemi
             | JoinType::RightAnti
             | JoinType::Left
@@ -179,7 +180,7 @@ impl HashJoinProbeState {
         mut input: DataBlock,
         probe_state: &mut ProbeState,
     ) -> Result<Vec<DataBlock>> {
-        let input_num_rows = input.num_rows();
+        let mut input_num_rows = input.num_rows();
         let mut _nullable_data_block = None;
         let evaluator = if matches!(
             self.hash_join_state.hash_join_desc.join_type,
@@ -267,7 +268,11 @@ impl HashJoinProbeState {
         if self.hash_join_state.fast_return.load(Ordering::Relaxed)
             && matches!(
                 self.hash_join_state.hash_join_desc.join_type,
-                JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+                JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::Full
+                    | JoinType::LeftAnti
+                    | JoinType::NullAwareLeftAnti
             )
         {
             return self.left_fast_return(
@@ -277,6 +282,36 @@ impl HashJoinProbeState {
             );
         }
 
+        // Null-aware anti join:
+        // (1) the build side is empty => all probe rows are returned.
+        // (2) the build side has NULL keys => no probe rows are returned.
+        // (3) otherwise, probe rows with NULL keys are filtered out before probing.
+        if self.hash_join_state.hash_join_desc.join_type == JoinType::NullAwareLeftAnti {
+            let build_state = unsafe { &*self.hash_join_state.build_state.get() };
+            if build_state.generation_state.build_num_rows == 0 {
+                return Ok(vec![input]);
+            }
+            if *self
+                .hash_join_state
+                .hash_join_desc
+                .marker_join_desc
+                .has_null
+                .read()
+            {
+                return Ok(vec![]);
+            }
+            if let Some(bitmap) = valids.take() {
+                if bitmap.unset_bits() == input_num_rows {
+                    return Ok(vec![]);
+                }
+                input = input.filter_with_bitmap(&bitmap)?;
+                for (col, _) in probe_keys.iter_mut() {
+                    *col = col.filter(&bitmap);
+                }
+                input_num_rows = input.num_rows();
+            }
+        }
+
         // Adaptive early filtering.
         // Thanks to the **adaptive** execution strategy of early filtering, we don't experience a performance decrease
         // when all keys have matches. This allows us to achieve the same performance as before.
@@ -379,7 +414,11 @@ impl HashJoinProbeState {
     pub fn need_unmatched_selection(join_type: &JoinType, with_conjunction: bool) -> bool {
         matches!(
             join_type,
-            JoinType::Left | JoinType::LeftSingle | JoinType::Full | JoinType::LeftAnti
+            JoinType::Left
+                | JoinType::LeftSingle
+                | JoinType::Full
+                | JoinType::LeftAnti
+                | JoinType::NullAwareLeftAnti
         ) && !with_conjunction
     }
 
@@ -141,7 +141,10 @@ impl HashJoinState {
         let (build_done_watcher, _build_done_dummy_receiver) = watch::channel(0);
         let (continue_build_watcher, _continue_build_dummy_receiver) = watch::channel(false);
         let mut enable_spill = false;
-        if ctx.get_settings().get_join_spilling_memory_ratio()? != 0 {
+        // Null-aware anti join needs to know whether the whole build side has NULL keys.
+        if ctx.get_settings().get_join_spilling_memory_ratio()? != 0
+            && hash_join_desc.join_type != JoinType::NullAwareLeftAnti
+        {
             enable_spill = true;
         }
         Ok(Arc::new(HashJoinState {
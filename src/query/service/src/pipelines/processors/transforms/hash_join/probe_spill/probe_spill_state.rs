@@ -17,7 +17,6 @@ use std::sync::Arc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
-use databend_common_pipeline_core::query_spill_prefix;
 use databend_common_sql::plans::JoinType;
 use databend_common_storage::DataOperator;
 
@@ -39,8 +38,7 @@ pub struct ProbeSpillState {
 
 impl ProbeSpillState {
     pub fn create(ctx: Arc<QueryContext>, probe_state: Arc<HashJoinProbeState>) -> Result<Self> {
-        let tenant = ctx.get_tenant();
-        let spill_config = SpillerConfig::create(query_spill_prefix(tenant.tenant_name()));
+        let spill_config = SpillerConfig::create(ctx.node_spill_prefix());
         let operator = DataOperator::instance().operator();
         let spiller = Spiller::create(ctx, operator, spill_config, SpillerType::HashJoinProbe)?;
         Ok(Self {
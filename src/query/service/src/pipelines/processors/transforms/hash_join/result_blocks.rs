@@ -81,7 +81,7 @@ impl HashJoinProbeState {
                 true => self.left_semi_join(input, keys, hash_table, probe_state),
                 false => self.left_semi_join_with_conjunct(input, keys, hash_table, probe_state),
             },
-            JoinType::LeftAnti => match has_other_predicate {
+            JoinType::LeftAnti | JoinType::NullAwareLeftAnti => match has_other_predicate {
                 true => self.left_anti_join(input, keys, hash_table, probe_state),
                 false => self.left_anti_join_with_conjunct(input, keys, hash_table, probe_state),
             },
@@ -115,7 +115,10 @@ impl HashJoinProbeState {
         is_probe_projected: bool,
         true_validity: &Bitmap,
     ) -> Result<Vec<DataBlock>> {
-        if self.hash_join_state.hash_join_desc.join_type == JoinType::LeftAnti {
+        if matches!(
+            self.hash_join_state.hash_join_desc.join_type,
+            JoinType::LeftAnti | JoinType::NullAwareLeftAnti
+        ) {
             return Ok(vec![input]);
         }
         let input_num_rows = input.num_rows();
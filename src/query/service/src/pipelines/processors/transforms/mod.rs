@@ -29,8 +29,11 @@ mod transform_filter;
 mod transform_limit;
 mod transform_materialized_cte;
 mod transform_merge_block;
+#[cfg(feature = "ml-inference")]
+mod transform_model_inference;
 mod transform_resort_addon;
 mod transform_resort_addon_without_source_schema;
+mod transform_returning;
 mod transform_runtime_cast_schema;
 mod transform_sort_spill;
 mod transform_srf;
@@ -54,8 +57,13 @@ pub use transform_materialized_cte::MaterializedCteSink;
 pub use transform_materialized_cte::MaterializedCteSource;
 pub use transform_materialized_cte::MaterializedCteState;
 pub use transform_merge_block::TransformMergeBlock;
+#[cfg(feature = "ml-inference")]
+pub use transform_model_inference::ModelSessionCache;
+#[cfg(feature = "ml-inference")]
+pub use transform_model_inference::TransformModelInference;
 pub use transform_resort_addon::TransformResortAddOn;
 pub use transform_resort_addon_without_source_schema::TransformResortAddOnWithoutSourceSchema;
+pub use transform_returning::TransformReturning;
 pub use transform_runtime_cast_schema::TransformRuntimeCastSchema;
 pub use transform_sort_spill::create_transform_sort_spill;
 pub use transform_srf::TransformSRF;
@@ -36,6 +36,7 @@ pub struct TransformFilter {
     projections: ColumnSet,
     output_data_blocks: VecDeque<DataBlock>,
     max_block_size: usize,
+    max_block_size_bytes: usize,
     filter: FilterExecutor,
 }
 
@@ -48,6 +49,7 @@ impl TransformFilter {
         projections: ColumnSet,
         func_ctx: FunctionContext,
         max_block_size: usize,
+        max_block_size_bytes: usize,
     ) -> Box<dyn Processor> {
         let filter = FilterExecutor::new(
             select_expr,
@@ -62,6 +64,7 @@ impl TransformFilter {
             projections,
             output_data_blocks: VecDeque::new(),
             max_block_size,
+            max_block_size_bytes,
             filter,
         })
     }
@@ -81,7 +84,8 @@ impl BlockingTransform for TransformFilter {
             self.output_data_blocks
                 .push_back(input.project_with_agg_index(&self.projections, num_evals));
         } else {
-            let blocks = input.split_by_rows_no_tail(self.max_block_size);
+            let blocks = input
+                .split_by_rows_and_bytes_no_tail(self.max_block_size, self.max_block_size_bytes);
             for block in blocks.into_iter() {
                 let data_block = self.filter.filter(block)?;
                 if data_block.num_rows() > 0 {
@@ -0,0 +1,277 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_base::runtime::spawn_blocking;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_pipeline_transforms::processors::AsyncTransform;
+use databend_common_pipeline_transforms::processors::AsyncTransformer;
+use databend_common_sql::binder::resolve_stage_location;
+use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+use databend_common_storage::init_stage_operator;
+use ort::Session;
+use parking_lot::Mutex;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::Processor;
+use crate::sessions::QueryContext;
+
+/// The loaded ONNX sessions of a pipeline, keyed by the stage location of the model file,
+/// so a model is only loaded once no matter how many processors evaluate it.
+#[derive(Default)]
+pub struct ModelSessionCache {
+    sessions: Mutex<HashMap<String, Arc<Session>>>,
+}
+
+impl ModelSessionCache {
+    pub fn create() -> Arc<ModelSessionCache> {
+        Arc::new(ModelSessionCache::default())
+    }
+
+    #[async_backtrace::framed]
+    async fn get_or_load(&self, ctx: &QueryContext, location: &str) -> Result<Arc<Session>> {
+        if let Some(session) = self.sessions.lock().get(location) {
+            return Ok(session.clone());
+        }
+
+        let (stage_info, path) = resolve_stage_location(ctx, location).await?;
+        let op = init_stage_operator(&stage_info)?;
+        let model = op.read(&path).await?;
+        let model_location = location.to_string();
+        let session = spawn_blocking(move || {
+            Session::builder()
+                .and_then(|builder| builder.commit_from_memory(&model))
+                .map_err(|err| {
+                    ErrorCode::UDFDataError(format!("Cannot load model '@{model_location}': {err}"))
+                })
+        })
+        .await
+        .map_err(ErrorCode::from_std_error)??;
+
+        let session = Arc::new(session);
+        self.sessions
+            .lock()
+            .insert(location.to_string(), session.clone());
+        Ok(session)
+    }
+}
+
+/// Evaluates `ml_predict` with ONNX Runtime, each block is scored as a single batch.
+///
+/// Loading and running a model block the thread until ONNX Runtime returns, so they run
+/// on the blocking pool instead of the async workers shared by the pipelines.
+pub struct TransformModelInference {
+    ctx: Arc<QueryContext>,
+    funcs: Vec<UdfFunctionDesc>,
+    cache: Arc<ModelSessionCache>,
+}
+
+impl TransformModelInference {
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        funcs: Vec<UdfFunctionDesc>,
+        cache: Arc<ModelSessionCache>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+    ) -> Result<Box<dyn Processor>> {
+        Ok(AsyncTransformer::create(input, output, Self {
+            ctx,
+            funcs,
+            cache,
+        }))
+    }
+
+    /// Collects the features of the rows without NULL features into a row-major matrix,
+    /// returns the matrix and the indices of the collected rows.
+    fn collect_features(
+        data_block: &DataBlock,
+        func: &UdfFunctionDesc,
+    ) -> Result<(Vec<f32>, Vec<usize>)> {
+        let num_rows = data_block.num_rows();
+        let num_features = func.arg_indices.len();
+        let mut features = Vec::with_capacity(num_rows * num_features);
+        let mut rows = Vec::with_capacity(num_rows);
+
+        'rows: for row in 0..num_rows {
+            let start = features.len();
+            for i in &func.arg_indices {
+                let value = &data_block.get_by_offset(*i).value;
+                match value.index(row) {
+                    Some(ScalarRef::Number(number)) => features.push(number_to_f32(number)),
+                    Some(ScalarRef::Null) | None => {
+                        features.truncate(start);
+                        continue 'rows;
+                    }
+                    Some(other) => {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Features of model '{}' must be numbers, but got {other}",
+                            func.name
+                        )));
+                    }
+                }
+            }
+            rows.push(row);
+        }
+        Ok((features, rows))
+    }
+
+    fn run(
+        session: &Session,
+        func: &UdfFunctionDesc,
+        features: Vec<f32>,
+        num_rows: usize,
+    ) -> Result<Vec<Scalar>> {
+        let map_err = |err: ort::Error| {
+            ErrorCode::UDFDataError(format!("Cannot run model '{}': {err}", func.name))
+        };
+
+        let shape = vec![num_rows as i64, func.arg_indices.len() as i64];
+        let input = ort::Tensor::from_array((shape, features)).map_err(map_err)?;
+        let outputs = session
+            .run(ort::inputs![input].map_err(map_err)?)
+            .map_err(map_err)?;
+        let output = &outputs[0];
+
+        let data_type = func.data_type.remove_nullable();
+        let inner_type = match &data_type {
+            DataType::Array(inner) => inner.remove_nullable(),
+            ty => ty.clone(),
+        };
+        let values = match inner_type {
+            DataType::Number(NumberDataType::Float32) => {
+                let (_, data) = output.try_extract_raw_tensor::<f32>().map_err(map_err)?;
+                data.iter()
+                    .map(|v| NumberScalar::Float32((*v).into()))
+                    .collect::<Vec<_>>()
+            }
+            DataType::Number(NumberDataType::Float64) => {
+                let (_, data) = output.try_extract_raw_tensor::<f64>().map_err(map_err)?;
+                data.iter()
+                    .map(|v| NumberScalar::Float64((*v).into()))
+                    .collect::<Vec<_>>()
+            }
+            DataType::Number(NumberDataType::Int64) => {
+                let (_, data) = output.try_extract_raw_tensor::<i64>().map_err(map_err)?;
+                data.iter()
+                    .map(|v| NumberScalar::Int64(*v))
+                    .collect::<Vec<_>>()
+            }
+            ty => {
+                return Err(ErrorCode::UDFDataError(format!(
+                    "Unsupported return type {ty} of model '{}'",
+                    func.name
+                )));
+            }
+        };
+
+        if num_rows == 0 || values.len() % num_rows != 0 {
+            return Err(ErrorCode::UDFDataError(format!(
+                "Model '{}' returned {} values for {} rows",
+                func.name,
+                values.len(),
+                num_rows
+            )));
+        }
+        let width = values.len() / num_rows;
+
+        match &data_type {
+            DataType::Array(inner) => values
+                .chunks(width)
+                .map(|chunk| {
+                    let mut builder = ColumnBuilder::with_capacity(inner, chunk.len());
+                    for value in chunk {
+                        builder.push(ScalarRef::Number(*value));
+                    }
+                    Ok(Scalar::Array(builder.build()))
+                })
+                .collect(),
+            _ if width == 1 => Ok(values.into_iter().map(Scalar::Number).collect()),
+            _ => Err(ErrorCode::UDFDataError(format!(
+                "Model '{}' returned {} values per row, but the return type {} expects one",
+                func.name, width, func.data_type
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncTransform for TransformModelInference {
+    const NAME: &'static str = "ModelInferenceTransform";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
+        let num_rows = data_block.num_rows();
+        for func in &self.funcs {
+            let location = func.udf_type.as_model().unwrap();
+            let (features, rows) = Self::collect_features(&data_block, func)?;
+
+            let mut builder = ColumnBuilder::with_capacity(&func.data_type, num_rows);
+            if rows.is_empty() {
+                for _ in 0..num_rows {
+                    builder.push_default();
+                }
+            } else {
+                let session = self.cache.get_or_load(&self.ctx, location).await?;
+                let func_desc = func.clone();
+                let num_features_rows = rows.len();
+                let results = spawn_blocking(move || {
+                    Self::run(&session, &func_desc, features, num_features_rows)
+                })
+                .await
+                .map_err(ErrorCode::from_std_error)??;
+                let mut results = rows.iter().zip(results).peekable();
+                for row in 0..num_rows {
+                    match results.next_if(|(r, _)| **r == row) {
+                        Some((_, value)) => builder.push(value.as_ref()),
+                        None => builder.push_default(),
+                    }
+                }
+            }
+
+            data_block.add_column(BlockEntry::new(
+                func.data_type.as_ref().clone(),
+                Value::Column(builder.build()),
+            ));
+        }
+        Ok(data_block)
+    }
+}
+
+fn number_to_f32(number: NumberScalar) -> f32 {
+    match number {
+        NumberScalar::UInt8(v) => v as f32,
+        NumberScalar::UInt16(v) => v as f32,
+        NumberScalar::UInt32(v) => v as f32,
+        NumberScalar::UInt64(v) => v as f32,
+        NumberScalar::Int8(v) => v as f32,
+        NumberScalar::Int16(v) => v as f32,
+        NumberScalar::Int32(v) => v as f32,
+        NumberScalar::Int64(v) => v as f32,
+        NumberScalar::Float32(v) => v.0,
+        NumberScalar::Float64(v) => v.0 as f32,
+    }
+}
@@ -22,6 +22,7 @@ use databend_common_expression::type_check::check_cast;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::Expr;
 use databend_common_expression::Scalar;
 use databend_common_expression::SourceSchemaIndex;
@@ -31,6 +32,8 @@ use databend_common_pipeline_transforms::processors::Transform;
 use databend_common_pipeline_transforms::processors::Transformer;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_sql::evaluator::CompoundBlockOperator;
+use databend_common_sql::is_dependent_default_expr;
+use databend_common_sql::parse_computed_expr;
 use databend_common_sql::parse_exprs;
 use databend_common_storages_factory::Table;
 use databend_common_storages_fuse::operations::UnMatchedExprs;
@@ -57,17 +60,32 @@ pub struct TransformResortAddOnWithoutSourceSchema {
     target_table_schema_with_computed: DataSchemaRef,
 }
 
+/// Build the operator which outputs the columns of `output_schema` after the input columns,
+/// the missing columns are filled with their default values.
+///
+/// The output columns are evaluated one by one in the order of `output_schema`, i.e. the
+/// order of the table columns. A default expression is evaluated per row, and it can refer
+/// to the columns defined before it, whose values are either from the input or their own
+/// defaults evaluated earlier.
 pub fn build_expression_transform(
     input_schema: DataSchemaRef,
     output_schema: DataSchemaRef,
     table: Arc<dyn Table>,
     ctx: Arc<QueryContext>,
 ) -> Result<CompoundBlockOperator> {
+    let input_len = input_schema.num_fields();
     let mut exprs = Vec::with_capacity(output_schema.fields().len());
-    for f in output_schema.fields().iter() {
+    for (i, f) in output_schema.fields().iter().enumerate() {
         let expr = if !input_schema.has_field(f.name()) {
             if let Some(default_expr) = f.default_expr() {
-                let expr = parse_exprs(ctx.clone(), table.clone(), default_expr)?.remove(0);
+                let expr = if is_dependent_default_expr(default_expr)? {
+                    // the previous output columns are appended after the input columns.
+                    let scope = DataSchemaRefExt::create(output_schema.fields()[..i].to_vec());
+                    parse_computed_expr(ctx.clone(), scope, default_expr)?
+                        .project_column_ref(|index| input_len + *index)
+                } else {
+                    parse_exprs(ctx.clone(), table.clone(), default_expr)?.remove(0)
+                };
                 check_cast(None, false, expr, f.data_type(), &BUILTIN_FUNCTIONS)?
             } else {
                 // #issue13932
@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::TableSchema;
+use databend_common_pipeline_transforms::processors::Transform;
+use databend_common_pipeline_transforms::processors::Transformer;
+use databend_common_sql::evaluator::BlockOperator;
+use databend_common_storages_fuse::operations::MutationSource;
+
+use crate::pipelines::processors::InputPort;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+use crate::sessions::QueryContext;
+
+/// Evaluates the `RETURNING` clause on the rows written into the table, the results are
+/// buffered in the query context and the input blocks are passed through unchanged.
+pub struct TransformReturning {
+    ctx: Arc<QueryContext>,
+    func_ctx: FunctionContext,
+    operator: BlockOperator,
+}
+
+impl TransformReturning
+where Self: Transform
+{
+    pub fn try_create(
+        ctx: Arc<QueryContext>,
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: &TableSchema,
+        returning: Vec<RemoteExpr<String>>,
+    ) -> Result<ProcessorPtr> {
+        let operator = MutationSource::returning_operator(returning, schema)?
+            .expect("RETURNING must not be empty");
+        let func_ctx = ctx.get_function_context()?;
+        Ok(ProcessorPtr::create(Transformer::create(
+            input,
+            output,
+            Self {
+                ctx,
+                func_ctx,
+                operator,
+            },
+        )))
+    }
+}
+
+impl Transform for TransformReturning {
+    const NAME: &'static str = "ReturningTransform";
+
+    fn transform(&mut self, block: DataBlock) -> Result<DataBlock> {
+        if !block.is_empty() {
+            let num_columns = block.num_columns();
+            let result = self.operator.execute(&self.func_ctx, block.clone())?;
+            let columns = result.columns()[num_columns..].to_vec();
+            self.ctx
+                .add_returning_block(DataBlock::new(columns, result.num_rows()));
+        }
+        Ok(block)
+    }
+}
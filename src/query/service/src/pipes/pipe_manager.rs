@@ -0,0 +1,301 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::GLOBAL_TASK;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures::TryStreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// How often the stages of the auto-ingest pipes are polled for new files.
+const PIPE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The max number of files loaded by one copy of a pipe.
+const PIPE_MAX_FILES_PER_LOAD: usize = 1000;
+
+/// The load status of a pipe on this node.
+#[derive(Clone, Debug, Default)]
+pub struct PipeStatus {
+    /// The files discovered but not loaded yet, relative to the location of the copy statement.
+    pub pending_files: VecDeque<String>,
+    pub loaded_file_count: u64,
+    pub last_ingested_file: Option<String>,
+    pub last_ingested_on: Option<DateTime<Utc>>,
+    pub last_polled_on: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+}
+
+#[derive(Default)]
+struct PipeState {
+    // A pipe is loaded by one task at a time on a node.
+    load_lock: tokio::sync::Mutex<()>,
+    status: Mutex<PipeStatus>,
+}
+
+/// Runs the pipes of the tenant.
+///
+/// A pipe keeps a queue of the files of its stage that are not loaded yet, and loads them
+/// with its copy statement in batches. A file is loaded into the table exactly once: the
+/// loaded files are recorded with the table commit, they are skipped when the files are
+/// discovered, and a commit that loads a recorded file again fails.
+pub struct PipeManager {
+    tenant: Tenant,
+    states: Mutex<HashMap<String, Arc<PipeState>>>,
+}
+
+impl PipeManager {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(PipeManager {
+            tenant: conf.query.tenant_id.clone(),
+            states: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PipeManager> {
+        GlobalInstance::get()
+    }
+
+    /// Starts polling the stages of the auto-ingest pipes.
+    pub fn start(self: &Arc<Self>) {
+        let this = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            loop {
+                tokio::time::sleep(PIPE_POLL_INTERVAL).await;
+                if let Err(cause) = this.poll().await {
+                    warn!("Failed to poll the pipes, cause {:?}", cause);
+                }
+            }
+        });
+    }
+
+    pub fn status(&self, name: &str) -> PipeStatus {
+        self.state(name).status.lock().clone()
+    }
+
+    /// Forgets the queue and the status of a dropped pipe.
+    pub fn remove(&self, name: &str) {
+        self.states.lock().remove(name);
+    }
+
+    fn state(&self, name: &str) -> Arc<PipeState> {
+        self.states
+            .lock()
+            .entry(name.to_string())
+            .or_default()
+            .clone()
+    }
+
+    #[async_backtrace::framed]
+    async fn poll(&self) -> Result<()> {
+        let pipes = UserApiProvider::instance().get_pipes(&self.tenant).await?;
+
+        let names = pipes
+            .iter()
+            .map(|pipe| pipe.name.clone())
+            .collect::<HashSet<_>>();
+        self.states.lock().retain(|name, _| names.contains(name));
+
+        for pipe in pipes {
+            if !pipe.auto_ingest || pipe.execution_paused {
+                continue;
+            }
+
+            if let Err(cause) = self.refresh(&pipe, None, None).await {
+                warn!("Failed to load pipe {}, cause {:?}", pipe.name, cause);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Queues the new files of the stage of the pipe, and loads the queued files.
+    #[async_backtrace::framed]
+    pub async fn refresh(
+        &self,
+        pipe: &PipeInfo,
+        prefix: Option<&str>,
+        modified_after: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let state = self.state(&pipe.name);
+        let _guard = state.load_lock.lock().await;
+
+        let res = self
+            .discover_and_load(pipe, &state, prefix, modified_after)
+            .await;
+        let mut status = state.status.lock();
+        status.last_polled_on = Some(Utc::now());
+        if let Err(cause) = &res {
+            status.last_error = Some(cause.message());
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn discover_and_load(
+        &self,
+        pipe: &PipeInfo,
+        state: &PipeState,
+        prefix: Option<&str>,
+        modified_after: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let ctx = self.create_query_context(pipe).await?;
+        let files = Self::discover(ctx, pipe, prefix, modified_after).await?;
+        {
+            let mut status = state.status.lock();
+            let queued = status.pending_files.iter().cloned().collect::<HashSet<_>>();
+            for file in files {
+                if !queued.contains(&file) {
+                    status.pending_files.push_back(file);
+                }
+            }
+        }
+
+        loop {
+            let files = {
+                let status = state.status.lock();
+                let n = status.pending_files.len().min(PIPE_MAX_FILES_PER_LOAD);
+                status
+                    .pending_files
+                    .iter()
+                    .take(n)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            };
+            if files.is_empty() {
+                return Ok(());
+            }
+
+            let ctx = self.create_query_context(pipe).await?;
+            Self::load(ctx, pipe, &files).await?;
+
+            info!("Pipe {} loaded {} files", pipe.name, files.len());
+            let mut status = state.status.lock();
+            status.pending_files.drain(..files.len());
+            status.loaded_file_count += files.len() as u64;
+            status.last_ingested_file = files.last().cloned();
+            status.last_ingested_on = Some(Utc::now());
+            status.last_error = None;
+        }
+    }
+
+    /// Returns the files not loaded yet, the copy statement skips the loaded files itself.
+    #[async_backtrace::framed]
+    async fn discover(
+        ctx: Arc<QueryContext>,
+        pipe: &PipeInfo,
+        prefix: Option<&str>,
+        modified_after: Option<DateTime<Utc>>,
+    ) -> Result<Vec<String>> {
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&pipe.copy_stmt).await?;
+        let Plan::CopyIntoTable(plan) = plan else {
+            return Err(ErrorCode::Internal(format!(
+                "pipe {} is not defined by a copy statement",
+                pipe.name
+            )));
+        };
+
+        let path = plan.stage_table_info.files_info.path.as_str();
+        let files = plan
+            .stage_table_info
+            .files_to_copy
+            .clone()
+            .unwrap_or_default();
+        Ok(files
+            .into_iter()
+            .filter(|file| modified_after.map_or(true, |t| file.last_modified >= t))
+            .map(|file| Self::relative_path(path, &file.path))
+            .filter(|file| prefix.map_or(true, |p| file.starts_with(p)))
+            .collect())
+    }
+
+    // The files of a copy statement are relative to its location, a location
+    // of a single file is loaded as a whole, which is an empty relative path.
+    fn relative_path(location_path: &str, file_path: &str) -> String {
+        match location_path {
+            "/" => file_path.to_string(),
+            path if path.ends_with('/') => file_path
+                .strip_prefix(path)
+                .unwrap_or(file_path)
+                .to_string(),
+            _ => String::new(),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn load(ctx: Arc<QueryContext>, pipe: &PipeInfo, files: &[String]) -> Result<()> {
+        let tokens = tokenize_sql(&pipe.copy_stmt)?;
+        let dialect = ctx.get_settings().get_sql_dialect()?;
+        let (stmt, _) = parse_sql(&tokens, dialect)?;
+        let Statement::CopyIntoTable(mut stmt) = stmt else {
+            return Err(ErrorCode::Internal(format!(
+                "pipe {} is not defined by a copy statement",
+                pipe.name
+            )));
+        };
+
+        if files.iter().all(|file| !file.is_empty()) {
+            stmt.files = Some(files.to_vec());
+            stmt.pattern = None;
+        }
+
+        let mut planner = Planner::new(ctx.clone());
+        let (plan, _) = planner.plan_sql(&stmt.to_string()).await?;
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+        let stream = interpreter.execute(ctx).await?;
+        stream.try_collect::<Vec<_>>().await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn create_query_context(&self, pipe: &PipeInfo) -> Result<Arc<QueryContext>> {
+        let user = UserApiProvider::instance()
+            .get_user(&self.tenant, pipe.creator.clone())
+            .await?;
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        session.set_authed_user(user, None).await?;
+        session.create_query_context().await
+    }
+}
@@ -28,6 +28,7 @@ use databend_common_sql::executor::physical_plans::CompactSource;
 use databend_common_sql::executor::physical_plans::ConstantTableScan;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
+use databend_common_sql::executor::physical_plans::CopyPullFiles;
 use databend_common_sql::executor::physical_plans::DeleteSource;
 use databend_common_sql::executor::physical_plans::ReclusterSource;
 use databend_common_sql::executor::physical_plans::ReclusterTask;
@@ -35,6 +36,7 @@ use databend_common_sql::executor::physical_plans::ReplaceDeduplicate;
 use databend_common_sql::executor::physical_plans::ReplaceInto;
 use databend_common_sql::executor::physical_plans::TableScan;
 use databend_common_storages_fuse::TableContext;
+use databend_common_storages_stage::StageTable;
 use databend_storages_common_table_meta::meta::BlockSlotDescription;
 use databend_storages_common_table_meta::meta::Location;
 
@@ -43,6 +45,7 @@ use crate::schedulers::QueryFragmentAction;
 use crate::schedulers::QueryFragmentActions;
 use crate::schedulers::QueryFragmentsActions;
 use crate::servers::flight::v1::exchange::DataExchange;
+use crate::servers::flight::v1::exchange::PartitionsQueue;
 use crate::sessions::QueryContext;
 use crate::sql::executor::physical_plans::UpdateSource;
 use crate::sql::executor::PhysicalPlan;
@@ -126,7 +129,7 @@ impl PlanFragment {
             }
             FragmentType::Source => {
                 // Redistribute partitions
-                self.redistribute_source_fragment(ctx, &mut fragment_actions)?;
+                self.redistribute_source_fragment(ctx, actions, &mut fragment_actions)?;
             }
             FragmentType::DeleteLeaf => {
                 self.redistribute_delete_leaf(ctx, &mut fragment_actions)?;
@@ -156,6 +159,7 @@ impl PlanFragment {
     fn redistribute_source_fragment(
         &self,
         ctx: Arc<QueryContext>,
+        actions: &mut QueryFragmentsActions,
         fragment_actions: &mut QueryFragmentActions,
     ) -> Result<()> {
         if self.fragment_type != FragmentType::Source {
@@ -166,7 +170,8 @@ impl PlanFragment {
 
         let data_sources = self.collect_data_sources()?;
 
-        let executors = Fragmenter::get_executors(ctx);
+        let executors = Fragmenter::get_executors(ctx.clone());
+        let pull_files = self.pull_copy_files(ctx, &executors)?;
 
         let mut executor_partitions: HashMap<String, HashMap<u32, DataSource>> = HashMap::new();
 
@@ -175,6 +180,11 @@ impl PlanFragment {
                 DataSource::Table(data_source_plan) => {
                     // Redistribute partitions of ReadDataSourcePlan.
                     let partitions = &data_source_plan.parts;
+                    if matches!(&pull_files, Some((scan_id, _)) if scan_id == plan_id) {
+                        let queue = PartitionsQueue::create(executors.clone(), partitions)?;
+                        actions.add_partitions_queue(*plan_id, queue);
+                    }
+
                     let partition_reshuffle = partitions.reshuffle(executors.clone())?;
                     for (executor, parts) in partition_reshuffle {
                         let mut source = data_source_plan.clone();
@@ -225,7 +235,12 @@ impl PlanFragment {
         for (executor, sources) in executor_partitions {
             let mut plan = self.plan.clone();
             // Replace `ReadDataSourcePlan` with rewritten one and generate new fragment for it.
-            let mut replace_read_source = ReplaceReadSource { sources };
+            let mut replace_read_source = ReplaceReadSource {
+                sources,
+                pull_files: pull_files
+                    .as_ref()
+                    .map(|(_, pull_files)| pull_files.clone()),
+            };
             plan = replace_read_source.replace(&plan)?;
 
             fragment_actions
@@ -235,6 +250,43 @@ impl PlanFragment {
         Ok(())
     }
 
+    /// Distributed COPY pulls the files from the coordinator in batches, so that the nodes
+    /// loading faster take more files. Returns the plan id of the stage scan to pull.
+    fn pull_copy_files(
+        &self,
+        ctx: Arc<QueryContext>,
+        executors: &[String],
+    ) -> Result<Option<(u32, CopyPullFiles)>> {
+        let settings = ctx.get_settings();
+        let batch_size = settings.get_distributed_copy_pull_batch_size()?;
+        if batch_size == 0 || executors.len() < 2 {
+            return Ok(None);
+        }
+
+        let PhysicalPlan::ExchangeSink(sink) = &self.plan else {
+            return Ok(None);
+        };
+        let PhysicalPlan::CopyIntoTable(copy) = sink.input.as_ref() else {
+            return Ok(None);
+        };
+        let CopyIntoTableSource::Stage(input) = &copy.source else {
+            return Ok(None);
+        };
+        let PhysicalPlan::TableScan(scan) = input.as_ref() else {
+            return Ok(None);
+        };
+
+        let stage_info = &copy.stage_table_info.stage_info;
+        if !StageTable::fetch_partitions_while_reading(&settings, stage_info)? {
+            return Ok(None);
+        }
+
+        Ok(Some((scan.plan_id, CopyPullFiles {
+            coordinator: Fragmenter::get_local_executor(ctx),
+            batch_size,
+        })))
+    }
+
     fn redistribute_delete_leaf(
         &self,
         ctx: Arc<QueryContext>,
@@ -533,6 +585,7 @@ impl TryFrom<DataSource> for ConstTableColumn {
 
 struct ReplaceReadSource {
     sources: HashMap<u32, DataSource>,
+    pull_files: Option<CopyPullFiles>,
 }
 
 impl PhysicalPlanReplacer for ReplaceReadSource {
@@ -587,6 +640,7 @@ impl PhysicalPlanReplacer for ReplaceReadSource {
                 let input = self.replace(v)?;
                 Ok(PhysicalPlan::CopyIntoTable(Box::new(CopyIntoTable {
                     source: CopyIntoTableSource::Stage(Box::new(input)),
+                    pull_files: self.pull_files.clone(),
                     ..plan.clone()
                 })))
             }
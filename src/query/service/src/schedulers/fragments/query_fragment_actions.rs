@@ -26,6 +26,7 @@ use itertools::Itertools;
 
 use crate::clusters::ClusterHelper;
 use crate::servers::flight::v1::exchange::DataExchange;
+use crate::servers::flight::v1::exchange::PartitionsQueue;
 use crate::servers::flight::v1::packets::ConnectionInfo;
 use crate::servers::flight::v1::packets::ExecutePartialQueryPacket;
 use crate::servers::flight::v1::packets::FragmentPlanPacket;
@@ -106,6 +107,8 @@ impl QueryFragmentActions {
 pub struct QueryFragmentsActions {
     ctx: Arc<QueryContext>,
     pub fragments_actions: Vec<QueryFragmentActions>,
+    // The partitions pulled by the executors at runtime, keyed by the plan id of the source.
+    partitions_queues: HashMap<u32, Arc<PartitionsQueue>>,
 }
 
 impl QueryFragmentsActions {
@@ -113,9 +116,18 @@ impl QueryFragmentsActions {
         QueryFragmentsActions {
             ctx,
             fragments_actions: Vec::new(),
+            partitions_queues: HashMap::new(),
         }
     }
 
+    pub fn add_partitions_queue(&mut self, plan_id: u32, queue: Arc<PartitionsQueue>) {
+        self.partitions_queues.insert(plan_id, queue);
+    }
+
+    pub fn get_partitions_queues(&self) -> HashMap<u32, Arc<PartitionsQueue>> {
+        self.partitions_queues.clone()
+    }
+
     pub fn get_executors(&self) -> Vec<String> {
         let cluster = self.ctx.get_cluster();
         let cluster_nodes = cluster.get_nodes();
@@ -173,6 +185,7 @@ impl QueryFragmentsActions {
 
         let cluster = self.ctx.get_cluster();
         let settings = self.ctx.get_settings();
+        let spill_key = self.ctx.get_spill_key();
         let local_query_fragments_plan_packet = QueryFragmentsPlanPacket::create(
             self.ctx.get_id(),
             self.ctx.get_query_kind(),
@@ -180,6 +193,7 @@ impl QueryFragmentsActions {
             fragments_packets.remove(&cluster.local_id).unwrap(),
             nodes_info.clone(),
             settings.changes().clone(),
+            spill_key,
             cluster.local_id(),
         );
 
@@ -195,6 +209,7 @@ impl QueryFragmentsActions {
                 fragments,
                 executors_info,
                 settings.changes().clone(),
+                spill_key,
                 cluster.local_id(),
             ));
         }
@@ -58,6 +58,10 @@ impl AdminService {
             .at("/v1/health", get(health_handler))
             .at("/v1/queries_queue", get(queries_queue_handler))
             .at("/v1/config", get(super::v1::config::config_handler))
+            .at(
+                "/v1/config/reload",
+                post(super::v1::config::config_reload_handler),
+            )
             .at("/v1/system", get(super::v1::system::system_handler))
             .at("/v1/logs", get(super::v1::logs::logs_handler))
             .at(
@@ -76,6 +80,22 @@ impl AdminService {
                 "/v1/cluster/list",
                 get(super::v1::cluster::cluster_list_handler),
             )
+            .at(
+                "/v1/cluster/status",
+                get(super::v1::cluster::cluster_status_handler),
+            )
+            .at(
+                "/v1/cluster/drain",
+                post(super::v1::cluster::cluster_drain_handler),
+            )
+            .at(
+                "/v1/cluster/leave",
+                post(super::v1::cluster::cluster_leave_handler),
+            )
+            .at(
+                "/v1/cluster/join",
+                post(super::v1::cluster::cluster_join_handler),
+            )
             .at("/debug/home", get(debug_home_handler))
             .at("/debug/pprof/profile", get(debug_pprof_handler))
             .at("/debug/async_tasks/dump", get(debug_dump_stack));
@@ -99,6 +119,11 @@ impl AdminService {
                     "/v1/tenants/:tenant/background_tasks",
                     get(super::v1::background_tasks::list_background_tasks),
                 )
+                .at(
+                    "/v1/tenants/:tenant/quota",
+                    get(super::v1::tenant_quota::get_tenant_quota)
+                        .post(super::v1::tenant_quota::set_tenant_quota),
+                )
                 .at(
                     "/v1/tenants/:tenant/settings",
                     get(super::v1::settings::list_settings),
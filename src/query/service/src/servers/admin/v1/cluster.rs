@@ -13,13 +13,18 @@
 // limitations under the License.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use databend_common_exception::Result;
 use databend_common_meta_types::NodeInfo;
 use poem::http::StatusCode;
 use poem::web::IntoResponse;
 use poem::web::Json;
+use poem::web::Query;
+use serde::Deserialize;
+use serde::Serialize;
 
+use crate::clusters::ClusterDiscovery;
 use crate::clusters::ClusterHelper;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
@@ -50,3 +55,75 @@ async fn list_nodes(sessions: &Arc<SessionManager>) -> Result<Vec<Arc<NodeInfo>>
     let watch_cluster_context = watch_cluster_session.create_query_context().await?;
     Ok(watch_cluster_context.get_cluster().get_nodes())
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClusterDrainQuery {
+    pub timeout_secs: Option<u64>,
+}
+
+impl ClusterDrainQuery {
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs.unwrap_or(600))
+    }
+}
+
+// GET /v1/cluster/status
+// return: the node id, whether the node is registered or draining, and the count of running queries
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn cluster_status_handler() -> poem::Result<impl IntoResponse> {
+    Ok(Json(ClusterDiscovery::instance().status().await))
+}
+
+// POST /v1/cluster/drain?timeout_secs=600
+// stop assigning the fragments of new queries to current node, and wait for the running queries
+// return: the status of current node, running_queries_count is 0 if the node is drained
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn cluster_drain_handler(
+    params: Query<ClusterDrainQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let status = ClusterDiscovery::instance()
+        .drain(params.timeout())
+        .await
+        .map_err(|cause| {
+            poem::Error::from_string(
+                format!("Failed to drain cluster node. cause: {cause}"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+    Ok(Json(status))
+}
+
+// POST /v1/cluster/leave?timeout_secs=600
+// drain current node, and remove it from the cluster once the running queries finish
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn cluster_leave_handler(
+    params: Query<ClusterDrainQuery>,
+) -> poem::Result<impl IntoResponse> {
+    let status = ClusterDiscovery::instance()
+        .leave(params.timeout())
+        .await
+        .map_err(|cause| {
+            poem::Error::from_string(
+                format!("Failed to leave cluster. cause: {cause}"),
+                StatusCode::INTERNAL_SERVER_ERROR,
+            )
+        })?;
+    Ok(Json(status))
+}
+
+// POST /v1/cluster/join
+// register current node to the cluster again, or stop draining it
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn cluster_join_handler() -> poem::Result<impl IntoResponse> {
+    let status = ClusterDiscovery::instance().join().await.map_err(|cause| {
+        poem::Error::from_string(
+            format!("Failed to join cluster. cause: {cause}"),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?;
+    Ok(Json(status))
+}
@@ -13,9 +13,12 @@
 // limitations under the License.
 
 use databend_common_config::GlobalConfig;
+use poem::http::StatusCode;
 use poem::web::Json;
 use poem::IntoResponse;
 
+use crate::config_reload::ConfigReloader;
+
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn config_handler() -> poem::Result<impl IntoResponse> {
@@ -23,3 +26,18 @@ pub async fn config_handler() -> poem::Result<impl IntoResponse> {
         GlobalConfig::instance().as_ref().clone().into_config(),
     ))
 }
+
+// POST /v1/config/reload
+// reload the config file, only the reloadable settings take effect
+// return: the changed settings
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn config_reload_handler() -> poem::Result<impl IntoResponse> {
+    let changes = ConfigReloader::reload_from_file("admin api").map_err(|cause| {
+        poem::Error::from_string(
+            format!("Failed to reload config. cause: {cause}"),
+            StatusCode::BAD_REQUEST,
+        )
+    })?;
+    Ok(Json(changes))
+}
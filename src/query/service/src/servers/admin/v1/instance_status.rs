@@ -14,6 +14,7 @@
 
 use std::time::SystemTime;
 
+use poem::http::StatusCode;
 use poem::web::Json;
 use poem::IntoResponse;
 use serde::Deserialize;
@@ -35,10 +36,13 @@ pub struct InstanceStatus {
     pub instance_started_at: u64,
     // the local timestamp, may be useful to avoid the clock drift issues
     pub instance_timestamp: u64,
+    // the instance is shutting down and rejects new queries
+    #[serde(default)]
+    pub draining: bool,
 }
 
 // lightweight way to get status
-// return Status in json
+// return Status in json, with 503 if the instance is draining, so it can be used as readiness probe
 #[poem::handler]
 #[async_backtrace::framed]
 pub async fn instance_status_handler() -> poem::Result<impl IntoResponse> {
@@ -51,8 +55,13 @@ pub async fn instance_status_handler() -> poem::Result<impl IntoResponse> {
         last_query_finished_at: status.last_query_finished_at.map(unix_timestamp_secs),
         instance_started_at: unix_timestamp_secs(status.instance_started_at),
         instance_timestamp: unix_timestamp_secs(SystemTime::now()),
+        draining: session_manager.is_draining(),
     };
-    Ok(Json(status))
+    let code = match status.draining {
+        true => StatusCode::SERVICE_UNAVAILABLE,
+        false => StatusCode::OK,
+    };
+    Ok(Json(status).with_status(code))
 }
 
 // Convert SystemTime to an u64 timestamp. The clock is almost impossible to drift before
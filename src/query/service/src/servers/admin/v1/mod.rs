@@ -22,4 +22,5 @@ pub mod queries_queue;
 pub mod settings;
 pub mod stream_status;
 pub mod system;
+pub mod tenant_quota;
 pub mod tenant_tables;
@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_app::tenant::TenantQuota;
+use databend_common_meta_types::MatchSeq;
+use databend_common_users::UserApiProvider;
+use minitrace::func_name;
+use poem::web::Json;
+use poem::web::Path;
+use poem::IntoResponse;
+
+/// The quotas to update, the quotas that are not specified are kept unchanged.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct TenantQuotaUpdate {
+    pub max_databases: Option<u32>,
+    pub max_tables_per_database: Option<u32>,
+    pub max_stages: Option<u32>,
+    pub max_files_per_stage: Option<u32>,
+    pub max_users: Option<u32>,
+    pub max_copy_file_size: Option<u64>,
+}
+
+async fn get_tenant_quota_impl(tenant: &str) -> Result<TenantQuota> {
+    let tenant = Tenant::new_or_err(tenant, func_name!())?;
+    let quota_api = UserApiProvider::instance().tenant_quota_api(&tenant);
+    Ok(quota_api.get_quota(MatchSeq::GE(0)).await?.data)
+}
+
+async fn set_tenant_quota_impl(tenant: &str, update: TenantQuotaUpdate) -> Result<TenantQuota> {
+    if tenant.is_empty() {
+        return Err(ErrorCode::TenantIsEmpty(
+            "Tenant can not empty(while set quota)",
+        ));
+    }
+
+    let tenant = Tenant::new_or_err(tenant, func_name!())?;
+    let quota_api = UserApiProvider::instance().tenant_quota_api(&tenant);
+    let res = quota_api.get_quota(MatchSeq::GE(0)).await?;
+
+    let mut quota = res.data;
+    if let Some(v) = update.max_databases {
+        quota.max_databases = v;
+    }
+    if let Some(v) = update.max_tables_per_database {
+        quota.max_tables_per_database = v;
+    }
+    if let Some(v) = update.max_stages {
+        quota.max_stages = v;
+    }
+    if let Some(v) = update.max_files_per_stage {
+        quota.max_files_per_stage = v;
+    }
+    if let Some(v) = update.max_users {
+        quota.max_users = v;
+    }
+    if let Some(v) = update.max_copy_file_size {
+        quota.max_copy_file_size = v;
+    }
+
+    quota_api
+        .set_quota(&quota, MatchSeq::Exact(res.seq))
+        .await?;
+    Ok(quota)
+}
+
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn get_tenant_quota(Path(tenant): Path<String>) -> poem::Result<impl IntoResponse> {
+    Ok(Json(
+        get_tenant_quota_impl(&tenant)
+            .await
+            .map_err(poem::error::InternalServerError)?,
+    ))
+}
+
+#[poem::handler]
+#[async_backtrace::framed]
+pub async fn set_tenant_quota(
+    Path(tenant): Path<String>,
+    update: Json<TenantQuotaUpdate>,
+) -> poem::Result<impl IntoResponse> {
+    Ok(Json(
+        set_tenant_quota_impl(&tenant, update.0)
+            .await
+            .map_err(poem::error::InternalServerError)?,
+    ))
+}
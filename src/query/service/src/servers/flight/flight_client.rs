@@ -24,8 +24,10 @@ use databend_common_arrow::arrow_format::flight::data::Ticket;
 use databend_common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use databend_common_base::base::tokio::time::Duration;
 use databend_common_base::runtime::drop_guard;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
 use futures::StreamExt;
 use futures_util::future::Either;
 use minitrace::full_name;
@@ -37,9 +39,12 @@ use tonic::Status;
 use tonic::Streaming;
 
 use crate::pipelines::executor::WatchNotify;
+use crate::servers::flight::flight_credits::flight_data_size;
 use crate::servers::flight::request_builder::RequestBuilder;
 use crate::servers::flight::v1::actions::FlightAction;
+use crate::servers::flight::v1::actions::PullPartitions;
 use crate::servers::flight::v1::packets::DataPacket;
+use crate::servers::flight::FlightCredits;
 
 pub struct FlightClient {
     inner: FlightServiceClient<Channel>,
@@ -63,6 +68,23 @@ impl FlightClient {
         Ok(())
     }
 
+    #[async_backtrace::framed]
+    pub async fn pull_partitions(
+        &mut self,
+        pull_partitions: PullPartitions,
+        timeout: u64,
+    ) -> Result<Vec<PartInfoPtr>> {
+        let action = FlightAction::PullPartitions(pull_partitions);
+        let body = match self.do_action(action, timeout).await {
+            Ok(body) => body,
+            Err(cause) => return Err(cause.add_message_back("(while in query flight)")),
+        };
+
+        serde_json::from_slice(&body).map_err_to_code(ErrorCode::BadBytes, || {
+            "Cannot deserialize the partitions pulled from the coordinator"
+        })
+    }
+
     #[async_backtrace::framed]
     pub async fn request_server_exchange(
         &mut self,
@@ -219,11 +241,19 @@ impl FlightReceiver {
 
 pub struct FlightSender {
     tx: Sender<Result<FlightData, Status>>,
+    credits: Arc<FlightCredits>,
 }
 
 impl FlightSender {
     pub fn create(tx: Sender<Result<FlightData, Status>>) -> FlightSender {
-        FlightSender { tx }
+        FlightSender {
+            tx,
+            credits: FlightCredits::create(),
+        }
+    }
+
+    pub fn credits(&self) -> &Arc<FlightCredits> {
+        &self.credits
     }
 
     pub fn is_closed(&self) -> bool {
@@ -232,7 +262,12 @@ impl FlightSender {
 
     #[async_backtrace::framed]
     pub async fn send(&self, data: DataPacket) -> Result<()> {
-        if let Err(_cause) = self.tx.send(Ok(FlightData::try_from(data)?)).await {
+        let data = FlightData::try_from(data)?;
+        let bytes = flight_data_size(&data);
+        self.credits.acquire(bytes).await;
+
+        if let Err(_cause) = self.tx.send(Ok(data)).await {
+            self.credits.release(bytes);
             return Err(ErrorCode::AbortedQuery(
                 "Aborted query, because the remote flight channel is closed.",
             ));
@@ -252,12 +287,18 @@ pub enum FlightExchange {
         notify: Arc<WatchNotify>,
         receiver: Receiver<Result<FlightData>>,
     },
-    Sender(Sender<Result<FlightData, Status>>),
+    Sender {
+        sender: Sender<Result<FlightData, Status>>,
+        credits: Arc<FlightCredits>,
+    },
 }
 
 impl FlightExchange {
-    pub fn create_sender(sender: Sender<Result<FlightData, Status>>) -> FlightExchange {
-        FlightExchange::Sender(sender)
+    pub fn create_sender(
+        sender: Sender<Result<FlightData, Status>>,
+        credits: Arc<FlightCredits>,
+    ) -> FlightExchange {
+        FlightExchange::Sender { sender, credits }
     }
 
     pub fn create_receiver(
@@ -269,7 +310,10 @@ impl FlightExchange {
 
     pub fn convert_to_sender(self) -> FlightSender {
         match self {
-            FlightExchange::Sender(tx) => FlightSender { tx },
+            FlightExchange::Sender { sender, credits } => FlightSender {
+                tx: sender,
+                credits,
+            },
             _ => unreachable!(),
         }
     }
@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_channel::Receiver;
+use databend_common_arrow::arrow_format::flight::data::FlightData;
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_metrics::cluster::metrics_dec_exchange_buffered_bytes;
+use databend_common_metrics::cluster::metrics_inc_exchange_buffered_bytes;
+use databend_common_metrics::cluster::metrics_observe_exchange_backpressure_wait_ms;
+use futures::Stream;
+use futures::StreamExt;
+use tonic::Status;
+
+pub fn flight_data_size(data: &FlightData) -> usize {
+    data.data_body.len() + data.data_header.len() + data.app_metadata.len()
+}
+
+/// The byte credits of a flight channel.
+///
+/// The sender takes the credits of a packet before sending it, and the credits are given
+/// back once the packet is taken by the remote stream. So a slow receiver makes the sender
+/// wait instead of buffering unbounded packets in memory.
+pub struct FlightCredits {
+    // 0 means no limit.
+    capacity: AtomicUsize,
+    buffered_bytes: AtomicUsize,
+    peak_buffered_bytes: AtomicUsize,
+    wait_milliseconds: AtomicU64,
+    closed: AtomicBool,
+    notify: Notify,
+}
+
+impl FlightCredits {
+    pub fn create() -> Arc<FlightCredits> {
+        Arc::new(FlightCredits {
+            capacity: AtomicUsize::new(0),
+            buffered_bytes: AtomicUsize::new(0),
+            peak_buffered_bytes: AtomicUsize::new(0),
+            wait_milliseconds: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+            notify: Notify::new(),
+        })
+    }
+
+    pub fn set_capacity(&self, capacity: usize) {
+        self.capacity.store(capacity, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn peak_buffered_bytes(&self) -> usize {
+        self.peak_buffered_bytes.load(Ordering::SeqCst)
+    }
+
+    pub fn wait_milliseconds(&self) -> u64 {
+        self.wait_milliseconds.load(Ordering::SeqCst)
+    }
+
+    /// Waits until there are enough credits for `bytes`. A packet larger than the capacity
+    /// is allowed once nothing is buffered, so it never waits forever.
+    #[async_backtrace::framed]
+    pub async fn acquire(&self, bytes: usize) {
+        let mut wait_start = None;
+        loop {
+            let notified = self.notify.notified();
+
+            let capacity = self.capacity.load(Ordering::SeqCst);
+            let buffered_bytes = self.buffered_bytes.load(Ordering::SeqCst);
+            if capacity == 0
+                || buffered_bytes == 0
+                || buffered_bytes + bytes <= capacity
+                || self.closed.load(Ordering::SeqCst)
+            {
+                if self
+                    .buffered_bytes
+                    .compare_exchange(
+                        buffered_bytes,
+                        buffered_bytes + bytes,
+                        Ordering::SeqCst,
+                        Ordering::SeqCst,
+                    )
+                    .is_err()
+                {
+                    continue;
+                }
+
+                self.peak_buffered_bytes
+                    .fetch_max(buffered_bytes + bytes, Ordering::SeqCst);
+                metrics_inc_exchange_buffered_bytes(bytes);
+
+                if let Some(wait_start) = wait_start {
+                    let elapsed = wait_start.elapsed().as_millis() as u64;
+                    self.wait_milliseconds.fetch_add(elapsed, Ordering::SeqCst);
+                    metrics_observe_exchange_backpressure_wait_ms(elapsed as f64);
+                }
+                return;
+            }
+
+            wait_start.get_or_insert_with(Instant::now);
+            notified.await;
+        }
+    }
+
+    pub fn release(&self, bytes: usize) {
+        self.buffered_bytes.fetch_sub(bytes, Ordering::SeqCst);
+        metrics_dec_exchange_buffered_bytes(bytes);
+        self.notify.notify_waiters();
+    }
+
+    /// The remote stream is gone, the waiting sender should go on and find the closed channel.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    /// Returns the stream of the receiver that gives back the credits of the taken packets.
+    pub fn release_on_recv(
+        self: &Arc<Self>,
+        rx: Receiver<Result<FlightData, Status>>,
+    ) -> impl Stream<Item = Result<FlightData, Status>> + Send + 'static {
+        let guard = FlightCreditsGuard(self.clone());
+        rx.map(move |data| {
+            if let Ok(data) = &data {
+                guard.0.release(flight_data_size(data));
+            }
+            data
+        })
+    }
+}
+
+impl Drop for FlightCredits {
+    fn drop(&mut self) {
+        metrics_dec_exchange_buffered_bytes(*self.buffered_bytes.get_mut());
+    }
+}
+
+struct FlightCreditsGuard(Arc<FlightCredits>);
+
+impl Drop for FlightCreditsGuard {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
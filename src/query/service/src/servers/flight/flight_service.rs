@@ -14,22 +14,31 @@
 
 use std::future::Future;
 use std::net::SocketAddr;
+use std::pin::Pin;
 use std::sync::Arc;
 
 use databend_common_arrow::arrow_format::flight::service::flight_service_server::FlightServiceServer;
 use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::net::TcpListener;
+use databend_common_base::base::tokio::net::TcpStream;
 use databend_common_base::base::tokio::sync::Notify;
+use databend_common_config::GlobalConfig;
 use databend_common_config::InnerConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use futures::Stream;
+use log::error;
 use log::info;
+use log::warn;
 use tonic::transport::server::TcpIncoming;
+use tonic::transport::Certificate;
 use tonic::transport::Identity;
 use tonic::transport::Server;
 use tonic::transport::ServerTlsConfig;
 
 use super::v1::DatabendQueryFlightService;
 use crate::servers::Server as DatabendQueryServer;
+use crate::servers::TlsReloader;
 
 pub struct FlightService {
     pub config: InnerConfig,
@@ -56,42 +65,145 @@ impl FlightService {
         let cert = tokio::fs::read(conf.query.rpc_tls_server_cert.as_str()).await?;
         let key = tokio::fs::read(conf.query.rpc_tls_server_key.as_str()).await?;
         let server_identity = Identity::from_pem(cert, key);
-        let tls_conf = ServerTlsConfig::new().identity(server_identity);
+        let mut tls_conf = ServerTlsConfig::new().identity(server_identity);
+        if !conf.query.rpc_tls_server_root_ca_cert.is_empty() {
+            // Mutual TLS, the other nodes must present a certificate signed by the CA.
+            let root_ca_cert =
+                tokio::fs::read(conf.query.rpc_tls_server_root_ca_cert.as_str()).await?;
+            tls_conf = tls_conf.client_ca_root(Certificate::from_pem(root_ca_cert));
+        }
         Ok(tls_conf)
     }
 
+    fn server_builder(tls_conf: Option<ServerTlsConfig>) -> Result<Server> {
+        match tls_conf {
+            None => Ok(Server::builder()),
+            Some(tls_conf) => Server::builder().tls_config(tls_conf).map_err(|e| {
+                ErrorCode::TLSConfigurationFailure(format!("failed to invoke tls_config: {e}",))
+            }),
+        }
+    }
+
+    fn flight_service() -> FlightServiceServer<DatabendQueryFlightService> {
+        FlightServiceServer::new(DatabendQueryFlightService::create())
+            .max_encoding_message_size(usize::MAX)
+            .max_decoding_message_size(usize::MAX)
+    }
+
     #[async_backtrace::framed]
     pub async fn start_with_incoming(&mut self, addr: SocketAddr) -> Result<()> {
-        let flight_api_service = DatabendQueryFlightService::create();
-        let builder = Server::builder();
-        let mut builder = if self.config.tls_rpc_server_enabled() {
-            info!("databend query tls rpc enabled");
-            builder
-                .tls_config(Self::server_tls_config(&self.config).await.map_err(|e| {
-                    ErrorCode::TLSConfigurationFailure(format!(
-                        "failed to load server tls config: {e}",
-                    ))
-                })?)
-                .map_err(|e| {
-                    ErrorCode::TLSConfigurationFailure(format!("failed to invoke tls_config: {e}",))
-                })?
-        } else {
-            builder
-        };
-
-        let incoming = TcpIncoming::new(addr, true, None)
+        if !self.config.tls_rpc_server_enabled() {
+            let incoming = TcpIncoming::new(addr, true, None)
+                .map_err(|e| ErrorCode::CannotListenerPort(format!("{e}")))?;
+            let server = Self::server_builder(None)?
+                .add_service(Self::flight_service())
+                .serve_with_incoming_shutdown(incoming, self.shutdown_notify());
+
+            databend_common_base::runtime::spawn(server);
+            return Ok(());
+        }
+
+        info!("databend query tls rpc enabled");
+        let tls_conf = Self::server_tls_config(&self.config).await.map_err(|e| {
+            ErrorCode::TLSConfigurationFailure(format!("failed to load server tls config: {e}",))
+        })?;
+        // Check the config before listening.
+        Self::server_builder(Some(tls_conf.clone()))?;
+
+        let listener = TcpListener::bind(addr)
+            .await
             .map_err(|e| ErrorCode::CannotListenerPort(format!("{e}")))?;
-        let server = builder
-            .add_service(
-                FlightServiceServer::new(flight_api_service)
-                    .max_encoding_message_size(usize::MAX)
-                    .max_decoding_message_size(usize::MAX),
-            )
-            .serve_with_incoming_shutdown(incoming, self.shutdown_notify());
-
-        databend_common_base::runtime::spawn(server);
+        databend_common_base::runtime::spawn(Self::serve_with_tls_reload(
+            Arc::new(listener),
+            tls_conf,
+            self.abort_notify.clone(),
+        ));
         Ok(())
     }
+
+    /// Serves on the listener with a server built from the TLS config. When the certificates
+    /// are reloaded, a new server built from the new certificates takes over the listener,
+    /// and the old one stops accepting but keeps serving its connections until they close.
+    #[async_backtrace::framed]
+    async fn serve_with_tls_reload(
+        listener: Arc<TcpListener>,
+        mut tls_conf: ServerTlsConfig,
+        abort_notify: Arc<Notify>,
+    ) {
+        let mut reload = TlsReloader::instance().subscribe();
+        loop {
+            let server = match Self::server_builder(Some(tls_conf.clone())) {
+                Ok(server) => server,
+                Err(cause) => {
+                    error!("Failed to build the flight server, cause: {:?}", cause);
+                    return;
+                }
+            };
+
+            let stop = Arc::new(Notify::new());
+            let shutdown = {
+                let stop = stop.clone();
+                let abort_notify = abort_notify.clone();
+                async move {
+                    tokio::select! {
+                        _ = stop.notified() => {},
+                        _ = abort_notify.notified() => {},
+                    }
+                }
+            };
+            let server = server
+                .add_service(Self::flight_service())
+                .serve_with_incoming_shutdown(Self::incoming(listener.clone()), shutdown);
+            databend_common_base::runtime::spawn(server);
+
+            // Wait for valid new certificates.
+            loop {
+                if reload.changed().await.is_err() {
+                    return;
+                }
+                let new_tls_conf = match Self::server_tls_config(GlobalConfig::instance().as_ref())
+                    .await
+                {
+                    Ok(new_tls_conf) => new_tls_conf,
+                    Err(cause) => {
+                        error!(
+                                "Failed to reload flight TLS certificates, keep the old ones, cause: {:?}",
+                                cause
+                            );
+                        continue;
+                    }
+                };
+                if let Err(cause) = Self::server_builder(Some(new_tls_conf.clone())) {
+                    error!(
+                        "Failed to reload flight TLS certificates, keep the old ones, cause: {:?}",
+                        cause
+                    );
+                    continue;
+                }
+                tls_conf = new_tls_conf;
+                break;
+            }
+
+            info!("Flight TLS certificates reloaded");
+            stop.notify_one();
+        }
+    }
+
+    fn incoming(
+        listener: Arc<TcpListener>,
+    ) -> Pin<Box<dyn Stream<Item = std::io::Result<TcpStream>> + Send>> {
+        Box::pin(async_stream::stream! {
+            loop {
+                let accepted = listener.accept().await.map(|(stream, _)| {
+                    if let Err(e) = stream.set_nodelay(true) {
+                        warn!("failed to set socket option nodelay {}", e);
+                    }
+                    stream
+                });
+                yield accepted;
+            }
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod flight_client;
+mod flight_credits;
 mod flight_service;
 mod request_builder;
 pub mod v1;
@@ -21,4 +22,5 @@ pub use flight_client::FlightClient;
 pub use flight_client::FlightExchange;
 pub use flight_client::FlightReceiver;
 pub use flight_client::FlightSender;
+pub use flight_credits::FlightCredits;
 pub use flight_service::FlightService;
@@ -132,6 +132,35 @@ impl TryInto<Vec<u8>> for KillQuery {
     }
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct PullPartitions {
+    pub query_id: String,
+    pub plan_id: u32,
+    pub executor: String,
+    pub max_partitions: usize,
+}
+
+impl TryInto<PullPartitions> for Vec<u8> {
+    type Error = Status;
+
+    fn try_into(self) -> Result<PullPartitions, Self::Error> {
+        match serde_json::from_slice::<PullPartitions>(&self) {
+            Err(cause) => Err(Status::invalid_argument(cause.to_string())),
+            Ok(action) => Ok(action),
+        }
+    }
+}
+
+impl TryInto<Vec<u8>> for PullPartitions {
+    type Error = ErrorCode;
+
+    fn try_into(self) -> Result<Vec<u8>, Self::Error> {
+        serde_json::to_vec(&self).map_err_to_code(ErrorCode::Internal, || {
+            "Logical error: cannot serialize PullPartitions."
+        })
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum FlightAction {
     InitQueryFragmentsPlan(InitQueryFragmentsPlan),
@@ -139,6 +168,7 @@ pub enum FlightAction {
     ExecutePartialQuery(String),
     TruncateTable(TruncateTable),
     KillQuery(KillQuery),
+    PullPartitions(PullPartitions),
 }
 
 impl TryInto<FlightAction> for Action {
@@ -158,6 +188,7 @@ impl TryInto<FlightAction> for Action {
             },
             "TruncateTable" => Ok(FlightAction::TruncateTable(self.body.try_into()?)),
             "KillQuery" => Ok(FlightAction::KillQuery(self.body.try_into()?)),
+            "PullPartitions" => Ok(FlightAction::PullPartitions(self.body.try_into()?)),
             un_implemented => Err(Status::unimplemented(format!(
                 "UnImplement action {}",
                 un_implemented
@@ -191,6 +222,10 @@ impl TryInto<Action> for FlightAction {
                 r#type: String::from("KillQuery"),
                 body: kill_query.try_into()?,
             }),
+            FlightAction::PullPartitions(pull_partitions) => Ok(Action {
+                r#type: String::from("PullPartitions"),
+                body: pull_partitions.try_into()?,
+            }),
         }
     }
 }
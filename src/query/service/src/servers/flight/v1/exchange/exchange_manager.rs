@@ -18,7 +18,6 @@ use std::collections::HashMap;
 use std::ops::Deref;
 use std::sync::Arc;
 
-use async_channel::Receiver;
 use databend_common_arrow::arrow_format::flight::data::FlightData;
 use databend_common_arrow::arrow_format::flight::service::flight_service_client::FlightServiceClient;
 use databend_common_base::base::GlobalInstance;
@@ -27,11 +26,13 @@ use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_base::runtime::Thread;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_grpc::ConnectionFactory;
 use databend_common_sql::executor::PhysicalPlan;
+use futures::Stream;
 use minitrace::prelude::*;
 use parking_lot::Mutex;
 use parking_lot::ReentrantMutex;
@@ -42,6 +43,7 @@ use super::exchange_params::MergeExchangeParams;
 use super::exchange_params::ShuffleExchangeParams;
 use super::exchange_sink::ExchangeSink;
 use super::exchange_transform::ExchangeTransform;
+use super::partitions_queue::PartitionsQueue;
 use super::statistics_receiver::StatisticsReceiver;
 use super::statistics_sender::StatisticsSender;
 use crate::pipelines::executor::ExecutorSettings;
@@ -50,6 +52,7 @@ use crate::pipelines::PipelineBuildResult;
 use crate::pipelines::PipelineBuilder;
 use crate::schedulers::QueryFragmentActions;
 use crate::schedulers::QueryFragmentsActions;
+use crate::servers::flight::v1::exchange::serde::BlockFormatOptions;
 use crate::servers::flight::v1::exchange::DataExchange;
 use crate::servers::flight::v1::exchange::DefaultExchangeInjector;
 use crate::servers::flight::v1::exchange::ExchangeInjector;
@@ -58,6 +61,7 @@ use crate::servers::flight::v1::packets::InitNodesChannelPacket;
 use crate::servers::flight::v1::packets::Packet;
 use crate::servers::flight::v1::packets::QueryFragmentsPlanPacket;
 use crate::servers::flight::FlightClient;
+use crate::servers::flight::FlightCredits;
 use crate::servers::flight::FlightExchange;
 use crate::servers::flight::FlightReceiver;
 use crate::servers::flight::FlightSender;
@@ -97,6 +101,12 @@ impl DataExchangeManager {
         )))
     }
 
+    pub fn get_queries_count(&self) -> usize {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &*queries_coordinator_guard.deref().get() };
+        queries_coordinator.len()
+    }
+
     pub fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
@@ -239,7 +249,7 @@ impl DataExchangeManager {
         &self,
         id: String,
         target: String,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+    ) -> Result<impl Stream<Item = Result<FlightData, Status>> + Send + 'static> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
 
@@ -257,7 +267,7 @@ impl DataExchangeManager {
         query: String,
         target: String,
         fragment: usize,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+    ) -> Result<impl Stream<Item = Result<FlightData, Status>> + Send + 'static> {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
 
@@ -269,6 +279,47 @@ impl DataExchangeManager {
         }
     }
 
+    fn add_partitions_queues(
+        &self,
+        query_id: &str,
+        partitions_queues: HashMap<u32, Arc<PartitionsQueue>>,
+    ) {
+        if partitions_queues.is_empty() {
+            return;
+        }
+
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        queries_coordinator
+            .entry(query_id.to_string())
+            .or_insert_with(QueryCoordinator::create)
+            .partitions_queues
+            .extend(partitions_queues);
+    }
+
+    pub fn pull_partitions(
+        &self,
+        query_id: &str,
+        plan_id: u32,
+        executor: &str,
+        max_partitions: usize,
+    ) -> Result<Vec<PartInfoPtr>> {
+        let queries_coordinator_guard = self.queries_coordinator.lock();
+        let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
+
+        match queries_coordinator
+            .get(query_id)
+            .and_then(|coordinator| coordinator.partitions_queues.get(&plan_id))
+        {
+            Some(queue) => Ok(queue.pull(executor, max_partitions)),
+            None => Err(ErrorCode::Internal(format!(
+                "Partitions queue of plan {} not found in query {}.",
+                plan_id, query_id
+            ))),
+        }
+    }
+
     pub fn shutdown_query(&self, query_id: &str) {
         let queries_coordinator_guard = self.queries_coordinator.lock();
         let queries_coordinator = unsafe { &mut *queries_coordinator_guard.deref().get() };
@@ -310,6 +361,9 @@ impl DataExchangeManager {
             .commit(conf.as_ref(), timeout)
             .await?;
 
+        // The partitions queues must be ready before the executors pull from them.
+        self.add_partitions_queues(&ctx.get_id(), actions.get_partitions_queues());
+
         // Submit distributed tasks to all nodes.
         let (local_query_fragments_plan_packet, query_fragments_plan_packets) =
             actions.get_query_fragments_plan_packets()?;
@@ -443,6 +497,7 @@ struct QueryCoordinator {
 
     statistics_exchanges: HashMap<String, FlightExchange>,
     fragment_exchanges: HashMap<(String, usize, u8), FlightExchange>,
+    partitions_queues: HashMap<u32, Arc<PartitionsQueue>>,
 }
 
 impl QueryCoordinator {
@@ -452,19 +507,21 @@ impl QueryCoordinator {
             fragments_coordinator: HashMap::new(),
             fragment_exchanges: HashMap::new(),
             statistics_exchanges: HashMap::new(),
+            partitions_queues: HashMap::new(),
         }
     }
 
     pub fn add_statistics_exchange(
         &mut self,
         target: String,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+    ) -> Result<impl Stream<Item = Result<FlightData, Status>> + Send + 'static> {
         let (tx, rx) = async_channel::bounded(8);
+        let credits = FlightCredits::create();
         match self
             .statistics_exchanges
-            .insert(target, FlightExchange::create_sender(tx))
+            .insert(target, FlightExchange::create_sender(tx, credits.clone()))
         {
-            None => Ok(rx),
+            None => Ok(credits.release_on_recv(rx)),
             Some(_) => Err(ErrorCode::Internal(
                 "statistics exchanges can only have one",
             )),
@@ -490,13 +547,14 @@ impl QueryCoordinator {
         &mut self,
         target: String,
         fragment: usize,
-    ) -> Result<Receiver<Result<FlightData, Status>>> {
+    ) -> Result<impl Stream<Item = Result<FlightData, Status>> + Send + 'static> {
         let (tx, rx) = async_channel::bounded(8);
+        let credits = FlightCredits::create();
         self.fragment_exchanges.insert(
             (target, fragment, FLIGHT_SENDER),
-            FlightExchange::create_sender(tx),
+            FlightExchange::create_sender(tx, credits.clone()),
         );
-        Ok(rx)
+        Ok(credits.release_on_recv(rx))
     }
 
     pub fn add_fragment_exchanges(
@@ -512,6 +570,20 @@ impl QueryCoordinator {
     }
 
     pub fn get_flight_senders(&mut self, params: &ExchangeParams) -> Result<Vec<FlightSender>> {
+        let senders = self.take_flight_senders(params)?;
+
+        if let Some(info) = &self.info {
+            let settings = info.query_ctx.get_settings();
+            let buffer_size = settings.get_exchange_buffer_size_bytes()?;
+            for sender in &senders {
+                sender.credits().set_capacity(buffer_size);
+            }
+        }
+
+        Ok(senders)
+    }
+
+    fn take_flight_senders(&mut self, params: &ExchangeParams) -> Result<Vec<FlightSender>> {
         match params {
             ExchangeParams::MergeExchange(params) => Ok(self
                 .fragment_exchanges
@@ -785,6 +857,7 @@ impl FragmentCoordinator {
         exchange_injector: Arc<dyn ExchangeInjector>,
     ) -> Result<ExchangeParams> {
         if let Some(data_exchange) = &self.data_exchange {
+            let block_format = BlockFormatOptions::try_create(&info.query_ctx.get_settings())?;
             return match data_exchange {
                 DataExchange::Merge(exchange) => {
                     Ok(ExchangeParams::MergeExchange(MergeExchangeParams {
@@ -795,6 +868,7 @@ impl FragmentCoordinator {
                         destination_id: exchange.destination_id.clone(),
                         allow_adjust_parallelism: exchange.allow_adjust_parallelism,
                         ignore_exchange: exchange.ignore_exchange,
+                        block_format,
                     }))
                 }
                 DataExchange::Broadcast(exchange) => {
@@ -807,6 +881,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        block_format,
                     }))
                 }
                 DataExchange::ShuffleDataExchange(exchange) => {
@@ -819,6 +894,7 @@ impl FragmentCoordinator {
                         destination_ids: exchange.destination_ids.to_owned(),
                         shuffle_scatter: exchange_injector
                             .flight_scatter(&info.query_ctx, data_exchange)?,
+                        block_format,
                     }))
                 }
             };
@@ -18,6 +18,7 @@ use databend_common_arrow::arrow::io::ipc::write::WriteOptions;
 use databend_common_arrow::arrow::io::ipc::IpcField;
 use databend_common_expression::DataSchemaRef;
 
+use crate::servers::flight::v1::exchange::serde::BlockFormatOptions;
 use crate::servers::flight::v1::exchange::ExchangeInjector;
 use crate::servers::flight::v1::scatter::FlightScatter;
 
@@ -37,6 +38,7 @@ pub struct ShuffleExchangeParams {
     pub destination_ids: Vec<String>,
     pub shuffle_scatter: Arc<Box<dyn FlightScatter>>,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    pub block_format: BlockFormatOptions,
 }
 
 #[derive(Clone)]
@@ -48,6 +50,7 @@ pub struct MergeExchangeParams {
     pub ignore_exchange: bool,
     pub allow_adjust_parallelism: bool,
     pub exchange_injector: Arc<dyn ExchangeInjector>,
+    pub block_format: BlockFormatOptions,
 }
 
 pub enum ExchangeParams {
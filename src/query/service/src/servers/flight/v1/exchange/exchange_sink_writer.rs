@@ -107,12 +107,19 @@ impl AsyncSink for ExchangeWriterSink {
             source: String,
             destination: String,
             fragment: usize,
+            buffered_bytes: usize,
+            peak_buffered_bytes: usize,
+            wait_milliseconds: u64,
         }
 
+        let credits = self.flight_sender.credits();
         Some(format!("{:?}", Display {
             source: self.source.clone(),
             destination: self.destination.clone(),
             fragment: self.fragment,
+            buffered_bytes: credits.buffered_bytes(),
+            peak_buffered_bytes: credits.peak_buffered_bytes(),
+            wait_milliseconds: credits.wait_milliseconds(),
         }))
     }
 }
@@ -24,6 +24,7 @@ mod exchange_source_reader;
 mod exchange_transform;
 mod exchange_transform_scatter;
 mod exchange_transform_shuffle;
+mod partitions_queue;
 mod statistics_receiver;
 mod statistics_sender;
 
@@ -40,3 +41,5 @@ pub use exchange_params::MergeExchangeParams;
 pub use exchange_params::ShuffleExchangeParams;
 pub use exchange_sorting::ExchangeSorting;
 pub use exchange_transform_shuffle::ExchangeShuffleMeta;
+pub use partitions_queue::PartitionsQueue;
+pub use partitions_queue::RemotePartitions;
@@ -0,0 +1,164 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use log::info;
+use parking_lot::Mutex;
+
+use crate::servers::flight::v1::actions::PullPartitions;
+use crate::servers::flight::v1::exchange::DataExchangeManager;
+use crate::sessions::QueryContext;
+
+/// The partitions of a distributed source kept by the coordinator.
+///
+/// The partitions are assigned to the executors at first, an executor pulls its own
+/// partitions in batches and steals the partitions of the others once its own are drained.
+pub struct PartitionsQueue {
+    queues: Mutex<HashMap<String, VecDeque<PartInfoPtr>>>,
+}
+
+impl PartitionsQueue {
+    pub fn create(executors: Vec<String>, partitions: &Partitions) -> Result<Arc<PartitionsQueue>> {
+        let queues = partitions
+            .reshuffle(executors)?
+            .into_iter()
+            .map(|(executor, parts)| (executor, VecDeque::from(parts.partitions)))
+            .collect();
+
+        Ok(Arc::new(PartitionsQueue {
+            queues: Mutex::new(queues),
+        }))
+    }
+
+    pub fn pull(&self, executor: &str, max_partitions: usize) -> Vec<PartInfoPtr> {
+        let mut queues = self.queues.lock();
+        let mut parts = Vec::with_capacity(max_partitions);
+
+        if let Some(queue) = queues.get_mut(executor) {
+            while parts.len() < max_partitions {
+                match queue.pop_front() {
+                    None => break,
+                    Some(part) => parts.push(part),
+                }
+            }
+        }
+
+        while parts.len() < max_partitions {
+            // Steal from the tail of the longest queue, the head is taken by its owner soon.
+            let Some(queue) = queues.values_mut().max_by_key(|queue| queue.len()) else {
+                break;
+            };
+
+            match queue.pop_back() {
+                None => break,
+                Some(part) => parts.push(part),
+            }
+        }
+
+        parts
+    }
+}
+
+/// Pulls the partitions of a distributed source from the coordinator.
+pub struct RemotePartitions {
+    query_id: String,
+    plan_id: u32,
+    executor: String,
+    coordinator: String,
+    coordinator_address: String,
+    batch_size: usize,
+    timeout: u64,
+    drained: AtomicBool,
+}
+
+impl RemotePartitions {
+    pub fn try_create(
+        ctx: &Arc<QueryContext>,
+        plan_id: u32,
+        coordinator: &str,
+        batch_size: usize,
+    ) -> Result<Arc<RemotePartitions>> {
+        let cluster = ctx.get_cluster();
+        let coordinator_address = match cluster.nodes.iter().find(|x| x.id == coordinator) {
+            Some(node) => node.flight_address.clone(),
+            None => {
+                return Err(ErrorCode::NotFoundClusterNode(format!(
+                    "Not found the coordinator node {} in cluster",
+                    coordinator
+                )));
+            }
+        };
+
+        Ok(Arc::new(RemotePartitions {
+            query_id: ctx.get_id(),
+            plan_id,
+            executor: cluster.local_id.clone(),
+            coordinator: coordinator.to_string(),
+            coordinator_address,
+            batch_size: batch_size.max(1),
+            timeout: ctx.get_settings().get_flight_client_timeout()?,
+            drained: AtomicBool::new(false),
+        }))
+    }
+
+    /// Returns an empty batch once the partitions of the coordinator are drained.
+    #[async_backtrace::framed]
+    pub async fn pull(&self) -> Result<Vec<PartInfoPtr>> {
+        if self.drained.load(Ordering::SeqCst) {
+            return Ok(vec![]);
+        }
+
+        let parts = match self.executor == self.coordinator {
+            true => DataExchangeManager::instance().pull_partitions(
+                &self.query_id,
+                self.plan_id,
+                &self.executor,
+                self.batch_size,
+            )?,
+            false => {
+                let mut client =
+                    DataExchangeManager::create_client(&self.coordinator_address, true).await?;
+                let pull_partitions = PullPartitions {
+                    query_id: self.query_id.clone(),
+                    plan_id: self.plan_id,
+                    executor: self.executor.clone(),
+                    max_partitions: self.batch_size,
+                };
+                client
+                    .pull_partitions(pull_partitions, self.timeout)
+                    .await?
+            }
+        };
+
+        if parts.is_empty() {
+            info!(
+                "Partitions of plan {} in query {} are drained",
+                self.plan_id, self.query_id
+            );
+            self.drained.store(true, Ordering::SeqCst);
+        }
+
+        Ok(parts)
+    }
+}
@@ -0,0 +1,299 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The versioned block format of data exchange.
+//!
+//! Version 0 is the arrow IPC format sent as [`DataPacket::FragmentData`].
+//!
+//! Version 1 is sent as [`DataPacket::BlockData`]:
+//!
+//! ```text
+//! meta: | version: u8 | schema fingerprint: u64 | num rows: u32 | block meta (bincode) |
+//! body: | column 0 | column 1 | ...
+//!
+//! column: | encoding: u8 | part 0 | part 1 | ...
+//! part:   | compression: u8 | uncompressed len: u64 | compressed len: u64 | bytes |
+//! ```
+//!
+//! A plain column has one part serialized by [`serialize_column`], a dictionary encoded
+//! string column has two parts, the values and the keys.
+//!
+//! A node can read the blocks of all the versions up to `CURRENT_BLOCK_FORMAT_VERSION`, so
+//! the nodes of adjacent versions can exchange data during rolling upgrades as long as the
+//! writers use the older version, which is controlled by the `exchange_block_format_version`
+//! setting.
+
+use databend_common_arrow::arrow_format::flight::data::FlightData;
+use databend_common_arrow::native::compression::CommonCompression;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::string::StringDictionaryColumn;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::utils::arrow::deserialize_column;
+use databend_common_expression::utils::arrow::serialize_column;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Value;
+use databend_common_io::prelude::bincode_deserialize_from_slice;
+use databend_common_io::prelude::bincode_serialize_into_buf;
+use databend_common_io::prelude::BinaryRead;
+use databend_common_io::prelude::BinaryWrite;
+use databend_common_settings::FlightCompression;
+use databend_common_settings::Settings;
+use naive_cityhash::cityhash128;
+
+use crate::servers::flight::v1::exchange::serde::ExchangeSerializeMeta;
+use crate::servers::flight::v1::packets::DataPacket;
+use crate::servers::flight::v1::packets::FragmentData;
+
+pub const CURRENT_BLOCK_FORMAT_VERSION: u8 = 1;
+
+const COLUMN_ENCODING_PLAIN: u8 = 0;
+const COLUMN_ENCODING_DICTIONARY: u8 = 1;
+
+const PART_COMPRESSION_NONE: u8 = 0;
+const PART_COMPRESSION_LZ4: u8 = 1;
+const PART_COMPRESSION_ZSTD: u8 = 2;
+
+/// The block format options of the exchanges of a query.
+#[derive(Clone, Copy, Debug)]
+pub struct BlockFormatOptions {
+    pub version: u8,
+    pub preserve_dictionary: bool,
+}
+
+impl BlockFormatOptions {
+    pub fn try_create(settings: &Settings) -> Result<Self> {
+        Ok(BlockFormatOptions {
+            version: settings.get_exchange_block_format_version()?,
+            preserve_dictionary: settings.get_exchange_preserve_dictionary()?,
+        })
+    }
+}
+
+/// The fingerprint of the data types of the schema, the receiver checks it to make sure
+/// the block is written with the same schema.
+pub fn schema_fingerprint(schema: &DataSchema) -> u64 {
+    let mut bytes = vec![];
+    for field in schema.fields() {
+        bytes.extend_from_slice(field.data_type().to_string().as_bytes());
+        bytes.push(0);
+    }
+    cityhash128(&bytes).lo
+}
+
+/// Writes the blocks in the block format version 1.
+#[derive(Clone)]
+pub struct BlockFormatWriter {
+    fingerprint: u64,
+    compression: CommonCompression,
+    preserve_dictionary: bool,
+}
+
+impl BlockFormatWriter {
+    /// Returns `None` if the blocks should be written in the version 0.
+    pub fn try_create(
+        schema: &DataSchema,
+        options: BlockFormatOptions,
+        compression: Option<FlightCompression>,
+    ) -> Option<Self> {
+        if options.version == 0 {
+            return None;
+        }
+
+        let compression = match compression {
+            None => CommonCompression::None,
+            Some(FlightCompression::Lz4) => CommonCompression::Lz4,
+            Some(FlightCompression::Zstd) => CommonCompression::Zstd,
+        };
+
+        Some(BlockFormatWriter {
+            fingerprint: schema_fingerprint(schema),
+            compression,
+            preserve_dictionary: options.preserve_dictionary,
+        })
+    }
+
+    pub fn serialize(&self, block_num: isize, data_block: DataBlock) -> Result<DataBlock> {
+        if data_block.is_empty() && data_block.get_meta().is_none() {
+            return Ok(DataBlock::empty_with_meta(ExchangeSerializeMeta::create(
+                block_num,
+                vec![],
+            )));
+        }
+
+        let mut meta = vec![];
+        meta.write_scalar_own(CURRENT_BLOCK_FORMAT_VERSION)?;
+        meta.write_scalar_own(self.fingerprint)?;
+        meta.write_scalar_own(data_block.num_rows() as u32)?;
+        bincode_serialize_into_buf(&mut meta, &data_block.get_meta())
+            .map_err(|_| ErrorCode::BadBytes("block meta serialize error when exchange"))?;
+
+        let num_rows = data_block.num_rows();
+        let mut body = vec![];
+        for entry in data_block.columns() {
+            match entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+            {
+                Column::StringDictionary(column) if self.preserve_dictionary => {
+                    body.write_scalar_own(COLUMN_ENCODING_DICTIONARY)?;
+                    let values = Column::String(column.values().clone());
+                    let keys = Column::Number(NumberColumn::UInt32(column.keys().clone()));
+                    self.write_part(&mut body, &serialize_column(&values))?;
+                    self.write_part(&mut body, &serialize_column(&keys))?;
+                }
+                column => {
+                    body.write_scalar_own(COLUMN_ENCODING_PLAIN)?;
+                    self.write_part(&mut body, &serialize_column(&column))?;
+                }
+            }
+        }
+
+        let data = FlightData {
+            app_metadata: vec![],
+            flight_descriptor: None,
+            data_header: vec![],
+            data_body: body,
+        };
+        let packet = DataPacket::BlockData(FragmentData::create(meta, data));
+        Ok(DataBlock::empty_with_meta(ExchangeSerializeMeta::create(
+            block_num,
+            vec![packet],
+        )))
+    }
+
+    fn write_part(&self, buf: &mut Vec<u8>, bytes: &[u8]) -> Result<()> {
+        let mut compressed = Vec::new();
+        let compression = match self.compression {
+            CommonCompression::None => CommonCompression::None,
+            compression => {
+                compression.compress(bytes, &mut compressed)?;
+                // Keep the part uncompressed if the compression doesn't help.
+                match compressed.len() < bytes.len() {
+                    true => compression,
+                    false => CommonCompression::None,
+                }
+            }
+        };
+
+        let (flag, data) = match compression {
+            CommonCompression::Lz4 => (PART_COMPRESSION_LZ4, compressed.as_slice()),
+            CommonCompression::Zstd => (PART_COMPRESSION_ZSTD, compressed.as_slice()),
+            _ => (PART_COMPRESSION_NONE, bytes),
+        };
+        buf.write_scalar_own(flag)?;
+        buf.write_scalar_own(bytes.len() as u64)?;
+        buf.write_scalar_own(data.len() as u64)?;
+        buf.extend_from_slice(data);
+        Ok(())
+    }
+}
+
+/// Reads the block written by [`BlockFormatWriter`].
+pub fn deserialize_block(
+    schema: &DataSchema,
+    fingerprint: u64,
+    block_data: &FragmentData,
+) -> Result<DataBlock> {
+    let mut meta = block_data.get_meta();
+    let version: u8 = meta.read_scalar()?;
+    if version == 0 || version > CURRENT_BLOCK_FORMAT_VERSION {
+        return Err(ErrorCode::BadBytes(format!(
+            "Unsupported exchange block format version {}, the max supported version is {}",
+            version, CURRENT_BLOCK_FORMAT_VERSION
+        )));
+    }
+
+    let block_fingerprint: u64 = meta.read_scalar()?;
+    if block_fingerprint != fingerprint {
+        return Err(ErrorCode::BadBytes(
+            "The schema of the exchange block doesn't match the schema of the receiver",
+        ));
+    }
+
+    let num_rows: u32 = meta.read_scalar()?;
+    let block_meta = bincode_deserialize_from_slice(meta)
+        .map_err(|_| ErrorCode::BadBytes("block meta deserialize error when exchange"))?;
+
+    let mut body = block_data.data.data_body.as_slice();
+    let mut columns = Vec::with_capacity(schema.num_fields());
+    if num_rows != 0 {
+        for field in schema.fields() {
+            let encoding: u8 = body.read_scalar()?;
+            let column = match encoding {
+                COLUMN_ENCODING_PLAIN => deserialize_column(&read_part(&mut body)?)?,
+                COLUMN_ENCODING_DICTIONARY => {
+                    let values = deserialize_column(&read_part(&mut body)?)?;
+                    let keys = deserialize_column(&read_part(&mut body)?)?;
+                    match (values, keys) {
+                        (Column::String(values), Column::Number(NumberColumn::UInt32(keys))) => {
+                            Column::StringDictionary(StringDictionaryColumn::try_new(values, keys)?)
+                        }
+                        _ => {
+                            return Err(ErrorCode::BadBytes(
+                                "Invalid dictionary column in exchange block",
+                            ));
+                        }
+                    }
+                }
+                _ => {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Unknown column encoding {} in exchange block",
+                        encoding
+                    )));
+                }
+            };
+            columns.push(BlockEntry::new(
+                field.data_type().clone(),
+                Value::Column(column),
+            ));
+        }
+    }
+
+    Ok(DataBlock::new_with_meta(
+        columns,
+        num_rows as usize,
+        block_meta,
+    ))
+}
+
+fn read_part(body: &mut &[u8]) -> Result<Vec<u8>> {
+    let compression: u8 = body.read_scalar()?;
+    let uncompressed_len: u64 = body.read_scalar()?;
+    let compressed_len: u64 = body.read_scalar()?;
+    let compressed_len = compressed_len as usize;
+    if body.len() < compressed_len {
+        return Err(ErrorCode::BadBytes("Unexpected end of exchange block"));
+    }
+    let (data, rest) = body.split_at(compressed_len);
+    *body = rest;
+
+    let compression = match compression {
+        PART_COMPRESSION_NONE => return Ok(data.to_vec()),
+        PART_COMPRESSION_LZ4 => CommonCompression::Lz4,
+        PART_COMPRESSION_ZSTD => CommonCompression::Zstd,
+        _ => {
+            return Err(ErrorCode::BadBytes(format!(
+                "Unknown compression {} in exchange block",
+                compression
+            )));
+        }
+    };
+    let mut bytes = vec![0; uncompressed_len as usize];
+    compression.decompress(data, &mut bytes)?;
+    Ok(bytes)
+}
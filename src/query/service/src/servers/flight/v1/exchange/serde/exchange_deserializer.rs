@@ -39,6 +39,8 @@ use databend_common_pipeline_transforms::processors::UnknownMode;
 use serde::Deserializer;
 use serde::Serializer;
 
+use super::block_format::deserialize_block;
+use super::block_format::schema_fingerprint;
 use crate::servers::flight::v1::packets::DataPacket;
 use crate::servers::flight::v1::packets::FragmentData;
 
@@ -46,6 +48,7 @@ pub struct TransformExchangeDeserializer {
     schema: DataSchemaRef,
     ipc_schema: IpcSchema,
     arrow_schema: Arc<ArrowSchema>,
+    schema_fingerprint: u64,
 }
 
 impl TransformExchangeDeserializer {
@@ -67,6 +70,7 @@ impl TransformExchangeDeserializer {
             TransformExchangeDeserializer {
                 ipc_schema,
                 arrow_schema: Arc::new(arrow_schema),
+                schema_fingerprint: schema_fingerprint(schema),
                 schema: schema.clone(),
             },
         ))
@@ -128,6 +132,9 @@ impl BlockMetaTransform<ExchangeDeserializeMeta> for TransformExchangeDeserializ
             DataPacket::QueryProfiles(_) => unreachable!(),
             DataPacket::DataCacheMetrics(_) => unreachable!(),
             DataPacket::FragmentData(v) => self.recv_data(meta.packet, v),
+            DataPacket::BlockData(v) => {
+                deserialize_block(&self.schema, self.schema_fingerprint, &v)
+            }
         }
     }
 }
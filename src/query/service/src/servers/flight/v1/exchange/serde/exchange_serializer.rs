@@ -44,6 +44,7 @@ use databend_common_settings::FlightCompression;
 use serde::Deserializer;
 use serde::Serializer;
 
+use crate::servers::flight::v1::exchange::serde::BlockFormatWriter;
 use crate::servers::flight::v1::exchange::ExchangeShuffleMeta;
 use crate::servers::flight::v1::exchange::MergeExchangeParams;
 use crate::servers::flight::v1::exchange::ShuffleExchangeParams;
@@ -98,6 +99,7 @@ impl BlockMetaInfo for ExchangeSerializeMeta {
 pub struct TransformExchangeSerializer {
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
+    writer: Option<BlockFormatWriter>,
 }
 
 impl TransformExchangeSerializer {
@@ -109,6 +111,8 @@ impl TransformExchangeSerializer {
     ) -> Result<ProcessorPtr> {
         let arrow_schema = ArrowSchema::from(params.schema.as_ref());
         let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let writer =
+            BlockFormatWriter::try_create(&params.schema, params.block_format, compression);
         let compression = match compression {
             None => None,
             Some(compression) => match compression {
@@ -123,6 +127,7 @@ impl TransformExchangeSerializer {
             TransformExchangeSerializer {
                 ipc_fields,
                 options: WriteOptions { compression },
+                writer,
             },
         )))
     }
@@ -133,7 +138,10 @@ impl Transform for TransformExchangeSerializer {
 
     fn transform(&mut self, data_block: DataBlock) -> Result<DataBlock> {
         Profile::record_usize_profile(ProfileStatisticsName::ExchangeRows, data_block.num_rows());
-        serialize_block(0, data_block, &self.ipc_fields, &self.options)
+        match &self.writer {
+            Some(writer) => writer.serialize(0, data_block),
+            None => serialize_block(0, data_block, &self.ipc_fields, &self.options),
+        }
     }
 }
 
@@ -141,6 +149,7 @@ pub struct TransformScatterExchangeSerializer {
     local_pos: usize,
     options: WriteOptions,
     ipc_fields: Vec<IpcField>,
+    writer: Option<BlockFormatWriter>,
 }
 
 impl TransformScatterExchangeSerializer {
@@ -153,6 +162,8 @@ impl TransformScatterExchangeSerializer {
         let local_id = &params.executor_id;
         let arrow_schema = ArrowSchema::from(params.schema.as_ref());
         let ipc_fields = default_ipc_fields(&arrow_schema.fields);
+        let writer =
+            BlockFormatWriter::try_create(&params.schema, params.block_format, compression);
         let compression = match compression {
             None => None,
             Some(compression) => match compression {
@@ -167,6 +178,7 @@ impl TransformScatterExchangeSerializer {
             TransformScatterExchangeSerializer {
                 ipc_fields,
                 options: WriteOptions { compression },
+                writer,
                 local_pos: params
                     .destination_ids
                     .iter()
@@ -191,7 +203,10 @@ impl BlockMetaTransform<ExchangeShuffleMeta> for TransformScatterExchangeSeriali
 
             new_blocks.push(match self.local_pos == index {
                 true => block,
-                false => serialize_block(0, block, &self.ipc_fields, &self.options)?,
+                false => match &self.writer {
+                    Some(writer) => writer.serialize(0, block)?,
+                    None => serialize_block(0, block, &self.ipc_fields, &self.options)?,
+                },
             });
         }
 
@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod block_format;
 mod exchange_deserializer;
 mod exchange_serializer;
 
+pub use block_format::BlockFormatOptions;
+pub use block_format::BlockFormatWriter;
 pub use exchange_deserializer::ExchangeDeserializeMeta;
 pub use exchange_deserializer::TransformExchangeDeserializer;
 pub use exchange_serializer::serialize_block;
@@ -175,6 +175,7 @@ impl FlightService for DatabendQueryFlightService {
                     let ctx = session.create_query_context().await?;
                     // Keep query id
                     ctx.set_id(init_query_fragments_plan.executor_packet.query_id.clone());
+                    ctx.set_spill_key(init_query_fragments_plan.executor_packet.spill_key);
                     ctx.attach_query_str(
                         init_query_fragments_plan.executor_packet.query_kind,
                         "".to_string(),
@@ -250,6 +251,19 @@ impl FlightService for DatabendQueryFlightService {
                     interpreter.execute2().await?;
                     FlightResult { body: vec![] }
                 }
+                FlightAction::PullPartitions(pull_partitions) => {
+                    let partitions = DataExchangeManager::instance().pull_partitions(
+                        &pull_partitions.query_id,
+                        pull_partitions.plan_id,
+                        &pull_partitions.executor,
+                        pull_partitions.max_partitions,
+                    )?;
+
+                    match serde_json::to_vec(&partitions) {
+                        Ok(body) => FlightResult { body },
+                        Err(cause) => return Err(Status::internal(cause.to_string())),
+                    }
+                }
             };
 
             Ok(RawResponse::new(
@@ -55,6 +55,8 @@ pub enum DataPacket {
     ErrorCode(ErrorCode),
     Dictionary(FlightData),
     FragmentData(FragmentData),
+    /// A block in the versioned exchange block format, see `exchange::serde::block_format`.
+    BlockData(FragmentData),
     QueryProfiles(Vec<PlanProfile>),
     SerializeProgress(Vec<ProgressInfo>),
     CopyStatus(CopyStatus),
@@ -75,6 +77,7 @@ impl DataPacket {
             DataPacket::SerializeProgress(_) => 0,
             DataPacket::Dictionary(v) => calc_size(v),
             DataPacket::FragmentData(v) => calc_size(&v.data) + v.meta.len(),
+            DataPacket::BlockData(v) => calc_size(&v.data) + v.meta.len(),
             DataPacket::QueryProfiles(_) => 0,
             DataPacket::DataCacheMetrics(_) => 0,
         }
@@ -91,6 +94,15 @@ impl TryFrom<DataPacket> for FlightData {
                 FlightData::from(error)
             }
             DataPacket::FragmentData(fragment_data) => FlightData::from(fragment_data),
+            DataPacket::BlockData(mut block_data) => {
+                block_data.meta.push(0x09);
+                FlightData {
+                    app_metadata: block_data.meta,
+                    data_body: block_data.data.data_body,
+                    data_header: vec![],
+                    flight_descriptor: None,
+                }
+            }
             DataPacket::QueryProfiles(profiles) => FlightData {
                 app_metadata: vec![0x03],
                 data_body: serde_json::to_vec(&profiles)?,
@@ -194,6 +206,7 @@ impl TryFrom<FlightData> for DataPacket {
                     serde_json::from_slice::<DataCacheMetricValues>(&flight_data.data_body)?;
                 Ok(DataPacket::DataCacheMetrics(status))
             }
+            0x09 => Ok(DataPacket::BlockData(FragmentData::try_from(flight_data)?)),
             _ => Err(ErrorCode::BadBytes("Unknown flight data packet type.")),
         }
     }
@@ -28,6 +28,7 @@ use crate::servers::flight::v1::actions::InitQueryFragmentsPlan;
 use crate::servers::flight::v1::packets::packet::create_client;
 use crate::servers::flight::v1::packets::FragmentPlanPacket;
 use crate::servers::flight::v1::packets::Packet;
+use crate::spillers::SpillKey;
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct QueryFragmentsPlanPacket {
@@ -37,6 +38,8 @@ pub struct QueryFragmentsPlanPacket {
     pub request_executor: String,
     pub fragments: Vec<FragmentPlanPacket>,
     pub changed_settings: Arc<DashMap<String, ChangeValue>>,
+    /// The key to encrypt the spilled data, the nodes read the data spilled by each other.
+    pub spill_key: SpillKey,
     // We send nodes info for each node. This is a bad choice
     pub executors_info: HashMap<String, Arc<NodeInfo>>,
 }
@@ -50,6 +53,7 @@ impl QueryFragmentsPlanPacket {
         fragments: Vec<FragmentPlanPacket>,
         executors_info: HashMap<String, Arc<NodeInfo>>,
         changed_settings: Arc<DashMap<String, ChangeValue>>,
+        spill_key: SpillKey,
         request_executor: String,
     ) -> QueryFragmentsPlanPacket {
         QueryFragmentsPlanPacket {
@@ -59,6 +63,7 @@ impl QueryFragmentsPlanPacket {
             fragments,
             executors_info,
             changed_settings,
+            spill_key,
             request_executor,
         }
     }
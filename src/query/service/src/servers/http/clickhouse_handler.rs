@@ -337,9 +337,13 @@ pub async fn clickhouse_handler_post(
         // other parts of the request already logged in middleware
         let len = sql.len();
         let msg = if len > n {
-            format!("{}...(omit {} bytes)", short_sql(sql.clone()), len - n)
+            format!(
+                "{}...(omit {} bytes)",
+                short_sql(session.redact_query_text(&sql)),
+                len - n
+            )
         } else {
-            sql.to_string()
+            session.redact_query_text(&sql)
         };
         info!("receive clickhouse http post, (query + body) = {}", &msg);
 
@@ -22,6 +22,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_http::HttpError;
 use databend_common_http::HttpShutdownHandler;
 use databend_common_meta_types::anyerror::AnyError;
+use futures::StreamExt;
+use log::error;
 use log::info;
 use poem::get;
 use poem::listener::RustlsCertificate;
@@ -38,11 +40,13 @@ use super::v1::upload_to_stage;
 use crate::auth::AuthMgr;
 use crate::servers::http::middleware::HTTPSessionMiddleware;
 use crate::servers::http::middleware::PanicHandler;
+use crate::servers::http::v1::async_query_route;
 use crate::servers::http::v1::clickhouse_router;
 use crate::servers::http::v1::list_suggestions;
 use crate::servers::http::v1::query_route;
 use crate::servers::http::v1::streaming_load;
 use crate::servers::Server;
+use crate::servers::TlsReloader;
 
 #[derive(Copy, Clone)]
 pub enum HttpHandlerKind {
@@ -96,6 +100,7 @@ impl HttpHandler {
     async fn build_router(&self, sock: SocketAddr) -> impl Endpoint {
         let ep_v1 = Route::new()
             .nest("/query", query_route())
+            .nest("/async_query", async_query_route())
             .at("/streaming_load", put(streaming_load))
             .at("/upload_to_stage", put(upload_to_stage))
             .at("/suggested_background_tasks", get(list_suggestions));
@@ -153,11 +158,29 @@ impl HttpHandler {
         let tls_config = Self::build_tls(config.as_ref())
             .map_err(|e: std::io::Error| HttpError::TlsConfigError(AnyError::new(&e)))?;
 
+        // Build the TLS config again from the certificate files on each reload.
+        let mut reload = TlsReloader::instance().subscribe();
+        let conf_stream = async_stream::stream! {
+            yield tls_config;
+            while reload.changed().await.is_ok() {
+                match HttpHandler::build_tls(GlobalConfig::instance().as_ref()) {
+                    Ok(tls_config) => {
+                        info!("Http Handler TLS certificates reloaded");
+                        yield tls_config;
+                    }
+                    Err(cause) => error!(
+                        "Failed to reload Http Handler TLS certificates, keep the old ones, cause: {:?}",
+                        cause
+                    ),
+                }
+            }
+        };
+
         let router = self.build_router(listening).await;
         self.shutdown_handler
-            .start_service(
+            .start_service_with_tls_stream(
                 listening,
-                Some(tls_config),
+                Some(conf_stream.boxed()),
                 router,
                 Some(Duration::from_millis(1000)),
             )
@@ -38,7 +38,6 @@ use opentelemetry::baggage::BaggageExt;
 use opentelemetry::propagation::TextMapPropagator;
 use opentelemetry_http::HeaderExtractor;
 use opentelemetry_sdk::propagation::BaggagePropagator;
-use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::http::StatusCode;
 use poem::Addr;
@@ -219,7 +218,7 @@ pub struct HTTPSessionEndpoint<E> {
 
 impl<E> HTTPSessionEndpoint<E> {
     #[async_backtrace::framed]
-    async fn auth(&self, req: &Request) -> Result<HttpQueryContext> {
+    async fn auth(&self, req: &Request, query_id: String) -> Result<HttpQueryContext> {
         let credential = get_credential(req, self.kind)?;
         let session_manager = SessionManager::instance();
         let session = session_manager.create_session(SessionType::Dummy).await?;
@@ -245,12 +244,6 @@ impl<E> HTTPSessionEndpoint<E> {
             .get(USER_AGENT)
             .map(|id| id.to_str().unwrap().to_string());
 
-        let query_id = req
-            .headers()
-            .get(QUERY_ID)
-            .map(|id| id.to_str().unwrap().to_string())
-            .unwrap_or_else(|| Uuid::new_v4().to_string());
-
         let trace_parent = req
             .headers()
             .get(TRACE_PARENT)
@@ -280,45 +273,57 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
         let uri = req.uri().clone();
         let headers = req.headers().clone();
 
-        let res = match self.auth(&req).await {
+        // The query id is assigned at admission, so the requests rejected before running
+        // can still be correlated with the server side logs.
+        let query_id = req
+            .headers()
+            .get(QUERY_ID)
+            .map(|id| id.to_str().unwrap().to_string())
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        let res = match self.auth(&req, query_id.clone()).await {
             Ok(ctx) => {
                 req.extensions_mut().insert(ctx);
                 self.ep.call(req).await
             }
-            Err(err) => match err.code() {
-                ErrorCode::AUTHENTICATE_FAILURE => {
-                    warn!(
-                        "http auth failure: {method} {uri}, headers={:?}, error={}",
-                        sanitize_request_headers(&headers),
-                        err
-                    );
-                    Err(PoemError::from_string(
-                        err.message(),
-                        StatusCode::UNAUTHORIZED,
-                    ))
-                }
-                _ => {
-                    error!(
-                        "http request err: {method} {uri}, headers={:?}, error={}",
-                        sanitize_request_headers(&headers),
-                        err
-                    );
-                    Err(PoemError::from_string(
-                        err.message(),
-                        StatusCode::INTERNAL_SERVER_ERROR,
-                    ))
-                }
-            },
+            Err(err) => {
+                let status = match err.code() {
+                    ErrorCode::AUTHENTICATE_FAILURE => {
+                        warn!(
+                            "{query_id}: http auth failure: {method} {uri}, headers={:?}, error={}",
+                            sanitize_request_headers(&headers),
+                            err
+                        );
+                        StatusCode::UNAUTHORIZED
+                    }
+                    ErrorCode::TOO_MANY_USER_CONNECTIONS => {
+                        warn!(
+                            "{query_id}: http request rejected: {method} {uri}, error={}",
+                            err
+                        );
+                        StatusCode::TOO_MANY_REQUESTS
+                    }
+                    _ => {
+                        error!(
+                            "{query_id}: http request err: {method} {uri}, headers={:?}, error={}",
+                            sanitize_request_headers(&headers),
+                            err
+                        );
+                        StatusCode::INTERNAL_SERVER_ERROR
+                    }
+                };
+                // The request is rejected before reaching the handler, return the query id
+                // assigned at admission to the client.
+                let body = error_body(status, &err.message(), Some(&query_id));
+                return Ok(Response::builder()
+                    .status(status)
+                    .header(QUERY_ID, query_id)
+                    .body(body));
+            }
         };
         match res {
             Err(err) => {
-                let body = Body::from_json(serde_json::json!({
-                    "error": {
-                        "code": err.status().as_str(),
-                        "message": err.to_string(),
-                    }
-                }))
-                .unwrap();
+                let body = error_body(err.status(), &err.to_string(), None);
                 Ok(Response::builder().status(err.status()).body(body))
             }
             Ok(res) => Ok(res.into_response()),
@@ -326,6 +331,17 @@ impl<E: Endpoint> Endpoint for HTTPSessionEndpoint<E> {
     }
 }
 
+fn error_body(status: StatusCode, message: &str, query_id: Option<&str>) -> Body {
+    let mut error = serde_json::json!({
+        "code": status.as_str(),
+        "message": message,
+    });
+    if let Some(query_id) = query_id {
+        error["query_id"] = query_id.into();
+    }
+    Body::from_json(serde_json::json!({ "error": error })).unwrap()
+}
+
 pub fn sanitize_request_headers(headers: &HeaderMap) -> HashMap<String, String> {
     let sensitive_headers = ["authorization", "x-clickhouse-key", "cookie"];
     headers
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_base::base::mask_connection_info;
 use databend_common_base::runtime::drop_guard;
 use databend_common_exception::ErrorCode;
@@ -26,6 +28,7 @@ use minitrace::prelude::*;
 use poem::error::Error as PoemError;
 use poem::error::Result as PoemResult;
 use poem::get;
+use poem::http::HeaderValue;
 use poem::http::StatusCode;
 use poem::post;
 use poem::web::Json;
@@ -38,9 +41,12 @@ use serde::Serialize;
 use serde_json::Value as JsonValue;
 
 use super::query::ExecuteStateKind;
+use super::query::HttpQuery;
 use super::query::HttpQueryRequest;
 use super::query::HttpQueryResponseInternal;
 use super::query::RemoveReason;
+use super::query::ResponseState;
+use super::query::ResultFile;
 use crate::servers::http::middleware::MetricsMiddleware;
 use crate::servers::http::v1::query::Progresses;
 use crate::servers::http::v1::HttpQueryContext;
@@ -52,6 +58,7 @@ use crate::sessions::QueryAffect;
 const HEADER_QUERY_ID: &str = "X-DATABEND-QUERY-ID";
 const HEADER_QUERY_STATE: &str = "X-DATABEND-QUERY-STATE";
 const HEADER_QUERY_PAGE_ROWS: &str = "X-DATABEND-QUERY-PAGE-ROWS";
+const HEADER_ROUTE_HINT: &str = "X-DATABEND-ROUTE-HINT";
 
 pub fn make_page_uri(query_id: &str, page_no: usize) -> String {
     format!("/v1/query/{}/page/{}", query_id, page_no)
@@ -69,19 +76,40 @@ pub fn make_kill_uri(query_id: &str) -> String {
     format!("/v1/query/{}/kill", query_id)
 }
 
+pub fn make_async_state_uri(query_id: &str) -> String {
+    format!("/v1/async_query/{}", query_id)
+}
+
+pub fn make_async_page_uri(query_id: &str, page_no: usize) -> String {
+    format!("/v1/async_query/{}/page/{}", query_id, page_no)
+}
+
+pub fn make_async_cancel_uri(query_id: &str) -> String {
+    format!("/v1/async_query/{}/cancel", query_id)
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueryError {
     pub code: u16,
     pub message: String,
     pub detail: String,
+    pub sqlstate: String,
+    /// The class of the error, for example: not_found, conflict.
+    pub category: String,
+    /// Whether the query may succeed if it's retried as is.
+    pub retryable: bool,
 }
 
 impl QueryError {
     pub(crate) fn from_error_code(e: &ErrorCode) -> Self {
+        let category = e.category();
         QueryError {
             code: e.code(),
             message: e.display_text(),
             detail: e.detail(),
+            sqlstate: e.sqlstate().to_string(),
+            category: category.to_string(),
+            retryable: category.is_retryable(),
         }
     }
 }
@@ -131,6 +159,9 @@ pub struct QueryResponse {
     pub final_uri: Option<String>,
     pub next_uri: Option<String>,
     pub kill_uri: Option<String>,
+    // the result is spooled to these files instead of returned in `data`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_files: Option<Vec<ResultFile>>,
 }
 
 impl QueryResponse {
@@ -183,8 +214,9 @@ impl QueryResponse {
             ExecuteStateKind::Starting => ExecuteStateKind::Running,
             _ => state.state,
         };
+        let route_hint = r.session.as_ref().map(|s| s.route_hint(&r.node_id));
 
-        Json(QueryResponse {
+        let mut response = Json(QueryResponse {
             data: data.into(),
             state: state_kind,
             schema: state.schema.clone(),
@@ -200,13 +232,103 @@ impl QueryResponse {
             final_uri: Some(make_final_uri(&id)),
             kill_uri: Some(make_kill_uri(&id)),
             error: r.state.error.as_ref().map(QueryError::from_error_code),
+            result_files: r.state.result_files,
         })
         .with_header(HEADER_QUERY_ID, id.clone())
         .with_header(HEADER_QUERY_STATE, state.state.to_string())
         .with_header(HEADER_QUERY_PAGE_ROWS, rows)
+        .into_response();
+        if let Some(value) = route_hint.and_then(|v| HeaderValue::from_str(&v).ok()) {
+            response.headers_mut().insert(HEADER_ROUTE_HINT, value);
+        }
+        response
+    }
+}
+
+/// The response of the async query endpoints, it carries the state of the query only,
+/// the result is fetched by page with `page_uri` once the query is succeeded.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AsyncQueryResponse {
+    pub id: String,
+    pub node_id: String,
+    pub schema: Vec<QueryResponseField>,
+    pub state: ExecuteStateKind,
+    pub error: Option<QueryError>,
+    pub stats: QueryStats,
+    pub affect: Option<QueryAffect>,
+    pub warnings: Vec<String>,
+    pub num_pages: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_files: Option<Vec<ResultFile>>,
+    pub state_uri: Option<String>,
+    pub page_uri: Option<String>,
+    pub cancel_uri: Option<String>,
+}
+
+impl AsyncQueryResponse {
+    pub(crate) fn from_state(
+        id: String,
+        node_id: String,
+        state: ResponseState,
+        num_pages: usize,
+    ) -> Self {
+        if let Some(err) = &state.error {
+            metrics_incr_http_response_errors_count(err.name(), err.code());
+        }
+
+        let page_uri = (state.state == ExecuteStateKind::Succeeded && num_pages > 0)
+            .then(|| make_async_page_uri(&id, 0));
+        AsyncQueryResponse {
+            node_id,
+            schema: state.schema,
+            state: match state.state {
+                ExecuteStateKind::Starting => ExecuteStateKind::Running,
+                _ => state.state,
+            },
+            error: state.error.as_ref().map(QueryError::from_error_code),
+            stats: QueryStats {
+                progresses: state.progresses,
+                running_time_ms: state.running_time_ms,
+            },
+            affect: state.affect,
+            warnings: state.warnings,
+            num_pages,
+            result_files: state.result_files,
+            state_uri: Some(make_async_state_uri(&id)),
+            page_uri,
+            cancel_uri: Some(make_async_cancel_uri(&id)),
+            id,
+        }
+    }
+
+    pub(crate) fn fail_to_start(query_id: &str, err: &ErrorCode) -> Self {
+        metrics_incr_http_response_errors_count(err.name(), err.code());
+        AsyncQueryResponse {
+            id: query_id.to_string(),
+            node_id: "".to_string(),
+            schema: vec![],
+            state: ExecuteStateKind::Failed,
+            error: Some(QueryError::from_error_code(err)),
+            stats: QueryStats::default(),
+            affect: None,
+            warnings: vec![],
+            num_pages: 0,
+            result_files: None,
+            state_uri: None,
+            page_uri: None,
+            cancel_uri: None,
+        }
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AsyncQueryPage {
+    pub id: String,
+    pub page_no: usize,
+    pub data: Vec<Vec<JsonValue>>,
+    pub next_uri: Option<String>,
+}
+
 /// final is not ACKed by client, so client should not depend on the final response,
 ///
 /// for server:
@@ -321,6 +443,13 @@ async fn query_page_handler(
             Some(query) => {
                 if let Some(reason) = query.check_removed() {
                     Err(query_id_removed(&query_id, reason))
+                } else if query.is_async {
+                    Err(PoemError::from_string(
+                        format!(
+                            "query id {query_id} is async, fetch its pages from /v1/async_query"
+                        ),
+                        StatusCode::BAD_REQUEST,
+                    ))
                 } else {
                     query.update_expire_time(true).await;
                     let resp = query.get_response_page(page_no).await.map_err(|err| {
@@ -347,12 +476,14 @@ pub(crate) async fn query_handler(
     let _t = SlowRequestLogTracker::new(ctx);
 
     async {
-        info!("http query new request: {:}", mask_connection_info(&format!("{:?}", req)));
+        let mut logged_req = req.clone();
+        logged_req.sql = ctx.redact_query_text(&req.sql);
+        info!("http query new request: {:}", mask_connection_info(&format!("{:?}", logged_req)));
         let http_query_manager = HttpQueryManager::instance();
         let sql = req.sql.clone();
 
         let query = http_query_manager
-            .try_create_query(ctx, req.clone())
+            .try_create_query(ctx, req.clone(), false)
             .await
             .map_err(|err| err.display_with_sql(&sql));
         match query {
@@ -372,7 +503,7 @@ pub(crate) async fn query_handler(
                     Some(p) => (p.page.data.num_rows(), p.next_page_no),
                 };
                 info!( "http query initial response to http query_id={}, state={:?}, rows={}, next_page={:?}, sql='{}'",
-                        &query.id, &resp.state, rows, next_page, mask_connection_info(&sql)
+                        &query.id, &resp.state, rows, next_page, mask_connection_info(&ctx.redact_query_text(&sql))
                     );
                 query.update_expire_time(false).await;
                 Ok(QueryResponse::from_internal(query.id.to_string(), resp, false).into_response())
@@ -380,7 +511,10 @@ pub(crate) async fn query_handler(
             Err(e) => {
                 error!("{}: http query fail to start sql, error: {:?}", &ctx.query_id, e);
                 ctx.set_fail();
-                Ok(req.fail_to_start_sql(&e).into_response())
+                Ok(req
+                    .fail_to_start_sql(&ctx.query_id, &e)
+                    .with_header(HEADER_QUERY_ID, ctx.query_id.clone())
+                    .into_response())
             }
         }
     }
@@ -388,6 +522,133 @@ pub(crate) async fn query_handler(
     .await
 }
 
+#[poem::handler]
+#[async_backtrace::framed]
+async fn async_query_handler(
+    ctx: &HttpQueryContext,
+    Json(req): Json<HttpQueryRequest>,
+) -> PoemResult<impl IntoResponse> {
+    let root = get_http_tracing_span(full_name!(), ctx, &ctx.query_id);
+    let _t = SlowRequestLogTracker::new(ctx);
+
+    async {
+        info!(
+            "http async query new request: {:}",
+            mask_connection_info(&format!("{:?}", req))
+        );
+        let http_query_manager = HttpQueryManager::instance();
+        let sql = req.sql.clone();
+
+        let query = http_query_manager
+            .try_create_query(ctx, req, true)
+            .await
+            .map_err(|err| err.display_with_sql(&sql));
+        match query {
+            Ok(query) => {
+                query.update_expire_time(false).await;
+                let (state, num_pages) = query.get_async_state().await;
+                info!(
+                    "http async query submitted, query_id={}, state={:?}, sql='{}'",
+                    &query.id,
+                    &state,
+                    mask_connection_info(&sql)
+                );
+                Ok(Json(AsyncQueryResponse::from_state(
+                    query.id.clone(),
+                    query.node_id.clone(),
+                    state,
+                    num_pages,
+                )))
+            }
+            Err(e) => {
+                error!(
+                    "{}: http async query fail to start sql, error: {:?}",
+                    &ctx.query_id, e
+                );
+                ctx.set_fail();
+                Ok(Json(AsyncQueryResponse::fail_to_start(&ctx.query_id, &e)))
+            }
+        }
+    }
+    .in_span(root)
+    .await
+}
+
+#[poem::handler]
+async fn async_query_state_handler(
+    ctx: &HttpQueryContext,
+    Path(query_id): Path<String>,
+) -> PoemResult<impl IntoResponse> {
+    let root = get_http_tracing_span(full_name!(), ctx, &query_id);
+
+    async {
+        let query = get_async_query(&query_id, &ctx.node_id)?;
+        query.update_expire_time(false).await;
+        let (state, num_pages) = query.get_async_state().await;
+        Ok(Json(AsyncQueryResponse::from_state(
+            query_id,
+            query.node_id.clone(),
+            state,
+            num_pages,
+        )))
+    }
+    .in_span(root)
+    .await
+}
+
+#[poem::handler]
+async fn async_query_page_handler(
+    ctx: &HttpQueryContext,
+    Path((query_id, page_no)): Path<(String, usize)>,
+) -> PoemResult<impl IntoResponse> {
+    let root = get_http_tracing_span(full_name!(), ctx, &query_id);
+    let _t = SlowRequestLogTracker::new(ctx);
+
+    async {
+        let query = get_async_query(&query_id, &ctx.node_id)?;
+        query.update_expire_time(false).await;
+        let data = query
+            .get_async_page(page_no)
+            .map_err(|err| poem::Error::from_string(err.message(), StatusCode::NOT_FOUND))?;
+        Ok(Json(AsyncQueryPage {
+            next_uri: data.next_page_no.map(|n| make_async_page_uri(&query_id, n)),
+            id: query_id,
+            page_no,
+            data: data.page.data.into(),
+        }))
+    }
+    .in_span(root)
+    .await
+}
+
+fn get_async_query(query_id: &str, node_id: &str) -> PoemResult<Arc<HttpQuery>> {
+    let http_query_manager = HttpQueryManager::instance();
+    match http_query_manager.get_query(query_id) {
+        Some(query) if query.is_async => match query.check_removed() {
+            Some(reason) => Err(query_id_removed(query_id, reason)),
+            None => Ok(query),
+        },
+        _ => Err(query_id_not_found(query_id, node_id)),
+    }
+}
+
+pub fn async_query_route() -> Route {
+    // the async query is submitted and returns at once, the client polls its state and
+    // fetches its pages later, which is friendly to the clients with short request timeouts.
+    let rules = [
+        ("/", post(async_query_handler)),
+        ("/:id", get(async_query_state_handler)),
+        ("/:id/page/:page_no", get(async_query_page_handler)),
+        ("/:id/cancel", post(query_cancel_handler)),
+    ];
+
+    let mut route = Route::new();
+    for (path, endpoint) in rules.into_iter() {
+        route = route.at(path, endpoint.with(MetricsMiddleware::new(path)));
+    }
+    route
+}
+
 pub fn query_route() -> Route {
     // Note: endpoints except /v1/query may change without notice, use uris in response instead
     let rules = [
@@ -19,10 +19,16 @@ mod query;
 mod stage;
 mod suggestions;
 
+pub use http_query_handlers::async_query_route;
+pub use http_query_handlers::make_async_cancel_uri;
+pub use http_query_handlers::make_async_page_uri;
+pub use http_query_handlers::make_async_state_uri;
 pub use http_query_handlers::make_final_uri;
 pub use http_query_handlers::make_page_uri;
 pub use http_query_handlers::make_state_uri;
 pub use http_query_handlers::query_route;
+pub use http_query_handlers::AsyncQueryPage;
+pub use http_query_handlers::AsyncQueryResponse;
 pub use http_query_handlers::QueryError;
 pub use http_query_handlers::QueryResponse;
 pub use http_query_handlers::QueryStats;
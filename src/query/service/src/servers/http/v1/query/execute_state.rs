@@ -42,6 +42,10 @@ use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
 use crate::servers::http::v1::http_query_handlers::QueryResponseField;
 use crate::servers::http::v1::query::http_query::ResponseState;
+use crate::servers::http::v1::query::result_spooler::ResultFile;
+use crate::servers::http::v1::query::result_spooler::ResultSpooler;
+use crate::servers::http::v1::query::result_spooler::ResultSpoolingConf;
+use crate::servers::http::v1::query::result_spooler::SpoolResult;
 use crate::servers::http::v1::query::sized_spsc::SizedChannelSender;
 use crate::sessions::AcquireQueueGuard;
 use crate::sessions::QueriesQueueManager;
@@ -136,6 +140,8 @@ pub struct ExecuteStopped {
 pub struct Executor {
     pub query_id: String,
     pub state: ExecuteState,
+    // set if the result is spooled to the user stage instead of returned in the pages
+    pub result_files: Option<Vec<ResultFile>>,
 }
 
 // ExecutorSessionState is used to record the session state when the query is stopped.
@@ -173,6 +179,7 @@ impl Executor {
             warnings: self.get_warnings(),
             affect: self.get_affect(),
             schema: self.get_schema(),
+            result_files: self.result_files.clone(),
         }
     }
     pub fn get_schema(&self) -> Vec<QueryResponseField> {
@@ -238,6 +245,12 @@ impl Executor {
             guard.state = state
         }
     }
+    #[async_backtrace::framed]
+    pub async fn set_result_files(this: &Arc<RwLock<Executor>>, files: Vec<ResultFile>) {
+        let mut guard = this.write().await;
+        guard.result_files = Some(files);
+    }
+
     #[async_backtrace::framed]
     pub async fn stop(this: &Arc<RwLock<Executor>>, reason: Result<()>) {
         {
@@ -315,6 +328,8 @@ impl ExecuteState {
         ctx: Arc<QueryContext>,
         block_sender: SizedChannelSender<DataBlock>,
         format_settings: Arc<parking_lot::RwLock<Option<FormatSettings>>>,
+        result_spooling: Option<ResultSpoolingConf>,
+        describe_only: bool,
     ) -> Result<()> {
         info!("{}: http query prepare to plan sql", &ctx.get_id());
 
@@ -344,13 +359,29 @@ impl ExecuteState {
             query_queue_manager.length()
         );
 
-        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
         let running_state = ExecuteRunning {
             session,
             ctx: ctx.clone(),
             queue_guard,
             schema: QueryResponseField::from_schema(plan.schema()),
         };
+
+        if describe_only {
+            // The schema is kept in the stopped state, no data is returned.
+            info!(
+                "{}: http query only describes the result schema",
+                &ctx.get_id()
+            );
+            Executor::start_to_running(&executor, Running(running_state)).await;
+            block_sender
+                .send(DataBlock::empty_with_schema(plan.schema()), 0)
+                .await;
+            Executor::stop(&executor, Ok(())).await;
+            block_sender.close();
+            return Ok(());
+        }
+
+        let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
         info!("{}: http query change state to Running", &ctx.get_id());
         Executor::start_to_running(&executor, Running(running_state)).await;
 
@@ -364,6 +395,7 @@ impl ExecuteState {
             ctx_clone,
             block_sender,
             executor_clone.clone(),
+            result_spooling,
         );
         match CatchUnwindFuture::create(res).await {
             Ok(Err(err)) => {
@@ -387,6 +419,7 @@ async fn execute(
     ctx: Arc<QueryContext>,
     block_sender: SizedChannelSender<DataBlock>,
     executor: Arc<RwLock<Executor>>,
+    result_spooling: Option<ResultSpoolingConf>,
 ) -> Result<()> {
     let data_stream_res = interpreter.execute(ctx.clone()).await;
     if let Err(err) = data_stream_res {
@@ -417,13 +450,25 @@ async fn execute(
             block_sender.close();
         }
         Some(Ok(block)) => {
-            let size = block.num_rows();
-            block_sender.send(block, size).await;
+            let mut spooler = match result_spooling {
+                Some(conf) => Some(ResultSpooler::try_create(&ctx, schema, conf)?),
+                None => None,
+            };
+            match &mut spooler {
+                Some(spooler) => spooler.push(block).await?,
+                None => {
+                    let size = block.num_rows();
+                    block_sender.send(block, size).await;
+                }
+            }
             while let Some(block_r) = data_stream.next().await {
                 match block_r {
-                    Ok(block) => {
-                        block_sender.send(block.clone(), block.num_rows()).await;
-                    }
+                    Ok(block) => match &mut spooler {
+                        Some(spooler) => spooler.push(block).await?,
+                        None => {
+                            block_sender.send(block.clone(), block.num_rows()).await;
+                        }
+                    },
                     Err(err) => {
                         // duplicate codes, but there is an async call
                         let data = BlockEntry::new(
@@ -438,6 +483,19 @@ async fn execute(
                     }
                 };
             }
+            if let Some(spooler) = spooler {
+                match spooler.finish().await? {
+                    SpoolResult::Blocks(blocks) => {
+                        for block in blocks {
+                            let size = block.num_rows();
+                            block_sender.send(block, size).await;
+                        }
+                    }
+                    SpoolResult::Files(files) => {
+                        Executor::set_result_files(&executor, files).await;
+                    }
+                }
+            }
             Executor::stop(&executor, Ok(())).await;
             block_sender.close();
         }
@@ -24,6 +24,7 @@ use databend_common_base::base::tokio;
 use databend_common_base::base::tokio::sync::Mutex as TokioMutex;
 use databend_common_base::base::tokio::sync::RwLock;
 use databend_common_base::runtime::GlobalQueryRuntime;
+use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
@@ -32,6 +33,7 @@ use databend_common_io::prelude::FormatSettings;
 use databend_common_metrics::http::metrics_incr_http_response_errors_count;
 use databend_common_settings::ScopeLevel;
 use databend_storages_common_txn::TxnState;
+use highway::HighwayHash;
 use log::info;
 use log::warn;
 use minitrace::prelude::*;
@@ -55,6 +57,9 @@ use crate::servers::http::v1::query::ExecuteStateKind;
 use crate::servers::http::v1::query::Executor;
 use crate::servers::http::v1::query::PageManager;
 use crate::servers::http::v1::query::ResponseData;
+use crate::servers::http::v1::query::ResultFile;
+use crate::servers::http::v1::query::ResultPages;
+use crate::servers::http::v1::query::ResultSpoolingConf;
 use crate::servers::http::v1::query::Wait;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::servers::http::v1::QueryError;
@@ -80,10 +85,14 @@ pub struct HttpQueryRequest {
     #[serde(default = "default_as_true")]
     pub string_fields: bool,
     pub stage_attachment: Option<StageAttachmentConf>,
+    pub result_spooling: Option<ResultSpoolingConf>,
+    /// Only plan the sql and return the schema of its result, like `DESCRIBE <query>`.
+    #[serde(default)]
+    pub describe_only: bool,
 }
 
 impl HttpQueryRequest {
-    pub(crate) fn fail_to_start_sql(&self, err: &ErrorCode) -> impl IntoResponse {
+    pub(crate) fn fail_to_start_sql(&self, query_id: &str, err: &ErrorCode) -> impl IntoResponse {
         metrics_incr_http_response_errors_count(err.name(), err.code());
         let session = self.session.as_ref().map(|s| {
             let txn_state = if matches!(s.txn_state, Some(TxnState::Active)) {
@@ -97,7 +106,7 @@ impl HttpQueryRequest {
             }
         });
         Json(QueryResponse {
-            id: "".to_string(),
+            id: query_id.to_string(),
             stats: QueryStats::default(),
             state: ExecuteStateKind::Failed,
             affect: None,
@@ -112,6 +121,7 @@ impl HttpQueryRequest {
             final_uri: None,
             kill_uri: None,
             error: Some(QueryError::from_error_code(err)),
+            result_files: None,
         })
     }
 }
@@ -125,6 +135,8 @@ impl Debug for HttpQueryRequest {
             .field("pagination", &self.pagination)
             .field("string_fields", &self.string_fields)
             .field("stage_attachment", &self.stage_attachment)
+            .field("result_spooling", &self.result_spooling)
+            .field("describe_only", &self.describe_only)
             .finish()
     }
 }
@@ -204,7 +216,24 @@ pub struct HttpSessionConf {
     pub last_query_ids: Vec<String>,
 }
 
-impl HttpSessionConf {}
+impl HttpSessionConf {
+    /// The route hint of the session, in the form of `<node_id>.<state_version>`.
+    ///
+    /// A load balancer may route the following requests of the session to the same node by
+    /// the node id. The version is a digest of the session state, it changes once the state is
+    /// changed, for example by `USE db` or `SET x = y`.
+    pub fn route_hint(&self, node_id: &str) -> String {
+        let state = (
+            &self.database,
+            &self.role,
+            &self.secondary_roles,
+            &self.settings,
+        );
+        let bytes = serde_json::to_vec(&state).unwrap_or_default();
+        let version = highway::PortableHash::default().hash64(&bytes);
+        format!("{}.{:016x}", node_id, version)
+    }
+}
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct StageAttachmentConf {
@@ -224,6 +253,7 @@ pub struct ResponseState {
     pub affect: Option<QueryAffect>,
     pub error: Option<ErrorCode>,
     pub warnings: Vec<String>,
+    pub result_files: Option<Vec<ResultFile>>,
 }
 
 pub struct HttpQueryResponseInternal {
@@ -260,6 +290,10 @@ pub struct HttpQuery {
     /// exceed this result_timeout_secs.
     pub(crate) result_timeout_secs: u64,
     pub(crate) is_txn_mgr_saved: AtomicBool,
+    /// The async query is submitted without waiting for the result, its pages are collected
+    /// in the background and fetched later by the async query endpoints.
+    pub(crate) is_async: bool,
+    result_pages: Arc<parking_lot::RwLock<ResultPages>>,
 }
 
 fn try_set_txn(
@@ -320,14 +354,35 @@ impl HttpQuery {
     pub(crate) async fn try_create(
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
+        is_async: bool,
     ) -> Result<Arc<HttpQuery>> {
         let http_query_manager = HttpQueryManager::instance();
 
         // If session_id is specified, the new query will be attached in the same session.
+        let mut is_recovered_session = false;
         let session = if let Some(id) = &request.session_id {
-            let session = http_query_manager.get_session(id).await.ok_or_else(|| {
-                ErrorCode::UnknownSession(format!("unknown session-id {}, maybe expired", id))
-            })?;
+            let session = match http_query_manager.get_session(id).await {
+                Some(session) => session,
+                None if request.session.is_some() => {
+                    // The session may be lost after failover to another node or restart, recover
+                    // it from the session state carried by the request, as a new session.
+                    // Only the database, the roles and the settings are recovered, temporary
+                    // tables are not supported and are not part of the session state.
+                    info!(
+                        "{}: http query recover unknown session-id {} from the session state in request",
+                        &ctx.query_id, id
+                    );
+                    is_recovered_session = true;
+                    ctx.upgrade_session(SessionType::HTTPQuery)
+                        .map_err(|err| ErrorCode::Internal(format!("{err}")))?
+                }
+                None => {
+                    return Err(ErrorCode::UnknownSession(format!(
+                        "unknown session-id {}, maybe expired",
+                        id
+                    )));
+                }
+            };
             let mut n = 1;
             while let ExpiringState::InUse(query_id) = session.expire_state() {
                 if let Some(last_query) = &http_query_manager.get_query(&query_id) {
@@ -391,7 +446,7 @@ impl HttpQuery {
             try_set_txn(&ctx.query_id, &session, session_conf, &http_query_manager)?;
 
             if let Some(secs) = session_conf.keep_server_session_secs {
-                if secs > 0 && request.session_id.is_none() {
+                if secs > 0 && (request.session_id.is_none() || is_recovered_session) {
                     http_query_manager
                         .add_session(session.clone(), Duration::from_secs(secs))
                         .await;
@@ -400,7 +455,11 @@ impl HttpQuery {
         };
 
         let settings = session.get_settings();
-        let result_timeout_secs = settings.get_http_handler_result_timeout_secs()?;
+        let result_timeout_secs = if is_async {
+            settings.get_http_handler_async_result_timeout_secs()?
+        } else {
+            settings.get_http_handler_result_timeout_secs()?
+        };
         let deduplicate_label = &ctx.deduplicate_label;
         let user_agent = &ctx.user_agent;
         let query_id = ctx.query_id.clone();
@@ -423,7 +482,7 @@ impl HttpQuery {
 
         let session_id = session.get_id().clone();
         let node_id = ctx.get_cluster().local_id.clone();
-        let sql = &request.sql;
+        let sql = session.redact_query_text(&request.sql);
         info!(query_id = query_id, session_id = session_id, node_id = node_id, sql = sql; "create query");
 
         // Stage attachment is used to carry the data payload to the INSERT/REPLACE statements.
@@ -448,11 +507,14 @@ impl HttpQuery {
         let state = Arc::new(RwLock::new(Executor {
             query_id: query_id.clone(),
             state: ExecuteState::Starting(ExecuteStarting { ctx: ctx.clone() }),
+            result_files: None,
         }));
         let block_sender_closer = block_sender.closer();
         let state_clone = state.clone();
         let ctx_clone = ctx.clone();
         let sql = request.sql.clone();
+        let result_spooling = request.result_spooling.clone();
+        let describe_only = request.describe_only;
         let query_id_clone = query_id.clone();
 
         let http_query_runtime_instance = GlobalQueryRuntime::instance();
@@ -466,7 +528,7 @@ impl HttpQuery {
         let format_settings_clone = format_settings.clone();
         http_query_runtime_instance.runtime().try_spawn(
             ctx.get_id(),
-            async move {
+            ThreadTracker::tracking_query_future(&query_id, async move {
                 let state = state_clone.clone();
                 if let Err(e) = ExecuteState::try_start_query(
                     state,
@@ -475,6 +537,8 @@ impl HttpQuery {
                     ctx_clone.clone(),
                     block_sender,
                     format_settings_clone,
+                    result_spooling,
+                    describe_only,
                 )
                 .await
                 {
@@ -495,7 +559,7 @@ impl HttpQuery {
                         .await;
                     block_sender_closer.close();
                 }
-            }
+            })
             .in_span(span),
         )?;
 
@@ -516,9 +580,56 @@ impl HttpQuery {
             result_timeout_secs,
             expire_state: Arc::new(parking_lot::Mutex::new(ExpireState::Working)),
             is_txn_mgr_saved: AtomicBool::new(false),
+            is_async,
+            result_pages: Default::default(),
         };
 
-        Ok(Arc::new(query))
+        let query = Arc::new(query);
+        if is_async {
+            query.start_collecting_pages()?;
+        }
+        Ok(query)
+    }
+
+    // Collect the pages of the async query in the background, so the query is not blocked by
+    // the full buffer while the client is away.
+    fn start_collecting_pages(self: &Arc<Self>) -> Result<()> {
+        let query = Arc::downgrade(self);
+        let query_id = self.id.clone();
+        let page_manager = self.page_manager.clone();
+        let result_pages = self.result_pages.clone();
+        GlobalQueryRuntime::instance()
+            .runtime()
+            .try_spawn(self.id.clone(), async move {
+                loop {
+                    match query.upgrade() {
+                        Some(query) if query.check_removed().is_none() => {}
+                        _ => break,
+                    }
+
+                    let mut page_manager = page_manager.lock().await;
+                    let Some(page_no) = page_manager.next_page_no() else {
+                        result_pages.write().set_end();
+                        break;
+                    };
+                    let wait = Wait::Deadline(Instant::now() + Duration::from_secs(1));
+                    match page_manager.get_a_page(page_no, &wait).await {
+                        Ok(page) => {
+                            if page.data.num_rows() > 0 {
+                                result_pages.write().push(page);
+                            }
+                        }
+                        Err(e) => {
+                            warn!(
+                                "{}: http query fail to collect page {}: {:?}",
+                                &query_id, page_no, e
+                            );
+                            break;
+                        }
+                    }
+                }
+            })?;
+        Ok(())
     }
 
     #[async_backtrace::framed]
@@ -551,6 +662,21 @@ impl HttpQuery {
         }
     }
 
+    /// The async query is reported as running until all its pages are collected.
+    #[async_backtrace::framed]
+    pub async fn get_async_state(&self) -> (ResponseState, usize) {
+        let mut state = self.get_state().await;
+        let result_pages = self.result_pages.read();
+        if state.state == ExecuteStateKind::Succeeded && !result_pages.is_end() {
+            state.state = ExecuteStateKind::Running;
+        }
+        (state, result_pages.num_pages())
+    }
+
+    pub fn get_async_page(&self, page_no: usize) -> Result<ResponseData> {
+        self.result_pages.read().get_page(page_no)
+    }
+
     #[async_backtrace::framed]
     async fn get_state(&self) -> ResponseState {
         let state = self.state.read().await;
@@ -71,6 +71,10 @@ impl HttpQueryContext {
         Ok(self.session.clone())
     }
 
+    pub fn redact_query_text(&self, sql: &str) -> String {
+        self.session.redact_query_text(sql)
+    }
+
     pub fn to_minitrace_properties(&self) -> BTreeMap<String, String> {
         let mut result = BTreeMap::new();
         let properties = self.session.to_minitrace_properties();
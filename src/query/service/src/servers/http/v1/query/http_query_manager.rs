@@ -113,8 +113,9 @@ impl HttpQueryManager {
         self: &Arc<Self>,
         ctx: &HttpQueryContext,
         request: HttpQueryRequest,
+        is_async: bool,
     ) -> Result<Arc<HttpQuery>> {
-        let query = HttpQuery::try_create(ctx, request).await?;
+        let query = HttpQuery::try_create(ctx, request, is_async).await?;
         self.add_query(&query.id, query.clone()).await;
         Ok(query)
     }
@@ -19,6 +19,7 @@ mod http_query;
 mod http_query_context;
 mod http_query_manager;
 mod page_manager;
+mod result_spooler;
 pub mod sized_spsc;
 
 pub(crate) use execute_state::ExecuteState;
@@ -27,12 +28,17 @@ pub(crate) use execute_state::Executor;
 pub use execute_state::Progresses;
 pub use expirable::ExpiringState;
 pub use expiring_map::ExpiringMap;
+pub use http_query::HttpQuery;
 pub use http_query::HttpQueryRequest;
 pub use http_query::HttpQueryResponseInternal;
 pub use http_query::HttpSessionConf;
+pub use http_query::ResponseState;
 pub use http_query_context::HttpQueryContext;
 pub use http_query_manager::HttpQueryManager;
 pub(crate) use http_query_manager::RemoveReason;
 pub use page_manager::PageManager;
 pub use page_manager::ResponseData;
+pub use page_manager::ResultPages;
 pub use page_manager::Wait;
+pub use result_spooler::ResultFile;
+pub use result_spooler::ResultSpoolingConf;
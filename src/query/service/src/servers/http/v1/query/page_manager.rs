@@ -47,6 +47,52 @@ pub struct ResponseData {
     pub next_page_no: Option<usize>,
 }
 
+/// The pages of an async query. They are collected in the background once the query starts,
+/// and kept until the query is removed, so the client can fetch any of them later.
+#[derive(Default)]
+pub struct ResultPages {
+    pages: Vec<Page>,
+    end: bool,
+}
+
+impl ResultPages {
+    pub fn push(&mut self, page: Page) {
+        self.pages.push(page);
+    }
+
+    pub fn set_end(&mut self) {
+        self.end = true;
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.end
+    }
+
+    pub fn num_pages(&self) -> usize {
+        self.pages.len()
+    }
+
+    pub fn get_page(&self, page_no: usize) -> Result<ResponseData> {
+        match self.pages.get(page_no) {
+            Some(page) => {
+                let has_next = page_no + 1 < self.pages.len() || !self.end;
+                Ok(ResponseData {
+                    page: page.clone(),
+                    next_page_no: has_next.then_some(page_no + 1),
+                })
+            }
+            None if self.end => Err(ErrorCode::HttpNotFound(format!(
+                "wrong page number {}",
+                page_no
+            ))),
+            None => Err(ErrorCode::HttpNotFound(format!(
+                "page {} is not ready yet",
+                page_no
+            ))),
+        }
+    }
+}
+
 pub struct PageManager {
     query_id: String,
     max_rows_per_page: usize,
@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_exception::Result;
+use databend_common_expression::infer_table_schema;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_storages_stage::StageTable;
+use databend_storages_common_blocks::blocks_to_parquet;
+use databend_storages_common_table_meta::table::TableCompression;
+use log::info;
+use opendal::Operator;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// The directory in the user stage to keep the spooled results, one sub directory per query.
+const RESULT_SPOOL_DIR: &str = "_result";
+
+const DEFAULT_THRESHOLD_BYTES: usize = 64 * 1024 * 1024;
+const DEFAULT_MAX_FILE_BYTES: usize = 64 * 1024 * 1024;
+const DEFAULT_PRESIGN_EXPIRE_SECS: u64 = 3600;
+
+fn default_threshold_bytes() -> usize {
+    DEFAULT_THRESHOLD_BYTES
+}
+
+fn default_max_file_bytes() -> usize {
+    DEFAULT_MAX_FILE_BYTES
+}
+
+fn default_presign_expire_secs() -> u64 {
+    DEFAULT_PRESIGN_EXPIRE_SECS
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ResultSpoolingConf {
+    /// The result is spooled to the user stage once its size exceeds the threshold,
+    /// a smaller result is still returned in the pages.
+    #[serde(default = "default_threshold_bytes")]
+    pub(crate) threshold_bytes: usize,
+    /// The max size of the blocks written into one Parquet file.
+    #[serde(default = "default_max_file_bytes")]
+    pub(crate) max_file_bytes: usize,
+    #[serde(default = "default_presign_expire_secs")]
+    pub(crate) presign_expire_secs: u64,
+}
+
+/// A Parquet file of the spooled result.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ResultFile {
+    /// The location in the user stage, for example: @~/_result/<query_id>/0.parquet
+    pub location: String,
+    /// None if the storage of the user stage doesn't support presign.
+    pub presigned_url: Option<String>,
+    pub rows: usize,
+    pub size: usize,
+}
+
+pub enum SpoolResult {
+    /// The result doesn't exceed the threshold, the blocks are returned in the pages as usual.
+    Blocks(Vec<DataBlock>),
+    Files(Vec<ResultFile>),
+}
+
+/// Buffers the result blocks of a http query, and writes them to the user stage as Parquet
+/// files once the result exceeds the threshold, so that a large result is not kept in memory
+/// until the client fetches all the pages.
+///
+/// The spooled files are not removed by the server, the client removes them after download.
+pub struct ResultSpooler {
+    query_id: String,
+    conf: ResultSpoolingConf,
+    op: Operator,
+    schema: TableSchemaRef,
+    blocks: Vec<DataBlock>,
+    buffered_bytes: usize,
+    spooling: bool,
+    files: Vec<ResultFile>,
+}
+
+impl ResultSpooler {
+    pub fn try_create(
+        ctx: &Arc<QueryContext>,
+        schema: DataSchemaRef,
+        conf: ResultSpoolingConf,
+    ) -> Result<Self> {
+        let stage = StageInfo::new_user_stage(&ctx.get_current_user()?.name);
+        Ok(ResultSpooler {
+            query_id: ctx.get_id(),
+            conf,
+            op: StageTable::get_op(&stage)?,
+            schema: infer_table_schema(&schema)?,
+            blocks: vec![],
+            buffered_bytes: 0,
+            spooling: false,
+            files: vec![],
+        })
+    }
+
+    #[async_backtrace::framed]
+    pub async fn push(&mut self, block: DataBlock) -> Result<()> {
+        self.buffered_bytes += block.memory_size();
+        self.blocks.push(block);
+
+        if !self.spooling && self.buffered_bytes > self.conf.threshold_bytes {
+            info!(
+                "{}: http query result exceeds {} bytes, spool it to user stage",
+                &self.query_id, self.conf.threshold_bytes
+            );
+            self.spooling = true;
+        }
+        if self.spooling && self.buffered_bytes >= self.conf.max_file_bytes {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    pub async fn finish(mut self) -> Result<SpoolResult> {
+        if !self.spooling {
+            return Ok(SpoolResult::Blocks(self.blocks));
+        }
+
+        self.flush().await?;
+        info!(
+            "{}: http query result spooled to {} files",
+            &self.query_id,
+            self.files.len()
+        );
+        Ok(SpoolResult::Files(self.files))
+    }
+
+    #[async_backtrace::framed]
+    async fn flush(&mut self) -> Result<()> {
+        let blocks = std::mem::take(&mut self.blocks);
+        self.buffered_bytes = 0;
+        if blocks.is_empty() {
+            return Ok(());
+        }
+
+        let rows = blocks.iter().map(|b| b.num_rows()).sum();
+        let mut buf = vec![];
+        blocks_to_parquet(&self.schema, blocks, &mut buf, TableCompression::Zstd)?;
+        let size = buf.len();
+
+        let path = format!(
+            "{}/{}/{}.parquet",
+            RESULT_SPOOL_DIR,
+            self.query_id,
+            self.files.len()
+        );
+        self.op.write(&path, buf).await?;
+
+        let presigned_url = if self.op.info().full_capability().presign {
+            let expire = Duration::from_secs(self.conf.presign_expire_secs);
+            let presigned_req = self.op.presign_read(&path, expire).await?;
+            Some(presigned_req.uri().to_string())
+        } else {
+            None
+        };
+
+        self.files.push(ResultFile {
+            location: format!("@~/{}", path),
+            presigned_url,
+            rows,
+            size,
+        });
+        Ok(())
+    }
+}
@@ -24,6 +24,7 @@ pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
 pub use self::mysql::MySQLTlsConfig;
+pub use self::tls_reloader::TlsReloader;
 
 pub mod admin;
 pub(crate) mod federated_helper;
@@ -33,3 +34,4 @@ pub mod http;
 pub mod metrics;
 mod mysql;
 pub(crate) mod server;
+mod tls_reloader;
@@ -168,6 +168,41 @@ impl MySQLFederated {
         FederatedHelper::block_match_rule(query, &SHOW_VARIABLES_RULES)
     }
 
+    // Check SHOW [SESSION] STATUS [LIKE 'pattern'].
+    // It reports the id of the last query of the session, so the clients can correlate
+    // their errors with the server side logs.
+    pub fn show_status_check(
+        query: &str,
+        last_query_id: &str,
+    ) -> Option<(DataSchemaRef, DataBlock)> {
+        #[ctor]
+        static SHOW_STATUS_RULE: Regex =
+            Regex::new("(?i)^SHOW\\s+(SESSION\\s+)?STATUS(\\s+LIKE\\s+'([^']*)')?\\s*;?\\s*$")
+                .unwrap();
+
+        let captures = SHOW_STATUS_RULE.captures(query.trim())?;
+        let name = "last_query_id";
+        let matched = match captures.get(3) {
+            None => true,
+            Some(pattern) => {
+                let pattern = pattern
+                    .as_str()
+                    .chars()
+                    .map(|c| match c {
+                        '%' => ".*".to_string(),
+                        '_' => ".".to_string(),
+                        c => regex::escape(&c.to_string()),
+                    })
+                    .collect::<String>();
+                Regex::new(&format!("(?i)^{pattern}$")).map_or(false, |re| re.is_match(name))
+            }
+        };
+
+        let (schema, block) = MySQLFederated::show_variables_block(name, last_query_id)?;
+        let block = if matched { block } else { block.slice(0..0) };
+        Some((Arc::new(DataSchema::from(schema)), block))
+    }
+
     // Check for SET or others query, this is the final check of the federated query.
     fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
         #[ctor]
@@ -210,8 +245,6 @@ impl MySQLFederated {
             (Regex::new("(?i)^(UNLOCK BINLOG(.*))").unwrap(), None),
             (Regex::new("(?i)^(/\\*!40101 SET(.*) \\*/)$").unwrap(), None),
             // DBeaver.
-            (Regex::new("(?i)^(SHOW WARNINGS)").unwrap(), None),
-            (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW WARNINGS)").unwrap(), None),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW PLUGINS)").unwrap(), None),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW COLLATION)").unwrap(), None),
             (Regex::new("(?i)^(/\\* ApplicationName=(.*)SHOW CHARSET)").unwrap(), None),
@@ -22,6 +22,7 @@ use databend_common_base::base::tokio::task::JoinHandle;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_base::GLOBAL_TASK;
+use databend_common_exception::ErrorCategory;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use futures::future::AbortHandle;
@@ -32,6 +33,7 @@ use log::error;
 use log::info;
 use log::warn;
 use opensrv_mysql::*;
+use parking_lot::Mutex;
 use rustls::ServerConfig;
 use socket2::SockRef;
 use socket2::TcpKeepalive;
@@ -40,6 +42,8 @@ use tokio_stream::wrappers::TcpListenerStream;
 use crate::servers::mysql::mysql_session::MySQLConnection;
 use crate::servers::mysql::reject_connection::RejectConnection;
 use crate::servers::mysql::tls::MySQLTlsConfig;
+use crate::servers::mysql::tls::ReloadableTlsConfig;
+use crate::servers::mysql::writers::error_kind;
 use crate::servers::server::ListeningStream;
 use crate::servers::server::Server;
 use crate::sessions::SessionManager;
@@ -50,7 +54,7 @@ pub struct MySQLHandler {
     abort_registration: Option<AbortRegistration>,
     join_handle: Option<JoinHandle<()>>,
     keepalive: TcpKeepalive,
-    tls: Option<Arc<ServerConfig>>,
+    tls: Arc<Mutex<ReloadableTlsConfig>>,
 }
 
 impl MySQLHandler {
@@ -61,7 +65,7 @@ impl MySQLHandler {
         let (abort_handle, registration) = AbortHandle::new_pair();
         let keepalive = TcpKeepalive::new()
             .with_time(std::time::Duration::from_secs(tcp_keepalive_timeout_secs));
-        let tls = tls_config.setup()?.map(Arc::new);
+        let tls = Arc::new(Mutex::new(ReloadableTlsConfig::create(tls_config)?));
 
         Ok(Box::new(MySQLHandler {
             abort_handle,
@@ -88,7 +92,7 @@ impl MySQLHandler {
         let tls = self.tls.clone();
 
         stream.for_each(move |accept_socket| {
-            let tls = tls.clone();
+            let tls = tls.lock().current();
             let keepalive = keepalive.clone();
             let executor = rt.clone();
             let sessions = SessionManager::instance();
@@ -134,12 +138,14 @@ impl MySQLHandler {
 
     #[async_backtrace::framed]
     async fn reject_session(stream: TcpStream, error: ErrorCode) {
-        let (kind, message) = match error.code() {
-            41 => (ErrorKind::ER_TOO_MANY_USER_CONNECTIONS, error.message()),
-            _ => (ErrorKind::ER_INTERNAL_ERROR, error.message()),
+        let kind = match error.category() {
+            ErrorCategory::ResourceExhausted | ErrorCategory::Authentication => error_kind(&error),
+            _ => ErrorKind::ER_INTERNAL_ERROR,
         };
 
-        if let Err(error) = RejectConnection::reject_mysql_connection(stream, kind, message).await {
+        if let Err(error) =
+            RejectConnection::reject_mysql_connection(stream, kind, error.message()).await
+        {
             error!(
                 "Unexpected error occurred during reject connection: {:?}",
                 error
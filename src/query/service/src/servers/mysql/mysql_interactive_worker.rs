@@ -18,6 +18,7 @@ use std::time::Instant;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -53,6 +54,7 @@ use crate::servers::mysql::writers::ProgressReporter;
 use crate::servers::mysql::writers::QueryResult;
 use crate::servers::mysql::MySQLFederated;
 use crate::servers::mysql::MYSQL_VERSION;
+use crate::sessions::ConnectionLimiter;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryEntry;
@@ -261,6 +263,9 @@ impl InteractiveWorkerBase {
         let ctx = self.session.create_query_context().await?;
         let identity = UserIdentity::new(&info.user_name, "%");
         let client_ip = info.user_client_address.split(':').collect::<Vec<_>>()[0];
+        let limiter = ConnectionLimiter::instance();
+        limiter.check_lockout(&ctx.get_tenant(), &info.user_name, Some(client_ip))?;
+
         let user_info = UserApiProvider::instance()
             .get_user_with_client_ip(&ctx.get_tenant(), identity.clone(), Some(client_ip))
             .await?;
@@ -271,11 +276,14 @@ impl InteractiveWorkerBase {
             .await?;
 
         let authed = user_info.auth_info.auth_mysql(&info.user_password, salt)?;
+        limiter.record_auth_result(&ctx.get_tenant(), &info.user_name, Some(client_ip), authed);
         UserApiProvider::instance()
             .update_user_login_result(ctx.get_tenant(), identity, authed)
             .await?;
         if authed {
+            let guard = limiter.acquire(&ctx.get_tenant(), &user_info.name)?;
             self.session.set_authed_user(user_info, None).await?;
+            self.session.set_connection_guard(guard);
         }
         Ok(authed)
     }
@@ -326,6 +334,10 @@ impl InteractiveWorkerBase {
         {
             return None;
         }
+        let last_query_id = self.session.get_last_query_id(-1);
+        if let Some(status) = MySQLFederated::show_status_check(query, &last_query_id) {
+            return Some(status);
+        }
         let federated = MySQLFederated::create();
         federated.check(query)
     }
@@ -335,7 +347,7 @@ impl InteractiveWorkerBase {
     async fn do_query(&mut self, query: &str) -> Result<(QueryResult, Option<FormatSettings>)> {
         match self.federated_server_command_check(query) {
             Some((schema, data_block)) => {
-                info!("Federated query: {}", query);
+                info!("Federated query: {}", self.session.redact_query_text(query));
                 if data_block.num_rows() > 0 {
                     info!("Federated response: {:?}", data_block);
                 }
@@ -352,35 +364,52 @@ impl InteractiveWorkerBase {
                 ))
             }
             None => {
-                info!("Normal query: {}", query);
+                info!("Normal query: {}", self.session.redact_query_text(query));
                 let context = self.session.create_query_context().await?;
+                let query_id = context.get_id();
 
-                // Use interpreter_plan_sql, we can write the query log if an error occurs.
-                let (plan, extras) = interpreter_plan_sql(context.clone(), query).await?;
-
-                let entry = QueryEntry::create(&context, &plan, &extras)?;
-                let _guard = QueriesQueueManager::instance().acquire(entry).await?;
-
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
-                let has_result_set = plan.has_result_set();
-
-                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
-                let schema = plan.schema();
-                let format = context.get_format_settings()?;
-                Ok((
-                    QueryResult::create(
-                        blocks,
-                        extra_info,
-                        has_result_set,
-                        schema,
-                        query.to_string(),
-                    ),
-                    Some(format),
-                ))
+                ThreadTracker::tracking_query_future(
+                    &query_id,
+                    Self::do_normal_query(context, query),
+                )
+                .await
+                .map_err(|err| {
+                    err.display_with_sql(query)
+                        .add_message_back(format!("(query_id: {query_id})"))
+                })
             }
         }
     }
 
+    #[async_backtrace::framed]
+    async fn do_normal_query(
+        context: Arc<QueryContext>,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        // Use interpreter_plan_sql, we can write the query log if an error occurs.
+        let (plan, extras) = interpreter_plan_sql(context.clone(), query).await?;
+
+        let entry = QueryEntry::create(&context, &plan, &extras)?;
+        let _guard = QueriesQueueManager::instance().acquire(entry).await?;
+
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await?;
+        let has_result_set = plan.has_result_set();
+
+        let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+        let schema = plan.schema();
+        let format = context.get_format_settings()?;
+        Ok((
+            QueryResult::create(
+                blocks,
+                extra_info,
+                has_result_set,
+                schema,
+                query.to_string(),
+            ),
+            Some(format),
+        ))
+    }
+
     #[async_backtrace::framed]
     #[minitrace::trace]
     async fn exec_query(
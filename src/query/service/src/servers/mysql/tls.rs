@@ -14,20 +14,29 @@
 
 use std::fs::File;
 use std::io::BufReader;
+use std::sync::Arc;
 
+use databend_common_base::base::tokio::sync::watch;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use log::error;
+use log::info;
+use rustls::server::AllowAnyAuthenticatedClient;
 use rustls::Certificate;
 use rustls::PrivateKey;
+use rustls::RootCertStore;
 use rustls::ServerConfig;
 use rustls_pemfile::certs;
 use rustls_pemfile::pkcs8_private_keys;
 use rustls_pemfile::rsa_private_keys;
 
+use crate::servers::TlsReloader;
+
 #[derive(Default)]
 pub struct MySQLTlsConfig {
     cert_path: String,
     key_path: String,
+    root_ca_path: String,
 }
 
 impl MySQLTlsConfig {
@@ -35,9 +44,16 @@ impl MySQLTlsConfig {
         Self {
             cert_path,
             key_path,
+            root_ca_path: String::new(),
         }
     }
 
+    /// Requires the clients to present a certificate signed by the CA, if the path is not empty.
+    pub fn with_root_ca(mut self, root_ca_path: String) -> Self {
+        self.root_ca_path = root_ca_path;
+        self
+    }
+
     fn enabled(&self) -> bool {
         !self.cert_path.is_empty() && !self.key_path.is_empty()
     }
@@ -69,12 +85,62 @@ impl MySQLTlsConfig {
             }
         };
 
-        let config = ServerConfig::builder()
-            .with_safe_defaults()
-            .with_no_client_auth()
+        let builder = ServerConfig::builder().with_safe_defaults();
+        let builder = if self.root_ca_path.is_empty() {
+            builder.with_no_client_auth()
+        } else {
+            let mut roots = RootCertStore::empty();
+            let ca_certs = certs(&mut BufReader::new(File::open(&self.root_ca_path)?))
+                .map_err(|err| ErrorCode::TLSConfigurationFailure(err.to_string()))?;
+            let (added, _) = roots.add_parsable_certificates(&ca_certs);
+            if added == 0 {
+                return Err(ErrorCode::TLSConfigurationFailure(
+                    "invalid root ca cert".to_string(),
+                ));
+            }
+            builder.with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots).boxed())
+        };
+        let config = builder
             .with_single_cert(cert, key)
             .map_err(|err| ErrorCode::TLSConfigurationFailure(err.to_string()))?;
 
         Ok(Some(config))
     }
 }
+
+/// The server config of the MySQL handler, built again from the certificate files when the
+/// TLS certificates are reloaded.
+pub struct ReloadableTlsConfig {
+    tls_config: MySQLTlsConfig,
+    reload: watch::Receiver<u64>,
+    current: Option<Arc<ServerConfig>>,
+}
+
+impl ReloadableTlsConfig {
+    pub fn create(tls_config: MySQLTlsConfig) -> Result<Self> {
+        let current = tls_config.setup()?.map(Arc::new);
+        Ok(ReloadableTlsConfig {
+            tls_config,
+            reload: TlsReloader::instance().subscribe(),
+            current,
+        })
+    }
+
+    /// The server config for the connection being accepted.
+    pub fn current(&mut self) -> Option<Arc<ServerConfig>> {
+        if self.current.is_some() && self.reload.has_changed().unwrap_or(false) {
+            self.reload.borrow_and_update();
+            match self.tls_config.setup() {
+                Ok(config) => {
+                    info!("MySQL handler TLS certificates reloaded");
+                    self.current = config.map(Arc::new);
+                }
+                Err(cause) => error!(
+                    "Failed to reload MySQL handler TLS certificates, keep the old ones, cause: {:?}",
+                    cause
+                ),
+            }
+        }
+        self.current.clone()
+    }
+}
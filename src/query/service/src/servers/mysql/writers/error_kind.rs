@@ -0,0 +1,59 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCategory;
+use databend_common_exception::ErrorCode;
+use opensrv_mysql::ErrorKind;
+
+/// Maps the error to the MySQL error kind with the closest SQLSTATE, so that the drivers
+/// can branch on the class of the error, the databend error code is kept in the message.
+pub fn error_kind(error: &ErrorCode) -> ErrorKind {
+    match error.code() {
+        ErrorCode::ABORTED_QUERY | ErrorCode::ABORTED_SESSION => {
+            return ErrorKind::ER_ABORTING_CONNECTION;
+        }
+        ErrorCode::UNKNOWN_DATABASE | ErrorCode::UNKNOWN_DATABASE_ID => {
+            return ErrorKind::ER_BAD_DB_ERROR;
+        }
+        ErrorCode::UNKNOWN_TABLE | ErrorCode::UNKNOWN_TABLE_ID | ErrorCode::UNKNOWN_VIEW => {
+            return ErrorKind::ER_NO_SUCH_TABLE;
+        }
+        ErrorCode::UNKNOWN_COLUMN => return ErrorKind::ER_BAD_FIELD_ERROR,
+        ErrorCode::UNKNOWN_FUNCTION | ErrorCode::UNKNOWN_U_D_F => {
+            return ErrorKind::ER_SP_DOES_NOT_EXIST;
+        }
+        ErrorCode::DATABASE_ALREADY_EXISTS => return ErrorKind::ER_DB_CREATE_EXISTS,
+        ErrorCode::TABLE_ALREADY_EXISTS | ErrorCode::VIEW_ALREADY_EXISTS => {
+            return ErrorKind::ER_TABLE_EXISTS_ERROR;
+        }
+        ErrorCode::TOO_MANY_USER_CONNECTIONS => return ErrorKind::ER_TOO_MANY_USER_CONNECTIONS,
+        _ => {}
+    }
+
+    match error.category() {
+        ErrorCategory::Syntax => ErrorKind::ER_PARSE_ERROR,
+        ErrorCategory::Semantic => ErrorKind::ER_SYNTAX_ERROR,
+        ErrorCategory::PermissionDenied => ErrorKind::ER_SPECIFIC_ACCESS_DENIED_ERROR,
+        ErrorCategory::Authentication => ErrorKind::ER_ACCESS_DENIED_ERROR,
+        ErrorCategory::InvalidArgument => ErrorKind::ER_WRONG_ARGUMENTS,
+        ErrorCategory::DataException => ErrorKind::ER_WARN_DATA_OUT_OF_RANGE,
+        ErrorCategory::ConstraintViolation => ErrorKind::ER_DUP_ENTRY,
+        // SQLSTATE 40001, the drivers retry the transaction on it.
+        ErrorCategory::Conflict => ErrorKind::ER_LOCK_DEADLOCK,
+        ErrorCategory::Timeout | ErrorCategory::Cancelled => ErrorKind::ER_QUERY_INTERRUPTED,
+        ErrorCategory::ResourceExhausted => ErrorKind::ER_OUT_OF_RESOURCES,
+        ErrorCategory::Unsupported => ErrorKind::ER_NOT_SUPPORTED_YET,
+        _ => ErrorKind::ER_UNKNOWN_ERROR,
+    }
+}
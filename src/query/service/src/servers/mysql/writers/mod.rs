@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod error_kind;
 mod init_result_writer;
 mod query_result_writer;
 
+pub use self::error_kind::error_kind;
 pub use self::init_result_writer::DFInitResultWriter;
 pub use self::query_result_writer::DFQueryResultWriter;
 pub use self::query_result_writer::ProgressReporter;
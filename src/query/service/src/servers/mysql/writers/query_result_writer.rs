@@ -31,6 +31,7 @@ use futures_util::StreamExt;
 use log::error;
 use opensrv_mysql::*;
 
+use crate::servers::mysql::writers::error_kind;
 use crate::sessions::Session;
 /// Reports progress information as string, intend to be put into the mysql Ok packet.
 /// Mainly for decoupling with concrete type like `QueryContext`
@@ -134,7 +135,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                     error!("dataset write failed: {:?}", e);
                     dataset_writer
                         .error(
-                            ErrorKind::ER_UNKNOWN_ERROR,
+                            error_kind(&e),
                             format!(
                                 "dataset write failed: {}",
                                 e.display_with_sql(&query_result.sql)
@@ -182,6 +183,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 },
                 DataType::Date => Ok(ColumnType::MYSQL_TYPE_DATE),
                 DataType::Timestamp => Ok(ColumnType::MYSQL_TYPE_DATETIME),
+                DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Array(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Map(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Bitmap => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
@@ -222,7 +224,7 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                             error!("result row write failed: {:?}", e);
                             row_writer
                                 .finish_error(
-                                    ErrorKind::ER_UNKNOWN_ERROR,
+                                    error_kind(&e),
                                     &e.display_with_sql(&query_result.sql).to_string().as_bytes(),
                                 )
                                 .await?;
@@ -320,17 +322,10 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
         self.session.txn_mgr().lock().set_fail();
         if error.code() != ErrorCode::ABORTED_QUERY && error.code() != ErrorCode::ABORTED_SESSION {
             error!("OnQuery Error: {:?}", error);
-            writer
-                .error(ErrorKind::ER_UNKNOWN_ERROR, error.to_string().as_bytes())
-                .await?;
-        } else {
-            writer
-                .error(
-                    ErrorKind::ER_ABORTING_CONNECTION,
-                    error.to_string().as_bytes(),
-                )
-                .await?;
         }
+        writer
+            .error(error_kind(error), error.to_string().as_bytes())
+            .await?;
 
         Ok(())
     }
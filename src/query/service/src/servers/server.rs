@@ -28,6 +28,7 @@ use futures::stream::Abortable;
 use futures::StreamExt;
 use log::error;
 use log::info;
+use log::warn;
 use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::clusters::ClusterDiscovery;
@@ -68,14 +69,31 @@ impl ShutdownHandle {
         futures::future::join_all(shutdown_jobs).await;
     }
 
+    /// Drains the server before stopping it. New queries are rejected and `/v1/status`
+    /// reports the node as draining, while the services keep serving the running queries,
+    /// e.g. fetching their results. Once the running queries finish or the timeout, the
+    /// remaining sessions are killed, the node leaves the cluster and the logs are flushed.
     #[async_backtrace::framed]
     pub async fn shutdown(&mut self, mut signal: SignalStream, timeout: Option<Duration>) {
+        info!("Draining, new queries are rejected");
+        self.sessions.start_draining();
+        // Stop assigning the fragments of new queries to this node.
+        if let Err(cause) = ClusterDiscovery::instance().drain(Duration::ZERO).await {
+            warn!(
+                "Cannot drain cluster node(while shutdown), cause {:?}",
+                cause
+            );
+        }
+
+        self.sessions.graceful_shutdown(&mut signal, timeout).await;
         self.shutdown_services(true).await;
         ClusterDiscovery::instance()
             .unregister_to_metastore(&mut signal)
             .await;
-        self.sessions.graceful_shutdown(signal, timeout).await;
         self.shutdown_services(false).await;
+
+        log::logger().flush();
+        minitrace::flush();
     }
 
     #[async_backtrace::framed]
@@ -0,0 +1,49 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::sync::watch;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+
+/// Tells the handlers to read their TLS certificates again from the files, so the rotated
+/// certificates take effect without restarting. Triggered by the config reload.
+///
+/// The connections accepted afterwards use the new certificates, the established ones are
+/// not affected. A handler keeps using its old certificates if the new ones are invalid.
+pub struct TlsReloader {
+    version: watch::Sender<u64>,
+}
+
+impl TlsReloader {
+    pub fn init() -> Result<()> {
+        let (version, _) = watch::channel(0);
+        GlobalInstance::set(Arc::new(TlsReloader { version }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TlsReloader> {
+        GlobalInstance::get()
+    }
+
+    pub fn reload(&self) {
+        self.version.send_modify(|version| *version += 1);
+    }
+
+    /// The receiver is notified on each reload after the subscription.
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.version.subscribe()
+    }
+}
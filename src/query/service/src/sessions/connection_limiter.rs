@@ -0,0 +1,237 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::GlobalInstance;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_metrics::session::*;
+use log::warn;
+use parking_lot::Mutex;
+
+/// Limits the concurrent sessions of the users and the tenants on this node, and locks out
+/// the clients failing to authenticate too often.
+///
+/// The limits are read from the global config on each check, so they follow the config reloads.
+pub struct ConnectionLimiter {
+    connections: Mutex<Connections>,
+    auth_failures: Mutex<HashMap<ClientKey, AuthFailures>>,
+}
+
+#[derive(Default)]
+struct Connections {
+    users: HashMap<(String, String), u64>,
+    tenants: HashMap<String, u64>,
+}
+
+/// A client is a user logging in from an address, the failures of the other addresses
+/// don't lock it out.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ClientKey {
+    tenant: String,
+    user: String,
+    client_ip: Option<String>,
+}
+
+#[derive(Default)]
+struct AuthFailures {
+    attempts: VecDeque<Instant>,
+    locked_until: Option<Instant>,
+}
+
+/// Holds a session slot of the user and the tenant, released on drop.
+pub struct ConnectionGuard {
+    limiter: Arc<ConnectionLimiter>,
+    tenant: String,
+    user: String,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.limiter.release(&self.tenant, &self.user);
+    }
+}
+
+impl ConnectionLimiter {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(ConnectionLimiter {
+            connections: Mutex::new(Connections::default()),
+            auth_failures: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<ConnectionLimiter> {
+        GlobalInstance::get()
+    }
+
+    /// Takes a session slot of the user, fails if the user or the tenant has reached
+    /// `max_connections_per_user` or `max_connections_per_tenant`.
+    pub fn acquire(self: &Arc<Self>, tenant: &Tenant, user: &str) -> Result<ConnectionGuard> {
+        let config = GlobalConfig::instance();
+        let max_per_user = config.query.max_connections_per_user;
+        let max_per_tenant = config.query.max_connections_per_tenant;
+
+        let tenant = tenant.tenant_name().to_string();
+        let mut connections = self.connections.lock();
+        let user_key = (tenant.clone(), user.to_string());
+        let user_count = connections.users.get(&user_key).copied().unwrap_or(0);
+        if max_per_user > 0 && user_count >= max_per_user {
+            incr_session_rejected_by_user_limit_count();
+            return Err(ErrorCode::TooManyUserConnections(format!(
+                "User '{}' has {} sessions, exceeding the max_connections_per_user limit ({})",
+                user, user_count, max_per_user
+            )));
+        }
+        let tenant_count = connections.tenants.get(&tenant).copied().unwrap_or(0);
+        if max_per_tenant > 0 && tenant_count >= max_per_tenant {
+            incr_session_rejected_by_tenant_limit_count();
+            return Err(ErrorCode::TooManyUserConnections(format!(
+                "Tenant '{}' has {} sessions, exceeding the max_connections_per_tenant limit ({})",
+                tenant, tenant_count, max_per_tenant
+            )));
+        }
+
+        *connections.users.entry(user_key).or_default() += 1;
+        *connections.tenants.entry(tenant.clone()).or_default() += 1;
+        Ok(ConnectionGuard {
+            limiter: self.clone(),
+            tenant,
+            user: user.to_string(),
+        })
+    }
+
+    fn release(&self, tenant: &str, user: &str) {
+        let mut connections = self.connections.lock();
+        let user_key = (tenant.to_string(), user.to_string());
+        if let Some(count) = connections.users.get_mut(&user_key) {
+            *count -= 1;
+            if *count == 0 {
+                connections.users.remove(&user_key);
+            }
+        }
+        if let Some(count) = connections.tenants.get_mut(tenant) {
+            *count -= 1;
+            if *count == 0 {
+                connections.tenants.remove(tenant);
+            }
+        }
+    }
+
+    /// Fails if the client is locked out, checked before authenticating.
+    pub fn check_lockout(
+        &self,
+        tenant: &Tenant,
+        user: &str,
+        client_ip: Option<&str>,
+    ) -> Result<()> {
+        let key = ClientKey::new(tenant, user, client_ip);
+        let now = Instant::now();
+        let mut auth_failures = self.auth_failures.lock();
+        let Some(failures) = auth_failures.get_mut(&key) else {
+            return Ok(());
+        };
+        match failures.locked_until {
+            Some(locked_until) if locked_until > now => {
+                incr_session_auth_rejected_by_lockout_count();
+                Err(ErrorCode::AuthenticateFailure(format!(
+                    "User '{}' is locked out for {} seconds after too many failed authentications",
+                    user,
+                    (locked_until - now).as_secs().max(1)
+                )))
+            }
+            Some(_) => {
+                // The lockout has expired, the client starts over.
+                auth_failures.remove(&key);
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Records the result of an authentication. The client is locked out for `auth_lockout_secs`
+    /// after `auth_failure_max_attempts` failures within `auth_failure_window_secs`.
+    pub fn record_auth_result(
+        &self,
+        tenant: &Tenant,
+        user: &str,
+        client_ip: Option<&str>,
+        authed: bool,
+    ) {
+        let key = ClientKey::new(tenant, user, client_ip);
+        let mut auth_failures = self.auth_failures.lock();
+        if authed {
+            auth_failures.remove(&key);
+            return;
+        }
+
+        incr_session_auth_failure_count();
+        let config = GlobalConfig::instance();
+        let max_attempts = config.query.auth_failure_max_attempts;
+        if max_attempts == 0 {
+            return;
+        }
+        let window = Duration::from_secs(config.query.auth_failure_window_secs);
+        let lockout = Duration::from_secs(config.query.auth_lockout_secs);
+
+        let now = Instant::now();
+        // Forget the clients which stopped failing, so the map doesn't grow without bound.
+        auth_failures.retain(|_, failures| match failures.locked_until {
+            Some(locked_until) => locked_until > now,
+            None => failures
+                .attempts
+                .back()
+                .is_some_and(|last| now.duration_since(*last) < window),
+        });
+
+        let failures = auth_failures.entry(key).or_default();
+        while failures
+            .attempts
+            .front()
+            .is_some_and(|first| now.duration_since(*first) >= window)
+        {
+            failures.attempts.pop_front();
+        }
+        failures.attempts.push_back(now);
+        if failures.attempts.len() as u64 >= max_attempts {
+            warn!(
+                "User '{}' from {:?} is locked out for {:?} after {} failed authentications",
+                user,
+                client_ip,
+                lockout,
+                failures.attempts.len()
+            );
+            incr_session_auth_lockout_count();
+            failures.attempts.clear();
+            failures.locked_until = Some(now + lockout);
+        }
+    }
+}
+
+impl ClientKey {
+    fn new(tenant: &Tenant, user: &str, client_ip: Option<&str>) -> Self {
+        ClientKey {
+            tenant: tenant.tenant_name().to_string(),
+            user: user.to_string(),
+            client_ip: client_ip.map(|ip| ip.to_string()),
+        }
+    }
+}
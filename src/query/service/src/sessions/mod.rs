@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod connection_limiter;
 mod query_affect;
 pub mod query_ctx;
 mod query_ctx_shared;
@@ -25,6 +26,8 @@ mod session_privilege_mgr;
 mod session_status;
 mod session_type;
 
+pub use connection_limiter::ConnectionGuard;
+pub use connection_limiter::ConnectionLimiter;
 pub use databend_common_catalog::table_context::TableContext;
 pub use query_affect::QueryAffect;
 pub use query_ctx::convert_query_log_timestamp;
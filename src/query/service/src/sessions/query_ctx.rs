@@ -37,6 +37,7 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_base::runtime::ThreadTracker;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::plan::DataSourceInfo;
@@ -58,6 +59,7 @@ use databend_common_exception::Result;
 use databend_common_expression::date_helper::TzFactory;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DecimalOverflowMode;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
 use databend_common_io::prelude::FormatSettings;
@@ -75,9 +77,12 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::PlanProfile;
+use databend_common_pipeline_core::query_spill_dir;
 use databend_common_pipeline_core::InputError;
 use databend_common_settings::Settings;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::IndexType;
+use databend_common_sql::MetadataRef;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::FileStatus;
@@ -105,6 +110,7 @@ use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::servers::flight::v1::exchange::DataExchangeManager;
+use crate::servers::flight::v1::exchange::RemotePartitions;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
 use crate::sessions::QueriesQueueManager;
@@ -112,6 +118,8 @@ use crate::sessions::QueryContextShared;
 use crate::sessions::Session;
 use crate::sessions::SessionManager;
 use crate::sessions::SessionType;
+use crate::spillers::SpillCipher;
+use crate::spillers::SpillKey;
 use crate::sql::binder::get_storage_params_from_options;
 use crate::storages::Table;
 
@@ -126,6 +134,8 @@ pub struct QueryContext {
     clickhouse_version: String,
     block_threshold: Arc<RwLock<BlockThresholds>>,
     partition_queue: Arc<RwLock<VecDeque<PartInfoPtr>>>,
+    // Set if the partitions are pulled from the coordinator instead of the partitions of the plan.
+    remote_partitions: Arc<RwLock<Option<Arc<RemotePartitions>>>>,
     shared: Arc<QueryContextShared>,
     query_settings: Arc<Settings>,
     fragment_id: Arc<AtomicUsize>,
@@ -145,6 +155,7 @@ impl QueryContext {
         let query_settings = Settings::create(tenant);
         Arc::new(QueryContext {
             partition_queue: Arc::new(RwLock::new(VecDeque::new())),
+            remote_partitions: Arc::new(RwLock::new(None)),
             version: format!("Databend Query {}", *DATABEND_COMMIT_VERSION),
             mysql_version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             clickhouse_version: CLICKHOUSE_VERSION.to_string(),
@@ -231,6 +242,10 @@ impl QueryContext {
         DataExchangeManager::instance()
     }
 
+    pub fn set_remote_partitions(&self, remote_partitions: Arc<RemotePartitions>) {
+        *self.remote_partitions.write() = Some(remote_partitions);
+    }
+
     // Get the current session.
     pub fn get_current_session(&self) -> Arc<Session> {
         self.shared.session.clone()
@@ -264,6 +279,16 @@ impl QueryContext {
         self.shared.pop_warnings()
     }
 
+    /// The warnings of the last statement in the session, they are kept for the next statement.
+    pub fn get_last_warnings(&self) -> Vec<String> {
+        self.shared.get_last_warnings()
+    }
+
+    /// Saves the warnings of the statement to the session once it's finished.
+    pub fn save_last_warnings(&self) {
+        self.shared.save_last_warnings()
+    }
+
     pub fn get_data_metrics(&self) -> StorageMetrics {
         self.shared.get_data_metrics()
     }
@@ -276,6 +301,64 @@ impl QueryContext {
         *self.shared.init_query_id.write() = id;
     }
 
+    pub fn attach_original_query(&self, query: &str) {
+        self.shared.attach_original_query(query);
+    }
+
+    pub fn get_original_query(&self) -> Option<String> {
+        self.shared.get_original_query()
+    }
+
+    pub fn set_query_plan(&self, plan: String) {
+        *self.shared.query_plan.write() = Some(plan);
+    }
+
+    pub fn get_query_plan(&self) -> Option<String> {
+        self.shared.query_plan.read().clone()
+    }
+
+    pub fn set_sampled_physical_plan(&self, plan: PhysicalPlan, metadata: MetadataRef) {
+        *self.shared.sampled_physical_plan.write() = Some((plan, metadata));
+    }
+
+    pub fn get_sampled_physical_plan(&self) -> Option<(PhysicalPlan, MetadataRef)> {
+        self.shared.sampled_physical_plan.read().clone()
+    }
+
+    /// The key to encrypt the spilled data of the query, shared by all the nodes running it.
+    pub fn get_spill_key(&self) -> SpillKey {
+        self.shared.get_spill_key()
+    }
+
+    pub fn set_spill_key(&self, key: SpillKey) {
+        self.shared.set_spill_key(key)
+    }
+
+    /// The query may have spilled data only if the spill key is used.
+    pub fn has_spill_key(&self) -> bool {
+        self.shared.has_spill_key()
+    }
+
+    pub fn get_spill_cipher(&self) -> SpillCipher {
+        SpillCipher::create(&self.get_spill_key())
+    }
+
+    /// The directory of the spill files of the query, see [`query_spill_dir`].
+    pub fn query_spill_dir(&self) -> String {
+        let config = GlobalConfig::instance();
+        query_spill_dir(
+            self.get_tenant().tenant_name(),
+            &config.query.cluster_id,
+            &self.get_id(),
+        )
+    }
+
+    /// The prefix of the spill files written by this node.
+    pub fn node_spill_prefix(&self) -> String {
+        let config = GlobalConfig::instance();
+        format!("{}/{}", self.query_spill_dir(), config.query.node_id)
+    }
+
     pub fn set_executor(&self, weak_ptr: Arc<PipelineExecutor>) -> Result<()> {
         self.shared.set_executor(weak_ptr)
     }
@@ -446,11 +529,37 @@ impl TableContext for QueryContext {
         res
     }
 
+    #[async_backtrace::framed]
+    async fn fetch_partition(&self) -> Result<Option<PartInfoPtr>> {
+        loop {
+            if let Some(part) = self.get_partition() {
+                return Ok(Some(part));
+            }
+
+            let remote_partitions = self.remote_partitions.read().clone();
+            let Some(remote_partitions) = remote_partitions else {
+                return Ok(None);
+            };
+
+            let parts = remote_partitions.pull().await?;
+            if parts.is_empty() {
+                return Ok(None);
+            }
+            self.partition_queue.write().extend(parts);
+        }
+    }
+
     // Update the context partition pool from the pipeline builder.
     fn set_partitions(&self, partitions: Partitions) -> Result<()> {
         let mut partition_queue = self.partition_queue.write();
 
         partition_queue.clear();
+        if self.remote_partitions.read().is_some() {
+            // The partitions of the plan are pulled through the coordinator,
+            // so that the other executors can take them as well.
+            return Ok(());
+        }
+
         for part in partitions.partitions {
             partition_queue.push_back(part);
         }
@@ -619,6 +728,9 @@ impl TableContext for QueryContext {
         let numeric_cast_option = self.get_settings().get_numeric_cast_option()?;
         let rounding_mode = numeric_cast_option.as_str() == "rounding";
         let disable_variant_check = self.get_settings().get_disable_variant_check()?;
+        let enable_expression_jit = self.get_settings().get_enable_expression_jit()?;
+        let decimal_overflow_mode =
+            DecimalOverflowMode::from_setting(&self.get_settings().get_decimal_overflow_mode()?);
 
         let query_config = &GlobalConfig::instance().query;
 
@@ -626,6 +738,8 @@ impl TableContext for QueryContext {
             tz,
             rounding_mode,
             disable_variant_check,
+            enable_expression_jit,
+            decimal_overflow_mode,
 
             openai_api_key: query_config.openai_api_key.clone(),
             openai_api_version: query_config.openai_api_version.clone(),
@@ -949,6 +1063,16 @@ impl TableContext for QueryContext {
         self.shared.multi_table_insert_status.clone()
     }
 
+    fn add_returning_block(&self, block: DataBlock) {
+        if !block.is_empty() {
+            self.shared.returning_blocks.lock().push(block);
+        }
+    }
+
+    fn get_returning_blocks(&self) -> Vec<DataBlock> {
+        std::mem::take(&mut *self.shared.returning_blocks.lock())
+    }
+
     fn get_license_key(&self) -> String {
         unsafe {
             self.get_settings()
@@ -1094,6 +1218,7 @@ impl TrySpawn for QueryContext {
         T: Future + Send + 'static,
         T::Output: Send + 'static,
     {
+        let task = ThreadTracker::tracking_query_future(&self.get_id(), task);
         Ok(self.shared.try_get_runtime()?.spawn(name, task))
     }
 }
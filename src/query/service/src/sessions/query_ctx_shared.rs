@@ -15,6 +15,7 @@
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -34,6 +35,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -42,7 +44,9 @@ use databend_common_meta_app::tenant::Tenant;
 use databend_common_pipeline_core::processors::PlanProfile;
 use databend_common_pipeline_core::InputError;
 use databend_common_settings::Settings;
+use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::IndexType;
+use databend_common_sql::MetadataRef;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
 use databend_common_storage::MergeStatus;
@@ -58,6 +62,8 @@ use crate::clusters::Cluster;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::Session;
+use crate::spillers::SpillCipher;
+use crate::spillers::SpillKey;
 use crate::storages::Table;
 
 type DatabaseAndTable = (String, String, String);
@@ -76,16 +82,25 @@ pub struct QueryContextShared {
     pub(in crate::sessions) agg_spill_progress: Arc<Progress>,
     /// Record how many bytes/rows have been spilled in group by
     pub(in crate::sessions) group_by_spill_progress: Arc<Progress>,
+    /// The key to encrypt the spilled data, generated on the first use, or received from the
+    /// coordinator of the query if the query is distributed.
+    pub(in crate::sessions) spill_key: Arc<RwLock<Option<SpillKey>>>,
     /// result_progress for metrics of result datablocks (uncompressed)
     pub(in crate::sessions) result_progress: Arc<Progress>,
     pub(in crate::sessions) error: Arc<Mutex<Option<ErrorCode>>>,
+    /// All the warnings of the query, they are kept for `SHOW WARNINGS` after popped.
     pub(in crate::sessions) warnings: Arc<Mutex<Vec<String>>>,
+    pub(in crate::sessions) popped_warnings: Arc<AtomicUsize>,
+    /// `SHOW WARNINGS` keeps the warnings of the last statement, like MySQL.
+    pub(in crate::sessions) keep_last_warnings: Arc<AtomicBool>,
     pub(in crate::sessions) session: Arc<Session>,
     pub(in crate::sessions) runtime: Arc<RwLock<Option<Arc<Runtime>>>>,
     pub(in crate::sessions) init_query_id: Arc<RwLock<String>>,
     pub(in crate::sessions) cluster_cache: Arc<Cluster>,
     pub(in crate::sessions) running_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) running_query_kind: Arc<RwLock<Option<QueryKind>>>,
+    /// The original SQL of the query, used to re-plan the query.
+    pub(in crate::sessions) original_query: Arc<RwLock<Option<String>>>,
     pub(in crate::sessions) aborting: Arc<AtomicBool>,
     pub(in crate::sessions) tables_refs: Arc<Mutex<HashMap<DatabaseAndTable, Arc<dyn Table>>>>,
     pub(in crate::sessions) affect: Arc<Mutex<Option<QueryAffect>>>,
@@ -105,6 +120,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) copy_status: Arc<CopyStatus>,
     pub(in crate::sessions) merge_status: Arc<RwLock<MergeStatus>>,
     pub(in crate::sessions) multi_table_insert_status: Arc<Mutex<MultiTableInsertStatus>>,
+    /// Rows emitted by the `RETURNING` clause of the running DML statement.
+    pub(in crate::sessions) returning_blocks: Arc<Mutex<Vec<DataBlock>>>,
     /// partitions_sha for each table in the query. Not empty only when enabling query result cache.
     pub(in crate::sessions) partitions_shas: Arc<RwLock<Vec<String>>>,
     pub(in crate::sessions) cacheable: Arc<AtomicBool>,
@@ -128,6 +145,12 @@ pub struct QueryContextShared {
     pub(in crate::sessions) query_cache_metrics: DataCacheMetrics,
 
     pub(in crate::sessions) query_queued_duration: Arc<RwLock<Duration>>,
+
+    // The plan of the query kept for the slow query log.
+    pub(in crate::sessions) query_plan: Arc<RwLock<Option<String>>>,
+    // The physical plan of the query sampled to record an EXPLAIN ANALYZE output
+    // if the query turns out to be slow.
+    pub(in crate::sessions) sampled_physical_plan: Arc<RwLock<Option<(PhysicalPlan, MetadataRef)>>>,
 }
 
 impl QueryContextShared {
@@ -147,9 +170,12 @@ impl QueryContextShared {
             write_progress: Arc::new(Progress::create()),
             error: Arc::new(Mutex::new(None)),
             warnings: Arc::new(Mutex::new(vec![])),
+            popped_warnings: Arc::new(AtomicUsize::new(0)),
+            keep_last_warnings: Arc::new(AtomicBool::new(false)),
             runtime: Arc::new(RwLock::new(None)),
             running_query: Arc::new(RwLock::new(None)),
             running_query_kind: Arc::new(RwLock::new(None)),
+            original_query: Arc::new(RwLock::new(None)),
             aborting: Arc::new(AtomicBool::new(false)),
             tables_refs: Arc::new(Mutex::new(HashMap::new())),
             affect: Arc::new(Mutex::new(None)),
@@ -171,12 +197,16 @@ impl QueryContextShared {
             join_spill_progress: Arc::new(Progress::create()),
             agg_spill_progress: Arc::new(Progress::create()),
             group_by_spill_progress: Arc::new(Progress::create()),
+            spill_key: Arc::new(RwLock::new(None)),
             query_cache_metrics: DataCacheMetrics::new(),
             query_profiles: Arc::new(RwLock::new(HashMap::new())),
             runtime_filters: Default::default(),
             merge_into_join: Default::default(),
             multi_table_insert_status: Default::default(),
+            returning_blocks: Default::default(),
             query_queued_duration: Arc::new(RwLock::new(Duration::from_secs(0))),
+            query_plan: Arc::new(RwLock::new(None)),
+            sampled_physical_plan: Arc::new(RwLock::new(None)),
         }))
     }
 
@@ -195,11 +225,23 @@ impl QueryContextShared {
         (*guard).push(warn);
     }
 
+    /// Returns the warnings pushed since the last pop.
     pub fn pop_warnings(&self) -> Vec<String> {
-        let mut guard = self.warnings.lock();
-        let warnings = (*guard).clone();
-        (*guard).clear();
-        warnings
+        let guard = self.warnings.lock();
+        let popped = self.popped_warnings.swap(guard.len(), Ordering::SeqCst);
+        guard[popped..].to_vec()
+    }
+
+    pub fn get_last_warnings(&self) -> Vec<String> {
+        self.keep_last_warnings.store(true, Ordering::SeqCst);
+        self.session.session_ctx.get_last_warnings()
+    }
+
+    pub fn save_last_warnings(&self) {
+        if !self.keep_last_warnings.load(Ordering::SeqCst) {
+            let warnings = self.warnings.lock().clone();
+            self.session.session_ctx.set_last_warnings(warnings);
+        }
     }
 
     pub fn set_on_error_map(&self, map: Arc<DashMap<String, HashMap<u16, InputError>>>) {
@@ -453,6 +495,29 @@ impl QueryContextShared {
         running_query.as_ref().unwrap_or(&"".to_string()).clone()
     }
 
+    pub fn attach_original_query(&self, query: &str) {
+        *self.original_query.write() = Some(query.to_string());
+    }
+
+    pub fn get_original_query(&self) -> Option<String> {
+        self.original_query.read().clone()
+    }
+
+    pub fn get_spill_key(&self) -> SpillKey {
+        *self
+            .spill_key
+            .write()
+            .get_or_insert_with(SpillCipher::generate_key)
+    }
+
+    pub fn set_spill_key(&self, key: SpillKey) {
+        *self.spill_key.write() = Some(key);
+    }
+
+    pub fn has_spill_key(&self) -> bool {
+        self.spill_key.read().is_some()
+    }
+
     pub fn get_query_kind(&self) -> QueryKind {
         let running_query_kind = self.running_query_kind.read();
         running_query_kind
@@ -29,6 +29,7 @@ use std::time::SystemTime;
 
 use databend_common_ast::ast::ExplainKind;
 use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::spawn;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -75,7 +76,12 @@ pub(crate) struct Inner<Data: QueueData> {
     pub is_abort: Arc<AtomicBool>,
 }
 
+// Zero permits means unlimited. The value fits in u32, so the permits can be taken
+// back at once when the limit is reduced.
+const UNLIMITED_PERMITS: usize = u32::MAX as usize;
+
 pub struct QueueManager<Data: QueueData> {
+    permits: Mutex<usize>,
     semaphore: Arc<Semaphore>,
     queue: Mutex<HashMap<Data::Key, Inner<Data>>>,
 }
@@ -93,15 +99,41 @@ impl<Data: QueueData> QueueManager<Data> {
 
     pub fn create(mut permits: usize) -> Arc<QueueManager<Data>> {
         if permits == 0 {
-            permits = usize::MAX >> 4;
+            permits = UNLIMITED_PERMITS;
         }
 
         Arc::new(QueueManager {
+            permits: Mutex::new(permits),
             queue: Mutex::new(HashMap::new()),
             semaphore: Arc::new(Semaphore::new(permits)),
         })
     }
 
+    /// Changes the number of permits, zero means unlimited.
+    ///
+    /// When the number is reduced, the running queries are not affected, the excess
+    /// permits are taken back as the running queries finish.
+    pub fn set_permits(&self, mut permits: usize) {
+        if permits == 0 {
+            permits = UNLIMITED_PERMITS;
+        }
+
+        let mut current = self.permits.lock();
+        info!("queue manager permits: {:?} -> {:?}", *current, permits);
+        if permits > *current {
+            self.semaphore.add_permits(permits - *current);
+        } else if permits < *current {
+            let excess = (*current - permits) as u32;
+            let semaphore = self.semaphore.clone();
+            spawn(async move {
+                if let Ok(permit) = semaphore.acquire_many_owned(excess).await {
+                    permit.forget();
+                }
+            });
+        }
+        *current = permits;
+    }
+
     /// The length of the queue.
     pub fn length(&self) -> usize {
         let queue = self.queue.lock();
@@ -27,15 +27,18 @@ use databend_common_meta_app::principal::UserInfo;
 use databend_common_meta_app::principal::UserPrivilegeType;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_settings::Settings;
+use databend_common_sql::QueryTextRedactor;
 use databend_common_users::GrantObjectVisibilityChecker;
 use databend_storages_common_txn::TxnManagerRef;
 use log::debug;
+use parking_lot::Mutex;
 use parking_lot::RwLock;
 
 use crate::clusters::ClusterDiscovery;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManager;
 use crate::sessions::session_privilege_mgr::SessionPrivilegeManagerImpl;
+use crate::sessions::ConnectionGuard;
 use crate::sessions::QueryContext;
 use crate::sessions::QueryContextShared;
 use crate::sessions::SessionContext;
@@ -51,6 +54,8 @@ pub struct Session {
     status: Arc<RwLock<SessionStatus>>,
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
+    // The session slot of the authed user, released when the session is dropped.
+    connection_guard: Mutex<Option<ConnectionGuard>>,
 }
 
 impl Session {
@@ -70,6 +75,7 @@ impl Session {
             privilege_mgr,
             mysql_connection_id,
             format_settings: FormatSettings::default(),
+            connection_guard: Mutex::new(None),
         }))
     }
 
@@ -218,6 +224,11 @@ impl Session {
             .await
     }
 
+    /// Holds the session slot taken by the handler for the authed user, see `ConnectionLimiter`.
+    pub fn set_connection_guard(self: &Arc<Self>, guard: ConnectionGuard) {
+        *self.connection_guard.lock() = Some(guard);
+    }
+
     #[async_backtrace::framed]
     pub async fn validate_available_role(self: &Arc<Self>, role_name: &str) -> Result<RoleInfo> {
         self.privilege_mgr.validate_available_role(role_name).await
@@ -297,6 +308,11 @@ impl Session {
         self.session_ctx.get_settings()
     }
 
+    /// Redacts the SQL text for logging, by the query text redaction settings of the session.
+    pub fn redact_query_text(self: &Arc<Self>, sql: &str) -> String {
+        QueryTextRedactor::create(&self.get_settings()).redact_sql(sql)
+    }
+
     pub fn get_memory_usage(self: &Arc<Self>) -> usize {
         // TODO(winter): use thread memory tracker
         0
@@ -310,6 +326,11 @@ impl Session {
         self.session_ctx.get_query_result_cache_key(query_id)
     }
 
+    /// The id of the last finished query of the session, `index` counts from the end if it's negative.
+    pub fn get_last_query_id(self: &Arc<Self>, index: i32) -> String {
+        self.session_ctx.get_last_query_id(index)
+    }
+
     pub fn update_query_ids_results(self: &Arc<Self>, query_id: String, result_cache_key: String) {
         self.session_ctx
             .update_query_ids_results(query_id, Some(result_cache_key))
@@ -66,6 +66,8 @@ pub struct SessionContext {
     // We store `query_id -> query_result_cache_key` to session context, so that we can fetch
     // query result through previous query_id easily.
     query_ids_results: RwLock<Vec<(String, Option<String>)>>,
+    // The warnings of the last statement, for `SHOW WARNINGS`.
+    last_warnings: RwLock<Vec<String>>,
     typ: SessionType,
     txn_mgr: Mutex<TxnManagerRef>,
 }
@@ -86,6 +88,7 @@ impl SessionContext {
             io_shutdown_tx: Default::default(),
             query_context_shared: Default::default(),
             query_ids_results: Default::default(),
+            last_warnings: Default::default(),
             typ,
             txn_mgr: Mutex::new(TxnManager::init()),
         }))
@@ -293,6 +296,14 @@ impl SessionContext {
         HashSet::from_iter(lock.iter().map(|result| result.clone().0))
     }
 
+    pub fn get_last_warnings(&self) -> Vec<String> {
+        self.last_warnings.read().clone()
+    }
+
+    pub fn set_last_warnings(&self, warnings: Vec<String>) {
+        *self.last_warnings.write() = warnings;
+    }
+
     pub fn txn_mgr(&self) -> TxnManagerRef {
         self.txn_mgr.lock().clone()
     }
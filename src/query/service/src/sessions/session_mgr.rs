@@ -15,7 +15,9 @@
 use std::collections::HashMap;
 use std::future::Future;
 use std::ops::DerefMut;
+use std::sync::atomic::AtomicBool;
 use std::sync::atomic::AtomicU32;
+use std::sync::atomic::AtomicUsize;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use std::sync::Weak;
@@ -45,13 +47,16 @@ use crate::sessions::SessionManagerStatus;
 use crate::sessions::SessionType;
 
 pub struct SessionManager {
-    pub(in crate::sessions) max_sessions: usize,
+    pub(in crate::sessions) max_sessions: AtomicUsize,
     pub(in crate::sessions) active_sessions: Arc<RwLock<HashMap<String, Weak<Session>>>>,
     pub status: Arc<RwLock<SessionManagerStatus>>,
 
     // When typ is MySQL, insert into this map, key is id, val is MySQL connection id.
     pub(crate) mysql_conn_map: Arc<RwLock<HashMap<Option<u32>, String>>>,
     pub(in crate::sessions) mysql_basic_conn_id: AtomicU32,
+
+    // Set when the server is shutting down, no new user sessions are accepted.
+    draining: AtomicBool,
 }
 
 impl SessionManager {
@@ -64,11 +69,12 @@ impl SessionManager {
     pub fn create(conf: &InnerConfig) -> Arc<SessionManager> {
         let max_sessions = conf.query.max_active_sessions as usize;
         Arc::new(SessionManager {
-            max_sessions,
+            max_sessions: AtomicUsize::new(max_sessions),
             mysql_basic_conn_id: AtomicU32::new(9_u32.to_le()),
             status: Arc::new(RwLock::new(SessionManagerStatus::default())),
             mysql_conn_map: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
             active_sessions: Arc::new(RwLock::new(HashMap::with_capacity(max_sessions))),
+            draining: AtomicBool::new(false),
         })
     }
 
@@ -105,6 +111,13 @@ impl SessionManager {
     }
 
     pub fn try_add_session(&self, session: Arc<Session>, typ: SessionType) -> Result<()> {
+        // The queries of the other nodes still run on this node while it is shutting down.
+        if self.is_draining() && typ.is_user_session() && typ != SessionType::FlightRPC {
+            return Err(ErrorCode::ServerShuttingDown(
+                "The server is shutting down, please retry on another node",
+            ));
+        }
+
         let mut sessions = self.active_sessions.write();
         if !matches!(typ, SessionType::Dummy | SessionType::FlightRPC) {
             self.validate_max_active_sessions(sessions.len(), "active sessions")?;
@@ -197,11 +210,20 @@ impl SessionManager {
         }
     }
 
-    pub fn graceful_shutdown(
+    /// Stops accepting new user sessions, the running queries are not affected.
+    pub fn start_draining(&self) {
+        self.draining.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_draining(&self) -> bool {
+        self.draining.load(Ordering::SeqCst)
+    }
+
+    pub fn graceful_shutdown<'a>(
         &self,
-        mut signal: SignalStream,
+        signal: &'a mut SignalStream,
         timeout: Option<Duration>,
-    ) -> impl Future<Output = ()> {
+    ) -> impl Future<Output = ()> + 'a {
         let active_sessions = self.active_sessions.clone();
         async move {
             if let Some(mut timeout) = timeout {
@@ -293,11 +315,17 @@ impl SessionManager {
         }
     }
 
+    /// Changes the max_active_sessions limit, the existing sessions are kept.
+    pub fn set_max_sessions(&self, max_sessions: usize) {
+        self.max_sessions.store(max_sessions, Ordering::Relaxed);
+    }
+
     fn validate_max_active_sessions(&self, count: usize, reason: &str) -> Result<()> {
-        if count >= self.max_sessions {
+        let max_sessions = self.max_sessions.load(Ordering::Relaxed);
+        if count >= max_sessions {
             return Err(ErrorCode::TooManyUserConnections(format!(
                 "Current {} ({}) has exceeded the max_active_sessions limit ({})",
-                reason, count, self.max_sessions
+                reason, count, max_sessions
             )));
         }
         Ok(())
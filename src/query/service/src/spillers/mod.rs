@@ -12,9 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod spill_cipher;
+mod spill_cleaner;
 mod spiller;
 mod spiller_buffer;
 
+pub use spill_cipher::SpillCipher;
+pub use spill_cipher::SpillKey;
+pub use spill_cleaner::remove_orphan_spill_files;
+pub use spill_cleaner::remove_query_spill_files;
 pub use spiller::Spiller;
 pub use spiller::SpillerConfig;
 pub use spiller::SpillerType;
@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Key;
+use aes_gcm::Nonce;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+const NONCE_LEN: usize = 12;
+
+pub type SpillKey = [u8; 32];
+
+/// Encrypts the spilled data with AES-256-GCM.
+///
+/// The key is generated for each query and never persisted, so the files left by a
+/// crashed query can not be read by anyone. Each encrypted chunk starts with its nonce.
+#[derive(Clone)]
+pub struct SpillCipher {
+    cipher: Aes256Gcm,
+}
+
+impl SpillCipher {
+    pub fn create(key: &SpillKey) -> SpillCipher {
+        SpillCipher {
+            cipher: Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)),
+        }
+    }
+
+    pub fn generate_key() -> SpillKey {
+        rand::random()
+    }
+
+    pub fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>> {
+        let nonce: [u8; NONCE_LEN] = rand::random();
+        let encrypted = self
+            .cipher
+            .encrypt(Nonce::from_slice(&nonce), data)
+            .map_err(|_| ErrorCode::Internal("Failed to encrypt spilled data"))?;
+
+        let mut chunk = Vec::with_capacity(NONCE_LEN + encrypted.len());
+        chunk.extend_from_slice(&nonce);
+        chunk.extend_from_slice(&encrypted);
+        Ok(chunk)
+    }
+
+    pub fn decrypt(&self, chunk: &[u8]) -> Result<Vec<u8>> {
+        if chunk.len() < NONCE_LEN {
+            return Err(ErrorCode::Internal("Invalid spilled data, too short"));
+        }
+        let (nonce, encrypted) = chunk.split_at(NONCE_LEN);
+        self.cipher
+            .decrypt(Nonce::from_slice(nonce), encrypted)
+            .map_err(|_| ErrorCode::Internal("Failed to decrypt spilled data"))
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_pipeline_core::cluster_spill_dir;
+use databend_common_storage::DataOperator;
+use futures::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::clusters::ClusterDiscovery;
+use crate::sessions::QueryContext;
+
+/// Removes the spill files of the query written by all the nodes, called by the coordinator
+/// of the query when it finishes, no matter whether it succeeds.
+pub fn remove_query_spill_files(ctx: &QueryContext) {
+    if !ctx.has_spill_key() {
+        return;
+    }
+
+    let dir = format!("{}/", ctx.query_spill_dir());
+    let operator = DataOperator::instance().operator();
+    let res = GlobalIORuntime::instance().block_on({
+        let dir = dir.clone();
+        async move { Ok(operator.remove_all(&dir).await?) }
+    });
+    if let Err(cause) = res {
+        warn!(
+            "Failed to remove the spill files in {}, cause: {:?}",
+            dir, cause
+        );
+    }
+}
+
+/// Removes the spill files left by the nodes which are no longer in the cluster, e.g. the
+/// nodes crashed while running queries. These files can not be read anymore, as the keys
+/// to decrypt them are gone with the nodes.
+pub async fn remove_orphan_spill_files(conf: &InnerConfig) -> Result<()> {
+    let cluster = ClusterDiscovery::instance().discover(conf).await?;
+    let alive_nodes = cluster
+        .nodes
+        .iter()
+        .map(|node| node.id.clone())
+        .collect::<HashSet<_>>();
+
+    let dir = format!(
+        "{}/",
+        cluster_spill_dir(conf.query.tenant_id.tenant_name(), &conf.query.cluster_id)
+    );
+    let operator = DataOperator::instance().operator();
+    let mut lister = operator.lister_with(&dir).recursive(true).await?;
+    let mut orphan_files = vec![];
+    while let Some(entry) = lister.try_next().await? {
+        if !entry.metadata().is_file() {
+            continue;
+        }
+        // <query_id>/<node_id>/<file>
+        let relative_path = entry.path().strip_prefix(&dir).unwrap_or_default();
+        let parts = relative_path.split('/').collect::<Vec<_>>();
+        if parts.len() == 3 && !alive_nodes.contains(parts[1]) {
+            orphan_files.push(entry.path().to_string());
+        }
+    }
+
+    if !orphan_files.is_empty() {
+        info!("Removing {} orphan spill files", orphan_files.len());
+        operator.remove(orphan_files).await?;
+    }
+    Ok(())
+}
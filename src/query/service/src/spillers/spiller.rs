@@ -34,6 +34,7 @@ use opendal::Operator;
 
 use crate::sessions::QueryContext;
 use crate::spillers::spiller_buffer::SpillerBuffer;
+use crate::spillers::SpillCipher;
 
 /// Spiller type, currently only supports HashJoin
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -73,10 +74,12 @@ impl SpillerConfig {
 /// 2. Partition data by the specified algorithm which specifies by operator
 /// 3. Serialization and deserialization input data
 /// 4. Interact with the underlying storage engine to write and read spilled data
+/// 5. Encrypt the spilled data with the key of the query
 #[derive(Clone)]
 pub struct Spiller {
     ctx: Arc<QueryContext>,
     operator: Operator,
+    cipher: SpillCipher,
     config: SpillerConfig,
     _spiller_type: SpillerType,
     spiller_buffer: SpillerBuffer,
@@ -98,9 +101,11 @@ impl Spiller {
         spiller_type: SpillerType,
     ) -> Result<Self> {
         let join_spilling_partition_bits = ctx.get_settings().get_join_spilling_partition_bits()?;
+        let cipher = ctx.get_spill_cipher();
         Ok(Self {
             ctx,
             operator,
+            cipher,
             config,
             _spiller_type: spiller_type,
             spiller_buffer: SpillerBuffer::create(),
@@ -119,8 +124,9 @@ impl Spiller {
     /// We should guarantee that the file is managed by this spiller.
     pub async fn read_spilled_file(&self, file: &str) -> Result<DataBlock> {
         debug_assert!(self.columns_layout.contains_key(file));
-        let data = self.operator.read(file).await?;
-        let bytes = data.len();
+        let encrypted = self.operator.read(file).await?;
+        let bytes = encrypted.len();
+        let data = self.cipher.decrypt(&encrypted)?;
 
         let mut begin = 0;
         let instant = Instant::now();
@@ -147,13 +153,7 @@ impl Spiller {
         let instant = Instant::now();
         let unique_name = GlobalUniqName::unique();
         let location = format!("{}/{}", self.config.location_prefix, unique_name);
-        let mut write_bytes = 0;
 
-        let mut writer = self
-            .operator
-            .writer_with(&location)
-            .buffer(8 * 1024 * 1024)
-            .await?;
         let columns = data.columns().to_vec();
         let mut columns_data = Vec::with_capacity(columns.len());
         for column in columns.into_iter() {
@@ -165,14 +165,12 @@ impl Spiller {
                     layouts.push(column_data.len());
                 })
                 .or_insert(vec![column_data.len()]);
-            write_bytes += column_data.len();
             columns_data.push(column_data);
         }
 
-        for data in columns_data.into_iter() {
-            writer.write(data).await?;
-        }
-        writer.close().await?;
+        let data = self.cipher.encrypt(&columns_data.concat())?;
+        let write_bytes = data.len();
+        self.operator.write(&location, data).await?;
 
         Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, 1);
         Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
@@ -0,0 +1,246 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::escape_for_key;
+use databend_common_base::base::tokio;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::GLOBAL_TASK;
+use databend_common_catalog::catalog::CATALOG_DEFAULT;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi::KVApi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_storages_fuse::io::MetaReaders;
+use databend_common_storages_fuse::io::SegmentsIO;
+use databend_common_storages_fuse::io::SnapshotHistoryReader;
+use databend_common_storages_fuse::io::TableMetaLocationGenerator;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_system::write_storage_usage_history;
+use databend_common_storages_system::StorageUsageRecord;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
+use futures::TryStreamExt;
+use log::info;
+use log::warn;
+
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+const STORAGE_USAGE_KEY_PREFIX: &str = "__fd_storage_usage";
+
+/// Collects the storage usage of the tables of the tenant into `system.storage_usage_history`
+/// periodically, so the usage over time can be queried without listing the storage.
+///
+/// Only one node of the tenant collects in a round: the node that creates the lease key in
+/// the meta, which expires after the collect interval. The collections are persisted in the
+/// data storage, see [`write_storage_usage_history`].
+///
+/// The row count and the sizes of the current snapshot come from the table statistics, the
+/// time travel bytes from the segments of the previous snapshots.
+pub struct StorageUsageCollector {
+    tenant: Tenant,
+    interval: Duration,
+}
+
+impl StorageUsageCollector {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(StorageUsageCollector {
+            tenant: conf.query.tenant_id.clone(),
+            interval: Duration::from_secs(conf.query.storage_usage_collect_interval_secs),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<StorageUsageCollector> {
+        GlobalInstance::get()
+    }
+
+    /// Starts collecting the storage usage, unless the collect interval is 0.
+    pub fn start(self: &Arc<Self>) {
+        if self.interval.is_zero() {
+            return;
+        }
+
+        let this = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            loop {
+                tokio::time::sleep(this.interval).await;
+                match this.try_acquire_lease().await {
+                    Ok(true) => {
+                        if let Err(cause) = this.collect().await {
+                            warn!("Failed to collect the storage usage, cause {:?}", cause);
+                        }
+                    }
+                    Ok(false) => {}
+                    Err(cause) => {
+                        warn!(
+                            "Failed to acquire the storage usage collect lease, cause {:?}",
+                            cause
+                        );
+                    }
+                }
+            }
+        });
+    }
+
+    /// Creates the lease key of the tenant, returns false if another node holds it.
+    #[async_backtrace::framed]
+    async fn try_acquire_lease(&self) -> Result<bool> {
+        let key = format!(
+            "{}/{}/collector",
+            STORAGE_USAGE_KEY_PREFIX,
+            escape_for_key(self.tenant.tenant_name())?
+        );
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let reply = meta_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::Exact(0),
+                Operation::Update(vec![]),
+                Some(MetaSpec::new_ttl(self.interval)),
+            ))
+            .await?;
+        Ok(reply.is_changed())
+    }
+
+    /// Collects and persists the storage usage of the tables once, returns the number of
+    /// the collected tables.
+    #[async_backtrace::framed]
+    pub async fn collect(&self) -> Result<usize> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::Dummy)
+            .await?;
+        let ctx = session.create_query_context().await?;
+        let catalog = ctx.get_default_catalog()?;
+
+        let collect_time = Utc::now().timestamp_micros();
+        let mut records = vec![];
+        for db in catalog.list_databases(&self.tenant).await? {
+            // The tables of a database created from a share are stored by the producer.
+            if db.name() == "system"
+                || db.name() == "information_schema"
+                || db.get_db_info().meta.from_share.is_some()
+            {
+                continue;
+            }
+
+            for table in catalog.list_tables(&self.tenant, db.name()).await? {
+                if matches!(table.engine(), VIEW_ENGINE | STREAM_ENGINE) {
+                    continue;
+                }
+
+                let stats = &table.get_table_info().meta.statistics;
+                let time_travel_bytes = match Self::time_travel_bytes(table.as_ref()).await {
+                    Ok(bytes) => bytes,
+                    Err(cause) => {
+                        warn!(
+                            "Failed to read the snapshots of table {}.{}, cause {:?}",
+                            db.name(),
+                            table.name(),
+                            cause
+                        );
+                        0
+                    }
+                };
+
+                records.push(StorageUsageRecord {
+                    collect_time,
+                    catalog: CATALOG_DEFAULT.to_string(),
+                    database: db.name().to_string(),
+                    table: table.name().to_string(),
+                    engine: table.engine().to_string(),
+                    row_count: stats.number_of_rows,
+                    logical_bytes: stats.data_bytes,
+                    physical_bytes: stats.compressed_data_bytes + stats.index_data_bytes,
+                    time_travel_bytes,
+                });
+            }
+        }
+
+        write_storage_usage_history(self.tenant.tenant_name(), collect_time, &records).await?;
+        info!("Collected the storage usage of {} tables", records.len());
+        Ok(records.len())
+    }
+
+    /// Returns the size of the blocks and bloom indexes referenced by the previous snapshots
+    /// of a fuse table but not by the current one, 0 for the other tables and the attached
+    /// tables, whose files belong to the table they are attached to.
+    ///
+    /// The sizes come from the block metas of the segments, a block shared by several
+    /// segments (e.g. after a segment compaction) is counted once.
+    #[async_backtrace::framed]
+    async fn time_travel_bytes(table: &dyn Table) -> Result<u64> {
+        let Ok(fuse_table) = FuseTable::try_from_table(table) else {
+            return Ok(0);
+        };
+        if table.is_read_only() || table.options().contains_key(OPT_KEY_STORAGE_PREFIX) {
+            return Ok(0);
+        }
+        let Some(location) = fuse_table.snapshot_loc().await? else {
+            return Ok(0);
+        };
+
+        let operator = fuse_table.get_operator();
+        let schema = fuse_table.schema();
+        let format_version = TableMetaLocationGenerator::snapshot_version(&location);
+        let reader = MetaReaders::table_snapshot_reader(operator.clone());
+        // The snapshots are ordered by timestamp DESC, the first one is the current snapshot.
+        let mut snapshots = reader.snapshot_history(
+            location,
+            format_version,
+            fuse_table.meta_location_generator().clone(),
+        );
+
+        let mut segments = HashSet::new();
+        let mut blocks = HashSet::new();
+        let mut is_current = true;
+        let mut total = 0;
+        while let Some((snapshot, _)) = snapshots.try_next().await? {
+            for location in &snapshot.segments {
+                if !segments.insert(location.clone()) {
+                    continue;
+                }
+
+                let segment = SegmentsIO::read_compact_segment(
+                    operator.clone(),
+                    location.clone(),
+                    schema.clone(),
+                    false,
+                )
+                .await?;
+                for block in segment.block_metas()? {
+                    if blocks.insert(block.location.0.clone()) && !is_current {
+                        total += block.file_size + block.bloom_filter_index_size;
+                    }
+                }
+            }
+            is_current = false;
+        }
+        Ok(total)
+    }
+}
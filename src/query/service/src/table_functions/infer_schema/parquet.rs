@@ -26,6 +26,7 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::ProcessorPtr;
@@ -36,6 +37,7 @@ use databend_common_storage::init_stage_operator;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
+use databend_common_storages_stage::infer_xlsx_schema;
 use opendal::Scheme;
 
 use crate::table_functions::infer_schema::infer_schema_table::INFER_SCHEMA;
@@ -145,9 +147,16 @@ impl AsyncSource for ParquetInferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Xlsx => {
+                let FileFormatParams::Xlsx(params) = &file_format_params else {
+                    unreachable!()
+                };
+                let data = operator.read(&first_file.path).await?;
+                infer_xlsx_schema(data, params, &first_file.path)?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet and XLSX",
                 ));
             }
         };
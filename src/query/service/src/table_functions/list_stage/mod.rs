@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod directory_table;
 mod list_stage_table;
 mod table_args;
 
+pub use directory_table::DirectoryTable;
 pub use list_stage_table::ListStageTable;
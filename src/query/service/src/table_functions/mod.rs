@@ -24,15 +24,20 @@ mod srf;
 mod sync_crash_me;
 mod table_function;
 mod table_function_factory;
+mod tpch;
 
 pub use numbers::generate_numbers_parts;
 pub use numbers::NumbersPartInfo;
 pub use numbers::NumbersTable;
 pub use openai::GPT2SQLTable;
+pub use others::CheckPrivilegeTable;
+pub use others::CollectStorageUsageTable;
 pub use others::ExecuteBackgroundJobTable;
 pub use others::LicenseInfoTable;
+pub use others::PipeStatusTable;
 pub use others::SuggestedBackgroundTasksSource;
 pub use others::SuggestedBackgroundTasksTable;
 pub use others::TenantQuotaTable;
 pub use table_function::TableFunction;
 pub use table_function_factory::TableFunctionFactory;
+pub use tpch::TpchGenTable;
@@ -0,0 +1,448 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::GrantEntry;
+use databend_common_meta_app::principal::GrantObject;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_meta_app::principal::UserPrivilegeSet;
+use databend_common_meta_app::principal::UserPrivilegeType;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_factory::Table;
+use databend_common_storages_fuse::table_functions::string_value;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_common_users::BUILTIN_ROLE_ACCOUNT_ADMIN;
+use databend_common_users::BUILTIN_ROLE_PUBLIC;
+
+const CHECK_PRIVILEGE: &str = "check_privilege";
+
+/// Explains whether a user has a privilege on an object, it's called by
+/// `CALL system$check_privilege('<user>', '<privilege>', '<object>')`.
+///
+/// Every principal evaluated on validating the privilege takes a row: the user itself and all the
+/// roles related to the user, with the grant entry or the ownership which granted the privilege.
+pub struct CheckPrivilegeTable {
+    user: String,
+    privilege: String,
+    object: String,
+    table_info: TableInfo,
+}
+
+impl CheckPrivilegeTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(3))?;
+        let user = string_value(&args[0])?;
+        let privilege = string_value(&args[1])?;
+        let object = string_value(&args[2])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: CHECK_PRIVILEGE.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(CheckPrivilegeTable {
+            user,
+            privilege,
+            object,
+            table_info,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        TableSchemaRefExt::create(vec![
+            TableField::new("user", TableDataType::String),
+            TableField::new("privilege", TableDataType::String),
+            TableField::new("object", TableDataType::String),
+            TableField::new("grantee", TableDataType::String),
+            TableField::new("granted", TableDataType::Boolean),
+            TableField::new("granted_by", TableDataType::String),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for CheckPrivilegeTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            Scalar::String(self.user.clone()),
+            Scalar::String(self.privilege.clone()),
+            Scalar::String(self.object.clone()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                CheckPrivilegeSource::create(
+                    ctx.clone(),
+                    output,
+                    self.user.clone(),
+                    self.privilege.clone(),
+                    self.object.clone(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for CheckPrivilegeTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct CheckPrivilegeSource {
+    is_finished: bool,
+    user: String,
+    privilege: String,
+    object: String,
+    ctx: Arc<dyn TableContext>,
+}
+
+impl CheckPrivilegeSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        user: String,
+        privilege: String,
+        object: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, CheckPrivilegeSource {
+            is_finished: false,
+            user,
+            privilege,
+            object,
+            ctx,
+        })
+    }
+
+    /// Only the user itself, or the users who are able to grant privileges can explain the
+    /// privileges of a user.
+    async fn ensure_can_check(&self, identity: &UserIdentity) -> Result<()> {
+        let current_user = self.ctx.get_current_user()?;
+        if &current_user.identity() == identity
+            || current_user
+                .grants
+                .verify_privilege(&GrantObject::Global, UserPrivilegeType::Grant)
+        {
+            return Ok(());
+        }
+        let roles = self.ctx.get_all_effective_roles().await?;
+        if roles.iter().any(|r| {
+            r.grants
+                .verify_privilege(&GrantObject::Global, UserPrivilegeType::Grant)
+        }) {
+            return Ok(());
+        }
+        Err(ErrorCode::PermissionDenied(format!(
+            "Permission denied: privilege [Grant] is required on *.* for user {} to check the privileges of user {}",
+            current_user.identity(),
+            identity,
+        )))
+    }
+
+    /// Resolves the object to the grant objects to verify, both by name and by id like
+    /// `PrivilegeAccess` does, and the ownership object if the object can be owned.
+    async fn resolve_object(
+        &self,
+        object: &GrantObject,
+    ) -> Result<(Vec<GrantObject>, Option<OwnershipObject>)> {
+        let tenant = self.ctx.get_tenant();
+        let mut objects = vec![object.clone()];
+        let owner_object = match object {
+            GrantObject::Database(catalog_name, db_name) => {
+                let db_id = self
+                    .ctx
+                    .get_catalog(catalog_name)
+                    .await?
+                    .get_database(&tenant, db_name)
+                    .await?
+                    .get_db_info()
+                    .ident
+                    .db_id;
+                objects.push(GrantObject::DatabaseById(catalog_name.clone(), db_id));
+                Some(OwnershipObject::Database {
+                    catalog_name: catalog_name.clone(),
+                    db_id,
+                })
+            }
+            GrantObject::Table(catalog_name, db_name, table_name) => {
+                let catalog = self.ctx.get_catalog(catalog_name).await?;
+                let db_id = catalog
+                    .get_database(&tenant, db_name)
+                    .await?
+                    .get_db_info()
+                    .ident
+                    .db_id;
+                let table_id = catalog
+                    .get_table(&tenant, db_name, table_name)
+                    .await?
+                    .get_id();
+                objects.push(GrantObject::TableById(
+                    catalog_name.clone(),
+                    db_id,
+                    table_id,
+                ));
+                Some(OwnershipObject::Table {
+                    catalog_name: catalog_name.clone(),
+                    db_id,
+                    table_id,
+                })
+            }
+            GrantObject::Stage(name) => Some(OwnershipObject::Stage { name: name.clone() }),
+            GrantObject::UDF(name) => Some(OwnershipObject::UDF { name: name.clone() }),
+            GrantObject::Procedure(name) => Some(OwnershipObject::Procedure { name: name.clone() }),
+            GrantObject::Global
+            | GrantObject::DatabaseById(_, _)
+            | GrantObject::TableById(_, _, _) => None,
+        };
+        Ok((objects, owner_object))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for CheckPrivilegeSource {
+    const NAME: &'static str = CHECK_PRIVILEGE;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.is_finished {
+            return Ok(None);
+        }
+        self.is_finished = true;
+
+        let tenant = self.ctx.get_tenant();
+        let identity = parse_user_identity(&self.user);
+        self.ensure_can_check(&identity).await?;
+
+        let privilege = parse_privilege(&self.privilege)?;
+        let object = parse_grant_object(
+            &self.object,
+            &self.ctx.get_current_catalog(),
+            &self.ctx.get_current_database(),
+        )?;
+        let (objects, owner_object) = self.resolve_object(&object).await?;
+
+        let user = UserApiProvider::instance()
+            .get_user(&tenant, identity.clone())
+            .await?;
+
+        let role_cache = RoleCacheManager::instance();
+        let owner_role = match &owner_object {
+            Some(owner_object) => Some(
+                role_cache
+                    .find_object_owner(&tenant, owner_object)
+                    .await?
+                    .unwrap_or_else(|| BUILTIN_ROLE_ACCOUNT_ADMIN.to_string()),
+            ),
+            None => None,
+        };
+
+        let mut role_names = user.grants.roles();
+        role_names.push(BUILTIN_ROLE_PUBLIC.to_string());
+        if let Some(default_role) = user.option.default_role() {
+            role_names.push(default_role.clone());
+        }
+        let mut roles = role_cache.find_related_roles(&tenant, &role_names).await?;
+        roles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // the grants by id are shown by the names of the object being checked.
+        let find_entry = |entries: Vec<GrantEntry>| {
+            let entry = entries.into_iter().find(|e| {
+                objects
+                    .iter()
+                    .any(|object| e.verify_privilege(object, privilege))
+            })?;
+            let named_object = match (entry.object(), &object) {
+                (
+                    GrantObject::DatabaseById(_, _),
+                    GrantObject::Database(catalog, db) | GrantObject::Table(catalog, db, _),
+                ) => GrantObject::Database(catalog.clone(), db.clone()),
+                (GrantObject::TableById(_, _, _), GrantObject::Table(_, _, _)) => object.clone(),
+                (named_object, _) => named_object.clone(),
+            };
+            Some(GrantEntry::new(named_object, *entry.privileges()))
+        };
+
+        let mut grantees = vec![format!("USER {}", identity)];
+        let mut granted_by = vec![find_entry(user.grants.entries()).map(|e| e.to_string())];
+        for role in roles {
+            let by = match find_entry(role.grants.entries()) {
+                Some(entry) => Some(entry.to_string()),
+                None if owner_role.as_ref() == Some(&role.name) => Some("OWNERSHIP".to_string()),
+                None => None,
+            };
+            grantees.push(format!("ROLE {}", role.name));
+            granted_by.push(by);
+        }
+
+        let num_rows = grantees.len();
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![identity.to_string(); num_rows]),
+            StringType::from_data(vec![privilege.to_string(); num_rows]),
+            StringType::from_data(vec![object.to_string(); num_rows]),
+            StringType::from_data(grantees),
+            BooleanType::from_data(granted_by.iter().map(|by| by.is_some()).collect::<Vec<_>>()),
+            StringType::from_data(
+                granted_by
+                    .into_iter()
+                    .map(|by| by.unwrap_or_default())
+                    .collect::<Vec<_>>(),
+            ),
+        ])))
+    }
+}
+
+/// Parses the user like `u1`, `u1@%` or `'u1'@'%'`, the host is `%` if it's omitted.
+fn parse_user_identity(user: &str) -> UserIdentity {
+    let user = user.trim();
+    match user.rsplit_once('@') {
+        Some((name, host)) => UserIdentity::new(name.trim_matches('\''), host.trim_matches('\'')),
+        None => UserIdentity::new(user.trim_matches('\''), "%"),
+    }
+}
+
+/// Parses the privilege by its name in the GRANT statement, like `select` or `create user`.
+fn parse_privilege(privilege: &str) -> Result<UserPrivilegeType> {
+    let name = privilege.trim().replace('_', " ");
+    UserPrivilegeSet::all_privileges()
+        .iter()
+        .chain(std::iter::once(UserPrivilegeType::Usage))
+        .find(|p| p.to_string().eq_ignore_ascii_case(&name))
+        .ok_or_else(|| ErrorCode::BadArguments(format!("Unknown privilege '{}'", privilege)))
+}
+
+/// Parses the object in the forms of the GRANT statement: `*.*`, `db.*`, `db.table`, `table`,
+/// `catalog.db.table`, `STAGE name`, `UDF name` and `PROCEDURE name`.
+fn parse_grant_object(object: &str, catalog: &str, database: &str) -> Result<GrantObject> {
+    let object = object.trim();
+    if let Some((kind, name)) = object.split_once(char::is_whitespace) {
+        let name = name.trim().to_string();
+        if kind.eq_ignore_ascii_case("stage") {
+            return Ok(GrantObject::Stage(name));
+        }
+        if kind.eq_ignore_ascii_case("udf") {
+            return Ok(GrantObject::UDF(name));
+        }
+        if kind.eq_ignore_ascii_case("procedure") {
+            return Ok(GrantObject::Procedure(name));
+        }
+    }
+
+    let parts = object
+        .split('.')
+        .map(|s| s.trim().trim_matches(|c| c == '`' || c == '"' || c == '\''))
+        .collect::<Vec<_>>();
+    match parts.as_slice() {
+        ["*"] | ["*", "*"] => Ok(GrantObject::Global),
+        [db, "*"] => Ok(GrantObject::Database(catalog.to_string(), db.to_string())),
+        [table] => Ok(GrantObject::Table(
+            catalog.to_string(),
+            database.to_string(),
+            table.to_string(),
+        )),
+        [db, table] => Ok(GrantObject::Table(
+            catalog.to_string(),
+            db.to_string(),
+            table.to_string(),
+        )),
+        [catalog, db, table] => Ok(GrantObject::Table(
+            catalog.to_string(),
+            db.to_string(),
+            table.to_string(),
+        )),
+        _ => Err(ErrorCode::BadArguments(format!(
+            "Invalid object '{}', expect *.*, db.*, db.table, STAGE name, UDF name or PROCEDURE name",
+            object
+        ))),
+    }
+}
@@ -12,14 +12,20 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check_privilege;
+mod collect_storage_usage;
 mod execute_background_job;
 mod license_info;
+mod pipe_status;
 mod suggested_background_compaction_tasks;
 mod suggested_background_tasks;
 mod tenant_quota;
 
+pub use check_privilege::CheckPrivilegeTable;
+pub use collect_storage_usage::CollectStorageUsageTable;
 pub use execute_background_job::ExecuteBackgroundJobTable;
 pub use license_info::LicenseInfoTable;
+pub use pipe_status::PipeStatusTable;
 pub use suggested_background_tasks::SuggestedBackgroundTasksSource;
 pub use suggested_background_tasks::SuggestedBackgroundTasksTable;
 pub use tenant_quota::TenantQuotaTable;
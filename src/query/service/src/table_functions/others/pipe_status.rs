@@ -0,0 +1,220 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::table_function::TableFunction;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_factory::Table;
+use databend_common_storages_fuse::table_functions::string_value;
+use databend_common_users::UserApiProvider;
+
+use crate::pipes::PipeManager;
+
+const PIPE_STATUS: &str = "pipe_status";
+
+/// The load status of a pipe on the current node, it's called by `CALL system$pipe_status('<pipe>')`.
+pub struct PipeStatusTable {
+    pipe_name: String,
+    table_info: TableInfo,
+}
+
+impl PipeStatusTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(1))?;
+        let pipe_name = string_value(&args[0])?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: Self::schema(),
+                engine: PIPE_STATUS.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(PipeStatusTable {
+            pipe_name,
+            table_info,
+        }))
+    }
+
+    fn schema() -> Arc<TableSchema> {
+        let nullable = |ty| TableDataType::Nullable(Box::new(ty));
+        TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("execution_state", TableDataType::String),
+            TableField::new(
+                "pending_file_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "loaded_file_count",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("last_ingested_file", nullable(TableDataType::String)),
+            TableField::new("last_ingested_on", nullable(TableDataType::Timestamp)),
+            TableField::new("last_polled_on", nullable(TableDataType::Timestamp)),
+            TableField::new("last_error", nullable(TableDataType::String)),
+        ])
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for PipeStatusTable {
+    fn is_local(&self) -> bool {
+        true
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![Scalar::String(
+            self.pipe_name.clone(),
+        )]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| PipeStatusSource::create(ctx.clone(), output, self.pipe_name.clone()),
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for PipeStatusTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct PipeStatusSource {
+    is_finished: bool,
+    pipe_name: String,
+    ctx: Arc<dyn TableContext>,
+}
+
+impl PipeStatusSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        pipe_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, PipeStatusSource {
+            is_finished: false,
+            pipe_name,
+            ctx,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for PipeStatusSource {
+    const NAME: &'static str = PIPE_STATUS;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.is_finished {
+            return Ok(None);
+        }
+        self.is_finished = true;
+
+        let pipe = UserApiProvider::instance()
+            .get_pipe(&self.ctx.get_tenant(), &self.pipe_name)
+            .await?;
+        let status = PipeManager::instance().status(&pipe.name);
+
+        let execution_state = match pipe.execution_paused {
+            true => "PAUSED",
+            false => "RUNNING",
+        };
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(vec![pipe.name]),
+            StringType::from_data(vec![execution_state.to_string()]),
+            UInt64Type::from_data(vec![status.pending_files.len() as u64]),
+            UInt64Type::from_data(vec![status.loaded_file_count]),
+            StringType::from_opt_data(vec![status.last_ingested_file]),
+            TimestampType::from_opt_data(vec![status
+                .last_ingested_on
+                .map(|t| t.timestamp_micros())]),
+            TimestampType::from_opt_data(vec![status.last_polled_on.map(|t| t.timestamp_micros())]),
+            StringType::from_opt_data(vec![status.last_error]),
+        ])))
+    }
+}
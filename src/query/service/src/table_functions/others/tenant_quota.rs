@@ -30,6 +30,7 @@ use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::UInt32Type;
+use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
@@ -77,6 +78,11 @@ impl TenantQuotaTable {
                 "max_files_per_stage",
                 TableDataType::Number(NumberDataType::UInt32),
             ),
+            TableField::new("max_users", TableDataType::Number(NumberDataType::UInt32)),
+            TableField::new(
+                "max_copy_file_size",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
         ])
     }
 
@@ -210,6 +216,14 @@ impl TenantQuotaSource {
                     DataType::Number(NumberDataType::UInt32),
                     Value::Scalar(UInt32Type::upcast_scalar(quota.max_files_per_stage)),
                 ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt32),
+                    Value::Scalar(UInt32Type::upcast_scalar(quota.max_users)),
+                ),
+                BlockEntry::new(
+                    DataType::Number(NumberDataType::UInt64),
+                    Value::Scalar(UInt64Type::upcast_scalar(quota.max_copy_file_size)),
+                ),
             ],
             1,
         ))
@@ -222,6 +236,8 @@ impl TenantQuotaSource {
 /// max_tables_per_database: u32
 /// max_stages: u32
 /// max_files_per_stage: u32
+/// max_users: u32
+/// max_copy_file_size: u64
 #[async_trait::async_trait]
 impl AsyncSource for TenantQuotaSource {
     const NAME: &'static str = "tenant_quota";
@@ -265,6 +281,12 @@ impl AsyncSource for TenantQuotaSource {
         if let Some(max_files_per_stage) = args.get(4) {
             quota.max_files_per_stage = max_files_per_stage.as_str().parse::<u32>()?
         };
+        if let Some(max_users) = args.get(5) {
+            quota.max_users = max_users.as_str().parse::<u32>()?
+        };
+        if let Some(max_copy_file_size) = args.get(6) {
+            quota.max_copy_file_size = max_copy_file_size.as_str().parse::<u64>()?
+        };
 
         quota_api
             .set_quota(&quota, MatchSeq::Exact(res.seq))
@@ -25,8 +25,11 @@ use databend_common_storages_stream::stream_status_table_func::StreamStatusTable
 use itertools::Itertools;
 use parking_lot::RwLock;
 
+use super::CheckPrivilegeTable;
+use super::CollectStorageUsageTable;
 use super::ExecuteBackgroundJobTable;
 use super::LicenseInfoTable;
+use super::PipeStatusTable;
 use super::SuggestedBackgroundTasksTable;
 use super::TenantQuotaTable;
 use crate::catalogs::SYS_TBL_FUC_ID_END;
@@ -41,12 +44,14 @@ use crate::table_functions::cloud::TaskDependentsEnableTable;
 use crate::table_functions::cloud::TaskDependentsTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::inspect_parquet::InspectParquetTable;
+use crate::table_functions::list_stage::DirectoryTable;
 use crate::table_functions::list_stage::ListStageTable;
 use crate::table_functions::numbers::NumbersTable;
 use crate::table_functions::srf::RangeTable;
 use crate::table_functions::sync_crash_me::SyncCrashMeTable;
 use crate::table_functions::GPT2SQLTable;
 use crate::table_functions::TableFunction;
+use crate::table_functions::TpchGenTable;
 
 type TableFunctionCreators = RwLock<HashMap<String, (MetaId, Arc<dyn TableFunctionCreator>)>>;
 
@@ -168,6 +173,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(ListStageTable::create)),
         );
 
+        creators.insert(
+            "directory".to_string(),
+            (next_id(), Arc::new(DirectoryTable::create)),
+        );
+
         creators.insert(
             "generate_series".to_string(),
             (next_id(), Arc::new(RangeTable::create)),
@@ -193,6 +203,21 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(LicenseInfoTable::create)),
         );
 
+        creators.insert(
+            "pipe_status".to_string(),
+            (next_id(), Arc::new(PipeStatusTable::create)),
+        );
+
+        creators.insert(
+            "check_privilege".to_string(),
+            (next_id(), Arc::new(CheckPrivilegeTable::create)),
+        );
+
+        creators.insert(
+            "collect_storage_usage".to_string(),
+            (next_id(), Arc::new(CollectStorageUsageTable::create)),
+        );
+
         creators.insert(
             "suggested_background_tasks".to_string(),
             (next_id(), Arc::new(SuggestedBackgroundTasksTable::create)),
@@ -218,6 +243,11 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(TaskDependentsEnableTable::create)),
         );
 
+        creators.insert(
+            "tpch_gen".to_string(),
+            (next_id(), Arc::new(TpchGenTable::create)),
+        );
+
         TableFunctionFactory {
             creators: RwLock::new(creators),
         }
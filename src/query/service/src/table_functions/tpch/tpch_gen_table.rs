@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::TableStatistics;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_number;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::F64;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_core::SourcePipeBuilder;
+use databend_common_pipeline_sources::EmptySource;
+use databend_common_pipeline_sources::SyncSource;
+use databend_common_pipeline_sources::SyncSourcer;
+use databend_storages_common_table_meta::table::ChangeType;
+
+use super::generate_tpch_parts;
+use super::TpchGenerator;
+use super::TpchPartInfo;
+use super::TpchTableKind;
+use crate::pipelines::processors::OutputPort;
+use crate::pipelines::processors::ProcessorPtr;
+use crate::sessions::TableContext;
+use crate::storages::fuse::table_functions::string_value;
+use crate::storages::Table;
+use crate::table_functions::TableFunction;
+
+/// `tpch_gen(scale_factor, table)` generates the data of a TPC-H table in parallel, e.g.
+/// `INSERT INTO lineitem SELECT * FROM tpch_gen(1, 'lineitem')`.
+///
+/// Only the eight TPC-H tables are generated, the TPC-DS tables are not.
+pub struct TpchGenTable {
+    table_info: TableInfo,
+    scale_factor: f64,
+    kind: TpchTableKind,
+}
+
+impl TpchGenTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let args = table_args.expect_all_positioned(table_func_name, Some(2))?;
+        let scale_factor = check_number::<_, F64>(
+            None,
+            &FunctionContext::default(),
+            &Expr::<usize>::Constant {
+                span: None,
+                scalar: args[0].clone(),
+                data_type: args[0].as_ref().infer_data_type(),
+            },
+            &BUILTIN_FUNCTIONS,
+        )?
+        .into_inner();
+        if !(scale_factor > 0.0 && scale_factor.is_finite()) {
+            return Err(ErrorCode::BadArguments(format!(
+                "scale factor of {} must be a positive number, but got {}",
+                table_func_name, scale_factor
+            )));
+        }
+        let kind = TpchTableKind::from_name(&string_value(&args[1])?)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: kind.schema(),
+                engine: "TpchGen".to_string(),
+                // Assuming that created_on is unnecessary for function table,
+                // we could make created_on fixed to pass test_shuffle_action_try_into.
+                created_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                updated_on: Utc
+                    .from_utc_datetime(&NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(TpchGenTable {
+            table_info,
+            scale_factor,
+            kind,
+        }))
+    }
+
+    fn generator(&self) -> TpchGenerator {
+        TpchGenerator::new(self.kind, self.scale_factor)
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for TpchGenTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        let mut total = self.generator().num_units();
+
+        // every unit generates at least one row.
+        if let Some(extras) = &push_downs {
+            if extras.filters.is_none() && extras.order_by.is_empty() {
+                if let Some(limit) = extras.limit {
+                    total = std::cmp::min(total, limit as u64);
+                }
+            }
+        }
+
+        let max_block_size = ctx.get_settings().get_max_block_size()?;
+        let num_rows = total * self.kind.rows_per_unit();
+        let fake_partitions = (num_rows / max_block_size) + 1;
+        let statistics = PartStatistics::new_estimated(
+            None,
+            num_rows as usize,
+            0,
+            fake_partitions as usize,
+            fake_partitions as usize,
+        );
+
+        let cluster = ctx.get_cluster();
+        let max_threads = ctx.get_settings().get_max_threads()?;
+        let workers = std::cmp::min(max_threads * cluster.nodes.len() as u64, fake_partitions);
+
+        Ok((
+            statistics,
+            generate_tpch_parts(self.kind.name(), workers, total),
+        ))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(TableArgs::new_positioned(vec![
+            Scalar::Number(NumberScalar::Float64(self.scale_factor.into())),
+            Scalar::String(self.kind.name().to_string()),
+        ]))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        if plan.parts.partitions.is_empty() {
+            pipeline.add_source(EmptySource::create, 1)?;
+            return Ok(());
+        }
+
+        let generator = Arc::new(self.generator());
+        let mut source_builder = SourcePipeBuilder::create();
+        for part in &plan.parts.partitions {
+            let output = OutputPort::create();
+            source_builder.add_source(
+                output.clone(),
+                TpchGenSource::create(output, ctx.clone(), generator.clone(), self.kind, part)?,
+            );
+        }
+
+        pipeline.add_pipe(source_builder.finalize());
+        Ok(())
+    }
+
+    async fn table_statistics(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _change_type: Option<ChangeType>,
+    ) -> Result<Option<TableStatistics>> {
+        let num_rows = self.generator().num_units() * self.kind.rows_per_unit();
+        Ok(Some(TableStatistics {
+            num_rows: Some(num_rows),
+            ..Default::default()
+        }))
+    }
+}
+
+struct TpchGenSource {
+    generator: Arc<TpchGenerator>,
+    begin: u64,
+    end: u64,
+    step: u64,
+}
+
+impl TpchGenSource {
+    pub fn create(
+        output: Arc<OutputPort>,
+        ctx: Arc<dyn TableContext>,
+        generator: Arc<TpchGenerator>,
+        kind: TpchTableKind,
+        part: &PartInfoPtr,
+    ) -> Result<ProcessorPtr> {
+        let part = TpchPartInfo::from_part(part)?;
+        let max_block_size = ctx.get_settings().get_max_block_size()?;
+
+        SyncSourcer::create(ctx, output, TpchGenSource {
+            generator,
+            begin: part.part_start,
+            end: part.part_end,
+            step: std::cmp::max(max_block_size / kind.rows_per_unit(), 1),
+        })
+    }
+}
+
+impl SyncSource for TpchGenSource {
+    const NAME: &'static str = "TpchGenSource";
+
+    fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.begin >= self.end {
+            return Ok(None);
+        }
+
+        let end = std::cmp::min(self.begin + self.step, self.end);
+        let block = self.generator.generate(self.begin, end);
+        self.begin = end;
+        Ok(Some(block))
+    }
+}
+
+impl TableFunction for TpchGenTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
@@ -0,0 +1,966 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Data generator of the TPC-H tables.
+//!
+//! The data follows the value domains and the key relationships of the TPC-H specification,
+//! but it is not byte-identical with the output of `dbgen`. Every row is generated from a
+//! random number generator seeded by the table and the row index, so any range of the rows
+//! can be generated independently and the result does not depend on the parallelism.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::Decimal128Type;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use rand::rngs::SmallRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+const MONEY_SIZE: DecimalSize = DecimalSize {
+    precision: 15,
+    scale: 2,
+};
+
+// days since 1970-01-01
+const START_DATE: i32 = 8035; // 1992-01-01
+const CURRENT_DATE: i32 = 9298; // 1995-06-17
+const END_DATE: i32 = 10591; // 1998-12-31
+const ORDER_DATE_MAX: i32 = END_DATE - 151;
+
+const REGIONS: [&str; 5] = ["AFRICA", "AMERICA", "ASIA", "EUROPE", "MIDDLE EAST"];
+
+const NATIONS: [(&str, i32); 25] = [
+    ("ALGERIA", 0),
+    ("ARGENTINA", 1),
+    ("BRAZIL", 1),
+    ("CANADA", 1),
+    ("EGYPT", 4),
+    ("ETHIOPIA", 0),
+    ("FRANCE", 3),
+    ("GERMANY", 3),
+    ("INDIA", 2),
+    ("INDONESIA", 2),
+    ("IRAN", 4),
+    ("IRAQ", 4),
+    ("JAPAN", 2),
+    ("JORDAN", 4),
+    ("KENYA", 0),
+    ("MOROCCO", 0),
+    ("MOZAMBIQUE", 0),
+    ("PERU", 1),
+    ("CHINA", 2),
+    ("ROMANIA", 3),
+    ("SAUDI ARABIA", 4),
+    ("VIETNAM", 2),
+    ("RUSSIA", 3),
+    ("UNITED KINGDOM", 3),
+    ("UNITED STATES", 1),
+];
+
+const COLORS: [&str; 92] = [
+    "almond",
+    "antique",
+    "aquamarine",
+    "azure",
+    "beige",
+    "bisque",
+    "black",
+    "blanched",
+    "blue",
+    "blush",
+    "brown",
+    "burlywood",
+    "burnished",
+    "chartreuse",
+    "chiffon",
+    "chocolate",
+    "coral",
+    "cornflower",
+    "cornsilk",
+    "cream",
+    "cyan",
+    "dark",
+    "deep",
+    "dim",
+    "dodger",
+    "drab",
+    "firebrick",
+    "floral",
+    "forest",
+    "frosted",
+    "gainsboro",
+    "ghost",
+    "goldenrod",
+    "green",
+    "grey",
+    "honeydew",
+    "hot",
+    "indian",
+    "ivory",
+    "khaki",
+    "lace",
+    "lavender",
+    "lawn",
+    "lemon",
+    "light",
+    "lime",
+    "linen",
+    "magenta",
+    "maroon",
+    "medium",
+    "metallic",
+    "midnight",
+    "mint",
+    "misty",
+    "moccasin",
+    "navajo",
+    "navy",
+    "olive",
+    "orange",
+    "orchid",
+    "pale",
+    "papaya",
+    "peach",
+    "peru",
+    "pink",
+    "plum",
+    "powder",
+    "puff",
+    "purple",
+    "red",
+    "rose",
+    "rosy",
+    "royal",
+    "saddle",
+    "salmon",
+    "sandy",
+    "seashell",
+    "sienna",
+    "sky",
+    "slate",
+    "smoke",
+    "snow",
+    "spring",
+    "steel",
+    "tan",
+    "thistle",
+    "tomato",
+    "turquoise",
+    "violet",
+    "wheat",
+    "white",
+    "yellow",
+];
+
+const TYPE_SYLLABLES: [&[&str]; 3] = [
+    &["STANDARD", "SMALL", "MEDIUM", "LARGE", "ECONOMY", "PROMO"],
+    &["ANODIZED", "BURNISHED", "PLATED", "POLISHED", "BRUSHED"],
+    &["TIN", "NICKEL", "BRASS", "STEEL", "COPPER"],
+];
+
+const CONTAINER_SYLLABLES: [&[&str]; 2] = [&["SM", "LG", "MED", "JUMBO", "WRAP"], &[
+    "CASE", "BOX", "BAG", "JAR", "PKG", "PACK", "CAN", "DRUM",
+]];
+
+const SEGMENTS: [&str; 5] = [
+    "AUTOMOBILE",
+    "BUILDING",
+    "FURNITURE",
+    "MACHINERY",
+    "HOUSEHOLD",
+];
+
+const PRIORITIES: [&str; 5] = ["1-URGENT", "2-HIGH", "3-MEDIUM", "4-NOT SPECIFIED", "5-LOW"];
+
+const SHIP_INSTRUCTIONS: [&str; 4] = [
+    "DELIVER IN PERSON",
+    "COLLECT COD",
+    "NONE",
+    "TAKE BACK RETURN",
+];
+
+const SHIP_MODES: [&str; 7] = ["REG AIR", "AIR", "RAIL", "SHIP", "TRUCK", "MAIL", "FOB"];
+
+const WORDS: [&str; 64] = [
+    "foxes",
+    "ideas",
+    "theodolites",
+    "pinto",
+    "beans",
+    "instructions",
+    "dependencies",
+    "excuses",
+    "platelets",
+    "asymptotes",
+    "courts",
+    "dolphins",
+    "multipliers",
+    "warthogs",
+    "frets",
+    "packages",
+    "requests",
+    "accounts",
+    "deposits",
+    "pearls",
+    "sleep",
+    "wake",
+    "are",
+    "cajole",
+    "haggle",
+    "nag",
+    "use",
+    "boost",
+    "affix",
+    "detect",
+    "integrate",
+    "maintain",
+    "nod",
+    "solve",
+    "furious",
+    "sly",
+    "careful",
+    "blithe",
+    "quick",
+    "fluffy",
+    "slow",
+    "quiet",
+    "ruthless",
+    "final",
+    "ironic",
+    "even",
+    "bold",
+    "silent",
+    "regular",
+    "express",
+    "special",
+    "pending",
+    "unusual",
+    "furiously",
+    "slyly",
+    "carefully",
+    "blithely",
+    "quickly",
+    "fluffily",
+    "slowly",
+    "quietly",
+    "ironically",
+    "evenly",
+    "boldly",
+];
+
+const ADDRESS_CHARS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ,";
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TpchTableKind {
+    Region,
+    Nation,
+    Supplier,
+    Customer,
+    Part,
+    PartSupp,
+    Orders,
+    LineItem,
+}
+
+impl TpchTableKind {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "region" => Ok(TpchTableKind::Region),
+            "nation" => Ok(TpchTableKind::Nation),
+            "supplier" => Ok(TpchTableKind::Supplier),
+            "customer" => Ok(TpchTableKind::Customer),
+            "part" => Ok(TpchTableKind::Part),
+            "partsupp" => Ok(TpchTableKind::PartSupp),
+            "orders" => Ok(TpchTableKind::Orders),
+            "lineitem" => Ok(TpchTableKind::LineItem),
+            _ => Err(ErrorCode::BadArguments(format!(
+                "unknown tpch table '{}', expected one of region, nation, supplier, customer, \
+                 part, partsupp, orders and lineitem",
+                name
+            ))),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TpchTableKind::Region => "region",
+            TpchTableKind::Nation => "nation",
+            TpchTableKind::Supplier => "supplier",
+            TpchTableKind::Customer => "customer",
+            TpchTableKind::Part => "part",
+            TpchTableKind::PartSupp => "partsupp",
+            TpchTableKind::Orders => "orders",
+            TpchTableKind::LineItem => "lineitem",
+        }
+    }
+
+    pub fn schema(&self) -> TableSchemaRef {
+        let int = || TableDataType::Number(NumberDataType::Int32);
+        let bigint = || TableDataType::Number(NumberDataType::Int64);
+        let string = || TableDataType::String;
+        let date = || TableDataType::Date;
+        let money = || TableDataType::Decimal(DecimalDataType::Decimal128(MONEY_SIZE));
+
+        let fields = match self {
+            TpchTableKind::Region => vec![
+                ("r_regionkey", int()),
+                ("r_name", string()),
+                ("r_comment", string()),
+            ],
+            TpchTableKind::Nation => vec![
+                ("n_nationkey", int()),
+                ("n_name", string()),
+                ("n_regionkey", int()),
+                ("n_comment", string()),
+            ],
+            TpchTableKind::Supplier => vec![
+                ("s_suppkey", bigint()),
+                ("s_name", string()),
+                ("s_address", string()),
+                ("s_nationkey", int()),
+                ("s_phone", string()),
+                ("s_acctbal", money()),
+                ("s_comment", string()),
+            ],
+            TpchTableKind::Customer => vec![
+                ("c_custkey", bigint()),
+                ("c_name", string()),
+                ("c_address", string()),
+                ("c_nationkey", int()),
+                ("c_phone", string()),
+                ("c_acctbal", money()),
+                ("c_mktsegment", string()),
+                ("c_comment", string()),
+            ],
+            TpchTableKind::Part => vec![
+                ("p_partkey", bigint()),
+                ("p_name", string()),
+                ("p_mfgr", string()),
+                ("p_brand", string()),
+                ("p_type", string()),
+                ("p_size", int()),
+                ("p_container", string()),
+                ("p_retailprice", money()),
+                ("p_comment", string()),
+            ],
+            TpchTableKind::PartSupp => vec![
+                ("ps_partkey", bigint()),
+                ("ps_suppkey", bigint()),
+                ("ps_availqty", bigint()),
+                ("ps_supplycost", money()),
+                ("ps_comment", string()),
+            ],
+            TpchTableKind::Orders => vec![
+                ("o_orderkey", bigint()),
+                ("o_custkey", bigint()),
+                ("o_orderstatus", string()),
+                ("o_totalprice", money()),
+                ("o_orderdate", date()),
+                ("o_orderpriority", string()),
+                ("o_clerk", string()),
+                ("o_shippriority", int()),
+                ("o_comment", string()),
+            ],
+            TpchTableKind::LineItem => vec![
+                ("l_orderkey", bigint()),
+                ("l_partkey", bigint()),
+                ("l_suppkey", bigint()),
+                ("l_linenumber", bigint()),
+                ("l_quantity", money()),
+                ("l_extendedprice", money()),
+                ("l_discount", money()),
+                ("l_tax", money()),
+                ("l_returnflag", string()),
+                ("l_linestatus", string()),
+                ("l_shipdate", date()),
+                ("l_commitdate", date()),
+                ("l_receiptdate", date()),
+                ("l_shipinstruct", string()),
+                ("l_shipmode", string()),
+                ("l_comment", string()),
+            ],
+        };
+
+        TableSchemaRefExt::create(
+            fields
+                .into_iter()
+                .map(|(name, data_type)| TableField::new(name, data_type))
+                .collect(),
+        )
+    }
+
+    /// The number of rows generated for each unit, the units of `partsupp` and
+    /// `lineitem` are the parts and the orders (which have 1 to 7 lines) respectively.
+    pub fn rows_per_unit(&self) -> u64 {
+        match self {
+            TpchTableKind::PartSupp | TpchTableKind::LineItem => 4,
+            _ => 1,
+        }
+    }
+
+    fn seed(&self) -> u64 {
+        (*self as u64 + 1) << 56
+    }
+}
+
+/// The cardinalities of the tables at a scale factor.
+#[derive(Clone, Copy, Debug)]
+struct Scale {
+    suppliers: u64,
+    customers: u64,
+    parts: u64,
+    orders: u64,
+    clerks: u64,
+}
+
+impl Scale {
+    fn new(scale_factor: f64) -> Self {
+        let scaled = |base: f64| ((base * scale_factor) as u64).max(1);
+        Scale {
+            suppliers: scaled(10_000.0),
+            customers: scaled(150_000.0),
+            parts: scaled(200_000.0),
+            orders: scaled(1_500_000.0),
+            clerks: scaled(1_000.0),
+        }
+    }
+}
+
+pub struct TpchGenerator {
+    kind: TpchTableKind,
+    scale: Scale,
+}
+
+impl TpchGenerator {
+    pub fn new(kind: TpchTableKind, scale_factor: f64) -> Self {
+        TpchGenerator {
+            kind,
+            scale: Scale::new(scale_factor),
+        }
+    }
+
+    /// The number of units of the table, see [`TpchTableKind::rows_per_unit`].
+    pub fn num_units(&self) -> u64 {
+        match self.kind {
+            TpchTableKind::Region => REGIONS.len() as u64,
+            TpchTableKind::Nation => NATIONS.len() as u64,
+            TpchTableKind::Supplier => self.scale.suppliers,
+            TpchTableKind::Customer => self.scale.customers,
+            TpchTableKind::Part | TpchTableKind::PartSupp => self.scale.parts,
+            TpchTableKind::Orders | TpchTableKind::LineItem => self.scale.orders,
+        }
+    }
+
+    /// Generates the rows of the units in `[start, end)`.
+    pub fn generate(&self, start: u64, end: u64) -> DataBlock {
+        match self.kind {
+            TpchTableKind::Region => self.generate_region(start, end),
+            TpchTableKind::Nation => self.generate_nation(start, end),
+            TpchTableKind::Supplier => self.generate_supplier(start, end),
+            TpchTableKind::Customer => self.generate_customer(start, end),
+            TpchTableKind::Part => self.generate_part(start, end),
+            TpchTableKind::PartSupp => self.generate_partsupp(start, end),
+            TpchTableKind::Orders => self.generate_orders(start, end),
+            TpchTableKind::LineItem => self.generate_lineitem(start, end),
+        }
+    }
+
+    fn rng(&self, kind: TpchTableKind, index: u64) -> SmallRng {
+        SmallRng::seed_from_u64(kind.seed() ^ index)
+    }
+
+    fn generate_region(&self, start: u64, end: u64) -> DataBlock {
+        let mut keys = vec![];
+        let mut names = vec![];
+        let mut comments = vec![];
+        for index in start..end {
+            let mut rng = self.rng(self.kind, index);
+            keys.push(index as i32);
+            names.push(REGIONS[index as usize].to_string());
+            comments.push(random_text(&mut rng, 31, 115));
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int32Type::from_data(keys),
+            StringType::from_data(names),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_nation(&self, start: u64, end: u64) -> DataBlock {
+        let mut keys = vec![];
+        let mut names = vec![];
+        let mut region_keys = vec![];
+        let mut comments = vec![];
+        for index in start..end {
+            let mut rng = self.rng(self.kind, index);
+            let (name, region_key) = NATIONS[index as usize];
+            keys.push(index as i32);
+            names.push(name.to_string());
+            region_keys.push(region_key);
+            comments.push(random_text(&mut rng, 31, 114));
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int32Type::from_data(keys),
+            StringType::from_data(names),
+            Int32Type::from_data(region_keys),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_supplier(&self, start: u64, end: u64) -> DataBlock {
+        let mut keys = vec![];
+        let mut names = vec![];
+        let mut addresses = vec![];
+        let mut nation_keys = vec![];
+        let mut phones = vec![];
+        let mut balances = vec![];
+        let mut comments = vec![];
+        for index in start..end {
+            let mut rng = self.rng(self.kind, index);
+            let key = index as i64 + 1;
+            let nation_key = rng.gen_range(0..NATIONS.len() as i32);
+            keys.push(key);
+            names.push(format!("Supplier#{:09}", key));
+            addresses.push(random_address(&mut rng));
+            nation_keys.push(nation_key);
+            phones.push(random_phone(&mut rng, nation_key));
+            balances.push(rng.gen_range(-99_999..=999_999) as i128);
+
+            // a few suppliers have complaints or recommendations of the customers, see Q16.
+            let mut comment = random_text(&mut rng, 25, 100);
+            if rng.gen_ratio(10, 10_000) {
+                let remark = match rng.gen_bool(0.5) {
+                    true => "Complaints",
+                    false => "Recommends",
+                };
+                comment = format!("Customer {} {}", comment, remark);
+            }
+            comments.push(comment);
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(keys),
+            StringType::from_data(names),
+            StringType::from_data(addresses),
+            Int32Type::from_data(nation_keys),
+            StringType::from_data(phones),
+            Decimal128Type::from_data_with_size(balances, MONEY_SIZE),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_customer(&self, start: u64, end: u64) -> DataBlock {
+        let mut keys = vec![];
+        let mut names = vec![];
+        let mut addresses = vec![];
+        let mut nation_keys = vec![];
+        let mut phones = vec![];
+        let mut balances = vec![];
+        let mut segments = vec![];
+        let mut comments = vec![];
+        for index in start..end {
+            let mut rng = self.rng(self.kind, index);
+            let key = index as i64 + 1;
+            let nation_key = rng.gen_range(0..NATIONS.len() as i32);
+            keys.push(key);
+            names.push(format!("Customer#{:09}", key));
+            addresses.push(random_address(&mut rng));
+            nation_keys.push(nation_key);
+            phones.push(random_phone(&mut rng, nation_key));
+            balances.push(rng.gen_range(-99_999..=999_999) as i128);
+            segments.push(pick(&mut rng, &SEGMENTS).to_string());
+            comments.push(random_text(&mut rng, 29, 116));
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(keys),
+            StringType::from_data(names),
+            StringType::from_data(addresses),
+            Int32Type::from_data(nation_keys),
+            StringType::from_data(phones),
+            Decimal128Type::from_data_with_size(balances, MONEY_SIZE),
+            StringType::from_data(segments),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_part(&self, start: u64, end: u64) -> DataBlock {
+        let mut keys = vec![];
+        let mut names = vec![];
+        let mut mfgrs = vec![];
+        let mut brands = vec![];
+        let mut types = vec![];
+        let mut sizes = vec![];
+        let mut containers = vec![];
+        let mut prices = vec![];
+        let mut comments = vec![];
+        for index in start..end {
+            let mut rng = self.rng(self.kind, index);
+            let key = index as i64 + 1;
+
+            let mut colors = Vec::with_capacity(5);
+            while colors.len() < 5 {
+                let color = pick(&mut rng, &COLORS);
+                if !colors.contains(&color) {
+                    colors.push(color);
+                }
+            }
+            let manufacturer = rng.gen_range(1..=5);
+
+            keys.push(key);
+            names.push(colors.join(" "));
+            mfgrs.push(format!("Manufacturer#{}", manufacturer));
+            brands.push(format!("Brand#{}{}", manufacturer, rng.gen_range(1..=5)));
+            types.push(
+                TYPE_SYLLABLES
+                    .iter()
+                    .map(|syllables| pick(&mut rng, syllables))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+            sizes.push(rng.gen_range(1..=50));
+            containers.push(
+                CONTAINER_SYLLABLES
+                    .iter()
+                    .map(|syllables| pick(&mut rng, syllables))
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            );
+            prices.push(retail_price(key));
+            comments.push(random_text(&mut rng, 5, 22));
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(keys),
+            StringType::from_data(names),
+            StringType::from_data(mfgrs),
+            StringType::from_data(brands),
+            StringType::from_data(types),
+            Int32Type::from_data(sizes),
+            StringType::from_data(containers),
+            Decimal128Type::from_data_with_size(prices, MONEY_SIZE),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_partsupp(&self, start: u64, end: u64) -> DataBlock {
+        let capacity = ((end - start) * 4) as usize;
+        let mut part_keys = Vec::with_capacity(capacity);
+        let mut supp_keys = Vec::with_capacity(capacity);
+        let mut quantities = Vec::with_capacity(capacity);
+        let mut costs = Vec::with_capacity(capacity);
+        let mut comments = Vec::with_capacity(capacity);
+        for index in start..end {
+            let mut rng = self.rng(self.kind, index);
+            let part_key = index as i64 + 1;
+            for i in 0..4 {
+                part_keys.push(part_key);
+                supp_keys.push(self.part_supplier(part_key, i));
+                quantities.push(rng.gen_range(1..=9_999));
+                costs.push(rng.gen_range(100..=100_000) as i128);
+                comments.push(random_text(&mut rng, 49, 198));
+            }
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(part_keys),
+            Int64Type::from_data(supp_keys),
+            Int64Type::from_data(quantities),
+            Decimal128Type::from_data_with_size(costs, MONEY_SIZE),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_orders(&self, start: u64, end: u64) -> DataBlock {
+        let mut keys = vec![];
+        let mut cust_keys = vec![];
+        let mut statuses = vec![];
+        let mut prices = vec![];
+        let mut dates = vec![];
+        let mut priorities = vec![];
+        let mut clerks = vec![];
+        let mut ship_priorities = vec![];
+        let mut comments = vec![];
+        for index in start..end {
+            let order = self.generate_order(index, false);
+            keys.push(order.key);
+            cust_keys.push(order.cust_key);
+            statuses.push(order.status().to_string());
+            prices.push(order.total_price());
+            dates.push(order.date);
+            priorities.push(order.priority.to_string());
+            clerks.push(order.clerk);
+            ship_priorities.push(0);
+            comments.push(order.comment);
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(keys),
+            Int64Type::from_data(cust_keys),
+            StringType::from_data(statuses),
+            Decimal128Type::from_data_with_size(prices, MONEY_SIZE),
+            DateType::from_data(dates),
+            StringType::from_data(priorities),
+            StringType::from_data(clerks),
+            Int32Type::from_data(ship_priorities),
+            StringType::from_data(comments),
+        ])
+    }
+
+    fn generate_lineitem(&self, start: u64, end: u64) -> DataBlock {
+        let capacity = ((end - start) * 4) as usize;
+        let mut order_keys = Vec::with_capacity(capacity);
+        let mut part_keys = Vec::with_capacity(capacity);
+        let mut supp_keys = Vec::with_capacity(capacity);
+        let mut line_numbers = Vec::with_capacity(capacity);
+        let mut quantities = Vec::with_capacity(capacity);
+        let mut prices = Vec::with_capacity(capacity);
+        let mut discounts = Vec::with_capacity(capacity);
+        let mut taxes = Vec::with_capacity(capacity);
+        let mut return_flags = Vec::with_capacity(capacity);
+        let mut line_statuses = Vec::with_capacity(capacity);
+        let mut ship_dates = Vec::with_capacity(capacity);
+        let mut commit_dates = Vec::with_capacity(capacity);
+        let mut receipt_dates = Vec::with_capacity(capacity);
+        let mut instructions = Vec::with_capacity(capacity);
+        let mut modes = Vec::with_capacity(capacity);
+        let mut comments = Vec::with_capacity(capacity);
+        for index in start..end {
+            let order = self.generate_order(index, true);
+            for line in order.lines {
+                order_keys.push(order.key);
+                part_keys.push(line.part_key);
+                supp_keys.push(line.supp_key);
+                line_numbers.push(line.number);
+                quantities.push(line.quantity * 100);
+                prices.push(line.extended_price);
+                discounts.push(line.discount);
+                taxes.push(line.tax);
+                return_flags.push(line.return_flag.to_string());
+                line_statuses.push(line.status.to_string());
+                ship_dates.push(line.ship_date);
+                commit_dates.push(line.commit_date);
+                receipt_dates.push(line.receipt_date);
+                instructions.push(line.ship_instruction.to_string());
+                modes.push(line.ship_mode.to_string());
+                comments.push(line.comment);
+            }
+        }
+
+        DataBlock::new_from_columns(vec![
+            Int64Type::from_data(order_keys),
+            Int64Type::from_data(part_keys),
+            Int64Type::from_data(supp_keys),
+            Int64Type::from_data(line_numbers),
+            Decimal128Type::from_data_with_size(quantities, MONEY_SIZE),
+            Decimal128Type::from_data_with_size(prices, MONEY_SIZE),
+            Decimal128Type::from_data_with_size(discounts, MONEY_SIZE),
+            Decimal128Type::from_data_with_size(taxes, MONEY_SIZE),
+            StringType::from_data(return_flags),
+            StringType::from_data(line_statuses),
+            DateType::from_data(ship_dates),
+            DateType::from_data(commit_dates),
+            DateType::from_data(receipt_dates),
+            StringType::from_data(instructions),
+            StringType::from_data(modes),
+            StringType::from_data(comments),
+        ])
+    }
+
+    /// Generates the order of `index` with its lines, both `orders` and `lineitem` are
+    /// generated from it so that the total price and the status of the orders are
+    /// consistent with the lines.
+    fn generate_order(&self, index: u64, with_line_comments: bool) -> Order {
+        let mut rng = self.rng(TpchTableKind::Orders, index);
+
+        // only the first 8 of every 32 keys are used.
+        let key = ((index / 8) * 32 + index % 8 + 1) as i64;
+        // the customers whose key is a multiple of 3 have no orders.
+        let candidates = self.scale.customers - self.scale.customers / 3;
+        let candidate = rng.gen_range(0..candidates);
+        let cust_key = ((candidate / 2) * 3 + candidate % 2 + 1) as i64;
+        let date = rng.gen_range(START_DATE..=ORDER_DATE_MAX);
+        let priority = pick(&mut rng, &PRIORITIES);
+        let clerk = format!("Clerk#{:09}", rng.gen_range(1..=self.scale.clerks));
+        let comment = random_text(&mut rng, 19, 78);
+        let num_lines = rng.gen_range(1..=7);
+
+        let lines = (1..=num_lines)
+            .map(|number| {
+                let mut rng = self.rng(TpchTableKind::LineItem, index * 8 + number as u64);
+                let part_key = rng.gen_range(1..=self.scale.parts) as i64;
+                let supp_key = self.part_supplier(part_key, rng.gen_range(0..4));
+                let quantity = rng.gen_range(1..=50);
+                let discount = rng.gen_range(0..=10);
+                let tax = rng.gen_range(0..=8);
+                let ship_date = date + rng.gen_range(1..=121);
+                let commit_date = date + rng.gen_range(30..=90);
+                let receipt_date = ship_date + rng.gen_range(1..=30);
+                let return_flag = match receipt_date <= CURRENT_DATE {
+                    true if rng.gen_bool(0.5) => "R",
+                    true => "A",
+                    false => "N",
+                };
+                let status = match ship_date > CURRENT_DATE {
+                    true => "O",
+                    false => "F",
+                };
+                let ship_instruction = pick(&mut rng, &SHIP_INSTRUCTIONS);
+                let ship_mode = pick(&mut rng, &SHIP_MODES);
+                let comment = match with_line_comments {
+                    true => random_text(&mut rng, 10, 43),
+                    false => String::new(),
+                };
+
+                LineItem {
+                    part_key,
+                    supp_key,
+                    number,
+                    quantity,
+                    extended_price: quantity * retail_price(part_key),
+                    discount,
+                    tax,
+                    return_flag,
+                    status,
+                    ship_date,
+                    commit_date,
+                    receipt_date,
+                    ship_instruction,
+                    ship_mode,
+                    comment,
+                }
+            })
+            .collect();
+
+        Order {
+            key,
+            cust_key,
+            date,
+            priority,
+            clerk,
+            comment,
+            lines,
+        }
+    }
+
+    /// The `i`-th (0 to 3) supplier of the part.
+    fn part_supplier(&self, part_key: i64, i: i64) -> i64 {
+        let suppliers = self.scale.suppliers as i64;
+        (part_key + i * (suppliers / 4 + (part_key - 1) / suppliers)) % suppliers + 1
+    }
+}
+
+struct Order {
+    key: i64,
+    cust_key: i64,
+    date: i32,
+    priority: &'static str,
+    clerk: String,
+    comment: String,
+    lines: Vec<LineItem>,
+}
+
+impl Order {
+    fn status(&self) -> &'static str {
+        if self.lines.iter().all(|line| line.status == "F") {
+            "F"
+        } else if self.lines.iter().all(|line| line.status == "O") {
+            "O"
+        } else {
+            "P"
+        }
+    }
+
+    /// sum(l_extendedprice * (1 + l_tax) * (1 - l_discount)), in cents.
+    fn total_price(&self) -> i128 {
+        self.lines
+            .iter()
+            .map(|line| line.extended_price * (100 + line.tax) * (100 - line.discount) / 10_000)
+            .sum()
+    }
+}
+
+struct LineItem {
+    part_key: i64,
+    supp_key: i64,
+    number: i64,
+    quantity: i128,
+    // in cents
+    extended_price: i128,
+    // in percents
+    discount: i128,
+    // in percents
+    tax: i128,
+    return_flag: &'static str,
+    status: &'static str,
+    ship_date: i32,
+    commit_date: i32,
+    receipt_date: i32,
+    ship_instruction: &'static str,
+    ship_mode: &'static str,
+    comment: String,
+}
+
+/// The retail price of the part, in cents.
+fn retail_price(part_key: i64) -> i128 {
+    (90_000 + (part_key / 10) % 20_001 + 100 * (part_key % 1_000)) as i128
+}
+
+fn pick<'a>(rng: &mut SmallRng, values: &[&'a str]) -> &'a str {
+    values[rng.gen_range(0..values.len())]
+}
+
+fn random_text(rng: &mut SmallRng, min_len: usize, max_len: usize) -> String {
+    let len = rng.gen_range(min_len..=max_len);
+    let mut text = String::with_capacity(len + 16);
+    while text.len() < len {
+        if !text.is_empty() {
+            text.push(' ');
+        }
+        text.push_str(pick(rng, &WORDS));
+    }
+    text.truncate(len);
+    text.trim_end().to_string()
+}
+
+fn random_address(rng: &mut SmallRng) -> String {
+    let len = rng.gen_range(10..=40);
+    (0..len)
+        .map(|_| ADDRESS_CHARS[rng.gen_range(0..ADDRESS_CHARS.len())] as char)
+        .collect()
+}
+
+fn random_phone(rng: &mut SmallRng, nation_key: i32) -> String {
+    format!(
+        "{}-{}-{}-{}",
+        nation_key + 10,
+        rng.gen_range(100..1000),
+        rng.gen_range(100..1000),
+        rng.gen_range(1000..10000)
+    )
+}
@@ -0,0 +1,104 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The units in `[part_start, part_end)` of a tpch table, which is the `part`-th of its
+/// `total_parts` partitions.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct TpchPartInfo {
+    pub table: String,
+    pub part: u64,
+    pub total_parts: u64,
+    pub part_start: u64,
+    pub part_end: u64,
+}
+
+#[typetag::serde(name = "tpch")]
+impl PartInfo for TpchPartInfo {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn equals(&self, info: &Box<dyn PartInfo>) -> bool {
+        info.as_any()
+            .downcast_ref::<TpchPartInfo>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn hash(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.table.hash(&mut s);
+        self.part.hash(&mut s);
+        self.total_parts.hash(&mut s);
+        s.finish()
+    }
+}
+
+impl TpchPartInfo {
+    pub fn create(
+        table: &str,
+        part: u64,
+        total_parts: u64,
+        start: u64,
+        end: u64,
+    ) -> Arc<Box<dyn PartInfo>> {
+        Arc::new(Box::new(TpchPartInfo {
+            table: table.to_string(),
+            part,
+            total_parts,
+            part_start: start,
+            part_end: end,
+        }))
+    }
+
+    pub fn from_part(info: &PartInfoPtr) -> Result<&TpchPartInfo> {
+        info.as_any()
+            .downcast_ref::<TpchPartInfo>()
+            .ok_or_else(|| ErrorCode::Internal("Cannot downcast from PartInfo to TpchPartInfo."))
+    }
+}
+
+/// Splits the units of `table` evenly into at most `workers` partitions.
+pub fn generate_tpch_parts(table: &str, workers: u64, total: u64) -> Partitions {
+    let workers = workers.clamp(1, total.max(1));
+    let part_size = total / workers;
+    let part_remain = total % workers;
+
+    let mut partitions = Vec::with_capacity(workers as usize);
+    let mut part_begin = 0;
+    for part in 0..workers {
+        let mut part_end = part_begin + part_size;
+        if part < part_remain {
+            part_end += 1;
+        }
+        partitions.push(TpchPartInfo::create(
+            table, part, workers, part_begin, part_end,
+        ));
+        part_begin = part_end;
+    }
+
+    Partitions::create(PartitionsShuffleKind::Seq, partitions)
+}
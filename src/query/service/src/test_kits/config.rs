@@ -130,6 +130,11 @@ impl ConfigBuilder {
         self
     }
 
+    pub fn rpc_tls_server_root_ca_cert(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.rpc_tls_server_root_ca_cert = value.into();
+        self
+    }
+
     pub fn query_flight_address(mut self, value: impl Into<String>) -> ConfigBuilder {
         self.conf.query.flight_api_address = value.into();
         self
@@ -0,0 +1,63 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::register_catalog_plugin;
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::catalog::CatalogCreator;
+use databend_common_catalog::catalog::CatalogCreatorRegistry;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CatalogInfo;
+use databend_common_meta_app::schema::CatalogType;
+
+#[derive(Debug)]
+struct FakeCreator;
+
+impl CatalogCreator for FakeCreator {
+    fn try_create(&self, _info: &CatalogInfo) -> Result<Arc<dyn Catalog>> {
+        Err(ErrorCode::Unimplemented("fake catalog"))
+    }
+}
+
+#[test]
+fn test_catalog_creator_registry() -> Result<()> {
+    register_catalog_plugin("Test_Registry_Plugin", Arc::new(FakeCreator))?;
+
+    // A plugin can be registered only once.
+    let res = register_catalog_plugin("test_registry_plugin", Arc::new(FakeCreator));
+    assert!(res.is_err());
+
+    let mut registry =
+        CatalogCreatorRegistry::create(vec![(CatalogType::Hive, Arc::new(FakeCreator))])?;
+
+    assert!(registry.contains(&CatalogType::Hive));
+    assert!(registry.contains(&CatalogType::Custom("test_registry_plugin".to_string())));
+
+    let res = registry.get(&CatalogType::Iceberg);
+    assert_eq!(
+        ErrorCode::UNKNOWN_CATALOG_TYPE,
+        res.unwrap_err().code(),
+        "iceberg is not registered"
+    );
+
+    let res = registry.register(CatalogType::Hive, Arc::new(FakeCreator));
+    assert!(res.is_err(), "hive is already registered");
+
+    let res = registry.register(CatalogType::Default, Arc::new(FakeCreator));
+    assert!(res.is_err(), "default catalog can not be registered");
+
+    Ok(())
+}
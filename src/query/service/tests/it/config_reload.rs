@@ -0,0 +1,76 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::Result;
+use databend_query::config_reload::ConfigChange;
+use databend_query::config_reload::ConfigReloader;
+use databend_query::sessions::SessionType;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_config_reload() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    // Nothing changed.
+    let config = GlobalConfig::instance().as_ref().clone();
+    let changes = ConfigReloader::reload(&config, "test")?;
+    assert!(changes.is_empty());
+
+    // The reloadable settings.
+    let mut config = GlobalConfig::instance().as_ref().clone();
+    config.log.file.level = "INFO".to_string();
+    config.query.max_running_queries = 2;
+    config.query.max_storage_io_requests = Some(16);
+    config.cache.table_meta_snapshot_count = 10;
+    // Needs a restart.
+    config.query.http_handler_port += 1;
+    let changes = ConfigReloader::reload(&config, "test")?;
+    let names = changes.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names, vec![
+        "log.file.level",
+        "query.max_running_queries",
+        "query.max_storage_io_requests",
+        "cache.table_meta_snapshot_count",
+    ]);
+    assert_eq!(changes[1], ConfigChange {
+        name: "query.max_running_queries".to_string(),
+        old_value: "8".to_string(),
+        new_value: "2".to_string(),
+    });
+
+    let reloaded = GlobalConfig::instance();
+    assert_eq!(reloaded.log.file.level, "INFO");
+    assert_eq!(reloaded.query.max_running_queries, 2);
+    assert_eq!(reloaded.cache.table_meta_snapshot_count, 10);
+    assert_ne!(
+        reloaded.query.http_handler_port,
+        config.query.http_handler_port
+    );
+
+    // New sessions read the reloaded settings.
+    let session = fixture.new_session_with_type(SessionType::Dummy).await?;
+    assert_eq!(session.get_settings().get_max_storage_io_requests()?, 16);
+
+    // Invalid settings are rejected, nothing is changed.
+    let mut config = GlobalConfig::instance().as_ref().clone();
+    config.log.file.level = "LOUD".to_string();
+    config.query.max_running_queries = 4;
+    let res = ConfigReloader::reload(&config, "test");
+    assert!(res.is_err());
+    assert_eq!(GlobalConfig::instance().query.max_running_queries, 2);
+
+    Ok(())
+}
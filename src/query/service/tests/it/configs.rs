@@ -25,6 +25,7 @@ use databend_common_config::InnerConfig;
 use databend_common_config::ThriftProtocol;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::storage::StorageParams;
 use pretty_assertions::assert_eq;
 
 // From env, defaulting.
@@ -966,6 +967,96 @@ protocol = "binary"
     Ok(())
 }
 
+/// Test storage policies
+#[test]
+fn test_override_config_storage_policies() -> Result<()> {
+    let file_path = temp_dir().join("databend_test_override_config_storage_policies.toml");
+
+    let mut f = fs::File::create(&file_path)?;
+    f.write_all(
+        r#"
+[storage_policies.cold]
+type = "s3"
+
+[storage_policies.cold.s3]
+bucket = "cold-bucket"
+endpoint_url = "http://127.0.0.1:9900"
+"#
+        .as_bytes(),
+    )?;
+
+    // Make sure all data flushed.
+    f.flush()?;
+
+    temp_env::with_vars(
+        vec![("CONFIG_FILE", Some(file_path.to_string_lossy().as_ref()))],
+        || {
+            let cfg = InnerConfig::load_for_test().expect("config load success");
+
+            assert_eq!(1, cfg.storage_policies.len());
+            match &cfg.storage_policies["cold"].params {
+                StorageParams::S3(s3) => {
+                    assert_eq!("cold-bucket", s3.bucket);
+                    assert_eq!("http://127.0.0.1:9900", s3.endpoint_url);
+                }
+                v => panic!("expect s3 storage params, but got {:?}", v),
+            }
+
+            // The storage policies are kept when converted back to the outer config.
+            let outer = cfg.into_config();
+            assert_eq!("s3", outer.storage_policies["cold"].typ);
+            assert_eq!("cold-bucket", outer.storage_policies["cold"].s3.bucket);
+        },
+    );
+
+    // remove temp file
+    fs::remove_file(file_path)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_override_config_storage_io() -> Result<()> {
+    let file_path = temp_dir().join("databend_test_override_config_storage_io.toml");
+
+    let mut f = fs::File::create(&file_path)?;
+    f.write_all(
+        r#"
+[storage.io]
+read_max_retries = 5
+read_timeout_secs = 30
+list_timeout_secs = 0
+retry_min_delay_ms = 500
+"#
+        .as_bytes(),
+    )?;
+
+    // Make sure all data flushed.
+    f.flush()?;
+
+    temp_env::with_vars(
+        vec![("CONFIG_FILE", Some(file_path.to_string_lossy().as_ref()))],
+        || {
+            let cfg = InnerConfig::load_for_test().expect("config load success");
+
+            let io = &cfg.storage.io;
+            assert_eq!(5, io.read.max_retries);
+            assert_eq!(30, io.read.timeout_secs);
+            assert_eq!(0, io.list.timeout_secs);
+            assert_eq!(500, io.retry_min_delay_ms);
+            // Not configured, use the default.
+            assert_eq!(3, io.write.max_retries);
+            assert_eq!(10, io.write.timeout_secs);
+            assert_eq!(60000, io.retry_max_delay_ms);
+        },
+    );
+
+    // remove temp file
+    fs::remove_file(file_path)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_env_config_obsoleted() -> Result<()> {
     let obsoleted = vec![
@@ -0,0 +1,147 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+async fn show_create_table(fixture: &TestFixture, table: &str) -> Result<String> {
+    let stream = fixture
+        .execute_query(&format!("SHOW CREATE TABLE {table}"))
+        .await?;
+    let blocks: Vec<DataBlock> = stream.try_collect().await?;
+    match blocks[0].get_by_offset(1).value.index(0) {
+        Some(ScalarRef::String(s)) => Ok(s.to_string()),
+        other => Err(ErrorCode::BadDataValueType(format!(
+            "Expected String, but got {:?}",
+            other
+        ))),
+    }
+}
+
+/// Recreates the table with the output of `SHOW CREATE TABLE`, the output
+/// of the recreated table must be the same.
+async fn check_round_trip(fixture: &TestFixture, table: &str, create_sql: &str) -> Result<()> {
+    fixture.execute_command(create_sql).await?;
+    let first = show_create_table(fixture, table).await?;
+
+    fixture
+        .execute_command(&format!("DROP TABLE {table}"))
+        .await?;
+    fixture.execute_command(&first).await?;
+    let second = show_create_table(fixture, table).await?;
+    assert_eq!(first, second, "round trip of: {create_sql}");
+
+    fixture
+        .execute_command(&format!("DROP TABLE {table}"))
+        .await?;
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_show_create_table_round_trip() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("SET hide_options_in_show_create_table = 0")
+        .await?;
+
+    let cases = [
+        (
+            "t_nested",
+            "CREATE TABLE t_nested (a ARRAY(INT NULL) NOT NULL, b ARRAY(INT NOT NULL) NULL, \
+             c MAP(STRING, ARRAY(INT NULL)), d TUPLE(Id INT NOT NULL, Name STRING NULL), \
+             e TUPLE(INT, ARRAY(STRING NOT NULL)) NOT NULL, f DECIMAL(15, 2) NOT NULL)",
+        ),
+        (
+            "t_default",
+            "CREATE TABLE t_default (a INT DEFAULT 3 COMMENT 'it''s a', \
+             b STRING DEFAULT 'x''y\\\\z' COMMENT 'back\\\\slash', \
+             c DATE NOT NULL DEFAULT '2024-01-01') COMMENT = 'table ''comment'''",
+        ),
+        (
+            "t_computed",
+            "CREATE TABLE t_computed (a INT, b STRING, \
+             c INT AS (a + 1) STORED, d STRING AS (concat(b, '-')) VIRTUAL)",
+        ),
+        (
+            "t_options",
+            "CREATE TABLE t_options (a INT NOT NULL, b STRING NOT NULL) CLUSTER BY (a, b) \
+             ROW_PER_BLOCK = 1000 BLOCK_PER_SEGMENT = 100 BLOOM_INDEX_COLUMNS = 'b' \
+             CHANGE_TRACKING = true COMPRESSION = 'zstd'",
+        ),
+        (
+            "t_transient",
+            "CREATE TRANSIENT TABLE t_transient (a INT NOT NULL) STORAGE_FORMAT = 'native'",
+        ),
+    ];
+
+    for (table, create_sql) in cases {
+        check_round_trip(&fixture, table, create_sql).await?;
+    }
+
+    // The options maintained by the storage are not part of the output.
+    fixture
+        .execute_command("CREATE TABLE t_data (a INT, b STRING)")
+        .await?;
+    fixture
+        .execute_command("INSERT INTO t_data VALUES (1, 'a'), (2, 'b')")
+        .await?;
+    let first = show_create_table(&fixture, "t_data").await?;
+    assert!(
+        !first.to_uppercase().contains("SNAPSHOT_LOCATION"),
+        "{first}"
+    );
+    fixture.execute_command("DROP TABLE t_data").await?;
+    fixture.execute_command(&first).await?;
+    assert_eq!(first, show_create_table(&fixture, "t_data").await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_show_create_table_with_location() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture
+        .execute_command("CREATE CONNECTION show_create_conn STORAGE_TYPE = 'fs'")
+        .await?;
+
+    let cases = [
+        (
+            "t_location",
+            "CREATE TABLE t_location (a INT NOT NULL) 'fs:///tmp/show_create/t_location/'",
+        ),
+        (
+            "t_connection",
+            "CREATE TABLE t_connection (a INT NOT NULL) 'fs:///tmp/show_create/t_connection/' \
+             CONNECTION = (connection_name = 'show_create_conn') CLUSTER BY (a)",
+        ),
+    ];
+    for (table, create_sql) in cases {
+        check_round_trip(&fixture, table, create_sql).await?;
+    }
+
+    fixture.execute_command(cases[1].1).await?;
+    let sql = show_create_table(&fixture, "t_connection").await?;
+    assert!(
+        sql.contains("'fs:///tmp/show_create/t_connection/' CONNECTION = ( connection_name = 'show_create_conn' )"),
+        "{sql}"
+    );
+    fixture.execute_command("DROP TABLE t_connection").await?;
+
+    Ok(())
+}
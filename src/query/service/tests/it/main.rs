@@ -22,6 +22,7 @@ extern crate core;
 mod auth;
 mod catalogs;
 mod clusters;
+mod config_reload;
 mod configs;
 mod databases;
 mod distributed;
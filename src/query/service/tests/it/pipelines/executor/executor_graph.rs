@@ -498,6 +498,8 @@ async fn create_executor_with_simple_pipeline(
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        io_priority: Default::default(),
+        priority: Default::default(),
     };
     QueryPipelineExecutor::create(pipeline, settings)
 }
@@ -46,6 +46,8 @@ async fn test_always_call_on_finished() -> Result<()> {
         enable_queries_executor: false,
         max_threads: 8,
         executor_node_id: "".to_string(),
+        io_priority: Default::default(),
+        priority: Default::default(),
     };
 
     {
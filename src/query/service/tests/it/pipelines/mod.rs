@@ -15,3 +15,5 @@
 mod builders;
 mod executor;
 mod filter;
+#[cfg(feature = "ml-inference")]
+mod model_inference;
@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_sorted_eq;
+use databend_common_expression::DataBlock;
+use databend_common_sql::binder::resolve_stage_location;
+use databend_common_storage::init_stage_operator;
+use databend_query::test_kits::TestFixture;
+use futures_util::TryStreamExt;
+
+// A model of `y = x1 + 2 * x2 + 0.5`, with a float input of shape [n, 2] and a float
+// output of shape [n, 1].
+const LINEAR_MODEL: &[u8] = include_bytes!("testdata/linear.onnx");
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_ml_predict() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.execute_command("CREATE STAGE models_stage").await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let (stage_info, path) =
+        resolve_stage_location(ctx.as_ref(), "models_stage/linear.onnx").await?;
+    init_stage_operator(&stage_info)?
+        .write(&path, LINEAR_MODEL.to_vec())
+        .await?;
+
+    fixture
+        .execute_command(
+            "CREATE MODEL linear (FLOAT, FLOAT) RETURNS FLOAT FROM @models_stage/linear.onnx",
+        )
+        .await?;
+
+    // The rows with NULL features are not scored.
+    let blocks = fixture
+        .execute_query(
+            "SELECT number, ml_predict('linear', number, if(number = 1, NULL, number + 1)) \
+             FROM numbers(3)",
+        )
+        .await?
+        .try_collect::<Vec<DataBlock>>()
+        .await?;
+    let expected = vec![
+        "+----------+----------+",
+        "| Column 0 | Column 1 |",
+        "+----------+----------+",
+        "| 0        | 2.5      |",
+        "| 1        | NULL     |",
+        "| 2        | 8.5      |",
+        "+----------+----------+",
+    ];
+    assert_blocks_sorted_eq(expected, blocks.as_slice());
+
+    Ok(())
+}
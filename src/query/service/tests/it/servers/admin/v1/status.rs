@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_meta_app::tenant::Tenant;
@@ -122,3 +123,35 @@ async fn test_status() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_status_draining() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+
+    let ep = Route::new().at("/v1/status", get(instance_status_handler));
+    assert!(!get_status(&ep).await.draining);
+
+    SessionManager::instance().start_draining();
+    let response = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/v1/status"))
+                .method(Method::GET)
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body = response.into_body().into_vec().await.unwrap();
+    let status = serde_json::from_str::<InstanceStatus>(&String::from_utf8_lossy(&body)).unwrap();
+    assert!(status.draining);
+
+    // New queries are rejected.
+    let res = fixture.new_session_with_type(SessionType::MySQL).await;
+    assert_eq!(
+        res.err().map(|e| e.code()),
+        Some(ErrorCode::SERVER_SHUTTING_DOWN)
+    );
+
+    Ok(())
+}
@@ -26,21 +26,27 @@ use databend_common_grpc::ConnectionFactory;
 use databend_common_grpc::GrpcConnectionError;
 use databend_common_grpc::RpcClientTlsConfig;
 use databend_query::servers::flight::FlightService;
+use databend_query::servers::TlsReloader;
 use databend_query::test_kits::*;
 
 use crate::tests::tls_constants::TEST_CA_CERT;
 use crate::tests::tls_constants::TEST_CN_NAME;
 use crate::tests::tls_constants::TEST_SERVER_CERT;
 use crate::tests::tls_constants::TEST_SERVER_KEY;
+use crate::tests::tls_constants::TEST_TLS_CA_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_CERT;
+use crate::tests::tls_constants::TEST_TLS_CLIENT_KEY;
+use crate::tests::tls_constants::TEST_TLS_SERVER_CERT;
+use crate::tests::tls_constants::TEST_TLS_SERVER_KEY;
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tls_rpc_server() -> Result<()> {
-    let mut rpc_service = FlightService::create(
-        ConfigBuilder::create()
-            .rpc_tls_server_key(TEST_SERVER_KEY)
-            .rpc_tls_server_cert(TEST_SERVER_CERT)
-            .build(),
-    )?;
+    let config = ConfigBuilder::create()
+        .rpc_tls_server_key(TEST_SERVER_KEY)
+        .rpc_tls_server_cert(TEST_SERVER_CERT)
+        .build();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+    let mut rpc_service = FlightService::create(config)?;
 
     let mut listener_address = SocketAddr::from_str("127.0.0.1:9991")?;
     listener_address = rpc_service.start(listener_address).await?;
@@ -48,6 +54,7 @@ async fn test_tls_rpc_server() -> Result<()> {
     let tls_conf = Some(RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: TEST_CA_CERT.to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     });
 
     // normal case
@@ -66,6 +73,60 @@ async fn test_tls_rpc_server() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_mutual_tls_rpc_server() -> Result<()> {
+    let config = ConfigBuilder::create()
+        .rpc_tls_server_key(TEST_TLS_SERVER_KEY)
+        .rpc_tls_server_cert(TEST_TLS_SERVER_CERT)
+        .rpc_tls_server_root_ca_cert(TEST_TLS_CA_CERT)
+        .build();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+    let mut rpc_service = FlightService::create(config)?;
+
+    let mut listener_address = SocketAddr::from_str("127.0.0.1:9992")?;
+    listener_address = rpc_service.start(listener_address).await?;
+
+    // client with certificate
+    let tls_conf = Some(RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        client_cert: TEST_TLS_CLIENT_CERT.to_string(),
+        client_key: TEST_TLS_CLIENT_KEY.to_string(),
+    });
+    let conn = ConnectionFactory::create_rpc_channel(listener_address, None, tls_conf).await?;
+    let mut f_client = FlightServiceClient::new(conn);
+    let r = f_client.list_actions(Empty {}).await;
+    assert!(r.is_ok());
+
+    // client without certificate will be rejected
+    let tls_conf = Some(RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
+    });
+    let r = ConnectionFactory::create_rpc_channel(listener_address, None, tls_conf).await;
+    if let Ok(conn) = r {
+        let mut f_client = FlightServiceClient::new(conn);
+        let r = f_client.list_actions(Empty {}).await;
+        assert!(r.is_err());
+    }
+
+    // the certificates are read again on reload
+    TlsReloader::instance().reload();
+    let tls_conf = Some(RpcClientTlsConfig {
+        rpc_tls_server_root_ca_cert: TEST_TLS_CA_CERT.to_string(),
+        domain_name: TEST_CN_NAME.to_string(),
+        client_cert: TEST_TLS_CLIENT_CERT.to_string(),
+        client_key: TEST_TLS_CLIENT_KEY.to_string(),
+    });
+    let conn = ConnectionFactory::create_rpc_channel(listener_address, None, tls_conf).await?;
+    let mut f_client = FlightServiceClient::new(conn);
+    let r = f_client.list_actions(Empty {}).await;
+    assert!(r.is_ok());
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn test_tls_rpc_server_invalid_server_config() -> Result<()> {
     // setup, invalid cert locations
@@ -91,6 +152,7 @@ async fn test_tls_rpc_server_invalid_client_config() -> Result<()> {
     let client_conf = RpcClientTlsConfig {
         rpc_tls_server_root_ca_cert: "../tests/data/certs/nowhere.pem".to_string(),
         domain_name: TEST_CN_NAME.to_string(),
+        ..Default::default()
     };
 
     let r = ConnectionFactory::create_rpc_channel("fake:1234", None, Some(client_conf)).await;
@@ -31,10 +31,15 @@ use databend_query::auth::AuthMgr;
 use databend_query::servers::http::middleware::get_client_ip;
 use databend_query::servers::http::middleware::HTTPSessionEndpoint;
 use databend_query::servers::http::middleware::HTTPSessionMiddleware;
+use databend_query::servers::http::v1::async_query_route;
+use databend_query::servers::http::v1::make_async_page_uri;
+use databend_query::servers::http::v1::make_async_state_uri;
 use databend_query::servers::http::v1::make_final_uri;
 use databend_query::servers::http::v1::make_page_uri;
 use databend_query::servers::http::v1::make_state_uri;
 use databend_query::servers::http::v1::query_route;
+use databend_query::servers::http::v1::AsyncQueryPage;
+use databend_query::servers::http::v1::AsyncQueryResponse;
 use databend_query::servers::http::v1::ExecuteStateKind;
 use databend_query::servers::http::v1::HttpSessionConf;
 use databend_query::servers::http::v1::QueryError;
@@ -602,6 +607,129 @@ async fn test_pagination() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_result_spooling() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let rows = 10000;
+    let sql = format!("select * from numbers({})", rows);
+
+    // the result smaller than the threshold is returned in the pages
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 5}, "result_spooling": {"threshold_bytes": 1024 * 1024}});
+    let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+    assert!(reply.error().is_none(), "{:?}", reply.error());
+    assert_eq!(reply.data().len(), rows);
+    assert!(reply.last().1.result_files.is_none());
+
+    let json = serde_json::json!({"sql": sql.to_string(), "pagination": {"wait_time_secs": 5}, "result_spooling": {"threshold_bytes": 1024, "max_file_bytes": 1024}});
+    let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+    assert!(reply.error().is_none(), "{:?}", reply.error());
+    assert_eq!(reply.state(), ExecuteStateKind::Succeeded);
+    assert!(reply.data().is_empty());
+    let files = reply.last().1.result_files.unwrap();
+    assert!(!files.is_empty());
+    assert_eq!(files.iter().map(|f| f.rows).sum::<usize>(), rows);
+    for file in files {
+        assert!(file.location.starts_with("@~/_result/"), "{:?}", file);
+        assert!(file.location.ends_with(".parquet"), "{:?}", file);
+        assert!(file.size > 0, "{:?}", file);
+    }
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_describe_only() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let sql = "select number, number + 1 as n1 from numbers(10)";
+    let json =
+        serde_json::json!({"sql": sql, "pagination": {"wait_time_secs": 5}, "describe_only": true});
+    let reply = TestHttpQueryRequest::new(json).fetch_total().await?;
+    assert!(reply.error().is_none(), "{:?}", reply.error());
+    assert_eq!(reply.state(), ExecuteStateKind::Succeeded);
+    assert!(reply.data().is_empty());
+    assert_eq!(reply.last().1.schema.len(), 2, "{:?}", reply.last().1);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_async_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let session_middleware =
+        HTTPSessionMiddleware::create(HttpHandlerKind::Query, AuthMgr::instance());
+    let ep = Route::new()
+        .nest("/v1/async_query", async_query_route())
+        .with(session_middleware);
+
+    let json = serde_json::json!({"sql": "select * from numbers(10)", "pagination": {"max_rows_per_page": 3}});
+    let response = post_json_to_uri(&ep, "/v1/async_query", &json).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let result: AsyncQueryResponse =
+        serde_json::from_str(&response.into_body().into_string().await.unwrap())?;
+    assert!(result.error.is_none(), "{:?}", result);
+    let query_id = result.id.clone();
+    assert_eq!(result.state_uri, Some(make_async_state_uri(&query_id)));
+
+    // poll the state until the query is succeeded
+    let mut result = result;
+    for _ in 0..50 {
+        if result.state != ExecuteStateKind::Running {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+        let response = get_uri(&ep, &make_async_state_uri(&query_id)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        result = serde_json::from_str(&response.into_body().into_string().await.unwrap())?;
+    }
+    assert_eq!(result.state, ExecuteStateKind::Succeeded, "{:?}", result);
+    assert_eq!(result.num_pages, 4, "{:?}", result);
+    assert_eq!(result.page_uri, Some(make_async_page_uri(&query_id, 0)));
+
+    // the pages can be fetched in any order
+    for (page_no, rows, next_uri) in [
+        (3, 1, None),
+        (0, 3, Some(make_async_page_uri(&query_id, 1))),
+    ] {
+        let response = get_uri(&ep, &make_async_page_uri(&query_id, page_no)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let page: AsyncQueryPage =
+            serde_json::from_str(&response.into_body().into_string().await.unwrap())?;
+        assert_eq!(page.data.len(), rows, "{:?}", page);
+        assert_eq!(page.next_uri, next_uri, "{:?}", page);
+    }
+    let response = get_uri(&ep, &make_async_page_uri(&query_id, 4)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    // cancel a running query
+    let json = serde_json::json!({"sql": "select sleep(3)"});
+    let response = post_json_to_uri(&ep, "/v1/async_query", &json).await;
+    let result: AsyncQueryResponse =
+        serde_json::from_str(&response.into_body().into_string().await.unwrap())?;
+    let response = post_json_to_uri(&ep, result.cancel_uri.as_ref().unwrap(), &json).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = get_uri(&ep, &make_async_state_uri(&result.id)).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    Ok(())
+}
+
+async fn post_json_to_uri(ep: &EndpointType, uri: &str, json: &serde_json::Value) -> Response {
+    let basic = headers::Authorization::basic("root", "");
+    ep.call(
+        Request::builder()
+            .uri(uri.parse().unwrap())
+            .method(Method::POST)
+            .header(header::CONTENT_TYPE, "application/json")
+            .typed_header(basic)
+            .body(serde_json::to_vec(json).unwrap()),
+    )
+    .await
+    .unwrap_or_else(|err| err.into_response())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_http_session() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
@@ -629,7 +757,11 @@ async fn test_http_session() -> Result<()> {
     let json = serde_json::json!({"sql": "select * from x", "session_id": session_id});
     let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
     assert_eq!(status, StatusCode::OK, "{:?}", result);
-    assert!(result.error.is_some(), "{:?}", result);
+    let error = result.error.clone().unwrap();
+    assert_eq!(error.code, ErrorCode::UNKNOWN_TABLE, "{:?}", result);
+    assert_eq!(error.sqlstate, "42P01", "{:?}", result);
+    assert_eq!(error.category, "not_found", "{:?}", result);
+    assert!(!error.retryable, "{:?}", result);
 
     let json = serde_json::json!({"sql": "select 1", "session_id": session_id});
     let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
@@ -640,6 +772,85 @@ async fn test_http_session() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_session_recovery() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+
+    // unknown session without session state
+    let json = serde_json::json!({"sql": "select 1", "session_id": "lost"});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_some(), "{:?}", result);
+
+    // unknown session is recovered from the session state
+    let json = serde_json::json!({"sql": "select database()", "session_id": "lost", "session": {"database": "system", "keep_server_session_secs": 10}});
+    let response = post_json_to_uri(&ep, "/v1/query", &json).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let route_hint = response
+        .headers()
+        .get("X-DATABEND-ROUTE-HINT")
+        .map(|v| v.to_str().unwrap().to_string());
+    let (_, result) = check_response(response).await?;
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(
+        result.data,
+        vec![vec![Value::from("system")]],
+        "{:?}",
+        result
+    );
+    let session_id = result.session_id.unwrap();
+    assert_ne!(session_id, "lost");
+    let route_hint = route_hint.unwrap();
+    assert!(
+        route_hint.starts_with(&format!("{}.", result.node_id)),
+        "{route_hint}"
+    );
+
+    // the recovered session is kept
+    let json = serde_json::json!({"sql": "select database()", "session_id": session_id});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data[0][0], "system", "{:?}", result);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_http_query_warnings() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let ep = create_endpoint().await?;
+    let json = serde_json::json!({"sql": "unset session unknown_setting", "session": {"keep_server_session_secs": 10}});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(
+        result.warnings,
+        vec!["Unknown setting: 'unknown_setting'".to_string()],
+        "{:?}",
+        result
+    );
+    let session_id = &result.session_id.unwrap();
+
+    let json = serde_json::json!({"sql": "show warnings", "session_id": session_id});
+    let (status, result) = post_json_to_endpoint(&ep, &json, HeaderMap::default()).await?;
+    assert_eq!(status, StatusCode::OK, "{:?}", result);
+    assert!(result.error.is_none(), "{:?}", result);
+    assert_eq!(result.data.len(), 1, "{:?}", result);
+    assert_eq!(result.data[0][0], "Warning", "{:?}", result);
+    assert_eq!(
+        result.data[0][1], "Unknown setting: 'unknown_setting'",
+        "{:?}",
+        result
+    );
+    assert!(result.warnings.is_empty(), "{:?}", result);
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 #[ignore]
 async fn test_result_timeout() -> Result<()> {
@@ -1053,6 +1264,8 @@ async fn assert_auth_failure(ep: &EndpointType, header: impl Header) -> Result<(
 
     let status = response.status();
     assert_eq!(status, StatusCode::UNAUTHORIZED);
+    // the query id assigned at admission is returned even if the request is rejected.
+    assert!(response.headers().contains_key("X-DATABEND-QUERY-ID"));
     Ok(())
 }
 
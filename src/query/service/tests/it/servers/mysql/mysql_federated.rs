@@ -67,3 +67,40 @@ fn test_mysql_federated() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_mysql_federated_show_status() -> Result<()> {
+    let query_id = "2b4c1d9e-6a1f-4f0b-9c8e-3f7a5d2e1b0c";
+
+    {
+        let result = MySQLFederated::show_status_check("select 1", query_id);
+        assert!(result.is_none());
+    }
+
+    {
+        let result = MySQLFederated::show_status_check("SHOW SESSION STATUS", query_id);
+        let (_, block) = result.unwrap();
+        let expect = vec![
+            "+-----------------+----------------------------------------+",
+            "| Column 0        | Column 1                               |",
+            "+-----------------+----------------------------------------+",
+            "| 'last_query_id' | '2b4c1d9e-6a1f-4f0b-9c8e-3f7a5d2e1b0c' |",
+            "+-----------------+----------------------------------------+",
+        ];
+        assert_blocks_eq(expect, &[block]);
+    }
+
+    {
+        let result = MySQLFederated::show_status_check("show status like 'last%'", query_id);
+        let (_, block) = result.unwrap();
+        assert_eq!(block.num_rows(), 1);
+    }
+
+    {
+        let result = MySQLFederated::show_status_check("show status like 'Ssl_cipher'", query_id);
+        let (_, block) = result.unwrap();
+        assert_eq!(block.num_rows(), 0);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_query::sessions::ConnectionLimiter;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_connection_limits() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.query.max_connections_per_user = 1;
+    conf.query.max_connections_per_tenant = 2;
+    let _fixture = TestFixture::setup_with_config(&conf).await?;
+
+    let limiter = ConnectionLimiter::instance();
+    let tenant = Tenant::new_literal("test_tenant");
+
+    let guard_a = limiter.acquire(&tenant, "a")?;
+    let err = limiter.acquire(&tenant, "a").err().unwrap();
+    assert_eq!(err.code(), ErrorCode::TOO_MANY_USER_CONNECTIONS);
+
+    let guard_b = limiter.acquire(&tenant, "b")?;
+    let err = limiter.acquire(&tenant, "c").err().unwrap();
+    assert_eq!(err.code(), ErrorCode::TOO_MANY_USER_CONNECTIONS);
+
+    // Other tenants have their own limit.
+    let _guard_other = limiter.acquire(&Tenant::new_literal("other_tenant"), "a")?;
+
+    // Dropping the guards releases the slots.
+    drop(guard_a);
+    let _guard_a = limiter.acquire(&tenant, "a")?;
+    drop(guard_b);
+    let _guard_c = limiter.acquire(&tenant, "c")?;
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_auth_failure_lockout() -> Result<()> {
+    let mut conf = ConfigBuilder::create().config();
+    conf.query.auth_failure_max_attempts = 3;
+    conf.query.auth_failure_window_secs = 60;
+    conf.query.auth_lockout_secs = 300;
+    let _fixture = TestFixture::setup_with_config(&conf).await?;
+
+    let limiter = ConnectionLimiter::instance();
+    let tenant = Tenant::new_literal("test_tenant");
+    let ip = Some("127.0.0.1");
+
+    // A success resets the failures.
+    limiter.record_auth_result(&tenant, "a", ip, false);
+    limiter.record_auth_result(&tenant, "a", ip, false);
+    limiter.record_auth_result(&tenant, "a", ip, true);
+    limiter.record_auth_result(&tenant, "a", ip, false);
+    limiter.record_auth_result(&tenant, "a", ip, false);
+    limiter.check_lockout(&tenant, "a", ip)?;
+
+    limiter.record_auth_result(&tenant, "a", ip, false);
+    let err = limiter.check_lockout(&tenant, "a", ip).err().unwrap();
+    assert_eq!(err.code(), ErrorCode::AUTHENTICATE_FAILURE);
+
+    // The lockout is per client address and per user.
+    limiter.check_lockout(&tenant, "a", Some("127.0.0.2"))?;
+    limiter.check_lockout(&tenant, "b", ip)?;
+
+    Ok(())
+}
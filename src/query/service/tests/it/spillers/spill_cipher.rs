@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_query::spillers::SpillCipher;
+
+#[test]
+fn test_spill_cipher_round_trip() -> Result<()> {
+    let key = SpillCipher::generate_key();
+    let cipher = SpillCipher::create(&key);
+
+    let data = (0..1024).map(|x| (x % 256) as u8).collect::<Vec<_>>();
+    let encrypted = cipher.encrypt(&data)?;
+    assert_ne!(&encrypted[12..], data.as_slice());
+    assert_eq!(cipher.decrypt(&encrypted)?, data);
+
+    // Nonces are random, the same data is never encrypted to the same bytes.
+    assert_ne!(cipher.encrypt(&data)?, encrypted);
+
+    // Another query can not read the data.
+    let other = SpillCipher::create(&SpillCipher::generate_key());
+    assert!(other.decrypt(&encrypted).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_spill_cipher_tampered() -> Result<()> {
+    let cipher = SpillCipher::create(&SpillCipher::generate_key());
+    let mut encrypted = cipher.encrypt(b"spill")?;
+    let last = encrypted.len() - 1;
+    encrypted[last] ^= 1;
+    assert!(cipher.decrypt(&encrypted).is_err());
+    assert!(cipher.decrypt(&encrypted[..4]).is_err());
+    Ok(())
+}
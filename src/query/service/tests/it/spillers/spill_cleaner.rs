@@ -0,0 +1,79 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_pipeline_core::cluster_spill_dir;
+use databend_common_storage::DataOperator;
+use databend_query::spillers::remove_orphan_spill_files;
+use databend_query::spillers::remove_query_spill_files;
+use databend_query::test_kits::ConfigBuilder;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_remove_orphan_spill_files() -> Result<()> {
+    let config = ConfigBuilder::create().config();
+    let _fixture = TestFixture::setup_with_config(&config).await?;
+
+    let dir = cluster_spill_dir(
+        config.query.tenant_id.tenant_name(),
+        &config.query.cluster_id,
+    );
+    let alive_file = format!("{}/query_1/{}/spill_1", dir, config.query.node_id);
+    let orphan_file = format!("{}/query_1/crashed_node/spill_1", dir);
+    let operator = DataOperator::instance().operator();
+    operator.write(&alive_file, vec![1, 2, 3]).await?;
+    operator.write(&orphan_file, vec![1, 2, 3]).await?;
+
+    // The files written by the nodes which are not in the cluster are removed on startup.
+    remove_orphan_spill_files(&config).await?;
+    assert!(operator.is_exist(&alive_file).await?);
+    assert!(!operator.is_exist(&orphan_file).await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_remove_query_spill_files() -> Result<()> {
+    let config = ConfigBuilder::create().config();
+    let fixture = TestFixture::setup_with_config(&config).await?;
+    let operator = DataOperator::instance().operator();
+
+    // The files of a query which never spilled are not touched.
+    let ctx = fixture.new_query_ctx().await?;
+    let file = format!("{}/{}/spill_1", ctx.query_spill_dir(), config.query.node_id);
+    operator.write(&file, vec![1, 2, 3]).await?;
+    remove_query_spill_files(&ctx);
+    assert!(operator.is_exist(&file).await?);
+
+    // The spill key is created by the first spill of the query.
+    ctx.get_spill_key();
+    let other_node_file = format!("{}/other_node/spill_1", ctx.query_spill_dir());
+    operator.write(&other_node_file, vec![1, 2, 3]).await?;
+    let other_query_ctx = fixture.new_query_ctx().await?;
+    let other_query_file = format!(
+        "{}/{}/spill_1",
+        other_query_ctx.query_spill_dir(),
+        config.query.node_id
+    );
+    operator.write(&other_query_file, vec![1, 2, 3]).await?;
+
+    // All the files of the query are removed when it finishes, only those of the query.
+    remove_query_spill_files(&ctx);
+    assert!(!operator.is_exist(&file).await?);
+    assert!(!operator.is_exist(&other_node_file).await?);
+    assert!(operator.is_exist(&other_query_file).await?);
+
+    Ok(())
+}
@@ -786,6 +786,14 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn add_returning_block(&self, _block: DataBlock) {
+        todo!()
+    }
+
+    fn get_returning_blocks(&self) -> Vec<DataBlock> {
+        todo!()
+    }
+
     fn add_query_profiles(&self, _: &[PlanProfile]) {
         todo!()
     }
@@ -0,0 +1,261 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden tests of the optimized plans of the TPC-H and TPC-DS queries.
+//!
+//! Each corpus under `testdata/<corpus>` consists of:
+//! - `schema.sql`: the DDL of the tables.
+//! - `statistics.json`: the fixture statistics (row count, ndv and integer min/max of columns)
+//!   of the tables, which are served to the optimizer instead of the real ones.
+//! - `queries.sql`: the queries, each of them starts with a `-- <name>` line.
+//! - `plans.txt`: the expected `EXPLAIN` output of the queries.
+//!
+//! Run with `UPDATE_GOLDENFILES=1` to regenerate `plans.txt` after changing the optimizer,
+//! and review the plan diffs.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io::Write as _;
+use std::path::Path;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::statistics::BasicColumnStatistics;
+use databend_common_catalog::table::ColumnStatisticsProvider;
+use databend_common_catalog::table::ParquetTableColumnStatisticsProvider;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableStatistics;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnId;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_storage::Datum;
+use databend_query::sessions::QueryContext;
+use databend_query::test_kits::execute_command;
+use databend_query::test_kits::execute_query;
+use databend_query::test_kits::TestFixture;
+use databend_storages_common_table_meta::table::ChangeType;
+use futures_util::TryStreamExt;
+use goldenfile::Mint;
+
+const TESTDATA_DIR: &str = "tests/it/sql/planner/optimizer/testdata";
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tpch_plans() -> Result<()> {
+    check_golden_plans("tpch").await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_tpcds_plans() -> Result<()> {
+    check_golden_plans("tpcds").await
+}
+
+async fn check_golden_plans(corpus: &str) -> Result<()> {
+    let dir = Path::new(TESTDATA_DIR).join(corpus);
+    let fixture = TestFixture::setup().await?;
+    let tables = create_fixture_tables(&fixture, corpus, &dir).await?;
+
+    let mut mint = Mint::new(&dir);
+    let file = &mut mint.new_goldenfile("plans.txt").unwrap();
+    for (name, query) in load_queries(&dir.join("queries.sql"))? {
+        // tables are resolved from the cache of the query context first,
+        // so the fixture tables must be attached to each new context.
+        let ctx = fixture.new_query_ctx().await?;
+        for table in &tables {
+            ctx.attach_table("default", corpus, table.name(), table.clone());
+        }
+
+        let plan = explain(ctx, &query)
+            .await
+            .map_err(|e| e.add_message(format!("failed to explain {corpus} {name}:")))?;
+        writeln!(file, "-- {name}\n{plan}").unwrap();
+    }
+    Ok(())
+}
+
+/// Creates the tables of `schema.sql` and wraps them into [`FixtureTable`]s
+/// with the statistics of `statistics.json`.
+async fn create_fixture_tables(
+    fixture: &TestFixture,
+    corpus: &str,
+    dir: &Path,
+) -> Result<Vec<Arc<dyn Table>>> {
+    let ctx = fixture.new_query_ctx().await?;
+    execute_command(ctx.clone(), &format!("CREATE DATABASE {corpus}")).await?;
+    execute_command(ctx.clone(), &format!("USE {corpus}")).await?;
+
+    let schema = read_file(&dir.join("schema.sql"))?;
+    for ddl in schema.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+        execute_command(fixture.new_query_ctx().await?, ddl).await?;
+    }
+
+    let statistics: BTreeMap<String, FixtureStatistics> =
+        serde_json::from_str(&read_file(&dir.join("statistics.json"))?)
+            .map_err(|e| ErrorCode::Internal(format!("invalid statistics.json: {e}")))?;
+
+    let catalog = ctx.get_catalog("default").await?;
+    let mut tables = Vec::with_capacity(statistics.len());
+    for (name, stats) in statistics {
+        let table = catalog.get_table(&ctx.get_tenant(), corpus, &name).await?;
+        tables.push(FixtureTable::try_create(
+            table.get_table_info().clone(),
+            stats,
+        )?);
+    }
+    Ok(tables)
+}
+
+/// Splits `queries.sql` into the named queries.
+fn load_queries(path: &Path) -> Result<Vec<(String, String)>> {
+    let mut queries: Vec<(String, String)> = vec![];
+    for line in read_file(path)?.lines() {
+        if let Some(name) = line.strip_prefix("-- ") {
+            queries.push((name.trim().to_string(), String::new()));
+        } else if let Some((_, query)) = queries.last_mut() {
+            query.push_str(line);
+            query.push('\n');
+        }
+    }
+
+    Ok(queries
+        .into_iter()
+        .map(|(name, query)| (name, query.trim().trim_end_matches(';').to_string()))
+        .collect())
+}
+
+async fn explain(ctx: Arc<QueryContext>, query: &str) -> Result<String> {
+    let stream = execute_query(ctx, &format!("EXPLAIN {query}")).await?;
+    let blocks = stream.try_collect::<Vec<_>>().await?;
+
+    let mut plan = String::new();
+    for block in blocks {
+        let entry = block.get_by_offset(0);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        if let Column::String(column) = column.remove_nullable() {
+            for line in column.iter() {
+                writeln!(plan, "{line}").unwrap();
+            }
+        }
+    }
+    Ok(plan)
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| ErrorCode::Internal(format!("failed to read {path:?}: {e}")))
+}
+
+#[derive(serde::Deserialize)]
+struct FixtureStatistics {
+    num_rows: u64,
+    #[serde(default)]
+    columns: BTreeMap<String, FixtureColumnStatistics>,
+}
+
+#[derive(serde::Deserialize)]
+struct FixtureColumnStatistics {
+    ndv: Option<u64>,
+    min: Option<i64>,
+    max: Option<i64>,
+    #[serde(default)]
+    null_count: u64,
+}
+
+/// A table without data, which reports the fixture statistics to the optimizer.
+struct FixtureTable {
+    table_info: TableInfo,
+    num_rows: u64,
+    column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
+}
+
+impl FixtureTable {
+    fn try_create(table_info: TableInfo, stats: FixtureStatistics) -> Result<Arc<dyn Table>> {
+        let schema = table_info.schema();
+        let mut column_stats = HashMap::with_capacity(stats.columns.len());
+        for (name, column) in stats.columns {
+            // the statistics of a column are looked up by its leaf index,
+            // which is the same as the field index for the flat schemas here.
+            let index = schema.index_of(&name)?;
+            column_stats.insert(
+                index as ColumnId,
+                Some(BasicColumnStatistics {
+                    min: column.min.map(Datum::Int),
+                    max: column.max.map(Datum::Int),
+                    ndv: column.ndv,
+                    null_count: column.null_count,
+                }),
+            );
+        }
+
+        Ok(Arc::new(FixtureTable {
+            table_info,
+            num_rows: stats.num_rows,
+            column_stats,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for FixtureTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn support_column_projection(&self) -> bool {
+        true
+    }
+
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    async fn table_statistics(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _change_type: Option<ChangeType>,
+    ) -> Result<Option<TableStatistics>> {
+        Ok(Some(TableStatistics {
+            num_rows: Some(self.num_rows),
+            ..Default::default()
+        }))
+    }
+
+    async fn column_statistics_provider(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+    ) -> Result<Box<dyn ColumnStatisticsProvider>> {
+        Ok(Box::new(ParquetTableColumnStatisticsProvider::new(
+            self.column_stats.clone(),
+            self.num_rows,
+        )))
+    }
+}
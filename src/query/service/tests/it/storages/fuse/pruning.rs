@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_ast::ast::Engine;
 use databend_common_base::base::tokio;
+use databend_common_catalog::plan::PruningStatistics;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_exception::Result;
 use databend_common_expression::types::number::Int64Type;
@@ -59,14 +60,18 @@ async fn apply_block_pruning(
     ctx: Arc<QueryContext>,
     op: Operator,
     bloom_index_cols: BloomIndexColumns,
-) -> Result<Vec<Arc<BlockMeta>>> {
+) -> Result<(Vec<Arc<BlockMeta>>, PruningStatistics)> {
     let ctx: Arc<dyn TableContext> = ctx;
     let segment_locs = table_snapshot.segments.clone();
     let segment_locs = create_segment_location_vector(segment_locs, None);
-    FusePruner::create(&ctx, op, schema, push_down, bloom_index_cols)?
+    let mut pruner = FusePruner::create(&ctx, op, schema, push_down, bloom_index_cols)?;
+    let blocks = pruner
         .read_pruning(segment_locs)
-        .await
-        .map(|v| v.into_iter().map(|(_, v)| v).collect())
+        .await?
+        .into_iter()
+        .map(|(_, v)| v)
+        .collect();
+    Ok((blocks, pruner.pruning_stats()))
 }
 
 #[tokio::test(flavor = "multi_thread")]
@@ -231,17 +236,18 @@ async fn test_block_pruner() -> Result<()> {
         ..Default::default()
     };
 
+    // the expected limit pruning stats are (blocks before, blocks after).
     let extras = vec![
-        (None, num_blocks, num_blocks * row_per_block),
-        (Some(e1), 0, 0),
-        (Some(e2), b2, b2 * row_per_block),
-        (Some(e3), 3, 3 * row_per_block),
-        (Some(e4), 4, 4 * row_per_block),
-        (Some(e5), 2, 2 * row_per_block),
+        (None, num_blocks, num_blocks * row_per_block, (0, 0)),
+        (Some(e1), 0, 0, (0, 0)),
+        (Some(e2), b2, b2 * row_per_block, (0, 0)),
+        (Some(e3), 3, 3 * row_per_block, (num_blocks, 3)),
+        (Some(e4), 4, 4 * row_per_block, (num_blocks, 4)),
+        (Some(e5), 2, 2 * row_per_block, (num_blocks, 2)),
     ];
 
-    for (extra, expected_blocks, expected_rows) in extras {
-        let blocks = apply_block_pruning(
+    for (extra, expected_blocks, expected_rows, expected_limit_pruning) in extras {
+        let (blocks, pruning_stats) = apply_block_pruning(
             snapshot.clone(),
             table.get_table_info().schema(),
             &extra,
@@ -254,6 +260,13 @@ async fn test_block_pruner() -> Result<()> {
         let rows = blocks.iter().map(|b| b.row_count as usize).sum::<usize>();
         assert_eq!(expected_rows, rows);
         assert_eq!(expected_blocks, blocks.len());
+        assert_eq!(
+            expected_limit_pruning,
+            (
+                pruning_stats.blocks_limit_pruning_before,
+                pruning_stats.blocks_limit_pruning_after
+            )
+        );
     }
 
     Ok(())
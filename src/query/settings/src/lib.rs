@@ -23,4 +23,6 @@ pub use settings::Settings;
 pub use settings_default::ReplaceIntoShuffleStrategy;
 pub use settings_default::SettingMode;
 pub use settings_default::SettingRange;
+pub use settings_getter_setter::CastDialect;
 pub use settings_getter_setter::FlightCompression;
+pub use settings_getter_setter::QueryTextRedaction;
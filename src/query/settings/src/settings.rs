@@ -29,12 +29,15 @@ use crate::settings_default::DefaultSettings;
 use crate::settings_default::SettingRange;
 use crate::SettingMode;
 
+/// The level a setting value comes from, a value of the latter level overrides the former:
+/// `DEFAULT` < `LOCAL`(config file) < `GLOBAL`(tenant) < `SESSION` < `QUERY`(statement hint).
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub enum ScopeLevel {
     Default,
     Local,
     Global,
     Session,
+    Query,
 }
 
 impl Debug for ScopeLevel {
@@ -52,6 +55,9 @@ impl Debug for ScopeLevel {
             ScopeLevel::Session => {
                 write!(f, "SESSION")
             }
+            ScopeLevel::Query => {
+                write!(f, "QUERY")
+            }
         }
     }
 }
@@ -108,6 +114,22 @@ impl Settings {
         Ok(())
     }
 
+    /// Sets the settings only for the current statement, e.g. `/*+ SET_VAR(max_threads=4) */`.
+    pub fn set_query_settings(&self, settings: &HashMap<String, String>) -> Result<()> {
+        for (k, v) in settings.iter() {
+            if self.has_setting(k.as_str())? {
+                DefaultSettings::check_setting_mode(k, SettingMode::Write)?;
+                let (key, value) = DefaultSettings::convert_value(k.to_string(), v.to_string())?;
+                self.changes.insert(key, ChangeValue {
+                    level: ScopeLevel::Query,
+                    value,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn is_changed(&self) -> bool {
         !self.changes.is_empty()
     }
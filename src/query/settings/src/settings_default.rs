@@ -134,6 +134,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(1..=u64::MAX)),
                 }),
+                ("max_block_size_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum byte size of a single data block that can be read or built by transforms. Setting it to 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("parquet_max_block_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(8192),
                     desc: "Max block size for parquet reader",
@@ -178,6 +184,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("storage_io_max_bytes_per_read_request", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(16 * 1024 * 1024),
+                    desc: "Sets the maximum byte size of a single read request, larger merged ranges are split and read concurrently. 0 means no split.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("flight_client_timeout", DefaultSettingValue {
                     value: UserSettingValue::UInt64(60),
                     desc: "Sets the maximum time in seconds that a flight client request can be processed.",
@@ -194,12 +206,54 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("http_handler_async_result_timeout_secs", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(3600),
+                    desc: "Set the timeout in seconds that the result of an async http query is kept without any polls.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1024 * 1024),
                     desc: "Sets the byte size of the buffer used for reading data into memory.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
                 }),
+                ("storage_write_part_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(16 * 1024 * 1024),
+                    desc: "Sets the byte size of each part when a large block or unloaded file is uploaded by multipart upload. Default value: 16MB",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(5 * 1024 * 1024..=5 * 1024 * 1024 * 1024)),
+                }),
+                ("storage_write_concurrency", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4),
+                    desc: "Sets the maximum number of parts of a multipart upload that are uploaded concurrently.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(1..=64)),
+                }),
+                ("io_priority", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("interactive")),
+                    desc: "Sets the priority of the storage requests of the queries when the concurrent storage requests of the node are limited, interactive or background.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["interactive".into(), "background".into()])),
+                }),
+                ("query_priority", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("normal")),
+                    desc: "Sets the priority of the queries when the node is busy, low, normal or high. The queries of lower priority yield their CPU time slices and storage requests to the queries of higher priority.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["low".into(), "normal".into(), "high".into()])),
+                }),
+                ("query_text_redaction", DefaultSettingValue {
+                    value: UserSettingValue::String(String::from("none")),
+                    desc: "Redacts the SQL text in the logs and the system tables, none, literals (masks all the literals) or sensitive_columns (masks the values of the columns in query_text_sensitive_columns). Set it globally to apply it to the whole tenant.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["none".into(), "literals".into(), "sensitive_columns".into()])),
+                }),
+                ("query_text_sensitive_columns", DefaultSettingValue {
+                    value: UserSettingValue::String(String::new()),
+                    desc: "Sets the comma-separated names of the columns whose values are masked in the SQL text when query_text_redaction is sensitive_columns.",
+                    mode: SettingMode::Both,
+                    range: None,
+                }),
                 ("input_read_buffer_size", DefaultSettingValue {
                     value: UserSettingValue::UInt64(4 * 1024 * 1024),
                     desc: "Sets the memory size in bytes allocated to the buffer used by the buffered reader to read data from storage.",
@@ -208,10 +262,16 @@ impl DefaultSettings {
                 }),
                 ("enable_new_copy_for_text_formats", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
-                    desc: "Use new implementation for loading CSV files.",
+                    desc: "Use new implementation for loading CSV, TSV and NDJSON files.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("text_file_split_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the size in bytes above which an uncompressed CSV or TSV file is split at record boundaries, so that it is loaded by several threads. 0 disables splitting. The SPLIT_SIZE option of 'COPY INTO' takes precedence.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("purge_duplicated_files_in_copy", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "Purge duplicated files detected during execution of copy into table.",
@@ -250,9 +310,9 @@ impl DefaultSettings {
                 }),
                 ("sql_dialect", DefaultSettingValue {
                     value: UserSettingValue::String("PostgreSQL".to_owned()),
-                    desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\",  \"Experimental\", \"Prql\", and \"Hive\".",
+                    desc: "Sets the SQL dialect. Available values include \"PostgreSQL\", \"MySQL\",  \"Experimental\", \"Prql\", \"Hive\", \"Strict\", and \"Snowflake\". The dialect also selects the implicit cast rules of the type checker.",
                     mode: SettingMode::Both,
-                    range: Some(SettingRange::String(vec!["PostgreSQL".into(), "MySQL".into(), "Experimental".into(), "Hive".into(), "Prql".into()])),
+                    range: Some(SettingRange::String(vec!["PostgreSQL".into(), "MySQL".into(), "Experimental".into(), "Hive".into(), "Prql".into(), "Strict".into(), "Snowflake".into()])),
                 }),
                 ("enable_dphyp", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
@@ -332,6 +392,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enforce_shuffle_join", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enforce shuffle join. It's enabled when a query is re-planned because the build side of its broadcast join is too large.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("broadcast_join_build_rows_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum estimated rows of the build side to prefer broadcast join regardless of the probe side. Setting it to 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("broadcast_join_max_build_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1024 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the build side of broadcast join, the query is re-planned with shuffle join if it's exceeded. Setting it to 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("storage_fetch_part_num", DefaultSettingValue {
                     value: UserSettingValue::UInt64(2),
                     desc: "Sets the number of partitions that are fetched in parallel from storage during query execution.",
@@ -374,6 +452,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_expression_jit", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables compiling the expressions on numbers into native code, requires the server built with the `jit` feature.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_plan_cache", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables caching the optimized plans of queries, identical queries skip parsing, binding and optimizing.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("query_result_cache_max_bytes", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1048576), // 1MB
                     desc: "Sets the maximum byte size of cache for a single query result.",
@@ -447,6 +537,42 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("max_direct_group_by_range", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the maximum range of a single integer group by key, estimated from statistics, to address the aggregate states directly instead of probing the hash table. Setting it to 0 disables it.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("enable_streaming_aggregation", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables aggregating the input sorted on the group by keys by streaming instead of hash tables.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("partial_aggregation_bypass_rows", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(65536),
+                    desc: "Sets the number of rows to check the reduction of partial aggregation, it's bypassed if the ratio of groups to rows exceeds partial_aggregation_bypass_ratio. Setting it to 0 disables the bypass.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("partial_aggregation_bypass_ratio", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(90),
+                    desc: "Sets the percentage of groups to rows above which partial aggregation is bypassed.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
+                ("enable_distinct_aggregate_rewrite", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables rewriting the DISTINCT aggregate functions on the same argument into a two-level aggregation, so the distinct values are shared instead of kept by each function.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_approx_count_distinct", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables computing COUNT(DISTINCT) and uniq with approx_count_distinct, the results are approximate.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("lazy_read_threshold", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1000),
                     desc: "Sets the maximum LIMIT in a query to enable lazy read optimization. Setting it to 0 disables the optimization.",
@@ -492,12 +618,24 @@ impl DefaultSettings {
                     mode: SettingMode::Write,
                     range: None,
                 }),
+                ("enable_insert_preserve_order", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Preserves the order of the data being inserted (e.g. INSERT INTO ... SELECT ... ORDER BY) while serializing blocks in parallel, so that the data ingested in order is kept clustered.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_distributed_copy_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables distributed execution for the 'COPY INTO'.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("distributed_copy_pull_batch_size", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(4),
+                    desc: "Sets the number of files a node pulls from the coordinator at a time in distributed 'COPY INTO', so that the nodes loading faster take more files. 0 means the files are assigned to the nodes when the query starts. Only the CSV, TSV and NDJSON files read with 'enable_new_copy_for_text_formats' are pulled.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_experimental_merge_into", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables the experimental feature for 'MERGE INTO'.",
@@ -522,6 +660,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("deletion_vector_threshold", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Sets the maximum percentage of deleted rows in a block for which DELETE records them in a deletion vector instead of rewriting the block, 0 disables deletion vectors.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
+                }),
                 ("enable_aggregating_index_scan", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables scanning aggregating index data while querying.",
@@ -548,10 +692,16 @@ impl DefaultSettings {
                 }),
                 ("enable_replace_into_partitioning", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
-                    desc: "Enables partitioning for replace-into statement (if table has cluster keys).",
+                    desc: "Enables partitioning for replace-into statement, by the left-most cluster key if table has cluster keys, otherwise by the key ranges of the on-conflict columns.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("replace_into_key_range_partitions", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(16),
+                    desc: "Sets the max number of key ranges the input of replace-into statement is partitioned into, if table has no cluster keys (0 or 1 disables it).",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1024)),
+                }),
                 ("enable_replace_into_bloom_pruning", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables bloom pruning for replace-into statement.",
@@ -630,6 +780,18 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_dictionary_encoded_string", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reading the low cardinality string columns of native tables as dictionary encoded columns.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("enable_run_length_columns", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Enables reading the columns of native tables with few runs as constant or run-length columns.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_experimental_aggregate_hashtable", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables experimental aggregate hashtable",
@@ -642,6 +804,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(vec!["rounding".into(), "truncating".into()])),
                 }),
+                ("decimal_overflow_mode", DefaultSettingValue {
+                    value: UserSettingValue::String("error".to_string()),
+                    desc: "Set the handling of decimal arithmetic overflow as \"error\", \"null\" or \"saturate\".",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::String(vec!["error".into(), "null".into(), "saturate".into()])),
+                }),
                 ("enable_experimental_rbac_check", DefaultSettingValue {
                     value: UserSettingValue::UInt64(0),
                     desc: "experiment setting disables stage and udf privilege check(disable by default).",
@@ -660,6 +828,24 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::String(vec!["None".into(), "LZ4".into(), "ZSTD".into()])),
                 }),
+                ("exchange_block_format_version", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Sets the version of the block format sent between nodes, set it to 0 while the cluster is upgrading from the nodes that only support version 0.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("exchange_preserve_dictionary", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Sends the dictionary encoded string columns between nodes without decoding them, only works with the block format version 1 and later.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
+                ("exchange_buffer_size_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(64 * 1024 * 1024),
+                    desc: "Sets the maximum bytes of the data sent to a node but not yet received by it, the sender waits once the limit is reached. 0 means no limit.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_refresh_virtual_column_after_write", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Refresh virtual column after new data written",
@@ -726,6 +912,18 @@ impl DefaultSettings {
                     desc: "The maximum steps allowed in a single execution of script.",
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("long_query_time", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(10),
+                    desc: "The queries running longer than this many seconds are recorded into system.slow_queries. Setting it to 0 disables the slow query log.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
+                ("slow_query_explain_analyze_sample_rate", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "The percentage of the queries whose physical plan is kept to record an EXPLAIN ANALYZE output into system.slow_queries if they turn out to be slow.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=100)),
                 })
             ]);
 
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use databend_common_ast::parser::Dialect;
+use databend_common_base::runtime::IoPriority;
+use databend_common_base::runtime::QueryPriority;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::UserSettingValue;
@@ -30,6 +32,27 @@ pub enum FlightCompression {
     Zstd,
 }
 
+/// The implicit cast rules of the type checker, selected by the `sql_dialect` setting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastDialect {
+    /// The builtin rules of the functions.
+    Default,
+    /// Only the lossless casts are implicit.
+    Strict,
+    MySQL,
+    Snowflake,
+}
+
+/// How the SQL text is redacted in the logs and the system tables, selected by the
+/// `query_text_redaction` setting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueryTextRedaction {
+    None,
+    Literals,
+    /// Only the values of the columns in `query_text_sensitive_columns`.
+    SensitiveColumns,
+}
+
 impl Settings {
     // Get u64 value, we don't get from the metasrv.
     fn try_get_u64(&self, key: &str) -> Result<u64> {
@@ -135,6 +158,10 @@ impl Settings {
         self.try_get_u64("max_block_size")
     }
 
+    pub fn get_max_block_size_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("max_block_size_bytes")? as usize)
+    }
+
     // Max block size for parquet reader
     pub fn get_parquet_max_block_size(&self) -> Result<u64> {
         self.try_get_u64("parquet_max_block_size")
@@ -204,6 +231,59 @@ impl Settings {
         self.try_get_u64("storage_io_max_page_bytes_for_read")
     }
 
+    pub fn get_storage_io_max_bytes_per_read_request(&self) -> Result<u64> {
+        self.try_get_u64("storage_io_max_bytes_per_read_request")
+    }
+
+    pub fn get_storage_write_part_size(&self) -> Result<u64> {
+        self.try_get_u64("storage_write_part_size")
+    }
+
+    pub fn get_storage_write_concurrency(&self) -> Result<u64> {
+        self.try_get_u64("storage_write_concurrency")
+    }
+
+    pub fn get_io_priority(&self) -> Result<IoPriority> {
+        match self.try_get_string("io_priority")?.to_lowercase().as_str() {
+            "background" => Ok(IoPriority::Background),
+            _ => Ok(IoPriority::Interactive),
+        }
+    }
+
+    pub fn get_query_priority(&self) -> Result<QueryPriority> {
+        match self
+            .try_get_string("query_priority")?
+            .to_lowercase()
+            .as_str()
+        {
+            "low" => Ok(QueryPriority::Low),
+            "high" => Ok(QueryPriority::High),
+            _ => Ok(QueryPriority::Normal),
+        }
+    }
+
+    pub fn get_query_text_redaction(&self) -> Result<QueryTextRedaction> {
+        match self
+            .try_get_string("query_text_redaction")?
+            .to_lowercase()
+            .as_str()
+        {
+            "literals" => Ok(QueryTextRedaction::Literals),
+            "sensitive_columns" => Ok(QueryTextRedaction::SensitiveColumns),
+            _ => Ok(QueryTextRedaction::None),
+        }
+    }
+
+    /// The lowercase names of the sensitive columns.
+    pub fn get_query_text_sensitive_columns(&self) -> Result<Vec<String>> {
+        Ok(self
+            .try_get_string("query_text_sensitive_columns")?
+            .split(',')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect())
+    }
+
     // Get max_execute_time_in_seconds.
     pub fn get_max_execute_time_in_seconds(&self) -> Result<u64> {
         self.try_get_u64("max_execute_time_in_seconds")
@@ -227,6 +307,10 @@ impl Settings {
         self.try_get_u64("enable_new_copy_for_text_formats")
     }
 
+    pub fn get_text_file_split_size(&self) -> Result<u64> {
+        self.try_get_u64("text_file_split_size")
+    }
+
     pub fn get_enable_purge_duplicated_files_in_copy(&self) -> Result<bool> {
         Ok(self.try_get_u64("purge_duplicated_files_in_copy")? != 0)
     }
@@ -301,6 +385,22 @@ impl Settings {
         Ok(self.try_get_u64("enforce_broadcast_join")? != 0)
     }
 
+    pub fn get_enforce_shuffle_join(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enforce_shuffle_join")? != 0)
+    }
+
+    pub fn set_enforce_shuffle_join(&self, val: bool) -> Result<()> {
+        self.try_set_u64("enforce_shuffle_join", u64::from(val))
+    }
+
+    pub fn get_broadcast_join_build_rows_threshold(&self) -> Result<u64> {
+        self.try_get_u64("broadcast_join_build_rows_threshold")
+    }
+
+    pub fn get_broadcast_join_max_build_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("broadcast_join_max_build_bytes")? as usize)
+    }
+
     pub fn get_sql_dialect(&self) -> Result<Dialect> {
         match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
             "hive" => Ok(Dialect::Hive),
@@ -311,6 +411,15 @@ impl Settings {
         }
     }
 
+    pub fn get_cast_dialect(&self) -> Result<CastDialect> {
+        match self.try_get_string("sql_dialect")?.to_lowercase().as_str() {
+            "strict" => Ok(CastDialect::Strict),
+            "mysql" => Ok(CastDialect::MySQL),
+            "snowflake" => Ok(CastDialect::Snowflake),
+            _ => Ok(CastDialect::Default),
+        }
+    }
+
     pub fn get_collation(&self) -> Result<&str> {
         match self.try_get_string("collation")?.to_lowercase().as_str() {
             "utf8" => Ok("utf8"),
@@ -342,6 +451,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_query_result_cache")? != 0)
     }
 
+    pub fn get_enable_expression_jit(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_expression_jit")? != 0)
+    }
+
+    pub fn get_enable_plan_cache(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_plan_cache")? != 0)
+    }
+
     pub fn get_query_result_cache_max_bytes(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
@@ -354,6 +471,10 @@ impl Settings {
         self.try_get_u64("http_handler_result_timeout_secs")
     }
 
+    pub fn get_http_handler_async_result_timeout_secs(&self) -> Result<u64> {
+        self.try_get_u64("http_handler_async_result_timeout_secs")
+    }
+
     pub fn get_query_result_cache_ttl_secs(&self) -> Result<u64> {
         self.try_get_u64("query_result_cache_ttl_secs")
     }
@@ -386,6 +507,30 @@ impl Settings {
         Ok(self.try_get_u64("efficiently_memory_group_by")? == 1)
     }
 
+    pub fn get_max_direct_group_by_range(&self) -> Result<u64> {
+        self.try_get_u64("max_direct_group_by_range")
+    }
+
+    pub fn get_enable_streaming_aggregation(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_streaming_aggregation")? == 1)
+    }
+
+    pub fn get_partial_aggregation_bypass_rows(&self) -> Result<u64> {
+        self.try_get_u64("partial_aggregation_bypass_rows")
+    }
+
+    pub fn get_partial_aggregation_bypass_ratio(&self) -> Result<u64> {
+        self.try_get_u64("partial_aggregation_bypass_ratio")
+    }
+
+    pub fn get_enable_distinct_aggregate_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_distinct_aggregate_rewrite")? == 1)
+    }
+
+    pub fn get_enable_approx_count_distinct(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_approx_count_distinct")? == 1)
+    }
+
     pub fn get_enable_experimental_aggregate_hashtable(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_aggregate_hashtable")? == 1)
     }
@@ -447,6 +592,14 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_copy_into")? != 0)
     }
 
+    pub fn get_distributed_copy_pull_batch_size(&self) -> Result<usize> {
+        Ok(self.try_get_u64("distributed_copy_pull_batch_size")? as usize)
+    }
+
+    pub fn get_enable_insert_preserve_order(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_insert_preserve_order")? != 0)
+    }
+
     pub fn get_enable_experimental_merge_into(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_experimental_merge_into")? != 0)
     }
@@ -463,6 +616,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_distributed_compact")? != 0)
     }
 
+    pub fn get_deletion_vector_threshold(&self) -> Result<u64> {
+        self.try_get_u64("deletion_vector_threshold")
+    }
+
     pub fn get_enable_aggregating_index_scan(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
@@ -491,6 +648,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_replace_into_partitioning")? != 0)
     }
 
+    pub fn get_replace_into_key_range_partitions(&self) -> Result<u64> {
+        self.try_get_u64("replace_into_key_range_partitions")
+    }
+
     pub fn get_enable_replace_into_bloom_pruning(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_replace_into_bloom_pruning")? != 0)
     }
@@ -540,10 +701,22 @@ impl Settings {
         Ok(self.try_get_u64("enable_parquet_prewhere")? != 0)
     }
 
+    pub fn get_enable_dictionary_encoded_string(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_dictionary_encoded_string")? != 0)
+    }
+
+    pub fn get_enable_run_length_columns(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_run_length_columns")? != 0)
+    }
+
     pub fn get_numeric_cast_option(&self) -> Result<String> {
         self.try_get_string("numeric_cast_option")
     }
 
+    pub fn get_decimal_overflow_mode(&self) -> Result<String> {
+        self.try_get_string("decimal_overflow_mode")
+    }
+
     pub fn get_external_server_connect_timeout_secs(&self) -> Result<u64> {
         self.try_get_u64("external_server_connect_timeout_secs")
     }
@@ -569,6 +742,18 @@ impl Settings {
         }
     }
 
+    pub fn get_exchange_block_format_version(&self) -> Result<u8> {
+        Ok(self.try_get_u64("exchange_block_format_version")? as u8)
+    }
+
+    pub fn get_exchange_preserve_dictionary(&self) -> Result<bool> {
+        Ok(self.try_get_u64("exchange_preserve_dictionary")? != 0)
+    }
+
+    pub fn get_exchange_buffer_size_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("exchange_buffer_size_bytes")? as usize)
+    }
+
     pub fn get_enable_refresh_virtual_column_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_refresh_virtual_column_after_write")? != 0)
     }
@@ -631,4 +816,12 @@ impl Settings {
     pub fn get_script_max_steps(&self) -> Result<u64> {
         self.try_get_u64("script_max_steps")
     }
+
+    pub fn get_long_query_time(&self) -> Result<u64> {
+        self.try_get_u64("long_query_time")
+    }
+
+    pub fn get_slow_query_explain_analyze_sample_rate(&self) -> Result<u64> {
+        self.try_get_u64("slow_query_explain_analyze_sample_rate")
+    }
 }
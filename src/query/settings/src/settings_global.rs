@@ -48,6 +48,27 @@ impl Settings {
             .await
     }
 
+    /// Unsets the session value, the global value of the tenant takes effect again if any.
+    #[async_backtrace::framed]
+    pub async fn try_unset_session_setting(&self, key: &str) -> Result<()> {
+        self.changes.remove(key);
+
+        let api = UserApiProvider::instance();
+        let global_settings = Settings::load_settings(api, &self.tenant).await?;
+        if let Some(global_setting) = global_settings.into_iter().find(|x| x.name == key) {
+            let (key, value) = DefaultSettings::convert_value(
+                global_setting.name,
+                global_setting.value.as_string(),
+            )?;
+            self.changes.insert(key, ChangeValue {
+                level: ScopeLevel::Global,
+                value,
+            });
+        }
+
+        Ok(())
+    }
+
     #[async_backtrace::framed]
     pub async fn set_global_setting(&self, k: String, v: String) -> Result<()> {
         let (key, value) = DefaultSettings::convert_value(k.clone(), v)?;
@@ -68,7 +68,14 @@ impl PhysicalPlan {
         profs: HashMap<u32, PlanProfile>,
     ) -> Result<FormatTreeNode<String>> {
         let metadata = metadata.read().clone();
-        to_format_tree(self, &metadata, &profs)
+        to_format_tree(self, &metadata, &profs, false)
+    }
+
+    /// Format the plan for `EXPLAIN(VERBOSE)`, which adds the estimated compressed
+    /// and decoded bytes of each table scan.
+    pub fn format_verbose(&self, metadata: MetadataRef) -> Result<FormatTreeNode<String>> {
+        let metadata = metadata.read().clone();
+        to_format_tree(self, &metadata, &HashMap::new(), true)
     }
 
     pub fn format_join(&self, metadata: &MetadataRef) -> Result<FormatTreeNode<String>> {
@@ -196,40 +203,53 @@ fn to_format_tree(
     plan: &PhysicalPlan,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     match plan {
-        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::EvalScalar(plan) => eval_scalar_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::TableScan(plan) => table_scan_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::Filter(plan) => filter_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::Project(plan) => project_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::EvalScalar(plan) => {
+            eval_scalar_to_format_tree(plan, metadata, profs, verbose)
+        }
         PhysicalPlan::AggregateExpand(plan) => {
-            aggregate_expand_to_format_tree(plan, metadata, profs)
+            aggregate_expand_to_format_tree(plan, metadata, profs, verbose)
         }
         PhysicalPlan::AggregatePartial(plan) => {
-            aggregate_partial_to_format_tree(plan, metadata, profs)
+            aggregate_partial_to_format_tree(plan, metadata, profs, verbose)
         }
-        PhysicalPlan::AggregateFinal(plan) => aggregate_final_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::RowFetch(plan) => row_fetch_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AggregateFinal(plan) => {
+            aggregate_final_to_format_tree(plan, metadata, profs, verbose)
+        }
+        PhysicalPlan::Window(plan) => window_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::Sort(plan) => sort_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::Limit(plan) => limit_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::RowFetch(plan) => row_fetch_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::HashJoin(plan) => hash_join_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::Exchange(plan) => exchange_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::UnionAll(plan) => union_all_to_format_tree(plan, metadata, profs, verbose),
         PhysicalPlan::ExchangeSource(plan) => exchange_source_to_format_tree(plan, metadata),
-        PhysicalPlan::ExchangeSink(plan) => exchange_sink_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::ExchangeSink(plan) => {
+            exchange_sink_to_format_tree(plan, metadata, profs, verbose)
+        }
         PhysicalPlan::DistributedInsertSelect(plan) => {
-            distributed_insert_to_format_tree(plan.as_ref(), metadata, profs)
+            distributed_insert_to_format_tree(plan.as_ref(), metadata, profs, verbose)
         }
         PhysicalPlan::DeleteSource(_) => Ok(FormatTreeNode::new("DeleteSource".to_string())),
         PhysicalPlan::ReclusterSource(_) => Ok(FormatTreeNode::new("ReclusterSource".to_string())),
-        PhysicalPlan::ReclusterSink(plan) => recluster_sink_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::ReclusterSink(plan) => {
+            recluster_sink_to_format_tree(plan, metadata, profs, verbose)
+        }
         PhysicalPlan::UpdateSource(_) => Ok(FormatTreeNode::new("UpdateSource".to_string())),
         PhysicalPlan::CompactSource(_) => Ok(FormatTreeNode::new("CompactSource".to_string())),
-        PhysicalPlan::CommitSink(plan) => commit_sink_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
-        PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::CommitSink(plan) => {
+            commit_sink_to_format_tree(plan, metadata, profs, verbose)
+        }
+        PhysicalPlan::ProjectSet(plan) => {
+            project_set_to_format_tree(plan, metadata, profs, verbose)
+        }
+        PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs, verbose),
+        PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs, verbose),
         PhysicalPlan::CopyIntoTable(plan) => copy_into_table(plan),
         PhysicalPlan::CopyIntoLocation(plan) => copy_into_location(plan),
         PhysicalPlan::ReplaceAsyncSourcer(_) => {
@@ -249,7 +269,7 @@ fn to_format_tree(
         }
         PhysicalPlan::CteScan(plan) => cte_scan_to_format_tree(plan),
         PhysicalPlan::MaterializedCte(plan) => {
-            materialized_cte_to_format_tree(plan, metadata, profs)
+            materialized_cte_to_format_tree(plan, metadata, profs, verbose)
         }
         PhysicalPlan::ConstantTableScan(plan) => constant_table_scan_to_format_tree(plan, metadata),
         PhysicalPlan::Duplicate(_) => Ok(FormatTreeNode::new("Duplicate".to_string())),
@@ -302,6 +322,7 @@ fn table_scan_to_format_tree(
     plan: &TableScan,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     if plan.table_index == Some(DUMMY_TABLE_INDEX) {
         return Ok(FormatTreeNode::new("DummyTableScan".to_string()));
@@ -363,7 +384,13 @@ fn table_scan_to_format_tree(
     ];
 
     // Part stats.
-    children.extend(part_stats_info_to_format_tree(&plan.source.statistics));
+    // the limit pruning stats are only shown by EXPLAIN ANALYZE, which has the profiles,
+    // the decode estimates by EXPLAIN ANALYZE and EXPLAIN(VERBOSE).
+    children.extend(part_stats_info_to_format_tree(
+        &plan.source.statistics,
+        !profs.is_empty(),
+        verbose || !profs.is_empty(),
+    ));
     // Push downs.
     let push_downs = match virtual_columns {
         Some(virtual_columns) => {
@@ -376,6 +403,21 @@ fn table_scan_to_format_tree(
         }
     };
     children.push(FormatTreeNode::new(push_downs));
+    // Push downs the table engine can't evaluate, they are kept in the upper operators.
+    if let Some(table_index) = plan.table_index {
+        let capabilities = metadata.table(table_index).table().push_down_capabilities();
+        let unsupported = capabilities
+            .unsupported()
+            .into_iter()
+            .filter(|name| matches!(*name, "filter" | "limit"))
+            .collect::<Vec<_>>();
+        if !unsupported.is_empty() {
+            children.push(FormatTreeNode::new(format!(
+                "unsupported push downs: [{}]",
+                unsupported.join(", ")
+            )));
+        }
+    }
     // Aggregating index
     if let Some(agg_index) = agg_index {
         let (_, agg_index_sql, _) = metadata
@@ -456,6 +498,7 @@ fn filter_to_format_tree(
     plan: &Filter,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let filter = plan
         .predicates
@@ -477,7 +520,7 @@ fn filter_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "Filter".to_string(),
@@ -489,6 +532,7 @@ fn project_to_format_tree(
     plan: &Project,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![FormatTreeNode::new(format!(
         "output columns: [{}]",
@@ -502,7 +546,7 @@ fn project_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "Project".to_string(),
@@ -514,9 +558,10 @@ fn eval_scalar_to_format_tree(
     plan: &EvalScalar,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     if plan.exprs.is_empty() {
-        return to_format_tree(&plan.input, metadata, profs);
+        return to_format_tree(&plan.input, metadata, profs, verbose);
     }
     let scalars = plan
         .exprs
@@ -539,7 +584,7 @@ fn eval_scalar_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "EvalScalar".to_string(),
@@ -563,6 +608,7 @@ fn aggregate_expand_to_format_tree(
     plan: &AggregateExpand,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let sets = plan
         .grouping_sets
@@ -593,7 +639,7 @@ fn aggregate_expand_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "AggregateExpand".to_string(),
@@ -605,6 +651,7 @@ fn aggregate_partial_to_format_tree(
     plan: &AggregatePartial,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let group_by = plan
         .group_by
@@ -623,6 +670,10 @@ fn aggregate_partial_to_format_tree(
         FormatTreeNode::new(format!("aggregate functions: [{agg_funcs}]")),
     ];
 
+    if plan.streaming {
+        children.push(FormatTreeNode::new("streaming: true".to_string()));
+    }
+
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
         children.extend(items);
@@ -630,7 +681,7 @@ fn aggregate_partial_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "AggregatePartial".to_string(),
@@ -642,6 +693,7 @@ fn aggregate_final_to_format_tree(
     plan: &AggregateFinal,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let group_by = plan
         .group_by
@@ -681,7 +733,7 @@ fn aggregate_final_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "AggregateFinal".to_string(),
@@ -693,6 +745,7 @@ fn window_to_format_tree(
     plan: &Window,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let partition_by = plan
         .partition_by
@@ -735,7 +788,7 @@ fn window_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "Window".to_string(),
@@ -790,6 +843,7 @@ fn limit_to_format_tree(
     plan: &Limit,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![
         FormatTreeNode::new(format!(
@@ -811,7 +865,7 @@ fn limit_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children("Limit".to_string(), children))
 }
@@ -820,6 +874,7 @@ fn row_fetch_to_format_tree(
     plan: &RowFetch,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let table_schema = plan.source.source_info.schema();
     let projected_schema = plan.cols_to_fetch.project_schema(&table_schema);
@@ -843,7 +898,7 @@ fn row_fetch_to_format_tree(
 
     append_profile_info(&mut children, profs, plan.plan_id);
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "RowFetch".to_string(),
@@ -855,6 +910,7 @@ fn range_join_to_format_tree(
     plan: &RangeJoin,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let range_join_conditions = plan
         .conditions
@@ -879,8 +935,8 @@ fn range_join_to_format_tree(
         .collect::<Vec<_>>()
         .join(", ");
 
-    let mut left_child = to_format_tree(&plan.left, metadata, profs)?;
-    let mut right_child = to_format_tree(&plan.right, metadata, profs)?;
+    let mut left_child = to_format_tree(&plan.left, metadata, profs, verbose)?;
+    let mut right_child = to_format_tree(&plan.right, metadata, profs, verbose)?;
 
     left_child.payload = format!("{}(Left)", left_child.payload);
     right_child.payload = format!("{}(Right)", right_child.payload);
@@ -918,6 +974,7 @@ fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let build_keys = plan
         .build_keys
@@ -938,8 +995,8 @@ fn hash_join_to_format_tree(
         .collect::<Vec<_>>()
         .join(", ");
 
-    let mut build_child = to_format_tree(&plan.build, metadata, profs)?;
-    let mut probe_child = to_format_tree(&plan.probe, metadata, profs)?;
+    let mut build_child = to_format_tree(&plan.build, metadata, profs, verbose)?;
+    let mut probe_child = to_format_tree(&plan.probe, metadata, profs, verbose)?;
 
     build_child.payload = format!("{}(Build)", build_child.payload);
     probe_child.payload = format!("{}(Probe)", probe_child.payload);
@@ -975,6 +1032,7 @@ fn exchange_to_format_tree(
     plan: &Exchange,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     Ok(FormatTreeNode::with_children("Exchange".to_string(), vec![
         FormatTreeNode::new(format!(
@@ -994,7 +1052,7 @@ fn exchange_to_format_tree(
             FragmentKind::Expansive => "Broadcast".to_string(),
             FragmentKind::Merge => "Merge".to_string(),
         })),
-        to_format_tree(&plan.input, metadata, profs)?,
+        to_format_tree(&plan.input, metadata, profs, verbose)?,
     ]))
 }
 
@@ -1002,6 +1060,7 @@ fn union_all_to_format_tree(
     plan: &UnionAll,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![FormatTreeNode::new(format!(
         "output columns: [{}]",
@@ -1016,8 +1075,8 @@ fn union_all_to_format_tree(
     append_profile_info(&mut children, profs, plan.plan_id);
 
     children.extend(vec![
-        to_format_tree(&plan.left, metadata, profs)?,
-        to_format_tree(&plan.right, metadata, profs)?,
+        to_format_tree(&plan.left, metadata, profs, verbose)?,
+        to_format_tree(&plan.right, metadata, profs, verbose)?,
     ]);
 
     Ok(FormatTreeNode::with_children(
@@ -1026,15 +1085,40 @@ fn union_all_to_format_tree(
     ))
 }
 
-fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<String>> {
+fn part_stats_info_to_format_tree(
+    info: &PartStatistics,
+    with_limit_pruning: bool,
+    with_decode_estimates: bool,
+) -> Vec<FormatTreeNode<String>> {
     let read_size = format_byte_size(info.read_bytes);
     let mut items = vec![
         FormatTreeNode::new(format!("read rows: {}", info.read_rows)),
         FormatTreeNode::new(format!("read size: {}", read_size)),
-        FormatTreeNode::new(format!("partitions total: {}", info.partitions_total)),
-        FormatTreeNode::new(format!("partitions scanned: {}", info.partitions_scanned)),
     ];
 
+    // estimated bytes fetched from storage and bytes held in memory once decoded,
+    // only known by the storages that keep block level metadata.
+    if with_decode_estimates && (info.read_compressed_bytes > 0 || info.read_uncompressed_bytes > 0)
+    {
+        items.push(FormatTreeNode::new(format!(
+            "bytes to read (compressed): {}",
+            format_byte_size(info.read_compressed_bytes)
+        )));
+        items.push(FormatTreeNode::new(format!(
+            "bytes after decode: {}",
+            format_byte_size(info.read_uncompressed_bytes)
+        )));
+    }
+
+    items.push(FormatTreeNode::new(format!(
+        "partitions total: {}",
+        info.partitions_total
+    )));
+    items.push(FormatTreeNode::new(format!(
+        "partitions scanned: {}",
+        info.partitions_scanned
+    )));
+
     // format is like "pruning stats: [segments: <range pruning: x to y>, blocks: <range pruning: x to y>]"
     let mut blocks_pruning_description = String::new();
 
@@ -1071,6 +1155,18 @@ fn part_stats_info_to_format_tree(info: &PartStatistics) -> Vec<FormatTreeNode<S
         );
     }
 
+    // limit pruning status.
+    if with_limit_pruning && info.pruning_stats.blocks_limit_pruning_before > 0 {
+        if !blocks_pruning_description.is_empty() {
+            blocks_pruning_description += ", ";
+        }
+        blocks_pruning_description += &format!(
+            "limit pruning: {} to {}",
+            info.pruning_stats.blocks_limit_pruning_before,
+            info.pruning_stats.blocks_limit_pruning_after
+        );
+    }
+
     // Combine segment pruning and blocks pruning descriptions if any
     if info.pruning_stats.segments_range_pruning_before > 0
         || !blocks_pruning_description.is_empty()
@@ -1132,6 +1228,7 @@ fn exchange_sink_to_format_tree(
     plan: &ExchangeSink,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![FormatTreeNode::new(format!(
         "output columns: [{}]",
@@ -1143,7 +1240,7 @@ fn exchange_sink_to_format_tree(
         plan.destination_fragment_id
     )));
 
-    children.push(to_format_tree(&plan.input, metadata, profs)?);
+    children.push(to_format_tree(&plan.input, metadata, profs, verbose)?);
 
     Ok(FormatTreeNode::with_children(
         "ExchangeSink".to_string(),
@@ -1155,8 +1252,9 @@ fn distributed_insert_to_format_tree(
     plan: &DistributedInsertSelect,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![to_format_tree(&plan.input, metadata, profs)?];
+    let children = vec![to_format_tree(&plan.input, metadata, profs, verbose)?];
 
     Ok(FormatTreeNode::with_children(
         "DistributedInsertSelect".to_string(),
@@ -1168,8 +1266,9 @@ fn recluster_sink_to_format_tree(
     plan: &ReclusterSink,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![to_format_tree(&plan.input, metadata, profs)?];
+    let children = vec![to_format_tree(&plan.input, metadata, profs, verbose)?];
     Ok(FormatTreeNode::with_children(
         "ReclusterSink".to_string(),
         children,
@@ -1180,8 +1279,9 @@ fn commit_sink_to_format_tree(
     plan: &CommitSink,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
-    let children = vec![to_format_tree(&plan.input, metadata, profs)?];
+    let children = vec![to_format_tree(&plan.input, metadata, profs, verbose)?];
     Ok(FormatTreeNode::with_children(
         "CommitSink".to_string(),
         children,
@@ -1192,6 +1292,7 @@ fn project_set_to_format_tree(
     plan: &ProjectSet,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![FormatTreeNode::new(format!(
         "output columns: [{}]",
@@ -1214,7 +1315,7 @@ fn project_set_to_format_tree(
             .join(", ")
     ))]);
 
-    children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
+    children.extend(vec![to_format_tree(&plan.input, metadata, profs, verbose)?]);
 
     Ok(FormatTreeNode::with_children(
         "ProjectSet".to_string(),
@@ -1226,6 +1327,7 @@ fn udf_to_format_tree(
     plan: &Udf,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let mut children = vec![FormatTreeNode::new(format!(
         "output columns: [{}]",
@@ -1251,7 +1353,7 @@ fn udf_to_format_tree(
             .join(", ")
     ))]);
 
-    children.extend(vec![to_format_tree(&plan.input, metadata, profs)?]);
+    children.extend(vec![to_format_tree(&plan.input, metadata, profs, verbose)?]);
 
     Ok(FormatTreeNode::with_children("Udf".to_string(), children))
 }
@@ -1260,14 +1362,15 @@ fn materialized_cte_to_format_tree(
     plan: &MaterializedCte,
     metadata: &Metadata,
     profs: &HashMap<u32, PlanProfile>,
+    verbose: bool,
 ) -> Result<FormatTreeNode<String>> {
     let children = vec![
         FormatTreeNode::new(format!(
             "output columns: [{}]",
             format_output_columns(plan.output_schema()?, metadata, true)
         )),
-        to_format_tree(&plan.left, metadata, profs)?,
-        to_format_tree(&plan.right, metadata, profs)?,
+        to_format_tree(&plan.left, metadata, profs, verbose)?,
+        to_format_tree(&plan.right, metadata, profs, verbose)?,
     ];
     Ok(FormatTreeNode::with_children(
         "MaterializedCTE".to_string(),
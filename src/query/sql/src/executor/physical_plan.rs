@@ -857,6 +857,52 @@ impl PhysicalPlan {
                 labels.insert(String::from("Total partitions"), vec![
                     v.source.statistics.partitions_total.to_string(),
                 ]);
+                if v.source.statistics.read_compressed_bytes > 0 {
+                    labels.insert(String::from("Bytes to read (compressed)"), vec![
+                        v.source.statistics.read_compressed_bytes.to_string(),
+                    ]);
+                }
+                if v.source.statistics.read_uncompressed_bytes > 0 {
+                    labels.insert(String::from("Bytes after decode"), vec![
+                        v.source.statistics.read_uncompressed_bytes.to_string(),
+                    ]);
+                }
+
+                let pruning_stats = &v.source.statistics.pruning_stats;
+                let pruning_labels = [
+                    (
+                        "segments range pruning",
+                        pruning_stats.segments_range_pruning_before,
+                        pruning_stats.segments_range_pruning_after,
+                    ),
+                    (
+                        "blocks range pruning",
+                        pruning_stats.blocks_range_pruning_before,
+                        pruning_stats.blocks_range_pruning_after,
+                    ),
+                    (
+                        "blocks bloom pruning",
+                        pruning_stats.blocks_bloom_pruning_before,
+                        pruning_stats.blocks_bloom_pruning_after,
+                    ),
+                    (
+                        "blocks inverted index pruning",
+                        pruning_stats.blocks_inverted_index_pruning_before,
+                        pruning_stats.blocks_inverted_index_pruning_after,
+                    ),
+                    (
+                        "blocks limit pruning",
+                        pruning_stats.blocks_limit_pruning_before,
+                        pruning_stats.blocks_limit_pruning_after,
+                    ),
+                ]
+                .into_iter()
+                .filter(|(_, before, _)| *before > 0)
+                .map(|(name, before, after)| format!("{name}: {before} to {after}"))
+                .collect::<Vec<_>>();
+                if !pruning_labels.is_empty() {
+                    labels.insert(String::from("Pruning statistics"), pruning_labels);
+                }
             }
             PhysicalPlan::Filter(v) => {
                 labels.insert(
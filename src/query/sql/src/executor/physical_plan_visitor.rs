@@ -201,6 +201,8 @@ pub trait PhysicalPlanReplacer {
             enable_experimental_aggregate_hashtable: plan.enable_experimental_aggregate_hashtable,
             group_by: plan.group_by.clone(),
             group_by_display: plan.group_by_display.clone(),
+            group_key_range: plan.group_key_range.clone(),
+            streaming: plan.streaming,
             agg_funcs: plan.agg_funcs.clone(),
             stat_info: plan.stat_info.clone(),
         }))
@@ -421,6 +423,7 @@ pub trait PhysicalPlanReplacer {
                 insert_schema: plan.insert_schema.clone(),
                 select_column_bindings: plan.select_column_bindings.clone(),
                 cast_needed: plan.cast_needed,
+                returning: plan.returning.clone(),
             },
         )))
     }
@@ -524,7 +527,7 @@ pub trait PhysicalPlanReplacer {
             input: Box::new(input),
             udf_funcs: plan.udf_funcs.clone(),
             stat_info: plan.stat_info.clone(),
-            script_udf: plan.script_udf,
+            kind: plan.kind,
         }))
     }
 
@@ -22,6 +22,7 @@ mod physical_aggregate_final;
 pub use physical_aggregate_final::AggregateFinal;
 mod physical_aggregate_partial;
 pub use physical_aggregate_partial::AggregatePartial;
+pub use physical_aggregate_partial::GroupKeyRange;
 mod physical_commit_sink;
 pub use physical_commit_sink::CommitSink;
 mod physical_compact_source;
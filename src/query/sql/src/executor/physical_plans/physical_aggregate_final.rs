@@ -16,11 +16,17 @@ use std::sync::Arc;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::Number;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::with_integer_mapped_type;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
+use databend_common_storage::Datum;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::AggregateExpand;
@@ -28,8 +34,10 @@ use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregateFunctionSignature;
 use crate::executor::physical_plans::AggregatePartial;
 use crate::executor::physical_plans::Exchange;
+use crate::executor::physical_plans::GroupKeyRange;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::plans::AggregateMode;
 use crate::plans::DummyTableScan;
@@ -159,6 +167,8 @@ impl PhysicalPlanBuilder {
                 let group_by_shuffle_mode = settings.get_group_by_shuffle_mode()?;
                 let enable_experimental_aggregate_hashtable =
                     settings.get_enable_experimental_aggregate_hashtable()?;
+                let group_key_range = self.group_key_range(s_expr, &agg, &input_schema)?;
+                let streaming = self.is_streaming_aggregate(s_expr, &agg)?;
 
                 if let Some(grouping_sets) = agg.grouping_sets.as_ref() {
                     assert_eq!(grouping_sets.dup_group_items.len(), group_items.len() - 1); // ignore `_grouping_id`.
@@ -196,6 +206,8 @@ impl PhysicalPlanBuilder {
                                 enable_experimental_aggregate_hashtable,
                                 group_by_display,
                                 group_by: group_items,
+                                group_key_range: None,
+                                streaming: false,
                                 stat_info: Some(stat_info),
                             }
                         } else {
@@ -206,6 +218,8 @@ impl PhysicalPlanBuilder {
                                 enable_experimental_aggregate_hashtable,
                                 group_by_display,
                                 group_by: group_items,
+                                group_key_range,
+                                streaming,
                                 stat_info: Some(stat_info),
                             }
                         };
@@ -273,6 +287,8 @@ impl PhysicalPlanBuilder {
                                 enable_experimental_aggregate_hashtable,
                                 group_by_display,
                                 group_by: group_items,
+                                group_key_range: None,
+                                streaming: false,
                                 input: Box::new(PhysicalPlan::AggregateExpand(expand)),
                                 stat_info: Some(stat_info),
                             })
@@ -283,6 +299,8 @@ impl PhysicalPlanBuilder {
                                 enable_experimental_aggregate_hashtable,
                                 group_by_display,
                                 group_by: group_items,
+                                group_key_range,
+                                streaming,
                                 input: Box::new(input),
                                 stat_info: Some(stat_info),
                             })
@@ -407,4 +425,86 @@ impl PhysicalPlanBuilder {
 
         Ok(result)
     }
+
+    // The aggregation streams if the input is sorted on the group keys, whatever the
+    // order of the keys and the directions are.
+    fn is_streaming_aggregate(
+        &self,
+        s_expr: &SExpr,
+        agg: &crate::plans::Aggregate,
+    ) -> Result<bool> {
+        if !self.ctx.get_settings().get_enable_streaming_aggregation()?
+            || !self.ctx.get_cluster().is_empty()
+            || agg.group_items.is_empty()
+            || agg.grouping_sets.is_some()
+        {
+            return Ok(false);
+        }
+
+        let prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        if prop.orderings.len() < agg.group_items.len() {
+            return Ok(false);
+        }
+
+        let group_items = agg
+            .group_items
+            .iter()
+            .map(|item| item.index)
+            .collect::<ColumnSet>();
+        let sorted_items = prop.orderings[..agg.group_items.len()]
+            .iter()
+            .map(|item| item.index)
+            .collect::<ColumnSet>();
+        Ok(group_items == sorted_items)
+    }
+
+    // Estimate the range of the single integer group key from the statistics of the input,
+    // None if it's unknown or wider than `max_direct_group_by_range`.
+    fn group_key_range(
+        &self,
+        s_expr: &SExpr,
+        agg: &crate::plans::Aggregate,
+        input_schema: &DataSchemaRef,
+    ) -> Result<Option<GroupKeyRange>> {
+        let max_range = self.ctx.get_settings().get_max_direct_group_by_range()?;
+        if max_range == 0 || agg.group_items.len() != 1 || agg.grouping_sets.is_some() {
+            return Ok(None);
+        }
+
+        let index = agg.group_items[0].index;
+        let number_type = match input_schema
+            .field_with_name(&index.to_string())?
+            .data_type()
+        {
+            DataType::Number(number_type) if number_type.is_integer() => *number_type,
+            _ => return Ok(None),
+        };
+
+        let stat_info = RelExpr::with_s_expr(s_expr.child(0)?).derive_cardinality()?;
+        let Some(column_stat) = stat_info.statistics.column_stats.get(&index) else {
+            return Ok(None);
+        };
+        let (min, max) = match (&column_stat.min, &column_stat.max) {
+            (Datum::Int(min), Datum::Int(max)) => (*min as i128, *max as i128),
+            (Datum::UInt(min), Datum::UInt(max)) => (*min as i128, *max as i128),
+            _ => return Ok(None),
+        };
+        let len = max - min + 1;
+        if len <= 0 || len > max_range as i128 {
+            return Ok(None);
+        }
+
+        let min = with_integer_mapped_type!(|NUM_TYPE| match number_type {
+            NumberDataType::NUM_TYPE => match NUM_TYPE::try_from(min) {
+                Ok(min) => Scalar::Number(NUM_TYPE::upcast_scalar(min)),
+                Err(_) => return Ok(None),
+            },
+            _ => return Ok(None),
+        });
+
+        Ok(Some(GroupKeyRange {
+            min,
+            len: len as usize,
+        }))
+    }
 }
@@ -19,6 +19,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::Scalar;
 
 use crate::executor::explain::PlanStatsInfo;
 use crate::executor::physical_plans::common::AggregateFunctionDesc;
@@ -34,11 +35,23 @@ pub struct AggregatePartial {
     pub agg_funcs: Vec<AggregateFunctionDesc>,
     pub enable_experimental_aggregate_hashtable: bool,
     pub group_by_display: Vec<String>,
+    pub group_key_range: Option<GroupKeyRange>,
+    // The input is sorted on the group keys, aggregate it with the final aggregation
+    // by streaming instead of hash tables.
+    pub streaming: bool,
 
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
 
+/// The range of the single integer group key estimated from statistics, the states of
+/// the keys in it are addressed by their distance from `min` instead of the hash table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
+pub struct GroupKeyRange {
+    pub min: Scalar,
+    pub len: usize,
+}
+
 impl AggregatePartial {
     pub fn output_schema(&self) -> Result<DataSchemaRef> {
         let input_schema = self.input.output_schema()?;
@@ -40,6 +40,14 @@ pub struct CopyIntoTable {
     pub table_info: TableInfo,
 
     pub source: CopyIntoTableSource,
+    /// Set by the coordinator of distributed COPY, the stage files are pulled from it in batches.
+    pub pull_files: Option<CopyPullFiles>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct CopyPullFiles {
+    pub coordinator: String,
+    pub batch_size: usize,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize, EnumAsInner)]
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::plan::Partitions;
+use databend_common_expression::RemoteExpr;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::TableInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
@@ -30,4 +31,6 @@ pub struct DeleteSource {
     pub col_indices: Vec<usize>,
     pub query_row_id_col: bool,
     pub snapshot: Arc<TableSnapshot>,
+    /// Expressions of the `RETURNING` clause, evaluated on the deleted rows.
+    pub returning: Vec<RemoteExpr<String>>,
 }
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
 use databend_common_meta_app::schema::CatalogInfo;
 use databend_common_meta_app::schema::TableInfo;
 
@@ -31,4 +32,6 @@ pub struct DistributedInsertSelect {
     pub select_schema: DataSchemaRef,
     pub select_column_bindings: Vec<ColumnBinding>,
     pub cast_needed: bool,
+    /// Expressions of the `RETURNING` clause, evaluated on the inserted rows.
+    pub returning: Vec<RemoteExpr<String>>,
 }
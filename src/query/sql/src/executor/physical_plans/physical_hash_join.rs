@@ -399,10 +399,15 @@ impl PhysicalPlanBuilder {
                 probe_fields.extend(build_fields);
                 probe_fields
             }
-            JoinType::LeftSemi | JoinType::LeftAnti | JoinType::RightSemi | JoinType::RightAnti => {
-                let (result_fields, dropped_fields) = if join.join_type == JoinType::LeftSemi
-                    || join.join_type == JoinType::LeftAnti
-                {
+            JoinType::LeftSemi
+            | JoinType::LeftAnti
+            | JoinType::NullAwareLeftAnti
+            | JoinType::RightSemi
+            | JoinType::RightAnti => {
+                let (result_fields, dropped_fields) = if matches!(
+                    join.join_type,
+                    JoinType::LeftSemi | JoinType::LeftAnti | JoinType::NullAwareLeftAnti
+                ) {
                     (probe_fields, build_fields)
                 } else {
                     (build_fields, probe_fields)
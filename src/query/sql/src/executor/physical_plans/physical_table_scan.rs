@@ -488,6 +488,7 @@ impl PhysicalPlanBuilder {
             agg_index: None,
             change_type: scan.change_type.clone(),
             inverted_index: scan.inverted_index.clone(),
+            vector_index: scan.vector_index.clone(),
         })
     }
 
@@ -26,6 +26,7 @@ use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
+use crate::plans::UDFKind;
 use crate::plans::UDFType;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -37,7 +38,7 @@ pub struct Udf {
     pub plan_id: u32,
     pub input: Box<PhysicalPlan>,
     pub udf_funcs: Vec<UdfFunctionDesc>,
-    pub script_udf: bool,
+    pub kind: UDFKind,
     // Only used for explain
     pub stat_info: Option<PlanStatsInfo>,
 }
@@ -91,7 +92,7 @@ impl PhysicalPlanBuilder {
         }
         let udf = crate::plans::Udf {
             items: used,
-            script_udf: udf.script_udf,
+            kind: udf.kind,
         };
         let input = self.build(s_expr.child(0)?, required).await?;
         let input_schema = input.output_schema()?;
@@ -158,7 +159,7 @@ impl PhysicalPlanBuilder {
             plan_id: 0,
             input: Box::new(input),
             udf_funcs,
-            script_udf: udf.script_udf,
+            kind: udf.kind,
             stat_info: Some(stat_info),
         }))
     }
@@ -33,4 +33,6 @@ pub struct UpdateSource {
     pub filters: Option<Filters>,
     pub update_list: Vec<(FieldIndex, RemoteExpr<String>)>,
     pub computed_list: BTreeMap<FieldIndex, RemoteExpr<String>>,
+    /// Expressions of the `RETURNING` clause, evaluated on the updated rows.
+    pub returning: Vec<RemoteExpr<String>>,
 }
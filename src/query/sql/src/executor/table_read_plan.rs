@@ -140,7 +140,7 @@ impl ToReadDataSourcePlan for dyn Table {
 
         let source_info = self.get_data_source_info();
         let description = statistics.get_description(&source_info.desc());
-        let mut output_schema = match (self.support_column_projection(), &push_downs) {
+        let mut output_schema = match (self.push_down_capabilities().projection, &push_downs) {
             (true, Some(push_downs)) => {
                 let schema = &self.schema_with_stream();
                 match &push_downs.prewhere {
@@ -430,6 +430,9 @@ impl Binder {
                 self.resolve_grouping_sets(bind_context, select_list, &sets, &available_aliases)
                     .await
             }
+            GroupBy::Fill(_) => Err(ErrorCode::Internal(
+                "GROUP BY WITH FILL should be rewritten before binding",
+            )),
         }
     }
 
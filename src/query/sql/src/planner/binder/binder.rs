@@ -63,6 +63,7 @@ use crate::plans::ShowConnectionsPlan;
 use crate::plans::ShowFileFormatsPlan;
 use crate::plans::ShowGrantsPlan;
 use crate::plans::ShowRolesPlan;
+use crate::plans::ShowWarningsPlan;
 use crate::plans::UseDatabasePlan;
 use crate::plans::Visitor;
 use crate::BindContext;
@@ -141,10 +142,25 @@ impl<'a> Binder {
         Ok(plan)
     }
 
+    /// Applies the `SET_VAR` hints to the query settings, the hints that can't be applied are
+    /// reported in the warnings of the query.
     pub(crate) async fn opt_hints_set_var(
         &mut self,
         bind_context: &mut BindContext,
         hints: &Hint,
+    ) -> Result<()> {
+        let res = self.apply_hints_set_var(bind_context, hints).await;
+        if let Err(e) = &res {
+            self.ctx
+                .push_warning(format!("Ignored hints {}: {}", hints, e.message()));
+        }
+        res
+    }
+
+    async fn apply_hints_set_var(
+        &mut self,
+        bind_context: &mut BindContext,
+        hints: &Hint,
     ) -> Result<()> {
         let mut type_checker = TypeChecker::try_create(
             bind_context,
@@ -177,11 +193,15 @@ impl<'a> Binder {
                 }
                 _ => {
                     warn!("fold hints {:?} failed. value must be constant value", hint);
+                    self.ctx.push_warning(format!(
+                        "Ignored hint SET_VAR({}={}): value must be constant value",
+                        hint.name, hint.expr
+                    ));
                 }
             }
         }
 
-        self.ctx.get_settings().set_batch_settings(&hint_settings)
+        self.ctx.get_settings().set_query_settings(&hint_settings)
     }
 
     #[async_recursion::async_recursion]
@@ -231,6 +251,15 @@ impl<'a> Binder {
                 Plan::ExplainAnalyze { plan: Box::new(plan) }
             }
 
+            Statement::DescribeQuery { query } => {
+                let stmt = Statement::Query(query.clone());
+                let plan = self.bind_statement(bind_context, &stmt).await?;
+                Plan::DescribeQuery { plan: Box::new(plan) }
+            }
+
+            // The priority is applied by the planner.
+            Statement::WithPriority { stmt, .. } => self.bind_statement(bind_context, stmt).await?,
+
             Statement::ShowFunctions { show_options } => {
                 self.bind_show_functions(bind_context, show_options).await?
             }
@@ -264,7 +293,9 @@ impl<'a> Binder {
             Statement::ShowMetrics { show_options } => self.bind_show_metrics(bind_context, show_options).await?,
             Statement::ShowProcessList { show_options } => self.bind_show_process_list(bind_context, show_options).await?,
             Statement::ShowEngines { show_options } => self.bind_show_engines(bind_context, show_options).await?,
+            Statement::ShowQuotas { show_options } => self.bind_show_quotas(bind_context, show_options).await?,
             Statement::ShowSettings { show_options } => self.bind_show_settings(bind_context, show_options).await?,
+            Statement::ShowWarnings => Plan::ShowWarnings(Box::new(ShowWarningsPlan {})),
             Statement::ShowIndexes { show_options } => self.bind_show_indexes(bind_context, show_options).await?,
             Statement::ShowLocks(stmt) => self.bind_show_locks(bind_context, stmt).await?,
             // Catalogs
@@ -280,6 +311,8 @@ impl<'a> Binder {
             Statement::DropDatabase(stmt) => self.bind_drop_database(stmt).await?,
             Statement::UndropDatabase(stmt) => self.bind_undrop_database(stmt).await?,
             Statement::AlterDatabase(stmt) => self.bind_alter_database(stmt).await?,
+            Statement::BackupDatabase(stmt) => self.bind_backup_database(stmt).await?,
+            Statement::RestoreDatabase(stmt) => self.bind_restore_database(stmt).await?,
             Statement::UseDatabase { database } => {
                 let database = normalize_identifier(database, &self.name_resolution_ctx).name;
                 Plan::UseDatabase(Box::new(UseDatabasePlan {
@@ -367,7 +400,7 @@ impl<'a> Binder {
             })),
 
             // Stages
-            Statement::ShowStages => self.bind_rewrite_to_query(bind_context, "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages ORDER BY name", RewriteKind::ShowStages).await?,
+            Statement::ShowStages { show_options } => self.bind_show_stages(bind_context, show_options).await?,
             Statement::ListStage { location, pattern } => {
                 let pattern = if let Some(pattern) = pattern {
                     format!(", pattern => '{pattern}'")
@@ -388,6 +421,9 @@ impl<'a> Binder {
             Statement::RemoveStage { location, pattern } => {
                 self.bind_remove_stage(location, pattern).await?
             }
+            Statement::RefreshStage { stage_name } => {
+                self.bind_refresh_stage(stage_name).await?
+            }
             Statement::Insert(stmt) => {
                 if let Some(hints) = &stmt.hints {
                     if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
@@ -483,6 +519,13 @@ impl<'a> Binder {
                 if_exists: *if_exists,
                 udf: udf_name.to_string(),
             })),
+
+            // Models
+            Statement::CreateModel(stmt) => self.bind_create_model(stmt).await?,
+            Statement::DropModel {
+                if_exists,
+                model_name,
+            } => self.bind_drop_model(*if_exists, model_name).await?,
             Statement::Call(stmt) => self.bind_call(bind_context, stmt).await?,
 
             Statement::Presign(stmt) => self.bind_presign(bind_context, stmt).await?,
@@ -615,18 +658,10 @@ impl<'a> Binder {
             Statement::ShowStreams(stmt) => self.bind_show_streams(bind_context, stmt).await?,
             Statement::DescribeStream(stmt) => self.bind_describe_stream(bind_context, stmt).await?,
 
-            Statement::CreatePipe(_) => {
-                todo!()
-            }
-            Statement::DescribePipe(_) => {
-                todo!()
-            }
-            Statement::AlterPipe(_) => {
-                todo!()
-            }
-            Statement::DropPipe(_) => {
-                todo!()
-            }
+            Statement::CreatePipe(stmt) => self.bind_create_pipe(stmt).await?,
+            Statement::DescribePipe(stmt) => self.bind_desc_pipe(stmt).await?,
+            Statement::AlterPipe(stmt) => self.bind_alter_pipe(stmt).await?,
+            Statement::DropPipe(stmt) => self.bind_drop_pipe(stmt).await?,
             Statement::CreateNotification(stmt) => {
                 self.bind_create_notification(stmt).await?
             }
@@ -642,7 +677,10 @@ impl<'a> Binder {
             Statement::Begin => Plan::Begin,
             Statement::Commit => Plan::Commit,
             Statement::Abort => Plan::Abort,
-            Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?
+            Statement::ExecuteImmediate(stmt) => self.bind_execute_immediate(stmt).await?,
+            Statement::CreateProcedure(stmt) => self.bind_create_procedure(stmt).await?,
+            Statement::DropProcedure(stmt) => self.bind_drop_procedure(stmt).await?,
+            Statement::CallProcedure(stmt) => self.bind_call_procedure(stmt).await?,
         };
         Ok(plan)
     }
@@ -16,11 +16,15 @@
 
 use core::fmt::Write;
 
+use databend_common_ast::ast::ShowLimit;
+use databend_common_ast::ast::ShowOptions;
+
 pub struct SelectBuilder {
     from: String,
     columns: Vec<String>,
     filters: Vec<String>,
     order_bys: Vec<String>,
+    limit: Option<u64>,
 }
 
 impl SelectBuilder {
@@ -30,6 +34,7 @@ impl SelectBuilder {
             columns: vec![],
             filters: vec![],
             order_bys: vec![],
+            limit: None,
         }
     }
     pub fn with_column(&mut self, col_name: impl Into<String>) -> &mut Self {
@@ -47,6 +52,35 @@ impl SelectBuilder {
         self
     }
 
+    pub fn with_limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Apply the `LIKE | WHERE` filter and the `LIMIT` of a `SHOW` statement,
+    /// the `LIKE` pattern is matched against `name_column`.
+    pub fn with_show_options(
+        &mut self,
+        show_options: &Option<ShowOptions>,
+        name_column: &str,
+    ) -> &mut Self {
+        if let Some(show_options) = show_options {
+            match &show_options.show_limit {
+                Some(ShowLimit::Like { pattern }) => {
+                    self.with_filter(format!("{name_column} LIKE '{pattern}'"));
+                }
+                Some(ShowLimit::Where { selection }) => {
+                    self.with_filter(format!("({selection})"));
+                }
+                None => (),
+            }
+            if let Some(limit) = show_options.limit {
+                self.with_limit(limit);
+            }
+        }
+        self
+    }
+
     pub fn build(self) -> String {
         let mut query = String::new();
         let mut columns = String::new();
@@ -73,8 +107,17 @@ impl SelectBuilder {
             write!(filters, "").unwrap();
         }
 
+        let mut limit = String::new();
+        if let Some(n) = self.limit {
+            write!(limit, "LIMIT {n}").unwrap();
+        }
+
         let from = self.from;
-        write!(query, "SELECT {columns} FROM {from} {filters} {order_bys} ").unwrap();
+        write!(
+            query,
+            "SELECT {columns} FROM {from} {filters} {order_bys} {limit}"
+        )
+        .unwrap();
         query
     }
 }
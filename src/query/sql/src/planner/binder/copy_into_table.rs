@@ -65,9 +65,11 @@ use parking_lot::RwLock;
 use crate::binder::location::parse_uri_location;
 use crate::binder::select::MaxColumnPosition;
 use crate::binder::Binder;
+use crate::is_dependent_default_expr;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::Plan;
+use crate::plans::UDFKind;
 use crate::plans::ValidationMode;
 use crate::BindContext;
 use crate::Metadata;
@@ -399,11 +401,15 @@ impl<'a> Binder {
         output_context.columns = from_context.columns;
 
         // rewrite udf for interpreter udf
-        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), true);
+        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), UDFKind::Script);
         s_expr = udf_rewriter.rewrite(&s_expr)?;
 
         // rewrite udf for server udf
-        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), false);
+        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), UDFKind::Server);
+        s_expr = udf_rewriter.rewrite(&s_expr)?;
+
+        // rewrite udf for model inference
+        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), UDFKind::Model);
         s_expr = udf_rewriter.rewrite(&s_expr)?;
 
         // disable variant check to allow copy invalid JSON into tables
@@ -525,6 +531,14 @@ impl<'a> Binder {
 
         let mut values = vec![];
         for field in &data_schema.fields {
+            if let Some(default_expr) = field.default_expr()
+                && is_dependent_default_expr(default_expr)?
+            {
+                // the default referring to other columns can't be evaluated before the row is
+                // decoded, the empty fields of such columns are filled with the type default.
+                values.push(Scalar::default_value(field.data_type()));
+                continue;
+            }
             let expr = scalar_binder.get_default_value(field, data_schema).await?;
             values.push(evaluator.run(&expr)?.as_scalar().unwrap().clone());
         }
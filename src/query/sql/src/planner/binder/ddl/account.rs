@@ -170,6 +170,7 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(GrantObject::UDF(udf.clone())),
             AccountMgrLevel::Stage(stage) => Ok(GrantObject::Stage(stage.clone())),
+            AccountMgrLevel::Procedure(procedure) => Ok(GrantObject::Procedure(procedure.clone())),
         }
     }
 
@@ -221,6 +222,9 @@ impl Binder {
             }
             AccountMgrLevel::UDF(udf) => Ok(vec![GrantObject::UDF(udf.clone())]),
             AccountMgrLevel::Stage(stage) => Ok(vec![GrantObject::Stage(stage.clone())]),
+            AccountMgrLevel::Procedure(procedure) => {
+                Ok(vec![GrantObject::Procedure(procedure.clone())])
+            }
         }
     }
 
@@ -32,6 +32,7 @@ use databend_common_expression::DataSchemaRefExt;
 use databend_common_meta_app::schema::CatalogMeta;
 use databend_common_meta_app::schema::CatalogOption;
 use databend_common_meta_app::schema::CatalogType;
+use databend_common_meta_app::schema::CustomCatalogOption;
 use databend_common_meta_app::schema::HiveCatalogOption;
 use databend_common_meta_app::schema::IcebergCatalogOption;
 use databend_common_meta_app::storage::StorageParams;
@@ -104,7 +105,7 @@ impl Binder {
         let tenant = self.ctx.get_tenant();
 
         let meta = self
-            .try_create_meta_from_options(&self.ctx, *catalog_type, options)
+            .try_create_meta_from_options(&self.ctx, catalog_type, options)
             .await?;
 
         Ok(Plan::CreateCatalog(Box::new(CreateCatalogPlan {
@@ -133,7 +134,7 @@ impl Binder {
     async fn try_create_meta_from_options(
         &self,
         ctx: &Arc<dyn TableContext>,
-        catalog_type: CatalogType,
+        catalog_type: &CatalogType,
         options: &BTreeMap<String, String>,
     ) -> Result<CatalogMeta> {
         // get catalog options from options
@@ -171,6 +172,12 @@ impl Binder {
                 };
                 CatalogOption::Iceberg(opt)
             }
+            // The options of a plugin catalog are interpreted by the plugin itself,
+            // they are checked while building the catalog.
+            CatalogType::Custom(typ) => CatalogOption::Custom(CustomCatalogOption {
+                catalog_type: typ.clone(),
+                options: options.clone(),
+            }),
         };
 
         Ok(CatalogMeta {
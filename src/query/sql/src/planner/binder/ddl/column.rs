@@ -13,7 +13,6 @@
 // limitations under the License.
 
 use databend_common_ast::ast::ShowColumnsStmt;
-use databend_common_ast::ast::ShowLimit;
 use databend_common_exception::Result;
 use log::debug;
 
@@ -36,7 +35,7 @@ impl Binder {
             database,
             table,
             full,
-            limit,
+            show_options,
         } = stmt;
 
         let catalog_name = match catalog {
@@ -93,17 +92,8 @@ impl Binder {
             .with_filter(format!("table_schema = '{database}'"))
             .with_filter(format!("table_name = '{table}'"));
 
-        let query = match limit {
-            None => select_builder.build(),
-            Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("column_name LIKE '{pattern}'"));
-                select_builder.build()
-            }
-            Some(ShowLimit::Where { selection }) => {
-                select_builder.with_filter(format!("({selection})"));
-                select_builder.build()
-            }
-        };
+        select_builder.with_show_options(show_options, "column_name");
+        let query = select_builder.build();
         debug!("show columns rewrite to: {:?}", query);
         self.bind_rewrite_to_query(
             bind_context,
@@ -16,14 +16,15 @@ use std::collections::BTreeMap;
 
 use databend_common_ast::ast::AlterDatabaseAction;
 use databend_common_ast::ast::AlterDatabaseStmt;
+use databend_common_ast::ast::BackupDatabaseStmt;
 use databend_common_ast::ast::CreateDatabaseStmt;
 use databend_common_ast::ast::DatabaseEngine;
 use databend_common_ast::ast::DatabaseRef;
 use databend_common_ast::ast::DropDatabaseStmt;
+use databend_common_ast::ast::RestoreDatabaseStmt;
 use databend_common_ast::ast::SQLProperty;
 use databend_common_ast::ast::ShowCreateDatabaseStmt;
 use databend_common_ast::ast::ShowDatabasesStmt;
-use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::UndropDatabaseStmt;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -34,13 +35,16 @@ use databend_common_meta_app::share::share_name_ident::ShareNameIdent;
 use databend_common_meta_app::share::share_name_ident::ShareNameIdentRaw;
 use log::debug;
 
+use crate::binder::resolve_stage_location;
 use crate::binder::Binder;
 use crate::planner::semantic::normalize_identifier;
+use crate::plans::BackupDatabasePlan;
 use crate::plans::CreateDatabasePlan;
 use crate::plans::DropDatabasePlan;
 use crate::plans::Plan;
 use crate::plans::RenameDatabaseEntity;
 use crate::plans::RenameDatabasePlan;
+use crate::plans::RestoreDatabasePlan;
 use crate::plans::RewriteKind;
 use crate::plans::ShowCreateDatabasePlan;
 use crate::plans::UndropDatabasePlan;
@@ -57,7 +61,7 @@ impl Binder {
         let ShowDatabasesStmt {
             catalog,
             full,
-            limit,
+            show_options,
         } = stmt;
 
         let mut select_builder = SelectBuilder::from("system.databases");
@@ -78,15 +82,7 @@ impl Binder {
         select_builder.with_order_by("catalog");
         select_builder.with_order_by("name");
 
-        match limit {
-            Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("name LIKE '{pattern}'"));
-            }
-            Some(ShowLimit::Where { selection }) => {
-                select_builder.with_filter(format!("({selection})"));
-            }
-            None => (),
-        }
+        select_builder.with_show_options(show_options, "name");
 
         let query = select_builder.build();
         debug!("show databases rewrite to: {:?}", query);
@@ -182,6 +178,66 @@ impl Binder {
         })))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_backup_database(
+        &self,
+        stmt: &BackupDatabaseStmt,
+    ) -> Result<Plan> {
+        let BackupDatabaseStmt {
+            catalog,
+            database,
+            location,
+            incremental,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+
+        Ok(Plan::BackupDatabase(Box::new(BackupDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            stage: Box::new(stage),
+            path,
+            incremental: *incremental,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_restore_database(
+        &self,
+        stmt: &RestoreDatabaseStmt,
+    ) -> Result<Plan> {
+        let RestoreDatabaseStmt {
+            catalog,
+            database,
+            location,
+            backup_id,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = catalog
+            .as_ref()
+            .map(|catalog| normalize_identifier(catalog, &self.name_resolution_ctx).name)
+            .unwrap_or_else(|| self.ctx.get_current_catalog());
+        let database = normalize_identifier(database, &self.name_resolution_ctx).name;
+        let (stage, path) = resolve_stage_location(self.ctx.as_ref(), location).await?;
+
+        Ok(Plan::RestoreDatabase(Box::new(RestoreDatabasePlan {
+            tenant,
+            catalog,
+            database,
+            stage: Box::new(stage),
+            path,
+            backup_id: backup_id.clone(),
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_undrop_database(
         &self,
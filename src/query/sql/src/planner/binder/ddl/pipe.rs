@@ -0,0 +1,151 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateOption;
+
+use crate::plans::AlterPipeAction;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescPipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        if let CopyIntoTableSource::Location(FileLocation::Uri(_)) = &copy_stmt.src {
+            return Err(ErrorCode::SemanticError(
+                "pipe can only load files from a stage".to_string(),
+            ));
+        }
+        // The loaded files are skipped by the pipe, it's how a file is loaded exactly once.
+        if copy_stmt.force {
+            return Err(ErrorCode::SemanticError(
+                "pipe does not support the FORCE copy option".to_string(),
+            ));
+        }
+
+        let create_option = if *if_not_exists {
+            CreateOption::CreateIfNotExists
+        } else {
+            CreateOption::Create
+        };
+
+        Ok(Plan::CreatePipe(Box::new(CreatePipePlan {
+            create_option,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            copy_stmt: copy_stmt.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.clone(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        let action = match options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => AlterPipeAction::Set {
+                execution_paused: *execution_paused,
+                comment: comments.clone(),
+            },
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => {
+                let modified_after = match modified_after {
+                    Some(v) => Some(
+                        DateTime::parse_from_rfc3339(v)
+                            .map_err(|e| {
+                                ErrorCode::SemanticError(format!(
+                                    "invalid MODIFIED_AFTER {}: {}",
+                                    v, e
+                                ))
+                            })?
+                            .with_timezone(&Utc),
+                    ),
+                    None => None,
+                };
+                AlterPipeAction::Refresh {
+                    prefix: prefix.clone(),
+                    modified_after,
+                }
+            }
+        };
+
+        Ok(Plan::AlterPipe(Box::new(AlterPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: name.to_string(),
+            action,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        Ok(Plan::DropPipe(Box::new(DropPipePlan {
+            if_exists: stmt.if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: stmt.name.to_string(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_desc_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        Ok(Plan::DescPipe(Box::new(DescPipePlan {
+            tenant: self.ctx.get_tenant(),
+            name: stmt.name.to_string(),
+        })))
+    }
+}
@@ -12,9 +12,25 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::CallProcedureStmt;
+use databend_common_ast::ast::CreateProcedureStmt;
+use databend_common_ast::ast::DropProcedureStmt;
 use databend_common_ast::ast::ExecuteImmediateStmt;
+use databend_common_ast::ast::ProcedureExecuteAs;
+use databend_common_ast::parser::run_parser;
+use databend_common_ast::parser::script::script_block;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::ParseMode;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal;
+use databend_common_users::UserApiProvider;
 
+use crate::normalize_identifier;
+use crate::planner::resolve_type_name;
+use crate::plans::CallProcedurePlan;
+use crate::plans::CreateProcedurePlan;
+use crate::plans::DropProcedurePlan;
 use crate::plans::ExecuteImmediatePlan;
 use crate::plans::Plan;
 use crate::Binder;
@@ -30,4 +46,104 @@ impl Binder {
             script: script.clone(),
         })))
     }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_procedure(
+        &mut self,
+        stmt: &CreateProcedureStmt,
+    ) -> Result<Plan> {
+        let CreateProcedureStmt {
+            create_option,
+            name,
+            args,
+            return_type,
+            execute_as,
+            comment,
+            script,
+        } = stmt;
+
+        let mut arg_names = Vec::with_capacity(args.len());
+        let mut arg_types = Vec::with_capacity(args.len());
+        for arg in args {
+            // Script variables are resolved by their name as written, so are the arguments.
+            let arg_name = arg.name.name.clone();
+            if arg_names.contains(&arg_name) {
+                return Err(ErrorCode::SemanticError(format!(
+                    "duplicate argument name `{arg_name}` in procedure `{name}`"
+                )));
+            }
+            resolve_type_name(&arg.data_type, true)?;
+            arg_names.push(arg_name);
+            arg_types.push(arg.data_type.to_string());
+        }
+        resolve_type_name(return_type, true)?;
+
+        // Reject a malformed body when the procedure is created rather than when it's called.
+        let res: Result<_> = try {
+            let tokens = tokenize_sql(script)?;
+            run_parser(
+                &tokens,
+                self.dialect,
+                ParseMode::Template,
+                false,
+                script_block,
+            )?
+        };
+        res.map_err(|err| err.display_with_sql(script))?;
+
+        let execute_as = match execute_as {
+            ProcedureExecuteAs::Owner => principal::ProcedureExecuteAs::Owner,
+            ProcedureExecuteAs::Caller => principal::ProcedureExecuteAs::Caller,
+        };
+
+        Ok(Plan::CreateProcedure(Box::new(CreateProcedurePlan {
+            create_option: *create_option,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+            arg_names,
+            arg_types,
+            return_type: return_type.to_string(),
+            script: script.clone(),
+            execute_as,
+            comment: comment.clone().unwrap_or_default(),
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_procedure(
+        &mut self,
+        stmt: &DropProcedureStmt,
+    ) -> Result<Plan> {
+        let DropProcedureStmt { if_exists, name } = stmt;
+        Ok(Plan::DropProcedure(Box::new(DropProcedurePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            name: normalize_identifier(name, &self.name_resolution_ctx).name,
+        })))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_call_procedure(
+        &mut self,
+        stmt: &CallProcedureStmt,
+    ) -> Result<Plan> {
+        let CallProcedureStmt { name, args } = stmt;
+        let name = normalize_identifier(name, &self.name_resolution_ctx).name;
+        let procedure = UserApiProvider::instance()
+            .get_procedure(&self.ctx.get_tenant(), &name)
+            .await?;
+        if procedure.arg_names.len() != args.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "procedure {} expects {} arguments, but got {}",
+                procedure.signature(),
+                procedure.arg_names.len(),
+                args.len()
+            )));
+        }
+
+        Ok(Plan::CallProcedure(Box::new(CallProcedurePlan {
+            procedure,
+            args: args.clone(),
+        })))
+    }
 }
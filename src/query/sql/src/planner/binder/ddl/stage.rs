@@ -22,6 +22,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::principal::StageType;
 use databend_common_storage::init_operator;
 
 use super::super::copy_into_table::resolve_stage_location;
@@ -29,6 +30,7 @@ use crate::binder::location::parse_storage_params_from_uri;
 use crate::binder::Binder;
 use crate::plans::CreateStagePlan;
 use crate::plans::Plan;
+use crate::plans::RefreshStagePlan;
 use crate::plans::RemoveStagePlan;
 
 impl Binder {
@@ -48,6 +50,25 @@ impl Binder {
         Ok(Plan::RemoveStage(Box::new(plan_node)))
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_stage(
+        &mut self,
+        stage_name: &str,
+    ) -> Result<Plan> {
+        let (stage, _) = resolve_stage_location(self.ctx.as_ref(), stage_name).await?;
+        if !stage.directory_enabled {
+            return Err(ErrorCode::BadArguments(format!(
+                "Directory table is not enabled on stage {}",
+                stage_name
+            )));
+        }
+
+        Ok(Plan::RefreshStage(Box::new(RefreshStagePlan {
+            tenant: self.ctx.get_tenant(),
+            stage,
+        })))
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_create_stage(
         &mut self,
@@ -62,6 +83,7 @@ impl Binder {
             size_limit,
             validation_mode: _,
             comments: _,
+            directory_enabled,
         } = stmt;
 
         let mut stage_info = match location {
@@ -114,6 +136,15 @@ impl Binder {
             stage_info.copy_options.size_limit = *size_limit;
         }
 
+        if *directory_enabled {
+            if stage_info.stage_type == StageType::User {
+                return Err(ErrorCode::BadArguments(
+                    "Directory table is not supported on user stage",
+                ));
+            }
+            stage_info.directory_enabled = true;
+        }
+
         Ok(Plan::CreateStage(Box::new(CreateStagePlan {
             create_option: *create_option,
             tenant: self.ctx.get_tenant(),
@@ -24,6 +24,7 @@ use databend_common_ast::ast::AttachTableStmt;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CompactTarget;
+use databend_common_ast::ast::CreateTableLikeOption;
 use databend_common_ast::ast::CreateTableSource;
 use databend_common_ast::ast::CreateTableStmt;
 use databend_common_ast::ast::DescribeTableStmt;
@@ -73,18 +74,23 @@ use databend_common_storages_delta::DeltaTable;
 use databend_common_storages_iceberg::IcebergTable;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::is_inheritable_opt_key;
 use databend_storages_common_table_meta::table::is_reserved_opt_key;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_ENGINE_META;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY_ENFORCED;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_LOCATION_URI;
 use derive_visitor::DriveMut;
 use log::debug;
 use log::error;
 
 use crate::binder::get_storage_params_from_options;
+use crate::binder::hide_connection_options;
 use crate::binder::parse_storage_params_from_uri;
 use crate::binder::scalar::ScalarBinder;
 use crate::binder::Binder;
@@ -142,7 +148,7 @@ impl Binder {
             catalog,
             database,
             full,
-            limit,
+            show_options,
             with_history,
         } = stmt;
 
@@ -197,17 +203,8 @@ impl Binder {
         };
 
         select_builder.with_filter(format!("catalog = '{catalog_name}'"));
-        let query = match limit {
-            None => select_builder.build(),
-            Some(ShowLimit::Like { pattern }) => {
-                select_builder.with_filter(format!("name LIKE '{pattern}'"));
-                select_builder.build()
-            }
-            Some(ShowLimit::Where { selection }) => {
-                select_builder.with_filter(format!("({selection})"));
-                select_builder.build()
-            }
-        };
+        select_builder.with_show_options(show_options, "name");
+        let query = select_builder.build();
         debug!("show tables rewrite to: {:?}", query);
         self.bind_rewrite_to_query(
             bind_context,
@@ -432,6 +429,16 @@ impl Binder {
             )?;
         }
 
+        // `CREATE TABLE ... LIKE ... INCLUDING OPTIONS | CLUSTER KEYS`, the options and
+        // the cluster key given in the statement take precedence over the inherited ones.
+        let inherited_cluster_key = match &source {
+            Some(source) => {
+                self.analyze_create_table_like_options(source, &mut options)
+                    .await?
+            }
+            None => None,
+        };
+
         let (mut storage_params, part_prefix) = match (uri_location, engine) {
             (Some(uri), Engine::Fuse) => {
                 let mut uri = UriLocation {
@@ -441,6 +448,12 @@ impl Binder {
                     part_prefix: uri.part_prefix.clone(),
                     connection: uri.connection.clone(),
                 };
+                // Keep the location to show it in `SHOW CREATE TABLE`, only the connection
+                // name is kept, the credentials are left out.
+                options.insert(
+                    OPT_KEY_TABLE_LOCATION_URI.to_string(),
+                    hide_connection_options(&uri).to_string(),
+                );
                 let sp = parse_storage_params_from_uri(
                     &mut uri,
                     Some(self.ctx.as_ref()),
@@ -473,7 +486,7 @@ impl Binder {
         }
 
         // todo(geometry): remove this when geometry stable.
-        if let Some(CreateTableSource::Columns(cols)) = &source {
+        if let Some(CreateTableSource::Columns(cols, _)) = &source {
             if cols
                 .iter()
                 .any(|col| matches!(col.data_type, TypeName::Geometry))
@@ -487,6 +500,34 @@ impl Binder {
             }
         }
 
+        // The primary key is kept in the table options, its columns are checked
+        // against the schema when the table is created.
+        if let Some(CreateTableSource::Columns(_, Some(primary_key))) = &source {
+            if engine != Engine::Fuse {
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "primary key is only supported by FUSE engine, but got {}",
+                    engine
+                )));
+            }
+            let columns = primary_key
+                .columns
+                .iter()
+                .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                .collect::<Vec<_>>();
+            self.insert_table_option_with_validation(
+                &mut options,
+                OPT_KEY_PRIMARY_KEY.to_string(),
+                columns.join(","),
+            )?;
+            if primary_key.enforced {
+                self.insert_table_option_with_validation(
+                    &mut options,
+                    OPT_KEY_PRIMARY_KEY_ENFORCED.to_string(),
+                    "true".to_string(),
+                )?;
+            }
+        }
+
         // Build table schema
         let (schema, field_comments) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -639,7 +680,7 @@ impl Binder {
                 .analyze_cluster_keys(cluster_by, schema.clone())
                 .await?;
             if keys.is_empty() {
-                None
+                inherited_cluster_key
             } else {
                 Some(format!("({})", keys.join(", ")))
             }
@@ -757,6 +798,7 @@ impl Binder {
             database,
             table,
             all,
+            cascade,
         } = stmt;
 
         let tenant = self.ctx.get_tenant();
@@ -770,6 +812,7 @@ impl Binder {
             database,
             table,
             all: *all,
+            cascade: *cascade,
         })))
     }
 
@@ -1113,6 +1156,7 @@ impl Binder {
                 CompactTarget::Block => OptimizeTableAction::CompactBlocks,
                 CompactTarget::Segment => OptimizeTableAction::CompactSegments,
             },
+            AstOptimizeTableAction::Tier => OptimizeTableAction::Tier,
         };
 
         Ok(Plan::OptimizeTable(Box::new(OptimizeTablePlan {
@@ -1291,8 +1335,13 @@ impl Binder {
         if let Some(expr) = &column.expr {
             match expr {
                 ColumnExpr::Default(default_expr) => {
-                    let expr =
-                        parse_default_expr_to_string(self.ctx.clone(), &field, default_expr, true)?;
+                    let expr = parse_default_expr_to_string(
+                        self.ctx.clone(),
+                        &[],
+                        &field,
+                        default_expr,
+                        true,
+                    )?;
                     field = field.with_default_expr(Some(expr));
                 }
                 ColumnExpr::Virtual(virtual_expr) => {
@@ -1324,6 +1373,9 @@ impl Binder {
         let mut has_computed = false;
         let mut fields = Vec::with_capacity(columns.len());
         let mut fields_comments = Vec::with_capacity(columns.len());
+        // the default expression can refer to the columns defined before it,
+        // except the computed columns.
+        let mut default_scope: Vec<TableField> = Vec::with_capacity(columns.len());
         let not_null = self.is_column_not_null();
         for column in columns.iter() {
             let name = normalize_identifier(&column.name, &self.name_resolution_ctx).name;
@@ -1336,6 +1388,7 @@ impl Binder {
                     ColumnExpr::Default(default_expr) => {
                         let expr = parse_default_expr_to_string(
                             self.ctx.clone(),
+                            &default_scope,
                             &field,
                             default_expr,
                             false,
@@ -1345,6 +1398,12 @@ impl Binder {
                     _ => has_computed = true,
                 }
             }
+            if !matches!(
+                column.expr,
+                Some(ColumnExpr::Virtual(_)) | Some(ColumnExpr::Stored(_))
+            ) {
+                default_scope.push(field.clone());
+            }
             fields.push(field);
         }
 
@@ -1403,7 +1462,7 @@ impl Binder {
         source: &CreateTableSource,
     ) -> Result<(TableSchemaRef, Vec<String>)> {
         match source {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, _) => {
                 self.analyze_create_table_schema_by_columns(columns).await
             }
             CreateTableSource::Like {
@@ -1432,6 +1491,50 @@ impl Binder {
         }
     }
 
+    /// Collect the table options and the cluster key that `CREATE TABLE ... LIKE` copies
+    /// from the source table, the options are only added if they are not specified.
+    #[async_backtrace::framed]
+    async fn analyze_create_table_like_options(
+        &self,
+        source: &CreateTableSource,
+        options: &mut BTreeMap<String, String>,
+    ) -> Result<Option<String>> {
+        let CreateTableSource::Like {
+            catalog,
+            database,
+            table,
+            including,
+        } = source
+        else {
+            return Ok(None);
+        };
+        if including.is_empty() {
+            return Ok(None);
+        }
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        let meta = &table.get_table_info().meta;
+
+        let mut cluster_key = None;
+        for option in including {
+            match option {
+                CreateTableLikeOption::Options => {
+                    for (key, value) in meta.options.iter() {
+                        if is_inheritable_opt_key(key) && !options.contains_key(key) {
+                            options.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+                CreateTableLikeOption::ClusterKeys => {
+                    cluster_key = meta.default_cluster_key.clone();
+                }
+            }
+        }
+        Ok(cluster_key)
+    }
+
     /// Validate the schema of the table to be created.
     fn validate_create_table_schema(schema: &TableSchemaRef) -> Result<()> {
         // Check if there are duplicated column names
@@ -64,7 +64,10 @@ impl<'a> Binder {
         stamt: &DeleteStmt,
     ) -> Result<Plan> {
         let DeleteStmt {
-            table, selection, ..
+            table,
+            selection,
+            returning,
+            ..
         } = stamt;
 
         let (catalog_name, database_name, table_name) = if let TableReference::Table {
@@ -109,6 +112,8 @@ impl<'a> Binder {
             }
         }
 
+        let returning = self.bind_returning(&mut context, returning).await?;
+
         let plan = DeletePlan {
             catalog_name,
             database_name,
@@ -116,6 +121,7 @@ impl<'a> Binder {
             metadata: self.metadata.clone(),
             selection,
             subquery_desc,
+            returning,
         };
         Ok(Plan::Delete(Box::new(plan)))
     }
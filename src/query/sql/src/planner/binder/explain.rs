@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ExplainFormat;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::ExplainOption;
 use databend_common_ast::ast::Statement;
@@ -28,12 +29,14 @@ pub struct ExplainConfig {
     pub verbose: bool,
     pub logical: bool,
     pub optimized: bool,
+    pub format: ExplainFormat,
 }
 
 struct ExplainConfigBuilder {
     verbose: bool,
     logical: bool,
     optimized: bool,
+    format: ExplainFormat,
 }
 
 impl ExplainConfigBuilder {
@@ -42,6 +45,7 @@ impl ExplainConfigBuilder {
             verbose: false,
             logical: false,
             optimized: false,
+            format: ExplainFormat::Text,
         }
     }
 
@@ -53,6 +57,7 @@ impl ExplainConfigBuilder {
                 self.logical = true;
                 self.optimized = true;
             }
+            ExplainOption::Format(format) => self.format = *format,
         }
 
         self
@@ -63,6 +68,7 @@ impl ExplainConfigBuilder {
             verbose: self.verbose,
             logical: self.logical,
             optimized: self.optimized,
+            format: self.format,
         }
     }
 }
@@ -146,5 +152,21 @@ fn validate_explain_config(kind: &ExplainKind, config: &ExplainConfig) -> Result
         ));
     }
 
+    if !matches!(kind, ExplainKind::Plan | ExplainKind::AnalyzePlan)
+        && config.format != ExplainFormat::Text
+    {
+        return Err(ErrorCode::SyntaxException(format!(
+            "FORMAT {} option is only supported for EXPLAIN and EXPLAIN ANALYZE statement",
+            config.format
+        )));
+    }
+
+    if config.logical && config.format != ExplainFormat::Text {
+        return Err(ErrorCode::SyntaxException(format!(
+            "FORMAT {} option can't be used together with LOGICAL option",
+            config.format
+        )));
+    }
+
     Ok(())
 }
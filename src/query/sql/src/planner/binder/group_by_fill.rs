@@ -0,0 +1,356 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::FunctionCall;
+use databend_common_ast::ast::GroupBy;
+use databend_common_ast::ast::GroupByFill;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::Join;
+use databend_common_ast::ast::JoinCondition;
+use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::OrderByExpr;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SelectStmt;
+use databend_common_ast::ast::SelectTarget;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::TableAlias;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::Window;
+use databend_common_ast::ast::WindowFrame;
+use databend_common_ast::ast::WindowFrameBound;
+use databend_common_ast::ast::WindowFrameUnits;
+use databend_common_ast::ast::WindowSpec;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use derive_visitor::DriveMut;
+
+use crate::binder::project::RemoveIdentifierQuote;
+use crate::normalize_identifier;
+use crate::planner::binder::Binder;
+use crate::planner::semantic::interval_to_micros;
+
+const FILL_TABLE_NAME: &str = "_fill";
+const FILL_COLUMN_NAME: &str = "generate_series";
+const FILLED_TABLE_NAME: &str = "_filled";
+
+impl Binder {
+    /// Rewrites `GROUP BY key WITH FILL FROM a TO b [STEP s] [INTERPOLATE (...)]` to a left join
+    /// from the series of the keys to the aggregated groups:
+    ///
+    /// ```sql
+    /// SELECT _fill.generate_series AS key, <items of _filled>
+    /// FROM generate_series(a, b[, s]) AS _fill
+    /// LEFT OUTER JOIN (SELECT ... GROUP BY key) AS _filled
+    /// ON _fill.generate_series = _filled.key
+    /// ```
+    ///
+    /// The interpolated items without an expression carry the last non-null value forward,
+    /// which is computed by `max_by(item, _fill.generate_series)` over the preceding rows.
+    ///
+    /// Returns the rewritten statement and its `ORDER BY` items, the result is ordered by the key
+    /// if there is no `ORDER BY` clause.
+    pub(super) fn rewrite_group_by_fill(
+        &self,
+        stmt: &SelectStmt,
+        fill: &GroupByFill,
+        order_by: &[OrderByExpr],
+    ) -> Result<(SelectStmt, Vec<OrderByExpr>)> {
+        let span = stmt.span;
+        let key_index = self.find_fill_key(stmt, fill)?;
+
+        let mut inner_select_list = Vec::with_capacity(stmt.select_list.len());
+        let mut names = Vec::with_capacity(stmt.select_list.len());
+        for target in stmt.select_list.iter() {
+            let SelectTarget::AliasedExpr { expr, alias } = target else {
+                return Err(ErrorCode::SemanticError(
+                    "GROUP BY WITH FILL does not support `*` in the select list".to_string(),
+                )
+                .set_span(span));
+            };
+            let name = fill_item_name(expr, alias);
+            inner_select_list.push(SelectTarget::AliasedExpr {
+                expr: expr.clone(),
+                alias: Some(name.clone()),
+            });
+            names.push(name);
+        }
+
+        let normalized_names = names
+            .iter()
+            .map(|name| normalize_identifier(name, &self.name_resolution_ctx).name)
+            .collect::<Vec<_>>();
+        let mut interpolates = vec![None; names.len()];
+        for item in fill.interpolate.iter() {
+            let name = normalize_identifier(&item.column, &self.name_resolution_ctx).name;
+            match normalized_names.iter().position(|n| n == &name) {
+                Some(index) if index != key_index => interpolates[index] = Some(item),
+                Some(_) => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "can not interpolate the fill key `{}`",
+                        item.column
+                    ))
+                    .set_span(item.column.span));
+                }
+                None => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "interpolated column `{}` is not in the select list",
+                        item.column
+                    ))
+                    .set_span(item.column.span));
+                }
+            }
+        }
+
+        let fill_key = column_ref(
+            Some(FILL_TABLE_NAME),
+            Identifier::from_name(span, FILL_COLUMN_NAME),
+        );
+        let mut select_list = Vec::with_capacity(names.len());
+        for (index, name) in names.iter().enumerate() {
+            let filled = column_ref(Some(FILLED_TABLE_NAME), name.clone());
+            let expr = if index == key_index {
+                fill_key.clone()
+            } else {
+                match interpolates[index] {
+                    None => filled,
+                    Some(item) => match &item.expr {
+                        Some(expr) => function_call(span, "coalesce", vec![filled, expr.clone()]),
+                        None => last_value(span, filled, fill_key.clone()),
+                    },
+                }
+            };
+            select_list.push(SelectTarget::AliasedExpr {
+                expr: Box::new(expr),
+                alias: Some(name.clone()),
+            });
+        }
+
+        let mut series_params = vec![fill.from.clone(), fill.to.clone()];
+        if let Some(step) = &fill.step {
+            series_params.push(match step {
+                Expr::Interval { span, expr, unit } => interval_to_micros(*span, expr, unit)
+                    .ok_or_else(|| {
+                        ErrorCode::SemanticError(format!(
+                            "STEP of GROUP BY WITH FILL only supports fixed intervals, but got {unit}"
+                        ))
+                        .set_span(*span)
+                    })?,
+                _ => step.clone(),
+            });
+        }
+        let series = TableReference::TableFunction {
+            span,
+            lateral: false,
+            name: Identifier::from_name(span, "generate_series"),
+            params: series_params,
+            named_params: vec![],
+            alias: Some(TableAlias {
+                name: Identifier::from_name(span, FILL_TABLE_NAME),
+                columns: vec![],
+            }),
+        };
+
+        let inner = SelectStmt {
+            select_list: inner_select_list,
+            group_by: Some(GroupBy::Normal(vec![fill.expr.clone()])),
+            ..stmt.clone()
+        };
+        let groups = TableReference::Subquery {
+            span,
+            lateral: false,
+            subquery: Box::new(Query {
+                span,
+                with: None,
+                body: SetExpr::Select(Box::new(inner)),
+                order_by: vec![],
+                limit: vec![],
+                offset: None,
+                ignore_result: false,
+            }),
+            alias: Some(TableAlias {
+                name: Identifier::from_name(span, FILLED_TABLE_NAME),
+                columns: vec![],
+            }),
+        };
+
+        let outer = SelectStmt {
+            span,
+            hints: None,
+            distinct: false,
+            select_list,
+            from: vec![TableReference::Join {
+                span,
+                join: Join {
+                    op: JoinOperator::LeftOuter,
+                    condition: JoinCondition::On(Box::new(Expr::BinaryOp {
+                        span,
+                        op: BinaryOperator::Eq,
+                        left: Box::new(fill_key),
+                        right: Box::new(column_ref(
+                            Some(FILLED_TABLE_NAME),
+                            names[key_index].clone(),
+                        )),
+                    })),
+                    left: Box::new(series),
+                    right: Box::new(groups),
+                },
+            }],
+            selection: None,
+            group_by: None,
+            having: None,
+            window_list: None,
+            qualify: None,
+        };
+
+        let order_by = if order_by.is_empty() {
+            vec![OrderByExpr {
+                expr: column_ref(None, names[key_index].clone()),
+                asc: None,
+                nulls_first: None,
+            }]
+        } else {
+            order_by.to_vec()
+        };
+
+        Ok((outer, order_by))
+    }
+
+    /// Finds the select item of the fill key, which can be referenced by
+    /// its position, its alias or the same expression.
+    fn find_fill_key(&self, stmt: &SelectStmt, fill: &GroupByFill) -> Result<usize> {
+        let key = match &fill.expr {
+            Expr::Literal {
+                value: Literal::UInt64(pos),
+                ..
+            } if (1..=stmt.select_list.len() as u64).contains(pos) => {
+                return Ok(*pos as usize - 1);
+            }
+            Expr::ColumnRef {
+                column:
+                    ColumnRef {
+                        database: None,
+                        table: None,
+                        column: ColumnID::Name(ident),
+                    },
+                ..
+            } => Some(normalize_identifier(ident, &self.name_resolution_ctx).name),
+            _ => None,
+        };
+        let key_expr = fill.expr.to_string();
+
+        stmt.select_list
+            .iter()
+            .position(|target| match target {
+                SelectTarget::AliasedExpr { expr, alias } => {
+                    let aliased = match (&key, alias) {
+                        (Some(key), Some(alias)) => {
+                            &normalize_identifier(alias, &self.name_resolution_ctx).name == key
+                        }
+                        _ => false,
+                    };
+                    aliased || expr.to_string() == key_expr
+                }
+                SelectTarget::StarColumns { .. } => false,
+            })
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(format!(
+                    "fill key `{}` of GROUP BY WITH FILL must be in the select list",
+                    fill.expr
+                ))
+                .set_span(fill.expr.span())
+            })
+    }
+}
+
+/// Gets the name of a select item, the same as the one generated in `normalize_select_list`.
+fn fill_item_name(expr: &Expr, alias: &Option<Identifier>) -> Identifier {
+    match (expr, alias) {
+        (_, Some(alias)) => alias.clone(),
+        (
+            Expr::ColumnRef {
+                column:
+                    ColumnRef {
+                        column: ColumnID::Name(column),
+                        ..
+                    },
+                ..
+            },
+            None,
+        ) => column.clone(),
+        _ => {
+            let mut expr = expr.clone();
+            expr.drive_mut(&mut RemoveIdentifierQuote);
+            Identifier::from_name_with_quoted(
+                expr.span(),
+                format!("{:#}", expr).to_lowercase(),
+                Some('"'),
+            )
+        }
+    }
+}
+
+fn column_ref(table: Option<&str>, column: Identifier) -> Expr {
+    Expr::ColumnRef {
+        span: column.span,
+        column: ColumnRef {
+            database: None,
+            table: table.map(|table| Identifier::from_name(column.span, table)),
+            column: ColumnID::Name(column),
+        },
+    }
+}
+
+fn function_call(span: Span, name: &str, args: Vec<Expr>) -> Expr {
+    Expr::FunctionCall {
+        span,
+        func: FunctionCall {
+            distinct: false,
+            name: Identifier::from_name(span, name),
+            args,
+            params: vec![],
+            window: None,
+            order_by: vec![],
+            lambda: None,
+        },
+    }
+}
+
+/// `max_by(value, key) OVER (ORDER BY key ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW)`,
+/// the null values are ignored by `max_by`, so it's the last non-null value up to the current row.
+fn last_value(span: Span, value: Expr, key: Expr) -> Expr {
+    let mut expr = function_call(span, "max_by", vec![value, key.clone()]);
+    if let Expr::FunctionCall { func, .. } = &mut expr {
+        func.window = Some(Window::WindowSpec(WindowSpec {
+            existing_window_name: None,
+            partition_by: vec![],
+            order_by: vec![OrderByExpr {
+                expr: key,
+                asc: None,
+                nulls_first: None,
+            }],
+            window_frame: Some(WindowFrame {
+                units: WindowFrameUnits::Rows,
+                start_bound: WindowFrameBound::Preceding(None),
+                end_bound: WindowFrameBound::CurrentRow,
+            }),
+        }));
+    }
+    expr
+}
@@ -18,12 +18,16 @@ use std::sync::Arc;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::InsertSource;
 use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::ReplaceStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::OnErrorMode;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY;
+use databend_storages_common_table_meta::table::OPT_KEY_PRIMARY_KEY_ENFORCED;
 
 use crate::binder::Binder;
 use crate::normalize_identifier;
@@ -83,6 +87,8 @@ impl Binder {
             columns,
             source,
             overwrite,
+            on_conflict_update,
+            returning,
             ..
         } = stmt;
         if let Some(with) = &with {
@@ -94,6 +100,51 @@ impl Binder {
             .ctx
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
+
+        // `INSERT ... ON CONFLICT DO UPDATE` and INSERT into a table with an enforced
+        // primary key look up the existing rows by the primary key, which is what
+        // REPLACE INTO does.
+        let options = table.get_table_info().options();
+        let primary_key = options.get(OPT_KEY_PRIMARY_KEY);
+        let enforced = options
+            .get(OPT_KEY_PRIMARY_KEY_ENFORCED)
+            .is_some_and(|v| v.to_lowercase() == "true");
+        if *on_conflict_update || (enforced && primary_key.is_some() && !*overwrite) {
+            let Some(primary_key) = primary_key else {
+                return Err(ErrorCode::BadArguments(format!(
+                    "table {table_name} has no primary key, which is required by ON CONFLICT"
+                )));
+            };
+            if *overwrite {
+                return Err(ErrorCode::BadArguments(
+                    "ON CONFLICT is not supported by INSERT OVERWRITE",
+                ));
+            }
+            if !returning.is_empty() {
+                return Err(ErrorCode::Unimplemented(
+                    "RETURNING is not supported by INSERT ON CONFLICT",
+                ));
+            }
+            let replace_stmt = ReplaceStmt {
+                hints: stmt.hints.clone(),
+                catalog: stmt.catalog.clone(),
+                database: stmt.database.clone(),
+                table: stmt.table.clone(),
+                on_conflict_columns: primary_key
+                    .split(',')
+                    .map(|col| Identifier::from_name_with_quoted(None, col.trim(), Some('"')))
+                    .collect(),
+                columns: columns.clone(),
+                source: source.clone(),
+                delete_when: None,
+            };
+            let mut plan = self.bind_replace(bind_context, &replace_stmt).await?;
+            if let Plan::Replace(replace) = &mut plan {
+                replace.error_on_conflict = !*on_conflict_update;
+            }
+            return Ok(plan);
+        }
+
         let schema = self.schema_project(&table.schema(), columns)?;
 
         let input_source: Result<InsertInputSource> = match source.clone() {
@@ -189,14 +240,35 @@ impl Binder {
             }
         };
 
+        let input_source = input_source?;
+
+        let returning = if returning.is_empty() {
+            None
+        } else {
+            let table_ref = TableReference::Table {
+                span: None,
+                catalog: stmt.catalog.clone(),
+                database: stmt.database.clone(),
+                table: stmt.table.clone(),
+                alias: None,
+                temporal: None,
+                pivot: None,
+                unpivot: None,
+            };
+            let (_, mut context) = self.bind_single_table(bind_context, &table_ref).await?;
+            context.allow_internal_columns(false);
+            self.bind_returning(&mut context, returning).await?
+        };
+
         let plan = Insert {
             catalog: catalog_name.to_string(),
             database: database_name.to_string(),
             table: table_name,
             schema,
             overwrite: *overwrite,
-            source: input_source?,
+            source: input_source,
             table_info: None,
+            returning,
         };
 
         Ok(Plan::Insert(Box::new(plan)))
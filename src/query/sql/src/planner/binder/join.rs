@@ -287,7 +287,10 @@ impl Binder {
                         need_push_down = true;
                         right_push_down.push(predicate.clone());
                     }
-                    JoinType::Right | JoinType::RightSingle | JoinType::LeftMark => {
+                    JoinType::Right
+                    | JoinType::RightSingle
+                    | JoinType::LeftMark
+                    | JoinType::NullAwareLeftAnti => {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                     }
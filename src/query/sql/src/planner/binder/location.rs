@@ -431,6 +431,22 @@ fn parse_huggingface_params(l: &mut UriLocation, root: String) -> Result<Storage
     Ok(sp)
 }
 
+/// Returns the location with only the `connection_name` of the connection options,
+/// the credentials are left out and must be given again to recreate the location.
+pub fn hide_connection_options(l: &UriLocation) -> UriLocation {
+    let conns = l
+        .connection
+        .conns
+        .iter()
+        .filter(|(k, _)| k.as_str() == "connection_name")
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect::<BTreeMap<_, _>>();
+    UriLocation {
+        connection: Connection::new(conns),
+        ..l.clone()
+    }
+}
+
 pub async fn parse_storage_params_from_uri(
     l: &mut UriLocation,
     ctx: Option<&dyn TableContext>,
@@ -26,6 +26,7 @@ mod ddl;
 mod delete;
 mod distinct;
 mod explain;
+mod group_by_fill;
 mod having;
 mod insert;
 mod insert_multi_table;
@@ -40,6 +41,7 @@ mod project;
 mod project_set;
 mod qualify;
 mod replace;
+mod returning;
 mod scalar;
 mod scalar_common;
 mod select;
@@ -67,6 +69,7 @@ pub use copy_into_table::resolve_stage_location;
 pub use explain::ExplainConfig;
 pub use internal_column_factory::INTERNAL_COLUMN_FACTORY;
 pub use location::get_storage_params_from_options;
+pub use location::hide_connection_options;
 pub use location::parse_storage_params_from_uri;
 pub use location::parse_uri_location;
 pub use merge_into::MergeIntoType;
@@ -67,7 +67,7 @@ use crate::WindowChecker;
 
 #[derive(VisitorMut)]
 #[visitor(Identifier(enter))]
-struct RemoveIdentifierQuote;
+pub(super) struct RemoveIdentifierQuote;
 
 impl RemoveIdentifierQuote {
     fn enter_identifier(&mut self, ident: &mut Identifier) {
@@ -486,6 +486,7 @@ impl Binder {
                 func: FunctionCall {
                     name: Identifier::from_name(span, "array_apply"),
                     args: vec![input_array],
+                    order_by: vec![],
                     lambda: lambda.cloned(),
                     distinct: false,
                     params: vec![],
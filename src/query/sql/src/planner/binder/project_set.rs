@@ -59,6 +59,7 @@ impl SrfCollector {
             name,
             args,
             params,
+            order_by,
             window,
             lambda,
         } = func;
@@ -76,6 +77,7 @@ impl SrfCollector {
                     name: name.clone(),
                     args: args.to_vec(),
                     params: params.to_vec(),
+                    order_by: order_by.clone(),
                     window: window.clone(),
                     lambda: lambda.clone(),
                 },
@@ -180,6 +180,7 @@ impl Binder {
             schema,
             source: input_source?,
             delete_when: delete_when.clone(),
+            error_on_conflict: false,
         };
 
         Ok(Plan::Replace(Box::new(plan)))
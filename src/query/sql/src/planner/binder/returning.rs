@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::SelectTarget;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRefExt;
+
+use crate::binder::Binder;
+use crate::plans::Returning;
+use crate::BindContext;
+
+impl Binder {
+    /// Binds the `RETURNING` list of INSERT, UPDATE and DELETE, `bind_context` holds
+    /// the columns of the target table.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_returning(
+        &mut self,
+        bind_context: &mut BindContext,
+        returning: &[SelectTarget],
+    ) -> Result<Option<Returning>> {
+        if returning.is_empty() {
+            return Ok(None);
+        }
+
+        let select_list = self.normalize_select_list(bind_context, returning).await?;
+        let mut items = Vec::with_capacity(select_list.items.len());
+        let mut fields = Vec::with_capacity(select_list.items.len());
+        for item in select_list.items {
+            if !self.check_allowed_scalar_expr(&item.scalar)? {
+                return Err(ErrorCode::SemanticError(
+                    "RETURNING can't contain subquery|window|aggregate|udf functions".to_string(),
+                )
+                .set_span(item.scalar.span()));
+            }
+            fields.push(DataField::new(&item.alias, item.scalar.data_type()?));
+            items.push((item.alias, item.scalar));
+        }
+
+        Ok(Some(Returning {
+            items,
+            schema: DataSchemaRefExt::create(fields),
+        }))
+    }
+}
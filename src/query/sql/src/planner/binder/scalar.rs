@@ -21,15 +21,20 @@ use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::parser::Dialect;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use indexmap::IndexMap;
 
 use crate::binder::wrap_cast;
 use crate::binder::CteInfo;
+use crate::is_dependent_default_expr;
+use crate::parse_computed_expr;
 use crate::planner::binder::BindContext;
 use crate::planner::semantic::NameResolutionContext;
 use crate::planner::semantic::TypeChecker;
@@ -104,6 +109,14 @@ impl<'a> ScalarBinder<'a> {
         schema: &DataSchema,
     ) -> Result<databend_common_expression::Expr> {
         if let Some(default_expr) = field.default_expr() {
+            if is_dependent_default_expr(default_expr)? {
+                // the columns before `field` in `schema` are visible to the default,
+                // the column references are resolved to their positions in `schema`.
+                let position = schema.index_of(field.name())?;
+                let scope = DataSchemaRefExt::create(schema.fields()[..position].to_vec());
+                let expr = parse_computed_expr(self.ctx.clone(), scope, default_expr)?;
+                return check_cast(None, false, expr, field.data_type(), &BUILTIN_FUNCTIONS);
+            }
             let tokens = tokenize_sql(default_expr)?;
             let ast = parse_expr(&tokens, self.dialect)?;
             let (mut scalar, _) = self.bind(&ast).await?;
@@ -70,6 +70,7 @@ use crate::plans::Filter;
 use crate::plans::JoinType;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
+use crate::plans::UDFKind;
 use crate::plans::UnionAll;
 use crate::plans::Visitor as _;
 use crate::ColumnBinding;
@@ -101,6 +102,18 @@ impl Binder {
         order_by: &[OrderByExpr],
         limit: usize,
     ) -> Result<(SExpr, BindContext)> {
+        if let Some(GroupBy::Fill(fill)) = &stmt.group_by {
+            let (stmt, order_by) = self.rewrite_group_by_fill(stmt, fill, order_by)?;
+            return self
+                .bind_set_expr(
+                    bind_context,
+                    &SetExpr::Select(Box::new(stmt)),
+                    &order_by,
+                    limit,
+                )
+                .await;
+        }
+
         if let Some(hints) = &stmt.hints {
             if let Some(e) = self.opt_hints_set_var(bind_context, hints).await.err() {
                 warn!(
@@ -296,11 +309,15 @@ impl Binder {
         s_expr = self.bind_projection(&mut from_context, &projections, &scalar_items, s_expr)?;
 
         // rewrite udf for interpreter udf
-        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), true);
+        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), UDFKind::Script);
         s_expr = udf_rewriter.rewrite(&s_expr)?;
 
         // rewrite udf for server udf
-        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), false);
+        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), UDFKind::Server);
+        s_expr = udf_rewriter.rewrite(&s_expr)?;
+
+        // rewrite udf for model inference
+        let mut udf_rewriter = UdfRewriter::new(self.metadata.clone(), UDFKind::Model);
         s_expr = udf_rewriter.rewrite(&s_expr)?;
 
         // rewrite variant inner fields as virtual columns
@@ -993,6 +1010,7 @@ impl<'a> SelectRewriter<'a> {
                     args,
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             }),
@@ -78,14 +78,20 @@ impl Binder {
         match &stmt.source {
             UnSetSource::Var { variable } => {
                 let vars = vec![variable.name.to_lowercase()];
-                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan { vars })))
+                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+                    is_session: stmt.session,
+                    vars,
+                })))
             }
             UnSetSource::Vars { variables } => {
                 let vars: Vec<String> = variables
                     .iter()
                     .map(|var| var.name.to_lowercase())
                     .collect();
-                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan { vars })))
+                Ok(Plan::UnSetVariable(Box::new(UnSettingPlan {
+                    is_session: stmt.session,
+                    vars,
+                })))
             }
         }
     }
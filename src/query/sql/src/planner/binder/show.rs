@@ -73,6 +73,22 @@ impl Binder {
             .await
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_stages(
+        &mut self,
+        bind_context: &mut BindContext,
+        show_options: &Option<ShowOptions>,
+    ) -> Result<Plan> {
+        let (show_limit, limit_str) = get_show_options(show_options, None);
+        // rewrite show stages to select * from system.stages ...
+        let query = format!(
+            "SELECT name, stage_type, number_of_files, creator, created_on, comment FROM system.stages {} ORDER BY name {}",
+            show_limit, limit_str,
+        );
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowStages)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_settings(
         &mut self,
@@ -139,6 +155,22 @@ impl Binder {
             .await
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_show_quotas(
+        &mut self,
+        bind_context: &mut BindContext,
+        show_options: &Option<ShowOptions>,
+    ) -> Result<Plan> {
+        let (show_limit, limit_str) = get_show_options(show_options, Some("name".to_string()));
+        let query = format!(
+            "SELECT name, value, used FROM system.quotas {} {}",
+            show_limit, limit_str,
+        );
+
+        self.bind_rewrite_to_query(bind_context, &query, RewriteKind::ShowQuotas)
+            .await
+    }
+
     #[async_backtrace::framed]
     pub(in crate::planner::binder) async fn bind_show_indexes(
         &mut self,
@@ -71,7 +71,12 @@ impl BindContext {
             if let AExpr::ColumnRef { column, .. } = expr {
                 if column.column.name().eq_ignore_ascii_case("default") {
                     let field = schema.field(i);
-                    map_exprs.push(scalar_binder.get_default_value(field, schema).await?);
+                    // the values of the previous columns are appended after the first dummy column.
+                    let expr = scalar_binder
+                        .get_default_value(field, schema)
+                        .await?
+                        .project_column_ref(|index| index + 1);
+                    map_exprs.push(expr);
                     continue;
                 }
             }
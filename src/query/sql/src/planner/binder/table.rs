@@ -70,6 +70,7 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::schema::DatabaseType;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
 use databend_common_meta_app::tenant::Tenant;
@@ -86,6 +87,7 @@ use databend_common_storages_view::view_table::QUERY;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::get_change_type;
 use databend_storages_common_table_meta::table::ChangeType;
+use derive_visitor::DriveMut;
 use log::info;
 use parking_lot::RwLock;
 
@@ -100,6 +102,7 @@ use crate::binder::Visibility;
 use crate::optimizer::SExpr;
 use crate::optimizer::StatInfo;
 use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::ShareViewRewriter;
 use crate::planner::semantic::TypeChecker;
 use crate::plans::CteScan;
 use crate::plans::DummyTableScan;
@@ -329,7 +332,13 @@ impl Binder {
                     .get(QUERY)
                     .ok_or_else(|| ErrorCode::Internal("Invalid VIEW object"))?;
                 let tokens = tokenize_sql(query.as_str())?;
-                let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+                let (mut stmt, _) = parse_sql(&tokens, self.dialect)?;
+                if let DatabaseType::ShareDB(_) = &table_meta.get_table_info().db_type {
+                    stmt.drive_mut(&mut ShareViewRewriter {
+                        catalog: catalog.clone(),
+                        database: database.clone(),
+                    });
+                }
                 // For view, we need use a new context to bind it.
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
                 new_bind_context.view_info = Some((database.clone(), table_name));
@@ -511,6 +520,7 @@ impl Binder {
                             args,
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     };
@@ -620,6 +630,7 @@ impl Binder {
                             params: vec![],
                             args,
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     }),
@@ -800,6 +811,14 @@ impl Binder {
             _ => location.clone(),
         };
         let (mut stage_info, path) = resolve_file_location(self.ctx.as_ref(), &location).await?;
+        if let FileLocation::Stage(stage_location) = &location {
+            // Without a file format, a stage with directory table returns the metadata of its files.
+            if stage_info.directory_enabled && options.file_format.is_none() {
+                return self
+                    .bind_directory_table(bind_context, stage_location, options, alias)
+                    .await;
+            }
+        }
         if let Some(f) = &options.file_format {
             stage_info.file_format_params = match StageFileFormatType::from_str(f) {
                 Ok(t) => FileFormatParams::default_by_type(t)?,
@@ -816,6 +835,50 @@ impl Binder {
             .await
     }
 
+    #[async_backtrace::framed]
+    async fn bind_directory_table(
+        &mut self,
+        bind_context: &mut BindContext,
+        stage_location: &str,
+        options: &SelectStageOptions,
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        let mut args = HashMap::new();
+        args.insert(
+            "location".to_string(),
+            Scalar::String(format!("@{stage_location}")),
+        );
+        if let Some(pattern) = &options.pattern {
+            args.insert("pattern".to_string(), Scalar::String(pattern.clone()));
+        }
+
+        let table_meta: Arc<dyn TableFunction> = self
+            .catalogs
+            .get_default_catalog(self.ctx.txn_mgr())?
+            .get_table_function("directory", TableArgs::new_named(args))?;
+        let table = table_meta.as_table();
+        let table_alias_name = alias
+            .as_ref()
+            .map(|alias| normalize_identifier(&alias.name, &self.name_resolution_ctx).name);
+        let table_index = self.metadata.write().add_table(
+            CATALOG_DEFAULT.to_string(),
+            "system".to_string(),
+            table.clone(),
+            table_alias_name,
+            false,
+            false,
+            false,
+        );
+
+        let (s_expr, mut bind_context) = self
+            .bind_base_table(bind_context, "system", table_index, None)
+            .await?;
+        if let Some(alias) = alias {
+            bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        }
+        Ok((s_expr, bind_context))
+    }
+
     #[async_recursion]
     #[async_backtrace::framed]
     pub(crate) async fn bind_single_table(
@@ -932,7 +995,7 @@ impl Binder {
                 )
                 .await?
             }
-            FileFormatParams::NdJson(..) => {
+            FileFormatParams::NdJson(..) | FileFormatParams::Avro(..) => {
                 let schema = Arc::new(TableSchema::new(vec![TableField::new(
                     "_$1", // TODO: this name should be in visible
                     TableDataType::Variant,
@@ -948,12 +1011,13 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
-            FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
+            FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) | FileFormatParams::Xlsx(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
                     let file_type = match stage_info.file_format_params {
                         FileFormatParams::Csv(..) => "CSV",
                         FileFormatParams::Tsv(..) => "TSV",
+                        FileFormatParams::Xlsx(..) => "XLSX",
                         _ => unreachable!(), // This branch should never be reached
                     };
 
@@ -985,7 +1049,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, TSV, AVRO and XLSX. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
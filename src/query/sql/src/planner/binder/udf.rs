@@ -14,6 +14,7 @@
 
 use chrono::Utc;
 use databend_common_ast::ast::AlterUDFStmt;
+use databend_common_ast::ast::CreateModelStmt;
 use databend_common_ast::ast::CreateUDFStmt;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::UDFDefinition;
@@ -21,17 +22,23 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
 use databend_common_expression::udf_client::UDFFlightClient;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
+use databend_common_meta_app::principal::UDFModel;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
+use databend_common_users::UserApiProvider;
 
+use crate::binder::resolve_stage_location;
+use crate::normalize_identifier;
 use crate::planner::resolve_type_name;
 use crate::planner::udf_validator::UDFValidator;
 use crate::plans::AlterUDFPlan;
 use crate::plans::CreateUDFPlan;
+use crate::plans::DropUDFPlan;
 use crate::plans::Plan;
 use crate::Binder;
 
@@ -190,4 +197,96 @@ impl Binder {
             .await?;
         Ok(Plan::AlterUDF(Box::new(AlterUDFPlan { udf })))
     }
+
+    pub(in crate::planner::binder) async fn bind_create_model(
+        &mut self,
+        stmt: &CreateModelStmt,
+    ) -> Result<Plan> {
+        let model_name = normalize_identifier(&stmt.model_name, &self.name_resolution_ctx).name;
+
+        let mut arg_types = Vec::with_capacity(stmt.arg_types.len());
+        for arg_type in &stmt.arg_types {
+            let data_type = DataType::from(&resolve_type_name(arg_type, true)?);
+            if !data_type.remove_nullable().is_numeric() {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "The features of model '{model_name}' must be numbers, but got {data_type}"
+                )));
+            }
+            arg_types.push(data_type);
+        }
+        if arg_types.is_empty() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Model '{model_name}' must have at least one feature"
+            )));
+        }
+
+        // The rows with NULL features are not evaluated, so the result is always nullable.
+        let return_type =
+            DataType::from(&resolve_type_name(&stmt.return_type, true)?).wrap_nullable();
+        let is_valid_output = |ty: &DataType| {
+            matches!(
+                ty.remove_nullable(),
+                DataType::Number(NumberDataType::Float32)
+                    | DataType::Number(NumberDataType::Float64)
+                    | DataType::Number(NumberDataType::Int64)
+            )
+        };
+        let valid_return_type = match return_type.remove_nullable() {
+            DataType::Array(inner) => inner.remove_nullable().is_floating(),
+            ty => is_valid_output(&ty),
+        };
+        if !valid_return_type {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Unsupported return type {return_type} of model '{model_name}', must be FLOAT, DOUBLE, BIGINT or an ARRAY of FLOAT or DOUBLE"
+            )));
+        }
+
+        // Make sure the stage of the model file exists.
+        let location = stmt.location.trim_start_matches('@').to_string();
+        let (_, path) = resolve_stage_location(self.ctx.as_ref(), &location).await?;
+        if path.ends_with('/') {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "The location of model '{model_name}' must be a file, but got '@{location}'"
+            )));
+        }
+
+        Ok(Plan::CreateUDF(Box::new(CreateUDFPlan {
+            create_option: stmt.create_option,
+            udf: UserDefinedFunction {
+                name: model_name,
+                description: stmt.description.clone().unwrap_or_default(),
+                definition: PlanUDFDefinition::UDFModel(UDFModel {
+                    location,
+                    arg_types,
+                    return_type,
+                }),
+                created_on: Utc::now(),
+            },
+        })))
+    }
+
+    pub(in crate::planner::binder) async fn bind_drop_model(
+        &mut self,
+        if_exists: bool,
+        model_name: &Identifier,
+    ) -> Result<Plan> {
+        let model_name = normalize_identifier(model_name, &self.name_resolution_ctx).name;
+
+        // Models share the namespace of udf functions, make sure a function is not dropped by `DROP MODEL`.
+        let udf = UserApiProvider::instance()
+            .get_udf(&self.ctx.get_tenant(), &model_name)
+            .await?;
+        if let Some(udf) = udf {
+            if !matches!(udf.definition, PlanUDFDefinition::UDFModel(_)) {
+                return Err(ErrorCode::InvalidArgument(format!(
+                    "'{model_name}' is not a model, use DROP FUNCTION to drop it"
+                )));
+            }
+        }
+
+        Ok(Plan::DropUDF(Box::new(DropUDFPlan {
+            if_exists,
+            udf: model_name,
+        })))
+    }
 }
@@ -48,6 +48,7 @@ impl Binder {
             table,
             update_list,
             selection,
+            returning,
             ..
         } = stmt;
 
@@ -146,6 +147,8 @@ impl Binder {
             update_columns.insert(index, row_version);
         }
 
+        let returning = self.bind_returning(&mut context, returning).await?;
+
         let plan = UpdatePlan {
             catalog: catalog_name,
             database: database_name,
@@ -155,6 +158,7 @@ impl Binder {
             bind_context: Box::new(context),
             metadata: self.metadata.clone(),
             subquery_desc,
+            returning,
         };
         Ok(Plan::Update(Box::new(plan)))
     }
@@ -180,6 +180,7 @@ impl Dataframe {
                     args: vec![],
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             }),
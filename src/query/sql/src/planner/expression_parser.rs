@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ColumnRef;
 use databend_common_ast::ast::Expr as AExpr;
+use databend_common_ast::ast::Lambda;
 use databend_common_ast::parser::parse_comma_separated_exprs;
 use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
 use databend_common_catalog::catalog::CATALOG_DEFAULT;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::table::Table;
@@ -42,7 +46,9 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::tenant::Tenant;
 use databend_common_settings::Settings;
+use derive_visitor::Drive;
 use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
 use parking_lot::RwLock;
 
 use crate::binder::wrap_cast;
@@ -235,15 +241,38 @@ pub fn parse_computed_expr(
     Ok(expr)
 }
 
+/// Check and normalize the default expression of `field`, it can refer to the columns
+/// in `prev_fields`, which are the columns defined before `field`.
 pub fn parse_default_expr_to_string(
     ctx: Arc<dyn TableContext>,
+    prev_fields: &[TableField],
     field: &TableField,
     ast: &AExpr,
     is_add_column: bool,
 ) -> Result<String> {
     let settings = Settings::create(Tenant::new_literal("dummy"));
     let mut bind_context = BindContext::new();
-    let metadata = Metadata::default();
+    let mut metadata = Metadata::default();
+    for (index, prev_field) in prev_fields.iter().enumerate() {
+        bind_context.add_column_binding(
+            ColumnBindingBuilder::new(
+                prev_field.name().clone(),
+                index,
+                Box::new(prev_field.data_type().into()),
+                Visibility::Visible,
+            )
+            .build(),
+        );
+        metadata.add_base_table_column(
+            prev_field.name().clone(),
+            prev_field.data_type().clone(),
+            0,
+            None,
+            None,
+            None,
+            None,
+        );
+    }
 
     let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
     let mut type_checker = TypeChecker::try_create(
@@ -263,6 +292,17 @@ pub fn parse_default_expr_to_string(
     }
     let expr = scalar.as_expr()?;
 
+    if !expr.column_refs().is_empty() {
+        // The default depends on other columns, it's evaluated per row after the
+        // columns it refers to, keep the normalized ast to be bound at that time.
+        let mut ast = ast.clone();
+        let mut normalizer = IdentifierNormalizer {
+            ctx: &name_resolution_ctx,
+        };
+        ast.drive_mut(&mut normalizer);
+        return Ok(format!("{:#}", ast));
+    }
+
     // Added columns are not allowed to use expressions,
     // as the default values will be generated at at each query.
     if is_add_column && !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
@@ -398,11 +438,51 @@ impl Table for DummyTable {
     }
 }
 
+#[derive(Default, Visitor)]
+#[visitor(ColumnRef(enter), Lambda(enter))]
+struct ColumnRefCollector {
+    columns: HashSet<String>,
+    lambda_params: HashSet<String>,
+}
+
+impl ColumnRefCollector {
+    fn enter_column_ref(&mut self, column: &ColumnRef) {
+        self.columns.insert(column.column.name().to_string());
+    }
+
+    fn enter_lambda(&mut self, lambda: &Lambda) {
+        self.lambda_params
+            .extend(lambda.params.iter().map(|v| v.name.clone()));
+    }
+}
+
+/// Returns true if the default expression refers to other columns of the table.
+pub fn is_dependent_default_expr(default_expr: &str) -> Result<bool> {
+    let tokens = tokenize_sql(default_expr)?;
+    let asts = parse_comma_separated_exprs(&tokens, Dialect::default())?;
+    let mut collector = ColumnRefCollector::default();
+    for ast in asts.iter() {
+        ast.drive(&mut collector);
+    }
+    Ok(collector
+        .columns
+        .iter()
+        .any(|column| !collector.lambda_params.contains(column)))
+}
+
+/// Evaluate the default value of `field` as a constant.
+///
+/// The defaults referring to other columns can't be evaluated without a row, and they are
+/// only used for the columns created with the table, so the default value of the type is
+/// returned for them.
 pub fn field_default_value(ctx: Arc<dyn TableContext>, field: &TableField) -> Result<Scalar> {
     let data_type = field.data_type();
     let data_type = DataType::from(data_type);
 
     match field.default_expr() {
+        Some(default_expr) if is_dependent_default_expr(default_expr)? => {
+            Ok(Scalar::default_value(&data_type))
+        }
         Some(default_expr) => {
             let table: Arc<dyn Table> = Arc::new(DummyTable::default());
             let mut exprs = parse_exprs(ctx.clone(), table.clone(), default_expr)?;
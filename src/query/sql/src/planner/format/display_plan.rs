@@ -55,6 +55,7 @@ impl Plan {
             Plan::ExplainAst { .. } => Ok("ExplainAst".to_string()),
             Plan::ExplainSyntax { .. } => Ok("ExplainSyntax".to_string()),
             Plan::ExplainAnalyze { .. } => Ok("ExplainAnalyze".to_string()),
+            Plan::DescribeQuery { .. } => Ok("DescribeQuery".to_string()),
 
             Plan::CopyIntoTable(_) => Ok("CopyIntoTable".to_string()),
             Plan::CopyIntoLocation(_) => Ok("CopyIntoLocation".to_string()),
@@ -70,6 +71,8 @@ impl Plan {
             Plan::DropDatabase(_) => Ok("DropDatabase".to_string()),
             Plan::UndropDatabase(_) => Ok("UndropDatabase".to_string()),
             Plan::RenameDatabase(_) => Ok("RenameDatabase".to_string()),
+            Plan::BackupDatabase(_) => Ok("BackupDatabase".to_string()),
+            Plan::RestoreDatabase(_) => Ok("RestoreDatabase".to_string()),
 
             // Tables
             Plan::CreateTable(create_table) => format_create_table(create_table),
@@ -130,6 +133,7 @@ impl Plan {
             // Stages
             Plan::CreateStage(_) => Ok("CreateStage".to_string()),
             Plan::DropStage(_) => Ok("DropStage".to_string()),
+            Plan::RefreshStage(_) => Ok("RefreshStage".to_string()),
             Plan::RemoveStage(_) => Ok("RemoveStage".to_string()),
 
             // FileFormat
@@ -159,6 +163,7 @@ impl Plan {
             Plan::SetSecondaryRoles(_) => Ok("SetSecondaryRoles".to_string()),
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
             Plan::Kill(_) => Ok("Kill".to_string()),
+            Plan::ShowWarnings(_) => Ok("ShowWarnings".to_string()),
 
             Plan::CreateShareEndpoint(_) => Ok("CreateShareEndpoint".to_string()),
             Plan::ShowShareEndpoint(_) => Ok("ShowShareEndpoint".to_string()),
@@ -206,6 +211,12 @@ impl Plan {
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
             Plan::DropConnection(_) => Ok("DropConnection".to_string()),
             Plan::ShowConnections(_) => Ok("ShowConnections".to_string()),
+
+            // pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescPipe(_) => Ok("DescPipe".to_string()),
             Plan::Begin => Ok("Begin".to_string()),
             Plan::Commit => Ok("commit".to_string()),
             Plan::Abort => Ok("Abort".to_string()),
@@ -218,6 +229,9 @@ impl Plan {
 
             // Stored procedures
             Plan::ExecuteImmediate(_) => Ok("ExecuteImmediate".to_string()),
+            Plan::CreateProcedure(_) => Ok("CreateProcedure".to_string()),
+            Plan::DropProcedure(_) => Ok("DropProcedure".to_string()),
+            Plan::CallProcedure(_) => Ok("CallProcedure".to_string()),
         }
     }
 }
@@ -265,6 +279,7 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             agg_index: None,
             change_type: None,
             inverted_index: None,
+            vector_index: None,
             statistics: Default::default(),
             update_stream_columns: false,
         });
@@ -15,8 +15,10 @@
 mod bloom_index;
 mod format;
 mod metadata;
+mod plan_cache;
 #[allow(clippy::module_inception)]
 mod planner;
+mod query_text_redactor;
 mod semantic;
 
 pub mod binder;
@@ -39,6 +41,10 @@ pub use bloom_index::BloomIndexColumns;
 pub use expression_parser::*;
 pub use format::format_scalar;
 pub use metadata::*;
+pub use plan_cache::CachedTable;
+pub use plan_cache::PlanCacheItem;
+pub use plan_cache::PlanCacheKey;
+pub use plan_cache::PlanCacheManager;
 pub use planner::get_query_kind;
 pub use planner::PlanExtras;
 pub use planner::Planner;
@@ -47,5 +53,6 @@ pub use plans::ScalarExpr;
 pub use plans::DELETE_NAME;
 pub use plans::INSERT_NAME;
 pub use plans::UPDATE_NAME;
+pub use query_text_redactor::QueryTextRedactor;
 pub use semantic::*;
 pub use stream_column::*;
@@ -0,0 +1,217 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+
+use crate::optimizer::SExpr;
+use crate::plans::Aggregate;
+use crate::plans::AggregateFunction;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::ScalarItem;
+use crate::ColumnBindingBuilder;
+use crate::IndexType;
+use crate::Visibility;
+
+/// Optimize the DISTINCT aggregate functions:
+///
+/// - If `approx` is enabled, `count(distinct x)` and `uniq(x)` are computed by
+///   `approx_count_distinct(x)`.
+/// - If `rewrite` is enabled and all the aggregate functions are distinct on the same column,
+///   the distinct values are produced by an aggregate on the group items and the column,
+///   then the functions are computed without distinct on them:
+///
+/// ```text
+/// SELECT k, count(distinct x), sum(distinct x) FROM t GROUP BY k
+/// =>
+/// SELECT k, count(x), sum(x) FROM (SELECT k, x FROM t GROUP BY k, x) GROUP BY k
+/// ```
+///
+/// so the functions share the distinct values instead of keeping a set per function.
+pub struct RuleDistinctAggregateOptimizer {
+    rewrite: bool,
+    approx: bool,
+}
+
+impl RuleDistinctAggregateOptimizer {
+    pub fn new(rewrite: bool, approx: bool) -> Self {
+        RuleDistinctAggregateOptimizer { rewrite, approx }
+    }
+
+    pub fn run(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            let child = self.run(child)?;
+            children.push(Arc::new(child));
+        }
+        let s_expr = s_expr.replace_children(children);
+        if let RelOperator::Aggregate(_) = s_expr.plan.as_ref() {
+            self.optimize_aggregate(&s_expr)
+        } else {
+            Ok(s_expr)
+        }
+    }
+
+    fn optimize_aggregate(&self, s_expr: &SExpr) -> Result<SExpr> {
+        let mut aggregate: Aggregate = s_expr.plan().clone().try_into()?;
+        if aggregate.mode != AggregateMode::Initial || aggregate.grouping_sets.is_some() {
+            return Ok(s_expr.clone());
+        }
+
+        let mut rewritten = false;
+        if self.approx {
+            for item in aggregate.aggregate_functions.iter_mut() {
+                if let ScalarExpr::AggregateFunction(function) = &mut item.scalar {
+                    if is_count_distinct(function) && function.args.len() == 1 {
+                        function.func_name = "approx_count_distinct".to_string();
+                        function.distinct = false;
+                        rewritten = true;
+                    }
+                }
+            }
+        }
+
+        let child = Arc::new(s_expr.child(0)?.clone());
+        if self.rewrite {
+            if let Some(argument) = Self::shared_distinct_argument(&aggregate) {
+                return Self::build_two_level_aggregate(aggregate, argument, child);
+            }
+        }
+
+        if !rewritten {
+            return Ok(s_expr.clone());
+        }
+        Ok(SExpr::create_unary(Arc::new(aggregate.into()), child))
+    }
+
+    /// Returns the argument if there are more than one aggregate functions
+    /// and all of them are distinct on the same column.
+    fn shared_distinct_argument(aggregate: &Aggregate) -> Option<BoundColumnRef> {
+        if aggregate.aggregate_functions.len() < 2 {
+            return None;
+        }
+
+        let mut argument: Option<&BoundColumnRef> = None;
+        for item in aggregate.aggregate_functions.iter() {
+            let ScalarExpr::AggregateFunction(function) = &item.scalar else {
+                return None;
+            };
+            if !(function.distinct || is_count_distinct(function)) || function.args.len() != 1 {
+                return None;
+            }
+            let ScalarExpr::BoundColumnRef(column) = &function.args[0] else {
+                return None;
+            };
+            match argument {
+                Some(argument) if argument.column.index != column.column.index => return None,
+                _ => argument = Some(column),
+            }
+        }
+        argument.cloned()
+    }
+
+    fn build_two_level_aggregate(
+        aggregate: Aggregate,
+        argument: BoundColumnRef,
+        child: Arc<SExpr>,
+    ) -> Result<SExpr> {
+        let mut distinct_group_items = aggregate.group_items.clone();
+        distinct_group_items.push(ScalarItem {
+            index: argument.column.index,
+            scalar: ScalarExpr::BoundColumnRef(argument),
+        });
+        let distinct_aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items: distinct_group_items,
+            aggregate_functions: vec![],
+            from_distinct: false,
+            limit: None,
+            grouping_sets: None,
+        };
+
+        // The group items are evaluated by the distinct aggregate,
+        // refer to them by the columns in the outer aggregate.
+        let group_items = aggregate
+            .group_items
+            .iter()
+            .map(|item| {
+                Ok(ScalarItem {
+                    index: item.index,
+                    scalar: group_item_column(item.index, &item.scalar)?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let aggregate_functions = aggregate
+            .aggregate_functions
+            .into_iter()
+            .map(|mut item| {
+                if let ScalarExpr::AggregateFunction(function) = &mut item.scalar {
+                    if function.func_name == "uniq" || function.func_name == "count_distinct" {
+                        function.func_name = "count".to_string();
+                    }
+                    function.distinct = false;
+                }
+                item
+            })
+            .collect();
+
+        let outer_aggregate = Aggregate {
+            mode: AggregateMode::Initial,
+            group_items,
+            aggregate_functions,
+            from_distinct: false,
+            limit: None,
+            grouping_sets: None,
+        };
+
+        Ok(SExpr::create_unary(
+            Arc::new(outer_aggregate.into()),
+            Arc::new(SExpr::create_unary(
+                Arc::new(distinct_aggregate.into()),
+                child,
+            )),
+        ))
+    }
+}
+
+fn is_count_distinct(function: &AggregateFunction) -> bool {
+    (function.distinct && function.func_name == "count")
+        || function.func_name == "uniq"
+        || function.func_name == "count_distinct"
+}
+
+fn group_item_column(index: IndexType, scalar: &ScalarExpr) -> Result<ScalarExpr> {
+    if let ScalarExpr::BoundColumnRef(column) = scalar {
+        if column.column.index == index {
+            return Ok(scalar.clone());
+        }
+    }
+
+    let column = ColumnBindingBuilder::new(
+        format!("group_item_{index}"),
+        index,
+        Box::new(scalar.data_type()?),
+        Visibility::Visible,
+    )
+    .build();
+    Ok(ScalarExpr::BoundColumnRef(BoundColumnRef {
+        span: None,
+        column,
+    }))
+}
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod distinct_aggregate;
 mod normalize_aggregate;
 
+pub use distinct_aggregate::RuleDistinctAggregateOptimizer;
 pub use normalize_aggregate::RuleNormalizeAggregateOptimizer;
@@ -28,6 +28,7 @@ use super::distributed::MergeSourceOptimizer;
 use super::format::display_memo;
 use super::Memo;
 use crate::binder::MergeIntoType;
+use crate::optimizer::aggregate::RuleDistinctAggregateOptimizer;
 use crate::optimizer::aggregate::RuleNormalizeAggregateOptimizer;
 use crate::optimizer::cascades::CascadesOptimizer;
 use crate::optimizer::decorrelate::decorrelate_subquery;
@@ -249,6 +250,14 @@ pub async fn optimize_query(opt_ctx: OptimizerContext, mut s_expr: SExpr) -> Res
     // Normalize aggregate, it should be executed before RuleSplitAggregate.
     s_expr = RuleNormalizeAggregateOptimizer::new().run(&s_expr)?;
 
+    // Share the distinct values among the distinct aggregate functions.
+    let settings = opt_ctx.table_ctx.get_settings();
+    s_expr = RuleDistinctAggregateOptimizer::new(
+        settings.get_enable_distinct_aggregate_rewrite()?,
+        settings.get_enable_approx_count_distinct()?,
+    )
+    .run(&s_expr)?;
+
     // Pull up and infer filter.
     s_expr = PullUpFilterOptimizer::new(opt_ctx.metadata.clone()).run(&s_expr)?;
 
@@ -64,8 +64,8 @@ impl RuleFactory {
             RuleID::PushDownFilterSort => Ok(Box::new(RulePushDownFilterSort::new())),
             RuleID::PushDownFilterProjectSet => Ok(Box::new(RulePushDownFilterProjectSet::new())),
             RuleID::PushDownLimitUnion => Ok(Box::new(RulePushDownLimitUnion::new())),
-            RuleID::PushDownLimitScan => Ok(Box::new(RulePushDownLimitScan::new())),
-            RuleID::PushDownSortScan => Ok(Box::new(RulePushDownSortScan::new())),
+            RuleID::PushDownLimitScan => Ok(Box::new(RulePushDownLimitScan::new(metadata))),
+            RuleID::PushDownSortScan => Ok(Box::new(RulePushDownSortScan::new(metadata))),
             RuleID::PushDownLimitOuterJoin => Ok(Box::new(RulePushDownLimitOuterJoin::new())),
             RuleID::PushDownLimitEvalScalar => Ok(Box::new(RulePushDownLimitEvalScalar::new())),
             RuleID::PushDownLimitSort => {
@@ -22,6 +22,8 @@ use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 
+/// Converts the mark join under a filter on the marker, `IN` subquery to semi join and
+/// `NOT IN` subquery to anti join.
 pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<(SExpr, bool)> {
     let mut filter: Filter = s_expr.plan().clone().try_into()?;
     let mut join: Join = s_expr.child(0)?.plan().clone().try_into()?;
@@ -33,21 +35,26 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<(SExpr, bool)> {
     }
 
     let mark_index = join.marker_index.unwrap();
-    let mut find_mark_index = false;
+    let mut is_anti = None;
 
     // remove mark index filter
     for (idx, predicate) in filter.predicates.iter().enumerate() {
         match predicate {
             ScalarExpr::BoundColumnRef(col) if col.column.index == mark_index => {
-                find_mark_index = true;
+                is_anti = Some(false);
                 filter.predicates.remove(idx);
                 break;
             }
             ScalarExpr::FunctionCall(func) if func.func_name == "not" => {
-                // Check if the argument is mark index, if so, we won't convert it to semi join
+                // Check if the argument is mark index, if so, try to convert it to anti join
                 if let ScalarExpr::BoundColumnRef(col) = &func.arguments[0] {
                     if col.column.index == mark_index {
-                        return Ok((s_expr.clone(), false));
+                        if !can_convert_to_anti_join(&join) {
+                            return Ok((s_expr.clone(), false));
+                        }
+                        is_anti = Some(true);
+                        filter.predicates.remove(idx);
+                        break;
                     }
                 }
             }
@@ -55,14 +62,38 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<(SExpr, bool)> {
         }
     }
 
-    if !find_mark_index {
+    let Some(is_anti) = is_anti else {
         // To be conservative, we do not convert
         return Ok((s_expr.clone(), false));
+    };
+    // The marker is removed from the output of the join.
+    if filter
+        .predicates
+        .iter()
+        .any(|predicate| predicate.used_columns().contains(&mark_index))
+    {
+        return Ok((s_expr.clone(), false));
     }
 
-    join.join_type = match join.join_type {
-        JoinType::LeftMark => JoinType::RightSemi,
-        JoinType::RightMark => JoinType::LeftSemi,
+    join.join_type = match (&join.join_type, is_anti) {
+        (JoinType::LeftMark, false) => JoinType::RightSemi,
+        (JoinType::RightMark, false) => JoinType::LeftSemi,
+        (JoinType::RightMark, true) => {
+            join.marker_index = None;
+            // Without NULL keys, `NOT IN` is the same as `NOT EXISTS`.
+            let nullable = join
+                .left_conditions
+                .iter()
+                .chain(join.right_conditions.iter())
+                .map(|condition| condition.data_type())
+                .collect::<Result<Vec<_>>>()?
+                .iter()
+                .any(|data_type| data_type.is_nullable_or_null());
+            match nullable {
+                true => JoinType::NullAwareLeftAnti,
+                false => JoinType::LeftAnti,
+            }
+        }
         _ => unreachable!(),
     };
 
@@ -78,3 +109,12 @@ pub fn convert_mark_to_semi_join(s_expr: &SExpr) -> Result<(SExpr, bool)> {
     }
     Ok((result, true))
 }
+
+/// The null-aware anti join only supports the uncorrelated `NOT IN` subquery on a single key,
+/// whose NULL semantics depend on the whole build side.
+fn can_convert_to_anti_join(join: &Join) -> bool {
+    join.join_type == JoinType::RightMark
+        && !join.from_correlated_subquery
+        && join.non_equi_conditions.is_empty()
+        && join.left_conditions.len() == 1
+}
@@ -190,7 +190,7 @@ pub fn try_push_down_filter_join(s_expr: &SExpr) -> Result<(bool, SExpr)> {
         return Ok((false, s_expr.clone()));
     }
 
-    if !matches!(join.join_type, JoinType::Full) {
+    if !matches!(join.join_type, JoinType::Full | JoinType::NullAwareLeftAnti) {
         // Infer new predicate and push down filter.
         for (left_condition, right_condition) in join
             .left_conditions
@@ -257,6 +257,10 @@ pub fn try_push_down_filter_join(s_expr: &SExpr) -> Result<(bool, SExpr)> {
         left_push_down.extend(all_push_down.to_vec());
         right_push_down.extend(all_push_down);
     }
+    if join.join_type == JoinType::NullAwareLeftAnti {
+        // The result of null-aware anti join depends on the whole build side.
+        right_push_down.clear();
+    }
 
     let mut left_child = join_expr.child(0)?.clone();
     let mut right_child = join_expr.child(1)?.clone();
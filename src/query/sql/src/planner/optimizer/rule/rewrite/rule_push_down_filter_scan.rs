@@ -376,6 +376,12 @@ impl RulePushDownFilterScan {
 
         Ok(filtered_predicates)
     }
+
+    fn support_filter_push_down(&self, scan: &Scan) -> bool {
+        let metadata = self.metadata.read();
+        let table = metadata.table(scan.table_index).table();
+        table.push_down_capabilities().filter
+    }
 }
 
 impl Rule for RulePushDownFilterScan {
@@ -386,6 +392,9 @@ impl Rule for RulePushDownFilterScan {
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let filter: Filter = s_expr.plan().clone().try_into()?;
         let mut scan: Scan = s_expr.child(0)?.plan().clone().try_into()?;
+        if !self.support_filter_push_down(&scan) {
+            return Ok(());
+        }
 
         let add_filters = self.find_push_down_predicates(&filter.predicates, &scan)?;
 
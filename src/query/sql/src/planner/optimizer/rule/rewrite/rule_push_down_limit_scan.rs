@@ -26,6 +26,7 @@ use crate::plans::Limit;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
+use crate::MetadataRef;
 
 /// Input:  Limit
 ///           \
@@ -38,10 +39,11 @@ use crate::plans::Scan;
 pub struct RulePushDownLimitScan {
     id: RuleID,
     matchers: Vec<Matcher>,
+    metadata: MetadataRef,
 }
 
 impl RulePushDownLimitScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef) -> Self {
         Self {
             id: RuleID::PushDownLimitScan,
             matchers: vec![Matcher::MatchOp {
@@ -51,6 +53,7 @@ impl RulePushDownLimitScan {
                     children: vec![],
                 }],
             }],
+            metadata,
         }
     }
 }
@@ -65,6 +68,10 @@ impl Rule for RulePushDownLimitScan {
         if let Some(mut count) = limit.limit {
             let child = s_expr.child(0)?;
             let mut get: Scan = child.plan().clone().try_into()?;
+            let table = self.metadata.read().table(get.table_index).table();
+            if !table.push_down_capabilities().limit {
+                return Ok(());
+            }
             count += limit.offset;
             get.limit = Some(get.limit.map_or(count, |c| cmp::max(c, count)));
             let get = SExpr::create_leaf(Arc::new(RelOperator::Scan(get)));
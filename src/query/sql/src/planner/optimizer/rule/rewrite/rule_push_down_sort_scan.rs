@@ -15,17 +15,26 @@
 use std::cmp;
 use std::sync::Arc;
 
+use databend_common_catalog::plan::VectorIndexInfo;
 use databend_common_exception::Result;
+use databend_common_expression::types::F32;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::TableDataType;
 
 use crate::optimizer::extract::Matcher;
 use crate::optimizer::rule::Rule;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
 use crate::plans::RelOp;
 use crate::plans::RelOperator;
 use crate::plans::Scan;
 use crate::plans::Sort;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+use crate::ScalarExpr;
 
 /// Input:  Sort
 ///           \
@@ -35,24 +44,153 @@ use crate::plans::Sort;
 ///         Sort
 ///           \
 ///           Scan(padding order_by and limit)
+///
+/// Input:  Sort
+///           \
+///        EvalScalar
+///             \
+///            Scan
+///
+/// Output:
+///         Sort
+///           \
+///        EvalScalar
+///             \
+///            Scan(padding vector_index)
+///
+/// The second pattern matches `ORDER BY cosine_distance(column, [...]) LIMIT n`
+/// on a `VECTOR` column, the blocks can be pruned with the vector index.
 pub struct RulePushDownSortScan {
     id: RuleID,
     matchers: Vec<Matcher>,
+    metadata: MetadataRef,
 }
 
 impl RulePushDownSortScan {
-    pub fn new() -> Self {
+    pub fn new(metadata: MetadataRef) -> Self {
         Self {
             id: RuleID::PushDownSortScan,
-            matchers: vec![Matcher::MatchOp {
-                op_type: RelOp::Sort,
-                children: vec![Matcher::MatchOp {
-                    op_type: RelOp::Scan,
-                    children: vec![],
-                }],
-            }],
+            matchers: vec![
+                Matcher::MatchOp {
+                    op_type: RelOp::Sort,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Scan,
+                        children: vec![],
+                    }],
+                },
+                Matcher::MatchOp {
+                    op_type: RelOp::Sort,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::EvalScalar,
+                        children: vec![Matcher::MatchOp {
+                            op_type: RelOp::Scan,
+                            children: vec![],
+                        }],
+                    }],
+                },
+            ],
+            metadata,
         }
     }
+
+    fn apply_vector_index(
+        &self,
+        s_expr: &SExpr,
+        sort: &Sort,
+        state: &mut TransformResult,
+    ) -> Result<()> {
+        // Only the nearest neighbors, which have the smallest distances, can be pruned.
+        let (Some(limit), [item]) = (sort.limit, sort.items.as_slice()) else {
+            return Ok(());
+        };
+        if !item.asc || item.nulls_first {
+            return Ok(());
+        }
+
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let Some(scalar_item) = eval_scalar.items.iter().find(|i| i.index == item.index) else {
+            return Ok(());
+        };
+
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+        if scan.vector_index.is_some() {
+            return Ok(());
+        }
+        let Some(vector_index) = self.vector_index_info(&scan, &scalar_item.scalar, limit) else {
+            return Ok(());
+        };
+        scan.vector_index = Some(vector_index);
+
+        let scan_expr = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        let eval_scalar_expr = eval_scalar_expr.replace_children(vec![Arc::new(scan_expr)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar_expr)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn vector_index_info(
+        &self,
+        scan: &Scan,
+        scalar: &ScalarExpr,
+        limit: usize,
+    ) -> Option<VectorIndexInfo> {
+        let ScalarExpr::FunctionCall(func) = scalar else {
+            return None;
+        };
+        if !matches!(func.func_name.as_str(), "cosine_distance" | "l2_distance") {
+            return None;
+        }
+
+        let (column, constant) = match func.arguments.as_slice() {
+            [ScalarExpr::BoundColumnRef(column), constant]
+            | [constant, ScalarExpr::BoundColumnRef(column)] => (column, constant),
+            _ => return None,
+        };
+        let column_name = {
+            let metadata = self.metadata.read();
+            match metadata.column(column.column.index) {
+                ColumnEntry::BaseTableColumn(base)
+                    if base.table_index == scan.table_index
+                        && base.path_indices.is_none()
+                        && matches!(base.data_type.remove_nullable(), TableDataType::Vector(_)) =>
+                {
+                    base.column_name.clone()
+                }
+                _ => return None,
+            }
+        };
+
+        let constant = match constant {
+            ScalarExpr::CastExpr(cast) => cast.argument.as_ref(),
+            constant => constant,
+        };
+        let ScalarExpr::ConstantExpr(constant) = constant else {
+            return None;
+        };
+        let Scalar::Array(values) = &constant.value else {
+            return None;
+        };
+        let query_values = values
+            .iter()
+            .map(|value| match value {
+                ScalarRef::Number(num) => num
+                    .float_to_f64()
+                    .or_else(|| num.integer_to_i128().map(|v| v as f64)),
+                ScalarRef::Decimal(dec) => Some(dec.to_float64()),
+                _ => None,
+            })
+            .map(|value| value.map(|v| F32::from(v as f32)))
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(VectorIndexInfo {
+            column_name,
+            func_name: func.func_name.clone(),
+            query_values,
+            limit,
+        })
+    }
 }
 
 impl Rule for RulePushDownSortScan {
@@ -63,6 +201,9 @@ impl Rule for RulePushDownSortScan {
     fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
         let sort: Sort = s_expr.plan().clone().try_into()?;
         let child = s_expr.child(0)?;
+        if child.plan().rel_op() == RelOp::EvalScalar {
+            return self.apply_vector_index(s_expr, &sort, state);
+        }
         let mut get: Scan = child.plan().clone().try_into()?;
         if get.order_by.is_none() {
             get.order_by = Some(sort.items);
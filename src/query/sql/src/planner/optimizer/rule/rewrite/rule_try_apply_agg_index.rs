@@ -241,6 +241,14 @@ impl Rule for RuleTryApplyAggIndex {
     ) -> Result<()> {
         let (table_index, table_name) = self.get_table(s_expr);
         let metadata = self.metadata.read();
+        if !metadata
+            .table(table_index)
+            .table()
+            .push_down_capabilities()
+            .aggregate
+        {
+            return Ok(());
+        }
         let index_plans = metadata.get_agg_indexes(&table_name);
         if index_plans.is_none() {
             // No enterprise license or no index.
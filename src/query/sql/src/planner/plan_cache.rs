@@ -0,0 +1,442 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_expr;
+use databend_common_ast::parser::token::Token;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Range;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use derive_visitor::Drive;
+use derive_visitor::DriveMut;
+use derive_visitor::Visitor;
+use derive_visitor::VisitorMut;
+use itertools::Itertools;
+use parking_lot::RwLock;
+
+use crate::plans::Plan;
+use crate::PlanExtras;
+
+/// The max number of plans kept in the cache, the least recently used one is evicted.
+const PLAN_CACHE_CAPACITY: usize = 1024;
+
+/// Identifies a cached plan.
+///
+/// Besides the normalized SQL, everything that may change the result of binding is included:
+/// the user, the current role, catalog and database, and the changed settings.
+/// The literals are replaced by placeholders, so the queries differing only in the literals
+/// share the entry, see [`PlanCacheEntry::try_reuse`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PlanCacheKey {
+    pub tenant: String,
+    pub user: String,
+    pub role: Option<String>,
+    pub catalog: String,
+    pub database: String,
+    pub settings: String,
+    pub sql: String,
+}
+
+impl PlanCacheKey {
+    pub fn try_create(ctx: &dyn TableContext, tokens: &[Token], dialect: Dialect) -> Result<Self> {
+        let settings = ctx.get_settings();
+        let settings = settings
+            .changes()
+            .iter()
+            .map(|change| format!("{}={}", change.key(), change.value().value))
+            .sorted()
+            .join(",");
+
+        Ok(PlanCacheKey {
+            tenant: ctx.get_tenant().tenant_name().to_string(),
+            user: ctx.get_current_user()?.identity().to_string(),
+            role: ctx.get_current_role().map(|role| role.name),
+            catalog: ctx.get_current_catalog(),
+            database: ctx.get_current_database(),
+            settings,
+            sql: normalize_sql(tokens, dialect),
+        })
+    }
+}
+
+/// The number and string literals, they are replaced by `?` in the cache key.
+fn is_literal_token(token: &Token, dialect: Dialect) -> bool {
+    match token.kind {
+        TokenKind::LiteralInteger | TokenKind::LiteralFloat => true,
+        TokenKind::QuotedString => token
+            .text()
+            .chars()
+            .next()
+            .is_some_and(|quote| dialect.is_string_quote(quote)),
+        _ => false,
+    }
+}
+
+/// Joins the tokens with a single space, so the whitespaces, comments and
+/// the case of keywords don't matter.
+fn normalize_sql(tokens: &[Token], dialect: Dialect) -> String {
+    tokens
+        .iter()
+        .filter(|token| token.kind != TokenKind::EOI)
+        .map(|token| {
+            if is_literal_token(token, dialect) {
+                "?".to_string()
+            } else if token.kind.is_keyword() {
+                token.text().to_uppercase()
+            } else {
+                token.text().to_string()
+            }
+        })
+        .join(" ")
+}
+
+/// The literals of a query in the order of appearance, the values of the placeholders
+/// in [`PlanCacheKey`].
+#[derive(Clone, Debug)]
+pub struct QueryLiterals {
+    dialect: Dialect,
+    spans: Vec<Range>,
+    texts: Vec<String>,
+}
+
+impl QueryLiterals {
+    pub fn new(tokens: &[Token], dialect: Dialect) -> Self {
+        let (spans, texts) = tokens
+            .iter()
+            .filter(|token| is_literal_token(token, dialect))
+            .map(|token| (token.span, token.text().to_string()))
+            .unzip();
+        QueryLiterals {
+            dialect,
+            spans,
+            texts,
+        }
+    }
+}
+
+/// Collects the spans of the literal expressions.
+#[derive(Visitor)]
+#[visitor(Expr(enter))]
+struct LiteralSpanCollector {
+    spans: BTreeSet<Range>,
+}
+
+impl LiteralSpanCollector {
+    fn enter_expr(&mut self, expr: &Expr) {
+        if let Expr::Literal {
+            span: Some(span), ..
+        } = expr
+        {
+            self.spans.insert(*span);
+        }
+    }
+}
+
+/// Replaces the values of the literal expressions at the given spans.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter))]
+struct LiteralReplacer {
+    values: BTreeMap<Range, Literal>,
+}
+
+impl LiteralReplacer {
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        if let Expr::Literal {
+            span: Some(span),
+            value,
+        } = expr
+        {
+            if let Some(new_value) = self.values.get(span) {
+                *value = new_value.clone();
+            }
+        }
+    }
+}
+
+/// The version of a table that the cached plan is bound to.
+#[derive(Clone, Debug)]
+pub struct CachedTable {
+    pub catalog: String,
+    pub database: String,
+    pub name: String,
+    pub ident: TableIdent,
+}
+
+/// The result of a cache hit.
+pub enum PlanCacheHit {
+    /// The cached plan, the query has the same literals.
+    Plan(Plan, PlanExtras),
+    /// The cached statement with the literals of the query, to be bound again.
+    Rebind(Statement),
+}
+
+pub struct PlanCacheEntry {
+    plan: Plan,
+    extras: PlanExtras,
+    query_str: String,
+    tables: Vec<CachedTable>,
+    literals: QueryLiterals,
+    // Whether the literal is a literal expression of the statement, the others, e.g. the
+    // `LIMIT` and the arguments of the types, must be the same to reuse the entry.
+    rebindable: Vec<bool>,
+    hits: AtomicU64,
+    last_used: AtomicU64,
+}
+
+impl PlanCacheEntry {
+    /// Creates the entry of a query plan, returns None if the plan can't be reused.
+    pub fn try_create(
+        ctx: &dyn TableContext,
+        plan: &Plan,
+        extras: &PlanExtras,
+        literals: QueryLiterals,
+        query_str: String,
+    ) -> Option<Self> {
+        let Plan::Query { metadata, .. } = plan else {
+            return None;
+        };
+        // The plan contains non-deterministic functions or UDFs.
+        if !ctx.get_cacheable() {
+            return None;
+        }
+
+        let mut tables = Vec::new();
+        for entry in metadata.read().tables() {
+            let table = entry.table();
+            // The views are checked by their versions like the tables.
+            if entry.is_source_of_stage()
+                || (table.engine() != VIEW_ENGINE && !table.result_can_be_cached())
+            {
+                return None;
+            }
+            tables.push(CachedTable {
+                catalog: entry.catalog().to_string(),
+                database: entry.database().to_string(),
+                name: entry.name().to_string(),
+                ident: table.get_table_info().ident,
+            });
+        }
+
+        let mut collector = LiteralSpanCollector {
+            spans: BTreeSet::new(),
+        };
+        extras.statement.drive(&mut collector);
+        let rebindable = literals
+            .spans
+            .iter()
+            .map(|span| collector.spans.contains(span))
+            .collect();
+
+        Some(PlanCacheEntry {
+            plan: plan.clone(),
+            extras: extras.clone(),
+            query_str,
+            tables,
+            literals,
+            rebindable,
+            hits: AtomicU64::new(0),
+            last_used: AtomicU64::new(0),
+        })
+    }
+
+    pub fn query_str(&self) -> &str {
+        &self.query_str
+    }
+
+    pub fn format(&self) -> Option<String> {
+        self.extras.format.clone()
+    }
+
+    /// Reuses the entry if none of the tables and views has been changed since the plan
+    /// was built. The versions are read from meta, so the changes made by the other nodes
+    /// are found as well.
+    ///
+    /// The plan is returned if the query has the same literals, every plan gets its own copy
+    /// of the metadata. Otherwise, the statement with the literals of the query is returned
+    /// to be bound again, the literals that are not literal expressions must be the same.
+    pub async fn try_reuse(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        literals: &QueryLiterals,
+    ) -> Result<Option<PlanCacheHit>> {
+        for table in self.tables.iter() {
+            let current = ctx
+                .get_table(&table.catalog, &table.database, &table.name)
+                .await?;
+            if current.get_table_info().ident != table.ident {
+                return Ok(None);
+            }
+        }
+
+        if literals.texts != self.literals.texts {
+            return Ok(self.rebind(literals)?.map(|stmt| {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                PlanCacheHit::Rebind(stmt)
+            }));
+        }
+
+        let metadata = Arc::new(RwLock::new(self.extras.metadata.read().clone()));
+        let mut plan = self.plan.clone();
+        if let Plan::Query {
+            metadata: plan_metadata,
+            ..
+        } = &mut plan
+        {
+            *plan_metadata = metadata.clone();
+        }
+        let extras = PlanExtras {
+            metadata,
+            format: self.extras.format.clone(),
+            statement: self.extras.statement.clone(),
+        };
+
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Ok(Some(PlanCacheHit::Plan(plan, extras)))
+    }
+
+    fn rebind(&self, literals: &QueryLiterals) -> Result<Option<Statement>> {
+        let mut values = BTreeMap::new();
+        for (i, (old, new)) in self.literals.texts.iter().zip(&literals.texts).enumerate() {
+            if old == new {
+                continue;
+            }
+            if !self.rebindable[i] {
+                return Ok(None);
+            }
+            let tokens = tokenize_sql(new)?;
+            let Expr::Literal { value, .. } = parse_expr(&tokens, literals.dialect)? else {
+                return Ok(None);
+            };
+            values.insert(self.literals.spans[i], value);
+        }
+
+        let mut stmt = self.extras.statement.clone();
+        stmt.drive_mut(&mut LiteralReplacer { values });
+        Ok(Some(stmt))
+    }
+}
+
+/// A snapshot of an entry, for `system.plan_cache`.
+pub struct PlanCacheItem {
+    pub key: PlanCacheKey,
+    pub tables: Vec<CachedTable>,
+    pub hits: u64,
+}
+
+/// The process level cache of the optimized query plans.
+///
+/// A plan is checked against the versions of its tables and views in meta before being
+/// reused. The plans with UDFs, stages or non-deterministic functions are not cached.
+/// The DDL on this node also invalidates the whole cache, which drops the outdated plans
+/// earlier and covers the objects without versions.
+pub struct PlanCacheManager {
+    entries: RwLock<HashMap<PlanCacheKey, Arc<PlanCacheEntry>>>,
+    // Increased by each invalidation, a plan built before an invalidation is not cached.
+    generation: AtomicU64,
+    tick: AtomicU64,
+}
+
+impl PlanCacheManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(PlanCacheManager {
+            entries: RwLock::new(HashMap::new()),
+            generation: AtomicU64::new(0),
+            tick: AtomicU64::new(0),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PlanCacheManager> {
+        GlobalInstance::get()
+    }
+
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn get(&self, key: &PlanCacheKey) -> Option<Arc<PlanCacheEntry>> {
+        let entry = self.entries.read().get(key).cloned()?;
+        entry
+            .last_used
+            .store(self.tick.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
+        Some(entry)
+    }
+
+    /// Caches the plan if there is no invalidation since `generation`.
+    pub fn insert(&self, key: PlanCacheKey, entry: PlanCacheEntry, generation: u64) {
+        let mut entries = self.entries.write();
+        if self.generation() != generation {
+            return;
+        }
+
+        if entries.len() >= PLAN_CACHE_CAPACITY && !entries.contains_key(&key) {
+            let lru = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                .map(|(key, _)| key.clone());
+            if let Some(lru) = lru {
+                entries.remove(&lru);
+            }
+        }
+
+        // The entry rebound with new literals replaces the old one, keep counting the hits.
+        if let Some(old) = entries.get(&key) {
+            entry
+                .hits
+                .store(old.hits.load(Ordering::Relaxed), Ordering::Relaxed);
+        }
+        entry
+            .last_used
+            .store(self.tick.fetch_add(1, Ordering::Relaxed), Ordering::Relaxed);
+        entries.insert(key, Arc::new(entry));
+    }
+
+    pub fn remove(&self, key: &PlanCacheKey) {
+        self.entries.write().remove(key);
+    }
+
+    /// Drops all the cached plans, called after DDL.
+    pub fn invalidate(&self) {
+        let mut entries = self.entries.write();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        entries.clear();
+    }
+
+    pub fn items(&self) -> Vec<PlanCacheItem> {
+        self.entries
+            .read()
+            .iter()
+            .map(|(key, entry)| PlanCacheItem {
+                key: key.clone(),
+                tables: entry.tables.clone(),
+                hits: entry.hits.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+}
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Hint;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_leading_hint;
 use databend_common_ast::parser::parse_raw_insert_stmt;
 use databend_common_ast::parser::parse_raw_replace_stmt;
 use databend_common_ast::parser::parse_sql;
@@ -35,13 +38,21 @@ use log::info;
 use log::warn;
 use parking_lot::RwLock;
 
+use super::plan_cache::PlanCacheEntry;
+use super::plan_cache::PlanCacheHit;
+use super::plan_cache::PlanCacheKey;
+use super::plan_cache::PlanCacheManager;
+use super::plan_cache::QueryLiterals;
+use super::query_text_redactor::QueryTextRedactor;
 use super::semantic::AggregateRewriter;
 use super::semantic::DistinctToGroupBy;
+use super::semantic::DynamicIdentifierResolver;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::Insert;
 use crate::plans::InsertInputSource;
 use crate::plans::Plan;
+use crate::BindContext;
 use crate::Binder;
 use crate::Metadata;
 use crate::MetadataRef;
@@ -95,6 +106,13 @@ impl Planner {
             false => sql.to_string(),
         };
 
+        // The hint at the beginning of the SQL applies to the whole statement, e.g.
+        // `/*+ SET_VAR(max_threads=4) */ SELECT ...`, it's skipped by the tokenizer below.
+        // The invalid hints are ignored with a warning to the client by `opt_hints_set_var`.
+        if let Some(hint) = parse_leading_hint(&final_sql, sql_dialect)? {
+            self.apply_leading_hint(&hint).await?;
+        }
+
         // Step 1: Tokenize the SQL.
         let mut tokenizer = Tokenizer::new(&final_sql).peekable();
 
@@ -104,18 +122,20 @@ impl Planner {
         let first_token = tokenizer
             .peek()
             .and_then(|token| Some(token.as_ref().ok()?.kind));
-        let is_insert_stmt = matches!(first_token, Some(TokenKind::INSERT)) && {
-            let mut tokenizer = Tokenizer::new(&final_sql);
-            tokenizer.next_chunk::<3>().is_ok_and(|first_three_tokens| {
-                matches!(first_token, Some(TokenKind::INSERT))
-                    && !first_three_tokens.iter().any(|token| {
-                        matches!(
-                            token.as_ref().map(|t| t.kind),
-                            Ok(TokenKind::ALL) | Ok(TokenKind::FIRST)
-                        )
-                    })
-            })
-        };
+        let is_insert_stmt = matches!(first_token, Some(TokenKind::INSERT))
+            && {
+                let mut tokenizer = Tokenizer::new(&final_sql);
+                tokenizer.next_chunk::<3>().is_ok_and(|first_three_tokens| {
+                    matches!(first_token, Some(TokenKind::INSERT))
+                        && !first_three_tokens.iter().any(|token| {
+                            matches!(
+                                token.as_ref().map(|t| t.kind),
+                                Ok(TokenKind::ALL) | Ok(TokenKind::FIRST)
+                            )
+                        })
+                })
+            }
+            && !has_returning_clause(&final_sql);
         let is_replace_stmt = matches!(first_token, Some(TokenKind::REPLACE));
         let is_insert_or_replace_stmt = is_insert_stmt || is_replace_stmt;
         let mut tokens: Vec<Token> = if is_insert_or_replace_stmt {
@@ -130,6 +150,64 @@ impl Planner {
             (&mut tokenizer).collect::<Result<_>>()?
         };
 
+        // Reuse the plan of the query that differs only in the literals if it's still valid.
+        let plan_cache_key = match !is_insert_or_replace_stmt
+            && sql_dialect != Dialect::PRQL
+            && settings.get_enable_plan_cache()?
+            && !self.ctx.txn_mgr().lock().is_active()
+        {
+            true => {
+                let key = PlanCacheKey::try_create(self.ctx.as_ref(), &tokens, sql_dialect)?;
+                let literals = QueryLiterals::new(&tokens, sql_dialect);
+                Some((key, literals, PlanCacheManager::instance().generation()))
+            }
+            false => None,
+        };
+        let redactor = QueryTextRedactor::create(&settings);
+        if let Some((key, literals, generation)) = &plan_cache_key {
+            let plan_cache = PlanCacheManager::instance();
+            if let Some(entry) = plan_cache.get(key) {
+                match entry.try_reuse(&self.ctx, literals).await? {
+                    Some(PlanCacheHit::Plan(plan, extras)) => {
+                        self.apply_statement_priority(&extras.statement)?;
+                        self.ctx.attach_query_str(
+                            get_query_kind(&extras.statement),
+                            entry.query_str().to_string(),
+                        );
+                        info!("plan cache hit, time used: {:?}", start.elapsed());
+                        return Ok((plan, extras));
+                    }
+                    Some(PlanCacheHit::Rebind(stmt)) => {
+                        // The statement is already parsed and rewritten, only bind the
+                        // literals of this query and optimize it again.
+                        self.apply_statement_priority(&stmt)?;
+                        let query_str = redactor.redact_statement(&stmt);
+                        let (plan, metadata) = self.bind_and_optimize(&stmt, &query_str).await?;
+                        let extras = PlanExtras {
+                            metadata,
+                            format: entry.format(),
+                            statement: stmt,
+                        };
+                        if let Some(entry) = PlanCacheEntry::try_create(
+                            self.ctx.as_ref(),
+                            &plan,
+                            &extras,
+                            literals.clone(),
+                            query_str,
+                        ) {
+                            plan_cache.insert(key.clone(), entry, *generation);
+                        }
+                        info!(
+                            "plan cache hit with new literals, time used: {:?}",
+                            start.elapsed()
+                        );
+                        return Ok((plan, extras));
+                    }
+                    None => plan_cache.remove(key),
+                }
+            }
+        }
+
         loop {
             let res = async {
                 // Step 2: Parse the SQL.
@@ -147,35 +225,12 @@ impl Planner {
                     return Err(ErrorCode::SyntaxException("convert prql to sql failed."));
                 }
 
+                DynamicIdentifierResolver::resolve(&mut stmt, sql_dialect)?;
                 self.replace_stmt(&mut stmt);
+                self.apply_statement_priority(&stmt)?;
 
-                // Step 3: Bind AST with catalog, and generate a pure logical SExpr
-                let metadata = Arc::new(RwLock::new(Metadata::default()));
-                let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
-                let binder = Binder::new(
-                    self.ctx.clone(),
-                    CatalogManager::instance(),
-                    name_resolution_ctx,
-                    metadata.clone(),
-                );
-
-                // Indicate binder there is no need to collect column statistics for the binding table.
-                self.ctx
-                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-                let plan = binder.bind(&stmt).await?;
-                // attach again to avoid the query kind is overwritten by the subquery
-                self.ctx
-                    .attach_query_str(get_query_kind(&stmt), stmt.to_mask_sql());
-
-                // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
-                let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
-                    .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
-                    .with_enable_join_reorder(unsafe {
-                        !self.ctx.get_settings().get_disable_join_reorder()?
-                    })
-                    .with_enable_dphyp(self.ctx.get_settings().get_enable_dphyp()?);
-
-                let optimized_plan = optimize(opt_ctx, plan).await?;
+                let query_str = redactor.redact_statement(&stmt);
+                let (optimized_plan, metadata) = self.bind_and_optimize(&stmt, &query_str).await?;
                 Ok((optimized_plan, PlanExtras {
                     metadata,
                     format,
@@ -220,17 +275,96 @@ impl Planner {
                 };
             } else {
                 info!("logical plan built, time used: {:?}", start.elapsed());
+                if let (Some((key, literals, generation)), Ok((plan, extras))) =
+                    (plan_cache_key, &res)
+                {
+                    let query_str = redactor.redact_statement(&extras.statement);
+                    if let Some(entry) = PlanCacheEntry::try_create(
+                        self.ctx.as_ref(),
+                        plan,
+                        extras,
+                        literals,
+                        query_str,
+                    ) {
+                        PlanCacheManager::instance().insert(key, entry, generation);
+                    }
+                }
                 return res;
             }
         }
     }
 
+    // Step 3 and 4 of `plan_sql`, binds the parsed statement and optimizes the plan.
+    async fn bind_and_optimize(
+        &self,
+        stmt: &Statement,
+        query_str: &str,
+    ) -> Result<(Plan, MetadataRef)> {
+        // Step 3: Bind AST with catalog, and generate a pure logical SExpr
+        let settings = self.ctx.get_settings();
+        let metadata = Arc::new(RwLock::new(Metadata::default()));
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            metadata.clone(),
+        );
+
+        // Indicate binder there is no need to collect column statistics for the binding table.
+        self.ctx
+            .attach_query_str(get_query_kind(stmt), query_str.to_string());
+        let plan = binder.bind(stmt).await?;
+        // attach again to avoid the query kind is overwritten by the subquery
+        self.ctx
+            .attach_query_str(get_query_kind(stmt), query_str.to_string());
+
+        // Step 4: Optimize the SExpr with optimizers, and generate optimized physical SExpr
+        let opt_ctx = OptimizerContext::new(self.ctx.clone(), metadata.clone())
+            .with_enable_distributed_optimization(!self.ctx.get_cluster().is_empty())
+            .with_enable_join_reorder(unsafe { !settings.get_disable_join_reorder()? })
+            .with_enable_dphyp(settings.get_enable_dphyp()?);
+
+        let optimized_plan = optimize(opt_ctx, plan).await?;
+        Ok((optimized_plan, metadata))
+    }
+
+    // set the query level settings by the hint
+    async fn apply_leading_hint(&self, hint: &Hint) -> Result<()> {
+        let settings = self.ctx.get_settings();
+        let name_resolution_ctx = NameResolutionContext::try_from(settings.as_ref())?;
+        let mut binder = Binder::new(
+            self.ctx.clone(),
+            CatalogManager::instance(),
+            name_resolution_ctx,
+            Arc::new(RwLock::new(Metadata::default())),
+        );
+        binder
+            .opt_hints_set_var(&mut BindContext::new(), hint)
+            .await
+    }
+
+    // set the query priority by `PRIORITY LOW | NORMAL | HIGH <statement>`
+    fn apply_statement_priority(&self, statement: &Statement) -> Result<()> {
+        if let Statement::WithPriority { priority, .. } = statement {
+            let priority = priority.to_string().to_lowercase();
+            self.ctx
+                .get_settings()
+                .set_query_settings(&HashMap::from([("query_priority".to_string(), priority)]))?;
+        }
+        Ok(())
+    }
+
     fn add_max_rows_limit(&self, statement: &mut Statement) {
         let max_rows = self.ctx.get_settings().get_max_result_rows().unwrap();
         if max_rows == 0 {
             return;
         }
 
+        let statement = match statement {
+            Statement::WithPriority { stmt, .. } => stmt.as_mut(),
+            statement => statement,
+        };
         if let Statement::Query(query) = statement {
             if query.limit.is_empty() {
                 query.limit = vec![Expr::Literal {
@@ -251,6 +385,7 @@ impl Planner {
 
 pub fn get_query_kind(stmt: &Statement) -> QueryKind {
     match stmt {
+        Statement::WithPriority { stmt, .. } => get_query_kind(stmt),
         Statement::Query { .. } => QueryKind::Query,
         Statement::CopyIntoTable(_) => QueryKind::CopyIntoTable,
         Statement::CopyIntoLocation(_) => QueryKind::CopyIntoLocation,
@@ -264,3 +399,14 @@ pub fn get_query_kind(stmt: &Statement) -> QueryKind {
         _ => QueryKind::Other,
     }
 }
+
+/// `INSERT ... RETURNING` has to be parsed as a whole instead of leaving the values
+/// unparsed, the whole SQL is only tokenized if it contains the keyword.
+fn has_returning_clause(sql: &str) -> bool {
+    sql.as_bytes()
+        .windows("RETURNING".len())
+        .any(|w| w.eq_ignore_ascii_case(b"RETURNING"))
+        && Tokenizer::new(sql)
+            .map_while(|token| token.ok())
+            .any(|token| token.kind == TokenKind::RETURNING)
+}
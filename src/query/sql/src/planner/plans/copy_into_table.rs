@@ -33,8 +33,10 @@ use databend_common_expression::Scalar;
 use databend_common_meta_app::principal::COPY_MAX_FILES_COMMIT_MSG;
 use databend_common_meta_app::principal::COPY_MAX_FILES_PER_COMMIT;
 use databend_common_meta_app::schema::CatalogInfo;
+use databend_common_meta_types::MatchSeq;
 use databend_common_metrics::storage::*;
 use databend_common_storage::init_stage_operator;
+use databend_common_users::UserApiProvider;
 use log::info;
 
 use crate::plans::Plan;
@@ -147,6 +149,24 @@ impl CopyIntoTablePlan {
 
         let num_all_files = all_source_file_infos.len();
 
+        let quota_api = UserApiProvider::instance().tenant_quota_api(&ctx.get_tenant());
+        let max_copy_file_size = quota_api
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data
+            .max_copy_file_size;
+        if max_copy_file_size != 0 {
+            if let Some(file) = all_source_file_infos
+                .iter()
+                .find(|f| f.size > max_copy_file_size)
+            {
+                return Err(ErrorCode::TenantQuotaExceeded(format!(
+                    "Max copy file size quota exceeded: {}, file {} is {} bytes",
+                    max_copy_file_size, file.path, file.size
+                )));
+            }
+        }
+
         let end_get_all_source = Instant::now();
         let cost_get_all_files = end_get_all_source.duration_since(start).as_millis();
         metrics_inc_copy_collect_files_get_all_source_files_milliseconds(cost_get_all_files as u64);
@@ -12,7 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::CreateDatabaseReq;
 use databend_common_meta_app::schema::CreateOption;
@@ -109,6 +114,55 @@ impl From<UndropDatabasePlan> for UndropDatabaseReq {
     }
 }
 
+/// Backup.
+#[derive(Clone, Debug)]
+pub struct BackupDatabasePlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    /// The stage and the path in it to keep the backups of the database.
+    pub stage: Box<StageInfo>,
+    pub path: String,
+    pub incremental: bool,
+}
+
+impl BackupDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("backup_id", DataType::String),
+            DataField::new("tables", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("views", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("files", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("copied_files", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("copied_bytes", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
+/// Restore.
+#[derive(Clone, Debug)]
+pub struct RestoreDatabasePlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    /// The database to create, it may differ from the name of the backed up database.
+    pub database: String,
+    pub stage: Box<StageInfo>,
+    pub path: String,
+    /// Restore the latest backup if not specified.
+    pub backup_id: Option<String>,
+}
+
+impl RestoreDatabasePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("backup_id", DataType::String),
+            DataField::new("tables", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("views", DataType::Number(NumberDataType::UInt64)),
+            DataField::new("grants", DataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+}
+
 /// Use.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UseDatabasePlan {
@@ -19,6 +19,7 @@ mod database;
 mod file_format;
 mod index;
 mod notification;
+mod pipe;
 mod procedure;
 mod stage;
 mod stream;
@@ -35,6 +36,7 @@ pub use database::*;
 pub use file_format::*;
 pub use index::*;
 pub use notification::*;
+pub use pipe::*;
 pub use procedure::*;
 pub use stage::*;
 pub use stream::*;
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipePlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub copy_stmt: String,
+    pub auto_ingest: bool,
+    pub comment: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipePlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AlterPipeAction {
+    Set {
+        execution_paused: Option<bool>,
+        comment: Option<String>,
+    },
+    /// Queues the files of the stage that are not loaded yet and loads them.
+    Refresh {
+        prefix: Option<String>,
+        modified_after: Option<DateTime<Utc>>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterPipePlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+    pub action: AlterPipeAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescPipePlan {
+    pub tenant: Tenant,
+    pub name: String,
+}
+
+impl DescPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![
+            DataField::new("name", DataType::String),
+            DataField::new("definition", DataType::String),
+            DataField::new("auto_ingest", DataType::Boolean),
+            DataField::new("execution_paused", DataType::Boolean),
+            DataField::new("comment", DataType::String),
+            DataField::new("owner", DataType::String),
+            DataField::new("created_on", DataType::Timestamp),
+        ])
+    }
+}
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::Expr;
 use databend_common_expression::types::DataType;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
+use databend_common_meta_app::principal::ProcedureExecuteAs;
+use databend_common_meta_app::principal::ProcedureInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ExecuteImmediatePlan {
@@ -27,3 +32,36 @@ impl ExecuteImmediatePlan {
         DataSchemaRefExt::create(vec![DataField::new("Result", DataType::String)])
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateProcedurePlan {
+    pub create_option: CreateOption,
+    pub tenant: Tenant,
+    pub name: String,
+    pub arg_names: Vec<String>,
+    pub arg_types: Vec<String>,
+    pub return_type: String,
+    pub script: String,
+    pub execute_as: ProcedureExecuteAs,
+    pub comment: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropProcedurePlan {
+    pub if_exists: bool,
+    pub tenant: Tenant,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallProcedurePlan {
+    pub procedure: ProcedureInfo,
+    /// The argument expressions, one for each argument of the procedure.
+    pub args: Vec<Expr>,
+}
+
+impl CallProcedurePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![DataField::new("Result", DataType::String)])
+    }
+}
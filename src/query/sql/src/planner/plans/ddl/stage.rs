@@ -32,6 +32,13 @@ pub struct DropStagePlan {
     pub name: String,
 }
 
+/// Refresh the directory of a stage.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshStagePlan {
+    pub tenant: Tenant,
+    pub stage: StageInfo,
+}
+
 /// Remove.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct RemoveStagePlan {
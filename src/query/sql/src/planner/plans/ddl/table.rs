@@ -90,6 +90,8 @@ pub struct DropTablePlan {
     /// The table name
     pub table: String,
     pub all: bool,
+    /// Drop the views that refer to the table as well.
+    pub cascade: bool,
 }
 
 impl DropTablePlan {
@@ -218,6 +220,7 @@ pub enum OptimizeTableAction {
     Purge(Option<NavigationPoint>),
     CompactBlocks,
     CompactSegments,
+    Tier,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
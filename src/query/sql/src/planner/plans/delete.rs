@@ -12,7 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+
 use crate::optimizer::SExpr;
+use crate::plans::Returning;
 use crate::plans::ScalarExpr;
 use crate::ColumnSet;
 use crate::IndexType;
@@ -35,4 +41,14 @@ pub struct DeletePlan {
     pub metadata: MetadataRef,
     pub selection: Option<ScalarExpr>,
     pub subquery_desc: Vec<SubqueryDesc>,
+    pub returning: Option<Returning>,
+}
+
+impl DeletePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        match &self.returning {
+            Some(returning) => returning.schema.clone(),
+            None => Arc::new(DataSchema::empty()),
+        }
+    }
 }
@@ -26,6 +26,7 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::Plan;
+use crate::plans::Returning;
 
 #[derive(Clone, Debug, EnumAsInner)]
 pub enum InsertInputSource {
@@ -63,6 +64,7 @@ pub struct Insert {
     // it should be provided as some `table_info`.
     // otherwise, the table being inserted will be resolved by using `catalog`.`database`.`table`
     pub table_info: Option<TableInfo>,
+    pub returning: Option<Returning>,
 }
 
 impl PartialEq for Insert {
@@ -61,6 +61,10 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Null-aware Anti Join is used to process `NOT IN` subquery, a probe row is returned
+    /// only if the build side is empty, or neither the probe key nor any build key is NULL
+    /// and there is no match.
+    NullAwareLeftAnti,
 }
 
 impl JoinType {
@@ -138,6 +142,9 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::NullAwareLeftAnti => {
+                write!(f, "NULL AWARE LEFT ANTI")
+            }
         }
     }
 }
@@ -484,7 +491,10 @@ impl Operator for Join {
             }
             JoinType::LeftSemi => f64::min(left_cardinality, inner_join_cardinality),
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
-            JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
+            JoinType::LeftSingle
+            | JoinType::RightMark
+            | JoinType::LeftAnti
+            | JoinType::NullAwareLeftAnti => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
@@ -529,7 +539,19 @@ impl Operator for Join {
             return Ok(required);
         }
 
+        // The null-aware anti join needs the whole build side to know if it's empty
+        // or contains NULL, so the build side is never shuffled.
+        if self.join_type == JoinType::NullAwareLeftAnti {
+            if child_index == 1 {
+                required.distribution = Distribution::Broadcast;
+            } else {
+                required.distribution = Distribution::Any;
+            }
+            return Ok(required);
+        }
+
         // Try to use broadcast join
+        let settings = ctx.get_settings();
         if !matches!(
             self.join_type,
             JoinType::Right
@@ -537,19 +559,24 @@ impl Operator for Join {
                 | JoinType::RightAnti
                 | JoinType::RightSemi
                 | JoinType::LeftMark
-        ) {
+        ) && !settings.get_enforce_shuffle_join()?
+        {
             let left_stat_info = rel_expr.derive_cardinality_child(0)?;
             let right_stat_info = rel_expr.derive_cardinality_child(1)?;
             // The broadcast join is cheaper than the hash join when one input is at least (n − 1)× larger than the other
             // where n is the number of servers in the cluster.
-            let broadcast_join_threshold = if ctx.get_settings().get_prefer_broadcast_join()? {
+            let broadcast_join_threshold = if settings.get_prefer_broadcast_join()? {
                 (ctx.get_cluster().nodes.len() - 1) as f64
             } else {
                 // Use a very large value to prevent broadcast join.
                 1000.0
             };
+            // A small enough build side is cheap to broadcast whatever the probe side is.
+            let build_rows_threshold = settings.get_broadcast_join_build_rows_threshold()?;
             if right_stat_info.cardinality * broadcast_join_threshold < left_stat_info.cardinality
-                || ctx.get_settings().get_enforce_broadcast_join()?
+                || (build_rows_threshold != 0
+                    && right_stat_info.cardinality <= build_rows_threshold as f64)
+                || settings.get_enforce_broadcast_join()?
             {
                 if child_index == 1 {
                     required.distribution = Distribution::Broadcast;
@@ -578,7 +605,11 @@ impl Operator for Join {
     ) -> Result<Vec<Vec<RequiredProperty>>> {
         let mut children_required = vec![];
 
-        if self.join_type != JoinType::Cross && !ctx.get_settings().get_enforce_broadcast_join()? {
+        if !matches!(
+            self.join_type,
+            JoinType::Cross | JoinType::NullAwareLeftAnti
+        ) && !ctx.get_settings().get_enforce_broadcast_join()?
+        {
             // (Hash, Hash)
             children_required.extend(
                 self.left_conditions
@@ -605,7 +636,12 @@ impl Operator for Join {
                 | JoinType::RightSemi
                 | JoinType::LeftMark
                 | JoinType::RightSingle
-        ) {
+        ) && (!ctx.get_settings().get_enforce_shuffle_join()?
+            || matches!(
+                self.join_type,
+                JoinType::Cross | JoinType::NullAwareLeftAnti
+            ))
+        {
             // (Any, Broadcast)
             let left_distribution = Distribution::Any;
             let right_distribution = Distribution::Broadcast;
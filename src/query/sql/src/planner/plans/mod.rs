@@ -41,11 +41,13 @@ mod presign;
 mod project_set;
 mod recluster_table;
 mod replace;
+mod returning;
 mod revert_table;
 mod scalar_expr;
 mod scan;
 mod setting;
 pub mod share;
+mod show_warnings;
 mod sort;
 mod union_all;
 mod update;
@@ -85,11 +87,13 @@ pub use presign::*;
 pub use project_set::*;
 pub use recluster_table::ReclusterTablePlan;
 pub use replace::Replace;
+pub use returning::Returning;
 pub use revert_table::RevertTablePlan;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use setting::*;
 pub use share::*;
+pub use show_warnings::ShowWarningsPlan;
 pub use sort::*;
 pub use udf::*;
 pub use union_all::UnionAll;
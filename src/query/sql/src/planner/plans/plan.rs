@@ -31,6 +31,7 @@ use crate::plans::AddTableColumnPlan;
 use crate::plans::AlterNetworkPolicyPlan;
 use crate::plans::AlterNotificationPlan;
 use crate::plans::AlterPasswordPolicyPlan;
+use crate::plans::AlterPipePlan;
 use crate::plans::AlterShareTenantsPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AlterTaskPlan;
@@ -39,6 +40,8 @@ use crate::plans::AlterUserPlan;
 use crate::plans::AlterViewPlan;
 use crate::plans::AlterVirtualColumnPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::BackupDatabasePlan;
+use crate::plans::CallProcedurePlan;
 use crate::plans::CopyIntoTableMode;
 use crate::plans::CopyIntoTablePlan;
 use crate::plans::CreateCatalogPlan;
@@ -50,6 +53,8 @@ use crate::plans::CreateIndexPlan;
 use crate::plans::CreateNetworkPolicyPlan;
 use crate::plans::CreateNotificationPlan;
 use crate::plans::CreatePasswordPolicyPlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::CreateProcedurePlan;
 use crate::plans::CreateRolePlan;
 use crate::plans::CreateShareEndpointPlan;
 use crate::plans::CreateSharePlan;
@@ -68,6 +73,7 @@ use crate::plans::DescDatamaskPolicyPlan;
 use crate::plans::DescNetworkPolicyPlan;
 use crate::plans::DescNotificationPlan;
 use crate::plans::DescPasswordPolicyPlan;
+use crate::plans::DescPipePlan;
 use crate::plans::DescSharePlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DescribeTaskPlan;
@@ -81,6 +87,8 @@ use crate::plans::DropIndexPlan;
 use crate::plans::DropNetworkPolicyPlan;
 use crate::plans::DropNotificationPlan;
 use crate::plans::DropPasswordPolicyPlan;
+use crate::plans::DropPipePlan;
+use crate::plans::DropProcedurePlan;
 use crate::plans::DropRolePlan;
 use crate::plans::DropShareEndpointPlan;
 use crate::plans::DropSharePlan;
@@ -111,6 +119,7 @@ use crate::plans::OptimizeTablePlan;
 use crate::plans::PresignPlan;
 use crate::plans::ReclusterTablePlan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshStagePlan;
 use crate::plans::RefreshTableIndexPlan;
 use crate::plans::RefreshVirtualColumnPlan;
 use crate::plans::RemoveStagePlan;
@@ -118,6 +127,7 @@ use crate::plans::RenameDatabasePlan;
 use crate::plans::RenameTableColumnPlan;
 use crate::plans::RenameTablePlan;
 use crate::plans::Replace;
+use crate::plans::RestoreDatabasePlan;
 use crate::plans::RevertTablePlan;
 use crate::plans::RevokePrivilegePlan;
 use crate::plans::RevokeRolePlan;
@@ -139,6 +149,7 @@ use crate::plans::ShowRolesPlan;
 use crate::plans::ShowShareEndpointPlan;
 use crate::plans::ShowSharesPlan;
 use crate::plans::ShowTasksPlan;
+use crate::plans::ShowWarningsPlan;
 use crate::plans::TruncateTablePlan;
 use crate::plans::UnSettingPlan;
 use crate::plans::UndropDatabasePlan;
@@ -178,6 +189,9 @@ pub enum Plan {
     ExplainAnalyze {
         plan: Box<Plan>,
     },
+    DescribeQuery {
+        plan: Box<Plan>,
+    },
 
     // Call is rewrite into Query
     // Call(Box<CallPlan>),
@@ -193,6 +207,8 @@ pub enum Plan {
     DropDatabase(Box<DropDatabasePlan>),
     UndropDatabase(Box<UndropDatabasePlan>),
     RenameDatabase(Box<RenameDatabasePlan>),
+    BackupDatabase(Box<BackupDatabasePlan>),
+    RestoreDatabase(Box<RestoreDatabasePlan>),
     UseDatabase(Box<UseDatabasePlan>),
 
     // Tables
@@ -285,6 +301,7 @@ pub enum Plan {
     // Stages
     CreateStage(Box<CreateStagePlan>),
     DropStage(Box<DropStagePlan>),
+    RefreshStage(Box<RefreshStagePlan>),
     RemoveStage(Box<RemoveStagePlan>),
 
     // Connection
@@ -293,6 +310,12 @@ pub enum Plan {
     DropConnection(Box<DropConnectionPlan>),
     ShowConnections(Box<ShowConnectionsPlan>),
 
+    // Pipe
+    CreatePipe(Box<CreatePipePlan>),
+    AlterPipe(Box<AlterPipePlan>),
+    DropPipe(Box<DropPipePlan>),
+    DescPipe(Box<DescPipePlan>),
+
     // Presign
     Presign(Box<PresignPlan>),
 
@@ -300,6 +323,7 @@ pub enum Plan {
     SetVariable(Box<SettingPlan>),
     UnSetVariable(Box<UnSettingPlan>),
     Kill(Box<KillPlan>),
+    ShowWarnings(Box<ShowWarningsPlan>),
 
     // Share
     CreateShareEndpoint(Box<CreateShareEndpointPlan>),
@@ -354,6 +378,9 @@ pub enum Plan {
 
     // Stored procedures
     ExecuteImmediate(Box<ExecuteImmediatePlan>),
+    CreateProcedure(Box<CreateProcedurePlan>),
+    DropProcedure(Box<DropProcedurePlan>),
+    CallProcedure(Box<CallProcedurePlan>),
 }
 
 #[derive(Clone, Debug)]
@@ -362,6 +389,7 @@ pub enum RewriteKind {
     ShowMetrics,
     ShowProcessList,
     ShowEngines,
+    ShowQuotas,
     ShowIndexes,
 
     ShowLocks,
@@ -433,8 +461,15 @@ impl Plan {
             | Plan::ExplainAnalyze { .. } => {
                 DataSchemaRefExt::create(vec![DataField::new("explain", DataType::String)])
             }
+            Plan::DescribeQuery { .. } => DataSchemaRefExt::create(vec![
+                DataField::new("Field", DataType::String),
+                DataField::new("Type", DataType::String),
+                DataField::new("Null", DataType::String),
+            ]),
             Plan::ShowCreateCatalog(plan) => plan.schema(),
             Plan::ShowCreateDatabase(plan) => plan.schema(),
+            Plan::BackupDatabase(plan) => plan.schema(),
+            Plan::RestoreDatabase(plan) => plan.schema(),
             Plan::ShowCreateTable(plan) => plan.schema(),
             Plan::DescribeTable(plan) => plan.schema(),
             Plan::VacuumTable(plan) => plan.schema(),
@@ -443,9 +478,15 @@ impl Plan {
             Plan::ExistsTable(plan) => plan.schema(),
             Plan::DescribeView(plan) => plan.schema(),
             Plan::ShowRoles(plan) => plan.schema(),
+            Plan::ShowWarnings(plan) => plan.schema(),
             Plan::ShowGrants(plan) => plan.schema(),
             Plan::ShowFileFormats(plan) => plan.schema(),
-            Plan::Insert(plan) => plan.schema(),
+            Plan::Insert(plan) => match &plan.returning {
+                Some(returning) => returning.schema.clone(),
+                None => plan.schema(),
+            },
+            Plan::Update(plan) => plan.schema(),
+            Plan::Delete(plan) => plan.schema(),
             Plan::Replace(plan) => plan.schema(),
             Plan::Presign(plan) => plan.schema(),
             Plan::ShowShareEndpoint(plan) => plan.schema(),
@@ -468,7 +509,9 @@ impl Plan {
             Plan::DescNotification(plan) => plan.schema(),
             Plan::DescConnection(plan) => plan.schema(),
             Plan::ShowConnections(plan) => plan.schema(),
+            Plan::DescPipe(plan) => plan.schema(),
             Plan::ExecuteImmediate(plan) => plan.schema(),
+            Plan::CallProcedure(plan) => plan.schema(),
             Plan::InsertMultiTable(plan) => plan.schema(),
 
             other => {
@@ -479,6 +522,12 @@ impl Plan {
     }
 
     pub fn has_result_set(&self) -> bool {
+        match self {
+            Plan::Insert(plan) => return plan.returning.is_some(),
+            Plan::Update(plan) => return plan.returning.is_some(),
+            Plan::Delete(plan) => return plan.returning.is_some(),
+            _ => {}
+        }
         matches!(
             self,
             Plan::Query { .. }
@@ -487,16 +536,20 @@ impl Plan {
                 | Plan::ExplainSyntax { .. }
                 | Plan::ExplainAnalyze { .. }
                 | Plan::ShowCreateDatabase(_)
+                | Plan::BackupDatabase(_)
+                | Plan::RestoreDatabase(_)
                 | Plan::ShowCreateTable(_)
                 | Plan::ShowCreateCatalog(_)
                 | Plan::ShowFileFormats(_)
                 | Plan::ShowRoles(_)
+                | Plan::ShowWarnings(_)
                 | Plan::DescShare(_)
                 | Plan::ShowShares(_)
                 | Plan::ShowShareEndpoint(_)
                 | Plan::ShowObjectGrantPrivileges(_)
                 | Plan::ShowGrantTenantsOfShare(_)
                 | Plan::DescribeTable(_)
+                | Plan::DescribeQuery { .. }
                 | Plan::ShowGrants(_)
                 | Plan::Presign(_)
                 | Plan::VacuumTable(_)
@@ -511,8 +564,10 @@ impl Plan {
                 | Plan::DescribeTask(_)
                 | Plan::DescConnection(_)
                 | Plan::ShowConnections(_)
+                | Plan::DescPipe(_)
                 | Plan::MergeInto(_)
                 | Plan::ExecuteImmediate(_)
+                | Plan::CallProcedure(_)
                 | Plan::InsertMultiTable(_)
         )
     }
@@ -32,6 +32,9 @@ pub struct Replace {
     pub schema: TableSchemaRef,
     pub source: InsertInputSource,
     pub delete_when: Option<Expr>,
+    /// Reject the rows conflicting with the existing ones instead of replacing them,
+    /// used by INSERT into tables with an enforced primary key.
+    pub error_on_conflict: bool,
 }
 
 impl PartialEq for Replace {
@@ -63,6 +66,7 @@ impl std::fmt::Debug for Replace {
             .field("table_id", &self.table_id)
             .field("schema", &self.schema)
             .field("on conflict", &self.on_conflict_fields)
+            .field("error on conflict", &self.error_on_conflict)
             .finish()
     }
 }
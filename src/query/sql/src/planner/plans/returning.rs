@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::RemoteExpr;
+
+use crate::plans::ScalarExpr;
+
+/// The `RETURNING` list of INSERT, UPDATE and DELETE, it's evaluated on the rows
+/// affected by the statement and sent to the client as the result set.
+#[derive(Clone, Debug)]
+pub struct Returning {
+    /// The bound expressions with their output names.
+    pub items: Vec<(String, ScalarExpr)>,
+    pub schema: DataSchemaRef,
+}
+
+impl Returning {
+    /// The expressions referring to the columns of the target table by name.
+    pub fn remote_exprs(&self) -> Result<Vec<RemoteExpr<String>>> {
+        self.items
+            .iter()
+            .map(|(_, scalar)| {
+                let expr = scalar
+                    .as_expr()?
+                    .project_column_ref(|col| col.column_name.clone());
+                Ok(expr.as_remote_expr())
+            })
+            .collect()
+    }
+}
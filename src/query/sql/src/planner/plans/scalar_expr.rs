@@ -628,17 +628,27 @@ pub struct UDFCall {
 pub enum UDFType {
     Server(String),                   // server_addr
     Script((String, String, String)), // Lang, Version, Code
+    Model(String),                    // stage location of the model file
 }
 
 impl UDFType {
-    pub fn match_type(&self, is_script: bool) -> bool {
+    pub fn kind(&self) -> UDFKind {
         match self {
-            UDFType::Server(_) => !is_script,
-            UDFType::Script(_) => is_script,
+            UDFType::Server(_) => UDFKind::Server,
+            UDFType::Script(_) => UDFKind::Script,
+            UDFType::Model(_) => UDFKind::Model,
         }
     }
 }
 
+/// The kind of udf functions evaluated by a `Udf` plan, each kind is executed by its own transform.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum UDFKind {
+    Server,
+    Script,
+    Model,
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct UDFLambdaCall {
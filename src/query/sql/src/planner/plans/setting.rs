@@ -26,5 +26,6 @@ pub struct SettingPlan {
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UnSettingPlan {
+    pub is_session: bool,
     pub vars: Vec<String>,
 }
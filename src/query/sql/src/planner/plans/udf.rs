@@ -26,12 +26,13 @@ use crate::optimizer::StatInfo;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarItem;
+use crate::plans::UDFKind;
 
 /// `Udf` is a plan that evaluate a series of udf functions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Udf {
     pub items: Vec<ScalarItem>,
-    pub script_udf: bool,
+    pub kind: UDFKind,
 }
 
 impl Udf {
@@ -35,6 +35,7 @@ use crate::binder::ColumnBindingBuilder;
 use crate::parse_computed_expr;
 use crate::plans::BoundColumnRef;
 use crate::plans::FunctionCall;
+use crate::plans::Returning;
 use crate::plans::ScalarExpr;
 use crate::plans::SubqueryDesc;
 use crate::BindContext;
@@ -51,11 +52,15 @@ pub struct UpdatePlan {
     pub bind_context: Box<BindContext>,
     pub metadata: MetadataRef,
     pub subquery_desc: Vec<SubqueryDesc>,
+    pub returning: Option<Returning>,
 }
 
 impl UpdatePlan {
     pub fn schema(&self) -> DataSchemaRef {
-        Arc::new(DataSchema::empty())
+        match &self.returning {
+            Some(returning) => returning.schema.clone(),
+            None => Arc::new(DataSchema::empty()),
+        }
     }
 
     pub fn generate_update_list(
@@ -0,0 +1,219 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::Literal;
+use databend_common_ast::ast::ReplaceStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::UpdateExpr;
+use databend_common_ast::parser::token::Token;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::token::Tokenizer;
+use databend_common_settings::QueryTextRedaction;
+use databend_common_settings::Settings;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+
+const MASKED_VALUE: &str = "***";
+const MASKED_LITERAL: &str = "'***'";
+
+/// Redacts the SQL text written to the logs and the system tables, by the
+/// `query_text_redaction` and `query_text_sensitive_columns` settings.
+pub struct QueryTextRedactor {
+    redaction: QueryTextRedaction,
+    sensitive_columns: HashSet<String>,
+}
+
+impl QueryTextRedactor {
+    pub fn create(settings: &Settings) -> Self {
+        // Mask everything if the settings can't be read.
+        let redaction = settings
+            .get_query_text_redaction()
+            .unwrap_or(QueryTextRedaction::Literals);
+        let sensitive_columns = settings
+            .get_query_text_sensitive_columns()
+            .unwrap_or_default();
+        QueryTextRedactor {
+            redaction,
+            sensitive_columns: sensitive_columns.into_iter().collect(),
+        }
+    }
+
+    /// The text of the statement, the connection options are always masked.
+    pub fn redact_statement(&self, stmt: &Statement) -> String {
+        match self.redaction {
+            QueryTextRedaction::None => stmt.to_mask_sql(),
+            QueryTextRedaction::Literals => redact_literals(&stmt.to_mask_sql()),
+            QueryTextRedaction::SensitiveColumns => {
+                let mut stmt = stmt.clone();
+                stmt.drive_mut(&mut SensitiveValueMasker {
+                    columns: &self.sensitive_columns,
+                });
+                stmt.to_mask_sql()
+            }
+        }
+    }
+
+    /// Redacts the SQL text which is not parsed. The sensitive columns can't be told without
+    /// parsing, so all the literals are masked unless the redaction is disabled.
+    pub fn redact_sql(&self, sql: &str) -> String {
+        match self.redaction {
+            QueryTextRedaction::None => sql.to_string(),
+            _ => redact_literals(sql),
+        }
+    }
+}
+
+/// Replaces the literals with the mask.
+fn redact_literals(sql: &str) -> String {
+    let mut redacted = String::with_capacity(sql.len());
+    // The end of the text copied to the result, and the end of the last token.
+    let mut copied_end = 0;
+    let mut token_end = 0;
+    for token in Tokenizer::new(sql) {
+        let Ok(token) = token else {
+            // The rest can't be tokenized, e.g. the CSV values of an INSERT, mask it as a whole.
+            redacted.push_str(&sql[copied_end..token_end]);
+            redacted.push(' ');
+            redacted.push_str(MASKED_LITERAL);
+            return redacted;
+        };
+        let range = std::ops::Range::from(token.span);
+        token_end = range.end;
+        if is_literal(&token) {
+            redacted.push_str(&sql[copied_end..range.start]);
+            redacted.push_str(MASKED_LITERAL);
+            copied_end = range.end;
+        }
+    }
+    redacted.push_str(&sql[copied_end..]);
+    redacted
+}
+
+fn is_literal(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::LiteralInteger
+        | TokenKind::LiteralFloat
+        | TokenKind::PGLiteralHex
+        | TokenKind::MySQLLiteralHex => true,
+        // The double quotes and the backticks quote the identifiers.
+        TokenKind::QuotedString => token.text().starts_with('\''),
+        _ => false,
+    }
+}
+
+/// Masks the literals compared with or assigned to the sensitive columns.
+#[derive(VisitorMut)]
+#[visitor(Expr(enter), UpdateExpr(enter), InsertStmt(enter), ReplaceStmt(enter))]
+struct SensitiveValueMasker<'a> {
+    columns: &'a HashSet<String>,
+}
+
+impl SensitiveValueMasker<'_> {
+    fn is_sensitive(&self, ident: &Identifier) -> bool {
+        self.columns.contains(&ident.name.to_lowercase())
+    }
+
+    fn is_sensitive_column(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::ColumnRef { column, .. } => {
+                self.columns.contains(&column.column.name().to_lowercase())
+            }
+            _ => false,
+        }
+    }
+
+    fn enter_expr(&mut self, expr: &mut Expr) {
+        match expr {
+            Expr::BinaryOp { left, right, .. } => {
+                if self.is_sensitive_column(left) {
+                    mask_literals(right);
+                }
+                if self.is_sensitive_column(right) {
+                    mask_literals(left);
+                }
+            }
+            Expr::InList { expr, list, .. } if self.is_sensitive_column(expr) => {
+                list.iter_mut().for_each(mask_literals);
+            }
+            Expr::Between {
+                expr, low, high, ..
+            } if self.is_sensitive_column(expr) => {
+                mask_literals(low);
+                mask_literals(high);
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_update_expr(&mut self, update: &mut UpdateExpr) {
+        if self.is_sensitive(&update.name) {
+            mask_literals(&mut update.expr);
+        }
+    }
+
+    fn enter_insert_stmt(&mut self, insert: &mut InsertStmt) {
+        self.mask_insert_source(&insert.columns, &mut insert.source);
+    }
+
+    fn enter_replace_stmt(&mut self, replace: &mut ReplaceStmt) {
+        self.mask_insert_source(&replace.columns, &mut replace.source);
+    }
+
+    /// Without the column list, the columns of the values are unknown and all of them are masked.
+    fn mask_insert_source(&self, columns: &[Identifier], source: &mut InsertSource) {
+        let sensitive = |idx: usize| {
+            columns.is_empty() || columns.get(idx).map_or(true, |c| self.is_sensitive(c))
+        };
+        match source {
+            InsertSource::Values { rows } => {
+                for row in rows.iter_mut() {
+                    for (idx, value) in row.iter_mut().enumerate() {
+                        if sensitive(idx) {
+                            mask_literals(value);
+                        }
+                    }
+                }
+            }
+            InsertSource::RawValues { rest_str, .. } | InsertSource::Streaming { rest_str, .. } => {
+                if (0..columns.len().max(1)).any(sensitive) {
+                    *rest_str = redact_literals(rest_str);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Masks all the literals in the expression.
+fn mask_literals(expr: &mut Expr) {
+    #[derive(VisitorMut)]
+    #[visitor(Literal(enter))]
+    struct LiteralMasker;
+
+    impl LiteralMasker {
+        fn enter_literal(&mut self, literal: &mut Literal) {
+            if !matches!(literal, Literal::Null) {
+                *literal = Literal::String(MASKED_VALUE.to_string());
+            }
+        }
+    }
+
+    expr.drive_mut(&mut LiteralMasker);
+}
@@ -79,6 +79,7 @@ impl AggregateRewriter {
                                         args: vec![],
                                         params: vec![],
                                         window: None,
+                                        order_by: vec![],
                                         lambda: None,
                                     },
                                 }),
@@ -91,6 +92,7 @@ impl AggregateRewriter {
                                     args: vec![other.clone()],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             }),
@@ -110,6 +112,7 @@ impl AggregateRewriter {
                                     args: vec![other.clone()],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             }),
@@ -125,6 +128,7 @@ impl AggregateRewriter {
                                         args: vec![],
                                         params: vec![],
                                         window: None,
+                                        order_by: vec![],
                                         lambda: None,
                                     },
                                 }),
@@ -154,6 +158,7 @@ impl AggregateRewriter {
                     args: vec![args[0].clone()],
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             }),
@@ -174,6 +179,7 @@ impl AggregateRewriter {
                                     args: vec![args[0].clone()],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             }),
@@ -194,12 +200,14 @@ impl AggregateRewriter {
                                 args: vec![args[0].clone()],
                                 params: vec![],
                                 window: None,
+                                order_by: vec![],
                                 lambda: None,
                             },
                         },
                     ],
                     params: vec![],
                     window: None,
+                    order_by: vec![],
                     lambda: None,
                 },
             }),
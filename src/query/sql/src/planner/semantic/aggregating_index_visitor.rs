@@ -212,6 +212,7 @@ impl AggregatingIndexChecker {
             name,
             params: _,
             args: _,
+            order_by: _,
             window: _,
             lambda: _,
         } = func;
@@ -305,6 +306,7 @@ impl RefreshAggregatingIndexRewriter {
                         args: vec![],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 };
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The implicit casts of the type checker.
+//!
+//! The function registry owns the builtin implicit cast rules, see `cast_rules.rs` in the
+//! functions crate. The `sql_dialect` setting adjusts them for each function call:
+//!
+//! - `PostgreSQL`, `Hive`, `Prql` and `Experimental` use the builtin rules.
+//! - `Strict` only allows the lossless implicit casts, e.g. `Int32` to `Int64` or `Date`
+//!   to `Timestamp`. Strings and variants are never cast implicitly, and neither are the
+//!   casts that may lose precision, like `Int64` to `Float64` or `Decimal` to `Float64`.
+//! - `MySQL` uses the builtin rules, and a string compared with a number is converted to
+//!   `Float64`, e.g. `1 < '1'`. Booleans are used as `UInt8` in arithmetic, e.g. `true + 1`.
+//! - `Snowflake` uses the builtin rules, and a string compared with a number is converted
+//!   to `Int64`, or to `Float64` if the number is a floating point number.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnIndex;
+use databend_common_expression::Expr;
+use databend_common_functions::scalars::ALL_COMP_FUNC_NAMES;
+use databend_common_settings::CastDialect;
+
+use crate::plans::CastExpr;
+use crate::plans::ScalarExpr;
+
+const ARITHMETIC_FUNC_NAMES: &[&str] = &["plus", "minus", "multiply", "divide", "div", "modulo"];
+
+/// Adds the implicit casts of the dialect that are not in the builtin rules.
+pub fn coerce_arguments(
+    dialect: CastDialect,
+    func_name: &str,
+    args: Vec<ScalarExpr>,
+) -> Result<Vec<ScalarExpr>> {
+    if !matches!(dialect, CastDialect::MySQL | CastDialect::Snowflake) {
+        return Ok(args);
+    }
+
+    let arg_types = args
+        .iter()
+        .map(|arg| Ok(arg.data_type()?.remove_nullable()))
+        .collect::<Result<Vec<_>>>()?;
+
+    if ALL_COMP_FUNC_NAMES.contains(&func_name) && args.len() == 2 {
+        let target_type = match (&arg_types[0], &arg_types[1]) {
+            (DataType::String, DataType::Number(n)) | (DataType::Number(n), DataType::String) => {
+                if dialect == CastDialect::MySQL || n.is_float() {
+                    NumberDataType::Float64
+                } else {
+                    NumberDataType::Int64
+                }
+            }
+            _ => return Ok(args),
+        };
+        return Ok(args
+            .into_iter()
+            .zip(arg_types)
+            .map(|(arg, arg_type)| match arg_type {
+                DataType::String => wrap_cast(arg, DataType::Number(target_type)),
+                _ => arg,
+            })
+            .collect());
+    }
+
+    if dialect == CastDialect::MySQL && ARITHMETIC_FUNC_NAMES.contains(&func_name) {
+        return Ok(args
+            .into_iter()
+            .zip(arg_types)
+            .map(|(arg, arg_type)| match arg_type {
+                DataType::Boolean => wrap_cast(arg, DataType::Number(NumberDataType::UInt8)),
+                _ => arg,
+            })
+            .collect());
+    }
+
+    Ok(args)
+}
+
+/// Checks that the implicit casts added to the arguments by the type checker
+/// are allowed in the dialect.
+pub fn check_implicit_casts<Index: ColumnIndex>(
+    dialect: CastDialect,
+    func_name: &str,
+    args: &[ScalarExpr],
+    checked_expr: &Expr<Index>,
+) -> Result<()> {
+    if dialect != CastDialect::Strict {
+        return Ok(());
+    }
+    let Expr::FunctionCall {
+        args: checked_args, ..
+    } = checked_expr
+    else {
+        return Ok(());
+    };
+
+    for (arg, checked_arg) in args.iter().zip(checked_args) {
+        let src_type = arg.data_type()?;
+        let dest_type = checked_arg.data_type();
+        if !is_lossless_cast(&src_type, dest_type) {
+            return Err(ErrorCode::SemanticError(format!(
+                "implicit cast from {src_type} to {dest_type} in `{func_name}` is not allowed with sql_dialect 'Strict', use an explicit CAST instead"
+            ))
+            .set_span(arg.span()));
+        }
+    }
+    Ok(())
+}
+
+fn is_lossless_cast(src_type: &DataType, dest_type: &DataType) -> bool {
+    match (src_type, dest_type) {
+        (src, dest) if src == dest => true,
+        (DataType::Null, _) => true,
+        (DataType::Nullable(src), DataType::Nullable(dest)) => is_lossless_cast(src, dest),
+        (src, DataType::Nullable(dest)) => is_lossless_cast(src, dest),
+        (DataType::Number(src), DataType::Number(dest)) => src.can_lossless_cast_to(*dest),
+        // The decimal casts are only implicit when the value fits in the target type.
+        (DataType::Number(src), DataType::Decimal(_)) => src.is_integer(),
+        (DataType::Decimal(_), DataType::Decimal(_)) => true,
+        (DataType::Date, DataType::Timestamp) => true,
+        (DataType::EmptyArray, DataType::Array(_)) | (DataType::EmptyMap, DataType::Map(_)) => true,
+        (DataType::Array(src), DataType::Array(dest))
+        | (DataType::Map(src), DataType::Map(dest)) => is_lossless_cast(src, dest),
+        (DataType::Tuple(srcs), DataType::Tuple(dests)) => {
+            srcs.len() == dests.len()
+                && srcs
+                    .iter()
+                    .zip(dests)
+                    .all(|(src, dest)| is_lossless_cast(src, dest))
+        }
+        _ => false,
+    }
+}
+
+fn wrap_cast(arg: ScalarExpr, target_type: DataType) -> ScalarExpr {
+    let target_type = match arg.data_type() {
+        Ok(data_type) if data_type.is_nullable_or_null() => target_type.wrap_nullable(),
+        _ => target_type,
+    };
+    CastExpr {
+        span: arg.span(),
+        is_try: false,
+        argument: Box::new(arg),
+        target_type: Box::new(target_type),
+    }
+    .into()
+}
@@ -113,6 +113,7 @@ impl DistinctToGroupBy {
                                     }],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             }),
@@ -0,0 +1,205 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_ast::ast::ColumnID;
+use databend_common_ast::ast::ColumnRef;
+use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::CreateViewStmt;
+use databend_common_ast::ast::DropTableStmt;
+use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::InsertStmt;
+use databend_common_ast::ast::ReplaceStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableReference;
+use databend_common_ast::parser::parse_dot_separated_idents;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::parser::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use derive_visitor::DriveMut;
+use derive_visitor::VisitorMut;
+
+/// Resolve `IDENTIFIER('<name>')` into plain identifiers before binding.
+///
+/// In table and column positions the string may be a qualified name such as
+/// `db.t` or `t.c`, which is expanded into the catalog, database and table parts.
+/// Anywhere else it must name a single object. Each part follows the usual rules,
+/// so `IDENTIFIER('"MyTable"')` is case sensitive while `IDENTIFIER('MyTable')` is not.
+#[derive(VisitorMut)]
+#[visitor(
+    TableReference(enter),
+    InsertStmt(enter),
+    ReplaceStmt(enter),
+    CreateTableStmt(enter),
+    DropTableStmt(enter),
+    CreateViewStmt(enter),
+    ColumnRef(enter),
+    Identifier(enter)
+)]
+pub struct DynamicIdentifierResolver {
+    dialect: Dialect,
+    error: Option<ErrorCode>,
+}
+
+impl DynamicIdentifierResolver {
+    pub fn resolve(stmt: &mut Statement, dialect: Dialect) -> Result<()> {
+        let mut resolver = DynamicIdentifierResolver {
+            dialect,
+            error: None,
+        };
+        stmt.drive_mut(&mut resolver);
+
+        match resolver.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn parse_object_name(&self, ident: &Identifier, max_parts: usize) -> Result<Vec<Identifier>> {
+        let invalid = || {
+            ErrorCode::SemanticError(format!(
+                "invalid object name '{}' in IDENTIFIER",
+                ident.name
+            ))
+            .set_span(ident.span)
+        };
+
+        let tokens = tokenize_sql(&ident.name).map_err(|_| invalid())?;
+        let parts = parse_dot_separated_idents(&tokens, self.dialect).map_err(|_| invalid())?;
+        if parts.len() > max_parts {
+            return Err(ErrorCode::SemanticError(format!(
+                "object name '{}' in IDENTIFIER has {} parts, expected at most {}",
+                ident.name,
+                parts.len(),
+                max_parts
+            ))
+            .set_span(ident.span));
+        }
+
+        Ok(parts
+            .into_iter()
+            .map(|part| Identifier {
+                span: ident.span,
+                ..part
+            })
+            .collect())
+    }
+
+    /// Expand a dynamic `name` into the unspecified qualifiers, filling from the right.
+    fn expand_object_name(
+        &mut self,
+        qualifiers: &mut [&mut Option<Identifier>],
+        name: &mut Identifier,
+    ) {
+        if !name.is_dynamic() || qualifiers.iter().any(|qualifier| qualifier.is_some()) {
+            return;
+        }
+
+        match self.parse_object_name(name, qualifiers.len() + 1) {
+            Ok(mut parts) => {
+                *name = parts.pop().unwrap();
+                for (qualifier, part) in qualifiers.iter_mut().rev().zip(parts.into_iter().rev()) {
+                    **qualifier = Some(part);
+                }
+            }
+            Err(e) => self.set_error(e),
+        }
+    }
+
+    fn set_error(&mut self, error: ErrorCode) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            catalog,
+            database,
+            table,
+            ..
+        } = table_ref
+        {
+            self.expand_object_name(&mut [catalog, database], table);
+        }
+    }
+
+    fn enter_insert_stmt(&mut self, stmt: &mut InsertStmt) {
+        let InsertStmt {
+            catalog,
+            database,
+            table,
+            ..
+        } = stmt;
+        self.expand_object_name(&mut [catalog, database], table);
+    }
+
+    fn enter_replace_stmt(&mut self, stmt: &mut ReplaceStmt) {
+        let ReplaceStmt {
+            catalog,
+            database,
+            table,
+            ..
+        } = stmt;
+        self.expand_object_name(&mut [catalog, database], table);
+    }
+
+    fn enter_create_table_stmt(&mut self, stmt: &mut CreateTableStmt) {
+        let CreateTableStmt {
+            catalog,
+            database,
+            table,
+            ..
+        } = stmt;
+        self.expand_object_name(&mut [catalog, database], table);
+    }
+
+    fn enter_drop_table_stmt(&mut self, stmt: &mut DropTableStmt) {
+        let DropTableStmt {
+            catalog,
+            database,
+            table,
+            ..
+        } = stmt;
+        self.expand_object_name(&mut [catalog, database], table);
+    }
+
+    fn enter_create_view_stmt(&mut self, stmt: &mut CreateViewStmt) {
+        let CreateViewStmt {
+            catalog,
+            database,
+            view,
+            ..
+        } = stmt;
+        self.expand_object_name(&mut [catalog, database], view);
+    }
+
+    fn enter_column_ref(&mut self, column_ref: &mut ColumnRef) {
+        if let ColumnID::Name(column) = &mut column_ref.column {
+            self.expand_object_name(
+                &mut [&mut column_ref.database, &mut column_ref.table],
+                column,
+            );
+        }
+    }
+
+    fn enter_identifier(&mut self, ident: &mut Identifier) {
+        if ident.is_dynamic() {
+            match self.parse_object_name(ident, 1) {
+                Ok(mut parts) => *ident = parts.pop().unwrap(),
+                Err(e) => self.set_error(e),
+            }
+        }
+    }
+}
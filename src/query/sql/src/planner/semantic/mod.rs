@@ -14,7 +14,9 @@
 
 mod aggregate_rewriter;
 mod aggregating_index_visitor;
+mod coercion;
 mod distinct_to_groupby;
+mod dynamic_identifier;
 mod grouping_check;
 mod lowering;
 mod name_resolution;
@@ -29,17 +31,20 @@ pub use aggregating_index_visitor::AggregatingIndexChecker;
 pub use aggregating_index_visitor::AggregatingIndexRewriter;
 pub use aggregating_index_visitor::RefreshAggregatingIndexRewriter;
 pub use distinct_to_groupby::DistinctToGroupBy;
+pub use dynamic_identifier::DynamicIdentifierResolver;
 pub use grouping_check::GroupingChecker;
 pub use lowering::*;
 pub use name_resolution::compare_table_name;
 pub use name_resolution::normalize_identifier;
 pub use name_resolution::IdentifierNormalizer;
 pub use name_resolution::NameResolutionContext;
+pub(crate) use type_check::interval_to_micros;
 pub use type_check::resolve_type_name;
 pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
 pub use type_check::TypeChecker;
 pub(crate) use udf_rewriter::UdfRewriter;
+pub use view_rewriter::ShareViewRewriter;
 pub use view_rewriter::ViewRewriter;
 pub(crate) use virtual_column_rewriter::VirtualColumnRewriter;
 pub use window_check::WindowChecker;
@@ -29,6 +29,7 @@ use databend_common_ast::ast::IntervalKind as ASTIntervalKind;
 use databend_common_ast::ast::Lambda;
 use databend_common_ast::ast::Literal;
 use databend_common_ast::ast::MapAccessor;
+use databend_common_ast::ast::OrderByExpr;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SelectTarget;
 use databend_common_ast::ast::SetExpr;
@@ -69,6 +70,7 @@ use databend_common_expression::ColumnIndex;
 use databend_common_expression::ConstantFolder;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
+use databend_common_expression::DecimalOverflowMode;
 use databend_common_expression::Expr as EExpr;
 use databend_common_expression::FunctionContext;
 use databend_common_expression::FunctionKind;
@@ -78,7 +80,12 @@ use databend_common_expression::TableDataType;
 use databend_common_expression::SEARCH_MATCHED_COL_NAME;
 use databend_common_expression::SEARCH_SCORE_COL_NAME;
 use databend_common_functions::aggregates::AggregateFunctionFactory;
+use databend_common_functions::aggregates::AggregateOrderByCombinator;
+use databend_common_functions::aggregates::AggregateSortDesc;
+use databend_common_functions::aggregates::ORDER_BY_SUFFIX;
 use databend_common_functions::is_builtin_function;
+use databend_common_functions::scalars::in_list_supported_type;
+use databend_common_functions::scalars::number_format_decimal_size;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_functions::GENERAL_LAMBDA_FUNCTIONS;
 use databend_common_functions::GENERAL_SEARCH_FUNCTIONS;
@@ -87,6 +94,7 @@ use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFScript;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_settings::CastDialect;
 use databend_common_users::UserApiProvider;
 use derive_visitor::Drive;
 use derive_visitor::Visitor;
@@ -96,6 +104,7 @@ use jsonb::keypath::KeyPath;
 use jsonb::keypath::KeyPaths;
 use simsearch::SimSearch;
 
+use super::coercion;
 use super::name_resolution::NameResolutionContext;
 use super::normalize_identifier;
 use crate::binder::bind_values;
@@ -155,6 +164,7 @@ pub struct TypeChecker<'a> {
     bind_context: &'a mut BindContext,
     ctx: Arc<dyn TableContext>,
     dialect: Dialect,
+    cast_dialect: CastDialect,
     func_ctx: FunctionContext,
     name_resolution_ctx: &'a NameResolutionContext,
     metadata: MetadataRef,
@@ -184,10 +194,12 @@ impl<'a> TypeChecker<'a> {
     ) -> Result<Self> {
         let func_ctx = ctx.get_function_context()?;
         let dialect = ctx.get_settings().get_sql_dialect()?;
+        let cast_dialect = ctx.get_settings().get_cast_dialect()?;
         Ok(Self {
             bind_context,
             ctx,
             dialect,
+            cast_dialect,
             func_ctx,
             name_resolution_ctx,
             metadata,
@@ -418,27 +430,20 @@ impl<'a> TypeChecker<'a> {
                             args: vec![array_expr],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                             distinct: false,
                         },
                     };
                     let args = vec![&array_expr, expr.as_ref()];
+                    let contains = self
+                        .resolve_function(*span, "contains", vec![], &args)
+                        .await?;
+                    let contains = self.rewrite_contains_to_in_list(*span, contains)?;
                     if *not {
-                        self.resolve_unary_op(*span, &UnaryOperator::Not, &Expr::FunctionCall {
-                            span: *span,
-                            func: ASTFunctionCall {
-                                distinct: false,
-                                name: Identifier::from_name(*span, "contains"),
-                                args: args.iter().copied().cloned().collect(),
-                                params: vec![],
-                                window: None,
-                                lambda: None,
-                            },
-                        })
-                        .await?
+                        self.resolve_scalar_function_call(*span, "not", vec![], vec![contains.0])?
                     } else {
-                        self.resolve_function(*span, "contains", vec![], &args)
-                            .await?
+                        contains
                     }
                 } else {
                     let mut result = list
@@ -680,6 +685,7 @@ impl<'a> TypeChecker<'a> {
                                     args: vec![*operand.clone(), c.clone()],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             };
@@ -730,6 +736,7 @@ impl<'a> TypeChecker<'a> {
                         name,
                         args,
                         params,
+                        order_by,
                         window,
                         lambda,
                     },
@@ -790,6 +797,14 @@ impl<'a> TypeChecker<'a> {
                     )
                     .set_span(*span));
                 }
+                // check order by legal
+                if !order_by.is_empty() && !AggregateFunctionFactory::instance().contains(func_name)
+                {
+                    return Err(ErrorCode::SemanticError(
+                        "only aggregate functions allowed ORDER BY in arguments",
+                    )
+                    .set_span(*span));
+                }
                 // check lambda function legal
                 if lambda.is_some() && !GENERAL_LAMBDA_FUNCTIONS.contains(&func_name) {
                     return Err(ErrorCode::SemanticError(
@@ -872,7 +887,7 @@ impl<'a> TypeChecker<'a> {
                     let in_aggregate_function = self.in_aggregate_function;
                     let (new_agg_func, data_type) = self
                         .resolve_aggregate_function(
-                            *span, func_name, expr, *distinct, new_params, &args,
+                            *span, func_name, expr, *distinct, new_params, &args, order_by,
                         )
                         .await?;
                     self.in_window_function = in_window;
@@ -1046,12 +1061,7 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr).await?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => self.resolve_interval(*span, expr, unit).await?,
             Expr::DateAdd {
                 span,
                 unit,
@@ -1665,6 +1675,7 @@ impl<'a> TypeChecker<'a> {
         distinct: bool,
         params: Vec<Scalar>,
         args: &[&Expr],
+        order_by: &[OrderByExpr],
     ) -> Result<(AggregateFunction, DataType)> {
         if matches!(
             self.bind_context.expr_context,
@@ -1710,18 +1721,24 @@ impl<'a> TypeChecker<'a> {
             arguments.push(argument);
             arg_types.push(arg_type);
         }
+        let mut sort_keys = Vec::with_capacity(order_by.len());
+        let mut sort_key_types = Vec::with_capacity(order_by.len());
+        for order in order_by.iter() {
+            let box (sort_key, sort_key_type) = self.resolve(&order.expr).await?;
+            sort_keys.push(sort_key);
+            sort_key_types.push(sort_key_type);
+        }
         self.in_aggregate_function = false;
 
         // Convert the delimiter of string_agg to params
-        let params = if func_name.eq_ignore_ascii_case("string_agg")
-            && arguments.len() == 2
-            && params.is_empty()
-        {
+        let is_string_agg = func_name.eq_ignore_ascii_case("string_agg")
+            || func_name.eq_ignore_ascii_case("listagg");
+        let params = if is_string_agg && arguments.len() == 2 && params.is_empty() {
             let delimiter_value = ConstantExpr::try_from(arguments[1].clone());
             if arg_types[1] != DataType::String || delimiter_value.is_err() {
-                return Err(ErrorCode::SemanticError(
-                    "The delimiter of `string_agg` must be a constant string",
-                ));
+                return Err(ErrorCode::SemanticError(format!(
+                    "The delimiter of `{func_name}` must be a constant string",
+                )));
             }
             let delimiter = delimiter_value.unwrap();
             vec![delimiter.value]
@@ -1751,6 +1768,53 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Rewrite `xxx(... ORDER BY ...)` to `xxx_order_by(..., sort keys)`,
+        // the sort descriptions are appended to the params.
+        if !order_by.is_empty() {
+            if distinct {
+                // The same as PostgreSQL, so the duplicated arguments can be removed after sorting.
+                if let Some(pos) = sort_keys.iter().position(|key| !arguments.contains(key)) {
+                    return Err(ErrorCode::SemanticError(
+                        "in an aggregate with DISTINCT, ORDER BY expressions must appear in argument list",
+                    )
+                    .set_span(order_by[pos].expr.span()));
+                }
+            }
+            // The delimiter of string_agg has been converted to params.
+            if is_string_agg && arguments.len() == 2 {
+                arguments.truncate(1);
+                arg_types.truncate(1);
+            }
+
+            // null is the largest value in databend, smallest in hive
+            let default_nulls_first = !self.ctx.get_settings().get_sql_dialect()?.is_null_biggest();
+            let sort_descs = order_by
+                .iter()
+                .map(|order| AggregateSortDesc {
+                    asc: order.asc.unwrap_or(true),
+                    nulls_first: order.nulls_first.unwrap_or(default_nulls_first),
+                })
+                .collect::<Vec<_>>();
+            let params = AggregateOrderByCombinator::encode_params(params, distinct, &sort_descs);
+            let func_name = format!("{func_name}{ORDER_BY_SUFFIX}");
+            arguments.extend(sort_keys);
+            arg_types.extend(sort_key_types);
+
+            let agg_func = AggregateFunctionFactory::instance()
+                .get(&func_name, params.clone(), arg_types)
+                .map_err(|e| e.set_span(span))?;
+            let data_type = agg_func.return_type()?;
+            let new_agg_func = AggregateFunction {
+                display_name: format!("{:#}", expr),
+                func_name,
+                distinct: false,
+                params,
+                args: arguments,
+                return_type: Box::new(data_type.clone()),
+            };
+            return Ok((new_agg_func, data_type));
+        }
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -2426,6 +2490,26 @@ impl<'a> TypeChecker<'a> {
         self.resolve_scalar_function_call(span, func_name, params, args)
     }
 
+    /// With `decimal_overflow_mode = 'null'`, the decimal arithmetic is resolved to its `try_`
+    /// variant, which returns NULL for the overflowed rows.
+    fn is_null_on_decimal_overflow(&self, func_name: &str, args: &[ScalarExpr]) -> Result<bool> {
+        if !matches!(func_name, "plus" | "minus" | "multiply" | "divide") || args.len() != 2 {
+            return Ok(false);
+        }
+        let mut has_decimal = false;
+        for arg in args {
+            let data_type = arg.data_type()?.remove_nullable();
+            if !data_type.is_decimal() && !data_type.is_integer() {
+                return Ok(false);
+            }
+            has_decimal |= data_type.is_decimal();
+        }
+        Ok(has_decimal
+            && DecimalOverflowMode::from_setting(
+                &self.ctx.get_settings().get_decimal_overflow_mode()?,
+            ) == DecimalOverflowMode::Null)
+    }
+
     pub fn resolve_scalar_function_call(
         &self,
         span: Span,
@@ -2433,6 +2517,13 @@ impl<'a> TypeChecker<'a> {
         mut params: Vec<Scalar>,
         args: Vec<ScalarExpr>,
     ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let args = coercion::coerce_arguments(self.cast_dialect, func_name, args)?;
+        let func_name = match self.is_null_on_decimal_overflow(func_name, &args)? {
+            true => format!("try_{func_name}"),
+            false => func_name.to_string(),
+        };
+        let func_name = func_name.as_str();
+
         // Type check
         let arguments = args.iter().map(|v| v.as_raw_expr()).collect::<Vec<_>>();
 
@@ -2459,6 +2550,25 @@ impl<'a> TypeChecker<'a> {
             params.push(Scalar::Number(NumberScalar::Int64(scale)));
         }
 
+        // inject the decimal size implied by the constant format of `to_number`
+        if ["to_number", "try_to_number"].contains(&func_name)
+            && args.len() == 2
+            && params.is_empty()
+        {
+            let (format, _) =
+                ConstantFolder::fold(&args[1].as_expr()?, &self.func_ctx, &BUILTIN_FUNCTIONS);
+            if let databend_common_expression::Expr::Constant {
+                scalar: Scalar::String(format),
+                ..
+            } = format
+            {
+                let size = number_format_decimal_size(&format)
+                    .map_err(|err| ErrorCode::BadArguments(err).set_span(span))?;
+                params.push(Scalar::Number(NumberScalar::Int64(size.precision as i64)));
+                params.push(Scalar::Number(NumberScalar::Int64(size.scale as i64)));
+            }
+        }
+
         let raw_expr = RawExpr::FunctionCall {
             span,
             name: func_name.to_string(),
@@ -2466,6 +2576,7 @@ impl<'a> TypeChecker<'a> {
             args: arguments,
         };
         let expr = type_check::check(&raw_expr, &BUILTIN_FUNCTIONS)?;
+        coercion::check_implicit_casts(self.cast_dialect, func_name, &args, &expr)?;
 
         // Run constant folding for arguments of the scalar function.
         // This will be helpful to simplify some constant expressions, especially
@@ -2511,6 +2622,33 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Rewrite `contains(<constant array>, expr)` to `in_list(<values>)(expr)`,
+    /// so the hash set of the values is built once instead of once per block.
+    fn rewrite_contains_to_in_list(
+        &self,
+        span: Span,
+        contains: Box<(ScalarExpr, DataType)>,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if let ScalarExpr::FunctionCall(func) = &contains.0 {
+            if let [ScalarExpr::ConstantExpr(ConstantExpr {
+                value: Scalar::Array(list),
+                ..
+            }), arg] = func.arguments.as_slice()
+            {
+                let data_type = arg.data_type()?;
+                if list.len() > 0
+                    && in_list_supported_type(&data_type)
+                    && list.data_type() == data_type.remove_nullable()
+                {
+                    let params = list.iter().map(|value| value.to_owned()).collect();
+                    return self
+                        .resolve_scalar_function_call(span, "in_list", params, vec![arg.clone()]);
+                }
+            }
+        }
+        Ok(contains)
+    }
+
     /// Resolve binary expressions. Most of the binary expressions
     /// would be transformed into `FunctionCall`, except comparison
     /// expressions, conjunction(`AND`) and disjunction(`OR`).
@@ -2639,6 +2777,46 @@ impl<'a> TypeChecker<'a> {
         }
     }
 
+    #[async_recursion::async_recursion]
+    pub async fn resolve_interval(
+        &mut self,
+        span: Span,
+        expr: &Expr,
+        interval_kind: &ASTIntervalKind,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let (func_name, target_type) = match interval_kind {
+            ASTIntervalKind::Year => ("to_years", TypeName::Int64),
+            ASTIntervalKind::Quarter => ("to_quarters", TypeName::Int64),
+            ASTIntervalKind::Month => ("to_months", TypeName::Int64),
+            ASTIntervalKind::Week => ("to_weeks", TypeName::Int64),
+            ASTIntervalKind::Day => ("to_days", TypeName::Int64),
+            ASTIntervalKind::Hour => ("to_hours", TypeName::Int64),
+            ASTIntervalKind::Minute => ("to_minutes", TypeName::Int64),
+            ASTIntervalKind::Second => ("to_seconds", TypeName::Float64),
+            ASTIntervalKind::Doy | ASTIntervalKind::Dow => {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Unsupported interval unit {interval_kind}"
+                ))
+                .set_span(span));
+            }
+        };
+        // The quantity of `INTERVAL '1' DAY` is a string literal, parse it as a number.
+        let arg = match expr {
+            Expr::Literal {
+                value: Literal::String(_),
+                ..
+            } => Expr::Cast {
+                span: expr.span(),
+                expr: Box::new(expr.clone()),
+                target_type,
+                pg_style: false,
+            },
+            _ => expr.clone(),
+        };
+        self.resolve_function(span, func_name, vec![], &[&arg])
+            .await
+    }
+
     #[async_recursion::async_recursion]
     pub async fn resolve_date_add(
         &mut self,
@@ -2848,6 +3026,7 @@ impl<'a> TypeChecker<'a> {
             "greatest",
             "least",
             "stream_has_data",
+            "ml_predict",
         ]
     }
 
@@ -2946,6 +3125,31 @@ impl<'a> TypeChecker<'a> {
                         .await,
                 )
             }
+            (
+                "date_bin",
+                &[Expr::Interval {
+                    span: interval_span,
+                    expr,
+                    unit,
+                }, ref rest @ ..],
+            ) if (1..=2).contains(&rest.len()) => {
+                // Rewrite date_bin(INTERVAL n unit, ts[, origin]) to date_bin(n * <micros of unit>, ts[, origin])
+                let stride = match interval_to_micros(*interval_span, expr, unit) {
+                    Some(stride) => stride,
+                    None => {
+                        return Some(Err(ErrorCode::SemanticError(format!(
+                            "date_bin only supports fixed intervals of SECOND, MINUTE, HOUR, DAY and WEEK, but got {unit}"
+                        ))
+                        .set_span(*interval_span)));
+                    }
+                };
+                let mut new_args = vec![&stride];
+                new_args.extend(rest.iter().copied());
+                Some(
+                    self.resolve_function(span, "date_bin", vec![], &new_args)
+                        .await,
+                )
+            }
             ("nvl2", &[arg_x, arg_y, arg_z]) => {
                 // Rewrite nvl2(x, y, z) to if(is_not_null(x), y, z)
                 Some(
@@ -2972,6 +3176,7 @@ impl<'a> TypeChecker<'a> {
                             args: vec![arg_x.clone()],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     })
@@ -2989,6 +3194,7 @@ impl<'a> TypeChecker<'a> {
                             args: vec![arg_x.clone()],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     })
@@ -3009,6 +3215,7 @@ impl<'a> TypeChecker<'a> {
                             args: vec![(*arg).clone()],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     };
@@ -3052,6 +3259,7 @@ impl<'a> TypeChecker<'a> {
                             args: vec![(*arg).clone()],
                             params: vec![],
                             window: None,
+                            order_by: vec![],
                             lambda: None,
                         },
                     };
@@ -3238,6 +3446,7 @@ impl<'a> TypeChecker<'a> {
                     .ok()?;
                 Some(self.resolve_scalar_function_call(span, "array_min", vec![], vec![array]))
             }
+            ("ml_predict", args) => Some(self.resolve_ml_predict(span, args).await),
             _ => None,
         }
     }
@@ -3428,6 +3637,10 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_udf_script(span, name, arguments, udf_def)
                     .await?,
             )),
+            UDFDefinition::UDFModel(_) => Err(ErrorCode::SemanticError(format!(
+                "'{name}' is a model, use `ml_predict('{name}', ...)` to evaluate it"
+            ))
+            .set_span(span)),
         }
     }
 
@@ -3537,6 +3750,87 @@ impl<'a> TypeChecker<'a> {
         )))
     }
 
+    /// Resolve `ml_predict(<model_name>, <feature>, ...)`, the features are cast to the
+    /// argument types of the model and evaluated by the model inference transform.
+    #[async_recursion::async_recursion]
+    async fn resolve_ml_predict(
+        &mut self,
+        span: Span,
+        args: &[&Expr],
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        let Some((model_arg, features)) = args.split_first() else {
+            return Err(ErrorCode::BadArguments(
+                "ml_predict requires a model name and the features of the model",
+            )
+            .set_span(span));
+        };
+
+        let box (scalar, _) = self.resolve(model_arg).await?;
+        let model_name = match ConstantExpr::try_from(scalar) {
+            Ok(ConstantExpr {
+                value: Scalar::String(name),
+                ..
+            }) => name,
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "the model name of ml_predict must be a constant string",
+                )
+                .set_span(span));
+            }
+        };
+
+        let udf = UserApiProvider::instance()
+            .get_udf(&self.ctx.get_tenant(), &model_name)
+            .await?;
+        let model = match udf.map(|udf| udf.definition) {
+            Some(UDFDefinition::UDFModel(model)) => model,
+            _ => {
+                return Err(ErrorCode::UnknownFunction(format!(
+                    "no model matches the given name: '{model_name}'"
+                ))
+                .set_span(span));
+            }
+        };
+
+        if features.len() != model.arg_types.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "model '{model_name}' requires {} features, but got: {}",
+                model.arg_types.len(),
+                features.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut arguments = Vec::with_capacity(features.len());
+        for (feature, dest_type) in features.iter().zip(model.arg_types.iter()) {
+            let box (arg, ty) = self.resolve(feature).await?;
+            if ty != *dest_type {
+                arguments.push(wrap_cast(&arg, dest_type));
+            } else {
+                arguments.push(arg);
+            }
+        }
+
+        let arg_names = args.iter().map(|arg| format!("{}", arg)).join(", ");
+        let display_name = format!("ml_predict({})", arg_names);
+
+        self.ctx.set_cacheable(false);
+        Ok(Box::new((
+            UDFCall {
+                span,
+                name: model_name.clone(),
+                func_name: model_name,
+                display_name,
+                udf_type: UDFType::Model(model.location),
+                arg_types: model.arg_types,
+                return_type: Box::new(model.return_type.clone()),
+                arguments,
+            }
+            .into(),
+            model.return_type,
+        )))
+    }
+
     #[async_recursion::async_recursion]
     async fn resolve_lambda_udf(
         &mut self,
@@ -4184,6 +4478,7 @@ impl<'a> TypeChecker<'a> {
                             name,
                             args,
                             params,
+                            order_by,
                             window,
                             lambda,
                         },
@@ -4197,6 +4492,19 @@ impl<'a> TypeChecker<'a> {
                             .map(|arg| self.clone_expr_with_replacement(arg, replacement_fn))
                             .collect::<Result<Vec<Expr>>>()?,
                         params: params.clone(),
+                        order_by: order_by
+                            .iter()
+                            .map(|order_by| {
+                                Ok(OrderByExpr {
+                                    expr: self.clone_expr_with_replacement(
+                                        &order_by.expr,
+                                        replacement_fn,
+                                    )?,
+                                    asc: order_by.asc,
+                                    nulls_first: order_by.nulls_first,
+                                })
+                            })
+                            .collect::<Result<Vec<OrderByExpr>>>()?,
                         window: window.clone(),
                         lambda: lambda.clone(),
                     },
@@ -4388,6 +4696,8 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         }
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::Vector(dimension) => TableDataType::Vector(*dimension),
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -4490,3 +4800,25 @@ fn satisfy_contain_func(expr: &Expr) -> bool {
         _ => false,
     }
 }
+
+/// Rewrites a fixed interval `INTERVAL expr unit` to `expr * <microseconds of unit>`,
+/// returns `None` if the length of the unit is not fixed, e.g. `MONTH`.
+pub(crate) fn interval_to_micros(span: Span, expr: &Expr, unit: &ASTIntervalKind) -> Option<Expr> {
+    let micros: u64 = match unit {
+        ASTIntervalKind::Second => 1_000_000,
+        ASTIntervalKind::Minute => 60_000_000,
+        ASTIntervalKind::Hour => 3_600_000_000,
+        ASTIntervalKind::Day => 86_400_000_000,
+        ASTIntervalKind::Week => 604_800_000_000,
+        _ => return None,
+    };
+    Some(Expr::BinaryOp {
+        span,
+        op: BinaryOperator::Multiply,
+        left: Box::new(expr.clone()),
+        right: Box::new(Expr::Literal {
+            span,
+            value: Literal::UInt64(micros),
+        }),
+    })
+}
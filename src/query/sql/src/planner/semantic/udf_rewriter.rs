@@ -27,6 +27,7 @@ use crate::plans::RelOperator;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
 use crate::plans::UDFCall;
+use crate::plans::UDFKind;
 use crate::plans::Udf;
 use crate::plans::VisitorMut;
 use crate::ColumnBindingBuilder;
@@ -46,18 +47,18 @@ pub(crate) struct UdfRewriter {
     /// Mapping: (udf function display name) -> (derived index)
     /// This is used to reuse already generated derived columns
     udf_functions_index_map: HashMap<String, IndexType>,
-    script_udf: bool,
+    kind: UDFKind,
 }
 
 impl UdfRewriter {
-    pub(crate) fn new(metadata: MetadataRef, script_udf: bool) -> Self {
+    pub(crate) fn new(metadata: MetadataRef, kind: UDFKind) -> Self {
         Self {
             metadata,
             udf_arguments: Default::default(),
             udf_functions: Default::default(),
             udf_functions_map: Default::default(),
             udf_functions_index_map: Default::default(),
-            script_udf,
+            kind,
         }
     }
 
@@ -117,7 +118,7 @@ impl UdfRewriter {
 
             let udf_plan = Udf {
                 items: mem::take(&mut self.udf_functions),
-                script_udf: self.script_udf,
+                kind: self.kind,
             };
             Arc::new(SExpr::create_unary(Arc::new(udf_plan.into()), child_expr))
         } else {
@@ -133,7 +134,7 @@ impl<'a> VisitorMut<'a> for UdfRewriter {
         if let ScalarExpr::UDFCall(udf) = expr {
             if let Some(column_ref) = self.udf_functions_map.get(&udf.display_name) {
                 *expr = ScalarExpr::BoundColumnRef(column_ref.clone());
-            } else if udf.udf_type.match_type(self.script_udf) {
+            } else if udf.udf_type.kind() == self.kind {
                 return Err(ErrorCode::Internal("Rewrite udf function failed"));
             }
         }
@@ -141,7 +142,7 @@ impl<'a> VisitorMut<'a> for UdfRewriter {
     }
 
     fn visit_udf_call(&mut self, udf: &'a mut UDFCall) -> Result<()> {
-        if !udf.udf_type.match_type(self.script_udf) {
+        if udf.udf_type.kind() != self.kind {
             return Ok(());
         }
 
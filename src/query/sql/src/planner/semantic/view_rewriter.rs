@@ -56,3 +56,28 @@ impl ViewRewriter {
         }
     }
 }
+
+/// Rewrites the query of a view in a database created from a share. The tables of the
+/// query are qualified when the view is created, the database of the producer is
+/// replaced by the database mounted from the share, so only the shared tables are visible.
+#[derive(Debug, Clone, VisitorMut)]
+#[visitor(TableReference(enter))]
+pub struct ShareViewRewriter {
+    pub catalog: String,
+    pub database: String,
+}
+
+impl ShareViewRewriter {
+    fn enter_table_reference(&mut self, table_ref: &mut TableReference) {
+        if let TableReference::Table {
+            span,
+            catalog,
+            database: Some(database),
+            ..
+        } = table_ref
+        {
+            *catalog = Some(Identifier::from_name(*span, self.catalog.clone()));
+            *database = Identifier::from_name(*span, self.database.clone());
+        }
+    }
+}
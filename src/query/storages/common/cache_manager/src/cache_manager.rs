@@ -14,6 +14,7 @@
 
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::RwLock;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_cache::CountableMeter;
@@ -102,6 +103,27 @@ impl CacheManager {
             }
         };
 
+        let manager = Self::create(config, max_server_memory_usage, table_data_cache);
+        GlobalInstance::set(Arc::new(RwLock::new(Arc::new(manager))));
+        Ok(())
+    }
+
+    /// Rebuilds the in-memory caches with the new capacities, the items in them are
+    /// dropped. The disk cache of the table data is kept.
+    pub fn reload(config: &CacheConfig, max_server_memory_usage: &u64) {
+        let global = GlobalInstance::get::<Arc<RwLock<Arc<CacheManager>>>>();
+        let mut current = global.write().unwrap_or_else(|e| e.into_inner());
+        let table_data_cache = current.table_data_cache.clone();
+        let manager = Self::create(config, max_server_memory_usage, table_data_cache);
+        *current = Arc::new(manager);
+        info!("in-memory caches reloaded");
+    }
+
+    fn create(
+        config: &CacheConfig,
+        max_server_memory_usage: &u64,
+        table_data_cache: Option<TableDataCache>,
+    ) -> Self {
         // setup in-memory table column cache
         let memory_cache_capacity = if config.table_data_deserialized_data_bytes != 0 {
             config.table_data_deserialized_data_bytes
@@ -116,7 +138,7 @@ impl CacheManager {
 
         // setup in-memory table meta cache
         if !config.enable_table_meta_cache {
-            GlobalInstance::set(Arc::new(Self {
+            Self {
                 table_snapshot_cache: None,
                 segment_info_cache: None,
                 bloom_index_filter_cache: None,
@@ -128,7 +150,7 @@ impl CacheManager {
                 table_statistic_cache: None,
                 table_data_cache,
                 table_column_array_cache,
-            }));
+            }
         } else {
             let table_snapshot_cache =
                 Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
@@ -169,7 +191,7 @@ impl CacheManager {
 
             let file_meta_data_cache =
                 Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS, "parquet_file_meta");
-            GlobalInstance::set(Arc::new(Self {
+            Self {
                 table_snapshot_cache,
                 segment_info_cache,
                 bloom_index_filter_cache,
@@ -181,14 +203,14 @@ impl CacheManager {
                 table_statistic_cache,
                 table_data_cache,
                 table_column_array_cache,
-            }));
+            }
         }
-
-        Ok(())
     }
 
     pub fn instance() -> Arc<CacheManager> {
-        GlobalInstance::get()
+        let global = GlobalInstance::get::<Arc<RwLock<Arc<CacheManager>>>>();
+        let manager = global.read().unwrap_or_else(|e| e.into_inner());
+        manager.clone()
     }
 
     pub fn get_table_snapshot_cache(&self) -> Option<TableSnapshotCache> {
@@ -492,7 +492,7 @@ impl BloomIndex {
     /// If it does, the bloom index for the column will not be established.
     fn check_large_string(column: &Column) -> bool {
         if let Column::String(v) = &column {
-            let bytes_per_row = v.data().len() / v.len().max(1);
+            let bytes_per_row = v.total_bytes_len() / v.len().max(1);
             if bytes_per_row > 256 {
                 return true;
             }
@@ -506,7 +506,8 @@ fn visit_expr_column_eq_constant(
     visitor: &mut impl FnMut(Span, &str, &Scalar, &DataType, &DataType) -> Result<Option<Expr<String>>>,
 ) -> Result<()> {
     // Find patterns like `Column = <constant>`, `<constant> = Column`,
-    // or `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`
+    // `MapColumn[<key>] = <constant>`, `<constant> = MapColumn[<key>]`
+    // or `in_list(<constants>)(Column)`
     match expr {
         Expr::FunctionCall {
             span,
@@ -573,6 +574,38 @@ fn visit_expr_column_eq_constant(
             }
             _ => (),
         },
+        Expr::FunctionCall {
+            span,
+            id,
+            function,
+            args,
+            return_type,
+            ..
+        } if function.signature.name == "in_list" => {
+            if let [Expr::ColumnRef {
+                id: column_name,
+                data_type: column_type,
+                ..
+            }] = args.as_slice()
+            {
+                // `in_list(v1, v2, ...)(Column)` is the disjunction of `Column = v`,
+                // it's false if all of them are rewritten to false.
+                let mut all_false = true;
+                for scalar in id.params() {
+                    if visitor(*span, column_name, scalar, column_type, return_type)?.is_none() {
+                        all_false = false;
+                    }
+                }
+                if all_false {
+                    *expr = Expr::Constant {
+                        span: *span,
+                        scalar: Scalar::Boolean(false),
+                        data_type: return_type.clone(),
+                    };
+                }
+                return Ok(());
+            }
+        }
         _ => (),
     }
 
@@ -21,6 +21,7 @@ mod index;
 mod inverted_index;
 mod page_index;
 mod range_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
@@ -30,3 +31,5 @@ pub use inverted_index::InvertedIndexDirectory;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
+pub use vector_index::VectorDistance;
+pub use vector_index::VectorIndex;
@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::f32::consts::PI;
+
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_expression::TableDataType;
+use databend_common_vector::kmeans;
+use databend_common_vector::l2_distance;
+use databend_storages_common_table_meta::meta::VectorCluster;
+use databend_storages_common_table_meta::meta::VectorIndexMeta;
+
+/// The max number of clusters of the IVF index of a block.
+const MAX_CLUSTERS: usize = 8;
+const KMEANS_MAX_ITERATIONS: usize = 10;
+
+/// The relative slack applied to the distance bounds, so the rounding errors of
+/// the distances computed at query time never make a block be pruned by mistake.
+const BOUND_SLACK: f32 = 1e-4;
+
+/// The distance functions that can be accelerated by the vector index.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    pub fn from_func_name(name: &str) -> Option<Self> {
+        match name {
+            "cosine_distance" => Some(VectorDistance::Cosine),
+            "l2_distance" => Some(VectorDistance::L2),
+            _ => None,
+        }
+    }
+}
+
+/// Per-block IVF index of vector columns.
+pub struct VectorIndex;
+
+impl VectorIndex {
+    pub fn supported_type(data_type: &TableDataType) -> bool {
+        matches!(data_type.remove_nullable(), TableDataType::Vector(_))
+    }
+
+    /// Builds the index of a vector column, the NULL vectors are not indexed.
+    ///
+    /// Returns `None` if the column is not a column of Float32 arrays.
+    pub fn build(column: &Column) -> Option<VectorIndexMeta> {
+        let vectors = vectors_of_column(column)?;
+        let dimension = vectors.first().map_or(0, |v| v.len());
+        if vectors.iter().any(|v| v.len() != dimension) {
+            return None;
+        }
+
+        let num_clusters = MAX_CLUSTERS.min((vectors.len() as f64).sqrt().ceil() as usize);
+        let (centroids, assignments) =
+            kmeans(&vectors, dimension, num_clusters, KMEANS_MAX_ITERATIONS);
+
+        let mut clusters = centroids
+            .into_iter()
+            .map(|centroid| VectorCluster {
+                centroid: centroid.into_iter().map(F32::from).collect(),
+                radius: F32::from(0.0),
+                max_angle: F32::from(0.0),
+                row_count: 0,
+            })
+            .collect::<Vec<_>>();
+        for (vector, assignment) in vectors.iter().zip(assignments) {
+            let cluster = &mut clusters[assignment];
+            let centroid = as_f32_slice(&cluster.centroid);
+            let radius = l2_distance(centroid, vector).unwrap_or(f32::INFINITY);
+            let angle = angle_between(centroid, vector).unwrap_or(PI);
+            cluster.radius = cluster.radius.max(F32::from(radius));
+            cluster.max_angle = cluster.max_angle.max(F32::from(angle));
+            cluster.row_count += 1;
+        }
+
+        Some(VectorIndexMeta { clusters })
+    }
+
+    /// Returns the lower and upper bounds of the distances between the query vector
+    /// and the vectors of each cluster, along with the number of vectors in the cluster.
+    ///
+    /// The upper bound is `None` if the distances of the cluster can not be bounded,
+    /// e.g. the cosine distance of a zero vector is NaN.
+    /// Returns `None` if the dimension of the query vector does not match the index.
+    pub fn distance_bounds(
+        meta: &VectorIndexMeta,
+        distance: VectorDistance,
+        query: &[f32],
+    ) -> Option<Vec<(f32, Option<f32>, u64)>> {
+        let mut bounds = Vec::with_capacity(meta.clusters.len());
+        for cluster in &meta.clusters {
+            let centroid = as_f32_slice(&cluster.centroid);
+            if centroid.len() != query.len() {
+                return None;
+            }
+            let (lower, upper) = match distance {
+                VectorDistance::L2 => {
+                    let d = l2_distance(centroid, query).ok()?;
+                    let radius = cluster.radius.0;
+                    ((d - radius).max(0.0), Some(d + radius))
+                }
+                VectorDistance::Cosine => {
+                    let max_angle = cluster.max_angle.0;
+                    match angle_between(centroid, query) {
+                        Some(angle) if max_angle < PI => {
+                            let lower = (angle - max_angle).max(0.0);
+                            let upper = (angle + max_angle).min(PI);
+                            (1.0 - lower.cos(), Some(1.0 - upper.cos()))
+                        }
+                        _ => (0.0, None),
+                    }
+                }
+            };
+            bounds.push((
+                lower * (1.0 - BOUND_SLACK) - BOUND_SLACK,
+                upper.map(|upper| upper * (1.0 + BOUND_SLACK) + BOUND_SLACK),
+                cluster.row_count,
+            ));
+        }
+        Some(bounds)
+    }
+}
+
+/// Returns the angle between two vectors, `None` if any of them is a zero vector.
+fn angle_between(a: &[f32], b: &[f32]) -> Option<f32> {
+    let (mut dot, mut aa, mut bb) = (0.0f64, 0.0f64, 0.0f64);
+    for (x, y) in a.iter().zip(b.iter()) {
+        let (x, y) = (*x as f64, *y as f64);
+        dot += x * y;
+        aa += x * x;
+        bb += y * y;
+    }
+    if aa == 0.0 || bb == 0.0 {
+        return None;
+    }
+    Some((dot / (aa.sqrt() * bb.sqrt())).clamp(-1.0, 1.0).acos() as f32)
+}
+
+fn as_f32_slice(values: &[F32]) -> &[f32] {
+    // Safety: `F32` is a transparent wrapper of `f32`.
+    unsafe { std::mem::transmute::<&[F32], &[f32]>(values) }
+}
+
+fn vectors_of_column(column: &Column) -> Option<Vec<&[f32]>> {
+    let (column, validity) = match column {
+        Column::Nullable(box nullable) => (&nullable.column, Some(&nullable.validity)),
+        column => (column, None),
+    };
+    let Column::Array(box array) = column else {
+        return None;
+    };
+    let Column::Number(NumberColumn::Float32(values)) = &array.values else {
+        return None;
+    };
+    let values = as_f32_slice(values.as_slice());
+
+    Some(
+        array
+            .offsets
+            .windows(2)
+            .enumerate()
+            .filter(|(row, _)| validity.map_or(true, |v| v.get_bit(*row)))
+            .map(|(_, w)| &values[w[0] as usize..w[1] as usize])
+            .collect(),
+    )
+}
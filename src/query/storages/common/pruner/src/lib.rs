@@ -20,6 +20,7 @@ mod limiter_pruner;
 mod page_pruner;
 mod range_pruner;
 mod topn_pruner;
+mod vector_index_pruner;
 
 pub use block_meta::BlockMetaIndex;
 pub use internal_column_pruner::InternalColumnPruner;
@@ -31,3 +32,4 @@ pub use page_pruner::PagePrunerCreator;
 pub use range_pruner::RangePruner;
 pub use range_pruner::RangePrunerCreator;
 pub use topn_pruner::TopNPrunner;
+pub use vector_index_pruner::VectorIndexPruner;
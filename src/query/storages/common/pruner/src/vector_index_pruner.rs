@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::ColumnId;
+use databend_common_expression::TableSchemaRef;
+use databend_storages_common_index::VectorDistance;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::BlockVectorIndex;
+
+use crate::BlockMetaIndex;
+
+/// Vector index pruner.
+/// Pruning for order by cosine_distance/l2_distance(column, query) limit N.
+///
+/// The clusters of the IVF index of the blocks give the upper bounds of the distances
+/// of their vectors, so we can find a threshold that at least N vectors are within,
+/// and the blocks whose lower bound is greater than the threshold are pruned.
+///
+/// It is only applied when there are no filters: the number of vectors of each cluster
+/// that pass the filters is unknown, so no threshold can be derived from the clusters.
+pub struct VectorIndexPruner {
+    column_id: ColumnId,
+    distance: VectorDistance,
+    query: Vec<f32>,
+    limit: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn try_create(
+        schema: TableSchemaRef,
+        column_name: &str,
+        func_name: &str,
+        query: Vec<f32>,
+        limit: usize,
+    ) -> Option<Self> {
+        let field = schema.field_with_name(column_name).ok()?;
+        if !VectorIndex::supported_type(field.data_type()) {
+            return None;
+        }
+        Some(Self {
+            column_id: field.column_id(),
+            distance: VectorDistance::from_func_name(func_name)?,
+            query,
+            limit,
+        })
+    }
+
+    /// `indexes` are the IVF indexes loaded from the `vector_index_location` of `metas`,
+    /// in the same order, `None` if the block is not indexed.
+    pub fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+        indexes: Vec<Option<BlockVectorIndex>>,
+    ) -> Vec<(BlockMetaIndex, Arc<BlockMeta>)> {
+        if self.limit == 0 || self.limit >= metas.len() {
+            return metas;
+        }
+
+        // The lower bound of the distances of each block, `None` if the block is not indexed.
+        let mut lower_bounds = Vec::with_capacity(metas.len());
+        // The upper bounds of the distances and the number of vectors of the clusters.
+        let mut upper_bounds = Vec::new();
+        for ((_, meta), index) in metas.iter().zip(&indexes) {
+            let index = index
+                .as_ref()
+                .and_then(|index| index.columns.get(&self.column_id));
+            let Some(index) = index else {
+                lower_bounds.push(None);
+                continue;
+            };
+            let Some(bounds) = VectorIndex::distance_bounds(index, self.distance, &self.query)
+            else {
+                return metas;
+            };
+
+            let mut lower_bound = f32::INFINITY;
+            for (lower, upper, row_count) in bounds {
+                lower_bound = lower_bound.min(lower);
+                // The rows deleted by the deletion vector may be in any of the clusters.
+                if meta.deletion_vector.is_some() {
+                    continue;
+                }
+                if let Some(upper) = upper {
+                    upper_bounds.push((upper, row_count));
+                }
+            }
+            lower_bounds.push(Some(lower_bound));
+        }
+
+        // Find the smallest distance that at least `limit` vectors are within.
+        upper_bounds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut row_count = 0;
+        let mut threshold = None;
+        for (upper, num_rows) in upper_bounds {
+            row_count += num_rows;
+            if row_count >= self.limit as u64 {
+                threshold = Some(upper);
+                break;
+            }
+        }
+        let Some(threshold) = threshold else {
+            return metas;
+        };
+
+        metas
+            .into_iter()
+            .zip(lower_bounds)
+            .filter(|(_, lower_bound)| lower_bound.map_or(true, |lower| lower <= threshold))
+            .map(|(meta, _)| meta)
+            .collect()
+    }
+}
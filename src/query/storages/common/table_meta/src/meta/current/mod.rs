@@ -14,12 +14,16 @@
 
 pub use v0::ColumnMeta as SingleColumnMeta;
 pub use v2::BlockMeta;
+pub use v2::BlockVectorIndex;
 pub use v2::ClusterStatistics;
 pub use v2::ColumnMeta;
 pub use v2::ColumnStatistics;
+pub use v2::DeletionVectorMeta;
 pub use v2::MetaHLL;
 pub use v2::Statistics;
 pub use v2::TableSnapshotStatistics;
+pub use v2::VectorCluster;
+pub use v2::VectorIndexMeta;
 pub use v4::CompactSegmentInfo;
 pub use v4::IndexInfo;
 pub use v4::SegmentInfo;
@@ -18,8 +18,12 @@ pub mod statistics;
 mod table_snapshot_statistics;
 
 pub use segment::BlockMeta;
+pub use segment::BlockVectorIndex;
 pub use segment::ColumnMeta;
+pub use segment::DeletionVectorMeta;
 pub use segment::SegmentInfo;
+pub use segment::VectorCluster;
+pub use segment::VectorIndexMeta;
 pub use snapshot::TableSnapshot;
 pub use statistics::ClusterStatistics;
 pub use statistics::ColumnStatistics;
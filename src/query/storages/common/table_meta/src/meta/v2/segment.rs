@@ -19,6 +19,8 @@ use std::sync::Arc;
 use chrono::DateTime;
 use chrono::Utc;
 use databend_common_arrow::native::ColumnMeta as NativeColumnMeta;
+use databend_common_exception::Result;
+use databend_common_expression::types::F32;
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::ColumnId;
@@ -27,6 +29,9 @@ use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::meta::format::decode;
+use crate::meta::format::encode;
+use crate::meta::format::MetaEncoding;
 use crate::meta::v0;
 use crate::meta::v1;
 use crate::meta::ClusterStatistics;
@@ -81,6 +86,64 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// rows of this block that have been deleted without rewriting the block.
+    ///
+    /// NOTE: if deletion vector presents, `row_count` is the number of rows that are
+    /// still visible, see [`BlockMeta::physical_row_count`].
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorMeta>,
+
+    /// location of the IVF index of the vector columns listed in the `vector_index_columns`
+    /// table option, see [`BlockVectorIndex`].
+    #[serde(default)]
+    pub vector_index_location: Option<Location>,
+}
+
+/// Meta of the deletion vector (a serialized roaring bitmap of deleted row offsets)
+/// kept alongside a block.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DeletionVectorMeta {
+    pub location: Location,
+    pub size: u64,
+    pub deleted_rows: u64,
+}
+
+/// IVF indexes of the vector columns of a block, stored in its own file next to the block.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct BlockVectorIndex {
+    pub columns: HashMap<ColumnId, VectorIndexMeta>,
+}
+
+impl BlockVectorIndex {
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        encode(&MetaEncoding::MessagePack, self)
+    }
+
+    pub fn from_slice(data: &[u8]) -> Result<Self> {
+        decode(&MetaEncoding::MessagePack, data)
+    }
+}
+
+/// IVF (inverted file) index of a vector column in a block.
+///
+/// The vectors of the block are partitioned into clusters by k-means, each cluster
+/// keeps the bounds of its vectors, which are enough to get the lower and upper
+/// bounds of the distances between a query vector and the vectors of the block.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexMeta {
+    pub clusters: Vec<VectorCluster>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorCluster {
+    pub centroid: Vec<F32>,
+    /// The max l2 distance between the centroid and the vectors of the cluster.
+    pub radius: F32,
+    /// The max angle (in radians) between the centroid and the vectors of the cluster.
+    pub max_angle: F32,
+    /// The number of non-null vectors in the cluster.
+    pub row_count: u64,
 }
 
 impl BlockMeta {
@@ -110,6 +173,8 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector: None,
+            vector_index_location: None,
         }
     }
 
@@ -117,6 +182,15 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of rows physically stored in the block file, including the deleted ones.
+    pub fn physical_row_count(&self) -> u64 {
+        self.row_count
+            + self
+                .deletion_vector
+                .as_ref()
+                .map_or(0, |dv| dv.deleted_rows)
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -125,7 +199,7 @@ impl BlockMeta {
         if let Some((_, ColumnMeta::Native(meta))) = self.col_metas.iter().next() {
             meta.pages.first().unwrap().num_values
         } else {
-            self.row_count
+            self.physical_row_count()
         }
     }
 }
@@ -256,6 +330,8 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector: None,
+            vector_index_location: None,
         }
     }
 
@@ -288,6 +364,8 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector: None,
+            vector_index_location: None,
         }
     }
 }
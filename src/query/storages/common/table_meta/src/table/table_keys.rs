@@ -24,11 +24,25 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// Comma separated column names of the primary key.
+pub const OPT_KEY_PRIMARY_KEY: &str = "primary_key";
+// If true, INSERT rejects the rows whose primary key already exists in the table.
+pub const OPT_KEY_PRIMARY_KEY_ENFORCED: &str = "primary_key_enforced";
+// Comma separated names of the vector columns to build the per-block IVF index.
+pub const OPT_KEY_VECTOR_INDEX_COLUMNS: &str = "vector_index_columns";
+// The name of the storage policy in the config that the table data is stored on.
+pub const OPT_KEY_STORAGE_POLICY: &str = "storage_policy";
+// The name of the storage policy that the cold segments of the table are moved to.
+pub const OPT_KEY_COLD_STORAGE_POLICY: &str = "cold_storage_policy";
+// The segments whose blocks are all older than this number of days are cold.
+pub const OPT_KEY_COLD_AFTER_DAYS: &str = "cold_after_days";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 // Read only attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_READ_ONLY: &str = "read_only_attached";
+// The external location of the table as written in `CREATE TABLE`, without the credentials.
+pub const OPT_KEY_TABLE_LOCATION_URI: &str = "table_location_uri";
 
 // the following are used in for delta and iceberg engine
 pub const OPT_KEY_LOCATION: &str = "location";
@@ -56,6 +70,7 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_TABLE_LOCATION_URI);
     r
 });
 
@@ -66,9 +81,33 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
+    r.insert(OPT_KEY_TABLE_LOCATION_URI);
     r
 });
 
+/// Table option keys that `CREATE TABLE ... LIKE ... INCLUDING OPTIONS` does not copy,
+/// they are bound to the data or the identity of the source table.
+pub static NON_INHERITABLE_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> =
+    LazyLock::new(|| {
+        let mut r = HashSet::new();
+        r.insert(OPT_KEY_DATABASE_ID);
+        r.insert(OPT_KEY_STORAGE_PREFIX);
+        r.insert(OPT_KEY_SNAPSHOT_LOCATION);
+        r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+        r.insert(OPT_KEY_COMMENT);
+        r.insert(OPT_KEY_ENGINE);
+        r.insert(OPT_KEY_ENGINE_META);
+        r.insert(OPT_KEY_CHANGE_TRACKING);
+        r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
+        r.insert(OPT_KEY_TABLE_ATTACHED_DATA_URI);
+        r.insert(OPT_KEY_TABLE_ATTACHED_READ_ONLY);
+        r.insert(OPT_KEY_TABLE_LOCATION_URI);
+        r.insert(OPT_KEY_LOCATION);
+        r.insert(OPT_KEY_CONNECTION_NAME);
+        r.insert("transient");
+        r
+    });
+
 pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     RESERVED_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
@@ -76,3 +115,7 @@ pub fn is_reserved_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
 pub fn is_internal_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
     INTERNAL_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
 }
+
+pub fn is_inheritable_opt_key<S: AsRef<str>>(opt_key: S) -> bool {
+    !NON_INHERITABLE_TABLE_OPTION_KEYS.contains(opt_key.as_ref().to_lowercase().as_str())
+}
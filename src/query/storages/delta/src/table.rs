@@ -24,6 +24,7 @@ use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
@@ -382,4 +383,13 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        // The filters prune the partitions and the row groups of the data files.
+        PushDownCapabilities {
+            filter: true,
+            projection: true,
+            ..PushDownCapabilities::NONE
+        }
+    }
 }
@@ -23,6 +23,7 @@ use databend_common_catalog::lock::Lock;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StreamColumn;
 use databend_common_catalog::table::AppendMode;
@@ -34,11 +35,14 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
+use databend_common_expression::FieldIndex;
 use databend_common_expression::RemoteExpr;
 use databend_common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use databend_common_expression::ORIGIN_VERSION_COL_NAME;
 use databend_common_expression::ROW_VERSION_COL_NAME;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
 use databend_common_expression::SEARCH_SCORE_COLUMN_ID;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
@@ -56,6 +60,8 @@ use databend_common_storage::DataOperator;
 use databend_common_storage::ShareTableConfig;
 use databend_common_storage::StorageMetrics;
 use databend_common_storage::StorageMetricsLayer;
+use databend_common_storage::StoragePolicies;
+use databend_common_storage::TieringLayer;
 use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -68,14 +74,17 @@ use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_STORAGE_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
+use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_POLICY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
+use databend_storages_common_table_meta::table::OPT_KEY_VECTOR_INDEX_COLUMNS;
 use log::error;
 use log::warn;
 use opendal::Operator;
@@ -189,7 +198,16 @@ impl FuseTable {
                     }
                     // Normal table.
                     None => {
-                        let operator = DataOperator::instance().operator();
+                        let options = table_info.options();
+                        let mut operator = match options.get(OPT_KEY_STORAGE_POLICY) {
+                            Some(policy) => StoragePolicies::instance().get(policy)?.operator(),
+                            None => DataOperator::instance().operator(),
+                        };
+                        // The cold segments are read from the cold storage policy.
+                        if let Some(policy) = options.get(OPT_KEY_COLD_STORAGE_POLICY) {
+                            let cold = StoragePolicies::instance().get(policy)?.operator();
+                            operator = operator.layer(TieringLayer::new(cold));
+                        }
                         (operator, FuseTableType::Standard)
                     }
                 }
@@ -438,6 +456,18 @@ impl FuseTable {
         self.bloom_index_cols.clone()
     }
 
+    /// The fields of `schema` to build the vector index, keyed by the index in `schema`.
+    pub fn vector_index_fields(&self, schema: &TableSchemaRef) -> BTreeMap<FieldIndex, TableField> {
+        let Some(value) = self.table_info.options().get(OPT_KEY_VECTOR_INDEX_COLUMNS) else {
+            return BTreeMap::new();
+        };
+        value
+            .split(',')
+            .filter_map(|name| schema.index_of(name.trim()).ok())
+            .map(|index| (index, schema.field(index).clone()))
+            .collect()
+    }
+
     // Check if table is attached.
     fn is_table_attached(table_meta_options: &BTreeMap<String, String>) -> bool {
         table_meta_options
@@ -905,6 +935,15 @@ impl Table for FuseTable {
         true
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        PushDownCapabilities {
+            filter: true,
+            projection: true,
+            limit: true,
+            aggregate: true,
+        }
+    }
+
     fn support_virtual_columns(&self) -> bool {
         true
     }
@@ -31,10 +31,14 @@ use crate::constants::FUSE_TBL_SNAPSHOT_PREFIX;
 use crate::constants::FUSE_TBL_SNAPSHOT_STATISTICS_PREFIX;
 use crate::constants::FUSE_TBL_VIRTUAL_BLOCK_PREFIX;
 use crate::index::filters::BlockFilter;
+use crate::io::write::DELETION_VECTOR_VERSION;
+use crate::io::write::VECTOR_INDEX_VERSION;
 use crate::FUSE_TBL_AGG_INDEX_PREFIX;
+use crate::FUSE_TBL_DELETION_VECTOR_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_INFO_PREFIX;
 use crate::FUSE_TBL_INVERTED_INDEX_PREFIX;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::FUSE_TBL_VECTOR_INDEX_PREFIX;
 use crate::FUSE_TBL_XOR_BLOOM_INDEX_PREFIX;
 
 static SNAPSHOT_V0: SnapshotVersion = SnapshotVersion::V0(PhantomData);
@@ -102,6 +106,33 @@ impl TableMetaLocationGenerator {
         )
     }
 
+    pub fn block_vector_index_location(&self, block_id: &Uuid) -> Location {
+        (
+            format!(
+                "{}/{}/{}_v{}.mpk",
+                &self.prefix,
+                FUSE_TBL_VECTOR_INDEX_PREFIX,
+                block_id.as_simple(),
+                VECTOR_INDEX_VERSION,
+            ),
+            VECTOR_INDEX_VERSION,
+        )
+    }
+
+    pub fn gen_deletion_vector_location(&self) -> Location {
+        let uuid = Uuid::new_v4();
+        (
+            format!(
+                "{}/{}/{}_v{}.bin",
+                &self.prefix,
+                FUSE_TBL_DELETION_VECTOR_PREFIX,
+                uuid.as_simple(),
+                DELETION_VECTOR_VERSION,
+            ),
+            DELETION_VECTOR_VERSION,
+        )
+    }
+
     pub fn gen_segment_info_location(&self) -> String {
         let segment_uuid = Uuid::new_v4().simple().to_string();
         format!(
@@ -21,6 +21,10 @@ mod write;
 
 pub use files::Files;
 pub use locations::TableMetaLocationGenerator;
+pub use read::deletion_vector_to_filter;
+pub use read::read_deletion_vector;
+pub use read::read_deletion_vector_sync;
+pub use read::read_vector_index;
 pub use read::AggIndexReader;
 pub use read::BlockReader;
 pub use read::BloomBlockFilterReader;
@@ -40,6 +44,7 @@ pub use snapshots::SnapshotLiteExtended;
 pub use snapshots::SnapshotsIO;
 pub use write::serialize_block;
 pub use write::write_data;
+pub use write::write_deletion_vector;
 pub use write::BlockBuilder;
 pub use write::BlockSerialization;
 pub use write::CachedMetaWriter;
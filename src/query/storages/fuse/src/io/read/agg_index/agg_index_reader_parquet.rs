@@ -49,6 +49,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 let res = self
                     .reader
@@ -99,6 +100,7 @@ impl AggIndexReader {
                     None,
                     None,
                     None,
+                    None,
                 );
                 Some((part, res))
             }
@@ -111,7 +111,8 @@ impl BlockReader {
         // Get the columns chunk.
         let column_chunks = data.columns_chunks()?;
 
-        let num_rows = meta.row_count as usize;
+        // The rows deleted by the deletion vector are still in the block.
+        let num_rows = meta.physical_row_count() as usize;
 
         match storage_format {
             FuseStorageFormat::Parquet => self.deserialize_parquet_chunks(
@@ -17,6 +17,7 @@ use std::collections::HashSet;
 use std::ops::Range;
 use std::time::Instant;
 
+use databend_common_base::rangemap::split_range;
 use databend_common_base::rangemap::RangeMerger;
 use databend_common_base::runtime::UnlimitedFuture;
 use databend_common_exception::ErrorCode;
@@ -41,6 +42,9 @@ impl BlockReader {
     ///
     /// It will *NOT* merge two requests:
     /// if the last io request size is larger than storage_io_page_bytes_for_read(Default is 512KB).
+    ///
+    /// A merged range larger than storage_io_max_bytes_per_read_request(Default is 16MB) is
+    /// read by several concurrent requests.
     #[async_backtrace::framed]
     pub async fn merge_io_read(
         read_settings: &ReadSettings,
@@ -87,12 +91,12 @@ impl BlockReader {
                 metrics_inc_remote_io_read_bytes_after_merged(range.end - range.start);
             }
 
-            read_handlers.push(UnlimitedFuture::create(Self::read_range(
+            read_handlers.push(UnlimitedFuture::create(Self::read_split_range(
                 op.clone(),
                 location,
                 idx,
-                range.start,
-                range.end,
+                range.clone(),
+                read_settings.storage_io_max_bytes_per_read_request,
             )));
         }
 
@@ -200,6 +204,45 @@ impl BlockReader {
         Ok(merge_io_read_res)
     }
 
+    /// Read a range by the sub ranges no larger than `max_request_size` concurrently,
+    /// and concat them.
+    #[async_backtrace::framed]
+    async fn read_split_range(
+        op: Operator,
+        path: &str,
+        index: usize,
+        range: Range<u64>,
+        max_request_size: u64,
+    ) -> Result<(usize, Vec<u8>)> {
+        let sub_ranges = split_range(range.clone(), max_request_size);
+        if sub_ranges.len() == 1 {
+            return Self::read_range(op, path, index, range.start, range.end).await;
+        }
+
+        // Perf.
+        {
+            metrics_inc_remote_io_seeks_after_merged(sub_ranges.len() as u64 - 1);
+        }
+
+        let read_handlers = sub_ranges.iter().enumerate().map(|(idx, sub_range)| {
+            UnlimitedFuture::create(Self::read_range(
+                op.clone(),
+                path,
+                idx,
+                sub_range.start,
+                sub_range.end,
+            ))
+        });
+        // try_join_all keeps the order of the sub ranges.
+        let chunks = try_join_all(read_handlers).await?;
+
+        let mut data = Vec::with_capacity((range.end - range.start) as usize);
+        for (_, chunk) in chunks {
+            data.extend_from_slice(&chunk);
+        }
+        Ok((index, data))
+    }
+
     #[inline]
     #[async_backtrace::framed]
     async fn read_range(
@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// Loads the deletion vector of a block, i.e. the offsets of the rows that have been deleted.
+#[async_backtrace::framed]
+pub async fn read_deletion_vector(
+    dal: &Operator,
+    deletion_vector: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = dal.read(&deletion_vector.location.0).await?;
+    Ok(RoaringBitmap::deserialize_from(data.as_slice())?)
+}
+
+pub fn read_deletion_vector_sync(
+    dal: &Operator,
+    deletion_vector: &DeletionVectorMeta,
+) -> Result<RoaringBitmap> {
+    let data = dal.blocking().read(&deletion_vector.location.0)?;
+    Ok(RoaringBitmap::deserialize_from(data.as_slice())?)
+}
+
+/// Converts the deletion vector into a filter of `num_rows` bits, in which the deleted rows are unset.
+pub fn deletion_vector_to_filter(num_rows: usize, deleted: &RoaringBitmap) -> Bitmap {
+    let mut filter = MutableBitmap::from_len_set(num_rows);
+    for row in deleted.iter() {
+        filter.set(row as usize, false);
+    }
+    filter.into()
+}
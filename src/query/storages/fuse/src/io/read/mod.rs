@@ -15,11 +15,13 @@
 mod agg_index;
 mod block;
 pub mod bloom;
+mod deletion_vector_reader;
 mod inverted_index;
 pub mod meta;
 mod read_settings;
 mod snapshot_history_reader;
 mod utils;
+mod vector_index_reader;
 mod virtual_column;
 
 pub use agg_index::AggIndexReader;
@@ -28,6 +30,9 @@ pub use block::MergeIOReadResult;
 pub use block::NativeReaderExt;
 pub use block::NativeSourceData;
 pub use bloom::BloomBlockFilterReader;
+pub use deletion_vector_reader::deletion_vector_to_filter;
+pub use deletion_vector_reader::read_deletion_vector;
+pub use deletion_vector_reader::read_deletion_vector_sync;
 pub use inverted_index::load_inverted_index_info;
 pub use inverted_index::InvertedIndexReader;
 pub use meta::CompactSegmentInfoReader;
@@ -35,5 +40,6 @@ pub use meta::MetaReaders;
 pub use meta::TableSnapshotReader;
 pub use read_settings::ReadSettings;
 pub use snapshot_history_reader::SnapshotHistoryReader;
+pub use vector_index_reader::read_vector_index;
 pub use virtual_column::VirtualColumnReader;
 pub use virtual_column::VirtualMergeIOReadResult;
@@ -0,0 +1,25 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::BlockVectorIndex;
+use databend_storages_common_table_meta::meta::Location;
+use opendal::Operator;
+
+/// Loads the IVF index of the vector columns of a block.
+#[async_backtrace::framed]
+pub async fn read_vector_index(dal: &Operator, location: &Location) -> Result<BlockVectorIndex> {
+    let data = dal.read(&location.0).await?;
+    BlockVectorIndex::from_slice(data.as_slice())
+}
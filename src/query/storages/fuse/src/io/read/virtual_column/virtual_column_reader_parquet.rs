@@ -91,6 +91,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result =
@@ -133,6 +134,7 @@ impl VirtualColumnReader {
                 None,
                 None,
                 None,
+                None,
             );
 
             let merge_io_result = BlockReader::merge_io_read(
@@ -20,17 +20,22 @@ use chrono::Utc;
 use databend_common_arrow::arrow::chunk::Chunk as ArrowChunk;
 use databend_common_arrow::native::write::NativeWriter;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Column;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FieldIndex;
+use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchemaRef;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_INDEX_BUFFER_SIZE;
 use databend_storages_common_blocks::blocks_to_parquet;
 use databend_storages_common_index::BloomIndex;
+use databend_storages_common_index::VectorIndex;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::BlockVectorIndex;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::Location;
@@ -151,11 +156,44 @@ impl BloomIndexState {
     }
 }
 
+pub const VECTOR_INDEX_VERSION: u64 = 0;
+
+/// The IVF index of the vector columns of a block, written to its own file next to the block.
+pub struct VectorIndexState {
+    pub data: Vec<u8>,
+    pub location: Location,
+}
+
+impl VectorIndexState {
+    pub fn try_create(
+        block: &DataBlock,
+        location: Location,
+        vector_index_fields: &BTreeMap<FieldIndex, TableField>,
+    ) -> Result<Option<Self>> {
+        if vector_index_fields.is_empty() {
+            return Ok(None);
+        }
+        let columns = vector_index_fields
+            .iter()
+            .filter_map(|(offset, field)| {
+                let entry = block.get_by_offset(*offset);
+                let column = entry
+                    .value
+                    .convert_to_full_column(&entry.data_type, block.num_rows());
+                VectorIndex::build(&column).map(|meta| (field.column_id(), meta))
+            })
+            .collect();
+        let data = BlockVectorIndex { columns }.to_bytes()?;
+        Ok(Some(Self { data, location }))
+    }
+}
+
 pub struct BlockSerialization {
     pub block_raw_data: Vec<u8>,
     pub size: u64, // TODO redundancy
     pub block_meta: BlockMeta,
     pub bloom_index_state: Option<BloomIndexState>,
+    pub vector_index_state: Option<VectorIndexState>,
 }
 
 #[derive(Clone)]
@@ -166,6 +204,7 @@ pub struct BlockBuilder {
     pub write_settings: WriteSettings,
     pub cluster_stats_gen: ClusterStatsGenerator,
     pub bloom_columns_map: BTreeMap<FieldIndex, TableField>,
+    pub vector_index_fields: BTreeMap<FieldIndex, TableField>,
 }
 
 impl BlockBuilder {
@@ -173,6 +212,7 @@ impl BlockBuilder {
     where F: Fn(DataBlock, &ClusterStatsGenerator) -> Result<(Option<ClusterStatistics>, DataBlock)>
     {
         let (cluster_stats, data_block) = f(data_block, &self.cluster_stats_gen)?;
+        check_vector_dimensions(&data_block, &self.source_schema)?;
         let (block_location, block_id) = self.meta_locations.gen_block_location();
 
         let bloom_index_location = self.meta_locations.block_bloom_index_location(&block_id);
@@ -190,6 +230,12 @@ impl BlockBuilder {
         let block_size = data_block.memory_size() as u64;
         let col_stats =
             gen_columns_statistics(&data_block, column_distinct_count, &self.source_schema)?;
+        let vector_index_location = self.meta_locations.block_vector_index_location(&block_id);
+        let vector_index_state = VectorIndexState::try_create(
+            &data_block,
+            vector_index_location,
+            &self.vector_index_fields,
+        )?;
 
         let mut buffer = Vec::with_capacity(DEFAULT_BLOCK_BUFFER_SIZE);
         let col_metas = serialize_block(
@@ -214,6 +260,8 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.into(),
             create_on: Some(Utc::now()),
+            deletion_vector: None,
+            vector_index_location: vector_index_state.as_ref().map(|v| v.location.clone()),
         };
 
         let serialized = BlockSerialization {
@@ -221,7 +269,39 @@ impl BlockBuilder {
             size: file_size,
             block_meta,
             bloom_index_state,
+            vector_index_state,
         };
         Ok(serialized)
     }
 }
+
+/// Checks the dimension of the vectors written to the `VECTOR(FLOAT32, n)` columns.
+fn check_vector_dimensions(block: &DataBlock, schema: &TableSchemaRef) -> Result<()> {
+    for (entry, field) in block.columns().iter().zip(schema.fields()) {
+        let TableDataType::Vector(dimension) = field.data_type().remove_nullable() else {
+            continue;
+        };
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        let (column, validity) = match &column {
+            Column::Nullable(nullable) => (&nullable.column, Some(&nullable.validity)),
+            column => (column, None),
+        };
+        let Column::Array(array) = column else {
+            continue;
+        };
+        for (row, w) in array.offsets.windows(2).enumerate() {
+            let len = w[1] - w[0];
+            if len != dimension && validity.map_or(true, |v| v.get_bit(row)) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The vector of column '{}' must have {} dimensions, but got {}",
+                    field.name(),
+                    dimension,
+                    len
+                )));
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::DeletionVectorMeta;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+use crate::io::write_data;
+use crate::io::TableMetaLocationGenerator;
+
+pub const DELETION_VECTOR_VERSION: u64 = 0;
+
+/// Persists the offsets of the deleted rows of a block as a roaring bitmap sidecar file.
+#[async_backtrace::framed]
+pub async fn write_deletion_vector(
+    dal: &Operator,
+    location_gen: &TableMetaLocationGenerator,
+    deleted: &RoaringBitmap,
+) -> Result<DeletionVectorMeta> {
+    let mut data = Vec::with_capacity(deleted.serialized_size());
+    deleted.serialize_into(&mut data)?;
+
+    let location = location_gen.gen_deletion_vector_location();
+    let size = data.len() as u64;
+    write_data(data, dal, &location.0).await?;
+    Ok(DeletionVectorMeta {
+        location,
+        size,
+        deleted_rows: deleted.len(),
+    })
+}
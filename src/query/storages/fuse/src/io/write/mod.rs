@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod block_writer;
+mod deletion_vector_writer;
 mod inverted_index_writer;
 mod meta_writer;
 mod segment_writer;
@@ -22,6 +23,10 @@ pub use block_writer::serialize_block;
 pub use block_writer::write_data;
 pub use block_writer::BlockBuilder;
 pub use block_writer::BlockSerialization;
+pub use block_writer::VectorIndexState;
+pub use block_writer::VECTOR_INDEX_VERSION;
+pub use deletion_vector_writer::write_deletion_vector;
+pub use deletion_vector_writer::DELETION_VECTOR_VERSION;
 pub(crate) use inverted_index_writer::create_tokenizer_manager;
 pub use inverted_index_writer::InvertedIndexWriter;
 pub use meta_writer::CachedMetaWriter;
@@ -48,9 +48,14 @@ impl FuseTable {
         append_mode: AppendMode,
     ) -> Result<()> {
         let block_thresholds = self.get_block_thresholds();
+        let max_threads = ctx.get_settings().get_max_threads()? as usize;
+        let preserve_order = ctx.get_settings().get_enable_insert_preserve_order()?;
 
         match append_mode {
             AppendMode::Normal => {
+                if preserve_order {
+                    pipeline.try_resize(1)?;
+                }
                 pipeline.add_transform(|transform_input_port, transform_output_port| {
                     Ok(ProcessorPtr::create(TransformCompact::try_create(
                         transform_input_port,
@@ -68,10 +73,18 @@ impl FuseTable {
                         BlockCompactorForCopy::new(block_thresholds),
                     )?))
                 })?;
-                pipeline.try_resize(ctx.get_settings().get_max_threads()? as usize)?;
+                if !preserve_order {
+                    pipeline.try_resize(max_threads)?;
+                }
             }
         }
 
+        // the compacted blocks are serialized in parallel, each of the transforms below outputs
+        // exactly one block per input, thus the blocks can be merged back in their original order.
+        if preserve_order {
+            pipeline.try_ordered_split(max_threads)?;
+        }
+
         let schema = DataSchema::from(self.schema()).into();
         let cluster_stats_gen =
             self.cluster_gen_for_append(ctx.clone(), pipeline, block_thresholds, Some(schema))?;
@@ -87,6 +100,10 @@ impl FuseTable {
             proc.into_processor()
         })?;
 
+        if preserve_order {
+            pipeline.try_ordered_merge()?;
+        }
+
         Ok(())
     }
 
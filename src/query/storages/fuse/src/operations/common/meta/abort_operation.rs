@@ -28,6 +28,8 @@ pub struct AbortOperation {
     pub segments: Vec<String>,
     pub blocks: Vec<String>,
     pub bloom_filter_indexes: Vec<String>,
+    #[serde(default)]
+    pub vector_indexes: Vec<String>,
 }
 
 impl AbortOperation {
@@ -35,6 +37,7 @@ impl AbortOperation {
         self.segments.extend(rhs.segments);
         self.blocks.extend(rhs.blocks);
         self.bloom_filter_indexes.extend(rhs.bloom_filter_indexes);
+        self.vector_indexes.extend(rhs.vector_indexes);
     }
 
     pub fn add_block(&mut self, block: &BlockMeta) {
@@ -43,6 +46,9 @@ impl AbortOperation {
         if let Some(index) = block.bloom_filter_index_location.clone() {
             self.bloom_filter_indexes.push(index.0);
         }
+        if let Some(index) = block.vector_index_location.clone() {
+            self.vector_indexes.push(index.0);
+        }
     }
 
     pub fn add_segment(&mut self, segment: String) {
@@ -63,6 +69,7 @@ impl AbortOperation {
             .blocks
             .into_iter()
             .chain(self.bloom_filter_indexes.into_iter())
+            .chain(self.vector_indexes.into_iter())
             .chain(self.segments.into_iter());
         fuse_file.remove_file_in_batch(locations).await
     }
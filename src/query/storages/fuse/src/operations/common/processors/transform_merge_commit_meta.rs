@@ -112,6 +112,12 @@ impl TransformMergeCommitMeta {
                     .into_iter()
                     .chain(r.abort_operation.bloom_filter_indexes)
                     .collect(),
+                vector_indexes: l
+                    .abort_operation
+                    .vector_indexes
+                    .into_iter()
+                    .chain(r.abort_operation.vector_indexes)
+                    .collect(),
             },
             table_id: l.table_id,
         }
@@ -33,10 +33,12 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_storage::MultipartUpload;
 use databend_storages_common_index::BloomIndex;
 use opendal::Operator;
 
 use crate::io::write_data;
+use crate::io::write_deletion_vector;
 use crate::io::BlockBuilder;
 use crate::io::BlockSerialization;
 use crate::operations::common::BlockMetaIndex;
@@ -44,6 +46,7 @@ use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
 
@@ -59,6 +62,7 @@ enum State {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
     },
+    WriteDeletionVector(SerializeDeletionVector),
 }
 
 pub struct TransformSerializeBlock {
@@ -69,6 +73,7 @@ pub struct TransformSerializeBlock {
 
     block_builder: BlockBuilder,
     dal: Operator,
+    multipart: MultipartUpload,
     table_id: Option<u64>, // Only used in mutli table insert
 }
 
@@ -126,6 +131,12 @@ impl TransformSerializeBlock {
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
+        let vector_index_fields = table.vector_index_fields(&source_schema);
+        let settings = ctx.get_settings();
+        let multipart = MultipartUpload::new(
+            settings.get_storage_write_part_size()? as usize,
+            settings.get_storage_write_concurrency()? as usize,
+        );
         let block_builder = BlockBuilder {
             ctx,
             meta_locations: table.meta_location_generator().clone(),
@@ -133,6 +144,7 @@ impl TransformSerializeBlock {
             write_settings: table.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            vector_index_fields,
         };
         Ok(TransformSerializeBlock {
             state: State::Consume,
@@ -141,6 +153,7 @@ impl TransformSerializeBlock {
             output_data: None,
             block_builder,
             dal: table.get_operator(),
+            multipart,
             table_id: if with_tid { Some(table.get_id()) } else { None },
         })
     }
@@ -183,7 +196,10 @@ impl Processor for TransformSerializeBlock {
             return Ok(Event::Sync);
         }
 
-        if matches!(self.state, State::Serialized { .. }) {
+        if matches!(
+            self.state,
+            State::Serialized { .. } | State::WriteDeletionVector(_)
+        ) {
             return Ok(Event::Async);
         }
 
@@ -241,6 +257,11 @@ impl Processor for TransformSerializeBlock {
                         Ok(Event::Sync)
                     }
                 }
+                SerializeDataMeta::SerializeDeletionVector(deletion_vector) => {
+                    // record the deleted rows, the block itself stays untouched
+                    self.state = State::WriteDeletionVector(deletion_vector);
+                    Ok(Event::Async)
+                }
                 SerializeDataMeta::CompactExtras(compact_extras) => {
                     // compact extras
                     let data_block = Self::mutation_logs(MutationLogEntry::CompactExtras {
@@ -303,7 +324,10 @@ impl Processor for TransformSerializeBlock {
                 let raw_block_data = serialized.block_raw_data;
                 let data_size = raw_block_data.len();
                 let path = serialized.block_meta.location.0.as_str();
-                write_data(raw_block_data, &self.dal, path).await?;
+                // Large blocks are uploaded in parts concurrently.
+                self.multipart
+                    .write(&self.dal, path, raw_block_data)
+                    .await?;
 
                 // Perf.
                 {
@@ -331,6 +355,14 @@ impl Processor for TransformSerializeBlock {
                         );
                     }
                 }
+                if let Some(vector_index_state) = serialized.vector_index_state {
+                    write_data(
+                        vector_index_state.data,
+                        &self.dal,
+                        &vector_index_state.location.0,
+                    )
+                    .await?;
+                }
 
                 let data_block = if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
@@ -357,6 +389,28 @@ impl Processor for TransformSerializeBlock {
                 };
                 self.output_data = Some(data_block);
             }
+            State::WriteDeletionVector(SerializeDeletionVector {
+                index,
+                block_meta,
+                deleted_rows,
+            }) => {
+                let deletion_vector = write_deletion_vector(
+                    &self.dal,
+                    &self.block_builder.meta_locations,
+                    &deleted_rows,
+                )
+                .await?;
+
+                let mut new_block_meta = block_meta.as_ref().clone();
+                new_block_meta.row_count =
+                    block_meta.physical_row_count() - deletion_vector.deleted_rows;
+                new_block_meta.deletion_vector = Some(deletion_vector);
+
+                self.output_data = Some(Self::mutation_logs(MutationLogEntry::ReplacedBlock {
+                    index,
+                    block_meta: Arc::new(new_block_meta),
+                }));
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
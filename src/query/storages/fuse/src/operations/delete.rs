@@ -103,6 +103,7 @@ impl FuseTable {
         filter: &RemoteExpr<String>,
         col_indices: Vec<usize>,
         query_row_id_col: bool,
+        returning: Vec<RemoteExpr<String>>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let projection = Projection::Columns(col_indices.clone());
@@ -110,6 +111,7 @@ impl FuseTable {
         let update_stream_columns = self.change_tracking_enabled();
         let block_reader =
             self.create_block_reader(ctx.clone(), projection, false, update_stream_columns, false)?;
+        let returning = MutationSource::returning_operator(returning, &block_reader.schema())?;
         let mut schema = block_reader.schema().as_ref().clone();
         if query_row_id_col {
             schema.add_internal_field(
@@ -172,6 +174,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    returning.clone(),
                 )
             },
             max_threads,
@@ -326,6 +329,7 @@ impl FuseTable {
                     let part_info_ptr: PartInfoPtr =
                         Arc::new(Box::new(Mutation::MutationPartInfo(MutationPartInfo {
                             index,
+                            block_meta,
                             cluster_stats,
                             inner_part,
                             whole_block_mutation,
@@ -348,6 +348,26 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
+            for loc in &locations.vector_index_location {
+                if locations_referenced_by_root
+                    .vector_index_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -393,6 +413,26 @@ impl FuseTable {
                 }
             }
 
+            // deletion vectors and vector indexes are purged together with the blocks they belong to.
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                blocks_to_be_purged.insert(loc.to_string());
+            }
+            for loc in &locations.vector_index_location {
+                if locations_referenced_by_root
+                    .vector_index_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                blocks_to_be_purged.insert(loc.to_string());
+            }
+
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
                 if locations_referenced_by_root.bloom_location.contains(loc) {
@@ -461,10 +501,14 @@ impl FuseTable {
             }));
         }
 
+        let mut blocks_to_be_purged = root_location_tuple.block_location;
+        blocks_to_be_purged.extend(root_location_tuple.deletion_vector_location);
+        blocks_to_be_purged.extend(root_location_tuple.vector_index_location);
+
         self.purge_block_segments(
             ctx,
             counter,
-            root_location_tuple.block_location,
+            blocks_to_be_purged,
             agg_indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
@@ -619,6 +663,8 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
+        let mut vector_indexes = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -642,12 +688,16 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
+                vector_indexes.extend(location_tuple.vector_index_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
+            vector_index_location: vector_indexes,
         })
     }
 
@@ -671,6 +721,8 @@ struct RootSnapshotInfo {
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
+    pub vector_index_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -678,16 +730,26 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
+        let mut vector_index_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(deletion_vector) = &block_meta.deletion_vector {
+                deletion_vector_location.insert(deletion_vector.location.0.clone());
+            }
+            if let Some(vector_index) = &block_meta.vector_index_location {
+                vector_index_location.insert(vector_index.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
+            vector_index_location,
         })
     }
 }
@@ -89,6 +89,7 @@ impl FuseTable {
         let bloom_columns_map = self
             .bloom_index_cols()
             .bloom_index_fields(new_schema.clone(), BloomIndex::supported_type)?;
+        let vector_index_fields = self.vector_index_fields(&new_schema);
         let block_builder = BlockBuilder {
             ctx: ctx.clone(),
             meta_locations: self.meta_location_generator().clone(),
@@ -96,6 +97,7 @@ impl FuseTable {
             write_settings: self.get_write_settings(),
             cluster_stats_gen,
             bloom_columns_map,
+            vector_index_fields,
         };
         let aggregator = MatchedAggregator::create(
             ctx,
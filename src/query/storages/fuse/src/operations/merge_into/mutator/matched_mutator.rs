@@ -49,6 +49,7 @@ use itertools::Itertools;
 use log::info;
 use opendal::Operator;
 
+use crate::io::read_deletion_vector;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
@@ -406,10 +407,22 @@ impl AggregationContext {
             origin_data_block.add_column(row_num);
         }
 
+        // rows deleted by the deletion vector are dropped along with the modified ones
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(deletion_vector) => {
+                Some(read_deletion_vector(&self.block_reader.operator, deletion_vector).await?)
+            }
+            None => None,
+        };
+
         // apply delete
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || deleted_rows
+                    .as_ref()
+                    .is_some_and(|deleted_rows| deleted_rows.contains(row as u32))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -464,6 +477,9 @@ impl AggregationContext {
         let new_block_raw_data = serialized.block_raw_data;
         let data_accessor = self.data_accessor.clone();
         write_data(new_block_raw_data, &data_accessor, &new_block_location).await?;
+        if let Some(index_state) = serialized.vector_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         metrics_inc_merge_into_replace_blocks_counter(1);
         metrics_inc_merge_into_replace_blocks_rows_counter(origin_num_rows as u32);
@@ -33,6 +33,7 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tiering;
 mod truncate;
 mod update;
 mod util;
@@ -47,6 +48,7 @@ pub use mutation::*;
 pub use read::build_row_fetcher_pipeline;
 pub use read::need_reserve_block_info;
 pub use replace_into::*;
+pub use tiering::TieringResult;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
@@ -23,6 +23,7 @@ pub use compact_part::CompactTaskInfo;
 pub use mutation_meta::ClusterStatsGenType;
 pub use mutation_meta::SerializeBlock;
 pub use mutation_meta::SerializeDataMeta;
+pub use mutation_meta::SerializeDeletionVector;
 pub use mutation_part::DeletedSegmentInfo;
 pub use mutation_part::Mutation;
 pub use mutation_part::MutationPartInfo;
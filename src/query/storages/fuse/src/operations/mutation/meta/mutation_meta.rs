@@ -12,9 +12,13 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
+use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use roaring::RoaringBitmap;
 
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactExtraInfo;
@@ -23,6 +27,7 @@ use crate::operations::mutation::DeletedSegmentInfo;
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
 pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
+    SerializeDeletionVector(SerializeDeletionVector),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
 }
@@ -55,3 +60,27 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// Rows deleted from a block, which are recorded in a deletion vector
+/// instead of rewriting the block.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct SerializeDeletionVector {
+    pub index: BlockMetaIndex,
+    pub block_meta: Arc<BlockMeta>,
+    /// offsets of all the deleted rows of the block, including the ones deleted before.
+    pub deleted_rows: RoaringBitmap,
+}
+
+impl SerializeDeletionVector {
+    pub fn create(
+        index: BlockMetaIndex,
+        block_meta: Arc<BlockMeta>,
+        deleted_rows: RoaringBitmap,
+    ) -> Self {
+        SerializeDeletionVector {
+            index,
+            block_meta,
+            deleted_rows,
+        }
+    }
+}
@@ -30,7 +30,10 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::StreamContext;
 use databend_storages_common_table_meta::meta::BlockMeta;
+use roaring::RoaringBitmap;
 
+use crate::io::deletion_vector_to_filter;
+use crate::io::read_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::mutation::ClusterStatsGenType;
@@ -44,7 +47,7 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        read_res: Vec<(MergeIOReadResult, Option<RoaringBitmap>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -138,7 +141,7 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, deleted_rows), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
@@ -149,6 +152,12 @@ impl Processor for CompactSource {
                             let stream_meta = gen_mutation_stream_meta(None, &meta.location.0)?;
                             block = stream_ctx.apply(block, &stream_meta)?;
                         }
+
+                        // Remove the rows deleted by the deletion vector.
+                        if let Some(deleted_rows) = deleted_rows {
+                            let filter = deletion_vector_to_filter(block.num_rows(), &deleted_rows);
+                            block = block.filter_with_bitmap(&filter)?;
+                        }
                         Ok(block)
                     })
                     .collect::<Result<Vec<_>>>()?;
@@ -205,14 +214,25 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let deleted_rows = match &block.deletion_vector {
+                                    Some(deletion_vector) => Some(
+                                        read_deletion_vector(
+                                            &block_reader.operator,
+                                            deletion_vector,
+                                        )
+                                        .await?,
+                                    ),
+                                    None => None,
+                                };
+                                let data = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                Ok::<_, ErrorCode>((data, deleted_rows))
                             });
                         }
 
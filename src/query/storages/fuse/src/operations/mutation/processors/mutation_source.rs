@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::build_origin_block_row_num;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
@@ -33,6 +34,8 @@ use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Evaluator;
 use databend_common_expression::Expr;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::TableSchema;
 use databend_common_expression::Value;
 use databend_common_expression::ROW_ID_COL_NAME;
 use databend_common_functions::BUILTIN_FUNCTIONS;
@@ -41,8 +44,12 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::evaluator::BlockOperator;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use roaring::RoaringBitmap;
 
 use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::deletion_vector_to_filter;
+use crate::io::read_deletion_vector;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
 use crate::operations::common::BlockMetaIndex;
@@ -50,6 +57,7 @@ use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
+use crate::operations::mutation::SerializeDeletionVector;
 use crate::FuseStorageFormat;
 use crate::MergeIOReadResult;
 
@@ -89,9 +97,20 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    // evaluates the `RETURNING` clause on the affected rows.
+    returning: Option<BlockOperator>,
+    // the rows of the current block matched by the update filter, None means all rows.
+    affected: Option<Value<BooleanType>>,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+
+    deletion_vector_threshold: u64,
+    block_meta: Option<Arc<BlockMeta>>,
+    // the rows deleted before, which are recorded in the deletion vector of the block.
+    deleted_rows: Option<RoaringBitmap>,
+    // the filter used to remove the rows that deleted before.
+    deletion_filter: Option<Bitmap>,
 }
 
 impl MutationSource {
@@ -106,7 +125,9 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        returning: Option<BlockOperator>,
     ) -> Result<ProcessorPtr> {
+        let deletion_vector_threshold = ctx.get_settings().get_deletion_vector_threshold()?;
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
             output,
@@ -118,10 +139,95 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            returning,
+            affected: None,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deletion_vector_threshold,
+            block_meta: None,
+            deleted_rows: None,
+            deletion_filter: None,
         })))
     }
+
+    /// Build the operator evaluating the `RETURNING` expressions, the column references
+    /// are resolved by name against `schema`, the layout of the blocks it's applied to.
+    pub fn returning_operator(
+        returning: Vec<RemoteExpr<String>>,
+        schema: &TableSchema,
+    ) -> Result<Option<BlockOperator>> {
+        if returning.is_empty() {
+            return Ok(None);
+        }
+
+        let mut exprs = Vec::with_capacity(returning.len());
+        for remote_expr in returning {
+            let expr = remote_expr.as_expr(&BUILTIN_FUNCTIONS);
+            for name in expr.column_refs().keys() {
+                schema.index_of(name).map_err(|_| {
+                    ErrorCode::Unimplemented(format!(
+                        "Column `{}` can't be referenced in RETURNING",
+                        name
+                    ))
+                })?;
+            }
+            exprs.push(expr.project_column_ref(|name| schema.index_of(name).unwrap()));
+        }
+        Ok(Some(BlockOperator::Map {
+            exprs,
+            projections: None,
+        }))
+    }
+
+    fn add_returning_block(&self, data_block: DataBlock) -> Result<()> {
+        if let Some(returning) = &self.returning {
+            if data_block.is_empty() {
+                return Ok(());
+            }
+            let num_columns = data_block.num_columns();
+            let func_ctx = self.ctx.get_function_context()?;
+            let block = returning.execute(&func_ctx, data_block)?;
+            let columns = block.columns()[num_columns..].to_vec();
+            self.ctx
+                .add_returning_block(DataBlock::new(columns, block.num_rows()));
+        }
+        Ok(())
+    }
+
+    /// Try to record the rows matched by `predicate` in the deletion vector of the block.
+    ///
+    /// Returns the offsets of all the deleted rows of the block, or None if the block
+    /// should be rewritten, i.e. the deleted rows would exceed `deletion_vector_threshold`
+    /// percent of the block.
+    fn try_extend_deletion_vector(
+        &self,
+        predicate: &Bitmap,
+        offsets: &Option<Vec<usize>>,
+    ) -> Option<RoaringBitmap> {
+        // deletion vector is only applied by the parquet reader, and the origin row numbers
+        // of stream columns rely on the rows being rewritten.
+        if self.deletion_vector_threshold == 0
+            || !matches!(self.storage_format, FuseStorageFormat::Parquet)
+            || self.block_reader.update_stream_columns()
+        {
+            return None;
+        }
+
+        let block_meta = self.block_meta.as_ref()?;
+        let mut deleted_rows = self.deleted_rows.clone().unwrap_or_default();
+        for (idx, deleted) in predicate.iter().enumerate() {
+            if deleted {
+                let offset = offsets.as_ref().map_or(idx, |offsets| offsets[idx]);
+                deleted_rows.insert(offset as u32);
+            }
+        }
+
+        let physical_rows = block_meta.physical_row_count();
+        if deleted_rows.len() * 100 > physical_rows * self.deletion_vector_threshold {
+            return None;
+        }
+        Some(deleted_rows)
+    }
 }
 
 #[async_trait::async_trait]
@@ -182,7 +288,22 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+
+                // Remove the rows that have been deleted before.
+                let mut offsets = None;
+                if let Some(deleted_rows) = &self.deleted_rows {
+                    let deletion_filter =
+                        deletion_vector_to_filter(data_block.num_rows(), deleted_rows);
+                    data_block = data_block.filter_with_bitmap(&deletion_filter)?;
+                    offsets = Some(
+                        (0..deletion_filter.len())
+                            .filter(|idx| deletion_filter.get_bit(*idx))
+                            .collect::<Vec<_>>(),
+                    );
+                    self.deletion_filter = Some(deletion_filter);
+                }
                 let num_rows = data_block.num_rows();
+                self.affected = None;
 
                 let fuse_part = FuseBlockPartInfo::from_part(&part)?;
                 if let Some(filter) = self.filter.as_ref() {
@@ -195,7 +316,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: offsets.clone(),
                             base_block_ids: None,
                             inner: None,
                             matched_rows: block_meta.matched_rows.clone(),
@@ -236,6 +357,17 @@ impl Processor for MutationSource {
                             data_block.pop_columns(1);
                         }
 
+                        if self.returning.is_some() {
+                            match self.action {
+                                MutationAction::Deletion => self.add_returning_block(
+                                    data_block.clone().filter_boolean_value(&predicates)?,
+                                )?,
+                                MutationAction::Update => {
+                                    self.affected = Some(predicates.clone());
+                                }
+                            }
+                        }
+
                         let progress_values = ProgressValues {
                             rows: affect_rows,
                             bytes: 0,
@@ -257,12 +389,31 @@ impl Processor for MutationSource {
                                         DataBlock::empty_with_meta(meta),
                                     );
                                 } else {
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    if let Some(deleted_rows) =
+                                        self.try_extend_deletion_vector(&predicate_col, &offsets)
+                                    {
+                                        // record the deleted rows instead of rewriting the block.
+                                        let meta =
+                                            Box::new(SerializeDataMeta::SerializeDeletionVector(
+                                                SerializeDeletionVector::create(
+                                                    self.index.clone(),
+                                                    self.block_meta.clone().unwrap(),
+                                                    deleted_rows,
+                                                ),
+                                            ));
+                                        self.state = State::Output(
+                                            self.ctx.get_partition(),
+                                            DataBlock::empty_with_meta(meta),
+                                        );
+                                        return Ok(());
+                                    }
+
                                     if self.block_reader.update_stream_columns {
                                         let row_num = build_origin_block_row_num(num_rows);
                                         data_block.add_column(row_num);
                                     }
 
-                                    let predicate_col = predicates.into_column().unwrap();
                                     let filter = predicate_col.not();
                                     data_block = data_block.filter_with_bitmap(&filter)?;
                                     if self.remain_reader.is_none() {
@@ -322,11 +473,14 @@ impl Processor for MutationSource {
                 let path = FuseBlockPartInfo::from_part(&part)?.location.clone();
                 if let Some(remain_reader) = self.remain_reader.as_ref() {
                     let chunks = merged_io_read_result.columns_chunks()?;
-                    let remain_block = remain_reader.deserialize_chunks_with_part_info(
+                    let mut remain_block = remain_reader.deserialize_chunks_with_part_info(
                         part,
                         chunks,
                         &self.storage_format,
                     )?;
+                    if let Some(deletion_filter) = &self.deletion_filter {
+                        remain_block = remain_block.filter_with_bitmap(deletion_filter)?;
+                    }
 
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
@@ -350,6 +504,13 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
+                if matches!(self.action, MutationAction::Update) && self.returning.is_some() {
+                    let updated = match self.affected.take() {
+                        Some(affected) => block.clone().filter_boolean_value(&affected)?,
+                        None => block.clone(),
+                    };
+                    self.add_returning_block(updated)?;
+                }
                 let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
                     SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                 ));
@@ -397,12 +558,23 @@ impl Processor for MutationSource {
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FuseBlockPartInfo::from_part(&inner_part)?;
 
+                        self.block_meta = Some(part.block_meta.clone());
+                        self.deletion_filter = None;
+                        self.deleted_rows = match &fuse_part.deletion_vector {
+                            Some(deletion_vector) => Some(
+                                read_deletion_vector(&self.block_reader.operator, deletion_vector)
+                                    .await?,
+                            ),
+                            None => None,
+                        };
+
                         if part.whole_block_mutation
                             && matches!(self.action, MutationAction::Deletion)
+                            && self.returning.is_none()
                         {
                             // whole block deletion.
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: part.block_meta.row_count as usize,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -141,6 +141,7 @@ pub fn build_fuse_parquet_source_pipeline(
     pipeline: &mut Pipeline,
     block_reader: Arc<BlockReader>,
     plan: &DataSourcePlan,
+    topk: Option<TopK>,
     mut max_threads: usize,
     mut max_io_requests: usize,
     index_reader: Arc<Option<AggIndexReader>>,
@@ -149,12 +150,21 @@ pub fn build_fuse_parquet_source_pipeline(
     (max_threads, max_io_requests) =
         adjust_threads_and_request(false, max_threads, max_io_requests, plan);
 
+    if topk.is_some() {
+        max_threads = max_threads.min(16);
+        max_io_requests = max_io_requests.min(16);
+    }
+
     let mut source_builder = SourcePipeBuilder::create();
 
     match block_reader.support_blocking_api() {
         true => {
             let partitions = dispatch_partitions(ctx.clone(), plan, max_threads);
-            let partitions = StealablePartitions::new(partitions, ctx.clone());
+            let mut partitions = StealablePartitions::new(partitions, ctx.clone());
+
+            if topk.is_some() {
+                partitions.disable_steal();
+            }
 
             for i in 0..max_threads {
                 let output = OutputPort::create();
@@ -179,7 +189,11 @@ pub fn build_fuse_parquet_source_pipeline(
             info!("read block data adjust max io requests:{}", max_io_requests);
 
             let partitions = dispatch_partitions(ctx.clone(), plan, max_io_requests);
-            let partitions = StealablePartitions::new(partitions, ctx.clone());
+            let mut partitions = StealablePartitions::new(partitions, ctx.clone());
+
+            if topk.is_some() {
+                partitions.disable_steal();
+            }
 
             for i in 0..max_io_requests {
                 let output = OutputPort::create();
@@ -214,6 +228,7 @@ pub fn build_fuse_parquet_source_pipeline(
             ctx.clone(),
             block_reader.clone(),
             plan,
+            topk.clone(),
             transform_input,
             transform_output,
             index_reader.clone(),
@@ -20,8 +20,11 @@ use std::ops::BitAnd;
 use std::sync::Arc;
 
 use databend_common_arrow::arrow::array::Array;
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_arrow::native::read::filter_array;
 use databend_common_arrow::native::read::ArrayIter;
+use databend_common_arrow::native::read::ArrayIterator;
 use databend_common_arrow::parquet::metadata::ColumnDescriptor;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
@@ -107,6 +110,9 @@ struct ReadPartState {
     /// The number of rows that are filtered while reading current set of pages.
     /// It's used for the filter executor.
     filtered_count: Option<usize>,
+    /// The selected rows of current set of pages, if it's set, the arrays in `arrays`
+    /// only contain the selected rows, and the remain columns decode only the selected rows.
+    selection: Option<Bitmap>,
 }
 
 impl ReadPartState {
@@ -121,6 +127,7 @@ impl ReadPartState {
             read_columns: HashSet::new(),
             arrays: Vec::new(),
             filtered_count: None,
+            selection: None,
         }
     }
 
@@ -141,6 +148,7 @@ impl ReadPartState {
         self.read_columns.clear();
         self.arrays.clear();
         self.filtered_count = None;
+        self.selection = None;
     }
 
     /// Skip one page for each unread column.
@@ -168,7 +176,11 @@ impl ReadPartState {
 
         if let Some(array_iter) = self.array_iters.get_mut(&index) {
             let skipped_pages = self.array_skip_pages.get(&index).unwrap();
-            match array_iter.nth(*skipped_pages) {
+            let array = match &self.selection {
+                Some(selection) => array_iter.nth_with_selection(*skipped_pages, selection),
+                None => array_iter.nth(*skipped_pages),
+            };
+            match array {
                 Some(array) => {
                     self.read_columns.insert(index);
                     self.arrays.push((index, array?));
@@ -184,6 +196,16 @@ impl ReadPartState {
         Ok(true)
     }
 
+    /// Keep only the selected rows of the columns already read,
+    /// the columns read after it decode only the selected rows.
+    fn apply_selection(&mut self, selection: Bitmap) -> Result<()> {
+        for (_, array) in self.arrays.iter_mut() {
+            *array = filter_array(array.as_ref(), &selection)?;
+        }
+        self.selection = Some(selection);
+        Ok(())
+    }
+
     /// Check if current partition is finished.
     #[inline(always)]
     fn is_finished(&self) -> bool {
@@ -237,9 +259,16 @@ pub struct NativeDeserializeDataTransform {
     /// Record how many sets of pages have been skipped.
     /// It's used for metrics.
     skipped_pages: usize,
+    /// Record how many blocks have been skipped by the top-k heap.
+    /// It's used for metrics.
+    skipped_blocks: usize,
 
     // for merge_into target build.
     need_reserve_block_info: bool,
+    /// Whether to encode the low cardinality string columns as dictionary.
+    encode_dictionary: bool,
+    /// Whether to encode the columns with few runs as constant or run-length columns.
+    encode_run_length: bool,
 }
 
 impl NativeDeserializeDataTransform {
@@ -323,6 +352,8 @@ impl NativeDeserializeDataTransform {
             .collect();
 
         let func_ctx = ctx.get_function_context()?;
+        let encode_dictionary = ctx.get_settings().get_enable_dictionary_encoded_string()?;
+        let encode_run_length = ctx.get_settings().get_enable_run_length_columns()?;
         let prewhere_schema = src_schema.project(&prewhere_columns);
         let prewhere_filter = Self::build_prewhere_filter_expr(plan, &prewhere_schema)?;
 
@@ -380,6 +411,7 @@ impl NativeDeserializeDataTransform {
                 prewhere_virtual_columns,
                 filter_executor,
                 skipped_pages: 0,
+                skipped_blocks: 0,
                 top_k,
                 index_reader,
                 virtual_reader,
@@ -387,6 +419,8 @@ impl NativeDeserializeDataTransform {
                 bloom_runtime_filter: None,
                 read_state: ReadPartState::new(),
                 need_reserve_block_info,
+                encode_dictionary,
+                encode_run_length,
             },
         )))
     }
@@ -558,6 +592,17 @@ impl NativeDeserializeDataTransform {
         Ok(false)
     }
 
+    /// Check if can skip the whole block by the min/max of the top-k column.
+    fn check_topk_min_max(&self) -> Result<bool> {
+        if let Some((_, sorter, _)) = self.top_k.as_ref() {
+            let part = FuseBlockPartInfo::from_part(&self.parts[0])?;
+            if let Some(min_max) = part.sort_min_max.as_ref() {
+                return Ok(sorter.never_match(min_max));
+            }
+        }
+        Ok(false)
+    }
+
     /// Finish the processing of current partition.
     fn finish_partition(&mut self) {
         self.read_state.finish();
@@ -700,6 +745,9 @@ impl NativeDeserializeDataTransform {
             }
 
             // 5. read remain columns and generate a data block.
+            if let Some(selection) = self.build_remain_selection() {
+                self.read_state.apply_selection(selection)?;
+            }
             if !self.read_remain_columns()? {
                 debug_assert!(self.read_state.is_finished());
                 return Ok(None);
@@ -707,6 +755,12 @@ impl NativeDeserializeDataTransform {
             let mut block = self
                 .block_reader
                 .build_block(&self.read_state.arrays, None)?;
+            if self.encode_run_length {
+                Self::encode_run_length_columns(&mut block);
+            }
+            if self.encode_dictionary {
+                Self::encode_dictionary_columns(&mut block);
+            }
 
             // 6. fill missing fields with default values.
             if self.read_state.if_need_fill_defaults {
@@ -902,6 +956,32 @@ impl NativeDeserializeDataTransform {
         Ok(true)
     }
 
+    /// Build the selection of the filtered rows if there are remain columns to read,
+    /// so that the remain columns decode only the selected rows.
+    fn build_remain_selection(&mut self) -> Option<Bitmap> {
+        let count = self.read_state.filtered_count?;
+        let num_rows = self.read_state.arrays.first()?.1.len();
+        if count == num_rows
+            || !self
+                .remain_columns
+                .iter()
+                .any(|index| self.read_state.array_iters.contains_key(index))
+        {
+            return None;
+        }
+
+        let filter_executor = self.filter_executor.as_mut().unwrap();
+        let true_selection = &mut filter_executor.mutable_true_selection()[0..count];
+        // The order of the indices may be changed if the filter has `or`,
+        // keep them sorted as the rows of the block.
+        true_selection.sort_unstable();
+        let mut selection = MutableBitmap::from_len_zeroed(num_rows);
+        for idx in true_selection.iter() {
+            selection.set(*idx as usize, true);
+        }
+        Some(selection.into())
+    }
+
     /// Read remain columns.
     ///
     /// Returns false if the partition is finished.
@@ -944,6 +1024,14 @@ impl NativeDeserializeDataTransform {
         debug_assert!(!self.chunks.is_empty());
         debug_assert!(!self.parts.is_empty());
 
+        if self.check_topk_min_max()? {
+            // The whole block can't beat the current top-k values,
+            // finish current partition without decoding any pages.
+            self.skipped_blocks += 1;
+            self.finish_partition();
+            return Ok(());
+        }
+
         // Create a new read state.
         self.new_read_state()?;
 
@@ -968,14 +1056,45 @@ impl NativeDeserializeDataTransform {
         Ok(())
     }
 
+    /// Encodes the columns with few runs as constant or run-length columns, the filters and
+    /// the common kernels work on the runs until the values are needed by the functions.
+    fn encode_run_length_columns(block: &mut DataBlock) {
+        for entry in block.columns_mut() {
+            if let Value::Column(column) = &entry.value {
+                if let Some(column) = column.encode_run_length() {
+                    entry.value = Value::Column(column);
+                }
+            }
+        }
+    }
+
     /// Post preprocess after reading a block.
+    /// Encodes the low cardinality string columns as dictionary, the following filters and
+    /// hash tables work on the dictionary keys instead of copying the strings.
+    fn encode_dictionary_columns(block: &mut DataBlock) {
+        for entry in block.columns_mut() {
+            if let Value::Column(column) = &entry.value {
+                if let Some(column) = column.encode_dictionary() {
+                    entry.value = Value::Column(column);
+                }
+            }
+        }
+    }
+
     fn post_process_block(&mut self, block: DataBlock) -> Result<DataBlock> {
-        let origin_num_rows = block.num_rows();
-        let block = if let Some(count) = &self.read_state.filtered_count {
-            let filter_executor = self.filter_executor.as_mut().unwrap();
-            filter_executor.take(block, origin_num_rows, *count)?
-        } else {
-            block
+        let (origin_num_rows, block) = match &self.read_state.selection {
+            // The block is already filtered while reading the pages.
+            Some(selection) => (selection.len(), block),
+            None => {
+                let origin_num_rows = block.num_rows();
+                let block = if let Some(count) = &self.read_state.filtered_count {
+                    let filter_executor = self.filter_executor.as_mut().unwrap();
+                    filter_executor.take(block, origin_num_rows, *count)?
+                } else {
+                    block
+                };
+                (origin_num_rows, block)
+            }
         };
 
         // Fill `InternalColumnMeta` as `DataBlock.meta` if query internal columns,
@@ -1060,6 +1179,7 @@ impl Processor for NativeDeserializeDataTransform {
 
         if self.input.is_finished() {
             metrics_inc_pruning_prewhere_nums(self.skipped_pages as u64);
+            metrics_inc_pruning_topk_blocks(self.skipped_blocks as u64);
             self.output.finish();
             return Ok(Event::Finished);
         }
@@ -14,6 +14,7 @@
 
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_expression::BlockMetaInfo;
+use roaring::RoaringBitmap;
 
 use crate::io::MergeIOReadResult;
 use crate::io::VirtualMergeIOReadResult;
@@ -21,7 +22,14 @@ use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 
 pub enum ParquetDataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal((MergeIOReadResult, Option<VirtualMergeIOReadResult>)),
+    Normal(
+        (
+            MergeIOReadResult,
+            Option<VirtualMergeIOReadResult>,
+            // rows deleted by the deletion vector of the block.
+            Option<RoaringBitmap>,
+        ),
+    ),
 }
 
 #[typetag::serde(name = "fuse_data_source")]
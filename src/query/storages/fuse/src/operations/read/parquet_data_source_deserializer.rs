@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::ops::BitAnd;
 use std::sync::Arc;
 use std::time::Instant;
@@ -25,6 +26,7 @@ use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::TopK;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -34,6 +36,7 @@ use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 use databend_common_expression::FieldIndex;
 use databend_common_expression::Scalar;
+use databend_common_expression::TopKSorter;
 use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
@@ -41,12 +44,14 @@ use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::IndexType;
+use roaring::RoaringBitmap;
 use xorf::BinaryFuse16;
 
 use super::parquet_data_source::ParquetDataSource;
 use super::util::add_data_block_meta;
 use super::util::need_reserve_block_info;
 use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::deletion_vector_to_filter;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::VirtualColumnReader;
@@ -61,7 +66,7 @@ pub struct DeserializeDataTransform {
 
     input: Arc<InputPort>,
     output: Arc<OutputPort>,
-    output_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
     src_schema: DataSchema,
     output_schema: DataSchema,
     parts: Vec<PartInfoPtr>,
@@ -74,15 +79,25 @@ pub struct DeserializeDataTransform {
     cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16)>>,
     // for merge_into target build.
     need_reserve_block_info: bool,
+    // The output blocks larger than it will be split, 0 means no limit.
+    max_block_size_bytes: usize,
+
+    // The top-k heap, only used if there are no filters on the scan.
+    top_k: Option<(TopK, TopKSorter, usize)>,
+    /// Record how many blocks have been skipped by the top-k heap.
+    /// It's used for metrics.
+    skipped_blocks: usize,
 }
 
 unsafe impl Send for DeserializeDataTransform {}
 
 impl DeserializeDataTransform {
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         ctx: Arc<dyn TableContext>,
         block_reader: Arc<BlockReader>,
         plan: &DataSourcePlan,
+        top_k: Option<TopK>,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
         index_reader: Arc<Option<AggIndexReader>>,
@@ -103,10 +118,17 @@ impl DeserializeDataTransform {
             src_schema = DataSchema::new(fields);
         }
 
+        let top_k = top_k.map(|top_k| {
+            let index = src_schema.index_of(top_k.field.name()).unwrap();
+            let sorter = TopKSorter::new(top_k.limit, top_k.asc);
+            (top_k, sorter, index)
+        });
+
         let mut output_schema = plan.schema().as_ref().clone();
         output_schema.remove_internal_fields();
         let output_schema: DataSchema = (&output_schema).into();
         let (need_reserve_block_info, _) = need_reserve_block_info(ctx.clone(), plan.table_index);
+        let max_block_size_bytes = ctx.get_settings().get_max_block_size_bytes()?;
         Ok(ProcessorPtr::create(Box::new(DeserializeDataTransform {
             ctx,
             table_index: plan.table_index,
@@ -114,7 +136,7 @@ impl DeserializeDataTransform {
             block_reader,
             input,
             output,
-            output_data: None,
+            output_data: VecDeque::new(),
             src_schema,
             output_schema,
             parts: vec![],
@@ -124,6 +146,9 @@ impl DeserializeDataTransform {
             base_block_ids: plan.base_block_ids.clone(),
             cached_runtime_filter: None,
             need_reserve_block_info,
+            max_block_size_bytes,
+            top_k,
+            skipped_blocks: 0,
         })))
     }
 
@@ -169,6 +194,118 @@ impl DeserializeDataTransform {
             Ok(None)
         }
     }
+
+    /// Check if can skip the whole block by the min/max of the top-k column.
+    fn check_topk_min_max(&self, part: &FuseBlockPartInfo) -> bool {
+        match (self.top_k.as_ref(), part.sort_min_max.as_ref()) {
+            (Some((_, sorter, _)), Some(min_max)) => sorter.never_match(min_max),
+            _ => false,
+        }
+    }
+
+    /// Update the top-k heap with the rows set in the filter and unset the rows
+    /// which can't beat the current top-k values.
+    fn update_topk_heap(
+        &mut self,
+        data_block: &DataBlock,
+        filter: Option<Bitmap>,
+    ) -> Option<Bitmap> {
+        let Some((_, sorter, index)) = self.top_k.as_mut() else {
+            return filter;
+        };
+
+        let num_rows = data_block.num_rows();
+        let entry = data_block.get_by_offset(*index);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, num_rows);
+        let mut bitmap = match filter {
+            Some(filter) => filter.make_mut(),
+            None => MutableBitmap::from_len_set(num_rows),
+        };
+        sorter.push_column(&column, &mut bitmap);
+        Some(bitmap.into())
+    }
+
+    /// Apply the deletion vector, runtime filters and top-k heap to the deserialized block,
+    /// then fill the block meta for the next pipeline.
+    fn process_data_block(
+        &mut self,
+        part: &FuseBlockPartInfo,
+        mut data_block: DataBlock,
+        deleted_rows: Option<RoaringBitmap>,
+        start: Instant,
+    ) -> Result<DataBlock> {
+        let origin_num_rows = data_block.num_rows();
+
+        // Remove the rows deleted by the deletion vector.
+        let mut filter = deleted_rows
+            .map(|deleted_rows| deletion_vector_to_filter(origin_num_rows, &deleted_rows));
+        if self.ctx.has_bloom_runtime_filters(self.table_index) {
+            if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
+                filter = Some(match filter {
+                    Some(deletion_filter) => &deletion_filter & &bitmap,
+                    None => bitmap,
+                });
+            }
+        }
+        filter = self.update_topk_heap(&data_block, filter);
+        if let Some(bitmap) = &filter {
+            data_block = data_block.filter_with_bitmap(bitmap)?;
+        }
+
+        // Perf.
+        {
+            metrics_inc_remote_io_deserialize_milliseconds(start.elapsed().as_millis() as u64);
+        }
+
+        let progress_values = ProgressValues {
+            rows: data_block.num_rows(),
+            bytes: data_block.memory_size(),
+        };
+        self.scan_progress.incr(&progress_values);
+        Profile::record_usize_profile(ProfileStatisticsName::ScanBytes, data_block.memory_size());
+
+        let data_block = data_block.resort(&self.src_schema, &self.output_schema)?;
+
+        // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
+        // `TransformAddInternalColumns` will generate internal columns using `BlockMetaIndex` in next pipeline.
+        let offsets = if self.block_reader.query_internal_columns() {
+            filter.as_ref().map(|bitmap| {
+                (0..origin_num_rows)
+                    .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
+                    .collect()
+            })
+        } else {
+            None
+        };
+
+        add_data_block_meta(
+            data_block,
+            part,
+            offsets,
+            self.base_block_ids.clone(),
+            self.block_reader.update_stream_columns(),
+            self.block_reader.query_internal_columns(),
+            self.need_reserve_block_info,
+        )
+    }
+
+    fn push_output_data(&mut self, data_block: DataBlock) {
+        // The block meta describes all the rows of the block, so blocks with meta are not split.
+        if self.max_block_size_bytes == 0
+            || data_block.num_rows() <= 1
+            || data_block.get_meta().is_some()
+            || data_block.memory_size() <= self.max_block_size_bytes
+        {
+            self.output_data.push_back(data_block);
+            return;
+        }
+
+        let blocks = data_block
+            .split_by_rows_and_bytes_no_tail(data_block.num_rows(), self.max_block_size_bytes);
+        self.output_data.extend(blocks);
+    }
 }
 
 #[async_trait::async_trait]
@@ -192,7 +329,7 @@ impl Processor for DeserializeDataTransform {
             return Ok(Event::NeedConsume);
         }
 
-        if let Some(data_block) = self.output_data.take() {
+        if let Some(data_block) = self.output_data.pop_front() {
             self.output.push_data(Ok(data_block));
             return Ok(Event::NeedConsume);
         }
@@ -219,6 +356,7 @@ impl Processor for DeserializeDataTransform {
         }
 
         if self.input.is_finished() {
+            metrics_inc_pruning_topk_blocks(self.skipped_blocks as u64);
             self.output.finish();
             return Ok(Event::Finished);
         }
@@ -246,12 +384,18 @@ impl Processor for DeserializeDataTransform {
                         block.memory_size(),
                     );
 
-                    self.output_data = Some(block);
+                    self.output_data.push_back(block);
                 }
-                ParquetDataSource::Normal((data, virtual_data)) => {
+                ParquetDataSource::Normal((data, virtual_data, deleted_rows)) => {
                     let start = Instant::now();
-                    let columns_chunks = data.columns_chunks()?;
                     let part = FuseBlockPartInfo::from_part(&part)?;
+                    if self.check_topk_min_max(part) {
+                        // The whole block can't beat the current top-k values, skip decoding it.
+                        self.skipped_blocks += 1;
+                        return Ok(());
+                    }
+
+                    let columns_chunks = data.columns_chunks()?;
 
                     let mut data_block = self.block_reader.deserialize_parquet_chunks(
                         part.nums_rows,
@@ -261,65 +405,15 @@ impl Processor for DeserializeDataTransform {
                         &part.location,
                     )?;
 
-                    let origin_num_rows = data_block.num_rows();
-
-                    let mut filter = None;
-                    if self.ctx.has_bloom_runtime_filters(self.table_index) {
-                        if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
-                        }
-                    }
-
                     // Add optional virtual columns
                     if let Some(virtual_reader) = self.virtual_reader.as_ref() {
                         data_block = virtual_reader
                             .deserialize_virtual_columns(data_block.clone(), virtual_data)?;
                     }
 
-                    // Perf.
-                    {
-                        metrics_inc_remote_io_deserialize_milliseconds(
-                            start.elapsed().as_millis() as u64
-                        );
-                    }
-
-                    let progress_values = ProgressValues {
-                        rows: data_block.num_rows(),
-                        bytes: data_block.memory_size(),
-                    };
-                    self.scan_progress.incr(&progress_values);
-                    Profile::record_usize_profile(
-                        ProfileStatisticsName::ScanBytes,
-                        data_block.memory_size(),
-                    );
-
-                    let mut data_block =
-                        data_block.resort(&self.src_schema, &self.output_schema)?;
-
-                    // Fill `BlockMetaIndex` as `DataBlock.meta` if query internal columns,
-                    // `TransformAddInternalColumns` will generate internal columns using `BlockMetaIndex` in next pipeline.
-                    let offsets = if self.block_reader.query_internal_columns() {
-                        filter.as_ref().map(|bitmap| {
-                            (0..origin_num_rows)
-                                .filter(|i| unsafe { bitmap.get_bit_unchecked(*i) })
-                                .collect()
-                        })
-                    } else {
-                        None
-                    };
-
-                    data_block = add_data_block_meta(
-                        data_block,
-                        part,
-                        offsets,
-                        self.base_block_ids.clone(),
-                        self.block_reader.update_stream_columns(),
-                        self.block_reader.query_internal_columns(),
-                        self.need_reserve_block_info,
-                    )?;
-
-                    self.output_data = Some(data_block);
+                    let data_block =
+                        self.process_data_block(part, data_block, deleted_rows, start)?;
+                    self.push_output_data(data_block);
                 }
             }
         }
@@ -34,6 +34,8 @@ use log::debug;
 
 use super::parquet_data_source::ParquetDataSource;
 use crate::fuse_part::FuseBlockPartInfo;
+use crate::io::read_deletion_vector;
+use crate::io::read_deletion_vector_sync;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
 use crate::io::ReadSettings;
@@ -136,8 +138,15 @@ impl SyncSource for ReadParquetDataSource<true> {
                     return Ok(Some(DataBlock::empty()));
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                let fuse_part = FuseBlockPartInfo::from_part(&part)?;
+                // The aggregating index is built from all the rows of the block,
+                // it can't be used once some rows of the block have been deleted.
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| fuse_part.deletion_vector.is_none())
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -158,7 +167,6 @@ impl SyncSource for ReadParquetDataSource<true> {
 
                 // If virtual column file exists, read the data from the virtual columns directly.
                 let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FuseBlockPartInfo::from_part(&part)?;
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -176,6 +184,14 @@ impl SyncSource for ReadParquetDataSource<true> {
                     &None
                 };
 
+                let deleted_rows = fuse_part
+                    .deletion_vector
+                    .as_ref()
+                    .map(|deletion_vector| {
+                        read_deletion_vector_sync(&self.block_reader.operator, deletion_vector)
+                    })
+                    .transpose()?;
+
                 let source = self.block_reader.sync_read_columns_data_by_merge_io(
                     &ReadSettings::from_ctx(&self.partitions.ctx)?,
                     &part,
@@ -186,6 +202,7 @@ impl SyncSource for ReadParquetDataSource<true> {
                     DataSourceWithMeta::create(vec![part], vec![ParquetDataSource::Normal((
                         source,
                         virtual_source,
+                        deleted_rows,
                     ))]),
                 )))
             }
@@ -262,7 +279,13 @@ impl Processor for ReadParquetDataSource<false> {
                     databend_common_base::runtime::spawn(async move {
                         let part = FuseBlockPartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index is built from all the rows of the block,
+                        // it can't be used once some rows of the block have been deleted.
+                        if let Some(index_reader) = index_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| part.deletion_vector.is_none())
+                        {
                             let loc =
                                 TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                                     &part.location,
@@ -296,6 +319,14 @@ impl Processor for ReadParquetDataSource<false> {
                             &None
                         };
 
+                        let deleted_rows = match &part.deletion_vector {
+                            Some(deletion_vector) => Some(
+                                read_deletion_vector(&block_reader.operator, deletion_vector)
+                                    .await?,
+                            ),
+                            None => None,
+                        };
+
                         let source = block_reader
                             .read_columns_data_by_merge_io(
                                 &settings,
@@ -305,7 +336,11 @@ impl Processor for ReadParquetDataSource<false> {
                             )
                             .await?;
 
-                        Ok(ParquetDataSource::Normal((source, virtual_source)))
+                        Ok(ParquetDataSource::Normal((
+                            source,
+                            virtual_source,
+                            deleted_rows,
+                        )))
                     })
                         .await
                         .unwrap()
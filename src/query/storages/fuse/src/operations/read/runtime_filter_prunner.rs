@@ -157,6 +157,20 @@ pub(crate) fn update_bitmap_with_bloom_filter(
                     }
                     idx += 1;
                 }),
+                KeysState::Column(Column::StringDictionary(col)) => {
+                    // Probe the filter once for each distinct value.
+                    let contains = col
+                        .values()
+                        .iter_binary()
+                        .map(|value| filter.contains(&value.fast_hash()))
+                        .collect::<Vec<_>>();
+                    col.keys().iter().for_each(|key| {
+                        if contains[*key as usize] {
+                            bitmap.set(idx, true);
+                        }
+                        idx += 1;
+                    })
+                }
                 _ => unreachable!(),
             }
         }
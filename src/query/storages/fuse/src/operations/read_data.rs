@@ -201,7 +201,9 @@ impl FuseTable {
         let topk = plan
             .push_downs
             .as_ref()
-            .filter(|_| self.is_native()) // Only native format supports topk push down.
+            // Parquet format applies the filters after deserializing, so the top-k heap
+            // can only be maintained when there are no filters.
+            .filter(|x| self.is_native() || x.filters.is_none())
             .and_then(|x| x.top_k(plan.schema().as_ref()));
 
         let index_reader = Arc::new(
@@ -289,6 +291,7 @@ impl FuseTable {
                 pipeline,
                 block_reader,
                 plan,
+                top_k,
                 max_threads,
                 max_io_requests,
                 index_reader,
@@ -101,6 +101,10 @@ impl FuseTable {
                             snapshot.summary.compressed_byte_size as usize,
                             snapshot.segments.len(),
                             snapshot.segments.len(),
+                        )
+                        .with_decode_estimates(
+                            snapshot.summary.compressed_byte_size as usize,
+                            snapshot.summary.uncompressed_byte_size as usize,
                         ),
                         Partitions::create(PartitionsShuffleKind::Mod, segments),
                     ));
@@ -238,7 +242,9 @@ impl FuseTable {
 
         let top_k = push_downs
             .as_ref()
-            .filter(|_| self.is_native()) // Only native format supports topk push down.
+            // Parquet format applies the filters after deserializing, so the top-k heap
+            // can only be maintained when there are no filters.
+            .filter(|p| self.is_native() || p.filters.is_none())
             .and_then(|p| p.top_k(self.schema().as_ref()))
             .map(|topk| field_default_value(ctx.clone(), &topk.field).map(|d| (topk, d)))
             .transpose()?;
@@ -362,6 +368,8 @@ impl FuseTable {
             ));
             statistics.read_rows += rows;
             statistics.read_bytes += block_meta.block_size as usize;
+            statistics.read_compressed_bytes += block_meta.file_size as usize;
+            statistics.read_uncompressed_bytes += block_meta.block_size as usize;
 
             if remaining > rows {
                 remaining -= rows;
@@ -412,6 +420,10 @@ impl FuseTable {
                     if let Some(col_metas) = block_meta.col_metas.get(column_id) {
                         let (_, len) = col_metas.offset_length();
                         statistics.read_bytes += len as usize;
+                        statistics.read_compressed_bytes += len as usize;
+                    }
+                    if let Some(col_stats) = block_meta.col_stats.get(column_id) {
+                        statistics.read_uncompressed_bytes += col_stats.in_memory_size as usize;
                     }
                 }
             }
@@ -456,7 +468,7 @@ impl FuseTable {
             }
         }
 
-        let rows_count = meta.row_count;
+        let rows_count = meta.physical_row_count();
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
 
@@ -476,6 +488,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 
@@ -502,7 +515,7 @@ impl FuseTable {
             }
         }
 
-        let rows_count = meta.row_count;
+        let rows_count = meta.physical_row_count();
         let location = meta.location.0.clone();
         let create_on = meta.create_on;
 
@@ -524,6 +537,7 @@ impl FuseTable {
             sort_min_max,
             block_meta_index.to_owned(),
             create_on,
+            meta.deletion_vector.clone(),
         )
     }
 }
@@ -58,6 +58,7 @@ use log::warn;
 use opendal::Operator;
 
 use crate::io::read::bloom::block_filter_reader::BloomBlockFilterReader;
+use crate::io::read_deletion_vector;
 use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
@@ -102,6 +103,8 @@ struct AggregationContext {
     query_id: String,
     // generate stream columns if necessary
     stream_ctx: Option<StreamContext>,
+    // reject the conflicting rows instead of replacing them
+    error_on_conflict: bool,
 }
 
 // Apply MergeIntoOperations to segments
@@ -123,6 +126,7 @@ impl MergeIntoOperationAggregator {
         read_settings: ReadSettings,
         block_builder: BlockBuilder,
         io_request_semaphore: Arc<Semaphore>,
+        error_on_conflict: bool,
     ) -> Result<Self> {
         let data_accessor = table.get_operator();
         let table_schema = table.schema_with_stream();
@@ -214,6 +218,7 @@ impl MergeIntoOperationAggregator {
                 io_request_semaphore,
                 query_id,
                 stream_ctx,
+                error_on_conflict,
             }),
         })
     }
@@ -428,6 +433,13 @@ impl AggregationContext {
 
         let num_rows = key_columns_data.num_rows();
 
+        let deleted_rows = match &block_meta.deletion_vector {
+            Some(deletion_vector) => {
+                Some(read_deletion_vector(&self.key_column_reader.operator, deletion_vector).await?)
+            }
+            None => None,
+        };
+
         let on_conflict_fields = &self.on_conflict_fields;
         let mut columns = Vec::with_capacity(on_conflict_fields.len());
         for (field, _) in on_conflict_fields.iter().enumerate() {
@@ -445,8 +457,16 @@ impl AggregationContext {
         }
 
         let mut bitmap = MutableBitmap::new();
+        let mut already_deleted = 0;
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deleted_rows
+                .as_ref()
+                .is_some_and(|deleted_rows| deleted_rows.contains(row as u32))
+            {
+                // rows deleted by the deletion vector are dropped anyway
+                bitmap.push(false);
+                already_deleted += 1;
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -456,7 +476,7 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.unset_bits();
+        let delete_nums = bitmap.unset_bits() - already_deleted;
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -467,6 +487,19 @@ impl AggregationContext {
             return Ok(None);
         }
 
+        if self.error_on_conflict {
+            let key = self
+                .on_conflict_fields
+                .iter()
+                .map(|field| field.table_field.name().as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(ErrorCode::PrimaryKeyViolation(format!(
+                "{} row(s) conflict with the existing rows on primary key ({}), segment index {}, block index {}",
+                delete_nums, key, segment_index, block_index
+            )));
+        }
+
         let progress_values = ProgressValues {
             rows: delete_nums,
             // ignore bytes.
@@ -482,9 +515,9 @@ impl AggregationContext {
         if delete_nums == block_meta.row_count as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
-            metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
+            metrics_inc_replace_deleted_blocks_rows(block_meta.row_count);
             // whole block deletion
-            // NOTE that if deletion marker is enabled, check the real meaning of `row_count`
+            // NOTE that `row_count` excludes the rows deleted by the deletion vector
             let mutation = MutationLogEntry::DeletedBlock {
                 index: BlockMetaIndex {
                     segment_idx: segment_index,
@@ -582,6 +615,9 @@ impl AggregationContext {
         if let Some(index_state) = serialized.bloom_index_state {
             write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
         }
+        if let Some(index_state) = serialized.vector_index_state {
+            write_data(index_state.data, &data_accessor, &index_state.location.0).await?;
+        }
 
         // generate log
         let mutation = MutationLogEntry::ReplacedBlock {
@@ -656,7 +692,7 @@ impl AggregationContext {
                 let column_chunks = merged_io_read_result.columns_chunks()?;
                 reader.deserialize_chunks(
                     block_meta_ptr.location.0.as_str(),
-                    block_meta_ptr.row_count as usize,
+                    block_meta_ptr.physical_row_count() as usize,
                     &block_meta_ptr.compression,
                     &block_meta_ptr.col_metas,
                     column_chunks,
@@ -24,6 +24,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberScalar;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnId;
 use databend_common_expression::DataBlock;
@@ -70,18 +71,29 @@ impl ReplaceIntoMutator {
         table_schema: &TableSchema,
         table_range_idx: HashMap<ColumnId, ColumnStatistics>,
     ) -> Result<Self> {
-        let partitioner = if !cluster_keys.is_empty()
-            && ctx.get_settings().get_enable_replace_into_partitioning()?
-        {
-            Some(Partitioner::try_new(
+        let settings = ctx.get_settings();
+        let partition_by = if !settings.get_enable_replace_into_partitioning()? {
+            None
+        } else if !cluster_keys.is_empty() {
+            let left_most_cluster_key = cluster_keys[0]
+                .as_expr(&BUILTIN_FUNCTIONS)
+                .project_column_ref(|name| table_schema.index_of(name).unwrap());
+            Some(PartitionBy::ClusterKey(left_most_cluster_key))
+        } else {
+            // table without cluster keys, partition the input by the key ranges of
+            // the left-most on-conflict field, which works well if the keys are
+            // (roughly) ordered, e.g. CDC streams with auto-increment keys
+            let num_ranges = settings.get_replace_into_key_range_partitions()? as usize;
+            (num_ranges > 1).then_some(PartitionBy::KeyRange(num_ranges))
+        };
+        let partitioner = match partition_by {
+            Some(partition_by) => Some(Partitioner::try_new(
                 ctx,
                 on_conflict_fields.clone(),
-                &cluster_keys,
+                partition_by,
                 bloom_filter_column_indexes,
-                table_schema,
-            )?)
-        } else {
-            None
+            )?),
+            None => None,
         };
         Ok(Self {
             on_conflict_fields,
@@ -115,8 +127,21 @@ impl ReplaceIntoMutator {
         }
 
         let merge_into_operation = if let Some(partitioner) = &self.partitioner {
-            // if table has cluster keys; we partition the input data block by left most column of cluster keys
-            let partitions = partitioner.partition(data_block)?;
+            // duplicated keys are not allowed, no matter the input is partitioned or not
+            let column_values = on_conflict_key_column_values(
+                &self.on_conflict_fields,
+                &data_block_may_have_conflicts,
+            );
+            if let ColumnHash::Conflict(conflict_row_idx) = Self::build_column_hash(
+                &column_values,
+                &mut self.key_saw,
+                row_number_after_pruning,
+            )? {
+                return Err(self.duplicated_key_error(&column_values, conflict_row_idx));
+            }
+            // partition the input data block by left most column of cluster keys,
+            // or by the key ranges of the left most on-conflict field
+            let partitions = partitioner.partition(&data_block_may_have_conflicts)?;
             metrics_inc_replace_partition_number(partitions.len() as u64);
             let vs = partitions
                 .into_iter()
@@ -186,31 +211,38 @@ impl ReplaceIntoMutator {
                 Ok(MergeIntoOperation::Delete(vec![delete_action]))
             }
             ColumnHash::Conflict(conflict_row_idx) => {
-                let conflict_description = {
-                    let conflicts = column_values
-                        .iter()
-                        .zip(self.on_conflict_fields.iter())
-                        .map(|(col, field)| {
-                            let col_name = &field.table_field.name;
-                            // if col.index(conflict_row_idx) is None, an exception will already be thrown in build_column_hash
-                            let row_value = col.index(conflict_row_idx).unwrap();
-                            let row_value_message =
-                                Self::extract_col_value_for_err_message(row_value);
-                            format!("\"{}\":{}", col_name, row_value_message)
-                        })
-                        .collect::<Vec<_>>()
-                        .join(", ");
-
-                    format!("at row {}, [{}]", conflict_row_idx, conflicts)
-                };
-                Err(ErrorCode::StorageOther(format!(
-                    "duplicated data detected in the values being replaced into (only the first one will be described): {}",
-                    conflict_description
-                )))
+                Err(self.duplicated_key_error(&column_values, conflict_row_idx))
             }
         }
     }
 
+    fn duplicated_key_error(
+        &self,
+        column_values: &[&Value<AnyType>],
+        conflict_row_idx: usize,
+    ) -> ErrorCode {
+        let conflict_description = {
+            let conflicts = column_values
+                .iter()
+                .zip(self.on_conflict_fields.iter())
+                .map(|(col, field)| {
+                    let col_name = &field.table_field.name;
+                    // if col.index(conflict_row_idx) is None, an exception will already be thrown in build_column_hash
+                    let row_value = col.index(conflict_row_idx).unwrap();
+                    let row_value_message = Self::extract_col_value_for_err_message(row_value);
+                    format!("\"{}\":{}", col_name, row_value_message)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("at row {}, [{}]", conflict_row_idx, conflicts)
+        };
+        ErrorCode::StorageOther(format!(
+            "duplicated data detected in the values being replaced into (only the first one will be described): {}",
+            conflict_description
+        ))
+    }
+
     fn build_column_hash(
         column_values: &[&Value<AnyType>],
         saw: &mut HashSet<UniqueKeyDigest>,
@@ -343,10 +375,17 @@ impl Partition {
     }
 }
 
+enum PartitionBy {
+    // partition by the value of the left-most cluster key expression
+    ClusterKey(Expr),
+    // partition by the given number of key ranges of the left-most on-conflict field
+    KeyRange(usize),
+}
+
 struct Partitioner {
     on_conflict_fields: Vec<OnConflictField>,
     func_ctx: FunctionContext,
-    left_most_cluster_key: Expr,
+    partition_by: PartitionBy,
     // information about the columns that will apply bloom pruning
     bloom_filter_column_info: Vec<(FieldIndex, DataType)>,
 }
@@ -355,14 +394,9 @@ impl Partitioner {
     fn try_new(
         ctx: &dyn TableContext,
         on_conflict_fields: Vec<OnConflictField>,
-        cluster_keys: &[RemoteExpr<String>],
+        partition_by: PartitionBy,
         bloom_filter_column_indexes: Vec<FieldIndex>,
-        table_schema: &TableSchema,
     ) -> Result<Self> {
-        let left_most_cluster_key = &cluster_keys[0];
-        let expr: Expr = left_most_cluster_key
-            .as_expr(&BUILTIN_FUNCTIONS)
-            .project_column_ref(|name| table_schema.index_of(name).unwrap());
         let func_ctx = ctx.get_function_context()?;
 
         let bloom_filter_column_info = bloom_filter_column_indexes
@@ -376,20 +410,19 @@ impl Partitioner {
         Ok(Self {
             on_conflict_fields,
             func_ctx,
-            left_most_cluster_key: expr,
+            partition_by,
             bloom_filter_column_info,
         })
     }
 
     fn partition(&self, data_block: &DataBlock) -> Result<Vec<Partition>> {
-        let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
-        let cluster_key_values = evaluator.run(&self.left_most_cluster_key)?;
-
         // extract the on-conflict column values
         let on_conflict_column_values =
             on_conflict_key_column_values(&self.on_conflict_fields, data_block);
 
-        // partitions by the left-most cluster key expression
+        let partition_keys = self.partition_keys(data_block, &on_conflict_column_values)?;
+
+        // partitions by the partition keys
         let mut partitions: HashMap<Scalar, Partition> = HashMap::new();
 
         // bloom hashes of the columns being pruned with
@@ -404,7 +437,7 @@ impl Partitioner {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        // partition the input block by the left-most cluster key expression
+        // partition the input block by the partition keys
         for row_idx in 0..data_block.num_rows() {
             if let Some(row_digest) = row_hash_of_columns(&on_conflict_column_values, row_idx)? {
                 // collect the bloom hashes of the columns being pruned with
@@ -417,9 +450,7 @@ impl Partitioner {
                         _ => Some(hashes.get(row_idx)),
                     })
                     .collect();
-                let cluster_key_value = cluster_key_values.row_scalar(row_idx)?.to_owned();
-
-                match partitions.entry(cluster_key_value) {
+                match partitions.entry(partition_keys[row_idx].clone()) {
                     Entry::Occupied(ref mut entry) => entry.get_mut().push_row(
                         row_digest,
                         &row_bloom_hashes,
@@ -439,6 +470,54 @@ impl Partitioner {
         }
         Ok(partitions.into_values().collect::<Vec<_>>())
     }
+
+    // the partition key of each row
+    fn partition_keys(
+        &self,
+        data_block: &DataBlock,
+        on_conflict_column_values: &[&Value<AnyType>],
+    ) -> Result<Vec<Scalar>> {
+        let num_rows = data_block.num_rows();
+        match &self.partition_by {
+            PartitionBy::ClusterKey(left_most_cluster_key) => {
+                let evaluator = Evaluator::new(data_block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+                let cluster_key_values = evaluator.run(left_most_cluster_key)?;
+                (0..num_rows)
+                    .map(|row_idx| Ok(cluster_key_values.row_scalar(row_idx)?.to_owned()))
+                    .collect()
+            }
+            PartitionBy::KeyRange(num_ranges) => {
+                Ok(
+                    key_range_ids(on_conflict_column_values[0], num_rows, *num_ranges)?
+                        .into_iter()
+                        .map(|range_id| Scalar::Number(NumberScalar::UInt64(range_id as u64)))
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+// sort the rows by the given key column, and split them into (at most) `num_ranges`
+// ranges of (almost) the same size, returns the range id of each row.
+//
+// rows of the same range have adjacent keys, thus the min/max of each range
+// is narrow enough to prune the blocks that could not have conflicts with it.
+fn key_range_ids(
+    key_column: &Value<AnyType>,
+    num_rows: usize,
+    num_ranges: usize,
+) -> Result<Vec<usize>> {
+    let mut sorted_rows = (0..num_rows)
+        .map(|row_idx| Ok((key_column.row_scalar(row_idx)?, row_idx)))
+        .collect::<Result<Vec<_>>>()?;
+    sorted_rows.sort();
+
+    let mut range_ids = vec![0; num_rows];
+    for (rank, (_, row_idx)) in sorted_rows.into_iter().enumerate() {
+        range_ids[row_idx] = rank * num_ranges / num_rows;
+    }
+    Ok(range_ids)
 }
 
 fn on_conflict_key_column_values<'a>(
@@ -524,4 +603,23 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_key_range_ids() -> Result<()> {
+        let column = Value::Column(NumberType::<u32>::from_data(vec![7, 1, 5, 3, 2, 8, 4, 6]));
+
+        // 8 rows are split into 4 ranges: [1, 2], [3, 4], [5, 6], [7, 8]
+        let range_ids = key_range_ids(&column, 8, 4)?;
+        assert_eq!(range_ids, vec![3, 0, 2, 1, 0, 3, 1, 2]);
+
+        // more ranges than rows, each row is a range
+        let range_ids = key_range_ids(&column, 8, 16)?;
+        assert_eq!(range_ids, vec![12, 0, 8, 4, 2, 14, 6, 10]);
+
+        // single range
+        let range_ids = key_range_ids(&column, 8, 1)?;
+        assert_eq!(range_ids, vec![0; 8]);
+
+        Ok(())
+    }
 }
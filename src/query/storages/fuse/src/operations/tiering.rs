@@ -0,0 +1,221 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::ListIndexesByIdReq;
+use databend_common_storage::cold_path;
+use databend_common_storage::StoragePolicies;
+use databend_common_storage::COLD_PATH_PREFIX;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_AFTER_DAYS;
+use databend_storages_common_table_meta::table::OPT_KEY_COLD_STORAGE_POLICY;
+use log::info;
+use opendal::ErrorKind;
+use opendal::Operator;
+
+use crate::io::SegmentWriter;
+use crate::io::SegmentsIO;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+use crate::DEFAULT_COLD_AFTER_DAYS;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TieringResult {
+    pub moved_segments: usize,
+    pub moved_blocks: usize,
+}
+
+impl FuseTable {
+    /// Moves the cold segments of the table to its `cold_storage_policy`.
+    ///
+    /// Object storages do not keep the access time of the files, so a segment is cold
+    /// if all of its blocks are created more than `cold_after_days` days ago.
+    ///
+    /// The files of the cold blocks are copied to the cold storage first, then a snapshot
+    /// that points to the copied files with the `_cold/` prefix is committed. The files
+    /// on the hot storage are removed by purge, like the files replaced by compaction.
+    #[async_backtrace::framed]
+    pub async fn do_tiering(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        limit: Option<usize>,
+    ) -> Result<TieringResult> {
+        let Some(policy) = self.table_info.options().get(OPT_KEY_COLD_STORAGE_POLICY) else {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "table {} has no {} option",
+                self.table_info.desc, OPT_KEY_COLD_STORAGE_POLICY
+            )));
+        };
+        let cold = StoragePolicies::instance().get(policy)?.operator();
+
+        let mut result = TieringResult::default();
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(result);
+        };
+
+        let cold_after_days = self.get_option(OPT_KEY_COLD_AFTER_DAYS, DEFAULT_COLD_AFTER_DAYS);
+        let cold_before = Utc::now() - Duration::days(cold_after_days as i64);
+
+        let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
+        let agg_index_ids = catalog
+            .list_index_ids_by_table_id(ListIndexesByIdReq::new(ctx.get_tenant(), self.get_id()))
+            .await?;
+
+        let schema = self.schema();
+        let segment_writer = SegmentWriter::new(&self.operator, &self.meta_location_generator);
+        let mut segments = snapshot.segments.clone();
+        for (idx, location) in snapshot.segments.iter().enumerate() {
+            if limit.is_some_and(|limit| result.moved_segments >= limit) {
+                break;
+            }
+            ctx.check_aborting()?;
+
+            let compact_segment = SegmentsIO::read_compact_segment(
+                self.operator.clone(),
+                location.clone(),
+                schema.clone(),
+                false,
+            )
+            .await?;
+            let segment = SegmentInfo::try_from(compact_segment.as_ref())?;
+            if !is_cold_segment(&segment, cold_before) {
+                continue;
+            }
+
+            let mut blocks = Vec::with_capacity(segment.blocks.len());
+            for block in segment.blocks.iter() {
+                if cold_path(&block.location.0).is_some() {
+                    blocks.push(block.clone());
+                    continue;
+                }
+                let block = self.move_block(&cold, block, &agg_index_ids).await?;
+                blocks.push(Arc::new(block));
+                result.moved_blocks += 1;
+            }
+
+            segments[idx] = segment_writer
+                .write_segment(SegmentInfo::new(blocks, segment.summary.clone()))
+                .await?;
+            result.moved_segments += 1;
+        }
+
+        if result.moved_segments == 0 {
+            return Ok(result);
+        }
+
+        let mut new_snapshot =
+            TableSnapshot::from_previous(&snapshot, Some(self.table_info.ident.seq));
+        new_snapshot.segments = segments;
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &self.table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await?;
+
+        info!(
+            "tiering: moved {} segments, {} blocks of table {} to storage policy {}",
+            result.moved_segments, result.moved_blocks, self.table_info.desc, policy
+        );
+        Ok(result)
+    }
+
+    /// Copies the files of a block to the cold storage, and returns the block meta
+    /// with the locations on the cold storage.
+    async fn move_block(
+        &self,
+        cold: &Operator,
+        block: &BlockMeta,
+        agg_index_ids: &[u64],
+    ) -> Result<BlockMeta> {
+        let location = &block.location.0;
+
+        // The indexes located by the block location are moved along with the block.
+        let mut derived = vec![TableMetaLocationGenerator::gen_virtual_block_location(
+            location,
+        )];
+        for index_id in agg_index_ids {
+            derived.push(
+                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                    location, *index_id,
+                ),
+            );
+        }
+        for index in self.table_info.meta.indexes.values() {
+            derived.push(
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    location,
+                    &index.name,
+                    &index.version,
+                ),
+            );
+        }
+        for path in derived {
+            self.copy_to_cold(cold, &path, false).await?;
+        }
+
+        let mut block = block.clone();
+        block.location.0 = self.copy_to_cold(cold, location, true).await?;
+        if let Some(bloom) = block.bloom_filter_index_location.as_mut() {
+            bloom.0 = self.copy_to_cold(cold, &bloom.0, true).await?;
+        }
+        if let Some(vector_index) = block.vector_index_location.as_mut() {
+            vector_index.0 = self.copy_to_cold(cold, &vector_index.0, true).await?;
+        }
+        if let Some(deletion_vector) = block.deletion_vector.as_mut() {
+            deletion_vector.location.0 = self
+                .copy_to_cold(cold, &deletion_vector.location.0, true)
+                .await?;
+        }
+        Ok(block)
+    }
+
+    /// Copies a file to the same path on the cold storage, and returns the location of it
+    /// with the cold prefix. The optional files that do not exist are skipped.
+    async fn copy_to_cold(&self, cold: &Operator, path: &str, required: bool) -> Result<String> {
+        match self.operator.read(path).await {
+            Ok(data) => cold.write(path, data).await?,
+            Err(e) if !required && e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e.into()),
+        }
+        Ok(format!("{COLD_PATH_PREFIX}{path}"))
+    }
+}
+
+/// A segment is cold if all of its blocks are created before `cold_before`, and some of
+/// them are not moved yet. The blocks without the creation time are never cold.
+fn is_cold_segment(segment: &SegmentInfo, cold_before: DateTime<Utc>) -> bool {
+    segment
+        .blocks
+        .iter()
+        .any(|block| cold_path(&block.location.0).is_none())
+        && segment.blocks.iter().all(|block| {
+            cold_path(&block.location.0).is_some()
+                || block.create_on.is_some_and(|t| t < cold_before)
+        })
+}
@@ -83,6 +83,7 @@ impl FuseTable {
         update_list: Vec<(FieldIndex, RemoteExpr<String>)>,
         computed_list: BTreeMap<FieldIndex, RemoteExpr<String>>,
         query_row_id_col: bool,
+        returning: Vec<RemoteExpr<String>>,
         pipeline: &mut Pipeline,
     ) -> Result<()> {
         let all_column_indices = self.all_column_indices();
@@ -202,6 +203,15 @@ impl FuseTable {
             projection: offset_map.values().cloned().collect(),
         });
 
+        // the updated block is in the order of the table columns.
+        let output_schema = TableSchema::new(
+            offset_map
+                .keys()
+                .map(|id| schema.fields()[*id].clone())
+                .collect(),
+        );
+        let returning = MutationSource::returning_operator(returning, &output_schema)?;
+
         let block_reader = self.create_block_reader(
             ctx.clone(),
             projection,
@@ -240,6 +250,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     true,
+                    returning.clone(),
                 )
             },
             max_threads,
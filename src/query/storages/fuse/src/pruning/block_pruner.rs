@@ -26,10 +26,12 @@ use databend_common_expression::types::F32;
 use databend_common_expression::BLOCK_NAME_COL_NAME;
 use databend_common_metrics::storage::*;
 use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_pruner::LimiterPruner;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use futures_util::future;
 
 use super::SegmentLocation;
+use crate::pruning::FusePruningStatistics;
 use crate::pruning::PruningContext;
 
 pub struct BlockPruner {
@@ -106,6 +108,9 @@ impl BlockPruner {
         let pruning_tasks = std::iter::from_fn(|| {
             // check limit speculatively
             if limit_pruner.exceeded() {
+                // the rest blocks are pruned by the limit.
+                let rest = block_meta_indexes.by_ref().count();
+                pruning_stats.set_blocks_limit_pruning_before(rest as u64);
                 return None;
             }
 
@@ -168,7 +173,7 @@ impl BlockPruner {
                                 let keep = bloom_pruner
                                     .should_keep(&index_location, index_size, column_ids)
                                     .await
-                                    && limit_pruner.within_limit(row_count);
+                                    && within_limit(&limit_pruner, &pruning_stats, row_count);
                                 if keep {
                                     // Perf.
                                     {
@@ -182,7 +187,7 @@ impl BlockPruner {
                                 }
                                 keep
                             } else {
-                                limit_pruner.within_limit(row_count)
+                                within_limit(&limit_pruner, &pruning_stats, row_count)
                             };
                             if keep {
                                 let (keep, range) =
@@ -297,7 +302,8 @@ impl BlockPruner {
 
         let mut result = Vec::with_capacity(block_meta_indexes.len());
         let block_num = block_metas.len();
-        for (block_idx, block_meta) in block_meta_indexes {
+        let num_indexes = block_meta_indexes.len();
+        for (i, (block_idx, block_meta)) in block_meta_indexes.into_iter().enumerate() {
             // Perf.
             {
                 metrics_inc_blocks_range_pruning_before(1);
@@ -308,11 +314,13 @@ impl BlockPruner {
 
             // check limit speculatively
             if limit_pruner.exceeded() {
+                // the rest blocks are pruned by the limit.
+                pruning_stats.set_blocks_limit_pruning_before((num_indexes - i) as u64);
                 break;
             }
             let row_count = block_meta.row_count;
             if range_pruner.should_keep(&block_meta.col_stats, Some(&block_meta.col_metas))
-                && limit_pruner.within_limit(row_count)
+                && within_limit(&limit_pruner, &pruning_stats, row_count)
             {
                 // Perf.
                 {
@@ -383,3 +391,17 @@ impl BlockPruneResult {
         }
     }
 }
+
+// Checks the block against the pushed down limit, and counts it in the limit pruning stats.
+fn within_limit(
+    limit_pruner: &LimiterPruner,
+    pruning_stats: &FusePruningStatistics,
+    row_count: u64,
+) -> bool {
+    pruning_stats.set_blocks_limit_pruning_before(1);
+    let keep = limit_pruner.within_limit(row_count);
+    if keep {
+        pruning_stats.set_blocks_limit_pruning_after(1);
+    }
+    keep
+}
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_base::base::tokio::sync::OwnedSemaphorePermit;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
@@ -37,6 +38,7 @@ use databend_storages_common_pruner::PagePrunerCreator;
 use databend_storages_common_pruner::RangePruner;
 use databend_storages_common_pruner::RangePrunerCreator;
 use databend_storages_common_pruner::TopNPrunner;
+use databend_storages_common_pruner::VectorIndexPruner;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -44,6 +46,7 @@ use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use log::warn;
 use opendal::Operator;
 
+use crate::io::read_vector_index;
 use crate::operations::DeletedSegmentInfo;
 use crate::pruning::segment_pruner::SegmentPruner;
 use crate::pruning::BlockPruner;
@@ -384,7 +387,9 @@ impl FusePruner {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
                     // TopN pruner.
-                    self.topn_pruning(metas)
+                    let metas = self.topn_pruning(metas)?;
+                    // Vector index pruner.
+                    self.vector_index_pruning(metas).await
                 }
             }
         }
@@ -447,7 +452,9 @@ impl FusePruner {
                 // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                 // will get here, we can prevent other mutations like update and so on.
                 // TopN pruner.
-                self.topn_pruning(metas)
+                let metas = self.topn_pruning(metas)?;
+                // Vector index pruner.
+                self.vector_index_pruning(metas).await
             }
         }
     }
@@ -469,11 +476,77 @@ impl FusePruner {
             let limit = push_down.limit.unwrap();
             let sort = push_down.order_by.clone();
             let topn_pruner = TopNPrunner::create(schema, sort, limit);
-            return Ok(topn_pruner.prune(metas.clone()).unwrap_or(metas));
+            let pruning_stats = &self.pruning_ctx.pruning_stats;
+            pruning_stats.set_blocks_topn_pruning_before(metas.len() as u64);
+            let metas = topn_pruner.prune(metas.clone()).unwrap_or(metas);
+            pruning_stats.set_blocks_topn_pruning_after(metas.len() as u64);
+            return Ok(metas);
         }
         Ok(metas)
     }
 
+    // vector index pruner:
+    // if there are ordering by the distance to a vector column + limit clause and no filters,
+    // use the IVF index of the blocks to prune the blocks that are too far away.
+    // With filters, it is unknown how many vectors of each cluster will be left, so the
+    // distance threshold can not be derived and all the blocks are kept.
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let Some(push_down) = self.push_down.as_ref().filter(|p| p.filters.is_none()) else {
+            return Ok(metas);
+        };
+        let Some(info) = &push_down.vector_index else {
+            return Ok(metas);
+        };
+        if info.limit == 0 || info.limit >= metas.len() {
+            return Ok(metas);
+        }
+        let query = info.query_values.iter().map(|v| v.0).collect();
+        let Some(pruner) = VectorIndexPruner::try_create(
+            self.table_schema.clone(),
+            &info.column_name,
+            &info.func_name,
+            query,
+            info.limit,
+        ) else {
+            return Ok(metas);
+        };
+
+        // Load the index files of the blocks, a block whose index can not be loaded is
+        // treated as not indexed and kept.
+        let load_tasks = metas.iter().map(|(_, meta)| {
+            let dal = self.pruning_ctx.dal.clone();
+            let location = meta.vector_index_location.clone();
+            move |permit: OwnedSemaphorePermit| async move {
+                let _permit = permit;
+                let location = location?;
+                match read_vector_index(&dal, &location).await {
+                    Ok(index) => Some(index),
+                    Err(e) => {
+                        warn!("failed to load vector index {}: {}", location.0, e);
+                        None
+                    }
+                }
+            }
+        });
+        let join_handlers = self
+            .pruning_ctx
+            .pruning_runtime
+            .try_spawn_batch_with_owned_semaphore(
+                self.pruning_ctx.pruning_semaphore.clone(),
+                load_tasks,
+            )
+            .await?;
+        let indexes = futures::future::try_join_all(join_handlers)
+            .await
+            .map_err(|e| ErrorCode::StorageOther(format!("vector index pruning failure, {}", e)))?;
+
+        Ok(pruner.prune(metas, indexes))
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> databend_common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
@@ -492,6 +565,24 @@ impl FusePruner {
         let blocks_inverted_index_pruning_after =
             stats.get_blocks_inverted_index_pruning_after() as usize;
 
+        // the limit is applied by the limit pruner without ordering, or by the topn pruner with
+        // ordering. Otherwise the limit pruner is unlimited and every block is counted as kept.
+        let limit_push_down = self
+            .push_down
+            .as_ref()
+            .filter(|p| p.filters.is_none() && p.limit.is_some());
+        let (blocks_limit_pruning_before, blocks_limit_pruning_after) = match limit_push_down {
+            Some(p) if p.order_by.is_empty() => (
+                stats.get_blocks_limit_pruning_before() as usize,
+                stats.get_blocks_limit_pruning_after() as usize,
+            ),
+            Some(_) => (
+                stats.get_blocks_topn_pruning_before() as usize,
+                stats.get_blocks_topn_pruning_after() as usize,
+            ),
+            None => (0, 0),
+        };
+
         databend_common_catalog::plan::PruningStatistics {
             segments_range_pruning_before,
             segments_range_pruning_after,
@@ -501,6 +592,8 @@ impl FusePruner {
             blocks_bloom_pruning_after,
             blocks_inverted_index_pruning_before,
             blocks_inverted_index_pruning_after,
+            blocks_limit_pruning_before,
+            blocks_limit_pruning_after,
         }
     }
 
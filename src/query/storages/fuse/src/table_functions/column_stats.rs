@@ -0,0 +1,53 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::TableSchema;
+use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+/// Formats the min/max statistics as a JSON object keyed by the column names, so that the users
+/// can tell how the blocks and segments would be pruned. The columns without statistics are
+/// skipped.
+pub fn column_stats_to_json(schema: &TableSchema, col_stats: &StatisticsOfColumns) -> String {
+    let mut columns = Map::new();
+    for field in schema.leaf_fields() {
+        if let Some(stats) = col_stats.get(&field.column_id()) {
+            columns.insert(
+                field.name().clone(),
+                json!({
+                    "min": stats.min.to_string(),
+                    "max": stats.max.to_string(),
+                    "null_count": stats.null_count,
+                    "distinct_of_values": stats.distinct_of_values,
+                }),
+            );
+        }
+    }
+    Value::Object(columns).to_string()
+}
+
+/// Formats the cluster statistics of a block, the level tells the depth the block was compacted
+/// to by the recluster.
+pub fn cluster_stats_to_json(cluster_stats: &ClusterStatistics) -> String {
+    json!({
+        "cluster_key_id": cluster_stats.cluster_key_id,
+        "level": cluster_stats.level,
+        "min": cluster_stats.min.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+        "max": cluster_stats.max.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+    })
+    .to_string()
+}
@@ -39,6 +39,8 @@ use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
 use crate::io::SnapshotHistoryReader;
 use crate::sessions::TableContext;
+use crate::table_functions::column_stats::cluster_stats_to_json;
+use crate::table_functions::column_stats::column_stats_to_json;
 use crate::FuseTable;
 
 pub struct FuseBlock<'a> {
@@ -111,6 +113,9 @@ impl<'a> FuseBlock<'a> {
         let mut row_count = Vec::with_capacity(len);
         let mut bloom_filter_location = vec![];
         let mut bloom_filter_size = Vec::with_capacity(len);
+        let mut column_stats = Vec::with_capacity(len);
+        let mut cluster_stats = Vec::with_capacity(len);
+        let schema = self.table.schema();
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -143,6 +148,8 @@ impl<'a> FuseBlock<'a> {
                             .map(|s| s.0.clone()),
                     );
                     bloom_filter_size.push(block.bloom_filter_index_size);
+                    column_stats.push(column_stats_to_json(&schema, &block.col_stats));
+                    cluster_stats.push(block.cluster_stats.as_ref().map(cluster_stats_to_json));
 
                     row_num += 1;
                     if row_num >= limit {
@@ -188,6 +195,14 @@ impl<'a> FuseBlock<'a> {
                     DataType::Number(NumberDataType::UInt64),
                     Value::Column(UInt64Type::from_data(bloom_filter_size)),
                 ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Column(StringType::from_data(column_stats)),
+                ),
+                BlockEntry::new(
+                    DataType::String.wrap_nullable(),
+                    Value::Column(StringType::from_opt_data(cluster_stats)),
+                ),
             ],
             row_num,
         ))
@@ -209,6 +224,8 @@ impl<'a> FuseBlock<'a> {
                 "bloom_filter_size",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new("column_stats", TableDataType::String),
+            TableField::new("cluster_stats", TableDataType::String.wrap_nullable()),
         ])
     }
 }
@@ -33,6 +33,7 @@ use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
 use crate::io::SnapshotHistoryReader;
 use crate::sessions::TableContext;
+use crate::table_functions::column_stats::column_stats_to_json;
 use crate::FuseTable;
 
 pub struct FuseSegment<'a> {
@@ -102,6 +103,8 @@ impl<'a> FuseSegment<'a> {
         let mut compressed: Vec<u64> = Vec::with_capacity(len);
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
         let mut file_location: Vec<String> = Vec::with_capacity(len);
+        let mut column_stats: Vec<String> = Vec::with_capacity(len);
+        let schema = self.table.schema();
 
         let segments_io = SegmentsIO::create(
             self.ctx.clone(),
@@ -120,12 +123,13 @@ impl<'a> FuseSegment<'a> {
 
             for (idx, segment) in segments.into_iter().enumerate() {
                 let segment = segment?;
-                format_versions.push(segment_locations[idx].1);
+                format_versions.push(chunk[idx].1);
                 block_count.push(segment.summary.block_count);
                 row_count.push(segment.summary.row_count);
                 compressed.push(segment.summary.compressed_byte_size);
                 uncompressed.push(segment.summary.uncompressed_byte_size);
-                file_location.push(segment_locations[idx].0.clone());
+                file_location.push(chunk[idx].0.clone());
+                column_stats.push(column_stats_to_json(&schema, &segment.summary.col_stats));
 
                 row_num += 1;
                 if row_num >= limit {
@@ -146,6 +150,7 @@ impl<'a> FuseSegment<'a> {
             UInt64Type::from_data(row_count),
             UInt64Type::from_data(uncompressed),
             UInt64Type::from_data(compressed),
+            StringType::from_data(column_stats),
         ]))
     }
 
@@ -166,6 +171,7 @@ impl<'a> FuseSegment<'a> {
                 "bytes_compressed",
                 TableDataType::Number(NumberDataType::UInt64),
             ),
+            TableField::new("column_stats", TableDataType::String),
         ])
     }
 }
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::table::Table;
 use databend_common_exception::Result;
 use databend_common_expression::types::StringType;
 use databend_common_expression::DataBlock;
@@ -26,6 +27,7 @@ use databend_storages_common_table_meta::meta::Statistics;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 
 use crate::sessions::TableContext;
+use crate::table_functions::column_stats::column_stats_to_json;
 use crate::FuseTable;
 
 pub struct FuseStatistic<'a> {
@@ -55,27 +57,33 @@ impl<'a> FuseStatistic<'a> {
 
     fn to_block(
         &self,
-        _summy: &Statistics,
+        summary: &Statistics,
         table_statistics: &Option<Arc<TableSnapshotStatistics>>,
     ) -> Result<DataBlock> {
         let mut col_ndvs: Vec<String> = Vec::with_capacity(1);
+        let mut column_stats: Vec<String> = Vec::with_capacity(1);
         if let Some(table_statistics) = table_statistics {
             let mut ndvs: String = "".to_string();
             for (i, n) in table_statistics.column_distinct_values().iter() {
                 ndvs.push_str(&format!("({},{});", *i, *n));
             }
             col_ndvs.push(ndvs);
+            column_stats.push(column_stats_to_json(
+                &self.table.schema(),
+                &summary.col_stats,
+            ));
         };
 
-        Ok(DataBlock::new_from_columns(vec![StringType::from_data(
-            col_ndvs,
-        )]))
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(col_ndvs),
+            StringType::from_data(column_stats),
+        ]))
     }
 
     pub fn schema() -> Arc<TableSchema> {
-        TableSchemaRefExt::create(vec![TableField::new(
-            "column_distinct_values",
-            TableDataType::String,
-        )])
+        TableSchemaRefExt::create(vec![
+            TableField::new("column_distinct_values", TableDataType::String),
+            TableField::new("column_stats", TableDataType::String),
+        ])
     }
 }
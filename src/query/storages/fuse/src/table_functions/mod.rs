@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod clustering_information;
+mod column_stats;
 mod fuse_blocks;
 mod fuse_columns;
 mod fuse_encodings;
@@ -28,6 +28,7 @@ use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_catalog::plan::Projection;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::TzLUT;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
@@ -108,6 +109,7 @@ impl HiveBlockReader {
         projection: Projection,
         partition_keys: &Option<Vec<String>>,
         chunk_size: usize,
+        tz: TzLUT,
     ) -> Result<Arc<HiveBlockReader>> {
         let original_projection = match projection {
             Projection::Columns(projection) => projection,
@@ -131,6 +133,7 @@ impl HiveBlockReader {
             Some(HivePartitionFiller::create(
                 schema.clone(),
                 partition_fields,
+                tz,
             ))
         } else {
             None
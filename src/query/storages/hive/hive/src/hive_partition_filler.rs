@@ -14,6 +14,7 @@
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::TzLUT;
 use databend_common_expression::types::AnyType;
 use databend_common_expression::BlockEntry;
 use databend_common_expression::DataBlock;
@@ -27,11 +28,15 @@ use crate::utils::str_field_to_scalar;
 #[derive(Debug, Clone)]
 pub struct HivePartitionFiller {
     pub partition_fields: Vec<TableField>,
+    pub tz: TzLUT,
 }
 
 impl HivePartitionFiller {
-    pub fn create(_schema: TableSchemaRef, partition_fields: Vec<TableField>) -> Self {
-        HivePartitionFiller { partition_fields }
+    pub fn create(_schema: TableSchemaRef, partition_fields: Vec<TableField>, tz: TzLUT) -> Self {
+        HivePartitionFiller {
+            partition_fields,
+            tz,
+        }
     }
 
     fn generate_value(
@@ -40,7 +45,7 @@ impl HivePartitionFiller {
         value: String,
         field: &TableField,
     ) -> Result<Value<AnyType>> {
-        let value = str_field_to_scalar(&value, &field.data_type().into())?;
+        let value = str_field_to_scalar(&value, &field.data_type().into(), self.tz)?;
         Ok(Value::Scalar(value))
     }
 
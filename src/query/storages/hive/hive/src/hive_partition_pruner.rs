@@ -51,13 +51,14 @@ impl HivePartitionPruner {
     }
 
     pub fn get_column_stats(&self, partitions: &Vec<String>) -> Result<Vec<StatisticsOfColumns>> {
+        let tz = self.ctx.get_function_context()?.tz;
         let mut data = Vec::with_capacity(partitions.len());
         for partition in partitions {
             let mut stats = HashMap::new();
             for (index, singe_value) in partition.split('/').enumerate() {
                 let kv = singe_value.split('=').collect::<Vec<&str>>();
                 let field = self.partition_schema.fields()[index].clone();
-                let scalar = str_field_to_scalar(kv[1], &field.data_type().into())?;
+                let scalar = str_field_to_scalar(kv[1], &field.data_type().into(), tz)?;
                 let null_count = u64::from(scalar.is_null());
                 let column_stats =
                     ColumnStatistics::new(scalar.clone(), scalar, null_count, 0, None);
@@ -24,6 +24,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::Projection;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
@@ -32,6 +33,7 @@ use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::TzLUT;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
 use databend_common_expression::DataSchemaRef;
@@ -181,6 +183,7 @@ impl HiveTable {
     ) -> Result<()> {
         let push_downs = &plan.push_downs;
         let chunk_size = ctx.get_settings().get_hive_parquet_chunk_size()? as usize;
+        let tz = ctx.get_function_context()?.tz;
 
         let parts_len = plan.parts.len();
         let max_threads = ctx.get_settings().get_max_threads()? as usize;
@@ -200,8 +203,9 @@ impl HiveTable {
             self.is_prewhere_column_partition_keys(self.table_info.schema(), &plan.push_downs)?;
         // create prewhere&remaindata block reader
         let prewhere_reader =
-            self.build_prewhere_reader(plan, chunk_size, prewhere_all_partitions)?;
-        let remain_reader = self.build_remain_reader(plan, chunk_size, prewhere_all_partitions)?;
+            self.build_prewhere_reader(plan, chunk_size, prewhere_all_partitions, tz)?;
+        let remain_reader =
+            self.build_remain_reader(plan, chunk_size, prewhere_all_partitions, tz)?;
         let prewhere_filter =
             self.build_prewhere_filter_executor(plan, prewhere_reader.get_output_schema())?;
 
@@ -302,6 +306,7 @@ impl HiveTable {
         plan: &DataSourcePlan,
         chunk_size: usize,
         prewhere_all_partitions: bool,
+        tz: TzLUT,
     ) -> Result<Arc<HiveBlockReader>> {
         match (
             prewhere_all_partitions,
@@ -318,6 +323,7 @@ impl HiveTable {
                     projection,
                     &self.table_options.partition_keys,
                     chunk_size,
+                    tz,
                 )
             }
             (false, Some(v)) => HiveBlockReader::create(
@@ -326,6 +332,7 @@ impl HiveTable {
                 v.prewhere_columns,
                 &self.table_options.partition_keys,
                 chunk_size,
+                tz,
             ),
         }
     }
@@ -351,6 +358,7 @@ impl HiveTable {
         plan: &DataSourcePlan,
         chunk_size: usize,
         prewhere_all_partitions: bool,
+        tz: TzLUT,
     ) -> Result<Arc<Option<HiveBlockReader>>> {
         Ok(
             match (
@@ -368,6 +376,7 @@ impl HiveTable {
                             v.remain_columns,
                             &self.table_options.partition_keys,
                             chunk_size,
+                            tz,
                         )?;
                         Arc::new(Some((*reader).clone()))
                     }
@@ -554,6 +563,16 @@ impl Table for HiveTable {
         true
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        // the files are read as a whole, regardless of the limit
+        PushDownCapabilities {
+            filter: true,
+            projection: true,
+            limit: false,
+            aggregate: false,
+        }
+    }
+
     fn has_exact_total_row_count(&self) -> bool {
         false
     }
@@ -14,7 +14,11 @@
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::date_helper::TzLUT;
+use databend_common_expression::serialize::uniform_date;
+use databend_common_expression::types::date::string_to_date;
 use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::timestamp::string_to_timestamp;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::Scalar;
@@ -22,16 +26,32 @@ use ordered_float::OrderedFloat;
 
 use crate::hive_table::HIVE_DEFAULT_PARTITION;
 
-pub(crate) fn str_field_to_scalar(value: &str, data_type: &DataType) -> Result<Scalar> {
+/// Converts the value of a partition to a scalar, the date and timestamp values
+/// are written without a timezone by hive, so they are read in the session timezone.
+pub(crate) fn str_field_to_scalar(value: &str, data_type: &DataType, tz: TzLUT) -> Result<Scalar> {
     match data_type {
         DataType::Nullable(c) => {
             if value == HIVE_DEFAULT_PARTITION {
                 Ok(Scalar::Null)
             } else {
-                str_field_to_scalar(value, c.as_ref())
+                str_field_to_scalar(value, c.as_ref(), tz)
             }
         }
         DataType::String => Ok(Scalar::String(value.to_string())),
+        DataType::Date => {
+            let date = string_to_date(value, tz.tz).ok_or_else(|| {
+                ErrorCode::BadBytes(format!("invalid date partition value: {}", value))
+            })?;
+            Ok(Scalar::Date(uniform_date(date)))
+        }
+        DataType::Timestamp => {
+            // hive escapes the colons in the partition path.
+            let value = value.replace("%3A", ":");
+            let ts = string_to_timestamp(&value, tz.tz).ok_or_else(|| {
+                ErrorCode::BadBytes(format!("invalid timestamp partition value: {}", value))
+            })?;
+            Ok(Scalar::Timestamp(ts.timestamp_micros()))
+        }
         DataType::Number(num_ty) => match num_ty {
             NumberDataType::UInt8 => {
                 let num = value.parse::<u8>().unwrap();
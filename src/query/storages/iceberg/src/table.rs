@@ -27,6 +27,7 @@ use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
@@ -368,6 +369,15 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        // The filters prune the data files by their column statistics.
+        PushDownCapabilities {
+            filter: true,
+            projection: true,
+            ..PushDownCapabilities::NONE
+        }
+    }
 }
 
 struct OperatorCreatorWrapper(DataOperator);
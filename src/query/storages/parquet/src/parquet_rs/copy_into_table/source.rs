@@ -171,7 +171,7 @@ impl Processor for ParquetCopySource {
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Init) {
             State::Init => {
-                if let Some(part) = self.ctx.get_partition() {
+                if let Some(part) = self.ctx.fetch_partition().await? {
                     match ParquetPart::from_part(&part)? {
                         ParquetPart::ParquetRSRowGroup(part) => {
                             let schema_index = part.schema_index;
@@ -28,6 +28,7 @@ use databend_common_catalog::plan::ParquetReadOptions;
 use databend_common_catalog::plan::ParquetTableInfo;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table::ColumnStatisticsProvider;
@@ -202,6 +203,16 @@ impl Table for ParquetRSTable {
         self.read_options.do_prewhere()
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        // The filters prune the row groups and pages, the row groups are read as a whole
+        // regardless of the limit.
+        PushDownCapabilities {
+            filter: true,
+            projection: true,
+            ..PushDownCapabilities::NONE
+        }
+    }
+
     fn has_exact_total_row_count(&self) -> bool {
         true
     }
@@ -20,6 +20,7 @@ use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_transforms::processors::AccumulatingTransformer;
+use databend_common_storage::MultipartUpload;
 
 use super::parquet_file::append_data_to_parquet_files;
 use super::row_based_file::append_data_to_row_based_files;
@@ -48,6 +49,10 @@ impl StageTable {
             }
         };
         let max_threads = settings.get_max_threads()? as usize;
+        let multipart = MultipartUpload::new(
+            settings.get_storage_write_part_size()? as usize,
+            settings.get_storage_write_concurrency()? as usize,
+        );
 
         let op = StageTable::get_op(&self.table_info.stage_info)?;
         let fmt = self.table_info.stage_info.file_format_params.clone();
@@ -59,6 +64,7 @@ impl StageTable {
                 ctx.clone(),
                 self.table_info.clone(),
                 op,
+                multipart,
                 max_file_size,
                 max_threads,
                 uuid,
@@ -69,6 +75,7 @@ impl StageTable {
                 ctx.clone(),
                 self.table_info.clone(),
                 op,
+                multipart,
                 max_file_size,
                 max_threads,
                 uuid,
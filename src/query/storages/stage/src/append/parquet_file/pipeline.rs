@@ -19,6 +19,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_formats::FileFormatOptionsExt;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_storage::MultipartUpload;
 use opendal::Operator;
 
 use super::limit_file_size_processor::LimitFileSizeProcessor;
@@ -32,6 +33,7 @@ pub(crate) fn append_data_to_parquet_files(
     ctx: Arc<dyn TableContext>,
     table_info: StageTableInfo,
     op: Operator,
+    multipart: MultipartUpload,
     max_file_size: usize,
     max_threads: usize,
     uuid: String,
@@ -58,6 +60,7 @@ pub(crate) fn append_data_to_parquet_files(
             table_info.clone(),
             output_format,
             op.clone(),
+            multipart,
             uuid.clone(),
             gid,
         )
@@ -28,6 +28,7 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_storage::MultipartUpload;
 use opendal::Operator;
 
 use super::block_batch::BlockBatch;
@@ -48,6 +49,7 @@ pub struct ParquetFileWriter {
     file_to_write: Option<(Vec<u8>, DataSummary)>,
 
     data_accessor: Operator,
+    multipart: MultipartUpload,
 
     uuid: String,
     group_id: usize,
@@ -61,6 +63,7 @@ impl ParquetFileWriter {
         table_info: StageTableInfo,
         output_format: Box<dyn OutputFormat>,
         data_accessor: Operator,
+        multipart: MultipartUpload,
         uuid: String,
         group_id: usize,
     ) -> Result<ProcessorPtr> {
@@ -76,6 +79,7 @@ impl ParquetFileWriter {
             input_data: None,
             file_to_write: None,
             data_accessor,
+            multipart,
             uuid,
             group_id,
             batch_id: 0,
@@ -165,7 +169,9 @@ impl Processor for ParquetFileWriter {
         );
         let (data, summary) = mem::take(&mut self.file_to_write).unwrap();
         self.unload_output.add_file(&path, summary);
-        self.data_accessor.write(&path, data).await?;
+        self.multipart
+            .write(&self.data_accessor, &path, data)
+            .await?;
         self.batch_id += 1;
         Ok(())
     }
@@ -20,6 +20,7 @@ use databend_common_exception::Result;
 use databend_common_formats::FileFormatOptionsExt;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::input_formats::InputContext;
+use databend_common_storage::MultipartUpload;
 use opendal::Operator;
 
 use super::limit_file_size_processor::LimitFileSizeProcessor;
@@ -35,6 +36,7 @@ pub(crate) fn append_data_to_row_based_files(
     ctx: Arc<dyn TableContext>,
     table_info: StageTableInfo,
     op: Operator,
+    multipart: MultipartUpload,
     max_file_size: usize,
     max_threads: usize,
     uuid: String,
@@ -78,6 +80,7 @@ pub(crate) fn append_data_to_row_based_files(
             output,
             table_info.clone(),
             op.clone(),
+            multipart,
             prefix.clone(),
             uuid.clone(),
             gid,
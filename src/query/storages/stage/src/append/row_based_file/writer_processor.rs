@@ -29,6 +29,7 @@ use databend_common_pipeline_core::processors::InputPort;
 use databend_common_pipeline_core::processors::OutputPort;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_storage::MultipartUpload;
 use opendal::Operator;
 
 use super::buffers::FileOutputBuffers;
@@ -50,6 +51,7 @@ pub struct RowBasedFileWriter {
     unload_output_blocks: Option<VecDeque<DataBlock>>,
 
     data_accessor: Operator,
+    multipart: MultipartUpload,
     prefix: Vec<u8>,
 
     uuid: String,
@@ -65,6 +67,7 @@ impl RowBasedFileWriter {
         output: Arc<OutputPort>,
         table_info: StageTableInfo,
         data_accessor: Operator,
+        multipart: MultipartUpload,
         prefix: Vec<u8>,
         uuid: String,
         group_id: usize,
@@ -77,6 +80,7 @@ impl RowBasedFileWriter {
             input,
             input_data: None,
             data_accessor,
+            multipart,
             prefix,
             uuid,
             group_id,
@@ -179,7 +183,9 @@ impl Processor for RowBasedFileWriter {
         );
         let (data, summary) = mem::take(&mut self.file_to_write).unwrap();
         self.unload_output.add_file(&path, summary);
-        self.data_accessor.write(&path, data).await?;
+        self.multipart
+            .write(&self.data_accessor, &path, data)
+            .await?;
         self.batch_id += 1;
         Ok(())
     }
@@ -22,6 +22,9 @@ mod append;
 mod input_context_bridge;
 mod one_file_partition;
 mod read;
+mod stage_directory;
 mod stage_table;
 
+pub use read::row_based::infer_xlsx_schema;
+pub use stage_directory::StageDirectory;
 pub use stage_table::StageTable;
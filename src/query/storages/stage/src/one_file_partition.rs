@@ -16,6 +16,7 @@ use std::any::Any;
 use std::hash::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::ops::Range;
 
 use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartInfoPtr;
@@ -26,6 +27,9 @@ use databend_common_exception::Result;
 pub struct OneFilePartition {
     pub path: String,
     pub size: usize,
+    /// Byte range of the file to read, `None` for the whole file.
+    /// Set when a large text file is split at record boundaries.
+    pub range: Option<Range<usize>>,
 }
 
 #[typetag::serde(name = "text_part")]
@@ -43,6 +47,7 @@ impl PartInfo for OneFilePartition {
     fn hash(&self) -> u64 {
         let mut s = DefaultHasher::new();
         self.path.hash(&mut s);
+        self.range.hash(&mut s);
         s.finish()
     }
 }
@@ -55,4 +60,8 @@ impl OneFilePartition {
                 ErrorCode::Internal("Cannot downcast from PartInfo to OneFilePartition.")
             })
     }
+
+    pub fn read_range(&self) -> Range<usize> {
+        self.range.clone().unwrap_or(0..self.size)
+    }
 }
@@ -91,6 +91,9 @@ impl RowBatchWithPosition {
 pub enum RowBatch {
     Csv(CSVRowBatch),
     NDJson(NdjsonRowBatch),
+    Tsv(TsvRowBatch),
+    Avro(AvroRowBatch),
+    Xlsx(XlsxRowBatch),
 }
 
 impl RowBatch {
@@ -98,6 +101,9 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.rows(),
             RowBatch::NDJson(b) => b.rows(),
+            RowBatch::Tsv(b) => b.rows(),
+            RowBatch::Avro(b) => b.rows(),
+            RowBatch::Xlsx(b) => b.rows(),
         }
     }
 
@@ -105,6 +111,9 @@ impl RowBatch {
         match self {
             RowBatch::Csv(b) => b.size(),
             RowBatch::NDJson(b) => b.size(),
+            RowBatch::Tsv(b) => b.size(),
+            RowBatch::Avro(b) => b.size(),
+            RowBatch::Xlsx(b) => b.size(),
         }
     }
 }
@@ -120,6 +129,19 @@ pub struct CSVRowBatch {
     pub num_fields: Vec<usize>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct TsvRowBatch {
+    /// the row started in the last batch, as the first row of this batch
+    pub tail_of_last_batch: Option<Vec<u8>>,
+
+    /// the BytesBatch read, without copy. The following rows start at `start`,
+    /// row[i] ends at row_ends[i], including the record delimiter,
+    /// except the last row of a file that does not end with it.
+    pub data: Vec<u8>,
+    pub start: usize,
+    pub row_ends: Vec<usize>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
 pub struct NdjsonRowBatch {
     // as the first row of this batch
@@ -199,7 +221,53 @@ impl NdjsonRowBatch {
     }
 }
 
+/// Records of an AVRO container file, already decoded since they can not be
+/// separated without decoding.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct AvroRowBatch {
+    pub records: Vec<serde_json::Value>,
+    /// the encoded size of the records
+    pub size: usize,
+}
+
+impl AvroRowBatch {
+    pub fn rows(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+/// Rows of a sheet, with the cells converted to JSON values.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Default)]
+pub struct XlsxRowBatch {
+    pub rows: Vec<Vec<serde_json::Value>>,
+    /// the size of the rows in the compressed file
+    pub size: usize,
+}
+
+impl XlsxRowBatch {
+    pub fn rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
 impl CSVRowBatch {
+    pub fn with_capacity(rows: usize, num_fields: usize) -> Self {
+        Self {
+            data: vec![],
+            row_ends: Vec::with_capacity(rows),
+            field_ends: Vec::with_capacity(rows * num_fields),
+            num_fields: Vec::with_capacity(rows),
+        }
+    }
+
     pub fn rows(&self) -> usize {
         self.row_ends.len()
     }
@@ -209,6 +277,32 @@ impl CSVRowBatch {
     }
 }
 
+impl TsvRowBatch {
+    pub fn iter(&self) -> impl Iterator<Item = &[u8]> {
+        self.tail_of_last_batch
+            .iter()
+            .map(|row| row.as_slice())
+            .chain(self.row_ends.iter().scan(self.start, |start, end| {
+                let row = &self.data[*start..*end];
+                *start = *end;
+                Some(row)
+            }))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.row_ends.len() + self.tail_of_last_batch.is_some() as usize
+    }
+
+    pub fn size(&self) -> usize {
+        self.data.len() - self.start
+            + self
+                .tail_of_last_batch
+                .as_ref()
+                .map(|v| v.len())
+                .unwrap_or(0)
+    }
+}
+
 #[typetag::serde(name = "row_batch")]
 impl BlockMetaInfo for RowBatchWithPosition {
     fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::Column;
 use databend_common_expression::DataBlock;
 use databend_common_meta_app::principal::FileFormatParams;
@@ -24,8 +25,11 @@ use super::batch::BytesBatch;
 use super::batch::RowBatchWithPosition;
 use super::processors::BlockBuilderState;
 use crate::read::load_context::LoadContext;
+use crate::read::row_based::formats::AvroInputFormat;
 use crate::read::row_based::formats::CsvInputFormat;
 use crate::read::row_based::formats::NdJsonInputFormat;
+use crate::read::row_based::formats::TsvInputFormat;
+use crate::read::row_based::formats::XlsxInputFormat;
 
 pub trait SeparatorState: Send + Sync {
     fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)>;
@@ -43,11 +47,44 @@ pub trait RowDecoder: Send + Sync {
     }
 }
 
+/// Replace the columns not selected by `select $1, $2 .. from @stage` with empty strings,
+/// since nothing was pushed into them.
+pub fn fill_unprojected_columns(
+    load_ctx: &LoadContext,
+    columns: Vec<Column>,
+    num_rows: usize,
+) -> Vec<Column> {
+    if let Some(projection) = &load_ctx.pos_projection {
+        let empty_strings = Column::String(
+            StringColumnBuilder {
+                need_estimated: false,
+                data: vec![],
+                offsets: vec![0; num_rows + 1],
+            }
+            .build(),
+        );
+        columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| {
+                if projection.contains(&i) {
+                    c
+                } else {
+                    empty_strings.clone()
+                }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        columns
+    }
+}
+
 pub trait RowBasedFileFormat: Sync + Send {
+    /// Create the separator of one file, or of one split of it, from its first batch.
     fn try_create_separator(
         &self,
         load_ctx: Arc<LoadContext>,
-        path: &str,
+        first_batch: &BytesBatch,
     ) -> Result<Box<dyn SeparatorState>>;
     fn try_create_decoder(&self, load_ctx: Arc<LoadContext>) -> Result<Arc<dyn RowDecoder>>;
 }
@@ -56,6 +93,9 @@ pub fn create_row_based_file_format(params: &FileFormatParams) -> Arc<dyn RowBas
     match params {
         FileFormatParams::Csv(p) => Arc::new(CsvInputFormat { params: p.clone() }),
         FileFormatParams::NdJson(p) => Arc::new(NdJsonInputFormat { params: p.clone() }),
+        FileFormatParams::Tsv(p) => Arc::new(TsvInputFormat { params: p.clone() }),
+        FileFormatParams::Avro(p) => Arc::new(AvroInputFormat { params: p.clone() }),
+        FileFormatParams::Xlsx(p) => Arc::new(XlsxInputFormat { params: p.clone() }),
         _ => {
             unreachable!("Unsupported row based file format")
         }
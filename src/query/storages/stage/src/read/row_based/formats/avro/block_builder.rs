@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::formats::ndjson::NdJsonDecoder;
+use crate::read::row_based::processors::BlockBuilderState;
+
+pub struct AvroDecoder {
+    pub inner: NdJsonDecoder,
+}
+
+impl RowDecoder for AvroDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let columns = &mut state.mutable_columns;
+        let data = batch.data.into_avro().unwrap();
+
+        for (row_id, record) in data.records.iter().enumerate() {
+            if let Err(e) = self.inner.read_value(record, columns, &[]) {
+                self.inner.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    &batch.start_pos.path,
+                    batch.start_pos.rows + row_id,
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+}
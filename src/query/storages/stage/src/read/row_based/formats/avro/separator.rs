@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Reader;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+use serde_json::Value as JsonValue;
+
+use crate::read::row_based::batch::AvroRowBatch;
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::SeparatorState;
+
+const RECORDS_PER_BATCH: usize = 8192;
+
+/// An AVRO container file starts with the schema, so it is buffered
+/// until the end of the file, and then decoded as a whole.
+pub struct AvroRowSeparator {
+    data: Vec<u8>,
+    pos: Position,
+}
+
+impl SeparatorState for AvroRowSeparator {
+    fn append(&mut self, mut batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        if self.data.is_empty() {
+            self.data = std::mem::take(&mut batch.data);
+        } else {
+            self.data.extend_from_slice(&batch.data);
+        }
+        if !batch.is_eof {
+            return Ok((vec![], FileStatus::default()));
+        }
+        let batches = self.decode()?;
+        Ok((batches, FileStatus::default()))
+    }
+}
+
+impl AvroRowSeparator {
+    pub fn try_create(path: &str) -> Result<Self> {
+        Ok(Self {
+            data: vec![],
+            pos: Position::new(path.to_string()),
+        })
+    }
+
+    fn decode(&mut self) -> Result<Vec<RowBatchWithPosition>> {
+        let data = std::mem::take(&mut self.data);
+        let map_err = |e: apache_avro::Error| {
+            ErrorCode::BadBytes(format!("fail to decode AVRO file {}: {e}", self.pos.path))
+        };
+        let reader = Reader::new(data.as_slice()).map_err(map_err)?;
+
+        let mut records = vec![];
+        for value in reader {
+            let value = value.map_err(map_err)?;
+            let record = avro_to_json(value).map_err(|e| {
+                ErrorCode::BadBytes(format!(
+                    "fail to decode AVRO file {} at record {}: {e}",
+                    self.pos.path,
+                    self.pos.rows + records.len()
+                ))
+            })?;
+            records.push(record);
+        }
+
+        // the size of each batch is estimated by the average record size.
+        let total_rows = records.len().max(1);
+        let mut batches = vec![];
+        let mut records = records.into_iter().peekable();
+        while records.peek().is_some() {
+            let batch = AvroRowBatch {
+                records: records.by_ref().take(RECORDS_PER_BATCH).collect(),
+                size: 0,
+            };
+            let size = data.len() * batch.rows() / total_rows;
+            let out_pos = self.pos.clone();
+            self.pos.rows += batch.rows();
+            self.pos.offset += size;
+            batches.push(RowBatchWithPosition::new(
+                RowBatch::Avro(AvroRowBatch { size, ..batch }),
+                out_pos,
+            ));
+        }
+        Ok(batches)
+    }
+}
+
+fn bytes_to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Convert an AVRO value to JSON, in the representation the JSON field decoder accepts:
+/// dates are days and timestamps are microseconds since epoch, bytes are hex strings.
+fn avro_to_json(value: AvroValue) -> std::result::Result<JsonValue, String> {
+    let json = match value {
+        AvroValue::Null => JsonValue::Null,
+        AvroValue::Boolean(v) => JsonValue::Bool(v),
+        AvroValue::Int(v) | AvroValue::Date(v) | AvroValue::TimeMillis(v) => JsonValue::from(v),
+        AvroValue::Long(v) | AvroValue::TimeMicros(v) | AvroValue::TimestampMicros(v) => {
+            JsonValue::from(v)
+        }
+        AvroValue::TimestampMillis(v) => JsonValue::from(v * 1000),
+        AvroValue::Float(v) => JsonValue::from(v as f64),
+        AvroValue::Double(v) => JsonValue::from(v),
+        AvroValue::String(v) | AvroValue::Enum(_, v) => JsonValue::String(v),
+        AvroValue::Bytes(v) | AvroValue::Fixed(_, v) => JsonValue::String(bytes_to_hex(&v)),
+        AvroValue::Uuid(v) => JsonValue::String(v.to_string()),
+        AvroValue::Union(_, v) => avro_to_json(*v)?,
+        AvroValue::Array(values) => JsonValue::Array(
+            values
+                .into_iter()
+                .map(avro_to_json)
+                .collect::<std::result::Result<_, _>>()?,
+        ),
+        AvroValue::Map(values) => JsonValue::Object(
+            values
+                .into_iter()
+                .map(|(k, v)| Ok((k, avro_to_json(v)?)))
+                .collect::<std::result::Result<_, String>>()?,
+        ),
+        AvroValue::Record(fields) => JsonValue::Object(
+            fields
+                .into_iter()
+                .map(|(k, v)| Ok((k, avro_to_json(v)?)))
+                .collect::<std::result::Result<_, String>>()?,
+        ),
+        other => return Err(format!("unsupported AVRO value {other:?}")),
+    };
+    Ok(json)
+}
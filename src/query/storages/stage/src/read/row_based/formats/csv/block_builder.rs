@@ -16,7 +16,6 @@ use std::sync::Arc;
 
 use databend_common_exception::Result;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
-use databend_common_expression::types::string::StringColumnBuilder;
 use databend_common_expression::Column;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
@@ -27,7 +26,9 @@ use databend_common_pipeline_sources::input_formats::error_utils::get_decode_err
 use databend_common_storage::FileParseError;
 
 use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::CSVRowBatch;
 use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::fill_unprojected_columns;
 use crate::read::row_based::format::RowDecoder;
 use crate::read::row_based::formats::csv::CsvInputFormat;
 use crate::read::row_based::processors::BlockBuilderState;
@@ -154,6 +155,44 @@ impl CsvDecoder {
         }
         Ok(())
     }
+
+    /// Decode the batch column by column, so only one column builder and its
+    /// field decoding path are hot at a time.
+    /// On the first bad field, the values pushed for the batch are rolled back and false is
+    /// returned, so the batch can be decoded row by row to apply the ON_ERROR handling.
+    fn read_columns(
+        &self,
+        data: &CSVRowBatch,
+        columns: &mut [ColumnBuilder],
+        num_rows: usize,
+    ) -> bool {
+        let num_fields = columns.len();
+        if data.num_fields.iter().any(|n| *n != num_fields) {
+            return false;
+        }
+        let mut ok = true;
+        'columns: for (c, column) in columns.iter_mut().enumerate() {
+            let mut row_start = 0;
+            for (i, row_end) in data.row_ends.iter().enumerate() {
+                let field_ends = &data.field_ends[i * num_fields..(i + 1) * num_fields];
+                let field_start = if c == 0 { 0 } else { field_ends[c - 1] };
+                let col_data = &data.data[row_start + field_start..row_start + field_ends[c]];
+                if self.read_column(column, col_data, c).is_err() {
+                    ok = false;
+                    break 'columns;
+                }
+                row_start = *row_end;
+            }
+        }
+        if !ok {
+            for column in columns.iter_mut() {
+                while column.len() > num_rows {
+                    column.pop();
+                }
+            }
+        }
+        ok
+    }
 }
 
 impl RowDecoder for CsvDecoder {
@@ -164,6 +203,14 @@ impl RowDecoder for CsvDecoder {
     ) -> Result<Vec<DataBlock>> {
         let data = batch.data.into_csv().unwrap();
         let columns = &mut state.mutable_columns;
+        if self.load_context.pos_projection.is_none()
+            && self.read_columns(&data, columns, state.num_rows)
+        {
+            let rows = data.rows();
+            state.num_rows += rows;
+            state.file_status.num_rows_loaded += rows;
+            return Ok(vec![]);
+        }
         let mut start = 0usize;
         let mut field_end_idx = 0;
         for (i, end) in data.row_ends.iter().enumerate() {
@@ -192,28 +239,6 @@ impl RowDecoder for CsvDecoder {
     }
 
     fn flush(&self, columns: Vec<Column>, num_rows: usize) -> Vec<Column> {
-        if let Some(projection) = &self.load_context.pos_projection {
-            let empty_strings = Column::String(
-                StringColumnBuilder {
-                    need_estimated: false,
-                    data: vec![],
-                    offsets: vec![0; num_rows + 1],
-                }
-                .build(),
-            );
-            columns
-                .into_iter()
-                .enumerate()
-                .map(|(i, c)| {
-                    if projection.contains(&i) {
-                        c
-                    } else {
-                        empty_strings.clone()
-                    }
-                })
-                .collect::<Vec<_>>()
-        } else {
-            columns
-        }
+        fill_unprojected_columns(&self.load_context, columns, num_rows)
     }
 }
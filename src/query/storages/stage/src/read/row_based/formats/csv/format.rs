@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::CsvFileFormatParams;
 
 use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::BytesBatch;
 use crate::read::row_based::format::RowBasedFileFormat;
 use crate::read::row_based::format::RowDecoder;
 use crate::read::row_based::format::SeparatorState;
@@ -33,9 +34,14 @@ impl RowBasedFileFormat for CsvInputFormat {
     fn try_create_separator(
         &self,
         load_ctx: Arc<LoadContext>,
-        path: &str,
+        first_batch: &BytesBatch,
     ) -> Result<Box<dyn SeparatorState>> {
-        Ok(Box::new(CsvReader::try_create(load_ctx, path, self)?))
+        Ok(Box::new(CsvReader::try_create(
+            load_ctx,
+            &first_batch.path,
+            first_batch.offset,
+            self,
+        )?))
     }
 
     fn try_create_decoder(&self, load_ctx: Arc<LoadContext>) -> Result<Arc<dyn RowDecoder>> {
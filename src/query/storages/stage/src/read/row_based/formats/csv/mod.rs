@@ -17,3 +17,4 @@ mod format;
 mod separator;
 
 pub use format::CsvInputFormat;
+pub use separator::csv_core_reader;
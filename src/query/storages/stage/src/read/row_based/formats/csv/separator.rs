@@ -19,6 +19,7 @@ use csv_core::ReadRecordResult;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_formats::RecordDelimiter;
+use databend_common_meta_app::principal::CsvFileFormatParams;
 use databend_common_storage::FileParseError;
 use databend_common_storage::FileStatus;
 use log::debug;
@@ -34,6 +35,23 @@ use crate::read::row_based::formats::csv::CsvInputFormat;
 
 pub const MAX_CSV_COLUMNS: usize = 1000;
 
+pub fn csv_core_reader(params: &CsvFileFormatParams) -> Result<csv_core::Reader> {
+    let escape = if params.escape.is_empty() {
+        None
+    } else {
+        Some(params.escape.as_bytes()[0])
+    };
+    Ok(csv_core::ReaderBuilder::new()
+        .delimiter(params.field_delimiter.as_bytes()[0])
+        .quote(params.quote.as_bytes()[0])
+        .escape(escape)
+        .terminator(match params.record_delimiter.as_str().try_into()? {
+            RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
+            RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
+        })
+        .build())
+}
+
 pub struct CsvReader {
     load_ctx: Arc<LoadContext>,
 
@@ -54,6 +72,8 @@ pub struct CsvReader {
 
     pos: Position,
     rows_to_skip: usize,
+    // rows of the last RowBatch, used to reserve the ends of the next one
+    last_batch_rows: usize,
 }
 
 impl SeparatorState for CsvReader {
@@ -72,22 +92,10 @@ impl CsvReader {
     pub fn try_create(
         load_ctx: Arc<LoadContext>,
         path: &str,
+        offset: usize,
         format: &CsvInputFormat,
     ) -> Result<Self> {
-        let escape = if format.params.escape.is_empty() {
-            None
-        } else {
-            Some(format.params.escape.as_bytes()[0])
-        };
-        let reader = csv_core::ReaderBuilder::new()
-            .delimiter(format.params.field_delimiter.as_bytes()[0])
-            .quote(format.params.quote.as_bytes()[0])
-            .escape(escape)
-            .terminator(match format.params.record_delimiter.as_str().try_into()? {
-                RecordDelimiter::Crlf => csv_core::Terminator::CRLF,
-                RecordDelimiter::Any(v) => csv_core::Terminator::Any(v),
-            })
-            .build();
+        let reader = csv_core_reader(&format.params)?;
         let projection = load_ctx.pos_projection.clone();
         let max_fields = match &projection {
             Some(p) => p.iter().copied().max().unwrap_or(1),
@@ -95,17 +103,28 @@ impl CsvReader {
         } + MAX_CSV_COLUMNS;
 
         let num_fields = load_ctx.schema.fields().len();
+        // only the split starting at the beginning of the file has the headers
+        let rows_to_skip = if offset == 0 {
+            format.params.headers as usize
+        } else {
+            0
+        };
         Ok(Self {
             load_ctx,
             projection,
             error_on_column_count_mismatch: format.params.error_on_column_count_mismatch,
             num_fields,
             reader,
-            pos: Position::new(path.to_string()),
-            rows_to_skip: format.params.headers as usize,
+            pos: Position {
+                path: path.to_string(),
+                rows: 0,
+                offset,
+            },
+            rows_to_skip,
             field_ends: vec![0; max_fields],
             last_partial_row: vec![],
             n_end: 0,
+            last_batch_rows: 0,
         })
     }
 
@@ -202,13 +221,21 @@ impl CsvReader {
         let mut buf_in = &batch.data[..];
         let size_in = buf_in.len();
         let mut file_status = FileStatus::default();
-        // the output of reader is always shorter than input
-        let mut buf_out = vec![0u8; buf_in.len()];
+        // The output of reader is always shorter than input.
+        // Decode right after the last partial row, so the rows of this batch
+        // are contiguous with it and need no extra copy.
+        let last_batch_remain_len = self.last_partial_row.len();
+        let mut buf_out = mem::take(&mut self.last_partial_row);
+        buf_out.resize(last_batch_remain_len + size_in, 0);
 
         // skip headers
         // be careful not passing empty input to reader, which indicates eof
         while self.rows_to_skip > 0 && !buf_in.is_empty() {
-            let (res, n_in) = self.read_record(buf_in, &mut buf_out, &mut file_status)?;
+            let (res, n_in) = self.read_record(
+                buf_in,
+                &mut buf_out[last_batch_remain_len..],
+                &mut file_status,
+            )?;
             buf_in = &buf_in[n_in..];
             if matches!(res, ReadRecordOutput::Record { .. }) {
                 self.rows_to_skip -= 1;
@@ -216,16 +243,17 @@ impl CsvReader {
         }
 
         if self.rows_to_skip > 0 {
+            buf_out.truncate(last_batch_remain_len);
+            self.last_partial_row = buf_out;
             return Ok((vec![], file_status));
         }
 
         // prepare for reading data
 
-        let mut buf_out_pos = 0usize;
+        let mut buf_out_pos = last_batch_remain_len;
         let mut buf_out_row_end: usize = 0;
-        let last_batch_remain_len = self.last_partial_row.len();
         let pos = Position::from_bytes_batch(&batch, self.pos.rows);
-        let mut row_batch = CSVRowBatch::default();
+        let mut row_batch = CSVRowBatch::with_capacity(self.last_batch_rows, self.num_fields);
 
         // read data
 
@@ -245,7 +273,7 @@ impl CsvReader {
                     row_batch
                         .field_ends
                         .extend_from_slice(&self.field_ends[..num_fields]);
-                    row_batch.row_ends.push(last_batch_remain_len + buf_out_pos);
+                    row_batch.row_ends.push(buf_out_pos);
                     buf_out_row_end = buf_out_pos;
                 }
                 ReadRecordOutput::PartialRecord { bytes } => {
@@ -261,36 +289,30 @@ impl CsvReader {
             }
         }
 
-        // try move data from (self.last_partial_row, buf_out) to (row_batch.data, self.last_partial_row)
+        // buf_out = [complete rows][partial row], keep the partial row for next batch
 
         buf_out.truncate(buf_out_pos);
         if row_batch.row_ends.is_empty() {
             debug!(
                 "csv aligner: {} + {} bytes => 0 rows",
-                self.last_partial_row.len(),
-                size_in,
+                last_batch_remain_len, size_in,
             );
-            self.last_partial_row.extend_from_slice(&buf_out);
+            self.last_partial_row = buf_out;
             Ok((vec![], file_status))
         } else {
-            let last_remain = mem::take(&mut self.last_partial_row);
-
             self.last_partial_row
                 .extend_from_slice(&buf_out[buf_out_row_end..]);
             debug!(
                 "csv aligner: {} + {} bytes => {} rows + {} bytes remain",
-                last_remain.len(),
+                last_batch_remain_len,
                 size_in,
                 row_batch.row_ends.len(),
                 self.last_partial_row.len()
             );
 
             buf_out.truncate(buf_out_row_end);
-            row_batch.data = if last_remain.is_empty() {
-                buf_out
-            } else {
-                [last_remain, buf_out].concat()
-            };
+            self.last_batch_rows = row_batch.row_ends.len();
+            row_batch.data = buf_out;
 
             Ok((
                 vec![RowBatchWithPosition::new(RowBatch::Csv(row_batch), pos)],
@@ -12,8 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod avro;
 mod csv;
 mod ndjson;
+mod tsv;
+mod xlsx;
 
+pub use avro::AvroInputFormat;
+pub use csv::csv_core_reader;
 pub use csv::CsvInputFormat;
 pub use ndjson::NdJsonInputFormat;
+pub use tsv::TsvInputFormat;
+pub use xlsx::infer_xlsx_schema;
+pub use xlsx::XlsxInputFormat;
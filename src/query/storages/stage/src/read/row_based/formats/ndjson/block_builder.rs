@@ -12,14 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use bstr::ByteSlice;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
 use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_meta_app::principal::ExtraField;
 use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::EXTRA_FIELDS_COLUMN;
 use databend_common_pipeline_sources::input_formats::error_utils::truncate_column_data;
 use databend_common_storage::FileParseError;
 
@@ -33,57 +38,136 @@ pub struct NdJsonDecoder {
     pub load_context: Arc<LoadContext>,
     pub fmt: NdJsonInputFormat,
     pub field_decoder: FieldJsonAstDecoder,
+    /// field name (lowercase if not case sensitive) => column index
+    field_indexes: HashMap<String, usize>,
+    /// the column to collect fields not in the schema, for `EXTRA_FIELD = VARIANT`
+    extra_fields_column: Option<usize>,
 }
 
 impl NdJsonDecoder {
-    pub fn create(fmt: NdJsonInputFormat, load_context: Arc<LoadContext>) -> Self {
+    pub fn try_create(fmt: NdJsonInputFormat, load_context: Arc<LoadContext>) -> Result<Self> {
         let field_decoder = FieldJsonAstDecoder::create(&load_context.file_format_options_ext);
-        Self {
+        let fields = load_context.schema.fields();
+
+        let extra_fields_column = match fmt.params.extra_field {
+            ExtraField::Variant if !field_decoder.is_select => {
+                let position = fields.iter().position(|f| {
+                    f.name() == EXTRA_FIELDS_COLUMN
+                        && f.data_type.remove_nullable() == TableDataType::Variant
+                });
+                if position.is_none() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "EXTRA_FIELD = VARIANT requires a column `{EXTRA_FIELDS_COLUMN}` of type VARIANT in the target table"
+                    )));
+                }
+                position
+            }
+            _ => None,
+        };
+
+        let field_indexes = fields
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| Some(*i) != extra_fields_column)
+            .map(|(i, f)| {
+                let name = if field_decoder.ident_case_sensitive {
+                    f.name().to_owned()
+                } else {
+                    f.name().to_lowercase()
+                };
+                (name, i)
+            })
+            .collect();
+
+        Ok(Self {
             load_context,
             fmt,
             field_decoder,
+            field_indexes,
+            extra_fields_column,
+        })
+    }
+
+    fn field_index(&self, key: &str) -> Option<usize> {
+        if self.field_decoder.ident_case_sensitive || !key.chars().any(|c| c.is_uppercase()) {
+            self.field_indexes.get(key).copied()
+        } else {
+            self.field_indexes.get(&key.to_lowercase()).copied()
         }
     }
+
+    /// Parse with simd-json first, which is much faster on wide rows.
+    /// It works in place, so the row is copied to `scratch`.
+    /// Fallback to serde_json to get a readable error message,
+    /// and in case simd-json rejects a row serde_json accepts.
+    fn parse_row(
+        buf: &[u8],
+        scratch: &mut Vec<u8>,
+    ) -> std::result::Result<serde_json::Value, FileParseError> {
+        scratch.clear();
+        scratch.extend_from_slice(buf);
+        match simd_json::serde::from_slice::<serde_json::Value>(scratch) {
+            Ok(json) => Ok(json),
+            Err(_) => serde_json::from_slice(buf).map_err(|e| FileParseError::InvalidNDJsonRow {
+                message: e.to_string(),
+            }),
+        }
+    }
+
     fn read_row(
         &self,
         buf: &[u8],
+        scratch: &mut Vec<u8>,
+        columns: &mut [ColumnBuilder],
+        null_if: &[&str],
+    ) -> std::result::Result<(), FileParseError> {
+        let json = Self::parse_row(buf, scratch)?;
+        self.read_value(&json, columns, null_if)
+    }
+
+    /// Push one row, which is expected to be an object, into the columns.
+    pub(crate) fn read_value(
+        &self,
+        json: &serde_json::Value,
         columns: &mut [ColumnBuilder],
         null_if: &[&str],
     ) -> std::result::Result<(), FileParseError> {
-        let mut json: serde_json::Value =
-            serde_json::from_reader(buf).map_err(|e| FileParseError::InvalidNDJsonRow {
-                message: e.to_string(),
-            })?;
         // todo: this is temporary
         if self.field_decoder.is_select {
             self.field_decoder
-                .read_field(&mut columns[0], &json)
+                .read_field(&mut columns[0], json)
                 .map_err(|e| FileParseError::InvalidNDJsonRow {
                     message: e.to_string(),
                 })?;
         } else {
-            // if it's not case_sensitive, we convert to lowercase
-            if !self.field_decoder.ident_case_sensitive {
-                if let serde_json::Value::Object(x) = json {
-                    let y = x.into_iter().map(|(k, v)| (k.to_lowercase(), v)).collect();
-                    json = serde_json::Value::Object(y);
+            // map the keys to columns in one pass, instead of a lookup per column
+            let mut values: Vec<Option<&serde_json::Value>> = vec![None; columns.len()];
+            let mut extra_fields = serde_json::Map::new();
+            if let serde_json::Value::Object(obj) = json {
+                for (key, value) in obj {
+                    match self.field_index(key) {
+                        Some(i) => values[i] = Some(value),
+                        None if self.extra_fields_column.is_some() => {
+                            extra_fields.insert(key.clone(), value.clone());
+                        }
+                        None => {}
+                    }
                 }
             }
+            let extra_fields = serde_json::Value::Object(extra_fields);
+            if let Some(i) = self.extra_fields_column {
+                values[i] = Some(&extra_fields);
+            }
 
-            for ((column_index, field), column) in self
+            for (((column_index, field), column), value) in self
                 .load_context
                 .schema
                 .fields()
                 .iter()
                 .enumerate()
                 .zip(columns.iter_mut())
+                .zip(values)
             {
-                let field_name = if self.field_decoder.ident_case_sensitive {
-                    field.name().to_owned()
-                } else {
-                    field.name().to_lowercase()
-                };
-                let value = json.get(field_name);
                 match value {
                     None => match self.fmt.params.missing_field_as {
                         NullAs::Error => {
@@ -168,6 +252,7 @@ impl RowDecoder for NdJsonDecoder {
         batch: RowBatchWithPosition,
     ) -> Result<Vec<DataBlock>> {
         let columns = &mut state.mutable_columns;
+        let mut scratch = Vec::new();
         let data = batch.data.into_nd_json().unwrap();
         let null_if = self
             .fmt
@@ -180,7 +265,7 @@ impl RowDecoder for NdJsonDecoder {
         for (row_id, row) in data.iter().enumerate() {
             let row = row.trim();
             if !row.is_empty() {
-                if let Err(e) = self.read_row(row, columns, &null_if) {
+                if let Err(e) = self.read_row(row, &mut scratch, columns, &null_if) {
                     self.load_context.error_handler.on_error(
                         e,
                         Some((columns, state.num_rows)),
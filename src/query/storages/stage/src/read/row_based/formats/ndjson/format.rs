@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 use databend_common_meta_app::principal::NdJsonFileFormatParams;
 
 use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::BytesBatch;
 use crate::read::row_based::format::RowBasedFileFormat;
 use crate::read::row_based::format::RowDecoder;
 use crate::read::row_based::format::SeparatorState;
@@ -33,15 +34,15 @@ impl RowBasedFileFormat for NdJsonInputFormat {
     fn try_create_separator(
         &self,
         _load_ctx: Arc<LoadContext>,
-        path: &str,
+        first_batch: &BytesBatch,
     ) -> Result<Box<dyn SeparatorState>> {
-        Ok(Box::new(NdJsonRowSeparator::try_create(path)?))
+        Ok(Box::new(NdJsonRowSeparator::try_create(&first_batch.path)?))
     }
 
     fn try_create_decoder(&self, load_ctx: Arc<LoadContext>) -> Result<Arc<dyn RowDecoder>> {
-        Ok(Arc::new(NdJsonDecoder::create(
+        Ok(Arc::new(NdJsonDecoder::try_create(
             self.clone(),
             load_ctx.clone(),
-        )))
+        )?))
     }
 }
@@ -16,4 +16,5 @@ mod block_builder;
 mod format;
 mod separator;
 
+pub(crate) use block_builder::NdJsonDecoder;
 pub use format::NdJsonInputFormat;
@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_formats::SeparatedTextDecoder;
+use databend_common_io::cursor_ext::BufferReadStringExt;
+use databend_common_pipeline_sources::input_formats::error_utils::get_decode_error_by_pos;
+use databend_common_storage::FileParseError;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::fill_unprojected_columns;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::formats::tsv::TsvInputFormat;
+use crate::read::row_based::processors::BlockBuilderState;
+
+pub struct TsvDecoder {
+    pub load_context: Arc<LoadContext>,
+    pub field_delimiter: u8,
+    pub record_delimiter: Vec<u8>,
+    pub field_decoder: SeparatedTextDecoder,
+}
+
+impl TsvDecoder {
+    pub fn create(fmt: TsvInputFormat, load_context: Arc<LoadContext>) -> Self {
+        let field_decoder =
+            SeparatedTextDecoder::create_tsv(&fmt.params, &load_context.file_format_options_ext);
+        Self {
+            field_delimiter: fmt.params.field_delimiter.as_bytes()[0],
+            record_delimiter: fmt.params.record_delimiter.as_bytes().to_vec(),
+            load_context,
+            field_decoder,
+        }
+    }
+
+    fn read_column(
+        &self,
+        builder: &mut ColumnBuilder,
+        col_data: &[u8],
+        column_index: usize,
+        unescaped: &mut Vec<u8>,
+    ) -> std::result::Result<(), FileParseError> {
+        let schema = &self.load_context.schema;
+        if col_data.is_empty() {
+            match &self.load_context.default_values {
+                None => {
+                    // query
+                    builder.push_default();
+                }
+                Some(values) => {
+                    // copy
+                    builder.push(values[column_index].as_ref());
+                }
+            }
+            return Ok(());
+        }
+        unescaped.clear();
+        let mut cursor = Cursor::new(col_data);
+        cursor
+            .read_escaped_string_text(unescaped)
+            .map_err(|e| get_decode_error_by_pos(column_index, schema, &e.to_string(), col_data))?;
+        self.field_decoder
+            .read_field(builder, unescaped)
+            .map_err(|e| get_decode_error_by_pos(column_index, schema, &e.message(), col_data))
+    }
+
+    fn read_row(
+        &self,
+        buf: &[u8],
+        columns: &mut [ColumnBuilder],
+        unescaped: &mut Vec<u8>,
+    ) -> std::result::Result<(), FileParseError> {
+        let projection = &self.load_context.pos_projection;
+        let num_columns = columns.len();
+        let buf_len = buf.len();
+        let mut column_index = 0;
+        let mut field_start = 0;
+        let mut field_end = 0;
+        let mut last_is_escape = false;
+        while field_end <= buf_len && column_index < num_columns {
+            if field_end == buf_len || (buf[field_end] == self.field_delimiter && !last_is_escape) {
+                if projection
+                    .as_ref()
+                    .map_or(true, |p| p.contains(&column_index))
+                {
+                    self.read_column(
+                        &mut columns[column_index],
+                        &buf[field_start..field_end],
+                        column_index,
+                        unescaped,
+                    )?;
+                }
+                column_index += 1;
+                field_start = field_end + 1;
+            }
+            if field_end < buf_len {
+                last_is_escape = buf[field_end] == b'\\' && !last_is_escape;
+            }
+            field_end += 1;
+        }
+
+        if let Some(projection) = projection {
+            // select $1, $2 .. from tsv, the missing fields are taken as empty
+            for c in projection {
+                if *c >= column_index {
+                    columns[*c].push_default();
+                }
+            }
+        } else if column_index < num_columns {
+            return Err(FileParseError::NumberOfColumnsMismatch {
+                table: num_columns,
+                file: column_index,
+            });
+        } else if field_end <= buf_len {
+            return Err(FileParseError::NumberOfColumnsMismatch {
+                table: num_columns,
+                file: num_columns + 1,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl RowDecoder for TsvDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let data = batch.data.into_tsv().unwrap();
+        let columns = &mut state.mutable_columns;
+        // reused by all the fields to unescape into
+        let mut unescaped = Vec::new();
+        for (i, row) in data.iter().enumerate() {
+            let buf = row
+                .strip_suffix(self.record_delimiter.as_slice())
+                .unwrap_or(row);
+            if let Err(e) = self.read_row(buf, columns, &mut unescaped) {
+                self.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    &batch.start_pos.path,
+                    i + batch.start_pos.rows,
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+
+    fn flush(&self, columns: Vec<Column>, num_rows: usize) -> Vec<Column> {
+        fill_unprojected_columns(&self.load_context, columns, num_rows)
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+
+use bstr::ByteSlice;
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::batch::TsvRowBatch;
+use crate::read::row_based::format::SeparatorState;
+use crate::read::row_based::formats::tsv::TsvInputFormat;
+
+pub struct TsvRowSeparator {
+    record_delimiter_end: u8,
+    // remain from last read batch
+    last_partial_row: Vec<u8>,
+    rows: usize,
+    rows_to_skip: usize,
+    // rows of the last RowBatch, used to reserve the ends of the next one
+    last_batch_rows: usize,
+}
+
+impl SeparatorState for TsvRowSeparator {
+    fn append(&mut self, batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        self.separate(batch)
+    }
+}
+
+impl TsvRowSeparator {
+    pub fn try_create(offset: usize, format: &TsvInputFormat) -> Result<Self> {
+        // only the split starting at the beginning of the file has the headers
+        let rows_to_skip = if offset == 0 {
+            format.params.headers as usize
+        } else {
+            0
+        };
+        Ok(Self {
+            record_delimiter_end: format.record_delimiter_end(),
+            last_partial_row: vec![],
+            rows: 0,
+            rows_to_skip,
+            last_batch_rows: 0,
+        })
+    }
+
+    fn separate(
+        &mut self,
+        mut batch: BytesBatch,
+    ) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        let data = mem::take(&mut batch.data);
+        let mut start = 0;
+
+        // skip headers, which may span batches
+        while self.rows_to_skip > 0 {
+            match data[start..].find_byte(self.record_delimiter_end) {
+                Some(i) => {
+                    start += i + 1;
+                    self.rows_to_skip -= 1;
+                }
+                None => return Ok((vec![], FileStatus::default())),
+            }
+        }
+
+        // the batch is moved into the RowBatch as is, only the row started in the last batch is copied
+        let mut rows = TsvRowBatch {
+            start,
+            row_ends: Vec::with_capacity(self.last_batch_rows),
+            ..Default::default()
+        };
+        let mut end = start;
+        while let Some(i) = data[end..].find_byte(self.record_delimiter_end) {
+            end += i + 1;
+            if self.last_partial_row.is_empty() {
+                rows.row_ends.push(end);
+            } else {
+                let mut row = mem::take(&mut self.last_partial_row);
+                row.extend_from_slice(&data[start..end]);
+                rows.tail_of_last_batch = Some(row);
+                rows.start = end;
+            }
+        }
+
+        if batch.is_eof {
+            // the last row may not end with the record delimiter
+            if self.last_partial_row.is_empty() {
+                if end < data.len() {
+                    rows.row_ends.push(data.len());
+                }
+            } else {
+                let mut row = mem::take(&mut self.last_partial_row);
+                row.extend_from_slice(&data[end..]);
+                rows.tail_of_last_batch = Some(row);
+            }
+        } else {
+            self.last_partial_row.extend_from_slice(&data[end..]);
+        }
+
+        let batches = if rows.rows() == 0 {
+            vec![]
+        } else {
+            rows.data = data;
+            let pos = Position::from_bytes_batch(&batch, self.rows);
+            self.rows += rows.rows();
+            self.last_batch_rows = rows.row_ends.len();
+            vec![RowBatchWithPosition::new(RowBatch::Tsv(rows), pos)]
+        };
+        Ok((batches, FileStatus::default()))
+    }
+}
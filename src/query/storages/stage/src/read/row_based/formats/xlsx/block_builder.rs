@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_formats::FieldJsonAstDecoder;
+use databend_common_pipeline_sources::input_formats::error_utils::truncate_column_data;
+use databend_common_storage::FileParseError;
+use serde_json::Value as JsonValue;
+
+use crate::read::load_context::LoadContext;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::format::RowDecoder;
+use crate::read::row_based::processors::BlockBuilderState;
+
+/// Decode the cells to the columns by position, like CSV.
+pub struct XlsxDecoder {
+    pub load_context: Arc<LoadContext>,
+    pub field_decoder: FieldJsonAstDecoder,
+}
+
+impl XlsxDecoder {
+    pub fn create(load_context: Arc<LoadContext>) -> Self {
+        let field_decoder = FieldJsonAstDecoder::create(&load_context.file_format_options_ext);
+        Self {
+            load_context,
+            field_decoder,
+        }
+    }
+
+    fn read_row(
+        &self,
+        row: &[JsonValue],
+        columns: &mut [ColumnBuilder],
+    ) -> std::result::Result<(), FileParseError> {
+        for ((column_index, field), column) in self
+            .load_context
+            .schema
+            .fields()
+            .iter()
+            .enumerate()
+            .zip(columns.iter_mut())
+        {
+            match row.get(column_index).unwrap_or(&JsonValue::Null) {
+                // empty cell
+                JsonValue::Null => {
+                    if field.is_nullable_or_null() {
+                        column.push_default();
+                    } else if let Some(values) = &self.load_context.default_values {
+                        column.push(values[column_index].as_ref());
+                    } else {
+                        column.push_default();
+                    }
+                }
+                value => {
+                    // numbers and booleans are loaded as their text into string columns
+                    let text;
+                    let value = if !value.is_string()
+                        && field.data_type.remove_nullable() == TableDataType::String
+                    {
+                        text = JsonValue::String(value.to_string());
+                        &text
+                    } else {
+                        value
+                    };
+                    self.field_decoder.read_field(column, value).map_err(|e| {
+                        FileParseError::ColumnDecodeError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                            decode_error: e.to_string(),
+                            column_data: truncate_column_data(value.to_string()),
+                        }
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl RowDecoder for XlsxDecoder {
+    fn add(
+        &self,
+        state: &mut BlockBuilderState,
+        batch: RowBatchWithPosition,
+    ) -> Result<Vec<DataBlock>> {
+        let columns = &mut state.mutable_columns;
+        let data = batch.data.into_xlsx().unwrap();
+
+        for (row_id, row) in data.rows.iter().enumerate() {
+            if let Err(e) = self.read_row(row, columns) {
+                self.load_context.error_handler.on_error(
+                    e,
+                    Some((columns, state.num_rows)),
+                    &mut state.file_status,
+                    &batch.start_pos.path,
+                    batch.start_pos.rows + row_id,
+                )?
+            } else {
+                state.num_rows += 1;
+                state.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_storage::FileStatus;
+
+use crate::read::row_based::batch::BytesBatch;
+use crate::read::row_based::batch::Position;
+use crate::read::row_based::batch::RowBatch;
+use crate::read::row_based::batch::RowBatchWithPosition;
+use crate::read::row_based::batch::XlsxRowBatch;
+use crate::read::row_based::format::SeparatorState;
+use crate::read::row_based::formats::xlsx::sheet::Sheet;
+
+const ROWS_PER_BATCH: usize = 8192;
+
+/// A XLSX file is a zip archive, so it is buffered until the end of the file,
+/// and then the sheet is read as a whole.
+pub struct XlsxRowSeparator {
+    sheet_name: String,
+    headers: usize,
+    data: Vec<u8>,
+    pos: Position,
+}
+
+impl SeparatorState for XlsxRowSeparator {
+    fn append(&mut self, mut batch: BytesBatch) -> Result<(Vec<RowBatchWithPosition>, FileStatus)> {
+        if self.data.is_empty() {
+            self.data = std::mem::take(&mut batch.data);
+        } else {
+            self.data.extend_from_slice(&batch.data);
+        }
+        if !batch.is_eof {
+            return Ok((vec![], FileStatus::default()));
+        }
+        let batches = self.read_sheet()?;
+        Ok((batches, FileStatus::default()))
+    }
+}
+
+impl XlsxRowSeparator {
+    pub fn try_create(path: &str, sheet_name: &str, headers: u64) -> Result<Self> {
+        Ok(Self {
+            sheet_name: sheet_name.to_string(),
+            headers: headers as usize,
+            data: vec![],
+            pos: Position::new(path.to_string()),
+        })
+    }
+
+    fn read_sheet(&mut self) -> Result<Vec<RowBatchWithPosition>> {
+        let data = std::mem::take(&mut self.data);
+        let file_size = data.len();
+        let sheet = Sheet::read(data, &self.sheet_name, &self.pos.path)?;
+        let total_rows = sheet.range.height().max(1);
+        // row numbers in errors are the ones in the sheet
+        self.pos.rows = sheet.first_row + self.headers;
+
+        let mut batches = vec![];
+        let mut rows = sheet.json_rows(self.headers).peekable();
+        while rows.peek().is_some() {
+            let batch_rows = rows.by_ref().take(ROWS_PER_BATCH).collect::<Vec<_>>();
+            let size = file_size * batch_rows.len() / total_rows;
+            let out_pos = self.pos.clone();
+            self.pos.rows += batch_rows.len();
+            self.pos.offset += size;
+            batches.push(RowBatchWithPosition::new(
+                RowBatch::Xlsx(XlsxRowBatch {
+                    rows: batch_rows,
+                    size,
+                }),
+                out_pos,
+            ));
+        }
+        Ok(batches)
+    }
+}
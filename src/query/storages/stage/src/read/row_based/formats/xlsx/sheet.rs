@@ -0,0 +1,204 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Cursor;
+
+use calamine::Data;
+use calamine::Range;
+use calamine::Reader;
+use calamine::Xlsx;
+use chrono::Duration;
+use chrono::NaiveDate;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_meta_app::principal::XlsxFileFormatParams;
+use serde_json::Value as JsonValue;
+
+/// Days from 1899-12-30, the day 0 of Excel, to 1970-01-01.
+const EXCEL_UNIX_EPOCH_DAYS: f64 = 25569.0;
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+/// Larger floats may not be integers exactly.
+const MAX_EXACT_INTEGER: f64 = (1i64 << 53) as f64;
+
+/// A sheet, with the leading empty columns kept, so `$1` is always the column `A`.
+pub struct Sheet {
+    /// the row number of the first row
+    pub first_row: usize,
+    pub first_column: usize,
+    pub range: Range<Data>,
+}
+
+impl Sheet {
+    pub fn read(data: Vec<u8>, sheet_name: &str, path: &str) -> Result<Self> {
+        let map_err = |e: calamine::XlsxError| {
+            ErrorCode::BadBytes(format!("fail to read XLSX file {path}: {e}"))
+        };
+        let mut workbook = Xlsx::new(Cursor::new(data)).map_err(map_err)?;
+        let sheet_name =
+            if sheet_name.is_empty() {
+                workbook.sheet_names().first().cloned().ok_or_else(|| {
+                    ErrorCode::BadBytes(format!("XLSX file {path} contains no sheet"))
+                })?
+            } else {
+                sheet_name.to_string()
+            };
+        let range = workbook.worksheet_range(&sheet_name).map_err(map_err)?;
+        let (first_row, first_column) = range.start().unwrap_or((0, 0));
+        Ok(Self {
+            first_row: first_row as usize,
+            first_column: first_column as usize,
+            range,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.first_column + self.range.width()
+    }
+
+    pub fn json_rows(&self, skip: usize) -> impl Iterator<Item = Vec<JsonValue>> + '_ {
+        self.range.rows().skip(skip).map(|row| {
+            std::iter::repeat(JsonValue::Null)
+                .take(self.first_column)
+                .chain(row.iter().map(cell_to_json))
+                .collect()
+        })
+    }
+}
+
+fn float_as_integer(v: f64) -> Option<i64> {
+    if v.fract() == 0.0 && v.abs() < MAX_EXACT_INTEGER {
+        Some(v as i64)
+    } else {
+        None
+    }
+}
+
+/// Convert a cell to JSON, in the representation the JSON field decoder accepts.
+fn cell_to_json(cell: &Data) -> JsonValue {
+    match cell {
+        Data::Empty => JsonValue::Null,
+        Data::Bool(v) => JsonValue::Bool(*v),
+        Data::Int(v) => JsonValue::from(*v),
+        // numbers are stored as floats by Excel
+        Data::Float(v) => match float_as_integer(*v) {
+            Some(i) => JsonValue::from(i),
+            None => JsonValue::from(*v),
+        },
+        Data::String(v) | Data::DateTimeIso(v) | Data::DurationIso(v) => {
+            JsonValue::String(v.clone())
+        }
+        Data::DateTime(v) if v.is_duration() => JsonValue::from(v.as_f64()),
+        Data::DateTime(v) => datetime_to_json(v.as_f64()),
+        Data::Error(e) => JsonValue::String(e.to_string()),
+    }
+}
+
+fn datetime_to_json(serial: f64) -> JsonValue {
+    let micros = ((serial - EXCEL_UNIX_EPOCH_DAYS) * MICROS_PER_DAY as f64).round() as i64;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    match epoch.checked_add_signed(Duration::microseconds(micros)) {
+        Some(dt) if micros % MICROS_PER_DAY == 0 => {
+            JsonValue::String(dt.format("%Y-%m-%d").to_string())
+        }
+        Some(dt) => JsonValue::String(dt.format("%Y-%m-%d %H:%M:%S%.6f").to_string()),
+        None => JsonValue::from(serial),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CellType {
+    Empty,
+    Boolean,
+    Int,
+    Float,
+    Timestamp,
+    String,
+}
+
+impl CellType {
+    fn of(cell: &Data) -> Self {
+        match cell {
+            Data::Empty => CellType::Empty,
+            Data::Bool(_) => CellType::Boolean,
+            Data::Int(_) => CellType::Int,
+            Data::Float(v) if float_as_integer(*v).is_some() => CellType::Int,
+            Data::Float(_) => CellType::Float,
+            Data::DateTime(v) if v.is_duration() => CellType::Float,
+            Data::DateTime(_) => CellType::Timestamp,
+            _ => CellType::String,
+        }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (a, b) if a == b => a,
+            (CellType::Empty, t) | (t, CellType::Empty) => t,
+            (CellType::Int, CellType::Float) | (CellType::Float, CellType::Int) => CellType::Float,
+            _ => CellType::String,
+        }
+    }
+
+    fn data_type(self) -> TableDataType {
+        let t = match self {
+            CellType::Boolean => TableDataType::Boolean,
+            CellType::Int => TableDataType::Number(NumberDataType::Int64),
+            CellType::Float => TableDataType::Number(NumberDataType::Float64),
+            CellType::Timestamp => TableDataType::Timestamp,
+            CellType::Empty | CellType::String => TableDataType::String,
+        };
+        TableDataType::Nullable(Box::new(t))
+    }
+}
+
+/// Infer the schema of a sheet: the column names are taken from the last header row,
+/// or `c1`, `c2`, ... without header, and the types are the common type of the cells.
+pub fn infer_xlsx_schema(
+    data: Vec<u8>,
+    params: &XlsxFileFormatParams,
+    path: &str,
+) -> Result<TableSchema> {
+    let sheet = Sheet::read(data, &params.sheet_name, path)?;
+    let width = sheet.width();
+    let mut names = (1..=width).map(|i| format!("c{i}")).collect::<Vec<_>>();
+    let mut types = vec![CellType::Empty; width];
+
+    let headers = params.headers as usize;
+    for (row_index, row) in sheet.range.rows().enumerate() {
+        for (i, cell) in row.iter().enumerate() {
+            let i = sheet.first_column + i;
+            if row_index + 1 == headers {
+                let name = cell.to_string();
+                if !name.is_empty() {
+                    names[i] = name;
+                }
+            } else if row_index >= headers {
+                types[i] = types[i].merge(CellType::of(cell));
+            }
+        }
+    }
+
+    let fields = names
+        .iter()
+        .zip(types)
+        .map(|(name, t)| TableField::new(name, t.data_type()))
+        .collect();
+    Ok(TableSchema::new(fields))
+}
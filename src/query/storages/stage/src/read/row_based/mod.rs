@@ -17,5 +17,8 @@ mod format;
 mod formats;
 mod processors;
 mod read_pipeline;
+mod split;
 
+pub use formats::infer_xlsx_schema;
 pub use read_pipeline::RowBasedReadPipelineBuilder;
+pub use split::split_text_file;
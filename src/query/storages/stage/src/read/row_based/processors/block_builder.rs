@@ -62,8 +62,8 @@ impl BlockBuilderState {
     }
 
     fn take_columns(&mut self, on_finish: bool) -> Result<Vec<Column>> {
-        // todo(youngsofun): calculate the capacity according to last batch
-        let capacity = if on_finish { 0 } else { 1024 };
+        // the next block is likely to be as large as this one
+        let capacity = if on_finish { 0 } else { self.num_rows };
         self.num_rows = 0;
         self.file_name = "".to_string();
         Ok(self
@@ -34,6 +34,7 @@ use crate::read::row_based::batch::BytesBatch;
 struct FileState {
     file: OneFilePartition,
     offset: usize,
+    end: usize,
     reader: opendal::Reader,
 }
 pub struct BytesReader {
@@ -62,7 +63,7 @@ impl BytesReader {
 
     pub async fn read_batch(&mut self) -> Result<DataBlock> {
         if let Some(state) = &mut self.file_state {
-            let end = min(self.read_batch_size + state.offset, state.file.size);
+            let end = min(self.read_batch_size + state.offset, state.end);
             let mut buffer = vec![0u8; end - state.offset];
             let n = read_full(&mut state.reader, &mut buffer[..]).await?;
             if n == 0 {
@@ -81,7 +82,7 @@ impl BytesReader {
             debug!("read {} bytes", n);
             let offset = state.offset;
             state.offset += n;
-            let is_eof = state.offset == state.file.size;
+            let is_eof = state.offset == state.end;
             let batch = Box::new(BytesBatch {
                 data: buffer,
                 path: state.file.path.clone(),
@@ -113,17 +114,23 @@ impl PrefetchAsyncSource for BytesReader {
     #[async_trait::unboxed_simple]
     async fn generate(&mut self) -> Result<Option<DataBlock>> {
         if self.file_state.is_none() {
-            let part = match self.table_ctx.get_partition() {
+            let part = match self.table_ctx.fetch_partition().await? {
                 Some(part) => part,
                 None => return Ok(None),
             };
             let file = OneFilePartition::from_part(&part)?.clone();
 
-            let reader = self.op.reader_with(&file.path).await?;
+            let range = file.read_range();
+            let reader = self
+                .op
+                .reader_with(&file.path)
+                .range(range.start as u64..range.end as u64)
+                .await?;
             self.file_state = Some(FileState {
                 file,
                 reader,
-                offset: 0,
+                offset: range.start,
+                end: range.end,
             })
         }
         match self.read_batch().await {
@@ -52,7 +52,7 @@ impl AccumulatingTransform for Separator {
 
         let state = self.state.get_or_insert_with(|| {
             self.format
-                .try_create_separator(self.ctx.clone(), &batch.path)
+                .try_create_separator(self.ctx.clone(), &batch)
                 .unwrap()
         });
         let mut process_values = ProgressValues { rows: 0, bytes: 0 };
@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::min;
+use std::ops::Range;
+
+use bstr::ByteSlice;
+use csv_core::ReadRecordResult;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::CsvFileFormatParams;
+use databend_common_meta_app::principal::FileFormatParams;
+use log::debug;
+use opendal::Operator;
+
+use crate::read::row_based::formats::csv_core_reader;
+
+/// Bytes read at each split point to look for the start of a record.
+const PROBE_SIZE: usize = 64 * 1024;
+
+/// Split an uncompressed CSV or TSV file into ranges of about `split_size` bytes,
+/// each starting at a record, so that the ranges can be loaded by different threads.
+///
+/// The split points are aligned by probing a small window after them. A split point is skipped
+/// (merged into the previous range) if no record start can be found for sure.
+pub async fn split_text_file(
+    op: &Operator,
+    path: &str,
+    size: usize,
+    split_size: usize,
+    params: &FileFormatParams,
+) -> Result<Vec<Range<usize>>> {
+    if split_size == 0 || size <= split_size {
+        return Ok(vec![0..size]);
+    }
+    let mut probe = match params {
+        FileFormatParams::Tsv(p) => {
+            let record_delimiter = p.record_delimiter.as_bytes();
+            RecordStartProbe::Tsv {
+                record_delimiter_end: record_delimiter[record_delimiter.len() - 1],
+            }
+        }
+        FileFormatParams::Csv(p) => RecordStartProbe::Csv(CsvRecordStartProbe::try_create(p)?),
+        _ => return Ok(vec![0..size]),
+    };
+
+    let mut ranges = vec![];
+    let mut start = 0;
+    let mut split_point = split_size;
+    while split_point < size {
+        let end = min(split_point + PROBE_SIZE, size);
+        let window = op
+            .read_with(path)
+            .range(split_point as u64..end as u64)
+            .await?;
+        match probe.find_record_start(&window) {
+            Some(i) if split_point + i > start && split_point + i < size => {
+                ranges.push(start..split_point + i);
+                start = split_point + i;
+            }
+            _ => {
+                debug!(
+                    "no record start found in {} bytes after {} of {}, skip the split point",
+                    window.len(),
+                    split_point,
+                    path
+                );
+            }
+        }
+        split_point += split_size;
+    }
+    ranges.push(start..size);
+    Ok(ranges)
+}
+
+enum RecordStartProbe {
+    /// The record delimiter of TSV is always escaped in fields, so any one ends a record.
+    Tsv { record_delimiter_end: u8 },
+    /// The record delimiter of CSV may be quoted in fields.
+    Csv(CsvRecordStartProbe),
+}
+
+impl RecordStartProbe {
+    /// Find the first position in `window` where a record starts.
+    fn find_record_start(&mut self, window: &[u8]) -> Option<usize> {
+        match self {
+            RecordStartProbe::Tsv {
+                record_delimiter_end,
+            } => window.find_byte(*record_delimiter_end).map(|i| i + 1),
+            RecordStartProbe::Csv(probe) => probe.find_record_start(window),
+        }
+    }
+}
+
+/// The split point may be at the start of a field, in an unquoted field or in a quoted field.
+/// The window is parsed from each of the states, and the first record end found by all of them
+/// is a record start no matter which state is the real one.
+///
+/// A state is ruled out if no record ends in the window from it, e.g. when the window has
+/// no quote, it is not in a quoted field, unless the field is longer than the window.
+struct CsvRecordStartProbe {
+    reader: csv_core::Reader,
+    // the bytes to feed before the window to get into the states
+    state_prefixes: [Vec<u8>; 3],
+    // the parsed fields are dropped
+    output: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl CsvRecordStartProbe {
+    fn try_create(params: &CsvFileFormatParams) -> Result<Self> {
+        let reader = csv_core_reader(params)?;
+        let quote = params.quote.as_bytes()[0];
+        // any byte with no special meaning starts an unquoted field
+        let special = [
+            params.field_delimiter.as_bytes()[0],
+            quote,
+            params.escape.as_bytes().first().copied().unwrap_or(quote),
+        ];
+        let unquoted = b"abcd"
+            .iter()
+            .copied()
+            .find(|b| !special.contains(b))
+            .unwrap_or(b'a');
+        Ok(Self {
+            reader,
+            state_prefixes: [vec![], vec![unquoted], vec![quote]],
+            output: vec![0; PROBE_SIZE],
+            ends: vec![0; 1024],
+        })
+    }
+
+    fn find_record_start(&mut self, window: &[u8]) -> Option<usize> {
+        let mut candidates: Option<Vec<usize>> = None;
+        for prefix in self.state_prefixes.clone() {
+            let record_ends = self.record_ends(&prefix, window);
+            if record_ends.is_empty() {
+                continue;
+            }
+            candidates = Some(match candidates {
+                None => record_ends,
+                Some(c) => c
+                    .into_iter()
+                    .filter(|e| record_ends.binary_search(e).is_ok())
+                    .collect(),
+            });
+        }
+        candidates.and_then(|c| c.first().copied())
+    }
+
+    /// Ends of the records completed in `window`, after feeding `prefix` to a new reader.
+    fn record_ends(&mut self, prefix: &[u8], window: &[u8]) -> Vec<usize> {
+        self.reader.reset();
+        let mut record_ends = vec![];
+        let mut input = prefix;
+        while !input.is_empty() {
+            let (_, n_in, _, _) = self
+                .reader
+                .read_record(input, &mut self.output, &mut self.ends);
+            input = &input[n_in..];
+        }
+
+        // never feed empty input, which flushes the last record as if the file ends
+        let mut pos = 0;
+        while pos < window.len() {
+            let (res, n_in, _, _) =
+                self.reader
+                    .read_record(&window[pos..], &mut self.output, &mut self.ends);
+            pos += n_in;
+            match res {
+                ReadRecordResult::Record => record_ends.push(pos),
+                ReadRecordResult::InputEmpty | ReadRecordResult::End => break,
+                // the fields are not needed, go on with the same buffers
+                ReadRecordResult::OutputFull | ReadRecordResult::OutputEndsFull => {}
+            }
+        }
+        record_ends
+    }
+}
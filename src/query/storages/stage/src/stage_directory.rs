@@ -0,0 +1,96 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use databend_common_base::base::GlobalInstance;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
+use log::info;
+
+use crate::StageTable;
+
+/// The file listings of the stages with directory table enabled.
+///
+/// A listing is taken when the directory of a stage is read for the first time, and it's
+/// kept until `ALTER STAGE ... REFRESH`. The listings are kept in the memory of each node.
+pub struct StageDirectory {
+    listings: DashMap<String, Arc<Vec<StageFileInfo>>>,
+}
+
+impl StageDirectory {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(StageDirectory {
+            listings: DashMap::new(),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<StageDirectory> {
+        GlobalInstance::get()
+    }
+
+    fn listing_key(tenant: &Tenant, stage_name: &str) -> String {
+        format!("{}/{}", tenant.tenant_name(), stage_name)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn list(
+        &self,
+        tenant: &Tenant,
+        stage_info: &StageInfo,
+        thread_num: usize,
+    ) -> Result<Arc<Vec<StageFileInfo>>> {
+        let key = Self::listing_key(tenant, &stage_info.stage_name);
+        if let Some(listing) = self.listings.get(&key) {
+            return Ok(listing.clone());
+        }
+
+        self.refresh(tenant, stage_info, thread_num).await
+    }
+
+    #[async_backtrace::framed]
+    pub async fn refresh(
+        &self,
+        tenant: &Tenant,
+        stage_info: &StageInfo,
+        thread_num: usize,
+    ) -> Result<Arc<Vec<StageFileInfo>>> {
+        let op = StageTable::get_op(stage_info)?;
+        let files_info = StageFilesInfo {
+            path: "/".to_string(),
+            files: None,
+            pattern: None,
+        };
+
+        let listing = Arc::new(files_info.list(&op, thread_num, false, None).await?);
+        info!(
+            "Refreshed the directory of stage {}, {} files",
+            stage_info.stage_name,
+            listing.len()
+        );
+
+        let key = Self::listing_key(tenant, &stage_info.stage_name);
+        self.listings.insert(key, listing.clone());
+        Ok(listing)
+    }
+
+    pub fn remove(&self, tenant: &Tenant, stage_name: &str) {
+        self.listings.remove(&Self::listing_key(tenant, stage_name));
+    }
+}
@@ -15,12 +15,14 @@
 use std::any::Any;
 use std::sync::Arc;
 
+use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::plan::DataSourceInfo;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::plan::StageTableInfo;
 use databend_common_catalog::table::AppendMode;
@@ -29,15 +31,19 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::principal::FileFormatParams;
+use databend_common_meta_app::principal::OnErrorMode;
+use databend_common_meta_app::principal::StageFileCompression;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
 use databend_common_storage::init_stage_operator;
 use databend_common_storage::StageFileInfo;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use opendal::Operator;
 
 use crate::one_file_partition::OneFilePartition;
+use crate::read::row_based::split_text_file;
 use crate::read::row_based::RowBasedReadPipelineBuilder;
 
 /// TODO: we need to track the data metrics in stage table.
@@ -73,6 +79,42 @@ impl StageTable {
         init_stage_operator(stage)
     }
 
+    /// Whether the files are taken by `TableContext::fetch_partition` while reading,
+    /// instead of being fixed when the pipeline is built.
+    pub fn fetch_partitions_while_reading(settings: &Settings, stage: &StageInfo) -> Result<bool> {
+        Self::use_row_based_pipeline(settings, stage)
+    }
+
+    /// Whether the files are read by the row based pipeline, AVRO and XLSX are only supported by it.
+    fn use_row_based_pipeline(settings: &Settings, stage: &StageInfo) -> Result<bool> {
+        Ok(match stage.file_format_params {
+            FileFormatParams::Avro(_) | FileFormatParams::Xlsx(_) => true,
+            FileFormatParams::Csv(_) | FileFormatParams::Tsv(_) | FileFormatParams::NdJson(_) => {
+                settings.get_enable_new_copy_for_text_formats()? == 1
+            }
+            _ => false,
+        })
+    }
+
+    /// The size to split large files at, 0 if the files are read as a whole.
+    ///
+    /// Only uncompressed CSV and TSV files are split, and not with ON_ERROR = CONTINUE or SKIP_FILE,
+    /// which report errors per file.
+    fn text_file_split_size(settings: &Settings, stage: &StageInfo) -> Result<usize> {
+        let params = &stage.file_format_params;
+        if !matches!(params, FileFormatParams::Csv(_) | FileFormatParams::Tsv(_))
+            || !matches!(params.compression(), StageFileCompression::None)
+            || !matches!(stage.copy_options.on_error, OnErrorMode::AbortNum(_))
+        {
+            return Ok(0);
+        }
+        if stage.copy_options.split_size > 0 {
+            Ok(stage.copy_options.split_size)
+        } else {
+            Ok(settings.get_text_file_split_size()? as usize)
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn list_files(
         stage_info: &StageTableInfo,
@@ -105,19 +147,60 @@ impl StageTable {
             partitions_total: files.len(),
             is_exact: false,
             pruning_stats: Default::default(),
+            read_compressed_bytes: 0,
+            read_uncompressed_bytes: 0,
         };
 
-        let partitions = files
-            .into_iter()
-            .map(|v| {
+        // split large files at record boundaries, so that a file is loaded by several threads
+        let mut file_ranges = files
+            .iter()
+            .map(|f| vec![0..f.size as usize])
+            .collect::<Vec<_>>();
+        let split_size =
+            Self::text_file_split_size(&ctx.get_settings(), &stage_table_info.stage_info)?;
+        if split_size > 0 && files.iter().any(|f| f.size as usize > split_size) {
+            let op = Self::get_op(&stage_table_info.stage_info)?;
+            let params = stage_table_info.stage_info.file_format_params.clone();
+            let tasks = files
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| f.size as usize > split_size)
+                .map(|(i, f)| {
+                    let op = op.clone();
+                    let params = params.clone();
+                    let path = f.path.clone();
+                    let size = f.size as usize;
+                    async move {
+                        let ranges = split_text_file(&op, &path, size, split_size, &params).await?;
+                        Ok::<_, ErrorCode>((i, ranges))
+                    }
+                });
+            let results = execute_futures_in_parallel(
+                tasks,
+                thread_num,
+                thread_num * 2,
+                "split-text-file-worker".to_owned(),
+            )
+            .await?;
+            for res in results {
+                let (i, ranges) = res?;
+                file_ranges[i] = ranges;
+            }
+        }
+
+        let mut partitions = Vec::with_capacity(files.len());
+        for (file, ranges) in files.into_iter().zip(file_ranges) {
+            let is_split = ranges.len() > 1;
+            for range in ranges {
                 let part = OneFilePartition {
-                    path: v.path.clone(),
-                    size: v.size as usize,
+                    path: file.path.clone(),
+                    size: file.size as usize,
+                    range: is_split.then_some(range),
                 };
                 let part_info: Box<dyn PartInfo> = Box::new(part);
-                Arc::new(part_info)
-            })
-            .collect::<Vec<_>>();
+                partitions.push(Arc::new(part_info));
+            }
+        }
 
         Ok((
             statistics,
@@ -154,9 +237,7 @@ impl Table for StageTable {
             FileFormatParams::Parquet(_) => {
                 ParquetTableForCopy::do_read_partitions(stage_table_info, ctx, _push_downs).await
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_)
-                if settings.get_enable_new_copy_for_text_formats()? == 1 =>
-            {
+            _ if Self::use_row_based_pipeline(&settings, &stage_table_info.stage_info)? => {
                 self.read_partitions_simple(ctx, stage_table_info).await
             }
             _ => self.read_partition_old(&ctx).await,
@@ -167,6 +248,12 @@ impl Table for StageTable {
         false
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        // The files are loaded as a whole, `SELECT FROM @stage` of parquet files is
+        // read by the parquet table.
+        PushDownCapabilities::NONE
+    }
+
     fn read_data(
         &self,
         ctx: Arc<dyn TableContext>,
@@ -185,9 +272,7 @@ impl Table for StageTable {
             FileFormatParams::Parquet(_) => {
                 ParquetTableForCopy::do_read_data(ctx, plan, pipeline, _put_cache)
             }
-            FileFormatParams::Csv(_) | FileFormatParams::NdJson(_)
-                if settings.get_enable_new_copy_for_text_formats()? == 1 =>
-            {
+            _ if Self::use_row_based_pipeline(&settings, &stage_table_info.stage_info)? => {
                 let compact_threshold = ctx.get_read_block_thresholds();
                 RowBasedReadPipelineBuilder {
                     stage_table_info,
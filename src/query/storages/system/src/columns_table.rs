@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -46,6 +47,10 @@ pub struct ColumnsTable {
 #[async_trait::async_trait]
 impl AsyncSystemTable for ColumnsTable {
     const NAME: &'static str = "system.columns";
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities {
+        filter: true,
+        ..PushDownCapabilities::NONE
+    };
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
@@ -66,23 +66,36 @@ impl AsyncSystemTable for FunctionsTable {
         );
         scalar_func_names.sort();
         let aggregate_function_factory = AggregateFunctionFactory::instance();
-        let aggr_func_names = aggregate_function_factory.registered_names();
-
-        let names: Vec<&str> = scalar_func_names
-            .iter()
-            .chain(&aggr_func_names)
-            .map(|x| x.as_str())
-            .collect();
-
-        let is_aggregate = (0..names.len())
-            .map(|i| i >= scalar_func_names.len())
-            .collect::<Vec<bool>>();
-
-        let descriptions = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
-
-        let syntaxes = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
-
-        let examples = (0..names.len()).map(|_| "").collect::<Vec<&str>>();
+        let mut aggr_funcs = aggregate_function_factory
+            .registered_names()
+            .into_iter()
+            .zip(aggregate_function_factory.registered_features())
+            .collect::<Vec<_>>();
+        aggr_funcs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let num_funcs = scalar_func_names.len() + aggr_funcs.len();
+        let mut names = Vec::with_capacity(num_funcs);
+        let mut is_aggregate = Vec::with_capacity(num_funcs);
+        let mut descriptions = Vec::with_capacity(num_funcs);
+        let mut syntaxes = Vec::with_capacity(num_funcs);
+        let mut examples = Vec::with_capacity(num_funcs);
+
+        for name in &scalar_func_names {
+            let doc = BUILTIN_FUNCTIONS.get_doc(name).unwrap_or_default();
+            names.push(name.as_str());
+            is_aggregate.push(false);
+            descriptions.push(doc.description);
+            // The typed signatures of the overloads, one per line.
+            syntaxes.push(BUILTIN_FUNCTIONS.get_signatures(name).join("\n"));
+            examples.push(doc.example);
+        }
+        for (name, features) in &aggr_funcs {
+            names.push(name.as_str());
+            is_aggregate.push(true);
+            descriptions.push(features.description);
+            syntaxes.push(features.definition.to_string());
+            examples.push(features.example);
+        }
 
         Ok(DataBlock::new_from_columns(vec![
             StringType::from_data(names),
@@ -45,14 +45,19 @@ mod notification_history_table;
 mod notifications_table;
 mod one_table;
 mod password_policies_table;
+mod plan_cache_table;
+mod procedures_table;
 mod processes_table;
 mod processor_profile_table;
 mod queries_queue;
 mod query_cache_table;
 mod query_log_table;
+mod quotas_table;
 mod roles_table;
 mod settings_table;
+mod slow_queries_table;
 mod stages_table;
+mod storage_usage_history_table;
 mod streams_table;
 mod table;
 mod table_functions_table;
@@ -64,6 +69,7 @@ mod tracing_table;
 mod user_functions_table;
 mod users_table;
 mod util;
+mod view_dependencies_table;
 mod virtual_columns_table;
 
 pub use background_jobs_table::BackgroundJobTable;
@@ -96,6 +102,8 @@ pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use plan_cache_table::PlanCacheTable;
+pub use procedures_table::ProceduresTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
 pub use queries_queue::QueriesQueueTable;
@@ -104,9 +112,17 @@ pub use query_log_table::LogType;
 pub use query_log_table::QueryLogElement;
 pub use query_log_table::QueryLogQueue;
 pub use query_log_table::QueryLogTable;
+pub use quotas_table::QuotasTable;
 pub use roles_table::RolesTable;
 pub use settings_table::SettingsTable;
+pub use slow_queries_table::SlowQueriesTable;
+pub use slow_queries_table::SlowQueryLogElement;
+pub use slow_queries_table::SlowQueryLogQueue;
 pub use stages_table::StagesTable;
+pub use storage_usage_history_table::storage_usage_history_prefix;
+pub use storage_usage_history_table::write_storage_usage_history;
+pub use storage_usage_history_table::StorageUsageHistoryTable;
+pub use storage_usage_history_table::StorageUsageRecord;
 pub use streams_table::StreamsTable;
 pub use table::SyncOneBlockSystemTable;
 pub use table::SyncSystemTable;
@@ -124,4 +140,5 @@ pub use temp_files_table::TempFilesTable;
 pub use tracing_table::TracingTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
+pub use view_dependencies_table::ViewDependenciesTable;
 pub use virtual_columns_table::VirtualColumnsTable;
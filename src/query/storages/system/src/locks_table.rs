@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -47,6 +48,10 @@ pub struct LocksTable {
 #[async_trait::async_trait]
 impl AsyncSystemTable for LocksTable {
     const NAME: &'static str = "system.locks";
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities {
+        filter: true,
+        ..PushDownCapabilities::NONE
+    };
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
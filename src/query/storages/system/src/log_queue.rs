@@ -22,6 +22,7 @@ use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -158,6 +159,10 @@ impl<Event: SystemLogElement + 'static> Table for SystemLogTable<Event> {
         &self.table_info
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        PushDownCapabilities::NONE
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
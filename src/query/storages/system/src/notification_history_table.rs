@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -82,6 +83,11 @@ pub struct NotificationHistoryTable {
 #[async_trait::async_trait]
 impl AsyncSystemTable for NotificationHistoryTable {
     const NAME: &'static str = "system.notification_history";
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities {
+        filter: true,
+        limit: true,
+        ..PushDownCapabilities::NONE
+    };
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
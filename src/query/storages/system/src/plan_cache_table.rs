@@ -0,0 +1,113 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_sql::PlanCacheManager;
+use itertools::Itertools;
+
+use crate::SyncOneBlockSystemTable;
+use crate::SyncSystemTable;
+
+pub struct PlanCacheTable {
+    table_info: TableInfo,
+}
+
+impl SyncSystemTable for PlanCacheTable {
+    const NAME: &'static str = "system.plan_cache";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let items = PlanCacheManager::instance()
+            .items()
+            .into_iter()
+            .filter(|item| item.key.tenant == tenant.tenant_name())
+            .collect::<Vec<_>>();
+
+        let mut sql = Vec::with_capacity(items.len());
+        let mut user = Vec::with_capacity(items.len());
+        let mut catalog = Vec::with_capacity(items.len());
+        let mut database = Vec::with_capacity(items.len());
+        let mut tables = Vec::with_capacity(items.len());
+        let mut hits = Vec::with_capacity(items.len());
+        for item in items {
+            sql.push(item.key.sql);
+            user.push(item.key.user);
+            catalog.push(item.key.catalog);
+            database.push(item.key.database);
+            tables.push(
+                item.tables
+                    .iter()
+                    .map(|table| format!("{}.{}.{}", table.catalog, table.database, table.name))
+                    .unique()
+                    .join(", "),
+            );
+            hits.push(item.hits);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(sql),
+            StringType::from_data(user),
+            StringType::from_data(catalog),
+            StringType::from_data(database),
+            StringType::from_data(tables),
+            UInt64Type::from_data(hits),
+        ]))
+    }
+}
+
+impl PlanCacheTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("sql", TableDataType::String),
+            TableField::new("user", TableDataType::String),
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("tables", TableDataType::String),
+            TableField::new("hits", TableDataType::Number(NumberDataType::UInt64)),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'plan_cache'".to_string(),
+            name: "plan_cache".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPlanCache".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        SyncOneBlockSystemTable::create(PlanCacheTable { table_info })
+    }
+}
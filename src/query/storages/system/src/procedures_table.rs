@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct ProceduresTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ProceduresTable {
+    const NAME: &'static str = "system.procedures";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let procedures = UserApiProvider::instance().get_procedures(&tenant).await?;
+
+        let mut names = Vec::with_capacity(procedures.len());
+        let mut arguments = Vec::with_capacity(procedures.len());
+        let mut return_types = Vec::with_capacity(procedures.len());
+        let mut execute_as = Vec::with_capacity(procedures.len());
+        let mut owners = Vec::with_capacity(procedures.len());
+        let mut comments = Vec::with_capacity(procedures.len());
+        let mut created_on_columns = Vec::with_capacity(procedures.len());
+        let mut updated_on_columns = Vec::with_capacity(procedures.len());
+        for procedure in procedures {
+            let args = procedure
+                .arg_names
+                .iter()
+                .zip(procedure.arg_types.iter())
+                .map(|(name, ty)| format!("{name} {ty}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            arguments.push(args);
+            names.push(procedure.name);
+            return_types.push(procedure.return_type);
+            execute_as.push(procedure.execute_as.to_string());
+            owners.push(procedure.owner.to_string());
+            comments.push(procedure.comment);
+            created_on_columns.push(procedure.created_on.timestamp_micros());
+            updated_on_columns.push(procedure.updated_on.map(|u| u.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            StringType::from_data(arguments),
+            StringType::from_data(return_types),
+            StringType::from_data(execute_as),
+            StringType::from_data(owners),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_opt_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl ProceduresTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("arguments", TableDataType::String),
+            TableField::new("return_type", TableDataType::String),
+            TableField::new("execute_as", TableDataType::String),
+            TableField::new("owner", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'procedures'".to_string(),
+            name: "procedures".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemProcedures".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(ProceduresTable { table_info })
+    }
+}
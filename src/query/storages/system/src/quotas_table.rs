@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_types::MatchSeq;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Lists the quotas of the current tenant, one row per quota.
+///
+/// `value` 0 means no limit. `used` is NULL for the quotas that limit each object,
+/// such as the tables of a database.
+pub struct QuotasTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for QuotasTable {
+    const NAME: &'static str = "system.quotas";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let user_api = UserApiProvider::instance();
+
+        let quota = user_api
+            .tenant_quota_api(&tenant)
+            .get_quota(MatchSeq::GE(0))
+            .await?
+            .data;
+
+        let databases = ctx
+            .get_default_catalog()?
+            .list_databases(&tenant)
+            .await?
+            .len() as u64;
+        let stages = user_api.get_stages(&tenant).await?.len() as u64;
+        let users = user_api.get_users(&tenant).await?.len() as u64;
+
+        let rows: Vec<(&str, u64, Option<u64>)> = vec![
+            ("max_databases", quota.max_databases as u64, Some(databases)),
+            (
+                "max_tables_per_database",
+                quota.max_tables_per_database as u64,
+                None,
+            ),
+            ("max_stages", quota.max_stages as u64, Some(stages)),
+            (
+                "max_files_per_stage",
+                quota.max_files_per_stage as u64,
+                None,
+            ),
+            ("max_users", quota.max_users as u64, Some(users)),
+            ("max_copy_file_size", quota.max_copy_file_size, None),
+        ];
+
+        let mut names = Vec::with_capacity(rows.len());
+        let mut values = Vec::with_capacity(rows.len());
+        let mut used = Vec::with_capacity(rows.len());
+        for (name, value, u) in rows {
+            names.push(name.to_string());
+            values.push(value);
+            used.push(u);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(values),
+            UInt64Type::from_opt_data(used),
+        ]))
+    }
+}
+
+impl QuotasTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new("value", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "used",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'quotas'".to_string(),
+            name: "quotas".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemQuotas".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(QuotasTable { table_info })
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::SystemLogElement;
+use crate::SystemLogQueue;
+use crate::SystemLogTable;
+
+/// A query that ran longer than the `long_query_time` setting.
+#[derive(Clone)]
+pub struct SlowQueryLogElement {
+    pub query_id: String,
+    pub event_time: i64,
+    pub query_start_time: i64,
+    pub query_duration_ms: i64,
+    pub sql_user: String,
+    pub current_database: String,
+    pub query_text: String,
+    /// The settings changed by the session, formatted as `name=value` pairs.
+    pub settings: String,
+    /// The optimized physical plan.
+    pub query_plan: String,
+    /// The per-operator profiles in JSON.
+    pub profiles: String,
+    /// The physical plan annotated with the profiles like `EXPLAIN ANALYZE`, it's only
+    /// captured for the queries sampled by `slow_query_explain_analyze_sample_rate`.
+    pub explain_analyze: String,
+    pub exception_code: i32,
+    pub exception_text: String,
+}
+
+impl SystemLogElement for SlowQueryLogElement {
+    const TABLE_NAME: &'static str = "slow_queries";
+
+    fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("query_id", TableDataType::String),
+            TableField::new("event_time", TableDataType::Timestamp),
+            TableField::new("query_start_time", TableDataType::Timestamp),
+            TableField::new(
+                "query_duration_ms",
+                TableDataType::Number(NumberDataType::Int64),
+            ),
+            TableField::new("sql_user", TableDataType::String),
+            TableField::new("current_database", TableDataType::String),
+            TableField::new("query_text", TableDataType::String),
+            TableField::new("settings", TableDataType::String),
+            TableField::new("query_plan", TableDataType::String),
+            TableField::new("profiles", TableDataType::String),
+            TableField::new("explain_analyze", TableDataType::String),
+            TableField::new(
+                "exception_code",
+                TableDataType::Number(NumberDataType::Int32),
+            ),
+            TableField::new("exception_text", TableDataType::String),
+        ])
+    }
+
+    fn fill_to_data_block(&self, columns: &mut Vec<ColumnBuilder>) -> Result<()> {
+        let mut columns = columns.iter_mut();
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_id.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.event_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Timestamp(self.query_start_time).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int64(self.query_duration_ms)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.sql_user.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.current_database.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_text.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.settings.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.query_plan.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.profiles.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.explain_analyze.clone()).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::Number(NumberScalar::Int32(self.exception_code)).as_ref());
+        columns
+            .next()
+            .unwrap()
+            .push(Scalar::String(self.exception_text.clone()).as_ref());
+        Ok(())
+    }
+}
+
+pub type SlowQueryLogQueue = SystemLogQueue<SlowQueryLogElement>;
+pub type SlowQueriesTable = SystemLogTable<SlowQueryLogElement>;
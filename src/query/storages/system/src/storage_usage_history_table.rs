@@ -0,0 +1,167 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storage::DataOperator;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The storage usage of a table at a point of time.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StorageUsageRecord {
+    pub collect_time: i64,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub engine: String,
+    pub row_count: u64,
+    /// The uncompressed size of the data.
+    pub logical_bytes: u64,
+    /// The size of the data and index files of the current snapshot.
+    pub physical_bytes: u64,
+    /// The size of the data and index files kept for time travel, not referenced by the
+    /// current snapshot.
+    pub time_travel_bytes: u64,
+}
+
+/// The directory of the storage usage history of a tenant, a collection is stored in
+/// `<collect_time>.json` under it.
+pub fn storage_usage_history_prefix(tenant: &str) -> String {
+    format!("_storage_usage/{}", tenant)
+}
+
+/// Persists the storage usage of the tables collected at `collect_time`.
+#[async_backtrace::framed]
+pub async fn write_storage_usage_history(
+    tenant: &str,
+    collect_time: i64,
+    records: &[StorageUsageRecord],
+) -> Result<()> {
+    let operator = DataOperator::instance().operator();
+    let path = format!(
+        "{}/{}.json",
+        storage_usage_history_prefix(tenant),
+        collect_time
+    );
+    operator.write(&path, serde_json::to_vec(records)?).await?;
+    Ok(())
+}
+
+pub struct StorageUsageHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for StorageUsageHistoryTable {
+    const NAME: &'static str = "system.storage_usage_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let operator = DataOperator::instance().operator();
+
+        let mut records = vec![];
+        let location_prefix = format!("{}/", storage_usage_history_prefix(tenant.tenant_name()));
+        if let Ok(mut lister) = operator.lister(&location_prefix).await {
+            while let Some(entry) = lister.try_next().await? {
+                if !entry.path().ends_with(".json") {
+                    continue;
+                }
+                let bytes = operator.read(entry.path()).await?;
+                records.extend(serde_json::from_slice::<Vec<StorageUsageRecord>>(&bytes)?);
+            }
+        }
+        records.sort_by_key(|r| r.collect_time);
+
+        Ok(DataBlock::new_from_columns(vec![
+            TimestampType::from_data(records.iter().map(|r| r.collect_time).collect()),
+            StringType::from_data(records.iter().map(|r| r.catalog.clone()).collect()),
+            StringType::from_data(records.iter().map(|r| r.database.clone()).collect()),
+            StringType::from_data(records.iter().map(|r| r.table.clone()).collect()),
+            StringType::from_data(records.iter().map(|r| r.engine.clone()).collect()),
+            UInt64Type::from_data(records.iter().map(|r| r.row_count).collect()),
+            UInt64Type::from_data(records.iter().map(|r| r.logical_bytes).collect()),
+            UInt64Type::from_data(records.iter().map(|r| r.physical_bytes).collect()),
+            UInt64Type::from_data(records.iter().map(|r| r.time_travel_bytes).collect()),
+        ]))
+    }
+}
+
+impl StorageUsageHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("collect_time", TableDataType::Timestamp),
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("engine", TableDataType::String),
+            TableField::new("row_count", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "logical_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "physical_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "time_travel_bytes",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'storage_usage_history'".to_string(),
+            name: "storage_usage_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemStorageUsageHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
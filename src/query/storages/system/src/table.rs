@@ -20,6 +20,7 @@ use databend_common_catalog::plan::PartInfo;
 use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -117,6 +118,11 @@ impl<TTable: 'static + SyncSystemTable> Table for SyncOneBlockSystemTable<TTable
         self.inner_table.get_table_info()
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        // The full data is generated without the push downs.
+        PushDownCapabilities::NONE
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -198,6 +204,8 @@ impl<TTable: 'static + SyncSystemTable> SyncSource for SystemTableSyncSource<TTa
 pub trait AsyncSystemTable: Send + Sync {
     const NAME: &'static str;
     const IS_LOCAL: bool = true;
+    /// The push downs evaluated by `get_full_data`.
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities::NONE;
 
     fn get_table_info(&self) -> &TableInfo;
     async fn get_full_data(
@@ -257,6 +265,10 @@ impl<TTable: 'static + AsyncSystemTable> Table for AsyncOneBlockSystemTable<TTab
         self.inner_table.get_table_info()
     }
 
+    fn push_down_capabilities(&self) -> PushDownCapabilities {
+        TTable::PUSH_DOWN_CAPABILITIES
+    }
+
     #[async_backtrace::framed]
     async fn read_partitions(
         &self,
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -101,6 +102,10 @@ impl<const T: bool, const U: bool> AsyncSystemTable for TablesTable<T, U>
 where TablesTable<T, U>: HistoryAware
 {
     const NAME: &'static str = Self::TABLE_NAME;
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities {
+        filter: true,
+        ..PushDownCapabilities::NONE
+    };
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use databend_common_catalog::plan::PushDownCapabilities;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -50,6 +51,10 @@ pub struct TempFilesTable {
 #[async_trait::async_trait]
 impl AsyncSystemTable for TempFilesTable {
     const NAME: &'static str = "system.temp_files";
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities {
+        limit: true,
+        ..PushDownCapabilities::NONE
+    };
 
     fn get_table_info(&self) -> &TableInfo {
         &self.table_info
@@ -67,11 +72,13 @@ impl AsyncSystemTable for TempFilesTable {
         let mut temp_files_name: Vec<String> = vec![];
         let mut temp_files_content_length = vec![];
         let mut temp_files_last_modified = vec![];
+        let mut temp_files_query_id = vec![];
 
         let location_prefix = format!("{}/", query_spill_prefix(tenant.tenant_name()));
         if let Ok(lister) = operator
             .lister_with(&location_prefix)
             .metakey(Metakey::LastModified | Metakey::ContentLength)
+            .recursive(true)
             .await
         {
             let limit = push_downs.and_then(|x| x.limit).unwrap_or(usize::MAX);
@@ -86,6 +93,8 @@ impl AsyncSystemTable for TempFilesTable {
                     temp_files_last_modified
                         .push(metadata.last_modified().map(|x| x.timestamp_micros()));
                     temp_files_content_length.push(metadata.content_length());
+                    temp_files_query_id
+                        .push(spill_query_id(&location_prefix, entry.path()).to_string());
                 }
             }
         }
@@ -109,6 +118,10 @@ impl AsyncSystemTable for TempFilesTable {
                     DataType::Timestamp.wrap_nullable(),
                     Value::Column(TimestampType::from_opt_data(temp_files_last_modified)),
                 ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Column(StringType::from_data(temp_files_query_id)),
+                ),
             ],
             num_rows,
         );
@@ -130,6 +143,7 @@ impl TempFilesTable {
                 "file_last_modified_time",
                 TableDataType::Timestamp.wrap_nullable(),
             ),
+            TableField::new("query_id", TableDataType::String),
         ]);
 
         let table_info = TableInfo {
@@ -148,3 +162,14 @@ impl TempFilesTable {
         AsyncOneBlockSystemTable::create(Self { table_info })
     }
 }
+
+/// The spill files of a query are located at `<cluster_id>/<query_id>/<node_id>/<file>`
+/// under the spill prefix of the tenant.
+fn spill_query_id<'a>(location_prefix: &str, path: &'a str) -> &'a str {
+    let relative_path = path.strip_prefix(location_prefix).unwrap_or_default();
+    let parts = relative_path.split('/').collect::<Vec<_>>();
+    match parts.len() {
+        4 => parts[1],
+        _ => "",
+    }
+}
@@ -57,6 +57,12 @@ fn encode_arguments(udf_definition: &UDFDefinition) -> jsonb::Value {
             "return_type": &x.return_type.to_string(),
         }))
             .into(),
+        UDFDefinition::UDFModel(x) => (&json!({
+            "arg_types": &x.arg_types.clone().into_iter().map(|dt| dt.to_string()).collect::<Vec<String>>(),
+            "return_type": &x.return_type.to_string(),
+            "location": &format!("@{}", x.location),
+        }))
+            .into(),
     }
 }
 
@@ -104,6 +110,7 @@ impl AsyncSystemTable for UserFunctionsTable {
                     UDFDefinition::LambdaUDF(_) => "SQL",
                     UDFDefinition::UDFServer(x) => &x.language,
                     UDFDefinition::UDFScript(x) => &x.language,
+                    UDFDefinition::UDFModel(_) => "ONNX",
                 })
             })
             .collect();
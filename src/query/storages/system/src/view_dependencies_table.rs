@@ -0,0 +1,196 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::catalog::Catalog;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::PushDownCapabilities;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use log::warn;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+use crate::util::find_eq_filter;
+
+/// Lists the tables referenced by each view, one row per view and referenced table.
+pub struct ViewDependenciesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ViewDependenciesTable {
+    const NAME: &'static str = "system.view_dependencies";
+    const PUSH_DOWN_CAPABILITIES: PushDownCapabilities = PushDownCapabilities {
+        filter: true,
+        ..PushDownCapabilities::NONE
+    };
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+
+        let catalog_mgr = CatalogManager::instance();
+        let ctls: Vec<(String, Arc<dyn Catalog>)> = catalog_mgr
+            .list_catalogs(&tenant, ctx.txn_mgr())
+            .await?
+            .iter()
+            .map(|e| (e.name(), e.clone()))
+            .collect();
+
+        let mut catalogs = vec![];
+        let mut databases = vec![];
+        let mut names = vec![];
+        let mut referenced_catalogs = vec![];
+        let mut referenced_databases = vec![];
+        let mut referenced_tables = vec![];
+
+        let visibility_checker = ctx.get_visibility_checker().await?;
+
+        for (ctl_name, ctl) in ctls.into_iter() {
+            let mut dbs = Vec::new();
+            if let Some(push_downs) = &push_downs {
+                let mut db_name: Vec<String> = Vec::new();
+                if let Some(filter) = push_downs.filters.as_ref().map(|f| &f.filter) {
+                    let expr = filter.as_expr(&BUILTIN_FUNCTIONS);
+                    find_eq_filter(&expr, &mut |col_name, scalar| {
+                        if col_name == "database" {
+                            if let Scalar::String(database) = scalar {
+                                if !db_name.contains(database) {
+                                    db_name.push(database.clone());
+                                }
+                            }
+                        }
+                    });
+                    for db in db_name {
+                        if let Ok(database) = ctl.get_database(&tenant, db.as_str()).await {
+                            dbs.push(database);
+                        }
+                    }
+                }
+            }
+
+            if dbs.is_empty() {
+                dbs = ctl.list_databases(&tenant).await?;
+            }
+
+            let final_dbs = dbs
+                .into_iter()
+                .filter(|db| {
+                    visibility_checker.check_database_visibility(
+                        &ctl_name,
+                        db.name(),
+                        db.get_db_info().ident.db_id,
+                    )
+                })
+                .collect::<Vec<_>>();
+            for db in final_dbs {
+                let tables = match ctl.list_tables(&tenant, db.name()).await {
+                    Ok(tables) => tables,
+                    Err(err) => {
+                        if db.get_db_info().meta.from_share.is_some() {
+                            warn!("list tables failed on sharing db {}: {}", db.name(), err);
+                            continue;
+                        }
+                        return Err(err);
+                    }
+                };
+
+                let db_id = db.get_db_info().ident.db_id;
+                for table in tables {
+                    if table.engine() != VIEW_ENGINE
+                        || !visibility_checker.check_table_visibility(
+                            &ctl_name,
+                            db.name(),
+                            table.name(),
+                            db_id,
+                            table.get_id(),
+                        )
+                    {
+                        continue;
+                    }
+                    for dependency in &table.get_table_info().meta.view_dependencies {
+                        catalogs.push(ctl_name.clone());
+                        databases.push(db.name().to_string());
+                        names.push(table.name().to_string());
+                        referenced_catalogs.push(dependency.catalog.clone());
+                        referenced_databases.push(dependency.database.clone());
+                        referenced_tables.push(dependency.table.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(catalogs),
+            StringType::from_data(databases),
+            StringType::from_data(names),
+            StringType::from_data(referenced_catalogs),
+            StringType::from_data(referenced_databases),
+            StringType::from_data(referenced_tables),
+        ]))
+    }
+}
+
+impl ViewDependenciesTable {
+    pub fn schema() -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("catalog", TableDataType::String),
+            TableField::new("database", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("referenced_catalog", TableDataType::String),
+            TableField::new("referenced_database", TableDataType::String),
+            TableField::new("referenced_table", TableDataType::String),
+        ])
+    }
+
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let table_info = TableInfo {
+            desc: "'system'.'view_dependencies'".to_string(),
+            name: "view_dependencies".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema: ViewDependenciesTable::schema(),
+                engine: "SystemViewDependencies".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(ViewDependenciesTable { table_info })
+    }
+}
@@ -20,6 +20,8 @@ extern crate core;
 mod jwt;
 mod network_policy;
 mod password_policy;
+mod pipe;
+mod procedure;
 mod role_mgr;
 mod user;
 mod user_api;
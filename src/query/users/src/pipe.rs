@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Pipe operations.
+impl UserApiProvider {
+    // Add a new pipe.
+    #[async_backtrace::framed]
+    pub async fn add_pipe(
+        &self,
+        tenant: &Tenant,
+        pipe: PipeInfo,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.pipe_api(tenant);
+        client.add(pipe, create_option).await?;
+        Ok(())
+    }
+
+    // Update a pipe, return None if the pipe doesn't exist and `if_exists` is true.
+    #[async_backtrace::framed]
+    pub async fn update_pipe(
+        &self,
+        tenant: &Tenant,
+        name: &str,
+        if_exists: bool,
+        f: impl FnOnce(&mut PipeInfo),
+    ) -> Result<Option<PipeInfo>> {
+        let client = self.pipe_api(tenant);
+        let seq_pipe = match client.get(name, MatchSeq::GE(0)).await {
+            Ok(seq_pipe) => seq_pipe,
+            Err(CrudError::ApiError(meta_err)) => {
+                return Err(ErrorCode::from(meta_err).add_message_back(" (while alter pipe)"));
+            }
+            Err(CrudError::Business(unknown)) => {
+                if if_exists {
+                    return Ok(None);
+                }
+                return Err(ErrorCode::from(unknown).add_message_back(" (while alter pipe)"));
+            }
+        };
+
+        let seq = seq_pipe.seq;
+        let mut pipe = seq_pipe.data;
+        f(&mut pipe);
+
+        match client.update(pipe.clone(), MatchSeq::Exact(seq)).await {
+            Ok(_) => Ok(Some(pipe)),
+            Err(e) => Err(ErrorCode::from(e).add_message_back(" (while alter pipe).")),
+        }
+    }
+
+    // Drop a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn drop_pipe(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.pipe_api(tenant);
+        match client.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(CrudError::ApiError(meta_err)) => {
+                Err(ErrorCode::from(meta_err).add_message_back(" (while drop pipe)"))
+            }
+            Err(CrudError::Business(unknown)) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(ErrorCode::from(unknown).add_message_back(" (while drop pipe)"))
+                }
+            }
+        }
+    }
+
+    // Get a pipe by name.
+    #[async_backtrace::framed]
+    pub async fn get_pipe(&self, tenant: &Tenant, name: &str) -> Result<PipeInfo> {
+        let client = self.pipe_api(tenant);
+        let pipe = client.get(name, MatchSeq::GE(0)).await?.data;
+        Ok(pipe)
+    }
+
+    // Get all the pipes of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_pipes(&self, tenant: &Tenant) -> Result<Vec<PipeInfo>> {
+        let client = self.pipe_api(tenant);
+        let pipes = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get pipes).")
+        })?;
+        Ok(pipes)
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudError;
+use databend_common_meta_app::principal::ProcedureInfo;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+/// Stored procedure operations.
+impl UserApiProvider {
+    // Add a new stored procedure.
+    #[async_backtrace::framed]
+    pub async fn add_procedure(
+        &self,
+        tenant: &Tenant,
+        procedure: ProcedureInfo,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        let client = self.procedure_api(tenant);
+        client.add(procedure, create_option).await?;
+        Ok(())
+    }
+
+    // Drop a stored procedure by name.
+    #[async_backtrace::framed]
+    pub async fn drop_procedure(&self, tenant: &Tenant, name: &str, if_exists: bool) -> Result<()> {
+        let client = self.procedure_api(tenant);
+        match client.remove(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(CrudError::ApiError(meta_err)) => {
+                Err(ErrorCode::from(meta_err).add_message_back(" (while drop procedure)"))
+            }
+            Err(CrudError::Business(unknown)) => {
+                if if_exists {
+                    Ok(())
+                } else {
+                    Err(ErrorCode::from(unknown).add_message_back(" (while drop procedure)"))
+                }
+            }
+        }
+    }
+
+    // Get a stored procedure by name.
+    #[async_backtrace::framed]
+    pub async fn get_procedure(&self, tenant: &Tenant, name: &str) -> Result<ProcedureInfo> {
+        let client = self.procedure_api(tenant);
+        let procedure = client.get(name, MatchSeq::GE(0)).await?.data;
+        Ok(procedure)
+    }
+
+    // Get all the stored procedures of the tenant.
+    #[async_backtrace::framed]
+    pub async fn get_procedures(&self, tenant: &Tenant) -> Result<Vec<ProcedureInfo>> {
+        let client = self.procedure_api(tenant);
+        let procedures = client.list().await.map_err(|e| {
+            let e = ErrorCode::from(e);
+            e.add_message_back(" (while get procedures).")
+        })?;
+        Ok(procedures)
+    }
+}
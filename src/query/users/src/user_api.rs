@@ -23,6 +23,8 @@ use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PipeMgr;
+use databend_common_management::ProcedureMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -155,6 +157,14 @@ impl UserApiProvider {
         PasswordPolicyMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn pipe_api(&self, tenant: &Tenant) -> PipeMgr {
+        PipeMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn procedure_api(&self, tenant: &Tenant) -> ProcedureMgr {
+        ProcedureMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
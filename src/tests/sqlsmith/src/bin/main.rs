@@ -45,6 +45,10 @@ pub struct Args {
     /// The number of timeout seconds of one query.
     #[clap(long, default_value = "5")]
     timeout: u64,
+
+    /// The seed of the random generator, used to reproduce the failed cases.
+    #[clap(long)]
+    seed: Option<u64>,
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 5)]
@@ -57,6 +61,6 @@ async fn main() {
         "databend://{}:{}@{}:{}/{}?sslmode=disable",
         args.user, args.pass, args.host, args.port, args.db
     );
-    let runner = Runner::new(dsn, args.count, None, args.timeout);
+    let runner = Runner::new(dsn, args.count, args.seed, args.timeout);
     runner.run().await;
 }
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use databend_client::error::Error as ClientError;
+use databend_common_ast::ast::BinaryOperator;
+use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::SetExpr;
 use databend_driver::Connection;
@@ -45,14 +47,23 @@ pub(crate) async fn try_reduce_query(
     if let SetExpr::Select(select_stmt) = query.body.clone() {
         let mut select_stmt = select_stmt.clone();
         let mut reduced_query = query.clone();
-        // TODO: reduce expr
-        if select_stmt.selection.is_some() {
+        if let Some(selection) = select_stmt.selection.clone() {
             let mut reduced_select_stmt = select_stmt.clone();
             reduced_select_stmt.selection = None;
             reduced_query.body = SetExpr::Select(reduced_select_stmt.clone());
             if execute_reduce_query(conn.clone(), code, &reduced_query).await {
                 select_stmt = reduced_select_stmt;
+            } else {
+                // the selection is needed, try to reduce the predicates in it
+                let mut selection = selection;
+                while let Some(reduced_selection) =
+                    reduce_predicates(conn.clone(), code, &reduced_query, &selection).await
+                {
+                    selection = reduced_selection;
+                }
+                select_stmt.selection = Some(selection);
             }
+            reduced_query.body = SetExpr::Select(select_stmt.clone());
         }
         if select_stmt.having.is_some() {
             let mut reduced_select_stmt = select_stmt.clone();
@@ -84,6 +95,34 @@ pub(crate) async fn try_reduce_query(
     query
 }
 
+// try to replace the `AND`/`OR` predicate with one of its sides,
+// returns the reduced predicate if the query still fails with the same error.
+async fn reduce_predicates(
+    conn: Box<dyn Connection>,
+    code: u16,
+    query: &Query,
+    selection: &Expr,
+) -> Option<Expr> {
+    if let Expr::BinaryOp {
+        op: BinaryOperator::And | BinaryOperator::Or,
+        left,
+        right,
+        ..
+    } = selection
+    {
+        for side in [left, right] {
+            let mut reduced_query = query.clone();
+            if let SetExpr::Select(select_stmt) = &mut reduced_query.body {
+                select_stmt.selection = Some(*side.clone());
+            }
+            if execute_reduce_query(conn.clone(), code, &reduced_query).await {
+                return Some(*side.clone());
+            }
+        }
+    }
+    None
+}
+
 async fn execute_reduce_query(conn: Box<dyn Connection>, code: u16, query: &Query) -> bool {
     let sql = query.to_string();
     if let Err(Error::Api(ClientError::InvalidResponse(err))) = conn.exec(&sql).await {
@@ -76,6 +76,9 @@ const KNOWN_ERRORS: &[&str] = &[
     "Having clause can't contain window functions",
 ];
 
+// error code of `ErrorCode::Internal`, which is always a bug
+const INTERNAL_ERROR_CODE: u16 = 1001;
+
 pub struct Runner {
     count: usize,
     seed: Option<u64>,
@@ -96,11 +99,20 @@ impl Runner {
     }
 
     fn generate_rng(seed: Option<u64>) -> impl Rng {
-        if let Some(seed) = seed {
-            SmallRng::seed_from_u64(seed)
-        } else {
-            SmallRng::from_entropy()
-        }
+        // always log the seed, so that the failed cases can be reproduced with `--seed`
+        let seed = seed.unwrap_or_else(|| SmallRng::from_entropy().gen());
+        tracing::info!("seed: {}", seed);
+        SmallRng::seed_from_u64(seed)
+    }
+
+    // check whether the server is still alive after a query failed,
+    // a crashed server can not respond to any query.
+    #[allow(clippy::borrowed_box)]
+    async fn check_alive(&self, conn: &Box<dyn Connection>) -> bool {
+        let ping = async { conn.exec("SELECT 1").await.is_ok() };
+        tokio::time::timeout(Duration::from_secs(self.timeout), ping)
+            .await
+            .unwrap_or(false)
     }
 
     #[allow(clippy::borrowed_box)]
@@ -120,7 +132,7 @@ impl Runner {
 
             let table_name = create_table_stmt.table.name.clone();
             let mut fields = Vec::new();
-            if let CreateTableSource::Columns(columns) = create_table_stmt.source.unwrap() {
+            if let CreateTableSource::Columns(columns, _) = create_table_stmt.source.unwrap() {
                 for column in columns {
                     let data_type = resolve_type_name(&column.data_type, true).unwrap();
                     let field = TableField::new(&column.name.name, data_type);
@@ -259,6 +271,7 @@ impl Runner {
         }
 
         // generate query
+        let mut failed = 0;
         for _ in 0..self.count {
             let query = generator.gen_query();
             let query_sql = query.to_string();
@@ -294,11 +307,21 @@ impl Runner {
             )
             .await;
             if try_reduce {
+                failed += 1;
                 tracing::info!("query_sql: {}", query_sql);
+                if !self.check_alive(&conn).await {
+                    tracing::error!("server crashed, query_sql: {}", query_sql);
+                    tracing::error!(err);
+                    break;
+                }
+                if err_code == INTERNAL_ERROR_CODE {
+                    tracing::error!("internal error, query_sql: {}", query_sql);
+                }
                 let reduced_query = try_reduce_query(conn.clone(), err_code, query).await;
                 tracing::info!("reduced query_sql: {}", reduced_query.to_string());
                 tracing::error!(err);
             }
         }
+        tracing::info!("{} of {} queries failed", failed, self.count);
     }
 }
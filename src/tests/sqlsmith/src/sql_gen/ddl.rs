@@ -200,7 +200,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs)
+        CreateTableSource::Columns(column_defs, None)
     }
 }
 
@@ -273,6 +273,19 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("POINT(0 0)".to_string()),
         },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            value: Literal::String("0 days".to_string()),
+        },
+        TypeName::Vector(dimension) => Expr::Array {
+            span: None,
+            exprs: (0..*dimension)
+                .map(|_| Expr::Literal {
+                    span: None,
+                    value: Literal::Float64(0.0),
+                })
+                .collect(),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             value: Literal::Null,
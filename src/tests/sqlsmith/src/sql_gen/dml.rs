@@ -88,6 +88,8 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             source,
             // TODO
             overwrite: false,
+            on_conflict_update: false,
+            returning: vec![],
         }
     }
 
@@ -100,6 +102,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             hints,
             table: table_reference,
             selection,
+            returning: vec![],
         }
     }
 
@@ -125,6 +128,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             table: table_reference,
             update_list,
             selection,
+            returning: vec![],
         }
     }
 
@@ -487,6 +491,8 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 columns,
                 source,
                 overwrite: false,
+                on_conflict_update: false,
+                returning: vec![],
             })
         } else {
             None
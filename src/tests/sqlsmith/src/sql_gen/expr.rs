@@ -153,6 +153,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -170,6 +171,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -227,6 +229,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -245,6 +248,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -262,6 +266,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -281,6 +286,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -610,6 +616,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args: vec![arg],
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -648,6 +655,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                         args,
                         params: vec![],
                         window: None,
+                        order_by: vec![],
                         lambda: None,
                     },
                 }
@@ -789,6 +797,7 @@ fn convert_to_type_name(ty: &DataType) -> TypeName {
         DataType::Variant => TypeName::Variant,
         DataType::Binary => TypeName::Binary,
         DataType::Geometry => TypeName::Geometry,
+        DataType::Interval => TypeName::Interval,
         DataType::Nullable(box inner_ty) => {
             TypeName::Nullable(Box::new(convert_to_type_name(inner_ty)))
         }
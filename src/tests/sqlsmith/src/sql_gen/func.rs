@@ -471,7 +471,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 (name, params, args_type)
             }
             DataType::Number(NumberDataType::Float64) => {
-                let idx = self.rng.gen_range(0..=14);
+                let idx = self.rng.gen_range(0..=17);
                 let name = match idx {
                     0 => "avg".to_string(),
                     1 => "covar_pop".to_string(),
@@ -488,10 +488,13 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     12 => "quantile_cont".to_string(),
                     13 => "quantile_tdigest".to_string(),
                     14 => "quantile_disc".to_string(),
+                    15 => "corr".to_string(),
+                    16 => "var_pop".to_string(),
+                    17 => "var_samp".to_string(),
                     _ => unreachable!(),
                 };
 
-                let args_type = if idx == 1 || idx == 2 {
+                let args_type = if idx == 1 || idx == 2 || idx == 15 {
                     vec![
                         self.gen_all_number_data_type(),
                         self.gen_all_number_data_type(),
@@ -500,7 +503,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     vec![self.gen_all_number_data_type()]
                 };
 
-                let params = if idx >= 11 {
+                let params = if (11..=14).contains(&idx) {
                     if self.rng.gen_bool(0.5) {
                         vec![Literal::Float64(self.rng.gen_range(0.01..=0.99))]
                     } else {
@@ -772,6 +775,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 name,
                 args,
                 params,
+                order_by: vec![],
                 window,
                 lambda,
             },
@@ -215,6 +215,11 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                             nulls_first: Some(self.flip_coin()),
                         }))
                     }
+                    GroupBy::Fill(fill) => orders.push(OrderByExpr {
+                        expr: fill.expr.clone(),
+                        asc: Some(self.flip_coin()),
+                        nulls_first: Some(self.flip_coin()),
+                    }),
                     GroupBy::All => {
                         for _ in 0..order_nums {
                             let ty = self.gen_data_type();
@@ -539,6 +544,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                     args: vec![arg],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             })
@@ -556,6 +562,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                                     args: vec![arg],
                                     params: vec![],
                                     window: None,
+                                    order_by: vec![],
                                     lambda: None,
                                 },
                             })